@@ -1,5 +1,4 @@
 use a2a_rs::types::{Role, TaskState};
-use serde_json;
 
 fn main() {
     println!("=== TaskState Enum Serialization ===");
@@ -85,7 +84,7 @@ fn main() {
         ("\"unknown\"", "TaskState::Unknown"),
     ];
 
-    for (json_str, expected) in &test_cases {
+    for (json_str, _expected) in &test_cases {
         match serde_json::from_str::<TaskState>(json_str) {
             Ok(state) => println!("{} => {:?} ✓", json_str, state),
             Err(e) => println!("{} => ERROR: {} ✗", json_str, e),
@@ -100,7 +99,7 @@ fn main() {
         ("\"unspecified\"", "Role::Unspecified"),
     ];
 
-    for (json_str, expected) in &role_test_cases {
+    for (json_str, _expected) in &role_test_cases {
         match serde_json::from_str::<Role>(json_str) {
             Ok(role) => println!("{} => {:?} ✓", json_str, role),
             Err(e) => println!("{} => ERROR: {} ✗", json_str, e),
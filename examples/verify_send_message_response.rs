@@ -18,6 +18,7 @@ fn main() {
             state: TaskState::Working,
             message: Some(Message::agent("msg-1", "Processing your request")),
             timestamp: Some("2025-01-15T10:30:00Z".to_string()),
+            raw_state: None,
         },
         artifacts: None,
         history: None,
@@ -0,0 +1,359 @@
+//! Procedural macros for `a2a-rs`, enabled with its `macros` feature and
+//! re-exported as `a2a_rs::server::skills`.
+//!
+//! See [`macro@skills`].
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::parse::ParseStream;
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{
+    parse_macro_input, Attribute, Expr, ExprLit, FnArg, ImplItem, ImplItemFn, ItemImpl, Lit,
+    LitStr, Meta, Token,
+};
+
+/// Implement `AgentExecutor` for a type by dispatching each message to the
+/// method declared for the requested skill.
+///
+/// Put `#[skills]` on an inherent `impl` block and `#[skill(...)]` on its
+/// skill handlers — `async fn(&self, RequestContext, EventQueue) ->
+/// A2AResult<()>` methods. The skill is read from the `skillId` metadata of
+/// the message (or, failing that, of the request); a message without one
+/// goes to the skill marked `default`, or to the only skill if there is
+/// just one. Unknown skills are rejected with `InvalidParams`.
+///
+/// `#[skill(...)]` takes:
+///
+/// - `id = "..."` (required)
+/// - `name = "..."` — defaults to the id
+/// - `description = "..."` — defaults to the method's doc comment
+/// - `tags(...)`, `examples(...)`, `input_modes(...)`, `output_modes(...)`
+///   — lists of string literals
+/// - `default` — the skill for messages that name none
+///
+/// An `async fn(&self, RequestContext, EventQueue) -> A2AResult<()>` marked
+/// `#[cancel]` handles `tasks/cancel`; without one the task is moved to
+/// `canceled`.
+///
+/// The block also gains `fn skills() -> Vec<AgentSkill>`, the declared
+/// skills for `AgentCardBuilder::with_skills`.
+///
+/// ```rust,ignore
+/// use a2a_rs::server::{skills, EventQueue, RequestContext};
+/// use a2a_rs::A2AResult;
+///
+/// struct Translator;
+///
+/// #[skills]
+/// impl Translator {
+///     /// Translate text between languages.
+///     #[skill(id = "translate", tags("i18n", "text"), default)]
+///     async fn translate(&self, context: RequestContext, queue: EventQueue) -> A2AResult<()> {
+///         let updater = context.task_updater(queue);
+///         updater.complete_with_text("Bonjour").await
+///     }
+/// }
+///
+/// let card = AgentCardBuilder::new("Translator", "Translates text", "1.0.0")
+///     .with_skills(Translator::skills())
+///     .build();
+/// ```
+#[proc_macro_attribute]
+pub fn skills(args: TokenStream, input: TokenStream) -> TokenStream {
+    let args = TokenStream2::from(args);
+    if !args.is_empty() {
+        return syn::Error::new(args.span(), "#[skills] takes no arguments")
+            .to_compile_error()
+            .into();
+    }
+    let item = parse_macro_input!(input as ItemImpl);
+    expand(item)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// One `#[skill(...)]` method.
+struct Skill {
+    method: syn::Ident,
+    id: LitStr,
+    name: Option<LitStr>,
+    description: Option<LitStr>,
+    tags: Vec<LitStr>,
+    examples: Option<Vec<LitStr>>,
+    input_modes: Option<Vec<LitStr>>,
+    output_modes: Option<Vec<LitStr>>,
+    default: bool,
+}
+
+fn expand(mut item: ItemImpl) -> syn::Result<TokenStream2> {
+    if let Some((_, path, _)) = &item.trait_ {
+        return Err(syn::Error::new(
+            path.span(),
+            "#[skills] goes on an inherent impl block, not a trait impl",
+        ));
+    }
+
+    let mut skills: Vec<Skill> = Vec::new();
+    let mut cancel: Option<syn::Ident> = None;
+    for impl_item in &mut item.items {
+        let ImplItem::Fn(method) = impl_item else {
+            continue;
+        };
+        if let Some(attr) = take_attr(&mut method.attrs, "cancel") {
+            attr.meta.require_path_only()?;
+            check_handler(method)?;
+            if cancel.is_some() {
+                return Err(syn::Error::new(
+                    attr.span(),
+                    "only one method can be marked #[cancel]",
+                ));
+            }
+            cancel = Some(method.sig.ident.clone());
+        }
+        if let Some(attr) = take_attr(&mut method.attrs, "skill") {
+            check_handler(method)?;
+            let skill = parse_skill(&attr, method)?;
+            if let Some(other) = skills.iter().find(|s| s.id.value() == skill.id.value()) {
+                return Err(syn::Error::new(
+                    skill.id.span(),
+                    format!(
+                        "skill `{}` is already declared on `{}`",
+                        skill.id.value(),
+                        other.method
+                    ),
+                ));
+            }
+            if skill.default && skills.iter().any(|s| s.default) {
+                return Err(syn::Error::new(
+                    attr.span(),
+                    "only one skill can be the default",
+                ));
+            }
+            skills.push(skill);
+        }
+    }
+    if skills.is_empty() {
+        return Err(syn::Error::new(
+            item.self_ty.span(),
+            "#[skills] needs at least one #[skill(...)] method",
+        ));
+    }
+
+    let self_ty = &item.self_ty;
+    let (impl_generics, _, where_clause) = item.generics.split_for_impl();
+
+    let default_skill = match skills.iter().find(|s| s.default) {
+        Some(skill) => {
+            let id = &skill.id;
+            quote!(::core::option::Option::Some(#id))
+        }
+        None if skills.len() == 1 => {
+            let id = &skills[0].id;
+            quote!(::core::option::Option::Some(#id))
+        }
+        None => quote!(::core::option::Option::None),
+    };
+    let arms = skills.iter().map(|skill| {
+        let id = &skill.id;
+        let method = &skill.method;
+        quote!(#id => self.#method(context, event_queue).await,)
+    });
+    let cancel_body = match &cancel {
+        Some(method) => quote!(self.#method(context, event_queue).await),
+        None => quote! {
+            context.task_updater(event_queue).cancel(::core::option::Option::None).await
+        },
+    };
+    let cards = skills.iter().map(skill_card);
+
+    Ok(quote! {
+        #item
+
+        impl #impl_generics #self_ty #where_clause {
+            /// The skills declared with `#[skill]`, for the agent card.
+            pub fn skills() -> ::std::vec::Vec<::a2a_rs::types::AgentSkill> {
+                ::std::vec![#(#cards),*]
+            }
+        }
+
+        #[::a2a_rs::__private::async_trait]
+        impl #impl_generics ::a2a_rs::server::AgentExecutor for #self_ty #where_clause {
+            async fn execute(
+                &self,
+                context: ::a2a_rs::server::RequestContext,
+                event_queue: ::a2a_rs::server::EventQueue,
+            ) -> ::a2a_rs::error::A2AResult<()> {
+                let default_skill: ::core::option::Option<&str> = #default_skill;
+                let skill = match context.requested_skill() {
+                    ::core::option::Option::Some(skill) => skill.to_string(),
+                    ::core::option::Option::None => match default_skill {
+                        ::core::option::Option::Some(skill) => skill.to_string(),
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                ::a2a_rs::error::A2AError::invalid_params(
+                                    "No skill requested: set `skillId` in the message metadata",
+                                ),
+                            );
+                        }
+                    },
+                };
+                match skill.as_str() {
+                    #(#arms)*
+                    other => ::core::result::Result::Err(
+                        ::a2a_rs::error::A2AError::invalid_params(
+                            ::std::format!("Unknown skill: {other}"),
+                        ),
+                    ),
+                }
+            }
+
+            async fn cancel(
+                &self,
+                context: ::a2a_rs::server::RequestContext,
+                event_queue: ::a2a_rs::server::EventQueue,
+            ) -> ::a2a_rs::error::A2AResult<()> {
+                #cancel_body
+            }
+        }
+    })
+}
+
+/// Remove and return the attribute named `name`.
+fn take_attr(attrs: &mut Vec<Attribute>, name: &str) -> Option<Attribute> {
+    let index = attrs.iter().position(|attr| attr.path().is_ident(name))?;
+    Some(attrs.remove(index))
+}
+
+/// Check that `method` is an `async fn(&self, _, _)`.
+fn check_handler(method: &ImplItemFn) -> syn::Result<()> {
+    let sig = &method.sig;
+    if sig.asyncness.is_none() {
+        return Err(syn::Error::new(
+            sig.fn_token.span(),
+            "skill handlers must be `async fn`",
+        ));
+    }
+    let by_ref_self = matches!(
+        sig.inputs.first(),
+        Some(FnArg::Receiver(receiver)) if receiver.reference.is_some() && receiver.mutability.is_none()
+    );
+    if !by_ref_self || sig.inputs.len() != 3 {
+        return Err(syn::Error::new(
+            sig.inputs.span(),
+            "skill handlers take `(&self, RequestContext, EventQueue)`",
+        ));
+    }
+    Ok(())
+}
+
+fn parse_skill(attr: &Attribute, method: &ImplItemFn) -> syn::Result<Skill> {
+    let mut skill = Skill {
+        method: method.sig.ident.clone(),
+        id: LitStr::new("", Span::call_site()),
+        name: None,
+        description: None,
+        tags: Vec::new(),
+        examples: None,
+        input_modes: None,
+        output_modes: None,
+        default: false,
+    };
+    let mut id = None;
+    attr.parse_nested_meta(|meta| {
+        if meta.path.is_ident("id") {
+            id = Some(meta.value()?.parse::<LitStr>()?);
+        } else if meta.path.is_ident("name") {
+            skill.name = Some(meta.value()?.parse()?);
+        } else if meta.path.is_ident("description") {
+            skill.description = Some(meta.value()?.parse()?);
+        } else if meta.path.is_ident("tags") {
+            skill.tags = parse_list(meta.input)?;
+        } else if meta.path.is_ident("examples") {
+            skill.examples = Some(parse_list(meta.input)?);
+        } else if meta.path.is_ident("input_modes") {
+            skill.input_modes = Some(parse_list(meta.input)?);
+        } else if meta.path.is_ident("output_modes") {
+            skill.output_modes = Some(parse_list(meta.input)?);
+        } else if meta.path.is_ident("default") {
+            skill.default = true;
+        } else {
+            return Err(meta.error(
+                "expected `id`, `name`, `description`, `tags`, `examples`, \
+                 `input_modes`, `output_modes` or `default`",
+            ));
+        }
+        Ok(())
+    })?;
+    skill.id = id.ok_or_else(|| syn::Error::new(attr.span(), "#[skill] needs `id = \"...\"`"))?;
+    if skill.description.is_none() {
+        skill.description = doc_comment(&method.attrs);
+    }
+    Ok(skill)
+}
+
+/// `("a", "b", ...)`.
+fn parse_list(input: ParseStream) -> syn::Result<Vec<LitStr>> {
+    let content;
+    syn::parenthesized!(content in input);
+    let items = Punctuated::<LitStr, Token![,]>::parse_terminated(&content)?;
+    Ok(items.into_iter().collect())
+}
+
+/// The `///` comment of a method, one line per line.
+fn doc_comment(attrs: &[Attribute]) -> Option<LitStr> {
+    let lines: Vec<String> = attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .filter_map(|attr| match &attr.meta {
+            Meta::NameValue(nv) => match &nv.value {
+                Expr::Lit(ExprLit {
+                    lit: Lit::Str(doc), ..
+                }) => Some(doc.value().trim().to_string()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect();
+    let doc = lines.join("\n").trim().to_string();
+    (!doc.is_empty()).then(|| LitStr::new(&doc, Span::call_site()))
+}
+
+/// The `AgentSkill` expression for `skill`.
+fn skill_card(skill: &Skill) -> TokenStream2 {
+    let id = &skill.id;
+    let name = skill.name.as_ref().unwrap_or(&skill.id);
+    let description = skill
+        .description
+        .clone()
+        .unwrap_or_else(|| LitStr::new("", Span::call_site()));
+    let tags = &skill.tags;
+    let examples = optional_list(&skill.examples);
+    let input_modes = optional_list(&skill.input_modes);
+    let output_modes = optional_list(&skill.output_modes);
+    quote! {
+        ::a2a_rs::types::AgentSkill {
+            id: #id.to_string(),
+            name: #name.to_string(),
+            description: #description.to_string(),
+            tags: ::std::vec![#(#tags.to_string()),*],
+            examples: #examples,
+            input_modes: #input_modes,
+            output_modes: #output_modes,
+            security_requirements: ::core::option::Option::None,
+            security: ::core::option::Option::None,
+        }
+    }
+}
+
+fn optional_list(list: &Option<Vec<LitStr>>) -> TokenStream2 {
+    match list {
+        Some(items) => {
+            quote!(::core::option::Option::Some(
+                ::std::vec![#(#items.to_string()),*]
+            ))
+        }
+        None => quote!(::core::option::Option::None),
+    }
+}
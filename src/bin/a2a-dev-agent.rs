@@ -0,0 +1,118 @@
+//! `a2a-dev-agent` — ready-to-run reference agent for client development.
+//!
+//! Run with:
+//! ```sh
+//! cargo run --features dev-agent --bin a2a-dev-agent -- --help
+//! ```
+
+use std::process::ExitCode;
+use std::sync::Arc;
+use std::time::Duration;
+
+use a2a_rs::server::{
+    a2a_router, DefaultRequestHandler, DevAgent, DevAgentConfig, InMemoryTaskStore,
+};
+
+const USAGE: &str = "\
+Usage: a2a-dev-agent [OPTIONS]
+
+Options:
+  --addr <HOST:PORT>     Listen address [default: 127.0.0.1:7420]
+  --public-url <URL>     Base URL advertised in the agent card [default: http://<addr>]
+  --mode <echo|mirror>   Echo the text, or mirror all parts [default: echo]
+  --delay-ms <MS>        Pause before each artifact chunk [default: 0]
+  --fail-rate <0..1>     Probability that a task fails [default: 0]
+  --chunks <N>           Split the echo reply into N artifact chunks [default: 1]
+  --input-required       Ask for input before replying
+  -h, --help             Print this help
+
+Messages can override the behaviour with metadata, e.g.
+  {\"devAgent\": {\"delayMs\": 500, \"failRate\": 0.5, \"chunks\": 4}}";
+
+struct Args {
+    addr: String,
+    public_url: Option<String>,
+    config: DevAgentConfig,
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Option<Args>, String> {
+    let mut parsed = Args {
+        addr: "127.0.0.1:7420".to_string(),
+        public_url: None,
+        config: DevAgentConfig::default(),
+    };
+    while let Some(flag) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("{flag} requires a value"))
+        };
+        let number = |v: String| v.parse::<f64>().map_err(|e| format!("{flag}: {e}"));
+        match flag.as_str() {
+            "-h" | "--help" => return Ok(None),
+            "--addr" => parsed.addr = value()?,
+            "--public-url" => parsed.public_url = Some(value()?),
+            "--mode" => {
+                parsed.config = parsed
+                    .config
+                    .with_mode(value()?.parse().map_err(|e| format!("{e}"))?)
+            }
+            "--delay-ms" => {
+                let ms = value()?.parse().map_err(|e| format!("{flag}: {e}"))?;
+                parsed.config = parsed.config.with_delay(Duration::from_millis(ms));
+            }
+            "--fail-rate" => parsed.config = parsed.config.with_fail_rate(number(value()?)?),
+            "--chunks" => {
+                let chunks = value()?.parse().map_err(|e| format!("{flag}: {e}"))?;
+                parsed.config = parsed.config.with_chunks(chunks);
+            }
+            "--input-required" => parsed.config = parsed.config.with_input_required(true),
+            other => return Err(format!("unknown option '{other}'")),
+        }
+    }
+    Ok(Some(parsed))
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let args = match parse_args(std::env::args().skip(1)) {
+        Ok(Some(args)) => args,
+        Ok(None) => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Err(e) => {
+            eprintln!("error: {e}\n\n{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let listener = match tokio::net::TcpListener::bind(&args.addr).await {
+        Ok(listener) => listener,
+        Err(e) => {
+            eprintln!("error: cannot listen on {}: {e}", args.addr);
+            return ExitCode::FAILURE;
+        }
+    };
+    let base_url = args
+        .public_url
+        .unwrap_or_else(|| format!("http://{}", args.addr));
+    let base_url = base_url.trim_end_matches('/');
+
+    let card = DevAgent::agent_card(&format!("{base_url}/a2a"));
+    let handler = Arc::new(DefaultRequestHandler::new(
+        Arc::new(DevAgent::new(args.config.clone())),
+        Arc::new(InMemoryTaskStore::new()),
+    ));
+    let app = a2a_router(handler, card);
+
+    println!("A2A Dev Agent listening on {base_url}");
+    println!("  Agent card: {base_url}/.well-known/agent.json");
+    println!("  A2A endpoint: {base_url}/a2a");
+    println!("  Behaviour: {:?}", args.config);
+
+    if let Err(e) = axum::serve(listener, app).await {
+        eprintln!("error: {e}");
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
@@ -75,7 +75,7 @@ impl AgentCardBuilder {
             icon_url: None,
             additional_interfaces: None,
             preferred_transport: None,
-            protocol_version: Some("0.3".to_string()),
+            protocol_version: Some(crate::utils::PROTOCOL_VERSION.to_string()),
             url: String::new(),
             supports_authenticated_extended_card: None,
             security: None,
@@ -87,9 +87,9 @@ impl AgentCardBuilder {
         let url_str = url.into();
         self.supported_interfaces.push(AgentInterface {
             url: url_str.clone(),
-            protocol_binding: "JSONRPC".to_string(),
+            transport: "JSONRPC".to_string(),
             tenant: None,
-            protocol_version: Some("0.3".to_string()),
+            protocol_version: Some(crate::utils::PROTOCOL_VERSION.to_string()),
         });
         if self.url.is_empty() {
             self.url = url_str;
@@ -134,6 +134,14 @@ impl AgentCardBuilder {
         self
     }
 
+    /// Declare whether task history includes the agent's status messages
+    /// (`stateTransitionHistory`). [`ServerBuilder`] records them only if
+    /// this is enabled.
+    pub fn with_state_transition_history(mut self, enabled: bool) -> Self {
+        self.capabilities.state_transition_history = Some(enabled);
+        self
+    }
+
     /// Add a protocol extension.
     pub fn with_extension(
         mut self,
@@ -198,6 +206,13 @@ impl AgentCardBuilder {
         self
     }
 
+    /// Add several skills, e.g. those declared with `#[skills]` (`macros`
+    /// feature).
+    pub fn with_skills(mut self, skills: impl IntoIterator<Item = AgentSkill>) -> Self {
+        self.skills.extend(skills);
+        self
+    }
+
     /// Set the default input MIME types.
     pub fn with_input_modes(mut self, modes: Vec<String>) -> Self {
         self.default_input_modes = modes;
@@ -247,6 +262,19 @@ impl AgentCardBuilder {
             security: self.security,
         }
     }
+
+    /// Build the [`AgentCard`] and [validate](AgentCard::validate) it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`A2AError::InvalidAgentCard`](crate::error::A2AError::InvalidAgentCard)
+    /// listing every spec violation.
+    pub fn try_build(self) -> crate::error::A2AResult<AgentCard> {
+        let card = self.build();
+        card.validate()
+            .map_err(crate::error::A2AError::invalid_agent_card)?;
+        Ok(card)
+    }
 }
 
 /// Builder for constructing [`crate::client::A2AClient`] with custom configuration.
@@ -266,11 +294,59 @@ impl AgentCardBuilder {
 /// # }
 /// ```
 #[cfg(feature = "client")]
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct ClientBuilder {
     url: String,
     timeout: Option<std::time::Duration>,
     headers: HashMap<String, String>,
+    observer: Option<std::sync::Arc<dyn crate::client::TransportObserver>>,
+    credentials: Option<std::sync::Arc<dyn crate::client::CredentialService>>,
+    auth_providers: Vec<std::sync::Arc<dyn crate::client::AuthProvider>>,
+    id_correlation: crate::client::IdCorrelation,
+    not_found_retry: Option<std::time::Duration>,
+    stream_interceptors: Vec<std::sync::Arc<dyn crate::client::StreamInterceptor>>,
+    transport_interceptors: Vec<std::sync::Arc<dyn crate::client::TransportInterceptor>>,
+    sse_reconnect: Option<crate::client::SseReconnectPolicy>,
+    retry: Option<crate::client::RetryPolicy>,
+    /// Connection pool and keep-alive settings for `JSONRPC` transports.
+    connection: crate::client::TransportConfig,
+    preferred_transports: Vec<String>,
+    schema_diagnostics: bool,
+    file_uploader: Option<std::sync::Arc<dyn crate::client::FileUploader>>,
+    inline_file_limit: Option<u64>,
+    client_id: Option<String>,
+    check_protocol_version: bool,
+    card_validation: bool,
+    card_cache: Option<std::sync::Arc<crate::client::AgentCardCache>>,
+}
+
+#[cfg(feature = "client")]
+impl std::fmt::Debug for ClientBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClientBuilder")
+            .field("url", &self.url)
+            .field("timeout", &self.timeout)
+            .field("headers", &self.headers)
+            .field("observer", &self.observer.is_some())
+            .field("credentials", &self.credentials.is_some())
+            .field("auth_providers", &self.auth_providers.len())
+            .field("id_correlation", &self.id_correlation)
+            .field("not_found_retry", &self.not_found_retry)
+            .field("stream_interceptors", &self.stream_interceptors.len())
+            .field("transport_interceptors", &self.transport_interceptors.len())
+            .field("sse_reconnect", &self.sse_reconnect)
+            .field("retry", &self.retry)
+            .field("connection", &self.connection)
+            .field("preferred_transports", &self.preferred_transports)
+            .field("schema_diagnostics", &self.schema_diagnostics)
+            .field("file_uploader", &self.file_uploader.is_some())
+            .field("inline_file_limit", &self.inline_file_limit)
+            .field("client_id", &self.client_id)
+            .field("check_protocol_version", &self.check_protocol_version)
+            .field("card_validation", &self.card_validation)
+            .field("card_cache", &self.card_cache.is_some())
+            .finish()
+    }
 }
 
 #[cfg(feature = "client")]
@@ -281,6 +357,24 @@ impl ClientBuilder {
             url: url.into(),
             timeout: None,
             headers: HashMap::new(),
+            observer: None,
+            credentials: None,
+            auth_providers: Vec::new(),
+            id_correlation: crate::client::IdCorrelation::default(),
+            not_found_retry: None,
+            stream_interceptors: Vec::new(),
+            transport_interceptors: Vec::new(),
+            sse_reconnect: None,
+            retry: None,
+            connection: crate::client::TransportConfig::default(),
+            preferred_transports: Vec::new(),
+            schema_diagnostics: false,
+            file_uploader: None,
+            inline_file_limit: None,
+            client_id: None,
+            check_protocol_version: true,
+            card_validation: false,
+            card_cache: None,
         }
     }
 
@@ -296,6 +390,42 @@ impl ClientBuilder {
         self
     }
 
+    /// Identify the calling application (e.g. `my-app/2.3`) on every
+    /// request, including agent card resolution. See
+    /// [`JsonRpcTransport::with_client_id`](crate::client::JsonRpcTransport::with_client_id).
+    pub fn with_client_id(mut self, app: impl Into<String>) -> Self {
+        self.client_id = Some(app.into());
+        self
+    }
+
+    /// Check the resolved card's `protocolVersion` in
+    /// [`build()`](Self::build) (default `true`).
+    ///
+    /// Disable to talk to agents declaring a version outside
+    /// [`ProtocolVersionRange::supported`](crate::utils::ProtocolVersionRange::supported)
+    /// anyway.
+    pub fn with_protocol_version_check(mut self, check: bool) -> Self {
+        self.check_protocol_version = check;
+        self
+    }
+
+    /// Check outgoing messages against the resolved card before sending.
+    /// Extensions requested with [`with_extensions`](Self::with_extensions)
+    /// count as supported. See
+    /// [`A2AClient::with_card_validation`](crate::client::A2AClient::with_card_validation).
+    ///
+    /// Only [`build()`](Self::build) resolves a card to check against.
+    pub fn with_card_validation(mut self, enabled: bool) -> Self {
+        self.card_validation = enabled;
+        self
+    }
+
+    /// Replace the default `User-Agent` (`a2a-rs/<version>`) of JSON-RPC
+    /// calls.
+    pub fn with_user_agent(self, user_agent: impl Into<String>) -> Self {
+        self.with_header("User-Agent", user_agent)
+    }
+
     /// Add an Authorization header with a bearer token.
     pub fn with_bearer_token(mut self, token: impl Into<String>) -> Self {
         self.headers.insert(
@@ -315,24 +445,326 @@ impl ClientBuilder {
         self
     }
 
+    /// Request A2A extensions on every call via the `X-A2A-Extensions`
+    /// header.
+    pub fn with_extensions<I, S>(self, uris: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let value = uris
+            .into_iter()
+            .map(Into::into)
+            .collect::<Vec<String>>()
+            .join(",");
+        self.with_header(crate::utils::HTTP_EXTENSION_HEADER, value)
+    }
+
+    /// Opt into Data part delta compression on streams.
+    ///
+    /// Adds [`DATA_DELTA_EXTENSION_URI`](crate::utils::DATA_DELTA_EXTENSION_URI)
+    /// to the requested extensions (keeping any already requested) and
+    /// installs a [`DataDeltaDecoder`](crate::utils::DataDeltaDecoder) ahead
+    /// of other stream interceptors, so consumers always see full Data parts.
+    /// Has no effect on the wire unless the agent advertises the extension.
+    pub fn with_data_delta(mut self) -> Self {
+        self.request_extension(crate::utils::DATA_DELTA_EXTENSION_URI);
+        self.stream_interceptors.insert(
+            0,
+            std::sync::Arc::new(crate::utils::DataDeltaDecoder::new()),
+        );
+        self
+    }
+
+    /// Opt into chunked `tasks/get` responses for very large tasks.
+    ///
+    /// Adds [`CHUNKED_TASK_EXTENSION_URI`](crate::utils::CHUNKED_TASK_EXTENSION_URI)
+    /// to the requested extensions (keeping any already requested). The
+    /// transport reassembles chunked responses, so `get_task` still returns
+    /// the full task. Has no effect on the wire unless the agent advertises
+    /// the extension.
+    pub fn with_chunked_tasks(mut self) -> Self {
+        self.request_extension(crate::utils::CHUNKED_TASK_EXTENSION_URI);
+        self
+    }
+
+    /// Add `uri` to the requested extensions unless already there.
+    fn request_extension(&mut self, uri: &str) {
+        let header = self
+            .headers
+            .entry(crate::utils::HTTP_EXTENSION_HEADER.to_string())
+            .or_default();
+        if !header.split(',').any(|requested| requested.trim() == uri) {
+            if !header.is_empty() {
+                header.push(',');
+            }
+            header.push_str(uri);
+        }
+    }
+
+    /// Attach a [`TransportObserver`](crate::client::TransportObserver) that
+    /// receives per-call metrics.
+    pub fn with_observer(
+        mut self,
+        observer: std::sync::Arc<dyn crate::client::TransportObserver>,
+    ) -> Self {
+        self.observer = Some(observer);
+        self
+    }
+
+    /// Attach a [`CredentialService`](crate::client::CredentialService)
+    /// consulted before every request. Streams reconnect transparently
+    /// before an expiring credential runs out.
+    pub fn with_credentials(
+        mut self,
+        credentials: std::sync::Arc<dyn crate::client::CredentialService>,
+    ) -> Self {
+        self.credentials = Some(credentials);
+        self
+    }
+
+    /// Add an [`AuthProvider`](crate::client::AuthProvider). On
+    /// [`build()`](Self::build) an
+    /// [`Authenticator`](crate::client::Authenticator) selects the card's
+    /// security scheme and the provider for it, trying providers in the
+    /// order added. Ignored if [`with_credentials`](Self::with_credentials)
+    /// is set.
+    pub fn with_auth_provider(
+        mut self,
+        provider: std::sync::Arc<dyn crate::client::AuthProvider>,
+    ) -> Self {
+        self.auth_providers.push(provider);
+        self
+    }
+
+    /// How response ids are checked against request ids. Defaults to
+    /// [`IdCorrelation::Strict`](crate::client::IdCorrelation::Strict).
+    pub fn with_id_correlation(mut self, mode: crate::client::IdCorrelation) -> Self {
+        self.id_correlation = mode;
+        self
+    }
+
+    /// Retry `tasks/get` on `TaskNotFound` for up to `window` after the
+    /// client created the task. See [`A2AClient::with_not_found_retry`].
+    ///
+    /// [`A2AClient::with_not_found_retry`]: crate::client::A2AClient::with_not_found_retry
+    pub fn with_not_found_retry(mut self, window: std::time::Duration) -> Self {
+        self.not_found_retry = Some(window);
+        self
+    }
+
+    /// Explain responses that fail to decode with a field path and payload
+    /// excerpt. See [`A2AClient::with_schema_diagnostics`].
+    ///
+    /// [`A2AClient::with_schema_diagnostics`]: crate::client::A2AClient::with_schema_diagnostics
+    pub fn with_schema_diagnostics(mut self, enabled: bool) -> Self {
+        self.schema_diagnostics = enabled;
+        self
+    }
+
+    /// Register a [`StreamInterceptor`](crate::client::StreamInterceptor)
+    /// applied to every streaming event. Interceptors run in registration
+    /// order.
+    pub fn with_stream_interceptor(
+        mut self,
+        interceptor: std::sync::Arc<dyn crate::client::StreamInterceptor>,
+    ) -> Self {
+        self.stream_interceptors.push(interceptor);
+        self
+    }
+
+    /// Register a [`TransportInterceptor`](crate::client::TransportInterceptor)
+    /// wrapped around every JSON-RPC call and stream establishment.
+    /// Interceptors see requests in registration order and responses in
+    /// reverse order. Only applies to `JSONRPC` interfaces.
+    pub fn with_transport_interceptor(
+        mut self,
+        interceptor: std::sync::Arc<dyn crate::client::TransportInterceptor>,
+    ) -> Self {
+        self.transport_interceptors.push(interceptor);
+        self
+    }
+
+    /// Reconnect streams that drop mid-task, resuming them with
+    /// `tasks/resubscribe` and `Last-Event-ID`. See
+    /// [`JsonRpcTransport::with_sse_reconnect`]. Only applies to `JSONRPC`
+    /// interfaces.
+    ///
+    /// [`JsonRpcTransport::with_sse_reconnect`]: crate::client::JsonRpcTransport::with_sse_reconnect
+    pub fn with_sse_reconnect(mut self, policy: crate::client::SseReconnectPolicy) -> Self {
+        self.sse_reconnect = Some(policy);
+        self
+    }
+
+    /// Retry calls that fail transiently per `policy`. See
+    /// [`RetryPolicy`](crate::client::RetryPolicy). Only applies to
+    /// `JSONRPC` interfaces.
+    pub fn with_retry(mut self, policy: crate::client::RetryPolicy) -> Self {
+        self.retry = Some(policy);
+        self
+    }
+
+    /// Keep at most `max` idle connections to the agent open. See
+    /// [`TransportConfig`](crate::client::TransportConfig) for this and the
+    /// other connection settings, which only apply to `JSONRPC` interfaces.
+    pub fn with_pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.connection.pool_max_idle_per_host = Some(max);
+        self
+    }
+
+    /// Close connections idle for `timeout`.
+    pub fn with_pool_idle_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.connection.pool_idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Give up connecting to the agent after `timeout`.
+    pub fn with_connect_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.connection.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Send TCP keepalive probes every `interval`.
+    pub fn with_tcp_keepalive(mut self, interval: std::time::Duration) -> Self {
+        self.connection.tcp_keepalive = Some(interval);
+        self
+    }
+
+    /// Apply HTTP/2 `settings`, e.g. keepalive pings. See
+    /// [`Http2Settings`](crate::client::Http2Settings).
+    pub fn with_http2(mut self, settings: crate::client::Http2Settings) -> Self {
+        self.connection.http2 = Some(settings);
+        self
+    }
+
+    /// Ask for streams in `encoding`, e.g. NDJSON behind proxies that strip
+    /// SSE. See [`StreamEncoding`](crate::utils::StreamEncoding).
+    pub fn with_stream_encoding(mut self, encoding: crate::utils::StreamEncoding) -> Self {
+        self.connection.stream_encoding = encoding;
+        self
+    }
+
+    /// Connect over `transport` (e.g. `"JSONRPC"`, `"GRPC"`) when the agent
+    /// card offers it, overriding the card's `preferred_transport`.
+    ///
+    /// Call repeatedly to rank several transports, most preferred first.
+    /// Transports this build can't speak are skipped, and if the card
+    /// offers none of them [`build()`](Self::build) negotiates as usual.
+    pub fn with_preferred_transport(mut self, transport: impl Into<String>) -> Self {
+        self.preferred_transports.push(transport.into());
+        self
+    }
+
+    /// Upload attached files over the inline limit with `uploader`. See
+    /// [`A2AClient::with_file_uploader`].
+    ///
+    /// [`A2AClient::with_file_uploader`]: crate::client::A2AClient::with_file_uploader
+    pub fn with_file_uploader(
+        mut self,
+        uploader: std::sync::Arc<dyn crate::client::FileUploader>,
+    ) -> Self {
+        self.file_uploader = Some(uploader);
+        self
+    }
+
+    /// Send attached files up to `bytes` inline. See
+    /// [`A2AClient::with_inline_file_limit`].
+    ///
+    /// [`A2AClient::with_inline_file_limit`]: crate::client::A2AClient::with_inline_file_limit
+    pub fn with_inline_file_limit(mut self, bytes: u64) -> Self {
+        self.inline_file_limit = Some(bytes);
+        self
+    }
+
+    /// Resolve the agent card through `cache` (see
+    /// [`AgentCardCache`](crate::client::AgentCardCache)), e.g.
+    /// [`AgentCardCache::shared()`](crate::client::AgentCardCache::shared).
+    ///
+    /// Without one, [`build()`](Self::build) always fetches the card.
+    pub fn with_card_cache(mut self, cache: std::sync::Arc<crate::client::AgentCardCache>) -> Self {
+        self.card_cache = Some(cache);
+        self
+    }
+
     /// Build the client by resolving the agent card and creating the transport.
-    pub async fn build(self) -> crate::A2AResult<crate::client::A2AClient> {
-        use crate::client::{A2AClient, CardResolver, JsonRpcTransport};
+    ///
+    /// The interface is negotiated as in
+    /// [`A2AClient::from_card`](crate::client::A2AClient::from_card), with
+    /// [`with_preferred_transport`](Self::with_preferred_transport)
+    /// preferences taking precedence over the card's. With
+    /// [`with_credentials`](Self::with_credentials) or
+    /// [`with_auth_provider`](Self::with_auth_provider) only `JSONRPC`
+    /// interfaces are considered; over `GRPC` the id-correlation setting,
+    /// transport interceptors and SSE reconnects do not apply.
+    pub async fn build(mut self) -> crate::A2AResult<crate::client::A2AClient> {
+        use crate::client::{A2AClient, Authenticator, CardResolver, JsonRpcTransport};
 
         // Resolve the agent card.
-        let resolver = CardResolver::new();
+        let mut resolver = CardResolver::for_app(self.client_id.as_deref());
+        if let Some(cache) = self.card_cache.take() {
+            resolver = resolver.with_cache(cache);
+        }
         let card = resolver.resolve(&self.url).await?;
+        if self.check_protocol_version {
+            crate::utils::check_card_protocol_version(&card)?;
+        }
+        if self.credentials.is_none() && !self.auth_providers.is_empty() {
+            let providers = std::mem::take(&mut self.auth_providers);
+            self.credentials = Some(std::sync::Arc::new(Authenticator::for_card(
+                &card, providers,
+            )?));
+        }
 
-        // Extract the JSON-RPC endpoint.
-        let endpoint_url = CardResolver::get_a2a_url(&card).ok_or_else(|| {
-            crate::error::A2AError::Transport(format!(
-                "agent card for '{}' has no JSONRPC interface",
-                card.name
-            ))
-        })?;
+        // Pick the interface. Credentials only apply to JSON-RPC, so they
+        // rule out other transports.
+        let supported: &[&str] = if self.credentials.is_some() {
+            &["JSONRPC"]
+        } else {
+            CardResolver::SUPPORTED_TRANSPORTS
+        };
+        let preferences: Vec<&str> = self
+            .preferred_transports
+            .iter()
+            .map(String::as_str)
+            .collect();
+        let iface = CardResolver::negotiate_interface_preferring(&card, supported, &preferences)
+            .ok_or_else(|| {
+                crate::error::A2AError::Transport(format!(
+                    "agent card for '{}' has no {} interface",
+                    card.name,
+                    supported.join(" or ")
+                ))
+            })?;
+        let downloader = self.artifact_downloader(&iface.url);
+        let extensions = crate::utils::get_requested_extensions(
+            &self
+                .headers
+                .iter()
+                .filter(|(key, _)| key.eq_ignore_ascii_case(crate::utils::HTTP_EXTENSION_HEADER))
+                .map(|(_, value)| value.clone())
+                .collect::<Vec<_>>(),
+        );
+        #[cfg(feature = "grpc")]
+        if iface.transport.eq_ignore_ascii_case("GRPC") {
+            let transport = self.grpc_transport(&iface.url)?;
+            let client = A2AClient::with_transport(Box::new(transport));
+            return Ok(finish_client(
+                client,
+                self.not_found_retry,
+                self.stream_interceptors,
+                self.schema_diagnostics,
+                self.file_uploader,
+                self.inline_file_limit,
+                downloader,
+            )
+            .with_agent_card(card)
+            .with_card_source(resolver, &self.url)
+            .with_card_validation(self.card_validation)
+            .with_supported_extensions(extensions));
+        }
 
         // Create a custom transport with the configuration.
-        let mut transport = JsonRpcTransport::new(endpoint_url);
+        let mut transport = JsonRpcTransport::with_config(&iface.url, self.connection);
 
         // Apply timeout if specified.
         if let Some(timeout) = self.timeout {
@@ -344,14 +776,89 @@ impl ClientBuilder {
             transport = transport.with_header(&key, &value);
         }
 
-        Ok(A2AClient::with_transport(Box::new(transport)))
+        if let Some(app) = &self.client_id {
+            transport = transport.with_client_id(app);
+        }
+
+        if let Some(observer) = self.observer {
+            transport = transport.with_observer(observer);
+        }
+
+        if let Some(credentials) = self.credentials {
+            transport = transport.with_credentials(credentials);
+        }
+        transport = transport.with_id_correlation(self.id_correlation);
+        for interceptor in self.transport_interceptors {
+            transport = transport.with_interceptor(interceptor);
+        }
+        if let Some(policy) = self.sse_reconnect {
+            transport = transport.with_sse_reconnect(policy);
+        }
+        if let Some(policy) = self.retry {
+            transport = transport.with_retry(policy);
+        }
+
+        let client = A2AClient::with_transport(Box::new(transport));
+        Ok(finish_client(
+            client,
+            self.not_found_retry,
+            self.stream_interceptors,
+            self.schema_diagnostics,
+            self.file_uploader,
+            self.inline_file_limit,
+            downloader,
+        )
+        .with_agent_card(card)
+        .with_card_source(resolver, &self.url)
+        .with_card_validation(self.card_validation)
+        .with_supported_extensions(extensions))
+    }
+
+    /// An [`ArtifactDownloader`](crate::client::ArtifactDownloader) sending
+    /// the builder's headers and credentials to the origins of the base URL
+    /// and `endpoint`.
+    fn artifact_downloader(&self, endpoint: &str) -> crate::client::ArtifactDownloader {
+        let mut downloader = crate::client::ArtifactDownloader::new()
+            .with_auth_origin(&self.url)
+            .with_auth_origin(endpoint);
+        for (key, value) in &self.headers {
+            downloader = downloader.with_header(key, value);
+        }
+        match &self.credentials {
+            Some(credentials) => downloader.with_credentials(credentials.clone()),
+            None => downloader,
+        }
+    }
+
+    /// A gRPC transport with the builder's timeout, headers, client
+    /// identification and observer.
+    #[cfg(feature = "grpc")]
+    fn grpc_transport(&self, url: &str) -> crate::A2AResult<crate::client::GrpcTransport> {
+        use crate::utils::identification::{ClientIdentification, CLIENT_IDENTIFICATION_HEADER};
+
+        let mut transport = crate::client::GrpcTransport::new(url)?;
+        if let Some(timeout) = self.timeout {
+            transport = transport.with_timeout(timeout);
+        }
+        for (key, value) in &self.headers {
+            transport = transport.with_header(key, value);
+        }
+        let identification = ClientIdentification::for_app(self.client_id.as_deref());
+        transport =
+            transport.with_header(CLIENT_IDENTIFICATION_HEADER, identification.header_value());
+        if let Some(observer) = &self.observer {
+            transport = transport.with_observer(observer.clone());
+        }
+        Ok(transport)
     }
 
     /// Build a client from a direct endpoint URL (skip agent card resolution).
     pub fn build_from_endpoint(self) -> crate::client::A2AClient {
         use crate::client::{A2AClient, JsonRpcTransport};
 
-        let mut transport = JsonRpcTransport::new(&self.url);
+        let downloader = self.artifact_downloader(&self.url);
+
+        let mut transport = JsonRpcTransport::with_config(&self.url, self.connection);
 
         if let Some(timeout) = self.timeout {
             transport = transport.with_timeout(timeout);
@@ -361,10 +868,73 @@ impl ClientBuilder {
             transport = transport.with_header(&key, &value);
         }
 
-        A2AClient::with_transport(Box::new(transport))
+        if let Some(app) = &self.client_id {
+            transport = transport.with_client_id(app);
+        }
+
+        if let Some(observer) = self.observer {
+            transport = transport.with_observer(observer);
+        }
+
+        if let Some(credentials) = self.credentials {
+            transport = transport.with_credentials(credentials);
+        }
+        transport = transport.with_id_correlation(self.id_correlation);
+        for interceptor in self.transport_interceptors {
+            transport = transport.with_interceptor(interceptor);
+        }
+        if let Some(policy) = self.sse_reconnect {
+            transport = transport.with_sse_reconnect(policy);
+        }
+        if let Some(policy) = self.retry {
+            transport = transport.with_retry(policy);
+        }
+
+        let client = A2AClient::with_transport(Box::new(transport));
+        finish_client(
+            client,
+            self.not_found_retry,
+            self.stream_interceptors,
+            self.schema_diagnostics,
+            self.file_uploader,
+            self.inline_file_limit,
+            downloader,
+        )
     }
 }
 
+/// Apply [`ClientBuilder`]'s client-level (non-transport) settings.
+#[cfg(feature = "client")]
+fn finish_client(
+    client: crate::client::A2AClient,
+    not_found_retry: Option<std::time::Duration>,
+    stream_interceptors: Vec<std::sync::Arc<dyn crate::client::StreamInterceptor>>,
+    schema_diagnostics: bool,
+    file_uploader: Option<std::sync::Arc<dyn crate::client::FileUploader>>,
+    inline_file_limit: Option<u64>,
+    artifact_downloader: crate::client::ArtifactDownloader,
+) -> crate::client::A2AClient {
+    let client = client.with_artifact_downloader(artifact_downloader);
+    let client = match not_found_retry {
+        Some(window) => client.with_not_found_retry(window),
+        None => client,
+    };
+    let client = client.with_schema_diagnostics(schema_diagnostics);
+    let client = match file_uploader {
+        Some(uploader) => client.with_file_uploader(uploader),
+        None => client,
+    };
+    let client = match inline_file_limit {
+        Some(bytes) => client.with_inline_file_limit(bytes),
+        None => client,
+    };
+    stream_interceptors
+        .into_iter()
+        .fold(client, |client, interceptor| {
+            client.with_stream_interceptor(interceptor)
+        })
+}
+
 /// Builder for constructing an A2A axum server with fluent configuration.
 ///
 /// # Example
@@ -399,8 +969,29 @@ pub struct ServerBuilder {
     task_store: Option<std::sync::Arc<dyn crate::server::TaskStore>>,
     agent_card: Option<AgentCard>,
     cors_enabled: bool,
+    captured_headers: Vec<String>,
+    strict_protocol_version: bool,
+    shutdown_signal: Option<ShutdownSignal>,
+    shutdown_timeout: std::time::Duration,
+    task_timeout: Option<std::time::Duration>,
+    content_negotiation: bool,
+    rate_limiter: Option<std::sync::Arc<crate::server::RateLimiter>>,
+    max_body_size: Option<usize>,
+    max_part_size: Option<usize>,
+    max_artifact_size: Option<usize>,
+    listeners: Vec<std::sync::Arc<dyn crate::server::TaskLifecycleListener>>,
+    context_store: Option<std::sync::Arc<dyn crate::server::ContextStore>>,
+    retention: Option<crate::server::RetentionPolicy>,
+    event_queue_capacity: Option<usize>,
+    overflow_policy: Option<crate::server::OverflowPolicy>,
+    #[cfg(feature = "observability")]
+    metrics: Option<std::sync::Arc<dyn crate::server::MetricsRecorder>>,
 }
 
+/// Future that resolves when the server should shut down.
+#[cfg(feature = "server")]
+type ShutdownSignal = std::pin::Pin<Box<dyn std::future::Future<Output = ()> + Send>>;
+
 #[cfg(feature = "server")]
 impl ServerBuilder {
     /// Create a new server builder with the given agent executor.
@@ -410,6 +1001,23 @@ impl ServerBuilder {
             task_store: None,
             agent_card: None,
             cors_enabled: false,
+            captured_headers: Vec::new(),
+            strict_protocol_version: false,
+            shutdown_signal: None,
+            shutdown_timeout: std::time::Duration::from_secs(30),
+            task_timeout: None,
+            content_negotiation: false,
+            rate_limiter: None,
+            max_body_size: None,
+            max_part_size: None,
+            max_artifact_size: None,
+            listeners: Vec::new(),
+            context_store: None,
+            retention: None,
+            event_queue_capacity: None,
+            overflow_policy: None,
+            #[cfg(feature = "observability")]
+            metrics: None,
         }
     }
 
@@ -442,27 +1050,587 @@ impl ServerBuilder {
         self
     }
 
+    /// Expose these request headers to executors via
+    /// [`RequestContext::http_request`](crate::server::RequestContext::http_request).
+    ///
+    /// Other headers are never captured. Invalid header names are logged and
+    /// ignored.
+    pub fn with_captured_headers<I, S>(mut self, headers: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.captured_headers
+            .extend(headers.into_iter().map(Into::into));
+        self
+    }
+
+    /// Reject requests declaring an incompatible A2A protocol version.
+    ///
+    /// See [`RouterConfig::with_strict_protocol_version`](crate::server::RouterConfig::with_strict_protocol_version).
+    pub fn with_strict_protocol_version(mut self, strict: bool) -> Self {
+        self.strict_protocol_version = strict;
+        self
+    }
+
+    /// Fail tasks whose execution takes longer than `timeout`.
+    ///
+    /// See [`DefaultRequestHandler::with_task_timeout`](crate::server::DefaultRequestHandler::with_task_timeout).
+    pub fn with_task_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.task_timeout = Some(timeout);
+        self
+    }
+
+    /// Enforce the agent card's input modes and the client's
+    /// `acceptedOutputModes`.
+    ///
+    /// See [`DefaultRequestHandler::with_content_negotiation`](crate::server::DefaultRequestHandler::with_content_negotiation).
+    pub fn with_content_negotiation(mut self, enabled: bool) -> Self {
+        self.content_negotiation = enabled;
+        self
+    }
+
+    /// Rate limit JSON-RPC requests with `limiter`.
+    ///
+    /// See [`RouterConfig::with_rate_limiter`](crate::server::RouterConfig::with_rate_limiter).
+    pub fn with_rate_limiter(
+        mut self,
+        limiter: std::sync::Arc<crate::server::RateLimiter>,
+    ) -> Self {
+        self.rate_limiter = Some(limiter);
+        self
+    }
+
+    /// Reject JSON-RPC request bodies larger than `bytes`.
+    ///
+    /// See [`RouterConfig::with_max_body_size`](crate::server::RouterConfig::with_max_body_size).
+    pub fn with_max_body_size(mut self, bytes: usize) -> Self {
+        self.max_body_size = Some(bytes);
+        self
+    }
+
+    /// Reject messages with a part larger than `bytes`.
+    ///
+    /// See [`DefaultRequestHandler::with_max_part_size`](crate::server::DefaultRequestHandler::with_max_part_size).
+    pub fn with_max_part_size(mut self, bytes: usize) -> Self {
+        self.max_part_size = Some(bytes);
+        self
+    }
+
+    /// Fail executions emitting an artifact larger than `bytes`.
+    ///
+    /// See [`DefaultRequestHandler::with_max_artifact_size`](crate::server::DefaultRequestHandler::with_max_artifact_size).
+    pub fn with_max_artifact_size(mut self, bytes: usize) -> Self {
+        self.max_artifact_size = Some(bytes);
+        self
+    }
+
+    /// Buffer up to `capacity` events per stream subscriber.
+    ///
+    /// See [`DefaultRequestHandler::with_event_queue_capacity`](crate::server::DefaultRequestHandler::with_event_queue_capacity).
+    pub fn with_event_queue_capacity(mut self, capacity: usize) -> Self {
+        self.event_queue_capacity = Some(capacity);
+        self
+    }
+
+    /// Handle stream subscribers that fall behind with `policy`.
+    ///
+    /// See [`DefaultRequestHandler::with_overflow_policy`](crate::server::DefaultRequestHandler::with_overflow_policy).
+    pub fn with_overflow_policy(mut self, policy: crate::server::OverflowPolicy) -> Self {
+        self.overflow_policy = Some(policy);
+        self
+    }
+
+    /// Record the tasks of each conversation in `store`.
+    ///
+    /// See [`DefaultRequestHandler::with_context_store`](crate::server::DefaultRequestHandler::with_context_store).
+    pub fn with_context_store(
+        mut self,
+        store: std::sync::Arc<dyn crate::server::ContextStore>,
+    ) -> Self {
+        self.context_store = Some(store);
+        self
+    }
+
+    /// Tell `listener` about task creation, state changes, added artifacts
+    /// and terminal states. Call repeatedly to add several listeners.
+    ///
+    /// See [`DefaultRequestHandler::with_lifecycle_listener`](crate::server::DefaultRequestHandler::with_lifecycle_listener).
+    pub fn with_listener(
+        mut self,
+        listener: std::sync::Arc<dyn crate::server::TaskLifecycleListener>,
+    ) -> Self {
+        self.listeners.push(listener);
+        self
+    }
+
+    /// Remove old finished tasks from the task store according to
+    /// `policy`, with a [`TaskSweeper`](crate::server::TaskSweeper) running
+    /// in the background until the [graceful
+    /// shutdown](Self::with_graceful_shutdown) completes.
+    ///
+    /// The sweeper is spawned by [`serve()`](Self::serve) or
+    /// [`build()`](Self::build); the latter must then be called within a
+    /// Tokio runtime.
+    pub fn with_retention(mut self, policy: crate::server::RetentionPolicy) -> Self {
+        self.retention = Some(policy);
+        self
+    }
+
+    /// Report task, SSE subscriber and eviction metrics to `recorder`
+    /// (`observability` feature).
+    ///
+    /// See [`DefaultRequestHandler::with_metrics`](crate::server::DefaultRequestHandler::with_metrics).
+    #[cfg(feature = "observability")]
+    pub fn with_metrics(
+        mut self,
+        recorder: std::sync::Arc<dyn crate::server::MetricsRecorder>,
+    ) -> Self {
+        self.metrics = Some(recorder);
+        self
+    }
+
+    /// Shut down gracefully once `signal` resolves, e.g.
+    /// `tokio::signal::ctrl_c()`.
+    ///
+    /// New `message/send` and `message/stream` requests are then refused,
+    /// running executions get the [shutdown
+    /// timeout](Self::with_shutdown_timeout) to finish while their events
+    /// keep flowing, and tasks still running after that are marked
+    /// `canceled`, which ends their SSE streams. See
+    /// [`DefaultRequestHandler::shutdown`](crate::server::DefaultRequestHandler::shutdown).
+    ///
+    /// [`serve()`](Self::serve) then stops accepting connections and returns
+    /// once the open ones have closed. With [`build()`](Self::build), the
+    /// drain starts in the background; stop the HTTP server yourself after
+    /// it.
+    pub fn with_graceful_shutdown<F>(mut self, signal: F) -> Self
+    where
+        F: std::future::Future<Output = ()> + Send + 'static,
+    {
+        self.shutdown_signal = Some(Box::pin(signal));
+        self
+    }
+
+    /// How long running executions may take to finish on graceful
+    /// shutdown before their tasks are canceled (default 30 s).
+    pub fn with_shutdown_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.shutdown_timeout = timeout;
+        self
+    }
+
+    /// Build the router and serve it on `listener` until the [graceful
+    /// shutdown](Self::with_graceful_shutdown) completes, or forever
+    /// without one.
+    pub async fn serve(self, listener: tokio::net::TcpListener) -> std::io::Result<()> {
+        let (router, shutdown) = self.build_parts();
+        let shutdown = async move {
+            match shutdown {
+                Some(shutdown) => shutdown.await,
+                None => std::future::pending().await,
+            }
+        };
+        axum::serve(listener, router)
+            .with_graceful_shutdown(shutdown)
+            .await
+    }
+
     /// Build the axum router.
+    ///
+    /// With a [graceful shutdown](Self::with_graceful_shutdown) configured,
+    /// must be called within a Tokio runtime, which the drain is spawned on.
     pub fn build(self) -> axum::Router {
-        use crate::server::{a2a_router, DefaultRequestHandler, InMemoryTaskStore};
+        let (router, shutdown) = self.build_parts();
+        if let Some(shutdown) = shutdown {
+            match tokio::runtime::Handle::try_current() {
+                Ok(runtime) => {
+                    runtime.spawn(shutdown);
+                }
+                Err(_) => tracing::warn!("No Tokio runtime; graceful shutdown is disabled"),
+            }
+        }
+        router
+    }
+
+    /// The router, and the graceful shutdown to run, if configured.
+    fn build_parts(self) -> (axum::Router, Option<ShutdownSignal>) {
+        use crate::server::{
+            a2a_router_with_config, DefaultRequestHandler, InMemoryTaskStore, RouterConfig,
+            TaskSweeper,
+        };
         use std::sync::Arc;
 
         let store = self
             .task_store
             .unwrap_or_else(|| Arc::new(InMemoryTaskStore::new()));
-        let handler = Arc::new(DefaultRequestHandler::new(self.executor, store));
+        let sweeper = self.retention.and_then(|policy| {
+            let sweeper = TaskSweeper::new(Arc::clone(&store), policy);
+            #[cfg(feature = "observability")]
+            let sweeper = match &self.metrics {
+                Some(metrics) => sweeper.with_metrics(Arc::clone(metrics)),
+                None => sweeper,
+            };
+            match tokio::runtime::Handle::try_current() {
+                Ok(_) => Some(sweeper.spawn()),
+                Err(_) => {
+                    tracing::warn!("No Tokio runtime; task retention is disabled");
+                    None
+                }
+            }
+        });
         let card = self.agent_card.unwrap_or_else(|| {
             AgentCardBuilder::new("A2A Agent", "An A2A-compatible agent", "1.0.0").build()
         });
+        let mut handler =
+            DefaultRequestHandler::new(self.executor, store).with_history_policy_for_card(&card);
+        if let Some(timeout) = self.task_timeout {
+            handler = handler.with_task_timeout(timeout);
+        }
+        if self.content_negotiation {
+            handler = handler.with_content_negotiation(&card);
+        }
+        if let Some(bytes) = self.max_part_size {
+            handler = handler.with_max_part_size(bytes);
+        }
+        if let Some(bytes) = self.max_artifact_size {
+            handler = handler.with_max_artifact_size(bytes);
+        }
+        if let Some(capacity) = self.event_queue_capacity {
+            handler = handler.with_event_queue_capacity(capacity);
+        }
+        if let Some(policy) = self.overflow_policy {
+            handler = handler.with_overflow_policy(policy);
+        }
+        for listener in self.listeners {
+            handler = handler.with_lifecycle_listener(listener);
+        }
+        if let Some(store) = self.context_store {
+            handler = handler.with_context_store(store);
+        }
+        #[cfg(feature = "observability")]
+        if let Some(metrics) = &self.metrics {
+            handler = handler.with_metrics(Arc::clone(metrics));
+        }
+        let handler = Arc::new(handler);
+        let shutdown = self.shutdown_signal.map(|signal| {
+            let handler = Arc::clone(&handler);
+            let timeout = self.shutdown_timeout;
+            Box::pin(async move {
+                signal.await;
+                tracing::info!("Shutting down gracefully");
+                handler.shutdown(timeout).await;
+                if let Some(sweeper) = sweeper {
+                    sweeper.abort();
+                }
+            }) as ShutdownSignal
+        });
+
+        let mut config =
+            RouterConfig::default().with_strict_protocol_version(self.strict_protocol_version);
+        for name in &self.captured_headers {
+            match config.clone().with_captured_header(name) {
+                Ok(updated) => config = updated,
+                Err(e) => tracing::warn!(error = %e, "Ignoring captured header"),
+            }
+        }
+
+        if let Some(limiter) = self.rate_limiter {
+            config = config.with_rate_limiter(limiter);
+        }
+        if let Some(bytes) = self.max_body_size {
+            config = config.with_max_body_size(bytes);
+        }
+        #[cfg(feature = "observability")]
+        if let Some(metrics) = self.metrics {
+            config = config.with_metrics(metrics);
+        }
 
-        let mut router = a2a_router(handler, card);
+        let mut router = a2a_router_with_config(handler, card, config);
 
         if self.cors_enabled {
             use tower_http::cors::CorsLayer;
             router = router.layer(CorsLayer::permissive());
         }
 
-        router
+        (router, shutdown)
+    }
+}
+
+// ============================================================================
+// Streaming event builders
+// ============================================================================
+
+/// Typestate of a [`TaskStatusUpdateEventBuilder`] without a status yet.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoStatus;
+
+/// Typestate of a [`TaskArtifactUpdateEventBuilder`] without an artifact yet.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoArtifact;
+
+impl TaskStatusUpdateEvent {
+    /// Start building a status update for a task; see
+    /// [`TaskStatusUpdateEventBuilder`].
+    pub fn builder(
+        task_id: impl Into<String>,
+        context_id: impl Into<String>,
+    ) -> TaskStatusUpdateEventBuilder<NoStatus> {
+        TaskStatusUpdateEventBuilder {
+            task_id: task_id.into(),
+            context_id: context_id.into(),
+            status: NoStatus,
+            message: None,
+            timestamp: None,
+            r#final: false,
+            metadata: None,
+        }
+    }
+}
+
+/// Builder for [`TaskStatusUpdateEvent`].
+///
+/// [`build()`](TaskStatusUpdateEventBuilder::build) is only available once a
+/// state or status has been given. The event is `final` when the state is
+/// terminal or [`with_final(true)`](Self::with_final) was called, and the
+/// status is stamped with the current time unless it already has a
+/// timestamp.
+///
+/// # Example
+///
+/// ```
+/// use a2a_rs::types::{Message, TaskState, TaskStatusUpdateEvent};
+///
+/// let event = TaskStatusUpdateEvent::builder("task-1", "ctx-1")
+///     .with_state(TaskState::Completed)
+///     .with_message(Message::agent("m1", "done"))
+///     .build();
+///
+/// assert_eq!(event.kind, "status-update");
+/// assert!(event.r#final);
+/// assert!(event.status.timestamp.is_some());
+/// ```
+///
+/// Without a state there is nothing to build:
+///
+/// ```compile_fail
+/// use a2a_rs::types::TaskStatusUpdateEvent;
+///
+/// let event = TaskStatusUpdateEvent::builder("task-1", "ctx-1").build();
+/// ```
+#[derive(Debug, Clone)]
+pub struct TaskStatusUpdateEventBuilder<S> {
+    task_id: String,
+    context_id: String,
+    status: S,
+    message: Option<Message>,
+    timestamp: Option<String>,
+    r#final: bool,
+    metadata: Option<serde_json::Value>,
+}
+
+impl<S> TaskStatusUpdateEventBuilder<S> {
+    /// Set the new state of the task.
+    pub fn with_state(self, state: TaskState) -> TaskStatusUpdateEventBuilder<TaskStatus> {
+        self.with_status(TaskStatus::new(state))
+    }
+
+    /// Set the whole status. A message or timestamp given to the builder
+    /// takes precedence over the one in `status`.
+    pub fn with_status(self, status: TaskStatus) -> TaskStatusUpdateEventBuilder<TaskStatus> {
+        TaskStatusUpdateEventBuilder {
+            task_id: self.task_id,
+            context_id: self.context_id,
+            status,
+            message: self.message,
+            timestamp: self.timestamp,
+            r#final: self.r#final,
+            metadata: self.metadata,
+        }
+    }
+
+    /// Set the message of the status.
+    pub fn with_message(mut self, message: Message) -> Self {
+        self.message = Some(message);
+        self
+    }
+
+    /// Set the ISO-8601 timestamp of the status instead of the current time.
+    pub fn with_timestamp(mut self, timestamp: impl Into<String>) -> Self {
+        self.timestamp = Some(timestamp.into());
+        self
+    }
+
+    /// Mark the event as the last one of the stream even if the state isn't
+    /// terminal, e.g. when ending the turn on `input-required`.
+    pub fn with_final(mut self, r#final: bool) -> Self {
+        self.r#final = r#final;
+        self
+    }
+
+    /// Set the event metadata.
+    pub fn with_metadata(mut self, metadata: serde_json::Value) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+}
+
+impl TaskStatusUpdateEventBuilder<TaskStatus> {
+    /// Build the event.
+    pub fn build(self) -> TaskStatusUpdateEvent {
+        let mut status = self.status;
+        if let Some(message) = self.message {
+            status.message = Some(message);
+        }
+        if let Some(timestamp) = self.timestamp {
+            status.timestamp = Some(timestamp);
+        }
+        if status.timestamp.is_none() {
+            status.timestamp = Some(chrono::Utc::now().to_rfc3339());
+        }
+        let r#final = self.r#final || status.state.is_terminal();
+        TaskStatusUpdateEvent {
+            task_id: self.task_id,
+            context_id: self.context_id,
+            kind: "status-update".to_string(),
+            status,
+            r#final,
+            metadata: self.metadata,
+        }
+    }
+}
+
+impl TaskArtifactUpdateEvent {
+    /// Start building an artifact update for a task; see
+    /// [`TaskArtifactUpdateEventBuilder`].
+    pub fn builder(
+        task_id: impl Into<String>,
+        context_id: impl Into<String>,
+    ) -> TaskArtifactUpdateEventBuilder<NoArtifact> {
+        TaskArtifactUpdateEventBuilder {
+            task_id: task_id.into(),
+            context_id: context_id.into(),
+            artifact: NoArtifact,
+            append: None,
+            last_chunk: None,
+            metadata: None,
+        }
+    }
+}
+
+/// Builder for [`TaskArtifactUpdateEvent`].
+///
+/// [`build()`](TaskArtifactUpdateEventBuilder::build) is only available once
+/// an artifact has been given. `append` and `lastChunk` are left out of the
+/// event unless set.
+///
+/// # Example
+///
+/// ```
+/// use a2a_rs::types::{Part, TaskArtifactUpdateEvent};
+///
+/// let event = TaskArtifactUpdateEvent::builder("task-1", "ctx-1")
+///     .with_parts("report", vec![Part::text("chunk 2")])
+///     .with_append(true)
+///     .with_last_chunk(true)
+///     .build();
+///
+/// assert_eq!(event.kind, "artifact-update");
+/// assert_eq!(event.artifact.artifact_id, "report");
+/// assert_eq!(event.append, Some(true));
+/// ```
+///
+/// Without an artifact there is nothing to build:
+///
+/// ```compile_fail
+/// use a2a_rs::types::TaskArtifactUpdateEvent;
+///
+/// let event = TaskArtifactUpdateEvent::builder("task-1", "ctx-1")
+///     .with_append(true)
+///     .build();
+/// ```
+#[derive(Debug, Clone)]
+pub struct TaskArtifactUpdateEventBuilder<A> {
+    task_id: String,
+    context_id: String,
+    artifact: A,
+    append: Option<bool>,
+    last_chunk: Option<bool>,
+    metadata: Option<serde_json::Value>,
+}
+
+impl<A> TaskArtifactUpdateEventBuilder<A> {
+    /// Set the artifact.
+    pub fn with_artifact(self, artifact: Artifact) -> TaskArtifactUpdateEventBuilder<Artifact> {
+        TaskArtifactUpdateEventBuilder {
+            task_id: self.task_id,
+            context_id: self.context_id,
+            artifact,
+            append: self.append,
+            last_chunk: self.last_chunk,
+            metadata: self.metadata,
+        }
+    }
+
+    /// Set an artifact made of `parts` only.
+    pub fn with_parts(
+        self,
+        artifact_id: impl Into<String>,
+        parts: Vec<Part>,
+    ) -> TaskArtifactUpdateEventBuilder<Artifact> {
+        self.with_artifact(Artifact {
+            artifact_id: artifact_id.into(),
+            name: None,
+            description: None,
+            parts,
+            metadata: None,
+            extensions: None,
+        })
+    }
+
+    /// Whether the parts are appended to an artifact sent earlier with the
+    /// same ID.
+    pub fn with_append(mut self, append: bool) -> Self {
+        self.append = Some(append);
+        self
+    }
+
+    /// Whether this is the last chunk of the artifact.
+    pub fn with_last_chunk(mut self, last_chunk: bool) -> Self {
+        self.last_chunk = Some(last_chunk);
+        self
+    }
+
+    /// Set the event metadata.
+    pub fn with_metadata(mut self, metadata: serde_json::Value) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+}
+
+impl TaskArtifactUpdateEventBuilder<Artifact> {
+    /// Set the artifact's name.
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.artifact.name = Some(name.into());
+        self
+    }
+
+    /// Set the artifact's description.
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.artifact.description = Some(description.into());
+        self
+    }
+
+    /// Build the event.
+    pub fn build(self) -> TaskArtifactUpdateEvent {
+        TaskArtifactUpdateEvent {
+            task_id: self.task_id,
+            context_id: self.context_id,
+            kind: "artifact-update".to_string(),
+            artifact: self.artifact,
+            append: self.append,
+            last_chunk: self.last_chunk,
+            metadata: self.metadata,
+        }
     }
 }
 
@@ -480,7 +1648,7 @@ mod tests {
         assert_eq!(card.description, "A test");
         assert_eq!(card.version, "1.0.0");
         assert_eq!(card.supported_interfaces.len(), 1);
-        assert_eq!(card.supported_interfaces[0].protocol_binding, "JSONRPC");
+        assert_eq!(card.supported_interfaces[0].transport, "JSONRPC");
     }
 
     #[test]
@@ -516,6 +1684,72 @@ mod tests {
         assert_eq!(card.capabilities.push_notifications, Some(false));
     }
 
+    #[test]
+    fn status_update_builder_defaults() {
+        let event = TaskStatusUpdateEvent::builder("t1", "c1")
+            .with_state(TaskState::Working)
+            .build();
+
+        assert_eq!(event.task_id, "t1");
+        assert_eq!(event.context_id, "c1");
+        assert_eq!(event.kind, "status-update");
+        assert_eq!(event.status.state, TaskState::Working);
+        assert!(!event.r#final);
+        assert!(event.status.timestamp.is_some());
+        assert!(event.metadata.is_none());
+    }
+
+    #[test]
+    fn status_update_builder_overrides() {
+        let mut status = TaskStatus::with_timestamp(TaskState::Working, "old");
+        status.message = Some(Message::agent("m0", "old"));
+        let event = TaskStatusUpdateEvent::builder("t1", "c1")
+            .with_message(Message::agent("m1", "more please"))
+            .with_status(status)
+            .with_timestamp("2026-01-01T00:00:00Z")
+            .with_final(true)
+            .with_metadata(serde_json::json!({"k": 1}))
+            .build();
+
+        assert_eq!(event.status.message.unwrap().message_id, "m1");
+        assert_eq!(
+            event.status.timestamp.as_deref(),
+            Some("2026-01-01T00:00:00Z")
+        );
+        assert!(event.r#final);
+        assert_eq!(event.metadata, Some(serde_json::json!({"k": 1})));
+    }
+
+    #[test]
+    fn status_update_builder_terminal_state_is_final() {
+        let event = TaskStatusUpdateEvent::builder("t1", "c1")
+            .with_final(false)
+            .with_state(TaskState::Failed)
+            .build();
+
+        assert!(event.r#final);
+    }
+
+    #[test]
+    fn artifact_update_builder() {
+        let event = TaskArtifactUpdateEvent::builder("t1", "c1")
+            .with_last_chunk(false)
+            .with_parts("a1", vec![Part::text("hello")])
+            .with_name("greeting")
+            .build();
+
+        assert_eq!(event.kind, "artifact-update");
+        assert_eq!(event.artifact.artifact_id, "a1");
+        assert_eq!(event.artifact.name.as_deref(), Some("greeting"));
+        assert_eq!(event.artifact.parts.len(), 1);
+        assert_eq!(event.append, None);
+        assert_eq!(event.last_chunk, Some(false));
+
+        let json = serde_json::to_value(&event).unwrap();
+        assert!(json.get("append").is_none());
+        assert_eq!(json["lastChunk"], false);
+    }
+
     #[cfg(feature = "client")]
     #[test]
     fn client_builder_basic() {
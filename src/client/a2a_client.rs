@@ -3,19 +3,44 @@
 //! Mirrors the Python SDK's `Client` / `BaseClient` architecture, providing
 //! typed methods for every JSON-RPC method in the A2A v0.3 specification.
 
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use futures::Stream;
 use serde::Serialize;
 
-use crate::error::{A2AError, A2AResult};
+use crate::error::{self, A2AError, A2AResult};
 use crate::types::{
-    AgentCard, CancelTaskParams, GetTaskParams, GetTaskPushNotificationConfigParams, JsonRpcId,
-    JsonRpcRequest, JsonRpcResponse, ListTasksParams, ListTasksResponse, Message, Part, Role,
-    SendMessageConfiguration, SendMessageParams, SendMessageResponse,
-    SetTaskPushNotificationConfigParams, Task, TaskIdParams, TaskPushNotificationConfig,
+    AgentCard, Artifact, CancelContextParams, CancelContextResult, CancelTaskParams,
+    DeleteTaskPushNotificationConfigParams, GetArtifactParams, GetTaskParams,
+    GetTaskPushNotificationConfigParams, JsonRpcId, JsonRpcRequest, JsonRpcResponse,
+    ListArtifactsParams, ListArtifactsResult, ListTaskPushNotificationConfigParams,
+    ListTaskPushNotificationConfigResponse, ListTasksParams, ListTasksResponse, Message, Part,
+    ReadConsistency, Role, SendMessageConfiguration, SendMessageParams, SendMessageResponse,
+    SetTaskPushNotificationConfigParams, SkillInvokeParams, SkillValidationResult, Task,
+    TaskIdParams, TaskPushNotificationConfig, TaskStatus,
+};
+use crate::utils::compatibility::check_message_compatibility;
+use crate::utils::extensions::{get_requested_extensions, HTTP_EXTENSION_HEADER};
+use crate::utils::skills::SKILLS_INVOKE_METHOD;
+use crate::utils::task::{
+    CONTEXTS_CANCEL_METHOD, MESSAGE_SEND_STREAM_METHOD, TASKS_ARTIFACTS_GET_METHOD,
+    TASKS_ARTIFACTS_LIST_METHOD,
 };
+use crate::utils::version::check_card_protocol_version;
 
+use super::card_cache::AgentCardCache;
 use super::card_resolver::CardResolver;
+use super::collector::StreamCollector;
+use super::downloads::ArtifactDownloader;
+use super::files::{file_part, FileSource, FileUploader, DEFAULT_INLINE_FILE_LIMIT};
+use super::message_builder::MessageBuilder;
+use super::observer::TransportObserver;
 use super::sse::SseStream;
-use super::transport::{JsonRpcTransport, Transport};
+use super::stream_interceptor::StreamInterceptor;
+use super::transport::{CallOptions, JsonRpcTransport, Transport, TransportMetadata};
 
 /// Client for interacting with A2A-compatible agents.
 ///
@@ -28,6 +53,8 @@ use super::transport::{JsonRpcTransport, Transport};
 /// - `tasks/resubscribe` — resubscribe to task update events
 /// - `tasks/pushNotificationConfig/set` — set push notification config
 /// - `tasks/pushNotificationConfig/get` — get push notification config
+/// - `tasks/pushNotificationConfig/list` — list push notification configs
+/// - `tasks/pushNotificationConfig/delete` — delete push notification config
 ///
 /// # Construction
 ///
@@ -47,11 +74,50 @@ use super::transport::{JsonRpcTransport, Transport};
 /// # Ok(())
 /// # }
 /// ```
+///
+/// # Per-call headers
+///
+/// Every JSON-RPC method has a `*_with_options` variant taking
+/// [`CallOptions`]. Headers set there are merged over the transport's
+/// defaults (e.g. those configured via [`crate::ClientBuilder`]).
+///
+/// # Cloning
+///
+/// Clones share the transport — for [`JsonRpcTransport`], one
+/// `reqwest::Client` and its connection pool — so handing a clone to each
+/// worker is cheap and keeps connections to the agent warm. Settings
+/// changed on a clone afterwards apply to that clone only.
+#[derive(Clone)]
 pub struct A2AClient {
-    transport: Box<dyn Transport>,
+    transport: Arc<dyn Transport>,
     agent_card: Option<AgentCard>,
+    /// How long after creating a task `tasks/get` retries `TaskNotFound`.
+    not_found_retry: Option<Duration>,
+    /// Creation times of tasks returned by `message/send` on this client.
+    recent_tasks: Arc<Mutex<HashMap<String, Instant>>>,
+    /// Applied, in order, to every event of streams opened by this client.
+    stream_interceptors: Vec<Arc<dyn StreamInterceptor>>,
+    /// Uploads attached files larger than `inline_file_limit`.
+    file_uploader: Option<Arc<dyn FileUploader>>,
+    /// Largest attached file sent inline as base64.
+    inline_file_limit: u64,
+    /// Fetches URI file parts of received artifacts.
+    artifact_downloader: ArtifactDownloader,
+    /// Attach a [`DecodeDiagnostic`](super::DecodeDiagnostic) to response
+    /// decode errors.
+    schema_diagnostics: bool,
+    /// Check outgoing messages against `agent_card` before sending.
+    card_validation: bool,
+    /// Extensions counted as used by every message in card validation.
+    supported_extensions: HashSet<String>,
+    /// Where [`refresh_card()`](Self::refresh_card) re-resolves the card:
+    /// the resolver and the agent's base URL.
+    card_source: Option<(CardResolver, String)>,
 }
 
+/// Delay between `tasks/get` retries inside the not-found retry window.
+const NOT_FOUND_RETRY_INTERVAL: Duration = Duration::from_millis(50);
+
 impl std::fmt::Debug for A2AClient {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("A2AClient")
@@ -66,40 +132,70 @@ impl A2AClient {
     /// This will:
     /// 1. Resolve the agent card from `{url}/.well-known/agent-card.json`
     ///    (falls back to `/.well-known/agent.json` for older agents)
-    /// 2. Extract the JSON-RPC endpoint URL from the card
-    /// 3. Create a `JsonRpcTransport` pointing to that endpoint
+    /// 2. Pick an interface from the card (see [`from_card()`](Self::from_card))
+    /// 3. Create a transport pointing to that endpoint
+    ///
+    /// # Errors
+    ///
+    /// The card is cached in [`AgentCardCache::shared()`], so clients for
+    /// the same agent don't each fetch it; see
+    /// [`refresh_card()`](Self::refresh_card).
     ///
     /// # Errors
     ///
     /// Returns an error if the agent card cannot be fetched or parsed,
-    /// or if no JSON-RPC interface is found in the card.
+    /// or if no supported interface is found in the card.
     pub async fn from_url(url: &str) -> A2AResult<Self> {
-        let resolver = CardResolver::new();
+        let resolver = CardResolver::new().with_cache(AgentCardCache::shared());
         let card = resolver.resolve(url).await?;
-        Self::from_card(card)
+        Ok(Self::from_card(card)?.with_card_source(resolver, url))
     }
 
     /// Create a client from an already-resolved agent card.
     ///
-    /// Extracts the JSON-RPC endpoint URL from the card's
-    /// `supported_interfaces` and creates a transport for it.
+    /// Picks the interface to connect to from those the card advertises
+    /// (`supported_interfaces`, `additional_interfaces`, or `url` for v0.3
+    /// cards) with [`CardResolver::negotiate_interface`]: the card's
+    /// `preferred_transport` if this build speaks it, else the first
+    /// interface it does. To override the card's preference, use
+    /// [`ClientBuilder::with_preferred_transport`](crate::builders::ClientBuilder::with_preferred_transport). `JSONRPC` gets a [`JsonRpcTransport`]; with
+    /// the `grpc` feature, `GRPC` gets a `GrpcTransport`.
     ///
     /// # Errors
     ///
-    /// Returns an error if the card does not contain a JSON-RPC interface.
+    /// Returns an error if the card offers no supported interface, or
+    /// declares a protocol version this SDK doesn't support (see
+    /// [`check_card_protocol_version`]).
     pub fn from_card(card: AgentCard) -> A2AResult<Self> {
-        let url = CardResolver::get_a2a_url(&card).ok_or_else(|| {
+        check_card_protocol_version(&card)?;
+        let supported = CardResolver::SUPPORTED_TRANSPORTS;
+        let iface = CardResolver::negotiate_interface(&card, supported).ok_or_else(|| {
             A2AError::Transport(format!(
-                "agent card for '{}' has no JSONRPC interface in supported_interfaces",
-                card.name
+                "agent card for '{}' advertises no {} interface",
+                card.name,
+                supported.join(" or ")
             ))
         })?;
 
-        let transport = JsonRpcTransport::new(url);
+        let transport: Arc<dyn Transport> = match iface.transport.to_ascii_uppercase().as_str() {
+            #[cfg(feature = "grpc")]
+            "GRPC" => Arc::new(super::GrpcTransport::new(&iface.url)?),
+            _ => Arc::new(JsonRpcTransport::new(&iface.url)),
+        };
 
         Ok(Self {
-            transport: Box::new(transport),
+            transport,
             agent_card: Some(card),
+            not_found_retry: None,
+            recent_tasks: Arc::default(),
+            stream_interceptors: Vec::new(),
+            file_uploader: None,
+            inline_file_limit: DEFAULT_INLINE_FILE_LIMIT,
+            artifact_downloader: ArtifactDownloader::new(),
+            schema_diagnostics: false,
+            card_validation: false,
+            supported_extensions: HashSet::new(),
+            card_source: None,
         })
     }
 
@@ -109,8 +205,18 @@ impl A2AClient {
     /// or a non-HTTP transport implementation.
     pub fn with_transport(transport: Box<dyn Transport>) -> Self {
         Self {
-            transport,
+            transport: Arc::from(transport),
             agent_card: None,
+            not_found_retry: None,
+            recent_tasks: Arc::default(),
+            stream_interceptors: Vec::new(),
+            file_uploader: None,
+            inline_file_limit: DEFAULT_INLINE_FILE_LIMIT,
+            artifact_downloader: ArtifactDownloader::new(),
+            schema_diagnostics: false,
+            card_validation: false,
+            supported_extensions: HashSet::new(),
+            card_source: None,
         }
     }
 
@@ -121,11 +227,154 @@ impl A2AClient {
     pub fn from_endpoint(url: &str) -> Self {
         let transport = JsonRpcTransport::new(url);
         Self {
-            transport: Box::new(transport),
+            transport: Arc::new(transport),
             agent_card: None,
+            not_found_retry: None,
+            recent_tasks: Arc::default(),
+            stream_interceptors: Vec::new(),
+            file_uploader: None,
+            inline_file_limit: DEFAULT_INLINE_FILE_LIMIT,
+            artifact_downloader: ArtifactDownloader::new(),
+            schema_diagnostics: false,
+            card_validation: false,
+            supported_extensions: HashSet::new(),
+            card_source: None,
         }
     }
 
+    /// Retry `tasks/get` on `TaskNotFound` for up to `window` after this
+    /// client created the task (builder-style).
+    ///
+    /// Useful with replicated task stores, where a read right after
+    /// `message/send` can hit a replica that hasn't seen the task yet.
+    /// Retries also upgrade the request's consistency hint to
+    /// [`ReadConsistency::Primary`] unless one was set explicitly. Only
+    /// tasks returned by [`send_message()`](Self::send_message) are tracked.
+    pub fn with_not_found_retry(mut self, window: Duration) -> Self {
+        self.not_found_retry = Some(window);
+        self
+    }
+
+    /// Register a [`StreamInterceptor`] applied to every event of streams
+    /// opened by this client (builder-style). Interceptors run in
+    /// registration order.
+    pub fn with_stream_interceptor(mut self, interceptor: Arc<dyn StreamInterceptor>) -> Self {
+        self.stream_interceptors.push(interceptor);
+        self
+    }
+
+    /// Upload attached files larger than the inline limit with `uploader`
+    /// and send them by URI (builder-style). See
+    /// [`send_text_with_files()`](Self::send_text_with_files).
+    pub fn with_file_uploader(mut self, uploader: Arc<dyn FileUploader>) -> Self {
+        self.file_uploader = Some(uploader);
+        self
+    }
+
+    /// Send attached files up to `bytes` inline as base64 (builder-style).
+    /// Defaults to [`DEFAULT_INLINE_FILE_LIMIT`].
+    pub fn with_inline_file_limit(mut self, bytes: u64) -> Self {
+        self.inline_file_limit = bytes;
+        self
+    }
+
+    /// Fetch URI file parts with `downloader` (builder-style). Defaults to
+    /// [`ArtifactDownloader::new()`]; clients from
+    /// [`ClientBuilder`](crate::builders::ClientBuilder) get one carrying
+    /// the builder's headers and credentials for the agent's origin.
+    pub fn with_artifact_downloader(mut self, downloader: ArtifactDownloader) -> Self {
+        self.artifact_downloader = downloader;
+        self
+    }
+
+    /// Returns the downloader for URI file parts of this client's results.
+    pub fn artifact_downloader(&self) -> &ArtifactDownloader {
+        &self.artifact_downloader
+    }
+
+    /// Explain responses that fail to decode (builder-style).
+    ///
+    /// When a result doesn't match the expected type, the
+    /// [`A2AError::InvalidJson`] error names the offending field path, the
+    /// expected and found JSON types, and includes an excerpt of the
+    /// payload (see [`diagnose()`](super::diagnose)). Off by default, since
+    /// the excerpt may carry message content into logs.
+    pub fn with_schema_diagnostics(mut self, enabled: bool) -> Self {
+        self.schema_diagnostics = enabled;
+        self
+    }
+
+    /// Cache `card` as the agent's card (builder-style), e.g. for a client
+    /// built with [`with_transport()`](Self::with_transport) or
+    /// [`from_endpoint()`](Self::from_endpoint).
+    pub fn with_agent_card(mut self, card: AgentCard) -> Self {
+        self.agent_card = Some(card);
+        self
+    }
+
+    /// Re-resolve the card from `base_url` with `resolver` in
+    /// [`refresh_card()`](Self::refresh_card).
+    pub(crate) fn with_card_source(mut self, resolver: CardResolver, base_url: &str) -> Self {
+        self.card_source = Some((resolver, base_url.to_string()));
+        self
+    }
+
+    /// Check every outgoing `message/send` and `message/stream` message
+    /// against the cached agent card (builder-style) and fail with
+    /// [`A2AError::IncompatibleAgent`] before sending if the agent can't
+    /// handle it. See [`check_message_compatibility`].
+    ///
+    /// Off by default. Without a cached card, messages are sent unchecked.
+    pub fn with_card_validation(mut self, enabled: bool) -> Self {
+        self.card_validation = enabled;
+        self
+    }
+
+    /// Extensions this client supports on every call (builder-style), in
+    /// addition to a message's own `extensions` and those requested per
+    /// call with [`CallOptions::with_extensions`]. Only used by
+    /// [card validation](Self::with_card_validation).
+    pub fn with_supported_extensions<I, S>(mut self, uris: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.supported_extensions
+            .extend(uris.into_iter().map(Into::into));
+        self
+    }
+
+    /// Install a [`TransportObserver`] on the client's transport, receiving
+    /// per-call metrics and [`TransportEvent`]s (builder-style).
+    ///
+    /// Replaces any observer the transport already had. Transports that
+    /// don't report to observers ignore it. Install it before cloning the
+    /// client: once clones share the transport, it can't be changed and
+    /// the observer is dropped with a warning.
+    ///
+    /// [`TransportEvent`]: super::TransportEvent
+    pub fn with_transport_observer(mut self, observer: Arc<dyn TransportObserver>) -> Self {
+        match Arc::get_mut(&mut self.transport) {
+            Some(transport) => transport.set_observer(observer),
+            None => tracing::warn!(
+                "Transport is shared with a clone of this client; observer not installed"
+            ),
+        }
+        self
+    }
+
+    /// Describe the transport this client calls through.
+    pub fn transport_metadata(&self) -> TransportMetadata {
+        self.transport.metadata()
+    }
+
+    /// Establish the transport's connection ahead of the first call.
+    ///
+    /// Optional: calls connect on demand. See [`Transport::connect`].
+    pub async fn connect(&self) -> A2AResult<()> {
+        self.transport.connect().await
+    }
+
     // ──────────────────────────────────────────────────
     // Core A2A JSON-RPC Methods
     // ──────────────────────────────────────────────────
@@ -139,40 +388,331 @@ impl A2AClient {
     /// [`get_task()`]: Self::get_task
     /// [`send_message_stream()`]: Self::send_message_stream
     pub async fn send_message(&self, params: SendMessageParams) -> A2AResult<SendMessageResponse> {
+        self.send_message_with_options(params, &CallOptions::default())
+            .await
+    }
+
+    /// Like [`send_message()`](Self::send_message), with per-call [`CallOptions`].
+    pub async fn send_message_with_options(
+        &self,
+        params: SendMessageParams,
+        options: &CallOptions,
+    ) -> A2AResult<SendMessageResponse> {
+        self.check_compatibility(&params, options)?;
         let request = build_request("message/send", &params)?;
-        let response = self.transport.send(&request).await?;
-        parse_result(response)
+        let response = self.transport.send_with_options(&request, options).await?;
+        let result: SendMessageResponse = parse_result(response, self.schema_diagnostics)?;
+        if let SendMessageResponse::Task(task) = &result {
+            warn_unrecognized_state(&task.id, &task.status);
+        }
+        if let (Some(window), SendMessageResponse::Task(task)) = (self.not_found_retry, &result) {
+            let now = Instant::now();
+            let mut recent = self.recent_tasks.lock().unwrap();
+            recent.retain(|_, created| now.duration_since(*created) < window);
+            recent.insert(task.id.clone(), now);
+        }
+        Ok(result)
+    }
+
+    /// Send a message whose parts are uploaded as `parts` yields them
+    /// (`message/sendStream`).
+    ///
+    /// `params.message` carries the first parts, if any. The agent starts
+    /// on them and reads the rest as they arrive, so long or generated
+    /// input needn't be held in memory first. The result is the same as
+    /// [`send_message()`](Self::send_message)'s, once the upload and the
+    /// task are done. Only servers built on this SDK's axum integration
+    /// implement the method; transports without uploads send the message
+    /// whole (see [`Transport::send_upload`]).
+    pub async fn send_message_incremental<S>(
+        &self,
+        params: SendMessageParams,
+        parts: S,
+    ) -> A2AResult<SendMessageResponse>
+    where
+        S: Stream<Item = Part> + Send + 'static,
+    {
+        self.send_message_incremental_with_options(params, parts, &CallOptions::default())
+            .await
+    }
+
+    /// Like [`send_message_incremental()`](Self::send_message_incremental),
+    /// with per-call [`CallOptions`].
+    pub async fn send_message_incremental_with_options<S>(
+        &self,
+        params: SendMessageParams,
+        parts: S,
+        options: &CallOptions,
+    ) -> A2AResult<SendMessageResponse>
+    where
+        S: Stream<Item = Part> + Send + 'static,
+    {
+        self.check_compatibility(&params, options)?;
+        let request = build_request(MESSAGE_SEND_STREAM_METHOD, &params)?;
+        let response = self
+            .transport
+            .send_upload(&request, Box::pin(parts), options)
+            .await?;
+        let result: SendMessageResponse = parse_result(response, self.schema_diagnostics)?;
+        if let SendMessageResponse::Task(task) = &result {
+            warn_unrecognized_state(&task.id, &task.status);
+        }
+        Ok(result)
     }
 
     /// Send a message with streaming (`message/stream`).
     ///
     /// Returns an SSE stream that yields [`crate::types::StreamResponse`] events as the
     /// agent processes the message. Events include status updates, artifact
-    /// updates, and the final task snapshot.
+    /// updates, and the final task snapshot — or, if the agent replies
+    /// without a task, a single [`Message`].
     pub async fn send_message_stream(&self, params: SendMessageParams) -> A2AResult<SseStream> {
+        self.send_message_stream_with_options(params, &CallOptions::default())
+            .await
+    }
+
+    /// Like [`send_message_stream()`](Self::send_message_stream), with per-call [`CallOptions`].
+    pub async fn send_message_stream_with_options(
+        &self,
+        params: SendMessageParams,
+        options: &CallOptions,
+    ) -> A2AResult<SseStream> {
+        self.check_compatibility(&params, options)?;
         let request = build_request("message/stream", &params)?;
-        self.transport.send_stream(&request).await
+        let stream = self
+            .transport
+            .send_stream_with_options(&request, options)
+            .await?;
+        Ok(stream.with_interceptors(&self.stream_interceptors))
     }
 
     /// Get the current state of a task (`tasks/get`).
     pub async fn get_task(&self, params: GetTaskParams) -> A2AResult<Task> {
+        self.get_task_with_options(params, &CallOptions::default())
+            .await
+    }
+
+    /// Like [`get_task()`](Self::get_task), with per-call [`CallOptions`].
+    pub async fn get_task_with_options(
+        &self,
+        params: GetTaskParams,
+        options: &CallOptions,
+    ) -> A2AResult<Task> {
         let request = build_request("tasks/get", &params)?;
-        let response = self.transport.send(&request).await?;
-        parse_result(response)
+        let response = self.transport.send_with_options(&request, options).await?;
+        let result = parse_task(response, self.schema_diagnostics);
+
+        let Some(deadline) = self.not_found_retry_deadline(&params.id) else {
+            return result;
+        };
+        let mut result = result;
+        let mut params = params;
+        if params.consistency.is_none() {
+            params.consistency = Some(ReadConsistency::Primary);
+        }
+        while is_task_not_found(&result) && Instant::now() < deadline {
+            tokio::time::sleep(NOT_FOUND_RETRY_INTERVAL).await;
+            let request = build_request("tasks/get", &params)?;
+            let response = self.transport.send_with_options(&request, options).await?;
+            result = parse_task(response, self.schema_diagnostics);
+        }
+        result
     }
 
     /// List tasks with optional filtering (`tasks/list`).
     pub async fn list_tasks(&self, params: ListTasksParams) -> A2AResult<ListTasksResponse> {
+        self.list_tasks_with_options(params, &CallOptions::default())
+            .await
+    }
+
+    /// Like [`list_tasks()`](Self::list_tasks), with per-call [`CallOptions`].
+    pub async fn list_tasks_with_options(
+        &self,
+        params: ListTasksParams,
+        options: &CallOptions,
+    ) -> A2AResult<ListTasksResponse> {
         let request = build_request("tasks/list", &params)?;
-        let response = self.transport.send(&request).await?;
-        parse_result(response)
+        let response = self.transport.send_with_options(&request, options).await?;
+        parse_result(response, self.schema_diagnostics)
+    }
+
+    /// Every task matching `params` (`tasks/list`), following
+    /// `nextPageToken` across pages.
+    ///
+    /// Pages are fetched as the stream is polled, starting at
+    /// `params.page_token`. An error ends the stream, as does a server
+    /// repeating a page token.
+    ///
+    /// ```no_run
+    /// use a2a_rs::client::A2AClient;
+    /// use a2a_rs::types::{ListTasksParams, TaskState};
+    /// use futures::{pin_mut, StreamExt};
+    ///
+    /// # async fn example(client: A2AClient) -> a2a_rs::error::A2AResult<()> {
+    /// let tasks = client.list_tasks_paged(
+    ///     ListTasksParams::new()
+    ///         .with_context_id("ctx-1")
+    ///         .with_status(TaskState::Completed),
+    /// );
+    /// pin_mut!(tasks);
+    /// while let Some(task) = tasks.next().await {
+    ///     println!("{}", task?.id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_tasks_paged(
+        &self,
+        params: ListTasksParams,
+    ) -> impl Stream<Item = A2AResult<Task>> + Send + '_ {
+        self.list_tasks_paged_with_options(params, &CallOptions::default())
+    }
+
+    /// Like [`list_tasks_paged()`](Self::list_tasks_paged), with
+    /// [`CallOptions`] applied to every page request.
+    pub fn list_tasks_paged_with_options(
+        &self,
+        params: ListTasksParams,
+        options: &CallOptions,
+    ) -> impl Stream<Item = A2AResult<Task>> + Send + '_ {
+        struct Pages {
+            params: ListTasksParams,
+            options: CallOptions,
+            tasks: VecDeque<Task>,
+            done: bool,
+        }
+
+        let pages = Pages {
+            params,
+            options: options.clone(),
+            tasks: VecDeque::new(),
+            done: false,
+        };
+        futures::stream::unfold(pages, move |mut pages| async move {
+            loop {
+                if let Some(task) = pages.tasks.pop_front() {
+                    return Some((Ok(task), pages));
+                }
+                if pages.done {
+                    return None;
+                }
+                pages.done = true;
+                let page = match self
+                    .list_tasks_with_options(pages.params.clone(), &pages.options)
+                    .await
+                {
+                    Ok(page) => page,
+                    Err(e) => return Some((Err(e), pages)),
+                };
+                let token = page.next_page_token;
+                if !token.is_empty() {
+                    if pages.params.page_token.as_deref() == Some(token.as_str()) {
+                        let error = A2AError::InvalidAgentResponse {
+                            message: format!("tasks/list returned page token '{token}' again"),
+                            data: None,
+                        };
+                        return Some((Err(error), pages));
+                    }
+                    pages.params.page_token = Some(token);
+                    pages.done = false;
+                }
+                pages.tasks.extend(page.tasks);
+            }
+        })
     }
 
     /// Cancel a running task (`tasks/cancel`).
     pub async fn cancel_task(&self, params: CancelTaskParams) -> A2AResult<Task> {
+        self.cancel_task_with_options(params, &CallOptions::default())
+            .await
+    }
+
+    /// Like [`cancel_task()`](Self::cancel_task), with per-call [`CallOptions`].
+    pub async fn cancel_task_with_options(
+        &self,
+        params: CancelTaskParams,
+        options: &CallOptions,
+    ) -> A2AResult<Task> {
         let request = build_request("tasks/cancel", &params)?;
-        let response = self.transport.send(&request).await?;
-        parse_result(response)
+        let response = self.transport.send_with_options(&request, options).await?;
+        parse_task(response, self.schema_diagnostics)
+    }
+
+    /// Cancel every non-terminal task of a context (`contexts/cancel`).
+    ///
+    /// The response has one entry per task, with the canceled task or the
+    /// reason it could not be canceled; check
+    /// [`all_canceled()`](CancelContextResult::all_canceled). Only servers
+    /// built on this SDK's axum integration implement the method.
+    pub async fn cancel_context(
+        &self,
+        params: CancelContextParams,
+    ) -> A2AResult<CancelContextResult> {
+        self.cancel_context_with_options(params, &CallOptions::default())
+            .await
+    }
+
+    /// Like [`cancel_context()`](Self::cancel_context), with per-call [`CallOptions`].
+    pub async fn cancel_context_with_options(
+        &self,
+        params: CancelContextParams,
+        options: &CallOptions,
+    ) -> A2AResult<CancelContextResult> {
+        let request = build_request(CONTEXTS_CANCEL_METHOD, &params)?;
+        let response = self.transport.send_with_options(&request, options).await?;
+        parse_result(response, self.schema_diagnostics)
+    }
+
+    /// List a task's artifacts without their parts (`tasks/artifacts/list`).
+    ///
+    /// Fetch the artifacts needed with [`get_artifact()`](Self::get_artifact)
+    /// rather than all of them with every `tasks/get`. Only servers built on
+    /// this SDK's axum integration implement the method.
+    pub async fn list_artifacts(&self, task_id: &str) -> A2AResult<ListArtifactsResult> {
+        let params = ListArtifactsParams {
+            task_id: task_id.to_string(),
+            tenant: None,
+        };
+        self.list_artifacts_with_options(params, &CallOptions::default())
+            .await
+    }
+
+    /// Like [`list_artifacts()`](Self::list_artifacts), with full params and
+    /// per-call [`CallOptions`].
+    pub async fn list_artifacts_with_options(
+        &self,
+        params: ListArtifactsParams,
+        options: &CallOptions,
+    ) -> A2AResult<ListArtifactsResult> {
+        let request = build_request(TASKS_ARTIFACTS_LIST_METHOD, &params)?;
+        let response = self.transport.send_with_options(&request, options).await?;
+        parse_result(response, self.schema_diagnostics)
+    }
+
+    /// Get one artifact of a task, with its parts (`tasks/artifacts/get`).
+    ///
+    /// Only servers built on this SDK's axum integration implement the
+    /// method.
+    pub async fn get_artifact(&self, task_id: &str, artifact_id: &str) -> A2AResult<Artifact> {
+        let params = GetArtifactParams {
+            task_id: task_id.to_string(),
+            artifact_id: artifact_id.to_string(),
+            tenant: None,
+        };
+        self.get_artifact_with_options(params, &CallOptions::default())
+            .await
+    }
+
+    /// Like [`get_artifact()`](Self::get_artifact), with full params and
+    /// per-call [`CallOptions`].
+    pub async fn get_artifact_with_options(
+        &self,
+        params: GetArtifactParams,
+        options: &CallOptions,
+    ) -> A2AResult<Artifact> {
+        let request = build_request(TASKS_ARTIFACTS_GET_METHOD, &params)?;
+        let response = self.transport.send_with_options(&request, options).await?;
+        parse_result(response, self.schema_diagnostics)
     }
 
     /// Resubscribe to a task's event stream (`tasks/resubscribe`).
@@ -183,8 +723,48 @@ impl A2AClient {
     ///
     /// Python SDK ref: `Client.resubscribe()`
     pub async fn resubscribe(&self, params: TaskIdParams) -> A2AResult<SseStream> {
+        self.resubscribe_with_options(params, &CallOptions::default())
+            .await
+    }
+
+    /// Like [`resubscribe()`](Self::resubscribe), with per-call [`CallOptions`].
+    pub async fn resubscribe_with_options(
+        &self,
+        params: TaskIdParams,
+        options: &CallOptions,
+    ) -> A2AResult<SseStream> {
         let request = build_request("tasks/resubscribe", &params)?;
-        self.transport.send_stream(&request).await
+        let stream = self
+            .transport
+            .send_stream_with_options(&request, options)
+            .await?;
+        Ok(stream.with_interceptors(&self.stream_interceptors))
+    }
+
+    /// Validate a candidate message against one of the agent's skills
+    /// without executing it (`skills/invoke` with `dryRun`).
+    ///
+    /// `dry_run` is forced to `true`. Only servers built on this SDK's
+    /// axum integration implement the method; with an agent card at hand,
+    /// [`crate::utils::validate_skill_message`] runs the same checks locally.
+    pub async fn validate_skill(
+        &self,
+        params: SkillInvokeParams,
+    ) -> A2AResult<SkillValidationResult> {
+        self.validate_skill_with_options(params, &CallOptions::default())
+            .await
+    }
+
+    /// Like [`validate_skill()`](Self::validate_skill), with per-call [`CallOptions`].
+    pub async fn validate_skill_with_options(
+        &self,
+        mut params: SkillInvokeParams,
+        options: &CallOptions,
+    ) -> A2AResult<SkillValidationResult> {
+        params.dry_run = true;
+        let request = build_request(SKILLS_INVOKE_METHOD, &params)?;
+        let response = self.transport.send_with_options(&request, options).await?;
+        parse_result(response, self.schema_diagnostics)
     }
 
     /// Set push notification configuration for a task
@@ -194,10 +774,20 @@ impl A2AClient {
     pub async fn set_task_callback(
         &self,
         params: SetTaskPushNotificationConfigParams,
+    ) -> A2AResult<TaskPushNotificationConfig> {
+        self.set_task_callback_with_options(params, &CallOptions::default())
+            .await
+    }
+
+    /// Like [`set_task_callback()`](Self::set_task_callback), with per-call [`CallOptions`].
+    pub async fn set_task_callback_with_options(
+        &self,
+        params: SetTaskPushNotificationConfigParams,
+        options: &CallOptions,
     ) -> A2AResult<TaskPushNotificationConfig> {
         let request = build_request("tasks/pushNotificationConfig/set", &params)?;
-        let response = self.transport.send(&request).await?;
-        parse_result(response)
+        let response = self.transport.send_with_options(&request, options).await?;
+        parse_result(response, self.schema_diagnostics)
     }
 
     /// Get push notification configuration for a task
@@ -207,10 +797,116 @@ impl A2AClient {
     pub async fn get_task_callback(
         &self,
         params: GetTaskPushNotificationConfigParams,
+    ) -> A2AResult<TaskPushNotificationConfig> {
+        self.get_task_callback_with_options(params, &CallOptions::default())
+            .await
+    }
+
+    /// Like [`get_task_callback()`](Self::get_task_callback), with per-call [`CallOptions`].
+    pub async fn get_task_callback_with_options(
+        &self,
+        params: GetTaskPushNotificationConfigParams,
+        options: &CallOptions,
     ) -> A2AResult<TaskPushNotificationConfig> {
         let request = build_request("tasks/pushNotificationConfig/get", &params)?;
-        let response = self.transport.send(&request).await?;
-        parse_result(response)
+        let response = self.transport.send_with_options(&request, options).await?;
+        parse_result(response, self.schema_diagnostics)
+    }
+
+    /// List the push notification configurations of a task
+    /// (`tasks/pushNotificationConfig/list`).
+    ///
+    /// Python SDK ref: `Client.list_task_callback()`
+    pub async fn list_task_callbacks(
+        &self,
+        params: ListTaskPushNotificationConfigParams,
+    ) -> A2AResult<Vec<TaskPushNotificationConfig>> {
+        self.list_task_callbacks_with_options(params, &CallOptions::default())
+            .await
+    }
+
+    /// Like [`list_task_callbacks()`](Self::list_task_callbacks), with per-call [`CallOptions`].
+    ///
+    /// Accepts both the spec's bare array and a
+    /// [`ListTaskPushNotificationConfigResponse`] object.
+    pub async fn list_task_callbacks_with_options(
+        &self,
+        params: ListTaskPushNotificationConfigParams,
+        options: &CallOptions,
+    ) -> A2AResult<Vec<TaskPushNotificationConfig>> {
+        let request = build_request("tasks/pushNotificationConfig/list", &params)?;
+        let response = self.transport.send_with_options(&request, options).await?;
+        let result: serde_json::Value = parse_result(response, false)?;
+        if result.is_array() {
+            decode_value(result, self.schema_diagnostics)
+        } else {
+            let page: ListTaskPushNotificationConfigResponse =
+                decode_value(result, self.schema_diagnostics)?;
+            Ok(page.configs)
+        }
+    }
+
+    /// Delete a push notification configuration of a task
+    /// (`tasks/pushNotificationConfig/delete`).
+    ///
+    /// Python SDK ref: `Client.delete_task_callback()`
+    pub async fn delete_task_callback(
+        &self,
+        params: DeleteTaskPushNotificationConfigParams,
+    ) -> A2AResult<()> {
+        self.delete_task_callback_with_options(params, &CallOptions::default())
+            .await
+    }
+
+    /// Like [`delete_task_callback()`](Self::delete_task_callback), with per-call [`CallOptions`].
+    pub async fn delete_task_callback_with_options(
+        &self,
+        params: DeleteTaskPushNotificationConfigParams,
+        options: &CallOptions,
+    ) -> A2AResult<()> {
+        let request = build_request("tasks/pushNotificationConfig/delete", &params)?;
+        let response = self.transport.send_with_options(&request, options).await?;
+        // The result is `null`, so only an error is of interest.
+        match response.error {
+            Some(error) => Err(A2AError::JsonRpc {
+                code: error.code,
+                message: error.message,
+                data: error.data,
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// With card validation on and a cached card, fail with
+    /// `IncompatibleAgent` if the card rules out `params.message`.
+    fn check_compatibility(
+        &self,
+        params: &SendMessageParams,
+        options: &CallOptions,
+    ) -> A2AResult<()> {
+        let (true, Some(card)) = (self.card_validation, &self.agent_card) else {
+            return Ok(());
+        };
+        let mut extensions = self.supported_extensions.clone();
+        for (key, value) in &options.headers {
+            if key.eq_ignore_ascii_case(HTTP_EXTENSION_HEADER) {
+                extensions.extend(get_requested_extensions(std::slice::from_ref(value)));
+            }
+        }
+        let issues = check_message_compatibility(card, &params.message, &extensions);
+        if issues.is_empty() {
+            Ok(())
+        } else {
+            Err(A2AError::incompatible_agent(issues))
+        }
+    }
+
+    /// When `tasks/get` for `task_id` should stop retrying `TaskNotFound`,
+    /// or `None` if retries don't apply to this task.
+    fn not_found_retry_deadline(&self, task_id: &str) -> Option<Instant> {
+        let window = self.not_found_retry?;
+        let recent = self.recent_tasks.lock().unwrap();
+        recent.get(task_id).map(|created| *created + window)
     }
 
     /// Get the cached agent card.
@@ -233,6 +929,28 @@ impl A2AClient {
         })
     }
 
+    /// Fetch the public agent card again, bypassing the TTL of the
+    /// [`AgentCardCache`], and update the client's copy.
+    ///
+    /// A cached card is revalidated with a conditional request, so an
+    /// unchanged card isn't downloaded again. The client keeps talking to
+    /// the interface it was created with.
+    ///
+    /// # Errors
+    ///
+    /// Fails for clients not created by [`from_url()`](Self::from_url) or
+    /// [`ClientBuilder::build()`](crate::ClientBuilder::build), and with
+    /// the resolver's errors.
+    pub async fn refresh_card(&mut self) -> A2AResult<&AgentCard> {
+        let (resolver, base_url) = self.card_source.as_ref().ok_or_else(|| {
+            A2AError::Transport(
+                "client has no agent card source; create it with A2AClient::from_url()".to_string(),
+            )
+        })?;
+        let card = resolver.refresh(base_url).await?;
+        Ok(self.agent_card.insert(card))
+    }
+
     /// Fetch the agent card from the server, updating the cached copy.
     ///
     /// If the agent supports authenticated extended cards
@@ -243,6 +961,16 @@ impl A2AClient {
     ///
     /// Python SDK ref: `BaseClient.get_card()` in `base_client.py`
     pub async fn get_card_from_server(&mut self) -> A2AResult<&AgentCard> {
+        self.get_card_from_server_with_options(&CallOptions::default())
+            .await
+    }
+
+    /// Like [`get_card_from_server()`](Self::get_card_from_server), with
+    /// per-call [`CallOptions`] applied to the extended card request.
+    pub async fn get_card_from_server_with_options(
+        &mut self,
+        options: &CallOptions,
+    ) -> A2AResult<&AgentCard> {
         // If we don't have a card yet, we can't know the base URL to fetch from.
         let card = self.agent_card.as_ref().ok_or_else(|| {
             A2AError::Transport(
@@ -259,8 +987,8 @@ impl A2AClient {
 
         // Make the JSON-RPC call to get the authenticated extended card.
         let request = build_request("getAuthenticatedExtendedCard", &serde_json::json!({}))?;
-        let response = self.transport.send(&request).await?;
-        let extended_card: AgentCard = parse_result(response)?;
+        let response = self.transport.send_with_options(&request, options).await?;
+        let extended_card: AgentCard = parse_result(response, self.schema_diagnostics)?;
 
         self.agent_card = Some(extended_card);
         Ok(self.agent_card.as_ref().unwrap())
@@ -268,11 +996,38 @@ impl A2AClient {
 
     /// Close the client and release any held resources.
     ///
+    /// Closes the transport for clones of this client too.
+    ///
     /// Python SDK ref: `BaseClient.close()` in `base_client.py`
     pub async fn close(self) -> A2AResult<()> {
         self.transport.close().await
     }
 
+    /// Send a JSON-RPC notification: `method` with `params` and no `id`.
+    ///
+    /// Fire-and-forget — the server sends no response body, so nothing
+    /// about how (or whether) it handled the notification is reported.
+    /// Errors only cover delivery (connection failures, non-2xx HTTP
+    /// status). Useful for telemetry and ack-free status signals.
+    pub async fn notify(&self, method: &str, params: impl Serialize) -> A2AResult<()> {
+        self.notify_with_options(method, params, &CallOptions::default())
+            .await
+    }
+
+    /// [`notify()`](Self::notify) with per-call options.
+    pub async fn notify_with_options(
+        &self,
+        method: &str,
+        params: impl Serialize,
+        options: &CallOptions,
+    ) -> A2AResult<()> {
+        let params = serde_json::to_value(params).map_err(|e| {
+            A2AError::Transport(format!("failed to serialize notification params: {e}"))
+        })?;
+        let request = JsonRpcRequest::notification(method, Some(params));
+        self.transport.notify_with_options(&request, options).await
+    }
+
     // ──────────────────────────────────────────────────
     // Convenience Helpers
     // ──────────────────────────────────────────────────
@@ -295,6 +1050,135 @@ impl A2AClient {
         self.send_message_stream(params).await
     }
 
+    /// Convenience: send a text message via `message/stream` and return
+    /// the agent's reply as one string.
+    ///
+    /// The text of the streamed artifacts and agent messages is collected
+    /// by a [`StreamCollector`]; use one directly for incremental deltas or
+    /// the task's final status.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first stream error.
+    pub async fn send_text_collect(&self, text: &str) -> A2AResult<String> {
+        let stream = self.send_text_stream(text).await?;
+        StreamCollector::new(stream).collect().await
+    }
+
+    /// Convenience: send a text prompt together with local files in one
+    /// message.
+    ///
+    /// The message has the prompt as its first part followed by one file
+    /// part per path, in order. Files up to the inline limit (see
+    /// [`with_inline_file_limit()`](Self::with_inline_file_limit)) are
+    /// embedded as base64; larger files are uploaded with the
+    /// [`FileUploader`] set by [`with_file_uploader()`](Self::with_file_uploader)
+    /// and referenced by URI. MIME types are guessed from file extensions.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`A2AError::InvalidParams`] if a file can't be read, or is
+    /// over the inline limit and no uploader is configured; upload errors
+    /// are returned as is. Nothing is sent in that case.
+    pub async fn send_text_with_files<P: AsRef<Path>>(
+        &self,
+        prompt: &str,
+        paths: &[P],
+    ) -> A2AResult<SendMessageResponse> {
+        let params = build_files_message_params(
+            prompt,
+            paths,
+            self.inline_file_limit,
+            self.file_uploader.as_deref(),
+        )
+        .await?;
+        self.send_message(params).await
+    }
+
+    /// Streaming variant of [`send_text_with_files()`](Self::send_text_with_files),
+    /// sent via `message/stream`.
+    pub async fn send_text_with_files_stream<P: AsRef<Path>>(
+        &self,
+        prompt: &str,
+        paths: &[P],
+    ) -> A2AResult<SseStream> {
+        let params = build_files_message_params(
+            prompt,
+            paths,
+            self.inline_file_limit,
+            self.file_uploader.as_deref(),
+        )
+        .await?;
+        self.send_message_stream(params).await
+    }
+
+    /// Send the message built by `message` via `message/send`.
+    ///
+    /// Files are attached as by
+    /// [`send_text_with_files()`](Self::send_text_with_files): inline up
+    /// to the inline limit, uploaded above it.
+    ///
+    /// # Errors
+    ///
+    /// As [`send_text_with_files()`](Self::send_text_with_files) when a
+    /// file can't be attached; nothing is sent in that case.
+    pub async fn send(&self, message: MessageBuilder) -> A2AResult<SendMessageResponse> {
+        let params = self.build_message_params(message).await?;
+        self.send_message(params).await
+    }
+
+    /// Streaming variant of [`send()`](Self::send), sent via
+    /// `message/stream`.
+    pub async fn send_stream(&self, message: MessageBuilder) -> A2AResult<SseStream> {
+        let params = self.build_message_params(message).await?;
+        self.send_message_stream(params).await
+    }
+
+    /// Convenience: send a single file, from a path or bytes.
+    ///
+    /// Without `mime_type`, a path's is guessed from its extension and
+    /// bytes are sent as `application/octet-stream`. See
+    /// [`send()`](Self::send) for how files are attached.
+    pub async fn send_file(
+        &self,
+        file: impl Into<FileSource>,
+        mime_type: Option<&str>,
+    ) -> A2AResult<SendMessageResponse> {
+        self.send(MessageBuilder::new().file(file, mime_type)).await
+    }
+
+    /// Streaming variant of [`send_file()`](Self::send_file).
+    pub async fn send_file_stream(
+        &self,
+        file: impl Into<FileSource>,
+        mime_type: Option<&str>,
+    ) -> A2AResult<SseStream> {
+        self.send_stream(MessageBuilder::new().file(file, mime_type))
+            .await
+    }
+
+    /// Convenience: send a message with a single structured data part.
+    pub async fn send_data(&self, data: serde_json::Value) -> A2AResult<SendMessageResponse> {
+        self.send(MessageBuilder::new().data(data)).await
+    }
+
+    /// Streaming variant of [`send_data()`](Self::send_data).
+    pub async fn send_data_stream(&self, data: serde_json::Value) -> A2AResult<SseStream> {
+        self.send_stream(MessageBuilder::new().data(data)).await
+    }
+
+    async fn build_message_params(&self, message: MessageBuilder) -> A2AResult<SendMessageParams> {
+        let message = message
+            .resolve(self.inline_file_limit, self.file_uploader.as_deref())
+            .await?;
+        Ok(SendMessageParams {
+            message,
+            configuration: None,
+            metadata: None,
+            tenant: None,
+        })
+    }
+
     /// Convenience: send a text message with a specific context ID.
     ///
     /// Useful for continuing a conversation within an existing context.
@@ -349,6 +1233,61 @@ impl A2AClient {
         self.send_message(params).await
     }
 
+    /// Answer a task waiting for input (`input-required`): send `message`
+    /// as the next turn of task `task_id`.
+    ///
+    /// The message's `task_id` is set to `task_id`. Without a `context_id`
+    /// it gets the task's, looked up with `tasks/get`; pass it on the
+    /// message to skip the lookup.
+    ///
+    /// ```no_run
+    /// # use a2a_rs::client::{create_text_message, A2AClient};
+    /// # use a2a_rs::types::Role;
+    /// # async fn example(client: A2AClient) -> a2a_rs::A2AResult<()> {
+    /// let reply = create_text_message(Role::User, "Berlin");
+    /// let response = client.continue_task("task-123", reply).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn continue_task(
+        &self,
+        task_id: &str,
+        message: Message,
+    ) -> A2AResult<SendMessageResponse> {
+        let params = self.continuation_params(task_id, message).await?;
+        self.send_message(params).await
+    }
+
+    /// Streaming variant of [`continue_task()`](Self::continue_task), sent
+    /// via `message/stream`.
+    pub async fn continue_task_stream(
+        &self,
+        task_id: &str,
+        message: Message,
+    ) -> A2AResult<SseStream> {
+        let params = self.continuation_params(task_id, message).await?;
+        self.send_message_stream(params).await
+    }
+
+    /// Params sending `message` as the next turn of task `task_id`.
+    async fn continuation_params(
+        &self,
+        task_id: &str,
+        mut message: Message,
+    ) -> A2AResult<SendMessageParams> {
+        if message.context_id.is_none() {
+            let task = self.get_task_by_id(task_id, None).await?;
+            message.context_id = Some(task.context_id);
+        }
+        message.task_id = Some(task_id.to_string());
+        Ok(SendMessageParams {
+            message,
+            configuration: None,
+            metadata: None,
+            tenant: None,
+        })
+    }
+
     /// Convenience: get a task by ID with optional history length.
     pub async fn get_task_by_id(
         &self,
@@ -360,6 +1299,7 @@ impl A2AClient {
             history_length,
             metadata: None,
             tenant: None,
+            consistency: None,
         })
         .await
     }
@@ -374,6 +1314,16 @@ impl A2AClient {
         .await
     }
 
+    /// Convenience: cancel every non-terminal task of a context by ID.
+    pub async fn cancel_context_by_id(&self, context_id: &str) -> A2AResult<CancelContextResult> {
+        self.cancel_context(CancelContextParams {
+            context_id: context_id.to_string(),
+            metadata: None,
+            tenant: None,
+        })
+        .await
+    }
+
     /// Convenience: resubscribe to a task by ID.
     pub async fn resubscribe_by_id(&self, task_id: &str) -> A2AResult<SseStream> {
         self.resubscribe(TaskIdParams {
@@ -388,6 +1338,21 @@ impl A2AClient {
 // Internal helpers
 // ──────────────────────────────────────────────────
 
+/// Params for a user message with `prompt` and one file part per path.
+async fn build_files_message_params<P: AsRef<Path>>(
+    prompt: &str,
+    paths: &[P],
+    inline_limit: u64,
+    uploader: Option<&dyn FileUploader>,
+) -> A2AResult<SendMessageParams> {
+    let mut params = build_text_message_params(prompt);
+    for path in paths {
+        let part = file_part(path.as_ref(), None, inline_limit, uploader).await?;
+        params.message.parts.push(part);
+    }
+    Ok(params)
+}
+
 /// Build a JSON-RPC request with a random UUID ID.
 fn build_request(method: &str, params: &impl Serialize) -> A2AResult<JsonRpcRequest> {
     let params_value = serde_json::to_value(params)
@@ -404,7 +1369,12 @@ fn build_request(method: &str, params: &impl Serialize) -> A2AResult<JsonRpcRequ
 /// Parse the `result` field from a JSON-RPC response into the expected type.
 ///
 /// If the response contains an error, converts it into an [`A2AError::JsonRpc`].
-fn parse_result<T: serde::de::DeserializeOwned>(response: JsonRpcResponse) -> A2AResult<T> {
+/// With `diagnostics`, a result that doesn't decode is explained with
+/// [`diagnose()`](super::diagnose).
+fn parse_result<T: serde::de::DeserializeOwned>(
+    response: JsonRpcResponse,
+    diagnostics: bool,
+) -> A2AResult<T> {
     // Check for JSON-RPC error.
     if let Some(error) = response.error {
         return Err(A2AError::JsonRpc {
@@ -418,9 +1388,53 @@ fn parse_result<T: serde::de::DeserializeOwned>(response: JsonRpcResponse) -> A2
     let result = response.result.ok_or_else(|| {
         A2AError::InvalidJson("JSON-RPC response has neither 'result' nor 'error'".to_string())
     })?;
+    decode_value(result, diagnostics)
+}
+
+/// Decode a JSON-RPC `result` as `T`, explaining failures with a
+/// [`DecodeDiagnostic`](super::DecodeDiagnostic) if `diagnostics` is set.
+fn decode_value<T: serde::de::DeserializeOwned>(
+    result: serde_json::Value,
+    diagnostics: bool,
+) -> A2AResult<T> {
+    if !diagnostics {
+        return serde_json::from_value(result).map_err(|e| {
+            A2AError::InvalidJson(format!("failed to deserialize response result: {e}"))
+        });
+    }
+    T::deserialize(&result).map_err(|e| match super::diagnose::<T>(&result) {
+        Some(diagnostic) => A2AError::InvalidJson(format!(
+            "failed to deserialize response result: {diagnostic}"
+        )),
+        None => A2AError::InvalidJson(format!("failed to deserialize response result: {e}")),
+    })
+}
+
+/// [`parse_result()`] for calls returning a [`Task`], logging states this
+/// SDK doesn't recognise.
+fn parse_task(response: JsonRpcResponse, diagnostics: bool) -> A2AResult<Task> {
+    let task: Task = parse_result(response, diagnostics)?;
+    warn_unrecognized_state(&task.id, &task.status);
+    Ok(task)
+}
+
+/// Log a task status whose state the peer sent but this SDK doesn't know.
+/// The state parses as [`TaskState::Unknown`](crate::types::TaskState::Unknown);
+/// the original string stays available from
+/// [`TaskStatus::unrecognized_state`].
+pub(crate) fn warn_unrecognized_state(task_id: &str, status: &TaskStatus) {
+    if let Some(raw) = status.unrecognized_state() {
+        tracing::warn!(
+            task_id,
+            state = %raw,
+            "Unrecognised task state, treating as unknown"
+        );
+    }
+}
 
-    serde_json::from_value(result)
-        .map_err(|e| A2AError::InvalidJson(format!("failed to deserialize response result: {e}")))
+/// Whether a call failed with the A2A `TaskNotFound` error.
+fn is_task_not_found<T>(result: &A2AResult<T>) -> bool {
+    matches!(result, Err(A2AError::JsonRpc { code, .. }) if *code == error::TASK_NOT_FOUND)
 }
 
 /// Build a simple text message params struct.
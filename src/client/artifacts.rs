@@ -0,0 +1,240 @@
+//! Client-side reassembly of streamed artifacts.
+//!
+//! An agent may stream several artifacts at once, interleaving their chunks
+//! (`artifact-update` events) in one stream. [`ArtifactAggregator`] sorts
+//! the chunks by `artifactId` and rebuilds each artifact, following the
+//! same rules as the server's task store:
+//!
+//! - a chunk without `append` starts (or restarts) its artifact
+//! - a chunk with `append: true` adds its parts to the artifact
+//! - a chunk with `lastChunk: true` completes the artifact
+//!
+//! Chunk order is preserved per artifact, whatever the interleaving.
+//! Artifacts still open when the stream ends — a final status update, or
+//! an explicit [`finish()`](ArtifactAggregator::finish) — are
+//! marked [`Incomplete`](ArtifactStatus::Incomplete).
+//!
+//! Retransmitted chunks are dropped: an appended chunk for an artifact
+//! that already completed, and a repeat of the chunk that completed it.
+//! Consecutive identical chunks mid-stream are kept by default, since
+//! token streams legitimately repeat; enable
+//! [`drop_repeated_chunks`](ArtifactAggregator::drop_repeated_chunks) for
+//! transports known to redeliver.
+//!
+//! To simply collect a stream's artifacts, use
+//! [`SseStream::collect_artifacts()`](super::SseStream::collect_artifacts),
+//! which runs [`ArtifactAssembler`](crate::utils::ArtifactAssembler) and
+//! also reorders chunks numbered with a `chunkIndex`.
+//!
+//! ```no_run
+//! # use a2a_rs::client::{A2AClient, ArtifactAggregator};
+//! # async fn example(client: A2AClient) -> a2a_rs::A2AResult<()> {
+//! let mut aggregator = ArtifactAggregator::new()
+//!     .on_complete(|artifact, status| println!("{} {status:?}", artifact.artifact_id));
+//! let mut stream = client.send_text_stream("Write two files").await?;
+//! while let Some(event) = stream.next().await {
+//!     aggregator.push(&event?);
+//! }
+//! aggregator.finish();
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use tracing::debug;
+
+use crate::types::{Artifact, StreamResponse, TaskArtifactUpdateEvent};
+
+/// How far an artifact has been received.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArtifactStatus {
+    /// Chunks are still arriving.
+    Streaming,
+    /// The chunk with `lastChunk: true` arrived.
+    Complete,
+    /// The stream ended before the last chunk.
+    Incomplete,
+}
+
+/// Callback invoked when an artifact completes or the stream ends without
+/// its last chunk.
+type CompletionCallback = Arc<dyn Fn(&Artifact, ArtifactStatus) + Send + Sync>;
+
+/// Reassembles interleaved artifact chunks of one stream. See the
+/// [module documentation](self).
+#[derive(Default)]
+pub struct ArtifactAggregator {
+    /// Artifact IDs in order of their first chunk.
+    order: Vec<String>,
+    entries: HashMap<String, Entry>,
+    on_complete: Option<CompletionCallback>,
+    drop_repeated: bool,
+}
+
+struct Entry {
+    artifact: Artifact,
+    status: ArtifactStatus,
+    /// The last applied chunk, for duplicate detection.
+    last_chunk: serde_json::Value,
+}
+
+impl std::fmt::Debug for ArtifactAggregator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ArtifactAggregator")
+            .field("artifacts", &self.order)
+            .field("on_complete", &self.on_complete.is_some())
+            .field("drop_repeated", &self.drop_repeated)
+            .finish()
+    }
+}
+
+impl ArtifactAggregator {
+    /// An empty aggregator.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Call `callback` once per artifact when it completes, or with
+    /// [`ArtifactStatus::Incomplete`] when the stream ends before its last
+    /// chunk. A restarted artifact may complete again.
+    pub fn on_complete<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&Artifact, ArtifactStatus) + Send + Sync + 'static,
+    {
+        self.on_complete = Some(Arc::new(callback));
+        self
+    }
+
+    /// Also drop a chunk identical to the previous chunk of its artifact.
+    pub fn drop_repeated_chunks(mut self) -> Self {
+        self.drop_repeated = true;
+        self
+    }
+
+    /// Feed a stream event. Artifact updates are aggregated; a final status
+    /// update ends the stream like [`finish()`](Self::finish). Other events
+    /// are ignored.
+    pub fn push(&mut self, event: &StreamResponse) {
+        match event {
+            StreamResponse::ArtifactUpdate(update) => {
+                self.push_chunk(update);
+            }
+            StreamResponse::StatusUpdate(update) if update.r#final => self.finish(),
+            _ => {}
+        }
+    }
+
+    /// Apply one chunk. Returns `false` if it was dropped as a duplicate.
+    ///
+    /// An appended chunk for an artifact not seen before starts it, so a
+    /// stream joined mid-artifact still yields the remainder.
+    pub fn push_chunk(&mut self, update: &TaskArtifactUpdateEvent) -> bool {
+        let artifact_id = &update.artifact.artifact_id;
+        let chunk = chunk_key(update);
+        let append = update.append.unwrap_or(false);
+        let last = update.last_chunk.unwrap_or(false);
+
+        let entry = match self.entries.get_mut(artifact_id) {
+            Some(entry) => {
+                let repeated = entry.last_chunk == chunk;
+                let duplicate = match entry.status {
+                    ArtifactStatus::Complete => append || repeated,
+                    _ => self.drop_repeated && repeated,
+                };
+                if duplicate {
+                    debug!(artifact_id = %artifact_id, "Dropping duplicate artifact chunk");
+                    return false;
+                }
+                if append {
+                    entry
+                        .artifact
+                        .parts
+                        .extend(update.artifact.parts.iter().cloned());
+                    if update.artifact.metadata.is_some() {
+                        entry.artifact.metadata = update.artifact.metadata.clone();
+                    }
+                } else {
+                    entry.artifact = update.artifact.clone();
+                }
+                entry.status = ArtifactStatus::Streaming;
+                entry.last_chunk = chunk;
+                entry
+            }
+            None => {
+                self.order.push(artifact_id.clone());
+                self.entries.entry(artifact_id.clone()).or_insert(Entry {
+                    artifact: update.artifact.clone(),
+                    status: ArtifactStatus::Streaming,
+                    last_chunk: chunk,
+                })
+            }
+        };
+
+        if last {
+            entry.status = ArtifactStatus::Complete;
+            if let Some(callback) = &self.on_complete {
+                callback(&entry.artifact, ArtifactStatus::Complete);
+            }
+        }
+        true
+    }
+
+    /// End the stream: every artifact still streaming becomes
+    /// [`ArtifactStatus::Incomplete`], in order of first appearance.
+    pub fn finish(&mut self) {
+        for id in &self.order {
+            let Some(entry) = self.entries.get_mut(id) else {
+                continue;
+            };
+            if entry.status == ArtifactStatus::Streaming {
+                entry.status = ArtifactStatus::Incomplete;
+                if let Some(callback) = &self.on_complete {
+                    callback(&entry.artifact, ArtifactStatus::Incomplete);
+                }
+            }
+        }
+    }
+
+    /// The artifact with this ID as assembled so far.
+    pub fn get(&self, artifact_id: &str) -> Option<&Artifact> {
+        self.entries.get(artifact_id).map(|entry| &entry.artifact)
+    }
+
+    /// How far the artifact with this ID has been received.
+    pub fn status(&self, artifact_id: &str) -> Option<ArtifactStatus> {
+        self.entries.get(artifact_id).map(|entry| entry.status)
+    }
+
+    /// All artifacts, in order of their first chunk.
+    pub fn artifacts(&self) -> impl Iterator<Item = &Artifact> {
+        self.order.iter().map(|id| &self.entries[id].artifact)
+    }
+
+    /// Whether every artifact received its last chunk.
+    pub fn is_complete(&self) -> bool {
+        self.entries
+            .values()
+            .all(|entry| entry.status == ArtifactStatus::Complete)
+    }
+
+    /// Take the artifacts, in order of their first chunk.
+    pub fn into_artifacts(mut self) -> Vec<Artifact> {
+        self.order
+            .iter()
+            .filter_map(|id| self.entries.remove(id))
+            .map(|entry| entry.artifact)
+            .collect()
+    }
+}
+
+/// What identifies a chunk for duplicate detection: its artifact content
+/// and flags.
+fn chunk_key(update: &TaskArtifactUpdateEvent) -> serde_json::Value {
+    serde_json::json!({
+        "artifact": update.artifact,
+        "append": update.append,
+        "lastChunk": update.last_chunk,
+    })
+}
@@ -0,0 +1,551 @@
+//! Per-request credentials for the client transport.
+//!
+//! A [`CredentialService`] attached to a
+//! [`JsonRpcTransport`](super::JsonRpcTransport) supplies a fresh
+//! [`Credential`] for every outgoing request. Credentials that carry an
+//! expiry also keep long-lived SSE streams alive: shortly before the token
+//! expires the transport reconnects with a new credential and resumes the
+//! task via `tasks/resubscribe`, so callers never see the token rotate.
+//!
+//! [`AuthProvider`]s authenticate against the [`SecurityScheme`]s an agent
+//! card declares: [`ApiKeyAuth`], [`BearerAuth`] and
+//! [`OAuth2ClientCredentials`] (cached, refreshed before expiry). An
+//! [`Authenticator`] picks the first security requirement of the card that
+//! one of its providers supports and is itself a [`CredentialService`];
+//! [`ClientBuilder::with_auth_provider`] wires this up from the resolved
+//! card.
+//!
+//! Python SDK ref: `CredentialService` in `client/auth/credentials.py`,
+//! `AuthInterceptor` in `client/auth/interceptor.py`
+//!
+//! [`ClientBuilder::with_auth_provider`]: crate::builders::ClientBuilder::with_auth_provider
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use serde::Deserialize;
+
+use crate::error::{A2AError, A2AResult};
+use crate::types::{AgentCard, ApiKeyLocation, SecurityRequirement, SecurityScheme};
+
+/// A credential attached to outgoing requests as a single HTTP header.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+/// use a2a_rs::client::Credential;
+///
+/// let credential = Credential::bearer("token-123").expires_in(Duration::from_secs(300));
+/// assert_eq!(credential.header, "Authorization");
+/// assert_eq!(credential.value, "Bearer token-123");
+/// assert!(credential.expires_at.is_some());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Credential {
+    /// Header name (e.g. `"Authorization"`).
+    pub header: String,
+
+    /// Header value (e.g. `"Bearer <token>"`).
+    pub value: String,
+
+    /// When the credential stops being accepted. `None` means it does not
+    /// expire, and streams are never reconnected for it.
+    pub expires_at: Option<Instant>,
+}
+
+impl Credential {
+    /// An `Authorization: Bearer <token>` credential.
+    pub fn bearer(token: impl Into<String>) -> Self {
+        Self::header("Authorization", format!("Bearer {}", token.into()))
+    }
+
+    /// A credential sent in an arbitrary header (e.g. an API key).
+    pub fn header(name: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            header: name.into(),
+            value: value.into(),
+            expires_at: None,
+        }
+    }
+
+    /// Set the expiry to `ttl` from now (builder-style).
+    pub fn expires_in(mut self, ttl: Duration) -> Self {
+        self.expires_at = Some(Instant::now() + ttl);
+        self
+    }
+
+    /// Set an absolute expiry (builder-style).
+    pub fn expires_at(mut self, at: Instant) -> Self {
+        self.expires_at = Some(at);
+        self
+    }
+}
+
+/// Source of credentials for outgoing requests.
+///
+/// Called before every request and every stream reconnect. Implementations
+/// should cache tokens and only hit the identity provider when the cached
+/// one is close to expiry.
+///
+/// Python SDK ref: `CredentialService.get_credentials()`
+///
+/// # Example
+///
+/// ```
+/// use a2a_rs::client::{Credential, CredentialService};
+/// use a2a_rs::error::A2AResult;
+///
+/// struct StaticToken(String);
+///
+/// #[async_trait::async_trait]
+/// impl CredentialService for StaticToken {
+///     async fn get_credential(&self) -> A2AResult<Credential> {
+///         Ok(Credential::bearer(self.0.clone()))
+///     }
+/// }
+/// ```
+#[async_trait]
+pub trait CredentialService: Send + Sync {
+    /// Return a currently valid credential.
+    async fn get_credential(&self) -> A2AResult<Credential>;
+}
+
+/// Authenticates against one kind of [`SecurityScheme`].
+///
+/// Python SDK ref: the per-scheme branches of `AuthInterceptor.intercept()`
+#[async_trait]
+pub trait AuthProvider: Send + Sync {
+    /// Whether this provider can authenticate with `scheme`.
+    fn supports(&self, scheme: &SecurityScheme) -> bool;
+
+    /// A currently valid credential for `scheme` with `scopes`.
+    ///
+    /// `scheme` is `None` when the agent card declares no security schemes;
+    /// providers then fall back to their default header.
+    async fn credential(
+        &self,
+        scheme: Option<&SecurityScheme>,
+        scopes: &[String],
+    ) -> A2AResult<Credential>;
+}
+
+/// Header used by [`ApiKeyAuth`] when the card names none.
+pub const DEFAULT_API_KEY_HEADER: &str = "X-API-Key";
+
+/// A static API key for `apiKey` schemes sent in a header or cookie.
+///
+/// `in: query` schemes are not supported: credentials are headers.
+#[derive(Clone)]
+pub struct ApiKeyAuth {
+    key: String,
+}
+
+impl std::fmt::Debug for ApiKeyAuth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ApiKeyAuth").finish_non_exhaustive()
+    }
+}
+
+impl ApiKeyAuth {
+    /// Authenticate with `key`.
+    pub fn new(key: impl Into<String>) -> Self {
+        Self { key: key.into() }
+    }
+}
+
+#[async_trait]
+impl AuthProvider for ApiKeyAuth {
+    fn supports(&self, scheme: &SecurityScheme) -> bool {
+        matches!(
+            scheme,
+            SecurityScheme::ApiKey {
+                location: ApiKeyLocation::Header | ApiKeyLocation::Cookie,
+                ..
+            }
+        )
+    }
+
+    async fn credential(
+        &self,
+        scheme: Option<&SecurityScheme>,
+        _scopes: &[String],
+    ) -> A2AResult<Credential> {
+        Ok(match scheme {
+            Some(SecurityScheme::ApiKey {
+                location: ApiKeyLocation::Cookie,
+                name,
+                ..
+            }) => Credential::header("Cookie", format!("{name}={}", self.key)),
+            Some(SecurityScheme::ApiKey { name, .. }) => Credential::header(name, &self.key),
+            _ => Credential::header(DEFAULT_API_KEY_HEADER, &self.key),
+        })
+    }
+}
+
+/// A static bearer token, for `http` schemes with `scheme: bearer` and for
+/// `oauth2` / `openIdConnect` schemes when the token was obtained elsewhere.
+#[derive(Clone)]
+pub struct BearerAuth {
+    token: String,
+}
+
+impl std::fmt::Debug for BearerAuth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BearerAuth").finish_non_exhaustive()
+    }
+}
+
+impl BearerAuth {
+    /// Authenticate with `token`.
+    pub fn new(token: impl Into<String>) -> Self {
+        Self {
+            token: token.into(),
+        }
+    }
+}
+
+#[async_trait]
+impl AuthProvider for BearerAuth {
+    fn supports(&self, scheme: &SecurityScheme) -> bool {
+        match scheme {
+            SecurityScheme::Http { scheme, .. } => scheme.eq_ignore_ascii_case("bearer"),
+            SecurityScheme::OAuth2 { .. } | SecurityScheme::OpenIdConnect { .. } => true,
+            _ => false,
+        }
+    }
+
+    async fn credential(
+        &self,
+        _scheme: Option<&SecurityScheme>,
+        _scopes: &[String],
+    ) -> A2AResult<Credential> {
+        Ok(Credential::bearer(&self.token))
+    }
+}
+
+/// How long before expiry [`OAuth2ClientCredentials`] fetches a new token.
+///
+/// Larger than the transport's default stream refresh margin, so a stream
+/// reconnecting for an expiring token gets a fresh one.
+pub const DEFAULT_TOKEN_REFRESH_MARGIN: Duration = Duration::from_secs(60);
+
+/// OAuth 2.0 client credentials grant (RFC 6749 §4.4) for `oauth2` schemes.
+///
+/// Tokens are requested from the scheme's `clientCredentials.tokenUrl`
+/// (or [`with_token_url`](Self::with_token_url)) with HTTP basic client
+/// authentication and the requirement's scopes, cached, and re-requested
+/// once they are within the refresh margin of `expires_in`.
+pub struct OAuth2ClientCredentials {
+    client_id: String,
+    client_secret: String,
+    token_url: Option<String>,
+    refresh_margin: Duration,
+    http: reqwest::Client,
+    /// Last token with the URL and scopes it was issued for.
+    cached: tokio::sync::Mutex<Option<(String, Vec<String>, Credential)>>,
+}
+
+impl std::fmt::Debug for OAuth2ClientCredentials {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OAuth2ClientCredentials")
+            .field("client_id", &self.client_id)
+            .field("token_url", &self.token_url)
+            .field("refresh_margin", &self.refresh_margin)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Successful token endpoint response (RFC 6749 §5.1).
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    #[serde(default)]
+    token_type: Option<String>,
+    #[serde(default)]
+    expires_in: Option<u64>,
+}
+
+impl OAuth2ClientCredentials {
+    /// Authenticate as `client_id` / `client_secret`.
+    pub fn new(client_id: impl Into<String>, client_secret: impl Into<String>) -> Self {
+        Self {
+            client_id: client_id.into(),
+            client_secret: client_secret.into(),
+            token_url: None,
+            refresh_margin: DEFAULT_TOKEN_REFRESH_MARGIN,
+            http: reqwest::Client::new(),
+            cached: tokio::sync::Mutex::new(None),
+        }
+    }
+
+    /// Request tokens from `url` instead of the scheme's token URL. Also
+    /// makes `oauth2` schemes without a client credentials flow supported.
+    pub fn with_token_url(mut self, url: impl Into<String>) -> Self {
+        self.token_url = Some(url.into());
+        self
+    }
+
+    /// Fetch a new token once the cached one expires within `margin`.
+    /// Defaults to [`DEFAULT_TOKEN_REFRESH_MARGIN`].
+    pub fn with_refresh_margin(mut self, margin: Duration) -> Self {
+        self.refresh_margin = margin;
+        self
+    }
+
+    fn token_url<'a>(&'a self, scheme: Option<&'a SecurityScheme>) -> Option<&'a str> {
+        if let Some(url) = &self.token_url {
+            return Some(url);
+        }
+        match scheme {
+            Some(SecurityScheme::OAuth2 { flows, .. }) => flows
+                .client_credentials
+                .as_ref()
+                .map(|flow| flow.token_url.as_str()),
+            _ => None,
+        }
+    }
+
+    async fn fetch_token(&self, url: &str, scopes: &[String]) -> A2AResult<Credential> {
+        let mut form = vec![("grant_type", "client_credentials".to_string())];
+        if !scopes.is_empty() {
+            form.push(("scope", scopes.join(" ")));
+        }
+        let response = self
+            .http
+            .post(url)
+            .basic_auth(&self.client_id, Some(&self.client_secret))
+            .form(&form)
+            .send()
+            .await
+            .map_err(|e| A2AError::Transport(format!("token request to {url} failed: {e}")))?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(A2AError::Http {
+                status: status.as_u16(),
+                body: response.text().await.unwrap_or_default(),
+            });
+        }
+        let token: TokenResponse = response.json().await.map_err(|e| {
+            A2AError::InvalidJson(format!("invalid token response from {url}: {e}"))
+        })?;
+        if let Some(token_type) = &token.token_type {
+            if !token_type.eq_ignore_ascii_case("bearer") {
+                return Err(A2AError::Transport(format!(
+                    "unsupported token type '{token_type}' from {url}"
+                )));
+            }
+        }
+        let credential = Credential::bearer(token.access_token);
+        Ok(match token.expires_in {
+            Some(secs) => credential.expires_in(Duration::from_secs(secs)),
+            None => credential,
+        })
+    }
+}
+
+#[async_trait]
+impl AuthProvider for OAuth2ClientCredentials {
+    fn supports(&self, scheme: &SecurityScheme) -> bool {
+        matches!(scheme, SecurityScheme::OAuth2 { .. }) && self.token_url(Some(scheme)).is_some()
+    }
+
+    async fn credential(
+        &self,
+        scheme: Option<&SecurityScheme>,
+        scopes: &[String],
+    ) -> A2AResult<Credential> {
+        let url = self.token_url(scheme).ok_or_else(|| {
+            A2AError::Transport("no token URL for the OAuth2 client credentials flow".into())
+        })?;
+
+        // Held across the fetch so concurrent requests share one token.
+        let mut cached = self.cached.lock().await;
+        if let Some((cached_url, cached_scopes, credential)) = &*cached {
+            let fresh = credential.expires_at.map_or(true, |at| {
+                at.saturating_duration_since(Instant::now()) > self.refresh_margin
+            });
+            if fresh && cached_url == url && cached_scopes == scopes {
+                return Ok(credential.clone());
+            }
+        }
+        let credential = self.fetch_token(url, scopes).await?;
+        *cached = Some((url.to_string(), scopes.to_vec(), credential.clone()));
+        Ok(credential)
+    }
+}
+
+/// A [`CredentialService`] that authenticates with the scheme an agent
+/// card asks for.
+///
+/// [`for_card()`](Self::for_card) walks the card's security requirements in
+/// order (`securityRequirements`, else `security`, else every declared
+/// scheme by name) and picks the first one naming a single scheme that one
+/// of the providers [`supports`](AuthProvider::supports); providers are
+/// tried in the order given. Requirements combining several schemes are
+/// skipped, since a [`Credential`] is a single header.
+///
+/// # Example
+///
+/// ```
+/// use std::sync::Arc;
+/// use a2a_rs::builders::AgentCardBuilder;
+/// use a2a_rs::client::{ApiKeyAuth, Authenticator, BearerAuth};
+/// use a2a_rs::types::{ApiKeyLocation, SecurityScheme};
+///
+/// let mut card = AgentCardBuilder::new("agent", "An agent", "1.0").build();
+/// card.security_schemes = Some(
+///     [(
+///         "key".to_string(),
+///         SecurityScheme::ApiKey {
+///             description: None,
+///             location: ApiKeyLocation::Header,
+///             name: "X-Agent-Key".to_string(),
+///         },
+///     )]
+///     .into(),
+/// );
+///
+/// let auth = Authenticator::for_card(
+///     &card,
+///     vec![Arc::new(BearerAuth::new("token")), Arc::new(ApiKeyAuth::new("secret"))],
+/// )
+/// .unwrap();
+/// assert_eq!(auth.scheme_name(), Some("key"));
+/// ```
+#[derive(Clone)]
+pub struct Authenticator {
+    provider: Arc<dyn AuthProvider>,
+    /// Selected scheme name and definition; `None` without a card.
+    scheme: Option<(String, SecurityScheme)>,
+    scopes: Vec<String>,
+}
+
+impl std::fmt::Debug for Authenticator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Authenticator")
+            .field("scheme", &self.scheme_name())
+            .field("scopes", &self.scopes)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Authenticator {
+    /// Always authenticate with `provider` using its default header, for
+    /// agents whose card declares nothing.
+    pub fn new(provider: Arc<dyn AuthProvider>) -> Self {
+        Self {
+            provider,
+            scheme: None,
+            scopes: Vec::new(),
+        }
+    }
+
+    /// Select a scheme of `card` and the provider for it.
+    ///
+    /// Falls back to [`new()`](Self::new) with the first provider if the
+    /// card declares no security schemes. Returns [`A2AError::Transport`]
+    /// if it declares some but no provider supports any of them, and
+    /// [`A2AError::InvalidParams`] if `providers` is empty.
+    pub fn for_card(card: &AgentCard, providers: Vec<Arc<dyn AuthProvider>>) -> A2AResult<Self> {
+        let first = providers
+            .first()
+            .cloned()
+            .ok_or_else(|| A2AError::invalid_params("no auth providers given"))?;
+        let schemes = match &card.security_schemes {
+            Some(schemes) if !schemes.is_empty() => schemes,
+            _ => return Ok(Self::new(first)),
+        };
+
+        let requirements: Vec<SecurityRequirement> = if !card.security_requirements.is_empty() {
+            card.security_requirements.clone()
+        } else if let Some(security) = card.security.as_ref().filter(|s| !s.is_empty()) {
+            security.clone()
+        } else {
+            let mut names: Vec<_> = schemes.keys().collect();
+            names.sort();
+            names
+                .into_iter()
+                .map(|name| [(name.clone(), Vec::new())].into())
+                .collect()
+        };
+
+        for requirement in requirements {
+            let mut entries = requirement.into_iter();
+            let (Some((name, scopes)), None) = (entries.next(), entries.next()) else {
+                continue;
+            };
+            let Some(scheme) = schemes.get(&name) else {
+                continue;
+            };
+            if let Some(provider) = providers.iter().find(|p| p.supports(scheme)) {
+                return Ok(Self {
+                    provider: provider.clone(),
+                    scheme: Some((name, scheme.clone())),
+                    scopes,
+                });
+            }
+        }
+
+        let mut names: Vec<_> = schemes.keys().map(String::as_str).collect();
+        names.sort_unstable();
+        Err(A2AError::Transport(format!(
+            "no auth provider supports the security schemes of agent '{}' ({})",
+            card.name,
+            names.join(", ")
+        )))
+    }
+
+    /// Name of the selected scheme in the card's `securitySchemes`.
+    pub fn scheme_name(&self) -> Option<&str> {
+        self.scheme.as_ref().map(|(name, _)| name.as_str())
+    }
+
+    /// Scopes requested for the selected scheme.
+    pub fn scopes(&self) -> &[String] {
+        &self.scopes
+    }
+}
+
+#[async_trait]
+impl CredentialService for Authenticator {
+    async fn get_credential(&self) -> A2AResult<Credential> {
+        let scheme = self.scheme.as_ref().map(|(_, scheme)| scheme);
+        self.provider.credential(scheme, &self.scopes).await
+    }
+}
+
+/// When to refresh a stream whose credential expires at `expires_at`.
+///
+/// Aims for `margin` before expiry; if the credential is already inside the
+/// margin, waits half its remaining lifetime instead so a short-lived token
+/// doesn't cause a reconnect loop.
+pub(crate) fn refresh_deadline(expires_at: Instant, margin: Duration) -> Instant {
+    let now = Instant::now();
+    let remaining = expires_at.saturating_duration_since(now);
+    now + remaining.saturating_sub(margin).max(remaining / 2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_refresh_deadline_uses_margin() {
+        let expires_at = Instant::now() + Duration::from_secs(300);
+        let at = refresh_deadline(expires_at, Duration::from_secs(30));
+        let before_expiry = expires_at - at;
+        assert!(before_expiry >= Duration::from_secs(30));
+        assert!(before_expiry < Duration::from_secs(31));
+    }
+
+    #[test]
+    fn test_refresh_deadline_inside_margin_waits_half_lifetime() {
+        let expires_at = Instant::now() + Duration::from_secs(10);
+        let at = refresh_deadline(expires_at, Duration::from_secs(30));
+        let before_expiry = expires_at - at;
+        assert!(before_expiry >= Duration::from_millis(4900));
+        assert!(before_expiry < Duration::from_secs(6));
+    }
+}
@@ -0,0 +1,179 @@
+//! Caching of resolved agent cards.
+//!
+//! An [`AgentCardCache`] keeps the cards a [`CardResolver`] fetched, keyed
+//! by the agent's base URL followed by the card path — e.g.
+//! `http://localhost:7420/.well-known/agent-card.json`, also when the card
+//! was found at the older `agent.json` path. A card younger than the cache's TTL
+//! is served without a request; an older one is revalidated with an HTTP
+//! conditional request (`If-None-Match` / `If-Modified-Since` from the
+//! card's `ETag` / `Last-Modified`), so an unchanged card costs a `304 Not
+//! Modified` rather than a download.
+//!
+//! [`A2AClient::from_url`](super::A2AClient::from_url) uses the
+//! process-wide [`AgentCardCache::shared()`]; other resolvers opt in with
+//! [`CardResolver::with_cache`]. A cache opened with
+//! [`persistent()`](AgentCardCache::persistent) also keeps its cards in a
+//! JSON file, so they survive restarts.
+//!
+//! [`CardResolver`]: super::CardResolver
+//! [`CardResolver::with_cache`]: super::CardResolver::with_cache
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+
+use crate::error::{A2AError, A2AResult};
+use crate::types::AgentCard;
+
+/// TTL of [`AgentCardCache::shared()`].
+pub const DEFAULT_CARD_TTL: Duration = Duration::from_secs(300);
+
+/// A cached agent card with its HTTP validators.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CachedCard {
+    /// The card.
+    pub card: AgentCard,
+    /// URL the card was fetched from.
+    pub url: String,
+    /// The response's `ETag`, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub etag: Option<String>,
+    /// The response's `Last-Modified`, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_modified: Option<String>,
+    /// When the card was last fetched or revalidated, in Unix milliseconds.
+    pub fetched_at: i64,
+}
+
+impl CachedCard {
+    /// Whether the card was fetched or revalidated less than `ttl` ago.
+    pub fn is_fresh(&self, ttl: Duration) -> bool {
+        let age = chrono::Utc::now().timestamp_millis() - self.fetched_at;
+        u128::try_from(age).is_ok_and(|age| age < ttl.as_millis())
+    }
+}
+
+/// Agent cards resolved earlier, with a TTL. See the
+/// [module documentation](self).
+#[derive(Debug)]
+pub struct AgentCardCache {
+    ttl: Duration,
+    path: Option<PathBuf>,
+    entries: Mutex<BTreeMap<String, CachedCard>>,
+}
+
+impl AgentCardCache {
+    /// An in-memory cache serving cards for `ttl` before revalidating them.
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            path: None,
+            entries: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    /// A cache kept in the JSON file at `path`, loading its cards. A
+    /// missing file is an empty cache; it is created on the first change.
+    ///
+    /// The whole file is rewritten on every change, through a temporary
+    /// file renamed over the original.
+    pub fn persistent(ttl: Duration, path: impl Into<PathBuf>) -> A2AResult<Self> {
+        let path = path.into();
+        let entries = match std::fs::read(&path) {
+            Ok(bytes) => serde_json::from_slice(&bytes).map_err(|e| {
+                A2AError::internal_error(format!("Invalid card cache {}: {e}", path.display()))
+            })?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(e) => return Err(io_error(&path, e)),
+        };
+        Ok(Self {
+            ttl,
+            path: Some(path),
+            entries: Mutex::new(entries),
+        })
+    }
+
+    /// The process-wide in-memory cache with a TTL of
+    /// [`DEFAULT_CARD_TTL`], used by
+    /// [`A2AClient::from_url`](super::A2AClient::from_url).
+    pub fn shared() -> Arc<AgentCardCache> {
+        static SHARED: OnceLock<Arc<AgentCardCache>> = OnceLock::new();
+        Arc::clone(SHARED.get_or_init(|| Arc::new(AgentCardCache::new(DEFAULT_CARD_TTL))))
+    }
+
+    /// How long a card is served before it is revalidated.
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// The file the cache is kept in, if persistent.
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// The entry under `key`, fresh or not.
+    pub async fn get(&self, key: &str) -> Option<CachedCard> {
+        self.entries.lock().await.get(key).cloned()
+    }
+
+    /// Store `entry` under `key`.
+    pub async fn insert(&self, key: &str, entry: CachedCard) -> A2AResult<()> {
+        let mut entries = self.entries.lock().await;
+        entries.insert(key.to_string(), entry);
+        self.write(&entries).await
+    }
+
+    /// Mark the entry under `key` as just revalidated.
+    pub(crate) async fn touch(&self, key: &str) -> A2AResult<()> {
+        let mut entries = self.entries.lock().await;
+        match entries.get_mut(key) {
+            Some(entry) => entry.fetched_at = chrono::Utc::now().timestamp_millis(),
+            None => return Ok(()),
+        }
+        self.write(&entries).await
+    }
+
+    /// Drop the entry under `key`.
+    pub async fn invalidate(&self, key: &str) -> A2AResult<()> {
+        let mut entries = self.entries.lock().await;
+        if entries.remove(key).is_none() {
+            return Ok(());
+        }
+        self.write(&entries).await
+    }
+
+    /// Drop every entry.
+    pub async fn clear(&self) -> A2AResult<()> {
+        let mut entries = self.entries.lock().await;
+        entries.clear();
+        self.write(&entries).await
+    }
+
+    /// Write `entries` to the file, if persistent. Called with the entries
+    /// lock held, so writes happen in the order of the changes.
+    async fn write(&self, entries: &BTreeMap<String, CachedCard>) -> A2AResult<()> {
+        let Some(path) = self.path.clone() else {
+            return Ok(());
+        };
+        let json = serde_json::to_vec_pretty(entries)
+            .map_err(|e| A2AError::internal_error(e.to_string()))?;
+        tokio::task::spawn_blocking(move || {
+            let mut tmp = path.clone().into_os_string();
+            tmp.push(".tmp");
+            std::fs::write(&tmp, json)
+                .and_then(|()| std::fs::rename(&tmp, &path))
+                .map_err(|e| io_error(&path, e))
+        })
+        .await
+        .map_err(|e| A2AError::internal_error(format!("card cache write task failed: {e}")))?
+    }
+}
+
+fn io_error(path: &Path, e: std::io::Error) -> A2AError {
+    A2AError::internal_error(format!("Card cache {}: {e}", path.display()))
+}
@@ -4,8 +4,12 @@
 //! An agent card describes the agent's capabilities, supported interfaces,
 //! skills, and the endpoint URL for JSON-RPC communication.
 
+use std::sync::Arc;
+
 use crate::error::{A2AError, A2AResult};
-use crate::types::AgentCard;
+use crate::types::{AgentCard, AgentInterface};
+
+use super::card_cache::{AgentCardCache, CachedCard};
 
 /// Default path for the agent card well-known endpoint (A2A v0.3+).
 const DEFAULT_AGENT_CARD_PATH: &str = "/.well-known/agent-card.json";
@@ -31,20 +35,42 @@ const PREV_AGENT_CARD_PATH: &str = "/.well-known/agent.json";
 /// # Ok(())
 /// # }
 /// ```
+///
+/// With an [`AgentCardCache`] (see [`with_cache()`](Self::with_cache)),
+/// cards are served from the cache while fresh and revalidated with
+/// conditional requests afterwards.
 #[derive(Debug, Clone)]
 pub struct CardResolver {
     client: reqwest::Client,
     /// Override the default agent card path. If `None`, uses
     /// `/.well-known/agent.json`.
     card_path: Option<String>,
+    cache: Option<Arc<AgentCardCache>>,
+    strict: bool,
 }
 
 impl CardResolver {
     /// Create a new resolver with default settings.
+    ///
+    /// Requests carry this SDK's `User-Agent` and `X-A2A-Client` headers.
     pub fn new() -> Self {
+        Self::for_app(None)
+    }
+
+    /// Create a resolver identifying the calling application as `app`
+    /// (see [`JsonRpcTransport::with_client_id`](super::JsonRpcTransport::with_client_id)).
+    pub fn for_app(app: Option<&str>) -> Self {
+        let headers = super::transport::identification_headers(
+            &crate::utils::ClientIdentification::for_app(app),
+        );
         Self {
-            client: reqwest::Client::new(),
+            client: reqwest::Client::builder()
+                .default_headers(headers)
+                .build()
+                .unwrap_or_else(|_| reqwest::Client::new()),
             card_path: None,
+            cache: None,
+            strict: false,
         }
     }
 
@@ -53,6 +79,8 @@ impl CardResolver {
         Self {
             client,
             card_path: None,
+            cache: None,
+            strict: false,
         }
     }
 
@@ -62,6 +90,21 @@ impl CardResolver {
         self
     }
 
+    /// Cache resolved cards in `cache`. See [`AgentCardCache`].
+    pub fn with_cache(mut self, cache: Arc<AgentCardCache>) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Reject cards that fail [`AgentCard::validate`] (default `false`).
+    ///
+    /// Invalid cards fail resolution with [`A2AError::InvalidAgentCard`]
+    /// and are not cached.
+    pub fn with_strict_validation(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
     /// Fetch and parse the agent card from the given base URL.
     ///
     /// Constructs the full URL as `{base_url}{card_path}` and performs an
@@ -72,21 +115,66 @@ impl CardResolver {
     /// back to the previous `/.well-known/agent.json` path for backwards
     /// compatibility.
     ///
+    /// With a [cache](Self::with_cache), a fresh cached card is returned
+    /// without a request, and a stale one is revalidated.
+    ///
     /// # Errors
     ///
     /// Returns [`A2AError::Transport`] on connection failures, [`A2AError::Http`]
     /// on non-2xx responses, and [`A2AError::InvalidJson`] on parse failures.
+    /// With [strict validation](Self::with_strict_validation), returns
+    /// [`A2AError::InvalidAgentCard`] if the card isn't spec-conformant.
     pub async fn resolve(&self, base_url: &str) -> A2AResult<AgentCard> {
+        self.resolve_cached(base_url, false).await
+    }
+
+    /// Like [`resolve()`](Self::resolve), but revalidate a cached card even
+    /// while it is fresh.
+    pub async fn refresh(&self, base_url: &str) -> A2AResult<AgentCard> {
+        self.resolve_cached(base_url, true).await
+    }
+
+    async fn resolve_cached(&self, base_url: &str, revalidate: bool) -> A2AResult<AgentCard> {
         let base = base_url.trim_end_matches('/');
+        let Some(cache) = &self.cache else {
+            return Ok(self.discover(base).await?.card);
+        };
+        let key = format!(
+            "{base}{}",
+            self.card_path.as_deref().unwrap_or(DEFAULT_AGENT_CARD_PATH)
+        );
+
+        if let Some(cached) = cache.get(&key).await {
+            if !revalidate && cached.is_fresh(cache.ttl()) {
+                return Ok(cached.card);
+            }
+            match self.fetch_card(&cached.url, Some(&cached)).await {
+                Ok(None) => {
+                    tracing::debug!("agent card at {} not modified", cached.url);
+                    if let Err(e) = cache.touch(&key).await {
+                        tracing::warn!(error = %e, "failed to update card cache");
+                    }
+                    return Ok(cached.card);
+                }
+                Ok(Some(fetched)) => return Ok(store(cache, &key, fetched).await),
+                // The card moved; discover it again.
+                Err(A2AError::Http { status: 404, .. }) => {}
+                Err(e) => return Err(e),
+            }
+        }
+        let fetched = self.discover(base).await?;
+        Ok(store(cache, &key, fetched).await)
+    }
 
-        if self.card_path.is_some() {
+    /// Fetch the card of `base` from the configured or well-known path.
+    async fn discover(&self, base: &str) -> A2AResult<CachedCard> {
+        if let Some(path) = &self.card_path {
             // Custom path — try it directly, no fallback.
-            let path = self.card_path.as_deref().unwrap();
-            return self.fetch_card(base, path).await;
+            return self.fetch_new_card(base, path).await;
         }
 
         // Try the new well-known path first.
-        match self.fetch_card(base, DEFAULT_AGENT_CARD_PATH).await {
+        match self.fetch_new_card(base, DEFAULT_AGENT_CARD_PATH).await {
             Ok(card) => Ok(card),
             Err(A2AError::Http { status: 404, .. }) => {
                 // Fall back to the previous well-known path.
@@ -96,14 +184,14 @@ impl CardResolver {
                     DEFAULT_AGENT_CARD_PATH,
                     PREV_AGENT_CARD_PATH,
                 );
-                self.fetch_card(base, PREV_AGENT_CARD_PATH).await
+                self.fetch_new_card(base, PREV_AGENT_CARD_PATH).await
             }
             Err(e) => Err(e),
         }
     }
 
     /// Fetch and parse an agent card from a specific path relative to a base URL.
-    async fn fetch_card(&self, base: &str, path: &str) -> A2AResult<AgentCard> {
+    async fn fetch_new_card(&self, base: &str, path: &str) -> A2AResult<CachedCard> {
         // Ensure path starts with '/'.
         let path = if path.starts_with('/') {
             path.to_string()
@@ -112,26 +200,45 @@ impl CardResolver {
         };
 
         let url = format!("{base}{path}");
+        self.fetch_card(&url, None).await?.ok_or_else(|| {
+            A2AError::Transport(format!(
+                "unexpected 304 Not Modified fetching agent card from {url}"
+            ))
+        })
+    }
 
+    /// Fetch and parse the agent card at `url`, conditionally on `cached`
+    /// having changed; `None` if it hasn't.
+    async fn fetch_card(
+        &self,
+        url: &str,
+        cached: Option<&CachedCard>,
+    ) -> A2AResult<Option<CachedCard>> {
         tracing::debug!("resolving agent card from {}", url);
 
-        let response = self
-            .client
-            .get(&url)
-            .header("Accept", "application/json")
-            .send()
-            .await
-            .map_err(|e| {
-                if e.is_connect() {
-                    A2AError::Transport(format!("failed to connect to agent at {url}: {e}"))
-                } else if e.is_timeout() {
-                    A2AError::Timeout(format!("timed out fetching agent card from {url}: {e}"))
-                } else {
-                    A2AError::Transport(format!("failed to fetch agent card from {url}: {e}"))
-                }
-            })?;
+        let mut request = self.client.get(url).header("Accept", "application/json");
+        if let Some(cached) = cached {
+            if let Some(etag) = &cached.etag {
+                request = request.header(reqwest::header::IF_NONE_MATCH, etag);
+            }
+            if let Some(last_modified) = &cached.last_modified {
+                request = request.header(reqwest::header::IF_MODIFIED_SINCE, last_modified);
+            }
+        }
+        let response = request.send().await.map_err(|e| {
+            if e.is_connect() {
+                A2AError::Transport(format!("failed to connect to agent at {url}: {e}"))
+            } else if e.is_timeout() {
+                A2AError::Timeout(format!("timed out fetching agent card from {url}: {e}"))
+            } else {
+                A2AError::Transport(format!("failed to fetch agent card from {url}: {e}"))
+            }
+        })?;
 
         let status = response.status();
+        if status == reqwest::StatusCode::NOT_MODIFIED && cached.is_some() {
+            return Ok(None);
+        }
         if !status.is_success() {
             let body = response.text().await.unwrap_or_default();
             return Err(A2AError::Http {
@@ -140,6 +247,16 @@ impl CardResolver {
             });
         }
 
+        let header = |name: reqwest::header::HeaderName| {
+            response
+                .headers()
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string)
+        };
+        let etag = header(reqwest::header::ETAG);
+        let last_modified = header(reqwest::header::LAST_MODIFIED);
+
         let bytes = response
             .bytes()
             .await
@@ -147,10 +264,19 @@ impl CardResolver {
 
         let card: AgentCard = serde_json::from_slice(&bytes)
             .map_err(|e| A2AError::InvalidJson(format!("failed to parse agent card: {e}")))?;
+        if self.strict {
+            card.validate().map_err(A2AError::invalid_agent_card)?;
+        }
 
         tracing::debug!("resolved agent card: {} v{}", card.name, card.version);
 
-        Ok(card)
+        Ok(Some(CachedCard {
+            card,
+            url: url.to_string(),
+            etag,
+            last_modified,
+            fetched_at: chrono::Utc::now().timestamp_millis(),
+        }))
     }
 
     /// Extract the A2A endpoint URL from an agent card.
@@ -162,9 +288,155 @@ impl CardResolver {
     pub fn get_a2a_url(card: &AgentCard) -> Option<String> {
         card.supported_interfaces
             .iter()
-            .find(|iface| iface.protocol_binding.eq_ignore_ascii_case("JSONRPC"))
+            .find(|iface| iface.transport.eq_ignore_ascii_case("JSONRPC"))
             .map(|iface| iface.url.clone())
     }
+
+    /// Transports [`A2AClient::from_card`](super::A2AClient::from_card) can
+    /// connect with: `JSONRPC`, plus `GRPC` with the `grpc` feature.
+    #[cfg(not(feature = "grpc"))]
+    pub const SUPPORTED_TRANSPORTS: &'static [&'static str] = &["JSONRPC"];
+
+    /// Transports [`A2AClient::from_card`](super::A2AClient::from_card) can
+    /// connect with: `JSONRPC`, plus `GRPC` with the `grpc` feature.
+    #[cfg(feature = "grpc")]
+    pub const SUPPORTED_TRANSPORTS: &'static [&'static str] = &["JSONRPC", "GRPC"];
+
+    /// Every interface `card` advertises, in order.
+    ///
+    /// That is `supported_interfaces` followed by `additional_interfaces`.
+    /// Cards without `supported_interfaces` (the v0.3 layout) advertise
+    /// their `url` over `preferred_transport` (JSON-RPC if unset) first
+    /// instead. Interfaces listed twice are returned once.
+    pub fn advertised_interfaces(card: &AgentCard) -> Vec<AgentInterface> {
+        let main = card
+            .supported_interfaces
+            .is_empty()
+            .then(|| AgentInterface {
+                url: card.url.clone(),
+                transport: card
+                    .preferred_transport
+                    .clone()
+                    .unwrap_or_else(|| "JSONRPC".to_string()),
+                tenant: None,
+                protocol_version: None,
+            });
+        let mut interfaces: Vec<AgentInterface> = Vec::new();
+        let listed = main
+            .into_iter()
+            .chain(card.supported_interfaces.iter().cloned())
+            .chain(card.additional_interfaces.iter().flatten().cloned());
+        for iface in listed {
+            let duplicate = interfaces.iter().any(|seen| {
+                seen.url == iface.url && seen.transport.eq_ignore_ascii_case(&iface.transport)
+            });
+            if !duplicate && !iface.url.is_empty() {
+                interfaces.push(iface);
+            }
+        }
+        interfaces
+    }
+
+    /// Pick the interface of `card` to connect to, among those whose
+    /// transport is in `transports` (case-insensitive).
+    ///
+    /// The card's `preferred_transport` wins if it is offered; otherwise the
+    /// first usable interface in [`advertised_interfaces()`] order is
+    /// chosen. Returns `None` if the card offers none of `transports`.
+    ///
+    /// [`advertised_interfaces()`]: Self::advertised_interfaces
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use a2a_rs::client::CardResolver;
+    /// use a2a_rs::types::AgentCard;
+    ///
+    /// let card: AgentCard = serde_json::from_value(serde_json::json!({
+    ///     "name": "agent", "description": "", "version": "1.0",
+    ///     "url": "http://localhost:7420/a2a",
+    ///     "capabilities": {}, "defaultInputModes": [], "defaultOutputModes": [],
+    ///     "skills": [],
+    ///     "preferredTransport": "GRPC",
+    ///     "supportedInterfaces": [
+    ///         {"url": "http://localhost:7420/a2a", "transport": "JSONRPC"},
+    ///         {"url": "http://localhost:50051", "transport": "GRPC"}
+    ///     ]
+    /// }))
+    /// .unwrap();
+    ///
+    /// let grpc = CardResolver::negotiate_interface(&card, &["JSONRPC", "GRPC"]).unwrap();
+    /// assert_eq!(grpc.url, "http://localhost:50051");
+    /// let jsonrpc = CardResolver::negotiate_interface(&card, &["JSONRPC"]).unwrap();
+    /// assert_eq!(jsonrpc.url, "http://localhost:7420/a2a");
+    /// ```
+    pub fn negotiate_interface(card: &AgentCard, transports: &[&str]) -> Option<AgentInterface> {
+        Self::negotiate_interface_preferring(card, transports, &[])
+    }
+
+    /// Like [`negotiate_interface()`](Self::negotiate_interface), but the
+    /// first of `preferences` the card offers wins over the card's own
+    /// `preferred_transport`.
+    ///
+    /// ```
+    /// use a2a_rs::client::CardResolver;
+    /// use a2a_rs::types::AgentCard;
+    ///
+    /// let card: AgentCard = serde_json::from_value(serde_json::json!({
+    ///     "name": "agent", "description": "", "version": "1.0",
+    ///     "url": "http://localhost:50051",
+    ///     "capabilities": {}, "defaultInputModes": [], "defaultOutputModes": [],
+    ///     "skills": [],
+    ///     "preferredTransport": "GRPC",
+    ///     "additionalInterfaces": [
+    ///         {"url": "http://localhost:7420/a2a", "transport": "JSONRPC"}
+    ///     ]
+    /// }))
+    /// .unwrap();
+    ///
+    /// let supported = ["JSONRPC", "GRPC"];
+    /// let chosen = CardResolver::negotiate_interface(&card, &supported).unwrap();
+    /// assert_eq!(chosen.transport, "GRPC");
+    /// let chosen =
+    ///     CardResolver::negotiate_interface_preferring(&card, &supported, &["JSONRPC"]).unwrap();
+    /// assert_eq!(chosen.url, "http://localhost:7420/a2a");
+    /// ```
+    pub fn negotiate_interface_preferring(
+        card: &AgentCard,
+        transports: &[&str],
+        preferences: &[&str],
+    ) -> Option<AgentInterface> {
+        let usable: Vec<AgentInterface> = Self::advertised_interfaces(card)
+            .into_iter()
+            .filter(|iface| {
+                transports
+                    .iter()
+                    .any(|t| iface.transport.eq_ignore_ascii_case(t))
+            })
+            .collect();
+        let offered = |transport: &str| {
+            usable
+                .iter()
+                .find(|iface| iface.transport.eq_ignore_ascii_case(transport))
+        };
+        preferences
+            .iter()
+            .copied()
+            .chain(card.preferred_transport.as_deref())
+            .find_map(offered)
+            .or_else(|| usable.first())
+            .cloned()
+    }
+}
+
+/// Put `fetched` in `cache` under `key` and return its card. A failed
+/// write is logged, not returned: the card itself was resolved.
+async fn store(cache: &AgentCardCache, key: &str, fetched: CachedCard) -> AgentCard {
+    let card = fetched.card.clone();
+    if let Err(e) = cache.insert(key, fetched).await {
+        tracing::warn!(error = %e, "failed to update card cache");
+    }
+    card
 }
 
 impl Default for CardResolver {
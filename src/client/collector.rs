@@ -0,0 +1,240 @@
+//! Client-side aggregation of a stream's text.
+//!
+//! Chat UIs usually want the agent's reply as one string, not a sequence
+//! of events. [`StreamCollector`] reads an [`SseStream`] and keeps the
+//! concatenated text of:
+//!
+//! - each artifact, rebuilt from its `artifact-update` chunks: a chunk
+//!   without `append` starts (or restarts) the artifact's text, one with
+//!   `append: true` extends it; once `lastChunk: true` has arrived,
+//!   retransmitted chunks are dropped
+//! - each agent message, whether a status update's message or a
+//!   `message` event; a message as the first event is the agent's whole
+//!   reply, and ends the stream
+//!
+//! Text parts are concatenated as they are, so token chunks join up;
+//! artifacts and messages are joined with a separator (`"\n"` unless set
+//! with [`with_separator()`](StreamCollector::with_separator)), in order of
+//! first appearance.
+//!
+//! ```no_run
+//! # use a2a_rs::client::{A2AClient, StreamCollector, TextDelta};
+//! # async fn example(client: A2AClient) -> a2a_rs::A2AResult<()> {
+//! let stream = client.send_text_stream("Tell me a story").await?;
+//! let mut collector = StreamCollector::new(stream);
+//! while let Some(delta) = collector.next_delta().await {
+//!     match delta? {
+//!         TextDelta::Append(text) => print!("{text}"),
+//!         TextDelta::Reset(text) => println!("\n(rewritten)\n{text}"),
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::error::A2AResult;
+use crate::types::{Message, Part, Role, StreamResponse, TaskStatus};
+
+use super::sse::SseStream;
+
+/// A change to the text collected by a [`StreamCollector`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TextDelta {
+    /// Text added to the end of the collected text.
+    Append(String),
+    /// The collected text changed other than at its end (an artifact was
+    /// restarted, or one before the last grew); this is the whole new text.
+    Reset(String),
+}
+
+/// Collects the text of an [`SseStream`]. See the
+/// [module documentation](self).
+pub struct StreamCollector {
+    stream: SseStream,
+    separator: String,
+    /// Artifacts and messages, in order of first appearance.
+    segments: Vec<Segment>,
+    /// The collected text, as last reported.
+    text: String,
+    status: Option<TaskStatus>,
+    /// An event was applied.
+    started: bool,
+    finished: bool,
+}
+
+struct Segment {
+    /// `None` for a message.
+    artifact_id: Option<String>,
+    text: String,
+    /// The artifact's last chunk arrived.
+    complete: bool,
+}
+
+impl std::fmt::Debug for StreamCollector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StreamCollector")
+            .field("separator", &self.separator)
+            .field("segments", &self.segments.len())
+            .field("text", &self.text)
+            .field("finished", &self.finished)
+            .finish()
+    }
+}
+
+impl StreamCollector {
+    /// Collect the text of `stream`.
+    pub fn new(stream: SseStream) -> Self {
+        Self {
+            stream,
+            separator: "\n".to_string(),
+            segments: Vec::new(),
+            text: String::new(),
+            status: None,
+            started: false,
+            finished: false,
+        }
+    }
+
+    /// Join artifacts and messages with `separator` instead of `"\n"`.
+    pub fn with_separator(mut self, separator: impl Into<String>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Read events until the collected text changes, and return the
+    /// change.
+    ///
+    /// Returns `None` once the stream has ended (at a final status update,
+    /// after a direct message reply, or when the server closes it), and the stream's errors as they come.
+    pub async fn next_delta(&mut self) -> Option<A2AResult<TextDelta>> {
+        while !self.finished {
+            let event = match self.stream.next().await {
+                Some(Ok(event)) => event,
+                Some(Err(e)) => return Some(Err(e)),
+                None => {
+                    self.finished = true;
+                    break;
+                }
+            };
+            if let Some(delta) = self.push(&event) {
+                return Some(Ok(delta));
+            }
+        }
+        None
+    }
+
+    /// Read the rest of the stream and return the collected text.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first stream error.
+    pub async fn collect(mut self) -> A2AResult<String> {
+        while let Some(delta) = self.next_delta().await {
+            delta?;
+        }
+        Ok(self.text)
+    }
+
+    /// The text collected so far.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// The task's latest status, if the stream carried one — e.g. to tell
+    /// a completed reply from a failed one.
+    pub fn status(&self) -> Option<&TaskStatus> {
+        self.status.as_ref()
+    }
+
+    /// Apply one event, returning the change to the collected text.
+    fn push(&mut self, event: &StreamResponse) -> Option<TextDelta> {
+        let first = !std::mem::replace(&mut self.started, true);
+        match event {
+            StreamResponse::ArtifactUpdate(update) => {
+                let text = concat_text(&update.artifact.parts);
+                let append = update.append.unwrap_or(false);
+                let last = update.last_chunk.unwrap_or(false);
+                let artifact_id = &update.artifact.artifact_id;
+                let existing = self
+                    .segments
+                    .iter_mut()
+                    .find(|segment| segment.artifact_id.as_ref() == Some(artifact_id));
+                match existing {
+                    Some(segment) => {
+                        if segment.complete && (append || segment.text == text) {
+                            return None;
+                        }
+                        if append {
+                            segment.text.push_str(&text);
+                        } else {
+                            segment.text = text;
+                        }
+                        segment.complete = last;
+                    }
+                    None => self.segments.push(Segment {
+                        artifact_id: Some(artifact_id.clone()),
+                        text,
+                        complete: last,
+                    }),
+                }
+            }
+            StreamResponse::StatusUpdate(update) => {
+                self.status = Some(update.status.clone());
+                if update.r#final {
+                    self.finished = true;
+                }
+                self.push_message(update.status.message.as_ref());
+            }
+            StreamResponse::Message(message) => {
+                if first {
+                    self.finished = true;
+                }
+                self.push_message(Some(message));
+            }
+            StreamResponse::Task(task) => self.status = Some(task.status.clone()),
+        }
+        self.refresh()
+    }
+
+    fn push_message(&mut self, message: Option<&Message>) {
+        let Some(message) = message.filter(|m| m.role == Role::Agent) else {
+            return;
+        };
+        self.segments.push(Segment {
+            artifact_id: None,
+            text: concat_text(&message.parts),
+            complete: true,
+        });
+    }
+
+    /// Rebuild the collected text and diff it against the previous one.
+    fn refresh(&mut self) -> Option<TextDelta> {
+        let text = self
+            .segments
+            .iter()
+            .map(|segment| segment.text.as_str())
+            .filter(|text| !text.is_empty())
+            .collect::<Vec<_>>()
+            .join(&self.separator);
+        if text == self.text {
+            return None;
+        }
+        let delta = match text.strip_prefix(self.text.as_str()) {
+            Some(added) => TextDelta::Append(added.to_string()),
+            None => TextDelta::Reset(text.clone()),
+        };
+        self.text = text;
+        Some(delta)
+    }
+}
+
+/// The text parts of `parts`, concatenated.
+fn concat_text(parts: &[Part]) -> String {
+    parts
+        .iter()
+        .filter_map(|part| match part {
+            Part::Text { text, .. } => Some(text.as_str()),
+            _ => None,
+        })
+        .collect()
+}
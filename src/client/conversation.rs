@@ -0,0 +1,186 @@
+//! Multi-turn conversations with one agent.
+//!
+//! A [`Conversation`] (from [`A2AClient::conversation`]) keeps the
+//! `contextId` the agent assigned on the first turn and sends every later
+//! message in it. A task waiting for input is continued by the next
+//! message; otherwise each message starts a new task whose
+//! `referenceTaskIds` name the conversation's earlier tasks.
+//!
+//! ```no_run
+//! use a2a_rs::client::A2AClient;
+//!
+//! # async fn example() -> a2a_rs::A2AResult<()> {
+//! let client = A2AClient::from_endpoint("http://localhost:7420/a2a");
+//! let mut conversation = client.conversation();
+//! conversation.send("Book a table for two").await?;
+//! conversation.send("Make it 8 pm").await?;
+//! for message in conversation.history().await? {
+//!     println!("{:?}: {:?}", message.role, message.parts);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::error::A2AResult;
+use crate::types::{Message, Role, SendMessageParams, SendMessageResponse, Task, TaskState};
+
+use super::a2a_client::{create_text_message, A2AClient};
+
+/// One turn of a [`Conversation`].
+#[derive(Debug, Clone)]
+enum Turn {
+    /// A task, possibly spanning several messages.
+    Task(String),
+    /// A message and the agent's direct answer, without a task.
+    Reply(Vec<Message>),
+}
+
+/// A conversation with an agent: the tasks of one `contextId`, across
+/// turns. Each message continues the task waiting for input, if any, and
+/// otherwise starts a new task referencing the earlier ones.
+///
+/// Holds a clone of the client, sharing its connections.
+#[derive(Debug, Clone)]
+pub struct Conversation {
+    client: A2AClient,
+    context_id: Option<String>,
+    turns: Vec<Turn>,
+    /// The latest task, while it waits for input or authorization.
+    open_task: Option<String>,
+}
+
+impl A2AClient {
+    /// Start a [`Conversation`]; its context is assigned by the agent on
+    /// the first message.
+    pub fn conversation(&self) -> Conversation {
+        Conversation {
+            client: self.clone(),
+            context_id: None,
+            turns: Vec::new(),
+            open_task: None,
+        }
+    }
+
+    /// Continue the conversation `context_id`, e.g. one started by another
+    /// client. Tasks from before are not tracked.
+    pub fn resume_conversation(&self, context_id: impl Into<String>) -> Conversation {
+        Conversation {
+            context_id: Some(context_id.into()),
+            ..self.conversation()
+        }
+    }
+}
+
+impl Conversation {
+    /// The conversation's context, once known.
+    pub fn context_id(&self) -> Option<&str> {
+        self.context_id.as_deref()
+    }
+
+    /// IDs of the tasks of this conversation, oldest first.
+    pub fn task_ids(&self) -> Vec<String> {
+        self.turns
+            .iter()
+            .filter_map(|turn| match turn {
+                Turn::Task(id) => Some(id.clone()),
+                Turn::Reply(_) => None,
+            })
+            .collect()
+    }
+
+    /// The task the next message continues, if the latest one waits for
+    /// input or authorization.
+    pub fn open_task_id(&self) -> Option<&str> {
+        self.open_task.as_deref()
+    }
+
+    /// Send `text` as the next user message.
+    pub async fn send(&mut self, text: &str) -> A2AResult<SendMessageResponse> {
+        self.send_message(create_text_message(Role::User, text))
+            .await
+    }
+
+    /// Send `message` as the next turn.
+    ///
+    /// Fills in what the message leaves unset: the conversation's
+    /// `contextId`, the open task's `taskId`, and for a new task
+    /// `referenceTaskIds` naming the conversation's earlier tasks.
+    pub async fn send_message(&mut self, mut message: Message) -> A2AResult<SendMessageResponse> {
+        if message.context_id.is_none() {
+            message.context_id = self.context_id.clone();
+        }
+        if message.task_id.is_none() {
+            message.task_id = self.open_task.clone();
+        }
+        if message.task_id.is_none() && message.reference_task_ids.is_none() {
+            let earlier = self.task_ids();
+            if !earlier.is_empty() {
+                message.reference_task_ids = Some(earlier);
+            }
+        }
+        let params = SendMessageParams {
+            message: message.clone(),
+            configuration: None,
+            metadata: None,
+            tenant: None,
+        };
+        let response = self.client.send_message(params).await?;
+        self.record(message, &response);
+        Ok(response)
+    }
+
+    /// The conversation's tasks as the agent stores them now, oldest first.
+    pub async fn tasks(&self) -> A2AResult<Vec<Task>> {
+        let mut tasks = Vec::new();
+        for id in self.task_ids() {
+            tasks.push(self.client.get_task_by_id(&id, None).await?);
+        }
+        Ok(tasks)
+    }
+
+    /// Every message of the conversation, in order: the history of each
+    /// task as the agent stores it, and messages the agent answered
+    /// directly.
+    pub async fn history(&self) -> A2AResult<Vec<Message>> {
+        let mut history: Vec<Message> = Vec::new();
+        for turn in &self.turns {
+            let messages = match turn {
+                Turn::Task(id) => {
+                    let task = self.client.get_task_by_id(id, None).await?;
+                    task.history.unwrap_or_default()
+                }
+                Turn::Reply(exchange) => exchange.clone(),
+            };
+            for message in messages {
+                if !history.iter().any(|m| m.message_id == message.message_id) {
+                    history.push(message);
+                }
+            }
+        }
+        Ok(history)
+    }
+
+    /// Track the turn `message` got `response` for.
+    fn record(&mut self, message: Message, response: &SendMessageResponse) {
+        match response {
+            SendMessageResponse::Task(task) => {
+                self.context_id = Some(task.context_id.clone());
+                if !self.task_ids().contains(&task.id) {
+                    self.turns.push(Turn::Task(task.id.clone()));
+                }
+                let waiting = matches!(
+                    task.status.state,
+                    TaskState::InputRequired | TaskState::AuthRequired
+                );
+                self.open_task = waiting.then(|| task.id.clone());
+            }
+            SendMessageResponse::Message(reply) => {
+                if let Some(context_id) = reply.context_id.clone().or(message.context_id.clone()) {
+                    self.context_id = Some(context_id);
+                }
+                self.open_task = None;
+                self.turns.push(Turn::Reply(vec![message, reply.clone()]));
+            }
+        }
+    }
+}
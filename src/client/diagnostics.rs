@@ -0,0 +1,214 @@
+//! Diagnostics for responses that don't match the A2A schema.
+//!
+//! serde reports a failed decode as a single line such as
+//! `unknown variant "done"`, without saying where in the payload it
+//! happened. [`diagnose()`] re-parses the JSON, tracking the path, and
+//! returns a [`DecodeDiagnostic`] naming the offending field, what was
+//! expected and what was found, with an excerpt of the surrounding JSON.
+//!
+//! [`A2AClient::with_schema_diagnostics`](super::A2AClient::with_schema_diagnostics)
+//! attaches these to every response decode error; SSE events always carry
+//! the path.
+
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+/// Longest payload excerpt included in a [`DecodeDiagnostic`], in
+/// characters.
+pub const EXCERPT_LIMIT: usize = 240;
+
+/// Where and why a JSON payload failed to decode.
+///
+/// # Example
+///
+/// ```
+/// use a2a_rs::client::diagnose;
+/// use a2a_rs::types::Task;
+///
+/// let payload = serde_json::json!({
+///     "id": "t1",
+///     "contextId": "c1",
+///     "kind": "task",
+///     "status": {"state": "working", "timestamp": 17},
+/// });
+/// let diagnostic = diagnose::<Task>(&payload).unwrap();
+/// assert_eq!(diagnostic.path, "status.timestamp");
+/// assert_eq!(diagnostic.found, Some("number"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodeDiagnostic {
+    /// The type being decoded, e.g. `Task` (the concrete event type for
+    /// `kind`-tagged responses).
+    pub target: String,
+
+    /// Path to the offending field, e.g. `history[2].parts[0]`; `.` for
+    /// the payload itself.
+    pub path: String,
+
+    /// serde's description of the problem, usually naming the expected
+    /// type (`invalid type: integer `17`, expected a string`).
+    pub message: String,
+
+    /// JSON type at `path` (`"string"`, `"null"`, …), if the path exists.
+    pub found: Option<&'static str>,
+
+    /// The JSON enclosing the offending field, cut to [`EXCERPT_LIMIT`]
+    /// characters.
+    pub excerpt: String,
+}
+
+impl std::fmt::Display for DecodeDiagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} at `{}`: {}", self.target, self.path, self.message)?;
+        if let Some(found) = self.found {
+            write!(f, " (found {found})")?;
+        }
+        write!(f, "; near {}", self.excerpt)
+    }
+}
+
+/// Explain why `value` doesn't decode as `T`; `None` if it does.
+///
+/// A payload rejected as a whole that carries a known `kind` (`task`,
+/// `message`, `status-update`, `artifact-update`) is diagnosed as that
+/// type, so errors inside `SendMessageResponse` and `StreamResponse` point
+/// at the field rather than the wrapper.
+pub fn diagnose<T: DeserializeOwned>(value: &Value) -> Option<DecodeDiagnostic> {
+    let (path, message) = locate::<T>(value)?;
+    if path.is_empty() {
+        let by_kind = match value.get("kind").and_then(Value::as_str) {
+            Some("task") => locate_as::<crate::types::Task>(value),
+            Some("message") => locate_as::<crate::types::Message>(value),
+            Some("status-update") => locate_as::<crate::types::TaskStatusUpdateEvent>(value),
+            Some("artifact-update") => locate_as::<crate::types::TaskArtifactUpdateEvent>(value),
+            _ => None,
+        };
+        if let Some((target, path, message)) = by_kind {
+            return Some(diagnostic(value, target, path, message));
+        }
+    }
+    Some(diagnostic(
+        value,
+        short_type_name(std::any::type_name::<T>()),
+        path,
+        message,
+    ))
+}
+
+/// Path segments and message of the first error decoding `value` as `T`.
+fn locate<T: DeserializeOwned>(value: &Value) -> Option<(Vec<Segment>, String)> {
+    let err = serde_path_to_error::deserialize::<_, T>(value).err()?;
+    let path = err.path().iter().map(Segment::from).collect();
+    Some((path, err.into_inner().to_string()))
+}
+
+fn locate_as<T: DeserializeOwned>(value: &Value) -> Option<(String, Vec<Segment>, String)> {
+    let (path, message) = locate::<T>(value)?;
+    Some((short_type_name(std::any::type_name::<T>()), path, message))
+}
+
+/// A step into a JSON value.
+enum Segment {
+    Index(usize),
+    Key(String),
+    /// Enum variants and steps serde couldn't name.
+    Opaque(String),
+}
+
+impl From<&serde_path_to_error::Segment> for Segment {
+    fn from(segment: &serde_path_to_error::Segment) -> Self {
+        match segment {
+            serde_path_to_error::Segment::Seq { index } => Segment::Index(*index),
+            serde_path_to_error::Segment::Map { key } => Segment::Key(key.clone()),
+            other => Segment::Opaque(other.to_string()),
+        }
+    }
+}
+
+fn diagnostic(
+    value: &Value,
+    target: String,
+    path: Vec<Segment>,
+    message: String,
+) -> DecodeDiagnostic {
+    let mut rendered = String::new();
+    for segment in &path {
+        match segment {
+            Segment::Index(index) => rendered.push_str(&format!("[{index}]")),
+            Segment::Key(name) | Segment::Opaque(name) => {
+                if !rendered.is_empty() {
+                    rendered.push('.');
+                }
+                rendered.push_str(name);
+            }
+        }
+    }
+    if rendered.is_empty() {
+        rendered.push('.');
+    }
+
+    // Walk as far down the path as the payload goes, remembering the
+    // innermost object or array for the excerpt.
+    let mut current = Some(value);
+    let mut enclosing = value;
+    for segment in &path {
+        let Some(node) = current else { break };
+        if node.is_object() || node.is_array() {
+            enclosing = node;
+        }
+        current = match segment {
+            Segment::Index(index) => node.get(*index),
+            Segment::Key(name) => node.get(name),
+            Segment::Opaque(_) => None,
+        };
+    }
+    if let Some(node) = current.filter(|node| node.is_object() || node.is_array()) {
+        enclosing = node;
+    }
+
+    DecodeDiagnostic {
+        target,
+        path: rendered,
+        message,
+        found: current.map(json_type),
+        excerpt: excerpt(enclosing),
+    }
+}
+
+fn json_type(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+/// `value` as JSON, cut to [`EXCERPT_LIMIT`] characters.
+fn excerpt(value: &Value) -> String {
+    let json = value.to_string();
+    match json.char_indices().nth(EXCERPT_LIMIT) {
+        Some((end, _)) => format!("{}…", &json[..end]),
+        None => json,
+    }
+}
+
+/// `a2a_rs::types::Task` → `Task`, keeping generic structure
+/// (`Vec<Task>`).
+fn short_type_name(name: &str) -> String {
+    let mut short = String::with_capacity(name.len());
+    let mut segment = String::new();
+    for c in name.chars() {
+        if c.is_alphanumeric() || c == '_' || c == ':' {
+            segment.push(c);
+        } else {
+            short.push_str(segment.rsplit("::").next().unwrap_or(""));
+            segment.clear();
+            short.push(c);
+        }
+    }
+    short.push_str(segment.rsplit("::").next().unwrap_or(""));
+    short
+}
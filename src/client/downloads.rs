@@ -0,0 +1,465 @@
+//! Fetching the content of URI-backed file parts.
+//!
+//! Agents may return large files as [`FileContent::Uri`] instead of inline
+//! bytes. An [`ArtifactDownloader`] fetches them — into memory as a
+//! [`FileWithBytes`], or streamed to disk — enforcing a size limit and
+//! checking the served `Content-Type` against the part's declared MIME
+//! type. [`Task::download_all_artifacts`] saves every file of a task's
+//! artifacts to a directory.
+//!
+//! Clients built with [`ClientBuilder`](crate::builders::ClientBuilder)
+//! carry a downloader sending the builder's headers and credentials to the
+//! agent's own origin; see [`A2AClient::artifact_downloader`](super::A2AClient::artifact_downloader).
+
+use std::collections::{HashMap, HashSet};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use crate::error::{A2AError, A2AResult};
+use crate::types::{Artifact, FileContent, FileWithBytes, Part, Task};
+
+use super::auth::CredentialService;
+
+/// Downloads larger than this (256 MiB) are rejected by default.
+pub const DEFAULT_MAX_DOWNLOAD_SIZE: u64 = 256 * 1024 * 1024;
+
+/// Fetches [`FileContent::Uri`] parts over HTTP.
+///
+/// Inline [`FileContent::Bytes`] parts pass through without a request, so
+/// every method accepts either kind.
+///
+/// Configured headers and credentials are sent with every download, unless
+/// [`with_auth_origin`](Self::with_auth_origin) restricts them to trusted
+/// origins — URIs come from the agent, and may point anywhere.
+///
+/// # Example
+///
+/// ```no_run
+/// use a2a_rs::client::ArtifactDownloader;
+/// use a2a_rs::types::Task;
+///
+/// # async fn example(task: Task) -> a2a_rs::error::A2AResult<()> {
+/// let downloader = ArtifactDownloader::new()
+///     .with_header("Authorization", "Bearer token-123")
+///     .with_auth_origin("https://files.example.com")
+///     .with_max_size(10 * 1024 * 1024);
+/// for path in downloader.download_artifacts(&task, "out".as_ref()).await? {
+///     println!("saved {}", path.display());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct ArtifactDownloader {
+    client: reqwest::Client,
+    headers: HashMap<String, String>,
+    credentials: Option<Arc<dyn CredentialService>>,
+    /// Origins headers and credentials are sent to; all if empty.
+    auth_origins: HashSet<String>,
+    max_size: u64,
+    check_mime_type: bool,
+}
+
+impl std::fmt::Debug for ArtifactDownloader {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ArtifactDownloader")
+            .field("headers", &self.headers.keys().collect::<Vec<_>>())
+            .field("credentials", &self.credentials.is_some())
+            .field("auth_origins", &self.auth_origins)
+            .field("max_size", &self.max_size)
+            .field("check_mime_type", &self.check_mime_type)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for ArtifactDownloader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ArtifactDownloader {
+    /// A downloader without credentials, limited to
+    /// [`DEFAULT_MAX_DOWNLOAD_SIZE`], checking MIME types.
+    pub fn new() -> Self {
+        Self::with_client(reqwest::Client::new())
+    }
+
+    /// As [`new()`](Self::new), fetching with `client` (e.g. one with
+    /// custom TLS settings or a timeout).
+    pub fn with_client(client: reqwest::Client) -> Self {
+        Self {
+            client,
+            headers: HashMap::new(),
+            credentials: None,
+            auth_origins: HashSet::new(),
+            max_size: DEFAULT_MAX_DOWNLOAD_SIZE,
+            check_mime_type: true,
+        }
+    }
+
+    /// Send a header with every download to a trusted origin
+    /// (builder-style).
+    pub fn with_header(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.insert(key.into(), value.into());
+        self
+    }
+
+    /// Send a [`CredentialService`]'s credential with every download to a
+    /// trusted origin (builder-style).
+    pub fn with_credentials(mut self, credentials: Arc<dyn CredentialService>) -> Self {
+        self.credentials = Some(credentials);
+        self
+    }
+
+    /// Trust the origin of `url` (`scheme://host[:port]`) with headers and
+    /// credentials (builder-style). Once any origin is trusted, downloads
+    /// from other origins are sent without them.
+    pub fn with_auth_origin(mut self, url: &str) -> Self {
+        if let Some(origin) = origin(url) {
+            self.auth_origins.insert(origin);
+        }
+        self
+    }
+
+    /// Reject files larger than `bytes` (builder-style). Defaults to
+    /// [`DEFAULT_MAX_DOWNLOAD_SIZE`].
+    pub fn with_max_size(mut self, bytes: u64) -> Self {
+        self.max_size = bytes;
+        self
+    }
+
+    /// Check the served `Content-Type` against the part's declared MIME
+    /// type (builder-style). On by default.
+    ///
+    /// Parts without a MIME type, declared as `application/octet-stream`,
+    /// and responses without a `Content-Type` always pass; `image/*`
+    /// matches any image type.
+    pub fn with_mime_check(mut self, enabled: bool) -> Self {
+        self.check_mime_type = enabled;
+        self
+    }
+
+    /// Returns the largest file this downloader accepts.
+    pub fn max_size(&self) -> u64 {
+        self.max_size
+    }
+
+    /// The content of `file` as inline bytes, fetched if it is a URI.
+    ///
+    /// The name and MIME type are kept; a URI part without a MIME type
+    /// takes the served `Content-Type`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`A2AError::Transport`] if the request fails,
+    /// [`A2AError::Http`] for a non-success status,
+    /// [`A2AError::PayloadTooLarge`] over the size limit and
+    /// [`A2AError::InvalidAgentResponse`] for a MIME type mismatch.
+    pub async fn download(&self, file: &FileContent) -> A2AResult<FileWithBytes> {
+        let file = match file {
+            FileContent::Bytes(file) => return Ok(file.clone()),
+            FileContent::Uri(file) => file,
+        };
+        let (mut response, served_type) = self.open(&file.uri, file.mime_type.as_deref()).await?;
+        let mut bytes = Vec::new();
+        let mut received = 0;
+        while let Some(chunk) = self
+            .next_chunk(&file.uri, &mut response, &mut received)
+            .await?
+        {
+            bytes.extend_from_slice(&chunk);
+        }
+        Ok(FileWithBytes {
+            name: file.name.clone(),
+            mime_type: file.mime_type.clone().or(served_type),
+            ..FileWithBytes::from_bytes(bytes)
+        })
+    }
+
+    /// Write the content of `file` to `path`, streaming URIs to disk
+    /// without buffering the whole file. Returns the number of bytes
+    /// written.
+    ///
+    /// A partially written file is removed if the download fails.
+    ///
+    /// # Errors
+    ///
+    /// As [`download()`](Self::download), plus
+    /// [`A2AError::InternalError`] if `path` can't be written.
+    pub async fn download_to(&self, file: &FileContent, path: &Path) -> A2AResult<u64> {
+        let result = self.write_file(file, path).await;
+        if result.is_err() {
+            let owned = path.to_path_buf();
+            let _ = tokio::task::spawn_blocking(move || std::fs::remove_file(owned)).await;
+        }
+        result
+    }
+
+    /// `artifact` with every URI file part replaced by its inline bytes.
+    ///
+    /// # Errors
+    ///
+    /// As [`download()`](Self::download), for the first part that fails.
+    pub async fn materialize(&self, artifact: &Artifact) -> A2AResult<Artifact> {
+        let mut materialized = artifact.clone();
+        for part in &mut materialized.parts {
+            if let Part::File { file, .. } = part {
+                if matches!(file, FileContent::Uri(_)) {
+                    *file = FileContent::Bytes(self.download(file).await?);
+                }
+            }
+        }
+        Ok(materialized)
+    }
+
+    /// Save every file part of `task`'s artifacts in `dir` (created if
+    /// missing) and return the paths written, in artifact and part order.
+    ///
+    /// Files are named after the part's name (its final path component
+    /// only), falling back to `<artifact id>-<part index>`. A name already
+    /// used by an earlier part is prefixed with `<artifact id>-<part index>-`.
+    ///
+    /// # Errors
+    ///
+    /// As [`download_to()`](Self::download_to). Files saved before the
+    /// failing part are kept.
+    pub async fn download_artifacts(&self, task: &Task, dir: &Path) -> A2AResult<Vec<PathBuf>> {
+        let owned = dir.to_path_buf();
+        blocking_write(dir, move || std::fs::create_dir_all(owned)).await?;
+
+        let mut used = HashSet::new();
+        let mut paths = Vec::new();
+        for artifact in task.artifacts.iter().flatten() {
+            for (index, part) in artifact.parts.iter().enumerate() {
+                let Part::File { file, .. } = part else {
+                    continue;
+                };
+                let fallback = format!("{}-{index}", artifact.artifact_id);
+                let name = file_name(file)
+                    .filter(|name| !used.contains(name))
+                    .or_else(|| {
+                        file_name(file)
+                            .map(|name| format!("{fallback}-{name}"))
+                            .filter(|name| !used.contains(name))
+                    })
+                    .unwrap_or(fallback);
+                let path = dir.join(&name);
+                self.download_to(file, &path).await?;
+                used.insert(name);
+                paths.push(path);
+            }
+        }
+        Ok(paths)
+    }
+
+    async fn write_file(&self, file: &FileContent, path: &Path) -> A2AResult<u64> {
+        let owned = path.to_path_buf();
+        let mut target = blocking_write(path, move || std::fs::File::create(owned)).await?;
+        let mut written = 0;
+        match file {
+            FileContent::Bytes(file) => {
+                let chunk = file.to_bytes()?;
+                written = chunk.len() as u64;
+                target = write_chunk(target, chunk, path).await?;
+            }
+            FileContent::Uri(file) => {
+                let (mut response, _) = self.open(&file.uri, file.mime_type.as_deref()).await?;
+                while let Some(chunk) = self
+                    .next_chunk(&file.uri, &mut response, &mut written)
+                    .await?
+                {
+                    target = write_chunk(target, chunk, path).await?;
+                }
+            }
+        }
+        blocking_write(path, move || target.sync_all()).await?;
+        Ok(written)
+    }
+
+    /// `GET` `uri` and check the response against the size limit and
+    /// `declared` MIME type. Returns the response and the served MIME
+    /// type.
+    async fn open(
+        &self,
+        uri: &str,
+        declared: Option<&str>,
+    ) -> A2AResult<(reqwest::Response, Option<String>)> {
+        let mut request = self.client.get(uri);
+        if self.sends_auth_to(uri) {
+            for (key, value) in &self.headers {
+                request = request.header(key, value);
+            }
+            if let Some(service) = &self.credentials {
+                let credential = service.get_credential().await?;
+                request = request.header(credential.header, credential.value);
+            }
+        }
+        let response = request.send().await.map_err(|e| failed(uri, e))?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(A2AError::Http {
+                status: status.as_u16(),
+                body: response.text().await.unwrap_or_default(),
+            });
+        }
+
+        if let Some(length) = response.content_length() {
+            if length > self.max_size {
+                return Err(self.too_large(uri, Some(length)));
+            }
+        }
+        let served = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.split(';').next())
+            .map(|essence| essence.trim().to_ascii_lowercase())
+            .filter(|essence| !essence.is_empty());
+        if let (true, Some(declared), Some(served)) = (self.check_mime_type, declared, &served) {
+            if !mime_matches(declared, served) {
+                return Err(A2AError::InvalidAgentResponse {
+                    message: format!(
+                        "{uri} is served as {served}, but the part declares {declared}"
+                    ),
+                    data: None,
+                });
+            }
+        }
+        Ok((response, served))
+    }
+
+    /// The next chunk of `response`, counted into `received` against the
+    /// size limit.
+    async fn next_chunk(
+        &self,
+        uri: &str,
+        response: &mut reqwest::Response,
+        received: &mut u64,
+    ) -> A2AResult<Option<bytes::Bytes>> {
+        let chunk = response.chunk().await.map_err(|e| failed(uri, e))?;
+        if let Some(chunk) = &chunk {
+            *received += chunk.len() as u64;
+            if *received > self.max_size {
+                return Err(self.too_large(uri, None));
+            }
+        }
+        Ok(chunk)
+    }
+
+    fn too_large(&self, uri: &str, size: Option<u64>) -> A2AError {
+        A2AError::payload_too_large(
+            &format!("Download of {uri}"),
+            size.map(|size| size as usize),
+            self.max_size as usize,
+        )
+    }
+
+    fn sends_auth_to(&self, uri: &str) -> bool {
+        self.auth_origins.is_empty()
+            || origin(uri).is_some_and(|origin| self.auth_origins.contains(&origin))
+    }
+}
+
+impl Task {
+    /// Save every file part of this task's artifacts in `dir` with a
+    /// default [`ArtifactDownloader`]; see
+    /// [`ArtifactDownloader::download_artifacts`].
+    ///
+    /// # Errors
+    ///
+    /// As [`ArtifactDownloader::download_artifacts`].
+    pub async fn download_all_artifacts(&self, dir: impl AsRef<Path>) -> A2AResult<Vec<PathBuf>> {
+        ArtifactDownloader::new()
+            .download_artifacts(self, dir.as_ref())
+            .await
+    }
+}
+
+/// `scheme://host[:port]` of `url`, lowercased.
+pub(crate) fn origin(url: &str) -> Option<String> {
+    let (scheme, rest) = url.split_once("://")?;
+    let authority = rest.split(['/', '?', '#']).next()?;
+    let host = authority.rsplit('@').next()?;
+    (!host.is_empty()).then(|| format!("{scheme}://{host}").to_ascii_lowercase())
+}
+
+/// Whether a file declared as `declared` may be served as `served`.
+fn mime_matches(declared: &str, served: &str) -> bool {
+    let declared = declared
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase();
+    if declared.is_empty() || declared == "application/octet-stream" || declared == served {
+        return true;
+    }
+    declared
+        .strip_suffix("/*")
+        .is_some_and(|kind| served.split('/').next() == Some(kind))
+}
+
+/// The part's file name, reduced to its final path component.
+fn file_name(file: &FileContent) -> Option<String> {
+    let name = match file {
+        FileContent::Bytes(file) => file.name.as_deref(),
+        FileContent::Uri(file) => file.name.as_deref(),
+    }?;
+    let name = Path::new(name).file_name()?.to_str()?;
+    (!name.is_empty() && name != "..").then(|| name.to_string())
+}
+
+fn failed(uri: &str, e: reqwest::Error) -> A2AError {
+    A2AError::Transport(format!("Download of {uri} failed: {e}"))
+}
+
+/// Append `chunk` to `file` on the blocking pool.
+async fn write_chunk(
+    mut file: std::fs::File,
+    chunk: bytes::Bytes,
+    path: &Path,
+) -> A2AResult<std::fs::File> {
+    blocking_write(path, move || file.write_all(&chunk).map(|()| file)).await
+}
+
+/// Run a filesystem call writing `path` on the blocking pool.
+async fn blocking_write<T: Send + 'static>(
+    path: &Path,
+    f: impl FnOnce() -> std::io::Result<T> + Send + 'static,
+) -> A2AResult<T> {
+    tokio::task::spawn_blocking(f)
+        .await
+        .map_err(|e| A2AError::internal_error(format!("file write task failed: {e}")))?
+        .map_err(|e| write_error(path, e))
+}
+
+fn write_error(path: &Path, e: std::io::Error) -> A2AError {
+    A2AError::internal_error(format!("Cannot write {}: {e}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn origins() {
+        assert_eq!(
+            origin("HTTPS://Files.Example.com:8443/a/b?x").as_deref(),
+            Some("https://files.example.com:8443")
+        );
+        assert_eq!(origin("http://user@host/x").as_deref(), Some("http://host"));
+        assert_eq!(origin("not a url"), None);
+    }
+
+    #[test]
+    fn mime_types() {
+        assert!(mime_matches("image/png", "image/png"));
+        assert!(mime_matches("image/*", "image/jpeg"));
+        assert!(mime_matches("Text/Plain; charset=utf-8", "text/plain"));
+        assert!(mime_matches("application/octet-stream", "text/html"));
+        assert!(!mime_matches("image/png", "text/html"));
+        assert!(!mime_matches("image/*", "text/html"));
+    }
+}
@@ -0,0 +1,181 @@
+//! Typed client errors — what went wrong, by who is to blame.
+//!
+//! Client calls return [`A2AError`], which mirrors the protocol's error
+//! codes; converting one into a [`ClientError`] sorts it by where the call
+//! failed, which is usually what callers branch on.
+
+use crate::error::{A2AError, INVALID_AGENT_RESPONSE, RATE_LIMITED};
+use crate::types::{JsonRpcError, Task, TaskStatus};
+
+/// A client call's error, classified by where it failed.
+///
+/// Converted from the [`A2AError`] variants of each cause:
+///
+/// | Variant | Cause | From |
+/// |---------|-------|------|
+/// | [`Transport`](ClientError::Transport) | no usable answer: connection failure, non-2xx HTTP status | `Transport`, `Http` |
+/// | [`Protocol`](ClientError::Protocol) | the agent answered with a JSON-RPC error, or broke the protocol | `JsonRpc`, protocol error variants, `InvalidJson`, `ProtocolViolation`, `Other` |
+/// | [`AgentFailed`](ClientError::AgentFailed) | the agent ran the task and it failed or was rejected | `TaskFailed`, `TaskRejected` |
+/// | [`Discovery`](ClientError::Discovery) | the agent card couldn't be resolved or used | `IncompatibleAgent`, `InvalidAgentCard`, [`ClientError::discovery`] |
+/// | [`Timeout`](ClientError::Timeout) | the call or stream timed out | `Timeout` |
+///
+/// ```no_run
+/// use a2a_rs::client::{A2AClient, ClientError};
+///
+/// # async fn example(client: A2AClient) {
+/// match client.send_text("hello").await.map_err(ClientError::from) {
+///     Ok(response) => println!("{response:?}"),
+///     Err(e) if e.is_retryable() => println!("try again in {:?}", e.retry_after()),
+///     Err(ClientError::Protocol(error)) => println!("agent said no: {}", error.message),
+///     Err(e) => println!("{e}"),
+/// }
+/// # }
+/// ```
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum ClientError {
+    /// The request didn't get a usable answer: the connection failed, or
+    /// the server answered with a non-2xx HTTP status.
+    #[error("Transport error: {message}")]
+    Transport {
+        /// What failed.
+        message: String,
+        /// The server's answer, if there was one.
+        response: Option<RawResponse>,
+    },
+
+    /// The agent answered with a JSON-RPC error, or with something that
+    /// isn't a valid A2A response (code
+    /// [`INVALID_AGENT_RESPONSE`]).
+    #[error("JSON-RPC error {}: {}", .0.code, .0.message)]
+    Protocol(JsonRpcError),
+
+    /// The agent ran the task, and it ended `failed` or `rejected`.
+    #[error("Task {} {}", .task.id, .status.state)]
+    AgentFailed {
+        /// The task's final status, with the agent's explanation.
+        status: TaskStatus,
+        /// The task as the agent returned it.
+        task: Box<Task>,
+    },
+
+    /// The agent's card couldn't be resolved, or doesn't allow the call.
+    #[error("Agent discovery failed: {0}")]
+    Discovery(#[source] Box<A2AError>),
+
+    /// The call or stream timed out.
+    #[error("Timeout: {0}")]
+    Timeout(String),
+}
+
+/// Result type of calls returning [`ClientError`].
+pub type ClientResult<T> = Result<T, ClientError>;
+
+/// A server's answer to a failed request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RawResponse {
+    /// HTTP status code.
+    pub status: u16,
+    /// Response body text.
+    pub body: String,
+}
+
+impl ClientError {
+    /// Classify `error`, raised while resolving an agent card (e.g. by
+    /// [`A2AClient::from_url`](super::A2AClient::from_url) or
+    /// [`CardResolver`](super::CardResolver)), as [`Discovery`](Self::Discovery).
+    pub fn discovery(error: A2AError) -> Self {
+        Self::Discovery(Box::new(error))
+    }
+
+    /// Whether the call may succeed if repeated: connection failures,
+    /// timeouts, HTTP 408, 429 and 5xx, and rate-limited JSON-RPC errors.
+    /// For [`Discovery`](Self::Discovery), whether the underlying error is.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::Transport { response, .. } => response
+                .as_ref()
+                .map_or(true, |r| matches!(r.status, 408 | 429 | 500..=599)),
+            Self::Timeout(_) => true,
+            Self::Protocol(error) => error.code == RATE_LIMITED,
+            Self::AgentFailed { .. } => false,
+            Self::Discovery(error) => match error.as_ref() {
+                A2AError::IncompatibleAgent { .. } | A2AError::InvalidAgentCard { .. } => false,
+                error => Self::from(error.clone()).is_retryable(),
+            },
+        }
+    }
+
+    /// How long the server asked to wait before retrying, for
+    /// rate-limited calls. See [`A2AError::retry_after`].
+    pub fn retry_after(&self) -> Option<std::time::Duration> {
+        match self {
+            Self::Transport {
+                response: Some(response),
+                ..
+            } => A2AError::Http {
+                status: response.status,
+                body: response.body.clone(),
+            }
+            .retry_after(),
+            Self::Protocol(error) => A2AError::JsonRpc {
+                code: error.code,
+                message: error.message.clone(),
+                data: error.data.clone(),
+            }
+            .retry_after(),
+            Self::Discovery(error) => error.retry_after(),
+            _ => None,
+        }
+    }
+
+    /// The server's answer to a failed request, if it sent one.
+    pub fn raw_response(&self) -> Option<&RawResponse> {
+        match self {
+            Self::Transport { response, .. } => response.as_ref(),
+            _ => None,
+        }
+    }
+}
+
+impl From<A2AError> for ClientError {
+    fn from(error: A2AError) -> Self {
+        match error {
+            A2AError::Transport(message) => Self::Transport {
+                message,
+                response: None,
+            },
+            A2AError::Http { status, body } => Self::Transport {
+                message: format!("HTTP {status}"),
+                response: Some(RawResponse { status, body }),
+            },
+            A2AError::Timeout(message) => Self::Timeout(message),
+            A2AError::InvalidJson(message) | A2AError::ProtocolViolation(message) => {
+                Self::Protocol(JsonRpcError {
+                    code: INVALID_AGENT_RESPONSE,
+                    message,
+                    data: None,
+                })
+            }
+            A2AError::JsonRpc {
+                code,
+                message,
+                data,
+            } => Self::Protocol(JsonRpcError {
+                code,
+                message,
+                data,
+            }),
+            A2AError::TaskFailed { task, .. } | A2AError::TaskRejected { task, .. } => {
+                Self::AgentFailed {
+                    status: task.status.clone(),
+                    task,
+                }
+            }
+            A2AError::IncompatibleAgent { .. } | A2AError::InvalidAgentCard { .. } => {
+                Self::discovery(error)
+            }
+            protocol => Self::Protocol(JsonRpcError::from(protocol)),
+        }
+    }
+}
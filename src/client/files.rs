@@ -0,0 +1,229 @@
+//! Attaching local files to outgoing messages.
+//!
+//! [`A2AClient::send_text_with_files`](super::A2AClient::send_text_with_files)
+//! turns each path into a file part: files up to the client's inline limit
+//! ([`DEFAULT_INLINE_FILE_LIMIT`] unless changed with
+//! [`A2AClient::with_inline_file_limit`](super::A2AClient::with_inline_file_limit))
+//! are embedded as base64 bytes, larger ones are handed to the configured
+//! [`FileUploader`] and sent as a URI.
+//!
+//! In the other direction, [`FileContent::download`] returns the content of
+//! a received file part, fetching URI-backed files over HTTP.
+
+use std::path::{Path, PathBuf};
+
+use async_trait::async_trait;
+
+use crate::error::{A2AError, A2AResult};
+use crate::types::{FileContent, FileWithBytes, FileWithUri, Part};
+use crate::utils::parts::guess_mime_type;
+
+/// Files up to this size (1 MiB) are sent inline by default.
+pub const DEFAULT_INLINE_FILE_LIMIT: u64 = 1024 * 1024;
+
+/// Uploads files too large to send inline and returns a URI the agent can
+/// fetch them from.
+///
+/// Implementations receive the path rather than the content, so they can
+/// stream the file to storage (an object store, a pre-signed URL, …)
+/// without buffering it in memory.
+///
+/// # Example
+///
+/// ```
+/// use std::path::Path;
+/// use a2a_rs::client::FileUploader;
+/// use a2a_rs::error::A2AResult;
+///
+/// /// Files are on a share the agent can read directly.
+/// struct SharedDrive;
+///
+/// #[async_trait::async_trait]
+/// impl FileUploader for SharedDrive {
+///     async fn upload(&self, path: &Path, _mime_type: &str) -> A2AResult<String> {
+///         Ok(format!("file://{}", path.display()))
+///     }
+/// }
+/// ```
+#[async_trait]
+pub trait FileUploader: Send + Sync {
+    /// Upload the file at `path` and return its URI.
+    async fn upload(&self, path: &Path, mime_type: &str) -> A2AResult<String>;
+}
+
+impl FileContent {
+    /// The content of the file: decoded from base64 for inline bytes,
+    /// fetched with a `GET` for a URI.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`A2AError::InvalidParams`] for invalid base64,
+    /// [`A2AError::Transport`] if the request fails and [`A2AError::Http`]
+    /// for a non-success status.
+    pub async fn download(&self) -> A2AResult<Vec<u8>> {
+        self.download_with(&reqwest::Client::new()).await
+    }
+
+    /// As [`download()`](Self::download), fetching URIs with `client`
+    /// (e.g. one carrying credentials for the agent's file store).
+    ///
+    /// # Errors
+    ///
+    /// As [`download()`](Self::download).
+    pub async fn download_with(&self, client: &reqwest::Client) -> A2AResult<Vec<u8>> {
+        let uri = match self {
+            FileContent::Bytes(file) => return file.decode(),
+            FileContent::Uri(file) => &file.uri,
+        };
+        let failed =
+            |e: reqwest::Error| A2AError::Transport(format!("Download of {uri} failed: {e}"));
+        let response = client.get(uri).send().await.map_err(failed)?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(A2AError::Http {
+                status: status.as_u16(),
+                body: response.text().await.unwrap_or_default(),
+            });
+        }
+        Ok(response.bytes().await.map_err(failed)?.to_vec())
+    }
+}
+
+/// Content of a file to send: a local file, or bytes already in memory.
+///
+/// Converts from paths (`&Path`, `PathBuf`, `&str`, `String`) and from
+/// bytes (`Vec<u8>`, `&[u8]`, `&[u8; N]`), so
+/// [`A2AClient::send_file`](super::A2AClient::send_file) and
+/// [`MessageBuilder::file`](super::MessageBuilder::file) take either.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FileSource {
+    /// A file on disk, read (or uploaded) when the message is sent.
+    Path(PathBuf),
+    /// File content, always sent inline.
+    Bytes(Vec<u8>),
+}
+
+impl From<&Path> for FileSource {
+    fn from(path: &Path) -> Self {
+        FileSource::Path(path.to_path_buf())
+    }
+}
+
+impl From<PathBuf> for FileSource {
+    fn from(path: PathBuf) -> Self {
+        FileSource::Path(path)
+    }
+}
+
+impl From<&PathBuf> for FileSource {
+    fn from(path: &PathBuf) -> Self {
+        FileSource::Path(path.clone())
+    }
+}
+
+impl From<&str> for FileSource {
+    fn from(path: &str) -> Self {
+        FileSource::Path(PathBuf::from(path))
+    }
+}
+
+impl From<String> for FileSource {
+    fn from(path: String) -> Self {
+        FileSource::Path(PathBuf::from(path))
+    }
+}
+
+impl From<Vec<u8>> for FileSource {
+    fn from(bytes: Vec<u8>) -> Self {
+        FileSource::Bytes(bytes)
+    }
+}
+
+impl From<&[u8]> for FileSource {
+    fn from(bytes: &[u8]) -> Self {
+        FileSource::Bytes(bytes.to_vec())
+    }
+}
+
+impl<const N: usize> From<&[u8; N]> for FileSource {
+    fn from(bytes: &[u8; N]) -> Self {
+        FileSource::Bytes(bytes.to_vec())
+    }
+}
+
+/// Build the file part for `source`. Paths are handled as by
+/// [`file_part`]; bytes are always inline, typed `mime_type` or
+/// `application/octet-stream`.
+pub(crate) async fn source_part(
+    source: FileSource,
+    mime_type: Option<&str>,
+    inline_limit: u64,
+    uploader: Option<&dyn FileUploader>,
+) -> A2AResult<Part> {
+    match source {
+        FileSource::Path(path) => file_part(&path, mime_type, inline_limit, uploader).await,
+        FileSource::Bytes(bytes) => Ok(Part::file_from_raw(
+            bytes,
+            None,
+            Some(mime_type.unwrap_or("application/octet-stream").to_string()),
+        )),
+    }
+}
+
+/// Build the file part for `path`: inline up to `inline_limit` bytes,
+/// uploaded via `uploader` above it. Without `mime_type`, it's guessed
+/// from the extension.
+pub(crate) async fn file_part(
+    path: &Path,
+    mime_type: Option<&str>,
+    inline_limit: u64,
+    uploader: Option<&dyn FileUploader>,
+) -> A2AResult<Part> {
+    let name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned());
+    let mime_type = mime_type.unwrap_or_else(|| guess_mime_type(path));
+    let size = blocking_io(path, |path| std::fs::metadata(path).map(|m| m.len())).await?;
+
+    let file = if size <= inline_limit {
+        let bytes = blocking_io(path, std::fs::read).await?;
+        FileContent::Bytes(FileWithBytes {
+            name,
+            mime_type: Some(mime_type.to_string()),
+            ..FileWithBytes::from_bytes(bytes)
+        })
+    } else {
+        let uploader = uploader.ok_or_else(|| A2AError::InvalidParams {
+            message: format!(
+                "{} is {size} bytes, over the inline limit of {inline_limit}; \
+                 configure a FileUploader to send it by URI",
+                path.display()
+            ),
+            data: None,
+        })?;
+        FileContent::Uri(FileWithUri {
+            uri: uploader.upload(path, mime_type).await?,
+            mime_type: Some(mime_type.to_string()),
+            name,
+        })
+    };
+    Ok(Part::File {
+        file,
+        metadata: None,
+    })
+}
+
+/// Run a filesystem call on the blocking pool.
+async fn blocking_io<T: Send + 'static>(
+    path: &Path,
+    f: fn(PathBuf) -> std::io::Result<T>,
+) -> A2AResult<T> {
+    let owned = path.to_path_buf();
+    tokio::task::spawn_blocking(move || f(owned))
+        .await
+        .map_err(|e| A2AError::internal_error(format!("file read task failed: {e}")))?
+        .map_err(|e| A2AError::InvalidParams {
+            message: format!("Cannot read {}: {e}", path.display()),
+            data: None,
+        })
+}
@@ -0,0 +1,393 @@
+//! gRPC transport (`grpc` feature).
+//!
+//! [`GrpcTransport`] speaks the A2A gRPC binding (`a2a.v1.A2AService`) and
+//! presents it through the JSON-RPC shaped [`Transport`] trait, so
+//! [`A2AClient`](super::A2AClient) works unchanged on top of it.
+
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use futures::StreamExt;
+use serde::de::DeserializeOwned;
+use tonic::codec::ProstCodec;
+use tonic::codegen::http::uri::PathAndQuery;
+use tonic::metadata::{MetadataKey, MetadataValue};
+use tonic::transport::{Channel, Endpoint};
+use tonic::Status;
+
+use crate::error::{A2AError, A2AResult};
+use crate::grpc::{error_from_status, proto, task_name};
+use crate::types::{
+    GetTaskParams, JsonRpcRequest, JsonRpcResponse, SendMessageParams, SendMessageResponse,
+    StreamResponse, Task, TaskIdParams,
+};
+use crate::utils::deadline::format_deadline;
+
+use super::observer::{CallMetrics, TransportEvent, TransportObserver};
+use super::sse::SseStream;
+use super::transport::{
+    outgoing_deadline, outgoing_request_id, CallOptions, Transport, TransportMetadata,
+};
+
+/// [`Transport`] for the A2A gRPC binding.
+///
+/// JSON-RPC methods map to `a2a.v1.A2AService` RPCs:
+///
+/// | JSON-RPC method                          | RPC                    |
+/// |------------------------------------------|------------------------|
+/// | `message/send`                           | `SendMessage`          |
+/// | `message/stream`                         | `SendStreamingMessage` |
+/// | `tasks/get`                              | `GetTask`              |
+/// | `tasks/cancel`                           | `CancelTask`           |
+/// | `tasks/subscribe`, `tasks/resubscribe`   | `TaskSubscription`     |
+///
+/// Other methods fail with `UnsupportedOperation`. A2A errors reported by
+/// the server come back as JSON-RPC error responses with the server's error
+/// code; connection failures are [`A2AError::Transport`]. Headers set with
+/// [`with_header()`](Self::with_header) or per call in [`CallOptions`] are
+/// sent as gRPC metadata.
+///
+/// The channel connects on first use; call [`connect()`](Transport::connect)
+/// to surface connection problems early.
+///
+/// # Example
+///
+/// ```no_run
+/// use a2a_rs::client::{A2AClient, GrpcTransport};
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let transport = GrpcTransport::new("http://localhost:50051")?;
+/// let client = A2AClient::with_transport(Box::new(transport));
+/// let response = client.send_text("Hello over gRPC").await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct GrpcTransport {
+    url: String,
+    endpoint: Endpoint,
+    channel: OnceLock<Channel>,
+    headers: HashMap<String, String>,
+    observer: Option<Arc<dyn TransportObserver>>,
+}
+
+impl std::fmt::Debug for GrpcTransport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GrpcTransport")
+            .field("url", &self.url)
+            .field("headers", &self.headers.keys().collect::<Vec<_>>())
+            .field("observer", &self.observer.is_some())
+            .finish()
+    }
+}
+
+impl GrpcTransport {
+    /// Create a transport for the gRPC endpoint at `url`
+    /// (e.g. `http://localhost:50051`), with a 60 second request timeout.
+    ///
+    /// Fails with [`A2AError::Transport`] if `url` is not a valid URI.
+    pub fn new(url: impl Into<String>) -> A2AResult<Self> {
+        let url = url.into();
+        let endpoint = Endpoint::from_shared(url.clone())
+            .map_err(|e| A2AError::Transport(format!("invalid gRPC endpoint {url}: {e}")))?
+            .timeout(Duration::from_secs(60));
+        Ok(Self {
+            url,
+            endpoint,
+            channel: OnceLock::new(),
+            headers: HashMap::new(),
+            observer: None,
+        })
+    }
+
+    /// Set the time a call may take until the response (or, for streams,
+    /// the response headers) arrives (builder-style).
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.endpoint = self.endpoint.timeout(timeout);
+        self.channel = OnceLock::new();
+        self
+    }
+
+    /// Add a metadata entry sent with every call (builder-style).
+    pub fn with_header(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.insert(key.into(), value.into());
+        self
+    }
+
+    /// Attach an observer notified with [`CallMetrics`] after every call
+    /// (builder-style).
+    pub fn with_observer(mut self, observer: Arc<dyn TransportObserver>) -> Self {
+        self.observer = Some(observer);
+        self
+    }
+
+    /// The endpoint URL.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    fn channel(&self) -> Channel {
+        self.channel
+            .get_or_init(|| self.endpoint.connect_lazy())
+            .clone()
+    }
+
+    /// Wrap `message` in a request carrying the transport and call headers
+    /// and the call timeout.
+    fn request<M>(&self, message: M, options: &CallOptions) -> tonic::Request<M> {
+        let mut request = tonic::Request::new(message);
+        if let Ok(request_id) = MetadataValue::try_from(outgoing_request_id(options)) {
+            request
+                .metadata_mut()
+                .insert(MetadataKey::from_static("x-a2a-request-id"), request_id);
+        }
+        if let Some(deadline) = outgoing_deadline(options) {
+            if let Ok(deadline) = MetadataValue::try_from(format_deadline(deadline)) {
+                request
+                    .metadata_mut()
+                    .insert(MetadataKey::from_static("x-a2a-deadline"), deadline);
+            }
+        }
+        for (key, value) in self.headers.iter().chain(&options.headers) {
+            let name = MetadataKey::from_bytes(key.to_ascii_lowercase().as_bytes());
+            match (name, MetadataValue::try_from(value.as_str())) {
+                (Ok(name), Ok(value)) => {
+                    request.metadata_mut().insert(name, value);
+                }
+                _ => tracing::warn!(header = %key, "Skipping header not valid as gRPC metadata"),
+            }
+        }
+        if let Some(timeout) = options.timeout {
+            request.set_timeout(timeout);
+        }
+        request
+    }
+
+    async fn unary<Req, Resp>(
+        &self,
+        path: &'static str,
+        message: Req,
+        options: &CallOptions,
+    ) -> Result<Resp, Status>
+    where
+        Req: prost::Message + Send + Sync + 'static,
+        Resp: prost::Message + Default + Send + Sync + 'static,
+    {
+        let mut grpc = tonic::client::Grpc::new(self.channel());
+        grpc.ready()
+            .await
+            .map_err(|e| Status::unavailable(e.to_string()))?;
+        let request = self.request(message, options);
+        grpc.unary(
+            request,
+            PathAndQuery::from_static(path),
+            ProstCodec::default(),
+        )
+        .await
+        .map(tonic::Response::into_inner)
+    }
+
+    async fn server_streaming<Req>(
+        &self,
+        path: &'static str,
+        message: Req,
+        options: &CallOptions,
+    ) -> Result<tonic::Streaming<proto::StreamResponse>, Status>
+    where
+        Req: prost::Message + Send + Sync + 'static,
+    {
+        let mut grpc = tonic::client::Grpc::new(self.channel());
+        grpc.ready()
+            .await
+            .map_err(|e| Status::unavailable(e.to_string()))?;
+        let request = self.request(message, options);
+        grpc.server_streaming(
+            request,
+            PathAndQuery::from_static(path),
+            ProstCodec::default(),
+        )
+        .await
+        .map(tonic::Response::into_inner)
+    }
+
+    /// Run a unary JSON-RPC method as the matching RPC.
+    async fn call(
+        &self,
+        request: &JsonRpcRequest,
+        options: &CallOptions,
+    ) -> A2AResult<Result<serde_json::Value, Status>> {
+        let result = match request.method.as_str() {
+            "message/send" => {
+                let params: SendMessageParams = params(request)?;
+                self.unary::<_, proto::SendMessageResponse>(
+                    proto::SEND_MESSAGE,
+                    proto::SendMessageRequest::try_from(params)?,
+                    options,
+                )
+                .await
+                .map(|response| to_json(SendMessageResponse::try_from(response)))
+            }
+            "tasks/get" => {
+                let params: GetTaskParams = params(request)?;
+                let message = proto::GetTaskRequest::from(params);
+                self.unary::<_, proto::Task>(proto::GET_TASK, message, options)
+                    .await
+                    .map(|task| to_json(Task::try_from(task)))
+            }
+            "tasks/cancel" => {
+                let params: TaskIdParams = params(request)?;
+                let message = proto::CancelTaskRequest {
+                    name: task_name(&params.id),
+                };
+                self.unary::<_, proto::Task>(proto::CANCEL_TASK, message, options)
+                    .await
+                    .map(|task| to_json(Task::try_from(task)))
+            }
+            method => return Err(unsupported(method)),
+        };
+        Ok(match result {
+            Ok(value) => Ok(value?),
+            Err(status) => Err(status),
+        })
+    }
+
+    /// Open the server stream for a streaming JSON-RPC method.
+    async fn open_stream(
+        &self,
+        request: &JsonRpcRequest,
+        options: &CallOptions,
+    ) -> A2AResult<tonic::Streaming<proto::StreamResponse>> {
+        let result = match request.method.as_str() {
+            "message/stream" => {
+                let params: SendMessageParams = params(request)?;
+                self.server_streaming(
+                    proto::SEND_STREAMING_MESSAGE,
+                    proto::SendMessageRequest::try_from(params)?,
+                    options,
+                )
+                .await
+            }
+            "tasks/subscribe" | "tasks/resubscribe" => {
+                let params: TaskIdParams = params(request)?;
+                let message = proto::TaskSubscriptionRequest {
+                    name: task_name(&params.id),
+                };
+                self.server_streaming(proto::TASK_SUBSCRIPTION, message, options)
+                    .await
+            }
+            method => return Err(unsupported(method)),
+        };
+        result.map_err(|status| error_from_status(&status))
+    }
+
+    /// Report a finished call to the observer.
+    fn report(&self, method: &str, streaming: bool, started: Instant, error: Option<String>) {
+        if let Some(observer) = &self.observer {
+            observer.on_call(&CallMetrics {
+                method: method.to_string(),
+                url: self.url.clone(),
+                streaming,
+                elapsed: started.elapsed(),
+                error,
+                ..Default::default()
+            });
+        }
+    }
+}
+
+fn params<T: DeserializeOwned>(request: &JsonRpcRequest) -> A2AResult<T> {
+    let params = request.params.clone().unwrap_or(serde_json::Value::Null);
+    serde_json::from_value(params)
+        .map_err(|e| A2AError::invalid_params(format!("invalid {} params: {e}", request.method)))
+}
+
+fn to_json<T: serde::Serialize>(value: A2AResult<T>) -> A2AResult<serde_json::Value> {
+    let value = value
+        .map_err(|e| A2AError::invalid_agent_response(format!("invalid gRPC response: {e}")))?;
+    serde_json::to_value(value).map_err(|e| A2AError::InvalidJson(e.to_string()))
+}
+
+fn unsupported(method: &str) -> A2AError {
+    A2AError::unsupported_operation(format!("{method} is not available over gRPC"))
+}
+
+/// Whether a status describes the connection rather than an A2A error.
+fn is_transport_failure(err: &A2AError) -> bool {
+    matches!(err, A2AError::Transport(_) | A2AError::Timeout(_))
+}
+
+#[async_trait]
+impl Transport for GrpcTransport {
+    async fn send(&self, request: &JsonRpcRequest) -> A2AResult<JsonRpcResponse> {
+        self.send_with_options(request, &CallOptions::default())
+            .await
+    }
+
+    async fn send_stream(&self, request: &JsonRpcRequest) -> A2AResult<SseStream> {
+        self.send_stream_with_options(request, &CallOptions::default())
+            .await
+    }
+
+    async fn send_with_options(
+        &self,
+        request: &JsonRpcRequest,
+        options: &CallOptions,
+    ) -> A2AResult<JsonRpcResponse> {
+        let started = Instant::now();
+        let id = request.id.clone();
+        let result = match self.call(request, options).await {
+            Ok(Ok(value)) => Ok(JsonRpcResponse::success(id, value)),
+            Ok(Err(status)) => match error_from_status(&status) {
+                err if is_transport_failure(&err) => Err(err),
+                err => Ok(JsonRpcResponse::from_a2a_error(id, err)),
+            },
+            Err(err) if is_transport_failure(&err) => Err(err),
+            Err(err) => Ok(JsonRpcResponse::from_a2a_error(id, err)),
+        };
+        let error = result.as_ref().err().map(ToString::to_string);
+        self.report(&request.method, false, started, error);
+        result
+    }
+
+    async fn send_stream_with_options(
+        &self,
+        request: &JsonRpcRequest,
+        options: &CallOptions,
+    ) -> A2AResult<SseStream> {
+        let started = Instant::now();
+        let result = self.open_stream(request, options).await;
+        let error = result.as_ref().err().map(ToString::to_string);
+        self.report(&request.method, true, started, error);
+
+        let events = result?.map(|event| match event {
+            Ok(event) => StreamResponse::try_from(event)
+                .map_err(|e| A2AError::invalid_agent_response(format!("invalid gRPC event: {e}"))),
+            Err(status) => Err(error_from_status(&status)),
+        });
+        Ok(SseStream::from_stream(events))
+    }
+
+    async fn connect(&self) -> A2AResult<()> {
+        let channel =
+            self.endpoint.connect().await.map_err(|e| {
+                A2AError::Transport(format!("connection to {} failed: {e}", self.url))
+            })?;
+        // Keep the established channel if no call has created one yet.
+        let _ = self.channel.set(channel);
+        if let Some(observer) = &self.observer {
+            observer.on_event(&TransportEvent::Connected {
+                endpoint: self.url.clone(),
+            });
+        }
+        Ok(())
+    }
+
+    fn metadata(&self) -> TransportMetadata {
+        TransportMetadata::new("GRPC").with_endpoint(&self.url)
+    }
+
+    fn set_observer(&mut self, observer: Arc<dyn TransportObserver>) {
+        self.observer = Some(observer);
+    }
+}
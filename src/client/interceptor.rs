@@ -0,0 +1,227 @@
+//! Interception of outbound JSON-RPC calls.
+//!
+//! A [`TransportInterceptor`] registered on a
+//! [`JsonRpcTransport`](super::JsonRpcTransport) (or via
+//! [`ClientBuilder`](crate::ClientBuilder)) wraps every call the transport
+//! makes, seeing it in A2A terms rather than as raw HTTP: the JSON-RPC
+//! method and params, the per-call headers, and the JSON-RPC response.
+//! Typical uses are logging and tracing calls, injecting tenant or
+//! correlation headers, and rewriting params or results.
+//!
+//! Interceptors form a chain, like tower layers: requests pass through
+//! them in registration order, responses and errors in reverse order. A
+//! request hook returning an error aborts the call: later interceptors
+//! and the request itself are skipped, and the interceptors before it see
+//! the error. A response hook returning an error fails the call, and the
+//! interceptors after it in the response order see the error.
+//!
+//! For streaming calls (`message/stream`, `tasks/resubscribe`) the response
+//! hook is [`on_stream_opened`](TransportInterceptor::on_stream_opened),
+//! run once the SSE stream is established. Transparent reconnects (see
+//! [`JsonRpcTransport::with_credentials`](super::JsonRpcTransport::with_credentials))
+//! run the chain again for the `tasks/resubscribe` call. Use a
+//! [`StreamInterceptor`](super::StreamInterceptor) to see the events.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::error::{A2AError, A2AResult};
+use crate::types::{JsonRpcRequest, JsonRpcResponse};
+
+use super::transport::CallOptions;
+
+/// An outbound call as seen by a [`TransportInterceptor`].
+#[derive(Debug, Clone)]
+pub struct InterceptedCall {
+    /// The JSON-RPC request. Changes made in
+    /// [`on_request`](TransportInterceptor::on_request) are what gets sent.
+    pub request: JsonRpcRequest,
+
+    /// Per-call options; add headers here.
+    pub options: CallOptions,
+
+    /// `true` for calls answered with an SSE stream.
+    pub streaming: bool,
+}
+
+impl InterceptedCall {
+    /// Wrap a request about to be sent.
+    pub fn new(request: JsonRpcRequest, options: CallOptions, streaming: bool) -> Self {
+        Self {
+            request,
+            options,
+            streaming,
+        }
+    }
+
+    /// The JSON-RPC method name (e.g. `"message/send"`).
+    pub fn method(&self) -> &str {
+        &self.request.method
+    }
+
+    /// The JSON-RPC params, if any.
+    pub fn params(&self) -> Option<&serde_json::Value> {
+        self.request.params.as_ref()
+    }
+
+    /// Set a header for this call only.
+    pub fn set_header(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        self.options.headers.insert(key.into(), value.into());
+    }
+}
+
+/// Hook wrapped around every JSON-RPC call of a transport.
+///
+/// Every method has a no-op default; implement the ones you need. Hooks
+/// run inline on the calling task.
+///
+/// # Example
+///
+/// ```
+/// use a2a_rs::client::{InterceptedCall, JsonRpcTransport, TransportInterceptor};
+/// use a2a_rs::error::A2AResult;
+/// use async_trait::async_trait;
+/// use std::sync::Arc;
+///
+/// /// Tags every call with the tenant it is made for.
+/// struct Tenant(String);
+///
+/// #[async_trait]
+/// impl TransportInterceptor for Tenant {
+///     async fn on_request(&self, call: &mut InterceptedCall) -> A2AResult<()> {
+///         call.set_header("X-Tenant", self.0.clone());
+///         Ok(())
+///     }
+/// }
+///
+/// let transport = JsonRpcTransport::new("http://localhost:7420/a2a")
+///     .with_interceptor(Arc::new(Tenant("acme".into())));
+/// ```
+#[async_trait]
+pub trait TransportInterceptor: Send + Sync {
+    /// Called before the request is sent; may modify it. An error aborts
+    /// the call.
+    async fn on_request(&self, call: &mut InterceptedCall) -> A2AResult<()> {
+        let _ = call;
+        Ok(())
+    }
+
+    /// Called with the JSON-RPC response of a unary call (including
+    /// JSON-RPC error responses); may modify it. An error fails the call.
+    async fn on_response(
+        &self,
+        call: &InterceptedCall,
+        response: &mut JsonRpcResponse,
+    ) -> A2AResult<()> {
+        let _ = (call, response);
+        Ok(())
+    }
+
+    /// Called once the SSE stream of a streaming call is established. An
+    /// error closes the stream and fails the call.
+    async fn on_stream_opened(&self, call: &InterceptedCall) -> A2AResult<()> {
+        let _ = call;
+        Ok(())
+    }
+
+    /// Called when the call failed: at the transport level, or in a later
+    /// interceptor's hook.
+    async fn on_error(&self, call: &InterceptedCall, error: &A2AError) {
+        let _ = (call, error);
+    }
+}
+
+/// An ordered chain of [`TransportInterceptor`]s.
+#[derive(Clone, Default)]
+pub(crate) struct InterceptorChain {
+    interceptors: Vec<Arc<dyn TransportInterceptor>>,
+}
+
+impl std::fmt::Debug for InterceptorChain {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InterceptorChain")
+            .field("interceptors", &self.interceptors.len())
+            .finish()
+    }
+}
+
+impl InterceptorChain {
+    pub(crate) fn push(&mut self, interceptor: Arc<dyn TransportInterceptor>) {
+        self.interceptors.push(interceptor);
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.interceptors.is_empty()
+    }
+
+    /// Run the request hooks in order. If one fails, the interceptors
+    /// before it see the error.
+    pub(crate) async fn request(&self, call: &mut InterceptedCall) -> A2AResult<()> {
+        for (i, interceptor) in self.interceptors.iter().enumerate() {
+            if let Err(e) = interceptor.on_request(call).await {
+                fail(&self.interceptors[..i], call, &e).await;
+                return Err(e);
+            }
+        }
+        Ok(())
+    }
+
+    /// Run the response hooks of a unary call in reverse order.
+    pub(crate) async fn response(
+        &self,
+        call: &InterceptedCall,
+        mut result: A2AResult<JsonRpcResponse>,
+    ) -> A2AResult<JsonRpcResponse> {
+        for interceptor in self.interceptors.iter().rev() {
+            result = match result {
+                Ok(mut response) => interceptor
+                    .on_response(call, &mut response)
+                    .await
+                    .map(|()| response),
+                Err(e) => {
+                    interceptor.on_error(call, &e).await;
+                    Err(e)
+                }
+            };
+        }
+        result
+    }
+
+    /// Run the stream hooks of a streaming call in reverse order.
+    pub(crate) async fn stream_opened<T>(
+        &self,
+        call: &InterceptedCall,
+        mut result: A2AResult<T>,
+    ) -> A2AResult<T> {
+        for interceptor in self.interceptors.iter().rev() {
+            result = match result {
+                Ok(stream) => interceptor.on_stream_opened(call).await.map(|()| stream),
+                Err(e) => {
+                    interceptor.on_error(call, &e).await;
+                    Err(e)
+                }
+            };
+        }
+        result
+    }
+
+    /// Run the error hooks of a call without a response (a notification)
+    /// if it failed.
+    pub(crate) async fn finish(&self, call: &InterceptedCall, result: &A2AResult<()>) {
+        if let Err(e) = result {
+            fail(&self.interceptors, call, e).await;
+        }
+    }
+}
+
+/// Run the error hooks of `interceptors` in reverse order.
+async fn fail(
+    interceptors: &[Arc<dyn TransportInterceptor>],
+    call: &InterceptedCall,
+    error: &A2AError,
+) {
+    for interceptor in interceptors.iter().rev() {
+        interceptor.on_error(call, error).await;
+    }
+}
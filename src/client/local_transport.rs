@@ -0,0 +1,495 @@
+//! In-process transport (`server` feature).
+//!
+//! [`LocalTransport`] hands JSON-RPC requests straight to a
+//! [`RequestHandler`] in the same process: no socket, no HTTP, no SSE
+//! encoding. Agent tests drive an executor through the real
+//! [`A2AClient`](super::A2AClient) API with deterministic timing.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Instant;
+
+use async_trait::async_trait;
+use serde_json::Value;
+use tokio::sync::broadcast;
+use tracing::{debug, warn};
+
+use crate::error::{A2AError, A2AResult};
+use crate::server::axum_integration::{
+    parse_cancel_task_params, parse_get_task_params, parse_list_tasks_params,
+    parse_send_message_params, parse_subscribe_params,
+};
+use crate::server::{IncrementalMessage, RequestHandler, ServerCallContext};
+use crate::types::{
+    AgentCard, CancelContextParams, GetArtifactParams, JsonRpcRequest, JsonRpcResponse,
+    ListArtifactsParams, SkillInvokeParams, StreamResponse,
+};
+use crate::utils::extensions::{
+    get_requested_extensions, negotiate_extensions, HTTP_EXTENSION_HEADER,
+};
+use crate::utils::skills::{validate_skill_message, SKILLS_INVOKE_METHOD};
+use crate::utils::task::{
+    CONTEXTS_CANCEL_METHOD, MESSAGE_SEND_STREAM_METHOD, TASKS_ARTIFACTS_GET_METHOD,
+    TASKS_ARTIFACTS_LIST_METHOD,
+};
+
+use super::observer::{CallMetrics, TransportObserver};
+use super::sse::SseStream;
+use super::transport::{
+    outgoing_deadline, outgoing_request_id, CallOptions, PartStream, Transport, TransportMetadata,
+};
+
+/// Endpoint reported in [`TransportMetadata`] and [`CallMetrics`].
+const LOCAL_ENDPOINT: &str = "local";
+
+/// [`Transport`] dispatching to a [`RequestHandler`] in-process.
+///
+/// Params are decoded exactly as the axum router decodes them, and handler
+/// errors come back as JSON-RPC error responses, so the client sees the
+/// same results it would over HTTP. Streaming calls read the handler's
+/// event channel directly; the stream ends after the final status update.
+///
+/// Each call runs inside a [`ServerCallContext`] scope: the one set with
+/// [`with_call_context()`](Self::with_call_context), plus the extensions
+/// requested in [`CallOptions`] and the call's request ID (see
+/// [`CallOptions::with_request_id()`]). With an agent card
+/// ([`with_agent_card()`](Self::with_agent_card)) extensions are negotiated
+/// against it, `message/stream` requires the streaming capability, and
+/// `agent/authenticatedExtendedCard` and `skills/invoke` are served;
+/// without one every requested extension is activated.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::sync::Arc;
+/// use a2a_rs::client::{A2AClient, LocalTransport};
+/// use a2a_rs::server::{DefaultRequestHandler, InMemoryTaskStore};
+/// # use a2a_rs::server::AgentExecutor;
+///
+/// # async fn example(executor: Arc<dyn AgentExecutor>) -> Result<(), Box<dyn std::error::Error>> {
+/// let handler = DefaultRequestHandler::new(executor, Arc::new(InMemoryTaskStore::new()));
+/// let client = A2AClient::with_transport(Box::new(LocalTransport::new(Arc::new(handler))));
+/// let response = client.send_text("Hello, agent!").await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct LocalTransport {
+    handler: Arc<dyn RequestHandler>,
+    agent_card: Option<AgentCard>,
+    call_context: ServerCallContext,
+    observer: Option<Arc<dyn TransportObserver>>,
+}
+
+impl std::fmt::Debug for LocalTransport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LocalTransport")
+            .field(
+                "agent_card",
+                &self.agent_card.as_ref().map(|card| &card.name),
+            )
+            .field("call_context", &self.call_context)
+            .field("observer", &self.observer.is_some())
+            .finish()
+    }
+}
+
+impl LocalTransport {
+    /// Create a transport calling `handler` directly.
+    pub fn new(handler: Arc<dyn RequestHandler>) -> Self {
+        Self {
+            handler,
+            agent_card: None,
+            call_context: ServerCallContext::default(),
+            observer: None,
+        }
+    }
+
+    /// Serve as the agent described by `card` (builder-style): negotiate
+    /// extensions and check capabilities like the router does.
+    pub fn with_agent_card(mut self, card: AgentCard) -> Self {
+        self.agent_card = Some(card);
+        self
+    }
+
+    /// Base [`ServerCallContext`] of every call (builder-style), e.g. with
+    /// an authenticated `user` or `http` details for executors that read
+    /// them.
+    pub fn with_call_context(mut self, context: ServerCallContext) -> Self {
+        self.call_context = context;
+        self
+    }
+
+    /// Attach an observer notified with [`CallMetrics`] after every call
+    /// (builder-style).
+    pub fn with_observer(mut self, observer: Arc<dyn TransportObserver>) -> Self {
+        self.observer = Some(observer);
+        self
+    }
+
+    /// The call context for a call with `options`.
+    fn call_context(&self, options: &CallOptions) -> A2AResult<ServerCallContext> {
+        let headers: Vec<String> = options
+            .headers
+            .iter()
+            .filter(|(key, _)| key.eq_ignore_ascii_case(HTTP_EXTENSION_HEADER))
+            .map(|(_, value)| value.clone())
+            .collect();
+        let requested = get_requested_extensions(&headers);
+        let mut context = self.call_context.clone();
+        let activated = match &self.agent_card {
+            Some(card) => {
+                let negotiation = negotiate_extensions(card, &requested);
+                if !negotiation.missing_required.is_empty() {
+                    return Err(A2AError::invalid_request(format!(
+                        "Required extension(s) not requested: {}",
+                        negotiation.missing_required.join(", ")
+                    )));
+                }
+                negotiation.activated
+            }
+            None => requested.clone(),
+        };
+        context.requested_extensions.extend(requested);
+        context.activated_extensions.extend(activated);
+        if options.request_id().is_some() || context.request_id.is_none() {
+            context.request_id = Some(outgoing_request_id(options));
+        }
+        if options.deadline().is_some() || context.deadline.is_none() {
+            context.deadline = outgoing_deadline(options);
+        }
+        Ok(context)
+    }
+
+    /// Run a unary method and return its JSON result.
+    async fn call(&self, method: &str, params: Value) -> A2AResult<Value> {
+        let handler = &self.handler;
+        match method {
+            "message/send" | "message:send" | "SendMessage" => {
+                let params = parse_send_message_params(params).map_err(invalid_params)?;
+                to_json(handler.on_message_send(params).await?)
+            }
+            "tasks/get" | "tasks:get" | "GetTask" => {
+                let params = parse_get_task_params(params).map_err(invalid_params)?;
+                to_json(handler.on_get_task(params).await?)
+            }
+            "tasks/list" | "tasks:list" | "ListTasks" => {
+                let params = parse_list_tasks_params(params).map_err(invalid_params)?;
+                let response = handler.on_list_tasks(params).await?;
+                let mut result = serde_json::Map::new();
+                result.insert("tasks".to_string(), to_json(response.tasks)?);
+                if let Some(token) = response.next_page_token {
+                    result.insert("nextPageToken".to_string(), Value::String(token));
+                }
+                Ok(Value::Object(result))
+            }
+            "tasks/cancel" | "tasks:cancel" | "CancelTask" => {
+                let params = parse_cancel_task_params(params).map_err(invalid_params)?;
+                to_json(handler.on_cancel_task(params).await?)
+            }
+            "tasks/pushNotificationConfig/set" | "SetTaskPushNotificationConfig" => {
+                handler.on_set_task_push_notification_config(params).await
+            }
+            "tasks/pushNotificationConfig/get" | "GetTaskPushNotificationConfig" => {
+                handler.on_get_task_push_notification_config(params).await
+            }
+            "tasks/pushNotificationConfig/list" | "ListTaskPushNotificationConfigs" => {
+                handler.on_list_task_push_notification_config(params).await
+            }
+            "tasks/pushNotificationConfig/delete" | "DeleteTaskPushNotificationConfig" => handler
+                .on_delete_task_push_notification_config(params)
+                .await
+                .map(|()| Value::Null),
+            CONTEXTS_CANCEL_METHOD => {
+                let params: CancelContextParams =
+                    serde_json::from_value(params).map_err(|e| invalid_params(e.to_string()))?;
+                to_json(handler.on_cancel_context(params).await?)
+            }
+            TASKS_ARTIFACTS_LIST_METHOD => {
+                let params: ListArtifactsParams =
+                    serde_json::from_value(params).map_err(|e| invalid_params(e.to_string()))?;
+                to_json(handler.on_list_artifacts(params).await?)
+            }
+            MESSAGE_SEND_STREAM_METHOD => {
+                let params = parse_send_message_params(params).map_err(invalid_params)?;
+                let incoming = IncrementalMessage::complete(params.message.clone());
+                to_json(
+                    handler
+                        .on_message_send_incremental(params, incoming)
+                        .await?,
+                )
+            }
+            TASKS_ARTIFACTS_GET_METHOD => {
+                let params: GetArtifactParams =
+                    serde_json::from_value(params).map_err(|e| invalid_params(e.to_string()))?;
+                to_json(handler.on_get_artifact(params).await?)
+            }
+            "agent/authenticatedExtendedCard" | "GetExtendedAgentCard" => to_json(self.card()?),
+            SKILLS_INVOKE_METHOD => {
+                let params: SkillInvokeParams =
+                    serde_json::from_value(params).map_err(|e| invalid_params(e.to_string()))?;
+                if !params.dry_run {
+                    return Err(A2AError::unsupported_operation(
+                        "skills/invoke only supports dryRun; use message/send to execute",
+                    ));
+                }
+                to_json(validate_skill_message(self.card()?, &params)?)
+            }
+            _ => Err(A2AError::method_not_found(format!(
+                "Method not found: {method}"
+            ))),
+        }
+    }
+
+    /// Open the event channel of a streaming method.
+    async fn open_stream(
+        &self,
+        method: &str,
+        params: Value,
+    ) -> A2AResult<broadcast::Receiver<StreamResponse>> {
+        let handler = &self.handler;
+        match method {
+            "message/stream" | "message:stream" | "SendStreamingMessage" => {
+                if let Some(card) = &self.agent_card {
+                    if !card.capabilities.streaming.unwrap_or(false) {
+                        return Err(A2AError::unsupported_operation(
+                            "Streaming is not supported by the agent",
+                        ));
+                    }
+                }
+                let params = parse_send_message_params(params).map_err(invalid_params)?;
+                handler.on_message_send_stream(params).await
+            }
+            "tasks/subscribe" | "tasks:subscribe" | "SubscribeToTask" => {
+                let params = parse_subscribe_params(params).map_err(invalid_params)?;
+                handler.on_subscribe_to_task(params).await
+            }
+            "tasks/resubscribe" | "tasks:resubscribe" | "ResubscribeToTask" => {
+                let params = parse_subscribe_params(params).map_err(invalid_params)?;
+                handler.on_resubscribe_to_task(params).await
+            }
+            _ => Err(A2AError::method_not_found(format!(
+                "Method not found: {method}"
+            ))),
+        }
+    }
+
+    /// Run a `message/sendStream` upload, feeding `parts` to the handler
+    /// while it runs.
+    async fn upload(&self, method: &str, params: Value, parts: PartStream) -> A2AResult<Value> {
+        use futures::StreamExt;
+
+        if method != MESSAGE_SEND_STREAM_METHOD {
+            return Err(A2AError::invalid_request(format!(
+                "only {MESSAGE_SEND_STREAM_METHOD} accepts an upload"
+            )));
+        }
+        let params = parse_send_message_params(params).map_err(invalid_params)?;
+        let (sender, incoming) = IncrementalMessage::channel(params.message.clone());
+        let feed = async move {
+            let mut parts = parts;
+            while let Some(part) = parts.next().await {
+                sender.send(part);
+            }
+            sender.finish();
+        };
+        let (result, ()) = tokio::join!(
+            self.handler.on_message_send_incremental(params, incoming),
+            feed
+        );
+        to_json(result?)
+    }
+
+    /// Report a finished unary call and turn its result into a response.
+    fn respond(
+        &self,
+        request: &JsonRpcRequest,
+        started: Instant,
+        activated: &HashSet<String>,
+        result: A2AResult<Value>,
+    ) -> JsonRpcResponse {
+        let error = result.as_ref().err().map(ToString::to_string);
+        self.report(&request.method, false, started, activated, error);
+        let id = request.id.clone();
+        match result {
+            Ok(value) => JsonRpcResponse::success(id, value),
+            Err(e) => JsonRpcResponse::from_a2a_error(id, e),
+        }
+    }
+
+    fn card(&self) -> A2AResult<&AgentCard> {
+        self.agent_card.as_ref().ok_or_else(|| {
+            A2AError::unsupported_operation("LocalTransport has no agent card configured")
+        })
+    }
+
+    /// Report a finished call to the observer.
+    fn report(
+        &self,
+        method: &str,
+        streaming: bool,
+        started: Instant,
+        activated: &HashSet<String>,
+        error: Option<String>,
+    ) {
+        if let Some(observer) = &self.observer {
+            observer.on_call(&CallMetrics {
+                method: method.to_string(),
+                url: LOCAL_ENDPOINT.to_string(),
+                streaming,
+                activated_extensions: Some(activated.clone()),
+                elapsed: started.elapsed(),
+                error,
+                ..Default::default()
+            });
+        }
+    }
+}
+
+fn invalid_params(message: String) -> A2AError {
+    A2AError::invalid_params(format!("Invalid params: {message}"))
+}
+
+fn to_json<T: serde::Serialize>(value: T) -> A2AResult<Value> {
+    serde_json::to_value(value).map_err(|e| A2AError::internal_error(e.to_string()))
+}
+
+/// The events of `rx` up to and including the final status update.
+fn events(
+    rx: broadcast::Receiver<StreamResponse>,
+) -> impl futures::Stream<Item = A2AResult<StreamResponse>> {
+    futures::stream::unfold(Some(rx), |rx| async move {
+        let mut rx = rx?;
+        loop {
+            match rx.recv().await {
+                Ok(event) => {
+                    let done =
+                        matches!(&event, StreamResponse::StatusUpdate(update) if update.r#final);
+                    return Some((Ok(event), (!done).then_some(rx)));
+                }
+                Err(broadcast::error::RecvError::Closed) => return None,
+                Err(broadcast::error::RecvError::Lagged(n)) => {
+                    warn!(missed = n, "Local stream lagged — some events were missed");
+                }
+            }
+        }
+    })
+}
+
+#[async_trait]
+impl Transport for LocalTransport {
+    async fn send(&self, request: &JsonRpcRequest) -> A2AResult<JsonRpcResponse> {
+        self.send_with_options(request, &CallOptions::default())
+            .await
+    }
+
+    async fn send_stream(&self, request: &JsonRpcRequest) -> A2AResult<SseStream> {
+        self.send_stream_with_options(request, &CallOptions::default())
+            .await
+    }
+
+    async fn send_with_options(
+        &self,
+        request: &JsonRpcRequest,
+        options: &CallOptions,
+    ) -> A2AResult<JsonRpcResponse> {
+        let started = Instant::now();
+        let params = request.params.clone().unwrap_or(Value::Null);
+        let (result, activated) = match self.call_context(options) {
+            Ok(context) => {
+                let activated = context.activated_extensions.clone();
+                let result = context.scope(self.call(&request.method, params)).await;
+                (result, activated)
+            }
+            Err(e) => (Err(e), HashSet::new()),
+        };
+        Ok(self.respond(request, started, &activated, result))
+    }
+
+    /// Feeds the parts to the handler's
+    /// [`IncrementalMessage`](crate::server::IncrementalMessage) as
+    /// `parts` yields them.
+    async fn send_upload(
+        &self,
+        request: &JsonRpcRequest,
+        parts: PartStream,
+        options: &CallOptions,
+    ) -> A2AResult<JsonRpcResponse> {
+        let started = Instant::now();
+        let params = request.params.clone().unwrap_or(Value::Null);
+        let (result, activated) = match self.call_context(options) {
+            Ok(context) => {
+                let activated = context.activated_extensions.clone();
+                let result = context
+                    .scope(self.upload(&request.method, params, parts))
+                    .await;
+                (result, activated)
+            }
+            Err(e) => (Err(e), HashSet::new()),
+        };
+        Ok(self.respond(request, started, &activated, result))
+    }
+
+    async fn send_stream_with_options(
+        &self,
+        request: &JsonRpcRequest,
+        options: &CallOptions,
+    ) -> A2AResult<SseStream> {
+        let started = Instant::now();
+        let params = request.params.clone().unwrap_or(Value::Null);
+        let context = self.call_context(options)?;
+        let activated = context.activated_extensions.clone();
+        let result = context
+            .scope(self.open_stream(&request.method, params))
+            .await;
+        let error = result.as_ref().err().map(ToString::to_string);
+        self.report(&request.method, true, started, &activated, error);
+        Ok(SseStream::from_stream(events(result?)).with_activated_extensions(activated))
+    }
+
+    /// Notifications for A2A methods are dispatched like calls, others go
+    /// to [`RequestHandler::on_notification`]. As over HTTP, failures are
+    /// only logged.
+    async fn notify_with_options(
+        &self,
+        request: &JsonRpcRequest,
+        options: &CallOptions,
+    ) -> A2AResult<()> {
+        let params = request.params.clone().unwrap_or(Value::Null);
+        let context = self.call_context(options)?;
+        let result = context
+            .scope(async {
+                match self.call(&request.method, params.clone()).await {
+                    Err(A2AError::MethodNotFound { .. }) => self
+                        .handler
+                        .on_notification(&request.method, params)
+                        .await
+                        .map(drop),
+                    result => result.map(drop),
+                }
+            })
+            .await;
+        if let Err(e) = result {
+            debug!(method = %request.method, error = %e, "Notification handler failed");
+        }
+        Ok(())
+    }
+
+    async fn notify(&self, request: &JsonRpcRequest) -> A2AResult<()> {
+        self.notify_with_options(request, &CallOptions::default())
+            .await
+    }
+
+    fn metadata(&self) -> TransportMetadata {
+        let streaming = self
+            .agent_card
+            .as_ref()
+            .map_or(true, |card| card.capabilities.streaming.unwrap_or(false));
+        TransportMetadata::new("LOCAL")
+            .with_endpoint(LOCAL_ENDPOINT)
+            .with_streaming(streaming)
+    }
+
+    fn set_observer(&mut self, observer: Arc<dyn TransportObserver>) {
+        self.observer = Some(observer);
+    }
+}
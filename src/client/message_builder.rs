@@ -0,0 +1,131 @@
+//! Fluent construction of multi-part user messages.
+
+use crate::error::A2AResult;
+use crate::types::{Message, Part, Role};
+
+use super::files::{source_part, FileSource, FileUploader};
+
+/// Builds a user [`Message`] from text, file and data parts.
+///
+/// Files given by path are read when the message is sent with
+/// [`A2AClient::send`](super::A2AClient::send) (or built with
+/// [`build()`](Self::build)), so attaching them can't fail here.
+///
+/// # Example
+///
+/// ```no_run
+/// use a2a_rs::client::{A2AClient, MessageBuilder};
+///
+/// # async fn example(client: A2AClient) -> a2a_rs::A2AResult<()> {
+/// let message = MessageBuilder::new()
+///     .text("Summarize the attached report")
+///     .file("report.pdf", None)
+///     .data(serde_json::json!({"maxWords": 200}))
+///     .context_id("ctx-1");
+/// let response = client.send(message).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MessageBuilder {
+    parts: Vec<PendingPart>,
+    context_id: Option<String>,
+    task_id: Option<String>,
+}
+
+/// A part as added to the builder; files are resolved at send time.
+#[derive(Debug, Clone)]
+enum PendingPart {
+    Ready(Part),
+    File {
+        source: FileSource,
+        mime_type: Option<String>,
+    },
+}
+
+impl MessageBuilder {
+    /// An empty message.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a text part.
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        self.parts.push(PendingPart::Ready(Part::text(text)));
+        self
+    }
+
+    /// Append a file part from a path or bytes. Without `mime_type`, a
+    /// path's is guessed from its extension and bytes are sent as
+    /// `application/octet-stream`.
+    pub fn file(mut self, source: impl Into<FileSource>, mime_type: Option<&str>) -> Self {
+        self.parts.push(PendingPart::File {
+            source: source.into(),
+            mime_type: mime_type.map(String::from),
+        });
+        self
+    }
+
+    /// Append a structured data part.
+    pub fn data(mut self, data: serde_json::Value) -> Self {
+        self.parts.push(PendingPart::Ready(Part::data(data)));
+        self
+    }
+
+    /// Append an already-built part.
+    pub fn part(mut self, part: Part) -> Self {
+        self.parts.push(PendingPart::Ready(part));
+        self
+    }
+
+    /// Continue the conversation in context `context_id`.
+    pub fn context_id(mut self, context_id: impl Into<String>) -> Self {
+        self.context_id = Some(context_id.into());
+        self
+    }
+
+    /// Send the message as the next turn of task `task_id`.
+    pub fn task_id(mut self, task_id: impl Into<String>) -> Self {
+        self.task_id = Some(task_id.into());
+        self
+    }
+
+    /// Build the message, embedding every file inline.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`A2AError::InvalidParams`](crate::error::A2AError::InvalidParams)
+    /// if a file can't be read.
+    pub async fn build(self) -> A2AResult<Message> {
+        self.resolve(u64::MAX, None).await
+    }
+
+    /// Build the message, sending files over `inline_limit` bytes through
+    /// `uploader`.
+    pub(crate) async fn resolve(
+        self,
+        inline_limit: u64,
+        uploader: Option<&dyn FileUploader>,
+    ) -> A2AResult<Message> {
+        let mut parts = Vec::with_capacity(self.parts.len());
+        for part in self.parts {
+            parts.push(match part {
+                PendingPart::Ready(part) => part,
+                PendingPart::File { source, mime_type } => {
+                    source_part(source, mime_type.as_deref(), inline_limit, uploader).await?
+                }
+            });
+        }
+        Ok(Message {
+            message_id: uuid::Uuid::new_v4().to_string(),
+            role: Role::User,
+            kind: "message".to_string(),
+            parts,
+            context_id: self.context_id,
+            task_id: self.task_id,
+            reference_task_ids: None,
+            metadata: None,
+            extensions: None,
+        })
+    }
+}
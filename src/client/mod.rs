@@ -5,8 +5,53 @@
 //! - [`A2AClient`] — high-level client with typed methods for every A2A
 //!   JSON-RPC operation (send messages, get/cancel tasks, subscribe to streams)
 //! - [`CardResolver`] — discover agent cards via the well-known URL convention
-//! - [`Transport`] / [`JsonRpcTransport`] — pluggable transport layer
+//! - [`Conversation`] — multi-turn exchanges in one `contextId`, continuing
+//!   open tasks and threading `referenceTaskIds` into new ones
+//! - [`AgentCardCache`] — resolved cards kept for a TTL, then revalidated
+//!   with `ETag` / `If-Modified-Since`; optionally persisted to disk
+//! - [`Transport`] / [`JsonRpcTransport`] — pluggable, object-safe transport
+//!   layer: unary and server-stream calls, connect/close lifecycle and
+//!   [`TransportMetadata`]; response ids are checked against request ids
+//!   per [`IdCorrelation`]
+//! - `GrpcTransport` — the A2A gRPC binding (`grpc` feature)
+//! - `LocalTransport` — calls a server `RequestHandler` in-process, for
+//!   testing agents without sockets (`server` feature)
 //! - [`SseStream`] — parsed SSE event stream for streaming responses
+//! - [`NdjsonStream`] — the newline-delimited JSON alternative to SSE,
+//!   negotiated with `Accept` per [`TransportConfig::stream_encoding`]
+//! - [`ReconnectingSseStream`] + [`SseReconnectPolicy`] — resume streams
+//!   after dropped connections with `Last-Event-ID` and backoff
+//! - [`RetryPolicy`] — retry idempotent calls failing with transient
+//!   errors, with exponential backoff and jitter
+//! - [`ClientError`] — errors sorted into transport, protocol, agent,
+//!   discovery and timeout failures, with the raw response and whether
+//!   retrying may help
+//! - [`diagnose()`] / [`DecodeDiagnostic`] — field path, expected type and
+//!   payload excerpt for responses that don't match the schema
+//! - [`CredentialService`] — per-request credentials, with transparent
+//!   stream reconnects before token expiry; [`AuthProvider`]s
+//!   ([`ApiKeyAuth`], [`BearerAuth`], [`OAuth2ClientCredentials`]) and an
+//!   [`Authenticator`] that picks one from the card's security schemes
+//! - [`TransportObserver`] — per-call metrics hook (connection timings with
+//!   the `metrics` feature) and [`TransportEvent`] lifecycle events
+//! - [`TransportInterceptor`] — ordered chain of hooks around every
+//!   JSON-RPC call and stream establishment: log, trace or rewrite the
+//!   method, params, headers and response
+//! - [`StreamInterceptor`] — drop, modify or tap streaming events before
+//!   they reach the consumer
+//! - [`ArtifactAggregator`] — reassemble artifacts whose chunks are
+//!   interleaved in one stream
+//! - [`StreamCollector`] — the agent's streamed text as one string or as
+//!   [`TextDelta`]s; [`A2AClient::send_text_collect`] for the simple case
+//! - [`A2AClient::wait_for_completion`] + [`PollOptions`] — wait for a
+//!   task to finish, streaming or polling with backoff
+//! - [`FileUploader`] — send large attachments of
+//!   [`A2AClient::send_text_with_files`] by URI instead of inline
+//! - [`ArtifactDownloader`] — fetch URI file parts of received artifacts
+//!   into memory or to disk, with size limits and MIME type checks
+//! - [`MessageBuilder`] — text, file ([`FileSource`]) and data parts in
+//!   one message, sent with [`A2AClient::send`]; [`A2AClient::send_file`]
+//!   and [`A2AClient::send_data`] for the single-part cases
 //!
 //! # Quick Start
 //!
@@ -38,13 +83,61 @@
 //! ```
 
 mod a2a_client;
+mod artifacts;
+mod auth;
+mod card_cache;
 mod card_resolver;
+mod collector;
+mod conversation;
+mod diagnostics;
+mod downloads;
+mod errors;
+mod files;
+#[cfg(feature = "grpc")]
+mod grpc_transport;
+mod interceptor;
+#[cfg(feature = "server")]
+mod local_transport;
+mod message_builder;
+mod ndjson;
+mod observer;
+mod polling;
+mod reconnect;
+mod retry;
 mod sse;
+mod stream_interceptor;
 mod transport;
 
 pub use a2a_client::{create_text_message, A2AClient};
+pub use artifacts::{ArtifactAggregator, ArtifactStatus};
+pub use auth::{
+    ApiKeyAuth, AuthProvider, Authenticator, BearerAuth, Credential, CredentialService,
+    OAuth2ClientCredentials, DEFAULT_API_KEY_HEADER, DEFAULT_TOKEN_REFRESH_MARGIN,
+};
 // Re-export from types for backward compat — previously this was a duplicate enum.
 pub use crate::types::SendMessageResponse;
+pub use card_cache::{AgentCardCache, CachedCard, DEFAULT_CARD_TTL};
 pub use card_resolver::CardResolver;
+pub use collector::{StreamCollector, TextDelta};
+pub use conversation::Conversation;
+pub use diagnostics::{diagnose, DecodeDiagnostic, EXCERPT_LIMIT};
+pub use downloads::{ArtifactDownloader, DEFAULT_MAX_DOWNLOAD_SIZE};
+pub use errors::{ClientError, ClientResult, RawResponse};
+pub use files::{FileSource, FileUploader, DEFAULT_INLINE_FILE_LIMIT};
+#[cfg(feature = "grpc")]
+pub use grpc_transport::GrpcTransport;
+pub use interceptor::{InterceptedCall, TransportInterceptor};
+#[cfg(feature = "server")]
+pub use local_transport::LocalTransport;
+pub use message_builder::MessageBuilder;
+pub use ndjson::NdjsonStream;
+pub use observer::{CallMetrics, TransportEvent, TransportObserver};
+pub use polling::{PollOptions, DEFAULT_MAX_POLL_INTERVAL, DEFAULT_POLL_INTERVAL};
+pub use reconnect::{ReconnectingSseStream, SseReconnectPolicy, LAST_EVENT_ID_HEADER};
+pub use retry::{RetryPolicy, DEFAULT_IDEMPOTENT_METHODS};
 pub use sse::{SseStream, SseStreamAdapter};
-pub use transport::{JsonRpcTransport, Transport, TransportConfig};
+pub use stream_interceptor::StreamInterceptor;
+pub use transport::{
+    CallOptions, Http2Settings, IdCorrelation, JsonRpcTransport, PartStream, Transport,
+    TransportConfig, TransportMetadata,
+};
@@ -0,0 +1,176 @@
+//! Newline-delimited JSON streams — the non-SSE streaming encoding.
+//!
+//! Behind proxies that strip or buffer SSE, servers can stream JSON-RPC
+//! responses as one JSON document per line (`application/x-ndjson`) over a
+//! chunked response instead; see
+//! [`StreamEncoding`](crate::utils::StreamEncoding). Ask for it with
+//! [`TransportConfig::stream_encoding`](super::TransportConfig::stream_encoding)
+//! (or [`ClientBuilder::with_stream_encoding`](crate::builders::ClientBuilder::with_stream_encoding)).
+//! Whichever encoding the server answers in, streaming calls return the
+//! same [`SseStream`], with the same interceptors and helpers.
+
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use bytes::Bytes;
+use futures::stream::Stream;
+use tokio::sync::mpsc;
+
+use super::sse::{parse_event_data, SseStream};
+use super::transport::ResponseIdCheck;
+use crate::error::{A2AError, A2AResult};
+use crate::types::StreamResponse;
+
+/// Events parsed from a newline-delimited JSON body.
+///
+/// Each non-empty line is a [`StreamResponse`], bare or wrapped in a
+/// JSON-RPC response; empty lines are keep-alives. A body ending without a
+/// newline still yields its last line. After an error the stream ends.
+///
+/// # Example
+///
+/// ```
+/// use a2a_rs::client::NdjsonStream;
+/// use futures::StreamExt;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let body = r#"{"jsonrpc":"2.0","id":1,"result":{"kind":"message","messageId":"m1","role":"agent","parts":[{"kind":"text","text":"hi"}]}}
+///
+/// "#;
+/// let chunks = vec![Ok::<_, std::io::Error>(bytes::Bytes::from(body))];
+/// let events: Vec<_> = NdjsonStream::new(futures::stream::iter(chunks)).collect().await;
+/// assert_eq!(events.len(), 1);
+/// # }
+/// ```
+pub struct NdjsonStream {
+    body: Pin<Box<dyn Stream<Item = Result<Bytes, String>> + Send>>,
+    ids: ResponseIdCheck,
+    /// Bytes of the current, incomplete line.
+    buffer: Vec<u8>,
+    done: bool,
+}
+
+impl std::fmt::Debug for NdjsonStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("NdjsonStream").finish_non_exhaustive()
+    }
+}
+
+impl NdjsonStream {
+    /// Parse the chunks of `body`.
+    pub fn new<S, E>(body: S) -> Self
+    where
+        S: Stream<Item = Result<Bytes, E>> + Send + 'static,
+        E: std::fmt::Display,
+    {
+        Self::with_ids(body, ResponseIdCheck::unchecked())
+    }
+
+    /// Parse the chunks of `body`, verifying the ids of JSON-RPC envelopes
+    /// with `ids`.
+    pub(crate) fn with_ids<S, E>(body: S, ids: ResponseIdCheck) -> Self
+    where
+        S: Stream<Item = Result<Bytes, E>> + Send + 'static,
+        E: std::fmt::Display,
+    {
+        use futures::StreamExt;
+
+        Self {
+            body: Box::pin(body.map(|chunk| chunk.map_err(|e| e.to_string()))),
+            ids,
+            buffer: Vec::new(),
+            done: false,
+        }
+    }
+
+    /// Parse the next complete line in the buffer; `Ok(None)` if there is
+    /// none or it is empty.
+    fn next_line(&mut self, flush: bool) -> Option<A2AResult<Option<StreamResponse>>> {
+        let end = match self.buffer.iter().position(|&b| b == b'\n') {
+            Some(end) => end,
+            None if flush && !self.buffer.is_empty() => self.buffer.len(),
+            None => return None,
+        };
+        let line: Vec<u8> = self.buffer.drain(..end).collect();
+        if !self.buffer.is_empty() {
+            // The newline.
+            self.buffer.remove(0);
+        }
+        let line = match std::str::from_utf8(&line) {
+            Ok(line) => line.trim(),
+            Err(e) => {
+                return Some(Err(A2AError::Transport(format!(
+                    "invalid UTF-8 in NDJSON stream: {e}"
+                ))))
+            }
+        };
+        if line.is_empty() {
+            return Some(Ok(None));
+        }
+        Some(parse_event_data(line, "NDJSON", &self.ids).map(Some))
+    }
+
+    /// Wrap a streaming HTTP response in an [`SseStream`], like
+    /// [`SseStream::from_response`]. `guard` is dropped when the body
+    /// ends.
+    pub(crate) fn into_sse_stream<G: Send + 'static>(
+        response: reqwest::Response,
+        guard: G,
+        ids: ResponseIdCheck,
+    ) -> SseStream {
+        use futures::StreamExt;
+
+        let activated = super::transport::activated_extensions(response.headers());
+        let mut events = Self::with_ids(response.bytes_stream(), ids);
+        let (tx, rx) = mpsc::channel(64);
+        let task = tokio::spawn(async move {
+            let _guard = guard;
+            while let Some(event) = events.next().await {
+                if tx.send(event).await.is_err() {
+                    return;
+                }
+            }
+        });
+        SseStream::from_channel(rx, task).with_activated_extensions(activated)
+    }
+}
+
+impl Stream for NdjsonStream {
+    type Item = A2AResult<StreamResponse>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            if self.done {
+                return Poll::Ready(None);
+            }
+            while let Some(parsed) = self.next_line(false) {
+                match parsed {
+                    Ok(Some(event)) => return Poll::Ready(Some(Ok(event))),
+                    Ok(None) => {}
+                    Err(e) => {
+                        self.done = true;
+                        return Poll::Ready(Some(Err(e)));
+                    }
+                }
+            }
+            match std::task::ready!(self.body.as_mut().poll_next(cx)) {
+                Some(Ok(chunk)) => self.buffer.extend_from_slice(&chunk),
+                Some(Err(e)) => {
+                    self.done = true;
+                    return Poll::Ready(Some(Err(A2AError::Transport(format!(
+                        "error reading NDJSON stream: {e}"
+                    )))));
+                }
+                None => {
+                    self.done = true;
+                    return match self.next_line(true) {
+                        Some(Ok(Some(event))) => Poll::Ready(Some(Ok(event))),
+                        Some(Err(e)) => Poll::Ready(Some(Err(e))),
+                        Some(Ok(None)) | None => Poll::Ready(None),
+                    };
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,272 @@
+//! Per-call observability for the client transport.
+//!
+//! A [`TransportObserver`] attached to a [`JsonRpcTransport`](super::JsonRpcTransport)
+//! is notified once per JSON-RPC call with a [`CallMetrics`] record: method,
+//! endpoint, HTTP status, time-to-response and — with the `metrics` feature —
+//! connection-level timings (DNS resolution, connection establishment) and
+//! whether a pooled connection was reused.
+//!
+//! This makes it possible to tell a slow downstream agent (large `elapsed`,
+//! reused connection) from a slow network (large `dns` / `connect`).
+//!
+//! Observers also receive [`TransportEvent`]s for the connection lifecycle:
+//! the transport becoming reachable or unreachable, and streams opening and
+//! closing.
+
+use std::collections::HashSet;
+use std::time::Duration;
+
+/// Metrics for a single outbound JSON-RPC call.
+///
+/// Connection-level fields are `None` unless the `metrics` feature is
+/// enabled and a new connection was opened for this call.
+#[derive(Debug, Clone, Default)]
+pub struct CallMetrics {
+    /// JSON-RPC method name (e.g. `"message/send"`).
+    pub method: String,
+
+    /// Endpoint URL the request was sent to.
+    pub url: String,
+
+    /// `true` for streaming (`message/stream`, `tasks/resubscribe`) calls.
+    pub streaming: bool,
+
+    /// HTTP status code, if a response was received.
+    pub status: Option<u16>,
+
+    /// Extensions the server activated (`X-A2A-Extensions` response
+    /// header), if a response was received.
+    pub activated_extensions: Option<HashSet<String>>,
+
+    /// Time from sending the request until response headers arrived
+    /// (for unary calls, until the body was read).
+    pub elapsed: Duration,
+
+    /// DNS resolution time, if a lookup was performed for this call.
+    ///
+    /// IP-literal URLs never trigger a lookup.
+    pub dns: Option<Duration>,
+
+    /// Time to establish a new connection (DNS + TCP + TLS handshake).
+    ///
+    /// The underlying HTTP stack does not expose the TLS handshake as a
+    /// separate phase, so it is included here.
+    pub connect: Option<Duration>,
+
+    /// Whether the call ran over a pooled connection. `None` when
+    /// connection tracking is unavailable (`metrics` feature disabled).
+    pub reused_connection: Option<bool>,
+
+    /// Error description, if the call failed at the transport level.
+    pub error: Option<String>,
+}
+
+/// Hook notified after every outbound call made by a transport.
+///
+/// Implementations must be cheap and non-blocking — they run inline on the
+/// request path. Forward to your metrics backend or a channel.
+///
+/// # Example
+///
+/// ```
+/// use a2a_rs::client::{CallMetrics, TransportObserver};
+///
+/// struct LogObserver;
+///
+/// impl TransportObserver for LogObserver {
+///     fn on_call(&self, metrics: &CallMetrics) {
+///         println!("{} took {:?}", metrics.method, metrics.elapsed);
+///     }
+/// }
+/// ```
+pub trait TransportObserver: Send + Sync {
+    /// Called once per call, after the response (or error) is known.
+    fn on_call(&self, metrics: &CallMetrics);
+
+    /// Called on connection lifecycle changes. The default ignores them.
+    fn on_event(&self, event: &TransportEvent) {
+        let _ = event;
+    }
+}
+
+/// Connection lifecycle event reported to [`TransportObserver::on_event`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TransportEvent {
+    /// The endpoint became reachable: [`connect()`](super::Transport::connect)
+    /// was called, or the first response arrived (again, after a
+    /// disconnect).
+    Connected {
+        /// Endpoint the transport talks to.
+        endpoint: String,
+    },
+    /// The endpoint became unreachable, or the transport was closed.
+    Disconnected {
+        /// Endpoint the transport talks to.
+        endpoint: String,
+        /// Why the connection was lost; `None` after
+        /// [`close()`](super::Transport::close).
+        reason: Option<String>,
+    },
+    /// A server-stream call started delivering events.
+    StreamOpened {
+        /// JSON-RPC method of the stream (e.g. `"message/stream"`).
+        method: String,
+    },
+    /// A server-stream call ended: the server finished it, it failed, or
+    /// the consumer dropped it.
+    StreamClosed {
+        /// JSON-RPC method of the stream.
+        method: String,
+    },
+}
+
+#[cfg(feature = "metrics")]
+pub(crate) use instrumentation::ConnectionStats;
+
+/// Connection-level instrumentation hooked into the `reqwest` client via a
+/// timing DNS resolver and a connector layer.
+#[cfg(feature = "metrics")]
+mod instrumentation {
+    use std::future::Future;
+    use std::net::SocketAddr;
+    use std::pin::Pin;
+    use std::sync::{Arc, Mutex};
+    use std::task::{Context, Poll};
+    use std::time::{Duration, Instant};
+
+    use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+    use tower::{Layer, Service};
+
+    #[derive(Debug, Default, Clone, Copy)]
+    struct Snapshot {
+        connects: u64,
+        last_dns: Option<Duration>,
+        last_connect: Option<Duration>,
+    }
+
+    /// Counters shared between a transport and the instrumented client.
+    ///
+    /// Each transport owns one client talking to one endpoint, so a new
+    /// connection observed while a call is in flight is attributed to that
+    /// call. Under heavy concurrency the attribution is best-effort.
+    #[derive(Debug, Default, Clone)]
+    pub(crate) struct ConnectionStats {
+        inner: Arc<Mutex<Snapshot>>,
+    }
+
+    impl ConnectionStats {
+        /// Number of connections opened so far — take before a call.
+        pub(crate) fn connects(&self) -> u64 {
+            self.inner.lock().unwrap().connects
+        }
+
+        /// Fill connection fields of `metrics` for a call that started when
+        /// the counter was at `before`.
+        pub(crate) fn fill(&self, before: u64, metrics: &mut super::CallMetrics) {
+            let snap = *self.inner.lock().unwrap();
+            if snap.connects > before {
+                metrics.reused_connection = Some(false);
+                metrics.dns = snap.last_dns;
+                metrics.connect = snap.last_connect;
+            } else {
+                metrics.reused_connection = Some(true);
+            }
+        }
+
+        fn record_dns(&self, elapsed: Duration) {
+            self.inner.lock().unwrap().last_dns = Some(elapsed);
+        }
+
+        fn record_connect(&self, elapsed: Duration) {
+            let mut snap = self.inner.lock().unwrap();
+            snap.connects += 1;
+            snap.last_connect = Some(elapsed);
+        }
+
+        /// Apply the timing resolver and connector layer to a client builder.
+        pub(crate) fn instrument(&self, builder: reqwest::ClientBuilder) -> reqwest::ClientBuilder {
+            builder
+                .dns_resolver(Arc::new(TimingResolver {
+                    stats: self.clone(),
+                }))
+                .connector_layer(TimingLayer {
+                    stats: self.clone(),
+                })
+        }
+
+        /// Forget the previous lookup so an IP-literal connection (which
+        /// skips the resolver) doesn't report a stale DNS time.
+        fn begin_connect(&self) {
+            self.inner.lock().unwrap().last_dns = None;
+        }
+    }
+
+    /// DNS resolver that times system lookups.
+    struct TimingResolver {
+        stats: ConnectionStats,
+    }
+
+    impl Resolve for TimingResolver {
+        fn resolve(&self, name: Name) -> Resolving {
+            let stats = self.stats.clone();
+            Box::pin(async move {
+                let started = Instant::now();
+                let addrs: Vec<SocketAddr> =
+                    tokio::net::lookup_host((name.as_str(), 0)).await?.collect();
+                stats.record_dns(started.elapsed());
+                Ok(Box::new(addrs.into_iter()) as Addrs)
+            })
+        }
+    }
+
+    /// Connector layer that times new connection establishment.
+    #[derive(Clone)]
+    struct TimingLayer {
+        stats: ConnectionStats,
+    }
+
+    impl<S> Layer<S> for TimingLayer {
+        type Service = TimingConnector<S>;
+
+        fn layer(&self, inner: S) -> Self::Service {
+            TimingConnector {
+                inner,
+                stats: self.stats.clone(),
+            }
+        }
+    }
+
+    #[derive(Clone)]
+    struct TimingConnector<S> {
+        inner: S,
+        stats: ConnectionStats,
+    }
+
+    impl<S, R> Service<R> for TimingConnector<S>
+    where
+        S: Service<R>,
+        S::Future: Send + 'static,
+    {
+        type Response = S::Response;
+        type Error = S::Error;
+        type Future = Pin<Box<dyn Future<Output = Result<S::Response, S::Error>> + Send>>;
+
+        fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            self.inner.poll_ready(cx)
+        }
+
+        fn call(&mut self, req: R) -> Self::Future {
+            let stats = self.stats.clone();
+            stats.begin_connect();
+            let started = Instant::now();
+            let fut = self.inner.call(req);
+            Box::pin(async move {
+                let result = fut.await;
+                if result.is_ok() {
+                    stats.record_connect(started.elapsed());
+                }
+                result
+            })
+        }
+    }
+}
@@ -0,0 +1,265 @@
+//! Waiting for a task to finish.
+//!
+//! [`A2AClient::wait_for_completion`] replaces hand-rolled `tasks/get`
+//! loops. When the agent card advertises streaming it first follows the
+//! task over `tasks/resubscribe`; otherwise, or if the stream can't be
+//! opened or ends early, it polls `tasks/get` with exponential backoff.
+//! Either way it returns the task once it is terminal or waiting on the
+//! user (`input-required`, `auth-required`), and turns `failed` and
+//! `rejected` into [`A2AError::TaskFailed`] / [`A2AError::TaskRejected`].
+//!
+//! ```no_run
+//! # use std::time::Duration;
+//! # use a2a_rs::client::{A2AClient, PollOptions};
+//! # async fn example(client: A2AClient) -> a2a_rs::A2AResult<()> {
+//! let options = PollOptions::new().with_timeout(Duration::from_secs(300));
+//! let task = client.wait_for_completion("task-123", &options).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::time::Duration;
+
+use tracing::debug;
+
+use crate::error::{A2AError, A2AResult};
+use crate::types::{StreamResponse, Task, TaskState};
+use crate::utils::get_message_text;
+
+use super::a2a_client::A2AClient;
+
+/// Delay before the second `tasks/get` when none is configured.
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Longest delay between polls when none is configured.
+pub const DEFAULT_MAX_POLL_INTERVAL: Duration = Duration::from_secs(10);
+
+/// How [`A2AClient::wait_for_completion`] waits.
+///
+/// ```
+/// use std::time::Duration;
+/// use a2a_rs::client::PollOptions;
+///
+/// let options = PollOptions::new()
+///     .with_interval(Duration::from_millis(100))
+///     .with_backoff(1.5)
+///     .with_timeout(Duration::from_secs(60));
+/// assert_eq!(options.max_interval, Duration::from_secs(10));
+/// ```
+#[derive(Debug, Clone)]
+pub struct PollOptions {
+    /// Delay between the first two polls.
+    pub interval: Duration,
+
+    /// Factor by which the delay grows after each poll (at least 1).
+    pub backoff: f64,
+
+    /// Upper bound for the delay.
+    pub max_interval: Duration,
+
+    /// Give up with [`A2AError::Timeout`] after this long; `None` waits
+    /// indefinitely.
+    pub timeout: Option<Duration>,
+
+    /// Follow the task over `tasks/resubscribe` when the agent card
+    /// advertises streaming.
+    pub streaming: bool,
+}
+
+impl Default for PollOptions {
+    fn default() -> Self {
+        Self {
+            interval: DEFAULT_POLL_INTERVAL,
+            backoff: 2.0,
+            max_interval: DEFAULT_MAX_POLL_INTERVAL,
+            timeout: None,
+            streaming: true,
+        }
+    }
+}
+
+impl PollOptions {
+    /// Default options: 500 ms doubling up to 10 s, no timeout, streaming
+    /// when available.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Wait `interval` between the first two polls.
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Multiply the delay by `factor` after each poll; `1.0` polls at a
+    /// fixed interval.
+    pub fn with_backoff(mut self, factor: f64) -> Self {
+        self.backoff = factor;
+        self
+    }
+
+    /// Never wait longer than `max_interval` between polls.
+    pub fn with_max_interval(mut self, max_interval: Duration) -> Self {
+        self.max_interval = max_interval;
+        self
+    }
+
+    /// Give up after `timeout`.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Whether to follow the task over `tasks/resubscribe` when possible.
+    pub fn with_streaming(mut self, streaming: bool) -> Self {
+        self.streaming = streaming;
+        self
+    }
+
+    /// The delay after `delay`.
+    fn next_delay(&self, delay: Duration) -> Duration {
+        let cap = self.max_interval.max(self.interval);
+        let secs = delay.as_secs_f64() * self.backoff.max(1.0);
+        if secs >= cap.as_secs_f64() {
+            cap
+        } else {
+            Duration::from_secs_f64(secs)
+        }
+    }
+}
+
+/// Whether waiting for a task in `state` is over.
+fn is_settled(state: &TaskState) -> bool {
+    matches!(
+        state,
+        TaskState::Completed
+            | TaskState::Failed
+            | TaskState::Canceled
+            | TaskState::Rejected
+            | TaskState::InputRequired
+            | TaskState::AuthRequired
+    )
+}
+
+/// `task`, or the typed error for a failed or rejected one.
+fn into_outcome(task: Task) -> A2AResult<Task> {
+    let message = || {
+        task.status
+            .message
+            .as_ref()
+            .map(|message| get_message_text(message, " "))
+            .filter(|text| !text.is_empty())
+            .unwrap_or_else(|| "no reason given".to_string())
+    };
+    match task.status.state {
+        TaskState::Failed => Err(A2AError::TaskFailed {
+            message: message(),
+            task: Box::new(task),
+        }),
+        TaskState::Rejected => Err(A2AError::TaskRejected {
+            message: message(),
+            task: Box::new(task),
+        }),
+        _ => Ok(task),
+    }
+}
+
+impl A2AClient {
+    /// Wait until task `task_id` is terminal or needs the user, and return
+    /// it.
+    ///
+    /// Returns [`A2AError::TaskFailed`] or [`A2AError::TaskRejected`] for
+    /// tasks that ended that way, [`A2AError::Timeout`] when
+    /// [`PollOptions::timeout`] passes first, and any error of `tasks/get`.
+    /// Canceled tasks are returned like completed ones.
+    pub async fn wait_for_completion(
+        &self,
+        task_id: &str,
+        options: &PollOptions,
+    ) -> A2AResult<Task> {
+        let wait = async {
+            if options.streaming && self.streaming_supported() {
+                match self.follow_stream(task_id).await {
+                    Ok(Some(task)) => return Ok(task),
+                    Ok(None) => debug!(task_id, "Stream ended early; polling"),
+                    Err(e) => debug!(task_id, error = %e, "Cannot follow task; polling"),
+                }
+            }
+            self.poll(task_id, options).await
+        };
+        let task = match options.timeout {
+            Some(timeout) => tokio::time::timeout(timeout, wait).await.map_err(|_| {
+                A2AError::Timeout(format!("task {task_id} did not finish within {timeout:?}"))
+            })??,
+            None => wait.await?,
+        };
+        into_outcome(task)
+    }
+
+    /// Whether the agent card says the agent streams.
+    fn streaming_supported(&self) -> bool {
+        self.get_card()
+            .is_ok_and(|card| card.capabilities.streaming == Some(true))
+    }
+
+    /// Follow the task's stream until it settles; `None` if the stream
+    /// ends first.
+    async fn follow_stream(&self, task_id: &str) -> A2AResult<Option<Task>> {
+        let mut stream = self.resubscribe_by_id(task_id).await?;
+        while let Some(event) = stream.next().await {
+            let settled = match event? {
+                StreamResponse::Task(task) if is_settled(&task.status.state) => true,
+                StreamResponse::StatusUpdate(update) => is_settled(&update.status.state),
+                _ => false,
+            };
+            if settled {
+                // The full task, with every artifact and the history.
+                return self.get_task_by_id(task_id, None).await.map(Some);
+            }
+        }
+        Ok(None)
+    }
+
+    /// Poll `tasks/get` with backoff until the task settles.
+    async fn poll(&self, task_id: &str, options: &PollOptions) -> A2AResult<Task> {
+        let mut delay = options.interval;
+        loop {
+            let task = self.get_task_by_id(task_id, None).await?;
+            if is_settled(&task.status.state) {
+                return Ok(task);
+            }
+            tokio::time::sleep(delay).await;
+            delay = options.next_delay(delay);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_delay_backs_off_to_max() {
+        let options = PollOptions::new()
+            .with_interval(Duration::from_millis(100))
+            .with_max_interval(Duration::from_millis(300));
+        let mut delay = options.interval;
+        let mut delays = Vec::new();
+        for _ in 0..4 {
+            delay = options.next_delay(delay);
+            delays.push(delay.as_millis());
+        }
+        assert_eq!(delays, [200, 300, 300, 300]);
+    }
+
+    #[test]
+    fn test_backoff_below_one_polls_at_fixed_interval() {
+        let options = PollOptions::new()
+            .with_interval(Duration::from_millis(100))
+            .with_backoff(0.5);
+        assert_eq!(
+            options.next_delay(options.interval),
+            Duration::from_millis(100)
+        );
+    }
+}
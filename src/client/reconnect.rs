@@ -0,0 +1,316 @@
+//! Reconnecting SSE streams.
+//!
+//! A [`ReconnectingSseStream`] follows a task across dropped connections:
+//! when the underlying stream fails with a transient error (a transport
+//! error, a timeout, or HTTP 408 / 429 / 5xx) before the task's final
+//! event, it reopens the task's stream with `tasks/resubscribe`, sending
+//! the `Last-Event-ID` of the last event received so the server can
+//! replay what was missed. Replayed events the consumer has already seen
+//! are dropped. Only once [`SseReconnectPolicy::max_retries`] consecutive
+//! attempts have failed does the stream yield an error.
+//!
+//! [`JsonRpcTransport`](super::JsonRpcTransport) streams reconnect when
+//! configured with
+//! [`JsonRpcTransport::with_sse_reconnect`](super::JsonRpcTransport::with_sse_reconnect)
+//! or [`ClientBuilder::with_sse_reconnect`](crate::builders::ClientBuilder::with_sse_reconnect):
+//!
+//! ```no_run
+//! # use std::time::Duration;
+//! # use a2a_rs::builders::ClientBuilder;
+//! # use a2a_rs::client::SseReconnectPolicy;
+//! # async fn example() -> a2a_rs::A2AResult<()> {
+//! let client = ClientBuilder::new("http://localhost:7420")
+//!     .with_sse_reconnect(SseReconnectPolicy::new().with_max_retries(10))
+//!     .build()
+//!     .await?;
+//! let mut stream = client.send_text_stream("Write a novel").await?;
+//! while let Some(event) = stream.next().await {
+//!     println!("{:?}", event?);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashSet, VecDeque};
+use std::future::Future;
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use tokio::sync::mpsc;
+use tracing::debug;
+
+use crate::error::{A2AError, A2AResult};
+use crate::types::StreamResponse;
+
+use super::sse::SseStream;
+use super::transport::event_task_id;
+
+/// HTTP header carrying the ID of the last event received when a stream
+/// is reopened.
+pub const LAST_EVENT_ID_HEADER: &str = "Last-Event-ID";
+
+/// How many recently delivered events are remembered to recognize replays.
+const REPLAY_WINDOW: usize = 1024;
+
+/// When and how often a [`ReconnectingSseStream`] reconnects.
+///
+/// ```
+/// use std::time::Duration;
+/// use a2a_rs::client::SseReconnectPolicy;
+///
+/// let policy = SseReconnectPolicy::new()
+///     .with_max_retries(3)
+///     .with_initial_backoff(Duration::from_millis(100))
+///     .with_backoff(1.5);
+/// assert_eq!(policy.max_backoff, Duration::from_secs(10));
+/// ```
+#[derive(Debug, Clone)]
+pub struct SseReconnectPolicy {
+    /// Consecutive failed reconnect attempts after which the stream gives
+    /// up and yields the last error. `0` disables reconnecting.
+    pub max_retries: u32,
+
+    /// Delay before the first reconnect attempt.
+    pub initial_backoff: Duration,
+
+    /// Factor by which the delay grows after each failed attempt (at
+    /// least 1).
+    pub backoff: f64,
+
+    /// Upper bound for the delay.
+    pub max_backoff: Duration,
+}
+
+impl Default for SseReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 5,
+            initial_backoff: Duration::from_millis(250),
+            backoff: 2.0,
+            max_backoff: Duration::from_secs(10),
+        }
+    }
+}
+
+impl SseReconnectPolicy {
+    /// Default policy: up to 5 attempts, 250 ms doubling up to 10 s.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Give up after `retries` consecutive failed attempts.
+    pub fn with_max_retries(mut self, retries: u32) -> Self {
+        self.max_retries = retries;
+        self
+    }
+
+    /// Wait `delay` before the first attempt.
+    pub fn with_initial_backoff(mut self, delay: Duration) -> Self {
+        self.initial_backoff = delay;
+        self
+    }
+
+    /// Multiply the delay by `factor` after each failed attempt; `1.0`
+    /// retries at a fixed interval.
+    pub fn with_backoff(mut self, factor: f64) -> Self {
+        self.backoff = factor;
+        self
+    }
+
+    /// Never wait longer than `delay` between attempts.
+    pub fn with_max_backoff(mut self, delay: Duration) -> Self {
+        self.max_backoff = delay;
+        self
+    }
+
+    /// The delay before attempt number `attempt` (counting from 0).
+    fn delay(&self, attempt: u32) -> Duration {
+        let cap = self.max_backoff.max(self.initial_backoff);
+        let factor = self.backoff.max(1.0).powi(attempt.min(64) as i32);
+        let secs = self.initial_backoff.as_secs_f64() * factor;
+        if secs >= cap.as_secs_f64() {
+            cap
+        } else {
+            Duration::from_secs_f64(secs)
+        }
+    }
+}
+
+/// Whether `error` may go away by reconnecting: a transport error, a
+/// timeout, or HTTP 408, 429 or 5xx.
+pub(crate) fn is_transient(error: &A2AError) -> bool {
+    match error {
+        A2AError::Transport(_) | A2AError::Timeout(_) => true,
+        A2AError::Http { status, .. } => matches!(status, 408 | 429 | 500..=599),
+        _ => false,
+    }
+}
+
+/// An [`SseStream`] that reopens itself after transient failures.
+///
+/// `reopen` is called with the task ID and the last event ID received (if
+/// the server sent any) and should return a fresh stream of the task,
+/// typically from `tasks/resubscribe` with a [`LAST_EVENT_ID_HEADER`].
+/// Events are de-duplicated across reconnects, so a server that replays
+/// from the start or from before `Last-Event-ID` is handled too.
+///
+/// Streams that end cleanly, haven't revealed a task ID yet, or fail with
+/// a non-transient error (including from `reopen`) end as an `SseStream`
+/// would.
+pub struct ReconnectingSseStream {
+    inner: SseStream,
+    reconnects: Arc<AtomicU32>,
+}
+
+impl std::fmt::Debug for ReconnectingSseStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ReconnectingSseStream")
+            .field("reconnects", &self.reconnects())
+            .finish_non_exhaustive()
+    }
+}
+
+impl ReconnectingSseStream {
+    /// Follow `stream`, reopening it with `reopen` as `policy` allows.
+    pub fn new<F, Fut>(mut stream: SseStream, policy: SseReconnectPolicy, mut reopen: F) -> Self
+    where
+        F: FnMut(String, Option<String>) -> Fut + Send + 'static,
+        Fut: Future<Output = A2AResult<SseStream>> + Send,
+    {
+        let (tx, rx) = mpsc::channel(64);
+        let activated = stream.activated_extensions().clone();
+        let last_event_id = Arc::new(Mutex::new(stream.last_event_id()));
+        let reconnects = Arc::new(AtomicU32::new(0));
+
+        let slot = last_event_id.clone();
+        let counter = reconnects.clone();
+        let task = tokio::spawn(async move {
+            let mut task_id: Option<String> = None;
+            let mut delivered = ReplayFilter::default();
+            let mut replaying = false;
+            let mut attempt = 0;
+            loop {
+                let error = match stream.next().await {
+                    None => return,
+                    Some(Ok(event)) => {
+                        attempt = 0;
+                        if let Some(id) = stream.last_event_id() {
+                            *slot.lock().unwrap() = Some(id);
+                        }
+                        if task_id.is_none() {
+                            task_id = event_task_id(&event);
+                        }
+                        if !delivered.insert(&event) && replaying {
+                            debug!("dropping event replayed after reconnect");
+                            continue;
+                        }
+                        let done = match &event {
+                            StreamResponse::StatusUpdate(e) => e.r#final,
+                            StreamResponse::Message(_) => true,
+                            _ => false,
+                        };
+                        if tx.send(Ok(event)).await.is_err() || done {
+                            return;
+                        }
+                        continue;
+                    }
+                    Some(Err(e)) => e,
+                };
+                let Some(id) = task_id.clone().filter(|_| is_transient(&error)) else {
+                    let _ = tx.send(Err(error)).await;
+                    return;
+                };
+
+                let mut error = error;
+                loop {
+                    if attempt >= policy.max_retries {
+                        let _ = tx.send(Err(error)).await;
+                        return;
+                    }
+                    let delay = policy.delay(attempt);
+                    attempt += 1;
+                    debug!(task_id = %id, attempt, error = %error, "reconnecting SSE stream");
+                    tokio::time::sleep(delay).await;
+                    let last = slot.lock().unwrap().clone();
+                    match reopen(id.clone(), last).await {
+                        Ok(next) => {
+                            std::mem::replace(&mut stream, next).abort();
+                            counter.fetch_add(1, Ordering::SeqCst);
+                            replaying = true;
+                            break;
+                        }
+                        Err(e) if is_transient(&e) => error = e,
+                        Err(e) => {
+                            let _ = tx.send(Err(e)).await;
+                            return;
+                        }
+                    }
+                }
+            }
+        });
+
+        let inner = SseStream::from_channel(rx, task)
+            .with_activated_extensions(activated)
+            .with_last_event_id(last_event_id);
+        Self { inner, reconnects }
+    }
+
+    /// Get the next event, reconnecting as needed. See [`SseStream::next`].
+    pub async fn next(&mut self) -> Option<A2AResult<StreamResponse>> {
+        self.inner.next().await
+    }
+
+    /// ID of the last event received (the `Last-Event-ID` a reconnect
+    /// would send), if the server assigns event IDs.
+    pub fn last_event_id(&self) -> Option<String> {
+        self.inner.last_event_id()
+    }
+
+    /// How many times the stream has been reopened.
+    pub fn reconnects(&self) -> u32 {
+        self.reconnects.load(Ordering::SeqCst)
+    }
+
+    /// Use this stream wherever an [`SseStream`] is expected.
+    pub fn into_sse_stream(self) -> SseStream {
+        self.inner
+    }
+}
+
+impl From<ReconnectingSseStream> for SseStream {
+    fn from(stream: ReconnectingSseStream) -> Self {
+        stream.into_sse_stream()
+    }
+}
+
+/// Fingerprints of the most recently delivered events.
+#[derive(Default)]
+struct ReplayFilter {
+    seen: HashSet<u64>,
+    order: VecDeque<u64>,
+}
+
+impl ReplayFilter {
+    /// Remember `event`; returns `false` if it was delivered before.
+    fn insert(&mut self, event: &StreamResponse) -> bool {
+        let Ok(json) = serde_json::to_string(event) else {
+            return true;
+        };
+        let mut hasher = DefaultHasher::new();
+        json.hash(&mut hasher);
+        let fingerprint = hasher.finish();
+        if !self.seen.insert(fingerprint) {
+            return false;
+        }
+        self.order.push_back(fingerprint);
+        if self.order.len() > REPLAY_WINDOW {
+            if let Some(oldest) = self.order.pop_front() {
+                self.seen.remove(&oldest);
+            }
+        }
+        true
+    }
+}
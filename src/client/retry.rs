@@ -0,0 +1,273 @@
+//! Retrying JSON-RPC calls that fail transiently.
+//!
+//! A [`RetryPolicy`] set with [`TransportConfig::retry`](super::TransportConfig::retry)
+//! (or [`JsonRpcTransport::with_retry`](super::JsonRpcTransport::with_retry),
+//! [`ClientBuilder::with_retry`](crate::builders::ClientBuilder::with_retry))
+//! makes [`JsonRpcTransport`](super::JsonRpcTransport) repeat unary calls,
+//! and the opening of streams, that fail with a transport error, a timeout,
+//! or HTTP 408, 429 or 5xx. Delays grow exponentially with random jitter.
+//!
+//! Only calls safe to repeat are retried: read-only methods (see
+//! [`RetryPolicy::idempotent_methods`]), and `message/send` /
+//! `message/stream` when the message carries a client-generated
+//! `messageId`, which lets the agent recognize the repeat.
+//!
+//! ```
+//! use std::time::Duration;
+//! use a2a_rs::client::{JsonRpcTransport, RetryPolicy, TransportConfig};
+//!
+//! let config = TransportConfig::default().retry(
+//!     RetryPolicy::new()
+//!         .with_max_retries(5)
+//!         .with_initial_backoff(Duration::from_millis(100)),
+//! );
+//! let transport = JsonRpcTransport::with_config("http://localhost:7420/a2a", config);
+//! ```
+
+use std::future::Future;
+use std::time::Duration;
+
+use tracing::Instrument;
+
+use crate::error::A2AResult;
+use crate::types::JsonRpcRequest;
+
+use super::reconnect::is_transient;
+
+/// Methods [`RetryPolicy::new`] retries regardless of their params.
+pub const DEFAULT_IDEMPOTENT_METHODS: &[&str] = &[
+    "tasks/get",
+    "tasks/list",
+    "tasks/artifacts/list",
+    "tasks/artifacts/get",
+    "tasks/resubscribe",
+    "tasks/pushNotificationConfig/get",
+    "tasks/pushNotificationConfig/list",
+    "agent/getAuthenticatedExtendedCard",
+];
+
+/// When and how often [`JsonRpcTransport`](super::JsonRpcTransport)
+/// retries a failed call.
+///
+/// ```
+/// use std::time::Duration;
+/// use a2a_rs::client::RetryPolicy;
+///
+/// let policy = RetryPolicy::new()
+///     .with_max_retries(2)
+///     .with_jitter(0.0)
+///     .with_retry_sends(false);
+/// assert_eq!(policy.initial_backoff, Duration::from_millis(200));
+/// ```
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Retries after the first attempt. `0` disables retrying.
+    pub max_retries: u32,
+
+    /// Delay before the first retry.
+    pub initial_backoff: Duration,
+
+    /// Factor by which the delay grows after each retry (at least 1).
+    pub backoff: f64,
+
+    /// Upper bound for the delay.
+    pub max_backoff: Duration,
+
+    /// Fraction of each delay that is randomized, from `0.0` (fixed
+    /// delays) to `1.0` (anywhere between zero and the full delay).
+    pub jitter: f64,
+
+    /// Retry `message/send` and `message/stream` when the message has a
+    /// non-empty `messageId`.
+    pub retry_sends: bool,
+
+    /// Methods retried regardless of their params.
+    pub idempotent_methods: Vec<String>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(200),
+            backoff: 2.0,
+            max_backoff: Duration::from_secs(5),
+            jitter: 0.5,
+            retry_sends: true,
+            idempotent_methods: DEFAULT_IDEMPOTENT_METHODS
+                .iter()
+                .map(|method| method.to_string())
+                .collect(),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Default policy: up to 3 retries, 200 ms doubling up to 5 s, half of
+    /// each delay jittered; [`DEFAULT_IDEMPOTENT_METHODS`] and sends with a
+    /// `messageId` are retried.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Retry at most `retries` times.
+    pub fn with_max_retries(mut self, retries: u32) -> Self {
+        self.max_retries = retries;
+        self
+    }
+
+    /// Wait `delay` before the first retry.
+    pub fn with_initial_backoff(mut self, delay: Duration) -> Self {
+        self.initial_backoff = delay;
+        self
+    }
+
+    /// Multiply the delay by `factor` after each retry.
+    pub fn with_backoff(mut self, factor: f64) -> Self {
+        self.backoff = factor;
+        self
+    }
+
+    /// Never wait longer than `delay` between attempts.
+    pub fn with_max_backoff(mut self, delay: Duration) -> Self {
+        self.max_backoff = delay;
+        self
+    }
+
+    /// Randomize `fraction` of each delay (clamped to `0.0..=1.0`).
+    pub fn with_jitter(mut self, fraction: f64) -> Self {
+        self.jitter = fraction;
+        self
+    }
+
+    /// Whether to retry sends carrying a `messageId`.
+    pub fn with_retry_sends(mut self, enabled: bool) -> Self {
+        self.retry_sends = enabled;
+        self
+    }
+
+    /// Also retry `method` regardless of its params.
+    pub fn with_idempotent_method(mut self, method: impl Into<String>) -> Self {
+        self.idempotent_methods.push(method.into());
+        self
+    }
+
+    /// Whether `request` is safe to repeat under this policy.
+    pub fn allows(&self, request: &JsonRpcRequest) -> bool {
+        if self.idempotent_methods.contains(&request.method) {
+            return true;
+        }
+        let is_send = matches!(request.method.as_str(), "message/send" | "message/stream");
+        is_send
+            && self.retry_sends
+            && request
+                .params
+                .as_ref()
+                .and_then(|params| params.pointer("/message/messageId"))
+                .and_then(|id| id.as_str())
+                .is_some_and(|id| !id.is_empty())
+    }
+
+    /// The delay before retry number `retry` (counting from 0), without
+    /// jitter.
+    fn base_delay(&self, retry: u32) -> Duration {
+        let cap = self.max_backoff.max(self.initial_backoff);
+        let factor = self.backoff.max(1.0).powi(retry.min(64) as i32);
+        let secs = self.initial_backoff.as_secs_f64() * factor;
+        if secs >= cap.as_secs_f64() {
+            cap
+        } else {
+            Duration::from_secs_f64(secs)
+        }
+    }
+
+    /// The delay before retry number `retry`, with jitter.
+    pub(crate) fn delay(&self, retry: u32) -> Duration {
+        let base = self.base_delay(retry);
+        let jitter = self.jitter.clamp(0.0, 1.0);
+        if jitter == 0.0 {
+            return base;
+        }
+        // The low 53 bits of a v4 UUID are random: a uniform fraction in
+        // [0, 1).
+        let bits = uuid::Uuid::new_v4().as_u128() as u64 & ((1 << 53) - 1);
+        let random = bits as f64 / (1u64 << 53) as f64;
+        base.mul_f64(1.0 - jitter * random)
+    }
+
+    /// Run `attempt` until it succeeds, fails permanently, or retries are
+    /// exhausted. Each attempt runs in an `a2a.attempt` tracing span.
+    pub(crate) async fn run<T, F, Fut>(
+        &self,
+        request: &JsonRpcRequest,
+        mut attempt: F,
+    ) -> A2AResult<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = A2AResult<T>>,
+    {
+        let retryable = self.allows(request);
+        let mut retry = 0;
+        loop {
+            let span =
+                tracing::debug_span!("a2a.attempt", method = %request.method, attempt = retry + 1);
+            let result = attempt().instrument(span).await;
+            let error = match result {
+                Err(e) if retryable && retry < self.max_retries && is_transient(&e) => e,
+                result => return result,
+            };
+            let delay = self.delay(retry);
+            tracing::warn!(
+                method = %request.method,
+                attempt = retry + 1,
+                delay_ms = delay.as_millis() as u64,
+                error = %error,
+                "retrying JSON-RPC call"
+            );
+            tokio::time::sleep(delay).await;
+            retry += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::JsonRpcId;
+
+    fn request(method: &str, params: serde_json::Value) -> JsonRpcRequest {
+        JsonRpcRequest::new(JsonRpcId::Number(1), method, Some(params))
+    }
+
+    #[test]
+    fn idempotency() {
+        let policy = RetryPolicy::new();
+        assert!(policy.allows(&request("tasks/get", serde_json::json!({"id": "t1"}))));
+        assert!(!policy.allows(&request("tasks/cancel", serde_json::json!({"id": "t1"}))));
+        let send = request(
+            "message/send",
+            serde_json::json!({"message": {"messageId": "m1"}}),
+        );
+        assert!(policy.allows(&send));
+        assert!(!policy.clone().with_retry_sends(false).allows(&send));
+        assert!(!policy.allows(&request(
+            "message/send",
+            serde_json::json!({"message": {"messageId": ""}})
+        )));
+    }
+
+    #[test]
+    fn delays() {
+        let policy = RetryPolicy::new()
+            .with_initial_backoff(Duration::from_millis(100))
+            .with_max_backoff(Duration::from_millis(350));
+        let fixed = policy.clone().with_jitter(0.0);
+        assert_eq!(fixed.delay(0), Duration::from_millis(100));
+        assert_eq!(fixed.delay(1), Duration::from_millis(200));
+        assert_eq!(fixed.delay(2), Duration::from_millis(350));
+        for _ in 0..100 {
+            let delay = policy.delay(1);
+            assert!(delay > Duration::from_millis(100) && delay <= Duration::from_millis(200));
+        }
+    }
+}
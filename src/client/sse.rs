@@ -4,14 +4,21 @@
 //! [`StreamResponse`] events (status updates, artifact updates, task snapshots,
 //! and direct messages).
 
+use std::collections::HashSet;
 use std::pin::Pin;
+use std::sync::{Arc, Mutex};
 use std::task::{Context, Poll};
 
 use futures::stream::Stream;
+use serde::Deserialize;
 use tokio::sync::mpsc;
 
+use super::a2a_client::warn_unrecognized_state;
+use super::stream_interceptor::{self, StreamInterceptor};
+use super::transport::ResponseIdCheck;
 use crate::error::{A2AError, A2AResult};
-use crate::types::StreamResponse;
+use crate::types::{Artifact, JsonRpcId, StreamResponse};
+use crate::utils::ArtifactAssembler;
 
 /// A stream of A2A server-sent events.
 ///
@@ -33,6 +40,12 @@ use crate::types::StreamResponse;
 /// ```
 pub struct SseStream {
     receiver: mpsc::Receiver<A2AResult<StreamResponse>>,
+    /// Extensions the server activated for this stream.
+    activated_extensions: HashSet<String>,
+    /// Applied to each event before it is returned.
+    interceptors: Vec<Arc<dyn StreamInterceptor>>,
+    /// ID of the last event received, from SSE `id:` fields.
+    last_event_id: Arc<Mutex<Option<String>>>,
     /// Background task handle — kept alive so the parsing task runs to completion.
     _task: tokio::task::JoinHandle<()>,
 }
@@ -47,31 +60,165 @@ impl SseStream {
     /// Create an `SseStream` from a raw `reqwest::Response`.
     ///
     /// Spawns a background task that reads the response body as SSE lines
-    /// and sends parsed events through a channel.
-    pub(crate) fn from_response(response: reqwest::Response) -> Self {
+    /// and sends parsed events through a channel. `guard` is dropped when
+    /// that task ends, whether the body finished, failed or was aborted.
+    /// The ids of JSON-RPC envelopes are verified with `ids`.
+    pub(crate) fn from_response<G: Send + 'static>(
+        response: reqwest::Response,
+        guard: G,
+        ids: ResponseIdCheck,
+    ) -> Self {
         let (tx, rx) = mpsc::channel(64);
+        let activated = super::transport::activated_extensions(response.headers());
+        let last_event_id = Arc::new(Mutex::new(None));
 
+        let slot = last_event_id.clone();
         let task = tokio::spawn(async move {
-            if let Err(e) = parse_sse_stream(response, &tx).await {
+            let _guard = guard;
+            if let Err(e) = parse_sse_stream(response, &ids, &slot, &tx).await {
                 // Send the final error and then stop. Ignore send failures
                 // (receiver may have been dropped).
                 let _ = tx.send(Err(e)).await;
             }
         });
 
+        Self::from_channel(rx, task)
+            .with_activated_extensions(activated)
+            .with_last_event_id(last_event_id)
+    }
+
+    /// Create an `SseStream` from any stream of events.
+    ///
+    /// This is how [`Transport`](super::Transport) implementations that
+    /// don't speak SSE (gRPC, WebSocket, in-process) return server-stream
+    /// calls. The source is polled by a background task until it ends or
+    /// the `SseStream` is dropped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use a2a_rs::client::SseStream;
+    /// use a2a_rs::error::A2AResult;
+    /// use a2a_rs::types::{Message, StreamResponse};
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let events: Vec<A2AResult<StreamResponse>> =
+    ///     vec![Ok(StreamResponse::Message(Message::agent("m1", "hi")))];
+    /// let mut stream = SseStream::from_stream(futures::stream::iter(events));
+    /// assert!(stream.next().await.unwrap().is_ok());
+    /// assert!(stream.next().await.is_none());
+    /// # }
+    /// ```
+    pub fn from_stream<S>(source: S) -> Self
+    where
+        S: Stream<Item = A2AResult<StreamResponse>> + Send + 'static,
+    {
+        use futures::StreamExt;
+
+        let (tx, rx) = mpsc::channel(64);
+        let task = tokio::spawn(async move {
+            futures::pin_mut!(source);
+            while let Some(event) = source.next().await {
+                if tx.send(event).await.is_err() {
+                    return;
+                }
+            }
+        });
+        Self::from_channel(rx, task)
+    }
+
+    /// Create an `SseStream` fed by a background task through `receiver`.
+    pub(crate) fn from_channel(
+        receiver: mpsc::Receiver<A2AResult<StreamResponse>>,
+        task: tokio::task::JoinHandle<()>,
+    ) -> Self {
         Self {
-            receiver: rx,
+            receiver,
+            activated_extensions: HashSet::new(),
+            interceptors: Vec::new(),
+            last_event_id: Arc::default(),
             _task: task,
         }
     }
 
+    pub(crate) fn with_activated_extensions(mut self, activated: HashSet<String>) -> Self {
+        self.activated_extensions = activated;
+        self
+    }
+
+    /// Share `slot` as this stream's last event ID, updated by the task
+    /// feeding it.
+    pub(crate) fn with_last_event_id(mut self, slot: Arc<Mutex<Option<String>>>) -> Self {
+        self.last_event_id = slot;
+        self
+    }
+
+    /// Append `interceptors` to those applied to this stream's events.
+    pub(crate) fn with_interceptors(mut self, interceptors: &[Arc<dyn StreamInterceptor>]) -> Self {
+        self.interceptors.extend(interceptors.iter().cloned());
+        self
+    }
+
+    /// Extensions the server activated for this stream, from the
+    /// `X-A2A-Extensions` response header.
+    pub fn activated_extensions(&self) -> &HashSet<String> {
+        &self.activated_extensions
+    }
+
+    /// ID of the last event received, from the SSE `id:` field; `None`
+    /// if the server doesn't assign event IDs.
+    ///
+    /// Events are read ahead of [`next()`](Self::next), so this may
+    /// belong to an event not yet returned. Sent as `Last-Event-ID` by a
+    /// [`ReconnectingSseStream`](super::ReconnectingSseStream).
+    pub fn last_event_id(&self) -> Option<String> {
+        self.last_event_id.lock().unwrap().clone()
+    }
+
     /// Get the next event from the stream.
     ///
     /// Returns `None` when the stream is exhausted (server closed the connection
     /// or sent a terminal event). Returns `Some(Err(...))` on parse or transport
-    /// errors.
+    /// errors. Events dropped by a [`StreamInterceptor`] are skipped.
     pub async fn next(&mut self) -> Option<A2AResult<StreamResponse>> {
-        self.receiver.recv().await
+        loop {
+            match self.receiver.recv().await? {
+                Ok(event) => {
+                    if let Some(event) = stream_interceptor::apply(&self.interceptors, event) {
+                        return Some(Ok(event));
+                    }
+                }
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+
+    /// Read the rest of the stream and return its artifacts, with chunked
+    /// `artifact-update` events merged by an [`ArtifactAssembler`].
+    ///
+    /// Stops at the end of the stream or at a final status update.
+    /// Artifacts are in order of their first chunk; one whose last chunk
+    /// never arrived is returned as far as it got. Other events are
+    /// skipped, and the first stream error is returned.
+    pub async fn collect_artifacts(mut self) -> A2AResult<Vec<Artifact>> {
+        let mut assembler = ArtifactAssembler::new();
+        while let Some(event) = self.next().await {
+            match event? {
+                StreamResponse::ArtifactUpdate(update) => {
+                    assembler.push(&update);
+                }
+                StreamResponse::StatusUpdate(update) if update.r#final => break,
+                _ => {}
+            }
+        }
+        Ok(assembler.into_artifacts())
+    }
+
+    /// Stop the background task, closing the underlying connection now
+    /// rather than when the next event fails to send.
+    pub(crate) fn abort(self) {
+        self._task.abort();
     }
 
     /// Convert this stream into a `futures::Stream`.
@@ -81,6 +228,7 @@ impl SseStream {
     pub fn into_stream(self) -> SseStreamAdapter {
         SseStreamAdapter {
             receiver: self.receiver,
+            interceptors: self.interceptors,
             _task: self._task,
         }
     }
@@ -91,6 +239,7 @@ impl SseStream {
 /// Created by [`SseStream::into_stream()`].
 pub struct SseStreamAdapter {
     receiver: mpsc::Receiver<A2AResult<StreamResponse>>,
+    interceptors: Vec<Arc<dyn StreamInterceptor>>,
     _task: tokio::task::JoinHandle<()>,
 }
 
@@ -98,19 +247,32 @@ impl Stream for SseStreamAdapter {
     type Item = A2AResult<StreamResponse>;
 
     fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-        self.receiver.poll_recv(cx)
+        loop {
+            match std::task::ready!(self.receiver.poll_recv(cx)) {
+                Some(Ok(event)) => {
+                    if let Some(event) = stream_interceptor::apply(&self.interceptors, event) {
+                        return Poll::Ready(Some(Ok(event)));
+                    }
+                }
+                other => return Poll::Ready(other),
+            }
+        }
     }
 }
 
-/// Parse an SSE response body line-by-line, sending parsed events to `tx`.
+/// Parse an SSE response body line-by-line, sending parsed events to `tx`
+/// and recording the ID of the last one dispatched in `last_event_id`.
 async fn parse_sse_stream(
     response: reqwest::Response,
+    ids: &ResponseIdCheck,
+    last_event_id: &Mutex<Option<String>>,
     tx: &mpsc::Sender<A2AResult<StreamResponse>>,
 ) -> A2AResult<()> {
     use futures::StreamExt;
 
     let mut stream = response.bytes_stream();
     let mut buffer = String::new();
+    let mut pending_id: Option<String> = None;
 
     while let Some(chunk_result) = stream.next().await {
         let chunk = chunk_result
@@ -126,7 +288,19 @@ async fn parse_sse_stream(
             let line = buffer[..newline_pos].trim_end_matches('\r').to_string();
             buffer = buffer[newline_pos + 1..].to_string();
 
-            if let Some(event) = parse_sse_line(&line)? {
+            if let Some(id) = parse_event_id(&line) {
+                pending_id = Some(id.to_string());
+                continue;
+            }
+            if line.is_empty() {
+                if let Some(id) = pending_id.take() {
+                    *last_event_id.lock().unwrap() = Some(id).filter(|id| !id.is_empty());
+                }
+            }
+            if let Some(event) = parse_sse_line(&line, ids)? {
+                if let Some(id) = pending_id.take() {
+                    *last_event_id.lock().unwrap() = Some(id).filter(|id| !id.is_empty());
+                }
                 if tx.send(Ok(event)).await.is_err() {
                     // Receiver dropped — stop parsing.
                     return Ok(());
@@ -137,7 +311,10 @@ async fn parse_sse_stream(
 
     // Process any remaining data in the buffer (no trailing newline).
     if !buffer.trim().is_empty() {
-        if let Some(event) = parse_sse_line(buffer.trim())? {
+        if let Some(event) = parse_sse_line(buffer.trim(), ids)? {
+            if let Some(id) = pending_id.take() {
+                *last_event_id.lock().unwrap() = Some(id).filter(|id| !id.is_empty());
+            }
             let _ = tx.send(Ok(event)).await;
         }
     }
@@ -145,6 +322,14 @@ async fn parse_sse_stream(
     Ok(())
 }
 
+/// The value of an SSE `id:` line. IDs containing NUL are ignored, as
+/// the SSE specification requires.
+fn parse_event_id(line: &str) -> Option<&str> {
+    let value = line.strip_prefix("id:")?;
+    let value = value.strip_prefix(' ').unwrap_or(value);
+    (!value.contains('\0')).then_some(value)
+}
+
 /// Parse a single SSE line. Returns `Some(event)` for `data:` lines with
 /// valid JSON, `None` for comments, empty lines, and keep-alive signals.
 ///
@@ -154,8 +339,8 @@ async fn parse_sse_stream(
 /// 2. **JSON-RPC wrapped** — the data is a full JSON-RPC response with
 ///    `jsonrpc`, `id`, and `result` fields (as sent by the Python SDK).
 ///    In this case, the `result` field is extracted and parsed as a
-///    `StreamResponse`.
-fn parse_sse_line(line: &str) -> A2AResult<Option<StreamResponse>> {
+///    `StreamResponse`. The envelope's `id` is verified with `ids`.
+fn parse_sse_line(line: &str, ids: &ResponseIdCheck) -> A2AResult<Option<StreamResponse>> {
     // Empty line = event boundary (we process data lines individually).
     if line.is_empty() {
         return Ok(None);
@@ -180,89 +365,133 @@ fn parse_sse_line(line: &str) -> A2AResult<Option<StreamResponse>> {
             return Ok(None);
         }
 
-        // Parse the JSON.
-        let value: serde_json::Value = serde_json::from_str(data).map_err(|e| {
-            A2AError::InvalidJson(format!(
-                "failed to parse SSE event data: {e} (data: {data})"
-            ))
-        })?;
-
-        // Detect JSON-RPC wrapper: has "jsonrpc" field.
-        let event_value = if value.get("jsonrpc").is_some() {
-            // JSON-RPC wrapped response — check for error.
-            if let Some(error) = value.get("error") {
-                let code = error.get("code").and_then(|c| c.as_i64()).unwrap_or(-1);
-                let message = error
-                    .get("message")
-                    .and_then(|m| m.as_str())
-                    .unwrap_or("unknown error")
-                    .to_string();
-                let data = error.get("data").cloned();
-                return Err(A2AError::JsonRpc {
-                    code,
-                    message,
-                    data,
-                });
-            }
-            // Extract the `result` field.
-            value.get("result").cloned().ok_or_else(|| {
-                A2AError::InvalidJson(format!(
-                    "JSON-RPC SSE response has neither 'result' nor 'error': {data}"
-                ))
-            })?
-        } else {
-            // Raw event — parse directly.
-            value
-        };
+        return parse_event_data(data, "SSE", ids).map(Some);
+    }
+
+    // Other SSE fields (event:, retry:) — ignore for now; `id:` is
+    // tracked by `parse_sse_stream`.
+    Ok(None)
+}
 
-        let event: StreamResponse = serde_json::from_value(event_value).map_err(|e| {
+/// Parse the JSON of one streamed event, in either of the formats of
+/// [`parse_sse_line`]. `encoding` names the stream's encoding in errors.
+pub(crate) fn parse_event_data(
+    data: &str,
+    encoding: &str,
+    ids: &ResponseIdCheck,
+) -> A2AResult<StreamResponse> {
+    // Parse the JSON.
+    let value: serde_json::Value = serde_json::from_str(data).map_err(|e| {
+        A2AError::InvalidJson(format!(
+            "failed to parse {encoding} event data: {e} (data: {data})"
+        ))
+    })?;
+
+    // Detect JSON-RPC wrapper: has "jsonrpc" field.
+    let event_value = if value.get("jsonrpc").is_some() {
+        // An id that isn't a valid JSON-RPC id is kept as its JSON
+        // text, so it never matches.
+        let id = value.get("id").filter(|id| !id.is_null()).map(|id| {
+            serde_json::from_value(id.clone()).unwrap_or_else(|_| JsonRpcId::String(id.to_string()))
+        });
+        ids.verify(id.as_ref(), value.get("error").is_some())?;
+        // JSON-RPC wrapped response — check for error.
+        if let Some(error) = value.get("error") {
+            let code = error.get("code").and_then(|c| c.as_i64()).unwrap_or(-1);
+            let message = error
+                .get("message")
+                .and_then(|m| m.as_str())
+                .unwrap_or("unknown error")
+                .to_string();
+            let data = error.get("data").cloned();
+            return Err(A2AError::JsonRpc {
+                code,
+                message,
+                data,
+            });
+        }
+        // Extract the `result` field.
+        value.get("result").cloned().ok_or_else(|| {
             A2AError::InvalidJson(format!(
-                "failed to parse SSE event as StreamResponse: {e} (data: {data})"
+                "JSON-RPC {encoding} response has neither 'result' nor 'error': {data}"
             ))
-        })?;
-
-        return Ok(Some(event));
+        })?
+    } else {
+        // Raw event — parse directly.
+        value
+    };
+
+    let event = StreamResponse::deserialize(&event_value).map_err(|e| {
+        let reason = match super::diagnose::<StreamResponse>(&event_value) {
+            Some(diagnostic) => format!(
+                "{} at `{}`: {}",
+                diagnostic.target, diagnostic.path, diagnostic.message
+            ),
+            None => e.to_string(),
+        };
+        A2AError::InvalidJson(format!(
+            "failed to parse {encoding} event as StreamResponse: {reason} (data: {data})"
+        ))
+    })?;
+    match &event {
+        StreamResponse::Task(task) => warn_unrecognized_state(&task.id, &task.status),
+        StreamResponse::StatusUpdate(update) => {
+            warn_unrecognized_state(&update.task_id, &update.status)
+        }
+        StreamResponse::Message(_) | StreamResponse::ArtifactUpdate(_) => {}
     }
 
-    // Other SSE fields (event:, id:, retry:) — ignore for now.
-    Ok(None)
+    Ok(event)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn parse(line: &str) -> A2AResult<Option<StreamResponse>> {
+        parse_sse_line(line, &ResponseIdCheck::unchecked())
+    }
+
     #[test]
     fn test_parse_empty_line() {
-        assert!(parse_sse_line("").unwrap().is_none());
+        assert!(parse("").unwrap().is_none());
     }
 
     #[test]
     fn test_parse_comment() {
-        assert!(parse_sse_line(": keepalive").unwrap().is_none());
+        assert!(parse(": keepalive").unwrap().is_none());
     }
 
     #[test]
     fn test_parse_done_sentinel() {
-        assert!(parse_sse_line("data: [DONE]").unwrap().is_none());
+        assert!(parse("data: [DONE]").unwrap().is_none());
     }
 
     #[test]
     fn test_parse_empty_data() {
-        assert!(parse_sse_line("data:").unwrap().is_none());
-        assert!(parse_sse_line("data:  ").unwrap().is_none());
+        assert!(parse("data:").unwrap().is_none());
+        assert!(parse("data:  ").unwrap().is_none());
     }
 
     #[test]
     fn test_parse_non_data_field() {
-        assert!(parse_sse_line("event: update").unwrap().is_none());
-        assert!(parse_sse_line("id: 123").unwrap().is_none());
-        assert!(parse_sse_line("retry: 5000").unwrap().is_none());
+        assert!(parse("event: update").unwrap().is_none());
+        assert!(parse("id: 123").unwrap().is_none());
+        assert!(parse("retry: 5000").unwrap().is_none());
+    }
+
+    #[test]
+    fn test_parse_event_id() {
+        assert_eq!(parse_event_id("id: 42"), Some("42"));
+        assert_eq!(parse_event_id("id:7"), Some("7"));
+        assert_eq!(parse_event_id("id:"), Some(""));
+        assert_eq!(parse_event_id("id: a\0b"), None);
+        assert_eq!(parse_event_id("data: {}"), None);
     }
 
     #[test]
     fn test_parse_invalid_json() {
-        let result = parse_sse_line("data: {not valid json}");
+        let result = parse("data: {not valid json}");
         assert!(result.is_err());
     }
 }
@@ -0,0 +1,64 @@
+//! Client-side transformation of streaming events.
+//!
+//! A [`StreamInterceptor`] registered on an [`A2AClient`](super::A2AClient)
+//! (or via [`ClientBuilder`](crate::ClientBuilder)) sees every
+//! [`StreamResponse`] of `message/stream` and `tasks/resubscribe` before the
+//! consumer does, and can drop it, modify it or pass it through. Typical
+//! uses are redacting sensitive parts, counting events and debug taps.
+//!
+//! Interceptors run in registration order; an event dropped by one is not
+//! seen by later ones. Stream errors bypass interceptors.
+
+use std::sync::Arc;
+
+use crate::types::StreamResponse;
+
+/// Hook applied to each streaming event before it reaches the consumer.
+///
+/// Implementations run inline while the consumer polls the stream, so they
+/// must be cheap and non-blocking.
+///
+/// Closures `Fn(StreamResponse) -> Option<StreamResponse>` implement this
+/// trait.
+///
+/// # Example
+///
+/// ```
+/// use a2a_rs::client::StreamInterceptor;
+/// use a2a_rs::types::StreamResponse;
+///
+/// /// Drops artifact updates, keeping only status changes.
+/// struct StatusOnly;
+///
+/// impl StreamInterceptor for StatusOnly {
+///     fn intercept(&self, event: StreamResponse) -> Option<StreamResponse> {
+///         match event {
+///             StreamResponse::ArtifactUpdate(_) => None,
+///             other => Some(other),
+///         }
+///     }
+/// }
+/// ```
+pub trait StreamInterceptor: Send + Sync {
+    /// Return the (possibly modified) event, or `None` to drop it.
+    fn intercept(&self, event: StreamResponse) -> Option<StreamResponse>;
+}
+
+impl<F> StreamInterceptor for F
+where
+    F: Fn(StreamResponse) -> Option<StreamResponse> + Send + Sync,
+{
+    fn intercept(&self, event: StreamResponse) -> Option<StreamResponse> {
+        self(event)
+    }
+}
+
+/// Run `event` through `interceptors` in order, stopping once one drops it.
+pub(crate) fn apply(
+    interceptors: &[Arc<dyn StreamInterceptor>],
+    event: StreamResponse,
+) -> Option<StreamResponse> {
+    interceptors
+        .iter()
+        .try_fold(event, |event, interceptor| interceptor.intercept(event))
+}
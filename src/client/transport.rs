@@ -3,21 +3,61 @@
 //! Provides the `Transport` trait for abstracting over different communication
 //! protocols, and `JsonRpcTransport` for the standard JSON-RPC over HTTP binding.
 
-use std::collections::HashMap;
-use std::time::Duration;
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use futures::stream::BoxStream;
 use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use tokio::sync::mpsc;
+use tracing::warn;
 
 use crate::error::{A2AError, A2AResult};
-use crate::types::{JsonRpcRequest, JsonRpcResponse};
+use crate::types::{
+    JsonRpcId, JsonRpcRequest, JsonRpcResponse, Part, StreamResponse, TaskIdParams,
+};
+use crate::utils::chunked_task::{assemble_task, TaskSection, CHUNKED_TASK_CONTENT_TYPE};
+use crate::utils::deadline::{current_deadline, format_deadline, parse_deadline, DEADLINE_HEADER};
+use crate::utils::extensions::{get_requested_extensions, HTTP_EXTENSION_HEADER};
+use crate::utils::identification::{ClientIdentification, CLIENT_IDENTIFICATION_HEADER};
+use crate::utils::request_id::{
+    current_request_id, new_request_id, request_id_or_new, REQUEST_ID_HEADER,
+};
+use crate::utils::stream_encoding::StreamEncoding;
+use crate::utils::version::PROTOCOL_VERSION;
 
+use super::auth::{refresh_deadline, Credential, CredentialService};
+use super::interceptor::{InterceptedCall, InterceptorChain, TransportInterceptor};
+use super::ndjson::NdjsonStream;
+use super::observer::{CallMetrics, TransportEvent, TransportObserver};
+use super::reconnect::{ReconnectingSseStream, SseReconnectPolicy, LAST_EVENT_ID_HEADER};
+use super::retry::RetryPolicy;
 use super::sse::SseStream;
 
 /// Transport abstraction for A2A communication.
 ///
 /// Implementations handle the low-level details of sending JSON-RPC requests
-/// and receiving responses (or SSE streams) over a particular protocol binding.
+/// and receiving responses (or event streams) over a particular protocol
+/// binding. The trait is object-safe, so any implementation plugs into
+/// [`A2AClient::with_transport`](super::A2AClient::with_transport):
+///
+/// - **Unary calls** — [`send()`](Self::send) /
+///   [`send_with_options()`](Self::send_with_options)
+/// - **Server-stream calls** — [`send_stream()`](Self::send_stream) /
+///   [`send_stream_with_options()`](Self::send_stream_with_options). Non-SSE
+///   transports wrap their event source with [`SseStream::from_stream`].
+/// - **Uploads** — [`send_upload()`](Self::send_upload) streams a
+///   `message/sendStream` message's parts to the agent
+/// - **Lifecycle** — [`connect()`](Self::connect) and
+///   [`close()`](Self::close), with [`TransportEvent`]s delivered to the
+///   observer installed via [`set_observer()`](Self::set_observer)
+/// - **Metadata** — [`metadata()`](Self::metadata) describes the binding
+///
+/// Only `send` and `send_stream` are required; everything else has a
+/// default.
 ///
 /// Python SDK ref: `ClientTransport` (abstract base class in `transports/base.py`)
 #[async_trait]
@@ -30,6 +70,97 @@ pub trait Transport: Send + Sync {
     /// Used for streaming methods like `message/stream` and `tasks/subscribe`.
     async fn send_stream(&self, request: &JsonRpcRequest) -> A2AResult<SseStream>;
 
+    /// Send a JSON-RPC request with per-call options (e.g. extra headers).
+    ///
+    /// The default implementation ignores `options` and delegates to
+    /// [`send()`](Self::send). Transports that support per-call headers
+    /// should override this.
+    async fn send_with_options(
+        &self,
+        request: &JsonRpcRequest,
+        options: &CallOptions,
+    ) -> A2AResult<JsonRpcResponse> {
+        let _ = options;
+        self.send(request).await
+    }
+
+    /// Send a streaming JSON-RPC request with per-call options.
+    ///
+    /// The default implementation ignores `options` and delegates to
+    /// [`send_stream()`](Self::send_stream).
+    async fn send_stream_with_options(
+        &self,
+        request: &JsonRpcRequest,
+        options: &CallOptions,
+    ) -> A2AResult<SseStream> {
+        let _ = options;
+        self.send_stream(request).await
+    }
+
+    /// Send a `message/sendStream` request whose message continues with
+    /// `parts`, uploaded as they are produced.
+    ///
+    /// The default implementation waits for every part, appends them to
+    /// the request's message and delegates to
+    /// [`send_with_options()`](Self::send_with_options); the agent then
+    /// gets the message whole.
+    async fn send_upload(
+        &self,
+        request: &JsonRpcRequest,
+        parts: PartStream,
+        options: &CallOptions,
+    ) -> A2AResult<JsonRpcResponse> {
+        use futures::StreamExt;
+
+        let parts: Vec<Part> = parts.collect().await;
+        let mut request = request.clone();
+        let message_parts = request
+            .params
+            .as_mut()
+            .and_then(|params| params.get_mut("message"))
+            .and_then(|message| message.get_mut("parts"))
+            .and_then(serde_json::Value::as_array_mut)
+            .ok_or_else(|| A2AError::invalid_params("request has no message parts"))?;
+        for part in parts {
+            message_parts.push(serde_json::to_value(part).map_err(|e| {
+                A2AError::Transport(format!("failed to serialize message part: {e}"))
+            })?);
+        }
+        self.send_with_options(&request, options).await
+    }
+
+    /// Send a JSON-RPC notification (a request without `id`); no response
+    /// is expected.
+    ///
+    /// The default implementation delegates to [`send()`](Self::send) and
+    /// discards the response. Transports whose peers don't answer
+    /// notifications should override this.
+    async fn notify(&self, request: &JsonRpcRequest) -> A2AResult<()> {
+        self.send(request).await.map(drop)
+    }
+
+    /// Send a notification with per-call options.
+    ///
+    /// The default implementation ignores `options` and delegates to
+    /// [`notify()`](Self::notify).
+    async fn notify_with_options(
+        &self,
+        request: &JsonRpcRequest,
+        options: &CallOptions,
+    ) -> A2AResult<()> {
+        let _ = options;
+        self.notify(request).await
+    }
+
+    /// Establish the connection ahead of the first call.
+    ///
+    /// Calls connect lazily if this isn't used, so it is optional; use it to
+    /// surface connection problems early. The default implementation is a
+    /// no-op.
+    async fn connect(&self) -> A2AResult<()> {
+        Ok(())
+    }
+
     /// Close the transport and release any held resources.
     ///
     /// Python SDK ref: `ClientTransport.close()`, `JsonRpcTransport.close()`
@@ -39,15 +170,111 @@ pub trait Transport: Send + Sync {
     async fn close(&self) -> A2AResult<()> {
         Ok(())
     }
+
+    /// Describe the protocol binding and endpoint of this transport.
+    fn metadata(&self) -> TransportMetadata {
+        TransportMetadata::new("custom")
+    }
+
+    /// Install an observer for per-call metrics and [`TransportEvent`]s,
+    /// replacing any previous one.
+    ///
+    /// The default implementation ignores the observer; transports that
+    /// report metrics or lifecycle events should override this.
+    fn set_observer(&mut self, observer: Arc<dyn TransportObserver>) {
+        let _ = observer;
+    }
+}
+
+/// Parts of a message uploaded with [`Transport::send_upload`].
+pub type PartStream = BoxStream<'static, Part>;
+
+/// Description of a [`Transport`], from [`Transport::metadata`].
+///
+/// # Example
+///
+/// ```
+/// use a2a_rs::client::{JsonRpcTransport, Transport};
+///
+/// let metadata = JsonRpcTransport::new("http://localhost:7420/a2a").metadata();
+/// assert_eq!(metadata.protocol, "JSONRPC");
+/// assert_eq!(metadata.endpoint.as_deref(), Some("http://localhost:7420/a2a"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransportMetadata {
+    /// Protocol binding, e.g. `"JSONRPC"`, `"GRPC"` or `"HTTP+JSON"` as in
+    /// an agent card's `supported_interfaces`.
+    pub protocol: String,
+    /// Endpoint the transport talks to, if it has one.
+    pub endpoint: Option<String>,
+    /// Whether [`Transport::send_stream`] is supported.
+    pub supports_streaming: bool,
+}
+
+impl TransportMetadata {
+    /// Metadata for a streaming-capable `protocol` binding without an
+    /// endpoint.
+    pub fn new(protocol: impl Into<String>) -> Self {
+        Self {
+            protocol: protocol.into(),
+            endpoint: None,
+            supports_streaming: true,
+        }
+    }
+
+    /// Set the endpoint (builder-style).
+    pub fn with_endpoint(mut self, endpoint: impl Into<String>) -> Self {
+        self.endpoint = Some(endpoint.into());
+        self
+    }
+
+    /// Set whether server-stream calls are supported (builder-style).
+    pub fn with_streaming(mut self, supports_streaming: bool) -> Self {
+        self.supports_streaming = supports_streaming;
+        self
+    }
 }
 
 /// Configuration for [`JsonRpcTransport`].
+///
+/// Besides the timeout and headers, it tunes the connection pool of the
+/// underlying HTTP client, which clones of the transport (and of an
+/// [`A2AClient`](super::A2AClient) using it) share. Unset knobs keep
+/// `reqwest`'s defaults.
+///
+/// ```
+/// use std::time::Duration;
+/// use a2a_rs::client::{Http2Settings, JsonRpcTransport, TransportConfig};
+///
+/// let config = TransportConfig::default()
+///     .pool_max_idle_per_host(64)
+///     .pool_idle_timeout(Duration::from_secs(30))
+///     .tcp_keepalive(Duration::from_secs(15))
+///     .http2(Http2Settings::new().with_keep_alive_interval(Duration::from_secs(10)));
+/// let transport = JsonRpcTransport::with_config("http://localhost:7420/a2a", config);
+/// ```
 #[derive(Debug, Clone)]
 pub struct TransportConfig {
     /// Request timeout. Defaults to 60 seconds.
     pub timeout: Duration,
     /// Additional HTTP headers to include on every request.
     pub headers: HashMap<String, String>,
+    /// How calls failing transiently are retried. `None` (the default)
+    /// disables retrying.
+    pub retry: Option<RetryPolicy>,
+    /// Timeout for establishing a connection.
+    pub connect_timeout: Option<Duration>,
+    /// Idle connections kept open per host.
+    pub pool_max_idle_per_host: Option<usize>,
+    /// How long an idle connection is kept open.
+    pub pool_idle_timeout: Option<Duration>,
+    /// Interval of TCP keepalive probes on open connections.
+    pub tcp_keepalive: Option<Duration>,
+    /// HTTP/2 settings; `None` negotiates the protocol as usual.
+    pub http2: Option<Http2Settings>,
+    /// Encoding asked for on streaming calls. Defaults to SSE; responses
+    /// in either encoding are accepted.
+    pub stream_encoding: StreamEncoding,
 }
 
 impl Default for TransportConfig {
@@ -55,10 +282,441 @@ impl Default for TransportConfig {
         Self {
             timeout: Duration::from_secs(60),
             headers: HashMap::new(),
+            retry: None,
+            connect_timeout: None,
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            tcp_keepalive: None,
+            http2: None,
+            stream_encoding: StreamEncoding::default(),
+        }
+    }
+}
+
+impl TransportConfig {
+    /// Retry calls that fail transiently per `policy` (builder-style). See
+    /// [`RetryPolicy`].
+    pub fn retry(mut self, policy: RetryPolicy) -> Self {
+        self.retry = Some(policy);
+        self
+    }
+
+    /// Give up connecting after `timeout` (builder-style).
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Keep at most `max` idle connections per host (builder-style).
+    pub fn pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.pool_max_idle_per_host = Some(max);
+        self
+    }
+
+    /// Close connections idle for `timeout` (builder-style).
+    pub fn pool_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.pool_idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Send TCP keepalive probes every `interval` (builder-style).
+    pub fn tcp_keepalive(mut self, interval: Duration) -> Self {
+        self.tcp_keepalive = Some(interval);
+        self
+    }
+
+    /// Apply HTTP/2 `settings` (builder-style).
+    pub fn http2(mut self, settings: Http2Settings) -> Self {
+        self.http2 = Some(settings);
+        self
+    }
+
+    /// Ask for streams in `encoding` (builder-style), e.g.
+    /// [`StreamEncoding::Ndjson`] behind proxies that strip SSE. See
+    /// [`NdjsonStream`](super::NdjsonStream).
+    pub fn stream_encoding(mut self, encoding: StreamEncoding) -> Self {
+        self.stream_encoding = encoding;
+        self
+    }
+
+    /// Apply the connection settings to `builder`.
+    fn configure(&self, mut builder: reqwest::ClientBuilder) -> reqwest::ClientBuilder {
+        builder = builder.timeout(self.timeout);
+        if let Some(timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(timeout);
+        }
+        if let Some(max) = self.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(max);
         }
+        if let Some(timeout) = self.pool_idle_timeout {
+            builder = builder.pool_idle_timeout(timeout);
+        }
+        if let Some(interval) = self.tcp_keepalive {
+            builder = builder.tcp_keepalive(interval);
+        }
+        if let Some(http2) = &self.http2 {
+            if http2.prior_knowledge {
+                builder = builder.http2_prior_knowledge();
+            }
+            builder = builder
+                .http2_keep_alive_interval(http2.keep_alive_interval)
+                .http2_keep_alive_while_idle(http2.keep_alive_while_idle)
+                .http2_adaptive_window(http2.adaptive_window);
+            if let Some(timeout) = http2.keep_alive_timeout {
+                builder = builder.http2_keep_alive_timeout(timeout);
+            }
+        }
+        builder
     }
 }
 
+/// HTTP/2 settings of a [`TransportConfig`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Http2Settings {
+    /// Speak HTTP/2 without negotiating it first (`h2c` to plain-HTTP
+    /// agents that support it).
+    pub prior_knowledge: bool,
+    /// Interval of HTTP/2 `PING` frames keeping connections alive.
+    pub keep_alive_interval: Option<Duration>,
+    /// How long to wait for a `PING` acknowledgement before closing the
+    /// connection.
+    pub keep_alive_timeout: Option<Duration>,
+    /// Also ping connections without open streams.
+    pub keep_alive_while_idle: bool,
+    /// Size flow-control windows adaptively to the measured bandwidth.
+    pub adaptive_window: bool,
+}
+
+impl Http2Settings {
+    /// Default settings: negotiated HTTP/2, no keepalive pings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Speak HTTP/2 without negotiation (builder-style).
+    pub fn with_prior_knowledge(mut self) -> Self {
+        self.prior_knowledge = true;
+        self
+    }
+
+    /// Ping every `interval` (builder-style).
+    pub fn with_keep_alive_interval(mut self, interval: Duration) -> Self {
+        self.keep_alive_interval = Some(interval);
+        self
+    }
+
+    /// Close connections whose ping isn't acknowledged within `timeout`
+    /// (builder-style).
+    pub fn with_keep_alive_timeout(mut self, timeout: Duration) -> Self {
+        self.keep_alive_timeout = Some(timeout);
+        self
+    }
+
+    /// Ping idle connections too (builder-style).
+    pub fn with_keep_alive_while_idle(mut self, enabled: bool) -> Self {
+        self.keep_alive_while_idle = enabled;
+        self
+    }
+
+    /// Use adaptive flow-control windows (builder-style).
+    pub fn with_adaptive_window(mut self, enabled: bool) -> Self {
+        self.adaptive_window = enabled;
+        self
+    }
+}
+
+/// How [`JsonRpcTransport`] checks that a response belongs to its request.
+///
+/// Each JSON-RPC response — and each JSON-RPC envelope of a streamed
+/// event — must carry the `id` of the request it answers. A mismatch means
+/// the agent (or a proxy in between) cross-wired responses. An error
+/// response with a `null` id is always accepted, as JSON-RPC allows it when
+/// the server could not read the request id. Streamed events sent without
+/// an envelope carry no id and are not checked.
+///
+/// # Example
+///
+/// ```
+/// use a2a_rs::client::{IdCorrelation, JsonRpcTransport};
+///
+/// // Talk to an agent known to echo a fixed id.
+/// let transport = JsonRpcTransport::new("http://localhost:8080/a2a")
+///     .with_id_correlation(IdCorrelation::Warn);
+/// assert_eq!(transport.id_correlation(), IdCorrelation::Warn);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum IdCorrelation {
+    /// Fail the call (or end the stream) with
+    /// [`A2AError::ProtocolViolation`].
+    #[default]
+    Strict,
+    /// Log a warning and accept the response.
+    Warn,
+    /// Accept every response without checking.
+    Off,
+}
+
+/// The id a response to one request must carry, and what to do if it
+/// doesn't.
+#[derive(Debug, Clone)]
+pub(crate) struct ResponseIdCheck {
+    mode: IdCorrelation,
+    method: String,
+    expected: Option<JsonRpcId>,
+}
+
+impl ResponseIdCheck {
+    pub(crate) fn new(mode: IdCorrelation, request: &JsonRpcRequest) -> Self {
+        Self {
+            mode,
+            method: request.method.clone(),
+            expected: request.id.clone(),
+        }
+    }
+
+    /// A check that accepts everything.
+    pub(crate) fn unchecked() -> Self {
+        Self {
+            mode: IdCorrelation::Off,
+            method: String::new(),
+            expected: None,
+        }
+    }
+
+    /// Verify the `id` of a response; `is_error` if it carries an error.
+    pub(crate) fn verify(&self, actual: Option<&JsonRpcId>, is_error: bool) -> A2AResult<()> {
+        let actual = actual.filter(|id| **id != JsonRpcId::Null);
+        if self.mode == IdCorrelation::Off
+            || actual == self.expected.as_ref()
+            || (is_error && actual.is_none())
+        {
+            return Ok(());
+        }
+        let describe = |id: Option<&JsonRpcId>| match id {
+            Some(JsonRpcId::String(s)) => format!("\"{s}\""),
+            Some(id) => id.to_string(),
+            None => "null".to_string(),
+        };
+        let message = format!(
+            "{} response id {} does not match request id {}",
+            self.method,
+            describe(actual),
+            describe(self.expected.as_ref())
+        );
+        if self.mode == IdCorrelation::Warn {
+            warn!(method = %self.method, "{message}");
+            return Ok(());
+        }
+        Err(A2AError::ProtocolViolation(message))
+    }
+}
+
+/// Per-call options applied on top of the transport's defaults.
+///
+/// Headers set here are merged with the builder-level headers from
+/// [`TransportConfig`]; on conflict the per-call value wins. Useful for
+/// values that vary per request or per task (deadlines, tenant IDs,
+/// trace baggage).
+///
+/// Python SDK ref: `ClientCallContext` / `http_kwargs` in `client/middleware.py`
+///
+/// # Example
+///
+/// ```
+/// use a2a_rs::client::CallOptions;
+///
+/// let options = CallOptions::new()
+///     .with_header("X-Tenant", "acme")
+///     .with_header("X-Deadline-Ms", "5000");
+/// assert_eq!(options.headers.len(), 2);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CallOptions {
+    /// Extra HTTP headers for this call only.
+    pub headers: HashMap<String, String>,
+    /// Timeout for this call only, overriding [`TransportConfig::timeout`].
+    /// For streams it bounds the whole stream, not just its opening.
+    pub timeout: Option<Duration>,
+}
+
+impl CallOptions {
+    /// Create empty call options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a header for this call (builder-style).
+    pub fn with_header(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.insert(key.into(), value.into());
+        self
+    }
+
+    /// Request A2A extensions for this call via the `X-A2A-Extensions`
+    /// header (builder-style). Replaces any previously requested set.
+    pub fn with_extensions<I, S>(self, uris: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.with_header(HTTP_EXTENSION_HEADER, join_extensions(uris))
+    }
+
+    /// Send `request_id` as this call's `X-A2A-Request-Id` (builder-style)
+    /// instead of the current request ID.
+    pub fn with_request_id(self, request_id: impl Into<String>) -> Self {
+        self.with_header(REQUEST_ID_HEADER, request_id)
+    }
+
+    /// The request ID set for this call, if any.
+    pub fn request_id(&self) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(REQUEST_ID_HEADER))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Send `deadline` as this call's `X-A2A-Deadline` (builder-style)
+    /// instead of the current deadline. See
+    /// [`deadline`](crate::utils::deadline).
+    pub fn with_deadline(self, deadline: DateTime<Utc>) -> Self {
+        self.with_header(DEADLINE_HEADER, format_deadline(deadline))
+    }
+
+    /// The deadline set for this call, if any.
+    pub fn deadline(&self) -> Option<DateTime<Utc>> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(DEADLINE_HEADER))
+            .and_then(|(_, value)| parse_deadline(value))
+    }
+
+    /// Give up on this call after `timeout` (builder-style).
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Merge `other` into a copy of `self`; headers and the timeout in
+    /// `other` take precedence.
+    ///
+    /// Handy for layering per-call headers over per-task ones.
+    pub fn merged_with(&self, other: &CallOptions) -> CallOptions {
+        let mut merged = self.clone();
+        for (key, value) in &other.headers {
+            merged.headers.insert(key.clone(), value.clone());
+        }
+        if other.timeout.is_some() {
+            merged.timeout = other.timeout;
+        }
+        merged
+    }
+}
+
+/// The request ID a call with `options` sends: the one set for the call,
+/// else the [current request ID](current_request_id), else a new one.
+pub(crate) fn outgoing_request_id(options: &CallOptions) -> String {
+    match options.request_id() {
+        Some(request_id) => request_id_or_new(Some(request_id)),
+        None => current_request_id().unwrap_or_else(new_request_id),
+    }
+}
+
+/// The deadline a call with `options` sends: the one set for the call,
+/// else the [current deadline](current_deadline), if any.
+pub(crate) fn outgoing_deadline(options: &CallOptions) -> Option<DateTime<Utc>> {
+    options.deadline().or_else(current_deadline)
+}
+
+/// Join extension URIs into an `X-A2A-Extensions` header value.
+fn join_extensions<I, S>(uris: I) -> String
+where
+    I: IntoIterator<Item = S>,
+    S: Into<String>,
+{
+    uris.into_iter()
+        .map(Into::into)
+        .collect::<Vec<String>>()
+        .join(",")
+}
+
+/// Extensions the server reports as activated in `headers`.
+pub(crate) fn activated_extensions(headers: &HeaderMap) -> HashSet<String> {
+    get_requested_extensions(
+        &headers
+            .get_all(HTTP_EXTENSION_HEADER)
+            .iter()
+            .filter_map(|v| v.to_str().ok().map(String::from))
+            .collect::<Vec<_>>(),
+    )
+}
+
+/// Reassemble a chunked `tasks/get` body into a single response carrying
+/// the full task. The first error line, if any, is the response.
+fn assemble_chunked_response(
+    body: &[u8],
+    request: &JsonRpcRequest,
+    id_correlation: IdCorrelation,
+) -> A2AResult<JsonRpcResponse> {
+    let ids = ResponseIdCheck::new(id_correlation, request);
+    let mut sections = Vec::new();
+    let mut id = None;
+    for line in body
+        .split(|b| *b == b'\n')
+        .filter(|l| !l.iter().all(u8::is_ascii_whitespace))
+    {
+        let response: JsonRpcResponse = serde_json::from_slice(line).map_err(|e| {
+            A2AError::InvalidJson(format!("failed to parse chunked JSON-RPC response: {e}"))
+        })?;
+        ids.verify(response.id.as_ref(), response.error.is_some())?;
+        if response.error.is_some() {
+            return Ok(response);
+        }
+        let section: TaskSection = serde_json::from_value(response.result.unwrap_or_default())
+            .map_err(|e| A2AError::InvalidJson(format!("invalid task section: {e}")))?;
+        sections.push(section);
+        id = response.id;
+    }
+    let task = assemble_task(sections)?;
+    let result = serde_json::to_value(task)
+        .map_err(|e| A2AError::InvalidJson(format!("failed to encode assembled task: {e}")))?;
+    Ok(JsonRpcResponse::success(id, result))
+}
+
+/// Convert a string header map into a `reqwest` [`HeaderMap`], skipping
+/// entries that are not valid header names or values.
+fn to_header_map(headers: &HashMap<String, String>) -> HeaderMap {
+    let mut map = HeaderMap::new();
+    for (key, value) in headers {
+        if let (Ok(name), Ok(val)) = (
+            HeaderName::from_bytes(key.as_bytes()),
+            HeaderValue::from_str(value),
+        ) {
+            map.insert(name, val);
+        }
+    }
+    map
+}
+
+/// `User-Agent` and `X-A2A-Client` headers for `id`, plus the
+/// `A2A-Version` this SDK speaks.
+pub(crate) fn identification_headers(id: &ClientIdentification) -> HeaderMap {
+    let mut map = HeaderMap::new();
+    if let Some(Ok(user_agent)) = id.user_agent.as_deref().map(HeaderValue::from_str) {
+        map.insert(reqwest::header::USER_AGENT, user_agent);
+    }
+    if let Ok(value) = HeaderValue::from_str(&id.header_value()) {
+        map.insert(HeaderName::from_static("x-a2a-client"), value);
+    }
+    map.insert(
+        HeaderName::from_static("a2a-version"),
+        HeaderValue::from_static(PROTOCOL_VERSION),
+    );
+    map
+}
+
+/// Default time before credential expiry at which streams reconnect.
+const DEFAULT_REFRESH_MARGIN: Duration = Duration::from_secs(30);
+
 /// JSON-RPC over HTTP transport using `reqwest`.
 ///
 /// This is the standard transport for the A2A JSON-RPC protocol binding.
@@ -67,6 +725,13 @@ impl Default for TransportConfig {
 ///
 /// For streaming methods, the response is interpreted as an SSE event stream.
 ///
+/// HTTP connections are pooled and opened on demand, so
+/// [`connect()`](Transport::connect) does no I/O; it only marks the endpoint
+/// as connected. Observers see [`TransportEvent::Connected`] on the first
+/// response (or `connect()`), [`TransportEvent::Disconnected`] when a
+/// request fails to reach the endpoint or the transport is closed, and
+/// `StreamOpened` / `StreamClosed` around every SSE stream.
+///
 /// # Example
 ///
 /// ```no_run
@@ -74,10 +739,69 @@ impl Default for TransportConfig {
 ///
 /// let transport = JsonRpcTransport::new("http://localhost:7420/a2a");
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct JsonRpcTransport {
     client: reqwest::Client,
     url: String,
+    config: TransportConfig,
+    observer: Option<Arc<dyn TransportObserver>>,
+    credentials: Option<Arc<dyn CredentialService>>,
+    refresh_margin: Duration,
+    id_correlation: IdCorrelation,
+    interceptors: InterceptorChain,
+    /// Reopen streams that fail mid-task, if set.
+    sse_reconnect: Option<SseReconnectPolicy>,
+    /// Whether the endpoint is currently reachable; shared between clones.
+    connected: Arc<AtomicBool>,
+    #[cfg(feature = "metrics")]
+    stats: Option<super::observer::ConnectionStats>,
+}
+
+impl std::fmt::Debug for JsonRpcTransport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JsonRpcTransport")
+            .field("client", &self.client)
+            .field("url", &self.url)
+            .field("config", &self.config)
+            .field("observer", &self.observer.is_some())
+            .field("credentials", &self.credentials.is_some())
+            .field("refresh_margin", &self.refresh_margin)
+            .field("id_correlation", &self.id_correlation)
+            .field("interceptors", &self.interceptors)
+            .field("sse_reconnect", &self.sse_reconnect)
+            .field("connected", &self.connected.load(Ordering::SeqCst))
+            .finish()
+    }
+}
+
+/// What a call learned from the HTTP response, for the observer.
+#[derive(Default)]
+struct ResponseInfo {
+    status: Option<u16>,
+    activated_extensions: Option<HashSet<String>>,
+    /// The body is a chunked task (see [`crate::utils::chunked_task`]).
+    chunked: bool,
+}
+
+impl ResponseInfo {
+    fn record(&mut self, response: &reqwest::Response) {
+        self.status = Some(response.status().as_u16());
+        self.activated_extensions = Some(activated_extensions(response.headers()));
+        self.chunked = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .is_some_and(|v| v.starts_with(CHUNKED_TASK_CONTENT_TYPE));
+    }
+}
+
+/// An in-flight call being measured for the observer.
+struct CallProbe {
+    method: String,
+    streaming: bool,
+    started: Instant,
+    #[cfg(feature = "metrics")]
+    connects_before: u64,
 }
 
 impl JsonRpcTransport {
@@ -89,26 +813,38 @@ impl JsonRpcTransport {
     }
 
     /// Create a new transport with custom configuration.
+    ///
+    /// Every request carries `User-Agent` and `X-A2A-Client` identification
+    /// headers (see [`with_client_id`](Self::with_client_id)) and
+    /// `A2A-Version`, unless `config.headers` sets them.
+    ///
+    /// With the `metrics` feature, the underlying client is instrumented to
+    /// report DNS and connection timings to an attached observer.
     pub fn with_config(url: impl Into<String>, config: TransportConfig) -> Self {
-        let mut default_headers = HeaderMap::new();
-        for (key, value) in &config.headers {
-            if let (Ok(name), Ok(val)) = (
-                HeaderName::from_bytes(key.as_bytes()),
-                HeaderValue::from_str(value),
-            ) {
-                default_headers.insert(name, val);
-            }
-        }
+        let mut headers = identification_headers(&ClientIdentification::for_app(None));
+        headers.extend(to_header_map(&config.headers));
+        let builder = config.configure(reqwest::Client::builder().default_headers(headers));
+
+        #[cfg(feature = "metrics")]
+        let stats = super::observer::ConnectionStats::default();
+        #[cfg(feature = "metrics")]
+        let builder = stats.instrument(builder);
 
-        let client = reqwest::Client::builder()
-            .timeout(config.timeout)
-            .default_headers(default_headers)
-            .build()
-            .unwrap_or_else(|_| reqwest::Client::new());
+        let client = builder.build().unwrap_or_else(|_| reqwest::Client::new());
 
         Self {
             client,
             url: url.into(),
+            config,
+            observer: None,
+            credentials: None,
+            refresh_margin: DEFAULT_REFRESH_MARGIN,
+            id_correlation: IdCorrelation::default(),
+            interceptors: InterceptorChain::default(),
+            sse_reconnect: None,
+            connected: Arc::default(),
+            #[cfg(feature = "metrics")]
+            stats: Some(stats),
         }
     }
 
@@ -116,10 +852,23 @@ impl JsonRpcTransport {
     ///
     /// Useful when you want to share a connection pool or configure TLS
     /// settings externally.
+    ///
+    /// Connection-level metrics are not available for externally built
+    /// clients; the observer still receives per-call timings.
     pub fn with_client(url: impl Into<String>, client: reqwest::Client) -> Self {
         Self {
             client,
             url: url.into(),
+            config: TransportConfig::default(),
+            observer: None,
+            credentials: None,
+            refresh_margin: DEFAULT_REFRESH_MARGIN,
+            id_correlation: IdCorrelation::default(),
+            interceptors: InterceptorChain::default(),
+            sse_reconnect: None,
+            connected: Arc::default(),
+            #[cfg(feature = "metrics")]
+            stats: None,
         }
     }
 
@@ -128,34 +877,259 @@ impl JsonRpcTransport {
         &self.url
     }
 
+    /// Returns the configuration this transport was built with.
+    pub fn config(&self) -> &TransportConfig {
+        &self.config
+    }
+
     /// Create a transport with a custom timeout (builder-style).
+    ///
+    /// Previously configured headers are kept.
     pub fn with_timeout(self, timeout: Duration) -> Self {
-        let mut config = TransportConfig::default();
-        config.timeout = timeout;
-        Self::with_config(self.url, config)
+        self.rebuild(|config| config.timeout = timeout)
     }
 
     /// Add a custom header (builder-style).
+    ///
+    /// Previously configured headers and timeout are kept.
     pub fn with_header(self, key: &str, value: &str) -> Self {
-        // Rebuild the client with the new header
-        let mut config = TransportConfig::default();
-        config.headers.insert(key.to_string(), value.to_string());
-        Self::with_config(self.url, config)
+        self.rebuild(|config| {
+            config.headers.insert(key.to_string(), value.to_string());
+        })
     }
-}
 
-#[async_trait]
-impl Transport for JsonRpcTransport {
-    async fn send(&self, request: &JsonRpcRequest) -> A2AResult<JsonRpcResponse> {
+    /// Identify the calling application as `app` (e.g. `my-app/2.3`) on
+    /// every call (builder-style).
+    ///
+    /// Sets `X-A2A-Client: sdk=a2a-rs/<version>; app=<app>` and, unless a
+    /// `User-Agent` header was configured explicitly,
+    /// `User-Agent: <app> a2a-rs/<version>`.
+    pub fn with_client_id(self, app: &str) -> Self {
+        let id = ClientIdentification::for_app(Some(app));
+        self.rebuild(|config| {
+            config
+                .headers
+                .insert(CLIENT_IDENTIFICATION_HEADER.to_string(), id.header_value());
+            if !config
+                .headers
+                .keys()
+                .any(|key| key.eq_ignore_ascii_case("user-agent"))
+            {
+                if let Some(user_agent) = id.user_agent {
+                    config.headers.insert("User-Agent".to_string(), user_agent);
+                }
+            }
+        })
+    }
+
+    /// Request A2A extensions on every call via the `X-A2A-Extensions`
+    /// header (builder-style).
+    ///
+    /// Per-call [`CallOptions::with_extensions`] overrides this set.
+    pub fn with_extensions<I, S>(self, uris: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let value = join_extensions(uris);
+        self.rebuild(|config| {
+            config
+                .headers
+                .insert(HTTP_EXTENSION_HEADER.to_string(), value);
+        })
+    }
+
+    /// Rebuild the HTTP client with an updated config, keeping attached hooks.
+    fn rebuild(mut self, update: impl FnOnce(&mut TransportConfig)) -> Self {
+        update(&mut self.config);
+        Self {
+            observer: self.observer,
+            credentials: self.credentials,
+            refresh_margin: self.refresh_margin,
+            id_correlation: self.id_correlation,
+            interceptors: self.interceptors,
+            sse_reconnect: self.sse_reconnect,
+            ..Self::with_config(self.url, self.config)
+        }
+    }
+
+    /// Retry calls that fail transiently per `policy` (builder-style).
+    ///
+    /// See [`TransportConfig::retry`].
+    pub fn with_retry(mut self, policy: RetryPolicy) -> Self {
+        self.config.retry = Some(policy);
+        self
+    }
+
+    /// Attach an observer notified with [`CallMetrics`] after every call
+    /// and with [`TransportEvent`]s on lifecycle changes (builder-style).
+    pub fn with_observer(mut self, observer: Arc<dyn TransportObserver>) -> Self {
+        self.observer = Some(observer);
+        self
+    }
+
+    /// Attach a [`CredentialService`] consulted before every request
+    /// (builder-style).
+    ///
+    /// If the returned [`Credential`] has an expiry, streams opened with it
+    /// reconnect with a fresh credential shortly before it expires and
+    /// resume via `tasks/resubscribe`, without surfacing an error.
+    pub fn with_credentials(mut self, credentials: Arc<dyn CredentialService>) -> Self {
+        self.credentials = Some(credentials);
+        self
+    }
+
+    /// How long before credential expiry a stream reconnects (builder-style).
+    ///
+    /// Defaults to 30 seconds.
+    pub fn with_credential_refresh_margin(mut self, margin: Duration) -> Self {
+        self.refresh_margin = margin;
+        self
+    }
+
+    /// How response ids are checked against request ids (builder-style).
+    ///
+    /// Defaults to [`IdCorrelation::Strict`].
+    pub fn with_id_correlation(mut self, mode: IdCorrelation) -> Self {
+        self.id_correlation = mode;
+        self
+    }
+
+    /// Add a [`TransportInterceptor`] wrapped around every call
+    /// (builder-style). Interceptors see requests in registration order
+    /// and responses in reverse order.
+    pub fn with_interceptor(mut self, interceptor: Arc<dyn TransportInterceptor>) -> Self {
+        self.interceptors.push(interceptor);
+        self
+    }
+
+    /// Reconnect streams that fail with a transient error before the
+    /// task's final event (builder-style).
+    ///
+    /// The task's stream is reopened with `tasks/resubscribe`, carrying
+    /// the last event ID received as `Last-Event-ID`, with backoff per
+    /// `policy`; see [`ReconnectingSseStream`](super::ReconnectingSseStream).
+    pub fn with_sse_reconnect(mut self, policy: SseReconnectPolicy) -> Self {
+        self.sse_reconnect = Some(policy);
+        self
+    }
+
+    /// Returns how response ids are checked against request ids.
+    pub fn id_correlation(&self) -> IdCorrelation {
+        self.id_correlation
+    }
+
+    /// Fetch the credential for the next request, if a service is attached.
+    async fn credential(&self) -> A2AResult<Option<Credential>> {
+        match &self.credentials {
+            Some(service) => service.get_credential().await.map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Start measuring a call, if anyone is observing.
+    fn begin_call(&self, request: &JsonRpcRequest, streaming: bool) -> Option<CallProbe> {
+        self.observer.as_ref()?;
+        Some(CallProbe {
+            method: request.method.clone(),
+            streaming,
+            started: Instant::now(),
+            #[cfg(feature = "metrics")]
+            connects_before: self.stats.as_ref().map_or(0, |s| s.connects()),
+        })
+    }
+
+    /// Report a finished call to the observer.
+    fn finish_call(&self, probe: Option<CallProbe>, info: ResponseInfo, error: Option<&A2AError>) {
+        let (Some(probe), Some(observer)) = (probe, self.observer.as_ref()) else {
+            return;
+        };
+        #[allow(unused_mut)]
+        let mut metrics = CallMetrics {
+            method: probe.method,
+            url: self.url.clone(),
+            streaming: probe.streaming,
+            status: info.status,
+            activated_extensions: info.activated_extensions,
+            elapsed: probe.started.elapsed(),
+            error: error.map(|e| e.to_string()),
+            ..Default::default()
+        };
+        #[cfg(feature = "metrics")]
+        if let Some(stats) = &self.stats {
+            stats.fill(probe.connects_before, &mut metrics);
+        }
+        observer.on_call(&metrics);
+    }
+
+    /// Report a lifecycle event to the observer.
+    fn emit(&self, event: TransportEvent) {
+        if let Some(observer) = &self.observer {
+            observer.on_event(&event);
+        }
+    }
+
+    /// Record whether the endpoint is reachable, emitting
+    /// [`TransportEvent::Connected`] / [`TransportEvent::Disconnected`] on
+    /// changes.
+    fn set_connected(&self, connected: bool, reason: Option<String>) {
+        if self.connected.swap(connected, Ordering::SeqCst) == connected {
+            return;
+        }
+        let endpoint = self.url.clone();
+        self.emit(if connected {
+            TransportEvent::Connected { endpoint }
+        } else {
+            TransportEvent::Disconnected { endpoint, reason }
+        });
+    }
+
+    /// POST a unary JSON-RPC request, recording the HTTP status.
+    async fn post_unary(
+        &self,
+        request: &JsonRpcRequest,
+        options: &CallOptions,
+        credential: Option<&Credential>,
+        info: &mut ResponseInfo,
+    ) -> A2AResult<JsonRpcResponse> {
+        let bytes = self.post(request, options, credential, info).await?;
+        if info.chunked {
+            return assemble_chunked_response(&bytes, request, self.id_correlation);
+        }
+        let response: JsonRpcResponse = serde_json::from_slice(&bytes).map_err(|e| {
+            A2AError::InvalidJson(format!("failed to parse JSON-RPC response: {e}"))
+        })?;
+        ResponseIdCheck::new(self.id_correlation, request)
+            .verify(response.id.as_ref(), response.error.is_some())?;
+        Ok(response)
+    }
+
+    /// POST a JSON-RPC request and return the body of a successful response.
+    async fn post(
+        &self,
+        request: &JsonRpcRequest,
+        options: &CallOptions,
+        credential: Option<&Credential>,
+        info: &mut ResponseInfo,
+    ) -> A2AResult<bytes::Bytes> {
         let body = serde_json::to_vec(request).map_err(|e| {
             A2AError::Transport(format!("failed to serialize JSON-RPC request: {e}"))
         })?;
 
-        let response = self
+        let mut builder = self
             .client
             .post(&self.url)
             .header("Content-Type", "application/json")
-            .body(body)
+            .headers(credential_header(credential))
+            .headers(trace_headers())
+            .header(REQUEST_ID_HEADER, outgoing_request_id(options))
+            .headers(deadline_header(options))
+            .headers(to_header_map(&options.headers))
+            .body(body);
+        if let Some(timeout) = options.timeout {
+            builder = builder.timeout(timeout);
+        }
+        let response = builder
             .send()
             .await
             .map_err(|e| {
@@ -166,8 +1140,14 @@ impl Transport for JsonRpcTransport {
                 } else {
                     A2AError::Transport(format!("HTTP request failed: {e}"))
                 }
+            })
+            .map_err(|e| {
+                self.set_connected(false, Some(e.to_string()));
+                e
             })?;
 
+        info.record(&response);
+        self.set_connected(true, None);
         let status = response.status();
         if !status.is_success() {
             let body_text = response.text().await.unwrap_or_default();
@@ -177,29 +1157,116 @@ impl Transport for JsonRpcTransport {
             });
         }
 
+        response
+            .bytes()
+            .await
+            .map_err(|e| A2AError::Transport(format!("failed to read response body: {e}")))
+    }
+
+    /// POST a `message/sendStream` request as a chunked NDJSON body: the
+    /// request on the first line, then one part per line as `parts`
+    /// yields them.
+    async fn post_upload(
+        &self,
+        request: &JsonRpcRequest,
+        parts: PartStream,
+        options: &CallOptions,
+        credential: Option<&Credential>,
+        info: &mut ResponseInfo,
+    ) -> A2AResult<JsonRpcResponse> {
+        use futures::StreamExt;
+
+        let mut first = serde_json::to_vec(request).map_err(|e| {
+            A2AError::Transport(format!("failed to serialize JSON-RPC request: {e}"))
+        })?;
+        first.push(b'\n');
+        let lines = parts.map(|part| {
+            let mut line = serde_json::to_vec(&part)
+                .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+            line.push(b'\n');
+            Ok::<_, std::io::Error>(bytes::Bytes::from(line))
+        });
+        let body = futures::stream::once(async move { Ok(bytes::Bytes::from(first)) }).chain(lines);
+
+        let mut builder = self
+            .client
+            .post(&self.url)
+            .header("Content-Type", StreamEncoding::Ndjson.content_type())
+            .headers(credential_header(credential))
+            .headers(trace_headers())
+            .header(REQUEST_ID_HEADER, outgoing_request_id(options))
+            .headers(deadline_header(options))
+            .headers(to_header_map(&options.headers))
+            .body(reqwest::Body::wrap_stream(body));
+        if let Some(timeout) = options.timeout {
+            builder = builder.timeout(timeout);
+        }
+        let response = builder
+            .send()
+            .await
+            .map_err(|e| {
+                if e.is_timeout() {
+                    A2AError::Timeout(format!("upload timed out: {e}"))
+                } else if e.is_connect() {
+                    A2AError::Transport(format!("connection failed: {e}"))
+                } else {
+                    A2AError::Transport(format!("upload failed: {e}"))
+                }
+            })
+            .map_err(|e| {
+                self.set_connected(false, Some(e.to_string()));
+                e
+            })?;
+
+        info.record(&response);
+        self.set_connected(true, None);
+        let status = response.status();
+        if !status.is_success() {
+            let body_text = response.text().await.unwrap_or_default();
+            return Err(A2AError::Http {
+                status: status.as_u16(),
+                body: body_text,
+            });
+        }
         let bytes = response
             .bytes()
             .await
             .map_err(|e| A2AError::Transport(format!("failed to read response body: {e}")))?;
-
-        let rpc_response: JsonRpcResponse = serde_json::from_slice(&bytes).map_err(|e| {
+        let response: JsonRpcResponse = serde_json::from_slice(&bytes).map_err(|e| {
             A2AError::InvalidJson(format!("failed to parse JSON-RPC response: {e}"))
         })?;
-
-        Ok(rpc_response)
+        ResponseIdCheck::new(self.id_correlation, request)
+            .verify(response.id.as_ref(), response.error.is_some())?;
+        Ok(response)
     }
 
-    async fn send_stream(&self, request: &JsonRpcRequest) -> A2AResult<SseStream> {
+    /// POST a streaming JSON-RPC request, recording the HTTP status.
+    async fn post_stream(
+        &self,
+        request: &JsonRpcRequest,
+        options: &CallOptions,
+        credential: Option<&Credential>,
+        info: &mut ResponseInfo,
+    ) -> A2AResult<SseStream> {
         let body = serde_json::to_vec(request).map_err(|e| {
             A2AError::Transport(format!("failed to serialize JSON-RPC request: {e}"))
         })?;
 
-        let response = self
+        let mut builder = self
             .client
             .post(&self.url)
             .header("Content-Type", "application/json")
-            .header("Accept", "text/event-stream")
-            .body(body)
+            .header("Accept", self.config.stream_encoding.accept_header())
+            .headers(credential_header(credential))
+            .headers(trace_headers())
+            .header(REQUEST_ID_HEADER, outgoing_request_id(options))
+            .headers(deadline_header(options))
+            .headers(to_header_map(&options.headers))
+            .body(body);
+        if let Some(timeout) = options.timeout {
+            builder = builder.timeout(timeout);
+        }
+        let response = builder
             .send()
             .await
             .map_err(|e| {
@@ -210,8 +1277,14 @@ impl Transport for JsonRpcTransport {
                 } else {
                     A2AError::Transport(format!("stream HTTP request failed: {e}"))
                 }
+            })
+            .map_err(|e| {
+                self.set_connected(false, Some(e.to_string()));
+                e
             })?;
 
+        info.record(&response);
+        self.set_connected(true, None);
         let status = response.status();
         if !status.is_success() {
             let body_text = response.text().await.unwrap_or_default();
@@ -221,6 +1294,420 @@ impl Transport for JsonRpcTransport {
             });
         }
 
-        Ok(SseStream::from_response(response))
+        let guard = self.observer.clone().map(|observer| {
+            observer.on_event(&TransportEvent::StreamOpened {
+                method: request.method.clone(),
+            });
+            StreamGuard {
+                observer,
+                method: request.method.clone(),
+            }
+        });
+        let ids = ResponseIdCheck::new(self.id_correlation, request);
+        let encoding = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .and_then(StreamEncoding::from_content_type);
+        Ok(match encoding {
+            Some(StreamEncoding::Ndjson) => NdjsonStream::into_sse_stream(response, guard, ids),
+            _ => SseStream::from_response(response, guard, ids),
+        })
+    }
+
+    /// Run `attempt`, retrying per the configured [`RetryPolicy`], if any.
+    async fn retrying<T, F, Fut>(&self, request: &JsonRpcRequest, mut attempt: F) -> A2AResult<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: std::future::Future<Output = A2AResult<T>>,
+    {
+        match &self.config.retry {
+            Some(policy) => policy.run(request, attempt).await,
+            None => attempt().await,
+        }
+    }
+
+    /// Make a unary call, bypassing the interceptors.
+    async fn call_unary(
+        &self,
+        request: &JsonRpcRequest,
+        options: &CallOptions,
+    ) -> A2AResult<JsonRpcResponse> {
+        let attempt = || async {
+            let credential = self.credential().await?;
+            let probe = self.begin_call(request, false);
+            let mut info = ResponseInfo::default();
+            let result = self
+                .post_unary(request, options, credential.as_ref(), &mut info)
+                .await;
+            self.finish_call(probe, info, result.as_ref().err());
+            result
+        };
+        let call = self.retrying(request, attempt);
+        #[cfg(feature = "observability")]
+        let call = crate::observability::client_call(request, call);
+        call.await
+    }
+
+    /// Upload a message, bypassing the interceptors. Never retried: the
+    /// parts can't be produced twice.
+    async fn call_upload(
+        &self,
+        request: &JsonRpcRequest,
+        parts: PartStream,
+        options: &CallOptions,
+    ) -> A2AResult<JsonRpcResponse> {
+        let call = async {
+            let credential = self.credential().await?;
+            let probe = self.begin_call(request, false);
+            let mut info = ResponseInfo::default();
+            let result = self
+                .post_upload(request, parts, options, credential.as_ref(), &mut info)
+                .await;
+            self.finish_call(probe, info, result.as_ref().err());
+            result
+        };
+        #[cfg(feature = "observability")]
+        let call = crate::observability::client_call(request, call);
+        call.await
+    }
+
+    /// Send a notification, bypassing the interceptors.
+    async fn call_notify(&self, request: &JsonRpcRequest, options: &CallOptions) -> A2AResult<()> {
+        let call = async {
+            let credential = self.credential().await?;
+            let probe = self.begin_call(request, false);
+            let mut info = ResponseInfo::default();
+            let result = self
+                .post(request, options, credential.as_ref(), &mut info)
+                .await
+                .map(drop);
+            self.finish_call(probe, info, result.as_ref().err());
+            result
+        };
+        #[cfg(feature = "observability")]
+        let call = crate::observability::client_call(request, call);
+        call.await
+    }
+
+    /// Open a stream through the interceptors, returning it with the
+    /// expiry of the credential used.
+    async fn open_stream(
+        &self,
+        request: &JsonRpcRequest,
+        options: &CallOptions,
+    ) -> A2AResult<(SseStream, Option<Instant>)> {
+        if self.interceptors.is_empty() {
+            return self.open_stream_direct(request, options).await;
+        }
+        let mut call = InterceptedCall::new(request.clone(), options.clone(), true);
+        self.interceptors.request(&mut call).await?;
+        let opened = self.open_stream_direct(&call.request, &call.options).await;
+        self.interceptors.stream_opened(&call, opened).await
+    }
+
+    /// Open a stream, bypassing the interceptors; returns it with the
+    /// expiry of the credential used.
+    async fn open_stream_direct(
+        &self,
+        request: &JsonRpcRequest,
+        options: &CallOptions,
+    ) -> A2AResult<(SseStream, Option<Instant>)> {
+        let attempt = || async {
+            let credential = self.credential().await?;
+            let probe = self.begin_call(request, true);
+            let mut info = ResponseInfo::default();
+            let result = self
+                .post_stream(request, options, credential.as_ref(), &mut info)
+                .await;
+            self.finish_call(probe, info, result.as_ref().err());
+            Ok((result?, credential.and_then(|c| c.expires_at)))
+        };
+        let call = self.retrying(request, attempt);
+        #[cfg(feature = "observability")]
+        let call = crate::observability::client_call(request, call);
+        call.await
+    }
+
+    /// Forward `stream`, switching to a `tasks/resubscribe` stream opened
+    /// with a fresh credential before each credential expires.
+    ///
+    /// The new connection is established before the old one is closed.
+    /// Streams that haven't revealed a task ID yet (or reply with a direct
+    /// message) cannot be resumed and are forwarded unchanged.
+    fn resume_on_expiry(
+        self,
+        mut stream: SseStream,
+        expires_at: Instant,
+        options: CallOptions,
+    ) -> SseStream {
+        let (tx, rx) = mpsc::channel(64);
+        let activated = stream.activated_extensions().clone();
+        let last_event_id = Arc::new(Mutex::new(stream.last_event_id()));
+        let slot = last_event_id.clone();
+        let task = tokio::spawn(async move {
+            let mut refresh_at = Some(refresh_deadline(expires_at, self.refresh_margin));
+            let mut task_id: Option<String> = None;
+            loop {
+                let resumable = task_id.is_some() && refresh_at.is_some();
+                let deadline = refresh_at.unwrap_or_else(Instant::now);
+                tokio::select! {
+                    event = stream.next() => {
+                        let Some(event) = event else { return };
+                        let done = match &event {
+                            Ok(StreamResponse::StatusUpdate(e)) => e.r#final,
+                            Ok(StreamResponse::Message(_)) | Err(_) => true,
+                            Ok(_) => false,
+                        };
+                        if let (None, Ok(e)) = (&task_id, &event) {
+                            task_id = event_task_id(e);
+                        }
+                        if let Some(id) = stream.last_event_id() {
+                            *slot.lock().unwrap() = Some(id);
+                        }
+                        if tx.send(event).await.is_err() || done {
+                            return;
+                        }
+                    }
+                    _ = tokio::time::sleep_until(deadline.into()), if resumable => {
+                        let id = task_id.clone().unwrap_or_default();
+                        let next = match resubscribe_request(id) {
+                            Ok(request) => self.open_stream(&request, &options).await,
+                            Err(e) => Err(e),
+                        };
+                        match next {
+                            Ok((next, expires_at)) => {
+                                std::mem::replace(&mut stream, next).abort();
+                                refresh_at = expires_at
+                                    .map(|at| refresh_deadline(at, self.refresh_margin));
+                            }
+                            Err(e) => {
+                                let _ = tx.send(Err(e)).await;
+                                return;
+                            }
+                        }
+                    }
+                }
+            }
+        });
+        SseStream::from_channel(rx, task)
+            .with_activated_extensions(activated)
+            .with_last_event_id(last_event_id)
+    }
+
+    /// Open a stream that resumes on credential expiry (see
+    /// [`resume_on_expiry()`](Self::resume_on_expiry)).
+    async fn open_resumable(
+        &self,
+        request: &JsonRpcRequest,
+        options: &CallOptions,
+    ) -> A2AResult<SseStream> {
+        let (stream, expires_at) = self.open_stream(request, options).await?;
+        Ok(match expires_at {
+            Some(expires_at) => self
+                .clone()
+                .resume_on_expiry(stream, expires_at, options.clone()),
+            None => stream,
+        })
+    }
+
+    /// Follow `stream` across dropped connections per `policy`, reopening
+    /// it with `tasks/resubscribe` and `Last-Event-ID`.
+    fn reconnecting(
+        &self,
+        stream: SseStream,
+        policy: SseReconnectPolicy,
+        options: CallOptions,
+    ) -> SseStream {
+        let transport = self.clone();
+        ReconnectingSseStream::new(stream, policy, move |task_id, last_event_id| {
+            let transport = transport.clone();
+            let options = match last_event_id {
+                Some(id) => options.clone().with_header(LAST_EVENT_ID_HEADER, id),
+                None => options.clone(),
+            };
+            async move {
+                let request = resubscribe_request(task_id)?;
+                transport.open_resumable(&request, &options).await
+            }
+        })
+        .into()
+    }
+}
+
+/// Reports [`TransportEvent::StreamClosed`] when an SSE stream's background
+/// task ends.
+struct StreamGuard {
+    observer: Arc<dyn TransportObserver>,
+    method: String,
+}
+
+impl Drop for StreamGuard {
+    fn drop(&mut self) {
+        self.observer.on_event(&TransportEvent::StreamClosed {
+            method: std::mem::take(&mut self.method),
+        });
+    }
+}
+
+/// Header map carrying `credential`, or empty if there is none.
+/// The `X-A2A-Deadline` header of a call with `options`, if it has a
+/// deadline.
+fn deadline_header(options: &CallOptions) -> HeaderMap {
+    let mut map = HeaderMap::new();
+    if let Some(deadline) = outgoing_deadline(options) {
+        if let Ok(value) = HeaderValue::from_str(&format_deadline(deadline)) {
+            map.insert(DEADLINE_HEADER, value);
+        }
+    }
+    map
+}
+
+fn credential_header(credential: Option<&Credential>) -> HeaderMap {
+    let mut map = HeaderMap::new();
+    if let Some(credential) = credential {
+        if let (Ok(name), Ok(value)) = (
+            HeaderName::from_bytes(credential.header.as_bytes()),
+            HeaderValue::from_str(&credential.value),
+        ) {
+            map.insert(name, value);
+        }
+    }
+    map
+}
+
+/// `traceparent` / `tracestate` headers of the current trace context.
+#[cfg(feature = "observability")]
+fn trace_headers() -> HeaderMap {
+    let headers: HashMap<String, String> = crate::observability::TraceContext::current()
+        .map(|trace| trace.headers())
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(name, value)| (name.to_string(), value))
+        .collect();
+    to_header_map(&headers)
+}
+
+#[cfg(not(feature = "observability"))]
+fn trace_headers() -> HeaderMap {
+    HeaderMap::new()
+}
+
+/// Task ID carried by a stream event, if any.
+pub(super) fn event_task_id(event: &StreamResponse) -> Option<String> {
+    match event {
+        StreamResponse::Task(task) => Some(task.id.clone()),
+        StreamResponse::StatusUpdate(e) => Some(e.task_id.clone()),
+        StreamResponse::ArtifactUpdate(e) => Some(e.task_id.clone()),
+        StreamResponse::Message(m) => m.task_id.clone(),
+    }
+}
+
+/// Build a `tasks/resubscribe` request for `task_id`.
+fn resubscribe_request(task_id: String) -> A2AResult<JsonRpcRequest> {
+    let params = serde_json::to_value(TaskIdParams {
+        id: task_id,
+        metadata: None,
+    })
+    .map_err(|e| A2AError::Transport(format!("failed to serialize request params: {e}")))?;
+    Ok(JsonRpcRequest {
+        jsonrpc: "2.0".to_string(),
+        id: Some(JsonRpcId::String(uuid::Uuid::new_v4().to_string())),
+        method: "tasks/resubscribe".to_string(),
+        params: Some(params),
+    })
+}
+
+#[async_trait]
+impl Transport for JsonRpcTransport {
+    async fn send(&self, request: &JsonRpcRequest) -> A2AResult<JsonRpcResponse> {
+        self.send_with_options(request, &CallOptions::default())
+            .await
+    }
+
+    async fn send_stream(&self, request: &JsonRpcRequest) -> A2AResult<SseStream> {
+        self.send_stream_with_options(request, &CallOptions::default())
+            .await
+    }
+
+    async fn send_with_options(
+        &self,
+        request: &JsonRpcRequest,
+        options: &CallOptions,
+    ) -> A2AResult<JsonRpcResponse> {
+        if self.interceptors.is_empty() {
+            return self.call_unary(request, options).await;
+        }
+        let mut call = InterceptedCall::new(request.clone(), options.clone(), false);
+        self.interceptors.request(&mut call).await?;
+        let result = self.call_unary(&call.request, &call.options).await;
+        self.interceptors.response(&call, result).await
+    }
+
+    /// Streams the parts in a chunked `application/x-ndjson` body, which
+    /// servers built on this SDK's axum integration read line by line.
+    async fn send_upload(
+        &self,
+        request: &JsonRpcRequest,
+        parts: PartStream,
+        options: &CallOptions,
+    ) -> A2AResult<JsonRpcResponse> {
+        if self.interceptors.is_empty() {
+            return self.call_upload(request, parts, options).await;
+        }
+        let mut call = InterceptedCall::new(request.clone(), options.clone(), false);
+        self.interceptors.request(&mut call).await?;
+        let result = self.call_upload(&call.request, parts, &call.options).await;
+        self.interceptors.response(&call, result).await
+    }
+
+    async fn send_stream_with_options(
+        &self,
+        request: &JsonRpcRequest,
+        options: &CallOptions,
+    ) -> A2AResult<SseStream> {
+        let stream = self.open_resumable(request, options).await?;
+        Ok(match &self.sse_reconnect {
+            Some(policy) => self.reconnecting(stream, policy.clone(), options.clone()),
+            None => stream,
+        })
+    }
+
+    async fn notify(&self, request: &JsonRpcRequest) -> A2AResult<()> {
+        self.notify_with_options(request, &CallOptions::default())
+            .await
+    }
+
+    async fn notify_with_options(
+        &self,
+        request: &JsonRpcRequest,
+        options: &CallOptions,
+    ) -> A2AResult<()> {
+        if self.interceptors.is_empty() {
+            return self.call_notify(request, options).await;
+        }
+        let mut call = InterceptedCall::new(request.clone(), options.clone(), false);
+        self.interceptors.request(&mut call).await?;
+        let result = self.call_notify(&call.request, &call.options).await;
+        self.interceptors.finish(&call, &result).await;
+        result
+    }
+
+    async fn connect(&self) -> A2AResult<()> {
+        self.set_connected(true, None);
+        Ok(())
+    }
+
+    async fn close(&self) -> A2AResult<()> {
+        self.set_connected(false, None);
+        Ok(())
+    }
+
+    fn metadata(&self) -> TransportMetadata {
+        TransportMetadata::new("JSONRPC").with_endpoint(&self.url)
+    }
+
+    fn set_observer(&mut self, observer: Arc<dyn TransportObserver>) {
+        self.observer = Some(observer);
     }
 }
@@ -0,0 +1,517 @@
+//! Local agent discovery over mDNS / DNS-SD (`discovery-mdns` feature).
+//!
+//! For LAN and development setups: a server announces its agent base URL
+//! with [`advertise`], and a client enumerates every agent on the local
+//! network with [`discover_local`], which then resolves each URL through
+//! [`CardResolver`].
+//!
+//! Agents are published under the DNS-SD service type `_a2a._tcp.local` as a
+//! `PTR` record pointing to an instance name, plus a `TXT` record on that
+//! instance carrying `url=<base URL>`. The URL travels in the `TXT` record,
+//! so no `SRV`/`A` lookups are needed. The implementation is a minimal
+//! responder and one-shot querier (RFC 6762 legacy unicast), not a general
+//! mDNS stack.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use a2a_rs::discovery::{advertise, discover_local};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! // Server side: announce the agent while the handle is alive.
+//! let _advertiser = advertise("Echo Agent", "http://192.168.1.20:3000").await?;
+//!
+//! // Client side, anywhere on the LAN:
+//! for card in discover_local().await? {
+//!     println!("found {} v{}", card.name, card.version);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashSet;
+use std::net::{Ipv4Addr, SocketAddr, SocketAddrV4};
+use std::time::Duration;
+
+use socket2::{Domain, Protocol, Socket, Type};
+use tokio::net::UdpSocket;
+use tokio::task::JoinHandle;
+
+use crate::client::CardResolver;
+use crate::error::{A2AError, A2AResult};
+use crate::types::AgentCard;
+
+/// DNS-SD service type under which A2A agents are announced.
+pub const SERVICE_TYPE: &str = "_a2a._tcp.local";
+
+/// IPv4 mDNS multicast group.
+pub const MDNS_ADDR: Ipv4Addr = Ipv4Addr::new(224, 0, 0, 251);
+
+/// mDNS UDP port.
+pub const MDNS_PORT: u16 = 5353;
+
+/// How long [`discover_local`] collects answers.
+pub const DEFAULT_DISCOVERY_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// TTL of announced records, in seconds.
+const RECORD_TTL: u32 = 120;
+
+const TYPE_PTR: u16 = 12;
+const TYPE_TXT: u16 = 16;
+const TYPE_ANY: u16 = 255;
+const CLASS_IN: u16 = 1;
+/// mDNS "cache flush" bit on the class of unique records.
+const CACHE_FLUSH: u16 = 0x8000;
+const FLAGS_RESPONSE: u16 = 0x8400;
+
+// ---------------------------------------------------------------------------
+// Advertising
+// ---------------------------------------------------------------------------
+
+/// Announce an agent on the local network until the returned handle is
+/// dropped or [stopped](MdnsAdvertiser::stop).
+///
+/// `instance_name` is the human-readable DNS-SD instance (typically the
+/// agent card's name) and `url` the base URL clients pass to
+/// [`CardResolver::resolve`]. Use a URL reachable from other hosts — a
+/// `localhost` URL is only useful to clients on the same machine.
+///
+/// # Errors
+///
+/// Returns [`A2AError::InvalidParams`] if `instance_name` is empty or longer
+/// than 63 bytes, or if the `url` does not fit in a TXT record, and
+/// [`A2AError::Transport`] if the multicast socket cannot be set up.
+pub async fn advertise(instance_name: &str, url: &str) -> A2AResult<MdnsAdvertiser> {
+    let record = ServiceRecord::new(instance_name, url)?;
+    let socket = multicast_socket().map_err(transport_error)?;
+
+    let announcement = record.response(0, RECORD_TTL);
+    let group = SocketAddr::V4(SocketAddrV4::new(MDNS_ADDR, MDNS_PORT));
+    socket
+        .send_to(&announcement, group)
+        .await
+        .map_err(transport_error)?;
+
+    let task = tokio::spawn(respond(socket, record.clone()));
+    Ok(MdnsAdvertiser { record, task })
+}
+
+/// Announce `card` under its name at `url`. See [`advertise`].
+pub async fn advertise_card(card: &AgentCard, url: &str) -> A2AResult<MdnsAdvertiser> {
+    advertise(&card.name, url).await
+}
+
+/// Handle of a running mDNS announcement, returned by [`advertise`].
+///
+/// Dropping the handle stops answering queries; [`stop`](Self::stop) also
+/// sends a goodbye packet so clients forget the agent immediately.
+#[derive(Debug)]
+pub struct MdnsAdvertiser {
+    record: ServiceRecord,
+    task: JoinHandle<()>,
+}
+
+impl MdnsAdvertiser {
+    /// The announced DNS-SD instance name.
+    pub fn instance_name(&self) -> &str {
+        &self.record.instance
+    }
+
+    /// The announced agent base URL.
+    pub fn url(&self) -> &str {
+        &self.record.url
+    }
+
+    /// Stop answering queries and send a goodbye (TTL 0) announcement.
+    pub async fn stop(self) -> A2AResult<()> {
+        self.task.abort();
+        let socket = UdpSocket::bind((Ipv4Addr::UNSPECIFIED, 0))
+            .await
+            .map_err(transport_error)?;
+        let goodbye = self.record.response(0, 0);
+        socket
+            .send_to(&goodbye, SocketAddrV4::new(MDNS_ADDR, MDNS_PORT))
+            .await
+            .map_err(transport_error)?;
+        Ok(())
+    }
+}
+
+impl Drop for MdnsAdvertiser {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Answer `_a2a._tcp.local` queries until aborted.
+async fn respond(socket: UdpSocket, record: ServiceRecord) {
+    let mut buf = vec![0u8; 9000];
+    loop {
+        let (len, from) = match socket.recv_from(&mut buf).await {
+            Ok(received) => received,
+            Err(e) => {
+                tracing::warn!(error = %e, "mDNS advertiser receive failed");
+                continue;
+            }
+        };
+        let Some(query_id) = parse_service_query(&buf[..len]) else {
+            continue;
+        };
+        // Legacy unicast queriers (source port != 5353) expect a direct reply
+        // echoing the query ID; full mDNS queriers listen on the group.
+        let (reply, to) = if from.port() == MDNS_PORT {
+            (
+                record.response(0, RECORD_TTL),
+                SocketAddr::V4(SocketAddrV4::new(MDNS_ADDR, MDNS_PORT)),
+            )
+        } else {
+            (record.response(query_id, RECORD_TTL), from)
+        };
+        if let Err(e) = socket.send_to(&reply, to).await {
+            tracing::warn!(error = %e, %to, "mDNS advertiser reply failed");
+        }
+    }
+}
+
+/// Socket bound to the mDNS port and joined to the multicast group, shared
+/// with any other responder on the host.
+fn multicast_socket() -> std::io::Result<UdpSocket> {
+    let socket = Socket::new(Domain::IPV4, Type::DGRAM, Some(Protocol::UDP))?;
+    socket.set_reuse_address(true)?;
+    #[cfg(unix)]
+    socket.set_reuse_port(true)?;
+    socket.bind(&SocketAddrV4::new(Ipv4Addr::UNSPECIFIED, MDNS_PORT).into())?;
+    socket.join_multicast_v4(&MDNS_ADDR, &Ipv4Addr::UNSPECIFIED)?;
+    socket.set_multicast_ttl_v4(255)?;
+    socket.set_multicast_loop_v4(true)?;
+    socket.set_nonblocking(true)?;
+    UdpSocket::from_std(socket.into())
+}
+
+// ---------------------------------------------------------------------------
+// Discovery
+// ---------------------------------------------------------------------------
+
+/// Enumerate agents announced on the local network.
+///
+/// Equivalent to [`discover_local_with_timeout`] with
+/// [`DEFAULT_DISCOVERY_TIMEOUT`].
+pub async fn discover_local() -> A2AResult<Vec<AgentCard>> {
+    discover_local_with_timeout(DEFAULT_DISCOVERY_TIMEOUT).await
+}
+
+/// Enumerate agents announced on the local network, collecting answers for
+/// `timeout` and resolving each announced URL to its [`AgentCard`].
+///
+/// Agents whose card cannot be fetched are skipped with a warning.
+pub async fn discover_local_with_timeout(timeout: Duration) -> A2AResult<Vec<AgentCard>> {
+    let resolver = CardResolver::new();
+    let mut cards = Vec::new();
+    for url in discover_local_urls(timeout).await? {
+        match resolver.resolve(&url).await {
+            Ok(card) => cards.push(card),
+            Err(e) => tracing::warn!(%url, error = %e, "skipping unresolvable local agent"),
+        }
+    }
+    Ok(cards)
+}
+
+/// Enumerate the base URLs announced on the local network within `timeout`,
+/// without fetching agent cards. URLs are deduplicated, in arrival order.
+pub async fn discover_local_urls(timeout: Duration) -> A2AResult<Vec<String>> {
+    let socket = UdpSocket::bind((Ipv4Addr::UNSPECIFIED, 0))
+        .await
+        .map_err(transport_error)?;
+    let query_id = (uuid::Uuid::new_v4().as_u128() as u16).max(1);
+    socket
+        .send_to(
+            &service_query(query_id),
+            SocketAddrV4::new(MDNS_ADDR, MDNS_PORT),
+        )
+        .await
+        .map_err(transport_error)?;
+
+    let mut seen = HashSet::new();
+    let mut urls = Vec::new();
+    let mut buf = vec![0u8; 9000];
+    let deadline = tokio::time::Instant::now() + timeout;
+    while let Ok(received) = tokio::time::timeout_at(deadline, socket.recv_from(&mut buf)).await {
+        let (len, _) = received.map_err(transport_error)?;
+        for url in parse_announced_urls(&buf[..len]) {
+            if seen.insert(url.clone()) {
+                urls.push(url);
+            }
+        }
+    }
+    Ok(urls)
+}
+
+fn transport_error(e: std::io::Error) -> A2AError {
+    A2AError::Transport(format!("mDNS: {e}"))
+}
+
+// ---------------------------------------------------------------------------
+// Wire format
+// ---------------------------------------------------------------------------
+
+/// An announced agent: one PTR and one TXT record.
+#[derive(Debug, Clone)]
+struct ServiceRecord {
+    instance: String,
+    url: String,
+}
+
+impl ServiceRecord {
+    fn new(instance: &str, url: &str) -> A2AResult<Self> {
+        if instance.is_empty() || instance.len() > 63 {
+            return Err(A2AError::InvalidParams {
+                message: format!(
+                    "mDNS instance name must be 1-63 bytes, got {}",
+                    instance.len()
+                ),
+                data: None,
+            });
+        }
+        if url.len() + "url=".len() > 255 {
+            return Err(A2AError::InvalidParams {
+                message: "Agent URL is too long for an mDNS TXT record".to_string(),
+                data: None,
+            });
+        }
+        Ok(Self {
+            instance: instance.to_string(),
+            url: url.to_string(),
+        })
+    }
+
+    /// Response packet with both records at `ttl` (0 = goodbye).
+    fn response(&self, id: u16, ttl: u32) -> Vec<u8> {
+        let mut packet = header(id, FLAGS_RESPONSE, 0, 2);
+        let instance_labels: Vec<&str> = std::iter::once(self.instance.as_str())
+            .chain(SERVICE_TYPE.split('.'))
+            .collect();
+
+        write_name(&mut packet, SERVICE_TYPE.split('.'));
+        let mut rdata = Vec::new();
+        write_name(&mut rdata, instance_labels.iter().copied());
+        write_record(&mut packet, TYPE_PTR, CLASS_IN, ttl, &rdata);
+
+        write_name(&mut packet, instance_labels.iter().copied());
+        let mut rdata = Vec::new();
+        for entry in ["txtvers=1".to_string(), format!("url={}", self.url)] {
+            rdata.push(entry.len() as u8);
+            rdata.extend_from_slice(entry.as_bytes());
+        }
+        write_record(&mut packet, TYPE_TXT, CLASS_IN | CACHE_FLUSH, ttl, &rdata);
+        packet
+    }
+}
+
+fn header(id: u16, flags: u16, questions: u16, answers: u16) -> Vec<u8> {
+    let mut packet = Vec::with_capacity(512);
+    for field in [id, flags, questions, answers, 0, 0] {
+        packet.extend_from_slice(&field.to_be_bytes());
+    }
+    packet
+}
+
+fn write_name<'a>(buf: &mut Vec<u8>, labels: impl Iterator<Item = &'a str>) {
+    for label in labels {
+        buf.push(label.len() as u8);
+        buf.extend_from_slice(label.as_bytes());
+    }
+    buf.push(0);
+}
+
+fn write_record(buf: &mut Vec<u8>, rtype: u16, class: u16, ttl: u32, rdata: &[u8]) {
+    buf.extend_from_slice(&rtype.to_be_bytes());
+    buf.extend_from_slice(&class.to_be_bytes());
+    buf.extend_from_slice(&ttl.to_be_bytes());
+    buf.extend_from_slice(&(rdata.len() as u16).to_be_bytes());
+    buf.extend_from_slice(rdata);
+}
+
+/// PTR query for [`SERVICE_TYPE`].
+fn service_query(id: u16) -> Vec<u8> {
+    let mut packet = header(id, 0, 1, 0);
+    write_name(&mut packet, SERVICE_TYPE.split('.'));
+    packet.extend_from_slice(&TYPE_PTR.to_be_bytes());
+    packet.extend_from_slice(&CLASS_IN.to_be_bytes());
+    packet
+}
+
+/// Big-endian reader over a DNS packet.
+struct Reader<'a> {
+    packet: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn u16(&mut self) -> Option<u16> {
+        let bytes = self.packet.get(self.pos..self.pos + 2)?;
+        self.pos += 2;
+        Some(u16::from_be_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        Some((u32::from(self.u16()?) << 16) | u32::from(self.u16()?))
+    }
+
+    fn bytes(&mut self, len: usize) -> Option<&'a [u8]> {
+        let bytes = self.packet.get(self.pos..self.pos + len)?;
+        self.pos += len;
+        Some(bytes)
+    }
+
+    /// Read a possibly compressed name, returning it dot-joined.
+    fn name(&mut self) -> Option<String> {
+        let mut labels = Vec::new();
+        let mut pos = self.pos;
+        let mut resume = None;
+        // Bounded so a pointer loop cannot spin forever.
+        for _ in 0..128 {
+            let len = *self.packet.get(pos)? as usize;
+            if len == 0 {
+                self.pos = resume.unwrap_or(pos + 1);
+                return Some(labels.join("."));
+            }
+            if len & 0xC0 == 0xC0 {
+                let low = *self.packet.get(pos + 1)? as usize;
+                resume.get_or_insert(pos + 2);
+                pos = ((len & 0x3F) << 8) | low;
+                continue;
+            }
+            let label = self.packet.get(pos + 1..pos + 1 + len)?;
+            labels.push(String::from_utf8_lossy(label).into_owned());
+            pos += 1 + len;
+        }
+        None
+    }
+}
+
+/// If `packet` is a query asking for [`SERVICE_TYPE`] PTR records, its ID.
+fn parse_service_query(packet: &[u8]) -> Option<u16> {
+    let mut reader = Reader { packet, pos: 0 };
+    let id = reader.u16()?;
+    let flags = reader.u16()?;
+    if flags & 0x8000 != 0 {
+        return None;
+    }
+    let questions = reader.u16()?;
+    reader.pos = 12;
+    for _ in 0..questions {
+        let name = reader.name()?;
+        let qtype = reader.u16()?;
+        let _class = reader.u16()?;
+        if (qtype == TYPE_PTR || qtype == TYPE_ANY) && name.eq_ignore_ascii_case(SERVICE_TYPE) {
+            return Some(id);
+        }
+    }
+    None
+}
+
+/// `url=` values of live (TTL > 0) A2A TXT records in a response packet.
+fn parse_announced_urls(packet: &[u8]) -> Vec<String> {
+    let mut reader = Reader { packet, pos: 0 };
+    let mut urls = Vec::new();
+    let header = (|| {
+        let _id = reader.u16()?;
+        let flags = reader.u16()?;
+        let counts = [reader.u16()?, reader.u16()?, reader.u16()?, reader.u16()?];
+        Some((flags, counts))
+    })();
+    let Some((flags, [questions, answers, authority, additional])) = header else {
+        return urls;
+    };
+    if flags & 0x8000 == 0 {
+        return urls;
+    }
+    for _ in 0..questions {
+        if reader.name().and(reader.u16()).and(reader.u16()).is_none() {
+            return urls;
+        }
+    }
+    let suffix = format!(".{SERVICE_TYPE}").to_ascii_lowercase();
+    for _ in 0..(answers as usize + authority as usize + additional as usize) {
+        let Some((name, rtype, ttl, rdata)) = (|| {
+            let name = reader.name()?;
+            let rtype = reader.u16()?;
+            let _class = reader.u16()?;
+            let ttl = reader.u32()?;
+            let len = reader.u16()? as usize;
+            Some((name, rtype, ttl, reader.bytes(len)?))
+        })() else {
+            break;
+        };
+        if rtype != TYPE_TXT || ttl == 0 || !name.to_ascii_lowercase().ends_with(&suffix) {
+            continue;
+        }
+        let mut rest = rdata;
+        while let Some((&len, tail)) = rest.split_first() {
+            let Some(entry) = tail.get(..len as usize) else {
+                break;
+            };
+            if let Some(url) = std::str::from_utf8(entry)
+                .ok()
+                .and_then(|s| s.strip_prefix("url="))
+            {
+                urls.push(url.to_string());
+            }
+            rest = &tail[len as usize..];
+        }
+    }
+    urls
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_response_round_trips_url() {
+        let record = ServiceRecord::new("Echo Agent", "http://10.0.0.5:3000").unwrap();
+        assert_eq!(
+            parse_announced_urls(&record.response(7, RECORD_TTL)),
+            vec!["http://10.0.0.5:3000"]
+        );
+    }
+
+    #[test]
+    fn test_goodbye_is_ignored() {
+        let record = ServiceRecord::new("Echo Agent", "http://10.0.0.5:3000").unwrap();
+        assert!(parse_announced_urls(&record.response(0, 0)).is_empty());
+    }
+
+    #[test]
+    fn test_query_is_recognized() {
+        assert_eq!(parse_service_query(&service_query(42)), Some(42));
+        let record = ServiceRecord::new("Echo Agent", "http://x").unwrap();
+        assert_eq!(parse_service_query(&record.response(42, RECORD_TTL)), None);
+    }
+
+    #[test]
+    fn test_compressed_names() {
+        // Response whose TXT owner name points back into the PTR rdata.
+        let mut packet = header(0, FLAGS_RESPONSE, 0, 2);
+        write_name(&mut packet, SERVICE_TYPE.split('.'));
+        let mut rdata = vec![4];
+        rdata.extend_from_slice(b"echo");
+        rdata.extend_from_slice(&[0xC0, 12]);
+        write_record(&mut packet, TYPE_PTR, CLASS_IN, RECORD_TTL, &rdata);
+        let instance_offset = (packet.len() - rdata.len()) as u8;
+        packet.extend_from_slice(&[0xC0, instance_offset]);
+        let entry = b"url=http://h:1";
+        let mut txt = vec![entry.len() as u8];
+        txt.extend_from_slice(entry);
+        write_record(&mut packet, TYPE_TXT, CLASS_IN, RECORD_TTL, &txt);
+
+        assert_eq!(parse_announced_urls(&packet), vec!["http://h:1"]);
+    }
+
+    #[test]
+    fn test_instance_name_limits() {
+        assert!(ServiceRecord::new("", "http://x").is_err());
+        assert!(ServiceRecord::new(&"a".repeat(64), "http://x").is_err());
+        assert!(ServiceRecord::new("ok", &"u".repeat(300)).is_err());
+    }
+}
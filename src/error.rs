@@ -3,8 +3,11 @@
 //! Mirrors the Python SDK's error handling:
 //! - Standard JSON-RPC 2.0 errors (-32700 through -32603)
 //! - A2A-specific errors (-32001 through -32007)
+//! - [`RATE_LIMITED`] (-32029), sent by the server's rate limiter
+//! - [`DEADLINE_EXCEEDED`] (-32030), sent when a request's deadline passes
 
-use crate::types::JsonRpcError;
+use crate::types::{JsonRpcError, Task};
+use crate::utils::{CardValidationError, CompatibilityIssue};
 
 // ---------------------------------------------------------------------------
 // Standard JSON-RPC 2.0 error codes
@@ -50,6 +53,14 @@ pub const INVALID_AGENT_RESPONSE: i64 = -32006;
 /// Authenticated extended card is not configured.
 pub const AUTHENTICATED_EXTENDED_CARD_NOT_CONFIGURED: i64 = -32007;
 
+/// The caller sent too many requests; `data.retryAfterMs` says when to
+/// try again. An SDK extension, not an A2A code.
+pub const RATE_LIMITED: i64 = -32029;
+
+/// The request's deadline passed before the task finished; see
+/// [`deadline`](crate::utils::deadline). An SDK extension, not an A2A code.
+pub const DEADLINE_EXCEEDED: i64 = -32030;
+
 // ---------------------------------------------------------------------------
 // A2AError enum
 // ---------------------------------------------------------------------------
@@ -176,6 +187,61 @@ pub enum A2AError {
         data: Option<serde_json::Value>,
     },
 
+    /// A request body, message part or artifact over the server's size
+    /// limit. Sent as code -32600; the data carries the `limit` in bytes
+    /// and, when known, the `size`.
+    #[error("Payload too large: {message}")]
+    PayloadTooLarge {
+        /// Human-readable error message.
+        message: String,
+        /// Optional structured error data.
+        data: Option<serde_json::Value>,
+    },
+
+    /// A task status change the task state machine forbids, e.g. leaving a
+    /// terminal state (see [`TaskState::can_transition_to`]). Sent as code
+    /// -32603; the data carries `taskId`, `from` and `to`.
+    ///
+    /// [`TaskState::can_transition_to`]: crate::types::TaskState::can_transition_to
+    #[error("Invalid state transition: {message}")]
+    InvalidStateTransition {
+        /// Human-readable error message.
+        message: String,
+        /// Optional structured error data.
+        data: Option<serde_json::Value>,
+    },
+
+    /// A task store update expected the task at a version it has moved
+    /// past: another writer changed it first. Sent as code -32603; the
+    /// data carries `taskId`, `expected` and `actual`.
+    #[error("Version conflict: {message}")]
+    VersionConflict {
+        /// Human-readable error message.
+        message: String,
+        /// Optional structured error data.
+        data: Option<serde_json::Value>,
+    },
+
+    /// The request's deadline passed before the task finished (code
+    /// -32030); the data carries `taskId` and `deadline`.
+    #[error("Deadline exceeded: {message}")]
+    DeadlineExceeded {
+        /// Human-readable error message.
+        message: String,
+        /// Optional structured error data.
+        data: Option<serde_json::Value>,
+    },
+
+    /// Too many requests (code -32029); the data carries `retryAfterMs`.
+    /// See [`retry_after()`](A2AError::retry_after).
+    #[error("Rate limited: {message}")]
+    RateLimited {
+        /// Human-readable error message.
+        message: String,
+        /// Optional structured error data.
+        data: Option<serde_json::Value>,
+    },
+
     // -- Client/transport-side errors (not A2A error codes) --
     /// Transport-level error (connection failed, request failed, etc.).
     #[error("Transport error: {0}")]
@@ -198,6 +264,51 @@ pub enum A2AError {
     #[error("Invalid JSON: {0}")]
     InvalidJson(String),
 
+    /// The remote agent broke the JSON-RPC protocol, e.g. a response whose
+    /// `id` does not match the request.
+    #[error("Protocol violation: {0}")]
+    ProtocolViolation(String),
+
+    /// The message doesn't fit the remote agent's card, detected
+    /// client-side before sending (see
+    /// [`check_message_compatibility`](crate::utils::check_message_compatibility)).
+    #[error("Incompatible agent: {message}")]
+    IncompatibleAgent {
+        /// The issues, joined into one line.
+        message: String,
+        /// Every reason the agent can't handle the message.
+        issues: Vec<CompatibilityIssue>,
+    },
+
+    /// An agent card doesn't conform to the specification (see
+    /// [`validate_agent_card`](crate::utils::validate_agent_card)).
+    #[error("Invalid agent card: {message}")]
+    InvalidAgentCard {
+        /// The errors, joined into one line.
+        message: String,
+        /// Every spec violation found in the card.
+        errors: Vec<CardValidationError>,
+    },
+
+    /// A task the client waited for ended `failed` (see
+    /// [`A2AClient::wait_for_completion`](crate::client::A2AClient::wait_for_completion)).
+    #[error("Task {} failed: {message}", .task.id)]
+    TaskFailed {
+        /// Text of the task's final status message.
+        message: String,
+        /// The failed task.
+        task: Box<Task>,
+    },
+
+    /// A task the client waited for ended `rejected`.
+    #[error("Task {} was rejected: {message}", .task.id)]
+    TaskRejected {
+        /// Text of the task's final status message.
+        message: String,
+        /// The rejected task.
+        task: Box<Task>,
+    },
+
     /// A JSON-RPC error response was received from the remote agent.
     #[error("JSON-RPC error {code}: {message}")]
     JsonRpc {
@@ -244,6 +355,26 @@ impl A2AError {
         }
     }
 
+    /// Create an `IncompatibleAgent` from a non-empty list of issues.
+    pub fn incompatible_agent(issues: Vec<CompatibilityIssue>) -> Self {
+        let message = issues
+            .iter()
+            .map(|issue| issue.message.as_str())
+            .collect::<Vec<_>>()
+            .join("; ");
+        Self::IncompatibleAgent { message, issues }
+    }
+
+    /// Create an `InvalidAgentCard` from a non-empty list of errors.
+    pub fn invalid_agent_card(errors: Vec<CardValidationError>) -> Self {
+        let message = errors
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join("; ");
+        Self::InvalidAgentCard { message, errors }
+    }
+
     /// Create an `InvalidParams` with a message and no data.
     pub fn invalid_params(message: impl Into<String>) -> Self {
         Self::InvalidParams {
@@ -316,6 +447,233 @@ impl A2AError {
         }
     }
 
+    /// Create a `PayloadTooLarge` error for `what` (e.g. "Request body")
+    /// of `size` bytes, if known, over `limit`.
+    pub fn payload_too_large(what: &str, size: Option<usize>, limit: usize) -> Self {
+        let message = match size {
+            Some(size) => format!("{what} is {size} bytes, over the limit of {limit} bytes"),
+            None => format!("{what} is over the limit of {limit} bytes"),
+        };
+        let mut data = serde_json::json!({ "limit": limit });
+        if let Some(size) = size {
+            data["size"] = size.into();
+        }
+        Self::PayloadTooLarge {
+            message,
+            data: Some(data),
+        }
+    }
+
+    /// Create an `InvalidStateTransition` error for task `task_id` moving
+    /// from `from` to `to`.
+    pub fn invalid_state_transition(
+        task_id: &str,
+        from: crate::types::TaskState,
+        to: crate::types::TaskState,
+    ) -> Self {
+        let message = if from.is_terminal() {
+            format!("Task {task_id} is already in terminal state {from}; cannot move to {to}")
+        } else {
+            format!("Task {task_id} cannot move from {from} to {to}")
+        };
+        Self::InvalidStateTransition {
+            message,
+            data: Some(serde_json::json!({ "taskId": task_id, "from": from, "to": to })),
+        }
+    }
+
+    /// Create a `VersionConflict` error for task `task_id`, expected at
+    /// version `expected` but stored at `actual`.
+    pub fn version_conflict(task_id: &str, expected: u64, actual: u64) -> Self {
+        Self::VersionConflict {
+            message: format!("Task {task_id} is at version {actual}, not {expected}"),
+            data: Some(serde_json::json!({
+                "taskId": task_id,
+                "expected": expected,
+                "actual": actual,
+            })),
+        }
+    }
+
+    /// Create a `DeadlineExceeded` error for a task that didn't finish by
+    /// `deadline`.
+    pub fn deadline_exceeded(task_id: &str, deadline: chrono::DateTime<chrono::Utc>) -> Self {
+        let deadline = crate::utils::format_deadline(deadline);
+        Self::DeadlineExceeded {
+            message: format!("Task {task_id} did not finish by {deadline}"),
+            data: Some(serde_json::json!({
+                "taskId": task_id,
+                "deadline": deadline,
+            })),
+        }
+    }
+
+    /// Whether this is a `DeadlineExceeded` error, or a `JsonRpc` error
+    /// carrying [`DEADLINE_EXCEEDED`] as received by clients.
+    pub fn is_deadline_exceeded(&self) -> bool {
+        matches!(
+            self,
+            A2AError::DeadlineExceeded { .. }
+                | A2AError::JsonRpc {
+                    code: DEADLINE_EXCEEDED,
+                    ..
+                }
+        )
+    }
+
+    /// Create a `RateLimited` error asking the caller to wait
+    /// `retry_after`.
+    pub fn rate_limited(retry_after: std::time::Duration) -> Self {
+        let millis = u64::try_from(retry_after.as_millis()).unwrap_or(u64::MAX);
+        Self::RateLimited {
+            message: format!("retry after {:.3}s", retry_after.as_secs_f64()),
+            data: Some(serde_json::json!({ "retryAfterMs": millis })),
+        }
+    }
+
+    /// How long to wait before retrying a rate-limited call.
+    ///
+    /// Read from the `retryAfterMs` data of a `RateLimited` error, or of a
+    /// `JsonRpc` error or `429` response body carrying [`RATE_LIMITED`].
+    pub fn retry_after(&self) -> Option<std::time::Duration> {
+        let data = match self {
+            A2AError::RateLimited { data, .. } => data.clone(),
+            A2AError::JsonRpc {
+                code: RATE_LIMITED,
+                data,
+                ..
+            } => data.clone(),
+            A2AError::Http { status: 429, body } => {
+                let body: serde_json::Value = serde_json::from_str(body).ok()?;
+                let error = body.get("error")?;
+                if error.get("code")?.as_i64()? != RATE_LIMITED {
+                    return None;
+                }
+                error.get("data").cloned()
+            }
+            _ => None,
+        };
+        let millis = data?.get("retryAfterMs")?.as_u64()?;
+        Some(std::time::Duration::from_millis(millis))
+    }
+
+    /// Attach structured `data` for the client, e.g. a quota error's
+    /// limits or a validation error's field list.
+    ///
+    /// Protocol errors keep their code; errors without a data slot
+    /// (transport errors, [`Other`](A2AError::Other), ...) become an
+    /// [`InternalError`](A2AError::InternalError) with the same message.
+    /// Data that fails to serialize is dropped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use a2a_rs::error::A2AError;
+    ///
+    /// let err = A2AError::invalid_params("Unknown city")
+    ///     .with_data(serde_json::json!({"field": "city"}));
+    /// assert_eq!(err.data().unwrap()["field"], "city");
+    /// ```
+    pub fn with_data(mut self, data: impl serde::Serialize) -> Self {
+        let value = serde_json::to_value(data).ok();
+        match self.protocol_data_mut() {
+            Some(slot) => {
+                *slot = value;
+                self
+            }
+            None => Self::InternalError {
+                message: self.to_string(),
+                data: value,
+            },
+        }
+    }
+
+    /// The structured data of this error, if any.
+    ///
+    /// For protocol and JSON-RPC errors this is the `data` member. For
+    /// [`TaskFailed`](A2AError::TaskFailed) and
+    /// [`TaskRejected`](A2AError::TaskRejected) it is the data of the
+    /// error the server recorded in the task's `error` metadata.
+    pub fn data(&self) -> Option<&serde_json::Value> {
+        match self {
+            A2AError::TaskFailed { task, .. } | A2AError::TaskRejected { task, .. } => task
+                .metadata
+                .as_ref()?
+                .get("error")?
+                .get("data")
+                .filter(|data| !data.is_null()),
+            _ => self.protocol_data(),
+        }
+    }
+
+    /// Deserialize the error's [`data()`](Self::data) into `T`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`A2AError::InvalidParams`] if the error carries no data or
+    /// it doesn't match `T`.
+    pub fn error_data<T: serde::de::DeserializeOwned>(&self) -> A2AResult<T> {
+        let data = self
+            .data()
+            .ok_or_else(|| A2AError::invalid_params("Error carries no data"))?;
+        T::deserialize(data).map_err(|e| {
+            A2AError::invalid_params(format!(
+                "Error data is not a valid {}: {e}",
+                std::any::type_name::<T>()
+            ))
+        })
+    }
+
+    /// The `data` member of protocol and JSON-RPC errors.
+    fn protocol_data(&self) -> Option<&serde_json::Value> {
+        match self {
+            A2AError::ParseError { data, .. }
+            | A2AError::InvalidRequest { data, .. }
+            | A2AError::MethodNotFound { data, .. }
+            | A2AError::InvalidParams { data, .. }
+            | A2AError::InternalError { data, .. }
+            | A2AError::TaskNotFound { data, .. }
+            | A2AError::TaskNotCancelable { data, .. }
+            | A2AError::PushNotificationNotSupported { data, .. }
+            | A2AError::UnsupportedOperation { data, .. }
+            | A2AError::ContentTypeNotSupported { data, .. }
+            | A2AError::InvalidAgentResponse { data, .. }
+            | A2AError::AuthenticatedExtendedCardNotConfigured { data, .. }
+            | A2AError::PayloadTooLarge { data, .. }
+            | A2AError::InvalidStateTransition { data, .. }
+            | A2AError::VersionConflict { data, .. }
+            | A2AError::DeadlineExceeded { data, .. }
+            | A2AError::RateLimited { data, .. }
+            | A2AError::JsonRpc { data, .. } => data.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Mutable `data` slot of protocol and JSON-RPC errors.
+    fn protocol_data_mut(&mut self) -> Option<&mut Option<serde_json::Value>> {
+        match self {
+            A2AError::ParseError { data, .. }
+            | A2AError::InvalidRequest { data, .. }
+            | A2AError::MethodNotFound { data, .. }
+            | A2AError::InvalidParams { data, .. }
+            | A2AError::InternalError { data, .. }
+            | A2AError::TaskNotFound { data, .. }
+            | A2AError::TaskNotCancelable { data, .. }
+            | A2AError::PushNotificationNotSupported { data, .. }
+            | A2AError::UnsupportedOperation { data, .. }
+            | A2AError::ContentTypeNotSupported { data, .. }
+            | A2AError::InvalidAgentResponse { data, .. }
+            | A2AError::AuthenticatedExtendedCardNotConfigured { data, .. }
+            | A2AError::PayloadTooLarge { data, .. }
+            | A2AError::InvalidStateTransition { data, .. }
+            | A2AError::VersionConflict { data, .. }
+            | A2AError::DeadlineExceeded { data, .. }
+            | A2AError::RateLimited { data, .. }
+            | A2AError::JsonRpc { data, .. } => Some(data),
+            _ => None,
+        }
+    }
+
     /// Returns the JSON-RPC error code for this error variant.
     ///
     /// For transport/client-side errors that don't map to A2A codes,
@@ -323,7 +681,7 @@ impl A2AError {
     pub fn code(&self) -> i64 {
         match self {
             A2AError::ParseError { .. } => PARSE_ERROR,
-            A2AError::InvalidRequest { .. } => INVALID_REQUEST,
+            A2AError::InvalidRequest { .. } | A2AError::PayloadTooLarge { .. } => INVALID_REQUEST,
             A2AError::MethodNotFound { .. } => METHOD_NOT_FOUND,
             A2AError::InvalidParams { .. } => INVALID_PARAMS,
             A2AError::InternalError { .. } => INTERNAL_ERROR,
@@ -336,11 +694,21 @@ impl A2AError {
             A2AError::AuthenticatedExtendedCardNotConfigured { .. } => {
                 AUTHENTICATED_EXTENDED_CARD_NOT_CONFIGURED
             }
+            A2AError::RateLimited { .. } => RATE_LIMITED,
+            A2AError::DeadlineExceeded { .. } => DEADLINE_EXCEEDED,
+            A2AError::InvalidStateTransition { .. } | A2AError::VersionConflict { .. } => {
+                INTERNAL_ERROR
+            }
             // Client/transport errors map to internal error.
             A2AError::Transport(_)
             | A2AError::Timeout(_)
             | A2AError::Http { .. }
             | A2AError::InvalidJson(_)
+            | A2AError::ProtocolViolation(_)
+            | A2AError::IncompatibleAgent { .. }
+            | A2AError::InvalidAgentCard { .. }
+            | A2AError::TaskFailed { .. }
+            | A2AError::TaskRejected { .. }
             | A2AError::Other(_) => INTERNAL_ERROR,
             A2AError::JsonRpc { code, .. } => *code,
         }
@@ -364,10 +732,20 @@ impl A2AError {
             A2AError::AuthenticatedExtendedCardNotConfigured { .. } => {
                 "Authenticated Extended Card is not configured"
             }
+            A2AError::PayloadTooLarge { .. } => "Payload too large",
+            A2AError::InvalidStateTransition { .. } => "Invalid task state transition",
+            A2AError::VersionConflict { .. } => "Task was changed concurrently",
+            A2AError::DeadlineExceeded { .. } => "Deadline exceeded",
+            A2AError::RateLimited { .. } => "Too many requests",
             A2AError::Transport(_) => "Transport error",
             A2AError::Timeout(_) => "Request timed out",
             A2AError::Http { .. } => "HTTP error",
             A2AError::InvalidJson(_) => "Invalid JSON",
+            A2AError::ProtocolViolation(_) => "Protocol violation",
+            A2AError::IncompatibleAgent { .. } => "Message is incompatible with the agent",
+            A2AError::InvalidAgentCard { .. } => "Invalid agent card",
+            A2AError::TaskFailed { .. } => "Task failed",
+            A2AError::TaskRejected { .. } => "Task was rejected",
             A2AError::JsonRpc { .. } => "JSON-RPC error",
             A2AError::Other(_) => "Error",
         }
@@ -379,22 +757,7 @@ impl From<A2AError> for JsonRpcError {
         let code = err.code();
         let message = err.to_string();
         // Preserve structured data from protocol error variants and JsonRpc variant.
-        let data = match &err {
-            A2AError::ParseError { data, .. }
-            | A2AError::InvalidRequest { data, .. }
-            | A2AError::MethodNotFound { data, .. }
-            | A2AError::InvalidParams { data, .. }
-            | A2AError::InternalError { data, .. }
-            | A2AError::TaskNotFound { data, .. }
-            | A2AError::TaskNotCancelable { data, .. }
-            | A2AError::PushNotificationNotSupported { data, .. }
-            | A2AError::UnsupportedOperation { data, .. }
-            | A2AError::ContentTypeNotSupported { data, .. }
-            | A2AError::InvalidAgentResponse { data, .. }
-            | A2AError::AuthenticatedExtendedCardNotConfigured { data, .. }
-            | A2AError::JsonRpc { data, .. } => data.clone(),
-            _ => None,
-        };
+        let data = err.protocol_data().cloned();
         JsonRpcError {
             code,
             message,
@@ -499,4 +862,50 @@ mod tests {
         assert_eq!(rpc_err.code, TASK_NOT_FOUND);
         assert!(rpc_err.data.is_none());
     }
+
+    #[test]
+    fn with_data_keeps_protocol_code() {
+        let err =
+            A2AError::unsupported_operation("no quota").with_data(serde_json::json!({"left": 0}));
+        assert_eq!(err.code(), UNSUPPORTED_OPERATION);
+        assert_eq!(err.data(), Some(&serde_json::json!({"left": 0})));
+        let rpc_err: JsonRpcError = err.into();
+        assert_eq!(rpc_err.data, Some(serde_json::json!({"left": 0})));
+    }
+
+    #[test]
+    fn with_data_on_plain_error_becomes_internal_error() {
+        let err = A2AError::Other("disk full".to_string()).with_data(vec!["/tmp"]);
+        assert!(matches!(err, A2AError::InternalError { .. }));
+        assert!(err.to_string().contains("disk full"));
+        assert_eq!(err.error_data::<Vec<String>>().unwrap(), ["/tmp"]);
+    }
+
+    #[test]
+    fn error_data_reads_failed_task_metadata() {
+        let mut task = Task {
+            id: "t1".to_string(),
+            context_id: "c1".to_string(),
+            kind: "task".to_string(),
+            status: crate::types::TaskStatus::new(crate::types::TaskState::Failed),
+            artifacts: None,
+            history: None,
+            metadata: Some(
+                serde_json::json!({"error": {"code": -32603, "message": "x", "data": 3}}),
+            ),
+        };
+        let err = A2AError::TaskFailed {
+            message: "x".to_string(),
+            task: Box::new(task.clone()),
+        };
+        assert_eq!(err.error_data::<u32>().unwrap(), 3);
+
+        task.metadata = None;
+        let err = A2AError::TaskFailed {
+            message: "x".to_string(),
+            task: Box::new(task),
+        };
+        assert!(err.data().is_none());
+        assert_eq!(err.error_data::<u32>().unwrap_err().code(), INVALID_PARAMS);
+    }
 }
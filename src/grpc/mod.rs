@@ -0,0 +1,109 @@
+//! A2A gRPC binding (`grpc` feature).
+//!
+//! The A2A specification defines a gRPC service, `a2a.v1.A2AService`,
+//! alongside JSON-RPC. This module holds what both sides of it share:
+//!
+//! - [`proto`] — the protobuf messages and their conversions to and from
+//!   [`crate::types`], re-exported from [`crate::proto`]
+//! - the mapping between [`A2AError`] and gRPC status codes
+//!
+//! The client side is [`GrpcTransport`](crate::client::GrpcTransport), a
+//! [`Transport`](crate::client::Transport) for [`A2AClient`]; the server
+//! side is [`GrpcService`](crate::server::GrpcService), a tonic service over
+//! any [`RequestHandler`](crate::server::RequestHandler) — so one
+//! [`AgentExecutor`](crate::server::AgentExecutor) can serve JSON-RPC and
+//! gRPC at once.
+//!
+//! Implemented methods: `SendMessage`, `SendStreamingMessage`, `GetTask`,
+//! `CancelTask` and `TaskSubscription`. The others answer `UNIMPLEMENTED`.
+//!
+//! See [`crate::proto`] for how the types map to protobuf.
+//!
+//! # Errors
+//!
+//! A2A errors travel as gRPC statuses with the closest status code and the
+//! exact JSON-RPC error code in the `a2a-error-code` metadata entry (error
+//! `data` as JSON in `a2a-error-data-bin`), so clients see the same
+//! [`A2AError::JsonRpc`] codes over both transports.
+//!
+//! [`A2AClient`]: crate::client::A2AClient
+
+pub use crate::proto;
+pub(crate) use crate::proto::{task_id_from_name, task_name};
+
+use tonic::metadata::{MetadataMap, MetadataValue};
+use tonic::{Code, Status};
+
+use crate::error::{self, A2AError};
+use crate::types::JsonRpcError;
+
+/// Metadata key carrying the JSON-RPC error code of a failed call.
+pub const ERROR_CODE_METADATA: &str = "a2a-error-code";
+
+/// Metadata key carrying the JSON-encoded `data` of a failed call.
+pub const ERROR_DATA_METADATA: &str = "a2a-error-data-bin";
+
+/// The gRPC status for an A2A error, carrying its JSON-RPC code and data.
+pub(crate) fn status_from_error(err: A2AError) -> Status {
+    let rpc: JsonRpcError = err.into();
+    let code = match rpc.code {
+        error::TASK_NOT_FOUND => Code::NotFound,
+        error::TASK_NOT_CANCELABLE | error::AUTHENTICATED_EXTENDED_CARD_NOT_CONFIGURED => {
+            Code::FailedPrecondition
+        }
+        error::PUSH_NOTIFICATION_NOT_SUPPORTED
+        | error::UNSUPPORTED_OPERATION
+        | error::METHOD_NOT_FOUND => Code::Unimplemented,
+        error::PARSE_ERROR
+        | error::INVALID_REQUEST
+        | error::INVALID_PARAMS
+        | error::CONTENT_TYPE_NOT_SUPPORTED => Code::InvalidArgument,
+        error::RATE_LIMITED => Code::ResourceExhausted,
+        error::DEADLINE_EXCEEDED => Code::DeadlineExceeded,
+        _ => Code::Internal,
+    };
+    let mut metadata = MetadataMap::new();
+    metadata.insert(ERROR_CODE_METADATA, MetadataValue::from(rpc.code));
+    if let Some(data) = rpc.data.and_then(|data| serde_json::to_vec(&data).ok()) {
+        metadata.insert_bin(ERROR_DATA_METADATA, MetadataValue::from_bytes(&data));
+    }
+    Status::with_metadata(code, rpc.message, metadata)
+}
+
+/// The A2A error a gRPC status stands for.
+///
+/// Statuses from an A2A server carry their JSON-RPC code and become
+/// [`A2AError::JsonRpc`]; connection failures and deadlines become
+/// [`A2AError::Transport`] / [`A2AError::Timeout`]; other statuses are
+/// mapped to the closest A2A error code.
+pub(crate) fn error_from_status(status: &Status) -> A2AError {
+    let explicit = status
+        .metadata()
+        .get(ERROR_CODE_METADATA)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|code| code.parse::<i64>().ok());
+    let code = match (explicit, status.code()) {
+        (Some(code), _) => code,
+        (None, Code::Unavailable) => {
+            return A2AError::Transport(format!("gRPC call failed: {}", status.message()))
+        }
+        (None, Code::DeadlineExceeded) => {
+            return A2AError::Timeout(format!("gRPC call timed out: {}", status.message()))
+        }
+        (None, Code::NotFound) => error::TASK_NOT_FOUND,
+        (None, Code::Unimplemented) => error::UNSUPPORTED_OPERATION,
+        (None, Code::InvalidArgument) => error::INVALID_PARAMS,
+        (None, Code::ResourceExhausted) => error::RATE_LIMITED,
+        (None, _) => error::INTERNAL_ERROR,
+    };
+    let data = status
+        .metadata()
+        .get_bin(ERROR_DATA_METADATA)
+        .and_then(|value| value.to_bytes().ok())
+        .and_then(|bytes| serde_json::from_slice(&bytes).ok());
+    A2AError::JsonRpc {
+        code,
+        message: status.message().to_string(),
+        data,
+    }
+}
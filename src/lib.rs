@@ -26,7 +26,23 @@
 //! |----------|---------|-------------|
 //! | `client` | yes     | HTTP client for calling A2A agents (reqwest + SSE) |
 //! | `server` | yes     | Server traits + axum integration for building agents |
-//! | `full`   | no      | Enable all features |
+//! | `grpc`   | no      | A2A gRPC binding: client transport and tonic service ([`grpc`]) |
+//! | `proto` | no     | Protobuf messages of `a2a.proto` with conversions to and from [`types`] ([`proto`]) |
+//! | `kv-sled` | no     | `SledKvStore` — embedded on-disk [`server::KvStore`] |
+//! | `kv-redis` | no    | `RedisKvStore` — Redis-backed [`server::KvStore`] |
+//! | `push-sqlite` | no | `SqlitePushNotificationConfigStore` — push notification configs in SQLite |
+//! | `push-secrets` | no | `AesGcmSecretCipher` — AES-256-GCM encryption of push notification secrets |
+//! | `discovery-mdns` | no | Announce and discover agents on the LAN via mDNS ([`discovery`]) |
+//! | `sentry` | no | `SentryErrorReporter` — executor failures to a Sentry-compatible endpoint |
+//! | `observability` | no | Tracing spans, W3C trace-context propagation and task / SSE metrics ([`observability`]) |
+//! | `macros` | no | `#[skills]` — an `AgentExecutor` dispatching to one method per skill ([`server::skills`]) |
+//! | `dev-agent` | no | `a2a-dev-agent` reference binary and [`server::DevAgent`] |
+//! | `testing` | no | [`testing::MockAgentServer`] — scripted mock agent with request recording and fault injection |
+//! | `schemars` | no | `JsonSchema` for every protocol type and [`schema::export_all`] |
+//! | `fs` | no | `Part::file_from_path` / `Part::file_from_reader` — file parts from disk or any `AsyncRead` |
+//! | `arbitrary-precision` | no | Relay JSON numbers in metadata and data parts byte-for-byte (serde_json `arbitrary_precision`) |
+//! | `lenient-base64` | no | Accept file parts with invalid base64 `bytes` instead of rejecting them on deserialize |
+//! | `full`   | no      | Enable all features except `arbitrary-precision` and `lenient-base64` |
 //!
 //! ## Quick Start: Client
 //!
@@ -192,6 +208,7 @@
 //! - [`server::EventQueue`] — Broadcast channel for SSE events
 //! - [`server::TaskUpdater`] — Helper for publishing task status/artifact updates
 //! - [`server::a2a_router`] — Creates an axum `Router` with A2A routes
+//! - [`store::migrate`] — Copies tasks between `TaskStore` backends
 //!
 //! ### Core Types
 //!
@@ -222,6 +239,34 @@ pub mod client;
 #[cfg(feature = "server")]
 pub mod server;
 
+#[cfg(feature = "server")]
+pub mod store;
+
+#[cfg(feature = "discovery-mdns")]
+pub mod discovery;
+
+#[cfg(feature = "grpc")]
+pub mod grpc;
+
+#[cfg(feature = "observability")]
+pub mod observability;
+
+#[cfg(feature = "proto")]
+pub mod proto;
+
+#[cfg(feature = "schemars")]
+pub mod schema;
+
+#[cfg(feature = "testing")]
+pub mod testing;
+
+#[cfg(feature = "macros")]
+#[doc(hidden)]
+pub mod __private {
+    //! Used by the code `#[skills]` generates.
+    pub use async_trait::async_trait;
+}
+
 /// Prelude module that re-exports commonly used types and traits.
 ///
 /// Import this module with `use a2a_rs::prelude::*;` to get access to the most
@@ -257,7 +302,7 @@ pub mod prelude {
     pub use crate::builders::ClientBuilder;
 
     #[cfg(feature = "client")]
-    pub use crate::client::A2AClient;
+    pub use crate::client::{A2AClient, ClientError};
 
     #[cfg(feature = "server")]
     pub use crate::builders::ServerBuilder;
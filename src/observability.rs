@@ -0,0 +1,276 @@
+//! Tracing spans and W3C trace-context propagation (`observability` feature).
+//!
+//! With the feature enabled the SDK:
+//!
+//! - opens an `a2a.client.call` span around every [`JsonRpcTransport`]
+//!   call and sends the current trace as `traceparent` / `tracestate`
+//!   headers;
+//! - continues the caller's trace on the server: each JSON-RPC request is
+//!   handled inside an `a2a.server.request` span and each
+//!   [`AgentExecutor::execute`] call inside an `a2a.executor.execute`
+//!   span, with the incoming [`TraceContext`] as [`TraceContext::current`];
+//! - records `task_id` / `context_id` on those spans (and on the existing
+//!   `a2a.event_pipeline` span).
+//!
+//! Because executors run with the request's trace context, calls an agent
+//! makes to other agents through an [`A2AClient`] (e.g. via a
+//! [`Delegator`]) join the same trace without extra code.
+//!
+//! Spans are plain [`tracing`] spans, so they reach OpenTelemetry through
+//! `tracing-opentelemetry` or any other subscriber. Task and SSE metrics
+//! are recorded through [`MetricsRecorder`].
+//!
+//! [`JsonRpcTransport`]: crate::client::JsonRpcTransport
+//! [`A2AClient`]: crate::client::A2AClient
+//! [`AgentExecutor::execute`]: crate::server::AgentExecutor::execute
+//! [`Delegator`]: crate::server::Delegator
+//! [`MetricsRecorder`]: crate::server::MetricsRecorder
+
+use std::future::Future;
+
+/// HTTP header carrying the W3C trace parent.
+pub const TRACEPARENT_HEADER: &str = "traceparent";
+
+/// HTTP header carrying vendor-specific W3C trace state.
+pub const TRACESTATE_HEADER: &str = "tracestate";
+
+/// A position in a distributed trace, as carried by the W3C
+/// [`traceparent`](https://www.w3.org/TR/trace-context/) header.
+///
+/// # Example
+///
+/// ```
+/// use a2a_rs::observability::TraceContext;
+///
+/// let parent = TraceContext::parse(
+///     "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01",
+///     None,
+/// )
+/// .unwrap();
+/// let child = parent.child();
+/// assert_eq!(child.trace_id, parent.trace_id);
+/// assert_ne!(child.span_id, parent.span_id);
+/// assert!(child.traceparent().starts_with("00-4bf92f3577b34da6a3ce929d0e0e4736-"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceContext {
+    /// 32 lowercase hex digits identifying the whole trace.
+    pub trace_id: String,
+    /// 16 lowercase hex digits identifying the current span.
+    pub span_id: String,
+    /// Whether the trace is sampled (the `01` trace flag).
+    pub sampled: bool,
+    /// The `tracestate` header, passed on unchanged.
+    pub trace_state: Option<String>,
+}
+
+tokio::task_local! {
+    static CURRENT_TRACE: TraceContext;
+}
+
+impl TraceContext {
+    /// Start a new, sampled trace.
+    pub fn new_root() -> Self {
+        Self {
+            trace_id: random_hex(32),
+            span_id: random_hex(16),
+            sampled: true,
+            trace_state: None,
+        }
+    }
+
+    /// A new span in the same trace.
+    pub fn child(&self) -> Self {
+        Self {
+            span_id: random_hex(16),
+            ..self.clone()
+        }
+    }
+
+    /// Parse a `traceparent` header (and optional `tracestate`).
+    ///
+    /// Returns `None` for malformed values, unknown version `ff` and
+    /// all-zero IDs, which the specification says to ignore.
+    pub fn parse(traceparent: &str, tracestate: Option<&str>) -> Option<Self> {
+        let mut fields = traceparent.trim().split('-');
+        let version = fields.next()?;
+        let trace_id = fields.next()?;
+        let span_id = fields.next()?;
+        let flags = fields.next()?;
+        // Later versions may append fields; version 00 must not.
+        if (version == "00" && fields.next().is_some())
+            || !is_hex(version, 2)
+            || version == "ff"
+            || !is_hex(trace_id, 32)
+            || !is_hex(span_id, 16)
+            || !is_hex(flags, 2)
+            || trace_id.bytes().all(|b| b == b'0')
+            || span_id.bytes().all(|b| b == b'0')
+        {
+            return None;
+        }
+        let flags = u8::from_str_radix(flags, 16).ok()?;
+        Some(Self {
+            trace_id: trace_id.to_string(),
+            span_id: span_id.to_string(),
+            sampled: flags & 0x01 != 0,
+            trace_state: tracestate
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+                .map(String::from),
+        })
+    }
+
+    /// The `traceparent` header value for this context.
+    pub fn traceparent(&self) -> String {
+        format!(
+            "00-{}-{}-{:02x}",
+            self.trace_id,
+            self.span_id,
+            u8::from(self.sampled)
+        )
+    }
+
+    /// The `traceparent` and (if set) `tracestate` headers to send.
+    pub fn headers(&self) -> Vec<(&'static str, String)> {
+        let mut headers = vec![(TRACEPARENT_HEADER, self.traceparent())];
+        if let Some(state) = &self.trace_state {
+            headers.push((TRACESTATE_HEADER, state.clone()));
+        }
+        headers
+    }
+
+    /// Run `future` with `self` as the current trace context.
+    ///
+    /// Outgoing client calls made inside the scope continue this trace.
+    pub async fn scope<F: Future>(self, future: F) -> F::Output {
+        CURRENT_TRACE.scope(self, future).await
+    }
+
+    /// The trace context of the code being run, if inside
+    /// [`scope()`](Self::scope).
+    pub fn current() -> Option<TraceContext> {
+        CURRENT_TRACE.try_with(Clone::clone).ok()
+    }
+}
+
+fn is_hex(value: &str, len: usize) -> bool {
+    value.len() == len
+        && value
+            .bytes()
+            .all(|b| b.is_ascii_digit() || (b'a'..=b'f').contains(&b))
+}
+
+fn random_hex(len: usize) -> String {
+    let mut hex = String::with_capacity(len);
+    while hex.len() < len {
+        hex.push_str(&uuid::Uuid::new_v4().simple().to_string());
+    }
+    hex.truncate(len);
+    hex
+}
+
+/// Task and context ID named by JSON-RPC `params`: the top-level `id` /
+/// `taskId` / `contextId`, or those of the `message`.
+#[cfg(feature = "client")]
+fn params_ids(params: Option<&serde_json::Value>) -> (Option<&str>, Option<&str>) {
+    let Some(params) = params else {
+        return (None, None);
+    };
+    let message = params.get("message");
+    let field = |key: &str| {
+        params
+            .get(key)
+            .or_else(|| message.and_then(|m| m.get(key)))
+            .and_then(serde_json::Value::as_str)
+    };
+    let task_id = params
+        .get("id")
+        .and_then(serde_json::Value::as_str)
+        .or_else(|| field("taskId"));
+    (task_id, field("contextId"))
+}
+
+/// Run an outgoing client call in an `a2a.client.call` span and a child of
+/// the current trace (or a new trace), whose headers the transport sends.
+#[cfg(feature = "client")]
+pub(crate) async fn client_call<F: Future>(
+    request: &crate::types::JsonRpcRequest,
+    call: F,
+) -> F::Output {
+    use tracing::Instrument;
+
+    let trace = TraceContext::current()
+        .map(|parent| parent.child())
+        .unwrap_or_else(TraceContext::new_root);
+    let (task_id, context_id) = params_ids(request.params.as_ref());
+    let span = tracing::info_span!(
+        "a2a.client.call",
+        rpc.method = %request.method,
+        task_id = task_id.unwrap_or_default(),
+        context_id = context_id.unwrap_or_default(),
+        trace_id = %trace.trace_id,
+        span_id = %trace.span_id,
+    );
+    trace.scope(call).instrument(span).await
+}
+
+/// Continue the trace of an incoming request whose `traceparent` /
+/// `tracestate` headers are given, or start a new one.
+#[cfg(feature = "server")]
+pub(crate) async fn continue_trace<F: Future>(
+    traceparent: Option<String>,
+    tracestate: Option<String>,
+    future: F,
+) -> F::Output {
+    let trace = traceparent
+        .and_then(|parent| TraceContext::parse(&parent, tracestate.as_deref()))
+        .map(|parent| parent.child())
+        .unwrap_or_else(TraceContext::new_root);
+    trace.scope(future).await
+}
+
+/// Handle a JSON-RPC request in an `a2a.server.request` span.
+#[cfg(feature = "server")]
+pub(crate) fn server_request<F: Future>(
+    method: &str,
+    task_id: Option<&str>,
+    context_id: Option<&str>,
+    future: F,
+) -> tracing::instrument::Instrumented<F> {
+    use tracing::Instrument;
+
+    let trace_id = TraceContext::current().map(|trace| trace.trace_id);
+    future.instrument(tracing::info_span!(
+        "a2a.server.request",
+        rpc.method = method,
+        task_id = task_id.unwrap_or_default(),
+        context_id = context_id.unwrap_or_default(),
+        trace_id = trace_id.as_deref().unwrap_or_default(),
+    ))
+}
+
+/// Run an executor call in an `a2a.executor.execute` span, inside a child
+/// of the request's trace `parent` so the agent's own outgoing calls join
+/// it.
+#[cfg(feature = "server")]
+pub(crate) async fn executor_call<F: Future>(
+    parent: Option<TraceContext>,
+    task_id: &str,
+    context_id: &str,
+    future: F,
+) -> F::Output {
+    use tracing::Instrument;
+
+    let trace = parent
+        .map(|parent| parent.child())
+        .unwrap_or_else(TraceContext::new_root);
+    let span = tracing::info_span!(
+        "a2a.executor.execute",
+        task_id = task_id,
+        context_id = context_id,
+        trace_id = %trace.trace_id,
+        span_id = %trace.span_id,
+    );
+    trace.scope(future).instrument(span).await
+}
@@ -0,0 +1,653 @@
+//! Conversions between [`crate::types`] and the [`proto`](super) messages.
+
+use base64::Engine;
+use tracing::debug;
+
+use super::{json_to_struct, struct_to_json, task_id_from_name, task_name};
+use crate::error::A2AError;
+use crate::proto;
+use crate::types::{
+    Artifact, CancelTaskParams, FileContent, FileWithBytes, FileWithUri, GetTaskParams, Message,
+    Part, PushNotificationAuthenticationInfo, PushNotificationConfig, Role,
+    SendMessageConfiguration, SendMessageParams, SendMessageResponse, StreamResponse,
+    SubscribeToTaskParams, Task, TaskArtifactUpdateEvent, TaskPushNotificationConfig, TaskState,
+    TaskStatus, TaskStatusUpdateEvent,
+};
+
+fn missing(field: &str) -> A2AError {
+    A2AError::invalid_params(format!("{field} is required"))
+}
+
+fn non_empty(s: String) -> Option<String> {
+    (!s.is_empty()).then_some(s)
+}
+
+fn non_empty_vec<T>(v: Vec<T>) -> Option<Vec<T>> {
+    (!v.is_empty()).then_some(v)
+}
+
+fn metadata_to_proto(metadata: Option<serde_json::Value>) -> Option<prost_types::Struct> {
+    metadata.and_then(json_to_struct)
+}
+
+fn metadata_from_proto(metadata: Option<prost_types::Struct>) -> Option<serde_json::Value> {
+    metadata.map(struct_to_json)
+}
+
+fn collect<T, U>(items: Vec<T>) -> Result<Vec<U>, A2AError>
+where
+    U: TryFrom<T, Error = A2AError>,
+{
+    items.into_iter().map(U::try_from).collect()
+}
+
+// -- Enums --
+
+impl From<TaskState> for proto::TaskState {
+    fn from(state: TaskState) -> Self {
+        match state {
+            TaskState::Submitted => proto::TaskState::Submitted,
+            TaskState::Working => proto::TaskState::Working,
+            TaskState::Completed => proto::TaskState::Completed,
+            TaskState::Failed => proto::TaskState::Failed,
+            TaskState::Canceled => proto::TaskState::Cancelled,
+            TaskState::InputRequired => proto::TaskState::InputRequired,
+            TaskState::Rejected => proto::TaskState::Rejected,
+            TaskState::AuthRequired => proto::TaskState::AuthRequired,
+            TaskState::Unknown => proto::TaskState::Unspecified,
+        }
+    }
+}
+
+impl From<proto::TaskState> for TaskState {
+    fn from(state: proto::TaskState) -> Self {
+        match state {
+            proto::TaskState::Submitted => TaskState::Submitted,
+            proto::TaskState::Working => TaskState::Working,
+            proto::TaskState::Completed => TaskState::Completed,
+            proto::TaskState::Failed => TaskState::Failed,
+            proto::TaskState::Cancelled => TaskState::Canceled,
+            proto::TaskState::InputRequired => TaskState::InputRequired,
+            proto::TaskState::Rejected => TaskState::Rejected,
+            proto::TaskState::AuthRequired => TaskState::AuthRequired,
+            proto::TaskState::Unspecified => TaskState::Unknown,
+        }
+    }
+}
+
+impl From<Role> for proto::Role {
+    fn from(role: Role) -> Self {
+        match role {
+            Role::User => proto::Role::User,
+            Role::Agent => proto::Role::Agent,
+            Role::Unspecified => proto::Role::Unspecified,
+        }
+    }
+}
+
+impl From<proto::Role> for Role {
+    fn from(role: proto::Role) -> Self {
+        match role {
+            proto::Role::User => Role::User,
+            proto::Role::Agent => Role::Agent,
+            proto::Role::Unspecified => Role::Unspecified,
+        }
+    }
+}
+
+// -- Parts --
+
+impl TryFrom<Part> for proto::Part {
+    type Error = A2AError;
+
+    fn try_from(part: Part) -> Result<Self, A2AError> {
+        let (part, metadata) = match part {
+            Part::Text { text, metadata } => (proto::part::Part::Text(text), metadata),
+            Part::File { file, metadata } => {
+                let (file, mime_type, name) = match file {
+                    FileContent::Bytes(file) => (
+                        proto::file_part::File::FileWithBytes(file.decoded_bytes()?),
+                        file.mime_type,
+                        file.name,
+                    ),
+                    FileContent::Uri(file) => (
+                        proto::file_part::File::FileWithUri(file.uri),
+                        file.mime_type,
+                        file.name,
+                    ),
+                };
+                let file = proto::FilePart {
+                    file: Some(file),
+                    mime_type: mime_type.unwrap_or_default(),
+                    name: name.unwrap_or_default(),
+                };
+                (proto::part::Part::File(file), metadata)
+            }
+            Part::Data { data, metadata } => (
+                proto::part::Part::Data(proto::DataPart {
+                    data: json_to_struct(data),
+                }),
+                metadata,
+            ),
+        };
+        Ok(proto::Part {
+            part: Some(part),
+            metadata: metadata_to_proto(metadata),
+        })
+    }
+}
+
+impl TryFrom<proto::Part> for Part {
+    type Error = A2AError;
+
+    fn try_from(part: proto::Part) -> Result<Self, A2AError> {
+        let metadata = metadata_from_proto(part.metadata);
+        Ok(match part.part.ok_or_else(|| missing("Part.part"))? {
+            proto::part::Part::Text(text) => Part::Text { text, metadata },
+            proto::part::Part::File(file) => {
+                let mime_type = non_empty(file.mime_type);
+                let name = non_empty(file.name);
+                let file = match file.file.ok_or_else(|| missing("FilePart.file"))? {
+                    proto::file_part::File::FileWithBytes(bytes) => {
+                        FileContent::Bytes(FileWithBytes {
+                            bytes: base64::engine::general_purpose::STANDARD.encode(bytes),
+                            mime_type,
+                            name,
+                        })
+                    }
+                    proto::file_part::File::FileWithUri(uri) => FileContent::Uri(FileWithUri {
+                        uri,
+                        mime_type,
+                        name,
+                    }),
+                };
+                Part::File { file, metadata }
+            }
+            proto::part::Part::Data(data) => Part::Data {
+                data: data
+                    .data
+                    .map(struct_to_json)
+                    .unwrap_or_else(|| serde_json::json!({})),
+                metadata,
+            },
+        })
+    }
+}
+
+// -- Messages and artifacts --
+
+impl TryFrom<Message> for proto::Message {
+    type Error = A2AError;
+
+    fn try_from(message: Message) -> Result<Self, A2AError> {
+        Ok(proto::Message {
+            message_id: message.message_id,
+            context_id: message.context_id.unwrap_or_default(),
+            task_id: message.task_id.unwrap_or_default(),
+            role: proto::Role::from(message.role) as i32,
+            content: collect(message.parts)?,
+            metadata: metadata_to_proto(message.metadata),
+            extensions: message.extensions.unwrap_or_default(),
+        })
+    }
+}
+
+impl TryFrom<proto::Message> for Message {
+    type Error = A2AError;
+
+    fn try_from(message: proto::Message) -> Result<Self, A2AError> {
+        Ok(Message {
+            message_id: message.message_id,
+            role: proto::Role::try_from(message.role)
+                .unwrap_or(proto::Role::Unspecified)
+                .into(),
+            kind: "message".to_string(),
+            parts: collect(message.content)?,
+            context_id: non_empty(message.context_id),
+            task_id: non_empty(message.task_id),
+            metadata: metadata_from_proto(message.metadata),
+            extensions: non_empty_vec(message.extensions),
+            reference_task_ids: None,
+        })
+    }
+}
+
+impl TryFrom<Artifact> for proto::Artifact {
+    type Error = A2AError;
+
+    fn try_from(artifact: Artifact) -> Result<Self, A2AError> {
+        Ok(proto::Artifact {
+            artifact_id: artifact.artifact_id,
+            name: artifact.name.unwrap_or_default(),
+            description: artifact.description.unwrap_or_default(),
+            parts: collect(artifact.parts)?,
+            metadata: metadata_to_proto(artifact.metadata),
+            extensions: artifact.extensions.unwrap_or_default(),
+        })
+    }
+}
+
+impl TryFrom<proto::Artifact> for Artifact {
+    type Error = A2AError;
+
+    fn try_from(artifact: proto::Artifact) -> Result<Self, A2AError> {
+        Ok(Artifact {
+            artifact_id: artifact.artifact_id,
+            name: non_empty(artifact.name),
+            description: non_empty(artifact.description),
+            parts: collect(artifact.parts)?,
+            metadata: metadata_from_proto(artifact.metadata),
+            extensions: non_empty_vec(artifact.extensions),
+        })
+    }
+}
+
+// -- Tasks and events --
+
+fn timestamp_to_proto(timestamp: Option<String>) -> Option<prost_types::Timestamp> {
+    let timestamp = timestamp?;
+    match chrono::DateTime::parse_from_rfc3339(&timestamp) {
+        Ok(at) => Some(prost_types::Timestamp {
+            seconds: at.timestamp(),
+            nanos: at.timestamp_subsec_nanos() as i32,
+        }),
+        Err(e) => {
+            debug!(timestamp = %timestamp, error = %e, "Dropping non-RFC 3339 timestamp");
+            None
+        }
+    }
+}
+
+fn timestamp_from_proto(timestamp: Option<prost_types::Timestamp>) -> Option<String> {
+    let timestamp = timestamp?;
+    chrono::DateTime::from_timestamp(timestamp.seconds, timestamp.nanos.max(0) as u32)
+        .map(|at| at.to_rfc3339())
+}
+
+impl TryFrom<TaskStatus> for proto::TaskStatus {
+    type Error = A2AError;
+
+    fn try_from(status: TaskStatus) -> Result<Self, A2AError> {
+        Ok(proto::TaskStatus {
+            state: proto::TaskState::from(status.state) as i32,
+            update: status.message.map(proto::Message::try_from).transpose()?,
+            timestamp: timestamp_to_proto(status.timestamp),
+        })
+    }
+}
+
+impl TryFrom<proto::TaskStatus> for TaskStatus {
+    type Error = A2AError;
+
+    fn try_from(status: proto::TaskStatus) -> Result<Self, A2AError> {
+        let state = proto::TaskState::try_from(status.state)
+            .map(TaskState::from)
+            .unwrap_or(TaskState::Unknown);
+        let mut converted = TaskStatus::new(state);
+        converted.message = status.update.map(Message::try_from).transpose()?;
+        converted.timestamp = timestamp_from_proto(status.timestamp);
+        Ok(converted)
+    }
+}
+
+impl TryFrom<Task> for proto::Task {
+    type Error = A2AError;
+
+    fn try_from(task: Task) -> Result<Self, A2AError> {
+        Ok(proto::Task {
+            id: task.id,
+            context_id: task.context_id,
+            status: Some(task.status.try_into()?),
+            artifacts: collect(task.artifacts.unwrap_or_default())?,
+            history: collect(task.history.unwrap_or_default())?,
+            metadata: metadata_to_proto(task.metadata),
+        })
+    }
+}
+
+impl TryFrom<proto::Task> for Task {
+    type Error = A2AError;
+
+    fn try_from(task: proto::Task) -> Result<Self, A2AError> {
+        Ok(Task {
+            id: task.id,
+            context_id: task.context_id,
+            kind: "task".to_string(),
+            status: task
+                .status
+                .ok_or_else(|| missing("Task.status"))?
+                .try_into()?,
+            artifacts: non_empty_vec(collect(task.artifacts)?),
+            history: non_empty_vec(collect(task.history)?),
+            metadata: metadata_from_proto(task.metadata),
+        })
+    }
+}
+
+impl TryFrom<TaskStatusUpdateEvent> for proto::TaskStatusUpdateEvent {
+    type Error = A2AError;
+
+    fn try_from(event: TaskStatusUpdateEvent) -> Result<Self, A2AError> {
+        Ok(proto::TaskStatusUpdateEvent {
+            task_id: event.task_id,
+            context_id: event.context_id,
+            status: Some(event.status.try_into()?),
+            r#final: event.r#final,
+            metadata: metadata_to_proto(event.metadata),
+        })
+    }
+}
+
+impl TryFrom<proto::TaskStatusUpdateEvent> for TaskStatusUpdateEvent {
+    type Error = A2AError;
+
+    fn try_from(event: proto::TaskStatusUpdateEvent) -> Result<Self, A2AError> {
+        Ok(TaskStatusUpdateEvent {
+            task_id: event.task_id,
+            context_id: event.context_id,
+            kind: "status-update".to_string(),
+            status: event
+                .status
+                .ok_or_else(|| missing("TaskStatusUpdateEvent.status"))?
+                .try_into()?,
+            r#final: event.r#final,
+            metadata: metadata_from_proto(event.metadata),
+        })
+    }
+}
+
+impl TryFrom<TaskArtifactUpdateEvent> for proto::TaskArtifactUpdateEvent {
+    type Error = A2AError;
+
+    fn try_from(event: TaskArtifactUpdateEvent) -> Result<Self, A2AError> {
+        Ok(proto::TaskArtifactUpdateEvent {
+            task_id: event.task_id,
+            context_id: event.context_id,
+            artifact: Some(event.artifact.try_into()?),
+            append: event.append.unwrap_or(false),
+            last_chunk: event.last_chunk.unwrap_or(false),
+            metadata: metadata_to_proto(event.metadata),
+        })
+    }
+}
+
+impl TryFrom<proto::TaskArtifactUpdateEvent> for TaskArtifactUpdateEvent {
+    type Error = A2AError;
+
+    fn try_from(event: proto::TaskArtifactUpdateEvent) -> Result<Self, A2AError> {
+        Ok(TaskArtifactUpdateEvent {
+            task_id: event.task_id,
+            context_id: event.context_id,
+            kind: "artifact-update".to_string(),
+            artifact: event
+                .artifact
+                .ok_or_else(|| missing("TaskArtifactUpdateEvent.artifact"))?
+                .try_into()?,
+            append: event.append.then_some(true),
+            last_chunk: event.last_chunk.then_some(true),
+            metadata: metadata_from_proto(event.metadata),
+        })
+    }
+}
+
+// -- Responses --
+
+impl TryFrom<SendMessageResponse> for proto::SendMessageResponse {
+    type Error = A2AError;
+
+    fn try_from(response: SendMessageResponse) -> Result<Self, A2AError> {
+        use proto::send_message_response::Payload;
+        let payload = match response {
+            SendMessageResponse::Task(task) => Payload::Task(task.try_into()?),
+            SendMessageResponse::Message(message) => Payload::Msg(message.try_into()?),
+        };
+        Ok(proto::SendMessageResponse {
+            payload: Some(payload),
+        })
+    }
+}
+
+impl TryFrom<proto::SendMessageResponse> for SendMessageResponse {
+    type Error = A2AError;
+
+    fn try_from(response: proto::SendMessageResponse) -> Result<Self, A2AError> {
+        use proto::send_message_response::Payload;
+        Ok(
+            match response
+                .payload
+                .ok_or_else(|| missing("SendMessageResponse.payload"))?
+            {
+                Payload::Task(task) => SendMessageResponse::Task(task.try_into()?),
+                Payload::Msg(message) => SendMessageResponse::Message(message.try_into()?),
+            },
+        )
+    }
+}
+
+impl TryFrom<StreamResponse> for proto::StreamResponse {
+    type Error = A2AError;
+
+    fn try_from(event: StreamResponse) -> Result<Self, A2AError> {
+        use proto::stream_response::Payload;
+        let payload = match event {
+            StreamResponse::Task(task) => Payload::Task(task.try_into()?),
+            StreamResponse::Message(message) => Payload::Msg(message.try_into()?),
+            StreamResponse::StatusUpdate(update) => Payload::StatusUpdate(update.try_into()?),
+            StreamResponse::ArtifactUpdate(update) => Payload::ArtifactUpdate(update.try_into()?),
+        };
+        Ok(proto::StreamResponse {
+            payload: Some(payload),
+        })
+    }
+}
+
+impl TryFrom<proto::StreamResponse> for StreamResponse {
+    type Error = A2AError;
+
+    fn try_from(event: proto::StreamResponse) -> Result<Self, A2AError> {
+        use proto::stream_response::Payload;
+        Ok(
+            match event
+                .payload
+                .ok_or_else(|| missing("StreamResponse.payload"))?
+            {
+                Payload::Task(task) => StreamResponse::Task(task.try_into()?),
+                Payload::Msg(message) => StreamResponse::Message(message.try_into()?),
+                Payload::StatusUpdate(update) => StreamResponse::StatusUpdate(update.try_into()?),
+                Payload::ArtifactUpdate(update) => {
+                    StreamResponse::ArtifactUpdate(update.try_into()?)
+                }
+            },
+        )
+    }
+}
+
+// -- Configuration --
+
+impl From<PushNotificationConfig> for proto::PushNotificationConfig {
+    fn from(config: PushNotificationConfig) -> Self {
+        proto::PushNotificationConfig {
+            id: config.id.unwrap_or_default(),
+            url: config.url,
+            token: config.token.unwrap_or_default(),
+            authentication: config.authentication.map(|auth| proto::AuthenticationInfo {
+                schemes: auth.schemes,
+                credentials: auth.credentials.unwrap_or_default(),
+            }),
+        }
+    }
+}
+
+impl From<proto::PushNotificationConfig> for PushNotificationConfig {
+    fn from(config: proto::PushNotificationConfig) -> Self {
+        PushNotificationConfig {
+            id: non_empty(config.id),
+            url: config.url,
+            token: non_empty(config.token),
+            authentication: config
+                .authentication
+                .map(|auth| PushNotificationAuthenticationInfo {
+                    schemes: auth.schemes,
+                    credentials: non_empty(auth.credentials),
+                }),
+        }
+    }
+}
+
+impl From<TaskPushNotificationConfig> for proto::TaskPushNotificationConfig {
+    fn from(config: TaskPushNotificationConfig) -> Self {
+        // A config set without an ID is stored under the task ID.
+        let config_id = config
+            .push_notification_config
+            .id
+            .clone()
+            .or(config.id)
+            .unwrap_or_else(|| config.task_id.clone());
+        proto::TaskPushNotificationConfig {
+            name: format!(
+                "{}/pushNotificationConfigs/{config_id}",
+                task_name(&config.task_id)
+            ),
+            push_notification_config: Some(config.push_notification_config.into()),
+        }
+    }
+}
+
+impl TryFrom<proto::TaskPushNotificationConfig> for TaskPushNotificationConfig {
+    type Error = A2AError;
+
+    fn try_from(config: proto::TaskPushNotificationConfig) -> Result<Self, A2AError> {
+        let (task_id, config_id) = task_id_from_name(&config.name)
+            .split_once("/pushNotificationConfigs/")
+            .filter(|(task_id, config_id)| !task_id.is_empty() && !config_id.is_empty())
+            .ok_or_else(|| {
+                A2AError::invalid_params(format!(
+                    "Invalid push notification config name {:?}",
+                    config.name
+                ))
+            })?;
+        let mut push_notification_config: PushNotificationConfig = config
+            .push_notification_config
+            .ok_or_else(|| missing("TaskPushNotificationConfig.push_notification_config"))?
+            .into();
+        push_notification_config
+            .id
+            .get_or_insert_with(|| config_id.to_string());
+        Ok(TaskPushNotificationConfig {
+            id: Some(config_id.to_string()),
+            task_id: task_id.to_string(),
+            push_notification_config,
+            tenant: None,
+        })
+    }
+}
+
+impl From<SendMessageConfiguration> for proto::SendMessageConfiguration {
+    fn from(config: SendMessageConfiguration) -> Self {
+        proto::SendMessageConfiguration {
+            accepted_output_modes: config.accepted_output_modes.unwrap_or_default(),
+            push_notification: config.push_notification_config.map(Into::into),
+            history_length: config.history_length.unwrap_or_default(),
+            blocking: config.blocking.unwrap_or(false),
+        }
+    }
+}
+
+impl From<proto::SendMessageConfiguration> for SendMessageConfiguration {
+    fn from(config: proto::SendMessageConfiguration) -> Self {
+        SendMessageConfiguration {
+            accepted_output_modes: non_empty_vec(config.accepted_output_modes),
+            push_notification_config: config.push_notification.map(Into::into),
+            history_length: (config.history_length > 0).then_some(config.history_length),
+            blocking: Some(config.blocking),
+            timeout_ms: None,
+        }
+    }
+}
+
+// -- Requests --
+
+impl TryFrom<SendMessageParams> for proto::SendMessageRequest {
+    type Error = A2AError;
+
+    fn try_from(params: SendMessageParams) -> Result<Self, A2AError> {
+        Ok(proto::SendMessageRequest {
+            request: Some(params.message.try_into()?),
+            configuration: params.configuration.map(Into::into),
+            metadata: metadata_to_proto(params.metadata),
+        })
+    }
+}
+
+impl TryFrom<proto::SendMessageRequest> for SendMessageParams {
+    type Error = A2AError;
+
+    fn try_from(request: proto::SendMessageRequest) -> Result<Self, A2AError> {
+        Ok(SendMessageParams {
+            message: request
+                .request
+                .ok_or_else(|| missing("SendMessageRequest.request"))?
+                .try_into()?,
+            configuration: request.configuration.map(Into::into),
+            metadata: metadata_from_proto(request.metadata),
+            tenant: None,
+        })
+    }
+}
+
+impl From<GetTaskParams> for proto::GetTaskRequest {
+    fn from(params: GetTaskParams) -> Self {
+        proto::GetTaskRequest {
+            name: task_name(&params.id),
+            history_length: params.history_length.unwrap_or_default(),
+        }
+    }
+}
+
+impl From<proto::GetTaskRequest> for GetTaskParams {
+    fn from(request: proto::GetTaskRequest) -> Self {
+        GetTaskParams {
+            id: task_id_from_name(&request.name).to_string(),
+            history_length: (request.history_length > 0).then_some(request.history_length),
+            metadata: None,
+            tenant: None,
+            consistency: None,
+        }
+    }
+}
+
+impl From<CancelTaskParams> for proto::CancelTaskRequest {
+    fn from(params: CancelTaskParams) -> Self {
+        proto::CancelTaskRequest {
+            name: task_name(&params.id),
+        }
+    }
+}
+
+impl From<proto::CancelTaskRequest> for CancelTaskParams {
+    fn from(request: proto::CancelTaskRequest) -> Self {
+        CancelTaskParams {
+            id: task_id_from_name(&request.name).to_string(),
+            metadata: None,
+            tenant: None,
+        }
+    }
+}
+
+impl From<SubscribeToTaskParams> for proto::TaskSubscriptionRequest {
+    fn from(params: SubscribeToTaskParams) -> Self {
+        proto::TaskSubscriptionRequest {
+            name: task_name(&params.id),
+        }
+    }
+}
+
+impl From<proto::TaskSubscriptionRequest> for SubscribeToTaskParams {
+    fn from(request: proto::TaskSubscriptionRequest) -> Self {
+        SubscribeToTaskParams {
+            id: task_id_from_name(&request.name).to_string(),
+            metadata: None,
+            tenant: None,
+        }
+    }
+}
@@ -0,0 +1,513 @@
+//! Protobuf flavor of the protocol types (`proto` feature).
+//!
+//! The A2A specification defines its data model in
+//! [`a2a.proto`](https://github.com/a2aproject/A2A/blob/main/specification/a2a.proto)
+//! (`package a2a.v1`); [`crate::types`] is its JSON flavor. This module
+//! holds hand-maintained [`prost`] equivalents of the proto messages, with
+//! the v0.3 field numbers so they interoperate with other SDKs' generated
+//! code, and `From` / `TryFrom` conversions between each pair:
+//!
+//! ```rust,ignore
+//! use a2a_rs::proto;
+//! use prost::Message as _;
+//!
+//! let bytes = proto::Task::try_from(task)?.encode_to_vec();
+//! let task = a2a_rs::types::Task::try_from(proto::Task::decode(&*bytes)?)?;
+//! ```
+//!
+//! The [`grpc`](crate::grpc) binding exchanges these messages; they are
+//! equally suited to binary persistence without gRPC.
+//!
+//! # Mapping
+//!
+//! - Empty proto strings and `0` / `false` scalars map to `None`; file
+//!   bytes are raw in protobuf and base64 in [`FileWithBytes`].
+//! - `metadata` and data parts are `google.protobuf.Struct`s. A value that
+//!   is not a JSON object travels wrapped as `{"value": ...}`; numbers are
+//!   doubles, so integers beyond 2^53 lose precision.
+//! - Timestamps must be RFC 3339; others are dropped.
+//! - Tasks are named `tasks/{id}` and push notification configs
+//!   `tasks/{id}/pushNotificationConfigs/{config_id}`.
+//! - Fields without a proto counterpart (`referenceTaskIds`, `tenant`,
+//!   request-level `metadata`) are not transmitted.
+//!
+//! Conversions to protobuf fail only for file parts whose `bytes` aren't
+//! valid base64; conversions from protobuf fail when a required message
+//! field is missing or a resource name is malformed. Both report
+//! [`A2AError::InvalidParams`](crate::error::A2AError::InvalidParams).
+//!
+//! [`FileWithBytes`]: crate::types::FileWithBytes
+
+mod convert;
+
+use std::collections::BTreeMap;
+
+use prost_types::value::Kind;
+use serde_json::Value;
+
+/// Convert a JSON value to a `google.protobuf.Struct`. `null` is `None`;
+/// non-objects are wrapped as `{"value": ...}`.
+pub(crate) fn json_to_struct(value: Value) -> Option<prost_types::Struct> {
+    let fields = match value {
+        Value::Null => return None,
+        Value::Object(map) => map
+            .into_iter()
+            .map(|(key, value)| (key, json_to_value(value)))
+            .collect(),
+        other => BTreeMap::from([("value".to_string(), json_to_value(other))]),
+    };
+    Some(prost_types::Struct { fields })
+}
+
+/// Convert a `google.protobuf.Struct` to a JSON object.
+pub(crate) fn struct_to_json(value: prost_types::Struct) -> Value {
+    Value::Object(
+        value
+            .fields
+            .into_iter()
+            .map(|(key, value)| (key, value_to_json(value)))
+            .collect(),
+    )
+}
+
+fn json_to_value(value: Value) -> prost_types::Value {
+    let kind = match value {
+        Value::Null => Kind::NullValue(0),
+        Value::Bool(b) => Kind::BoolValue(b),
+        Value::Number(n) => Kind::NumberValue(n.as_f64().unwrap_or_default()),
+        Value::String(s) => Kind::StringValue(s),
+        Value::Array(items) => Kind::ListValue(prost_types::ListValue {
+            values: items.into_iter().map(json_to_value).collect(),
+        }),
+        Value::Object(map) => Kind::StructValue(prost_types::Struct {
+            fields: map
+                .into_iter()
+                .map(|(key, value)| (key, json_to_value(value)))
+                .collect(),
+        }),
+    };
+    prost_types::Value { kind: Some(kind) }
+}
+
+fn value_to_json(value: prost_types::Value) -> Value {
+    match value.kind {
+        None | Some(Kind::NullValue(_)) => Value::Null,
+        Some(Kind::BoolValue(b)) => Value::Bool(b),
+        // Whole numbers come back as integers, so `1` doesn't turn into `1.0`.
+        Some(Kind::NumberValue(n)) if n.fract() == 0.0 && n.abs() < 9.007_199_254_740_992e15 => {
+            Value::from(n as i64)
+        }
+        Some(Kind::NumberValue(n)) => serde_json::Number::from_f64(n)
+            .map(Value::Number)
+            .unwrap_or(Value::Null),
+        Some(Kind::StringValue(s)) => Value::String(s),
+        Some(Kind::ListValue(list)) => {
+            Value::Array(list.values.into_iter().map(value_to_json).collect())
+        }
+        Some(Kind::StructValue(s)) => struct_to_json(s),
+    }
+}
+
+/// The task ID of a `tasks/{id}` resource name; a bare ID is accepted too.
+pub(crate) fn task_id_from_name(name: &str) -> &str {
+    name.strip_prefix("tasks/").unwrap_or(name)
+}
+
+/// The `tasks/{id}` resource name of a task.
+pub(crate) fn task_name(task_id: &str) -> String {
+    format!("tasks/{task_id}")
+}
+
+/// Fully-qualified name of the A2A gRPC service.
+pub const SERVICE_NAME: &str = "a2a.v1.A2AService";
+
+/// Path of `A2AService.SendMessage`.
+pub const SEND_MESSAGE: &str = "/a2a.v1.A2AService/SendMessage";
+
+/// Path of `A2AService.SendStreamingMessage`.
+pub const SEND_STREAMING_MESSAGE: &str = "/a2a.v1.A2AService/SendStreamingMessage";
+
+/// Path of `A2AService.GetTask`.
+pub const GET_TASK: &str = "/a2a.v1.A2AService/GetTask";
+
+/// Path of `A2AService.CancelTask`.
+pub const CANCEL_TASK: &str = "/a2a.v1.A2AService/CancelTask";
+
+/// Path of `A2AService.TaskSubscription`.
+pub const TASK_SUBSCRIPTION: &str = "/a2a.v1.A2AService/TaskSubscription";
+
+/// `enum TaskState`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum TaskState {
+    /// `TASK_STATE_UNSPECIFIED`
+    Unspecified = 0,
+    /// `TASK_STATE_SUBMITTED`
+    Submitted = 1,
+    /// `TASK_STATE_WORKING`
+    Working = 2,
+    /// `TASK_STATE_COMPLETED`
+    Completed = 3,
+    /// `TASK_STATE_FAILED`
+    Failed = 4,
+    /// `TASK_STATE_CANCELLED`
+    Cancelled = 5,
+    /// `TASK_STATE_INPUT_REQUIRED`
+    InputRequired = 6,
+    /// `TASK_STATE_REJECTED`
+    Rejected = 7,
+    /// `TASK_STATE_AUTH_REQUIRED`
+    AuthRequired = 8,
+}
+
+/// `enum Role`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum Role {
+    /// `ROLE_UNSPECIFIED`
+    Unspecified = 0,
+    /// `ROLE_USER`
+    User = 1,
+    /// `ROLE_AGENT`
+    Agent = 2,
+}
+
+/// `message Task`.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Task {
+    /// Unique task identifier.
+    #[prost(string, tag = "1")]
+    pub id: String,
+    /// Context the task belongs to.
+    #[prost(string, tag = "2")]
+    pub context_id: String,
+    /// Current status.
+    #[prost(message, optional, tag = "3")]
+    pub status: Option<TaskStatus>,
+    /// Artifacts produced so far.
+    #[prost(message, repeated, tag = "4")]
+    pub artifacts: Vec<Artifact>,
+    /// Message history.
+    #[prost(message, repeated, tag = "5")]
+    pub history: Vec<Message>,
+    /// Arbitrary metadata.
+    #[prost(message, optional, tag = "6")]
+    pub metadata: Option<::prost_types::Struct>,
+}
+
+/// `message TaskStatus`.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TaskStatus {
+    /// The state.
+    #[prost(enumeration = "TaskState", tag = "1")]
+    pub state: i32,
+    /// Message accompanying the status.
+    #[prost(message, optional, tag = "2")]
+    pub update: Option<Message>,
+    /// When the status was set.
+    #[prost(message, optional, tag = "3")]
+    pub timestamp: Option<::prost_types::Timestamp>,
+}
+
+/// `message Part`.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Part {
+    /// The content.
+    #[prost(oneof = "part::Part", tags = "1, 2, 3")]
+    pub part: Option<part::Part>,
+    /// Arbitrary metadata.
+    #[prost(message, optional, tag = "4")]
+    pub metadata: Option<::prost_types::Struct>,
+}
+
+/// Nested types of [`Part`].
+pub mod part {
+    /// `oneof part`.
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Part {
+        /// Text content.
+        #[prost(string, tag = "1")]
+        Text(String),
+        /// File content.
+        #[prost(message, tag = "2")]
+        File(super::FilePart),
+        /// Structured data.
+        #[prost(message, tag = "3")]
+        Data(super::DataPart),
+    }
+}
+
+/// `message FilePart`.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FilePart {
+    /// The file, by URI or inline.
+    #[prost(oneof = "file_part::File", tags = "1, 2")]
+    pub file: Option<file_part::File>,
+    /// MIME type.
+    #[prost(string, tag = "3")]
+    pub mime_type: String,
+    /// File name.
+    #[prost(string, tag = "4")]
+    pub name: String,
+}
+
+/// Nested types of [`FilePart`].
+pub mod file_part {
+    /// `oneof file`.
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum File {
+        /// A URI to fetch the content from.
+        #[prost(string, tag = "1")]
+        FileWithUri(String),
+        /// The raw content.
+        #[prost(bytes = "vec", tag = "2")]
+        FileWithBytes(Vec<u8>),
+    }
+}
+
+/// `message DataPart`.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DataPart {
+    /// The data.
+    #[prost(message, optional, tag = "1")]
+    pub data: Option<::prost_types::Struct>,
+}
+
+/// `message Message`.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Message {
+    /// Unique message identifier.
+    #[prost(string, tag = "1")]
+    pub message_id: String,
+    /// Context the message belongs to.
+    #[prost(string, tag = "2")]
+    pub context_id: String,
+    /// Task the message belongs to.
+    #[prost(string, tag = "3")]
+    pub task_id: String,
+    /// Sender.
+    #[prost(enumeration = "Role", tag = "4")]
+    pub role: i32,
+    /// Content parts.
+    #[prost(message, repeated, tag = "5")]
+    pub content: Vec<Part>,
+    /// Arbitrary metadata.
+    #[prost(message, optional, tag = "6")]
+    pub metadata: Option<::prost_types::Struct>,
+    /// Protocol extensions.
+    #[prost(string, repeated, tag = "7")]
+    pub extensions: Vec<String>,
+}
+
+/// `message Artifact`.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Artifact {
+    /// Unique artifact identifier.
+    #[prost(string, tag = "1")]
+    pub artifact_id: String,
+    /// Human-readable name.
+    #[prost(string, tag = "3")]
+    pub name: String,
+    /// Description.
+    #[prost(string, tag = "4")]
+    pub description: String,
+    /// Content parts.
+    #[prost(message, repeated, tag = "5")]
+    pub parts: Vec<Part>,
+    /// Arbitrary metadata.
+    #[prost(message, optional, tag = "6")]
+    pub metadata: Option<::prost_types::Struct>,
+    /// Protocol extensions.
+    #[prost(string, repeated, tag = "7")]
+    pub extensions: Vec<String>,
+}
+
+/// `message TaskStatusUpdateEvent`.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TaskStatusUpdateEvent {
+    /// Task whose status changed.
+    #[prost(string, tag = "1")]
+    pub task_id: String,
+    /// Context of the task.
+    #[prost(string, tag = "2")]
+    pub context_id: String,
+    /// The new status.
+    #[prost(message, optional, tag = "3")]
+    pub status: Option<TaskStatus>,
+    /// Whether this is the last event of the stream.
+    #[prost(bool, tag = "4")]
+    pub r#final: bool,
+    /// Arbitrary metadata.
+    #[prost(message, optional, tag = "5")]
+    pub metadata: Option<::prost_types::Struct>,
+}
+
+/// `message TaskArtifactUpdateEvent`.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TaskArtifactUpdateEvent {
+    /// Task that produced the artifact.
+    #[prost(string, tag = "1")]
+    pub task_id: String,
+    /// Context of the task.
+    #[prost(string, tag = "2")]
+    pub context_id: String,
+    /// The artifact (chunk).
+    #[prost(message, optional, tag = "3")]
+    pub artifact: Option<Artifact>,
+    /// Whether to append to a previous chunk.
+    #[prost(bool, tag = "4")]
+    pub append: bool,
+    /// Whether this is the last chunk.
+    #[prost(bool, tag = "5")]
+    pub last_chunk: bool,
+    /// Arbitrary metadata.
+    #[prost(message, optional, tag = "6")]
+    pub metadata: Option<::prost_types::Struct>,
+}
+
+/// `message AuthenticationInfo`.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AuthenticationInfo {
+    /// Supported schemes.
+    #[prost(string, repeated, tag = "1")]
+    pub schemes: Vec<String>,
+    /// Credentials for the push endpoint.
+    #[prost(string, tag = "2")]
+    pub credentials: String,
+}
+
+/// `message PushNotificationConfig`.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PushNotificationConfig {
+    /// Config identifier.
+    #[prost(string, tag = "1")]
+    pub id: String,
+    /// Where to deliver notifications.
+    #[prost(string, tag = "2")]
+    pub url: String,
+    /// Verification token.
+    #[prost(string, tag = "3")]
+    pub token: String,
+    /// Authentication for the push endpoint.
+    #[prost(message, optional, tag = "4")]
+    pub authentication: Option<AuthenticationInfo>,
+}
+
+/// `message TaskPushNotificationConfig`.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TaskPushNotificationConfig {
+    /// Resource name, `tasks/{id}/pushNotificationConfigs/{config_id}`.
+    #[prost(string, tag = "1")]
+    pub name: String,
+    /// The config.
+    #[prost(message, optional, tag = "2")]
+    pub push_notification_config: Option<PushNotificationConfig>,
+}
+
+/// `message SendMessageConfiguration`.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SendMessageConfiguration {
+    /// Output MIME types the client accepts.
+    #[prost(string, repeated, tag = "1")]
+    pub accepted_output_modes: Vec<String>,
+    /// Push notifications for the task.
+    #[prost(message, optional, tag = "2")]
+    pub push_notification: Option<PushNotificationConfig>,
+    /// History messages to return; `0` for the server default.
+    #[prost(int32, tag = "3")]
+    pub history_length: i32,
+    /// Wait for the task to finish.
+    #[prost(bool, tag = "4")]
+    pub blocking: bool,
+}
+
+/// `message SendMessageRequest`.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SendMessageRequest {
+    /// The message.
+    #[prost(message, optional, tag = "1")]
+    pub request: Option<Message>,
+    /// Send configuration.
+    #[prost(message, optional, tag = "2")]
+    pub configuration: Option<SendMessageConfiguration>,
+    /// Arbitrary metadata.
+    #[prost(message, optional, tag = "3")]
+    pub metadata: Option<::prost_types::Struct>,
+}
+
+/// `message SendMessageResponse`.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SendMessageResponse {
+    /// The task or direct reply.
+    #[prost(oneof = "send_message_response::Payload", tags = "1, 2")]
+    pub payload: Option<send_message_response::Payload>,
+}
+
+/// Nested types of [`SendMessageResponse`].
+pub mod send_message_response {
+    /// `oneof payload`.
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Payload {
+        /// A task.
+        #[prost(message, tag = "1")]
+        Task(super::Task),
+        /// A direct reply.
+        #[prost(message, tag = "2")]
+        Msg(super::Message),
+    }
+}
+
+/// `message StreamResponse`.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StreamResponse {
+    /// The event.
+    #[prost(oneof = "stream_response::Payload", tags = "1, 2, 3, 4")]
+    pub payload: Option<stream_response::Payload>,
+}
+
+/// Nested types of [`StreamResponse`].
+pub mod stream_response {
+    /// `oneof payload`.
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Payload {
+        /// A task snapshot.
+        #[prost(message, tag = "1")]
+        Task(super::Task),
+        /// A direct reply.
+        #[prost(message, tag = "2")]
+        Msg(super::Message),
+        /// A status update.
+        #[prost(message, tag = "3")]
+        StatusUpdate(super::TaskStatusUpdateEvent),
+        /// An artifact update.
+        #[prost(message, tag = "4")]
+        ArtifactUpdate(super::TaskArtifactUpdateEvent),
+    }
+}
+
+/// `message GetTaskRequest`.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetTaskRequest {
+    /// Resource name, `tasks/{id}`.
+    #[prost(string, tag = "1")]
+    pub name: String,
+    /// History messages to return; `0` for the server default.
+    #[prost(int32, tag = "2")]
+    pub history_length: i32,
+}
+
+/// `message CancelTaskRequest`.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CancelTaskRequest {
+    /// Resource name, `tasks/{id}`.
+    #[prost(string, tag = "1")]
+    pub name: String,
+}
+
+/// `message TaskSubscriptionRequest`.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TaskSubscriptionRequest {
+    /// Resource name, `tasks/{id}`.
+    #[prost(string, tag = "1")]
+    pub name: String,
+}
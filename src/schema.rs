@@ -0,0 +1,123 @@
+//! JSON Schemas of the protocol types (`schemars` feature).
+//!
+//! Every type in [`types`](crate::types) implements
+//! [`schemars::JsonSchema`], describing its JSON wire format. [`export_all()`]
+//! collects a standalone schema per type, for validation gateways or code
+//! generation in other languages, and [`write_all()`] writes them to a
+//! directory as `{TypeName}.json`:
+//!
+//! ```rust,ignore
+//! a2a_rs::schema::write_all("schemas")?;
+//! ```
+//!
+//! Schemas describe what this SDK sends. Deserializing is more lenient in
+//! places — e.g. protobuf-style enum names such as `TASK_STATE_WORKING` are
+//! accepted but not part of the schema.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use schemars::Schema;
+
+use crate::types::*;
+
+/// `(name, schema)` of each listed type.
+macro_rules! schemas {
+    ($($ty:ident),* $(,)?) => {
+        vec![$((stringify!($ty), schemars::schema_for!($ty))),*]
+    };
+}
+
+/// The schema of every protocol type, keyed by type name.
+///
+/// Each schema is a self-contained root schema (JSON Schema 2020-12) with
+/// the types it references under `$defs`.
+pub fn export_all() -> BTreeMap<String, Schema> {
+    schemas![
+        // Core task and message types
+        TaskState,
+        Role,
+        TaskStatus,
+        Task,
+        Message,
+        FileWithBytes,
+        FileWithUri,
+        FileContent,
+        Part,
+        Artifact,
+        TaskStatusUpdateEvent,
+        TaskArtifactUpdateEvent,
+        StreamResponse,
+        SendMessageResponse,
+        // Agent card
+        AgentCard,
+        AgentInterface,
+        AgentCapabilities,
+        AgentExtension,
+        AgentSkill,
+        AgentProvider,
+        AgentCardSignature,
+        SecurityScheme,
+        ApiKeyLocation,
+        OAuthFlows,
+        AuthorizationCodeOAuthFlow,
+        ClientCredentialsOAuthFlow,
+        ImplicitOAuthFlow,
+        PasswordOAuthFlow,
+        // Push notifications
+        PushNotificationConfig,
+        PushNotificationAuthenticationInfo,
+        TaskPushNotificationConfig,
+        CreateTaskPushNotificationConfigParams,
+        GetTaskPushNotificationConfigParams,
+        ListTaskPushNotificationConfigParams,
+        ListTaskPushNotificationConfigResponse,
+        DeleteTaskPushNotificationConfigParams,
+        // JSON-RPC
+        JsonRpcId,
+        JsonRpcRequest,
+        JsonRpcResponse,
+        JsonRpcError,
+        // Method params and results
+        SendMessageParams,
+        SendMessageConfiguration,
+        GetTaskParams,
+        ReadConsistency,
+        ListTasksParams,
+        ListTasksResponse,
+        CancelTaskParams,
+        SubscribeToTaskParams,
+        TaskIdParams,
+        GetExtendedAgentCardParams,
+        SkillInvokeParams,
+        SkillValidationCode,
+        SkillValidationIssue,
+        SkillValidationResult,
+        CancelContextParams,
+        ContextTaskCancelResult,
+        CancelContextResult,
+        ListArtifactsParams,
+        GetArtifactParams,
+        ArtifactSummary,
+        ListArtifactsResult,
+    ]
+    .into_iter()
+    .map(|(name, schema)| (name.to_string(), schema))
+    .collect()
+}
+
+/// Write [`export_all()`] to `dir` as pretty-printed `{TypeName}.json`
+/// files, creating the directory if needed. Returns the written paths.
+pub fn write_all(dir: impl AsRef<Path>) -> std::io::Result<Vec<PathBuf>> {
+    let dir = dir.as_ref();
+    std::fs::create_dir_all(dir)?;
+    export_all()
+        .into_iter()
+        .map(|(name, schema)| {
+            let path = dir.join(format!("{name}.json"));
+            let json = serde_json::to_vec_pretty(&schema)?;
+            std::fs::write(&path, json)?;
+            Ok(path)
+        })
+        .collect()
+}
@@ -14,17 +14,31 @@
 //! Implementors provide the actual agent logic: reading from a [`RequestContext`]
 //! and publishing events (status updates, artifacts, messages) to an [`EventQueue`].
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+use std::net::SocketAddr;
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use serde_json::Value;
+use tokio_util::sync::CancellationToken;
 
-use crate::error::A2AResult;
+use crate::error::{A2AError, A2AResult};
 use crate::types::{Message, SendMessageConfiguration, SendMessageParams, Task};
-
+use crate::utils::deadline::{time_remaining, with_deadline};
+use crate::utils::identification::ClientIdentification;
+use crate::utils::request_id::with_request_id;
+
+use super::artifact_stream::ArtifactStream;
+use super::auth::AuthenticatedUser;
+use super::content_validation::ContentValidator;
+use super::error_reporter::SKILL_ID_METADATA_KEY;
 use super::event_queue::EventQueue;
-use super::task_store::TaskStore;
+use super::incremental::IncrementalMessage;
+use super::rate_limit::CallerIdentity;
+use super::task_store::{task_tenant, TaskStore};
+use super::task_updater::TaskUpdater;
+use super::workspace::Workspace;
 
 // ---------------------------------------------------------------------------
 // ServerCallContext — per-request server-side context
@@ -46,6 +60,109 @@ pub struct ServerCallContext {
 
     /// Extensions that have been activated for this request.
     pub activated_extensions: HashSet<String>,
+
+    /// HTTP details of the request, when it arrived through the axum
+    /// integration.
+    pub http: Option<HttpRequestInfo>,
+
+    /// The caller's `User-Agent` and `X-A2A-Client` identification, when
+    /// it sent either header.
+    pub client: Option<ClientIdentification>,
+
+    /// The authenticated caller, when the router has an
+    /// [`Authenticator`](super::Authenticator) and the request carried
+    /// valid credentials.
+    pub user: Option<AuthenticatedUser>,
+
+    /// The keys the router's [`RateLimiter`](super::RateLimiter) counted
+    /// this request under, one per rule that applied.
+    pub caller_identities: Vec<CallerIdentity>,
+
+    /// The request's ID (see [`request_id`](crate::utils::request_id)):
+    /// the caller's `X-A2A-Request-Id`, or one made up by the server.
+    pub request_id: Option<String>,
+
+    /// When the request must be done by (see
+    /// [`deadline`](crate::utils::deadline)): the caller's
+    /// `X-A2A-Deadline`, if it sent one.
+    pub deadline: Option<DateTime<Utc>>,
+}
+
+/// HTTP-level details of the request being served.
+///
+/// Filled in by the axum integration. Only headers on the router's
+/// allow-list (see [`RouterConfig::with_captured_header`](super::RouterConfig::with_captured_header))
+/// are captured, so credentials and other sensitive headers do not reach
+/// executors unless asked for.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HttpRequestInfo {
+    /// HTTP method, e.g. `POST`.
+    pub method: String,
+
+    /// Request path, e.g. `/a2a`.
+    pub path: String,
+
+    /// Address of the connected peer. Only known when the router is served
+    /// with `into_make_service_with_connect_info::<SocketAddr>()`; behind a
+    /// proxy this is the proxy's address.
+    pub peer_addr: Option<SocketAddr>,
+
+    /// Captured headers keyed by lowercase name. Repeated headers are joined
+    /// with `", "`.
+    pub headers: HashMap<String, String>,
+}
+
+impl HttpRequestInfo {
+    /// Value of a captured header (case-insensitive).
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .get(&name.to_ascii_lowercase())
+            .map(String::as_str)
+    }
+
+    /// The captured `User-Agent` header.
+    pub fn user_agent(&self) -> Option<&str> {
+        self.header("user-agent")
+    }
+}
+
+tokio::task_local! {
+    static CURRENT_CALL_CONTEXT: ServerCallContext;
+}
+
+impl ServerCallContext {
+    /// Run `future` with `self` as the current call context.
+    ///
+    /// The axum integration scopes every JSON-RPC dispatch this way, so
+    /// [`RequestHandler`](super::RequestHandler) implementations can pick up
+    /// the context (e.g. negotiated extensions) via [`current()`](Self::current)
+    /// without it being threaded through each method. The context's
+    /// [`request_id`](Self::request_id) and [`deadline`](Self::deadline),
+    /// if any, become the [current request ID](crate::utils::current_request_id)
+    /// and [current deadline](crate::utils::current_deadline) too.
+    pub async fn scope<F: std::future::Future>(self, future: F) -> F::Output {
+        let deadline = self.deadline;
+        let future = async move {
+            match deadline {
+                Some(deadline) => with_deadline(deadline, future).await,
+                None => future.await,
+            }
+        };
+        match self.request_id.clone() {
+            Some(request_id) => {
+                CURRENT_CALL_CONTEXT
+                    .scope(self, with_request_id(request_id, future))
+                    .await
+            }
+            None => CURRENT_CALL_CONTEXT.scope(self, future).await,
+        }
+    }
+
+    /// The call context of the request being handled, if inside
+    /// [`scope()`](Self::scope).
+    pub fn current() -> Option<ServerCallContext> {
+        CURRENT_CALL_CONTEXT.try_with(Clone::clone).ok()
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -59,7 +176,7 @@ pub struct ServerCallContext {
 /// (if any), and optional metadata.
 ///
 /// Mirrors Python SDK's `RequestContext` from `a2a.server.agent_execution.context`.
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct RequestContext {
     /// Unique identifier for this task.
     pub task_id: String,
@@ -84,10 +201,65 @@ pub struct RequestContext {
     /// Optional metadata from the client request.
     pub metadata: Option<Value>,
 
+    /// Tenant the task belongs to, from the request's `tenant`.
+    pub tenant: Option<String>,
+
     /// Server call context with per-request state and extensions.
     ///
     /// Mirrors Python SDK's `RequestContext._call_context`.
     pub call_context: Option<ServerCallContext>,
+
+    /// Store backing [`load_artifact_stream()`](Self::load_artifact_stream).
+    ///
+    /// Set by [`DefaultRequestHandler`](super::DefaultRequestHandler) and by
+    /// [`SimpleRequestContextBuilder`] when it has a store.
+    pub task_store: Option<Arc<dyn TaskStore>>,
+
+    /// Scratch directory of this task; see [`workspace()`](Self::workspace).
+    ///
+    /// Set by [`DefaultRequestHandler`](super::DefaultRequestHandler).
+    pub workspace: Option<Workspace>,
+
+    /// Fires when the task is canceled (`tasks/cancel`), before
+    /// [`AgentExecutor::cancel`] runs. Long-running executors check it or
+    /// `select!` on [`cancelled()`](CancellationToken::cancelled) to stop
+    /// cooperatively; see [`task_updater()`](Self::task_updater).
+    pub cancellation_token: CancellationToken,
+
+    /// Parts of [`message`](Self::message) still being uploaded, for
+    /// `message/sendStream` calls; see
+    /// [`incremental_message()`](Self::incremental_message).
+    ///
+    /// Set by [`DefaultRequestHandler`](super::DefaultRequestHandler).
+    pub incremental_message: Option<IncrementalMessage>,
+
+    /// Validator of the artifacts published through
+    /// [`task_updater()`](Self::task_updater); see
+    /// [`content_validation`](super::content_validation).
+    ///
+    /// Set by [`DefaultRequestHandler`](super::DefaultRequestHandler).
+    pub content_validator: Option<Arc<dyn ContentValidator>>,
+}
+
+impl std::fmt::Debug for RequestContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RequestContext")
+            .field("task_id", &self.task_id)
+            .field("context_id", &self.context_id)
+            .field("message", &self.message)
+            .field("task", &self.task)
+            .field("configuration", &self.configuration)
+            .field("related_tasks", &self.related_tasks)
+            .field("metadata", &self.metadata)
+            .field("tenant", &self.tenant)
+            .field("call_context", &self.call_context)
+            .field("task_store", &self.task_store.is_some())
+            .field("workspace", &self.workspace)
+            .field("cancelled", &self.cancellation_token.is_cancelled())
+            .field("incremental_message", &self.incremental_message)
+            .field("content_validator", &self.content_validator.is_some())
+            .finish()
+    }
 }
 
 impl RequestContext {
@@ -117,6 +289,92 @@ impl RequestContext {
             .join(delimiter)
     }
 
+    /// The skill the message asked for: `skillId` in the message metadata,
+    /// or else in the request metadata.
+    pub fn requested_skill(&self) -> Option<&str> {
+        [
+            self.message.as_ref().and_then(|m| m.metadata.as_ref()),
+            self.metadata.as_ref(),
+        ]
+        .into_iter()
+        .flatten()
+        .find_map(|metadata| metadata.get(SKILL_ID_METADATA_KEY)?.as_str())
+    }
+
+    /// The message of a `message/sendStream` call, whose parts may still
+    /// be arriving.
+    ///
+    /// [`message`](Self::message) then holds only the parts sent with the
+    /// first line; read the rest with
+    /// [`IncrementalMessage::next_part()`] as they come, or wait for the
+    /// whole message with [`IncrementalMessage::assemble()`]. `None` for
+    /// other calls, whose message is complete.
+    pub fn incremental_message(&self) -> Option<IncrementalMessage> {
+        self.incremental_message.clone()
+    }
+
+    /// ID of the request being served (see
+    /// [`request_id`](crate::utils::request_id)), from the
+    /// [`call_context`](Self::call_context).
+    ///
+    /// The executor runs with it as the current request ID, so clients it
+    /// uses to call other agents send it on.
+    pub fn request_id(&self) -> Option<&str> {
+        self.call_context.as_ref()?.request_id.as_deref()
+    }
+
+    /// When the request must be done by, if it has a deadline: the
+    /// caller's `X-A2A-Deadline` or `timeoutMs`, whichever is earlier. The
+    /// [`DefaultRequestHandler`](super::DefaultRequestHandler) fails the
+    /// task once it passes.
+    pub fn deadline(&self) -> Option<DateTime<Utc>> {
+        self.call_context.as_ref()?.deadline
+    }
+
+    /// Time left until the [`deadline()`](Self::deadline), zero once it
+    /// has passed; `None` without a deadline.
+    pub fn time_remaining(&self) -> Option<std::time::Duration> {
+        self.deadline().map(time_remaining)
+    }
+
+    /// Returns `true` once the task was canceled.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancellation_token.is_cancelled()
+    }
+
+    /// A [`TaskUpdater`] for this task publishing to `event_queue`, which
+    /// refuses every update but `canceled` once the task is canceled, and
+    /// artifacts the [`content_validator`](Self::content_validator) refuses.
+    pub fn task_updater(&self, event_queue: EventQueue) -> TaskUpdater {
+        let updater = TaskUpdater::new(event_queue, self.task_id.clone(), self.context_id.clone())
+            .with_cancellation(self.cancellation_token.clone());
+        match &self.content_validator {
+            Some(validator) => updater.with_content_validator(Arc::clone(validator)),
+            None => updater,
+        }
+    }
+
+    /// The task's scratch directory, shared by all turns of the task and
+    /// deleted once it reaches a terminal state.
+    ///
+    /// `None` unless the context was built by
+    /// [`DefaultRequestHandler`](super::DefaultRequestHandler).
+    pub fn workspace(&self) -> Option<&Workspace> {
+        self.workspace.as_ref()
+    }
+
+    /// A [`Delegator`](super::Delegator) sending sub-tasks of this task to
+    /// the agent behind `client`, republishing their artifacts through
+    /// `updater`. See [`delegation`](super::delegation).
+    #[cfg(feature = "client")]
+    pub fn delegator(
+        &self,
+        client: Arc<crate::client::A2AClient>,
+        updater: &super::TaskUpdater,
+    ) -> super::Delegator {
+        super::Delegator::new(client, updater.clone())
+    }
+
     /// Attach a related task to this context.
     ///
     /// Useful for scenarios like tool execution where a new task might be spawned.
@@ -146,6 +404,68 @@ impl RequestContext {
             .map(|ctx| ctx.requested_extensions.clone())
             .unwrap_or_default()
     }
+
+    /// The caller's identification headers, if it sent any.
+    pub fn client_identification(&self) -> Option<&ClientIdentification> {
+        self.call_context
+            .as_ref()
+            .and_then(|ctx| ctx.client.as_ref())
+    }
+
+    /// The authenticated caller, if any.
+    pub fn user(&self) -> Option<&AuthenticatedUser> {
+        self.call_context.as_ref().and_then(|ctx| ctx.user.as_ref())
+    }
+
+    /// The keys the request was rate limited under; empty without a
+    /// [`RateLimiter`](super::RateLimiter).
+    pub fn caller_identities(&self) -> &[CallerIdentity] {
+        self.call_context
+            .as_ref()
+            .map_or(&[], |ctx| ctx.caller_identities.as_slice())
+    }
+
+    /// HTTP details of the request, if it arrived over HTTP.
+    pub fn http_request(&self) -> Option<&HttpRequestInfo> {
+        self.call_context.as_ref().and_then(|ctx| ctx.http.as_ref())
+    }
+
+    /// Stream an artifact of a referenced prior task as an `AsyncRead`.
+    ///
+    /// Lets pipeline agents consume upstream outputs without copying whole
+    /// payloads into memory. `task_id` must be the current task, one of the
+    /// message's `reference_task_ids`, or an attached related task.
+    ///
+    /// Fails with `InternalError` if the context has no task store,
+    /// `InvalidParams` if the task isn't referenced or lacks the artifact,
+    /// and `TaskNotFound` if the task doesn't exist.
+    pub async fn load_artifact_stream(
+        &self,
+        task_id: &str,
+        artifact_id: &str,
+    ) -> A2AResult<ArtifactStream> {
+        let referenced = task_id == self.task_id
+            || self.related_tasks.iter().any(|t| t.id == task_id)
+            || self
+                .message
+                .as_ref()
+                .and_then(|m| m.reference_task_ids.as_ref())
+                .is_some_and(|ids| ids.iter().any(|id| id == task_id));
+        if !referenced {
+            return Err(A2AError::InvalidParams {
+                message: format!("Task {task_id} is not referenced by this request"),
+                data: None,
+            });
+        }
+        let store = self
+            .task_store
+            .as_ref()
+            .ok_or_else(|| A2AError::InternalError {
+                message: "No task store available to load artifacts from".to_string(),
+                data: None,
+            })?;
+        store.open_artifact(task_id, artifact_id).await
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -219,7 +539,9 @@ impl RequestContextBuilder for SimpleRequestContextBuilder {
             if let (Some(store), Some(params)) = (&self.task_store, params) {
                 if let Some(ref ref_ids) = params.message.reference_task_ids {
                     for ref_id in ref_ids {
-                        if let Ok(Some(t)) = store.get(ref_id).await {
+                        // Only tasks of the same tenant can be referred to.
+                        let tenant = params.tenant.as_deref();
+                        if let Ok(Some(t)) = store.get_for_tenant(tenant, ref_id).await {
                             related_tasks.push(t);
                         }
                     }
@@ -249,7 +571,15 @@ impl RequestContextBuilder for SimpleRequestContextBuilder {
             configuration: params.and_then(|p| p.configuration.clone()),
             related_tasks,
             metadata: params.and_then(|p| p.metadata.clone()),
+            tenant: params
+                .and_then(|p| p.tenant.clone())
+                .or_else(|| task.and_then(task_tenant).map(String::from)),
             call_context,
+            task_store: self.task_store.clone(),
+            workspace: None,
+            cancellation_token: CancellationToken::new(),
+            incremental_message: None,
+            content_validator: None,
         })
     }
 }
@@ -309,6 +639,9 @@ pub trait AgentExecutor: Send + Sync {
     ///
     /// This method should return once the agent's execution is complete
     /// or yields control (e.g., enters an `input-required` state).
+    ///
+    /// Returning an error fails the task. Attach machine-readable details
+    /// for the client with [`A2AError::with_data`](crate::error::A2AError::with_data).
     async fn execute(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()>;
 
     /// Request the agent to cancel an ongoing task.
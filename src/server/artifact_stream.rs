@@ -0,0 +1,93 @@
+//! Streaming access to stored artifacts.
+//!
+//! [`ArtifactStream`] is what [`TaskStore::open_artifact()`] and
+//! [`RequestContext::load_artifact_stream()`] hand to executors: an
+//! [`AsyncRead`] over an artifact's parts, so pipeline agents can consume
+//! upstream outputs incrementally instead of materialising them.
+//!
+//! [`PartsReader`] is the default implementation: it encodes one part at a
+//! time (text as UTF-8, inline files base64-decoded, data parts as JSON).
+//! Stores backed by blob storage can override
+//! [`TaskStore::open_artifact()`] to stream straight from storage.
+//!
+//! [`TaskStore::open_artifact()`]: super::TaskStore::open_artifact
+//! [`RequestContext::load_artifact_stream()`]: super::RequestContext::load_artifact_stream
+
+use std::collections::VecDeque;
+use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use tokio::io::{AsyncRead, ReadBuf};
+
+use crate::types::{FileContent, Part};
+
+/// Byte stream over an artifact's content.
+pub type ArtifactStream = Pin<Box<dyn AsyncRead + Send>>;
+
+/// [`AsyncRead`] over a list of parts, encoding each part only when the
+/// reader reaches it.
+///
+/// File parts that reference a URI can't be resolved from the store and
+/// fail the read with [`io::ErrorKind::Unsupported`] when reached.
+#[derive(Debug)]
+pub struct PartsReader {
+    parts: VecDeque<Part>,
+    current: Vec<u8>,
+    offset: usize,
+}
+
+impl PartsReader {
+    /// Create a reader over `parts`.
+    pub fn new(parts: Vec<Part>) -> Self {
+        Self {
+            parts: parts.into(),
+            current: Vec::new(),
+            offset: 0,
+        }
+    }
+}
+
+/// Raw bytes for a single part.
+fn part_bytes(part: Part) -> io::Result<Vec<u8>> {
+    match part {
+        Part::Text { text, .. } => Ok(text.into_bytes()),
+        Part::File {
+            file: FileContent::Bytes(file),
+            ..
+        } => file
+            .decoded_bytes()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+        Part::File {
+            file: FileContent::Uri(file),
+            ..
+        } => Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            format!("file part references '{}', not stored bytes", file.uri),
+        )),
+        Part::Data { data, .. } => {
+            serde_json::to_vec(&data).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        }
+    }
+}
+
+impl AsyncRead for PartsReader {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        while self.offset == self.current.len() {
+            let Some(part) = self.parts.pop_front() else {
+                return Poll::Ready(Ok(()));
+            };
+            self.current = part_bytes(part)?;
+            self.offset = 0;
+        }
+        let n = buf.remaining().min(self.current.len() - self.offset);
+        let start = self.offset;
+        buf.put_slice(&self.current[start..start + n]);
+        self.offset += n;
+        Poll::Ready(Ok(()))
+    }
+}
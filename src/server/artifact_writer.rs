@@ -0,0 +1,176 @@
+//! Incremental artifact emission.
+//!
+//! [`ArtifactWriter`], from [`TaskUpdater::new_artifact()`], publishes one
+//! artifact as a series of `TaskArtifactUpdateEvent`s: parts are batched up
+//! to a chunk size, every update carries the same `artifactId`, the first
+//! starts the artifact, later ones set `append`, and the update sent by
+//! [`finish()`](ArtifactWriter::finish) sets `lastChunk`.
+//!
+//! ```rust,ignore
+//! let mut writer = updater.new_artifact("report.md");
+//! for section in sections {
+//!     writer.write_text(&section).await?;
+//! }
+//! let artifact_id = writer.finish().await?;
+//! ```
+
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::error::A2AResult;
+use crate::types::Part;
+use crate::utils::parts::part_size;
+
+use super::task_updater::TaskUpdater;
+
+/// Chunk size of an [`ArtifactWriter`] when none is configured (64 KiB).
+pub const DEFAULT_ARTIFACT_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Writes one artifact as correctly-flagged chunks. See the
+/// [module docs](self).
+///
+/// Parts are held back until the next write would take the batch over the
+/// chunk size, so the final update always carries content. Text longer
+/// than the chunk size is split into several parts at character
+/// boundaries; other parts are never split.
+///
+/// A writer dropped without [`finish()`](Self::finish) leaves the
+/// artifact without a `lastChunk` update and loses the parts it held.
+pub struct ArtifactWriter {
+    updater: TaskUpdater,
+    /// ID passed to [`TaskUpdater::add_artifact()`], which applies the
+    /// updater's scope.
+    id: String,
+    /// `id` as published, with the scope prefix.
+    artifact_id: String,
+    name: Option<String>,
+    chunk_size: usize,
+    pending: Vec<Part>,
+    pending_size: usize,
+    started: bool,
+    finished: bool,
+}
+
+impl ArtifactWriter {
+    pub(crate) fn new(updater: TaskUpdater, name: Option<String>) -> Self {
+        let id = Uuid::new_v4().to_string();
+        let artifact_id = match updater.artifact_prefix() {
+            Some(prefix) => format!("{prefix}/{id}"),
+            None => id.clone(),
+        };
+        Self {
+            updater,
+            id,
+            artifact_id,
+            name,
+            chunk_size: DEFAULT_ARTIFACT_CHUNK_SIZE,
+            pending: Vec::new(),
+            pending_size: 0,
+            started: false,
+            finished: false,
+        }
+    }
+
+    /// Batch up to `bytes` of content (see [`part_size`]) per update.
+    /// Values below 1 are treated as 1.
+    pub fn with_chunk_size(mut self, bytes: usize) -> Self {
+        self.chunk_size = bytes.max(1);
+        self
+    }
+
+    /// The artifact ID every update carries.
+    pub fn artifact_id(&self) -> &str {
+        &self.artifact_id
+    }
+
+    /// Append `text`, split into parts of at most the chunk size.
+    ///
+    /// # Errors
+    ///
+    /// Returns whatever publishing a completed chunk fails with.
+    pub async fn write_text(&mut self, text: &str) -> A2AResult<()> {
+        let mut rest = text;
+        while rest.len() > self.chunk_size {
+            let mut end = self.chunk_size;
+            while !rest.is_char_boundary(end) {
+                end -= 1;
+            }
+            if end == 0 {
+                // A single character wider than the chunk size.
+                end = rest.chars().next().map_or(rest.len(), char::len_utf8);
+            }
+            self.write_part(Part::text(&rest[..end])).await?;
+            rest = &rest[end..];
+        }
+        self.write_part(Part::text(rest)).await
+    }
+
+    /// Append `part`, publishing the batch held so far first if `part`
+    /// would take it over the chunk size.
+    ///
+    /// # Errors
+    ///
+    /// Returns whatever publishing a completed chunk fails with.
+    pub async fn write_part(&mut self, part: Part) -> A2AResult<()> {
+        let size = part_size(&part);
+        if !self.pending.is_empty() && self.pending_size + size > self.chunk_size {
+            self.flush(false).await?;
+        }
+        self.pending.push(part);
+        self.pending_size += size;
+        Ok(())
+    }
+
+    /// Publish the held parts as the last chunk and return the artifact ID.
+    /// A writer nothing was written to publishes an artifact without parts.
+    ///
+    /// # Errors
+    ///
+    /// Returns whatever publishing the update fails with.
+    pub async fn finish(mut self) -> A2AResult<String> {
+        self.flush(true).await?;
+        self.finished = true;
+        Ok(std::mem::take(&mut self.artifact_id))
+    }
+
+    async fn flush(&mut self, last: bool) -> A2AResult<()> {
+        let parts = std::mem::take(&mut self.pending);
+        self.pending_size = 0;
+        self.updater
+            .add_artifact(
+                parts,
+                Some(self.id.clone()),
+                self.name.clone(),
+                None,
+                Some(self.started),
+                Some(last),
+                None,
+            )
+            .await?;
+        self.started = true;
+        Ok(())
+    }
+}
+
+impl std::fmt::Debug for ArtifactWriter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ArtifactWriter")
+            .field("artifact_id", &self.artifact_id)
+            .field("name", &self.name)
+            .field("chunk_size", &self.chunk_size)
+            .field("pending_parts", &self.pending.len())
+            .field("started", &self.started)
+            .finish()
+    }
+}
+
+impl Drop for ArtifactWriter {
+    fn drop(&mut self) {
+        if !self.finished && (self.started || !self.pending.is_empty()) {
+            warn!(
+                artifact_id = %self.artifact_id,
+                "ArtifactWriter dropped without finish(); artifact left incomplete"
+            );
+        }
+    }
+}
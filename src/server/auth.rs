@@ -0,0 +1,479 @@
+//! Server-side authentication — who is calling, checked against the card.
+//!
+//! An [`Authenticator`] attached with
+//! [`RouterConfig::with_authenticator`](super::RouterConfig::with_authenticator)
+//! runs before every JSON-RPC request and turns the request's credentials
+//! into an [`AuthenticatedUser`]. The router then checks the user against
+//! the agent card's security requirements and exposes it to the handler as
+//! [`ServerCallContext::user`](super::ServerCallContext::user), so
+//! executors can act per user (see
+//! [`RequestContext::user`](super::RequestContext::user)) or ask for more
+//! with an `auth-required` task state
+//! ([`TaskUpdater::requires_auth`](super::TaskUpdater::requires_auth)).
+//!
+//! | Outcome | Response |
+//! |---------|----------|
+//! | Credentials invalid (`Err`) | `401 Unauthorized` |
+//! | No credentials, card declares security | `401 Unauthorized` |
+//! | No credentials, card declares none | request runs anonymously |
+//! | User satisfies no security requirement | `403 Forbidden` |
+//!
+//! The agent card endpoints stay public.
+//!
+//! [`SecuritySchemeAuthenticator`] reads credentials the way the card's
+//! [`SecurityScheme`]s declare them (API key in a header, query parameter
+//! or cookie; HTTP `Authorization` schemes; bearer tokens for OAuth 2.0 and
+//! OpenID Connect) and checks them with a [`CredentialValidator`] per
+//! scheme: [`StaticCredentials`] for fixed keys and tokens, or
+//! `OAuth2Introspection` (`client` feature) for RFC 7662 token
+//! introspection.
+//!
+//! ```rust,ignore
+//! let auth = SecuritySchemeAuthenticator::new(&agent_card)
+//!     .with_validator("apiKey", Arc::new(
+//!         StaticCredentials::new().with_credential("s3cret", AuthenticatedUser::new("alice")),
+//!     ));
+//! let config = RouterConfig::default().with_authenticator(Arc::new(auth));
+//! let app = a2a_router_with_config(handler, agent_card, config);
+//! ```
+//!
+//! Python SDK ref: `User` in `a2a.auth.user`
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use axum::extract::Query;
+use axum::http::{header, HeaderMap, Uri};
+use serde_json::Value;
+
+use crate::error::{A2AError, A2AResult};
+use crate::types::{AgentCard, ApiKeyLocation, SecurityRequirement, SecurityScheme};
+
+/// The caller of a request, as established by an [`Authenticator`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AuthenticatedUser {
+    /// User name or subject identifier.
+    pub name: String,
+
+    /// Name of the card's security scheme the user authenticated with.
+    /// [`SecuritySchemeAuthenticator`] fills this in.
+    pub scheme: String,
+
+    /// Scopes granted to the user, checked against the scopes security
+    /// requirements ask for.
+    pub scopes: Vec<String>,
+
+    /// Further claims about the user (e.g. from token introspection).
+    pub claims: HashMap<String, Value>,
+}
+
+impl AuthenticatedUser {
+    /// A user called `name`, with no scopes or claims.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Grant `scopes`.
+    pub fn with_scopes<I, S>(mut self, scopes: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.scopes.extend(scopes.into_iter().map(Into::into));
+        self
+    }
+
+    /// Add claim `name`.
+    pub fn with_claim(mut self, name: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.claims.insert(name.into(), value.into());
+        self
+    }
+
+    /// Whether the user was granted every scope in `scopes`.
+    pub fn has_scopes(&self, scopes: &[String]) -> bool {
+        scopes.iter().all(|scope| self.scopes.contains(scope))
+    }
+}
+
+/// The parts of an HTTP request an [`Authenticator`] looks at.
+#[derive(Debug, Clone, Copy)]
+pub struct AuthRequest<'a> {
+    headers: &'a HeaderMap,
+    uri: &'a Uri,
+}
+
+impl<'a> AuthRequest<'a> {
+    /// A request with `headers` for `uri`.
+    pub fn new(headers: &'a HeaderMap, uri: &'a Uri) -> Self {
+        Self { headers, uri }
+    }
+
+    /// All request headers.
+    pub fn headers(&self) -> &'a HeaderMap {
+        self.headers
+    }
+
+    /// First value of header `name` (case-insensitive), if valid UTF-8.
+    pub fn header(&self, name: &str) -> Option<&'a str> {
+        self.headers.get(name).and_then(|v| v.to_str().ok())
+    }
+
+    /// Decoded value of query parameter `name`.
+    pub fn query_param(&self, name: &str) -> Option<String> {
+        let Query(mut params) = Query::<HashMap<String, String>>::try_from_uri(self.uri).ok()?;
+        params.remove(name)
+    }
+
+    /// Value of cookie `name`.
+    pub fn cookie(&self, name: &str) -> Option<&'a str> {
+        self.headers
+            .get_all(header::COOKIE)
+            .iter()
+            .filter_map(|v| v.to_str().ok())
+            .flat_map(|cookies| cookies.split(';'))
+            .find_map(|cookie| {
+                let (key, value) = cookie.trim().split_once('=')?;
+                (key == name).then_some(value)
+            })
+    }
+
+    /// Credentials of the `Authorization` header if it uses `scheme`
+    /// (case-insensitive), e.g. the token of `Authorization: Bearer <token>`.
+    pub fn authorization(&self, scheme: &str) -> Option<&'a str> {
+        let (name, credentials) = self
+            .header(header::AUTHORIZATION.as_str())?
+            .split_once(' ')?;
+        let credentials = credentials.trim();
+        (name.eq_ignore_ascii_case(scheme) && !credentials.is_empty()).then_some(credentials)
+    }
+}
+
+/// Establishes who is calling.
+#[async_trait]
+pub trait Authenticator: Send + Sync {
+    /// The user `request` authenticates as.
+    ///
+    /// Returns `Ok(None)` if the request carries no credentials this
+    /// authenticator understands, and an error if it carries invalid ones
+    /// (or they could not be checked); the router answers an error with
+    /// `401 Unauthorized` and its message.
+    async fn authenticate(&self, request: &AuthRequest<'_>)
+        -> A2AResult<Option<AuthenticatedUser>>;
+}
+
+/// Checks one credential — an API key, token or `Authorization` value.
+#[async_trait]
+pub trait CredentialValidator: Send + Sync {
+    /// The user `credential` belongs to, or `None` if it is not valid.
+    async fn validate(&self, credential: &str) -> A2AResult<Option<AuthenticatedUser>>;
+}
+
+/// A fixed set of credentials, each belonging to a user.
+#[derive(Clone, Default)]
+pub struct StaticCredentials {
+    users: HashMap<String, AuthenticatedUser>,
+}
+
+impl std::fmt::Debug for StaticCredentials {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StaticCredentials")
+            .field("credentials", &self.users.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl StaticCredentials {
+    /// No credentials.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Accept `credential` as `user`.
+    pub fn with_credential(
+        mut self,
+        credential: impl Into<String>,
+        user: AuthenticatedUser,
+    ) -> Self {
+        self.users.insert(credential.into(), user);
+        self
+    }
+}
+
+#[async_trait]
+impl CredentialValidator for StaticCredentials {
+    async fn validate(&self, credential: &str) -> A2AResult<Option<AuthenticatedUser>> {
+        Ok(self.users.get(credential).cloned())
+    }
+}
+
+/// An [`Authenticator`] for the security schemes an agent card declares.
+///
+/// Schemes are tried by name; the first whose credential is present in
+/// the request decides. Only schemes with a validator take part, and
+/// mutual TLS is left to the TLS terminator.
+///
+/// # Example
+///
+/// ```
+/// use std::sync::Arc;
+/// use a2a_rs::builders::AgentCardBuilder;
+/// use a2a_rs::server::{AuthenticatedUser, SecuritySchemeAuthenticator, StaticCredentials};
+/// use a2a_rs::types::SecurityScheme;
+///
+/// let mut card = AgentCardBuilder::new("agent", "An agent", "1.0").build();
+/// card.security_schemes = Some(
+///     [(
+///         "bearer".to_string(),
+///         SecurityScheme::Http {
+///             description: None,
+///             scheme: "bearer".to_string(),
+///             bearer_format: None,
+///         },
+///     )]
+///     .into(),
+/// );
+///
+/// let auth = SecuritySchemeAuthenticator::new(&card).with_validator(
+///     "bearer",
+///     Arc::new(StaticCredentials::new().with_credential("t0ken", AuthenticatedUser::new("alice"))),
+/// );
+/// ```
+#[derive(Clone)]
+pub struct SecuritySchemeAuthenticator {
+    /// Scheme name, definition and validator, sorted by name.
+    schemes: Vec<(String, SecurityScheme, Arc<dyn CredentialValidator>)>,
+    declared: HashMap<String, SecurityScheme>,
+}
+
+impl std::fmt::Debug for SecuritySchemeAuthenticator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let names: Vec<_> = self.schemes.iter().map(|(name, ..)| name).collect();
+        f.debug_struct("SecuritySchemeAuthenticator")
+            .field("schemes", &names)
+            .finish_non_exhaustive()
+    }
+}
+
+impl SecuritySchemeAuthenticator {
+    /// Authenticate against the security schemes of `card`.
+    pub fn new(card: &AgentCard) -> Self {
+        Self {
+            schemes: Vec::new(),
+            declared: card.security_schemes.clone().unwrap_or_default(),
+        }
+    }
+
+    /// Check credentials for scheme `name` with `validator`. Ignored if the
+    /// card declares no such scheme.
+    pub fn with_validator(mut self, name: &str, validator: Arc<dyn CredentialValidator>) -> Self {
+        let Some(scheme) = self.declared.get(name) else {
+            return self;
+        };
+        self.schemes.retain(|(existing, ..)| existing != name);
+        self.schemes
+            .push((name.to_string(), scheme.clone(), validator));
+        self.schemes.sort_by(|a, b| a.0.cmp(&b.0));
+        self
+    }
+}
+
+#[async_trait]
+impl Authenticator for SecuritySchemeAuthenticator {
+    async fn authenticate(
+        &self,
+        request: &AuthRequest<'_>,
+    ) -> A2AResult<Option<AuthenticatedUser>> {
+        for (name, scheme, validator) in &self.schemes {
+            let Some(credential) = scheme_credential(request, scheme) else {
+                continue;
+            };
+            return match validator.validate(&credential).await? {
+                Some(user) => Ok(Some(AuthenticatedUser {
+                    scheme: name.clone(),
+                    ..user
+                })),
+                None => Err(A2AError::invalid_request(format!(
+                    "invalid credentials for security scheme '{name}'"
+                ))),
+            };
+        }
+        Ok(None)
+    }
+}
+
+/// The credential `request` presents for `scheme`, if any.
+fn scheme_credential(request: &AuthRequest<'_>, scheme: &SecurityScheme) -> Option<String> {
+    match scheme {
+        SecurityScheme::ApiKey { location, name, .. } => match location {
+            ApiKeyLocation::Header => request.header(name).map(str::to_string),
+            ApiKeyLocation::Query => request.query_param(name),
+            ApiKeyLocation::Cookie => request.cookie(name).map(str::to_string),
+        },
+        SecurityScheme::Http { scheme, .. } => request.authorization(scheme).map(str::to_string),
+        SecurityScheme::OAuth2 { .. } | SecurityScheme::OpenIdConnect { .. } => {
+            request.authorization("bearer").map(str::to_string)
+        }
+        SecurityScheme::MutualTls { .. } => None,
+    }
+}
+
+/// The security requirements of `card`, one of which a caller must meet:
+/// `securityRequirements`, else `security`, else each declared scheme on
+/// its own. Empty if the card declares no security.
+pub(crate) fn card_security_requirements(card: &AgentCard) -> Vec<SecurityRequirement> {
+    if !card.security_requirements.is_empty() {
+        return card.security_requirements.clone();
+    }
+    if let Some(security) = card.security.as_ref().filter(|s| !s.is_empty()) {
+        return security.clone();
+    }
+    let mut names: Vec<_> = card
+        .security_schemes
+        .iter()
+        .flat_map(|schemes| schemes.keys())
+        .collect();
+    names.sort();
+    names
+        .into_iter()
+        .map(|name| [(name.clone(), Vec::new())].into())
+        .collect()
+}
+
+/// Whether `user` meets one of `requirements`: a requirement naming only
+/// the user's scheme, whose scopes the user was granted.
+pub(crate) fn satisfies_requirements(
+    user: &AuthenticatedUser,
+    requirements: &[SecurityRequirement],
+) -> bool {
+    requirements.iter().any(|requirement| {
+        requirement.len() == 1
+            && requirement
+                .get(&user.scheme)
+                .is_some_and(|scopes| user.has_scopes(scopes))
+    })
+}
+
+/// `WWW-Authenticate` challenges for the `Authorization`-based schemes of
+/// `card`, e.g. `Bearer`.
+pub(crate) fn www_authenticate_challenges(card: &AgentCard) -> Vec<String> {
+    let mut challenges: Vec<String> = card
+        .security_schemes
+        .iter()
+        .flat_map(|schemes| schemes.values())
+        .filter_map(|scheme| match scheme {
+            SecurityScheme::Http { scheme, .. } => {
+                let mut chars = scheme.chars();
+                let first = chars.next()?;
+                Some(first.to_ascii_uppercase().to_string() + &chars.as_str().to_ascii_lowercase())
+            }
+            SecurityScheme::OAuth2 { .. } | SecurityScheme::OpenIdConnect { .. } => {
+                Some("Bearer".to_string())
+            }
+            _ => None,
+        })
+        .collect();
+    challenges.sort();
+    challenges.dedup();
+    challenges
+}
+
+#[cfg(feature = "client")]
+pub use introspection::OAuth2Introspection;
+
+#[cfg(feature = "client")]
+mod introspection {
+    use async_trait::async_trait;
+    use serde_json::Value;
+
+    use super::{AuthenticatedUser, CredentialValidator};
+    use crate::error::{A2AError, A2AResult};
+
+    /// Validates OAuth 2.0 bearer tokens with the authorization server's
+    /// token introspection endpoint (RFC 7662).
+    ///
+    /// Active tokens become a user named by `username`, else `sub`, else
+    /// `client_id`, with the token's space-separated `scope` and every
+    /// response field as claims.
+    #[derive(Clone)]
+    pub struct OAuth2Introspection {
+        url: String,
+        client_id: String,
+        client_secret: String,
+        http: reqwest::Client,
+    }
+
+    impl std::fmt::Debug for OAuth2Introspection {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("OAuth2Introspection")
+                .field("url", &self.url)
+                .field("client_id", &self.client_id)
+                .finish_non_exhaustive()
+        }
+    }
+
+    impl OAuth2Introspection {
+        /// Introspect at `url`, authenticating as the resource server with
+        /// `client_id` and `client_secret` (HTTP Basic).
+        pub fn new(
+            url: impl Into<String>,
+            client_id: impl Into<String>,
+            client_secret: impl Into<String>,
+        ) -> Self {
+            Self {
+                url: url.into(),
+                client_id: client_id.into(),
+                client_secret: client_secret.into(),
+                http: reqwest::Client::new(),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl CredentialValidator for OAuth2Introspection {
+        async fn validate(&self, credential: &str) -> A2AResult<Option<AuthenticatedUser>> {
+            let url = &self.url;
+            let response = self
+                .http
+                .post(url)
+                .basic_auth(&self.client_id, Some(&self.client_secret))
+                .form(&[("token", credential), ("token_type_hint", "access_token")])
+                .send()
+                .await
+                .map_err(|e| {
+                    A2AError::Transport(format!("token introspection at {url} failed: {e}"))
+                })?;
+            let status = response.status();
+            if !status.is_success() {
+                return Err(A2AError::Http {
+                    status: status.as_u16(),
+                    body: response.text().await.unwrap_or_default(),
+                });
+            }
+            let claims: serde_json::Map<String, Value> = response.json().await.map_err(|e| {
+                A2AError::InvalidJson(format!("invalid introspection response from {url}: {e}"))
+            })?;
+            if claims.get("active") != Some(&Value::Bool(true)) {
+                return Ok(None);
+            }
+            let claim = |name: &str| claims.get(name).and_then(Value::as_str);
+            let name = claim("username")
+                .or_else(|| claim("sub"))
+                .or_else(|| claim("client_id"))
+                .unwrap_or_default()
+                .to_string();
+            let scopes = claim("scope")
+                .map(|scope| scope.split_whitespace().map(str::to_string).collect())
+                .unwrap_or_default();
+            Ok(Some(AuthenticatedUser {
+                name,
+                scheme: String::new(),
+                scopes,
+                claims: claims.into_iter().collect(),
+            }))
+        }
+    }
+}
@@ -12,16 +12,21 @@
 //! | Method | Description |
 //! |--------|-------------|
 //! | `message/send` | Send a message and get a task or message |
-//! | `message/stream` | Send a message with SSE streaming |
-//! | `tasks/get` | Retrieve a task by ID |
+//! | `message/stream` | Send a message with SSE (or NDJSON) streaming |
+//! | `tasks/get` | Retrieve a task by ID (chunked above a size threshold, see [`chunked_task`](crate::utils::chunked_task)) |
 //! | `tasks/list` | List tasks with filtering |
 //! | `tasks/cancel` | Cancel a running task |
-//! | `tasks/subscribe` | Subscribe to task updates (SSE) |
+//! | `tasks/subscribe` | Subscribe to task updates (SSE or NDJSON) |
 //! | `tasks/resubscribe` | Re-subscribe to a running task's stream |
 //! | `tasks/pushNotificationConfig/set` | Set push notification config |
 //! | `tasks/pushNotificationConfig/get` | Get push notification config |
 //! | `tasks/pushNotificationConfig/list` | List push notification configs |
 //! | `tasks/pushNotificationConfig/delete` | Delete push notification config |
+//! | `skills/invoke` | Dry-run a message against a skill (SDK extension) |
+//! | `contexts/cancel` | Cancel every non-terminal task of a context (SDK extension) |
+//! | `tasks/artifacts/list` | List a task's artifacts without their parts (SDK extension) |
+//! | `tasks/artifacts/get` | Retrieve one artifact of a task (SDK extension) |
+//! | `message/sendStream` | Send a message whose parts are uploaded incrementally (SDK extension) |
 //!
 //! # Example
 //!
@@ -36,11 +41,120 @@
 //! let listener = tokio::net::TcpListener::bind("0.0.0.0:3000").await?;
 //! axum::serve(listener, app).await?;
 //! ```
+//!
+//! # Multiple Agents
+//!
+//! [`MultiAgentRouter`] serves several agents from one router, each with
+//! its own card and handler under `/agents/{name}` (`POST
+//! /agents/{name}/a2a`, `GET /agents/{name}/.well-known/agent.json`), plus
+//! `GET /agents` listing their cards. The agents share a [`RouterConfig`]
+//! and any layers added to the router.
+//!
+//! # Notifications
+//!
+//! Requests without an `id` are JSON-RPC 2.0 notifications: the server
+//! answers `204 No Content` right away and processes them in the
+//! background, discarding any result or error. A2A methods run as usual;
+//! other methods go to [`RequestHandler::on_notification`].
+//!
+//! # Incremental Uploads
+//!
+//! `message/sendStream` requests may be sent as a chunked
+//! `application/x-ndjson` body: the JSON-RPC request on the first line,
+//! then one [`Part`](crate::types::Part) per line, appended to the message
+//! as they arrive. The agent starts on the first line and reads the parts
+//! through [`RequestContext::incremental_message`](super::RequestContext::incremental_message);
+//! see [`RequestHandler::on_message_send_incremental`]. The body ends the
+//! message, and the response is the `message/send` result. Sent as plain
+//! JSON, the method behaves like `message/send`.
+//!
+//! # Tracing
+//!
+//! Streamed events can be followed from the executor to the client through
+//! debug-level spans, each carrying a `sequence` number:
+//!
+//! - `a2a.event.enqueue` — the executor publishes an event; `sequence` is
+//!   its position on the executor's [`EventQueue`](super::EventQueue)
+//! - `a2a.event.persist` (in `a2a.event_pipeline`, with the `task_id`) —
+//!   the event is stored; `source_sequence` matches the enqueue span,
+//!   `sequence` is its position on the task's stream
+//! - `a2a.sse.write` (in `a2a.sse`, with the `task_id`) — the event is
+//!   encoded for the client; `sequence` is its position on this SSE stream
+//!
+//! For `message/stream` the SSE stream is subscribed before the first
+//! event, so its `sequence` matches the persist span's; comparing span
+//! timestamps shows where a slow stream spends its time.
+//!
+//! With the `observability` feature, requests continue the caller's W3C
+//! `traceparent` and run in info-level `a2a.server.request` spans; see
+//! `crate::observability`. `RouterConfig::with_metrics` counts open SSE
+//! streams.
+//!
+//! Every request runs in a debug-level `a2a.request` span carrying its
+//! `request_id`: the caller's `X-A2A-Request-Id`, or a new ID, echoed in
+//! the response header. The executor runs in an `a2a.execute` span with the
+//! same ID and sends it on in its own calls to other agents, so the logs
+//! of a whole delegation chain share it (see `crate::utils::request_id`).
+//! An `X-A2A-Deadline` header likewise becomes the executor's deadline
+//! and is passed on (see `crate::utils::deadline`).
+//!
+//! # Streaming Through Proxies
+//!
+//! SSE responses carry `Cache-Control: no-cache, no-transform` and
+//! `X-Accel-Buffering: no` so that nginx-style reverse proxies forward each
+//! event as it is written instead of buffering the response. Every event is
+//! emitted as its own body frame, which hyper flushes immediately. Use
+//! [`a2a_router_with_sse_config`] with an [`SseConfig`] to change the headers
+//! or the keep-alive interval.
+//!
+//! For proxies that strip SSE altogether, streaming methods also answer in
+//! newline-delimited JSON (`Content-Type: application/x-ndjson`, one
+//! JSON-RPC response per line over a chunked body) when the request's
+//! `Accept` header ranks `application/x-ndjson` above `text/event-stream`;
+//! see [`StreamEncoding`]. The [`SseConfig`]
+//! headers apply to these responses too, and an empty line is written as
+//! keep-alive. Both encodings share the tracing spans above.
+//!
+//! # HTTP Request Details
+//!
+//! Each dispatch exposes an [`HttpRequestInfo`] (method, path, peer address
+//! and allow-listed headers) through [`ServerCallContext::http`], which
+//! executors read via [`RequestContext::http_request`](super::RequestContext::http_request).
+//! Headers are captured only when named in [`RouterConfig::with_captured_header`];
+//! the peer address needs the router to be served with
+//! `into_make_service_with_connect_info::<SocketAddr>()`.
+//!
+//! # Authentication
+//!
+//! With [`RouterConfig::with_authenticator`], every JSON-RPC request is
+//! authenticated before it is parsed and checked against the agent card's
+//! security requirements; failures get `401` or `403` and the caller ends
+//! up in [`ServerCallContext::user`]. See [`auth`](super::auth).
+//!
+//! # Payload Limits
+//!
+//! Request bodies over [`RouterConfig::with_max_body_size`] (default
+//! [`DEFAULT_MAX_BODY_SIZE`]) get `413` with a JSON-RPC
+//! [`A2AError::PayloadTooLarge`] error. Limits on single message parts and
+//! on artifacts are enforced by
+//! [`DefaultRequestHandler`](super::DefaultRequestHandler).
+//!
+//! # Rate Limiting
+//!
+//! With [`RouterConfig::with_rate_limiter`], authenticated requests are
+//! then counted per IP, API key, tenant or custom key; callers over a limit
+//! get `429` with `Retry-After` and a `-32029` JSON-RPC error. See
+//! [`rate_limit`](super::rate_limit).
 
+use std::collections::HashSet;
 use std::convert::Infallible;
+use std::net::SocketAddr;
 use std::sync::Arc;
+use std::time::Duration;
 
-use axum::extract::State;
+use axum::body::{Body, Bytes};
+use axum::extract::{ConnectInfo, DefaultBodyLimit, FromRequest, Request, State};
+use axum::http::{header, Extensions, HeaderMap, HeaderName, HeaderValue, Method, StatusCode, Uri};
 use axum::response::sse::{Event, KeepAlive, Sse};
 use axum::response::{IntoResponse, Json, Response};
 use axum::routing::{get, post};
@@ -48,21 +162,395 @@ use axum::Router;
 use futures::stream::Stream;
 use serde_json::Value;
 use tokio::sync::broadcast;
-use tracing::{debug, error, warn};
+use tracing::{debug, debug_span, error, field, warn, Instrument};
 
-use crate::error::{self, A2AError};
-use crate::types::{AgentCard, JsonRpcError as A2AJsonRpcError, StreamResponse};
+use crate::error::{self, A2AError, A2AResult};
+use crate::types::{
+    AgentCard, CancelContextParams, GetArtifactParams, JsonRpcError as A2AJsonRpcError,
+    ListArtifactsParams, SecurityRequirement, SendMessageResponse, SkillInvokeParams,
+    StreamResponse, Task,
+};
+use crate::utils::chunked_task::{
+    split_task, CHUNKED_TASK_CONTENT_TYPE, CHUNKED_TASK_EXTENSION_URI,
+    DEFAULT_CHUNKED_TASK_THRESHOLD, HISTORY_PAGE_SIZE,
+};
+use crate::utils::data_delta::{DataDeltaEncoder, DATA_DELTA_EXTENSION_URI};
+use crate::utils::deadline::{parse_deadline, DEADLINE_HEADER};
+use crate::utils::extensions::{
+    format_extension_header, get_requested_extensions, negotiate_extensions, HTTP_EXTENSION_HEADER,
+};
+use crate::utils::identification::{ClientIdentification, CLIENT_IDENTIFICATION_HEADER};
+use crate::utils::request_id::{request_id_or_new, REQUEST_ID_HEADER};
+use crate::utils::skills::{validate_skill_message, SKILLS_INVOKE_METHOD};
+use crate::utils::stream_encoding::StreamEncoding;
+use crate::utils::task::{
+    CONTEXTS_CANCEL_METHOD, MESSAGE_SEND_STREAM_METHOD, TASKS_ARTIFACTS_GET_METHOD,
+    TASKS_ARTIFACTS_LIST_METHOD,
+};
+use crate::utils::version::{
+    ProtocolVersionRange, PROTOCOL_VERSION, PROTOCOL_VERSION_HEADER, PROTOCOL_VERSION_PARAM,
+};
 
+use super::agent_executor::{HttpRequestInfo, ServerCallContext};
+use super::auth::{
+    card_security_requirements, satisfies_requirements, www_authenticate_challenges, AuthRequest,
+    AuthenticatedUser, Authenticator,
+};
+use super::incremental::{IncrementalMessage, IncrementalMessageSender};
+use super::rate_limit::{CallerIdentity, RateLimitRequest, RateLimiter};
 use super::request_handler::{
     CancelTaskParams, GetTaskParams, RequestHandler, SendMessageConfiguration, SendMessageParams,
     SubscribeToTaskParams,
 };
 use super::task_store::TaskListParams;
+#[cfg(feature = "observability")]
+use super::telemetry::MetricsRecorder;
 
 /// Shared state for the axum routes.
 struct AppState {
     handler: Arc<dyn RequestHandler>,
     agent_card: AgentCard,
+    sse: SseConfig,
+    captured_headers: Vec<HeaderName>,
+    strict_protocol_version: bool,
+    chunked_task_threshold: usize,
+    authenticator: Option<Arc<dyn Authenticator>>,
+    /// Security requirements of the card, one of which callers must meet.
+    security_requirements: Vec<SecurityRequirement>,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    max_body_size: usize,
+    #[cfg(feature = "observability")]
+    metrics: Option<Arc<dyn MetricsRecorder>>,
+}
+
+impl AppState {
+    /// Respond with the events of `rx` in `encoding`, counted as a
+    /// subscriber while open.
+    fn respond_stream(
+        &self,
+        encoding: StreamEncoding,
+        request_id: Option<Value>,
+        rx: broadcast::Receiver<StreamResponse>,
+    ) -> Response {
+        match encoding {
+            StreamEncoding::Sse => {
+                let stream = make_sse_stream(request_id, rx, delta_encoder());
+                #[cfg(feature = "observability")]
+                let stream = super::telemetry::count_subscriber(self.metrics.clone(), stream);
+                self.sse.respond(stream)
+            }
+            StreamEncoding::Ndjson => {
+                let stream = make_ndjson_stream(request_id, rx, delta_encoder());
+                #[cfg(feature = "observability")]
+                let stream = super::telemetry::count_subscriber(self.metrics.clone(), stream);
+                self.sse.respond_ndjson(stream)
+            }
+        }
+    }
+}
+
+/// Options for [`a2a_router_with_config`].
+///
+/// ```rust,ignore
+/// let config = RouterConfig::default()
+///     .with_captured_header("user-agent")?
+///     .with_captured_header("x-forwarded-for")?;
+/// let app = a2a_router_with_config(handler, agent_card, config);
+/// ```
+#[derive(Clone, Default)]
+pub struct RouterConfig {
+    sse: SseConfig,
+    captured_headers: Vec<HeaderName>,
+    strict_protocol_version: bool,
+    chunked_task_threshold: Option<usize>,
+    authenticator: Option<Arc<dyn Authenticator>>,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    max_body_size: Option<usize>,
+    #[cfg(feature = "observability")]
+    metrics: Option<Arc<dyn MetricsRecorder>>,
+}
+
+impl std::fmt::Debug for RouterConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut debug = f.debug_struct("RouterConfig");
+        debug
+            .field("sse", &self.sse)
+            .field("captured_headers", &self.captured_headers)
+            .field("strict_protocol_version", &self.strict_protocol_version)
+            .field("chunked_task_threshold", &self.chunked_task_threshold)
+            .field("authenticator", &self.authenticator.is_some())
+            .field("rate_limiter", &self.rate_limiter)
+            .field("max_body_size", &self.max_body_size);
+        #[cfg(feature = "observability")]
+        debug.field("metrics", &self.metrics.is_some());
+        debug.finish()
+    }
+}
+
+impl RouterConfig {
+    /// Use `sse` for streaming responses.
+    pub fn with_sse(mut self, sse: SseConfig) -> Self {
+        self.sse = sse;
+        self
+    }
+
+    /// Copy request header `name` into [`HttpRequestInfo::headers`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`A2AError::InvalidParams`] if `name` is not a valid header
+    /// name.
+    pub fn with_captured_header(mut self, name: &str) -> Result<Self, A2AError> {
+        let name =
+            HeaderName::from_bytes(name.as_bytes()).map_err(|e| A2AError::InvalidParams {
+                message: format!("Invalid header name '{name}': {e}"),
+                data: None,
+            })?;
+        if !self.captured_headers.contains(&name) {
+            self.captured_headers.push(name);
+        }
+        Ok(self)
+    }
+
+    /// Reject requests that declare an incompatible protocol version.
+    ///
+    /// Callers declare a version in the [`PROTOCOL_VERSION_HEADER`] or a
+    /// top-level `protocolVersion` param (the header wins). With `strict`,
+    /// versions outside [`ProtocolVersionRange::supported`] get an
+    /// `InvalidRequest` error; otherwise they are only logged. Requests
+    /// that declare no version are always accepted.
+    pub fn with_strict_protocol_version(mut self, strict: bool) -> Self {
+        self.strict_protocol_version = strict;
+        self
+    }
+
+    /// Chunk `tasks/get` responses for tasks whose JSON exceeds `bytes`,
+    /// for clients that request [`CHUNKED_TASK_EXTENSION_URI`]. Defaults
+    /// to [`DEFAULT_CHUNKED_TASK_THRESHOLD`]. See
+    /// [`chunked_task`](crate::utils::chunked_task).
+    pub fn with_chunked_task_threshold(mut self, bytes: usize) -> Self {
+        self.chunked_task_threshold = Some(bytes);
+        self
+    }
+
+    /// Authenticate JSON-RPC requests with `authenticator` and require
+    /// callers to meet one of the agent card's security requirements.
+    /// See [`auth`](super::auth).
+    pub fn with_authenticator(mut self, authenticator: Arc<dyn Authenticator>) -> Self {
+        self.authenticator = Some(authenticator);
+        self
+    }
+
+    /// Rate limit JSON-RPC requests with `limiter`. See
+    /// [`rate_limit`](super::rate_limit).
+    pub fn with_rate_limiter(mut self, limiter: Arc<RateLimiter>) -> Self {
+        self.rate_limiter = Some(limiter);
+        self
+    }
+
+    /// Reject JSON-RPC request bodies over `bytes` with `413` and
+    /// [`A2AError::PayloadTooLarge`]. Defaults to [`DEFAULT_MAX_BODY_SIZE`].
+    pub fn with_max_body_size(mut self, bytes: usize) -> Self {
+        self.max_body_size = Some(bytes);
+        self
+    }
+
+    /// Count open SSE streams with `recorder`. See
+    /// [`telemetry`](super::telemetry).
+    #[cfg(feature = "observability")]
+    pub fn with_metrics(mut self, recorder: Arc<dyn MetricsRecorder>) -> Self {
+        self.metrics = Some(recorder);
+        self
+    }
+
+    /// Serialized task size above which `tasks/get` is chunked.
+    pub fn chunked_task_threshold(&self) -> usize {
+        self.chunked_task_threshold
+            .unwrap_or(DEFAULT_CHUNKED_TASK_THRESHOLD)
+    }
+
+    /// Largest accepted JSON-RPC request body.
+    pub fn max_body_size(&self) -> usize {
+        self.max_body_size.unwrap_or(DEFAULT_MAX_BODY_SIZE)
+    }
+
+    /// Whether incompatible protocol versions are rejected.
+    pub fn strict_protocol_version(&self) -> bool {
+        self.strict_protocol_version
+    }
+
+    /// The SSE settings.
+    pub fn sse(&self) -> &SseConfig {
+        &self.sse
+    }
+
+    /// The header allow-list.
+    pub fn captured_headers(&self) -> &[HeaderName] {
+        &self.captured_headers
+    }
+}
+
+/// Header that tells nginx (and proxies copying its convention) not to
+/// buffer the response.
+pub const X_ACCEL_BUFFERING: &str = "x-accel-buffering";
+
+/// Largest JSON-RPC request body accepted when none is configured (2 MiB,
+/// axum's default).
+pub const DEFAULT_MAX_BODY_SIZE: usize = 2 * 1024 * 1024;
+
+/// Interval between SSE keep-alive comments when none is configured.
+pub const DEFAULT_SSE_KEEP_ALIVE: Duration = Duration::from_secs(15);
+
+/// Response headers and keep-alive policy for SSE streams (and their
+/// NDJSON alternative).
+///
+/// The defaults keep events flowing through buffering HTTP/1.1 proxies:
+///
+/// | Header | Default |
+/// |--------|---------|
+/// | `Cache-Control` | `no-cache, no-transform` |
+/// | `X-Accel-Buffering` | `no` |
+///
+/// `Content-Type: text/event-stream` is always set and cannot be removed.
+///
+/// ```rust,ignore
+/// let sse = SseConfig::default()
+///     .with_header("x-stream-region", "eu-west")?
+///     .with_keep_alive_interval(Duration::from_secs(5));
+/// let app = a2a_router_with_sse_config(handler, agent_card, sse);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SseConfig {
+    headers: HeaderMap,
+    keep_alive_interval: Duration,
+}
+
+impl Default for SseConfig {
+    fn default() -> Self {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            axum::http::header::CACHE_CONTROL,
+            HeaderValue::from_static("no-cache, no-transform"),
+        );
+        headers.insert(
+            HeaderName::from_static(X_ACCEL_BUFFERING),
+            HeaderValue::from_static("no"),
+        );
+        Self {
+            headers,
+            keep_alive_interval: DEFAULT_SSE_KEEP_ALIVE,
+        }
+    }
+}
+
+impl SseConfig {
+    /// Config without any extra headers (axum's own `Cache-Control: no-cache`
+    /// still applies).
+    pub fn bare() -> Self {
+        Self {
+            headers: HeaderMap::new(),
+            keep_alive_interval: DEFAULT_SSE_KEEP_ALIVE,
+        }
+    }
+
+    /// Set (or replace) a response header.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`A2AError::InvalidParams`] if the name or value is not a
+    /// valid HTTP header.
+    pub fn with_header(mut self, name: &str, value: &str) -> Result<Self, A2AError> {
+        let invalid = |message: String| A2AError::InvalidParams {
+            message,
+            data: None,
+        };
+        let name = HeaderName::from_bytes(name.as_bytes())
+            .map_err(|e| invalid(format!("Invalid SSE header name '{name}': {e}")))?;
+        let value = HeaderValue::from_str(value)
+            .map_err(|e| invalid(format!("Invalid value for SSE header '{name}': {e}")))?;
+        self.headers.insert(name, value);
+        Ok(self)
+    }
+
+    /// Stop sending a header, e.g. `X-Accel-Buffering` behind proxies that
+    /// reject unknown headers.
+    pub fn without_header(mut self, name: &str) -> Self {
+        if let Ok(name) = HeaderName::from_bytes(name.as_bytes()) {
+            self.headers.remove(name);
+        }
+        self
+    }
+
+    /// Set how often a keep-alive comment (an empty line in NDJSON) is sent
+    /// on an idle stream.
+    pub fn with_keep_alive_interval(mut self, interval: Duration) -> Self {
+        self.keep_alive_interval = interval;
+        self
+    }
+
+    /// The headers added to every SSE response.
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    /// The keep-alive interval.
+    pub fn keep_alive_interval(&self) -> Duration {
+        self.keep_alive_interval
+    }
+
+    /// Turn an event stream into a response carrying this config's headers.
+    fn respond<S>(&self, stream: S) -> Response
+    where
+        S: Stream<Item = Result<Event, Infallible>> + Send + 'static,
+    {
+        let response = Sse::new(stream)
+            .keep_alive(KeepAlive::new().interval(self.keep_alive_interval))
+            .into_response();
+        self.with_headers(response)
+    }
+
+    /// Turn a stream of JSON lines into a chunked NDJSON response carrying
+    /// this config's headers, with an empty line as keep-alive.
+    fn respond_ndjson<S>(&self, lines: S) -> Response
+    where
+        S: Stream<Item = String> + Send + 'static,
+    {
+        use futures::StreamExt;
+
+        let interval = self.keep_alive_interval;
+        let body = async_stream::stream! {
+            futures::pin_mut!(lines);
+            let start = tokio::time::Instant::now() + interval;
+            let mut keep_alive = tokio::time::interval_at(start, interval);
+            loop {
+                tokio::select! {
+                    line = lines.next() => match line {
+                        Some(mut line) => {
+                            line.push('\n');
+                            keep_alive.reset();
+                            yield Ok::<_, Infallible>(axum::body::Bytes::from(line));
+                        }
+                        None => break,
+                    },
+                    _ = keep_alive.tick() => yield Ok(axum::body::Bytes::from_static(b"\n")),
+                }
+            }
+        };
+        let mut response = Body::from_stream(body).into_response();
+        response.headers_mut().insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static(StreamEncoding::Ndjson.content_type()),
+        );
+        self.with_headers(response)
+    }
+
+    /// Add this config's headers to `response`.
+    fn with_headers(&self, mut response: Response) -> Response {
+        for (name, value) in &self.headers {
+            response.headers_mut().insert(name, value.clone());
+        }
+        response
+    }
 }
 
 /// Create an axum Router with A2A protocol routes.
@@ -78,18 +566,174 @@ struct AppState {
 /// - `handler` — the request handler implementing A2A logic
 /// - `agent_card` — the agent card to serve at the well-known endpoint
 pub fn a2a_router(handler: Arc<dyn RequestHandler>, agent_card: AgentCard) -> Router {
+    a2a_router_with_sse_config(handler, agent_card, SseConfig::default())
+}
+
+/// Create an A2A router whose SSE responses use `sse` instead of the
+/// default [`SseConfig`].
+pub fn a2a_router_with_sse_config(
+    handler: Arc<dyn RequestHandler>,
+    agent_card: AgentCard,
+    sse: SseConfig,
+) -> Router {
+    a2a_router_with_config(handler, agent_card, RouterConfig::default().with_sse(sse))
+}
+
+/// Create an A2A router with the given [`RouterConfig`].
+pub fn a2a_router_with_config(
+    handler: Arc<dyn RequestHandler>,
+    agent_card: AgentCard,
+    config: RouterConfig,
+) -> Router {
+    let chunked_task_threshold = config.chunked_task_threshold();
+    let max_body_size = config.max_body_size();
+    let security_requirements = card_security_requirements(&agent_card);
     let state = Arc::new(AppState {
         handler,
         agent_card,
+        sse: config.sse,
+        captured_headers: config.captured_headers,
+        strict_protocol_version: config.strict_protocol_version,
+        chunked_task_threshold,
+        authenticator: config.authenticator,
+        security_requirements,
+        rate_limiter: config.rate_limiter,
+        max_body_size,
+        #[cfg(feature = "observability")]
+        metrics: config.metrics,
     });
 
     Router::new()
         .route("/.well-known/agent.json", get(handle_agent_card))
         .route("/.well-known/agent", get(handle_agent_card_deprecated))
-        .route("/a2a", post(handle_jsonrpc))
+        .route(
+            "/a2a",
+            post(handle_jsonrpc).layer(DefaultBodyLimit::max(max_body_size)),
+        )
         .with_state(state)
 }
 
+/// Several agents served by one router, each under `/agents/{name}`.
+///
+/// Every agent gets the routes of [`a2a_router`] below its prefix:
+///
+/// - `POST /agents/{name}/a2a` — JSON-RPC endpoint of the agent
+/// - `GET /agents/{name}/.well-known/agent.json` — its agent card
+/// - `GET /agents/{name}/.well-known/agent` — deprecated card path
+///
+/// and `GET /agents` lists the cards of all agents. The agents share one
+/// [`RouterConfig`], so an authenticator or [`RateLimiter`] set there sees
+/// the requests of all of them, and layers added to the built router wrap
+/// every agent. Cards should advertise their agent's
+/// `/agents/{name}/a2a` URL.
+///
+/// ```rust,ignore
+/// let app = MultiAgentRouter::new()
+///     .with_config(RouterConfig::default().with_rate_limiter(limiter))
+///     .with_agent("weather", weather_card, weather_handler)?
+///     .with_agent("travel", travel_card, travel_handler)?
+///     .build()
+///     .layer(TraceLayer::new_for_http());
+/// ```
+#[derive(Clone, Default)]
+pub struct MultiAgentRouter {
+    config: RouterConfig,
+    agents: Vec<(String, AgentCard, Arc<dyn RequestHandler>)>,
+}
+
+impl std::fmt::Debug for MultiAgentRouter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MultiAgentRouter")
+            .field("config", &self.config)
+            .field(
+                "agents",
+                &self
+                    .agents
+                    .iter()
+                    .map(|(name, ..)| name)
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+impl MultiAgentRouter {
+    /// A router without agents, using the default [`RouterConfig`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Use `config` for every agent.
+    pub fn with_config(mut self, config: RouterConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Serve `handler` with `agent_card` under `/agents/{name}`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`A2AError::InvalidParams`] if `name` is empty, isn't made of
+    /// ASCII letters, digits, `-`, `_` and `.`, or is already taken.
+    pub fn with_agent(
+        mut self,
+        name: impl Into<String>,
+        agent_card: AgentCard,
+        handler: Arc<dyn RequestHandler>,
+    ) -> Result<Self, A2AError> {
+        let name = name.into();
+        let valid = !name.is_empty()
+            && name != "."
+            && name != ".."
+            && name
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_' | b'.'));
+        if !valid {
+            return Err(A2AError::InvalidParams {
+                message: format!("Invalid agent name '{name}'"),
+                data: None,
+            });
+        }
+        if self.agents.iter().any(|(taken, ..)| *taken == name) {
+            return Err(A2AError::InvalidParams {
+                message: format!("Agent '{name}' is already registered"),
+                data: None,
+            });
+        }
+        self.agents.push((name, agent_card, handler));
+        Ok(self)
+    }
+
+    /// Names of the registered agents, in registration order.
+    pub fn agent_names(&self) -> impl Iterator<Item = &str> {
+        self.agents.iter().map(|(name, ..)| name.as_str())
+    }
+
+    /// Build the axum router.
+    pub fn build(self) -> Router {
+        let cards: Arc<Vec<AgentCard>> = Arc::new(
+            self.agents
+                .iter()
+                .map(|(_, card, _)| card.clone())
+                .collect(),
+        );
+        let mut router = Router::new().route(
+            "/agents",
+            get(move || {
+                let cards = cards.clone();
+                async move { Json(cards.as_ref().clone()) }
+            }),
+        );
+        for (name, agent_card, handler) in self.agents {
+            router = router.nest(
+                &format!("/agents/{name}"),
+                a2a_router_with_config(handler, agent_card, self.config.clone()),
+            );
+        }
+        router
+    }
+}
+
 /// Serve the agent card at the well-known endpoint.
 async fn handle_agent_card(State(state): State<Arc<AppState>>) -> impl IntoResponse {
     Json(&state.agent_card).into_response()
@@ -109,6 +753,7 @@ async fn handle_agent_card_deprecated(State(state): State<Arc<AppState>>) -> imp
 /// JSON-RPC 2.0 request envelope.
 #[derive(Debug, serde::Deserialize)]
 struct JsonRpcRequest {
+    #[allow(dead_code)]
     jsonrpc: String,
     id: Option<Value>,
     method: String,
@@ -168,20 +813,144 @@ impl JsonRpcResponse {
 /// (spec requires JSON-RPC errors as HTTP 200, not 4xx).
 ///
 /// Mirrors Python SDK's `_handle_requests` method routing.
+///
+/// Extensions requested via the `X-A2A-Extensions` header are negotiated
+/// against the agent card: supported ones are activated, exposed to the
+/// handler through [`ServerCallContext::current()`], and echoed in the
+/// response header. Requests missing an extension the card marks
+/// `required` are rejected.
+///
+/// With an authenticator configured, unauthenticated requests get `401`
+/// and callers meeting none of the card's security requirements `403`;
+/// with a rate limiter, callers over a limit get `429`.
+///
+/// The request runs under the caller's `X-A2A-Request-Id`, or a new ID if
+/// it sent none (see [`request_id`](crate::utils::request_id)), which is
+/// echoed in the response.
 async fn handle_jsonrpc(
     State(state): State<Arc<AppState>>,
-    body: axum::body::Bytes,
+    method: Method,
+    uri: Uri,
+    extensions: Extensions,
+    headers: HeaderMap,
+    request: Request,
 ) -> Response {
+    let request_id = request_id_or_new(header_str(&headers, REQUEST_ID_HEADER));
+    let span = debug_span!("a2a.request", request_id = %request_id);
+    let response = serve_jsonrpc(
+        state,
+        method,
+        uri,
+        extensions,
+        headers,
+        request,
+        &request_id,
+    )
+    .instrument(span)
+    .await;
+    with_request_id_header(response, &request_id)
+}
+
+/// Serve a JSON-RPC request for [`handle_jsonrpc`] as request
+/// `request_id`.
+async fn serve_jsonrpc(
+    state: Arc<AppState>,
+    method: Method,
+    uri: Uri,
+    extensions: Extensions,
+    headers: HeaderMap,
+    request: Request,
+    request_id: &str,
+) -> Response {
+    let http = http_request_info(&state, &method, &uri, &extensions, &headers);
+    let encoding = StreamEncoding::negotiate(header_str(&headers, "accept"));
+    let user = match authenticate(&state, &headers, &uri).await {
+        Ok(user) => user,
+        Err(response) => return with_version_header(response),
+    };
+    if is_upload(&headers) {
+        return handle_upload(state, headers, http, user, request.into_body(), request_id).await;
+    }
+    let body = match Bytes::from_request(request, &state).await {
+        Ok(body) => body,
+        Err(rejection) if rejection.status() == StatusCode::PAYLOAD_TOO_LARGE => {
+            let size = header_str(&headers, "content-length").and_then(|v| v.parse().ok());
+            let err = A2AError::payload_too_large("Request body", size, state.max_body_size);
+            debug!(error = %err, "Rejecting oversized request");
+            return with_version_header(
+                (
+                    StatusCode::PAYLOAD_TOO_LARGE,
+                    Json(JsonRpcResponse::from_a2a_error(None, err)),
+                )
+                    .into_response(),
+            );
+        }
+        Err(rejection) => {
+            return Json(JsonRpcResponse::error(
+                None,
+                error::PARSE_ERROR,
+                format!("Parse error: {rejection}"),
+            ))
+            .into_response();
+        }
+    };
+
+    let PreparedCall {
+        request,
+        call_context,
+        activated,
+    } = match prepare_call(&state, &headers, http, user, &body, request_id) {
+        Ok(call) => call,
+        Err(response) => return response,
+    };
+    if request.id.is_none() {
+        // Notification: no response body, whatever the outcome.
+        let dispatched = async move {
+            dispatch(state, request, encoding).await;
+        };
+        #[cfg(feature = "observability")]
+        let dispatched = continue_trace(&headers, dispatched);
+        tokio::spawn(call_context.scope(dispatched));
+        return with_version_header(StatusCode::NO_CONTENT.into_response());
+    }
+    let dispatched = dispatch(state, request, encoding);
+    #[cfg(feature = "observability")]
+    let dispatched = continue_trace(&headers, dispatched);
+    let response = call_context.scope(dispatched).await;
+    with_activated_extensions(response, &activated)
+}
+
+/// A validated JSON-RPC request and the context to dispatch it in.
+struct PreparedCall {
+    request: JsonRpcRequest,
+    call_context: ServerCallContext,
+    /// Extensions to echo in the response header.
+    activated: HashSet<String>,
+}
+
+/// Validate the JSON-RPC envelope in `body`, count it against the rate
+/// limiter and negotiate its extensions.
+///
+/// Returns the response for a request that may not proceed.
+#[allow(clippy::result_large_err)]
+fn prepare_call(
+    state: &AppState,
+    headers: &HeaderMap,
+    http: HttpRequestInfo,
+    user: Option<AuthenticatedUser>,
+    body: &[u8],
+    request_id: &str,
+) -> Result<PreparedCall, Response> {
     // Parse JSON first — return JSON-RPC parse error (not HTTP 422) for malformed input
-    let value: Value = match serde_json::from_slice(&body) {
+    let value: Value = match serde_json::from_slice(body) {
         Ok(v) => v,
         Err(e) => {
-            return Json(JsonRpcResponse::error(
+            return Err(Json(JsonRpcResponse::error(
                 None,
                 error::PARSE_ERROR,
                 format!("Parse error: {}", e),
             ))
-            .into_response();
+            .into_response());
         }
     };
 
@@ -189,12 +958,12 @@ async fn handle_jsonrpc(
     let obj = match value.as_object() {
         Some(o) => o,
         None => {
-            return Json(JsonRpcResponse::error(
+            return Err(Json(JsonRpcResponse::error(
                 None,
                 error::INVALID_REQUEST,
                 "Invalid Request: expected JSON object".to_string(),
             ))
-            .into_response();
+            .into_response());
         }
     };
 
@@ -205,20 +974,20 @@ async fn handle_jsonrpc(
     match obj.get("jsonrpc").and_then(|v| v.as_str()) {
         Some("2.0") => {}
         Some(_) => {
-            return Json(JsonRpcResponse::error(
+            return Err(Json(JsonRpcResponse::error(
                 id,
                 error::INVALID_REQUEST,
                 "Invalid JSON-RPC version — must be \"2.0\"".to_string(),
             ))
-            .into_response();
+            .into_response());
         }
         None => {
-            return Json(JsonRpcResponse::error(
+            return Err(Json(JsonRpcResponse::error(
                 id,
                 error::INVALID_REQUEST,
                 "Invalid Request: missing 'jsonrpc' field".to_string(),
             ))
-            .into_response();
+            .into_response());
         }
     }
 
@@ -226,16 +995,34 @@ async fn handle_jsonrpc(
     let method = match obj.get("method").and_then(|v| v.as_str()) {
         Some(m) => m.to_string(),
         None => {
-            return Json(JsonRpcResponse::error(
+            return Err(Json(JsonRpcResponse::error(
                 id,
                 error::INVALID_REQUEST,
                 "Invalid Request: missing or invalid 'method' field".to_string(),
             ))
-            .into_response();
+            .into_response());
         }
     };
 
-    let params = obj.get("params").cloned().unwrap_or(Value::Object(Default::default()));
+    let params = obj
+        .get("params")
+        .cloned()
+        .unwrap_or(Value::Object(Default::default()));
+
+    let caller_identities = match rate_limit(state, headers, &http, &method, &params, &user) {
+        Ok(identities) => identities,
+        Err(e) => return Err(with_version_header(rate_limited(id, e))),
+    };
+
+    if let Err(e) = check_declared_version(state, headers, &params) {
+        if id.is_none() {
+            debug!(method = %method, error = %e, "Dropping notification");
+            return Err(with_version_header(StatusCode::NO_CONTENT.into_response()));
+        }
+        return Err(with_version_header(
+            Json(JsonRpcResponse::from_a2a_error(id, e)).into_response(),
+        ));
+    }
 
     let request = JsonRpcRequest {
         jsonrpc: "2.0".to_string(),
@@ -244,7 +1031,471 @@ async fn handle_jsonrpc(
         params,
     };
 
-    debug!(method = %request.method, "JSON-RPC request received");
+    let client = ClientIdentification::from_headers(
+        header_str(headers, CLIENT_IDENTIFICATION_HEADER),
+        header_str(headers, "user-agent"),
+    );
+    debug!(
+        method = %request.method,
+        client_sdk = client.as_ref().and_then(|c| c.sdk.as_deref()),
+        client_app = client.as_ref().and_then(|c| c.app.as_deref()),
+        "JSON-RPC request received"
+    );
+
+    let requested = get_requested_extensions(
+        &headers
+            .get_all(HTTP_EXTENSION_HEADER)
+            .iter()
+            .filter_map(|v| v.to_str().ok().map(String::from))
+            .collect::<Vec<_>>(),
+    );
+    let negotiation = negotiate_extensions(&state.agent_card, &requested);
+    if !negotiation.missing_required.is_empty() {
+        if request.id.is_none() {
+            debug!(method = %request.method, "Dropping notification: required extension missing");
+            return Err(with_version_header(StatusCode::NO_CONTENT.into_response()));
+        }
+        return Err(Json(JsonRpcResponse::error(
+            request.id,
+            error::INVALID_REQUEST,
+            format!(
+                "Required extension(s) not requested: {}",
+                negotiation.missing_required.join(", ")
+            ),
+        ))
+        .into_response());
+    }
+
+    let call_context = ServerCallContext {
+        requested_extensions: requested,
+        activated_extensions: negotiation.activated.clone(),
+        http: Some(http),
+        client,
+        user,
+        caller_identities,
+        request_id: Some(request_id.to_string()),
+        deadline: header_str(headers, DEADLINE_HEADER).and_then(parse_deadline),
+        ..Default::default()
+    };
+    Ok(PreparedCall {
+        request,
+        call_context,
+        activated: negotiation.activated,
+    })
+}
+
+/// Echo the `activated` extensions in the response header, and advertise
+/// the protocol version.
+fn with_activated_extensions(mut response: Response, activated: &HashSet<String>) -> Response {
+    if !activated.is_empty() {
+        if let (Ok(name), Ok(value)) = (
+            HeaderName::from_bytes(HTTP_EXTENSION_HEADER.as_bytes()),
+            HeaderValue::from_str(&format_extension_header(activated)),
+        ) {
+            response.headers_mut().insert(name, value);
+        }
+    }
+    with_version_header(response)
+}
+
+/// Echo `request_id` in the response's `X-A2A-Request-Id` header.
+fn with_request_id_header(mut response: Response, request_id: &str) -> Response {
+    if let Ok(value) = HeaderValue::from_str(request_id) {
+        response
+            .headers_mut()
+            .insert(HeaderName::from_static("x-a2a-request-id"), value);
+    }
+    response
+}
+
+/// Whether the request body is an NDJSON `message/sendStream` upload.
+fn is_upload(headers: &HeaderMap) -> bool {
+    header_str(headers, "content-type")
+        .and_then(StreamEncoding::from_content_type)
+        .is_some_and(|encoding| encoding == StreamEncoding::Ndjson)
+}
+
+/// Serve a `message/sendStream` upload: the JSON-RPC request on the first
+/// line of the NDJSON body, then one part per line.
+///
+/// The agent starts on the first line and reads the following parts as
+/// they arrive; the response is sent once the task (or the agent's direct
+/// reply) and the upload are done. Bodies over the router's maximum size
+/// break off the upload.
+async fn handle_upload(
+    state: Arc<AppState>,
+    headers: HeaderMap,
+    http: HttpRequestInfo,
+    user: Option<AuthenticatedUser>,
+    body: Body,
+    request_id: &str,
+) -> Response {
+    let mut lines = UploadLines::new(body, state.max_body_size);
+    let first = match lines.next_line().await {
+        Some(Ok(line)) => line,
+        Some(Err(e @ A2AError::PayloadTooLarge { .. })) => {
+            debug!(error = %e, "Rejecting oversized request");
+            return with_version_header(
+                (
+                    StatusCode::PAYLOAD_TOO_LARGE,
+                    Json(JsonRpcResponse::from_a2a_error(None, e)),
+                )
+                    .into_response(),
+            );
+        }
+        Some(Err(e)) => {
+            return Json(JsonRpcResponse::error(
+                None,
+                error::PARSE_ERROR,
+                format!("Parse error: {e}"),
+            ))
+            .into_response();
+        }
+        None => {
+            return Json(JsonRpcResponse::error(
+                None,
+                error::PARSE_ERROR,
+                "Parse error: empty request body".to_string(),
+            ))
+            .into_response();
+        }
+    };
+    let PreparedCall {
+        request,
+        call_context,
+        activated,
+    } = match prepare_call(&state, &headers, http, user, &first, request_id) {
+        Ok(call) => call,
+        Err(response) => return response,
+    };
+    if request.method != MESSAGE_SEND_STREAM_METHOD {
+        return with_version_header(
+            Json(JsonRpcResponse::error(
+                request.id,
+                error::INVALID_REQUEST,
+                format!(
+                    "Invalid Request: only {MESSAGE_SEND_STREAM_METHOD} accepts an NDJSON body"
+                ),
+            ))
+            .into_response(),
+        );
+    }
+    let params = match parse_send_message_params(request.params) {
+        Ok(p) => p,
+        Err(e) => {
+            return with_version_header(
+                Json(JsonRpcResponse::error(
+                    request.id,
+                    error::INVALID_PARAMS,
+                    format!("Invalid params: {}", e),
+                ))
+                .into_response(),
+            );
+        }
+    };
+
+    let (sender, incoming) = IncrementalMessage::channel(params.message.clone());
+    let id = request.id;
+    let notification = id.is_none();
+    let sent = send_incremental(state, id, params, incoming);
+    #[cfg(feature = "observability")]
+    let sent = continue_trace(&headers, sent);
+    let (response, ()) = tokio::join!(call_context.scope(sent), lines.feed(sender));
+    if notification {
+        return with_version_header(StatusCode::NO_CONTENT.into_response());
+    }
+    with_activated_extensions(response, &activated)
+}
+
+/// Lines of an NDJSON request body, at most `limit` bytes in all.
+struct UploadLines {
+    body: axum::body::BodyDataStream,
+    /// Bytes received but not yet returned as lines.
+    buffer: Vec<u8>,
+    received: usize,
+    limit: usize,
+    done: bool,
+}
+
+impl UploadLines {
+    fn new(body: Body, limit: usize) -> Self {
+        Self {
+            body: body.into_data_stream(),
+            buffer: Vec::new(),
+            received: 0,
+            limit,
+            done: false,
+        }
+    }
+
+    /// The next non-empty line, or `None` at the end of the body. A body
+    /// ending without a newline still yields its last line.
+    async fn next_line(&mut self) -> Option<A2AResult<Vec<u8>>> {
+        use futures::StreamExt;
+
+        loop {
+            if let Some(end) = self.buffer.iter().position(|&b| b == b'\n') {
+                let mut line: Vec<u8> = self.buffer.drain(..=end).collect();
+                line.pop();
+                if line.iter().all(u8::is_ascii_whitespace) {
+                    continue;
+                }
+                return Some(Ok(line));
+            }
+            if self.done {
+                let line = std::mem::take(&mut self.buffer);
+                return (!line.iter().all(u8::is_ascii_whitespace)).then_some(Ok(line));
+            }
+            match self.body.next().await {
+                Some(Ok(chunk)) => {
+                    self.received += chunk.len();
+                    if self.received > self.limit {
+                        self.done = true;
+                        self.buffer.clear();
+                        return Some(Err(A2AError::payload_too_large(
+                            "Request body",
+                            None,
+                            self.limit,
+                        )));
+                    }
+                    self.buffer.extend_from_slice(&chunk);
+                }
+                Some(Err(e)) => {
+                    self.done = true;
+                    self.buffer.clear();
+                    return Some(Err(A2AError::invalid_params(format!(
+                        "failed to read request body: {e}"
+                    ))));
+                }
+                None => self.done = true,
+            }
+        }
+    }
+
+    /// Send the remaining lines to `sender` as parts, finishing the message
+    /// at the end of the body. A line that isn't a part breaks off the
+    /// upload.
+    async fn feed(mut self, sender: IncrementalMessageSender) {
+        while let Some(line) = self.next_line().await {
+            let part = line.and_then(|line| {
+                serde_json::from_slice(&line)
+                    .map_err(|e| A2AError::invalid_params(format!("invalid part: {e}")))
+            });
+            match part {
+                Ok(part) => sender.send(part),
+                Err(e) => {
+                    debug!(error = %e, "Message upload broke off");
+                    return sender.fail(e);
+                }
+            }
+        }
+        sender.finish();
+    }
+}
+
+/// Authenticate the caller with the router's authenticator, if any.
+///
+/// Returns the user, or the `401`/`403` response for a caller that may not
+/// proceed.
+async fn authenticate(
+    state: &AppState,
+    headers: &HeaderMap,
+    uri: &Uri,
+) -> Result<Option<AuthenticatedUser>, Response> {
+    let Some(authenticator) = &state.authenticator else {
+        return Ok(None);
+    };
+    let user = match authenticator
+        .authenticate(&AuthRequest::new(headers, uri))
+        .await
+    {
+        Ok(user) => user,
+        Err(e) => {
+            debug!(error = %e, "Rejecting request with invalid credentials");
+            return Err(unauthenticated(state, format!("Unauthenticated: {e}")));
+        }
+    };
+    if state.security_requirements.is_empty() {
+        return Ok(user);
+    }
+    match user {
+        None => Err(unauthenticated(
+            state,
+            "Unauthenticated: credentials required".to_string(),
+        )),
+        Some(user) if satisfies_requirements(&user, &state.security_requirements) => Ok(Some(user)),
+        Some(user) => {
+            debug!(user = %user.name, scheme = %user.scheme, "Rejecting request: insufficient scope");
+            Err((
+                StatusCode::FORBIDDEN,
+                Json(JsonRpcResponse::error(
+                    None,
+                    error::INVALID_REQUEST,
+                    format!(
+                        "Forbidden: '{}' meets none of the agent's security requirements",
+                        user.name
+                    ),
+                )),
+            )
+                .into_response())
+        }
+    }
+}
+
+/// A `401 Unauthorized` response challenging the card's schemes.
+fn unauthenticated(state: &AppState, message: String) -> Response {
+    let mut response = (
+        StatusCode::UNAUTHORIZED,
+        Json(JsonRpcResponse::error(
+            None,
+            error::INVALID_REQUEST,
+            message,
+        )),
+    )
+        .into_response();
+    for challenge in www_authenticate_challenges(&state.agent_card) {
+        if let Ok(value) = HeaderValue::from_str(&challenge) {
+            response
+                .headers_mut()
+                .append(header::WWW_AUTHENTICATE, value);
+        }
+    }
+    response
+}
+
+/// Count the request against the router's rate limiter, if any.
+fn rate_limit(
+    state: &AppState,
+    headers: &HeaderMap,
+    http: &HttpRequestInfo,
+    method: &str,
+    params: &Value,
+    user: &Option<AuthenticatedUser>,
+) -> A2AResult<Vec<CallerIdentity>> {
+    let Some(limiter) = &state.rate_limiter else {
+        return Ok(Vec::new());
+    };
+    let request = RateLimitRequest::new(headers, method)
+        .with_peer_addr(http.peer_addr)
+        .with_tenant(params.get("tenant").and_then(Value::as_str))
+        .with_user(user.as_ref());
+    limiter.check(&request)
+}
+
+/// A `429 Too Many Requests` response for a rate-limited request; empty
+/// for notifications.
+fn rate_limited(id: Option<Value>, err: A2AError) -> Response {
+    let retry_after = err.retry_after().unwrap_or_default();
+    debug!(
+        retry_after_ms = retry_after.as_millis() as u64,
+        "Rejecting rate-limited request"
+    );
+    let mut response = match id {
+        Some(id) => (
+            StatusCode::TOO_MANY_REQUESTS,
+            Json(JsonRpcResponse::from_a2a_error(Some(id), err)),
+        )
+            .into_response(),
+        None => StatusCode::TOO_MANY_REQUESTS.into_response(),
+    };
+    // Whole seconds, rounded up.
+    let seconds = retry_after.as_secs() + u64::from(retry_after.subsec_nanos() > 0);
+    response
+        .headers_mut()
+        .insert(header::RETRY_AFTER, HeaderValue::from(seconds));
+    response
+}
+
+/// Check the protocol version the caller declared, if any. Only fails in
+/// strict mode.
+fn check_declared_version(state: &AppState, headers: &HeaderMap, params: &Value) -> A2AResult<()> {
+    let declared = header_str(headers, PROTOCOL_VERSION_HEADER)
+        .or_else(|| params.get(PROTOCOL_VERSION_PARAM).and_then(Value::as_str));
+    let Some(declared) = declared else {
+        return Ok(());
+    };
+    match ProtocolVersionRange::supported().check(declared) {
+        Err(e) if state.strict_protocol_version => Err(e),
+        Err(e) => {
+            debug!(
+                declared,
+                "Accepting request with unsupported protocol version: {e}"
+            );
+            Ok(())
+        }
+        Ok(_) => Ok(()),
+    }
+}
+
+/// Advertise the protocol version this server speaks.
+fn with_version_header(mut response: Response) -> Response {
+    response.headers_mut().insert(
+        HeaderName::from_static("a2a-version"),
+        HeaderValue::from_static(PROTOCOL_VERSION),
+    );
+    response
+}
+
+/// First value of header `name` if it is valid UTF-8.
+fn header_str<'a>(headers: &'a HeaderMap, name: &str) -> Option<&'a str> {
+    headers.get(name).and_then(|v| v.to_str().ok())
+}
+
+/// Run `future` in the trace carried by the request's `traceparent`
+/// header, or in a new trace.
+#[cfg(feature = "observability")]
+fn continue_trace<F: std::future::Future>(
+    headers: &HeaderMap,
+    future: F,
+) -> impl std::future::Future<Output = F::Output> {
+    use crate::observability::{TRACEPARENT_HEADER, TRACESTATE_HEADER};
+
+    crate::observability::continue_trace(
+        header_str(headers, TRACEPARENT_HEADER).map(String::from),
+        header_str(headers, TRACESTATE_HEADER).map(String::from),
+        future,
+    )
+}
+
+/// Collect the HTTP details exposed to handlers, keeping only allow-listed
+/// headers.
+fn http_request_info(
+    state: &AppState,
+    method: &Method,
+    uri: &Uri,
+    extensions: &Extensions,
+    headers: &HeaderMap,
+) -> HttpRequestInfo {
+    let captured = state
+        .captured_headers
+        .iter()
+        .filter_map(|name| {
+            let values: Vec<&str> = headers
+                .get_all(name)
+                .iter()
+                .filter_map(|v| v.to_str().ok())
+                .collect();
+            (!values.is_empty()).then(|| (name.as_str().to_string(), values.join(", ")))
+        })
+        .collect();
+    HttpRequestInfo {
+        method: method.to_string(),
+        path: uri.path().to_string(),
+        peer_addr: extensions
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|info| info.0),
+        headers: captured,
+    }
+}
+
+/// Route a validated JSON-RPC request to its method handler. Streams are
+/// answered in `encoding`.
+async fn dispatch(
+    state: Arc<AppState>,
+    request: JsonRpcRequest,
+    encoding: StreamEncoding,
+) -> Response {
+    let method = request.method.clone();
 
     // Method dispatch — accept both spec names and common aliases
     match method.as_str() {
@@ -252,18 +1503,16 @@ async fn handle_jsonrpc(
             handle_message_send(state, request).await
         }
         "message/stream" | "message:stream" | "SendStreamingMessage" => {
-            handle_message_stream(state, request).await
+            handle_message_stream(state, request, encoding).await
         }
         "tasks/get" | "tasks:get" | "GetTask" => handle_tasks_get(state, request).await,
         "tasks/list" | "tasks:list" | "ListTasks" => handle_tasks_list(state, request).await,
-        "tasks/cancel" | "tasks:cancel" | "CancelTask" => {
-            handle_tasks_cancel(state, request).await
-        }
+        "tasks/cancel" | "tasks:cancel" | "CancelTask" => handle_tasks_cancel(state, request).await,
         "tasks/subscribe" | "tasks:subscribe" | "SubscribeToTask" => {
-            handle_tasks_subscribe(state, request).await
+            handle_tasks_subscribe(state, request, encoding).await
         }
         "tasks/resubscribe" | "tasks:resubscribe" | "ResubscribeToTask" => {
-            handle_tasks_resubscribe(state, request).await
+            handle_tasks_resubscribe(state, request, encoding).await
         }
         "tasks/pushNotificationConfig/set" | "SetTaskPushNotificationConfig" => {
             handle_push_notification_config_set(state, request).await
@@ -280,6 +1529,17 @@ async fn handle_jsonrpc(
         "agent/authenticatedExtendedCard" | "GetExtendedAgentCard" => {
             handle_authenticated_extended_card(state, request).await
         }
+        SKILLS_INVOKE_METHOD => handle_skills_invoke(state, request),
+        CONTEXTS_CANCEL_METHOD => handle_contexts_cancel(state, request).await,
+        TASKS_ARTIFACTS_LIST_METHOD => handle_artifacts_list(state, request).await,
+        TASKS_ARTIFACTS_GET_METHOD => handle_artifacts_get(state, request).await,
+        MESSAGE_SEND_STREAM_METHOD => handle_message_send_stream(state, request).await,
+        _ if request.id.is_none() => {
+            if let Err(e) = state.handler.on_notification(&method, request.params).await {
+                debug!(method = %method, error = %e, "Notification handler failed");
+            }
+            StatusCode::NO_CONTENT.into_response()
+        }
         _ => {
             warn!(method = %method, "Unknown JSON-RPC method");
             Json(JsonRpcResponse::error(
@@ -293,7 +1553,7 @@ async fn handle_jsonrpc(
 }
 
 /// Parse `SendMessageParams` from JSON-RPC params.
-fn parse_send_message_params(params: Value) -> Result<SendMessageParams, String> {
+pub(crate) fn parse_send_message_params(params: Value) -> Result<SendMessageParams, String> {
     let obj = params.as_object().ok_or("params must be an object")?;
 
     let message: crate::types::Message = serde_json::from_value(
@@ -345,6 +1605,10 @@ fn parse_send_config(value: Value) -> Result<SendMessageConfiguration, String> {
             .get("historyLength")
             .and_then(|v| v.as_u64().map(|n| n as usize)),
         push_notification_config: obj.get("pushNotificationConfig").cloned(),
+        timeout: obj
+            .get("timeoutMs")
+            .and_then(|v| v.as_u64())
+            .map(Duration::from_millis),
     })
 }
 
@@ -365,15 +1629,54 @@ async fn handle_message_send(state: Arc<AppState>, request: JsonRpcRequest) -> R
         }
     };
 
-    match state.handler.on_message_send(params).await {
+    let result = state.handler.on_message_send(params).await;
+    send_message_response(request.id, result)
+}
+
+/// Handle `message/sendStream` sent as a plain JSON request: the message
+/// arrived whole. Uploads go through [`handle_upload`].
+async fn handle_message_send_stream(state: Arc<AppState>, request: JsonRpcRequest) -> Response {
+    let params = match parse_send_message_params(request.params) {
+        Ok(p) => p,
+        Err(e) => {
+            return Json(JsonRpcResponse::error(
+                request.id,
+                error::INVALID_PARAMS,
+                format!("Invalid params: {}", e),
+            ))
+            .into_response();
+        }
+    };
+
+    let incoming = IncrementalMessage::complete(params.message.clone());
+    send_incremental(state, request.id, params, incoming).await
+}
+
+/// Hand a `message/sendStream` call to the handler.
+async fn send_incremental(
+    state: Arc<AppState>,
+    id: Option<Value>,
+    params: SendMessageParams,
+    incoming: IncrementalMessage,
+) -> Response {
+    let result = state
+        .handler
+        .on_message_send_incremental(params, incoming)
+        .await;
+    send_message_response(id, result)
+}
+
+/// The JSON-RPC response to a `message/send` or `message/sendStream` call.
+fn send_message_response(id: Option<Value>, result: A2AResult<SendMessageResponse>) -> Response {
+    match result {
         Ok(response) => {
             let result = serde_json::to_value(&response);
             match result {
-                Ok(v) => Json(JsonRpcResponse::success(request.id, v)).into_response(),
+                Ok(v) => Json(JsonRpcResponse::success(id, v)).into_response(),
                 Err(e) => {
                     error!(error = %e, "Failed to serialize response");
                     Json(JsonRpcResponse::error(
-                        request.id,
+                        id,
                         error::INTERNAL_ERROR,
                         format!("Internal error: {}", e),
                     ))
@@ -381,7 +1684,7 @@ async fn handle_message_send(state: Arc<AppState>, request: JsonRpcRequest) -> R
                 }
             }
         }
-        Err(e) => Json(JsonRpcResponse::from_a2a_error(request.id, e)).into_response(),
+        Err(e) => Json(JsonRpcResponse::from_a2a_error(id, e)).into_response(),
     }
 }
 
@@ -391,7 +1694,11 @@ async fn handle_message_send(state: Arc<AppState>, request: JsonRpcRequest) -> R
 /// 1. Validates streaming is supported via agent card capabilities
 /// 2. Wraps each event in a JSON-RPC success response envelope
 /// 3. Catches errors and yields them as JSON-RPC error responses
-async fn handle_message_stream(state: Arc<AppState>, request: JsonRpcRequest) -> Response {
+async fn handle_message_stream(
+    state: Arc<AppState>,
+    request: JsonRpcRequest,
+    encoding: StreamEncoding,
+) -> Response {
     // Check streaming capability (mirrors Python SDK's @validate decorator).
     if !state.agent_card.capabilities.streaming.unwrap_or(false) {
         return Json(JsonRpcResponse::error(
@@ -415,12 +1722,7 @@ async fn handle_message_stream(state: Arc<AppState>, request: JsonRpcRequest) ->
     };
 
     match state.handler.on_message_send_stream(params).await {
-        Ok(rx) => {
-            let stream = make_sse_stream(request.id, rx);
-            Sse::new(stream)
-                .keep_alive(KeepAlive::default())
-                .into_response()
-        }
+        Ok(rx) => state.respond_stream(encoding, request.id, rx),
         Err(e) => Json(JsonRpcResponse::from_a2a_error(request.id, e)).into_response(),
     }
 }
@@ -441,6 +1743,14 @@ async fn handle_tasks_get(state: Arc<AppState>, request: JsonRpcRequest) -> Resp
 
     match state.handler.on_get_task(params).await {
         Ok(task) => match serde_json::to_value(&task) {
+            Ok(v) if chunked_tasks_activated() => {
+                let size = serde_json::to_vec(&v).map_or(0, |bytes| bytes.len());
+                if size > state.chunked_task_threshold {
+                    chunked_task_response(request.id, &task)
+                } else {
+                    Json(JsonRpcResponse::success(request.id, v)).into_response()
+                }
+            }
             Ok(v) => Json(JsonRpcResponse::success(request.id, v)).into_response(),
             Err(e) => Json(JsonRpcResponse::error(
                 request.id,
@@ -453,6 +1763,34 @@ async fn handle_tasks_get(state: Arc<AppState>, request: JsonRpcRequest) -> Resp
     }
 }
 
+/// Whether the current request activated [`CHUNKED_TASK_EXTENSION_URI`].
+fn chunked_tasks_activated() -> bool {
+    ServerCallContext::current().is_some_and(|ctx| {
+        ctx.activated_extensions
+            .contains(CHUNKED_TASK_EXTENSION_URI)
+    })
+}
+
+/// `task` as a chunked body: one JSON-RPC response per line, each carrying
+/// a [`TaskSection`](crate::utils::TaskSection).
+fn chunked_task_response(id: Option<Value>, task: &Task) -> Response {
+    let lines: Vec<Result<Vec<u8>, Infallible>> = split_task(task, HISTORY_PAGE_SIZE)
+        .into_iter()
+        .map(|section| {
+            let value = serde_json::to_value(section).unwrap_or(Value::Null);
+            let mut line = serde_json::to_vec(&JsonRpcResponse::success(id.clone(), value))
+                .unwrap_or_default();
+            line.push(b'\n');
+            Ok(line)
+        })
+        .collect();
+    (
+        [(header::CONTENT_TYPE, CHUNKED_TASK_CONTENT_TYPE)],
+        Body::from_stream(futures::stream::iter(lines)),
+    )
+        .into_response()
+}
+
 /// Handle `tasks/list`.
 async fn handle_tasks_list(state: Arc<AppState>, request: JsonRpcRequest) -> Response {
     let params = match parse_list_tasks_params(request.params) {
@@ -517,7 +1855,11 @@ async fn handle_tasks_cancel(state: Arc<AppState>, request: JsonRpcRequest) -> R
 }
 
 /// Handle `tasks/subscribe` — SSE streaming for an existing task.
-async fn handle_tasks_subscribe(state: Arc<AppState>, request: JsonRpcRequest) -> Response {
+async fn handle_tasks_subscribe(
+    state: Arc<AppState>,
+    request: JsonRpcRequest,
+    encoding: StreamEncoding,
+) -> Response {
     let params = match parse_subscribe_params(request.params) {
         Ok(p) => p,
         Err(e) => {
@@ -531,12 +1873,7 @@ async fn handle_tasks_subscribe(state: Arc<AppState>, request: JsonRpcRequest) -
     };
 
     match state.handler.on_subscribe_to_task(params).await {
-        Ok(rx) => {
-            let stream = make_sse_stream(request.id, rx);
-            Sse::new(stream)
-                .keep_alive(KeepAlive::default())
-                .into_response()
-        }
+        Ok(rx) => state.respond_stream(encoding, request.id, rx),
         Err(e) => Json(JsonRpcResponse::from_a2a_error(request.id, e)).into_response(),
     }
 }
@@ -544,7 +1881,11 @@ async fn handle_tasks_subscribe(state: Arc<AppState>, request: JsonRpcRequest) -
 /// Handle `tasks/resubscribe` — re-subscribe to a running task's event stream.
 ///
 /// Mirrors Python SDK's `on_resubscribe_to_task`.
-async fn handle_tasks_resubscribe(state: Arc<AppState>, request: JsonRpcRequest) -> Response {
+async fn handle_tasks_resubscribe(
+    state: Arc<AppState>,
+    request: JsonRpcRequest,
+    encoding: StreamEncoding,
+) -> Response {
     let params = match parse_subscribe_params(request.params) {
         Ok(p) => p,
         Err(e) => {
@@ -558,12 +1899,7 @@ async fn handle_tasks_resubscribe(state: Arc<AppState>, request: JsonRpcRequest)
     };
 
     match state.handler.on_resubscribe_to_task(params).await {
-        Ok(rx) => {
-            let stream = make_sse_stream(request.id, rx);
-            Sse::new(stream)
-                .keep_alive(KeepAlive::default())
-                .into_response()
-        }
+        Ok(rx) => state.respond_stream(encoding, request.id, rx),
         Err(e) => Json(JsonRpcResponse::from_a2a_error(request.id, e)).into_response(),
     }
 }
@@ -648,9 +1984,132 @@ async fn handle_authenticated_extended_card(
     }
 }
 
+/// Handle `skills/invoke` — validate a message against a skill of the
+/// served agent card without executing it. Only `dryRun: true` is
+/// supported.
+fn handle_skills_invoke(state: Arc<AppState>, request: JsonRpcRequest) -> Response {
+    let params: SkillInvokeParams = match serde_json::from_value(request.params) {
+        Ok(p) => p,
+        Err(e) => {
+            return Json(JsonRpcResponse::error(
+                request.id,
+                error::INVALID_PARAMS,
+                format!("Invalid params: {}", e),
+            ))
+            .into_response();
+        }
+    };
+    if !params.dry_run {
+        return Json(JsonRpcResponse::error(
+            request.id,
+            error::UNSUPPORTED_OPERATION,
+            "skills/invoke only supports dryRun; use message/send to execute".to_string(),
+        ))
+        .into_response();
+    }
+
+    let result = validate_skill_message(&state.agent_card, &params).and_then(|result| {
+        serde_json::to_value(result).map_err(|e| A2AError::InternalError {
+            message: e.to_string(),
+            data: None,
+        })
+    });
+    match result {
+        Ok(v) => Json(JsonRpcResponse::success(request.id, v)).into_response(),
+        Err(e) => Json(JsonRpcResponse::from_a2a_error(request.id, e)).into_response(),
+    }
+}
+
+/// Handle `contexts/cancel` — cancel every non-terminal task of a context.
+async fn handle_contexts_cancel(state: Arc<AppState>, request: JsonRpcRequest) -> Response {
+    let params: CancelContextParams = match serde_json::from_value(request.params) {
+        Ok(p) => p,
+        Err(e) => {
+            return Json(JsonRpcResponse::error(
+                request.id,
+                error::INVALID_PARAMS,
+                format!("Invalid params: {}", e),
+            ))
+            .into_response();
+        }
+    };
+    let result = state
+        .handler
+        .on_cancel_context(params)
+        .await
+        .and_then(|result| {
+            serde_json::to_value(result).map_err(|e| A2AError::InternalError {
+                message: e.to_string(),
+                data: None,
+            })
+        });
+    match result {
+        Ok(v) => Json(JsonRpcResponse::success(request.id, v)).into_response(),
+        Err(e) => Json(JsonRpcResponse::from_a2a_error(request.id, e)).into_response(),
+    }
+}
+
+/// Handle `tasks/artifacts/list` — a task's artifacts without their parts.
+async fn handle_artifacts_list(state: Arc<AppState>, request: JsonRpcRequest) -> Response {
+    let params: ListArtifactsParams = match serde_json::from_value(request.params) {
+        Ok(p) => p,
+        Err(e) => {
+            return Json(JsonRpcResponse::error(
+                request.id,
+                error::INVALID_PARAMS,
+                format!("Invalid params: {}", e),
+            ))
+            .into_response();
+        }
+    };
+    let result = state
+        .handler
+        .on_list_artifacts(params)
+        .await
+        .and_then(|result| {
+            serde_json::to_value(result).map_err(|e| A2AError::InternalError {
+                message: e.to_string(),
+                data: None,
+            })
+        });
+    match result {
+        Ok(v) => Json(JsonRpcResponse::success(request.id, v)).into_response(),
+        Err(e) => Json(JsonRpcResponse::from_a2a_error(request.id, e)).into_response(),
+    }
+}
+
+/// Handle `tasks/artifacts/get` — one artifact of a task.
+async fn handle_artifacts_get(state: Arc<AppState>, request: JsonRpcRequest) -> Response {
+    let params: GetArtifactParams = match serde_json::from_value(request.params) {
+        Ok(p) => p,
+        Err(e) => {
+            return Json(JsonRpcResponse::error(
+                request.id,
+                error::INVALID_PARAMS,
+                format!("Invalid params: {}", e),
+            ))
+            .into_response();
+        }
+    };
+    let result = state
+        .handler
+        .on_get_artifact(params)
+        .await
+        .and_then(|result| {
+            serde_json::to_value(result).map_err(|e| A2AError::InternalError {
+                message: e.to_string(),
+                data: None,
+            })
+        });
+    match result {
+        Ok(v) => Json(JsonRpcResponse::success(request.id, v)).into_response(),
+        Err(e) => Json(JsonRpcResponse::from_a2a_error(request.id, e)).into_response(),
+    }
+}
+
 // ---- Parameter parsing helpers ----
 
-fn parse_get_task_params(params: Value) -> Result<GetTaskParams, String> {
+pub(crate) fn parse_get_task_params(params: Value) -> Result<GetTaskParams, String> {
     let obj = params.as_object().ok_or("params must be an object")?;
     let id = obj
         .get("id")
@@ -665,30 +2124,37 @@ fn parse_get_task_params(params: Value) -> Result<GetTaskParams, String> {
         .and_then(|v| if v.is_null() { None } else { Some(v.clone()) });
 
     let tenant = obj.get("tenant").and_then(|v| v.as_str().map(String::from));
+    let consistency = match obj.get("consistency") {
+        None | Some(Value::Null) => None,
+        Some(v) => Some(
+            serde_json::from_value(v.clone()).map_err(|e| format!("invalid 'consistency': {e}"))?,
+        ),
+    };
 
     Ok(GetTaskParams {
         id,
         history_length,
         metadata,
         tenant,
+        consistency,
     })
 }
 
-fn parse_list_tasks_params(params: Value) -> Result<TaskListParams, String> {
+pub(crate) fn parse_list_tasks_params(params: Value) -> Result<TaskListParams, String> {
     let obj = params.as_object().ok_or("params must be an object")?;
 
     let context_id = obj
         .get("contextId")
         .and_then(|v| v.as_str().map(String::from));
-    let status = obj.get("status").and_then(|v| {
-        v.as_array().map(|arr| {
-            arr.iter()
-                .filter_map(|v| {
-                    v.as_str()
-                        .and_then(|s| serde_json::from_value(Value::String(s.to_string())).ok())
-                })
-                .collect()
-        })
+    // A list of states, or the single state of the spec's `ListTasksRequest`.
+    let parse_state = |v: &Value| {
+        v.as_str()
+            .and_then(|s| serde_json::from_value(Value::String(s.to_string())).ok())
+    };
+    let status = obj.get("status").and_then(|v| match v {
+        Value::Array(arr) => Some(arr.iter().filter_map(parse_state).collect()),
+        Value::String(_) => Some(parse_state(v).into_iter().collect()),
+        _ => None,
     });
     let page_size = obj
         .get("pageSize")
@@ -696,16 +2162,22 @@ fn parse_list_tasks_params(params: Value) -> Result<TaskListParams, String> {
     let page_token = obj
         .get("pageToken")
         .and_then(|v| v.as_str().map(String::from));
+    let history_length = obj
+        .get("historyLength")
+        .and_then(|v| v.as_u64().map(|n| n as usize));
+    let tenant = obj.get("tenant").and_then(|v| v.as_str().map(String::from));
 
     Ok(TaskListParams {
         context_id,
         status,
         page_size,
         page_token,
+        history_length,
+        tenant,
     })
 }
 
-fn parse_cancel_task_params(params: Value) -> Result<CancelTaskParams, String> {
+pub(crate) fn parse_cancel_task_params(params: Value) -> Result<CancelTaskParams, String> {
     let obj = params.as_object().ok_or("params must be an object")?;
     let id = obj
         .get("id")
@@ -725,7 +2197,7 @@ fn parse_cancel_task_params(params: Value) -> Result<CancelTaskParams, String> {
     })
 }
 
-fn parse_subscribe_params(params: Value) -> Result<SubscribeToTaskParams, String> {
+pub(crate) fn parse_subscribe_params(params: Value) -> Result<SubscribeToTaskParams, String> {
     let obj = params.as_object().ok_or("params must be an object")?;
     let id = obj
         .get("id")
@@ -747,6 +2219,14 @@ fn parse_subscribe_params(params: Value) -> Result<SubscribeToTaskParams, String
 
 // ---- SSE streaming ----
 
+/// A Data part delta encoder if the current request activated
+/// [`DATA_DELTA_EXTENSION_URI`].
+fn delta_encoder() -> Option<DataDeltaEncoder> {
+    ServerCallContext::current()
+        .filter(|ctx| ctx.activated_extensions.contains(DATA_DELTA_EXTENSION_URI))
+        .map(|_| DataDeltaEncoder::new())
+}
+
 /// Create an SSE stream from a broadcast receiver.
 ///
 /// Each `StreamResponse` event is wrapped in a JSON-RPC 2.0 success response
@@ -754,63 +2234,81 @@ fn parse_subscribe_params(params: Value) -> Result<SubscribeToTaskParams, String
 /// `JSONRPCHandler.on_message_send_stream` which wraps each event in a
 /// `SendStreamingMessageSuccessResponse`.
 ///
-/// The stream ends when the channel is closed or a terminal status update is received.
+/// The stream ends with a `done` event when the channel is closed, a
+/// terminal status update is received, or after a direct message reply.
 fn make_sse_stream(
     request_id: Option<Value>,
-    mut rx: broadcast::Receiver<StreamResponse>,
+    rx: broadcast::Receiver<StreamResponse>,
+    delta: Option<DataDeltaEncoder>,
 ) -> impl Stream<Item = Result<Event, Infallible>> {
+    use futures::StreamExt;
+
+    encode_events(request_id, rx, delta)
+        .map(|(event_type, json)| Ok(Event::default().event(event_type).data(json)))
+        .chain(futures::stream::once(async {
+            // Send a final empty event to signal completion.
+            Ok(Event::default().event("done").data(""))
+        }))
+}
+
+/// Create an NDJSON stream from a broadcast receiver: the JSON-RPC
+/// envelopes of [`make_sse_stream`], one per line (without the newline).
+fn make_ndjson_stream(
+    request_id: Option<Value>,
+    rx: broadcast::Receiver<StreamResponse>,
+    delta: Option<DataDeltaEncoder>,
+) -> impl Stream<Item = String> {
+    use futures::StreamExt;
+
+    encode_events(request_id, rx, delta).map(|(_, json)| json)
+}
+
+/// Encode the events of `rx` as JSON-RPC response envelopes, each with its
+/// SSE event type, until the channel is closed, a terminal status update is
+/// received, or the first event is a message (the agent's direct reply).
+///
+/// The stream is traced in an `a2a.sse` span; each event is encoded in an
+/// `a2a.sse.write` child span with its 1-based `sequence` on this stream.
+fn encode_events(
+    request_id: Option<Value>,
+    mut rx: broadcast::Receiver<StreamResponse>,
+    mut delta: Option<DataDeltaEncoder>,
+) -> impl Stream<Item = (&'static str, String)> {
+    let stream_span = debug_span!("a2a.sse", request_id = ?request_id, task_id = field::Empty);
+    let mut sequence = 0u64;
     async_stream::stream! {
         loop {
             match rx.recv().await {
                 Ok(event) => {
+                    sequence += 1;
+                    if sequence == 1 {
+                        stream_span.record("task_id", event.task_id());
+                    }
+                    let span = debug_span!(
+                        parent: &stream_span,
+                        "a2a.sse.write",
+                        sequence,
+                        kind = event.kind(),
+                    );
+                    // A message first is a direct reply, the whole stream.
                     let is_terminal = match &event {
                         StreamResponse::StatusUpdate(update) => update.r#final,
+                        StreamResponse::Message(_) => sequence == 1,
                         _ => false,
                     };
-
-                    // Determine the event type for SSE.
-                    let event_type = match &event {
-                        StreamResponse::StatusUpdate(_) => "statusUpdate",
-                        StreamResponse::ArtifactUpdate(_) => "artifactUpdate",
-                        StreamResponse::Task(_) => "task",
-                        StreamResponse::Message(_) => "message",
-                    };
-
-                    // Wrap in JSON-RPC response envelope (mirrors Python SDK's
-                    // prepare_response_object wrapping in SendStreamingMessageResponse).
-                    match serde_json::to_value(&event) {
-                        Ok(result_value) => {
-                            let rpc_response = JsonRpcResponse::success(
-                                request_id.clone(),
-                                result_value,
-                            );
-                            match serde_json::to_string(&rpc_response) {
-                                Ok(json) => {
-                                    yield Ok(Event::default()
-                                        .event(event_type)
-                                        .data(json));
-                                }
-                                Err(e) => {
-                                    error!(error = %e, "Failed to serialize SSE JSON-RPC response");
-                                }
-                            }
-                        }
-                        Err(e) => {
-                            error!(error = %e, "Failed to serialize SSE event");
-                        }
+                    if let Some(encoded) =
+                        span.in_scope(|| encode_event(&request_id, event, delta.as_mut()))
+                    {
+                        yield encoded;
                     }
 
                     if is_terminal {
-                        // Send a final empty event to signal completion.
-                        yield Ok(Event::default().event("done").data(""));
                         break;
                     }
                 }
-                Err(broadcast::error::RecvError::Closed) => {
-                    yield Ok(Event::default().event("done").data(""));
-                    break;
-                }
+                Err(broadcast::error::RecvError::Closed) => break,
                 Err(broadcast::error::RecvError::Lagged(n)) => {
+                    sequence += n;
                     warn!(missed = n, "SSE stream lagged — some events were missed");
                     // Continue receiving.
                 }
@@ -818,3 +2316,42 @@ fn make_sse_stream(
         }
     }
 }
+
+/// Encode one event as a JSON-RPC response envelope, with its SSE event
+/// type.
+fn encode_event(
+    request_id: &Option<Value>,
+    event: StreamResponse,
+    delta: Option<&mut DataDeltaEncoder>,
+) -> Option<(&'static str, String)> {
+    let event = match delta {
+        Some(encoder) => encoder.encode(event),
+        None => event,
+    };
+
+    // Determine the event type for SSE.
+    let event_type = match &event {
+        StreamResponse::StatusUpdate(_) => "statusUpdate",
+        StreamResponse::ArtifactUpdate(_) => "artifactUpdate",
+        StreamResponse::Task(_) => "task",
+        StreamResponse::Message(_) => "message",
+    };
+
+    // Wrap in JSON-RPC response envelope (mirrors Python SDK's
+    // prepare_response_object wrapping in SendStreamingMessageResponse).
+    let result_value = match serde_json::to_value(&event) {
+        Ok(value) => value,
+        Err(e) => {
+            error!(error = %e, "Failed to serialize SSE event");
+            return None;
+        }
+    };
+    let rpc_response = JsonRpcResponse::success(request_id.clone(), result_value);
+    match serde_json::to_string(&rpc_response) {
+        Ok(json) => Some((event_type, json)),
+        Err(e) => {
+            error!(error = %e, "Failed to serialize SSE JSON-RPC response");
+            None
+        }
+    }
+}
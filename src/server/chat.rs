@@ -0,0 +1,382 @@
+//! Plain HTTP chat endpoint alongside the A2A surface.
+//!
+//! [`chat_router`] serves `POST /chat` in the shape of the OpenAI chat
+//! completions API, backed by the same [`RequestHandler`] (and so the same
+//! [`AgentExecutor`](super::AgentExecutor) and task store) as
+//! [`a2a_router`](super::a2a_router). Existing chat frontends can talk to
+//! the agent without an A2A client, while `/a2a` stays spec-compliant.
+//!
+//! # Translation
+//!
+//! - The last `user` message becomes the A2A message; earlier turns are
+//!   not replayed — the agent's context carries the conversation. Pass the
+//!   `context_id` from a previous response (a non-standard request field)
+//!   to continue it.
+//! - The reply is the text of the task's artifacts, or of its final status
+//!   message if it has none, or of a direct `Message` reply.
+//! - With `"stream": true`, artifact text is streamed as
+//!   `chat.completion.chunk` deltas over SSE, ending with `data: [DONE]`.
+//! - Failed, rejected and canceled tasks answer HTTP 502 with an
+//!   OpenAI-style `error` object (in-band on streams).
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! let handler: Arc<dyn RequestHandler> = Arc::new(DefaultRequestHandler::new(executor, store));
+//! let app = a2a_router(handler.clone(), card).merge(chat_router(handler));
+//! ```
+//!
+//! ```text
+//! POST /chat
+//! {"messages": [{"role": "user", "content": "hello"}], "stream": false}
+//!
+//! 200 OK
+//! {"id": "<task id>", "object": "chat.completion", "context_id": "...",
+//!  "choices": [{"index": 0, "message": {"role": "assistant", "content": "..."},
+//!               "finish_reason": "stop"}], ...}
+//! ```
+
+use std::convert::Infallible;
+use std::sync::Arc;
+
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::response::sse::{Event, KeepAlive, Sse};
+use axum::response::{IntoResponse, Response};
+use axum::routing::post;
+use axum::{Json, Router};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use tokio::sync::broadcast;
+use tracing::warn;
+
+use crate::error::A2AError;
+use crate::types::{Message, Part, SendMessageResponse, StreamResponse, Task, TaskState};
+use crate::utils::{get_message_text, get_text_parts};
+
+use super::request_handler::{RequestHandler, SendMessageConfiguration, SendMessageParams};
+
+/// `model` reported when the request doesn't name one.
+const DEFAULT_MODEL: &str = "a2a-agent";
+
+/// Router serving `POST /chat` from `handler`. See the
+/// [module documentation](self) for the request and response format.
+///
+/// Merge it into the A2A router (`a2a_router(..).merge(..)`) to serve both
+/// from one listener.
+pub fn chat_router(handler: Arc<dyn RequestHandler>) -> Router {
+    Router::new()
+        .route("/chat", post(handle_chat))
+        .with_state(handler)
+}
+
+/// `POST /chat` request body.
+#[derive(Debug, Deserialize)]
+struct ChatRequest {
+    messages: Vec<ChatMessage>,
+    #[serde(default)]
+    model: Option<String>,
+    #[serde(default)]
+    stream: bool,
+    /// Non-standard: the A2A context to continue.
+    #[serde(default)]
+    context_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatMessage {
+    role: String,
+    #[serde(default)]
+    content: ChatContent,
+}
+
+/// A message's `content`: a string, or an array of content parts of which
+/// only `text` ones are used.
+#[derive(Debug, Default, Deserialize)]
+#[serde(untagged)]
+enum ChatContent {
+    Text(String),
+    Parts(Vec<ChatContentPart>),
+    #[default]
+    None,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatContentPart {
+    #[serde(default)]
+    text: Option<String>,
+}
+
+impl ChatContent {
+    fn text(&self) -> String {
+        match self {
+            ChatContent::Text(text) => text.clone(),
+            ChatContent::Parts(parts) => parts.iter().filter_map(|p| p.text.as_deref()).collect(),
+            ChatContent::None => String::new(),
+        }
+    }
+}
+
+/// An OpenAI-style error response.
+fn error_response(status: StatusCode, kind: &str, message: impl Into<String>) -> Response {
+    (status, Json(error_body(kind, message))).into_response()
+}
+
+fn error_body(kind: &str, message: impl Into<String>) -> Value {
+    json!({"error": {"message": message.into(), "type": kind}})
+}
+
+fn handler_error(err: A2AError) -> Response {
+    let (status, kind) = match &err {
+        A2AError::InvalidParams { .. } | A2AError::InvalidRequest { .. } => {
+            (StatusCode::BAD_REQUEST, "invalid_request_error")
+        }
+        A2AError::TaskNotFound { .. } => (StatusCode::NOT_FOUND, "invalid_request_error"),
+        A2AError::UnsupportedOperation { .. } | A2AError::ContentTypeNotSupported { .. } => {
+            (StatusCode::BAD_REQUEST, "invalid_request_error")
+        }
+        _ => (StatusCode::INTERNAL_SERVER_ERROR, "server_error"),
+    };
+    error_response(status, kind, err.to_string())
+}
+
+/// Message parameters for the last user message of `request`, if any.
+fn send_params(request: &ChatRequest) -> Option<SendMessageParams> {
+    let last = request.messages.iter().rev().find(|m| m.role == "user")?;
+    let mut message = Message::user(uuid::Uuid::new_v4().to_string(), last.content.text());
+    message.context_id = request.context_id.clone();
+    Some(SendMessageParams {
+        message,
+        configuration: Some(SendMessageConfiguration {
+            accepted_output_modes: Some(vec!["text/plain".to_string()]),
+            blocking: Some(true),
+            history_length: Some(0),
+            push_notification_config: None,
+            timeout: None,
+        }),
+        metadata: None,
+        tenant: None,
+    })
+}
+
+async fn handle_chat(
+    State(handler): State<Arc<dyn RequestHandler>>,
+    body: axum::body::Bytes,
+) -> Response {
+    let request: ChatRequest = match serde_json::from_slice(&body) {
+        Ok(request) => request,
+        Err(e) => {
+            return error_response(
+                StatusCode::BAD_REQUEST,
+                "invalid_request_error",
+                format!("Invalid chat request: {e}"),
+            )
+        }
+    };
+    let Some(params) = send_params(&request) else {
+        return error_response(
+            StatusCode::BAD_REQUEST,
+            "invalid_request_error",
+            "`messages` must contain a user message",
+        );
+    };
+    let model = request
+        .model
+        .clone()
+        .unwrap_or_else(|| DEFAULT_MODEL.to_string());
+
+    if request.stream {
+        match handler.on_message_send_stream(params).await {
+            Ok(rx) => stream_response(rx, model),
+            Err(e) => handler_error(e),
+        }
+    } else {
+        match handler.on_message_send(params).await {
+            Ok(response) => completion_response(response, &model),
+            Err(e) => handler_error(e),
+        }
+    }
+}
+
+/// Whether a task that ended in `state` failed to answer.
+fn is_failure(state: &TaskState) -> bool {
+    matches!(
+        state,
+        TaskState::Failed | TaskState::Rejected | TaskState::Canceled
+    )
+}
+
+/// Whether a task in `state` has answered or failed, i.e. its turn is over.
+fn is_done(state: &TaskState) -> bool {
+    is_failure(state)
+        || matches!(
+            state,
+            TaskState::Completed | TaskState::InputRequired | TaskState::AuthRequired
+        )
+}
+
+fn status_text(task: &Task) -> String {
+    task.status
+        .message
+        .as_ref()
+        .map(|message| get_message_text(message, ""))
+        .unwrap_or_default()
+}
+
+/// The reply text of a task: its artifacts' text, else its status message.
+fn task_text(task: &Task) -> String {
+    let artifacts = task
+        .artifacts
+        .iter()
+        .flatten()
+        .map(|artifact| get_text_parts(&artifact.parts).concat())
+        .filter(|text| !text.is_empty())
+        .collect::<Vec<_>>();
+    if artifacts.is_empty() {
+        status_text(task)
+    } else {
+        artifacts.join("\n")
+    }
+}
+
+fn task_failure(task: &Task) -> String {
+    let text = status_text(task);
+    if text.is_empty() {
+        format!("Task {} ended {}", task.id, task.status.state)
+    } else {
+        text
+    }
+}
+
+fn created() -> i64 {
+    chrono::Utc::now().timestamp()
+}
+
+fn completion_response(response: SendMessageResponse, model: &str) -> Response {
+    let (id, context_id, content) = match &response {
+        SendMessageResponse::Message(message) => (
+            message.message_id.clone(),
+            message.context_id.clone(),
+            get_message_text(message, ""),
+        ),
+        SendMessageResponse::Task(task) if is_failure(&task.status.state) => {
+            return error_response(StatusCode::BAD_GATEWAY, "agent_error", task_failure(task));
+        }
+        SendMessageResponse::Task(task) => (
+            task.id.clone(),
+            Some(task.context_id.clone()),
+            task_text(task),
+        ),
+    };
+    Json(json!({
+        "id": id,
+        "object": "chat.completion",
+        "created": created(),
+        "model": model,
+        "context_id": context_id,
+        "choices": [{
+            "index": 0,
+            "message": {"role": "assistant", "content": content},
+            "finish_reason": "stop",
+        }],
+    }))
+    .into_response()
+}
+
+/// One `chat.completion.chunk` event.
+fn chunk(id: &str, model: &str, delta: Value, finish_reason: Option<&str>) -> Event {
+    let chunk = json!({
+        "id": id,
+        "object": "chat.completion.chunk",
+        "created": created(),
+        "model": model,
+        "choices": [{"index": 0, "delta": delta, "finish_reason": finish_reason}],
+    });
+    Event::default().data(chunk.to_string())
+}
+
+fn text_of(parts: &[Part]) -> String {
+    get_text_parts(parts).concat()
+}
+
+/// Stream `rx` as chat completion chunks: a role delta, one content delta
+/// per text-bearing event, a closing `stop` chunk and `[DONE]`.
+fn stream_response(mut rx: broadcast::Receiver<StreamResponse>, model: String) -> Response {
+    let events = async_stream::stream! {
+        let mut id = String::new();
+        let mut streamed_text = false;
+        let mut started = false;
+        loop {
+            let event = match rx.recv().await {
+                Ok(event) => event,
+                Err(broadcast::error::RecvError::Lagged(n)) => {
+                    warn!(missed = n, "Chat stream lagged — some events were missed");
+                    continue;
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            };
+            if !started {
+                id = match &event {
+                    StreamResponse::Task(task) => task.id.clone(),
+                    StreamResponse::Message(message) => message.message_id.clone(),
+                    StreamResponse::StatusUpdate(update) => update.task_id.clone(),
+                    StreamResponse::ArtifactUpdate(update) => update.task_id.clone(),
+                };
+                started = true;
+                yield Ok::<_, Infallible>(chunk(&id, &model, json!({"role": "assistant"}), None));
+            }
+            let (text, last, failure) = match &event {
+                StreamResponse::ArtifactUpdate(update) => {
+                    (text_of(&update.artifact.parts), false, None)
+                }
+                StreamResponse::Message(message) => (get_message_text(message, ""), true, None),
+                StreamResponse::StatusUpdate(update) if update.r#final => {
+                    let text = update
+                        .status
+                        .message
+                        .as_ref()
+                        .map(|message| get_message_text(message, ""))
+                        .unwrap_or_default();
+                    if is_failure(&update.status.state) {
+                        let failure = if text.is_empty() {
+                            format!("Task {} ended {}", update.task_id, update.status.state)
+                        } else {
+                            text
+                        };
+                        (String::new(), true, Some(failure))
+                    } else if streamed_text {
+                        (String::new(), true, None)
+                    } else {
+                        (text, true, None)
+                    }
+                }
+                StreamResponse::Task(task) if is_done(&task.status.state) => {
+                    if is_failure(&task.status.state) {
+                        (String::new(), true, Some(task_failure(task)))
+                    } else if streamed_text {
+                        (String::new(), true, None)
+                    } else {
+                        (task_text(task), true, None)
+                    }
+                }
+                _ => (String::new(), false, None),
+            };
+            if !text.is_empty() {
+                streamed_text = true;
+                yield Ok(chunk(&id, &model, json!({"content": text}), None));
+            }
+            if let Some(failure) = failure {
+                yield Ok(Event::default().data(error_body("agent_error", failure).to_string()));
+                break;
+            }
+            if last {
+                yield Ok(chunk(&id, &model, json!({}), Some("stop")));
+                break;
+            }
+        }
+        yield Ok(Event::default().data("[DONE]"));
+    };
+    Sse::new(events)
+        .keep_alive(KeepAlive::default())
+        .into_response()
+}
@@ -0,0 +1,237 @@
+//! Validation of message and artifact content.
+//!
+//! A [`ContentValidator`] sees every message a client sends and every
+//! artifact an executor publishes, and may refuse it with a
+//! [`ContentViolation`]: a part that is too large, a media type that isn't
+//! allowed, or anything else a policy (e.g. moderation) refuses.
+//!
+//! - [`DefaultRequestHandler`](super::DefaultRequestHandler) (see
+//!   [`with_content_validator()`](super::DefaultRequestHandler::with_content_validator))
+//!   checks incoming messages before a task is created or resumed, and
+//!   answers refused ones with the [input error](ContentViolation::into_input_error).
+//! - [`TaskUpdater`](super::TaskUpdater) checks artifacts before publishing
+//!   them; refused ones are not published and the executor gets the
+//!   [output error](ContentViolation::into_output_error). Updaters from
+//!   [`RequestContext::task_updater()`](super::RequestContext::task_updater)
+//!   use the handler's validator; others need
+//!   [`with_content_validator()`](super::TaskUpdater::with_content_validator).
+//!   Streamed artifacts are checked chunk by chunk.
+//!
+//! Violations map to protocol errors, each carrying structured data:
+//!
+//! | Violation | Error | Code |
+//! |-----------|-------|------|
+//! | [`TooLarge`](ContentViolation::TooLarge) | [`A2AError::PayloadTooLarge`] | `-32600` |
+//! | [`MediaTypeNotAllowed`](ContentViolation::MediaTypeNotAllowed) | [`A2AError::ContentTypeNotSupported`] | `-32005` |
+//! | [`Refused`](ContentViolation::Refused), incoming | [`A2AError::InvalidParams`] | `-32602` |
+//! | [`Refused`](ContentViolation::Refused), outgoing | [`A2AError::InvalidAgentResponse`] | `-32006` |
+//!
+//! An executor returning the output error fails its task, and
+//! `message/send` answers with the error.
+//!
+//! ```rust,ignore
+//! let handler = DefaultRequestHandler::new(executor, store).with_content_validator(Arc::new(
+//!     ContentLimits::new()
+//!         .with_max_part_size(1024 * 1024)
+//!         .with_allowed_media_types(["text/*", "application/json", "image/png"]),
+//! ));
+//! ```
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde_json::json;
+
+use crate::error::A2AError;
+use crate::types::{Artifact, Message, Part};
+use crate::utils::parts::part_size;
+
+use super::output_modes::{mode_accepted, part_mode};
+
+/// Why content was refused.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ContentViolation {
+    /// A part is larger than allowed (see [`part_size`]).
+    TooLarge {
+        /// What is too large, e.g. `"Message part 2"`.
+        what: String,
+        /// Its size in bytes.
+        size: usize,
+        /// The limit in bytes.
+        limit: usize,
+    },
+
+    /// A part's media type (see [`part_mode`]) isn't allowed.
+    MediaTypeNotAllowed {
+        /// The part's media type.
+        mime_type: String,
+        /// The allowed media types.
+        allowed: Vec<String>,
+    },
+
+    /// Refused by policy, e.g. moderation.
+    Refused {
+        /// Why, for the client.
+        reason: String,
+    },
+}
+
+impl ContentViolation {
+    /// Refuse content for `reason`.
+    pub fn refused(reason: impl Into<String>) -> Self {
+        Self::Refused {
+            reason: reason.into(),
+        }
+    }
+
+    /// The error answering a client whose message was refused.
+    pub fn into_input_error(self) -> A2AError {
+        self.into_error(|reason| A2AError::InvalidParams {
+            message: format!("Message refused: {reason}"),
+            data: Some(json!({ "reason": reason })),
+        })
+    }
+
+    /// The error for an executor whose artifact was refused.
+    pub fn into_output_error(self) -> A2AError {
+        self.into_error(|reason| A2AError::InvalidAgentResponse {
+            message: format!("Artifact refused: {reason}"),
+            data: Some(json!({ "reason": reason })),
+        })
+    }
+
+    /// The error for this violation, with `refused` making the one for
+    /// [`Refused`](Self::Refused), whose code depends on the direction.
+    fn into_error(self, refused: impl FnOnce(String) -> A2AError) -> A2AError {
+        match self {
+            Self::TooLarge { what, size, limit } => {
+                A2AError::payload_too_large(&what, Some(size), limit)
+            }
+            Self::MediaTypeNotAllowed { mime_type, allowed } => A2AError::ContentTypeNotSupported {
+                message: format!(
+                    "'{mime_type}' content is not allowed (allowed: {})",
+                    allowed.join(", ")
+                ),
+                data: Some(json!({
+                    "mimeType": mime_type,
+                    "allowedMediaTypes": allowed,
+                })),
+            },
+            Self::Refused { reason } => refused(reason),
+        }
+    }
+}
+
+/// Checks incoming messages and outgoing artifacts.
+///
+/// Both methods accept everything by default, so validators implement
+/// only the direction they care about.
+#[async_trait]
+pub trait ContentValidator: Send + Sync {
+    /// Check a message sent by a client.
+    async fn validate_message(&self, message: &Message) -> Result<(), ContentViolation> {
+        let _ = message;
+        Ok(())
+    }
+
+    /// Check an artifact, or a chunk of one, before it is published.
+    async fn validate_artifact(&self, artifact: &Artifact) -> Result<(), ContentViolation> {
+        let _ = artifact;
+        Ok(())
+    }
+}
+
+/// Accepts all content; the default validator.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AllowAllContent;
+
+impl ContentValidator for AllowAllContent {}
+
+/// Runs each validator in turn; the first violation wins.
+#[async_trait]
+impl ContentValidator for Vec<Arc<dyn ContentValidator>> {
+    async fn validate_message(&self, message: &Message) -> Result<(), ContentViolation> {
+        for validator in self {
+            validator.validate_message(message).await?;
+        }
+        Ok(())
+    }
+
+    async fn validate_artifact(&self, artifact: &Artifact) -> Result<(), ContentViolation> {
+        for validator in self {
+            validator.validate_artifact(artifact).await?;
+        }
+        Ok(())
+    }
+}
+
+/// Size and media type limits on the parts of messages and artifacts.
+///
+/// Media types may use wildcards (`*/*`, `image/*`); without any, every
+/// type is allowed.
+#[derive(Debug, Clone, Default)]
+pub struct ContentLimits {
+    max_part_size: Option<usize>,
+    allowed_media_types: Vec<String>,
+}
+
+impl ContentLimits {
+    /// Limits allowing everything, to narrow with the `with_*` methods.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Refuse parts larger than `bytes`.
+    pub fn with_max_part_size(mut self, bytes: usize) -> Self {
+        self.max_part_size = Some(bytes);
+        self
+    }
+
+    /// Refuse parts whose media type isn't one of `media_types`.
+    pub fn with_allowed_media_types<I, S>(mut self, media_types: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.allowed_media_types = media_types.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Check `parts`, calling them `"{what} part {index}"` in violations.
+    fn check(&self, what: &str, parts: &[Part]) -> Result<(), ContentViolation> {
+        for (index, part) in parts.iter().enumerate() {
+            let mime_type = part_mode(part);
+            if !mode_accepted(mime_type, &self.allowed_media_types) {
+                return Err(ContentViolation::MediaTypeNotAllowed {
+                    mime_type: mime_type.to_string(),
+                    allowed: self.allowed_media_types.clone(),
+                });
+            }
+            if let Some(limit) = self.max_part_size {
+                let size = part_size(part);
+                if size > limit {
+                    return Err(ContentViolation::TooLarge {
+                        what: format!("{what} part {index}"),
+                        size,
+                        limit,
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ContentValidator for ContentLimits {
+    async fn validate_message(&self, message: &Message) -> Result<(), ContentViolation> {
+        self.check("Message", &message.parts)
+    }
+
+    async fn validate_artifact(&self, artifact: &Artifact) -> Result<(), ContentViolation> {
+        self.check(
+            &format!("Artifact {}", artifact.artifact_id),
+            &artifact.parts,
+        )
+    }
+}
@@ -0,0 +1,210 @@
+//! Context store — the tasks of each conversation.
+//!
+//! A2A groups the tasks of one conversation under a `contextId`. With a
+//! [`ContextStore`] (see [`DefaultRequestHandler::with_context_store`]),
+//! the handler records every task it creates in the [`ConversationContext`]
+//! of its context, so executors and operators can look up a conversation's
+//! earlier tasks without scanning the task store.
+//!
+//! ```rust,ignore
+//! let contexts = Arc::new(InMemoryContextStore::new());
+//! let handler = DefaultRequestHandler::new(executor, store)
+//!     .with_context_store(contexts.clone());
+//! // Later, e.g. in an executor holding `contexts`:
+//! let earlier = contexts.get(&context.context_id).await?.map(|c| c.task_ids);
+//! ```
+//!
+//! [`DefaultRequestHandler::with_context_store`]: super::DefaultRequestHandler::with_context_store
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+
+use crate::error::A2AResult;
+
+use super::kv_store::{self, InMemoryKvStore, KvStore};
+
+/// One conversation: a context and its tasks.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConversationContext {
+    /// The context ID.
+    pub context_id: String,
+
+    /// Tenant of the task that started the conversation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tenant: Option<String>,
+
+    /// IDs of the context's tasks, oldest first.
+    pub task_ids: Vec<String>,
+
+    /// When the first task was recorded (RFC 3339).
+    pub created_at: String,
+
+    /// When the latest task was recorded (RFC 3339).
+    pub updated_at: String,
+}
+
+/// Persistence for [`ConversationContext`]s.
+#[async_trait]
+pub trait ContextStore: Send + Sync {
+    /// Append `task_id` to the tasks of `context_id`, creating the context
+    /// (owned by `tenant`) if it is new. Recording a task twice keeps one
+    /// entry. Returns the updated context.
+    async fn add_task(
+        &self,
+        context_id: &str,
+        task_id: &str,
+        tenant: Option<&str>,
+    ) -> A2AResult<ConversationContext>;
+
+    /// The context `context_id`, if any task was recorded in it.
+    async fn get(&self, context_id: &str) -> A2AResult<Option<ConversationContext>>;
+
+    /// Remove `task_id` from the tasks of `context_id`, forgetting the
+    /// context once it has none left. Silently succeeds if the task is not
+    /// recorded.
+    async fn remove_task(&self, context_id: &str, task_id: &str) -> A2AResult<()>;
+
+    /// Forget the context `context_id`. Silently succeeds if there is none.
+    async fn delete(&self, context_id: &str) -> A2AResult<()>;
+}
+
+/// Key prefix of contexts in a [`KvStore`].
+const CONTEXT_KEY_PREFIX: &str = "context/";
+
+/// [`ContextStore`] on top of a [`KvStore`]: each context is a JSON value
+/// under `context/{context_id}`.
+///
+/// Updates are serialized within the process; stores shared by several
+/// processes may lose a task recorded concurrently in the same context.
+#[derive(Clone)]
+pub struct KvContextStore {
+    store: Arc<dyn KvStore>,
+    update: Arc<Mutex<()>>,
+}
+
+impl std::fmt::Debug for KvContextStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("KvContextStore").finish_non_exhaustive()
+    }
+}
+
+impl KvContextStore {
+    /// Keep contexts in `store`.
+    pub fn new(store: Arc<dyn KvStore>) -> Self {
+        Self {
+            store,
+            update: Arc::default(),
+        }
+    }
+
+    fn key(context_id: &str) -> String {
+        format!("{CONTEXT_KEY_PREFIX}{context_id}")
+    }
+}
+
+#[async_trait]
+impl ContextStore for KvContextStore {
+    async fn add_task(
+        &self,
+        context_id: &str,
+        task_id: &str,
+        tenant: Option<&str>,
+    ) -> A2AResult<ConversationContext> {
+        let _update = self.update.lock().await;
+        let key = Self::key(context_id);
+        let now = chrono::Utc::now().to_rfc3339();
+        let mut context = kv_store::get_json::<ConversationContext>(&*self.store, &key)
+            .await?
+            .unwrap_or_else(|| ConversationContext {
+                context_id: context_id.to_string(),
+                tenant: tenant.map(str::to_string),
+                task_ids: Vec::new(),
+                created_at: now.clone(),
+                updated_at: now.clone(),
+            });
+        if !context.task_ids.iter().any(|id| id == task_id) {
+            context.task_ids.push(task_id.to_string());
+            context.updated_at = now;
+            kv_store::put_json(&*self.store, &key, &context).await?;
+        }
+        Ok(context)
+    }
+
+    async fn remove_task(&self, context_id: &str, task_id: &str) -> A2AResult<()> {
+        let _update = self.update.lock().await;
+        let key = Self::key(context_id);
+        let Some(mut context) =
+            kv_store::get_json::<ConversationContext>(&*self.store, &key).await?
+        else {
+            return Ok(());
+        };
+        let before = context.task_ids.len();
+        context.task_ids.retain(|id| id != task_id);
+        if context.task_ids.is_empty() {
+            self.store.delete(&key).await
+        } else if context.task_ids.len() < before {
+            context.updated_at = chrono::Utc::now().to_rfc3339();
+            kv_store::put_json(&*self.store, &key, &context).await
+        } else {
+            Ok(())
+        }
+    }
+
+    async fn get(&self, context_id: &str) -> A2AResult<Option<ConversationContext>> {
+        kv_store::get_json(&*self.store, &Self::key(context_id)).await
+    }
+
+    async fn delete(&self, context_id: &str) -> A2AResult<()> {
+        self.store.delete(&Self::key(context_id)).await
+    }
+}
+
+/// In-memory [`ContextStore`]: a [`KvContextStore`] over an
+/// [`InMemoryKvStore`].
+#[derive(Debug)]
+pub struct InMemoryContextStore {
+    inner: KvContextStore,
+}
+
+impl Default for InMemoryContextStore {
+    fn default() -> Self {
+        Self {
+            inner: KvContextStore::new(Arc::new(InMemoryKvStore::new())),
+        }
+    }
+}
+
+impl InMemoryContextStore {
+    /// Create an empty store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl ContextStore for InMemoryContextStore {
+    async fn add_task(
+        &self,
+        context_id: &str,
+        task_id: &str,
+        tenant: Option<&str>,
+    ) -> A2AResult<ConversationContext> {
+        self.inner.add_task(context_id, task_id, tenant).await
+    }
+
+    async fn remove_task(&self, context_id: &str, task_id: &str) -> A2AResult<()> {
+        self.inner.remove_task(context_id, task_id).await
+    }
+
+    async fn get(&self, context_id: &str) -> A2AResult<Option<ConversationContext>> {
+        self.inner.get(context_id).await
+    }
+
+    async fn delete(&self, context_id: &str) -> A2AResult<()> {
+        self.inner.delete(context_id).await
+    }
+}
@@ -0,0 +1,161 @@
+//! Delegating sub-tasks to downstream A2A agents.
+//!
+//! An orchestrator's executor gets a [`Delegator`] for each downstream
+//! agent from [`RequestContext::delegator`] and hands it messages. Each
+//! [`delegate`](Delegator::delegate) call:
+//!
+//! - sends the message over `message/stream` in the orchestrator's
+//!   `context_id` (unless the message names its own), so the downstream
+//!   task belongs to the same conversation;
+//! - republishes the downstream `artifact-update` events as artifacts of
+//!   the current task through the orchestrator's [`TaskUpdater`] (use a
+//!   [`scoped`](TaskUpdater::scoped) updater to keep artifact IDs of
+//!   different agents apart);
+//! - cancels the downstream task if the call is dropped before the task
+//!   finished — which is what happens to a running executor when the
+//!   upstream task is canceled.
+//!
+//! ```rust,ignore
+//! async fn execute(&self, context: RequestContext, queue: EventQueue) -> A2AResult<()> {
+//!     let updater = TaskUpdater::new(queue, context.task_id.clone(), context.context_id.clone());
+//!     let research = context.delegator(self.research_client.clone(), &updater.scoped("research"));
+//!     let outcome = research.delegate(create_text_message(Role::User, "find sources")).await?;
+//!     updater.complete(None).await
+//! }
+//! ```
+//!
+//! [`RequestContext::delegator`]: super::RequestContext::delegator
+
+use std::sync::Arc;
+
+use tracing::debug;
+
+use crate::client::A2AClient;
+use crate::error::{A2AError, A2AResult};
+use crate::types::{Message, SendMessageParams, SendMessageResponse, StreamResponse};
+
+use super::task_updater::TaskUpdater;
+
+/// Sends sub-tasks of the current task to one downstream agent.
+///
+/// Cheap to clone. See the [module docs](self).
+#[derive(Clone)]
+pub struct Delegator {
+    client: Arc<A2AClient>,
+    updater: TaskUpdater,
+}
+
+impl std::fmt::Debug for Delegator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Delegator")
+            .field("task_id", &self.updater.task_id())
+            .field("context_id", &self.updater.context_id())
+            .finish()
+    }
+}
+
+impl Delegator {
+    /// Delegate to the agent behind `client`, publishing its artifacts
+    /// through `updater`.
+    pub fn new(client: Arc<A2AClient>, updater: TaskUpdater) -> Self {
+        Self { client, updater }
+    }
+
+    /// The client of the downstream agent.
+    pub fn client(&self) -> &A2AClient {
+        &self.client
+    }
+
+    /// Send `message` to the downstream agent and follow its task until
+    /// it stops (terminal, `input-required` or `auth-required`).
+    ///
+    /// Returns the downstream task as of then, or the message if the agent
+    /// answered without a task. Its artifacts have been republished on the
+    /// current task along the way.
+    ///
+    /// # Errors
+    ///
+    /// Errors opening or reading the stream and publishing artifacts are
+    /// returned as is; the downstream task is canceled in that case.
+    pub async fn delegate(&self, mut message: Message) -> A2AResult<SendMessageResponse> {
+        if message.context_id.is_none() {
+            message.context_id = Some(self.updater.context_id().to_string());
+        }
+        let params = SendMessageParams {
+            message,
+            configuration: None,
+            metadata: None,
+            tenant: None,
+        };
+        let mut stream = self.client.send_message_stream(params).await?;
+        let mut downstream = Downstream {
+            client: Arc::clone(&self.client),
+            task_id: None,
+        };
+        while let Some(event) = stream.next().await {
+            let event = event?;
+            if downstream.task_id.is_none() {
+                downstream.task_id = event.task_id().map(String::from);
+            }
+            match event {
+                StreamResponse::Message(message) => {
+                    downstream.finish();
+                    return Ok(SendMessageResponse::Message(message));
+                }
+                StreamResponse::ArtifactUpdate(update) => {
+                    let artifact = update.artifact;
+                    self.updater
+                        .add_artifact(
+                            artifact.parts,
+                            Some(artifact.artifact_id),
+                            artifact.name,
+                            artifact.metadata,
+                            update.append,
+                            update.last_chunk,
+                            artifact.extensions,
+                        )
+                        .await?;
+                }
+                StreamResponse::StatusUpdate(update) if update.r#final => break,
+                _ => {}
+            }
+        }
+        let task_id = downstream.finish().ok_or_else(|| {
+            A2AError::internal_error("Downstream stream ended without naming a task")
+        })?;
+        let task = self.client.get_task_by_id(&task_id, None).await?;
+        Ok(SendMessageResponse::Task(task))
+    }
+}
+
+/// The downstream task of a [`Delegator::delegate`] call in flight,
+/// canceled if the call is dropped before [`finish`](Self::finish).
+struct Downstream {
+    client: Arc<A2AClient>,
+    task_id: Option<String>,
+}
+
+impl Downstream {
+    /// The call completed: keep the task and return its ID.
+    fn finish(&mut self) -> Option<String> {
+        self.task_id.take()
+    }
+}
+
+impl Drop for Downstream {
+    fn drop(&mut self) {
+        let Some(task_id) = self.task_id.take() else {
+            return;
+        };
+        let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+            return;
+        };
+        let client = Arc::clone(&self.client);
+        runtime.spawn(async move {
+            debug!(task_id = %task_id, "Canceling abandoned downstream task");
+            if let Err(e) = client.cancel_task_by_id(&task_id).await {
+                debug!(task_id = %task_id, error = %e, "Downstream cancel failed");
+            }
+        });
+    }
+}
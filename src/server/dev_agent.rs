@@ -0,0 +1,288 @@
+//! Configurable reference agent for client development (`dev-agent` feature).
+//!
+//! [`DevAgent`] is the executor behind the `a2a-dev-agent` binary: it echoes
+//! or mirrors the user's message and can be told to be slow, flaky, chunky
+//! or to ask for more input, so client code can be exercised against
+//! realistic agent behaviour without writing a server first.
+//!
+//! Behaviour comes from a [`DevAgentConfig`]; a message may override any of
+//! its fields for itself via the `devAgent` metadata key, e.g.
+//! `{"devAgent": {"delayMs": 500, "chunks": 4}}`.
+//!
+//! ```sh
+//! cargo run --features dev-agent --bin a2a-dev-agent -- --delay-ms 200 --chunks 3
+//! ```
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use super::{AgentExecutor, EventQueue, RequestContext, TaskUpdater};
+use crate::builders::AgentCardBuilder;
+use crate::error::{A2AError, A2AResult};
+use crate::types::{AgentCard, Message, Part, TaskState};
+
+/// Message metadata key holding per-message [`DevAgentConfig`] overrides.
+pub const DEV_AGENT_METADATA_KEY: &str = "devAgent";
+
+/// ID of the artifact the dev agent produces.
+pub const DEV_AGENT_ARTIFACT_ID: &str = "dev-agent-output";
+
+/// What the dev agent replies with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DevAgentMode {
+    /// Reply with `Echo: <text of the message>`.
+    #[default]
+    Echo,
+    /// Reply with the message's parts unchanged (files and data included).
+    Mirror,
+}
+
+impl std::str::FromStr for DevAgentMode {
+    type Err = A2AError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "echo" => Ok(DevAgentMode::Echo),
+            "mirror" => Ok(DevAgentMode::Mirror),
+            other => Err(A2AError::InvalidParams {
+                message: format!("Unknown dev agent mode '{other}' (expected echo or mirror)"),
+                data: None,
+            }),
+        }
+    }
+}
+
+/// Behaviour of a [`DevAgent`].
+///
+/// Serialized in camelCase, which is also the format of the `devAgent`
+/// metadata overrides.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct DevAgentConfig {
+    /// Reply content.
+    pub mode: DevAgentMode,
+    /// Pause before each artifact chunk, in milliseconds.
+    pub delay_ms: u64,
+    /// Probability in `[0, 1]` that a task fails after its first pause.
+    pub fail_rate: f64,
+    /// Number of artifact chunks the echo text is split into (`append` /
+    /// `lastChunk` streaming). Mirror replies are always one chunk.
+    pub chunks: usize,
+    /// Ask for confirmation (`input-required`) before replying; the reply,
+    /// built from the client's answer, is sent once it arrives on the same
+    /// task.
+    pub input_required: bool,
+}
+
+impl Default for DevAgentConfig {
+    fn default() -> Self {
+        Self {
+            mode: DevAgentMode::Echo,
+            delay_ms: 0,
+            fail_rate: 0.0,
+            chunks: 1,
+            input_required: false,
+        }
+    }
+}
+
+impl DevAgentConfig {
+    /// Set the reply mode.
+    pub fn with_mode(mut self, mode: DevAgentMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Set the pause before each artifact chunk.
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay_ms = delay.as_millis() as u64;
+        self
+    }
+
+    /// Set the failure probability (clamped to `[0, 1]`).
+    pub fn with_fail_rate(mut self, fail_rate: f64) -> Self {
+        self.fail_rate = fail_rate.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Split the echo reply into `chunks` artifact chunks (at least 1).
+    pub fn with_chunks(mut self, chunks: usize) -> Self {
+        self.chunks = chunks.max(1);
+        self
+    }
+
+    /// Require an `input-required` round trip before replying.
+    pub fn with_input_required(mut self, enabled: bool) -> Self {
+        self.input_required = enabled;
+        self
+    }
+
+    /// This config with the `devAgent` overrides of `message` applied.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`A2AError::InvalidParams`] if the overrides are malformed.
+    pub fn for_message(&self, message: Option<&Message>) -> A2AResult<Self> {
+        let Some(overrides) = message
+            .and_then(|m| m.metadata.as_ref())
+            .and_then(|m| m.get(DEV_AGENT_METADATA_KEY))
+        else {
+            return Ok(self.clone());
+        };
+        let invalid = |message: String| A2AError::InvalidParams {
+            message,
+            data: None,
+        };
+        let serde_json::Value::Object(overrides) = overrides else {
+            return Err(invalid(format!(
+                "'{DEV_AGENT_METADATA_KEY}' metadata must be an object"
+            )));
+        };
+        let mut merged = serde_json::to_value(self)?;
+        if let Some(fields) = merged.as_object_mut() {
+            fields.extend(overrides.clone());
+        }
+        let merged: Self = serde_json::from_value(merged)
+            .map_err(|e| invalid(format!("Invalid '{DEV_AGENT_METADATA_KEY}' metadata: {e}")))?;
+        let (fail_rate, chunks) = (merged.fail_rate, merged.chunks);
+        Ok(merged.with_fail_rate(fail_rate).with_chunks(chunks))
+    }
+}
+
+/// Reference [`AgentExecutor`] with configurable echo, delay, failure,
+/// chunking and input-required behaviour. See the [module docs](self).
+#[derive(Debug, Clone, Default)]
+pub struct DevAgent {
+    config: DevAgentConfig,
+}
+
+impl DevAgent {
+    /// Create a dev agent with the given default behaviour.
+    pub fn new(config: DevAgentConfig) -> Self {
+        Self { config }
+    }
+
+    /// The default behaviour.
+    pub fn config(&self) -> &DevAgentConfig {
+        &self.config
+    }
+
+    /// Agent card advertising the dev agent at JSON-RPC endpoint `url`.
+    pub fn agent_card(url: &str) -> AgentCard {
+        AgentCardBuilder::new(
+            "A2A Dev Agent",
+            "Reference agent that echoes or mirrors messages, with configurable \
+             delay, failure rate, chunked artifacts and input-required flows",
+            env!("CARGO_PKG_VERSION"),
+        )
+        .with_jsonrpc_interface(url)
+        .with_streaming(true)
+        .with_input_modes(vec!["*/*".to_string()])
+        .with_output_modes(vec!["*/*".to_string()])
+        .with_skill(
+            "echo",
+            "Echo",
+            "Echoes text, or mirrors all parts in mirror mode",
+            vec!["echo".to_string(), "test".to_string()],
+        )
+        .build()
+    }
+}
+
+#[async_trait]
+impl AgentExecutor for DevAgent {
+    async fn execute(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        let config = self.config.for_message(context.message.as_ref())?;
+        let updater = TaskUpdater::new(
+            event_queue,
+            context.task_id.clone(),
+            context.context_id.clone(),
+        );
+
+        let answered = context
+            .task
+            .as_ref()
+            .is_some_and(|task| task.status.state == TaskState::InputRequired);
+        if config.input_required && !answered {
+            let prompt = updater.new_agent_message(
+                vec![Part::text("Reply to this task to receive the response.")],
+                None,
+            );
+            return updater.requires_input(Some(prompt), true).await;
+        }
+
+        updater.start_work(None).await?;
+        let delay = Duration::from_millis(config.delay_ms);
+        tokio::time::sleep(delay).await;
+        if config.fail_rate > 0.0 && random_unit() < config.fail_rate {
+            return updater
+                .failed_with_text(&format!(
+                    "Simulated failure (fail rate {})",
+                    config.fail_rate
+                ))
+                .await;
+        }
+
+        let chunks = match config.mode {
+            DevAgentMode::Echo => split_chunks(
+                &format!("Echo: {}", context.get_user_input("\n")),
+                config.chunks,
+            )
+            .into_iter()
+            .map(|chunk| vec![Part::text(chunk)])
+            .collect(),
+            DevAgentMode::Mirror => {
+                vec![context.message.map(|m| m.parts).unwrap_or_default()]
+            }
+        };
+        let last = chunks.len() - 1;
+        for (index, parts) in chunks.into_iter().enumerate() {
+            if index > 0 {
+                tokio::time::sleep(delay).await;
+            }
+            updater
+                .add_artifact(
+                    parts,
+                    Some(DEV_AGENT_ARTIFACT_ID.to_string()),
+                    Some("response".to_string()),
+                    None,
+                    Some(index > 0),
+                    Some(index == last),
+                    None,
+                )
+                .await?;
+        }
+        updater.complete(None).await
+    }
+
+    async fn cancel(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        TaskUpdater::new(event_queue, context.task_id, context.context_id)
+            .cancel(None)
+            .await
+    }
+}
+
+/// Split `text` into `chunks` pieces whose character counts differ by at
+/// most one (fewer pieces if `text` is shorter than `chunks`).
+fn split_chunks(text: &str, chunks: usize) -> Vec<String> {
+    let chars: Vec<char> = text.chars().collect();
+    let chunks = chunks.clamp(1, chars.len().max(1));
+    let (base, extra) = (chars.len() / chunks, chars.len() % chunks);
+    let mut rest = chars.as_slice();
+    (0..chunks)
+        .map(|index| {
+            let (piece, tail) = rest.split_at(base + usize::from(index < extra));
+            rest = tail;
+            piece.iter().collect()
+        })
+        .collect()
+}
+
+/// Uniform random number in `[0, 1)`.
+fn random_unit() -> f64 {
+    // The low 62 bits of a v4 UUID are random.
+    (uuid::Uuid::new_v4().as_u128() & ((1 << 53) - 1)) as f64 / (1u64 << 53) as f64
+}
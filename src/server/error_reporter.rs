@@ -0,0 +1,329 @@
+//! Executor failure telemetry — tells operators when an agent fails.
+//!
+//! Whenever [`AgentExecutor::execute`](super::AgentExecutor::execute)
+//! returns an error or panics, [`DefaultRequestHandler`] marks the task
+//! `failed` and hands an [`ExecutorErrorReport`] to its
+//! [`ExecutorErrorReporter`]: task and context IDs, the requested skill, a
+//! redacted excerpt of the message and a backtrace. The default
+//! [`TracingErrorReporter`] logs it with `tracing`; `SentryErrorReporter`
+//! (`sentry` feature) sends it to a Sentry-compatible endpoint.
+//!
+//! ```rust,ignore
+//! let handler = DefaultRequestHandler::new(executor, store)
+//!     .with_error_reporter(Arc::new(SentryErrorReporter::new(dsn)?));
+//! ```
+//!
+//! [`DefaultRequestHandler`]: super::DefaultRequestHandler
+
+use std::any::Any;
+use std::backtrace::{Backtrace, BacktraceStatus};
+use std::sync::Arc;
+
+use serde::Serialize;
+use tracing::error;
+
+use crate::types::Message;
+use crate::utils::get_message_text;
+
+/// Longest message excerpt in a report, in characters.
+pub const MESSAGE_EXCERPT_LIMIT: usize = 256;
+
+/// Metadata key naming the skill a message is for.
+pub const SKILL_ID_METADATA_KEY: &str = "skillId";
+
+/// Turns message text into what may appear in a report.
+pub type ExcerptRedactor = Arc<dyn Fn(&str) -> String + Send + Sync>;
+
+/// How the executor failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ExecutorFailureKind {
+    /// `execute` returned an error.
+    Error,
+    /// `execute` panicked.
+    Panic,
+    /// `execute` ran longer than the task timeout and was canceled.
+    Timeout,
+    /// `execute` was still running when the request's deadline passed, and
+    /// was canceled.
+    DeadlineExceeded,
+}
+
+/// Everything known about a failed execution.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExecutorErrorReport {
+    /// Task being executed.
+    pub task_id: String,
+
+    /// Context of the task.
+    pub context_id: String,
+
+    /// Skill the message asked for (`skillId` in message or request
+    /// metadata), if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skill: Option<String>,
+
+    /// Redacted text of the message, at most [`MESSAGE_EXCERPT_LIMIT`]
+    /// characters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_excerpt: Option<String>,
+
+    /// Error or panic.
+    pub kind: ExecutorFailureKind,
+
+    /// The error's message, or the panic payload.
+    pub error: String,
+
+    /// Backtrace captured when the handler observed the failure, if
+    /// backtraces are enabled (`RUST_BACKTRACE` / `RUST_LIB_BACKTRACE`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub backtrace: Option<String>,
+}
+
+impl ExecutorErrorReport {
+    /// Build a report for `message` on task `task_id`, redacting the
+    /// excerpt with `redactor` and capturing a backtrace.
+    pub(crate) fn new(
+        task_id: &str,
+        context_id: &str,
+        message: &Message,
+        request_metadata: Option<&serde_json::Value>,
+        kind: ExecutorFailureKind,
+        error: String,
+        redactor: &ExcerptRedactor,
+    ) -> Self {
+        let skill = [message.metadata.as_ref(), request_metadata]
+            .into_iter()
+            .flatten()
+            .find_map(|metadata| metadata.get(SKILL_ID_METADATA_KEY)?.as_str())
+            .map(str::to_string);
+        let text = get_message_text(message, " ");
+        let message_excerpt = (!text.is_empty()).then(|| truncate(&redactor(&text)));
+        let backtrace = Backtrace::capture();
+        Self {
+            task_id: task_id.to_string(),
+            context_id: context_id.to_string(),
+            skill,
+            message_excerpt,
+            kind,
+            error,
+            backtrace: (backtrace.status() == BacktraceStatus::Captured)
+                .then(|| backtrace.to_string()),
+        }
+    }
+}
+
+/// Receives a report for every failed or panicked execution.
+///
+/// Called on the task that ran the executor, after the task was marked
+/// `failed`; implementations doing I/O should hand the report off (e.g.
+/// `tokio::spawn`) rather than block.
+pub trait ExecutorErrorReporter: Send + Sync {
+    /// Record `report`.
+    fn report(&self, report: &ExecutorErrorReport);
+}
+
+impl<T: ExecutorErrorReporter + ?Sized> ExecutorErrorReporter for Arc<T> {
+    fn report(&self, report: &ExecutorErrorReport) {
+        (**self).report(report)
+    }
+}
+
+/// Default reporter: one `tracing` error event per failure.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TracingErrorReporter;
+
+impl ExecutorErrorReporter for TracingErrorReporter {
+    fn report(&self, report: &ExecutorErrorReport) {
+        error!(
+            task_id = %report.task_id,
+            context_id = %report.context_id,
+            skill = report.skill.as_deref().unwrap_or(""),
+            message_excerpt = report.message_excerpt.as_deref().unwrap_or(""),
+            kind = ?report.kind,
+            backtrace = report.backtrace.as_deref().unwrap_or(""),
+            error = %report.error,
+            "Agent execution failed"
+        );
+    }
+}
+
+/// Default [`ExcerptRedactor`]: masks e-mail addresses, numbers of 6 or
+/// more digits (card, phone and account numbers) and long opaque tokens
+/// (API keys, bearer tokens), word by word.
+///
+/// # Example
+///
+/// ```
+/// use a2a_rs::server::redact_excerpt;
+///
+/// assert_eq!(
+///     redact_excerpt("mail bob@example.com card 4111-1111-1111-1111"),
+///     "mail [email] card [number]"
+/// );
+/// ```
+pub fn redact_excerpt(text: &str) -> String {
+    text.split_whitespace()
+        .map(|word| {
+            let digits = word.chars().filter(char::is_ascii_digit).count();
+            let opaque = word.len() >= 32
+                && word
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || "-_.=+/".contains(c));
+            if word.contains('@') && word.contains('.') {
+                "[email]"
+            } else if opaque {
+                "[secret]"
+            } else if digits >= 6 {
+                "[number]"
+            } else {
+                word
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Cut `text` to [`MESSAGE_EXCERPT_LIMIT`] characters.
+fn truncate(text: &str) -> String {
+    match text.char_indices().nth(MESSAGE_EXCERPT_LIMIT) {
+        Some((end, _)) => format!("{}…", &text[..end]),
+        None => text.to_string(),
+    }
+}
+
+/// The message of a panic payload.
+pub(crate) fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "non-string panic payload".to_string()
+    }
+}
+
+#[cfg(feature = "sentry")]
+pub use sentry::SentryErrorReporter;
+
+#[cfg(feature = "sentry")]
+mod sentry {
+    use serde_json::json;
+    use tracing::warn;
+
+    use super::{ExecutorErrorReport, ExecutorErrorReporter, ExecutorFailureKind};
+    use crate::error::{A2AError, A2AResult};
+
+    /// Sends reports as events to a Sentry-compatible store endpoint
+    /// (Sentry, GlitchTip, …), configured by DSN.
+    ///
+    /// Events are posted in the background on the current Tokio runtime;
+    /// delivery failures are logged and dropped.
+    #[derive(Clone)]
+    pub struct SentryErrorReporter {
+        store_url: String,
+        auth_header: String,
+        environment: Option<String>,
+        http: reqwest::Client,
+    }
+
+    impl std::fmt::Debug for SentryErrorReporter {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("SentryErrorReporter")
+                .field("store_url", &self.store_url)
+                .field("environment", &self.environment)
+                .finish_non_exhaustive()
+        }
+    }
+
+    impl SentryErrorReporter {
+        /// Report to the project of `dsn`
+        /// (`https://<public_key>@<host>[/<path>]/<project_id>`).
+        pub fn new(dsn: &str) -> A2AResult<Self> {
+            let invalid = || A2AError::invalid_params(format!("Invalid Sentry DSN: {dsn}"));
+            let url = reqwest::Url::parse(dsn).map_err(|_| invalid())?;
+            let key = url.username();
+            let host = url.host_str().ok_or_else(invalid)?;
+            let path = url.path().trim_end_matches('/');
+            let (prefix, project) = path.rsplit_once('/').ok_or_else(invalid)?;
+            if key.is_empty() || project.is_empty() {
+                return Err(invalid());
+            }
+            let port = url.port().map(|p| format!(":{p}")).unwrap_or_default();
+            Ok(Self {
+                store_url: format!(
+                    "{}://{host}{port}{prefix}/api/{project}/store/",
+                    url.scheme()
+                ),
+                auth_header: format!(
+                    "Sentry sentry_version=7, sentry_key={key}, sentry_client=a2a-rs/{}",
+                    env!("CARGO_PKG_VERSION")
+                ),
+                environment: None,
+                http: reqwest::Client::new(),
+            })
+        }
+
+        /// Tag events with `environment` (e.g. `"production"`).
+        pub fn with_environment(mut self, environment: impl Into<String>) -> Self {
+            self.environment = Some(environment.into());
+            self
+        }
+
+        /// The Sentry event for `report`.
+        fn event(&self, report: &ExecutorErrorReport) -> serde_json::Value {
+            let (level, exception_type) = match report.kind {
+                ExecutorFailureKind::Error => ("error", "ExecutorError"),
+                ExecutorFailureKind::Panic => ("fatal", "ExecutorPanic"),
+                ExecutorFailureKind::Timeout => ("error", "ExecutorTimeout"),
+                ExecutorFailureKind::DeadlineExceeded => ("error", "ExecutorDeadlineExceeded"),
+            };
+            let mut tags = json!({
+                "task_id": report.task_id,
+                "context_id": report.context_id,
+            });
+            if let Some(skill) = &report.skill {
+                tags["skill"] = json!(skill);
+            }
+            json!({
+                "event_id": uuid::Uuid::new_v4().simple().to_string(),
+                "timestamp": chrono::Utc::now().to_rfc3339(),
+                "level": level,
+                "logger": "a2a_rs::server",
+                "platform": "rust",
+                "environment": self.environment,
+                "exception": {"values": [{"type": exception_type, "value": report.error}]},
+                "tags": tags,
+                "extra": {
+                    "message_excerpt": report.message_excerpt,
+                    "backtrace": report.backtrace,
+                },
+            })
+        }
+    }
+
+    impl ExecutorErrorReporter for SentryErrorReporter {
+        fn report(&self, report: &ExecutorErrorReport) {
+            let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+                warn!(task_id = %report.task_id, "No runtime to send Sentry event on");
+                return;
+            };
+            let request = self
+                .http
+                .post(&self.store_url)
+                .header("X-Sentry-Auth", &self.auth_header)
+                .json(&self.event(report));
+            let task_id = report.task_id.clone();
+            runtime.spawn(async move {
+                match request.send().await {
+                    Ok(response) if response.status().is_success() => {}
+                    Ok(response) => {
+                        warn!(task_id = %task_id, status = %response.status(), "Sentry rejected event")
+                    }
+                    Err(e) => warn!(task_id = %task_id, error = %e, "Failed to send Sentry event"),
+                }
+            });
+        }
+    }
+}
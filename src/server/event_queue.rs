@@ -11,21 +11,64 @@
 //! managing per-task event queues (mirrors Python SDK's `QueueManager` and
 //! `InMemoryQueueManager`), and [`EventConsumer`] for consuming events from
 //! a queue (mirrors Python SDK's `EventConsumer`).
-
-use std::collections::HashMap;
-use std::sync::atomic::{AtomicBool, Ordering};
+//!
+//! Every published event is numbered per queue and recorded in an
+//! `a2a.event.enqueue` debug span carrying that `sequence`, the event
+//! `kind` and its `task_id`.
+//!
+//! # Overflow
+//!
+//! A queue buffers up to its capacity of events per subscriber; a
+//! subscriber further behind loses the oldest ones and its raw receiver
+//! reports `RecvError::Lagged`. An [`EventReceiver`] (from
+//! [`EventQueue::receiver`]) handles that according to the queue's
+//! [`OverflowPolicy`] instead: it announces the gap with a synthesized
+//! status update (see [`GAP_METADATA_KEY`]), reads the missed events back
+//! from an [`EventStore`], or disconnects.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
 
 use async_trait::async_trait;
 use tokio::sync::{broadcast, Mutex};
-use tracing::{debug, warn};
+use tracing::{debug, debug_span, warn};
 
 use crate::error::{A2AError, A2AResult};
-use crate::types::{StreamResponse, TaskState};
+use crate::types::{Message, StreamResponse, TaskState, TaskStatus, TaskStatusUpdateEvent};
+
+use super::event_store::EventStore;
 
 /// Default channel capacity for the event queue.
-const DEFAULT_CAPACITY: usize = 1024;
+pub(crate) const DEFAULT_CAPACITY: usize = 1024;
+
+/// Key of the status update metadata entry marking a synthesized gap event:
+/// `{"missed": n}`, the number of events the subscriber didn't receive.
+pub const GAP_METADATA_KEY: &str = "eventGap";
+
+/// How often a relay waiting for its subscriber to catch up checks again.
+const BACKPRESSURE_POLL: Duration = Duration::from_millis(5);
+
+/// What a subscriber that falls more than the queue capacity behind gets.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// The oldest events it hasn't read are dropped, and it receives a
+    /// gap event in their place: a non-final status update repeating the
+    /// last status it saw, with [`GAP_METADATA_KEY`] metadata. Clients
+    /// can refetch the task to catch up.
+    #[default]
+    DropOldest,
+
+    /// It reads the events it missed back from an [`EventStore`] the
+    /// events are recorded in (see [`EventReceiver::with_store`]), so
+    /// nothing is lost. Falls back to a gap event if the store doesn't
+    /// have them.
+    BufferToStore,
+
+    /// Its stream ends, as if the queue had closed.
+    Disconnect,
+}
 
 /// Event queue for publishing and subscribing to A2A streaming events.
 ///
@@ -57,6 +100,10 @@ pub struct EventQueue {
     tx: broadcast::Sender<StreamResponse>,
     closed: Arc<AtomicBool>,
     children: Arc<Mutex<Vec<EventQueue>>>,
+    /// Number of events published so far.
+    published: Arc<AtomicU64>,
+    capacity: usize,
+    overflow: OverflowPolicy,
 }
 
 impl EventQueue {
@@ -73,6 +120,9 @@ impl EventQueue {
             tx,
             closed: Arc::new(AtomicBool::new(false)),
             children: Arc::new(Mutex::new(Vec::new())),
+            published: Arc::new(AtomicU64::new(0)),
+            capacity,
+            overflow: OverflowPolicy::default(),
         }
     }
 
@@ -81,15 +131,43 @@ impl EventQueue {
         Self::new(DEFAULT_CAPACITY)
     }
 
+    /// Handle subscribers that fall behind with `policy` (builder-style).
+    /// Applies to [`receiver()`](Self::receiver)s, and is inherited by
+    /// [`tap()`](Self::tap)ped child queues.
+    pub fn with_overflow_policy(mut self, policy: OverflowPolicy) -> Self {
+        self.overflow = policy;
+        self
+    }
+
+    /// The number of events buffered per subscriber.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// How subscribers that fall behind are handled.
+    pub fn overflow_policy(&self) -> OverflowPolicy {
+        self.overflow
+    }
+
     /// Subscribe to events on this queue.
     ///
     /// Returns a receiver that will receive all events published after
     /// this subscription was created. Multiple subscribers can exist
     /// simultaneously, each receiving an independent copy of events.
+    ///
+    /// A subscriber more than [`capacity()`](Self::capacity) events behind
+    /// gets `RecvError::Lagged`; use [`receiver()`](Self::receiver) to
+    /// apply the [overflow policy](Self::overflow_policy) instead.
     pub fn subscribe(&self) -> broadcast::Receiver<StreamResponse> {
         self.tx.subscribe()
     }
 
+    /// Subscribe to events on this queue, handling lag with its
+    /// [overflow policy](Self::overflow_policy).
+    pub fn receiver(&self) -> EventReceiver {
+        EventReceiver::new(self.subscribe(), self.overflow)
+    }
+
     /// Publish an event to all subscribers and child queues.
     ///
     /// If the queue is closed, the event is silently dropped (matching
@@ -104,15 +182,7 @@ impl EventQueue {
 
         debug!("Enqueuing event to queue");
 
-        match self.tx.send(event.clone()) {
-            Ok(count) => {
-                debug!(subscriber_count = count, "Published event to queue");
-            }
-            Err(_) => {
-                warn!("Failed to publish event (no subscribers)");
-                // Not fatal — subscriber may have disconnected.
-            }
-        }
+        self.send(event.clone());
 
         // Forward to child queues (mirrors Python SDK's child forwarding).
         let children = self.children.lock().await;
@@ -134,18 +204,49 @@ impl EventQueue {
             return Ok(());
         }
 
+        self.send(event);
+        Ok(())
+    }
+
+    /// Answer the request with `message` alone, without a task.
+    ///
+    /// This must be the first event the agent publishes, and the agent
+    /// should return right after: `DefaultRequestHandler` answers
+    /// `message/send` with the message and ends the `message/stream`
+    /// stream after it, and discards the task it created for the request.
+    /// A message published after other events is an ordinary
+    /// `message` event of the task.
+    pub async fn reply_message(&self, message: Message) -> A2AResult<()> {
+        self.enqueue_event(StreamResponse::Message(message)).await
+    }
+
+    /// Number and send `event` inside its `a2a.event.enqueue` span.
+    fn send(&self, event: StreamResponse) {
+        let sequence = self.published.fetch_add(1, Ordering::AcqRel) + 1;
+        let span = debug_span!(
+            "a2a.event.enqueue",
+            sequence,
+            kind = event.kind(),
+            task_id = event.task_id(),
+        );
+        let _entered = span.enter();
         match self.tx.send(event) {
             Ok(count) => {
                 debug!(subscriber_count = count, "Published event to queue");
-                Ok(())
             }
             Err(_) => {
                 warn!("Failed to publish event (no subscribers)");
-                Ok(())
+                // Not fatal — subscriber may have disconnected.
             }
         }
     }
 
+    /// Number of events published on this queue so far — the sequence
+    /// number of the latest one.
+    pub fn published(&self) -> u64 {
+        self.published.load(Ordering::Acquire)
+    }
+
     /// Returns the number of active subscribers.
     pub fn subscriber_count(&self) -> usize {
         self.tx.receiver_count()
@@ -160,7 +261,7 @@ impl EventQueue {
     /// Returns a new `EventQueue` instance.
     pub async fn tap(&self) -> EventQueue {
         debug!("Tapping EventQueue to create a child queue.");
-        let child = EventQueue::with_default_capacity();
+        let child = EventQueue::new(self.capacity).with_overflow_policy(self.overflow);
         let mut children = self.children.lock().await;
         children.push(child.clone());
         child
@@ -256,6 +357,8 @@ pub trait QueueManager: Send + Sync {
 /// `a2a.server.events.in_memory_queue_manager`.
 pub struct InMemoryQueueManager {
     queues: Mutex<HashMap<String, EventQueue>>,
+    capacity: usize,
+    overflow: OverflowPolicy,
 }
 
 impl InMemoryQueueManager {
@@ -263,8 +366,25 @@ impl InMemoryQueueManager {
     pub fn new() -> Self {
         Self {
             queues: Mutex::new(HashMap::new()),
+            capacity: DEFAULT_CAPACITY,
+            overflow: OverflowPolicy::default(),
         }
     }
+
+    /// Create the queues of [`create_or_tap`](QueueManager::create_or_tap)
+    /// with `capacity` (builder-style; default 1024).
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        assert!(capacity > 0, "capacity must be greater than 0");
+        self.capacity = capacity;
+        self
+    }
+
+    /// Create the queues of [`create_or_tap`](QueueManager::create_or_tap)
+    /// with overflow `policy` (builder-style).
+    pub fn with_overflow_policy(mut self, policy: OverflowPolicy) -> Self {
+        self.overflow = policy;
+        self
+    }
 }
 
 impl Default for InMemoryQueueManager {
@@ -313,13 +433,286 @@ impl QueueManager for InMemoryQueueManager {
         if let Some(existing) = queues.get(task_id) {
             existing.tap().await
         } else {
-            let queue = EventQueue::with_default_capacity();
+            let queue = EventQueue::new(self.capacity).with_overflow_policy(self.overflow);
             queues.insert(task_id.to_string(), queue.clone());
             queue
         }
     }
 }
 
+// ---------------------------------------------------------------------------
+// EventReceiver — subscription applying the overflow policy
+// ---------------------------------------------------------------------------
+
+/// Where the events of a subscription are recorded, for
+/// [`OverflowPolicy::BufferToStore`].
+struct StoreCursor {
+    store: Arc<dyn EventStore>,
+    task_id: String,
+    /// Store sequence number of the last event received or skipped.
+    sequence: u64,
+}
+
+/// A subscription to an [`EventQueue`] that never reports
+/// `RecvError::Lagged`: falling behind is handled by its
+/// [`OverflowPolicy`], and counted in [`missed()`](Self::missed).
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let queue = EventQueue::new(64).with_overflow_policy(OverflowPolicy::DropOldest);
+/// let mut events = queue.receiver();
+/// while let Ok(event) = events.recv().await {
+///     if let Some(missed) = gap_missed(&event) {
+///         // Fell behind by `missed` events: refetch the task.
+///     }
+/// }
+/// ```
+pub struct EventReceiver {
+    rx: broadcast::Receiver<StreamResponse>,
+    policy: OverflowPolicy,
+    store: Option<StoreCursor>,
+    /// Recovered or synthesized events to deliver before the next live one.
+    pending: VecDeque<StreamResponse>,
+    /// Task, context and status of the last status seen, for gap events.
+    last_status: Option<(String, String, TaskStatus)>,
+    missed: u64,
+    disconnected: bool,
+    /// End after the next final status update.
+    until_final: bool,
+}
+
+impl std::fmt::Debug for EventReceiver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EventReceiver")
+            .field("policy", &self.policy)
+            .field("missed", &self.missed)
+            .field("disconnected", &self.disconnected)
+            .finish_non_exhaustive()
+    }
+}
+
+impl EventReceiver {
+    /// Apply `policy` to the lag of `rx`.
+    pub fn new(rx: broadcast::Receiver<StreamResponse>, policy: OverflowPolicy) -> Self {
+        Self {
+            rx,
+            policy,
+            store: None,
+            pending: VecDeque::new(),
+            last_status: None,
+            missed: 0,
+            disconnected: false,
+            until_final: false,
+        }
+    }
+
+    /// With [`OverflowPolicy::BufferToStore`], read missed events from
+    /// `store`, where every event published on the queue is recorded
+    /// under `task_id` before it is published. `recorded` is the number
+    /// of events of the task in the store when this receiver subscribed.
+    pub fn with_store(mut self, store: Arc<dyn EventStore>, task_id: &str, recorded: u64) -> Self {
+        self.store = Some(StoreCursor {
+            store,
+            task_id: task_id.to_string(),
+            sequence: recorded,
+        });
+        self
+    }
+
+    /// Deliver `events` before the live ones.
+    pub(crate) fn with_prefix(mut self, events: Vec<StreamResponse>) -> Self {
+        self.pending.extend(events);
+        self
+    }
+
+    /// End the stream after the next final status update.
+    pub(crate) fn until_final(mut self) -> Self {
+        self.until_final = true;
+        self
+    }
+
+    /// Number of events this receiver didn't deliver because it fell
+    /// behind: dropped or cut off by a disconnect, not those recovered
+    /// from a store.
+    pub fn missed(&self) -> u64 {
+        self.missed
+    }
+
+    /// Receive the next event, like `broadcast::Receiver::recv`, but
+    /// with lag handled by the overflow policy: the error is always
+    /// `RecvError::Closed`, also when [`Disconnect`](OverflowPolicy::Disconnect)
+    /// cut the subscriber off.
+    pub async fn recv(&mut self) -> Result<StreamResponse, broadcast::error::RecvError> {
+        loop {
+            if let Some(event) = self.pending.pop_front() {
+                self.observe(&event);
+                return Ok(event);
+            }
+            if self.disconnected {
+                return Err(broadcast::error::RecvError::Closed);
+            }
+            match self.rx.recv().await {
+                Ok(event) => {
+                    if let Some(cursor) = &mut self.store {
+                        cursor.sequence += 1;
+                    }
+                    self.observe(&event);
+                    return Ok(event);
+                }
+                Err(broadcast::error::RecvError::Closed) => {
+                    return Err(broadcast::error::RecvError::Closed)
+                }
+                Err(broadcast::error::RecvError::Lagged(n)) => self.lagged(n).await,
+            }
+        }
+    }
+
+    /// Handle `n` events dropped before this receiver read them.
+    async fn lagged(&mut self, n: u64) {
+        warn!(missed = n, policy = ?self.policy, "Event subscriber lagged");
+        match self.policy {
+            OverflowPolicy::Disconnect => {
+                self.missed += n;
+                self.disconnected = true;
+                return;
+            }
+            OverflowPolicy::BufferToStore => {
+                if let Some(recovered) = self.recover(n).await {
+                    self.pending.extend(recovered);
+                    return;
+                }
+            }
+            OverflowPolicy::DropOldest => {
+                if let Some(cursor) = &mut self.store {
+                    cursor.sequence += n;
+                }
+            }
+        }
+        self.missed += n;
+        if let Some(gap) = self.gap_event(n) {
+            self.pending.push_back(gap);
+        }
+    }
+
+    /// The `n` events after the cursor, if the store has all of them.
+    /// Advances the cursor past them either way.
+    async fn recover(&mut self, n: u64) -> Option<Vec<StreamResponse>> {
+        let cursor = self.store.as_mut()?;
+        let after = cursor.sequence;
+        cursor.sequence += n;
+        let events = match cursor.store.events(&cursor.task_id, after).await {
+            Ok(events) => events,
+            Err(e) => {
+                warn!(task_id = %cursor.task_id, error = %e, "Failed to read missed events");
+                return None;
+            }
+        };
+        let recovered: Vec<StreamResponse> = events
+            .into_iter()
+            .take_while(|stored| stored.sequence <= after + n)
+            .map(|stored| stored.event)
+            .collect();
+        if recovered.len() as u64 != n {
+            warn!(
+                task_id = %cursor.task_id,
+                missed = n,
+                recorded = recovered.len(),
+                "Event store doesn't have the missed events"
+            );
+            return None;
+        }
+        debug!(task_id = %cursor.task_id, recovered = n, "Recovered missed events from store");
+        Some(recovered)
+    }
+
+    /// A status update announcing `n` missed events, if a status was seen.
+    fn gap_event(&self, n: u64) -> Option<StreamResponse> {
+        let (task_id, context_id, status) = self.last_status.clone()?;
+        let mut metadata = serde_json::Map::new();
+        metadata.insert(
+            GAP_METADATA_KEY.to_string(),
+            serde_json::json!({ "missed": n }),
+        );
+        Some(StreamResponse::StatusUpdate(TaskStatusUpdateEvent {
+            task_id,
+            context_id,
+            kind: "status-update".to_string(),
+            status,
+            r#final: false,
+            metadata: Some(serde_json::Value::Object(metadata)),
+        }))
+    }
+
+    fn observe(&mut self, event: &StreamResponse) {
+        let seen = match event {
+            StreamResponse::StatusUpdate(update) => Some((
+                update.task_id.clone(),
+                update.context_id.clone(),
+                update.status.clone(),
+            )),
+            StreamResponse::Task(task) => Some((
+                task.id.clone(),
+                task.context_id.clone(),
+                task.status.clone(),
+            )),
+            StreamResponse::ArtifactUpdate(update) if self.last_status.is_none() => Some((
+                update.task_id.clone(),
+                update.context_id.clone(),
+                TaskStatus::new(TaskState::Working),
+            )),
+            _ => None,
+        };
+        if seen.is_some() {
+            self.last_status = seen;
+        }
+    }
+
+    /// Forward the events to a `broadcast::Receiver` of `capacity` on a
+    /// spawned task, until the queue closes or the receiver is dropped.
+    ///
+    /// The relay waits for the returned receiver to make room rather than
+    /// overwrite events it hasn't read, so a slow subscriber falls behind
+    /// on this receiver, where the overflow policy applies, instead.
+    pub fn into_broadcast(mut self, capacity: usize) -> broadcast::Receiver<StreamResponse> {
+        let (tx, rx) = broadcast::channel(capacity);
+        tokio::spawn(async move {
+            while let Ok(event) = self.recv().await {
+                let is_final = self.until_final
+                    && matches!(
+                        &event,
+                        StreamResponse::StatusUpdate(update)
+                            if update.r#final || update.status.state.is_terminal()
+                    );
+                while tx.len() >= capacity {
+                    if tx.receiver_count() == 0 {
+                        return;
+                    }
+                    tokio::time::sleep(BACKPRESSURE_POLL).await;
+                }
+                if tx.send(event).is_err() || is_final {
+                    break;
+                }
+            }
+        });
+        rx
+    }
+}
+
+/// The number of missed events a gap event (see [`GAP_METADATA_KEY`])
+/// announces; `None` for other events.
+pub fn gap_missed(event: &StreamResponse) -> Option<u64> {
+    let StreamResponse::StatusUpdate(update) = event else {
+        return None;
+    };
+    update
+        .metadata
+        .as_ref()?
+        .get(GAP_METADATA_KEY)?
+        .get("missed")?
+        .as_u64()
+}
+
 // ---------------------------------------------------------------------------
 // EventConsumer — consumes events from the queue
 // ---------------------------------------------------------------------------
@@ -0,0 +1,315 @@
+//! Event store — every event of a task, for replay to late subscribers.
+//!
+//! Without an [`EventStore`], `tasks/subscribe` and `tasks/resubscribe`
+//! only deliver events published after the call, and fail for tasks that
+//! have already finished. With one (see
+//! [`DefaultRequestHandler::with_event_store`]) the event pipeline appends
+//! every persisted [`StreamResponse`] to the store, and a subscriber first
+//! receives the task's recorded events, then live ones — nothing is
+//! missed or delivered twice in between. Subscribing to a completed task
+//! replays its events and ends, as with the Python SDK's task
+//! re-subscription.
+//!
+//! ```rust,ignore
+//! let handler = DefaultRequestHandler::new(executor, store)
+//!     .with_event_store(Arc::new(FileEventStore::open("events")?));
+//! ```
+//!
+//! [`DefaultRequestHandler::with_event_store`]: super::DefaultRequestHandler::with_event_store
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::hash::{Hash, Hasher};
+use std::io::Write as _;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+use tracing::{debug, warn};
+
+use crate::error::{A2AError, A2AResult};
+use crate::types::StreamResponse;
+
+/// An event recorded for a task.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StoredEvent {
+    /// Position of the event among the task's events, from 1.
+    pub sequence: u64,
+
+    /// The event as published to subscribers.
+    pub event: StreamResponse,
+}
+
+/// Persistence for the events of tasks.
+///
+/// Events are appended once each, in the order they were published, by
+/// the task's event pipeline.
+#[async_trait]
+pub trait EventStore: Send + Sync {
+    /// Record `event` as the next event of `task_id`; returns its sequence
+    /// number.
+    async fn append(&self, task_id: &str, event: &StreamResponse) -> A2AResult<u64>;
+
+    /// The events of `task_id` with a sequence number above `after`, in
+    /// order; `after = 0` returns all of them. Unknown tasks have none.
+    async fn events(&self, task_id: &str, after: u64) -> A2AResult<Vec<StoredEvent>>;
+
+    /// Forget the events of `task_id`. Silently succeeds if there are none.
+    async fn remove(&self, task_id: &str) -> A2AResult<()>;
+}
+
+/// In-memory [`EventStore`].
+///
+/// Does not survive a restart; keeps every event until
+/// [`remove()`](EventStore::remove)d.
+#[derive(Debug, Default)]
+pub struct InMemoryEventStore {
+    events: Mutex<HashMap<String, Vec<StreamResponse>>>,
+}
+
+impl InMemoryEventStore {
+    /// Create an empty store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl EventStore for InMemoryEventStore {
+    async fn append(&self, task_id: &str, event: &StreamResponse) -> A2AResult<u64> {
+        let mut events = self.events.lock().await;
+        let task_events = events.entry(task_id.to_string()).or_default();
+        task_events.push(event.clone());
+        Ok(task_events.len() as u64)
+    }
+
+    async fn events(&self, task_id: &str, after: u64) -> A2AResult<Vec<StoredEvent>> {
+        let events = self.events.lock().await;
+        Ok(events
+            .get(task_id)
+            .into_iter()
+            .flatten()
+            .zip(1u64..)
+            .skip_while(|(_, sequence)| *sequence <= after)
+            .map(|(event, sequence)| StoredEvent {
+                sequence,
+                event: event.clone(),
+            })
+            .collect())
+    }
+
+    async fn remove(&self, task_id: &str) -> A2AResult<()> {
+        self.events.lock().await.remove(task_id);
+        Ok(())
+    }
+}
+
+/// [`EventStore`] keeping one JSON Lines file of [`StoredEvent`]s per
+/// task in a directory.
+///
+/// Events are appended to the file as they are published; a line torn by
+/// a crash is skipped when reading. Task IDs are percent-encoded into file
+/// names, so any ID is safe.
+#[derive(Debug)]
+pub struct FileEventStore {
+    dir: PathBuf,
+    /// Last sequence number of each task appended to since opening.
+    sequences: Mutex<HashMap<String, u64>>,
+}
+
+impl FileEventStore {
+    /// Keep events in `dir`, creating it if needed.
+    pub fn open(dir: impl Into<PathBuf>) -> A2AResult<Self> {
+        let dir = dir.into();
+        std::fs::create_dir_all(&dir).map_err(|e| io_error(&dir, e))?;
+        debug!(dir = %dir.display(), "Opened event store");
+        Ok(Self {
+            dir,
+            sequences: Mutex::default(),
+        })
+    }
+
+    /// The directory events are kept in.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// The file holding the events of `task_id`.
+    fn path(&self, task_id: &str) -> PathBuf {
+        let mut name = String::with_capacity(task_id.len() + 6);
+        for byte in task_id.bytes() {
+            if byte.is_ascii_alphanumeric() || byte == b'-' || byte == b'_' {
+                name.push(char::from(byte));
+            } else {
+                let _ = write!(name, "%{byte:02X}");
+            }
+        }
+        name.push_str(".jsonl");
+        self.dir.join(name)
+    }
+}
+
+/// Read the events in the file at `path`; a missing file has none.
+fn read_events(path: &Path) -> A2AResult<Vec<StoredEvent>> {
+    let text = match std::fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(io_error(path, e)),
+    };
+    Ok(text
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| match serde_json::from_str(line) {
+            Ok(event) => Some(event),
+            Err(e) => {
+                warn!(path = %path.display(), error = %e, "Skipping unreadable stored event");
+                None
+            }
+        })
+        .collect())
+}
+
+#[async_trait]
+impl EventStore for FileEventStore {
+    async fn append(&self, task_id: &str, event: &StreamResponse) -> A2AResult<u64> {
+        let path = self.path(task_id);
+        // Held across the write, so a task's lines are in sequence order.
+        let mut sequences = self.sequences.lock().await;
+        let mut line = Vec::new();
+        let last = match sequences.get(task_id) {
+            Some(last) => *last,
+            None => {
+                let path = path.clone();
+                let (events, torn) = blocking(move || {
+                    let torn = std::fs::read(&path)
+                        .map(|bytes| bytes.last().is_some_and(|b| *b != b'\n'))
+                        .unwrap_or(false);
+                    Ok((read_events(&path)?, torn))
+                })
+                .await?;
+                if torn {
+                    // Start after a line torn by a crash rather than on it.
+                    line.push(b'\n');
+                }
+                events.last().map_or(0, |stored| stored.sequence)
+            }
+        };
+        let stored = StoredEvent {
+            sequence: last + 1,
+            event: event.clone(),
+        };
+        serde_json::to_writer(&mut line, &stored)
+            .map_err(|e| A2AError::internal_error(e.to_string()))?;
+        line.push(b'\n');
+        blocking(move || {
+            std::fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&path)
+                .and_then(|mut file| file.write_all(&line))
+                .map_err(|e| io_error(&path, e))
+        })
+        .await?;
+        sequences.insert(task_id.to_string(), stored.sequence);
+        Ok(stored.sequence)
+    }
+
+    async fn events(&self, task_id: &str, after: u64) -> A2AResult<Vec<StoredEvent>> {
+        let path = self.path(task_id);
+        let mut events = blocking(move || read_events(&path)).await?;
+        events.retain(|stored| stored.sequence > after);
+        Ok(events)
+    }
+
+    async fn remove(&self, task_id: &str) -> A2AResult<()> {
+        let path = self.path(task_id);
+        let mut sequences = self.sequences.lock().await;
+        sequences.remove(task_id);
+        blocking(move || match std::fs::remove_file(&path) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(io_error(&path, e)),
+            _ => Ok(()),
+        })
+        .await
+    }
+}
+
+/// Run blocking file I/O off the async runtime.
+async fn blocking<T, F>(f: F) -> A2AResult<T>
+where
+    F: FnOnce() -> A2AResult<T> + Send + 'static,
+    T: Send + 'static,
+{
+    tokio::task::spawn_blocking(f)
+        .await
+        .map_err(|e| A2AError::internal_error(format!("event store task failed: {e}")))?
+}
+
+fn io_error(path: &Path, e: std::io::Error) -> A2AError {
+    A2AError::internal_error(format!("Event store {}: {e}", path.display()))
+}
+
+/// Number of locks [`EventLog`] spreads tasks over.
+const LOCK_STRIPES: usize = 64;
+
+/// An [`EventStore`] as used by the request handler: appending an event
+/// and publishing it happen under the same per-task lock as reading the
+/// recorded events and subscribing to live ones, so a subscriber sees
+/// every event exactly once.
+pub(crate) struct EventLog {
+    store: Arc<dyn EventStore>,
+    stripes: Vec<Mutex<()>>,
+}
+
+impl EventLog {
+    pub(crate) fn new(store: Arc<dyn EventStore>) -> Self {
+        Self {
+            store,
+            stripes: (0..LOCK_STRIPES).map(|_| Mutex::new(())).collect(),
+        }
+    }
+
+    /// The store events are recorded in.
+    pub(crate) fn store(&self) -> &Arc<dyn EventStore> {
+        &self.store
+    }
+
+    fn stripe(&self, task_id: &str) -> &Mutex<()> {
+        let mut hasher = DefaultHasher::new();
+        task_id.hash(&mut hasher);
+        &self.stripes[hasher.finish() as usize % LOCK_STRIPES]
+    }
+
+    /// Append `event` to the events of `task_id`, then `publish` it.
+    pub(crate) async fn record(
+        &self,
+        task_id: &str,
+        event: &StreamResponse,
+        publish: impl FnOnce(),
+    ) {
+        let _guard = self.stripe(task_id).lock().await;
+        if let Err(e) = self.store.append(task_id, event).await {
+            warn!(task_id = %task_id, error = %e, "Failed to record event");
+        }
+        publish();
+    }
+
+    /// The recorded events of `task_id`, and the result of `subscribe`
+    /// taken at the same point of the stream.
+    pub(crate) async fn replay<T>(
+        &self,
+        task_id: &str,
+        subscribe: impl FnOnce() -> T,
+    ) -> A2AResult<(Vec<StreamResponse>, T)> {
+        let _guard = self.stripe(task_id).lock().await;
+        let live = subscribe();
+        let events = self.store.events(task_id, 0).await?;
+        Ok((
+            events.into_iter().map(|stored| stored.event).collect(),
+            live,
+        ))
+    }
+}
@@ -0,0 +1,300 @@
+//! gRPC service (`grpc` feature).
+//!
+//! [`GrpcService`] serves the A2A gRPC binding (`a2a.v1.A2AService`) from
+//! a [`RequestHandler`], the same one the axum router dispatches JSON-RPC
+//! to, so an agent can expose both interfaces from one executor.
+
+use std::convert::Infallible;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use futures::Stream;
+use tokio::sync::broadcast;
+use tonic::codec::ProstCodec;
+use tonic::codegen::{http, Body, BoxFuture, Service, StdError};
+use tonic::server::{Grpc, NamedService};
+use tonic::{Request, Response, Status};
+use tracing::{debug, warn};
+
+use crate::error::{A2AError, A2AResult};
+use crate::grpc::{proto, status_from_error, task_id_from_name};
+use crate::types::{self, StreamResponse};
+use crate::utils::deadline::{parse_deadline, DEADLINE_HEADER};
+use crate::utils::extensions::{get_requested_extensions, HTTP_EXTENSION_HEADER};
+use crate::utils::identification::{ClientIdentification, CLIENT_IDENTIFICATION_HEADER};
+use crate::utils::request_id::{request_id_or_new, REQUEST_ID_HEADER};
+
+use super::agent_executor::ServerCallContext;
+use super::request_handler::{
+    CancelTaskParams, GetTaskParams, RequestHandler, SendMessageConfiguration, SendMessageParams,
+    SubscribeToTaskParams,
+};
+
+type EventStream = Pin<Box<dyn Stream<Item = Result<proto::StreamResponse, Status>> + Send>>;
+
+/// tonic service for the A2A gRPC binding.
+///
+/// Serves `SendMessage`, `SendStreamingMessage`, `GetTask`, `CancelTask`
+/// and `TaskSubscription`; other RPCs answer `UNIMPLEMENTED`. Handler
+/// calls run inside a [`ServerCallContext`] carrying the extensions
+/// requested in the `x-a2a-extensions` metadata entry and the caller's
+/// identification. Streams end after a final status update or a message.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::sync::Arc;
+/// use a2a_rs::server::{DefaultRequestHandler, GrpcService, InMemoryTaskStore};
+/// # use a2a_rs::server::AgentExecutor;
+///
+/// # async fn example(executor: Arc<dyn AgentExecutor>) -> Result<(), Box<dyn std::error::Error>> {
+/// let handler = Arc::new(DefaultRequestHandler::new(
+///     executor,
+///     Arc::new(InMemoryTaskStore::new()),
+/// ));
+/// tonic::transport::Server::builder()
+///     .add_service(GrpcService::new(handler))
+///     .serve("127.0.0.1:50051".parse()?)
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct GrpcService {
+    handler: Arc<dyn RequestHandler>,
+}
+
+impl std::fmt::Debug for GrpcService {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GrpcService").finish_non_exhaustive()
+    }
+}
+
+impl GrpcService {
+    /// Serve `handler` over gRPC.
+    pub fn new(handler: Arc<dyn RequestHandler>) -> Self {
+        Self { handler }
+    }
+}
+
+impl NamedService for GrpcService {
+    const NAME: &'static str = proto::SERVICE_NAME;
+}
+
+impl<B> Service<http::Request<B>> for GrpcService
+where
+    B: Body + Send + 'static,
+    B::Error: Into<StdError> + Send + 'static,
+{
+    type Response = http::Response<tonic::body::Body>;
+    type Error = Infallible;
+    type Future = BoxFuture<Self::Response, Self::Error>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: http::Request<B>) -> Self::Future {
+        let handler = self.handler.clone();
+        match req.uri().path() {
+            proto::SEND_MESSAGE => Box::pin(async move {
+                let svc = tower::service_fn(move |request| send_message(handler.clone(), request));
+                Ok(Grpc::new(ProstCodec::default()).unary(svc, req).await)
+            }),
+            proto::SEND_STREAMING_MESSAGE => Box::pin(async move {
+                let svc = tower::service_fn(move |request| {
+                    send_streaming_message(handler.clone(), request)
+                });
+                Ok(Grpc::new(ProstCodec::default())
+                    .server_streaming(svc, req)
+                    .await)
+            }),
+            proto::GET_TASK => Box::pin(async move {
+                let svc = tower::service_fn(move |request| get_task(handler.clone(), request));
+                Ok(Grpc::new(ProstCodec::default()).unary(svc, req).await)
+            }),
+            proto::CANCEL_TASK => Box::pin(async move {
+                let svc = tower::service_fn(move |request| cancel_task(handler.clone(), request));
+                Ok(Grpc::new(ProstCodec::default()).unary(svc, req).await)
+            }),
+            proto::TASK_SUBSCRIPTION => Box::pin(async move {
+                let svc =
+                    tower::service_fn(move |request| task_subscription(handler.clone(), request));
+                Ok(Grpc::new(ProstCodec::default())
+                    .server_streaming(svc, req)
+                    .await)
+            }),
+            path => {
+                debug!(path, "Unimplemented gRPC method");
+                let status = Status::unimplemented(format!("Method not found: {path}"));
+                Box::pin(async move { Ok(status.into_http()) })
+            }
+        }
+    }
+}
+
+/// The call context for a gRPC request.
+fn call_context<M>(request: &Request<M>) -> ServerCallContext {
+    let metadata = request.metadata();
+    let header = |name: &str| {
+        metadata
+            .get(name.to_ascii_lowercase())
+            .and_then(|value| value.to_str().ok())
+    };
+    let requested = metadata
+        .get_all(HTTP_EXTENSION_HEADER.to_ascii_lowercase())
+        .iter()
+        .filter_map(|value| value.to_str().ok().map(String::from))
+        .collect::<Vec<_>>();
+    ServerCallContext {
+        requested_extensions: get_requested_extensions(&requested),
+        client: ClientIdentification::from_headers(
+            header(CLIENT_IDENTIFICATION_HEADER),
+            header("user-agent"),
+        ),
+        request_id: Some(request_id_or_new(header(REQUEST_ID_HEADER))),
+        deadline: header(DEADLINE_HEADER).and_then(parse_deadline),
+        ..Default::default()
+    }
+}
+
+/// Server-side parameters for a `SendMessageRequest`; malformed requests
+/// fail with `InvalidParams`.
+fn send_params(request: proto::SendMessageRequest) -> A2AResult<SendMessageParams> {
+    let params = types::SendMessageParams::try_from(request)?;
+    let configuration = params.configuration.map(|config| SendMessageConfiguration {
+        accepted_output_modes: config.accepted_output_modes,
+        blocking: config.blocking,
+        history_length: config.history_length.map(|n| n.max(0) as usize),
+        push_notification_config: config
+            .push_notification_config
+            .and_then(|push| serde_json::to_value(push).ok()),
+        timeout: config.timeout_ms.map(Duration::from_millis),
+    });
+    Ok(SendMessageParams {
+        message: params.message,
+        configuration,
+        metadata: params.metadata,
+        tenant: None,
+    })
+}
+
+async fn send_message(
+    handler: Arc<dyn RequestHandler>,
+    request: Request<proto::SendMessageRequest>,
+) -> Result<Response<proto::SendMessageResponse>, Status> {
+    let context = call_context(&request);
+    let params = send_params(request.into_inner()).map_err(status_from_error)?;
+    let response = context
+        .scope(handler.on_message_send(params))
+        .await
+        .map_err(status_from_error)?;
+    proto::SendMessageResponse::try_from(response)
+        .map(Response::new)
+        .map_err(status_from_error)
+}
+
+async fn send_streaming_message(
+    handler: Arc<dyn RequestHandler>,
+    request: Request<proto::SendMessageRequest>,
+) -> Result<Response<EventStream>, Status> {
+    let context = call_context(&request);
+    let params = send_params(request.into_inner()).map_err(status_from_error)?;
+    let rx = context
+        .scope(handler.on_message_send_stream(params))
+        .await
+        .map_err(status_from_error)?;
+    Ok(Response::new(event_stream(rx)))
+}
+
+async fn get_task(
+    handler: Arc<dyn RequestHandler>,
+    request: Request<proto::GetTaskRequest>,
+) -> Result<Response<proto::Task>, Status> {
+    let context = call_context(&request);
+    let request = request.into_inner();
+    let params = GetTaskParams {
+        id: task_id_from_name(&request.name).to_string(),
+        history_length: (request.history_length > 0).then_some(request.history_length as usize),
+        metadata: None,
+        tenant: None,
+        consistency: None,
+    };
+    let task = context
+        .scope(handler.on_get_task(params))
+        .await
+        .map_err(status_from_error)?;
+    proto::Task::try_from(task)
+        .map(Response::new)
+        .map_err(status_from_error)
+}
+
+async fn cancel_task(
+    handler: Arc<dyn RequestHandler>,
+    request: Request<proto::CancelTaskRequest>,
+) -> Result<Response<proto::Task>, Status> {
+    let context = call_context(&request);
+    let params = CancelTaskParams {
+        id: task_id_from_name(&request.get_ref().name).to_string(),
+        metadata: None,
+        tenant: None,
+    };
+    let task = context
+        .scope(handler.on_cancel_task(params))
+        .await
+        .map_err(status_from_error)?;
+    proto::Task::try_from(task)
+        .map(Response::new)
+        .map_err(status_from_error)
+}
+
+async fn task_subscription(
+    handler: Arc<dyn RequestHandler>,
+    request: Request<proto::TaskSubscriptionRequest>,
+) -> Result<Response<EventStream>, Status> {
+    let context = call_context(&request);
+    let params = SubscribeToTaskParams {
+        id: task_id_from_name(&request.get_ref().name).to_string(),
+        metadata: None,
+        tenant: None,
+    };
+    let rx = context
+        .scope(handler.on_subscribe_to_task(params))
+        .await
+        .map_err(status_from_error)?;
+    Ok(Response::new(event_stream(rx)))
+}
+
+/// Forward events until a final status update, a message, or the end of
+/// the channel.
+fn event_stream(mut rx: broadcast::Receiver<StreamResponse>) -> EventStream {
+    Box::pin(async_stream::stream! {
+        loop {
+            match rx.recv().await {
+                Ok(event) => {
+                    let last = match &event {
+                        StreamResponse::StatusUpdate(update) => update.r#final,
+                        StreamResponse::Message(_) => true,
+                        _ => false,
+                    };
+                    match proto::StreamResponse::try_from(event) {
+                        Ok(event) => yield Ok(event),
+                        Err(e) => {
+                            yield Err(status_from_error(A2AError::internal_error(e.to_string())));
+                            break;
+                        }
+                    }
+                    if last {
+                        break;
+                    }
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+                Err(broadcast::error::RecvError::Lagged(n)) => {
+                    warn!(missed = n, "gRPC stream lagged — some events were missed");
+                }
+            }
+        }
+    })
+}
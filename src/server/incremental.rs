@@ -0,0 +1,210 @@
+//! Messages whose parts arrive incrementally (`message/sendStream`).
+//!
+//! With the `message/sendStream` extension a client uploads a message's
+//! parts one at a time instead of in a single request body, and the agent
+//! starts working as soon as the first line arrives. The executor finds an
+//! [`IncrementalMessage`] in
+//! [`RequestContext::incremental_message()`](super::RequestContext::incremental_message)
+//! and reads the parts as they come with
+//! [`next_part()`](IncrementalMessage::next_part), or waits for the whole
+//! message with [`assemble()`](IncrementalMessage::assemble).
+//!
+//! The transport feeds the parts through the [`IncrementalMessageSender`]
+//! returned alongside by [`IncrementalMessage::channel()`].
+
+use std::sync::{Arc, Mutex};
+
+use tokio::sync::Notify;
+
+use crate::error::{A2AError, A2AResult};
+use crate::types::{Message, Part};
+
+/// Parts received so far, and how the upload ended.
+#[derive(Debug, Default)]
+struct Progress {
+    parts: Vec<Part>,
+    /// `None` while parts are still arriving.
+    end: Option<A2AResult<()>>,
+}
+
+#[derive(Debug)]
+struct Shared {
+    message: Message,
+    progress: Mutex<Progress>,
+    changed: Notify,
+}
+
+/// A message whose parts are still arriving.
+///
+/// Handles are cheap to clone and share the received parts; each reads
+/// them from its own position, starting where the handle it was cloned
+/// from stood.
+///
+/// # Example
+///
+/// ```
+/// use a2a_rs::server::IncrementalMessage;
+/// use a2a_rs::types::{Message, Part};
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let (sender, mut incoming) = IncrementalMessage::channel(Message::user("m1", "first"));
+/// sender.send(Part::text("second"));
+/// sender.finish();
+///
+/// assert!(incoming.next_part().await.unwrap().is_ok());
+/// assert_eq!(incoming.assemble().await.unwrap().parts.len(), 2);
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct IncrementalMessage {
+    shared: Arc<Shared>,
+    /// Index of the next part [`next_part()`](Self::next_part) returns.
+    cursor: usize,
+    /// Whether this handle already returned the upload's error.
+    failed: bool,
+}
+
+impl IncrementalMessage {
+    /// A message starting with the parts of `message`, and the sender for
+    /// the rest of them.
+    pub fn channel(message: Message) -> (IncrementalMessageSender, IncrementalMessage) {
+        let mut message = message;
+        let parts = std::mem::take(&mut message.parts);
+        let shared = Arc::new(Shared {
+            message,
+            progress: Mutex::new(Progress { parts, end: None }),
+            changed: Notify::new(),
+        });
+        let incoming = IncrementalMessage {
+            shared: Arc::clone(&shared),
+            cursor: 0,
+            failed: false,
+        };
+        (IncrementalMessageSender { shared }, incoming)
+    }
+
+    /// A message that arrived whole, e.g. a `message/sendStream` call made
+    /// with a plain JSON body.
+    pub fn complete(message: Message) -> Self {
+        let (sender, incoming) = Self::channel(message);
+        sender.finish();
+        incoming
+    }
+
+    /// The message as it started: its IDs, role and metadata, without
+    /// parts.
+    pub fn message(&self) -> &Message {
+        &self.shared.message
+    }
+
+    /// Number of parts received so far.
+    pub fn parts_received(&self) -> usize {
+        self.shared.progress.lock().unwrap().parts.len()
+    }
+
+    /// Returns `true` once the last part arrived, or the upload failed.
+    pub fn is_complete(&self) -> bool {
+        self.shared.progress.lock().unwrap().end.is_some()
+    }
+
+    /// The next part, waiting for it to arrive.
+    ///
+    /// Returns `None` after the last part. If the upload breaks off, the
+    /// parts received are returned first, then its error once.
+    pub async fn next_part(&mut self) -> Option<A2AResult<Part>> {
+        loop {
+            let changed = self.shared.changed.notified();
+            tokio::pin!(changed);
+            changed.as_mut().enable();
+            {
+                let progress = self.shared.progress.lock().unwrap();
+                if let Some(part) = progress.parts.get(self.cursor) {
+                    self.cursor += 1;
+                    return Some(Ok(part.clone()));
+                }
+                match &progress.end {
+                    Some(Err(e)) if !self.failed => {
+                        self.failed = true;
+                        return Some(Err(e.clone()));
+                    }
+                    Some(_) => return None,
+                    None => {}
+                }
+            }
+            changed.await;
+        }
+    }
+
+    /// The whole message, once its last part arrived.
+    ///
+    /// Doesn't move [`next_part()`](Self::next_part) along. Fails with the
+    /// upload's error if it breaks off.
+    pub async fn assemble(&self) -> A2AResult<Message> {
+        loop {
+            let changed = self.shared.changed.notified();
+            tokio::pin!(changed);
+            changed.as_mut().enable();
+            {
+                let progress = self.shared.progress.lock().unwrap();
+                match &progress.end {
+                    Some(Ok(())) => {
+                        let mut message = self.shared.message.clone();
+                        message.parts = progress.parts.clone();
+                        return Ok(message);
+                    }
+                    Some(Err(e)) => return Err(e.clone()),
+                    None => {}
+                }
+            }
+            changed.await;
+        }
+    }
+}
+
+/// The sending half of an [`IncrementalMessage`], fed by the transport.
+///
+/// Dropping it without [`finish()`](Self::finish) fails the upload, as a
+/// client disconnecting mid-message does.
+#[derive(Debug)]
+pub struct IncrementalMessageSender {
+    shared: Arc<Shared>,
+}
+
+impl IncrementalMessageSender {
+    /// Append `part` to the message. Ignored once the upload ended.
+    pub fn send(&self, part: Part) {
+        let mut progress = self.shared.progress.lock().unwrap();
+        if progress.end.is_none() {
+            progress.parts.push(part);
+            self.shared.changed.notify_waiters();
+        }
+    }
+
+    /// Mark the message complete: no more parts follow.
+    pub fn finish(self) {
+        self.end(Ok(()));
+    }
+
+    /// Break off the upload with `error`, which readers of the message
+    /// get after the parts already received.
+    pub fn fail(self, error: A2AError) {
+        self.end(Err(error));
+    }
+
+    fn end(&self, result: A2AResult<()>) {
+        let mut progress = self.shared.progress.lock().unwrap();
+        if progress.end.is_none() {
+            progress.end = Some(result);
+            self.shared.changed.notify_waiters();
+        }
+    }
+}
+
+impl Drop for IncrementalMessageSender {
+    fn drop(&mut self) {
+        self.end(Err(A2AError::invalid_params(
+            "message upload ended before its last part",
+        )));
+    }
+}
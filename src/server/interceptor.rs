@@ -0,0 +1,274 @@
+//! Request lifecycle hooks for [`DefaultRequestHandler`].
+//!
+//! A [`ServerInterceptor`] registered with
+//! [`DefaultRequestHandler::with_interceptor`] runs
+//! [`before`](ServerInterceptor::before) every JSON-RPC method the handler
+//! serves and [`after`](ServerInterceptor::after) it, seeing the method
+//! name, its typed params and the [`ServerCallContext`] (extensions,
+//! authenticated user, HTTP details). Typical uses are audit logging,
+//! quota enforcement and rejecting requests, without reimplementing
+//! [`RequestHandler`](super::RequestHandler).
+//!
+//! Interceptors run `before` in registration order and `after` in reverse
+//! order. An error from `before` rejects the request with that error: the
+//! method and later interceptors don't run, and the interceptors before it
+//! see the error in `after`. Method names are the canonical ones
+//! (`message/send`, `tasks/get`, …) whichever alias the client used. For
+//! streaming methods `after` runs once the stream is established, not
+//! when it ends.
+//!
+//! `contexts/cancel` is intercepted as a whole and, through the default
+//! implementation, each `tasks/cancel` it makes as well.
+//!
+//! ```rust,ignore
+//! let handler = DefaultRequestHandler::new(executor, store)
+//!     .with_interceptor(Arc::new(AuditLog::new()))
+//!     .with_interceptor(Arc::new(Quota::per_user(100)));
+//! ```
+//!
+//! [`DefaultRequestHandler`]: super::DefaultRequestHandler
+//! [`DefaultRequestHandler::with_interceptor`]: super::DefaultRequestHandler::with_interceptor
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::error::{A2AError, A2AResult};
+use crate::types::{CancelContextParams, GetArtifactParams, ListArtifactsParams};
+
+use super::agent_executor::ServerCallContext;
+use super::request_handler::{
+    CancelTaskParams, GetTaskParams, SendMessageParams, SubscribeToTaskParams,
+};
+use super::task_store::TaskListParams;
+
+/// The params of an intercepted method.
+#[derive(Debug, Clone, Copy)]
+pub enum MethodParams<'a> {
+    /// `message/send`, `message/stream`.
+    SendMessage(&'a SendMessageParams),
+    /// `tasks/get`.
+    GetTask(&'a GetTaskParams),
+    /// `tasks/list`.
+    ListTasks(&'a TaskListParams),
+    /// `tasks/cancel`.
+    CancelTask(&'a CancelTaskParams),
+    /// `contexts/cancel`.
+    CancelContext(&'a CancelContextParams),
+    /// `tasks/artifacts/list`.
+    ListArtifacts(&'a ListArtifactsParams),
+    /// `tasks/artifacts/get`.
+    GetArtifact(&'a GetArtifactParams),
+    /// `tasks/subscribe`, `tasks/resubscribe`.
+    Subscribe(&'a SubscribeToTaskParams),
+    /// `tasks/pushNotificationConfig/*` and notifications, undecoded.
+    Json(&'a serde_json::Value),
+}
+
+impl MethodParams<'_> {
+    /// The task the request is about, if it names one.
+    pub fn task_id(&self) -> Option<&str> {
+        match self {
+            Self::SendMessage(params) => params.message.task_id.as_deref(),
+            Self::GetTask(params) => Some(&params.id),
+            Self::CancelTask(params) => Some(&params.id),
+            Self::Subscribe(params) => Some(&params.id),
+            Self::ListArtifacts(params) => Some(&params.task_id),
+            Self::GetArtifact(params) => Some(&params.task_id),
+            Self::Json(params) => params
+                .get("taskId")
+                .or_else(|| params.get("id"))
+                .and_then(|id| id.as_str()),
+            Self::ListTasks(_) | Self::CancelContext(_) => None,
+        }
+    }
+
+    /// The context the request is about, if it names one.
+    pub fn context_id(&self) -> Option<&str> {
+        match self {
+            Self::SendMessage(params) => params.message.context_id.as_deref(),
+            Self::ListTasks(params) => params.context_id.as_deref(),
+            Self::CancelContext(params) => Some(&params.context_id),
+            _ => None,
+        }
+    }
+}
+
+/// A request seen by a [`ServerInterceptor`].
+#[derive(Debug, Clone, Copy)]
+pub struct InterceptedRequest<'a> {
+    /// Canonical JSON-RPC method name, e.g. `message/send`.
+    pub method: &'a str,
+
+    /// The method's params.
+    pub params: MethodParams<'a>,
+
+    /// Context of the call, if the handler is called from a transport
+    /// (see [`ServerCallContext::current`]).
+    pub call_context: Option<&'a ServerCallContext>,
+}
+
+/// Hook run around every method of [`DefaultRequestHandler`].
+///
+/// Both methods have no-op defaults.
+///
+/// # Example
+///
+/// ```
+/// use a2a_rs::error::{A2AError, A2AResult};
+/// use a2a_rs::server::{InterceptedRequest, ServerInterceptor};
+/// use async_trait::async_trait;
+///
+/// /// Serves reads only.
+/// struct ReadOnly;
+///
+/// #[async_trait]
+/// impl ServerInterceptor for ReadOnly {
+///     async fn before(&self, request: &InterceptedRequest<'_>) -> A2AResult<()> {
+///         match request.method {
+///             "tasks/get" | "tasks/list" => Ok(()),
+///             method => Err(A2AError::invalid_request(format!("{method} is disabled"))),
+///         }
+///     }
+/// }
+/// ```
+///
+/// [`DefaultRequestHandler`]: super::DefaultRequestHandler
+#[async_trait]
+pub trait ServerInterceptor: Send + Sync {
+    /// Called before the method runs. An error rejects the request with
+    /// that error.
+    async fn before(&self, request: &InterceptedRequest<'_>) -> A2AResult<()> {
+        let _ = request;
+        Ok(())
+    }
+
+    /// Called after the method ran (or was rejected by a later
+    /// interceptor), with its error if it failed.
+    async fn after(&self, request: &InterceptedRequest<'_>, outcome: Result<(), &A2AError>) {
+        let _ = (request, outcome);
+    }
+}
+
+/// Params of a method that can be intercepted.
+pub(crate) trait Intercepted: Clone {
+    fn method_params(&self) -> MethodParams<'_>;
+}
+
+impl Intercepted for SendMessageParams {
+    fn method_params(&self) -> MethodParams<'_> {
+        MethodParams::SendMessage(self)
+    }
+}
+
+impl Intercepted for GetTaskParams {
+    fn method_params(&self) -> MethodParams<'_> {
+        MethodParams::GetTask(self)
+    }
+}
+
+impl Intercepted for TaskListParams {
+    fn method_params(&self) -> MethodParams<'_> {
+        MethodParams::ListTasks(self)
+    }
+}
+
+impl Intercepted for CancelTaskParams {
+    fn method_params(&self) -> MethodParams<'_> {
+        MethodParams::CancelTask(self)
+    }
+}
+
+impl Intercepted for CancelContextParams {
+    fn method_params(&self) -> MethodParams<'_> {
+        MethodParams::CancelContext(self)
+    }
+}
+
+impl Intercepted for ListArtifactsParams {
+    fn method_params(&self) -> MethodParams<'_> {
+        MethodParams::ListArtifacts(self)
+    }
+}
+
+impl Intercepted for GetArtifactParams {
+    fn method_params(&self) -> MethodParams<'_> {
+        MethodParams::GetArtifact(self)
+    }
+}
+
+impl Intercepted for SubscribeToTaskParams {
+    fn method_params(&self) -> MethodParams<'_> {
+        MethodParams::Subscribe(self)
+    }
+}
+
+impl Intercepted for serde_json::Value {
+    fn method_params(&self) -> MethodParams<'_> {
+        MethodParams::Json(self)
+    }
+}
+
+/// Run `run` on `params` between the hooks of `interceptors`.
+pub(crate) async fn intercept<P, T, F>(
+    interceptors: &[Arc<dyn ServerInterceptor>],
+    method: &str,
+    params: P,
+    run: impl FnOnce(P) -> F,
+) -> A2AResult<T>
+where
+    P: Intercepted,
+    F: std::future::Future<Output = A2AResult<T>>,
+{
+    #[cfg(feature = "observability")]
+    let (task_id, context_id) = {
+        let ids = params.method_params();
+        (
+            ids.task_id().map(String::from),
+            ids.context_id().map(String::from),
+        )
+    };
+    let hooked = run_hooks(interceptors, method, params, run);
+    #[cfg(feature = "observability")]
+    let hooked = crate::observability::server_request(
+        method,
+        task_id.as_deref(),
+        context_id.as_deref(),
+        hooked,
+    );
+    hooked.await
+}
+
+async fn run_hooks<P, T, F>(
+    interceptors: &[Arc<dyn ServerInterceptor>],
+    method: &str,
+    params: P,
+    run: impl FnOnce(P) -> F,
+) -> A2AResult<T>
+where
+    P: Intercepted,
+    F: std::future::Future<Output = A2AResult<T>>,
+{
+    if interceptors.is_empty() {
+        return run(params).await;
+    }
+    let call_context = ServerCallContext::current();
+    let request = InterceptedRequest {
+        method,
+        params: params.method_params(),
+        call_context: call_context.as_ref(),
+    };
+    for (i, interceptor) in interceptors.iter().enumerate() {
+        if let Err(e) = interceptor.before(&request).await {
+            for earlier in interceptors[..i].iter().rev() {
+                earlier.after(&request, Err(&e)).await;
+            }
+            return Err(e);
+        }
+    }
+    let result = run(params.clone()).await;
+    for interceptor in interceptors.iter().rev() {
+        interceptor.after(&request, result.as_ref().map(drop)).await;
+    }
+    result
+}
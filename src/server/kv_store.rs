@@ -0,0 +1,352 @@
+//! Key-value store — one persistence backend for every server subsystem.
+//!
+//! [`KvStore`] is a minimal async byte store: get, put, delete and a
+//! prefix scan. The server's stores are built on it, each under its own
+//! key prefix, so a backend implemented once persists all of them:
+//!
+//! | Store                                          | Keys                          |
+//! |------------------------------------------------|-------------------------------|
+//! | [`KvTaskStore`](super::KvTaskStore)            | `task/{task_id}`, `task-version/{task_id}` |
+//! | [`KvSubscriptionRegistry`](super::KvSubscriptionRegistry) | `subscription/{task_id}` |
+//! | message dedup ([`DefaultRequestHandler::with_message_dedup_store`](super::DefaultRequestHandler::with_message_dedup_store)) | `dedup/{key}` |
+//! | [`KvPushNotificationConfigStore`](super::KvPushNotificationConfigStore) | `push-config/{task_id}/{config_id}` |
+//!
+//! Values are JSON. Provided backends:
+//!
+//! - [`InMemoryKvStore`] — a `BTreeMap`; lost on restart
+//! - `SledKvStore` — embedded on-disk database (`kv-sled` feature)
+//! - `RedisKvStore` — shared between replicas (`kv-redis` feature)
+//!
+//! ```rust,ignore
+//! let kv: Arc<dyn KvStore> = Arc::new(SledKvStore::open("a2a.db")?);
+//! let handler = DefaultRequestHandler::new(executor, Arc::new(KvTaskStore::new(kv.clone())))
+//!     .with_subscription_registry(Arc::new(KvSubscriptionRegistry::new(kv.clone())))
+//!     .with_message_dedup_store(Duration::from_secs(300), kv);
+//! ```
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use tokio::sync::RwLock;
+
+use crate::error::{A2AError, A2AResult};
+
+/// Async key-value store of byte values under string keys.
+///
+/// Implementations must be safe to share between tasks; each operation
+/// stands alone (no transactions). [`scan_prefix()`](Self::scan_prefix)
+/// returns entries ordered by key.
+#[async_trait]
+pub trait KvStore: Send + Sync {
+    /// The value under `key`, if any.
+    async fn get(&self, key: &str) -> A2AResult<Option<Vec<u8>>>;
+
+    /// Insert or replace the value under `key`.
+    async fn put(&self, key: &str, value: Vec<u8>) -> A2AResult<()>;
+
+    /// Remove `key`. Silently succeeds if it is absent.
+    async fn delete(&self, key: &str) -> A2AResult<()>;
+
+    /// All entries whose key starts with `prefix`, ordered by key.
+    async fn scan_prefix(&self, prefix: &str) -> A2AResult<Vec<(String, Vec<u8>)>>;
+}
+
+#[async_trait]
+impl<T: KvStore + ?Sized> KvStore for Arc<T> {
+    async fn get(&self, key: &str) -> A2AResult<Option<Vec<u8>>> {
+        (**self).get(key).await
+    }
+
+    async fn put(&self, key: &str, value: Vec<u8>) -> A2AResult<()> {
+        (**self).put(key, value).await
+    }
+
+    async fn delete(&self, key: &str) -> A2AResult<()> {
+        (**self).delete(key).await
+    }
+
+    async fn scan_prefix(&self, prefix: &str) -> A2AResult<Vec<(String, Vec<u8>)>> {
+        (**self).scan_prefix(prefix).await
+    }
+}
+
+/// Read the JSON value under `key`.
+pub(crate) async fn get_json<T: DeserializeOwned>(
+    store: &dyn KvStore,
+    key: &str,
+) -> A2AResult<Option<T>> {
+    match store.get(key).await? {
+        Some(bytes) => decode(key, &bytes).map(Some),
+        None => Ok(None),
+    }
+}
+
+/// Store `value` as JSON under `key`.
+pub(crate) async fn put_json<T: Serialize>(
+    store: &dyn KvStore,
+    key: &str,
+    value: &T,
+) -> A2AResult<()> {
+    let bytes = serde_json::to_vec(value)
+        .map_err(|e| A2AError::internal_error(format!("Cannot encode {key}: {e}")))?;
+    store.put(key, bytes).await
+}
+
+/// Decode a JSON value read from `key`.
+pub(crate) fn decode<T: DeserializeOwned>(key: &str, bytes: &[u8]) -> A2AResult<T> {
+    serde_json::from_slice(bytes)
+        .map_err(|e| A2AError::internal_error(format!("Invalid value under {key}: {e}")))
+}
+
+/// In-memory [`KvStore`].
+///
+/// Does not survive a restart; useful for tests and single-process
+/// servers.
+#[derive(Debug, Default)]
+pub struct InMemoryKvStore {
+    entries: RwLock<BTreeMap<String, Vec<u8>>>,
+}
+
+impl InMemoryKvStore {
+    /// Create an empty store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl KvStore for InMemoryKvStore {
+    async fn get(&self, key: &str) -> A2AResult<Option<Vec<u8>>> {
+        Ok(self.entries.read().await.get(key).cloned())
+    }
+
+    async fn put(&self, key: &str, value: Vec<u8>) -> A2AResult<()> {
+        self.entries.write().await.insert(key.to_string(), value);
+        Ok(())
+    }
+
+    async fn delete(&self, key: &str) -> A2AResult<()> {
+        self.entries.write().await.remove(key);
+        Ok(())
+    }
+
+    async fn scan_prefix(&self, prefix: &str) -> A2AResult<Vec<(String, Vec<u8>)>> {
+        Ok(self
+            .entries
+            .read()
+            .await
+            .range(prefix.to_string()..)
+            .take_while(|(key, _)| key.starts_with(prefix))
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect())
+    }
+}
+
+/// [`KvStore`] in an embedded [sled](https://docs.rs/sled) database
+/// (`kv-sled` feature).
+///
+/// Writes are durable once sled flushes them (every 500 ms by default);
+/// call [`flush()`](Self::flush) to force it, e.g. before shutdown.
+#[cfg(feature = "kv-sled")]
+#[derive(Debug, Clone)]
+pub struct SledKvStore {
+    db: sled::Db,
+}
+
+#[cfg(feature = "kv-sled")]
+impl SledKvStore {
+    /// Open (or create) the database at `path`.
+    pub fn open(path: impl AsRef<std::path::Path>) -> A2AResult<Self> {
+        let path = path.as_ref();
+        let db = sled::open(path).map_err(|e| {
+            A2AError::internal_error(format!("Cannot open sled database {}: {e}", path.display()))
+        })?;
+        Ok(Self { db })
+    }
+
+    /// Use an already opened database.
+    pub fn from_db(db: sled::Db) -> Self {
+        Self { db }
+    }
+
+    /// Write all pending changes to disk.
+    pub async fn flush(&self) -> A2AResult<()> {
+        self.db.flush_async().await.map(drop).map_err(sled_error)
+    }
+}
+
+#[cfg(feature = "kv-sled")]
+fn sled_error(e: sled::Error) -> A2AError {
+    A2AError::internal_error(format!("sled: {e}"))
+}
+
+#[cfg(feature = "kv-sled")]
+#[async_trait]
+impl KvStore for SledKvStore {
+    async fn get(&self, key: &str) -> A2AResult<Option<Vec<u8>>> {
+        Ok(self.db.get(key).map_err(sled_error)?.map(|v| v.to_vec()))
+    }
+
+    async fn put(&self, key: &str, value: Vec<u8>) -> A2AResult<()> {
+        self.db.insert(key, value).map(drop).map_err(sled_error)
+    }
+
+    async fn delete(&self, key: &str) -> A2AResult<()> {
+        self.db.remove(key).map(drop).map_err(sled_error)
+    }
+
+    async fn scan_prefix(&self, prefix: &str) -> A2AResult<Vec<(String, Vec<u8>)>> {
+        self.db
+            .scan_prefix(prefix)
+            .map(|entry| {
+                let (key, value) = entry.map_err(sled_error)?;
+                let key = String::from_utf8(key.to_vec())
+                    .map_err(|e| A2AError::internal_error(format!("sled: non-UTF-8 key: {e}")))?;
+                Ok((key, value.to_vec()))
+            })
+            .collect()
+    }
+}
+
+/// [`KvStore`] in [Redis](https://redis.io) (`kv-redis` feature).
+///
+/// Servers sharing one Redis see each other's tasks, subscriptions and
+/// dedup records. Keys can be namespaced with
+/// [`with_key_prefix()`](Self::with_key_prefix) to share a database with
+/// other applications. [`scan_prefix()`](KvStore::scan_prefix) uses
+/// `SCAN`, so it is not a point-in-time snapshot.
+#[cfg(feature = "kv-redis")]
+#[derive(Clone)]
+pub struct RedisKvStore {
+    connection: redis::aio::ConnectionManager,
+    key_prefix: String,
+}
+
+#[cfg(feature = "kv-redis")]
+impl std::fmt::Debug for RedisKvStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RedisKvStore")
+            .field("key_prefix", &self.key_prefix)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "kv-redis")]
+impl RedisKvStore {
+    /// Connect to the Redis server at `url` (e.g. `redis://127.0.0.1/`).
+    /// The connection is re-established automatically if it drops.
+    pub async fn connect(url: &str) -> A2AResult<Self> {
+        let client = redis::Client::open(url).map_err(redis_error)?;
+        let connection = redis::aio::ConnectionManager::new(client)
+            .await
+            .map_err(redis_error)?;
+        Ok(Self {
+            connection,
+            key_prefix: String::new(),
+        })
+    }
+
+    /// Prepend `prefix` to every key (builder-style).
+    pub fn with_key_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.key_prefix = prefix.into();
+        self
+    }
+
+    fn key(&self, key: &str) -> String {
+        format!("{}{key}", self.key_prefix)
+    }
+}
+
+#[cfg(feature = "kv-redis")]
+fn redis_error(e: redis::RedisError) -> A2AError {
+    A2AError::internal_error(format!("redis: {e}"))
+}
+
+/// Escape the glob characters of `s` for a `SCAN ... MATCH` pattern.
+#[cfg(feature = "kv-redis")]
+fn escape_glob(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        if matches!(c, '*' | '?' | '[' | ']' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+#[cfg(feature = "kv-redis")]
+#[async_trait]
+impl KvStore for RedisKvStore {
+    async fn get(&self, key: &str) -> A2AResult<Option<Vec<u8>>> {
+        redis::cmd("GET")
+            .arg(self.key(key))
+            .query_async(&mut self.connection.clone())
+            .await
+            .map_err(redis_error)
+    }
+
+    async fn put(&self, key: &str, value: Vec<u8>) -> A2AResult<()> {
+        redis::cmd("SET")
+            .arg(self.key(key))
+            .arg(value)
+            .query_async(&mut self.connection.clone())
+            .await
+            .map_err(redis_error)
+    }
+
+    async fn delete(&self, key: &str) -> A2AResult<()> {
+        redis::cmd("DEL")
+            .arg(self.key(key))
+            .query_async(&mut self.connection.clone())
+            .await
+            .map_err(redis_error)
+    }
+
+    async fn scan_prefix(&self, prefix: &str) -> A2AResult<Vec<(String, Vec<u8>)>> {
+        let mut connection = self.connection.clone();
+        let pattern = format!("{}*", escape_glob(&self.key(prefix)));
+        let mut keys: Vec<String> = Vec::new();
+        let mut cursor = 0u64;
+        loop {
+            let (next, batch): (u64, Vec<String>) = redis::cmd("SCAN")
+                .arg(cursor)
+                .arg("MATCH")
+                .arg(&pattern)
+                .arg("COUNT")
+                .arg(500)
+                .query_async(&mut connection)
+                .await
+                .map_err(redis_error)?;
+            keys.extend(batch);
+            if next == 0 {
+                break;
+            }
+            cursor = next;
+        }
+        // SCAN may return a key more than once.
+        keys.sort();
+        keys.dedup();
+        if keys.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let values: Vec<Option<Vec<u8>>> = redis::cmd("MGET")
+            .arg(&keys)
+            .query_async(&mut connection)
+            .await
+            .map_err(redis_error)?;
+        Ok(keys
+            .into_iter()
+            .zip(values)
+            // Keys deleted between SCAN and MGET come back as nil.
+            .filter_map(|(key, value)| {
+                let key = key[self.key_prefix.len()..].to_string();
+                value.map(|value| (key, value))
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,237 @@
+//! Task lifecycle callbacks — side effects on every task change.
+//!
+//! A [`TaskLifecycleListener`] registered with
+//! [`DefaultRequestHandler::with_lifecycle_listener`] (or
+//! [`ServerBuilder::with_listener`]) hears about every task the handler
+//! creates, each state change, each artifact added and the task reaching a
+//! terminal state — the place for metrics, notifications or billing.
+//!
+//! Listeners run in the background after the change was stored: each has
+//! its own queue, so a slow listener never holds up task processing or
+//! other listeners, and sees a task's events in order. A listener that
+//! returns an error or panics is logged and keeps receiving later events.
+//!
+//! ```rust,ignore
+//! struct Billing;
+//!
+//! #[async_trait::async_trait]
+//! impl TaskLifecycleListener for Billing {
+//!     async fn on_terminal(&self, task: &Task) -> A2AResult<()> {
+//!         charge(&task.id, task.status.state).await
+//!     }
+//! }
+//!
+//! let handler = DefaultRequestHandler::new(executor, store)
+//!     .with_lifecycle_listener(Arc::new(Billing));
+//! ```
+//!
+//! [`DefaultRequestHandler::with_lifecycle_listener`]: super::DefaultRequestHandler::with_lifecycle_listener
+//! [`ServerBuilder::with_listener`]: crate::builders::ServerBuilder::with_listener
+
+use std::sync::{Arc, OnceLock};
+
+use async_trait::async_trait;
+use tokio::sync::mpsc;
+use tracing::{error, warn};
+
+use crate::error::A2AResult;
+use crate::types::{Task, TaskArtifactUpdateEvent, TaskState};
+
+/// Receives task lifecycle callbacks. Every method defaults to doing
+/// nothing; implement those you need.
+///
+/// Each callback gets the task as stored after the change.
+#[async_trait]
+pub trait TaskLifecycleListener: Send + Sync {
+    /// A task was created.
+    async fn on_task_created(&self, _task: &Task) -> A2AResult<()> {
+        Ok(())
+    }
+
+    /// The task moved from state `from` to `task.status.state`.
+    async fn on_state_changed(&self, _task: &Task, _from: TaskState) -> A2AResult<()> {
+        Ok(())
+    }
+
+    /// `event` added an artifact to the task, or a chunk to one.
+    async fn on_artifact_added(
+        &self,
+        _task: &Task,
+        _event: &TaskArtifactUpdateEvent,
+    ) -> A2AResult<()> {
+        Ok(())
+    }
+
+    /// The task reached a terminal state. Follows the
+    /// [`on_state_changed`](Self::on_state_changed) (or
+    /// [`on_task_created`](Self::on_task_created)) call for it.
+    async fn on_terminal(&self, _task: &Task) -> A2AResult<()> {
+        Ok(())
+    }
+}
+
+/// A change delivered to [`TaskLifecycleListener`]s.
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone)]
+pub enum TaskLifecycleEvent {
+    /// See [`TaskLifecycleListener::on_task_created`].
+    Created(Task),
+    /// See [`TaskLifecycleListener::on_state_changed`].
+    StateChanged {
+        /// The task after the change.
+        task: Task,
+        /// The state before the change.
+        from: TaskState,
+    },
+    /// See [`TaskLifecycleListener::on_artifact_added`].
+    ArtifactAdded {
+        /// The task after the change.
+        task: Task,
+        /// The event that added the artifact.
+        event: TaskArtifactUpdateEvent,
+    },
+    /// See [`TaskLifecycleListener::on_terminal`].
+    Terminal(Task),
+}
+
+impl TaskLifecycleEvent {
+    /// The task after the change.
+    pub fn task(&self) -> &Task {
+        match self {
+            Self::Created(task) | Self::Terminal(task) => task,
+            Self::StateChanged { task, .. } | Self::ArtifactAdded { task, .. } => task,
+        }
+    }
+
+    /// Name of the callback, for logging.
+    fn callback(&self) -> &'static str {
+        match self {
+            Self::Created(_) => "on_task_created",
+            Self::StateChanged { .. } => "on_state_changed",
+            Self::ArtifactAdded { .. } => "on_artifact_added",
+            Self::Terminal(_) => "on_terminal",
+        }
+    }
+
+    /// Call the matching method of `listener`.
+    async fn deliver(&self, listener: &dyn TaskLifecycleListener) -> A2AResult<()> {
+        match self {
+            Self::Created(task) => listener.on_task_created(task).await,
+            Self::StateChanged { task, from } => listener.on_state_changed(task, *from).await,
+            Self::ArtifactAdded { task, event } => listener.on_artifact_added(task, event).await,
+            Self::Terminal(task) => listener.on_terminal(task).await,
+        }
+    }
+}
+
+/// The registered [`TaskLifecycleListener`]s, each fed by its own queue.
+///
+/// Clones share the listeners and their queues.
+#[derive(Clone, Default)]
+pub struct TaskLifecycleListeners {
+    workers: Vec<Arc<Worker>>,
+}
+
+/// One listener and the queue of its delivery task.
+struct Worker {
+    listener: Arc<dyn TaskLifecycleListener>,
+    /// Started on the first event, which is always emitted on a runtime.
+    queue: OnceLock<mpsc::UnboundedSender<TaskLifecycleEvent>>,
+}
+
+impl std::fmt::Debug for TaskLifecycleListeners {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TaskLifecycleListeners")
+            .field("listeners", &self.workers.len())
+            .finish()
+    }
+}
+
+impl TaskLifecycleListeners {
+    /// No listeners.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add `listener` (builder-style).
+    pub fn with(mut self, listener: Arc<dyn TaskLifecycleListener>) -> Self {
+        self.workers.push(Arc::new(Worker {
+            listener,
+            queue: OnceLock::new(),
+        }));
+        self
+    }
+
+    /// Whether no listener is registered.
+    pub fn is_empty(&self) -> bool {
+        self.workers.is_empty()
+    }
+
+    /// Queue `event` for every listener. Must be called on a Tokio
+    /// runtime.
+    pub fn emit(&self, event: TaskLifecycleEvent) {
+        for worker in &self.workers {
+            let queue = worker.queue.get_or_init(|| spawn_worker(&worker.listener));
+            // The delivery task only ends when the queue closes.
+            let _ = queue.send(event.clone());
+        }
+    }
+
+    /// Emit the events for `task` having been stored, given its state
+    /// before (`None` if it was just created).
+    pub(crate) fn task_saved(&self, before: Option<TaskState>, task: &Task) {
+        if self.is_empty() {
+            return;
+        }
+        let state = task.status.state;
+        match before {
+            None => self.emit(TaskLifecycleEvent::Created(task.clone())),
+            Some(from) if from != state => self.emit(TaskLifecycleEvent::StateChanged {
+                task: task.clone(),
+                from,
+            }),
+            Some(_) => return,
+        }
+        if state.is_terminal() && !before.is_some_and(|from| from.is_terminal()) {
+            self.emit(TaskLifecycleEvent::Terminal(task.clone()));
+        }
+    }
+
+    /// Emit [`TaskLifecycleEvent::ArtifactAdded`] for `event` on `task`.
+    pub(crate) fn artifact_added(&self, task: &Task, event: &TaskArtifactUpdateEvent) {
+        if self.is_empty() {
+            return;
+        }
+        self.emit(TaskLifecycleEvent::ArtifactAdded {
+            task: task.clone(),
+            event: event.clone(),
+        });
+    }
+}
+
+/// Start delivering events to `listener`, one at a time. Each call runs
+/// as its own task so a panic is contained to that event.
+fn spawn_worker(
+    listener: &Arc<dyn TaskLifecycleListener>,
+) -> mpsc::UnboundedSender<TaskLifecycleEvent> {
+    let (tx, mut rx) = mpsc::unbounded_channel::<TaskLifecycleEvent>();
+    let listener = Arc::clone(listener);
+    tokio::spawn(async move {
+        while let Some(event) = rx.recv().await {
+            let callback = event.callback();
+            let task_id = event.task().id.clone();
+            let listener = Arc::clone(&listener);
+            let delivery = tokio::spawn(async move { event.deliver(listener.as_ref()).await });
+            match delivery.await {
+                Ok(Ok(())) => {}
+                Ok(Err(e)) => {
+                    warn!(task_id = %task_id, callback, error = %e, "Task lifecycle listener failed")
+                }
+                Err(e) => {
+                    error!(task_id = %task_id, callback, error = %e, "Task lifecycle listener panicked")
+                }
+            }
+        }
+    });
+    tx
+}
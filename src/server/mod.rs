@@ -3,16 +3,68 @@
 //! Mirrors the Python SDK's server module architecture:
 //!
 //! - [`AgentExecutor`] trait — implement your agent logic
+//! - [`SkillRouter`] — one card, several executors: dispatch by skill ID
+//!   or another metadata value, with a fallback and input-mode checks
+//! - `#[skills]` — derive an [`AgentExecutor`] dispatching to one method
+//!   per skill, plus the card's skills list (`macros` feature)
 //! - [`RequestContext`] — execution context with task IDs, message, metadata
+//! - [`IncrementalMessage`] — a message whose parts the client is still
+//!   uploading (`message/sendStream`)
 //! - [`ServerCallContext`] — per-request context with extensions and state
 //! - [`RequestContextBuilder`] trait + [`SimpleRequestContextBuilder`] — build contexts
 //! - [`TaskStore`] trait + [`InMemoryTaskStore`] — task persistence
+//! - [`TaskSweeper`] + [`RetentionPolicy`] — purge tasks that finished long
+//!   ago, and cap how many tasks a store holds
+//! - [`KvStore`] trait + [`InMemoryKvStore`] — key-value backend shared by
+//!   [`KvTaskStore`], [`KvSubscriptionRegistry`] and message dedup; sled and
+//!   Redis backends with the `kv-sled` / `kv-redis` features
 //! - [`TaskUpdater`] — thread-safe task state transition helper
-//! - [`EventQueue`] — broadcast channel for streaming events
+//! - [`ArtifactWriter`] — one artifact written incrementally, chunked and
+//!   flagged for the client
+//! - `Delegator` — orchestrators delegating sub-tasks to other agents, with
+//!   context forwarding, cancellation and artifacts republished locally
+//!   (`client` feature)
+//! - [`Workspace`] — per-task scratch directory, cleaned up when the task ends
+//! - [`ObservedTaskStore`] + [`TaskStateGauges`] — task-per-state gauges in
+//!   OpenMetrics format, served by [`openmetrics_router`]
+//! - [`ContextStore`] + [`InMemoryContextStore`] — the tasks of each
+//!   conversation (`contextId`)
+//! - [`SubscriptionRegistry`] + [`FileSubscriptionRegistry`] — open task
+//!   streams, recovered after a restart
+//! - [`EventStore`] + [`InMemoryEventStore`] / [`FileEventStore`] — every
+//!   event of a task, replayed to `tasks/subscribe` before live ones
+//! - [`PushNotificationConfigStore`] + [`InMemoryPushNotificationConfigStore`]
+//!   — webhooks registered with `tasks/pushNotificationConfig/*`, their
+//!   secrets optionally encrypted by [`EncryptedPushNotificationConfigStore`]
+//! - [`EventQueue`] — broadcast channel for streaming events, with an
+//!   [`OverflowPolicy`] for subscribers that fall behind
 //! - [`QueueManager`] trait + [`InMemoryQueueManager`] — per-task queue management
 //! - [`EventConsumer`] — consumes events from a queue (one-shot or streaming)
 //! - [`RequestHandler`] trait + [`DefaultRequestHandler`] — JSON-RPC dispatch
+//! - [`ServerInterceptor`] — hooks before and after every method of
+//!   [`DefaultRequestHandler`] for audit logging, quotas and rejecting
+//!   requests
+//! - [`TaskLifecycleListener`] — callbacks on task creation, state changes,
+//!   artifacts and terminal states, run in the background per listener
+//! - [`ExecutorErrorReporter`] + [`TracingErrorReporter`] — task, skill,
+//!   redacted message excerpt and backtrace of failed or panicked
+//!   executions; `SentryErrorReporter` with the `sentry` feature
+//! - [`OutputAdapter`] — transcode, drop or reject output the client's
+//!   `acceptedOutputModes` excludes
+//! - [`Authenticator`] + [`SecuritySchemeAuthenticator`] — check callers
+//!   against the card's security schemes and expose the
+//!   [`AuthenticatedUser`] to executors
+//! - [`RateLimiter`] — token-bucket limits per IP, API key, tenant or custom
+//!   key, answered with `429` and a `-32029` JSON-RPC error
 //! - [`a2a_router`] — ready-made axum routes for A2A servers
+//! - [`chat_router`] — OpenAI-style `POST /chat` (JSON or SSE) over the
+//!   same handler, for non-A2A frontends
+//! - `GrpcService` — the same handler over the A2A gRPC binding (`grpc`
+//!   feature)
+//! - `DevAgent` — configurable reference agent behind the `a2a-dev-agent`
+//!   binary (`dev-agent` feature)
+//! - `MetricsRecorder` + `OpenMetricsRecorder` — task state, duration and
+//!   SSE subscriber metrics (`observability` feature)
 //!
 //! # Quick start
 //!
@@ -53,27 +105,124 @@
 //! ```
 
 pub mod agent_executor;
+pub mod artifact_stream;
+pub mod artifact_writer;
+pub mod auth;
 pub mod axum_integration;
+pub mod chat;
+pub mod content_validation;
+pub mod context_store;
+#[cfg(feature = "client")]
+pub mod delegation;
+#[cfg(feature = "dev-agent")]
+pub mod dev_agent;
+pub mod error_reporter;
 pub mod event_queue;
+pub mod event_store;
+#[cfg(feature = "grpc")]
+pub mod grpc_service;
+pub mod incremental;
+pub mod interceptor;
+pub mod kv_store;
+pub mod lifecycle;
+pub mod output_modes;
+pub mod push_config_store;
+pub mod rate_limit;
 pub mod request_handler;
+pub mod retention;
+pub mod skill_router;
+pub mod subscriptions;
 pub mod task_manager;
+pub mod task_metrics;
 pub mod task_store;
 pub mod task_updater;
+#[cfg(feature = "observability")]
+pub mod telemetry;
+pub mod workspace;
 
 // Re-export key types at the server module level for convenience.
 pub use crate::types::SendMessageResponse;
+#[cfg(feature = "macros")]
+pub use a2a_rs_macros::skills;
 pub use agent_executor::{
-    AgentExecutor, RequestContext, RequestContextBuilder, ServerCallContext,
+    AgentExecutor, HttpRequestInfo, RequestContext, RequestContextBuilder, ServerCallContext,
     SimpleRequestContextBuilder,
 };
-pub use axum_integration::a2a_router;
+pub use artifact_stream::{ArtifactStream, PartsReader};
+pub use artifact_writer::{ArtifactWriter, DEFAULT_ARTIFACT_CHUNK_SIZE};
+#[cfg(feature = "client")]
+pub use auth::OAuth2Introspection;
+pub use auth::{
+    AuthRequest, AuthenticatedUser, Authenticator, CredentialValidator,
+    SecuritySchemeAuthenticator, StaticCredentials,
+};
+pub use axum_integration::{
+    a2a_router, a2a_router_with_config, a2a_router_with_sse_config, MultiAgentRouter, RouterConfig,
+    SseConfig,
+};
+pub use chat::chat_router;
+pub use content_validation::{AllowAllContent, ContentLimits, ContentValidator, ContentViolation};
+pub use context_store::{ContextStore, ConversationContext, InMemoryContextStore, KvContextStore};
+#[cfg(feature = "client")]
+pub use delegation::Delegator;
+#[cfg(feature = "dev-agent")]
+pub use dev_agent::{DevAgent, DevAgentConfig, DevAgentMode};
+#[cfg(feature = "sentry")]
+pub use error_reporter::SentryErrorReporter;
+pub use error_reporter::{
+    redact_excerpt, ExcerptRedactor, ExecutorErrorReport, ExecutorErrorReporter,
+    ExecutorFailureKind, TracingErrorReporter, MESSAGE_EXCERPT_LIMIT, SKILL_ID_METADATA_KEY,
+};
 pub use event_queue::{
-    EventConsumer, EventQueue, InMemoryQueueManager, NoTaskQueue, QueueManager, TaskQueueExists,
+    gap_missed, EventConsumer, EventQueue, EventReceiver, InMemoryQueueManager, NoTaskQueue,
+    OverflowPolicy, QueueManager, TaskQueueExists, GAP_METADATA_KEY,
 };
+pub use event_store::{EventStore, FileEventStore, InMemoryEventStore, StoredEvent};
+#[cfg(feature = "grpc")]
+pub use grpc_service::GrpcService;
+pub use incremental::{IncrementalMessage, IncrementalMessageSender};
+pub use interceptor::{InterceptedRequest, MethodParams, ServerInterceptor};
+#[cfg(feature = "kv-redis")]
+pub use kv_store::RedisKvStore;
+#[cfg(feature = "kv-sled")]
+pub use kv_store::SledKvStore;
+pub use kv_store::{InMemoryKvStore, KvStore};
+pub use lifecycle::{TaskLifecycleEvent, TaskLifecycleListener, TaskLifecycleListeners};
+pub use output_modes::{JsonEnvelope, OutputAdapter, UnsupportedOutput};
+#[cfg(feature = "push-secrets")]
+pub use push_config_store::AesGcmSecretCipher;
+#[cfg(feature = "push-sqlite")]
+pub use push_config_store::SqlitePushNotificationConfigStore;
+pub use push_config_store::{
+    EncryptedPushNotificationConfigStore, InMemoryPushNotificationConfigStore,
+    KvPushNotificationConfigStore, PushNotificationConfigStore, SecretCipher, SecretContext,
+};
+pub use rate_limit::{CallerIdentity, RateLimit, RateLimitRequest, RateLimitScope, RateLimiter};
 pub use request_handler::{
-    CancelTaskParams, DefaultRequestHandler, GetTaskParams, RequestHandler,
-    SendMessageConfiguration, SendMessageParams, SubscribeToTaskParams,
+    CancelTaskParams, DefaultRequestHandler, ExpiryOutcome, GetTaskParams, InputRequiredExpiry,
+    RequestHandler, SendMessageConfiguration, SendMessageParams, SubscribeToTaskParams,
+};
+pub use retention::{
+    RetentionPolicy, SweepReport, TaskSweeper, EVICTION_CAPACITY, EVICTION_EXPIRED,
+};
+pub use skill_router::SkillRouter;
+pub use subscriptions::{
+    FileSubscriptionRegistry, InMemorySubscriptionRegistry, KvSubscriptionRegistry,
+    SubscriptionRecord, SubscriptionRegistry,
+};
+pub use task_manager::{
+    append_artifact_to_task, push_history, HistoryPolicy, TaskEvent, TaskManager,
+};
+pub use task_metrics::{
+    openmetrics_router, ObservedTaskStore, TaskStateGauge, TaskStateGauges, TaskStoreChange,
+    TaskStoreObserver, OPENMETRICS_CONTENT_TYPE,
+};
+pub use task_store::{
+    check_tenant, finished_before, set_task_tenant, task_tenant, InMemoryTaskStore, KvTaskStore,
+    TaskListParams, TaskListResponse, TaskStore, TaskUpdate, VersionedTask, TENANT_METADATA_KEY,
 };
-pub use task_manager::{append_artifact_to_task, TaskEvent, TaskManager};
-pub use task_store::{InMemoryTaskStore, TaskListParams, TaskListResponse, TaskStore};
 pub use task_updater::TaskUpdater;
+#[cfg(feature = "observability")]
+pub use telemetry::{metrics_router, MetricsRecorder, OpenMetricsRecorder};
+pub use tokio_util::sync::CancellationToken;
+pub use workspace::Workspace;
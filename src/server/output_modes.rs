@@ -0,0 +1,293 @@
+//! Enforcement of the client's `acceptedOutputModes`.
+//!
+//! A client may restrict the media types it accepts with
+//! `configuration.acceptedOutputModes` on `message/send` and
+//! `message/stream`. When a [`DefaultRequestHandler`] is given an
+//! [`OutputAdapter`] (see
+//! [`DefaultRequestHandler::with_output_adapter`]), every part the executor
+//! emits — in messages, status messages and artifacts — whose mode isn't
+//! accepted is handed to the adapter, which can transcode it, drop it, or
+//! reject it with [`A2AError::ContentTypeNotSupported`].
+//!
+//! A rejection fails the task: the handler publishes a final `failed`
+//! status whose metadata carries the JSON-RPC error under `"error"`, and
+//! `message/send` returns the error itself (code `-32005`).
+//!
+//! The input side is checked against the agent card: with
+//! [`DefaultRequestHandler::with_content_negotiation`], a message part whose
+//! mode isn't among the requested skill's `inputModes` (the skill named by
+//! `skillId` metadata), or else the card's `defaultInputModes`, is rejected
+//! with [`A2AError::ContentTypeNotSupported`] before a task is created. The
+//! same call drops unaccepted output unless an adapter is set.
+//!
+//! Part modes are `text/plain` for text parts, `application/json` for data
+//! parts and the declared MIME type (default `application/octet-stream`)
+//! for file parts. Accepted modes may use wildcards (`*/*`, `text/*`); an
+//! empty or absent list accepts everything.
+//!
+//! ```rust,ignore
+//! let handler = DefaultRequestHandler::new(executor, store)
+//!     .with_output_adapter(Arc::new(JsonEnvelope::new(UnsupportedOutput::Reject)));
+//! ```
+//!
+//! [`DefaultRequestHandler`]: super::DefaultRequestHandler
+//! [`DefaultRequestHandler::with_output_adapter`]: super::DefaultRequestHandler::with_output_adapter
+//! [`DefaultRequestHandler::with_content_negotiation`]: super::DefaultRequestHandler::with_content_negotiation
+
+use std::collections::HashMap;
+
+use serde_json::{json, Value};
+
+use crate::error::{A2AError, A2AResult};
+use crate::types::{AgentCard, FileContent, Message, Part, StreamResponse};
+
+use super::error_reporter::SKILL_ID_METADATA_KEY;
+
+/// Media type of text parts.
+pub const TEXT_MODE: &str = "text/plain";
+
+/// Media type of data parts.
+pub const JSON_MODE: &str = "application/json";
+
+/// Media type of file parts without a declared MIME type.
+pub const BINARY_MODE: &str = "application/octet-stream";
+
+/// Converts parts the client didn't accept.
+///
+/// Only called for parts whose [`part_mode`] isn't in `accepted`, which is
+/// never empty. Return `Ok(Some(part))` to emit a (transcoded) part,
+/// `Ok(None)` to drop it, or an error — normally
+/// [`A2AError::ContentTypeNotSupported`] — to fail the task.
+///
+/// Transcoded parts are emitted as returned; they are not checked again.
+pub trait OutputAdapter: Send + Sync {
+    /// Adapt `part` for a client accepting only `accepted`.
+    fn adapt(&self, part: Part, accepted: &[String]) -> A2AResult<Option<Part>>;
+}
+
+/// What to do with a part that can't be transcoded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UnsupportedOutput {
+    /// Fail the task with [`A2AError::ContentTypeNotSupported`].
+    #[default]
+    Reject,
+    /// Silently leave the part out.
+    Drop,
+}
+
+impl OutputAdapter for UnsupportedOutput {
+    fn adapt(&self, part: Part, accepted: &[String]) -> A2AResult<Option<Part>> {
+        match self {
+            UnsupportedOutput::Drop => Ok(None),
+            UnsupportedOutput::Reject => Err(not_accepted(&part, accepted)),
+        }
+    }
+}
+
+/// Wraps text parts in a JSON envelope, `{"text": "..."}`, for clients that
+/// accept `application/json` but not `text/plain`.
+///
+/// Other unaccepted parts are handled by the fallback.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct JsonEnvelope {
+    fallback: UnsupportedOutput,
+}
+
+impl JsonEnvelope {
+    /// Envelope text parts; apply `fallback` to everything else.
+    pub fn new(fallback: UnsupportedOutput) -> Self {
+        Self { fallback }
+    }
+}
+
+impl OutputAdapter for JsonEnvelope {
+    fn adapt(&self, part: Part, accepted: &[String]) -> A2AResult<Option<Part>> {
+        match part {
+            Part::Text { text, metadata } if mode_accepted(JSON_MODE, accepted) => {
+                Ok(Some(Part::Data {
+                    data: json!({ "text": text }),
+                    metadata,
+                }))
+            }
+            part => self.fallback.adapt(part, accepted),
+        }
+    }
+}
+
+/// The media type of `part`.
+pub fn part_mode(part: &Part) -> &str {
+    match part {
+        Part::Text { .. } => TEXT_MODE,
+        Part::Data { .. } => JSON_MODE,
+        Part::File { file, .. } => {
+            let mime_type = match file {
+                FileContent::Bytes(f) => f.mime_type.as_deref(),
+                FileContent::Uri(f) => f.mime_type.as_deref(),
+            };
+            mime_type.unwrap_or(BINARY_MODE)
+        }
+    }
+}
+
+/// Whether `mode` matches one of `accepted`. Comparison ignores case and
+/// media type parameters (`; charset=utf-8`); `*/*` and `type/*` match by
+/// wildcard, and an empty list accepts everything.
+pub fn mode_accepted(mode: &str, accepted: &[String]) -> bool {
+    if accepted.is_empty() {
+        return true;
+    }
+    let essence = |m: &str| {
+        m.split(';')
+            .next()
+            .unwrap_or("")
+            .trim()
+            .to_ascii_lowercase()
+    };
+    let mode = essence(mode);
+    let main_type = mode.split('/').next().unwrap_or("");
+    accepted.iter().any(|candidate| {
+        let candidate = essence(candidate);
+        candidate == mode
+            || candidate == "*/*"
+            || candidate
+                .strip_suffix("/*")
+                .is_some_and(|prefix| prefix == main_type)
+    })
+}
+
+/// The input modes of an agent card: its defaults and each skill's own.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct InputModes {
+    defaults: Vec<String>,
+    skills: HashMap<String, Vec<String>>,
+}
+
+impl InputModes {
+    pub(crate) fn from_card(card: &AgentCard) -> Self {
+        Self {
+            defaults: card.default_input_modes.clone(),
+            skills: card
+                .skills
+                .iter()
+                .filter_map(|skill| Some((skill.id.clone(), skill.input_modes.clone()?)))
+                .collect(),
+        }
+    }
+
+    /// Check the parts of `message` against the modes of the skill it
+    /// names (in its own metadata or `request_metadata`), or the defaults.
+    pub(crate) fn check(
+        &self,
+        message: &Message,
+        request_metadata: Option<&Value>,
+    ) -> A2AResult<()> {
+        let skill = [message.metadata.as_ref(), request_metadata]
+            .into_iter()
+            .flatten()
+            .find_map(|metadata| metadata.get(SKILL_ID_METADATA_KEY)?.as_str());
+        let (accepted, accepter) = match skill.and_then(|id| Some((id, self.skills.get(id)?))) {
+            Some((id, modes)) => (modes, format!("Skill '{id}'")),
+            None => (&self.defaults, "Agent".to_string()),
+        };
+        match message
+            .parts
+            .iter()
+            .map(part_mode)
+            .find(|mode| !mode_accepted(mode, accepted))
+        {
+            Some(mode) => Err(A2AError::ContentTypeNotSupported {
+                message: format!(
+                    "{accepter} does not accept '{mode}' input (accepts: {})",
+                    accepted.join(", ")
+                ),
+                data: Some(json!({
+                    "mimeType": mode,
+                    "inputModes": accepted,
+                })),
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Run `parts` through `adapter`, keeping accepted parts as they are.
+pub(crate) fn adapt_parts(
+    adapter: &dyn OutputAdapter,
+    parts: Vec<Part>,
+    accepted: &[String],
+) -> A2AResult<Vec<Part>> {
+    let mut adapted = Vec::with_capacity(parts.len());
+    for part in parts {
+        if mode_accepted(part_mode(&part), accepted) {
+            adapted.push(part);
+        } else if let Some(part) = adapter.adapt(part, accepted)? {
+            adapted.push(part);
+        }
+    }
+    Ok(adapted)
+}
+
+fn not_accepted(part: &Part, accepted: &[String]) -> A2AError {
+    let mode = part_mode(part);
+    A2AError::ContentTypeNotSupported {
+        message: format!(
+            "Agent produced '{mode}' output, but the client accepts only {}",
+            accepted.join(", ")
+        ),
+        data: Some(json!({
+            "mimeType": mode,
+            "acceptedOutputModes": accepted,
+        })),
+    }
+}
+
+/// Run the agent-produced parts of `event` through `adapter`.
+///
+/// Returns `None` if nothing is left of the event: an artifact update or
+/// message whose parts were all dropped. Status messages that lose all
+/// parts are removed from the status instead.
+pub(crate) fn adapt_event(
+    adapter: &dyn OutputAdapter,
+    event: StreamResponse,
+    accepted: &[String],
+) -> A2AResult<Option<StreamResponse>> {
+    let adapt_message = |mut message: Message| -> A2AResult<Option<Message>> {
+        message.parts = adapt_parts(adapter, std::mem::take(&mut message.parts), accepted)?;
+        Ok((!message.parts.is_empty()).then_some(message))
+    };
+    Ok(match event {
+        StreamResponse::Message(message) => adapt_message(message)?.map(StreamResponse::Message),
+        StreamResponse::StatusUpdate(mut update) => {
+            update.status.message = update
+                .status
+                .message
+                .map(adapt_message)
+                .transpose()?
+                .flatten();
+            Some(StreamResponse::StatusUpdate(update))
+        }
+        StreamResponse::ArtifactUpdate(mut update) => {
+            update.artifact.parts = adapt_parts(
+                adapter,
+                std::mem::take(&mut update.artifact.parts),
+                accepted,
+            )?;
+            (!update.artifact.parts.is_empty()).then_some(StreamResponse::ArtifactUpdate(update))
+        }
+        StreamResponse::Task(mut task) => {
+            task.status.message = task
+                .status
+                .message
+                .map(adapt_message)
+                .transpose()?
+                .flatten();
+            if let Some(artifacts) = &mut task.artifacts {
+                for artifact in artifacts.iter_mut() {
+                    artifact.parts =
+                        adapt_parts(adapter, std::mem::take(&mut artifact.parts), accepted)?;
+                }
+            }
+            Some(StreamResponse::Task(task))
+        }
+    })
+}
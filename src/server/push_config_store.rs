@@ -0,0 +1,704 @@
+//! Push notification config store — where `tasks/pushNotificationConfig/*`
+//! keeps the webhooks clients register for their tasks.
+//!
+//! [`DefaultRequestHandler`] serves the four push config methods once given
+//! a store with
+//! [`with_push_config_store()`](super::DefaultRequestHandler::with_push_config_store);
+//! without one they answer `UnsupportedOperation`. A config passed in
+//! `message/send`'s `configuration.pushNotificationConfig` is stored the
+//! same way as one set with `tasks/pushNotificationConfig/set`.
+//!
+//! Configs are keyed by task ID and config ID. A config set without an ID
+//! gets the task ID, so a task has at most one such config and `get`
+//! without a config ID finds it. `tasks/pushNotificationConfig/list` pages
+//! through a task's configs in config ID order when given a `pageSize` or
+//! `pageToken`.
+//!
+//! Provided stores:
+//!
+//! - [`InMemoryPushNotificationConfigStore`] — lost on restart
+//! - [`KvPushNotificationConfigStore`] — in any [`KvStore`], e.g. sled or
+//!   Redis
+//! - `SqlitePushNotificationConfigStore` — a SQLite table (`push-sqlite`
+//!   feature)
+//!
+//! Webhook tokens and credentials are stored as given unless the store is
+//! wrapped in an [`EncryptedPushNotificationConfigStore`], which encrypts
+//! them with a [`SecretCipher`] such as `AesGcmSecretCipher`
+//! (`push-secrets` feature):
+//!
+//! ```rust,ignore
+//! let configs = SqlitePushNotificationConfigStore::open("push.db")?;
+//! let cipher = AesGcmSecretCipher::from_base64(&std::env::var("PUSH_SECRET_KEY")?)?;
+//! let handler = DefaultRequestHandler::new(executor, store).with_push_config_store(Arc::new(
+//!     EncryptedPushNotificationConfigStore::new(Arc::new(configs), Arc::new(cipher)),
+//! ));
+//! ```
+//!
+//! [`DefaultRequestHandler`]: super::DefaultRequestHandler
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+#[cfg(any(feature = "push-secrets", feature = "push-sqlite"))]
+use crate::error::A2AError;
+use crate::error::A2AResult;
+use crate::types::{ListTaskPushNotificationConfigResponse, TaskPushNotificationConfig};
+
+use super::kv_store::{self, InMemoryKvStore, KvStore};
+
+/// Persistence for [`TaskPushNotificationConfig`]s.
+///
+/// Every config passed in is normalised by the handler:
+/// `push_notification_config.id` is set, and it is the config ID used by
+/// [`get()`](Self::get) and [`delete()`](Self::delete).
+#[async_trait]
+pub trait PushNotificationConfigStore: Send + Sync {
+    /// Insert or replace the config with `config.task_id` and
+    /// `config.push_notification_config.id`.
+    async fn set(&self, config: TaskPushNotificationConfig) -> A2AResult<()>;
+
+    /// The config `config_id` of `task_id`, if any.
+    async fn get(
+        &self,
+        task_id: &str,
+        config_id: &str,
+    ) -> A2AResult<Option<TaskPushNotificationConfig>>;
+
+    /// All configs of `task_id`, ordered by config ID.
+    async fn list(&self, task_id: &str) -> A2AResult<Vec<TaskPushNotificationConfig>>;
+
+    /// Remove the config `config_id` of `task_id`. Silently succeeds if
+    /// there is none.
+    async fn delete(&self, task_id: &str, config_id: &str) -> A2AResult<()>;
+
+    /// Up to `page_size` configs of `task_id` whose config ID sorts after
+    /// `page_token`, ordered by config ID, with the token of the next page
+    /// if there are more.
+    ///
+    /// The default implementation pages through [`list()`](Self::list).
+    async fn list_page(
+        &self,
+        task_id: &str,
+        page_size: usize,
+        page_token: Option<&str>,
+    ) -> A2AResult<ListTaskPushNotificationConfigResponse> {
+        let configs = self.list(task_id).await?;
+        Ok(page(configs, page_size, page_token))
+    }
+}
+
+/// The ID a config is stored under: its own, or the task ID.
+fn config_id(config: &TaskPushNotificationConfig) -> &str {
+    config
+        .push_notification_config
+        .id
+        .as_deref()
+        .unwrap_or(&config.task_id)
+}
+
+/// The page of `configs`, ordered by config ID, after `page_token`.
+fn page(
+    configs: Vec<TaskPushNotificationConfig>,
+    page_size: usize,
+    page_token: Option<&str>,
+) -> ListTaskPushNotificationConfigResponse {
+    let mut configs: Vec<_> = configs
+        .into_iter()
+        .filter(|config| page_token.map_or(true, |token| config_id(config) > token))
+        .collect();
+    configs.sort_by(|a, b| config_id(a).cmp(config_id(b)));
+    let next_page_token = if configs.len() > page_size {
+        configs.truncate(page_size);
+        configs.last().map(|config| config_id(config).to_string())
+    } else {
+        None
+    };
+    ListTaskPushNotificationConfigResponse {
+        configs,
+        next_page_token,
+    }
+}
+
+#[async_trait]
+impl<T: PushNotificationConfigStore + ?Sized> PushNotificationConfigStore for Arc<T> {
+    async fn set(&self, config: TaskPushNotificationConfig) -> A2AResult<()> {
+        (**self).set(config).await
+    }
+
+    async fn get(
+        &self,
+        task_id: &str,
+        config_id: &str,
+    ) -> A2AResult<Option<TaskPushNotificationConfig>> {
+        (**self).get(task_id, config_id).await
+    }
+
+    async fn list(&self, task_id: &str) -> A2AResult<Vec<TaskPushNotificationConfig>> {
+        (**self).list(task_id).await
+    }
+
+    async fn delete(&self, task_id: &str, config_id: &str) -> A2AResult<()> {
+        (**self).delete(task_id, config_id).await
+    }
+
+    async fn list_page(
+        &self,
+        task_id: &str,
+        page_size: usize,
+        page_token: Option<&str>,
+    ) -> A2AResult<ListTaskPushNotificationConfigResponse> {
+        (**self).list_page(task_id, page_size, page_token).await
+    }
+}
+
+/// Key prefix of push notification configs in a [`KvStore`].
+const PUSH_CONFIG_KEY_PREFIX: &str = "push-config/";
+
+/// [`PushNotificationConfigStore`] on top of a [`KvStore`]: each config is
+/// a JSON value under `push-config/{task_id}/{config_id}`, with `%` and `/`
+/// in the task ID percent-encoded.
+#[derive(Clone)]
+pub struct KvPushNotificationConfigStore {
+    store: Arc<dyn KvStore>,
+}
+
+impl std::fmt::Debug for KvPushNotificationConfigStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("KvPushNotificationConfigStore")
+            .finish_non_exhaustive()
+    }
+}
+
+impl KvPushNotificationConfigStore {
+    /// Keep configs in `store`.
+    pub fn new(store: Arc<dyn KvStore>) -> Self {
+        Self { store }
+    }
+
+    /// Key prefix of `task_id`'s configs. Encoding the ID keeps it free of
+    /// `/`, so the prefix of task `a` doesn't cover the keys of task `a/b`.
+    fn task_prefix(task_id: &str) -> String {
+        let mut prefix = String::with_capacity(PUSH_CONFIG_KEY_PREFIX.len() + task_id.len() + 1);
+        prefix.push_str(PUSH_CONFIG_KEY_PREFIX);
+        for c in task_id.chars() {
+            match c {
+                '%' => prefix.push_str("%25"),
+                '/' => prefix.push_str("%2F"),
+                c => prefix.push(c),
+            }
+        }
+        prefix.push('/');
+        prefix
+    }
+
+    fn key(task_id: &str, config_id: &str) -> String {
+        format!("{}{config_id}", Self::task_prefix(task_id))
+    }
+}
+
+#[async_trait]
+impl PushNotificationConfigStore for KvPushNotificationConfigStore {
+    async fn set(&self, config: TaskPushNotificationConfig) -> A2AResult<()> {
+        let key = Self::key(&config.task_id, config_id(&config));
+        kv_store::put_json(&*self.store, &key, &config).await
+    }
+
+    async fn get(
+        &self,
+        task_id: &str,
+        config_id: &str,
+    ) -> A2AResult<Option<TaskPushNotificationConfig>> {
+        kv_store::get_json(&*self.store, &Self::key(task_id, config_id)).await
+    }
+
+    async fn list(&self, task_id: &str) -> A2AResult<Vec<TaskPushNotificationConfig>> {
+        self.store
+            .scan_prefix(&Self::task_prefix(task_id))
+            .await?
+            .iter()
+            .map(|(key, value)| kv_store::decode(key, value))
+            .collect()
+    }
+
+    async fn delete(&self, task_id: &str, config_id: &str) -> A2AResult<()> {
+        self.store.delete(&Self::key(task_id, config_id)).await
+    }
+}
+
+/// In-memory [`PushNotificationConfigStore`]: a
+/// [`KvPushNotificationConfigStore`] over an [`InMemoryKvStore`].
+#[derive(Debug)]
+pub struct InMemoryPushNotificationConfigStore {
+    inner: KvPushNotificationConfigStore,
+}
+
+impl Default for InMemoryPushNotificationConfigStore {
+    fn default() -> Self {
+        Self {
+            inner: KvPushNotificationConfigStore::new(Arc::new(InMemoryKvStore::new())),
+        }
+    }
+}
+
+impl InMemoryPushNotificationConfigStore {
+    /// Create an empty store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl PushNotificationConfigStore for InMemoryPushNotificationConfigStore {
+    async fn set(&self, config: TaskPushNotificationConfig) -> A2AResult<()> {
+        self.inner.set(config).await
+    }
+
+    async fn get(
+        &self,
+        task_id: &str,
+        config_id: &str,
+    ) -> A2AResult<Option<TaskPushNotificationConfig>> {
+        self.inner.get(task_id, config_id).await
+    }
+
+    async fn list(&self, task_id: &str) -> A2AResult<Vec<TaskPushNotificationConfig>> {
+        self.inner.list(task_id).await
+    }
+
+    async fn delete(&self, task_id: &str, config_id: &str) -> A2AResult<()> {
+        self.inner.delete(task_id, config_id).await
+    }
+}
+
+/// The config a secret belongs to, passed to each [`SecretCipher`] call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SecretContext<'a> {
+    /// ID of the config's task.
+    pub task_id: &'a str,
+    /// ID the config is stored under.
+    pub config_id: &'a str,
+}
+
+/// Encrypts the secrets of push notification configs before they are
+/// stored; see [`EncryptedPushNotificationConfigStore`].
+///
+/// Ciphertexts are strings (e.g. base64) since configs are stored as JSON.
+/// Ciphers should bind each ciphertext to its [`SecretContext`] (e.g. as
+/// additional authenticated data), so a secret copied into another config
+/// in the backing store fails to decrypt there.
+pub trait SecretCipher: Send + Sync {
+    /// Encrypt `plaintext`, a secret of the config `context`.
+    fn encrypt(&self, plaintext: &str, context: SecretContext<'_>) -> A2AResult<String>;
+
+    /// Decrypt a `ciphertext` that [`encrypt()`](Self::encrypt) returned
+    /// for the same `context`.
+    fn decrypt(&self, ciphertext: &str, context: SecretContext<'_>) -> A2AResult<String>;
+}
+
+/// [`PushNotificationConfigStore`] keeping the webhook `token` and
+/// `authentication.credentials` of each config encrypted in another store.
+///
+/// Configs are decrypted when read, so the handler and clients see them as
+/// set. Secrets stored before the wrapping can't be decrypted and fail
+/// reads with [`A2AError::InternalError`](crate::error::A2AError::InternalError).
+#[derive(Clone)]
+pub struct EncryptedPushNotificationConfigStore {
+    inner: Arc<dyn PushNotificationConfigStore>,
+    cipher: Arc<dyn SecretCipher>,
+}
+
+impl std::fmt::Debug for EncryptedPushNotificationConfigStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EncryptedPushNotificationConfigStore")
+            .finish_non_exhaustive()
+    }
+}
+
+impl EncryptedPushNotificationConfigStore {
+    /// Keep configs in `inner`, their secrets encrypted with `cipher`.
+    pub fn new(inner: Arc<dyn PushNotificationConfigStore>, cipher: Arc<dyn SecretCipher>) -> Self {
+        Self { inner, cipher }
+    }
+
+    /// `config` with `f` applied to each of its secrets.
+    fn map_secrets(
+        mut config: TaskPushNotificationConfig,
+        f: impl Fn(&str, SecretContext<'_>) -> A2AResult<String>,
+    ) -> A2AResult<TaskPushNotificationConfig> {
+        let config_id = config_id(&config).to_string();
+        let context = SecretContext {
+            task_id: &config.task_id,
+            config_id: &config_id,
+        };
+        let push = &mut config.push_notification_config;
+        if let Some(token) = &push.token {
+            push.token = Some(f(token, context)?);
+        }
+        if let Some(credentials) = push
+            .authentication
+            .as_mut()
+            .and_then(|auth| auth.credentials.as_mut())
+        {
+            *credentials = f(credentials, context)?;
+        }
+        Ok(config)
+    }
+
+    fn decrypt(&self, config: TaskPushNotificationConfig) -> A2AResult<TaskPushNotificationConfig> {
+        Self::map_secrets(config, |secret, context| {
+            self.cipher.decrypt(secret, context)
+        })
+    }
+}
+
+#[async_trait]
+impl PushNotificationConfigStore for EncryptedPushNotificationConfigStore {
+    async fn set(&self, config: TaskPushNotificationConfig) -> A2AResult<()> {
+        let config = Self::map_secrets(config, |secret, context| {
+            self.cipher.encrypt(secret, context)
+        })?;
+        self.inner.set(config).await
+    }
+
+    async fn get(
+        &self,
+        task_id: &str,
+        config_id: &str,
+    ) -> A2AResult<Option<TaskPushNotificationConfig>> {
+        self.inner
+            .get(task_id, config_id)
+            .await?
+            .map(|config| self.decrypt(config))
+            .transpose()
+    }
+
+    async fn list(&self, task_id: &str) -> A2AResult<Vec<TaskPushNotificationConfig>> {
+        self.inner
+            .list(task_id)
+            .await?
+            .into_iter()
+            .map(|config| self.decrypt(config))
+            .collect()
+    }
+
+    async fn delete(&self, task_id: &str, config_id: &str) -> A2AResult<()> {
+        self.inner.delete(task_id, config_id).await
+    }
+
+    async fn list_page(
+        &self,
+        task_id: &str,
+        page_size: usize,
+        page_token: Option<&str>,
+    ) -> A2AResult<ListTaskPushNotificationConfigResponse> {
+        let mut page = self.inner.list_page(task_id, page_size, page_token).await?;
+        page.configs = page
+            .configs
+            .into_iter()
+            .map(|config| self.decrypt(config))
+            .collect::<A2AResult<_>>()?;
+        Ok(page)
+    }
+}
+
+/// [`SecretCipher`] using AES-256-GCM (`push-secrets` feature).
+///
+/// Each secret gets a random 96-bit nonce; the ciphertext is the base64 of
+/// the nonce followed by the sealed secret and its tag. The task and config
+/// IDs are authenticated with it, so it only decrypts for the same config.
+#[cfg(feature = "push-secrets")]
+pub struct AesGcmSecretCipher {
+    key: ring::aead::LessSafeKey,
+    rng: ring::rand::SystemRandom,
+}
+
+#[cfg(feature = "push-secrets")]
+impl std::fmt::Debug for AesGcmSecretCipher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AesGcmSecretCipher").finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "push-secrets")]
+impl AesGcmSecretCipher {
+    /// A cipher with the 32-byte `key`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`A2AError::InvalidParams`] if `key` isn't 32 bytes long.
+    pub fn new(key: &[u8]) -> A2AResult<Self> {
+        let key = ring::aead::UnboundKey::new(&ring::aead::AES_256_GCM, key).map_err(|_| {
+            A2AError::invalid_params(format!("AES-256-GCM keys are 32 bytes, got {}", key.len()))
+        })?;
+        Ok(Self {
+            key: ring::aead::LessSafeKey::new(key),
+            rng: ring::rand::SystemRandom::new(),
+        })
+    }
+
+    /// A cipher with the base64 (standard alphabet) encoded 32-byte `key`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`A2AError::InvalidParams`] if `key` isn't base64 of a
+    /// 32-byte key.
+    pub fn from_base64(key: &str) -> A2AResult<Self> {
+        use base64::Engine;
+
+        let key = base64::engine::general_purpose::STANDARD
+            .decode(key.trim())
+            .map_err(|e| A2AError::invalid_params(format!("Invalid base64 key: {e}")))?;
+        Self::new(&key)
+    }
+}
+
+#[cfg(feature = "push-secrets")]
+impl SecretCipher for AesGcmSecretCipher {
+    fn encrypt(&self, plaintext: &str, context: SecretContext<'_>) -> A2AResult<String> {
+        use base64::Engine;
+        use ring::aead::{Aad, Nonce, NONCE_LEN};
+        use ring::rand::SecureRandom;
+
+        let mut nonce = [0u8; NONCE_LEN];
+        self.rng
+            .fill(&mut nonce)
+            .map_err(|_| A2AError::internal_error("Cannot generate a nonce"))?;
+        let mut sealed = plaintext.as_bytes().to_vec();
+        self.key
+            .seal_in_place_append_tag(
+                Nonce::assume_unique_for_key(nonce),
+                Aad::from(aad(context)),
+                &mut sealed,
+            )
+            .map_err(|_| A2AError::internal_error("Cannot encrypt secret"))?;
+        let mut ciphertext = nonce.to_vec();
+        ciphertext.extend_from_slice(&sealed);
+        Ok(base64::engine::general_purpose::STANDARD.encode(ciphertext))
+    }
+
+    fn decrypt(&self, ciphertext: &str, context: SecretContext<'_>) -> A2AResult<String> {
+        use base64::Engine;
+        use ring::aead::{Aad, Nonce, NONCE_LEN};
+
+        let invalid = || A2AError::internal_error("Cannot decrypt push notification secret");
+        let bytes = base64::engine::general_purpose::STANDARD
+            .decode(ciphertext)
+            .map_err(|_| invalid())?;
+        if bytes.len() < NONCE_LEN {
+            return Err(invalid());
+        }
+        let (nonce, sealed) = bytes.split_at(NONCE_LEN);
+        let nonce = Nonce::try_assume_unique_for_key(nonce).map_err(|_| invalid())?;
+        let mut sealed = sealed.to_vec();
+        let plaintext = self
+            .key
+            .open_in_place(nonce, Aad::from(aad(context)), &mut sealed)
+            .map_err(|_| invalid())?;
+        String::from_utf8(plaintext.to_vec()).map_err(|_| invalid())
+    }
+}
+
+/// Additional authenticated data for a secret of the config `context`: the
+/// length-prefixed task ID followed by the config ID.
+#[cfg(feature = "push-secrets")]
+fn aad(context: SecretContext<'_>) -> Vec<u8> {
+    let task_id = context.task_id.as_bytes();
+    let mut aad = Vec::with_capacity(8 + task_id.len() + context.config_id.len());
+    aad.extend_from_slice(&(task_id.len() as u64).to_be_bytes());
+    aad.extend_from_slice(task_id);
+    aad.extend_from_slice(context.config_id.as_bytes());
+    aad
+}
+
+/// [`PushNotificationConfigStore`] in a SQLite database (`push-sqlite`
+/// feature).
+///
+/// Configs are JSON in the `a2a_push_configs` table, created if missing,
+/// keyed by task ID and config ID. Pages are read with an indexed range
+/// query rather than listing the whole task. Queries run on the blocking
+/// thread pool, one at a time.
+#[cfg(feature = "push-sqlite")]
+pub struct SqlitePushNotificationConfigStore {
+    connection: Arc<std::sync::Mutex<rusqlite::Connection>>,
+}
+
+#[cfg(feature = "push-sqlite")]
+impl std::fmt::Debug for SqlitePushNotificationConfigStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SqlitePushNotificationConfigStore")
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "push-sqlite")]
+fn sqlite_error(e: rusqlite::Error) -> A2AError {
+    A2AError::internal_error(format!("sqlite: {e}"))
+}
+
+/// The configs of `task_id` after `page_token`, at most `limit`.
+#[cfg(feature = "push-sqlite")]
+fn sqlite_query(
+    connection: &rusqlite::Connection,
+    task_id: &str,
+    page_token: Option<&str>,
+    limit: i64,
+) -> A2AResult<Vec<TaskPushNotificationConfig>> {
+    let mut statement = connection
+        .prepare_cached(
+            "SELECT config_id, config FROM a2a_push_configs
+             WHERE task_id = ?1 AND config_id > ?2
+             ORDER BY config_id LIMIT ?3",
+        )
+        .map_err(sqlite_error)?;
+    let rows = statement
+        .query_map(
+            rusqlite::params![task_id, page_token.unwrap_or(""), limit],
+            |row| Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?)),
+        )
+        .map_err(sqlite_error)?;
+    rows.map(|row| {
+        let (config_id, config) = row.map_err(sqlite_error)?;
+        kv_store::decode(&format!("{task_id}/{config_id}"), config.as_bytes())
+    })
+    .collect()
+}
+
+#[cfg(feature = "push-sqlite")]
+impl SqlitePushNotificationConfigStore {
+    /// Open (or create) the database at `path`.
+    pub fn open(path: impl AsRef<std::path::Path>) -> A2AResult<Self> {
+        let path = path.as_ref();
+        let connection = rusqlite::Connection::open(path).map_err(|e| {
+            A2AError::internal_error(format!(
+                "Cannot open SQLite database {}: {e}",
+                path.display()
+            ))
+        })?;
+        Self::from_connection(connection)
+    }
+
+    /// A store in a private in-memory database; lost when dropped.
+    pub fn open_in_memory() -> A2AResult<Self> {
+        Self::from_connection(rusqlite::Connection::open_in_memory().map_err(sqlite_error)?)
+    }
+
+    /// Use an already opened database, creating the table if missing.
+    pub fn from_connection(connection: rusqlite::Connection) -> A2AResult<Self> {
+        connection
+            .execute_batch(
+                "CREATE TABLE IF NOT EXISTS a2a_push_configs (
+                    task_id TEXT NOT NULL,
+                    config_id TEXT NOT NULL,
+                    config TEXT NOT NULL,
+                    PRIMARY KEY (task_id, config_id)
+                )",
+            )
+            .map_err(sqlite_error)?;
+        Ok(Self {
+            connection: Arc::new(std::sync::Mutex::new(connection)),
+        })
+    }
+
+    /// Run `f` with the connection on the blocking thread pool.
+    async fn blocking<T, F>(&self, f: F) -> A2AResult<T>
+    where
+        F: FnOnce(&rusqlite::Connection) -> A2AResult<T> + Send + 'static,
+        T: Send + 'static,
+    {
+        let connection = Arc::clone(&self.connection);
+        tokio::task::spawn_blocking(move || {
+            let connection = connection
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner);
+            f(&connection)
+        })
+        .await
+        .map_err(|e| A2AError::internal_error(format!("sqlite task failed: {e}")))?
+    }
+}
+
+#[cfg(feature = "push-sqlite")]
+#[async_trait]
+impl PushNotificationConfigStore for SqlitePushNotificationConfigStore {
+    async fn set(&self, config: TaskPushNotificationConfig) -> A2AResult<()> {
+        let json = serde_json::to_string(&config)
+            .map_err(|e| A2AError::internal_error(format!("Cannot encode push config: {e}")))?;
+        let config_id = config_id(&config).to_string();
+        self.blocking(move |connection| {
+            connection
+                .execute(
+                    "INSERT OR REPLACE INTO a2a_push_configs (task_id, config_id, config)
+                     VALUES (?1, ?2, ?3)",
+                    rusqlite::params![config.task_id, config_id, json],
+                )
+                .map(drop)
+                .map_err(sqlite_error)
+        })
+        .await
+    }
+
+    async fn get(
+        &self,
+        task_id: &str,
+        config_id: &str,
+    ) -> A2AResult<Option<TaskPushNotificationConfig>> {
+        use rusqlite::OptionalExtension;
+
+        let (task_id, config_id) = (task_id.to_string(), config_id.to_string());
+        self.blocking(move |connection| {
+            let config: Option<String> = connection
+                .query_row(
+                    "SELECT config FROM a2a_push_configs WHERE task_id = ?1 AND config_id = ?2",
+                    rusqlite::params![task_id, config_id],
+                    |row| row.get(0),
+                )
+                .optional()
+                .map_err(sqlite_error)?;
+            config
+                .map(|config| {
+                    kv_store::decode(&format!("{task_id}/{config_id}"), config.as_bytes())
+                })
+                .transpose()
+        })
+        .await
+    }
+
+    async fn list(&self, task_id: &str) -> A2AResult<Vec<TaskPushNotificationConfig>> {
+        let task_id = task_id.to_string();
+        self.blocking(move |connection| sqlite_query(connection, &task_id, None, -1))
+            .await
+    }
+
+    async fn delete(&self, task_id: &str, config_id: &str) -> A2AResult<()> {
+        let (task_id, config_id) = (task_id.to_string(), config_id.to_string());
+        self.blocking(move |connection| {
+            connection
+                .execute(
+                    "DELETE FROM a2a_push_configs WHERE task_id = ?1 AND config_id = ?2",
+                    rusqlite::params![task_id, config_id],
+                )
+                .map(drop)
+                .map_err(sqlite_error)
+        })
+        .await
+    }
+
+    async fn list_page(
+        &self,
+        task_id: &str,
+        page_size: usize,
+        page_token: Option<&str>,
+    ) -> A2AResult<ListTaskPushNotificationConfigResponse> {
+        // One more than the page, to know whether there is a next one.
+        let limit = i64::try_from(page_size).unwrap_or(i64::MAX - 1) + 1;
+        let (task_id, page_token) = (task_id.to_string(), page_token.map(str::to_string));
+        let configs = self
+            .blocking(move |connection| {
+                sqlite_query(connection, &task_id, page_token.as_deref(), limit)
+            })
+            .await?;
+        Ok(page(configs, page_size, None))
+    }
+}
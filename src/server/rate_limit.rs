@@ -0,0 +1,412 @@
+//! Rate limiting of JSON-RPC requests.
+//!
+//! A [`RateLimiter`] attached with
+//! [`RouterConfig::with_rate_limiter`](super::RouterConfig::with_rate_limiter)
+//! runs after authentication on every JSON-RPC request. Each rule keys a
+//! token bucket on something about the caller — the peer IP, an API key
+//! header, the request's `tenant`, or whatever a custom key function
+//! returns — and a request is admitted only if every rule that applies to
+//! it has a token left. Rules whose key is absent (no API key header, no
+//! tenant) don't apply.
+//!
+//! A rejected request gets `429 Too Many Requests` with a `Retry-After`
+//! header and a JSON-RPC error [`RATE_LIMITED`](crate::error::RATE_LIMITED)
+//! (-32029) whose data carries `retryAfterMs`; clients read it with
+//! [`A2AError::retry_after`]. Admitted requests record the keys they were
+//! counted under in
+//! [`ServerCallContext::caller_identities`](super::ServerCallContext::caller_identities).
+//!
+//! ```rust,ignore
+//! let limiter = RateLimiter::new()
+//!     .per_ip(RateLimit::per_minute(120))
+//!     .per_api_key("x-api-key", RateLimit::per_second(10).with_burst(20))
+//!     .per_tenant(RateLimit::per_minute(1_000));
+//! let config = RouterConfig::default().with_rate_limiter(Arc::new(limiter));
+//! let app = a2a_router_with_config(handler, agent_card, config);
+//! ```
+//!
+//! The peer IP is only known when the router is served with
+//! `into_make_service_with_connect_info::<SocketAddr>()`. Behind a reverse
+//! proxy, key on the forwarded address instead:
+//!
+//! ```rust,ignore
+//! let limiter = RateLimiter::new().per_key("forwardedIp", RateLimit::per_minute(120), |req| {
+//!     let forwarded = req.header("x-forwarded-for")?;
+//!     Some(forwarded.split(',').next()?.trim().to_string())
+//! });
+//! ```
+//!
+//! The limiter keeps at most [`with_max_buckets`](RateLimiter::with_max_buckets)
+//! buckets (10 000 by default). When it is full, refilled buckets are dropped
+//! first, then the least recently used ones; an evicted caller starts over
+//! with a full bucket.
+
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use axum::http::HeaderMap;
+
+use crate::error::{A2AError, A2AResult};
+
+use super::auth::AuthenticatedUser;
+
+/// Buckets a [`RateLimiter`] keeps unless configured otherwise.
+const DEFAULT_MAX_BUCKETS: usize = 10_000;
+
+/// How many requests a caller may make: `requests` per `per`, in bursts of
+/// up to `burst`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateLimit {
+    requests: u32,
+    per: Duration,
+    burst: u32,
+}
+
+impl RateLimit {
+    /// `requests` per `per`, with a burst of `requests`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `requests` is zero or `per` is zero.
+    pub fn new(requests: u32, per: Duration) -> Self {
+        assert!(requests > 0, "a rate limit must allow some requests");
+        assert!(!per.is_zero(), "a rate limit needs a non-zero period");
+        Self {
+            requests,
+            per,
+            burst: requests,
+        }
+    }
+
+    /// `requests` per second.
+    pub fn per_second(requests: u32) -> Self {
+        Self::new(requests, Duration::from_secs(1))
+    }
+
+    /// `requests` per minute.
+    pub fn per_minute(requests: u32) -> Self {
+        Self::new(requests, Duration::from_secs(60))
+    }
+
+    /// Allow up to `burst` requests at once (at least one).
+    pub fn with_burst(mut self, burst: u32) -> Self {
+        self.burst = burst.max(1);
+        self
+    }
+
+    /// Tokens added per second.
+    fn rate(&self) -> f64 {
+        f64::from(self.requests) / self.per.as_secs_f64()
+    }
+}
+
+/// What a rate limiting rule keys its buckets on.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RateLimitScope {
+    /// The peer's IP address.
+    Ip,
+    /// An API key header.
+    ApiKey,
+    /// The request's `tenant`.
+    Tenant,
+    /// A key function added with [`RateLimiter::per_key`], by name.
+    Custom(String),
+}
+
+impl std::fmt::Display for RateLimitScope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RateLimitScope::Ip => f.write_str("ip"),
+            RateLimitScope::ApiKey => f.write_str("apiKey"),
+            RateLimitScope::Tenant => f.write_str("tenant"),
+            RateLimitScope::Custom(name) => f.write_str(name),
+        }
+    }
+}
+
+/// A key a request was rate limited under.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallerIdentity {
+    /// The rule's scope.
+    pub scope: RateLimitScope,
+    /// The caller's key in that scope. API keys are redacted to their last
+    /// four characters (none for short keys) so the credential doesn't
+    /// reach executors.
+    pub key: String,
+}
+
+/// The parts of a request rate limiting rules look at.
+#[derive(Debug, Clone, Copy)]
+pub struct RateLimitRequest<'a> {
+    headers: &'a HeaderMap,
+    peer_addr: Option<SocketAddr>,
+    method: &'a str,
+    tenant: Option<&'a str>,
+    user: Option<&'a AuthenticatedUser>,
+}
+
+impl<'a> RateLimitRequest<'a> {
+    /// A request for JSON-RPC `method` with `headers`.
+    pub fn new(headers: &'a HeaderMap, method: &'a str) -> Self {
+        Self {
+            headers,
+            peer_addr: None,
+            method,
+            tenant: None,
+            user: None,
+        }
+    }
+
+    /// Set the peer address.
+    pub fn with_peer_addr(mut self, peer_addr: Option<SocketAddr>) -> Self {
+        self.peer_addr = peer_addr;
+        self
+    }
+
+    /// Set the request's `tenant`.
+    pub fn with_tenant(mut self, tenant: Option<&'a str>) -> Self {
+        self.tenant = tenant;
+        self
+    }
+
+    /// Set the authenticated caller.
+    pub fn with_user(mut self, user: Option<&'a AuthenticatedUser>) -> Self {
+        self.user = user;
+        self
+    }
+
+    /// First value of header `name` (case-insensitive), if valid UTF-8.
+    pub fn header(&self, name: &str) -> Option<&'a str> {
+        self.headers.get(name).and_then(|v| v.to_str().ok())
+    }
+
+    /// The peer address, when the router is served with connect info.
+    pub fn peer_addr(&self) -> Option<SocketAddr> {
+        self.peer_addr
+    }
+
+    /// The JSON-RPC method.
+    pub fn method(&self) -> &'a str {
+        self.method
+    }
+
+    /// The request's `tenant` param.
+    pub fn tenant(&self) -> Option<&'a str> {
+        self.tenant
+    }
+
+    /// The caller, when the router has an authenticator.
+    pub fn user(&self) -> Option<&'a AuthenticatedUser> {
+        self.user
+    }
+}
+
+type KeyFn = Arc<dyn Fn(&RateLimitRequest<'_>) -> Option<String> + Send + Sync>;
+
+struct Rule {
+    scope: RateLimitScope,
+    limit: RateLimit,
+    key: KeyFn,
+}
+
+struct Bucket {
+    tokens: f64,
+    /// When a request last used the bucket.
+    updated: Instant,
+}
+
+impl Bucket {
+    /// Add the tokens earned since the last update.
+    fn refill(&mut self, limit: &RateLimit, now: Instant) {
+        let earned = now.duration_since(self.updated).as_secs_f64() * limit.rate();
+        self.tokens = (self.tokens + earned).min(f64::from(limit.burst));
+        self.updated = now;
+    }
+}
+
+/// Token-bucket rate limits keyed per caller. See the
+/// [module documentation](self).
+pub struct RateLimiter {
+    rules: Vec<Rule>,
+    max_buckets: usize,
+    buckets: Mutex<HashMap<(usize, String), Bucket>>,
+}
+
+impl Default for RateLimiter {
+    fn default() -> Self {
+        Self {
+            rules: Vec::new(),
+            max_buckets: DEFAULT_MAX_BUCKETS,
+            buckets: Mutex::default(),
+        }
+    }
+}
+
+impl std::fmt::Debug for RateLimiter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let rules: Vec<_> = self
+            .rules
+            .iter()
+            .map(|rule| (rule.scope.to_string(), rule.limit))
+            .collect();
+        f.debug_struct("RateLimiter")
+            .field("rules", &rules)
+            .field("max_buckets", &self.max_buckets)
+            .finish()
+    }
+}
+
+impl RateLimiter {
+    /// A limiter without rules, admitting everything.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keep at most `max` buckets (at least one); see the
+    /// [module documentation](self) for what is evicted.
+    pub fn with_max_buckets(mut self, max: usize) -> Self {
+        self.max_buckets = max.max(1);
+        self
+    }
+
+    /// Limit each peer IP address.
+    pub fn per_ip(self, limit: RateLimit) -> Self {
+        self.rule(RateLimitScope::Ip, limit, |req| {
+            Some(req.peer_addr()?.ip().to_string())
+        })
+    }
+
+    /// Limit each value of the API key header `header`.
+    pub fn per_api_key(self, header: &str, limit: RateLimit) -> Self {
+        let header = header.to_ascii_lowercase();
+        self.rule(RateLimitScope::ApiKey, limit, move |req| {
+            req.header(&header).map(str::to_string)
+        })
+    }
+
+    /// Limit each tenant.
+    pub fn per_tenant(self, limit: RateLimit) -> Self {
+        self.rule(RateLimitScope::Tenant, limit, |req| {
+            req.tenant().map(str::to_string)
+        })
+    }
+
+    /// Limit each key `key` returns, e.g. the authenticated user's name;
+    /// requests it returns `None` for are not limited by this rule.
+    pub fn per_key<F>(self, name: impl Into<String>, limit: RateLimit, key: F) -> Self
+    where
+        F: Fn(&RateLimitRequest<'_>) -> Option<String> + Send + Sync + 'static,
+    {
+        self.rule(RateLimitScope::Custom(name.into()), limit, key)
+    }
+
+    fn rule<F>(mut self, scope: RateLimitScope, limit: RateLimit, key: F) -> Self
+    where
+        F: Fn(&RateLimitRequest<'_>) -> Option<String> + Send + Sync + 'static,
+    {
+        self.rules.push(Rule {
+            scope,
+            limit,
+            key: Arc::new(key),
+        });
+        self
+    }
+
+    /// Take a token for `request` from every bucket it falls in.
+    ///
+    /// Returns the keys it was counted under, or
+    /// [`A2AError::RateLimited`] with the wait until every bucket has a
+    /// token again. A rejected request takes no tokens.
+    pub fn check(&self, request: &RateLimitRequest<'_>) -> A2AResult<Vec<CallerIdentity>> {
+        let keys: Vec<(usize, String)> = self
+            .rules
+            .iter()
+            .enumerate()
+            .filter_map(|(index, rule)| Some((index, (rule.key)(request)?)))
+            .collect();
+        if keys.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let now = Instant::now();
+        let mut buckets = self.buckets.lock().unwrap();
+        let new = keys.iter().filter(|key| !buckets.contains_key(key)).count();
+        if buckets.len() + new > self.max_buckets {
+            self.prune(&mut buckets, now, new);
+        }
+        let mut wait = Duration::ZERO;
+        for key in &keys {
+            let limit = &self.rules[key.0].limit;
+            let bucket = buckets.entry(key.clone()).or_insert_with(|| Bucket {
+                tokens: f64::from(limit.burst),
+                updated: now,
+            });
+            bucket.refill(limit, now);
+            if bucket.tokens < 1.0 {
+                wait = wait.max(Duration::from_secs_f64(
+                    (1.0 - bucket.tokens) / limit.rate(),
+                ));
+            }
+        }
+        if !wait.is_zero() {
+            return Err(A2AError::rate_limited(wait));
+        }
+        for key in &keys {
+            if let Some(bucket) = buckets.get_mut(key) {
+                bucket.tokens -= 1.0;
+            }
+        }
+        drop(buckets);
+
+        Ok(keys
+            .into_iter()
+            .map(|(index, key)| {
+                let scope = self.rules[index].scope.clone();
+                let key = match scope {
+                    RateLimitScope::ApiKey => redact(&key),
+                    _ => key,
+                };
+                CallerIdentity { scope, key }
+            })
+            .collect())
+    }
+
+    /// Make room for `new` buckets: drop the ones that have refilled
+    /// completely, as they behave like new ones, then the least recently
+    /// used ones. Evicts a tenth of the capacity at once so a stream of new
+    /// callers doesn't sort the buckets on every request.
+    fn prune(&self, buckets: &mut HashMap<(usize, String), Bucket>, now: Instant, new: usize) {
+        buckets.retain(|(index, _), bucket| {
+            let limit = &self.rules[*index].limit;
+            let earned = now.duration_since(bucket.updated).as_secs_f64() * limit.rate();
+            bucket.tokens + earned < f64::from(limit.burst)
+        });
+        if buckets.len() + new <= self.max_buckets {
+            return;
+        }
+        let keep = self
+            .max_buckets
+            .saturating_sub(new.max(self.max_buckets / 10));
+        let mut by_age: Vec<_> = buckets
+            .iter()
+            .map(|(key, bucket)| (bucket.updated, key.clone()))
+            .collect();
+        by_age.sort_unstable_by_key(|(updated, _)| *updated);
+        let evict = buckets.len().saturating_sub(keep);
+        for (_, key) in by_age.into_iter().take(evict) {
+            buckets.remove(&key);
+        }
+    }
+}
+
+/// The last four characters of `key`, for display; nothing of short keys.
+fn redact(key: &str) -> String {
+    let count = key.chars().count();
+    if count <= 8 {
+        return "…".to_string();
+    }
+    let tail: String = key.chars().skip(count - 4).collect();
+    format!("…{tail}")
+}
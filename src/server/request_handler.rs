@@ -9,23 +9,71 @@
 //! [`TaskStore`], and [`EventQueue`].
 
 use std::collections::HashMap;
+use std::panic::AssertUnwindSafe;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use async_trait::async_trait;
-use tokio::sync::{broadcast, Mutex};
+use futures::FutureExt;
+use tokio::sync::{broadcast, Mutex, OnceCell};
 use tokio::task::JoinHandle;
-use tracing::{debug, error, warn};
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, debug_span, error, warn, Instrument};
 use uuid::Uuid;
 
 use crate::error::{A2AError, A2AResult};
 use crate::types::{
-    Message, Part, SendMessageResponse, StreamResponse, Task, TaskState, TaskStatus,
-    TaskStatusUpdateEvent,
+    AgentCard, Artifact, ArtifactSummary, CancelContextParams, CancelContextResult,
+    ContextTaskCancelResult, CreateTaskPushNotificationConfigParams,
+    DeleteTaskPushNotificationConfigParams, GetArtifactParams, GetTaskPushNotificationConfigParams,
+    ListArtifactsParams, ListArtifactsResult, ListTaskPushNotificationConfigParams, Message, Part,
+    PushNotificationConfig, ReadConsistency, SendMessageResponse, StreamResponse, Task,
+    TaskPushNotificationConfig, TaskState, TaskStatus, TaskStatusUpdateEvent,
 };
+use crate::utils::deadline::{
+    deadline_after, earliest_deadline, format_deadline, time_remaining, with_deadline,
+};
+use crate::utils::parts::part_size;
+use crate::utils::request_id::{current_request_id, set_task_request_id, with_request_id};
+use crate::utils::task::{
+    CONTEXTS_CANCEL_METHOD, MESSAGE_SEND_STREAM_METHOD, TASKS_ARTIFACTS_GET_METHOD,
+    TASKS_ARTIFACTS_LIST_METHOD,
+};
+
+use super::agent_executor::{AgentExecutor, RequestContext, ServerCallContext};
+use super::content_validation::{AllowAllContent, ContentValidator, ContentViolation};
+use super::context_store::ContextStore;
+use super::error_reporter::{
+    panic_message, redact_excerpt, ExcerptRedactor, ExecutorErrorReport, ExecutorErrorReporter,
+    ExecutorFailureKind, TracingErrorReporter,
+};
+use super::event_queue::{
+    EventQueue, EventReceiver, OverflowPolicy, DEFAULT_CAPACITY as DEFAULT_QUEUE_CAPACITY,
+};
+use super::event_store::{EventLog, EventStore};
+use super::incremental::IncrementalMessage;
+use super::interceptor::{intercept, ServerInterceptor};
+use super::kv_store::{self, KvStore};
+use super::lifecycle::{TaskLifecycleListener, TaskLifecycleListeners};
+use super::output_modes::{adapt_event, InputModes, OutputAdapter, UnsupportedOutput};
+use super::push_config_store::PushNotificationConfigStore;
+use super::subscriptions::{SubscriptionRecord, SubscriptionRegistry};
+use super::task_manager::{push_history, HistoryPolicy, TaskManager};
+use super::task_store::{
+    check_tenant, find_artifact, set_task_tenant, task_tenant, TaskListParams, TaskListResponse,
+    TaskStore, TaskUpdate,
+};
+use super::workspace::{ExecutionGuard, Workspaces};
 
-use super::agent_executor::{AgentExecutor, RequestContext};
-use super::event_queue::EventQueue;
-use super::task_store::{TaskListParams, TaskListResponse, TaskStore};
+/// How often [`DefaultRequestHandler::shutdown`] checks for finished
+/// executions.
+const SHUTDOWN_POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+/// How long [`DefaultRequestHandler::shutdown`] waits for a task's pipeline
+/// to store its cancellation before storing it directly.
+const SHUTDOWN_FLUSH_TIMEOUT: Duration = Duration::from_secs(5);
 
 /// Parameters for `message/send` and `message/stream`.
 #[derive(Debug, Clone)]
@@ -59,6 +107,11 @@ pub struct SendMessageConfiguration {
 
     /// Push notification configuration.
     pub push_notification_config: Option<serde_json::Value>,
+
+    /// Time budget of the request: the task fails with
+    /// [`A2AError::DeadlineExceeded`] once it is used up. Combined with the
+    /// caller's deadline, if it sent one.
+    pub timeout: Option<Duration>,
 }
 
 /// Parameters for `tasks/get`.
@@ -75,6 +128,9 @@ pub struct GetTaskParams {
 
     /// Optional tenant identifier.
     pub tenant: Option<String>,
+
+    /// Read-consistency hint passed to the task store.
+    pub consistency: Option<ReadConsistency>,
 }
 
 /// Parameters for `tasks/cancel`.
@@ -124,6 +180,22 @@ pub trait RequestHandler: Send + Sync {
         params: SendMessageParams,
     ) -> A2AResult<broadcast::Receiver<StreamResponse>>;
 
+    /// Handle `message/sendStream` — `message/send` for a message whose
+    /// parts are still being uploaded (SDK extension).
+    ///
+    /// `params.message` holds the parts sent with the request's first
+    /// line, `incoming` all of them as they arrive. The default
+    /// implementation waits for the whole message and hands it to
+    /// [`on_message_send`](Self::on_message_send).
+    async fn on_message_send_incremental(
+        &self,
+        mut params: SendMessageParams,
+        incoming: IncrementalMessage,
+    ) -> A2AResult<SendMessageResponse> {
+        params.message = incoming.assemble().await?;
+        self.on_message_send(params).await
+    }
+
     /// Handle `tasks/get` — retrieve a task by ID.
     async fn on_get_task(&self, params: GetTaskParams) -> A2AResult<Task>;
 
@@ -133,6 +205,62 @@ pub trait RequestHandler: Send + Sync {
     /// Handle `tasks/cancel` — cancel a running task.
     async fn on_cancel_task(&self, params: CancelTaskParams) -> A2AResult<Task>;
 
+    /// Handle `contexts/cancel` — cancel every non-terminal task of a
+    /// context (SDK extension).
+    ///
+    /// The default implementation lists the context's tasks with
+    /// [`on_list_tasks`](Self::on_list_tasks) and cancels the non-terminal
+    /// ones concurrently with [`on_cancel_task`](Self::on_cancel_task),
+    /// answering once all have settled. Failures are reported per task
+    /// rather than failing the request; tasks created after the listing
+    /// are not included.
+    async fn on_cancel_context(
+        &self,
+        params: CancelContextParams,
+    ) -> A2AResult<CancelContextResult> {
+        cancel_context(self, params).await
+    }
+
+    /// Handle `tasks/artifacts/list` — list a task's artifacts without
+    /// their parts (SDK extension).
+    ///
+    /// The default implementation fetches the task with
+    /// [`on_get_task`](Self::on_get_task) and summarizes its artifacts.
+    async fn on_list_artifacts(
+        &self,
+        params: ListArtifactsParams,
+    ) -> A2AResult<ListArtifactsResult> {
+        let task = self
+            .on_get_task(artifacts_task_params(params.task_id, params.tenant))
+            .await?;
+        Ok(ListArtifactsResult {
+            artifacts: task
+                .artifacts
+                .iter()
+                .flatten()
+                .map(ArtifactSummary::from)
+                .collect(),
+            task_id: task.id,
+        })
+    }
+
+    /// Handle `tasks/artifacts/get` — one artifact of a task, with its
+    /// parts (SDK extension).
+    ///
+    /// The default implementation fetches the task with
+    /// [`on_get_task`](Self::on_get_task). Fails with `InvalidParams` if
+    /// the task has no artifact with the requested ID.
+    async fn on_get_artifact(&self, params: GetArtifactParams) -> A2AResult<Artifact> {
+        let task = self
+            .on_get_task(artifacts_task_params(params.task_id, params.tenant))
+            .await?;
+        find_artifact(
+            task.artifacts.unwrap_or_default(),
+            &task.id,
+            &params.artifact_id,
+        )
+    }
+
     /// Handle `tasks/resubscribe` — re-subscribe to events for a running task.
     ///
     /// Allows a client to re-attach to a running streaming task's event stream.
@@ -154,6 +282,17 @@ pub trait RequestHandler: Send + Sync {
         params: SubscribeToTaskParams,
     ) -> A2AResult<broadcast::Receiver<StreamResponse>>;
 
+    /// Handle a JSON-RPC notification (a request without `id`) for a
+    /// method that isn't part of A2A.
+    ///
+    /// No response is sent for notifications, so errors are only logged.
+    /// The default implementation ignores the notification.
+    async fn on_notification(&self, method: &str, params: serde_json::Value) -> A2AResult<()> {
+        let _ = params;
+        debug!(method, "Ignoring notification");
+        Ok(())
+    }
+
     /// Handle `tasks/pushNotificationConfig/set`.
     ///
     /// Default implementation returns `UnsupportedOperation`.
@@ -207,12 +346,184 @@ pub trait RequestHandler: Send + Sync {
     }
 }
 
+/// `tasks/get` params fetching a task for its artifacts, without history.
+fn artifacts_task_params(task_id: String, tenant: Option<String>) -> GetTaskParams {
+    GetTaskParams {
+        id: task_id,
+        history_length: Some(0),
+        metadata: None,
+        tenant,
+        consistency: None,
+    }
+}
+
+/// The default [`RequestHandler::on_cancel_context`].
+async fn cancel_context<H: RequestHandler + ?Sized>(
+    handler: &H,
+    params: CancelContextParams,
+) -> A2AResult<CancelContextResult> {
+    let mut task_ids = Vec::new();
+    let mut page_token = None;
+    loop {
+        let page = handler
+            .on_list_tasks(TaskListParams {
+                context_id: Some(params.context_id.clone()),
+                page_token,
+                tenant: params.tenant.clone(),
+                ..Default::default()
+            })
+            .await?;
+        task_ids.extend(
+            page.tasks
+                .into_iter()
+                .filter(|task| !is_terminal_state(&task.status.state))
+                .map(|task| task.id),
+        );
+        page_token = page.next_page_token;
+        if page_token.is_none() {
+            break;
+        }
+    }
+    task_ids.sort();
+
+    let results = futures::future::join_all(task_ids.into_iter().map(|task_id| async {
+        let outcome = handler
+            .on_cancel_task(CancelTaskParams {
+                id: task_id.clone(),
+                metadata: params.metadata.clone(),
+                tenant: params.tenant.clone(),
+            })
+            .await;
+        match outcome {
+            Ok(task) => ContextTaskCancelResult {
+                task_id,
+                task: Some(task),
+                error: None,
+            },
+            Err(e) => ContextTaskCancelResult {
+                task_id,
+                task: None,
+                error: Some(e.into()),
+            },
+        }
+    }))
+    .await;
+    Ok(CancelContextResult {
+        context_id: params.context_id,
+        results,
+    })
+}
+
+/// Whether no further transitions follow `state`.
+fn is_terminal_state(state: &TaskState) -> bool {
+    matches!(
+        state,
+        TaskState::Completed | TaskState::Failed | TaskState::Canceled | TaskState::Rejected
+    )
+}
+
 /// Tracks a running agent execution.
 struct RunningAgent {
-    /// Handle to the spawned tokio task running the agent.
-    handle: JoinHandle<()>,
-    /// The event queue for this execution.
+    /// Handle to the spawned tokio task running the agent; `None` for a
+    /// stream recovered from the subscription registry, whose executor
+    /// ran in a previous process.
+    handle: Option<JoinHandle<()>>,
+    /// The queue the executor publishes to (input of the event pipeline).
     event_queue: EventQueue,
+    /// The queue subscribers read from (output of the event pipeline).
+    /// Every event on it has already been applied to the task store.
+    persisted: EventQueue,
+    /// Set by the event pipeline if the output adapter rejected a part or
+    /// an artifact outgrew its limit, and by the executor task if the
+    /// executor failed with structured error data.
+    output_rejection: OutputRejection,
+    /// Sequence number of the last event published on `persisted`.
+    sequence: Arc<AtomicU64>,
+    /// The execution's [`RequestContext::cancellation_token`], fired by
+    /// `tasks/cancel`.
+    cancellation: CancellationToken,
+}
+
+/// Error `message/send` returns instead of the task: the output adapter's
+/// or artifact limit's rejection, or the executor's error with data.
+type OutputRejection = Arc<std::sync::Mutex<Option<A2AError>>>;
+
+/// Output mode enforcement for one execution.
+struct OutputCheck {
+    adapter: Arc<dyn OutputAdapter>,
+    accepted: Vec<String>,
+    rejection: OutputRejection,
+}
+
+/// Artifact size enforcement for one execution.
+struct ArtifactLimit {
+    max: usize,
+    rejection: OutputRejection,
+    /// Size so far of each artifact, by ID.
+    sizes: HashMap<String, usize>,
+}
+
+impl ArtifactLimit {
+    /// Count the parts of an artifact update, failing once the artifact
+    /// outgrows the limit.
+    fn check(&mut self, event: &StreamResponse) -> A2AResult<()> {
+        let StreamResponse::ArtifactUpdate(update) = event else {
+            return Ok(());
+        };
+        let added: usize = update.artifact.parts.iter().map(part_size).sum();
+        let size = self
+            .sizes
+            .entry(update.artifact.artifact_id.clone())
+            .or_default();
+        *size = match update.append {
+            Some(true) => *size + added,
+            _ => added,
+        };
+        if *size > self.max {
+            return Err(A2AError::payload_too_large(
+                &format!("Artifact '{}'", update.artifact.artifact_id),
+                Some(*size),
+                self.max,
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Inputs of [`DefaultRequestHandler::spawn_event_pipeline`].
+struct EventPipeline<'a> {
+    task: &'a Task,
+    /// Queue the executor publishes to.
+    source: &'a EventQueue,
+    /// Queue persisted events are published on.
+    sink: EventQueue,
+    /// Count of published events.
+    sequence: Arc<AtomicU64>,
+    output_check: Option<OutputCheck>,
+    artifact_limit: Option<ArtifactLimit>,
+    /// Dedup key of the message the task was created for.
+    created: Option<DedupKey>,
+}
+
+impl<'a> EventPipeline<'a> {
+    /// A pipeline from `source` to `sink` without output checks, for a task
+    /// that already exists.
+    fn new(
+        task: &'a Task,
+        source: &'a EventQueue,
+        sink: EventQueue,
+        sequence: Arc<AtomicU64>,
+    ) -> Self {
+        Self {
+            task,
+            source,
+            sink,
+            sequence,
+            output_check: None,
+            artifact_limit: None,
+            created: None,
+        }
+    }
 }
 
 /// Default request handler — standard implementation wiring executor, store, and events.
@@ -227,327 +538,1762 @@ struct RunningAgent {
 /// 1. `on_message_send` or `on_message_send_stream` creates a new task (or looks up
 ///    an existing one by context ID), persists it, and spawns the agent executor.
 /// 2. The executor publishes events to the task's `EventQueue`.
-/// 3. For `message/send`: events are consumed until a terminal state, then the
+/// 3. A single pipeline task applies each event to the [`TaskStore`] (via
+///    [`TaskManager`]) and only then fans it out to subscribers, so a
+///    subscriber never sees an event that isn't persisted yet.
+/// 4. For `message/send`: events are consumed until a terminal state, then the
 ///    final task is returned.
-/// 4. For `message/stream`: the event receiver is returned directly for SSE delivery.
-/// 5. `on_cancel_task` calls the executor's cancel method and waits for the
+/// 5. For `message/stream`: the event receiver is returned directly for SSE delivery.
+/// 6. `on_cancel_task` calls the executor's cancel method and waits for the
 ///    cancellation event.
+///
+/// # Executor errors
+///
+/// An executor returning `Err` fails the task: the final status carries
+/// the error text, and its JSON-RPC form (code, message, `data`) goes into
+/// the task metadata under `error`. Errors with structured data (see
+/// [`A2AError::with_data`]) are meant for the caller, so `message/send`
+/// answers with the error itself instead of the failed task.
+///
+/// Agent replies are recorded in task history per [`HistoryPolicy`]
+/// (default [`HistoryPolicy::AgentMessages`], so `tasks/get` returns the
+/// full conversation).
 pub struct DefaultRequestHandler {
     executor: Arc<dyn AgentExecutor>,
     task_store: Arc<dyn TaskStore>,
     /// Per-task event queues and running agent handles.
     running_agents: Mutex<HashMap<String, RunningAgent>>,
+    /// Set by [`shutdown()`](Self::shutdown); new messages are refused.
+    closing: AtomicBool,
+    /// Wall-clock limit on each execution, if configured.
+    task_timeout: Option<Duration>,
+    /// Which agent messages the event pipeline records in task history.
+    history_policy: HistoryPolicy,
+    /// Whether the event pipeline enforces the task state machine.
+    transition_checks: bool,
+    /// Duplicate `(contextId, messageId)` detection, if enabled.
+    dedup: Option<Arc<MessageDedup>>,
+    /// Expiry of tasks left in `input-required`, if enabled.
+    expiry: Option<Arc<ExpiryTimers>>,
+    /// Scratch directories of unfinished tasks.
+    workspaces: Arc<Workspaces>,
+    /// Applied to output the client's `acceptedOutputModes` excludes.
+    output_adapter: Option<Arc<dyn OutputAdapter>>,
+    /// Input modes incoming messages must match, if enforced.
+    input_modes: Option<InputModes>,
+    /// Largest accepted message part, in bytes (see [`part_size`]).
+    max_part_size: Option<usize>,
+    /// Largest artifact an execution may emit, in bytes.
+    max_artifact_size: Option<usize>,
+    /// Checks incoming messages and the artifacts executors publish.
+    content_validator: Arc<dyn ContentValidator>,
+    /// Receives notifications for non-A2A methods.
+    on_notification: Option<NotificationCallback>,
+    /// Records open task streams so they can be recovered after a restart.
+    subscriptions: Option<Arc<dyn SubscriptionRegistry>>,
+    /// Records every event for replay to subscribers, if configured.
+    event_log: Option<Arc<EventLog>>,
+    /// Capacity of the event queues of executions.
+    queue_capacity: usize,
+    /// Applied to stream subscribers that fall behind.
+    overflow: OverflowPolicy,
+    /// Serves `tasks/pushNotificationConfig/*`, if configured.
+    push_configs: Option<Arc<dyn PushNotificationConfigStore>>,
+    /// Records the tasks of each context, if configured.
+    contexts: Option<Arc<dyn ContextStore>>,
+    /// Told about every failed or panicked execution.
+    error_reporter: Arc<dyn ExecutorErrorReporter>,
+    /// Applied to message excerpts in error reports.
+    excerpt_redactor: ExcerptRedactor,
+    /// Run around every method, in order.
+    interceptors: Vec<Arc<dyn ServerInterceptor>>,
+    /// Told about task creation, state changes, artifacts and terminal
+    /// states.
+    lifecycle: TaskLifecycleListeners,
+    /// Told about every persisted event, if configured.
+    #[cfg(feature = "observability")]
+    metrics: Option<Arc<dyn super::telemetry::MetricsRecorder>>,
 }
 
-impl DefaultRequestHandler {
-    /// Create a new default request handler.
-    pub fn new(executor: Arc<dyn AgentExecutor>, task_store: Arc<dyn TaskStore>) -> Self {
-        Self {
-            executor,
-            task_store,
-            running_agents: Mutex::new(HashMap::new()),
+/// `(contextId, messageId)` of a received message.
+type DedupKey = (Option<String>, String);
+
+/// Remembers which task each recently received message resolved to.
+struct MessageDedup {
+    window: Duration,
+    seen: std::sync::Mutex<HashMap<DedupKey, DedupEntry>>,
+    /// Shared record of the same, surviving restarts, if configured.
+    store: Option<Arc<dyn KvStore>>,
+}
+
+/// A dedup record in a [`KvStore`].
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct StoredDedup {
+    task_id: String,
+    /// Unix time in milliseconds.
+    first_seen: i64,
+}
+
+struct DedupEntry {
+    /// Set once the first request for this key has created its task;
+    /// concurrent duplicates wait on it.
+    task_id: Arc<OnceCell<String>>,
+    first_seen: Instant,
+}
+
+impl MessageDedup {
+    /// The cell for `key`, evicting entries older than the window.
+    fn cell(&self, key: DedupKey) -> Arc<OnceCell<String>> {
+        let now = Instant::now();
+        let mut seen = self.seen.lock().unwrap();
+        seen.retain(|_, entry| now.duration_since(entry.first_seen) < self.window);
+        let entry = seen.entry(key).or_insert_with(|| DedupEntry {
+            task_id: Arc::new(OnceCell::new()),
+            first_seen: now,
+        });
+        Arc::clone(&entry.task_id)
+    }
+
+    fn store_key(key: &DedupKey) -> String {
+        format!(
+            "dedup/{}",
+            serde_json::to_string(key).unwrap_or_else(|_| key.1.clone())
+        )
+    }
+
+    /// The task a message was resolved to within the window, according to
+    /// the store.
+    async fn stored(&self, key: &DedupKey) -> A2AResult<Option<String>> {
+        let Some(store) = &self.store else {
+            return Ok(None);
+        };
+        let record: Option<StoredDedup> =
+            kv_store::get_json(&**store, &Self::store_key(key)).await?;
+        let window = i64::try_from(self.window.as_millis()).unwrap_or(i64::MAX);
+        let now = chrono::Utc::now().timestamp_millis();
+        Ok(record
+            .filter(|record| now.saturating_sub(record.first_seen) < window)
+            .map(|record| record.task_id))
+    }
+
+    /// Record in the store that a message was resolved to `task_id`.
+    async fn remember(&self, key: &DedupKey, task_id: &str) -> A2AResult<()> {
+        let Some(store) = &self.store else {
+            return Ok(());
+        };
+        let record = StoredDedup {
+            task_id: task_id.to_string(),
+            first_seen: chrono::Utc::now().timestamp_millis(),
+        };
+        kv_store::put_json(&**store, &Self::store_key(key), &record).await
+    }
+
+    /// Forget which task a message was resolved to, so that a retry is
+    /// handled as a new message.
+    async fn forget(&self, key: &DedupKey) -> A2AResult<()> {
+        self.seen.lock().unwrap().remove(key);
+        match &self.store {
+            Some(store) => store.delete(&Self::store_key(key)).await,
+            None => Ok(()),
         }
     }
+}
 
-    /// Create or retrieve a task for the given message.
-    ///
-    /// Mirrors Python SDK's `_setup_message_execution` task resolution logic:
-    /// 1. If `task_id` is set, look up the existing task and validate state.
-    /// 2. If the task exists but is terminal, return `InvalidParams`.
-    /// 3. If `task_id` is set but doesn't exist, return `TaskNotFound`.
-    /// 4. Otherwise create a new task in `submitted` state.
-    async fn get_or_create_task(&self, params: &SendMessageParams) -> A2AResult<Task> {
-        // Check if the message references an existing task.
-        if let Some(ref task_id) = params.message.task_id {
-            if let Some(task) = self.task_store.get(task_id).await? {
-                // Verify it's not in a terminal state (mirrors Python SDK check).
-                if Self::is_terminal(&task.status.state) {
-                    return Err(A2AError::InvalidParams {
-                        message: format!(
-                            "Task {} is in terminal state: {}",
-                            task_id, task.status.state
-                        ),
-                        data: None,
-                    });
-                }
-                // Add the new message to history (mirrors Python's update_with_message).
-                // Python SDK moves status.message to history first, then clears it.
-                let mut updated_task = task;
-                if let Some(ref status_msg) = updated_task.status.message {
-                    let history = updated_task.history.get_or_insert_with(Vec::new);
-                    history.push(status_msg.clone());
-                    updated_task.status.message = None;
-                }
-                let history = updated_task.history.get_or_insert_with(Vec::new);
-                history.push(params.message.clone());
-                self.task_store.save(updated_task.clone()).await?;
-                return Ok(updated_task);
-            } else {
-                // task_id was specified but doesn't exist (mirrors Python SDK).
-                return Err(A2AError::TaskNotFound {
-                    message: format!("Task {} was specified but does not exist", task_id),
-                    data: None,
-                });
-            }
+/// Final state given to a task whose `input-required` wait expired.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExpiryOutcome {
+    /// Transition to `failed` (default).
+    #[default]
+    Failed,
+    /// Transition to `canceled`.
+    Canceled,
+}
+
+impl ExpiryOutcome {
+    fn state(self) -> TaskState {
+        match self {
+            ExpiryOutcome::Failed => TaskState::Failed,
+            ExpiryOutcome::Canceled => TaskState::Canceled,
         }
+    }
+}
 
-        // Create a new task.
-        let task_id = Uuid::new_v4().to_string();
-        let context_id = params
-            .message
-            .context_id
-            .clone()
-            .unwrap_or_else(|| Uuid::new_v4().to_string());
+/// Callback invoked with a task after it expired.
+type ExpiryCallback = Arc<dyn Fn(&Task) + Send + Sync>;
 
-        let task = Task {
-            id: task_id,
-            context_id,
-            kind: "task".to_string(),
-            status: TaskStatus {
-                state: TaskState::Submitted,
-                message: None,
-                timestamp: Some(chrono::Utc::now().to_rfc3339()),
-            },
-            artifacts: None,
-            history: Some(vec![params.message.clone()]),
-            metadata: params.metadata.clone(),
-        };
+/// Callback invoked with the method and params of a notification.
+type NotificationCallback = Arc<dyn Fn(&str, &serde_json::Value) + Send + Sync>;
 
-        self.task_store.save(task.clone()).await?;
-        debug!(task_id = %task.id, "Created new task");
+/// Policy for tasks left waiting in `input-required`.
+///
+/// Once a task has been in `input-required` for `timeout` without a new
+/// message, [`DefaultRequestHandler`] moves it to the configured
+/// [`ExpiryOutcome`], publishes a final status update to its subscribers
+/// and calls the [`on_expire`](Self::on_expire) callback, if any.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let handler = DefaultRequestHandler::new(executor, store).with_input_required_expiry(
+///     InputRequiredExpiry::new(Duration::from_secs(600))
+///         .with_outcome(ExpiryOutcome::Canceled)
+///         .on_expire(|task| tracing::info!(task_id = %task.id, "input timed out")),
+/// );
+/// ```
+#[derive(Clone)]
+pub struct InputRequiredExpiry {
+    timeout: Duration,
+    outcome: ExpiryOutcome,
+    on_expire: Option<ExpiryCallback>,
+}
 
-        Ok(task)
+impl InputRequiredExpiry {
+    /// Expire tasks after `timeout` in `input-required`, failing them.
+    pub fn new(timeout: Duration) -> Self {
+        Self {
+            timeout,
+            outcome: ExpiryOutcome::default(),
+            on_expire: None,
+        }
     }
 
-    /// Spawn the agent executor for a task.
-    ///
-    /// Returns the event queue for subscribing to events.
-    ///
-    /// Mirrors Python SDK's `_run_event_stream` — executes the agent and closes
-    /// the queue afterwards. Does NOT auto-publish a `Working` status; that is
-    /// the responsibility of the `AgentExecutor` implementation (matching the
-    /// Python SDK where `_run_event_stream` just calls `execute` + `close`).
-    async fn spawn_executor(
-        &self,
-        task: &Task,
-        message: &Message,
-        configuration: Option<&SendMessageConfiguration>,
-    ) -> A2AResult<EventQueue> {
-        let event_queue = EventQueue::with_default_capacity();
+    /// Set the state expired tasks transition to.
+    pub fn with_outcome(mut self, outcome: ExpiryOutcome) -> Self {
+        self.outcome = outcome;
+        self
+    }
 
-        // Convert the request_handler's SendMessageConfiguration to the
-        // types.rs SendMessageConfiguration used by RequestContext.
-        let types_config = configuration.map(|c| crate::types::SendMessageConfiguration {
-            accepted_output_modes: c.accepted_output_modes.clone(),
-            push_notification_config: c
-                .push_notification_config
-                .as_ref()
-                .and_then(|v| serde_json::from_value(v.clone()).ok()),
-            history_length: c.history_length.map(|h| h as i32),
-            blocking: c.blocking,
-        });
+    /// Call `callback` with each expired task, after its final state has
+    /// been stored and published. Runs on the timer task; keep it cheap or
+    /// spawn from it.
+    pub fn on_expire<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&Task) + Send + Sync + 'static,
+    {
+        self.on_expire = Some(Arc::new(callback));
+        self
+    }
 
-        let context = RequestContext {
-            task_id: task.id.clone(),
-            context_id: task.context_id.clone(),
-            message: Some(message.clone()),
-            task: Some(task.clone()),
-            configuration: types_config,
-            related_tasks: Vec::new(),
-            metadata: task.metadata.clone(),
-            call_context: None,
-        };
+    /// How long a task may wait in `input-required`.
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
 
-        let executor = Arc::clone(&self.executor);
-        let queue_clone = event_queue.clone();
-        let task_id = task.id.clone();
-        let context_id = task.context_id.clone();
+    /// The state expired tasks transition to.
+    pub fn outcome(&self) -> ExpiryOutcome {
+        self.outcome
+    }
+}
 
-        let handle = tokio::spawn(async move {
-            // Execute the agent — state transitions (working, etc.) are the
-            // agent's responsibility, matching the Python SDK pattern.
-            if let Err(e) = executor.execute(context, queue_clone.clone()).await {
-                error!(task_id = %task_id, error = %e, "Agent execution failed");
+impl std::fmt::Debug for InputRequiredExpiry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InputRequiredExpiry")
+            .field("timeout", &self.timeout)
+            .field("outcome", &self.outcome)
+            .field("on_expire", &self.on_expire.is_some())
+            .finish()
+    }
+}
 
-                // Publish a failed status (matches Python SDK behavior where
-                // execution errors result in a failed task).
-                let failed_event = StreamResponse::StatusUpdate(TaskStatusUpdateEvent {
-                    task_id: task_id.clone(),
-                    context_id: context_id.clone(),
-                    kind: "status-update".to_string(),
-                    status: TaskStatus {
-                        state: TaskState::Failed,
-                        message: Some(Message {
-                            message_id: Uuid::new_v4().to_string(),
-                            role: crate::types::Role::Agent,
-                            kind: "message".to_string(),
-                            parts: vec![Part::text(format!("Agent execution failed: {}", e))],
-                            context_id: None,
-                            task_id: Some(task_id.clone()),
-                            metadata: None,
-                            extensions: None,
-                            reference_task_ids: None,
-                        }),
-                        timestamp: Some(chrono::Utc::now().to_rfc3339()),
-                    },
-                    r#final: true,
-                    metadata: None,
-                });
-                let _ = queue_clone.publish(failed_event);
-            }
-            // Note: Python SDK calls queue.close() here. Our broadcast channel
-            // auto-closes when all senders are dropped, achieving the same effect.
-        });
+/// Pending expiry timers, one per task waiting in `input-required`.
+struct ExpiryTimers {
+    policy: InputRequiredExpiry,
+    task_store: Arc<dyn TaskStore>,
+    timers: std::sync::Mutex<HashMap<String, JoinHandle<()>>>,
+}
 
-        // Track the running agent (mirrors Python's _register_producer).
-        let mut running = self.running_agents.lock().await;
-        running.insert(
-            task.id.clone(),
-            RunningAgent {
-                handle,
-                event_queue: event_queue.clone(),
-            },
-        );
+impl ExpiryTimers {
+    /// Stop the timer of `task_id`, if any.
+    fn cancel(&self, task_id: &str) {
+        if let Some(timer) = self.timers.lock().unwrap().remove(task_id) {
+            timer.abort();
+        }
+    }
 
-        Ok(event_queue)
+    /// (Re)start the timer of `task_id`; on expiry the final status is
+    /// persisted and published on `sink`.
+    fn schedule(
+        self: &Arc<Self>,
+        task_id: &str,
+        context_id: &str,
+        sink: EventQueue,
+        history_policy: HistoryPolicy,
+        workspaces: Arc<Workspaces>,
+        listeners: TaskLifecycleListeners,
+    ) {
+        let this = Arc::clone(self);
+        let (task_id, context_id) = (task_id.to_string(), context_id.to_string());
+        let key = task_id.clone();
+        let timer = tokio::spawn(async move {
+            tokio::time::sleep(this.policy.timeout).await;
+            this.timers.lock().unwrap().remove(&task_id);
+            match this
+                .expire(&task_id, &context_id, &sink, history_policy, listeners)
+                .await
+            {
+                Ok(true) => workspaces.release(&task_id),
+                Ok(false) => {}
+                Err(e) => {
+                    error!(task_id = %task_id, error = %e, "Failed to expire input-required task");
+                }
+            }
+        });
+        if let Some(previous) = self.timers.lock().unwrap().insert(key, timer) {
+            previous.abort();
+        }
     }
 
-    /// Consume events from the queue until a terminal state is reached.
-    ///
-    /// Updates the task in the store as events arrive. Returns the final task.
-    async fn consume_until_terminal(
+    async fn expire(
         &self,
         task_id: &str,
-        mut rx: broadcast::Receiver<StreamResponse>,
-    ) -> A2AResult<Task> {
-        loop {
-            match rx.recv().await {
-                Ok(event) => {
-                    self.apply_event(task_id, &event).await?;
+        context_id: &str,
+        sink: &EventQueue,
+        history_policy: HistoryPolicy,
+        listeners: TaskLifecycleListeners,
+    ) -> A2AResult<bool> {
+        let still_waiting = self
+            .task_store
+            .get(task_id)
+            .await?
+            .is_some_and(|task| task.status.state == TaskState::InputRequired);
+        if !still_waiting {
+            return Ok(false);
+        }
 
-                    if let StreamResponse::StatusUpdate(ref update) = event {
-                        if Self::is_terminal(&update.status.state) || update.r#final {
-                            break;
-                        }
-                    }
-                }
-                Err(broadcast::error::RecvError::Closed) => {
-                    // Channel closed — agent is done.
-                    break;
-                }
-                Err(broadcast::error::RecvError::Lagged(n)) => {
-                    warn!(task_id = %task_id, missed = n, "Event consumer lagged");
-                    // Continue receiving.
-                }
+        let event = StreamResponse::StatusUpdate(TaskStatusUpdateEvent {
+            task_id: task_id.to_string(),
+            context_id: context_id.to_string(),
+            kind: "status-update".to_string(),
+            status: TaskStatus::with_timestamp(
+                self.policy.outcome.state(),
+                chrono::Utc::now().to_rfc3339(),
+            )
+            .with_message(Message {
+                message_id: Uuid::new_v4().to_string(),
+                role: crate::types::Role::Agent,
+                kind: "message".to_string(),
+                parts: vec![Part::text(format!(
+                    "No input received within {:?}; task expired",
+                    self.policy.timeout
+                ))],
+                context_id: Some(context_id.to_string()),
+                task_id: Some(task_id.to_string()),
+                metadata: None,
+                extensions: None,
+                reference_task_ids: None,
+            }),
+            r#final: true,
+            metadata: None,
+        });
+        let mut manager = TaskManager::new(
+            Some(task_id.to_string()),
+            Some(context_id.to_string()),
+            Box::new(Arc::clone(&self.task_store)),
+            None,
+        )?
+        .with_history_policy(history_policy)
+        .with_listeners(listeners);
+        persist_event(&mut manager, &event).await?;
+        debug!(task_id = %task_id, "Expired input-required task");
+        let _ = sink.publish(event);
+
+        if let Some(callback) = &self.policy.on_expire {
+            if let Some(task) = self.task_store.get(task_id).await? {
+                callback(&task);
             }
         }
+        Ok(true)
+    }
+}
 
-        // Clean up the running agent entry.
-        {
-            let mut running = self.running_agents.lock().await;
-            running.remove(task_id);
+impl DefaultRequestHandler {
+    /// Create a new default request handler.
+    pub fn new(executor: Arc<dyn AgentExecutor>, task_store: Arc<dyn TaskStore>) -> Self {
+        Self {
+            executor,
+            task_store,
+            running_agents: Mutex::new(HashMap::new()),
+            closing: AtomicBool::new(false),
+            task_timeout: None,
+            history_policy: HistoryPolicy::AgentMessages,
+            transition_checks: true,
+            dedup: None,
+            expiry: None,
+            workspaces: Arc::new(Workspaces::new(std::env::temp_dir())),
+            output_adapter: None,
+            input_modes: None,
+            max_part_size: None,
+            max_artifact_size: None,
+            content_validator: Arc::new(AllowAllContent),
+            on_notification: None,
+            subscriptions: None,
+            event_log: None,
+            queue_capacity: DEFAULT_QUEUE_CAPACITY,
+            overflow: OverflowPolicy::default(),
+            push_configs: None,
+            contexts: None,
+            error_reporter: Arc::new(TracingErrorReporter),
+            excerpt_redactor: Arc::new(redact_excerpt),
+            interceptors: Vec::new(),
+            lifecycle: TaskLifecycleListeners::default(),
+            #[cfg(feature = "observability")]
+            metrics: None,
         }
+    }
 
-        // Return the final task state.
-        self.task_store
-            .get(task_id)
-            .await?
-            .ok_or_else(|| A2AError::TaskNotFound {
-                message: task_id.to_string(),
-                data: None,
-            })
+    /// A new event queue with the configured capacity and overflow policy.
+    fn new_queue(&self) -> EventQueue {
+        EventQueue::new(self.queue_capacity).with_overflow_policy(self.overflow)
     }
 
-    /// Apply a stream event to the persisted task state.
-    ///
-    /// Mirrors Python SDK's `TaskManager.save_task_event` behavior:
-    /// - For `StatusUpdate`: moves current status.message to history first, merges
-    ///   event metadata into task metadata, then updates status.
-    /// - For `ArtifactUpdate`: uses `append_artifact_to_task` logic — when
-    ///   `append=true` and the artifact doesn't exist, the chunk is ignored.
-    /// - For `Task`: replaces the entire task.
-    /// - For `Message`: appends to history.
-    async fn apply_event(&self, task_id: &str, event: &StreamResponse) -> A2AResult<()> {
-        let mut task =
-            self.task_store
-                .get(task_id)
-                .await?
-                .ok_or_else(|| A2AError::TaskNotFound {
-                    message: task_id.to_string(),
-                    data: None,
-                })?;
-
-        match event {
-            StreamResponse::StatusUpdate(update) => {
-                // Python SDK moves the CURRENT status.message to history
-                // BEFORE replacing with the new status.
-                if let Some(ref current_msg) = task.status.message {
-                    let history = task.history.get_or_insert_with(Vec::new);
-                    history.push(current_msg.clone());
-                }
+    /// Create task [`Workspace`](super::Workspace)s under `root` instead of
+    /// the system temp directory.
+    pub fn with_workspace_root(mut self, root: impl Into<PathBuf>) -> Self {
+        self.workspaces = Arc::new(Workspaces::new(root.into()));
+        self
+    }
+
+    /// Enforce the client's `acceptedOutputModes`: parts the executor emits
+    /// in a mode the client didn't accept are passed to `adapter`, which
+    /// transcodes, drops or rejects them. See [`output_modes`].
+    ///
+    /// Without an adapter, output is delivered unchanged.
+    ///
+    /// [`output_modes`]: super::output_modes
+    pub fn with_output_adapter(mut self, adapter: Arc<dyn OutputAdapter>) -> Self {
+        self.output_adapter = Some(adapter);
+        self
+    }
+
+    /// Enforce the content types of `card` in both directions.
+    ///
+    /// Messages with a part outside the input modes of the skill they
+    /// name (`skillId` metadata) or, failing that, the card's
+    /// `defaultInputModes` are rejected with
+    /// [`A2AError::ContentTypeNotSupported`] (`-32005`) before a task is
+    /// created or resumed; an empty list accepts anything. Output the
+    /// client's `acceptedOutputModes` excludes is dropped, unless an
+    /// adapter is set with [`with_output_adapter()`](Self::with_output_adapter).
+    /// See [`output_modes`](super::output_modes).
+    pub fn with_content_negotiation(mut self, card: &AgentCard) -> Self {
+        self.input_modes = Some(InputModes::from_card(card));
+        if self.output_adapter.is_none() {
+            self.output_adapter = Some(Arc::new(UnsupportedOutput::Drop));
+        }
+        self
+    }
+
+    /// Reject messages with a part larger than `bytes` (see
+    /// [`part_size`]) with [`A2AError::PayloadTooLarge`], before a task is
+    /// created or resumed.
+    pub fn with_max_part_size(mut self, bytes: usize) -> Self {
+        self.max_part_size = Some(bytes);
+        self
+    }
+
+    /// Check incoming messages with `validator` before a task is created or
+    /// resumed, and the artifacts executors publish through
+    /// [`RequestContext::task_updater()`]; see
+    /// [`content_validation`](super::content_validation). All content is
+    /// accepted by default.
+    pub fn with_content_validator(mut self, validator: Arc<dyn ContentValidator>) -> Self {
+        self.content_validator = validator;
+        self
+    }
+
+    /// Fail executions that emit an artifact larger than `bytes`, counting
+    /// appended chunks together.
+    ///
+    /// The update that crosses the limit is replaced by a final `failed`
+    /// status carrying the [`A2AError::PayloadTooLarge`] error, which
+    /// `message/send` also returns.
+    pub fn with_max_artifact_size(mut self, bytes: usize) -> Self {
+        self.max_artifact_size = Some(bytes);
+        self
+    }
+
+    /// Call `callback` with the method and params of every JSON-RPC
+    /// notification for a method outside A2A, e.g. telemetry or status
+    /// signals from other agents.
+    ///
+    /// The callback runs on the request's task; keep it short.
+    pub fn with_notification_handler<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&str, &serde_json::Value) + Send + Sync + 'static,
+    {
+        self.on_notification = Some(Arc::new(callback));
+        self
+    }
+
+    /// Record open task streams in `registry`, so that after a restart
+    /// [`recover()`](Self::recover) can recreate them. See
+    /// [`subscriptions`](super::subscriptions).
+    pub fn with_subscription_registry(mut self, registry: Arc<dyn SubscriptionRegistry>) -> Self {
+        self.subscriptions = Some(registry);
+        self
+    }
+
+    /// Record every event of every task in `store`, and replay a task's
+    /// recorded events to `tasks/subscribe` and `tasks/resubscribe` before
+    /// live ones — also for tasks that have already finished. See
+    /// [`event_store`](super::event_store).
+    pub fn with_event_store(mut self, store: Arc<dyn EventStore>) -> Self {
+        self.event_log = Some(Arc::new(EventLog::new(store)));
+        self
+    }
+
+    /// Buffer up to `capacity` events per stream subscriber (default 1024)
+    /// before the [overflow policy](Self::with_overflow_policy) applies.
+    pub fn with_event_queue_capacity(mut self, capacity: usize) -> Self {
+        assert!(capacity > 0, "capacity must be greater than 0");
+        self.queue_capacity = capacity;
+        self
+    }
+
+    /// Handle stream subscribers (`message/stream`, `tasks/subscribe`)
+    /// that fall more than the [queue
+    /// capacity](Self::with_event_queue_capacity) behind with `policy`
+    /// instead of dropping events silently. See [`OverflowPolicy`];
+    /// [`BufferToStore`](OverflowPolicy::BufferToStore) reads missed events
+    /// from the [event store](Self::with_event_store), and falls back to a
+    /// gap event without one.
+    pub fn with_overflow_policy(mut self, policy: OverflowPolicy) -> Self {
+        self.overflow = policy;
+        self
+    }
+
+    /// Serve `tasks/pushNotificationConfig/set|get|list|delete` from
+    /// `store`, and keep the `pushNotificationConfig` passed with
+    /// `message/send` and `message/stream` there too. See
+    /// [`push_config_store`](super::push_config_store).
+    ///
+    /// Without a store those methods answer `UnsupportedOperation`.
+    pub fn with_push_config_store(mut self, store: Arc<dyn PushNotificationConfigStore>) -> Self {
+        self.push_configs = Some(store);
+        self
+    }
+
+    /// Run `interceptor` before and after every method, e.g. for audit
+    /// logging or quotas. Interceptors run in the order added. See
+    /// [`interceptor`](super::interceptor).
+    pub fn with_interceptor(mut self, interceptor: Arc<dyn ServerInterceptor>) -> Self {
+        self.interceptors.push(interceptor);
+        self
+    }
+
+    /// Record task state transitions, durations and event throughput with
+    /// `recorder`. See [`telemetry`](super::telemetry).
+    #[cfg(feature = "observability")]
+    pub fn with_metrics(mut self, recorder: Arc<dyn super::telemetry::MetricsRecorder>) -> Self {
+        self.metrics = Some(recorder);
+        self
+    }
+
+    /// Report failed and panicked executions to `reporter` instead of
+    /// logging them with [`TracingErrorReporter`]. See
+    /// [`error_reporter`](super::error_reporter).
+    pub fn with_error_reporter(mut self, reporter: Arc<dyn ExecutorErrorReporter>) -> Self {
+        self.error_reporter = reporter;
+        self
+    }
+
+    /// Record every task created in the [`ConversationContext`] of its
+    /// context in `store`. See [`context_store`](super::context_store).
+    ///
+    /// [`ConversationContext`]: super::ConversationContext
+    pub fn with_context_store(mut self, store: Arc<dyn ContextStore>) -> Self {
+        self.contexts = Some(store);
+        self
+    }
+
+    /// The context store, if configured.
+    pub fn context_store(&self) -> Option<&Arc<dyn ContextStore>> {
+        self.contexts.as_ref()
+    }
+
+    /// Tell `listener` about every task created, each state change, added
+    /// artifact and terminal state. Listeners run in the background, each
+    /// in order and isolated from the others' failures; see
+    /// [`lifecycle`](super::lifecycle).
+    pub fn with_lifecycle_listener(mut self, listener: Arc<dyn TaskLifecycleListener>) -> Self {
+        self.lifecycle = self.lifecycle.with(listener);
+        self
+    }
+
+    /// Redact message excerpts in error reports with `redactor` instead of
+    /// [`redact_excerpt`].
+    pub fn with_excerpt_redactor<F>(mut self, redactor: F) -> Self
+    where
+        F: Fn(&str) -> String + Send + Sync + 'static,
+    {
+        self.excerpt_redactor = Arc::new(redactor);
+        self
+    }
+
+    /// Recreate the streams recorded in the subscription registry by a
+    /// previous process, so clients can subscribe to their tasks again.
+    ///
+    /// Call once at startup, before serving requests. Records of tasks that
+    /// are gone from the task store or already terminal are removed.
+    /// Returns the number of recovered streams; `0` without a registry.
+    pub async fn recover(&self) -> A2AResult<usize> {
+        let Some(registry) = &self.subscriptions else {
+            return Ok(0);
+        };
+        let mut recovered = 0;
+        for record in registry.list().await? {
+            let task = match self.task_store.get(&record.task_id).await? {
+                Some(task) if !Self::is_terminal(&task.status.state) => task,
+                _ => {
+                    debug!(task_id = %record.task_id, "Dropping stale subscription record");
+                    registry.remove(&record.task_id).await?;
+                    continue;
+                }
+            };
+            let mut running = self.running_agents.lock().await;
+            if running.contains_key(&task.id) {
+                continue;
+            }
+            let event_queue = self.new_queue();
+            let sequence = Arc::new(AtomicU64::new(record.last_sequence));
+            let persisted = self.spawn_event_pipeline(EventPipeline::new(
+                &task,
+                &event_queue,
+                self.new_queue(),
+                Arc::clone(&sequence),
+            ));
+            running.insert(
+                task.id.clone(),
+                RunningAgent {
+                    handle: None,
+                    event_queue,
+                    persisted,
+                    output_rejection: OutputRejection::default(),
+                    sequence,
+                    cancellation: CancellationToken::new(),
+                },
+            );
+            debug!(task_id = %task.id, last_sequence = record.last_sequence, "Recovered task stream");
+            recovered += 1;
+        }
+        Ok(recovered)
+    }
+
+    /// Shut down gracefully.
+    ///
+    /// From now on `message/send` and `message/stream` are refused. Running
+    /// executions get up to `grace` to finish; their pending events are
+    /// still persisted and delivered. Executions still running after that
+    /// are aborted (their cancellation token fired first) and their tasks
+    /// moved to `canceled`, which ends their subscribers' streams. Finally
+    /// every remaining stream is closed.
+    ///
+    /// Returns the number of tasks that were canceled.
+    pub async fn shutdown(&self, grace: Duration) -> usize {
+        self.closing.store(true, Ordering::SeqCst);
+        let deadline = Instant::now() + grace;
+        loop {
+            let busy = self
+                .running_agents
+                .lock()
+                .await
+                .values()
+                .filter(|agent| agent.handle.as_ref().is_some_and(|h| !h.is_finished()))
+                .count();
+            if busy == 0 || Instant::now() >= deadline {
+                if busy > 0 {
+                    warn!(busy, "Shutdown grace period elapsed; canceling tasks");
+                }
+                break;
+            }
+            tokio::time::sleep(SHUTDOWN_POLL_INTERVAL).await;
+        }
+
+        let agents: Vec<_> = self.running_agents.lock().await.drain().collect();
+        let mut canceled = 0;
+        for (task_id, agent) in agents {
+            let Some(handle) = agent.handle.as_ref().filter(|h| !h.is_finished()) else {
+                continue;
+            };
+            agent.cancellation.cancel();
+            handle.abort();
+            match self.cancel_for_shutdown(&task_id, &agent).await {
+                Ok(true) => canceled += 1,
+                Ok(false) => {}
+                Err(e) => {
+                    warn!(task_id = %task_id, error = %e, "Failed to cancel task on shutdown")
+                }
+            }
+        }
+        // Dropping the agents closes their queues, so every pipeline and
+        // stream still open ends once its buffered events are delivered.
+        debug!(canceled, "Request handler shut down");
+        canceled
+    }
+
+    /// Move the task of an aborted execution to `canceled`, unless it
+    /// already finished. Returns whether it was canceled.
+    async fn cancel_for_shutdown(&self, task_id: &str, agent: &RunningAgent) -> A2AResult<bool> {
+        // Subscribed before reading the task: the pipeline stores an event
+        // before publishing it, so a final event not yet stored is seen.
+        let mut rx = agent.persisted.subscribe();
+        let Some(task) = self.task_store.get(task_id).await? else {
+            return Ok(false);
+        };
+        if Self::is_terminal(&task.status.state) {
+            return Ok(false);
+        }
+        let event = StreamResponse::StatusUpdate(TaskStatusUpdateEvent {
+            task_id: task_id.to_string(),
+            context_id: task.context_id.clone(),
+            kind: "status-update".to_string(),
+            status: TaskStatus::with_timestamp(
+                TaskState::Canceled,
+                chrono::Utc::now().to_rfc3339(),
+            )
+            .with_message(Message {
+                message_id: Uuid::new_v4().to_string(),
+                role: crate::types::Role::Agent,
+                kind: "message".to_string(),
+                parts: vec![Part::text("Server shutting down; task canceled")],
+                context_id: Some(task.context_id.clone()),
+                task_id: Some(task_id.to_string()),
+                metadata: None,
+                extensions: None,
+                reference_task_ids: None,
+            }),
+            r#final: true,
+            metadata: None,
+        });
+
+        // Let the pipeline persist and deliver it after the pending events.
+        if agent.event_queue.publish(event.clone()).is_ok() {
+            let flushed = tokio::time::timeout(SHUTDOWN_FLUSH_TIMEOUT, async {
+                loop {
+                    match rx.recv().await {
+                        Ok(StreamResponse::StatusUpdate(update))
+                            if update.r#final || Self::is_terminal(&update.status.state) =>
+                        {
+                            return true
+                        }
+                        Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) => {}
+                        Err(broadcast::error::RecvError::Closed) => return false,
+                    }
+                }
+            })
+            .await;
+            if flushed == Ok(true) {
+                return Ok(self
+                    .task_store
+                    .get(task_id)
+                    .await?
+                    .is_some_and(|task| task.status.state == TaskState::Canceled));
+            }
+        }
+
+        // The pipeline is gone or stuck: store the cancellation directly.
+        warn!(task_id = %task_id, "Event pipeline did not flush; canceling task directly");
+        let mut manager = TaskManager::new(
+            Some(task_id.to_string()),
+            Some(task.context_id.clone()),
+            Box::new(Arc::clone(&self.task_store)),
+            None,
+        )?
+        .with_history_policy(self.history_policy)
+        .with_listeners(self.lifecycle.clone());
+        persist_event(&mut manager, &event).await?;
+        let _ = agent.persisted.publish(event);
+        Ok(true)
+    }
+
+    /// Refuse new messages once [`shutdown()`](Self::shutdown) has begun.
+    fn check_open(&self) -> A2AResult<()> {
+        if self.closing.load(Ordering::SeqCst) {
+            return Err(A2AError::internal_error(
+                "Server is shutting down; not accepting new messages",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Refuse messages with parts the agent doesn't accept, if enforced.
+    fn check_input_modes(&self, params: &SendMessageParams) -> A2AResult<()> {
+        match &self.input_modes {
+            Some(modes) => modes.check(&params.message, params.metadata.as_ref()),
+            None => Ok(()),
+        }
+    }
+
+    /// Refuse messages with a part over the size limit, if set.
+    fn check_part_sizes(&self, params: &SendMessageParams) -> A2AResult<()> {
+        let Some(max) = self.max_part_size else {
+            return Ok(());
+        };
+        for (index, part) in params.message.parts.iter().enumerate() {
+            let size = part_size(part);
+            if size > max {
+                return Err(A2AError::payload_too_large(
+                    &format!("Message part {index}"),
+                    Some(size),
+                    max,
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Expire tasks that wait in `input-required` longer than the policy's
+    /// timeout. See [`InputRequiredExpiry`].
+    ///
+    /// The timer starts when the `input-required` status is stored and is
+    /// reset by any later status update, e.g. once a follow-up message
+    /// resumes the task.
+    pub fn with_input_required_expiry(mut self, policy: InputRequiredExpiry) -> Self {
+        self.expiry = Some(Arc::new(ExpiryTimers {
+            policy,
+            task_store: Arc::clone(&self.task_store),
+            timers: std::sync::Mutex::new(HashMap::new()),
+        }));
+        self
+    }
+
+    /// Limit each execution to `timeout` of wall-clock time.
+    ///
+    /// An executor still running after that has its cancellation token
+    /// fired and is dropped; the task gets a final `failed` status saying
+    /// it timed out, which is stored and delivered like any other event,
+    /// and the error reporter is told with [`ExecutorFailureKind::Timeout`].
+    pub fn with_task_timeout(mut self, timeout: Duration) -> Self {
+        self.task_timeout = Some(timeout);
+        self
+    }
+
+    /// Detect messages resent with the same `(contextId, messageId)` within
+    /// `window` and answer them with the originally created task instead of
+    /// starting a new one.
+    ///
+    /// Guards against clients that retry `message/send` or
+    /// `message/stream` after a lost response. Messages without a
+    /// `contextId` are keyed by `messageId` alone.
+    pub fn with_message_dedup(mut self, window: Duration) -> Self {
+        self.dedup = Some(Arc::new(MessageDedup {
+            window,
+            seen: std::sync::Mutex::new(HashMap::new()),
+            store: None,
+        }));
+        self
+    }
+
+    /// Like [`with_message_dedup()`](Self::with_message_dedup), but also
+    /// record which task each message created in `store` (under
+    /// `dedup/...` keys), so duplicates are recognised across restarts and
+    /// by other replicas sharing the store and the task store.
+    ///
+    /// Stale records are ignored, not deleted; clean up `dedup/` keys older
+    /// than the window with the store's own tooling if needed.
+    pub fn with_message_dedup_store(mut self, window: Duration, store: Arc<dyn KvStore>) -> Self {
+        self.dedup = Some(Arc::new(MessageDedup {
+            window,
+            seen: std::sync::Mutex::new(HashMap::new()),
+            store: Some(store),
+        }));
+        self
+    }
+
+    /// Resolve the task for `params` like [`get_or_create_task`], but with
+    /// duplicate detection enabled return the task created by the first
+    /// request for the same `(contextId, messageId)`.
+    ///
+    /// The flag is `true` for duplicates; they must not be executed again.
+    ///
+    /// [`get_or_create_task`]: Self::get_or_create_task
+    async fn resolve_task(&self, params: &SendMessageParams) -> A2AResult<(Task, bool)> {
+        let Some(dedup) = &self.dedup else {
+            return Ok((self.get_or_create_task(params).await?, false));
+        };
+        let key = (
+            params.message.context_id.clone(),
+            params.message.message_id.clone(),
+        );
+        let cell = dedup.cell(key.clone());
+        let mut created = None;
+        let task_id = cell
+            .get_or_try_init(|| async {
+                if let Some(task_id) = dedup.stored(&key).await? {
+                    return Ok(task_id);
+                }
+                let task = self.get_or_create_task(params).await?;
+                dedup.remember(&key, &task.id).await?;
+                let id = task.id.clone();
+                created = Some(task);
+                Ok::<_, A2AError>(id)
+            })
+            .await?;
+        if let Some(task) = created {
+            return Ok((task, false));
+        }
+
+        debug!(
+            task_id = %task_id,
+            message_id = %params.message.message_id,
+            "Duplicate message, returning original task"
+        );
+        let task = self
+            .task_store
+            .get_for_tenant(params.tenant.as_deref(), task_id)
+            .await?
+            .ok_or_else(|| A2AError::TaskNotFound {
+                message: task_id.clone(),
+                data: None,
+            })?;
+        Ok((task, true))
+    }
+
+    /// Set which agent messages are recorded in task history.
+    pub fn with_history_policy(mut self, policy: HistoryPolicy) -> Self {
+        self.history_policy = policy;
+        self
+    }
+
+    /// Record agent messages in task history as `card` advertises with its
+    /// `stateTransitionHistory` capability. See
+    /// [`HistoryPolicy::for_capabilities`].
+    pub fn with_history_policy_for_card(self, card: &AgentCard) -> Self {
+        self.with_history_policy(HistoryPolicy::for_capabilities(&card.capabilities))
+    }
+
+    /// Enforce the task state machine on executor events (the default):
+    /// an event the stored task can't move to, e.g. `working` after
+    /// `completed`, is dropped instead of saved and delivered. Turning the
+    /// checks off is meant for tests. See [`TaskManager`].
+    pub fn with_transition_checks(mut self, enabled: bool) -> Self {
+        self.transition_checks = enabled;
+        self
+    }
+
+    /// Create or retrieve a task for the given message.
+    ///
+    /// Mirrors Python SDK's `_setup_message_execution` task resolution logic:
+    /// 1. If `task_id` is set, look up the existing task and validate state.
+    /// 2. If the task exists but is terminal, return `InvalidParams`.
+    /// 3. If `task_id` is set but doesn't exist, return `TaskNotFound`.
+    /// 4. Otherwise create a new task in `submitted` state.
+    async fn get_or_create_task(&self, params: &SendMessageParams) -> A2AResult<Task> {
+        // Check if the message references an existing task.
+        if let Some(ref task_id) = params.message.task_id {
+            let message = params.message.clone();
+            let tenant = params.tenant.clone();
+            let updated = self
+                .task_store
+                .update(
+                    task_id,
+                    None,
+                    Box::new(move |task| {
+                        check_tenant(task, tenant.as_deref())?;
+                        // Verify it's not in a terminal state (mirrors Python SDK check).
+                        if Self::is_terminal(&task.status.state) {
+                            return Err(A2AError::InvalidParams {
+                                message: format!(
+                                    "Task {} is in terminal state: {}",
+                                    task.id, task.status.state
+                                ),
+                                data: None,
+                            });
+                        }
+                        // Add the new message to history (mirrors Python's update_with_message).
+                        // Python SDK moves status.message to history first, then clears it.
+                        if let Some(status_msg) = task.status.message.take() {
+                            push_history(task, status_msg);
+                        }
+                        push_history(task, message);
+                        Ok(())
+                    }),
+                )
+                .await;
+            return match updated {
+                // task_id was specified but doesn't exist (mirrors Python SDK).
+                Err(A2AError::TaskNotFound { .. }) => Err(A2AError::TaskNotFound {
+                    message: format!("Task {} was specified but does not exist", task_id),
+                    data: None,
+                }),
+                updated => updated,
+            };
+        }
+
+        // Create a new task.
+        let task_id = Uuid::new_v4().to_string();
+        let context_id = params
+            .message
+            .context_id
+            .clone()
+            .unwrap_or_else(|| Uuid::new_v4().to_string());
+
+        let mut task = Task {
+            id: task_id,
+            context_id,
+            kind: "task".to_string(),
+            status: TaskStatus::with_timestamp(
+                TaskState::Submitted,
+                chrono::Utc::now().to_rfc3339(),
+            ),
+            artifacts: None,
+            history: Some(vec![params.message.clone()]),
+            metadata: params.metadata.clone(),
+        };
+        set_task_tenant(&mut task, params.tenant.as_deref());
+        if let Some(request_id) = current_request_id() {
+            set_task_request_id(&mut task, &request_id);
+        }
+
+        self.task_store
+            .save_for_tenant(params.tenant.as_deref(), task.clone())
+            .await?;
+        debug!(task_id = %task.id, "Created new task");
+        if let Some(contexts) = &self.contexts {
+            contexts
+                .add_task(&task.context_id, &task.id, params.tenant.as_deref())
+                .await?;
+        }
+        self.lifecycle.task_saved(None, &task);
+
+        Ok(task)
+    }
+
+    /// Spawn the agent executor for a task.
+    ///
+    /// Returns a receiver on the persisted side of the event pipeline. It is
+    /// subscribed before the executor starts, so no event can be missed.
+    ///
+    /// Mirrors Python SDK's `_run_event_stream` — executes the agent and closes
+    /// the queue afterwards. Does NOT auto-publish a `Working` status; that is
+    /// the responsibility of the `AgentExecutor` implementation (matching the
+    /// Python SDK where `_run_event_stream` just calls `execute` + `close`).
+    ///
+    /// `created` tells whether `task` was created for this message, and is
+    /// discarded if the agent replies with a message alone. `incoming`
+    /// carries the rest of `message` for `message/sendStream` calls.
+    async fn spawn_executor(
+        &self,
+        task: &Task,
+        message: &Message,
+        configuration: Option<&SendMessageConfiguration>,
+        created: bool,
+        incoming: Option<IncrementalMessage>,
+    ) -> A2AResult<broadcast::Receiver<StreamResponse>> {
+        // A new message resumes the task; it no longer waits for input.
+        if let Some(expiry) = &self.expiry {
+            expiry.cancel(&task.id);
+        }
+        let event_queue = self.new_queue();
+        let output_rejection = OutputRejection::default();
+        let accepted = configuration
+            .and_then(|c| c.accepted_output_modes.clone())
+            .unwrap_or_default();
+        let output_check = match &self.output_adapter {
+            Some(adapter) if !accepted.is_empty() => Some(OutputCheck {
+                adapter: Arc::clone(adapter),
+                accepted,
+                rejection: Arc::clone(&output_rejection),
+            }),
+            _ => None,
+        };
+        // A recovered stream keeps its subscribers and sequence numbers.
+        let (sink, sequence) = match self.running_agents.lock().await.get(&task.id) {
+            Some(agent) if agent.handle.is_none() => {
+                (agent.persisted.clone(), Arc::clone(&agent.sequence))
+            }
+            _ => (self.new_queue(), Arc::default()),
+        };
+        let artifact_limit = self.max_artifact_size.map(|max| ArtifactLimit {
+            max,
+            rejection: Arc::clone(&output_rejection),
+            sizes: HashMap::new(),
+        });
+        let created = created.then(|| (message.context_id.clone(), message.message_id.clone()));
+        let persisted = self.spawn_event_pipeline(EventPipeline {
+            task,
+            source: &event_queue,
+            sink,
+            sequence: Arc::clone(&sequence),
+            output_check,
+            artifact_limit,
+            created,
+        });
+        let rx = self.stream_receiver(&persisted, &task.id).await;
+
+        // Convert the request_handler's SendMessageConfiguration to the
+        // types.rs SendMessageConfiguration used by RequestContext.
+        let types_config = configuration.map(|c| crate::types::SendMessageConfiguration {
+            accepted_output_modes: c.accepted_output_modes.clone(),
+            push_notification_config: c
+                .push_notification_config
+                .as_ref()
+                .and_then(|v| serde_json::from_value(v.clone()).ok()),
+            history_length: c.history_length.map(|h| h as i32),
+            blocking: c.blocking,
+            timeout_ms: c
+                .timeout
+                .map(|t| u64::try_from(t.as_millis()).unwrap_or(u64::MAX)),
+        });
+
+        // The caller's deadline or the request's time budget, whichever
+        // ends first, bounds the execution.
+        let mut call_context = ServerCallContext::current();
+        let deadline = earliest_deadline(
+            call_context.as_ref().and_then(|c| c.deadline),
+            configuration.and_then(|c| c.timeout).map(deadline_after),
+        );
+        if let Some(deadline) = deadline {
+            call_context.get_or_insert_with(Default::default).deadline = Some(deadline);
+        }
+
+        let context = RequestContext {
+            task_id: task.id.clone(),
+            context_id: task.context_id.clone(),
+            message: Some(message.clone()),
+            task: Some(task.clone()),
+            configuration: types_config,
+            related_tasks: Vec::new(),
+            metadata: task.metadata.clone(),
+            tenant: task_tenant(task).map(String::from),
+            call_context,
+            task_store: Some(Arc::clone(&self.task_store)),
+            workspace: Some(self.workspaces.acquire(&task.id)),
+            cancellation_token: CancellationToken::new(),
+            incremental_message: incoming,
+            content_validator: Some(Arc::clone(&self.content_validator)),
+        };
+        let cancellation = context.cancellation_token.clone();
 
-                // Merge event metadata into task metadata (mirrors Python SDK).
-                if let Some(ref event_meta) = update.metadata {
-                    if let Some(ref mut task_meta) = task.metadata {
-                        if let (Some(task_obj), Some(event_obj)) =
-                            (task_meta.as_object_mut(), event_meta.as_object())
+        let executor = Arc::clone(&self.executor);
+        let mut guard = ExecutionGuard::new(Arc::clone(&self.workspaces), task.id.clone());
+        let queue_clone = event_queue.clone();
+        let task_id = task.id.clone();
+        let context_id = task.context_id.clone();
+        let reporter = Arc::clone(&self.error_reporter);
+        let redactor = Arc::clone(&self.excerpt_redactor);
+        let message = message.clone();
+        let request_metadata = task.metadata.clone();
+        let task_timeout = self.task_timeout;
+        let timed_out = cancellation.clone();
+        let rejection = Arc::clone(&output_rejection);
+        #[cfg(feature = "observability")]
+        let trace = crate::observability::TraceContext::current();
+        let request_id = context.request_id().map(String::from);
+
+        let handle = tokio::spawn(async move {
+            // Execute the agent — state transitions (working, etc.) are the
+            // agent's responsibility, matching the Python SDK pattern. It
+            // runs under the request's ID, which its own calls send on.
+            let execution = executor.execute(context, queue_clone.clone());
+            #[cfg(feature = "observability")]
+            let execution =
+                crate::observability::executor_call(trace, &task_id, &context_id, execution);
+            let execution = async move {
+                match deadline {
+                    Some(deadline) => with_deadline(deadline, execution).await,
+                    None => execution.await,
+                }
+            };
+            let execution = async move {
+                match request_id {
+                    Some(request_id) => {
+                        let span = debug_span!("a2a.execute", request_id = %request_id);
+                        with_request_id(request_id, execution.instrument(span)).await
+                    }
+                    None => execution.await,
+                }
+            };
+            let execution = AssertUnwindSafe(execution).catch_unwind();
+            // Whichever of the task timeout and the deadline ends first
+            // stops the execution.
+            let (limit, binding_deadline) = match (task_timeout, deadline.map(time_remaining)) {
+                (Some(timeout), Some(budget)) if timeout < budget => (Some(timeout), None),
+                (_, Some(budget)) => (Some(budget), deadline),
+                (timeout, None) => (timeout, None),
+            };
+            let result = match limit {
+                Some(limit) => tokio::time::timeout(limit, execution).await.ok(),
+                None => Some(execution.await),
+            };
+            let mut error = None;
+            let failure = match result {
+                Some(Ok(Ok(()))) => None,
+                Some(Ok(Err(e))) => {
+                    let text = e.to_string();
+                    error = Some(e);
+                    Some((ExecutorFailureKind::Error, text))
+                }
+                Some(Err(payload)) => Some((ExecutorFailureKind::Panic, panic_message(&*payload))),
+                None => {
+                    // The execution future is dropped; tell the agent too,
+                    // for work it spawned.
+                    timed_out.cancel();
+                    match binding_deadline {
+                        // Blocking callers get the error instead of the task.
+                        Some(deadline) => {
+                            error = Some(A2AError::deadline_exceeded(&task_id, deadline));
+                            let deadline = format_deadline(deadline);
+                            Some((
+                                ExecutorFailureKind::DeadlineExceeded,
+                                format!("deadline {deadline} passed"),
+                            ))
+                        }
+                        None => {
+                            let limit = task_timeout.unwrap_or_default();
+                            Some((ExecutorFailureKind::Timeout, format!("exceeded {limit:?}")))
+                        }
+                    }
+                }
+            };
+            // A panicked executor's workspace is released like before.
+            if !matches!(failure, Some((ExecutorFailureKind::Panic, _))) {
+                guard.finish();
+            }
+            if let Some((kind, e)) = failure {
+                let status_text = match kind {
+                    ExecutorFailureKind::Error => format!("Agent execution failed: {e}"),
+                    ExecutorFailureKind::Panic => format!("Agent execution panicked: {e}"),
+                    ExecutorFailureKind::Timeout => format!("Agent execution timed out: {e}"),
+                    ExecutorFailureKind::DeadlineExceeded => {
+                        format!("Agent execution exceeded its deadline: {e}")
+                    }
+                };
+
+                // An error with structured data is meant for the client:
+                // `message/send` answers with it instead of the task.
+                if let Some(error) = error.as_ref().filter(|e| e.data().is_some()) {
+                    rejection
+                        .lock()
+                        .unwrap()
+                        .get_or_insert_with(|| error.clone());
+                }
+
+                // Publish a failed status (matches Python SDK behavior where
+                // execution errors result in a failed task).
+                let failed_event =
+                    Self::failed_event(&task_id, &context_id, status_text, error.as_ref());
+                let _ = queue_clone.publish(failed_event);
+
+                reporter.report(&ExecutorErrorReport::new(
+                    &task_id,
+                    &context_id,
+                    &message,
+                    request_metadata.as_ref(),
+                    kind,
+                    e,
+                    &redactor,
+                ));
+            }
+            // Note: Python SDK calls queue.close() here. Our broadcast channel
+            // auto-closes when all senders are dropped, achieving the same effect.
+        });
+
+        // Track the running agent (mirrors Python's _register_producer).
+        let mut running = self.running_agents.lock().await;
+        running.insert(
+            task.id.clone(),
+            RunningAgent {
+                handle: Some(handle),
+                event_queue,
+                persisted,
+                output_rejection,
+                sequence,
+                cancellation,
+            },
+        );
+
+        Ok(rx)
+    }
+
+    /// Spawn the single authoritative event pipeline for a task.
+    ///
+    /// Subscribes to `source` immediately, then for every event: apply it to
+    /// the task store via [`TaskManager`], and only afterwards publish it on
+    /// `sink`, which is returned. The pipeline ends after a terminal or
+    /// `final` status update, or when `source` closes.
+    ///
+    /// With an [`OutputCheck`], events are adapted to the accepted output
+    /// modes first; a rejection is recorded and replaces the event with a
+    /// final `failed` status. An [`ArtifactLimit`] likewise replaces the
+    /// artifact update that outgrows it. Events the task state machine
+    /// refuses are dropped.
+    ///
+    /// A message as the first event is the agent's whole answer (see
+    /// [`EventQueue::reply_message`]) and ends the pipeline too. If the
+    /// task was `created` for the request (`created` holds the dedup key of
+    /// its message), it is deleted instead of recording the message, along
+    /// with its dedup and context store entries, and the message is
+    /// published without its task ID.
+    ///
+    /// `sequence` counts the published events; with a subscription registry
+    /// it is recorded after each one, and the record removed once the
+    /// pipeline ends with a final event.
+    ///
+    /// Runs in an `a2a.event_pipeline` span; each event is handled in an
+    /// `a2a.event.persist` span with its `sequence` and `source_sequence`
+    /// (its position on `source`, the `sequence` of its enqueue span).
+    fn spawn_event_pipeline(&self, pipeline: EventPipeline<'_>) -> EventQueue {
+        let EventPipeline {
+            task,
+            source,
+            sink,
+            sequence,
+            output_check,
+            mut artifact_limit,
+            created,
+        } = pipeline;
+        let persisted = sink.clone();
+        let task_store = Arc::clone(&self.task_store);
+        let mut rx = source.subscribe();
+        let registry = self.subscriptions.clone();
+        let event_log = self.event_log.clone();
+        let task_id = task.id.clone();
+        let context_id = task.context_id.clone();
+        let expiry = self.expiry.clone();
+        let workspaces = Arc::clone(&self.workspaces);
+        let history_policy = self.history_policy;
+        let lifecycle = self.lifecycle.clone();
+        let dedup = self.dedup.clone();
+        let contexts = self.contexts.clone();
+        #[cfg(feature = "observability")]
+        let (metrics, started) = (self.metrics.clone(), Instant::now());
+        let mut manager = match TaskManager::new(
+            Some(task.id.clone()),
+            Some(task.context_id.clone()),
+            Box::new(Arc::clone(&self.task_store)),
+            None,
+        ) {
+            Ok(manager) => manager
+                .with_history_policy(self.history_policy)
+                .with_transition_checks(self.transition_checks)
+                .with_listeners(self.lifecycle.clone()),
+            Err(e) => {
+                // Task IDs are always generated non-empty, so this is unreachable
+                // in practice; fall back to a pass-through pipeline.
+                error!(task_id = %task_id, error = %e, "Failed to create TaskManager");
+                return source.clone();
+            }
+        };
+        let pipeline_span = debug_span!(
+            "a2a.event_pipeline",
+            task_id = %task_id,
+            context_id = %context_id
+        );
+        tokio::spawn(async move {
+            let record = |last_sequence| SubscriptionRecord {
+                task_id: task_id.clone(),
+                context_id: context_id.clone(),
+                last_sequence,
+            };
+            if let Some(registry) = &registry {
+                if let Err(e) = registry
+                    .save(record(sequence.load(Ordering::Acquire)))
+                    .await
+                {
+                    warn!(task_id = %task_id, error = %e, "Failed to register task stream");
+                }
+            }
+            // Position of the received event on `source`.
+            let mut source_sequence = 0u64;
+            loop {
+                match rx.recv().await {
+                    Ok(event) => {
+                        source_sequence += 1;
+                        let mut event = match &output_check {
+                            Some(check) => {
+                                match adapt_event(check.adapter.as_ref(), event, &check.accepted) {
+                                    Ok(Some(event)) => event,
+                                    Ok(None) => continue,
+                                    Err(e) => {
+                                        warn!(task_id = %task_id, error = %e, "Output rejected");
+                                        let event =
+                                            Self::rejected_output_event(&task_id, &context_id, &e);
+                                        *check.rejection.lock().unwrap() = Some(e);
+                                        event
+                                    }
+                                }
+                            }
+                            None => event,
+                        };
+                        if let Some(limit) = &mut artifact_limit {
+                            if let Err(e) = limit.check(&event) {
+                                warn!(task_id = %task_id, error = %e, "Artifact rejected");
+                                event = Self::rejected_output_event(&task_id, &context_id, &e);
+                                *limit.rejection.lock().unwrap() = Some(e);
+                            }
+                        }
+                        let is_terminal = matches!(
+                            &event,
+                            StreamResponse::StatusUpdate(update)
+                                if Self::is_terminal(&update.status.state)
+                        );
+                        let direct_reply =
+                            source_sequence == 1 && matches!(event, StreamResponse::Message(_));
+                        let is_final = is_terminal
+                            || direct_reply
+                            || matches!(&event, StreamResponse::StatusUpdate(update) if update.r#final);
+                        // Counted once persisted: a refused event gets no
+                        // sequence number.
+                        let last_sequence = sequence.load(Ordering::Acquire) + 1;
+                        let span = debug_span!(
+                            "a2a.event.persist",
+                            sequence = last_sequence,
+                            source_sequence,
+                            kind = event.kind(),
+                        );
+
+                        let persisted = match &created {
+                            Some(key) if direct_reply => {
+                                if let StreamResponse::Message(message) = &mut event {
+                                    message.task_id = None;
+                                }
+                                workspaces.release(&task_id);
+                                debug!(task_id = %task_id, "Direct reply, discarding task");
+                                // A retry of the message must not resolve to
+                                // the deleted task.
+                                async {
+                                    task_store.delete(&task_id).await?;
+                                    if let Some(dedup) = &dedup {
+                                        dedup.forget(key).await?;
+                                    }
+                                    if let Some(contexts) = &contexts {
+                                        contexts.remove_task(&context_id, &task_id).await?;
+                                    }
+                                    Ok::<_, A2AError>(())
+                                }
+                                .instrument(span.clone())
+                                .await
+                            }
+                            _ => {
+                                persist_event(&mut manager, &event)
+                                    .instrument(span.clone())
+                                    .await
+                            }
+                        };
+                        match persisted {
+                            Ok(()) => {}
+                            Err(e @ A2AError::InvalidStateTransition { .. }) => {
+                                warn!(task_id = %task_id, error = %e, "Dropping event");
+                                continue;
+                            }
+                            Err(e) => {
+                                error!(task_id = %task_id, error = %e, "Failed to persist event");
+                            }
+                        }
+                        sequence.store(last_sequence, Ordering::Release);
+                        #[cfg(feature = "observability")]
+                        if let Some(metrics) = &metrics {
+                            super::telemetry::record_event(
+                                metrics.as_ref(),
+                                &task_id,
+                                &event,
+                                is_terminal,
+                                started,
+                            );
+                        }
+
+                        if let (Some(expiry), StreamResponse::StatusUpdate(update)) =
+                            (&expiry, &event)
                         {
-                            for (k, v) in event_obj {
-                                task_obj.insert(k.clone(), v.clone());
+                            if update.status.state == TaskState::InputRequired {
+                                expiry.schedule(
+                                    &task_id,
+                                    &context_id,
+                                    sink.clone(),
+                                    history_policy,
+                                    Arc::clone(&workspaces),
+                                    lifecycle.clone(),
+                                );
+                            } else {
+                                expiry.cancel(&task_id);
+                            }
+                        }
+
+                        if is_terminal {
+                            workspaces.release(&task_id);
+                        }
+                        if let Some(registry) = &registry {
+                            let result = if is_final {
+                                registry.remove(&task_id).instrument(span.clone()).await
+                            } else {
+                                registry
+                                    .save(record(last_sequence))
+                                    .instrument(span.clone())
+                                    .await
+                            };
+                            if let Err(e) = result {
+                                warn!(task_id = %task_id, error = %e, "Failed to update task stream record");
                             }
                         }
-                    } else {
-                        task.metadata = Some(event_meta.clone());
+                        match &event_log {
+                            Some(log) => {
+                                let publish = || {
+                                    let _ = span.in_scope(|| sink.publish(event.clone()));
+                                };
+                                log.record(&task_id, &event, publish)
+                                    .instrument(span.clone())
+                                    .await;
+                            }
+                            None => {
+                                let _ = span.in_scope(|| sink.publish(event));
+                            }
+                        }
+                        if is_final {
+                            break;
+                        }
                     }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                    Err(broadcast::error::RecvError::Lagged(n)) => {
+                        source_sequence += n;
+                        warn!(task_id = %task_id, missed = n, "Event pipeline lagged");
+                    }
+                }
+            }
+        }
+        .instrument(pipeline_span));
+
+        persisted
+    }
+
+    /// Consume persisted events until a terminal state is reached.
+    ///
+    /// Returns the final task as stored by the event pipeline.
+    async fn consume_until_terminal(
+        &self,
+        task_id: &str,
+        rx: broadcast::Receiver<StreamResponse>,
+    ) -> A2AResult<Task> {
+        self.consume_events(task_id, rx, false).await?;
+        self.final_task(task_id).await
+    }
+
+    /// Consume persisted events until a terminal or final one, then forget
+    /// the running agent.
+    ///
+    /// If `rx` starts with the execution and its first event is a message,
+    /// that is the agent's direct reply; it is returned instead.
+    async fn consume_events(
+        &self,
+        task_id: &str,
+        mut rx: broadcast::Receiver<StreamResponse>,
+        from_start: bool,
+    ) -> A2AResult<Option<Message>> {
+        let mut reply = None;
+        let mut first = from_start;
+        loop {
+            match rx.recv().await {
+                // Already persisted by the event pipeline.
+                Ok(StreamResponse::Message(message)) if first => {
+                    reply = Some(message);
+                    break;
+                }
+                Ok(StreamResponse::StatusUpdate(update))
+                    if Self::is_terminal(&update.status.state) || update.r#final =>
+                {
+                    break;
+                }
+                Ok(_) => first = false,
+                Err(broadcast::error::RecvError::Closed) => {
+                    // Channel closed — agent is done.
+                    break;
+                }
+                Err(broadcast::error::RecvError::Lagged(n)) => {
+                    warn!(task_id = %task_id, missed = n, "Event consumer lagged");
+                    // Continue receiving.
+                }
+            }
+        }
+
+        // Clean up the running agent entry.
+        let removed = self.running_agents.lock().await.remove(task_id);
+        if let Some(rejection) =
+            removed.and_then(|agent| agent.output_rejection.lock().unwrap().take())
+        {
+            return Err(rejection);
+        }
+        Ok(reply)
+    }
+
+    /// The task as stored once its execution ended.
+    async fn final_task(&self, task_id: &str) -> A2AResult<Task> {
+        self.task_store
+            .get(task_id)
+            .await?
+            .ok_or_else(|| A2AError::TaskNotFound {
+                message: task_id.to_string(),
+                data: None,
+            })
+    }
+
+    /// Subscribe to the persisted events of `agent`.
+    ///
+    /// With an event store, the subscriber first receives the events the
+    /// current execution has recorded so far. Otherwise a recovered stream
+    /// starts with a snapshot of the stored task, since the subscriber
+    /// can't have seen the events of the previous process. Either way the
+    /// receiver is subscribed before the prefix is read, so no event falls
+    /// between the two.
+    async fn subscribe(
+        &self,
+        agent: &RunningAgent,
+        task_id: &str,
+    ) -> broadcast::Receiver<StreamResponse> {
+        let capacity = agent.persisted.capacity();
+        let (recorded, source) = match &self.event_log {
+            Some(log) => match log.replay(task_id, || agent.persisted.receiver()).await {
+                Ok((events, source)) => {
+                    let source =
+                        source.with_store(Arc::clone(log.store()), task_id, events.len() as u64);
+                    (Self::latest_run(events, false), source)
                 }
-
-                task.status = update.status.clone();
+                Err(e) => {
+                    warn!(task_id = %task_id, error = %e, "Failed to read recorded events");
+                    (Vec::new(), agent.persisted.receiver())
+                }
+            },
+            None => (Vec::new(), agent.persisted.receiver()),
+        };
+        if !recorded.is_empty() {
+            return Self::relay(recorded, Some(source), capacity);
+        }
+        if agent.handle.is_some() {
+            return source.into_broadcast(capacity);
+        }
+        let snapshot = match self.task_store.get(task_id).await {
+            Ok(Some(task)) => task,
+            Ok(None) => return source.into_broadcast(capacity),
+            Err(e) => {
+                warn!(task_id = %task_id, error = %e, "Failed to load task snapshot");
+                return source.into_broadcast(capacity);
             }
-            StreamResponse::ArtifactUpdate(update) => {
-                let artifacts = task.artifacts.get_or_insert_with(Vec::new);
-                let append_parts = update.append.unwrap_or(false);
-                let artifact_id = &update.artifact.artifact_id;
-
-                // Find existing artifact by ID.
-                let existing_idx = artifacts.iter().position(|a| &a.artifact_id == artifact_id);
-
-                if !append_parts {
-                    // First chunk — replace existing or add new.
-                    if let Some(idx) = existing_idx {
-                        artifacts[idx] = update.artifact.clone();
-                    } else {
-                        artifacts.push(update.artifact.clone());
+        };
+        Self::relay(vec![StreamResponse::Task(snapshot)], Some(source), capacity)
+    }
+
+    /// A subscription to `queue` for a client stream, with the overflow
+    /// policy applied. For [`OverflowPolicy::BufferToStore`] it is taken
+    /// under the event log's lock, to know where in the recorded events it
+    /// starts.
+    async fn stream_receiver(
+        &self,
+        queue: &EventQueue,
+        task_id: &str,
+    ) -> broadcast::Receiver<StreamResponse> {
+        let receiver = match &self.event_log {
+            Some(log) if self.overflow == OverflowPolicy::BufferToStore => {
+                match log.replay(task_id, || queue.receiver()).await {
+                    Ok((events, receiver)) => {
+                        receiver.with_store(Arc::clone(log.store()), task_id, events.len() as u64)
+                    }
+                    Err(e) => {
+                        warn!(task_id = %task_id, error = %e, "Failed to read recorded events");
+                        queue.receiver()
                     }
-                } else if let Some(idx) = existing_idx {
-                    // Append parts to existing artifact.
-                    artifacts[idx].parts.extend(update.artifact.parts.clone());
-                } else {
-                    // append=true but no existing artifact — ignore per Python SDK.
-                    warn!(
-                        task_id = %task_id,
-                        artifact_id = %artifact_id,
-                        "Received append=True for nonexistent artifact — ignoring chunk"
-                    );
                 }
             }
-            StreamResponse::Task(updated_task) => {
-                task = updated_task.clone();
-            }
-            StreamResponse::Message(msg) => {
-                let history = task.history.get_or_insert_with(Vec::new);
-                history.push(msg.clone());
-            }
+            _ => queue.receiver(),
+        };
+        receiver.into_broadcast(queue.capacity())
+    }
+
+    /// The recorded events of the last execution of finished task
+    /// `task_id`, if an event store has any.
+    async fn replay_finished(
+        &self,
+        task_id: &str,
+    ) -> A2AResult<Option<broadcast::Receiver<StreamResponse>>> {
+        let Some(log) = &self.event_log else {
+            return Ok(None);
+        };
+        let (events, ()) = log.replay(task_id, || ()).await?;
+        let events = Self::latest_run(events, true);
+        Ok((!events.is_empty()).then(|| Self::relay(events, None, self.queue_capacity)))
+    }
+
+    /// The events of the latest execution among a task's recorded
+    /// `events`: those after the last final status update, or with
+    /// `finished` the one before it, so a replay never stops at the end of
+    /// an earlier execution (e.g. at `input-required`).
+    fn latest_run(mut events: Vec<StreamResponse>, finished: bool) -> Vec<StreamResponse> {
+        let is_final = |event: &StreamResponse| {
+            matches!(
+                event,
+                StreamResponse::StatusUpdate(update)
+                    if update.r#final || Self::is_terminal(&update.status.state)
+            )
+        };
+        let searched = if finished {
+            events.len().saturating_sub(1)
+        } else {
+            events.len()
+        };
+        if let Some(last) = events[..searched].iter().rposition(is_final) {
+            events.drain(..=last);
         }
+        events
+    }
+
+    /// A receiver yielding `prefix`, then the events of `source` up to the
+    /// next final one.
+    fn relay(
+        prefix: Vec<StreamResponse>,
+        source: Option<EventReceiver>,
+        capacity: usize,
+    ) -> broadcast::Receiver<StreamResponse> {
+        let source = source.unwrap_or_else(|| {
+            // Closed right away: only the prefix is relayed.
+            EventReceiver::new(broadcast::channel(1).1, OverflowPolicy::default())
+        });
+        source
+            .with_prefix(prefix)
+            .until_final()
+            .into_broadcast(capacity)
+    }
+
+    /// Final `failed` status replacing output rejected by the output
+    /// adapter or an [`ArtifactLimit`].
+    fn rejected_output_event(task_id: &str, context_id: &str, err: &A2AError) -> StreamResponse {
+        Self::failed_event(task_id, context_id, err.to_string(), Some(err))
+    }
 
-        self.task_store.save(task).await
+    /// Final `failed` status with `text` as the agent message. The
+    /// JSON-RPC form of `err`, if any, is kept in the event metadata under
+    /// `error`, and so merged into the task's.
+    fn failed_event(
+        task_id: &str,
+        context_id: &str,
+        text: String,
+        err: Option<&A2AError>,
+    ) -> StreamResponse {
+        let error = err.and_then(|err| {
+            serde_json::to_value(crate::types::JsonRpcError::from(err.clone())).ok()
+        });
+        StreamResponse::StatusUpdate(TaskStatusUpdateEvent {
+            task_id: task_id.to_string(),
+            context_id: context_id.to_string(),
+            kind: "status-update".to_string(),
+            status: TaskStatus::with_timestamp(TaskState::Failed, chrono::Utc::now().to_rfc3339())
+                .with_message(Message {
+                    message_id: Uuid::new_v4().to_string(),
+                    role: crate::types::Role::Agent,
+                    kind: "message".to_string(),
+                    parts: vec![Part::text(text)],
+                    context_id: None,
+                    task_id: Some(task_id.to_string()),
+                    metadata: None,
+                    extensions: None,
+                    reference_task_ids: None,
+                }),
+            r#final: true,
+            metadata: error.map(|error| serde_json::json!({ "error": error })),
+        })
     }
 
     /// Check if a state is terminal.
     fn is_terminal(state: &TaskState) -> bool {
-        matches!(
-            state,
-            TaskState::Completed | TaskState::Failed | TaskState::Canceled | TaskState::Rejected
-        )
+        is_terminal_state(state)
     }
 
     /// Trim task history to the requested length.
@@ -556,166 +2302,271 @@ impl DefaultRequestHandler {
     /// - Only trims if `max_length` is `Some` AND > 0 AND history exists.
     /// - Keeps the most recent N messages (tail).
     fn trim_history(task: &mut Task, max_length: Option<usize>) {
-        if let Some(max) = max_length {
-            if max > 0 {
-                if let Some(ref mut history) = task.history {
-                    if history.len() > max {
-                        let start = history.len() - max;
-                        *history = history.split_off(start);
-                    }
-                }
-            }
+        if let Some(max) = max_length.filter(|&max| max > 0) {
+            task.truncate_history(max);
+        }
+    }
+
+    /// The push config store, or `UnsupportedOperation` without one.
+    fn push_config_store(&self) -> A2AResult<&Arc<dyn PushNotificationConfigStore>> {
+        self.push_configs
+            .as_ref()
+            .ok_or_else(|| A2AError::UnsupportedOperation {
+                message: "Push notification config is not supported".to_string(),
+                data: None,
+            })
+    }
+
+    /// `TaskNotFound` unless `task_id` is in the task store and belongs to
+    /// `tenant`.
+    async fn require_task(&self, tenant: Option<&str>, task_id: &str) -> A2AResult<()> {
+        match self.task_store.get_for_tenant(tenant, task_id).await? {
+            Some(_) => Ok(()),
+            None => Err(A2AError::task_not_found(task_id)),
+        }
+    }
+
+    /// Store `config` for `task_id`, defaulting its ID to the task ID.
+    async fn store_push_config(
+        store: &Arc<dyn PushNotificationConfigStore>,
+        mut config: TaskPushNotificationConfig,
+    ) -> A2AResult<TaskPushNotificationConfig> {
+        if config.push_notification_config.id.is_none() {
+            config.push_notification_config.id = Some(config.task_id.clone());
         }
+        store.set(config.clone()).await?;
+        Ok(config)
+    }
+
+    /// Keep the `pushNotificationConfig` sent with a message for `task`, if
+    /// a push config store is configured.
+    async fn remember_push_config(
+        &self,
+        task: &Task,
+        configuration: Option<&SendMessageConfiguration>,
+    ) -> A2AResult<()> {
+        let (Some(store), Some(value)) = (
+            &self.push_configs,
+            configuration.and_then(|c| c.push_notification_config.as_ref()),
+        ) else {
+            return Ok(());
+        };
+        let config: PushNotificationConfig = parse_params("pushNotificationConfig", value.clone())?;
+        Self::store_push_config(
+            store,
+            TaskPushNotificationConfig {
+                id: None,
+                task_id: task.id.clone(),
+                push_notification_config: config,
+                tenant: task_tenant(task).map(String::from),
+            },
+        )
+        .await?;
+        Ok(())
     }
 }
 
-#[async_trait]
-impl RequestHandler for DefaultRequestHandler {
-    async fn on_message_send(&self, params: SendMessageParams) -> A2AResult<SendMessageResponse> {
-        let task = self.get_or_create_task(&params).await?;
-        let event_queue = self
-            .spawn_executor(&task, &params.message, params.configuration.as_ref())
+impl DefaultRequestHandler {
+    /// Refuse messages the content validator refuses.
+    async fn check_content(&self, params: &SendMessageParams) -> A2AResult<()> {
+        self.content_validator
+            .validate_message(&params.message)
+            .await
+            .map_err(ContentViolation::into_input_error)
+    }
+
+    /// Serve `message/send`, or `message/sendStream` with the rest of the
+    /// message in `incoming`.
+    async fn send_message(
+        &self,
+        params: SendMessageParams,
+        incoming: Option<IncrementalMessage>,
+    ) -> A2AResult<SendMessageResponse> {
+        self.check_open()?;
+        self.check_input_modes(&params)?;
+        self.check_part_sizes(&params)?;
+        self.check_content(&params).await?;
+        let history_length = params.configuration.as_ref().and_then(|c| c.history_length);
+        let (task, duplicate) = self.resolve_task(&params).await?;
+        if duplicate {
+            let mut task = task;
+            Self::trim_history(&mut task, history_length);
+            return Ok(SendMessageResponse::Task(task));
+        }
+        self.remember_push_config(&task, params.configuration.as_ref())
+            .await?;
+        let created = params.message.task_id.is_none();
+        let rx = self
+            .spawn_executor(
+                &task,
+                &params.message,
+                params.configuration.as_ref(),
+                created,
+                incoming.clone(),
+            )
             .await?;
-        let rx = event_queue.subscribe();
 
-        // Consume events until terminal.
-        let mut final_task = self.consume_until_terminal(&task.id, rx).await?;
+        // Consume events until terminal, or the agent's direct reply.
+        let (reply, ()) = tokio::join!(
+            self.consume_events(&task.id, rx, true),
+            self.record_uploaded(&task.id, incoming)
+        );
+        if let Some(reply) = reply? {
+            return Ok(SendMessageResponse::Message(reply));
+        }
+        let mut final_task = self.final_task(&task.id).await?;
 
         // Apply history_length trimming.
-        let history_length = params.configuration.as_ref().and_then(|c| c.history_length);
         Self::trim_history(&mut final_task, history_length);
 
         Ok(SendMessageResponse::Task(final_task))
     }
 
-    async fn on_message_send_stream(
+    /// Serve `message/stream`.
+    async fn send_message_stream(
         &self,
         params: SendMessageParams,
     ) -> A2AResult<broadcast::Receiver<StreamResponse>> {
-        let task = self.get_or_create_task(&params).await?;
-        let event_queue = self
-            .spawn_executor(&task, &params.message, params.configuration.as_ref())
+        self.check_open()?;
+        self.check_input_modes(&params)?;
+        self.check_part_sizes(&params)?;
+        self.check_content(&params).await?;
+        let (task, duplicate) = self.resolve_task(&params).await?;
+        if duplicate {
+            // Attach to the original execution if it is still running,
+            // otherwise replay the task as stored.
+            if !Self::is_terminal(&task.status.state) {
+                let persisted = self
+                    .running_agents
+                    .lock()
+                    .await
+                    .get(&task.id)
+                    .map(|agent| agent.persisted.clone());
+                if let Some(persisted) = persisted {
+                    return Ok(self.stream_receiver(&persisted, &task.id).await);
+                }
+            }
+            let (tx, rx) = broadcast::channel(1);
+            let _ = tx.send(StreamResponse::Task(task));
+            return Ok(rx);
+        }
+        self.remember_push_config(&task, params.configuration.as_ref())
             .await?;
-        let rx = event_queue.subscribe();
-
-        // Spawn a background task to persist events as they arrive.
-        let task_id = task.id.clone();
-        let task_store = Arc::clone(&self.task_store);
-
-        // We need a separate subscription for persistence.
-        let mut persist_rx = event_queue.subscribe();
-
-        tokio::spawn(async move {
-            loop {
-                match persist_rx.recv().await {
-                    Ok(event) => {
-                        // Apply event to task store — we need to inline the logic here
-                        // since we can't call self methods from a spawned task.
-                        if let Ok(Some(mut task)) = {
-                            let store = &task_store;
-                            store.get(&task_id).await
-                        } {
-                            match &event {
-                                StreamResponse::StatusUpdate(update) => {
-                                    // Move current status.message to history first
-                                    // (mirrors Python SDK's save_task_event).
-                                    if let Some(ref current_msg) = task.status.message {
-                                        let history = task.history.get_or_insert_with(Vec::new);
-                                        history.push(current_msg.clone());
-                                    }
-                                    // Merge event metadata into task metadata.
-                                    if let Some(ref event_meta) = update.metadata {
-                                        if let Some(ref mut task_meta) = task.metadata {
-                                            if let (Some(task_obj), Some(event_obj)) =
-                                                (task_meta.as_object_mut(), event_meta.as_object())
-                                            {
-                                                for (k, v) in event_obj {
-                                                    task_obj.insert(k.clone(), v.clone());
-                                                }
-                                            }
-                                        } else {
-                                            task.metadata = Some(event_meta.clone());
-                                        }
-                                    }
-                                    task.status = update.status.clone();
-                                }
-                                StreamResponse::ArtifactUpdate(update) => {
-                                    let artifacts = task.artifacts.get_or_insert_with(Vec::new);
-                                    let append_parts = update.append.unwrap_or(false);
-                                    let artifact_id = &update.artifact.artifact_id;
-                                    let existing_idx = artifacts
-                                        .iter()
-                                        .position(|a| &a.artifact_id == artifact_id);
-                                    if !append_parts {
-                                        if let Some(idx) = existing_idx {
-                                            artifacts[idx] = update.artifact.clone();
-                                        } else {
-                                            artifacts.push(update.artifact.clone());
-                                        }
-                                    } else if let Some(idx) = existing_idx {
-                                        artifacts[idx].parts.extend(update.artifact.parts.clone());
-                                    }
-                                    // append=true with no existing artifact: silently ignore
-                                }
-                                StreamResponse::Task(updated_task) => {
-                                    task = updated_task.clone();
-                                }
-                                StreamResponse::Message(msg) => {
-                                    let history = task.history.get_or_insert_with(Vec::new);
-                                    history.push(msg.clone());
-                                }
-                            }
-                            let _ = task_store.save(task).await;
-                        }
+        // Events are persisted by the pipeline before they reach `rx`.
+        let created = params.message.task_id.is_none();
+        self.spawn_executor(
+            &task,
+            &params.message,
+            params.configuration.as_ref(),
+            created,
+            None,
+        )
+        .await
+    }
 
-                        // Check for terminal state.
-                        if let StreamResponse::StatusUpdate(ref update) = event {
-                            if DefaultRequestHandler::is_terminal(&update.status.state)
-                                || update.r#final
-                            {
-                                break;
-                            }
-                        }
-                    }
-                    Err(broadcast::error::RecvError::Closed) => break,
-                    Err(broadcast::error::RecvError::Lagged(n)) => {
-                        warn!(task_id = %task_id, missed = n, "Persist consumer lagged");
-                    }
-                }
+    /// Replace the message of a `message/sendStream` call in the task's
+    /// history with the whole message, once its last part arrived.
+    ///
+    /// Tasks discarded for a direct reply, and uploads that broke off, are
+    /// left alone.
+    async fn record_uploaded(&self, task_id: &str, incoming: Option<IncrementalMessage>) {
+        let Some(incoming) = incoming else {
+            return;
+        };
+        let message = match incoming.assemble().await {
+            Ok(message) => message,
+            Err(e) => {
+                debug!(task_id, error = %e, "Message upload broke off");
+                return;
+            }
+        };
+        let update: TaskUpdate = Box::new(move |task| {
+            let turn = task
+                .history
+                .iter_mut()
+                .flatten()
+                .find(|m| m.message_id == message.message_id);
+            if let Some(turn) = turn {
+                turn.parts = message.parts;
             }
+            Ok(())
         });
+        if let Err(e) = self.task_store.update(task_id, None, update).await {
+            debug!(task_id, error = %e, "Uploaded message not recorded");
+        }
+    }
 
-        Ok(rx)
+    /// Serve a notification.
+    async fn notify(&self, method: &str, params: serde_json::Value) -> A2AResult<()> {
+        match &self.on_notification {
+            Some(callback) => callback(method, &params),
+            None => debug!(method, "Ignoring notification"),
+        }
+        Ok(())
     }
 
-    async fn on_get_task(&self, params: GetTaskParams) -> A2AResult<Task> {
-        let mut task =
-            self.task_store
-                .get(&params.id)
-                .await?
-                .ok_or_else(|| A2AError::TaskNotFound {
-                    message: params.id.clone(),
-                    data: None,
-                })?;
+    /// Serve `tasks/get`.
+    async fn get_task(&self, params: GetTaskParams) -> A2AResult<Task> {
+        let consistency = params.consistency.unwrap_or_default();
+        let mut task = self
+            .task_store
+            .get_with_consistency(&params.id, &consistency)
+            .await?
+            .ok_or_else(|| A2AError::TaskNotFound {
+                message: params.id.clone(),
+                data: None,
+            })?;
+        check_tenant(&task, params.tenant.as_deref())?;
 
         Self::trim_history(&mut task, params.history_length);
         Ok(task)
     }
 
-    async fn on_list_tasks(&self, params: TaskListParams) -> A2AResult<TaskListResponse> {
-        self.task_store.list(&params).await
+    /// Serve `tasks/artifacts/list` from the task store.
+    async fn list_artifacts(&self, params: ListArtifactsParams) -> A2AResult<ListArtifactsResult> {
+        let artifacts = self
+            .task_store
+            .list_artifacts(params.tenant.as_deref(), &params.task_id)
+            .await?;
+        Ok(ListArtifactsResult {
+            task_id: params.task_id,
+            artifacts,
+        })
     }
 
-    async fn on_cancel_task(&self, params: CancelTaskParams) -> A2AResult<Task> {
+    /// Serve `tasks/artifacts/get` from the task store.
+    async fn get_artifact(&self, params: GetArtifactParams) -> A2AResult<Artifact> {
+        self.task_store
+            .get_artifact(
+                params.tenant.as_deref(),
+                &params.task_id,
+                &params.artifact_id,
+            )
+            .await
+    }
+
+    /// Serve `tasks/list`.
+    async fn list_tasks(&self, mut params: TaskListParams) -> A2AResult<TaskListResponse> {
+        // Only the caller's tenant's tasks; "" selects tasks without one.
+        params.tenant.get_or_insert_with(String::new);
+        let mut response = self.task_store.list(&params).await?;
+        for task in &mut response.tasks {
+            Self::trim_history(task, params.history_length);
+        }
+        Ok(response)
+    }
+
+    /// Serve `tasks/cancel`.
+    async fn cancel_task(&self, params: CancelTaskParams) -> A2AResult<Task> {
         // Look up the task.
-        let task =
-            self.task_store
-                .get(&params.id)
-                .await?
-                .ok_or_else(|| A2AError::TaskNotFound {
-                    message: params.id.clone(),
-                    data: None,
-                })?;
+        let task = self
+            .task_store
+            .get_for_tenant(params.tenant.as_deref(), &params.id)
+            .await?
+            .ok_or_else(|| A2AError::TaskNotFound {
+                message: params.id.clone(),
+                data: None,
+            })?;
 
         // Can't cancel a terminal task (mirrors Python SDK check).
         if Self::is_terminal(&task.status.state) {
@@ -728,19 +2579,33 @@ impl RequestHandler for DefaultRequestHandler {
             });
         }
 
-        // Get or create event queue for this task.
-        let event_queue = {
+        // Get or create event queue (and its persisted side) for this task,
+        // and tell a running executor it is being canceled.
+        let (event_queue, rx, cancellation) = {
             let running = self.running_agents.lock().await;
             if let Some(agent) = running.get(&params.id) {
-                agent.event_queue.clone()
+                agent.cancellation.cancel();
+                (
+                    agent.event_queue.clone(),
+                    agent.persisted.subscribe(),
+                    agent.cancellation.clone(),
+                )
             } else {
-                // No running agent — create a temporary queue.
-                EventQueue::with_default_capacity()
+                // No running agent — create a temporary queue and pipeline.
+                let event_queue = self.new_queue();
+                let persisted = self.spawn_event_pipeline(EventPipeline::new(
+                    &task,
+                    &event_queue,
+                    self.new_queue(),
+                    Arc::default(),
+                ));
+                let rx = persisted.subscribe();
+                let cancellation = CancellationToken::new();
+                cancellation.cancel();
+                (event_queue, rx, cancellation)
             }
         };
 
-        let rx = event_queue.subscribe();
-
         // Call the executor's cancel method.
         // Python SDK passes `None` for the request in cancel context.
         let context = RequestContext {
@@ -751,7 +2616,13 @@ impl RequestHandler for DefaultRequestHandler {
             configuration: None,
             related_tasks: Vec::new(),
             metadata: params.metadata,
-            call_context: None,
+            tenant: task_tenant(&task).map(String::from),
+            call_context: ServerCallContext::current(),
+            task_store: Some(Arc::clone(&self.task_store)),
+            workspace: Some(self.workspaces.acquire(&task.id)),
+            cancellation_token: cancellation,
+            incremental_message: None,
+            content_validator: Some(Arc::clone(&self.content_validator)),
         };
 
         self.executor.cancel(context, event_queue.clone()).await?;
@@ -760,8 +2631,8 @@ impl RequestHandler for DefaultRequestHandler {
         // (mirrors Python SDK's `producer_task.cancel()`).
         {
             let running = self.running_agents.lock().await;
-            if let Some(agent) = running.get(&params.id) {
-                agent.handle.abort();
+            if let Some(handle) = running.get(&params.id).and_then(|a| a.handle.as_ref()) {
+                handle.abort();
             }
         }
 
@@ -783,22 +2654,27 @@ impl RequestHandler for DefaultRequestHandler {
         Ok(final_task)
     }
 
-    async fn on_subscribe_to_task(
+    /// Serve `tasks/subscribe`.
+    async fn subscribe_to_task(
         &self,
         params: SubscribeToTaskParams,
     ) -> A2AResult<broadcast::Receiver<StreamResponse>> {
         // Verify the task exists.
-        let task =
-            self.task_store
-                .get(&params.id)
-                .await?
-                .ok_or_else(|| A2AError::TaskNotFound {
-                    message: params.id.clone(),
-                    data: None,
-                })?;
+        let task = self
+            .task_store
+            .get_for_tenant(params.tenant.as_deref(), &params.id)
+            .await?
+            .ok_or_else(|| A2AError::TaskNotFound {
+                message: params.id.clone(),
+                data: None,
+            })?;
 
-        // If the task is already terminal, return an error.
+        // If the task is already terminal, replay its recorded events or
+        // return an error.
         if Self::is_terminal(&task.status.state) {
+            if let Some(replay) = self.replay_finished(&params.id).await? {
+                return Ok(replay);
+            }
             return Err(A2AError::InvalidParams {
                 message: format!(
                     "Task {} is in terminal state {:?} — cannot subscribe",
@@ -811,7 +2687,7 @@ impl RequestHandler for DefaultRequestHandler {
         // Get the event queue for this running task.
         let running = self.running_agents.lock().await;
         if let Some(agent) = running.get(&params.id) {
-            Ok(agent.event_queue.subscribe())
+            Ok(self.subscribe(agent, &params.id).await)
         } else {
             Err(A2AError::TaskNotFound {
                 message: format!(
@@ -823,22 +2699,27 @@ impl RequestHandler for DefaultRequestHandler {
         }
     }
 
-    async fn on_resubscribe_to_task(
+    /// Serve `tasks/resubscribe`.
+    async fn resubscribe_to_task(
         &self,
         params: SubscribeToTaskParams,
     ) -> A2AResult<broadcast::Receiver<StreamResponse>> {
         // Verify the task exists (mirrors Python SDK).
-        let task =
-            self.task_store
-                .get(&params.id)
-                .await?
-                .ok_or_else(|| A2AError::TaskNotFound {
-                    message: params.id.clone(),
-                    data: None,
-                })?;
+        let task = self
+            .task_store
+            .get_for_tenant(params.tenant.as_deref(), &params.id)
+            .await?
+            .ok_or_else(|| A2AError::TaskNotFound {
+                message: params.id.clone(),
+                data: None,
+            })?;
 
-        // If the task is already terminal, return an error (mirrors Python SDK).
+        // If the task is already terminal, replay its recorded events or
+        // return an error (mirrors Python SDK).
         if Self::is_terminal(&task.status.state) {
+            if let Some(replay) = self.replay_finished(&params.id).await? {
+                return Ok(replay);
+            }
             return Err(A2AError::InvalidParams {
                 message: format!(
                     "Task {} is in terminal state: {}",
@@ -851,7 +2732,7 @@ impl RequestHandler for DefaultRequestHandler {
         // Get the event queue for this running task (mirrors Python SDK's queue_manager.tap).
         let running = self.running_agents.lock().await;
         if let Some(agent) = running.get(&params.id) {
-            Ok(agent.event_queue.subscribe())
+            Ok(self.subscribe(agent, &params.id).await)
         } else {
             Err(A2AError::TaskNotFound {
                 message: format!("Task {} has no active agent execution", params.id),
@@ -859,4 +2740,289 @@ impl RequestHandler for DefaultRequestHandler {
             })
         }
     }
+
+    /// Serve `tasks/pushNotificationConfig/set`.
+    async fn set_push_config(&self, params: serde_json::Value) -> A2AResult<serde_json::Value> {
+        let store = self.push_config_store()?;
+        // Accept both the spec's `TaskPushNotificationConfig` and the
+        // `{taskId, configId, config}` shape sent by `A2AClient`.
+        let config = if params.get("config").is_some() {
+            let params: CreateTaskPushNotificationConfigParams =
+                parse_params("tasks/pushNotificationConfig/set", params)?;
+            let mut config = params.config;
+            if !params.config_id.is_empty() {
+                config.id = Some(params.config_id);
+            }
+            TaskPushNotificationConfig {
+                id: None,
+                task_id: params.task_id,
+                push_notification_config: config,
+                tenant: params.tenant,
+            }
+        } else {
+            parse_params("tasks/pushNotificationConfig/set", params)?
+        };
+        self.require_task(config.tenant.as_deref(), &config.task_id)
+            .await?;
+        let config = Self::store_push_config(store, config).await?;
+        serde_json::to_value(config).map_err(|e| A2AError::internal_error(e.to_string()))
+    }
+
+    /// Serve `tasks/pushNotificationConfig/get`.
+    async fn get_push_config(&self, params: serde_json::Value) -> A2AResult<serde_json::Value> {
+        let store = self.push_config_store()?;
+        let params: GetTaskPushNotificationConfigParams =
+            parse_params("tasks/pushNotificationConfig/get", params)?;
+        self.require_task(params.tenant.as_deref(), &params.id)
+            .await?;
+        let config = match &params.push_notification_config_id {
+            Some(config_id) => store.get(&params.id, config_id).await?,
+            None => store.list(&params.id).await?.into_iter().next(),
+        };
+        let config = config.ok_or_else(|| {
+            A2AError::invalid_params(format!(
+                "No push notification config {} for task {}",
+                params
+                    .push_notification_config_id
+                    .as_deref()
+                    .unwrap_or("set"),
+                params.id
+            ))
+        })?;
+        serde_json::to_value(config).map_err(|e| A2AError::internal_error(e.to_string()))
+    }
+
+    /// Serve `tasks/pushNotificationConfig/list`.
+    async fn list_push_configs(&self, params: serde_json::Value) -> A2AResult<serde_json::Value> {
+        let store = self.push_config_store()?;
+        let params: ListTaskPushNotificationConfigParams =
+            parse_params("tasks/pushNotificationConfig/list", params)?;
+        self.require_task(params.tenant.as_deref(), &params.id)
+            .await?;
+        // The bare array of the spec, unless the client asks for pages.
+        if params.page_size.is_none() && params.page_token.is_none() {
+            let configs = store.list(&params.id).await?;
+            return serde_json::to_value(configs)
+                .map_err(|e| A2AError::internal_error(e.to_string()));
+        }
+        let page_size = match params.page_size {
+            Some(size) => usize::try_from(size)
+                .ok()
+                .filter(|&size| size > 0)
+                .ok_or_else(|| A2AError::invalid_params(format!("Invalid pageSize {size}")))?,
+            None => usize::MAX,
+        };
+        let page = store
+            .list_page(&params.id, page_size, params.page_token.as_deref())
+            .await?;
+        serde_json::to_value(page).map_err(|e| A2AError::internal_error(e.to_string()))
+    }
+
+    /// Serve `tasks/pushNotificationConfig/delete`.
+    async fn delete_push_config(&self, params: serde_json::Value) -> A2AResult<()> {
+        let store = self.push_config_store()?;
+        let params: DeleteTaskPushNotificationConfigParams =
+            parse_params("tasks/pushNotificationConfig/delete", params)?;
+        self.require_task(params.tenant.as_deref(), &params.id)
+            .await?;
+        store
+            .delete(&params.id, &params.push_notification_config_id)
+            .await
+    }
+}
+
+#[async_trait]
+impl RequestHandler for DefaultRequestHandler {
+    async fn on_message_send(&self, params: SendMessageParams) -> A2AResult<SendMessageResponse> {
+        intercept(&self.interceptors, "message/send", params, |params| {
+            self.send_message(params, None)
+        })
+        .await
+    }
+
+    async fn on_message_send_incremental(
+        &self,
+        params: SendMessageParams,
+        incoming: IncrementalMessage,
+    ) -> A2AResult<SendMessageResponse> {
+        intercept(
+            &self.interceptors,
+            MESSAGE_SEND_STREAM_METHOD,
+            params,
+            |params| self.send_message(params, Some(incoming)),
+        )
+        .await
+    }
+
+    async fn on_message_send_stream(
+        &self,
+        params: SendMessageParams,
+    ) -> A2AResult<broadcast::Receiver<StreamResponse>> {
+        intercept(&self.interceptors, "message/stream", params, |params| {
+            self.send_message_stream(params)
+        })
+        .await
+    }
+
+    async fn on_notification(&self, method: &str, params: serde_json::Value) -> A2AResult<()> {
+        intercept(&self.interceptors, method, params, |params| {
+            self.notify(method, params)
+        })
+        .await
+    }
+
+    async fn on_get_task(&self, params: GetTaskParams) -> A2AResult<Task> {
+        intercept(&self.interceptors, "tasks/get", params, |params| {
+            self.get_task(params)
+        })
+        .await
+    }
+
+    async fn on_list_tasks(&self, params: TaskListParams) -> A2AResult<TaskListResponse> {
+        intercept(&self.interceptors, "tasks/list", params, |params| {
+            self.list_tasks(params)
+        })
+        .await
+    }
+
+    async fn on_cancel_task(&self, params: CancelTaskParams) -> A2AResult<Task> {
+        intercept(&self.interceptors, "tasks/cancel", params, |params| {
+            self.cancel_task(params)
+        })
+        .await
+    }
+
+    async fn on_cancel_context(
+        &self,
+        params: CancelContextParams,
+    ) -> A2AResult<CancelContextResult> {
+        intercept(
+            &self.interceptors,
+            CONTEXTS_CANCEL_METHOD,
+            params,
+            |params| cancel_context(self, params),
+        )
+        .await
+    }
+
+    async fn on_list_artifacts(
+        &self,
+        params: ListArtifactsParams,
+    ) -> A2AResult<ListArtifactsResult> {
+        intercept(
+            &self.interceptors,
+            TASKS_ARTIFACTS_LIST_METHOD,
+            params,
+            |params| self.list_artifacts(params),
+        )
+        .await
+    }
+
+    async fn on_get_artifact(&self, params: GetArtifactParams) -> A2AResult<Artifact> {
+        intercept(
+            &self.interceptors,
+            TASKS_ARTIFACTS_GET_METHOD,
+            params,
+            |params| self.get_artifact(params),
+        )
+        .await
+    }
+
+    async fn on_subscribe_to_task(
+        &self,
+        params: SubscribeToTaskParams,
+    ) -> A2AResult<broadcast::Receiver<StreamResponse>> {
+        intercept(&self.interceptors, "tasks/subscribe", params, |params| {
+            self.subscribe_to_task(params)
+        })
+        .await
+    }
+
+    async fn on_resubscribe_to_task(
+        &self,
+        params: SubscribeToTaskParams,
+    ) -> A2AResult<broadcast::Receiver<StreamResponse>> {
+        intercept(&self.interceptors, "tasks/resubscribe", params, |params| {
+            self.resubscribe_to_task(params)
+        })
+        .await
+    }
+
+    async fn on_set_task_push_notification_config(
+        &self,
+        params: serde_json::Value,
+    ) -> A2AResult<serde_json::Value> {
+        intercept(
+            &self.interceptors,
+            "tasks/pushNotificationConfig/set",
+            params,
+            |params| self.set_push_config(params),
+        )
+        .await
+    }
+
+    async fn on_get_task_push_notification_config(
+        &self,
+        params: serde_json::Value,
+    ) -> A2AResult<serde_json::Value> {
+        intercept(
+            &self.interceptors,
+            "tasks/pushNotificationConfig/get",
+            params,
+            |params| self.get_push_config(params),
+        )
+        .await
+    }
+
+    async fn on_list_task_push_notification_config(
+        &self,
+        params: serde_json::Value,
+    ) -> A2AResult<serde_json::Value> {
+        intercept(
+            &self.interceptors,
+            "tasks/pushNotificationConfig/list",
+            params,
+            |params| self.list_push_configs(params),
+        )
+        .await
+    }
+
+    async fn on_delete_task_push_notification_config(
+        &self,
+        params: serde_json::Value,
+    ) -> A2AResult<()> {
+        intercept(
+            &self.interceptors,
+            "tasks/pushNotificationConfig/delete",
+            params,
+            |params| self.delete_push_config(params),
+        )
+        .await
+    }
+}
+
+/// Decode the params of `method`, or `InvalidParams`.
+fn parse_params<T: serde::de::DeserializeOwned>(
+    method: &str,
+    params: serde_json::Value,
+) -> A2AResult<T> {
+    serde_json::from_value(params)
+        .map_err(|e| A2AError::invalid_params(format!("Invalid {method} params: {e}")))
+}
+
+/// Apply one executor event to the task store through `manager`.
+///
+/// Under [`HistoryPolicy::OnReplace`], `TaskManager::process` passes direct
+/// messages through untouched; the handler still keeps them in the task
+/// history.
+async fn persist_event(manager: &mut TaskManager, event: &StreamResponse) -> A2AResult<()> {
+    match event {
+        StreamResponse::Message(msg) if manager.history_policy() == HistoryPolicy::OnReplace => {
+            manager.append_history(msg.clone()).await?;
+        }
+        _ => {
+            manager.process(event.clone()).await?;
+        }
+    }
+    Ok(())
 }
@@ -0,0 +1,253 @@
+//! Task retention — bounding how many finished tasks a store keeps.
+//!
+//! Task stores keep every task until it is deleted, so a long-running
+//! server's [`InMemoryTaskStore`](super::InMemoryTaskStore) grows without
+//! bound. A [`TaskSweeper`] periodically applies a [`RetentionPolicy`] to a
+//! store: it [purges](super::TaskStore::purge) tasks that finished longer
+//! ago than the policy's maximum age, then evicts the oldest finished tasks
+//! while the store holds more than the maximum number of tasks. Tasks that
+//! are still running are never removed.
+//!
+//! [`ServerBuilder::with_retention`](crate::builders::ServerBuilder::with_retention)
+//! starts a sweeper for the server's store:
+//!
+//! ```rust,ignore
+//! let app = ServerBuilder::new(executor)
+//!     .with_retention(
+//!         RetentionPolicy::default()
+//!             .with_max_terminal_age(Duration::from_secs(3600))
+//!             .with_max_tasks(10_000),
+//!     )
+//!     .build();
+//! ```
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use tokio::task::JoinHandle;
+use tracing::{debug, info, warn};
+
+use crate::error::A2AResult;
+use crate::types::Task;
+
+use super::task_store::{TaskListParams, TaskStore};
+
+/// [`MetricsRecorder::task_evicted`](super::MetricsRecorder::task_evicted)
+/// reason of tasks that finished longer ago than the maximum age.
+pub const EVICTION_EXPIRED: &str = "expired";
+
+/// [`MetricsRecorder::task_evicted`](super::MetricsRecorder::task_evicted)
+/// reason of tasks evicted because the store held too many tasks.
+pub const EVICTION_CAPACITY: &str = "capacity";
+
+/// How long finished tasks are kept, and how many tasks a store may hold.
+///
+/// The default keeps everything and sweeps once a minute.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+/// use a2a_rs::server::RetentionPolicy;
+///
+/// let policy = RetentionPolicy::default()
+///     .with_max_terminal_age(Duration::from_secs(24 * 3600))
+///     .with_max_tasks(50_000)
+///     .with_sweep_interval(Duration::from_secs(300));
+/// assert_eq!(policy.max_tasks, Some(50_000));
+/// ```
+#[derive(Debug, Clone)]
+pub struct RetentionPolicy {
+    /// Delete tasks whose terminal status was set longer ago than this.
+    /// `None` keeps them regardless of age.
+    pub max_terminal_age: Option<Duration>,
+
+    /// Once the store holds more tasks than this, evict the finished tasks
+    /// that finished first. Running tasks are never evicted, so the store
+    /// may stay above the limit. `None` doesn't limit the count.
+    pub max_tasks: Option<usize>,
+
+    /// Time between sweeps.
+    pub sweep_interval: Duration,
+}
+
+impl Default for RetentionPolicy {
+    fn default() -> Self {
+        Self {
+            max_terminal_age: None,
+            max_tasks: None,
+            sweep_interval: Duration::from_secs(60),
+        }
+    }
+}
+
+impl RetentionPolicy {
+    /// Set [`max_terminal_age`](Self::max_terminal_age).
+    pub fn with_max_terminal_age(mut self, age: Duration) -> Self {
+        self.max_terminal_age = Some(age);
+        self
+    }
+
+    /// Set [`max_tasks`](Self::max_tasks).
+    pub fn with_max_tasks(mut self, max: usize) -> Self {
+        self.max_tasks = Some(max);
+        self
+    }
+
+    /// Set [`sweep_interval`](Self::sweep_interval).
+    pub fn with_sweep_interval(mut self, interval: Duration) -> Self {
+        self.sweep_interval = interval;
+        self
+    }
+}
+
+/// Tasks removed by one [`TaskSweeper::sweep`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SweepReport {
+    /// Tasks purged for exceeding the maximum age.
+    pub expired: usize,
+    /// Tasks evicted to bring the store down to the maximum count.
+    pub evicted: usize,
+}
+
+/// Applies a [`RetentionPolicy`] to a [`TaskStore`], once with
+/// [`sweep()`](Self::sweep) or periodically with [`spawn()`](Self::spawn).
+pub struct TaskSweeper {
+    store: Arc<dyn TaskStore>,
+    policy: RetentionPolicy,
+    #[cfg(feature = "observability")]
+    metrics: Option<Arc<dyn super::telemetry::MetricsRecorder>>,
+}
+
+impl std::fmt::Debug for TaskSweeper {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TaskSweeper")
+            .field("policy", &self.policy)
+            .finish_non_exhaustive()
+    }
+}
+
+impl TaskSweeper {
+    /// Sweep `store` according to `policy`.
+    pub fn new(store: Arc<dyn TaskStore>, policy: RetentionPolicy) -> Self {
+        Self {
+            store,
+            policy,
+            #[cfg(feature = "observability")]
+            metrics: None,
+        }
+    }
+
+    /// Report each removed task to `recorder` (`observability` feature).
+    #[cfg(feature = "observability")]
+    pub fn with_metrics(mut self, recorder: Arc<dyn super::telemetry::MetricsRecorder>) -> Self {
+        self.metrics = Some(recorder);
+        self
+    }
+
+    /// The policy applied.
+    pub fn policy(&self) -> &RetentionPolicy {
+        &self.policy
+    }
+
+    /// Remove the tasks the policy doesn't keep: first those past the
+    /// maximum age, then the oldest finished tasks over the maximum count.
+    pub async fn sweep(&self) -> A2AResult<SweepReport> {
+        let mut report = SweepReport::default();
+        if let Some(age) = self.policy.max_terminal_age {
+            let age = chrono::Duration::from_std(age).unwrap_or(chrono::Duration::MAX);
+            let before = Utc::now()
+                .checked_sub_signed(age)
+                .unwrap_or(DateTime::<Utc>::MIN_UTC);
+            let purged = self.store.purge(before).await?;
+            for task in &purged {
+                self.record_eviction(task, EVICTION_EXPIRED);
+            }
+            report.expired = purged.len();
+        }
+        if let Some(max) = self.policy.max_tasks {
+            report.evicted = self.evict_over(max).await?;
+        }
+        if report != SweepReport::default() {
+            info!(
+                expired = report.expired,
+                evicted = report.evicted,
+                "Swept task store"
+            );
+        }
+        Ok(report)
+    }
+
+    /// Delete the finished tasks that finished first until at most `max`
+    /// tasks remain; returns how many were deleted.
+    async fn evict_over(&self, max: usize) -> A2AResult<usize> {
+        let mut params = TaskListParams::default();
+        let mut total = 0;
+        let mut finished = Vec::new();
+        loop {
+            let page = self.store.list(&params).await?;
+            let listed = page.tasks.len();
+            total += listed;
+            finished.extend(
+                page.tasks
+                    .into_iter()
+                    .filter(|task| task.status.state.is_terminal()),
+            );
+            match page.next_page_token {
+                Some(token) if listed > 0 => params.page_token = Some(token),
+                _ => break,
+            }
+        }
+        if total <= max {
+            return Ok(0);
+        }
+        // Tasks without a timestamp count as the oldest.
+        finished.sort_by_cached_key(|task| {
+            task.status
+                .timestamp
+                .as_deref()
+                .and_then(|timestamp| DateTime::parse_from_rfc3339(timestamp).ok())
+        });
+        let excess = total - max;
+        if finished.len() < excess {
+            warn!(
+                total,
+                max,
+                finished = finished.len(),
+                "Task store over capacity with running tasks; evicting all finished tasks"
+            );
+        }
+        let mut evicted = 0;
+        for task in finished.iter().take(excess) {
+            self.store.delete(&task.id).await?;
+            self.record_eviction(task, EVICTION_CAPACITY);
+            evicted += 1;
+        }
+        Ok(evicted)
+    }
+
+    fn record_eviction(&self, task: &Task, reason: &str) {
+        debug!(task_id = %task.id, reason, "Evicted task");
+        #[cfg(feature = "observability")]
+        if let Some(metrics) = &self.metrics {
+            metrics.task_evicted(&task.id, reason);
+        }
+    }
+
+    /// Sweep every [`sweep_interval`](RetentionPolicy::sweep_interval) on
+    /// the current Tokio runtime, starting now, until the handle is
+    /// aborted. Failed sweeps are logged and retried at the next interval.
+    pub fn spawn(self) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(self.policy.sweep_interval);
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                interval.tick().await;
+                if let Err(e) = self.sweep().await {
+                    warn!(error = %e, "Task sweep failed");
+                }
+            }
+        })
+    }
+}
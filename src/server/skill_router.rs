@@ -0,0 +1,213 @@
+//! Routing messages to one of several executors.
+//!
+//! A [`SkillRouter`] lets one server host several logical agents or skills
+//! behind a single agent card. Each message is sent to the executor
+//! registered for the value of a metadata key — `skillId` by default, see
+//! [`with_metadata_key()`](SkillRouter::with_metadata_key) — read from the
+//! message metadata, or else from the request metadata. Messages naming no
+//! route, or an unknown one, go to the fallback executor if there is one
+//! and are rejected with [`A2AError::InvalidParams`] otherwise.
+//!
+//! Routes added with [`route_skill()`](SkillRouter::route_skill) also check
+//! the message's parts against the skill's `inputModes` before running it
+//! (see [`part_mime_type`]), failing with
+//! [`A2AError::ContentTypeNotSupported`] on a mismatch.
+//!
+//! `tasks/cancel` carries no message, so a cancellation is routed by the
+//! task's history: the latest message naming a route.
+//!
+//! ```rust,ignore
+//! let card = AgentCardBuilder::new("Toolbox", "Several tools", "1.0.0")
+//!     .with_skills([translate.clone(), summarize.clone()])
+//!     .build();
+//! let router = SkillRouter::new()
+//!     .route_skill(&translate, Arc::new(Translator))
+//!     .route_skill(&summarize, Arc::new(Summarizer))
+//!     .with_fallback(Arc::new(ChatAgent));
+//! let handler = DefaultRequestHandler::new(Arc::new(router), store);
+//! ```
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde_json::Value;
+
+use crate::error::{A2AError, A2AResult};
+use crate::types::{AgentSkill, Message};
+use crate::utils::skills::{mime_type_matches, part_mime_type};
+
+use super::agent_executor::{AgentExecutor, RequestContext};
+use super::error_reporter::SKILL_ID_METADATA_KEY;
+use super::event_queue::EventQueue;
+
+/// An [`AgentExecutor`] dispatching to inner executors by a metadata value.
+/// See the [module documentation](self).
+pub struct SkillRouter {
+    metadata_key: String,
+    routes: HashMap<String, Route>,
+    fallback: Option<Arc<dyn AgentExecutor>>,
+}
+
+struct Route {
+    executor: Arc<dyn AgentExecutor>,
+    /// Accepted input MIME types; empty accepts anything.
+    input_modes: Vec<String>,
+}
+
+impl std::fmt::Debug for SkillRouter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut routes: Vec<_> = self.routes.keys().collect();
+        routes.sort();
+        f.debug_struct("SkillRouter")
+            .field("metadata_key", &self.metadata_key)
+            .field("routes", &routes)
+            .field("fallback", &self.fallback.is_some())
+            .finish()
+    }
+}
+
+impl Default for SkillRouter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SkillRouter {
+    /// A router without routes, keyed on `skillId`.
+    pub fn new() -> Self {
+        Self {
+            metadata_key: SKILL_ID_METADATA_KEY.to_string(),
+            routes: HashMap::new(),
+            fallback: None,
+        }
+    }
+
+    /// Route on the metadata value under `key` instead of `skillId`.
+    pub fn with_metadata_key(mut self, key: impl Into<String>) -> Self {
+        self.metadata_key = key.into();
+        self
+    }
+
+    /// Send messages whose metadata value is `value` to `executor`.
+    ///
+    /// Replaces an earlier route for the same value.
+    pub fn route(mut self, value: impl Into<String>, executor: Arc<dyn AgentExecutor>) -> Self {
+        self.routes.insert(
+            value.into(),
+            Route {
+                executor,
+                input_modes: Vec::new(),
+            },
+        );
+        self
+    }
+
+    /// Send messages for `skill` to `executor`, rejecting those with parts
+    /// outside the skill's `inputModes`.
+    ///
+    /// Replaces an earlier route for the same skill ID.
+    pub fn route_skill(mut self, skill: &AgentSkill, executor: Arc<dyn AgentExecutor>) -> Self {
+        self.routes.insert(
+            skill.id.clone(),
+            Route {
+                executor,
+                input_modes: skill.input_modes.clone().unwrap_or_default(),
+            },
+        );
+        self
+    }
+
+    /// Send messages that name no route, or an unknown one, to `executor`.
+    pub fn with_fallback(mut self, executor: Arc<dyn AgentExecutor>) -> Self {
+        self.fallback = Some(executor);
+        self
+    }
+
+    /// The route value of `context`: the metadata value of its message,
+    /// else of its request.
+    fn requested_route<'a>(&self, context: &'a RequestContext) -> Option<&'a str> {
+        context
+            .message
+            .as_ref()
+            .and_then(|message| self.route_value(message.metadata.as_ref()))
+            .or_else(|| self.route_value(context.metadata.as_ref()))
+    }
+
+    fn route_value<'a>(&self, metadata: Option<&'a Value>) -> Option<&'a str> {
+        metadata?.get(&self.metadata_key)?.as_str()
+    }
+
+    /// The route for `value`, or `None` for the fallback.
+    fn lookup(&self, value: Option<&str>) -> A2AResult<Option<&Route>> {
+        let route = value.and_then(|value| self.routes.get(value));
+        if route.is_some() || self.fallback.is_some() {
+            return Ok(route);
+        }
+        Err(match value {
+            Some(value) => A2AError::invalid_params(format!("Unknown skill: {value}")),
+            None => A2AError::invalid_params(format!(
+                "No skill requested: set `{}` in the message metadata",
+                self.metadata_key
+            )),
+        })
+    }
+
+    fn executor<'a>(&'a self, route: Option<&'a Route>) -> &'a Arc<dyn AgentExecutor> {
+        match route {
+            Some(route) => &route.executor,
+            None => self
+                .fallback
+                .as_ref()
+                .expect("lookup() only returns None with a fallback"),
+        }
+    }
+}
+
+/// Check the parts of `message` against the route's input modes.
+fn check_input_modes(route: &Route, value: &str, message: &Message) -> A2AResult<()> {
+    if route.input_modes.is_empty() {
+        return Ok(());
+    }
+    for part in &message.parts {
+        let mime_type = part_mime_type(part);
+        if !route
+            .input_modes
+            .iter()
+            .any(|mode| mime_type_matches(mode, mime_type))
+        {
+            return Err(A2AError::content_type_not_supported(format!(
+                "Skill '{value}' does not accept '{mime_type}' (accepts: {})",
+                route.input_modes.join(", ")
+            )));
+        }
+    }
+    Ok(())
+}
+
+#[async_trait]
+impl AgentExecutor for SkillRouter {
+    async fn execute(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        let value = self.requested_route(&context);
+        let route = self.lookup(value)?;
+        if let (Some(route), Some(value), Some(message)) = (route, value, &context.message) {
+            check_input_modes(route, value, message)?;
+        }
+        self.executor(route).execute(context, event_queue).await
+    }
+
+    async fn cancel(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        let value = self.requested_route(&context).or_else(|| {
+            let history = context.task.as_ref()?.history.as_ref()?;
+            history
+                .iter()
+                .rev()
+                .find_map(|message| self.route_value(message.metadata.as_ref()))
+        });
+        match self.lookup(value) {
+            Ok(route) => self.executor(route).cancel(context, event_queue).await,
+            // Nothing to hand the cancellation to.
+            Err(_) => context.task_updater(event_queue).cancel(None).await,
+        }
+    }
+}
@@ -0,0 +1,258 @@
+//! Subscription registry — which task streams are open, surviving restarts.
+//!
+//! [`DefaultRequestHandler`] keeps one event stream per running task; it
+//! lives in memory and is lost when the process exits. With a
+//! [`SubscriptionRegistry`] (see
+//! [`DefaultRequestHandler::with_subscription_registry`]) the handler
+//! records a [`SubscriptionRecord`] for every open stream — the task and
+//! the sequence number of the last event published on it — and removes it
+//! when the stream ends with a final event.
+//!
+//! After a restart, [`DefaultRequestHandler::recover`] reads the registry
+//! and recreates the stream of every task the [`TaskStore`] still shows as
+//! unfinished. `tasks/subscribe` and `tasks/resubscribe` then work as
+//! before: a subscriber first receives the stored task as a snapshot, then
+//! every later event — from `tasks/cancel`, an `input-required` expiry, or
+//! a follow-up message that restarts the executor. Sequence numbers
+//! continue where the previous process stopped.
+//!
+//! The executor run that was interrupted by the restart is not resumed;
+//! that is up to the application, e.g. by cancelling or re-driving the
+//! recovered tasks.
+//!
+//! ```rust,ignore
+//! let handler = DefaultRequestHandler::new(executor, store)
+//!     .with_subscription_registry(Arc::new(FileSubscriptionRegistry::open("subscriptions.json")?));
+//! let recovered = handler.recover().await?;
+//! ```
+//!
+//! [`DefaultRequestHandler`]: super::DefaultRequestHandler
+//! [`DefaultRequestHandler::with_subscription_registry`]: super::DefaultRequestHandler::with_subscription_registry
+//! [`DefaultRequestHandler::recover`]: super::DefaultRequestHandler::recover
+//! [`TaskStore`]: super::TaskStore
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+use tracing::debug;
+
+use crate::error::{A2AError, A2AResult};
+
+use super::kv_store::{self, InMemoryKvStore, KvStore};
+
+/// An open task event stream.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubscriptionRecord {
+    /// The task the stream belongs to.
+    pub task_id: String,
+
+    /// The task's context.
+    pub context_id: String,
+
+    /// Sequence number of the last event published on the stream; `0`
+    /// before the first event.
+    pub last_sequence: u64,
+}
+
+/// Persistence for [`SubscriptionRecord`]s.
+///
+/// Records are saved each time an event is published, so implementations
+/// should make [`save()`](Self::save) cheap — an upsert keyed by task ID.
+#[async_trait]
+pub trait SubscriptionRegistry: Send + Sync {
+    /// Insert or replace the record of `record.task_id`.
+    async fn save(&self, record: SubscriptionRecord) -> A2AResult<()>;
+
+    /// Remove the record of `task_id`. Silently succeeds if there is none.
+    async fn remove(&self, task_id: &str) -> A2AResult<()>;
+
+    /// All records, in any order.
+    async fn list(&self) -> A2AResult<Vec<SubscriptionRecord>>;
+}
+
+/// Key prefix of subscription records in a [`KvStore`].
+const SUBSCRIPTION_KEY_PREFIX: &str = "subscription/";
+
+/// [`SubscriptionRegistry`] on top of a [`KvStore`]: each record is a JSON
+/// value under `subscription/{task_id}`.
+///
+/// Backed by the same store as a [`KvTaskStore`](super::KvTaskStore), the
+/// registry survives restarts together with the tasks it refers to.
+#[derive(Clone)]
+pub struct KvSubscriptionRegistry {
+    store: Arc<dyn KvStore>,
+}
+
+impl std::fmt::Debug for KvSubscriptionRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("KvSubscriptionRegistry")
+            .finish_non_exhaustive()
+    }
+}
+
+impl KvSubscriptionRegistry {
+    /// Keep records in `store`.
+    pub fn new(store: Arc<dyn KvStore>) -> Self {
+        Self { store }
+    }
+
+    fn key(task_id: &str) -> String {
+        format!("{SUBSCRIPTION_KEY_PREFIX}{task_id}")
+    }
+}
+
+#[async_trait]
+impl SubscriptionRegistry for KvSubscriptionRegistry {
+    async fn save(&self, record: SubscriptionRecord) -> A2AResult<()> {
+        kv_store::put_json(&*self.store, &Self::key(&record.task_id), &record).await
+    }
+
+    async fn remove(&self, task_id: &str) -> A2AResult<()> {
+        self.store.delete(&Self::key(task_id)).await
+    }
+
+    async fn list(&self) -> A2AResult<Vec<SubscriptionRecord>> {
+        self.store
+            .scan_prefix(SUBSCRIPTION_KEY_PREFIX)
+            .await?
+            .iter()
+            .map(|(key, value)| kv_store::decode(key, value))
+            .collect()
+    }
+}
+
+/// In-memory [`SubscriptionRegistry`]: a [`KvSubscriptionRegistry`] over
+/// an [`InMemoryKvStore`].
+///
+/// Does not survive a restart by itself; useful for tests and for sharing
+/// one registry between handlers in the same process.
+#[derive(Debug)]
+pub struct InMemorySubscriptionRegistry {
+    inner: KvSubscriptionRegistry,
+}
+
+impl Default for InMemorySubscriptionRegistry {
+    fn default() -> Self {
+        Self {
+            inner: KvSubscriptionRegistry::new(Arc::new(InMemoryKvStore::new())),
+        }
+    }
+}
+
+impl InMemorySubscriptionRegistry {
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl SubscriptionRegistry for InMemorySubscriptionRegistry {
+    async fn save(&self, record: SubscriptionRecord) -> A2AResult<()> {
+        self.inner.save(record).await
+    }
+
+    async fn remove(&self, task_id: &str) -> A2AResult<()> {
+        self.inner.remove(task_id).await
+    }
+
+    async fn list(&self) -> A2AResult<Vec<SubscriptionRecord>> {
+        self.inner.list().await
+    }
+}
+
+/// [`SubscriptionRegistry`] kept in a JSON file.
+///
+/// The whole registry is rewritten on every change, through a temporary
+/// file renamed over the original, so a crash never leaves a torn file.
+/// Suited to a single server process with a modest number of concurrently
+/// running tasks; larger deployments should use a
+/// [`KvSubscriptionRegistry`] on the same store as their tasks.
+#[derive(Debug)]
+pub struct FileSubscriptionRegistry {
+    path: PathBuf,
+    records: Mutex<BTreeMap<String, SubscriptionRecord>>,
+}
+
+impl FileSubscriptionRegistry {
+    /// Open the registry at `path`, loading its records. A missing file is
+    /// an empty registry; it is created on the first change.
+    pub fn open(path: impl Into<PathBuf>) -> A2AResult<Self> {
+        let path = path.into();
+        let records: Vec<SubscriptionRecord> = match std::fs::read(&path) {
+            Ok(bytes) => serde_json::from_slice(&bytes).map_err(|e| {
+                A2AError::internal_error(format!(
+                    "Invalid subscription registry {}: {e}",
+                    path.display()
+                ))
+            })?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+            Err(e) => return Err(io_error(&path, e)),
+        };
+        debug!(path = %path.display(), records = records.len(), "Opened subscription registry");
+        Ok(Self {
+            path,
+            records: Mutex::new(
+                records
+                    .into_iter()
+                    .map(|record| (record.task_id.clone(), record))
+                    .collect(),
+            ),
+        })
+    }
+
+    /// The file the registry is kept in.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Write `records` to the file. Called with the records lock held, so
+    /// writes happen in the order of the changes.
+    async fn write(&self, records: &BTreeMap<String, SubscriptionRecord>) -> A2AResult<()> {
+        let json = serde_json::to_vec_pretty(&records.values().collect::<Vec<_>>())
+            .map_err(|e| A2AError::internal_error(e.to_string()))?;
+        let path = self.path.clone();
+        tokio::task::spawn_blocking(move || {
+            let mut tmp = path.clone().into_os_string();
+            tmp.push(".tmp");
+            std::fs::write(&tmp, json)
+                .and_then(|()| std::fs::rename(&tmp, &path))
+                .map_err(|e| io_error(&path, e))
+        })
+        .await
+        .map_err(|e| A2AError::internal_error(format!("registry write task failed: {e}")))?
+    }
+}
+
+#[async_trait]
+impl SubscriptionRegistry for FileSubscriptionRegistry {
+    async fn save(&self, record: SubscriptionRecord) -> A2AResult<()> {
+        let mut records = self.records.lock().await;
+        if records.get(&record.task_id) == Some(&record) {
+            return Ok(());
+        }
+        records.insert(record.task_id.clone(), record);
+        self.write(&records).await
+    }
+
+    async fn remove(&self, task_id: &str) -> A2AResult<()> {
+        let mut records = self.records.lock().await;
+        if records.remove(task_id).is_none() {
+            return Ok(());
+        }
+        self.write(&records).await
+    }
+
+    async fn list(&self) -> A2AResult<Vec<SubscriptionRecord>> {
+        Ok(self.records.lock().await.values().cloned().collect())
+    }
+}
+
+fn io_error(path: &Path, e: std::io::Error) -> A2AError {
+    A2AError::internal_error(format!("Subscription registry {}: {e}", path.display()))
+}
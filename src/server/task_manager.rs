@@ -6,18 +6,65 @@
 //! events received from the agent. Handles the mapping between streaming events
 //! (status updates, artifact updates) and the persisted task state.
 //!
+//! Status changes are checked against the task state machine
+//! ([`TaskState::can_transition_to`]): an event that would, say, move a
+//! completed task back to `working` is refused with
+//! [`A2AError::InvalidStateTransition`] and not saved.
+//!
+//! Changes never overwrite what other writers (a cancellation, a follow-up
+//! message) stored in the meantime: events are applied to the stored task
+//! in one [`TaskStore::update()`], and a task snapshot from the executor
+//! replaces the stored task only at the version it was checked against,
+//! retrying on a [`A2AError::VersionConflict`].
+//!
+//! Every stored change is reported to the manager's
+//! [`TaskLifecycleListeners`]: creation, state changes, added artifacts and
+//! terminal states.
+//!
 //! Also includes the `append_artifact_to_task` utility (from Python SDK's
 //! `a2a.utils.helpers.append_artifact_to_task`).
 
+use std::sync::{Arc, Mutex};
+
 use tracing::{debug, info, warn};
 
 use crate::error::{A2AError, A2AResult};
 use crate::types::{
-    Artifact, Message, StreamResponse, Task, TaskArtifactUpdateEvent, TaskState, TaskStatus,
-    TaskStatusUpdateEvent,
+    AgentCapabilities, Artifact, Message, Role, StreamResponse, Task, TaskArtifactUpdateEvent,
+    TaskState, TaskStatus, TaskStatusUpdateEvent,
 };
+use crate::utils::request_id::{set_task_request_id, task_request_id};
+
+use super::lifecycle::TaskLifecycleListeners;
+use super::task_store::{set_task_tenant, task_tenant, TaskStore, TENANT_METADATA_KEY};
+
+/// Which agent messages [`TaskManager`] records in `Task.history`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HistoryPolicy {
+    /// A status message enters history only once a later status replaces
+    /// it; direct replies are not recorded. The final status message is
+    /// only ever in `status.message`.
+    ///
+    /// Mirrors Python SDK's `TaskManager` behaviour.
+    #[default]
+    OnReplace,
+    /// Agent-role status messages and direct replies are appended to
+    /// history as they arrive, so history holds the complete conversation.
+    AgentMessages,
+}
 
-use super::task_store::TaskStore;
+impl HistoryPolicy {
+    /// The policy matching an agent's `stateTransitionHistory` capability:
+    /// [`AgentMessages`](Self::AgentMessages) if the agent declares it
+    /// keeps a history of its status messages, otherwise the default
+    /// [`OnReplace`](Self::OnReplace).
+    pub fn for_capabilities(capabilities: &AgentCapabilities) -> Self {
+        match capabilities.state_transition_history {
+            Some(true) => Self::AgentMessages,
+            _ => Self::OnReplace,
+        }
+    }
+}
 
 /// Manages a task's lifecycle during execution of a request.
 ///
@@ -40,6 +87,15 @@ pub struct TaskManager {
 
     /// The current in-memory task state.
     current_task: Option<Task>,
+
+    /// Which agent messages are recorded in task history.
+    history_policy: HistoryPolicy,
+
+    /// Whether status changes are checked against the state machine.
+    check_transitions: bool,
+
+    /// Told about every stored change.
+    listeners: TaskLifecycleListeners,
 }
 
 impl TaskManager {
@@ -78,9 +134,37 @@ impl TaskManager {
             task_store,
             initial_message,
             current_task: None,
+            history_policy: HistoryPolicy::default(),
+            check_transitions: true,
+            listeners: TaskLifecycleListeners::default(),
         })
     }
 
+    /// Set which agent messages are recorded in task history.
+    pub fn with_history_policy(mut self, policy: HistoryPolicy) -> Self {
+        self.history_policy = policy;
+        self
+    }
+
+    /// Check status changes against the task state machine (the
+    /// default), or save whatever the events say. Turning the checks off
+    /// is meant for tests.
+    pub fn with_transition_checks(mut self, enabled: bool) -> Self {
+        self.check_transitions = enabled;
+        self
+    }
+
+    /// Report stored changes to `listeners`.
+    pub fn with_listeners(mut self, listeners: TaskLifecycleListeners) -> Self {
+        self.listeners = listeners;
+        self
+    }
+
+    /// Returns the history policy.
+    pub fn history_policy(&self) -> HistoryPolicy {
+        self.history_policy
+    }
+
     /// Retrieves the current task object, either from memory or the store.
     ///
     /// If `task_id` is set, it first checks the in-memory `current_task`,
@@ -112,6 +196,11 @@ impl TaskManager {
     /// Handles `Task`, `TaskStatusUpdateEvent`, and `TaskArtifactUpdateEvent`.
     ///
     /// Mirrors Python SDK's `TaskManager.save_task_event`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`A2AError::InvalidStateTransition`] for a task snapshot or
+    /// status update the stored task's state can't move to.
     pub async fn save_task_event(&mut self, event: TaskEvent) -> A2AResult<Option<Task>> {
         let (task_id_from_event, context_id_from_event) = match &event {
             TaskEvent::Task(t) => (t.id.clone(), t.context_id.clone()),
@@ -159,111 +248,179 @@ impl TaskManager {
 
         match event {
             TaskEvent::Task(task) => {
-                self.save_task(task.clone()).await?;
+                let (task, before) = self.replace_task(task).await?;
+                self.listeners.task_saved(before, &task);
                 Ok(Some(task))
             }
             TaskEvent::StatusUpdate(status_event) => {
-                let mut task = self.ensure_task_from_status(&status_event).await?;
-
                 debug!(
-                    task_id = %task.id,
+                    task_id = %status_event.task_id,
                     new_state = %status_event.status.state,
                     "Updating task status"
                 );
-
-                // Move current status message to history before replacing
-                if let Some(ref msg) = task.status.message {
-                    let history = task.history.get_or_insert_with(Vec::new);
-                    history.push(msg.clone());
-                }
-
-                // Merge event metadata into task metadata
-                if let Some(event_meta) = status_event.metadata {
-                    let task_meta = task
-                        .metadata
-                        .get_or_insert_with(|| serde_json::Value::Object(serde_json::Map::new()));
-                    if let (Some(task_obj), Some(event_obj)) =
-                        (task_meta.as_object_mut(), event_meta.as_object())
-                    {
-                        for (k, v) in event_obj {
-                            task_obj.insert(k.clone(), v.clone());
+                let policy = self.history_policy;
+                let check_transitions = self.check_transitions;
+                let (task_id, context_id) = (
+                    status_event.task_id.clone(),
+                    status_event.context_id.clone(),
+                );
+                let (task, before) = self
+                    .update_task(&task_id, &context_id, move |task| {
+                        if check_transitions {
+                            check_transition(
+                                &task.id,
+                                task.status.state,
+                                status_event.status.state,
+                            )?;
                         }
-                    }
-                }
-
-                task.status = status_event.status;
-                self.save_task(task.clone()).await?;
+                        apply_status_update(task, &status_event, policy);
+                        Ok(())
+                    })
+                    .await?;
+                self.listeners.task_saved(before, &task);
                 Ok(Some(task))
             }
             TaskEvent::ArtifactUpdate(artifact_event) => {
-                let mut task = self.ensure_task_from_artifact(&artifact_event).await?;
-
-                debug!(task_id = %task.id, "Appending artifact to task");
-                append_artifact_to_task(&mut task, &artifact_event);
-
-                self.save_task(task.clone()).await?;
+                debug!(task_id = %artifact_event.task_id, "Appending artifact to task");
+                let (task_id, context_id) = (
+                    artifact_event.task_id.clone(),
+                    artifact_event.context_id.clone(),
+                );
+                let event = (!self.listeners.is_empty()).then(|| artifact_event.clone());
+                let (task, before) = self
+                    .update_task(&task_id, &context_id, move |task| {
+                        append_artifact_to_task(task, &artifact_event);
+                        Ok(())
+                    })
+                    .await?;
+                self.listeners.task_saved(before, &task);
+                if let Some(event) = event {
+                    self.listeners.artifact_added(&task, &event);
+                }
                 Ok(Some(task))
             }
         }
     }
 
-    /// Ensures a Task object exists in memory for a status update event,
-    /// loading from store or creating new if needed.
-    async fn ensure_task_from_status(&mut self, event: &TaskStatusUpdateEvent) -> A2AResult<Task> {
-        if let Some(ref task) = self.current_task {
-            return Ok(task.clone());
+    /// Store the task snapshot `task` in place of the stored task.
+    ///
+    /// The snapshot is checked against the stored task's state and keeps
+    /// its tenant (the executor can't move the task to another tenant) and,
+    /// unless it names one, the request ID it was created by; it is then
+    /// written with a compare-and-swap [`TaskStore::update()`] at the
+    /// version the checks were made against. If another writer got in
+    /// between, e.g. a cancellation, the stored task is read again and the
+    /// checks repeated, so its change is never silently overwritten. A
+    /// task that isn't stored yet is created.
+    ///
+    /// Returns the stored task and its state before, `None` if it was
+    /// created.
+    async fn replace_task(&mut self, mut task: Task) -> A2AResult<(Task, Option<TaskState>)> {
+        if self.history_policy == HistoryPolicy::AgentMessages {
+            if let Some(msg) = task.status.message.clone() {
+                if msg.role == Role::Agent {
+                    push_history(&mut task, msg);
+                }
+            }
         }
-
-        if let Some(ref task_id) = self.task_id {
-            debug!(task_id = %task_id, "Attempting to retrieve existing task");
-            if let Some(task) = self.task_store.get(task_id).await? {
-                self.current_task = Some(task.clone());
-                return Ok(task);
+        loop {
+            let Some(stored) = self.task_store.get_versioned(&task.id).await? else {
+                self.save_task(task.clone()).await?;
+                return Ok((task, None));
+            };
+            if self.check_transitions {
+                check_transition(&task.id, stored.task.status.state, task.status.state)?;
+            }
+            set_task_tenant(&mut task, task_tenant(&stored.task));
+            if task_request_id(&task).is_none() {
+                if let Some(request_id) = task_request_id(&stored.task) {
+                    set_task_request_id(&mut task, request_id);
+                }
+            }
+            let replacement = task.clone();
+            let replaced = self
+                .task_store
+                .update(
+                    &task.id,
+                    Some(stored.version),
+                    Box::new(move |task| {
+                        *task = replacement;
+                        Ok(())
+                    }),
+                )
+                .await;
+            match replaced {
+                Ok(task) => {
+                    self.current_task = Some(task.clone());
+                    return Ok((task, Some(stored.task.status.state)));
+                }
+                // Changed or deleted since it was read: read it again.
+                Err(A2AError::VersionConflict { .. } | A2AError::TaskNotFound { .. }) => {
+                    debug!(task_id = %task.id, "Task changed concurrently, retrying");
+                }
+                Err(e) => return Err(e),
             }
         }
-
-        info!(
-            task_id = %event.task_id,
-            context_id = %event.context_id,
-            "Task not found. Creating new task for event."
-        );
-        let task = self.init_task_obj(event.task_id.clone(), event.context_id.clone());
-        self.save_task(task.clone()).await?;
-        Ok(task)
     }
 
-    /// Ensures a Task object exists in memory for an artifact update event,
-    /// loading from store or creating new if needed.
-    async fn ensure_task_from_artifact(
+    /// Apply `change` to the stored task in one [`TaskStore::update()`], so
+    /// changes other writers made since this manager last read the task
+    /// (a follow-up message, a cancellation) are kept. A task that isn't
+    /// stored yet is created first. An error from `change` leaves the task
+    /// as it was.
+    ///
+    /// Returns the updated task and its state before the change, `None` if
+    /// it was created.
+    async fn update_task<F>(
         &mut self,
-        event: &TaskArtifactUpdateEvent,
-    ) -> A2AResult<Task> {
-        if let Some(ref task) = self.current_task {
-            return Ok(task.clone());
-        }
-
-        if let Some(ref task_id) = self.task_id {
-            debug!(task_id = %task_id, "Attempting to retrieve existing task");
-            if let Some(task) = self.task_store.get(task_id).await? {
+        task_id: &str,
+        context_id: &str,
+        change: F,
+    ) -> A2AResult<(Task, Option<TaskState>)>
+    where
+        F: Fn(&mut Task) -> A2AResult<()> + Send + Sync + 'static,
+    {
+        let change = Arc::new(change);
+        let apply = Arc::clone(&change);
+        let before = Arc::new(Mutex::new(None));
+        let record = Arc::clone(&before);
+        let updated = self
+            .task_store
+            .update(
+                task_id,
+                None,
+                Box::new(move |task| {
+                    *record.lock().unwrap() = Some(task.status.state);
+                    apply(task)
+                }),
+            )
+            .await;
+        match updated {
+            Ok(task) => {
                 self.current_task = Some(task.clone());
-                return Ok(task);
+                let before = *before.lock().unwrap();
+                Ok((task, before))
             }
+            Err(A2AError::TaskNotFound { .. }) => {
+                info!(
+                    task_id = %task_id,
+                    context_id = %context_id,
+                    "Task not found. Creating new task for event."
+                );
+                let mut task = self.init_task_obj(task_id.to_string(), context_id.to_string());
+                change(&mut task)?;
+                self.save_task(task.clone()).await?;
+                Ok((task, None))
+            }
+            Err(e) => Err(e),
         }
-
-        info!(
-            task_id = %event.task_id,
-            context_id = %event.context_id,
-            "Task not found. Creating new task for artifact event."
-        );
-        let task = self.init_task_obj(event.task_id.clone(), event.context_id.clone());
-        self.save_task(task.clone()).await?;
-        Ok(task)
     }
 
     /// Process a `StreamResponse` event, updating task state if applicable.
     ///
     /// If the event is task-related, the internal task state is updated and persisted.
-    /// Non-task events (e.g., direct Messages) are passed through.
+    /// Direct agent replies are appended to the task history under
+    /// [`HistoryPolicy::AgentMessages`]; otherwise they are passed through.
     ///
     /// Mirrors Python SDK's `TaskManager.process`.
     pub async fn process(&mut self, event: StreamResponse) -> A2AResult<StreamResponse> {
@@ -279,13 +436,43 @@ impl TaskManager {
                 self.save_task_event(TaskEvent::ArtifactUpdate(artifact.clone()))
                     .await?;
             }
-            StreamResponse::Message(_) => {
-                // Messages are not persisted in the task store
+            StreamResponse::Message(msg) => {
+                if self.history_policy == HistoryPolicy::AgentMessages && msg.role == Role::Agent {
+                    self.append_history(msg.clone()).await?;
+                }
             }
         }
         Ok(event)
     }
 
+    /// Append `message` to the stored task's history in one
+    /// [`TaskStore::update()`]. Returns the updated task, or `None` if the
+    /// task isn't known or stored.
+    pub async fn append_history(&mut self, message: Message) -> A2AResult<Option<Task>> {
+        let Some(task_id) = self.task_id.clone() else {
+            return Ok(None);
+        };
+        let updated = self
+            .task_store
+            .update(
+                &task_id,
+                None,
+                Box::new(move |task| {
+                    push_history(task, message);
+                    Ok(())
+                }),
+            )
+            .await;
+        match updated {
+            Ok(task) => {
+                self.current_task = Some(task.clone());
+                Ok(Some(task))
+            }
+            Err(A2AError::TaskNotFound { .. }) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
     /// Updates a task object by adding a new message to its history.
     ///
     /// If the task has a message in its current status, that message is moved
@@ -293,13 +480,10 @@ impl TaskManager {
     ///
     /// Mirrors Python SDK's `TaskManager.update_with_message`.
     pub fn update_with_message(&mut self, message: Message, task: &mut Task) {
-        if let Some(ref status_msg) = task.status.message {
-            let history = task.history.get_or_insert_with(Vec::new);
-            history.push(status_msg.clone());
-            task.status.message = None;
+        if let Some(status_msg) = task.status.message.take() {
+            push_history(task, status_msg);
         }
-        let history = task.history.get_or_insert_with(Vec::new);
-        history.push(message);
+        push_history(task, message);
         self.current_task = Some(task.clone());
     }
 
@@ -329,11 +513,7 @@ impl TaskManager {
             id: task_id,
             context_id,
             kind: "task".to_string(),
-            status: TaskStatus {
-                state: TaskState::Submitted,
-                message: None,
-                timestamp: None,
-            },
+            status: TaskStatus::new(TaskState::Submitted),
             artifacts: None,
             history,
             metadata: None,
@@ -380,6 +560,63 @@ impl TaskEvent {
     }
 }
 
+/// `InvalidStateTransition` unless task `task_id` may move from `from` to
+/// `to`.
+fn check_transition(task_id: &str, from: TaskState, to: TaskState) -> A2AResult<()> {
+    if from.can_transition_to(to) {
+        return Ok(());
+    }
+    warn!(task_id, from = %from, to = %to, "Refusing invalid task state transition");
+    Err(A2AError::invalid_state_transition(task_id, from, to))
+}
+
+/// Apply a status update to `task`: the replaced status message moves to
+/// history (and, under [`HistoryPolicy::AgentMessages`], an agent message
+/// in the new status too), event metadata is merged into the task's. The
+/// reserved [`TENANT_METADATA_KEY`] is skipped, so events can't move a task
+/// to another tenant.
+fn apply_status_update(task: &mut Task, event: &TaskStatusUpdateEvent, policy: HistoryPolicy) {
+    // Move current status message to history before replacing
+    if let Some(msg) = task.status.message.take() {
+        push_history(task, msg);
+    }
+    if policy == HistoryPolicy::AgentMessages {
+        if let Some(ref msg) = event.status.message {
+            if msg.role == Role::Agent {
+                push_history(task, msg.clone());
+            }
+        }
+    }
+
+    // Merge event metadata into task metadata
+    if let Some(event_obj) = event.metadata.as_ref().and_then(|m| m.as_object()) {
+        let task_meta = task
+            .metadata
+            .get_or_insert_with(|| serde_json::Value::Object(serde_json::Map::new()));
+        if let Some(task_obj) = task_meta.as_object_mut() {
+            for (k, v) in event_obj {
+                if k == TENANT_METADATA_KEY {
+                    warn!(task_id = %task.id, "Ignoring tenant in status update metadata");
+                    continue;
+                }
+                task_obj.insert(k.clone(), v.clone());
+            }
+        }
+    }
+
+    task.status = event.status.clone();
+}
+
+/// Appends `message` to the task history unless a message with the same
+/// ID is already recorded (e.g. a status message appended on arrival under
+/// [`HistoryPolicy::AgentMessages`] and later moved out of `status`).
+pub fn push_history(task: &mut Task, message: Message) {
+    let history = task.history.get_or_insert_with(Vec::new);
+    if !history.iter().any(|m| m.message_id == message.message_id) {
+        history.push(message);
+    }
+}
+
 /// Appends an artifact to a task based on an artifact update event.
 ///
 /// Handles creating the artifacts list if it doesn't exist, adding new artifacts,
@@ -444,11 +681,7 @@ mod tests {
             id: id.to_string(),
             context_id: ctx.to_string(),
             kind: "task".to_string(),
-            status: TaskStatus {
-                state: TaskState::Submitted,
-                message: None,
-                timestamp: None,
-            },
+            status: TaskStatus::new(TaskState::Submitted),
             artifacts: None,
             history: None,
             metadata: None,
@@ -560,11 +793,7 @@ mod tests {
             task_id: "t1".to_string(),
             context_id: "ctx1".to_string(),
             kind: "status-update".to_string(),
-            status: TaskStatus {
-                state: TaskState::Working,
-                message: None,
-                timestamp: None,
-            },
+            status: TaskStatus::new(TaskState::Working),
             r#final: false,
             metadata: None,
         });
@@ -586,11 +815,7 @@ mod tests {
             task_id: "t2".to_string(),
             context_id: "ctx1".to_string(),
             kind: "status-update".to_string(),
-            status: TaskStatus {
-                state: TaskState::Working,
-                message: None,
-                timestamp: None,
-            },
+            status: TaskStatus::new(TaskState::Working),
             r#final: false,
             metadata: None,
         });
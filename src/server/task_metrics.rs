@@ -0,0 +1,423 @@
+//! Task state gauges in OpenMetrics text format.
+//!
+//! [`ObservedTaskStore`] wraps any [`TaskStore`] and reports every saved or
+//! deleted task to [`TaskStoreObserver`]s. [`TaskStateGauges`] is such an
+//! observer: it tracks the current state of each task and exposes, per
+//! tenant and state, the number of tasks and the time the longest-waiting
+//! one entered that state — enough to alert on stuck `working` tasks or a
+//! growing `input-required` backlog.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use std::sync::Arc;
+//! use a2a_rs::server::*;
+//!
+//! let gauges = Arc::new(TaskStateGauges::new());
+//! let store = Arc::new(
+//!     ObservedTaskStore::new(InMemoryTaskStore::new()).with_observer(gauges.clone()),
+//! );
+//! let handler = Arc::new(DefaultRequestHandler::new(executor, store));
+//! let app = a2a_router(handler, card).merge(openmetrics_router(gauges));
+//! // GET /metrics now serves the gauges.
+//! ```
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write as _;
+use std::sync::{Arc, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use async_trait::async_trait;
+use axum::http::header;
+use axum::routing::get;
+use axum::Router;
+use chrono::{DateTime, Utc};
+
+use crate::error::A2AResult;
+use crate::types::{Artifact, ArtifactSummary, ReadConsistency, Task, TaskState};
+
+use super::artifact_stream::ArtifactStream;
+use super::task_store::{TaskListParams, TaskListResponse, TaskStore, TaskUpdate, VersionedTask};
+
+/// `Content-Type` of the OpenMetrics text exposition format.
+pub const OPENMETRICS_CONTENT_TYPE: &str =
+    "application/openmetrics-text; version=1.0.0; charset=utf-8";
+
+/// States reported by [`TaskStateGauges`], in output order.
+const STATES: [TaskState; 9] = [
+    TaskState::Submitted,
+    TaskState::Working,
+    TaskState::InputRequired,
+    TaskState::AuthRequired,
+    TaskState::Completed,
+    TaskState::Failed,
+    TaskState::Canceled,
+    TaskState::Rejected,
+    TaskState::Unknown,
+];
+
+/// A task written to or removed from an [`ObservedTaskStore`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaskStoreChange {
+    /// ID of the affected task.
+    pub task_id: String,
+    /// Tenant of the task, from the store's tenant resolver.
+    pub tenant: Option<String>,
+    /// State after the change; `None` if the task was deleted.
+    pub state: Option<TaskState>,
+}
+
+/// Receives change notifications from an [`ObservedTaskStore`].
+///
+/// Called inline after each successful write, so implementations must be
+/// cheap and non-blocking.
+pub trait TaskStoreObserver: Send + Sync {
+    /// Called after a task was saved or deleted.
+    fn on_change(&self, change: &TaskStoreChange);
+}
+
+type TenantResolver = Arc<dyn Fn(&Task) -> Option<String> + Send + Sync>;
+
+/// [`TaskStore`] wrapper that notifies [`TaskStoreObserver`]s of every
+/// successful `save`, `update`, `delete` and `purge`. Reads are passed
+/// through unchanged.
+pub struct ObservedTaskStore<S> {
+    inner: S,
+    observers: Vec<Arc<dyn TaskStoreObserver>>,
+    tenant_of: Option<TenantResolver>,
+}
+
+impl<S: TaskStore> ObservedTaskStore<S> {
+    /// Wrap `inner` with no observers.
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner,
+            observers: Vec::new(),
+            tenant_of: None,
+        }
+    }
+
+    /// Add an observer.
+    pub fn with_observer(mut self, observer: Arc<dyn TaskStoreObserver>) -> Self {
+        self.observers.push(observer);
+        self
+    }
+
+    /// Derive each task's tenant, e.g. from its metadata. Without a
+    /// resolver every change reports `tenant: None`.
+    pub fn with_tenant_resolver<F>(mut self, resolver: F) -> Self
+    where
+        F: Fn(&Task) -> Option<String> + Send + Sync + 'static,
+    {
+        self.tenant_of = Some(Arc::new(resolver));
+        self
+    }
+
+    /// The wrapped store.
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+
+    /// Report every task already in the inner store to the observers, e.g.
+    /// to seed gauges from a persistent store at startup.
+    pub async fn replay(&self) -> A2AResult<()> {
+        let mut params = TaskListParams::default();
+        loop {
+            let page = self.inner.list(&params).await?;
+            for task in &page.tasks {
+                self.notify(&task.id, self.tenant(task), Some(task.status.state));
+            }
+            match page.next_page_token {
+                Some(token) if !page.tasks.is_empty() => params.page_token = Some(token),
+                _ => return Ok(()),
+            }
+        }
+    }
+
+    fn tenant(&self, task: &Task) -> Option<String> {
+        self.tenant_of.as_ref().and_then(|resolve| resolve(task))
+    }
+
+    fn notify(&self, task_id: &str, tenant: Option<String>, state: Option<TaskState>) {
+        let change = TaskStoreChange {
+            task_id: task_id.to_string(),
+            tenant,
+            state,
+        };
+        for observer in &self.observers {
+            observer.on_change(&change);
+        }
+    }
+}
+
+impl<S> std::fmt::Debug for ObservedTaskStore<S>
+where
+    S: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ObservedTaskStore")
+            .field("inner", &self.inner)
+            .field("observers", &self.observers.len())
+            .field("tenant_resolver", &self.tenant_of.is_some())
+            .finish()
+    }
+}
+
+#[async_trait]
+impl<S: TaskStore> TaskStore for ObservedTaskStore<S> {
+    async fn save(&self, task: Task) -> A2AResult<()> {
+        let (task_id, state, tenant) = (task.id.clone(), task.status.state, self.tenant(&task));
+        self.inner.save(task).await?;
+        self.notify(&task_id, tenant, Some(state));
+        Ok(())
+    }
+
+    async fn get(&self, task_id: &str) -> A2AResult<Option<Task>> {
+        self.inner.get(task_id).await
+    }
+
+    async fn get_versioned(&self, task_id: &str) -> A2AResult<Option<VersionedTask>> {
+        self.inner.get_versioned(task_id).await
+    }
+
+    async fn get_with_consistency(
+        &self,
+        task_id: &str,
+        consistency: &ReadConsistency,
+    ) -> A2AResult<Option<Task>> {
+        self.inner.get_with_consistency(task_id, consistency).await
+    }
+
+    async fn get_for_tenant(&self, tenant: Option<&str>, task_id: &str) -> A2AResult<Option<Task>> {
+        self.inner.get_for_tenant(tenant, task_id).await
+    }
+
+    async fn open_artifact(&self, task_id: &str, artifact_id: &str) -> A2AResult<ArtifactStream> {
+        self.inner.open_artifact(task_id, artifact_id).await
+    }
+
+    async fn list_artifacts(
+        &self,
+        tenant: Option<&str>,
+        task_id: &str,
+    ) -> A2AResult<Vec<ArtifactSummary>> {
+        self.inner.list_artifacts(tenant, task_id).await
+    }
+
+    async fn get_artifact(
+        &self,
+        tenant: Option<&str>,
+        task_id: &str,
+        artifact_id: &str,
+    ) -> A2AResult<Artifact> {
+        self.inner.get_artifact(tenant, task_id, artifact_id).await
+    }
+
+    async fn update(
+        &self,
+        task_id: &str,
+        expected_version: Option<u64>,
+        update: TaskUpdate,
+    ) -> A2AResult<Task> {
+        let task = self.inner.update(task_id, expected_version, update).await?;
+        self.notify(&task.id, self.tenant(&task), Some(task.status.state));
+        Ok(task)
+    }
+
+    async fn delete(&self, task_id: &str) -> A2AResult<()> {
+        // Look the task up first so observers learn its tenant.
+        let existing = if self.observers.is_empty() {
+            None
+        } else {
+            self.inner.get(task_id).await?
+        };
+        self.inner.delete(task_id).await?;
+        if let Some(task) = existing {
+            self.notify(task_id, self.tenant(&task), None);
+        }
+        Ok(())
+    }
+
+    async fn list(&self, params: &TaskListParams) -> A2AResult<TaskListResponse> {
+        self.inner.list(params).await
+    }
+
+    async fn purge(&self, before: DateTime<Utc>) -> A2AResult<Vec<Task>> {
+        let purged = self.inner.purge(before).await?;
+        for task in &purged {
+            self.notify(&task.id, self.tenant(task), None);
+        }
+        Ok(purged)
+    }
+}
+
+/// Current count and oldest entry time of one `(tenant, state)` pair.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaskStateGauge {
+    /// Tenant label (`""` for tasks without a tenant).
+    pub tenant: String,
+    /// Task state.
+    pub state: TaskState,
+    /// Number of tasks currently in `state`.
+    pub count: u64,
+    /// When the longest-waiting of those tasks entered `state`.
+    pub oldest_since: Option<SystemTime>,
+}
+
+#[derive(Debug)]
+struct TrackedTask {
+    tenant: String,
+    state: TaskState,
+    since: SystemTime,
+}
+
+/// Per-tenant gauges of tasks by state, fed by an [`ObservedTaskStore`].
+///
+/// Exported by [`render_openmetrics()`](Self::render_openmetrics) as two
+/// gauge families labelled `tenant` and `state`:
+///
+/// - `a2a_tasks` — number of tasks currently in the state
+/// - `a2a_tasks_oldest_timestamp_seconds` — Unix time at which the
+///   longest-waiting task entered the state (only for non-empty states)
+#[derive(Debug, Default)]
+pub struct TaskStateGauges {
+    tasks: Mutex<HashMap<String, TrackedTask>>,
+}
+
+impl TaskStateGauges {
+    /// Create empty gauges.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of tasks of `tenant` currently in `state`.
+    pub fn count(&self, tenant: Option<&str>, state: TaskState) -> u64 {
+        let tenant = tenant.unwrap_or("");
+        let tasks = self.tasks.lock().unwrap();
+        tasks
+            .values()
+            .filter(|t| t.tenant == tenant && t.state == state)
+            .count() as u64
+    }
+
+    /// Gauges for every tenant seen and every state, sorted by tenant.
+    /// States without tasks are included with a count of zero.
+    pub fn snapshot(&self) -> Vec<TaskStateGauge> {
+        let mut per_tenant: BTreeMap<String, HashMap<TaskState, (u64, SystemTime)>> =
+            BTreeMap::new();
+        for task in self.tasks.lock().unwrap().values() {
+            let entry = per_tenant
+                .entry(task.tenant.clone())
+                .or_default()
+                .entry(task.state)
+                .or_insert((0, task.since));
+            entry.0 += 1;
+            entry.1 = entry.1.min(task.since);
+        }
+        per_tenant
+            .into_iter()
+            .flat_map(|(tenant, states)| {
+                STATES.iter().map(move |state| {
+                    let (count, oldest) = states.get(state).copied().unzip();
+                    TaskStateGauge {
+                        tenant: tenant.clone(),
+                        state: *state,
+                        count: count.unwrap_or(0),
+                        oldest_since: oldest,
+                    }
+                })
+            })
+            .collect()
+    }
+
+    /// Render the gauges in the OpenMetrics text format (served with
+    /// [`OPENMETRICS_CONTENT_TYPE`]).
+    pub fn render_openmetrics(&self) -> String {
+        let gauges = self.snapshot();
+        let mut out = String::new();
+        out.push_str("# TYPE a2a_tasks gauge\n");
+        out.push_str("# HELP a2a_tasks Number of tasks currently in each state.\n");
+        for gauge in &gauges {
+            let _ = writeln!(out, "a2a_tasks{{{}}} {}", labels(gauge), gauge.count);
+        }
+        out.push_str("# TYPE a2a_tasks_oldest_timestamp_seconds gauge\n");
+        out.push_str("# UNIT a2a_tasks_oldest_timestamp_seconds seconds\n");
+        out.push_str(
+            "# HELP a2a_tasks_oldest_timestamp_seconds Time the longest-waiting task entered each state.\n",
+        );
+        for gauge in &gauges {
+            if let Some(since) = gauge.oldest_since {
+                let seconds = since
+                    .duration_since(UNIX_EPOCH)
+                    .unwrap_or_default()
+                    .as_secs_f64();
+                let _ = writeln!(
+                    out,
+                    "a2a_tasks_oldest_timestamp_seconds{{{}}} {seconds:.3}",
+                    labels(gauge)
+                );
+            }
+        }
+        out.push_str("# EOF\n");
+        out
+    }
+}
+
+impl TaskStoreObserver for TaskStateGauges {
+    fn on_change(&self, change: &TaskStoreChange) {
+        let mut tasks = self.tasks.lock().unwrap();
+        let Some(state) = change.state else {
+            tasks.remove(&change.task_id);
+            return;
+        };
+        let tenant = change.tenant.clone().unwrap_or_default();
+        match tasks.get_mut(&change.task_id) {
+            // Re-saves in the same state keep the original entry time.
+            Some(task) if task.state == state && task.tenant == tenant => {}
+            _ => {
+                tasks.insert(
+                    change.task_id.clone(),
+                    TrackedTask {
+                        tenant,
+                        state,
+                        since: SystemTime::now(),
+                    },
+                );
+            }
+        }
+    }
+}
+
+fn labels(gauge: &TaskStateGauge) -> String {
+    format!(
+        "tenant=\"{}\",state=\"{}\"",
+        escape_label(&gauge.tenant),
+        gauge.state.as_str()
+    )
+}
+
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// Router serving `gauges` at `GET /metrics` in the OpenMetrics format.
+///
+/// Merge it into the A2A router (`a2a_router(..).merge(..)`) or serve it on
+/// a separate admin listener.
+pub fn openmetrics_router(gauges: Arc<TaskStateGauges>) -> Router {
+    Router::new().route(
+        "/metrics",
+        get(move || {
+            let gauges = gauges.clone();
+            async move {
+                (
+                    [(header::CONTENT_TYPE, OPENMETRICS_CONTENT_TYPE)],
+                    gauges.render_openmetrics(),
+                )
+            }
+        }),
+    )
+}
@@ -11,11 +11,88 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use tokio::sync::RwLock;
+use chrono::{DateTime, Utc};
+use tokio::sync::{Mutex, RwLock};
 use tracing::{debug, warn};
 
-use crate::error::A2AResult;
-use crate::types::{Task, TaskState};
+use crate::error::{A2AError, A2AResult};
+use crate::types::{Artifact, ArtifactSummary, ReadConsistency, Task, TaskState};
+
+use super::artifact_stream::{ArtifactStream, PartsReader};
+use super::kv_store::{self, KvStore};
+
+/// Key of the task metadata entry naming the tenant that owns the task.
+/// Reserved: status update events can't set it (see
+/// [`TaskManager`](super::task_manager::TaskManager)).
+pub const TENANT_METADATA_KEY: &str = "a2a-rs/tenant";
+
+/// The tenant owning `task`, as recorded by
+/// [`TaskStore::save_for_tenant()`]; `None` for tasks without one.
+pub fn task_tenant(task: &Task) -> Option<&str> {
+    task.metadata.as_ref()?.get(TENANT_METADATA_KEY)?.as_str()
+}
+
+/// Record `tenant` as the owner of `task`; `None` removes the owner.
+pub fn set_task_tenant(task: &mut Task, tenant: Option<&str>) {
+    match tenant {
+        Some(tenant) => {
+            let metadata = task
+                .metadata
+                .get_or_insert_with(|| serde_json::Value::Object(Default::default()));
+            if !metadata.is_object() {
+                warn!(task_id = %task.id, "Replacing non-object task metadata to record tenant");
+                *metadata = serde_json::Value::Object(Default::default());
+            }
+            if let Some(entries) = metadata.as_object_mut() {
+                entries.insert(TENANT_METADATA_KEY.to_string(), tenant.into());
+            }
+        }
+        None => {
+            if let Some(entries) = task.metadata.as_mut().and_then(|m| m.as_object_mut()) {
+                entries.remove(TENANT_METADATA_KEY);
+            }
+        }
+    }
+}
+
+/// Whether `task` is in a terminal state set before `before`, going by its
+/// status timestamp. Tasks without a (parseable) timestamp never are.
+pub fn finished_before(task: &Task, before: DateTime<Utc>) -> bool {
+    task.status.state.is_terminal()
+        && task
+            .status
+            .timestamp
+            .as_deref()
+            .and_then(|timestamp| DateTime::parse_from_rfc3339(timestamp).ok())
+            .is_some_and(|finished| finished < before)
+}
+
+/// Fail with [`A2AError::TaskNotFound`] unless `task` belongs to `tenant`,
+/// so other tenants can't tell the task exists. Tasks without a tenant
+/// belong to requests without one.
+pub fn check_tenant(task: &Task, tenant: Option<&str>) -> A2AResult<()> {
+    if task_tenant(task) == tenant {
+        return Ok(());
+    }
+    debug!(task_id = %task.id, "Rejecting access to another tenant's task");
+    Err(A2AError::task_not_found(task.id.clone()))
+}
+
+/// The artifact `artifact_id` of `artifacts`, or `InvalidParams` naming
+/// `task_id`.
+pub(crate) fn find_artifact(
+    artifacts: impl IntoIterator<Item = Artifact>,
+    task_id: &str,
+    artifact_id: &str,
+) -> A2AResult<Artifact> {
+    artifacts
+        .into_iter()
+        .find(|a| a.artifact_id == artifact_id)
+        .ok_or_else(|| A2AError::InvalidParams {
+            message: format!("Task {task_id} has no artifact {artifact_id}"),
+            data: None,
+        })
+}
 
 /// Parameters for listing tasks with optional filtering and pagination.
 #[derive(Debug, Clone, Default)]
@@ -31,6 +108,24 @@ pub struct TaskListParams {
 
     /// Opaque token for pagination — the task ID to start after.
     pub page_token: Option<String>,
+
+    /// Keep only the most recent N history messages of each task (0 or
+    /// `None` = unlimited). Applied by the request handler, not the store.
+    pub history_length: Option<usize>,
+
+    /// Only tasks owned by this tenant (see [`task_tenant()`]); `""` selects
+    /// tasks without a tenant, `None` doesn't filter. The request handler
+    /// always sets it to the caller's tenant.
+    pub tenant: Option<String>,
+}
+
+impl TaskListParams {
+    /// Whether `task` passes the [`tenant`](Self::tenant) filter.
+    pub fn matches_tenant(&self, task: &Task) -> bool {
+        self.tenant
+            .as_deref()
+            .map_or(true, |tenant| task_tenant(task).unwrap_or("") == tenant)
+    }
 }
 
 /// Response for a task listing request.
@@ -43,6 +138,39 @@ pub struct TaskListResponse {
     pub next_page_token: Option<String>,
 }
 
+/// Change applied to a stored task by [`TaskStore::update()`]. Returning an
+/// error aborts the update and leaves the stored task unchanged.
+pub type TaskUpdate = Box<dyn FnOnce(&mut Task) -> A2AResult<()> + Send>;
+
+/// A stored task and its version.
+///
+/// The version starts at 1 when a task is first saved and goes up by one
+/// with every [`save()`](TaskStore::save) and
+/// [`update()`](TaskStore::update), so a writer can tell whether the task
+/// changed since it read it. Stores that don't track versions report 0.
+#[derive(Debug, Clone)]
+pub struct VersionedTask {
+    /// The task as stored.
+    pub task: Task,
+    /// Version of the stored task.
+    pub version: u64,
+}
+
+/// `VersionConflict` unless task `task_id`, stored at `actual`, is at the
+/// `expected` version (if any).
+fn check_version(task_id: &str, expected: Option<u64>, actual: u64) -> A2AResult<()> {
+    match expected {
+        Some(expected) if expected != actual => {
+            debug!(
+                task_id,
+                expected, actual, "Rejecting update of a changed task"
+            );
+            Err(A2AError::version_conflict(task_id, expected, actual))
+        }
+        _ => Ok(()),
+    }
+}
+
 /// Trait for persisting and retrieving A2A tasks.
 ///
 /// Implementations must be `Send + Sync` for use in async server contexts.
@@ -51,6 +179,7 @@ pub struct TaskListResponse {
 /// # Provided implementations
 ///
 /// - [`InMemoryTaskStore`] — simple in-memory store (data lost on restart)
+/// - [`KvTaskStore`] — tasks in any [`KvStore`]
 #[async_trait]
 pub trait TaskStore: Send + Sync {
     /// Save or update a task in the store.
@@ -63,6 +192,156 @@ pub trait TaskStore: Send + Sync {
     /// Returns `None` if the task does not exist.
     async fn get(&self, task_id: &str) -> A2AResult<Option<Task>>;
 
+    /// Retrieve a task by its ID together with its version, to pass to
+    /// [`update()`](Self::update) as the expected version.
+    ///
+    /// The default calls [`get()`](Self::get) and reports version 0, for
+    /// stores that don't track versions.
+    async fn get_versioned(&self, task_id: &str) -> A2AResult<Option<VersionedTask>> {
+        let task = self.get(task_id).await?;
+        Ok(task.map(|task| VersionedTask { task, version: 0 }))
+    }
+
+    /// Retrieve a task by its ID, honouring a read-consistency hint.
+    ///
+    /// Replicated backends override this to read from the primary or wait
+    /// for a write sequence. The default ignores the hint and calls
+    /// [`get()`](Self::get), which is correct for single-node stores such as
+    /// [`InMemoryTaskStore`].
+    async fn get_with_consistency(
+        &self,
+        task_id: &str,
+        consistency: &ReadConsistency,
+    ) -> A2AResult<Option<Task>> {
+        let _ = consistency;
+        self.get(task_id).await
+    }
+
+    /// Save `task` as owned by `tenant` (see [`task_tenant()`]).
+    ///
+    /// The default records the tenant in the task's metadata and calls
+    /// [`save()`](Self::save). Stores keeping tenants apart physically,
+    /// e.g. in one table or key prefix each, override this together with
+    /// [`get_for_tenant()`](Self::get_for_tenant).
+    async fn save_for_tenant(&self, tenant: Option<&str>, mut task: Task) -> A2AResult<()> {
+        set_task_tenant(&mut task, tenant);
+        self.save(task).await
+    }
+
+    /// Retrieve a task by its ID on behalf of `tenant`.
+    ///
+    /// Returns `None` if the task does not exist, and fails with
+    /// [`A2AError::TaskNotFound`] if it belongs to another tenant. The
+    /// default calls [`get()`](Self::get) and checks the owner with
+    /// [`check_tenant()`].
+    async fn get_for_tenant(&self, tenant: Option<&str>, task_id: &str) -> A2AResult<Option<Task>> {
+        let task = self.get(task_id).await?;
+        if let Some(task) = &task {
+            check_tenant(task, tenant)?;
+        }
+        Ok(task)
+    }
+
+    /// Open an artifact of a stored task for streaming reads.
+    ///
+    /// The default loads the task via [`get()`](Self::get) and encodes the
+    /// artifact's parts lazily with [`PartsReader`]. Stores that keep
+    /// artifact payloads in blob storage should override this to stream
+    /// directly from it.
+    ///
+    /// Fails with `TaskNotFound` if the task doesn't exist, or
+    /// `InvalidParams` if it has no artifact with this ID.
+    async fn open_artifact(&self, task_id: &str, artifact_id: &str) -> A2AResult<ArtifactStream> {
+        let task = self
+            .get(task_id)
+            .await?
+            .ok_or_else(|| A2AError::TaskNotFound {
+                message: task_id.to_string(),
+                data: None,
+            })?;
+        let artifact = find_artifact(task.artifacts.unwrap_or_default(), task_id, artifact_id)?;
+        Ok(Box::pin(PartsReader::new(artifact.parts)))
+    }
+
+    /// The artifacts of `tenant`'s task, without their parts, in the order
+    /// the task holds them.
+    ///
+    /// The default loads the task via [`get_for_tenant()`](Self::get_for_tenant);
+    /// stores that keep artifacts apart from tasks should override this and
+    /// [`get_artifact()`](Self::get_artifact) to read only what's needed.
+    ///
+    /// Fails with `TaskNotFound` if the task doesn't exist.
+    async fn list_artifacts(
+        &self,
+        tenant: Option<&str>,
+        task_id: &str,
+    ) -> A2AResult<Vec<ArtifactSummary>> {
+        let task = self
+            .get_for_tenant(tenant, task_id)
+            .await?
+            .ok_or_else(|| A2AError::task_not_found(task_id))?;
+        Ok(task
+            .artifacts
+            .iter()
+            .flatten()
+            .map(ArtifactSummary::from)
+            .collect())
+    }
+
+    /// One artifact of `tenant`'s task, with its parts.
+    ///
+    /// The default loads the task via [`get_for_tenant()`](Self::get_for_tenant).
+    ///
+    /// Fails with `TaskNotFound` if the task doesn't exist, or
+    /// `InvalidParams` if it has no artifact with this ID.
+    async fn get_artifact(
+        &self,
+        tenant: Option<&str>,
+        task_id: &str,
+        artifact_id: &str,
+    ) -> A2AResult<Artifact> {
+        let task = self
+            .get_for_tenant(tenant, task_id)
+            .await?
+            .ok_or_else(|| A2AError::task_not_found(task_id))?;
+        find_artifact(task.artifacts.unwrap_or_default(), task_id, artifact_id)
+    }
+
+    /// Read, modify and write back a stored task as one step, so that
+    /// concurrent writers (the event pipeline, a cancel request, a
+    /// follow-up message) don't overwrite each other's changes. Returns the
+    /// updated task.
+    ///
+    /// With an `expected_version` the update is a compare-and-swap: it
+    /// fails with [`A2AError::VersionConflict`] unless the task is still at
+    /// that version (see [`get_versioned()`](Self::get_versioned)), so a
+    /// writer that decided on a change from what it read can read again
+    /// and retry instead of overwriting a newer state. Without one the
+    /// change is applied to whatever is stored.
+    ///
+    /// Fails with `TaskNotFound` if the task doesn't exist, or with the
+    /// error `update` returns; the stored task is unchanged in all cases.
+    ///
+    /// The default is a plain [`get_versioned()`](Self::get_versioned)
+    /// followed by [`save()`](Self::save) and is **not** atomic. Stores
+    /// with concurrent writers must override it, e.g. with a row lock or a
+    /// transaction.
+    async fn update(
+        &self,
+        task_id: &str,
+        expected_version: Option<u64>,
+        update: TaskUpdate,
+    ) -> A2AResult<Task> {
+        let VersionedTask { mut task, version } = self
+            .get_versioned(task_id)
+            .await?
+            .ok_or_else(|| A2AError::task_not_found(task_id))?;
+        check_version(task_id, expected_version, version)?;
+        update(&mut task)?;
+        self.save(task.clone()).await?;
+        Ok(task)
+    }
+
     /// Delete a task by its ID.
     ///
     /// Silently succeeds if the task does not exist.
@@ -70,9 +349,38 @@ pub trait TaskStore: Send + Sync {
 
     /// List tasks matching the given parameters.
     ///
-    /// Supports filtering by context ID and status, and pagination via
+    /// Supports filtering by tenant, context ID and status, and pagination via
     /// `page_size` and `page_token`.
     async fn list(&self, params: &TaskListParams) -> A2AResult<TaskListResponse>;
+
+    /// Delete every task that reached a terminal state before `before` (see
+    /// [`finished_before()`]) and return the deleted tasks. Running tasks
+    /// are never purged.
+    ///
+    /// The default pages through [`list()`](Self::list) and
+    /// [`delete()`](Self::delete)s the matches one by one; stores that can
+    /// delete by age in one query should override it.
+    async fn purge(&self, before: DateTime<Utc>) -> A2AResult<Vec<Task>> {
+        let mut params = TaskListParams::default();
+        let mut expired = Vec::new();
+        loop {
+            let page = self.list(&params).await?;
+            expired.extend(
+                page.tasks
+                    .iter()
+                    .filter(|task| finished_before(task, before))
+                    .cloned(),
+            );
+            match page.next_page_token {
+                Some(token) if !page.tasks.is_empty() => params.page_token = Some(token),
+                _ => break,
+            }
+        }
+        for task in &expired {
+            self.delete(&task.id).await?;
+        }
+        Ok(expired)
+    }
 }
 
 /// In-memory task store backed by a `HashMap`.
@@ -83,7 +391,7 @@ pub trait TaskStore: Send + Sync {
 /// Thread-safe via `tokio::sync::RwLock`.
 #[derive(Debug)]
 pub struct InMemoryTaskStore {
-    tasks: Arc<RwLock<HashMap<String, Task>>>,
+    tasks: Arc<RwLock<HashMap<String, VersionedTask>>>,
     /// Insertion order for deterministic listing/pagination.
     insertion_order: Arc<RwLock<Vec<String>>>,
 }
@@ -109,8 +417,9 @@ impl TaskStore for InMemoryTaskStore {
     async fn save(&self, task: Task) -> A2AResult<()> {
         let task_id = task.id.clone();
         let mut tasks = self.tasks.write().await;
-        let is_new = !tasks.contains_key(&task_id);
-        tasks.insert(task_id.clone(), task);
+        let version = tasks.get(&task_id).map_or(0, |stored| stored.version) + 1;
+        let is_new = version == 1;
+        tasks.insert(task_id.clone(), VersionedTask { task, version });
 
         if is_new {
             let mut order = self.insertion_order.write().await;
@@ -122,12 +431,75 @@ impl TaskStore for InMemoryTaskStore {
     }
 
     async fn get(&self, task_id: &str) -> A2AResult<Option<Task>> {
+        Ok(self.get_versioned(task_id).await?.map(|stored| stored.task))
+    }
+
+    async fn get_versioned(&self, task_id: &str) -> A2AResult<Option<VersionedTask>> {
         let tasks = self.tasks.read().await;
         let task = tasks.get(task_id).cloned();
         debug!(task_id = %task_id, found = task.is_some(), "Task lookup");
         Ok(task)
     }
 
+    async fn list_artifacts(
+        &self,
+        tenant: Option<&str>,
+        task_id: &str,
+    ) -> A2AResult<Vec<ArtifactSummary>> {
+        let tasks = self.tasks.read().await;
+        let task = tasks
+            .get(task_id)
+            .map(|stored| &stored.task)
+            .ok_or_else(|| A2AError::task_not_found(task_id))?;
+        check_tenant(task, tenant)?;
+        Ok(task
+            .artifacts
+            .iter()
+            .flatten()
+            .map(ArtifactSummary::from)
+            .collect())
+    }
+
+    async fn get_artifact(
+        &self,
+        tenant: Option<&str>,
+        task_id: &str,
+        artifact_id: &str,
+    ) -> A2AResult<Artifact> {
+        let tasks = self.tasks.read().await;
+        let task = tasks
+            .get(task_id)
+            .map(|stored| &stored.task)
+            .ok_or_else(|| A2AError::task_not_found(task_id))?;
+        check_tenant(task, tenant)?;
+        // Clone only the requested artifact, not the whole task.
+        let artifacts = task.artifacts.iter().flatten();
+        find_artifact(
+            artifacts.filter(|a| a.artifact_id == artifact_id).cloned(),
+            task_id,
+            artifact_id,
+        )
+    }
+
+    async fn update(
+        &self,
+        task_id: &str,
+        expected_version: Option<u64>,
+        update: TaskUpdate,
+    ) -> A2AResult<Task> {
+        let mut tasks = self.tasks.write().await;
+        let stored = tasks
+            .get_mut(task_id)
+            .ok_or_else(|| A2AError::task_not_found(task_id))?;
+        check_version(task_id, expected_version, stored.version)?;
+        let mut task = stored.task.clone();
+        update(&mut task)?;
+        stored.task = task.clone();
+        stored.version += 1;
+        debug!(task_id = %task_id, version = stored.version, "Task updated");
+        Ok(task)
+    }
+
     async fn delete(&self, task_id: &str) -> A2AResult<()> {
         let mut tasks = self.tasks.write().await;
         if tasks.remove(task_id).is_some() {
@@ -169,7 +541,11 @@ impl TaskStore for InMemoryTaskStore {
                 break;
             }
 
-            if let Some(task) = tasks.get(id) {
+            if let Some(VersionedTask { task, .. }) = tasks.get(id) {
+                if !params.matches_tenant(task) {
+                    continue;
+                }
+
                 // Apply context_id filter.
                 if let Some(ref ctx_id) = params.context_id {
                     if task.context_id != *ctx_id {
@@ -217,4 +593,250 @@ impl TaskStore for InMemoryTaskStore {
             next_page_token,
         })
     }
+
+    async fn purge(&self, before: DateTime<Utc>) -> A2AResult<Vec<Task>> {
+        let mut tasks = self.tasks.write().await;
+        let mut order = self.insertion_order.write().await;
+        let mut purged = Vec::new();
+        order.retain(|id| match tasks.get(id) {
+            Some(stored) if finished_before(&stored.task, before) => {
+                purged.extend(tasks.remove(id).map(|stored| stored.task));
+                false
+            }
+            _ => true,
+        });
+        debug!(count = purged.len(), "Purged finished tasks");
+        Ok(purged)
+    }
+}
+
+/// Key prefix of tasks in a [`KvStore`].
+const TASK_KEY_PREFIX: &str = "task/";
+
+/// Key prefix of task versions in a [`KvStore`].
+const TASK_VERSION_KEY_PREFIX: &str = "task-version/";
+
+/// [`TaskStore`] on top of a [`KvStore`]: each task is a JSON value under
+/// `task/{task_id}`, and its version a number under
+/// `task-version/{task_id}`.
+///
+/// Listing scans all tasks and pages through them in task ID order (not
+/// insertion order, unlike [`InMemoryTaskStore`]); the page token is the
+/// last task ID of the previous page.
+///
+/// [`update()`](TaskStore::update) is atomic with respect to other writes
+/// through this store and its clones. Replicas sharing the backend don't
+/// coordinate; route a task's writes to one replica.
+#[derive(Clone)]
+pub struct KvTaskStore {
+    store: Arc<dyn KvStore>,
+    /// Serialises writes, so an update's read-modify-write isn't
+    /// interleaved with another write.
+    writes: Arc<Mutex<()>>,
+}
+
+impl std::fmt::Debug for KvTaskStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("KvTaskStore").finish_non_exhaustive()
+    }
+}
+
+impl KvTaskStore {
+    /// Keep tasks in `store`.
+    pub fn new(store: Arc<dyn KvStore>) -> Self {
+        Self {
+            store,
+            writes: Arc::default(),
+        }
+    }
+
+    fn key(task_id: &str) -> String {
+        format!("{TASK_KEY_PREFIX}{task_id}")
+    }
+
+    fn version_key(task_id: &str) -> String {
+        format!("{TASK_VERSION_KEY_PREFIX}{task_id}")
+    }
+
+    /// The stored version of task `task_id`; 0 for tasks saved before
+    /// versions were recorded.
+    async fn version(&self, task_id: &str) -> A2AResult<u64> {
+        let version = kv_store::get_json(&*self.store, &Self::version_key(task_id)).await?;
+        Ok(version.unwrap_or(0))
+    }
+
+    /// Store `task` at the version after `version`.
+    async fn put(&self, task: &Task, version: u64) -> A2AResult<()> {
+        kv_store::put_json(&*self.store, &Self::key(&task.id), task).await?;
+        kv_store::put_json(&*self.store, &Self::version_key(&task.id), &(version + 1)).await
+    }
+}
+
+#[async_trait]
+impl TaskStore for KvTaskStore {
+    async fn save(&self, task: Task) -> A2AResult<()> {
+        let _writes = self.writes.lock().await;
+        let version = self.version(&task.id).await?;
+        self.put(&task, version).await
+    }
+
+    async fn get(&self, task_id: &str) -> A2AResult<Option<Task>> {
+        kv_store::get_json(&*self.store, &Self::key(task_id)).await
+    }
+
+    async fn get_versioned(&self, task_id: &str) -> A2AResult<Option<VersionedTask>> {
+        // Under the write lock, so the version belongs to the task read.
+        let _writes = self.writes.lock().await;
+        let Some(task) = self.get(task_id).await? else {
+            return Ok(None);
+        };
+        let version = self.version(task_id).await?;
+        Ok(Some(VersionedTask { task, version }))
+    }
+
+    async fn update(
+        &self,
+        task_id: &str,
+        expected_version: Option<u64>,
+        update: TaskUpdate,
+    ) -> A2AResult<Task> {
+        let _writes = self.writes.lock().await;
+        let mut task = self
+            .get(task_id)
+            .await?
+            .ok_or_else(|| A2AError::task_not_found(task_id))?;
+        let version = self.version(task_id).await?;
+        check_version(task_id, expected_version, version)?;
+        update(&mut task)?;
+        self.put(&task, version).await?;
+        Ok(task)
+    }
+
+    async fn delete(&self, task_id: &str) -> A2AResult<()> {
+        let _writes = self.writes.lock().await;
+        self.store.delete(&Self::key(task_id)).await?;
+        self.store.delete(&Self::version_key(task_id)).await
+    }
+
+    async fn list(&self, params: &TaskListParams) -> A2AResult<TaskListResponse> {
+        let entries = self.store.scan_prefix(TASK_KEY_PREFIX).await?;
+        let page_size = params.page_size.unwrap_or(usize::MAX);
+        let mut tasks = Vec::new();
+        let mut more = false;
+        for (key, value) in entries {
+            let task_id = &key[TASK_KEY_PREFIX.len()..];
+            if params
+                .page_token
+                .as_deref()
+                .is_some_and(|token| task_id <= token)
+            {
+                continue;
+            }
+            let task: Task = kv_store::decode(&key, &value)?;
+            if !params.matches_tenant(&task) {
+                continue;
+            }
+            if params
+                .context_id
+                .as_ref()
+                .is_some_and(|context_id| &task.context_id != context_id)
+            {
+                continue;
+            }
+            if params
+                .status
+                .as_ref()
+                .is_some_and(|states| !states.contains(&task.status.state))
+            {
+                continue;
+            }
+            if tasks.len() == page_size {
+                more = true;
+                break;
+            }
+            tasks.push(task);
+        }
+        let next_page_token = more
+            .then(|| tasks.last().map(|task| task.id.clone()))
+            .flatten();
+        Ok(TaskListResponse {
+            tasks,
+            next_page_token,
+        })
+    }
+}
+
+/// Shared stores are stores too — lets an `Arc<dyn TaskStore>` back a
+/// [`TaskManager`](super::TaskManager), which takes ownership of its store.
+#[async_trait]
+impl<T: TaskStore + ?Sized> TaskStore for Arc<T> {
+    async fn save(&self, task: Task) -> A2AResult<()> {
+        (**self).save(task).await
+    }
+
+    async fn get(&self, task_id: &str) -> A2AResult<Option<Task>> {
+        (**self).get(task_id).await
+    }
+
+    async fn get_versioned(&self, task_id: &str) -> A2AResult<Option<VersionedTask>> {
+        (**self).get_versioned(task_id).await
+    }
+
+    async fn get_with_consistency(
+        &self,
+        task_id: &str,
+        consistency: &ReadConsistency,
+    ) -> A2AResult<Option<Task>> {
+        (**self).get_with_consistency(task_id, consistency).await
+    }
+
+    async fn save_for_tenant(&self, tenant: Option<&str>, task: Task) -> A2AResult<()> {
+        (**self).save_for_tenant(tenant, task).await
+    }
+
+    async fn get_for_tenant(&self, tenant: Option<&str>, task_id: &str) -> A2AResult<Option<Task>> {
+        (**self).get_for_tenant(tenant, task_id).await
+    }
+
+    async fn open_artifact(&self, task_id: &str, artifact_id: &str) -> A2AResult<ArtifactStream> {
+        (**self).open_artifact(task_id, artifact_id).await
+    }
+
+    async fn list_artifacts(
+        &self,
+        tenant: Option<&str>,
+        task_id: &str,
+    ) -> A2AResult<Vec<ArtifactSummary>> {
+        (**self).list_artifacts(tenant, task_id).await
+    }
+
+    async fn get_artifact(
+        &self,
+        tenant: Option<&str>,
+        task_id: &str,
+        artifact_id: &str,
+    ) -> A2AResult<Artifact> {
+        (**self).get_artifact(tenant, task_id, artifact_id).await
+    }
+
+    async fn update(
+        &self,
+        task_id: &str,
+        expected_version: Option<u64>,
+        update: TaskUpdate,
+    ) -> A2AResult<Task> {
+        (**self).update(task_id, expected_version, update).await
+    }
+
+    async fn delete(&self, task_id: &str) -> A2AResult<()> {
+        (**self).delete(task_id).await
+    }
+
+    async fn list(&self, params: &TaskListParams) -> A2AResult<TaskListResponse> {
+        (**self).list(params).await
+    }
+
+    async fn purge(&self, before: DateTime<Utc>) -> A2AResult<Vec<Task>> {
+        (**self).purge(before).await
+    }
 }
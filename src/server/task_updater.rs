@@ -2,22 +2,27 @@
 //!
 //! Mirrors Python SDK's `TaskUpdater` from `a2a.server.tasks.task_updater`.
 //!
-//! The updater enforces the A2A state machine: once a task reaches a terminal
-//! state (completed, failed, canceled, rejected), no further status updates
+//! The updater enforces the A2A state machine: status updates must follow
+//! [`TaskState::can_transition_to`], so once a task reaches a terminal
+//! state (completed, failed, canceled, rejected) no further status updates
 //! are accepted. It provides convenience methods for common transitions and
 //! handles artifact ID generation.
 
-use chrono::Utc;
+use std::sync::Arc;
+
 use tokio::sync::Mutex;
+use tokio_util::sync::CancellationToken;
 use tracing::{debug, warn};
 use uuid::Uuid;
 
 use crate::error::{A2AError, A2AResult};
 use crate::types::{
-    Artifact, Message, Part, StreamResponse, TaskArtifactUpdateEvent, TaskState, TaskStatus,
+    Artifact, Message, Part, StreamResponse, TaskArtifactUpdateEvent, TaskState,
     TaskStatusUpdateEvent,
 };
 
+use super::artifact_writer::ArtifactWriter;
+use super::content_validation::ContentValidator;
 use super::event_queue::EventQueue;
 
 /// Thread-safe task state transition helper.
@@ -37,18 +42,46 @@ use super::event_queue::EventQueue;
 ///
 /// # Thread safety
 ///
-/// All mutation is protected by a `tokio::sync::Mutex`, making it safe
-/// to share across tasks via `Arc<TaskUpdater>`.
+/// All mutation is protected by a `tokio::sync::Mutex`. Cloning is cheap
+/// and every clone shares the same terminal-state arbitration, so executors
+/// can hand a clone to each subtask: the first terminal update wins and
+/// later ones from any clone return an error.
+///
+/// Use [`scoped()`](Self::scoped) to give a subtask its own artifact
+/// namespace.
+///
+/// # State machine
+///
+/// A status update the state machine forbids, e.g. `working` after
+/// `input-required` is fine but `submitted` after `working` is not, returns
+/// [`A2AError::InvalidStateTransition`] without publishing anything. Tests
+/// that need odd sequences can opt out with
+/// [`without_transition_checks()`](Self::without_transition_checks); the
+/// terminal-state guard stays.
+///
+/// # Cancellation
+///
+/// An updater given the request's cancellation token (see
+/// [`with_cancellation()`](Self::with_cancellation) and
+/// [`RequestContext::task_updater()`](super::RequestContext::task_updater))
+/// refuses every update but the transition to `canceled` once the task
+/// is canceled, so a long-running executor can't report results for it.
+#[derive(Clone)]
 pub struct TaskUpdater {
     event_queue: EventQueue,
     task_id: String,
     context_id: String,
-    state: Mutex<UpdaterState>,
+    artifact_prefix: Option<String>,
+    cancellation: Option<CancellationToken>,
+    check_transitions: bool,
+    content_validator: Option<Arc<dyn ContentValidator>>,
+    state: Arc<Mutex<UpdaterState>>,
 }
 
 /// Internal mutable state protected by the mutex.
 struct UpdaterState {
-    terminal_reached: bool,
+    /// State of the last status update published.
+    current: Option<TaskState>,
     artifact_counter: u64,
 }
 
@@ -59,17 +92,85 @@ impl TaskUpdater {
             event_queue,
             task_id,
             context_id,
-            state: Mutex::new(UpdaterState {
-                terminal_reached: false,
+            artifact_prefix: None,
+            cancellation: None,
+            check_transitions: true,
+            content_validator: None,
+            state: Arc::new(Mutex::new(UpdaterState {
+                current: None,
                 artifact_counter: 0,
-            }),
+            })),
+        }
+    }
+
+    /// Create a sub-updater whose artifact IDs are namespaced under `prefix`.
+    ///
+    /// The sub-updater shares the event queue and terminal-state tracking
+    /// with `self`. Artifact IDs it publishes become `"{prefix}/{id}"`;
+    /// scoping a scoped updater nests the prefixes (`"outer/inner/{id}"`).
+    pub fn scoped(&self, prefix: impl Into<String>) -> Self {
+        let prefix = prefix.into();
+        let artifact_prefix = match &self.artifact_prefix {
+            Some(parent) => format!("{parent}/{prefix}"),
+            None => prefix,
+        };
+        Self {
+            artifact_prefix: Some(artifact_prefix),
+            ..self.clone()
+        }
+    }
+
+    /// Refuse updates other than `canceled` once `token` is cancelled.
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = Some(token);
+        self
+    }
+
+    /// Check artifacts with `validator` before publishing them; refused
+    /// ones fail [`add_artifact()`](Self::add_artifact) with the
+    /// [output error](super::ContentViolation::into_output_error) of the violation.
+    pub fn with_content_validator(mut self, validator: Arc<dyn ContentValidator>) -> Self {
+        self.content_validator = Some(validator);
+        self
+    }
+
+    /// Skip the state machine checks, except that nothing follows a
+    /// terminal state. Meant for tests.
+    pub fn without_transition_checks(mut self) -> Self {
+        self.check_transitions = false;
+        self
+    }
+
+    /// Returns `true` if the task was canceled (see
+    /// [`with_cancellation()`](Self::with_cancellation)).
+    pub fn is_cancelled(&self) -> bool {
+        self.cancellation
+            .as_ref()
+            .is_some_and(CancellationToken::is_cancelled)
+    }
+
+    /// Error for an update refused because the task was canceled.
+    fn check_not_cancelled(&self, update: &str) -> A2AResult<()> {
+        if !self.is_cancelled() {
+            return Ok(());
         }
+        warn!(task_id = %self.task_id, update, "Attempted update after cancellation");
+        Err(A2AError::Other(format!(
+            "Task {} was canceled — cannot publish {update}",
+            self.task_id
+        )))
+    }
+
+    /// The artifact namespace of this updater, if it was created via
+    /// [`scoped()`](Self::scoped).
+    pub fn artifact_prefix(&self) -> Option<&str> {
+        self.artifact_prefix.as_deref()
     }
 
     /// Returns `true` if the task has reached a terminal state.
     pub async fn is_terminal(&self) -> bool {
         let state = self.state.lock().await;
-        state.terminal_reached
+        state.current.is_some_and(|current| current.is_terminal())
     }
 
     /// Publish a status update event with a full `Message` object.
@@ -84,7 +185,9 @@ impl TaskUpdater {
     ///
     /// # Errors
     ///
-    /// Returns an error if the task has already reached a terminal state.
+    /// Returns [`A2AError::InvalidStateTransition`] if the task can't move
+    /// to `task_state`, and an error if it was canceled and `task_state`
+    /// isn't `canceled`.
     pub async fn update_status(
         &self,
         task_state: TaskState,
@@ -103,7 +206,9 @@ impl TaskUpdater {
     ///
     /// # Errors
     ///
-    /// Returns an error if the task has already reached a terminal state.
+    /// Returns [`A2AError::InvalidStateTransition`] if the task can't move
+    /// to `task_state`, e.g. because it already reached a terminal state,
+    /// and an error if it was canceled and `task_state` isn't `canceled`.
     pub async fn update_status_with_timestamp(
         &self,
         task_state: TaskState,
@@ -112,46 +217,53 @@ impl TaskUpdater {
         timestamp: Option<String>,
         metadata: Option<serde_json::Value>,
     ) -> A2AResult<()> {
-        let is_terminal = Self::is_terminal_state(&task_state);
-        let is_final = if is_terminal { true } else { r#final };
+        if task_state != TaskState::Canceled {
+            self.check_not_cancelled(&format!("a {task_state} status"))?;
+        }
+        let is_terminal = task_state.is_terminal();
 
-        // Check and update terminal state under the lock, then drop it before
+        // Check and update the state under the lock, then drop it before
         // the async enqueue_event call to avoid holding the mutex across an
         // await point.
         {
             let mut state = self.state.lock().await;
 
-            if state.terminal_reached {
-                warn!(
-                    task_id = %self.task_id,
-                    requested_state = ?task_state,
-                    "Attempted status update after terminal state"
-                );
-                return Err(A2AError::Other(format!(
-                    "Task {} has already reached a terminal state — cannot transition to {:?}",
-                    self.task_id, task_state
-                )));
-            }
-
-            if is_terminal {
-                state.terminal_reached = true;
+            if let Some(current) = state.current {
+                let allowed = if self.check_transitions {
+                    current.can_transition_to(task_state)
+                } else {
+                    !current.is_terminal()
+                };
+                if !allowed {
+                    warn!(
+                        task_id = %self.task_id,
+                        current_state = ?current,
+                        requested_state = ?task_state,
+                        "Attempted invalid status transition"
+                    );
+                    return Err(A2AError::invalid_state_transition(
+                        &self.task_id,
+                        current,
+                        task_state,
+                    ));
+                }
             }
+            state.current = Some(task_state);
         }
 
-        let current_timestamp = timestamp.unwrap_or_else(|| Utc::now().to_rfc3339());
-
-        let event = StreamResponse::StatusUpdate(TaskStatusUpdateEvent {
-            task_id: self.task_id.clone(),
-            context_id: self.context_id.clone(),
-            kind: "status-update".to_string(),
-            status: TaskStatus {
-                state: task_state,
-                message,
-                timestamp: Some(current_timestamp),
-            },
-            r#final: is_final,
-            metadata,
-        });
+        let mut builder = TaskStatusUpdateEvent::builder(&self.task_id, &self.context_id)
+            .with_state(task_state)
+            .with_final(r#final);
+        if let Some(message) = message {
+            builder = builder.with_message(message);
+        }
+        if let Some(timestamp) = timestamp {
+            builder = builder.with_timestamp(timestamp);
+        }
+        if let Some(metadata) = metadata {
+            builder = builder.with_metadata(metadata);
+        }
+        let event = StreamResponse::StatusUpdate(builder.build());
 
         self.event_queue.enqueue_event(event).await?;
 
@@ -185,13 +297,16 @@ impl TaskUpdater {
     /// Add an artifact to the task.
     ///
     /// Publishes a `TaskArtifactUpdateEvent` with the given parts.
-    /// An artifact ID is auto-generated if not provided.
+    /// An artifact ID is auto-generated if not provided. On a
+    /// [`scoped()`](Self::scoped) updater the ID is prefixed with the scope.
     ///
     /// Mirrors Python SDK's `TaskUpdater.add_artifact(parts, artifact_id, name, metadata, append, last_chunk, extensions)`.
     ///
     /// NOTE: Unlike `update_status`, this method does NOT check for terminal state.
     /// The Python SDK's `add_artifact` has no terminal state guard — artifacts can
     /// be added independently of the task's status. This matches the Python SDK behavior.
+    /// Artifacts are refused once the task was canceled, though, and when
+    /// the [content validator](Self::with_content_validator) refuses them.
     #[allow(clippy::too_many_arguments)]
     pub async fn add_artifact(
         &self,
@@ -203,6 +318,7 @@ impl TaskUpdater {
         last_chunk: Option<bool>,
         extensions: Option<Vec<String>>,
     ) -> A2AResult<()> {
+        self.check_not_cancelled("artifacts")?;
         let artifact_id = if let Some(id) = artifact_id {
             id
         } else {
@@ -210,19 +326,36 @@ impl TaskUpdater {
             state.artifact_counter += 1;
             Uuid::new_v4().to_string()
         };
+        let artifact_id = match &self.artifact_prefix {
+            Some(prefix) => format!("{prefix}/{artifact_id}"),
+            None => artifact_id,
+        };
+
+        let artifact = Artifact {
+            artifact_id: artifact_id.clone(),
+            parts,
+            name,
+            description: None,
+            metadata: metadata.clone(),
+            extensions,
+        };
+        if let Some(validator) = &self.content_validator {
+            if let Err(violation) = validator.validate_artifact(&artifact).await {
+                warn!(
+                    task_id = %self.task_id,
+                    artifact_id = %artifact_id,
+                    ?violation,
+                    "Artifact refused by content validator"
+                );
+                return Err(violation.into_output_error());
+            }
+        }
 
         let event = StreamResponse::ArtifactUpdate(TaskArtifactUpdateEvent {
             task_id: self.task_id.clone(),
             context_id: self.context_id.clone(),
             kind: "artifact-update".to_string(),
-            artifact: Artifact {
-                artifact_id: artifact_id.clone(),
-                parts,
-                name,
-                description: None,
-                metadata: metadata.clone(),
-                extensions,
-            },
+            artifact,
             append,
             last_chunk,
             metadata,
@@ -239,6 +372,61 @@ impl TaskUpdater {
         Ok(())
     }
 
+    /// Start an artifact written incrementally through an
+    /// [`ArtifactWriter`], which takes care of chunking and of the
+    /// `append` / `lastChunk` flags. The ID is prefixed like
+    /// [`add_artifact()`](Self::add_artifact)'s.
+    pub fn new_artifact(&self, name: impl Into<String>) -> ArtifactWriter {
+        ArtifactWriter::new(self.clone(), Some(name.into()))
+    }
+
+    /// Publish the content of `reader` as one artifact of base64 file
+    /// parts, `chunk_size` bytes per update (`fs` feature), through an
+    /// [`ArtifactWriter`].
+    ///
+    /// The first update starts the artifact, later ones set `append`, and
+    /// the last sets `lastChunk`, so clients and the task store rebuild it
+    /// without the server holding the whole file. An empty reader publishes
+    /// one empty part. Returns the artifact ID, prefixed like
+    /// [`add_artifact()`](Self::add_artifact)'s.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`A2AError::InvalidParams`] if `chunk_size` is zero or
+    /// reading fails, and whatever publishing an update fails with.
+    #[cfg(feature = "fs")]
+    pub async fn stream_artifact<R>(
+        &self,
+        mut reader: R,
+        chunk_size: usize,
+        name: Option<String>,
+        mime_type: Option<String>,
+    ) -> A2AResult<String>
+    where
+        R: tokio::io::AsyncRead + Unpin,
+    {
+        if chunk_size == 0 {
+            return Err(A2AError::invalid_params("chunk_size must be at least 1"));
+        }
+        let mut writer =
+            ArtifactWriter::new(self.clone(), name.clone()).with_chunk_size(chunk_size);
+        let mut written = false;
+        loop {
+            let chunk = read_chunk(&mut reader, chunk_size).await?;
+            let last = chunk.len() < chunk_size;
+            // Skip the empty read that ends a reader of whole chunks.
+            if !chunk.is_empty() || !written {
+                let part = Part::file_from_raw(&chunk, name.clone(), mime_type.clone());
+                writer.write_part(part).await?;
+                written = true;
+            }
+            if last {
+                break;
+            }
+        }
+        writer.finish().await
+    }
+
     // ---- Convenience methods for common state transitions ----
     // These mirror the Python SDK's convenience methods exactly.
 
@@ -297,6 +485,27 @@ impl TaskUpdater {
             .await
     }
 
+    /// Pause for user input: transition to `input-required` with `prompt`
+    /// as the status message, ending the turn (`final`).
+    ///
+    /// `schema`, a JSON Schema describing the expected reply, is attached
+    /// under the message's [`INPUT_SCHEMA_METADATA_KEY`] metadata; clients
+    /// read it back with [`get_input_schema`]. The client answers with
+    /// [`A2AClient::continue_task`](crate::client::A2AClient::continue_task).
+    ///
+    /// [`INPUT_SCHEMA_METADATA_KEY`]: crate::utils::INPUT_SCHEMA_METADATA_KEY
+    /// [`get_input_schema`]: crate::utils::get_input_schema
+    pub async fn require_input(
+        &self,
+        prompt: &str,
+        schema: Option<serde_json::Value>,
+    ) -> A2AResult<()> {
+        let metadata = schema
+            .map(|schema| serde_json::json!({ crate::utils::INPUT_SCHEMA_METADATA_KEY: schema }));
+        let message = self.new_agent_message(vec![Part::text(prompt)], metadata);
+        self.requires_input(Some(message), true).await
+    }
+
     /// Transition to `auth-required` state.
     ///
     /// The agent requires authentication before proceeding.
@@ -350,14 +559,6 @@ impl TaskUpdater {
         }
     }
 
-    /// Check whether a given state is terminal.
-    fn is_terminal_state(state: &TaskState) -> bool {
-        matches!(
-            state,
-            TaskState::Completed | TaskState::Failed | TaskState::Canceled | TaskState::Rejected
-        )
-    }
-
     /// Get the task ID this updater is tracking.
     pub fn task_id(&self) -> &str {
         &self.task_id
@@ -368,3 +569,20 @@ impl TaskUpdater {
         &self.context_id
     }
 }
+
+/// Read up to `size` bytes, fewer only at the end of `reader`.
+#[cfg(feature = "fs")]
+async fn read_chunk<R>(reader: &mut R, size: usize) -> A2AResult<Vec<u8>>
+where
+    R: tokio::io::AsyncRead + Unpin,
+{
+    use tokio::io::AsyncReadExt;
+
+    let mut chunk = Vec::with_capacity(size);
+    (&mut *reader)
+        .take(size as u64)
+        .read_to_end(&mut chunk)
+        .await
+        .map_err(|e| A2AError::invalid_params(format!("Cannot read artifact content: {e}")))?;
+    Ok(chunk)
+}
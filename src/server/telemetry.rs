@@ -0,0 +1,335 @@
+//! Task and SSE metrics (`observability` feature).
+//!
+//! A [`MetricsRecorder`] attached with
+//! [`DefaultRequestHandler::with_metrics`](super::DefaultRequestHandler::with_metrics)
+//! sees every event the task pipeline persists: state transitions, task
+//! durations and event throughput. Attached with
+//! [`RouterConfig::with_metrics`](super::RouterConfig::with_metrics) it also
+//! counts open SSE subscribers.
+//!
+//! Implement the trait to feed an OpenTelemetry meter (or any other metrics
+//! backend), or use the built-in [`OpenMetricsRecorder`] and serve it with
+//! [`metrics_router`]:
+//!
+//! ```rust,ignore
+//! let metrics = Arc::new(OpenMetricsRecorder::new());
+//! let handler = DefaultRequestHandler::new(executor, store).with_metrics(metrics.clone());
+//! let config = RouterConfig::default().with_metrics(metrics.clone());
+//! let app = a2a_router_with_config(Arc::new(handler), card, config)
+//!     .merge(metrics_router(metrics));
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use axum::http::header;
+use axum::routing::get;
+use axum::Router;
+use futures::Stream;
+
+use crate::types::{StreamResponse, TaskState};
+
+use super::task_metrics::{TaskStateGauges, OPENMETRICS_CONTENT_TYPE};
+
+/// Receives task and stream metrics. All methods default to doing nothing.
+///
+/// Called inline on the event pipeline and SSE paths, so implementations
+/// should only update in-memory instruments.
+pub trait MetricsRecorder: Send + Sync {
+    /// An event of `kind` (`status-update`, `artifact-update`, `task`,
+    /// `message`) was persisted for `task_id`.
+    fn event_published(&self, task_id: &str, kind: &str) {
+        let _ = (task_id, kind);
+    }
+
+    /// `task_id` entered `state` via a status update.
+    fn task_state_changed(&self, task_id: &str, state: TaskState) {
+        let _ = (task_id, state);
+    }
+
+    /// An execution of `task_id` ended in terminal `state`, `duration`
+    /// after it started.
+    fn task_finished(&self, task_id: &str, state: TaskState, duration: Duration) {
+        let _ = (task_id, state, duration);
+    }
+
+    /// An SSE stream was opened.
+    fn sse_subscriber_opened(&self) {}
+
+    /// An SSE stream was closed (finished or disconnected).
+    fn sse_subscriber_closed(&self) {}
+
+    /// A [`TaskSweeper`](super::TaskSweeper) deleted `task_id`, because it
+    /// finished too long ago (`reason` = `expired`) or the store held too
+    /// many tasks (`capacity`).
+    fn task_evicted(&self, task_id: &str, reason: &str) {
+        let _ = (task_id, reason);
+    }
+}
+
+/// Report a persisted event of `task_id` to `metrics`; `started` is when
+/// the execution began.
+pub(crate) fn record_event(
+    metrics: &dyn MetricsRecorder,
+    task_id: &str,
+    event: &StreamResponse,
+    is_terminal: bool,
+    started: Instant,
+) {
+    metrics.event_published(task_id, event.kind());
+    if let StreamResponse::StatusUpdate(update) = event {
+        metrics.task_state_changed(task_id, update.status.state);
+        if is_terminal {
+            metrics.task_finished(task_id, update.status.state, started.elapsed());
+        }
+    }
+}
+
+/// Count `stream` as an SSE subscriber of `metrics` until it is dropped.
+pub(crate) fn count_subscriber<S>(
+    metrics: Option<Arc<dyn MetricsRecorder>>,
+    stream: S,
+) -> impl Stream<Item = S::Item>
+where
+    S: Stream,
+{
+    use futures::StreamExt;
+
+    let guard = metrics.map(|metrics| {
+        metrics.sse_subscriber_opened();
+        SubscriberGuard(metrics)
+    });
+    stream.map(move |item| {
+        let _ = &guard;
+        item
+    })
+}
+
+struct SubscriberGuard(Arc<dyn MetricsRecorder>);
+
+impl Drop for SubscriberGuard {
+    fn drop(&mut self) {
+        self.0.sse_subscriber_closed();
+    }
+}
+
+/// Upper bounds (seconds) of the task duration histogram buckets.
+pub const TASK_DURATION_BUCKETS: [f64; 10] =
+    [0.01, 0.05, 0.1, 0.5, 1.0, 5.0, 10.0, 60.0, 300.0, 1800.0];
+
+#[derive(Debug, Default)]
+struct Histogram {
+    /// Observations per bucket of [`TASK_DURATION_BUCKETS`] (not cumulative).
+    buckets: [u64; TASK_DURATION_BUCKETS.len()],
+    count: u64,
+    sum: f64,
+}
+
+#[derive(Debug, Default)]
+struct Counters {
+    transitions: BTreeMap<&'static str, u64>,
+    durations: BTreeMap<&'static str, Histogram>,
+    events: BTreeMap<String, u64>,
+    sse_subscribers: u64,
+    evictions: BTreeMap<String, u64>,
+}
+
+/// In-memory [`MetricsRecorder`] exported in the OpenMetrics text format.
+///
+/// [`render_openmetrics()`](Self::render_openmetrics) emits:
+///
+/// - `a2a_task_state_transitions_total{state}` — status updates by the
+///   state entered
+/// - `a2a_task_duration_seconds{state}` — histogram of execution durations
+///   by terminal state
+/// - `a2a_events_published_total{kind}` — events persisted by kind
+/// - `a2a_sse_subscribers` — SSE streams currently open
+/// - `a2a_tasks_evicted_total{reason}` — tasks deleted by the retention
+///   sweeper
+///
+/// plus the families of [`TaskStateGauges`] if attached with
+/// [`with_task_state_gauges`](Self::with_task_state_gauges).
+#[derive(Debug, Default)]
+pub struct OpenMetricsRecorder {
+    counters: Mutex<Counters>,
+    gauges: Option<Arc<TaskStateGauges>>,
+}
+
+impl OpenMetricsRecorder {
+    /// Create a recorder with all counters at zero.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Include `gauges` in [`render_openmetrics()`](Self::render_openmetrics)
+    /// (builder-style), so one `/metrics` endpoint serves both.
+    pub fn with_task_state_gauges(mut self, gauges: Arc<TaskStateGauges>) -> Self {
+        self.gauges = Some(gauges);
+        self
+    }
+
+    /// Number of status updates that entered `state`.
+    pub fn state_transitions(&self, state: TaskState) -> u64 {
+        let counters = self.counters.lock().unwrap();
+        counters
+            .transitions
+            .get(state.as_str())
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Number of executions that ended in `state`.
+    pub fn tasks_finished(&self, state: TaskState) -> u64 {
+        let counters = self.counters.lock().unwrap();
+        counters
+            .durations
+            .get(state.as_str())
+            .map_or(0, |histogram| histogram.count)
+    }
+
+    /// Number of events of `kind` persisted.
+    pub fn events_published(&self, kind: &str) -> u64 {
+        let counters = self.counters.lock().unwrap();
+        counters.events.get(kind).copied().unwrap_or(0)
+    }
+
+    /// Number of SSE streams currently open.
+    pub fn sse_subscribers(&self) -> u64 {
+        self.counters.lock().unwrap().sse_subscribers
+    }
+
+    /// Number of tasks evicted for `reason` (`expired` or `capacity`).
+    pub fn tasks_evicted(&self, reason: &str) -> u64 {
+        let counters = self.counters.lock().unwrap();
+        counters.evictions.get(reason).copied().unwrap_or(0)
+    }
+
+    /// Render the metrics in the OpenMetrics text format (served with
+    /// [`OPENMETRICS_CONTENT_TYPE`]).
+    pub fn render_openmetrics(&self) -> String {
+        let mut out = String::new();
+        {
+            let counters = self.counters.lock().unwrap();
+            out.push_str("# TYPE a2a_task_state_transitions counter\n");
+            out.push_str(
+                "# HELP a2a_task_state_transitions Status updates by the state entered.\n",
+            );
+            for (state, count) in &counters.transitions {
+                let _ = writeln!(
+                    out,
+                    "a2a_task_state_transitions_total{{state=\"{state}\"}} {count}"
+                );
+            }
+            out.push_str("# TYPE a2a_task_duration_seconds histogram\n");
+            out.push_str("# UNIT a2a_task_duration_seconds seconds\n");
+            out.push_str(
+                "# HELP a2a_task_duration_seconds Execution time of tasks by terminal state.\n",
+            );
+            for (state, histogram) in &counters.durations {
+                let mut cumulative = 0;
+                for (bound, count) in TASK_DURATION_BUCKETS.iter().zip(histogram.buckets) {
+                    cumulative += count;
+                    let _ = writeln!(
+                        out,
+                        "a2a_task_duration_seconds_bucket{{state=\"{state}\",le=\"{bound}\"}} {cumulative}"
+                    );
+                }
+                let _ = writeln!(
+                    out,
+                    "a2a_task_duration_seconds_bucket{{state=\"{state}\",le=\"+Inf\"}} {}",
+                    histogram.count
+                );
+                let _ = writeln!(
+                    out,
+                    "a2a_task_duration_seconds_sum{{state=\"{state}\"}} {:.6}",
+                    histogram.sum
+                );
+                let _ = writeln!(
+                    out,
+                    "a2a_task_duration_seconds_count{{state=\"{state}\"}} {}",
+                    histogram.count
+                );
+            }
+            out.push_str("# TYPE a2a_events_published counter\n");
+            out.push_str("# HELP a2a_events_published Task events persisted by kind.\n");
+            for (kind, count) in &counters.events {
+                let _ = writeln!(out, "a2a_events_published_total{{kind=\"{kind}\"}} {count}");
+            }
+            out.push_str("# TYPE a2a_sse_subscribers gauge\n");
+            out.push_str("# HELP a2a_sse_subscribers SSE streams currently open.\n");
+            let _ = writeln!(out, "a2a_sse_subscribers {}", counters.sse_subscribers);
+            out.push_str("# TYPE a2a_tasks_evicted counter\n");
+            out.push_str("# HELP a2a_tasks_evicted Tasks deleted by the retention sweeper.\n");
+            for (reason, count) in &counters.evictions {
+                let _ = writeln!(
+                    out,
+                    "a2a_tasks_evicted_total{{reason=\"{reason}\"}} {count}"
+                );
+            }
+        }
+        if let Some(gauges) = &self.gauges {
+            let rendered = gauges.render_openmetrics();
+            out.push_str(rendered.strip_suffix("# EOF\n").unwrap_or(&rendered));
+        }
+        out.push_str("# EOF\n");
+        out
+    }
+}
+
+impl MetricsRecorder for OpenMetricsRecorder {
+    fn event_published(&self, _task_id: &str, kind: &str) {
+        let mut counters = self.counters.lock().unwrap();
+        *counters.events.entry(kind.to_string()).or_default() += 1;
+    }
+
+    fn task_state_changed(&self, _task_id: &str, state: TaskState) {
+        let mut counters = self.counters.lock().unwrap();
+        *counters.transitions.entry(state.as_str()).or_default() += 1;
+    }
+
+    fn task_finished(&self, _task_id: &str, state: TaskState, duration: Duration) {
+        let seconds = duration.as_secs_f64();
+        let mut counters = self.counters.lock().unwrap();
+        let histogram = counters.durations.entry(state.as_str()).or_default();
+        if let Some(bucket) = TASK_DURATION_BUCKETS
+            .iter()
+            .position(|bound| seconds <= *bound)
+        {
+            histogram.buckets[bucket] += 1;
+        }
+        histogram.count += 1;
+        histogram.sum += seconds;
+    }
+
+    fn sse_subscriber_opened(&self) {
+        self.counters.lock().unwrap().sse_subscribers += 1;
+    }
+
+    fn sse_subscriber_closed(&self) {
+        let mut counters = self.counters.lock().unwrap();
+        counters.sse_subscribers = counters.sse_subscribers.saturating_sub(1);
+    }
+
+    fn task_evicted(&self, _task_id: &str, reason: &str) {
+        let mut counters = self.counters.lock().unwrap();
+        *counters.evictions.entry(reason.to_string()).or_default() += 1;
+    }
+}
+
+/// Router serving `recorder` at `GET /metrics` in the OpenMetrics format.
+pub fn metrics_router(recorder: Arc<OpenMetricsRecorder>) -> Router {
+    Router::new().route(
+        "/metrics",
+        get(move || {
+            let recorder = recorder.clone();
+            async move {
+                (
+                    [(header::CONTENT_TYPE, OPENMETRICS_CONTENT_TYPE)],
+                    recorder.render_openmetrics(),
+                )
+            }
+        }),
+    )
+}
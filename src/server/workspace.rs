@@ -0,0 +1,290 @@
+//! Per-task scratch directories for executors.
+//!
+//! [`DefaultRequestHandler`] gives every task a [`Workspace`], reachable
+//! from executors through [`RequestContext::workspace()`]. The directory is
+//! created on first use under the handler's workspace root (the system temp
+//! directory unless set with
+//! [`DefaultRequestHandler::with_workspace_root`]), survives across turns
+//! of the same task (e.g. `input-required` follow-ups), and is deleted once
+//! the task reaches a terminal state, its `input-required` wait expires, or
+//! the executor panics.
+//!
+//! ```rust,ignore
+//! async fn execute(&self, ctx: RequestContext, queue: EventQueue) -> A2AResult<()> {
+//!     let workspace = ctx.workspace().expect("set by DefaultRequestHandler");
+//!     for path in workspace.materialize_message(ctx.message.as_ref().unwrap())? {
+//!         // process the uploaded file at `path` ...
+//!     }
+//!     let report = workspace.file_path("report.txt")?;
+//!     // ...
+//! }
+//! ```
+//!
+//! Filesystem access is synchronous; keep files small or move heavy I/O to
+//! `tokio::task::spawn_blocking`.
+//!
+//! [`DefaultRequestHandler`]: super::DefaultRequestHandler
+//! [`DefaultRequestHandler::with_workspace_root`]: super::DefaultRequestHandler::with_workspace_root
+//! [`RequestContext::workspace()`]: super::RequestContext::workspace
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use tracing::{debug, warn};
+
+use crate::error::{A2AError, A2AResult};
+use crate::types::{FileContent, Message, Part};
+
+/// Isolated scratch directory of one task.
+///
+/// Cloning is cheap; all clones refer to the same directory. It is removed
+/// by [`cleanup()`](Self::cleanup), or when the last clone is dropped.
+#[derive(Clone)]
+pub struct Workspace {
+    inner: Arc<WorkspaceDir>,
+}
+
+struct WorkspaceDir {
+    path: PathBuf,
+    /// Guards creation so concurrent first uses don't race.
+    created: Mutex<bool>,
+}
+
+impl Drop for WorkspaceDir {
+    fn drop(&mut self) {
+        remove_dir(&self.path);
+    }
+}
+
+impl std::fmt::Debug for Workspace {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Workspace")
+            .field("path", &self.inner.path)
+            .finish()
+    }
+}
+
+impl Workspace {
+    /// A workspace in a new uniquely named directory under `root`.
+    ///
+    /// Nothing is created on disk until the workspace is first used.
+    pub fn new(root: impl AsRef<Path>) -> Self {
+        let path = root
+            .as_ref()
+            .join(format!("a2a-workspace-{}", uuid::Uuid::new_v4()));
+        Self {
+            inner: Arc::new(WorkspaceDir {
+                path,
+                created: Mutex::new(false),
+            }),
+        }
+    }
+
+    /// The workspace directory. It may not exist yet; use
+    /// [`dir()`](Self::dir) to create it.
+    pub fn path(&self) -> &Path {
+        &self.inner.path
+    }
+
+    /// The workspace directory, created if necessary.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`A2AError::InternalError`] if the directory can't be created.
+    pub fn dir(&self) -> A2AResult<&Path> {
+        let mut created = self.inner.created.lock().unwrap();
+        if !*created {
+            fs::create_dir_all(&self.inner.path).map_err(|e| {
+                A2AError::internal_error(format!(
+                    "Failed to create workspace {}: {e}",
+                    self.inner.path.display()
+                ))
+            })?;
+            *created = true;
+        }
+        Ok(&self.inner.path)
+    }
+
+    /// Path for a file called `name` inside the workspace, creating the
+    /// workspace if necessary.
+    ///
+    /// Only the final component of `name` is used, so names from clients
+    /// (`../../etc/passwd`, `C:\x`) can't escape the workspace.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`A2AError::InvalidParams`] if `name` has no usable file name,
+    /// or [`A2AError::InternalError`] if the workspace can't be created.
+    pub fn file_path(&self, name: &str) -> A2AResult<PathBuf> {
+        let file_name = name
+            .rsplit(['/', '\\'])
+            .next()
+            .filter(|n| !n.is_empty() && *n != "." && *n != "..")
+            .ok_or_else(|| A2AError::InvalidParams {
+                message: format!("Invalid workspace file name '{name}'"),
+                data: None,
+            })?;
+        Ok(self.dir()?.join(file_name))
+    }
+
+    /// Write the content of a file part with inline bytes into the
+    /// workspace and return its path.
+    ///
+    /// The file keeps the part's name; unnamed parts get a generated one.
+    /// Returns `None` for text, data and URI file parts, which carry no
+    /// bytes to write.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`A2AError::InvalidParams`] if the bytes aren't valid base64,
+    /// or [`A2AError::InternalError`] if writing fails.
+    pub fn materialize(&self, part: &Part) -> A2AResult<Option<PathBuf>> {
+        let Part::File {
+            file: FileContent::Bytes(file),
+            ..
+        } = part
+        else {
+            return Ok(None);
+        };
+        let bytes = file.decoded_bytes()?;
+        let name = match &file.name {
+            Some(name) => name.clone(),
+            None => format!("file-{}", uuid::Uuid::new_v4()),
+        };
+        let path = self.unused_path(&name)?;
+        fs::write(&path, bytes).map_err(|e| {
+            A2AError::internal_error(format!("Failed to write {}: {e}", path.display()))
+        })?;
+        Ok(Some(path))
+    }
+
+    /// [`materialize()`](Self::materialize) every file part of `message`, in
+    /// order. Files with the same name are written side by side rather than
+    /// overwritten.
+    pub fn materialize_message(&self, message: &Message) -> A2AResult<Vec<PathBuf>> {
+        let mut paths = Vec::new();
+        for part in &message.parts {
+            paths.extend(self.materialize(part)?);
+        }
+        Ok(paths)
+    }
+
+    /// Delete the workspace directory and everything in it.
+    ///
+    /// The workspace stays usable; the next use creates it again.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`A2AError::InternalError`] if the directory exists but
+    /// can't be removed.
+    pub fn cleanup(&self) -> A2AResult<()> {
+        let mut created = self.inner.created.lock().unwrap();
+        *created = false;
+        match fs::remove_dir_all(&self.inner.path) {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(A2AError::internal_error(format!(
+                "Failed to remove workspace {}: {e}",
+                self.inner.path.display()
+            ))),
+        }
+    }
+
+    /// [`file_path()`](Self::file_path) for `name`, suffixed (`report-1.txt`)
+    /// if that file already exists.
+    fn unused_path(&self, name: &str) -> A2AResult<PathBuf> {
+        let path = self.file_path(name)?;
+        if !path.exists() {
+            return Ok(path);
+        }
+        let stem = path
+            .file_stem()
+            .map(|s| s.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let extension = path
+            .extension()
+            .map(|e| format!(".{}", e.to_string_lossy()))
+            .unwrap_or_default();
+        (1..)
+            .map(|i| path.with_file_name(format!("{stem}-{i}{extension}")))
+            .find(|candidate| !candidate.exists())
+            .ok_or_else(|| A2AError::internal_error("No free workspace file name"))
+    }
+}
+
+/// Remove `path` recursively, logging failures other than "not found".
+fn remove_dir(path: &Path) {
+    match fs::remove_dir_all(path) {
+        Ok(()) => debug!(path = %path.display(), "Removed task workspace"),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+        Err(e) => warn!(path = %path.display(), error = %e, "Failed to remove task workspace"),
+    }
+}
+
+/// Workspaces of the tasks a handler is working on.
+pub(crate) struct Workspaces {
+    root: PathBuf,
+    active: Mutex<HashMap<String, Workspace>>,
+}
+
+impl Workspaces {
+    pub(crate) fn new(root: PathBuf) -> Self {
+        Self {
+            root,
+            active: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// The workspace of `task_id`, provisioned on first request.
+    pub(crate) fn acquire(&self, task_id: &str) -> Workspace {
+        self.active
+            .lock()
+            .unwrap()
+            .entry(task_id.to_string())
+            .or_insert_with(|| Workspace::new(&self.root))
+            .clone()
+    }
+
+    /// Forget the workspace of `task_id` and delete its directory.
+    pub(crate) fn release(&self, task_id: &str) {
+        let released = self.active.lock().unwrap().remove(task_id);
+        if let Some(workspace) = released {
+            if let Err(e) = workspace.cleanup() {
+                warn!(task_id, error = %e, "Failed to release task workspace");
+            }
+        }
+    }
+}
+
+/// Releases a task's workspace if the executor doesn't finish normally
+/// (panic or abort).
+pub(crate) struct ExecutionGuard {
+    workspaces: Arc<Workspaces>,
+    task_id: String,
+    finished: bool,
+}
+
+impl ExecutionGuard {
+    pub(crate) fn new(workspaces: Arc<Workspaces>, task_id: String) -> Self {
+        Self {
+            workspaces,
+            task_id,
+            finished: false,
+        }
+    }
+
+    /// The executor returned; leave the workspace to the task's lifecycle.
+    pub(crate) fn finish(&mut self) {
+        self.finished = true;
+    }
+}
+
+impl Drop for ExecutionGuard {
+    fn drop(&mut self) {
+        if !self.finished {
+            self.workspaces.release(&self.task_id);
+        }
+    }
+}
@@ -0,0 +1,187 @@
+//! Task store maintenance utilities.
+//!
+//! [`migrate()`] copies every task from one [`TaskStore`] backend to another,
+//! page by page, e.g. when graduating from [`InMemoryTaskStore`] to a
+//! database-backed store. Tasks carry their history and artifacts, so
+//! copying the task records moves the complete task state. Push
+//! notification configs are not part of [`TaskStore`] and are not copied.
+//!
+//! Migration is resumable: after each page the progress callback receives a
+//! [`MigrationProgress::checkpoint`]. Persist it, and pass it to
+//! [`MigrationOptions::resume_from`] to continue an interrupted run without
+//! re-copying finished pages.
+//!
+//! ```rust,ignore
+//! use a2a_rs::store::{migrate, MigrationOptions};
+//!
+//! let options = MigrationOptions::new()
+//!     .with_page_size(500)
+//!     .on_progress(|p| println!("{} tasks copied, checkpoint {:?}", p.copied, p.checkpoint));
+//! let report = migrate(&old_store, &new_store, options).await?;
+//! ```
+//!
+//! [`InMemoryTaskStore`]: crate::server::InMemoryTaskStore
+
+use std::sync::Arc;
+
+use tracing::{debug, info};
+
+use crate::error::{A2AError, A2AResult};
+use crate::server::{TaskListParams, TaskStore};
+
+/// Default number of tasks read from the source per page.
+pub const DEFAULT_MIGRATION_PAGE_SIZE: usize = 100;
+
+/// Progress callback for [`migrate()`].
+type ProgressFn = dyn Fn(&MigrationProgress) + Send + Sync;
+
+/// Options for [`migrate()`].
+#[derive(Clone)]
+pub struct MigrationOptions {
+    page_size: usize,
+    resume_from: Option<String>,
+    skip_existing: bool,
+    on_progress: Option<Arc<ProgressFn>>,
+}
+
+impl std::fmt::Debug for MigrationOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MigrationOptions")
+            .field("page_size", &self.page_size)
+            .field("resume_from", &self.resume_from)
+            .field("skip_existing", &self.skip_existing)
+            .field("on_progress", &self.on_progress.is_some())
+            .finish()
+    }
+}
+
+impl Default for MigrationOptions {
+    fn default() -> Self {
+        Self {
+            page_size: DEFAULT_MIGRATION_PAGE_SIZE,
+            resume_from: None,
+            skip_existing: false,
+            on_progress: None,
+        }
+    }
+}
+
+impl MigrationOptions {
+    /// Options with the defaults: pages of [`DEFAULT_MIGRATION_PAGE_SIZE`],
+    /// starting from the first task, overwriting tasks already in the
+    /// destination.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Read `page_size` tasks per page (at least 1).
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size.max(1);
+        self
+    }
+
+    /// Continue after a [`MigrationProgress::checkpoint`] of an earlier run.
+    pub fn resume_from(mut self, checkpoint: impl Into<String>) -> Self {
+        self.resume_from = Some(checkpoint.into());
+        self
+    }
+
+    /// Leave tasks that already exist in the destination untouched instead
+    /// of overwriting them, e.g. when the destination is already serving
+    /// traffic.
+    pub fn skip_existing(mut self, skip: bool) -> Self {
+        self.skip_existing = skip;
+        self
+    }
+
+    /// Call `callback` after every page.
+    pub fn on_progress(
+        mut self,
+        callback: impl Fn(&MigrationProgress) + Send + Sync + 'static,
+    ) -> Self {
+        self.on_progress = Some(Arc::new(callback));
+        self
+    }
+}
+
+/// Progress of a running migration, reported after each page.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MigrationProgress {
+    /// Tasks written to the destination so far.
+    pub copied: usize,
+    /// Tasks skipped because they already existed (with
+    /// [`MigrationOptions::skip_existing`]).
+    pub skipped: usize,
+    /// Pages processed so far.
+    pub pages: usize,
+    /// Resume point after the last finished page; `None` once the source is
+    /// exhausted.
+    pub checkpoint: Option<String>,
+}
+
+/// Outcome of a finished migration.
+pub type MigrationReport = MigrationProgress;
+
+/// Copy every task in `source` to `dest`.
+///
+/// Tasks are read with [`TaskStore::list`] in pages and written with
+/// [`TaskStore::save`]; the source is not modified. Counts in the returned
+/// report cover this run only, not pages copied before a resume.
+///
+/// # Errors
+///
+/// Returns the first error from listing the source or reading from or
+/// writing to the destination. Pages before the failing one are complete;
+/// resume from the last reported checkpoint.
+pub async fn migrate(
+    source: &dyn TaskStore,
+    dest: &dyn TaskStore,
+    options: MigrationOptions,
+) -> A2AResult<MigrationReport> {
+    let mut progress = MigrationProgress {
+        checkpoint: options.resume_from.clone(),
+        ..Default::default()
+    };
+    loop {
+        let page = source
+            .list(&TaskListParams {
+                page_size: Some(options.page_size),
+                page_token: progress.checkpoint.clone(),
+                ..Default::default()
+            })
+            .await?;
+        if page.next_page_token.is_some() && page.next_page_token == progress.checkpoint {
+            return Err(A2AError::internal_error(
+                "Source task store returned the same page token twice",
+            ));
+        }
+
+        for task in page.tasks {
+            if options.skip_existing && dest.get(&task.id).await?.is_some() {
+                progress.skipped += 1;
+                continue;
+            }
+            dest.save(task).await?;
+            progress.copied += 1;
+        }
+        progress.pages += 1;
+        progress.checkpoint = page.next_page_token;
+        debug!(
+            pages = progress.pages,
+            copied = progress.copied,
+            "Task migration page done"
+        );
+        if let Some(callback) = &options.on_progress {
+            callback(&progress);
+        }
+        if progress.checkpoint.is_none() {
+            break;
+        }
+    }
+    info!(
+        copied = progress.copied,
+        skipped = progress.skipped,
+        "Task migration finished"
+    );
+    Ok(progress)
+}
@@ -0,0 +1,503 @@
+//! Scripted mock agent for client tests (`testing` feature).
+//!
+//! [`MockAgentServer`] is a real HTTP server on a random local port that
+//! answers JSON-RPC calls from a script instead of running an executor:
+//!
+//! - [`on()`](MockAgentServer::on) sets the reply to a method,
+//!   [`on_next()`](MockAgentServer::on_next) queues one-shot replies that
+//!   are used first, in order
+//! - a [`MockReply`] is a result, a JSON-RPC error or a list of stream
+//!   events sent as SSE
+//! - every request is recorded for assertions
+//!   ([`requests()`](MockAgentServer::requests))
+//! - faults ([`Fault`]) and delays make replies misbehave, for testing how
+//!   clients cope with slow agents, malformed JSON and dropped streams
+//!
+//! The agent card is served at the well-known paths; unscripted methods get
+//! a `METHOD_NOT_FOUND` error. [`fixtures`] builds the tasks and events
+//! replies are usually made of.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use a2a_rs::client::A2AClient;
+//! use a2a_rs::testing::{fixtures, MockAgentServer, MockReply};
+//! use a2a_rs::types::TaskState;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let server = MockAgentServer::start().await?;
+//! let task = fixtures::task("t1", TaskState::Completed);
+//! server.on("message/send", MockReply::task(&task));
+//!
+//! let client = A2AClient::from_url(server.url()).await?;
+//! client.send_text("hi").await?;
+//! assert_eq!(server.requests_for("message/send").len(), 1);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::{HashMap, VecDeque};
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use axum::body::{Body, Bytes};
+use axum::extract::State;
+use axum::http::{header, HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Json, Response};
+use axum::routing::{get, post};
+use axum::Router;
+use serde_json::Value;
+
+use crate::builders::AgentCardBuilder;
+use crate::error::{A2AError, A2AResult};
+use crate::types::{
+    AgentCard, JsonRpcError, JsonRpcId, JsonRpcRequest, JsonRpcResponse, Message, StreamResponse,
+    Task,
+};
+use crate::utils::constants::{AGENT_CARD_WELL_KNOWN_PATH, PREV_AGENT_CARD_WELL_KNOWN_PATH};
+
+/// What a [`MockReply`] answers with.
+#[derive(Debug, Clone)]
+enum ReplyBody {
+    Result(Value),
+    Error(JsonRpcError),
+    Events(Vec<StreamResponse>),
+}
+
+/// A way for a [`MockReply`] to misbehave.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Fault {
+    /// The response body (for streams: the first event's data) is cut off
+    /// and isn't valid JSON.
+    MalformedJson,
+    /// The SSE connection is aborted after this many events, without a
+    /// final status update.
+    DropStream {
+        /// Events sent before the connection is dropped.
+        after: usize,
+    },
+    /// Respond with this HTTP status and an empty body.
+    Status(u16),
+}
+
+/// The scripted reply to one call. See the [module docs](self).
+#[derive(Debug, Clone)]
+pub struct MockReply {
+    body: ReplyBody,
+    delay: Option<Duration>,
+    event_delay: Option<Duration>,
+    fault: Option<Fault>,
+}
+
+impl MockReply {
+    fn new(body: ReplyBody) -> Self {
+        Self {
+            body,
+            delay: None,
+            event_delay: None,
+            fault: None,
+        }
+    }
+
+    /// Reply with `result` as the JSON-RPC result.
+    pub fn result(result: Value) -> Self {
+        Self::new(ReplyBody::Result(result))
+    }
+
+    /// Reply with `task` (e.g. to `message/send` or `tasks/get`).
+    pub fn task(task: &Task) -> Self {
+        Self::result(serde_json::to_value(task).unwrap_or_default())
+    }
+
+    /// Reply with a direct `message` (to `message/send`).
+    pub fn message(message: &Message) -> Self {
+        Self::result(serde_json::to_value(message).unwrap_or_default())
+    }
+
+    /// Reply with `err` as a JSON-RPC error, keeping its code and data.
+    pub fn error(err: A2AError) -> Self {
+        Self::new(ReplyBody::Error(err.into()))
+    }
+
+    /// Reply with an SSE stream of `events`, each in a JSON-RPC envelope
+    /// (e.g. to `message/stream` or `tasks/resubscribe`).
+    pub fn events(events: Vec<StreamResponse>) -> Self {
+        Self::new(ReplyBody::Events(events))
+    }
+
+    /// Wait `delay` before responding (builder-style).
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = Some(delay);
+        self
+    }
+
+    /// Wait `delay` before each stream event after the first
+    /// (builder-style).
+    pub fn with_event_delay(mut self, delay: Duration) -> Self {
+        self.event_delay = Some(delay);
+        self
+    }
+
+    /// Misbehave as `fault` describes (builder-style).
+    pub fn with_fault(mut self, fault: Fault) -> Self {
+        self.fault = Some(fault);
+        self
+    }
+}
+
+/// A request received by a [`MockAgentServer`].
+#[derive(Debug, Clone)]
+pub struct RecordedRequest {
+    /// JSON-RPC method.
+    pub method: String,
+    /// Request ID; `None` for notifications.
+    pub id: Option<JsonRpcId>,
+    /// Params, `null` if absent.
+    pub params: Value,
+    /// HTTP headers, names lowercased.
+    pub headers: HashMap<String, String>,
+}
+
+impl RecordedRequest {
+    /// Decode the params as `T`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidParams` if they don't match `T`.
+    pub fn params_as<T: serde::de::DeserializeOwned>(&self) -> A2AResult<T> {
+        serde_json::from_value(self.params.clone())
+            .map_err(|e| A2AError::invalid_params(format!("invalid {} params: {e}", self.method)))
+    }
+
+    /// The value of header `name` (case-insensitive).
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .get(&name.to_ascii_lowercase())
+            .map(String::as_str)
+    }
+}
+
+#[derive(Debug, Default)]
+struct MethodScript {
+    queued: VecDeque<MockReply>,
+    default: Option<MockReply>,
+}
+
+#[derive(Debug)]
+struct MockState {
+    card: Mutex<AgentCard>,
+    scripts: Mutex<HashMap<String, MethodScript>>,
+    requests: Mutex<Vec<RecordedRequest>>,
+}
+
+impl MockState {
+    fn reply(&self, method: &str) -> Option<MockReply> {
+        let mut scripts = self.scripts.lock().unwrap();
+        let script = scripts.get_mut(method)?;
+        script.queued.pop_front().or_else(|| script.default.clone())
+    }
+}
+
+/// A scripted A2A agent over HTTP. See the [module docs](self).
+///
+/// The server stops when dropped.
+#[derive(Debug)]
+pub struct MockAgentServer {
+    url: String,
+    state: Arc<MockState>,
+    task: tokio::task::JoinHandle<()>,
+}
+
+impl MockAgentServer {
+    /// Start a server on a random port of `127.0.0.1`, serving a card for
+    /// a streaming-capable agent.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`A2AError::Transport`] if the port can't be bound.
+    pub async fn start() -> A2AResult<Self> {
+        let listener = tokio::net::TcpListener::bind(SocketAddr::from(([127, 0, 0, 1], 0)))
+            .await
+            .map_err(|e| A2AError::Transport(format!("failed to bind mock server: {e}")))?;
+        let addr = listener
+            .local_addr()
+            .map_err(|e| A2AError::Transport(e.to_string()))?;
+        let url = format!("http://{addr}");
+        let card = AgentCardBuilder::new("Mock Agent", "Scripted A2A agent for tests", "0.0.0")
+            .with_jsonrpc_interface(format!("{url}/a2a"))
+            .with_streaming(true)
+            .build();
+        let state = Arc::new(MockState {
+            card: Mutex::new(card),
+            scripts: Mutex::default(),
+            requests: Mutex::default(),
+        });
+        let app = Router::new()
+            .route(AGENT_CARD_WELL_KNOWN_PATH, get(handle_card))
+            .route(PREV_AGENT_CARD_WELL_KNOWN_PATH, get(handle_card))
+            .route("/a2a", post(handle_rpc))
+            .with_state(state.clone());
+        let task = tokio::spawn(async move {
+            if let Err(e) = axum::serve(listener, app).await {
+                tracing::warn!(error = %e, "Mock agent server stopped");
+            }
+        });
+        Ok(Self { url, state, task })
+    }
+
+    /// Base URL, e.g. `http://127.0.0.1:41234`, for
+    /// [`A2AClient::from_url`](crate::client::A2AClient::from_url).
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// The JSON-RPC endpoint (`{url}/a2a`).
+    pub fn endpoint(&self) -> String {
+        format!("{}/a2a", self.url)
+    }
+
+    /// Serve `card` instead of the default one.
+    pub fn set_agent_card(&self, card: AgentCard) {
+        *self.state.card.lock().unwrap() = card;
+    }
+
+    /// Answer every call to `method` with `reply` once the queued replies
+    /// are used up.
+    pub fn on(&self, method: &str, reply: MockReply) {
+        let mut scripts = self.state.scripts.lock().unwrap();
+        scripts.entry(method.to_string()).or_default().default = Some(reply);
+    }
+
+    /// Queue `reply` for a single call to `method`. Queued replies are used
+    /// in order, before the one set with [`on()`](Self::on).
+    pub fn on_next(&self, method: &str, reply: MockReply) {
+        let mut scripts = self.state.scripts.lock().unwrap();
+        scripts
+            .entry(method.to_string())
+            .or_default()
+            .queued
+            .push_back(reply);
+    }
+
+    /// Every request received so far, in order.
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.state.requests.lock().unwrap().clone()
+    }
+
+    /// The requests received so far for `method`, in order.
+    pub fn requests_for(&self, method: &str) -> Vec<RecordedRequest> {
+        self.state
+            .requests
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|request| request.method == method)
+            .cloned()
+            .collect()
+    }
+
+    /// Forget the requests received so far.
+    pub fn clear_requests(&self) {
+        self.state.requests.lock().unwrap().clear();
+    }
+}
+
+impl Drop for MockAgentServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+async fn handle_card(State(state): State<Arc<MockState>>) -> Response {
+    let card = state.card.lock().unwrap().clone();
+    Json(card).into_response()
+}
+
+async fn handle_rpc(
+    State(state): State<Arc<MockState>>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Response {
+    let request: JsonRpcRequest = match serde_json::from_slice(&body) {
+        Ok(request) => request,
+        Err(e) => {
+            let err = A2AError::parse_error(format!("Parse error: {e}"));
+            return Json(JsonRpcResponse::from_a2a_error(None, err)).into_response();
+        }
+    };
+    state.requests.lock().unwrap().push(RecordedRequest {
+        method: request.method.clone(),
+        id: request.id.clone(),
+        params: request.params.clone().unwrap_or(Value::Null),
+        headers: headers
+            .iter()
+            .filter_map(|(name, value)| {
+                Some((name.as_str().to_string(), value.to_str().ok()?.to_string()))
+            })
+            .collect(),
+    });
+
+    let Some(reply) = state.reply(&request.method) else {
+        if request.id.is_none() {
+            return StatusCode::NO_CONTENT.into_response();
+        }
+        let err =
+            A2AError::method_not_found(format!("No mock reply scripted for {}", request.method));
+        return Json(JsonRpcResponse::from_a2a_error(request.id, err)).into_response();
+    };
+
+    if let Some(delay) = reply.delay {
+        tokio::time::sleep(delay).await;
+    }
+    if let Some(Fault::Status(status)) = reply.fault {
+        return StatusCode::from_u16(status)
+            .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
+            .into_response();
+    }
+    let malformed = reply.fault == Some(Fault::MalformedJson);
+    let response = match reply.body {
+        ReplyBody::Result(result) => JsonRpcResponse::success(request.id, result),
+        ReplyBody::Error(error) => JsonRpcResponse::error(request.id, error),
+        ReplyBody::Events(events) => {
+            let drop_after = match reply.fault {
+                Some(Fault::DropStream { after }) => Some(after),
+                _ => None,
+            };
+            return sse_response(request.id, events, reply.event_delay, malformed, drop_after);
+        }
+    };
+    let mut body = serde_json::to_string(&response).unwrap_or_default();
+    if malformed {
+        body.truncate(body.len() / 2);
+    }
+    ([(header::CONTENT_TYPE, "application/json")], body).into_response()
+}
+
+/// An SSE response sending `events` in JSON-RPC envelopes.
+fn sse_response(
+    id: Option<JsonRpcId>,
+    events: Vec<StreamResponse>,
+    event_delay: Option<Duration>,
+    malformed: bool,
+    drop_after: Option<usize>,
+) -> Response {
+    let sent = drop_after.map_or(events.len(), |after| after.min(events.len()));
+    let stream = async_stream::stream! {
+        for (i, event) in events.into_iter().take(sent).enumerate() {
+            if i > 0 {
+                if let Some(delay) = event_delay {
+                    tokio::time::sleep(delay).await;
+                }
+            }
+            let result = serde_json::to_value(&event).unwrap_or_default();
+            let mut data =
+                serde_json::to_string(&JsonRpcResponse::success(id.clone(), result))
+                    .unwrap_or_default();
+            if malformed && i == 0 {
+                data.truncate(data.len() / 2);
+            }
+            yield Ok(Bytes::from(format!("event: {}\ndata: {data}\n\n", event.kind())));
+        }
+        if drop_after.is_some() {
+            // Let the events written so far reach the client first.
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            yield Err(std::io::Error::new(
+                std::io::ErrorKind::ConnectionAborted,
+                "mock stream dropped",
+            ));
+        }
+    };
+    (
+        [
+            (header::CONTENT_TYPE, "text/event-stream"),
+            (header::CACHE_CONTROL, "no-cache"),
+        ],
+        Body::from_stream(stream),
+    )
+        .into_response()
+}
+
+/// Tasks and stream events to script [`MockReply`]s with.
+pub mod fixtures {
+    use crate::types::{
+        Artifact, Part, StreamResponse, Task, TaskArtifactUpdateEvent, TaskState, TaskStatus,
+        TaskStatusUpdateEvent,
+    };
+
+    /// A task `id` in `state`, in context `{id}-context`, without history
+    /// or artifacts.
+    pub fn task(id: &str, state: TaskState) -> Task {
+        Task {
+            id: id.to_string(),
+            context_id: format!("{id}-context"),
+            kind: "task".to_string(),
+            status: TaskStatus::new(state),
+            artifacts: None,
+            history: None,
+            metadata: None,
+        }
+    }
+
+    /// A status update moving `task` to `state`; `final` for terminal
+    /// states.
+    pub fn status_update(task: &Task, state: TaskState) -> StreamResponse {
+        StreamResponse::StatusUpdate(TaskStatusUpdateEvent {
+            task_id: task.id.clone(),
+            context_id: task.context_id.clone(),
+            kind: "status-update".to_string(),
+            status: TaskStatus::new(state),
+            r#final: state.is_terminal(),
+            metadata: None,
+        })
+    }
+
+    /// A text chunk of artifact `artifact_id` of `task`.
+    pub fn artifact_update(
+        task: &Task,
+        artifact_id: &str,
+        text: &str,
+        append: bool,
+        last_chunk: bool,
+    ) -> StreamResponse {
+        StreamResponse::ArtifactUpdate(TaskArtifactUpdateEvent {
+            task_id: task.id.clone(),
+            context_id: task.context_id.clone(),
+            kind: "artifact-update".to_string(),
+            artifact: Artifact {
+                artifact_id: artifact_id.to_string(),
+                name: None,
+                description: None,
+                parts: vec![Part::text(text)],
+                metadata: None,
+                extensions: None,
+            },
+            append: Some(append),
+            last_chunk: Some(last_chunk),
+            metadata: None,
+        })
+    }
+
+    /// The events of a task that starts working, streams `chunks` as one
+    /// artifact and completes: the task, `working`, one artifact update
+    /// per chunk, `completed`.
+    pub fn completed_run(task_id: &str, chunks: &[&str]) -> Vec<StreamResponse> {
+        let task = task(task_id, TaskState::Submitted);
+        let mut events = vec![
+            StreamResponse::Task(task.clone()),
+            status_update(&task, TaskState::Working),
+        ];
+        for (i, chunk) in chunks.iter().enumerate() {
+            events.push(artifact_update(
+                &task,
+                "result",
+                chunk,
+                i > 0,
+                i + 1 == chunks.len(),
+            ));
+        }
+        events.push(status_update(&task, TaskState::Completed));
+        events
+    }
+}
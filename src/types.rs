@@ -6,6 +6,7 @@
 //! This module implements ALL message types from the proto spec with correct
 //! JSON-RPC serialization (matching the Python SDK wire format).
 
+use base64::Engine;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt;
@@ -20,7 +21,14 @@ use std::fmt;
 /// official A2A proto specification (`enum TaskState`).
 ///
 /// Proto ref: `enum TaskState`
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+///
+/// Accepts both kebab-case ("input-required") and protobuf-style
+/// ("TASK_STATE_INPUT_REQUIRED") on deserialize. Any other string maps to
+/// [`TaskState::Unknown`] instead of failing, so peers on newer protocol
+/// versions can't break parsing; [`TaskStatus::unrecognized_state`]
+/// returns the original value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "kebab-case")]
 pub enum TaskState {
     /// Task has been received but not yet started.
@@ -43,9 +51,10 @@ pub enum TaskState {
     Unknown,
 }
 
-impl fmt::Display for TaskState {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let s = match self {
+impl TaskState {
+    /// The kebab-case wire name of this state.
+    pub fn as_str(&self) -> &'static str {
+        match self {
             TaskState::Submitted => "submitted",
             TaskState::Working => "working",
             TaskState::Completed => "completed",
@@ -55,8 +64,81 @@ impl fmt::Display for TaskState {
             TaskState::Rejected => "rejected",
             TaskState::AuthRequired => "auth-required",
             TaskState::Unknown => "unknown",
-        };
-        write!(f, "{}", s)
+        }
+    }
+
+    /// Whether the task is over: `completed`, `failed`, `canceled` or
+    /// `rejected`.
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            TaskState::Completed | TaskState::Failed | TaskState::Canceled | TaskState::Rejected
+        )
+    }
+
+    /// Whether a task may move from this state to `next`.
+    ///
+    /// | From | Allowed next states |
+    /// |------|---------------------|
+    /// | `submitted` | any |
+    /// | `working`, `input-required`, `auth-required` | any but `submitted` |
+    /// | `completed`, `failed`, `canceled`, `rejected` | none |
+    ///
+    /// `unknown` on either side is allowed, so states from newer protocol
+    /// versions don't wedge a task.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use a2a_rs::types::TaskState;
+    ///
+    /// assert!(TaskState::Working.can_transition_to(TaskState::InputRequired));
+    /// assert!(TaskState::InputRequired.can_transition_to(TaskState::Working));
+    /// assert!(!TaskState::Working.can_transition_to(TaskState::Submitted));
+    /// assert!(!TaskState::Completed.can_transition_to(TaskState::Working));
+    /// ```
+    pub fn can_transition_to(&self, next: TaskState) -> bool {
+        match (self, next) {
+            (TaskState::Unknown, _) | (_, TaskState::Unknown) => true,
+            (from, _) if from.is_terminal() => false,
+            (TaskState::Submitted, _) => true,
+            (_, TaskState::Submitted) => false,
+            _ => true,
+        }
+    }
+
+    /// Parse a wire state name, returning `None` for unrecognised values.
+    pub fn from_wire(s: &str) -> Option<Self> {
+        match s {
+            "submitted" | "TASK_STATE_SUBMITTED" => Some(TaskState::Submitted),
+            "working" | "TASK_STATE_WORKING" => Some(TaskState::Working),
+            "completed" | "TASK_STATE_COMPLETED" => Some(TaskState::Completed),
+            "failed" | "TASK_STATE_FAILED" => Some(TaskState::Failed),
+            "canceled" | "TASK_STATE_CANCELLED" | "TASK_STATE_CANCELED" => {
+                Some(TaskState::Canceled)
+            }
+            "input-required" | "TASK_STATE_INPUT_REQUIRED" => Some(TaskState::InputRequired),
+            "rejected" | "TASK_STATE_REJECTED" => Some(TaskState::Rejected),
+            "auth-required" | "TASK_STATE_AUTH_REQUIRED" => Some(TaskState::AuthRequired),
+            "unknown" | "TASK_STATE_UNSPECIFIED" => Some(TaskState::Unknown),
+            _ => None,
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for TaskState {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(TaskState::from_wire(&s).unwrap_or(TaskState::Unknown))
+    }
+}
+
+impl fmt::Display for TaskState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
     }
 }
 
@@ -70,6 +152,7 @@ impl fmt::Display for TaskState {
 /// Accepts both lowercase ("user") and protobuf-style ("ROLE_USER") on deserialize.
 /// Serializes as lowercase for JSON compat.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum Role {
     /// Message from the user / client.
@@ -109,6 +192,7 @@ impl fmt::Display for Role {
 ///
 /// Python SDK ref: `In` enum
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum ApiKeyLocation {
     /// API key sent as a cookie.
@@ -126,25 +210,83 @@ pub enum ApiKeyLocation {
 /// Current status of a task.
 ///
 /// Proto ref: `message TaskStatus`
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "schemars",
+    derive(schemars::JsonSchema),
+    schemars(rename_all = "camelCase")
+)]
 pub struct TaskStatus {
     /// The current state.
     pub state: TaskState,
 
     /// Optional message associated with this status.
-    #[serde(skip_serializing_if = "Option::is_none")]
     pub message: Option<Message>,
 
     /// ISO-8601 timestamp of when this status was set.
-    #[serde(skip_serializing_if = "Option::is_none")]
     pub timestamp: Option<String>,
+
+    /// The state string as received, when it wasn't a recognised
+    /// [`TaskState`] (in which case `state` is [`TaskState::Unknown`]).
+    ///
+    /// Not a wire field: serialization writes it back as `state`, so
+    /// unrecognised states survive a round trip through this SDK. Read it
+    /// with [`TaskStatus::unrecognized_state`]; build statuses with
+    /// [`TaskStatus::new`].
+    #[doc(hidden)]
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub raw_state: Option<String>,
+}
+
+/// Wire form of [`TaskStatus`], keeping `state` as the raw string.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TaskStatusWire<S, M> {
+    state: S,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message: Option<M>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    timestamp: Option<S>,
+}
+
+impl Serialize for TaskStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        TaskStatusWire {
+            state: self.state_name(),
+            message: self.message.as_ref(),
+            timestamp: self.timestamp.as_deref(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for TaskStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let wire = TaskStatusWire::<String, Message>::deserialize(deserializer)?;
+        let (state, raw_state) = match TaskState::from_wire(&wire.state) {
+            Some(state) => (state, None),
+            None => (TaskState::Unknown, Some(wire.state)),
+        };
+        Ok(TaskStatus {
+            state,
+            message: wire.message,
+            timestamp: wire.timestamp,
+            raw_state,
+        })
+    }
 }
 
 /// A task — the primary unit of work in the A2A protocol.
 ///
 /// Python SDK ref: `Task` (has `kind: Literal['task'] = 'task'`)
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct Task {
     /// Unique task identifier.
@@ -184,6 +326,7 @@ pub type A2ATask = Task;
 ///
 /// Python SDK ref: `Message` (has `kind: Literal['message'] = 'message'`)
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct Message {
     /// Unique message identifier.
@@ -222,11 +365,18 @@ pub struct Message {
 
 /// File content provided as base64-encoded bytes.
 ///
+/// Build from raw bytes with [`FileWithBytes::from_bytes`] and read them back
+/// with [`FileWithBytes::decoded_bytes`]; `bytes` holds the wire encoding.
+/// Deserializing rejects content that isn't standard base64 unless the
+/// `lenient-base64` feature is enabled.
+///
 /// Python SDK ref: `FileWithBytes`
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct FileWithBytes {
     /// Base64-encoded file content.
+    #[serde(deserialize_with = "deserialize_base64")]
     pub bytes: String,
     /// MIME type of the file.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -240,6 +390,7 @@ pub struct FileWithBytes {
 ///
 /// Python SDK ref: `FileWithUri`
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct FileWithUri {
     /// URI pointing to the file content.
@@ -254,8 +405,12 @@ pub struct FileWithUri {
 
 /// File content — either inline bytes or a URI reference.
 ///
+/// Objects with a `bytes` key deserialize as [`FileContent::Bytes`], all
+/// others as [`FileContent::Uri`].
+///
 /// Python SDK ref: `FileWithBytes | FileWithUri` (union in FilePart.file)
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(untagged)]
 pub enum FileContent {
     /// File with inline base64-encoded bytes.
@@ -264,6 +419,59 @@ pub enum FileContent {
     Uri(FileWithUri),
 }
 
+impl<'de> serde::Deserialize<'de> for FileContent {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        // Dispatch on `bytes` rather than trying each variant, so invalid
+        // base64 reports its own error instead of "no variant matched".
+        let value = serde_json::Value::deserialize(deserializer)?;
+        if value.get("bytes").is_some() {
+            serde_json::from_value(value)
+                .map(FileContent::Bytes)
+                .map_err(serde::de::Error::custom)
+        } else {
+            serde_json::from_value(value)
+                .map(FileContent::Uri)
+                .map_err(serde::de::Error::custom)
+        }
+    }
+}
+
+/// Deserialize `FileWithBytes::bytes`, checking it is valid base64.
+fn deserialize_base64<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let bytes = String::deserialize(deserializer)?;
+    #[cfg(not(feature = "lenient-base64"))]
+    validate_base64(&bytes).map_err(serde::de::Error::custom)?;
+    Ok(bytes)
+}
+
+/// Check that `encoded` is standard, padded base64 without decoding it
+/// into a buffer.
+#[cfg_attr(feature = "lenient-base64", allow(dead_code))]
+fn validate_base64(encoded: &str) -> Result<(), String> {
+    let bytes = encoded.as_bytes();
+    let invalid = |reason: &str| Err(format!("file bytes are not valid base64: {reason}"));
+    if bytes.len() % 4 != 0 {
+        return invalid("length is not a multiple of 4");
+    }
+    let padding = bytes.iter().rev().take_while(|&&b| b == b'=').count();
+    if padding > 2 {
+        return invalid("too much padding");
+    }
+    match bytes[..bytes.len() - padding]
+        .iter()
+        .position(|&b| !(b.is_ascii_alphanumeric() || b == b'+' || b == b'/'))
+    {
+        Some(offset) => invalid(&format!("invalid character at offset {offset}")),
+        None => Ok(()),
+    }
+}
+
 /// A content part within a message or artifact.
 ///
 /// Discriminated by the `kind` field, matching the Python SDK's
@@ -281,6 +489,7 @@ pub enum FileContent {
 /// (`{"text": "..."}`) formats for spec/SDK/protobuf compat.
 /// Serializes with `kind` tag for Python SDK compat.
 #[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(tag = "kind")]
 pub enum Part {
     /// A text content part. Discriminator: `"text"`.
@@ -322,14 +531,12 @@ impl<'de> serde::Deserialize<'de> for Part {
             .as_object()
             .ok_or_else(|| serde::de::Error::custom("Part must be an object"))?;
 
-        // Determine variant: check "kind" tag first, then infer from fields
-        let kind = obj
-            .get("kind")
-            .and_then(|v| v.as_str())
-            .map(String::from);
+        // Determine variant from the required "kind" tag (Python SDK rejects
+        // parts without a discriminator).
+        let kind = obj.get("kind").and_then(|v| v.as_str()).map(String::from);
 
         match kind.as_deref() {
-            Some("text") | None if obj.contains_key("text") => {
+            Some("text") if obj.contains_key("text") => {
                 let text = obj
                     .get("text")
                     .and_then(|v| v.as_str())
@@ -338,7 +545,7 @@ impl<'de> serde::Deserialize<'de> for Part {
                 let metadata = obj.get("metadata").cloned();
                 Ok(Part::Text { text, metadata })
             }
-            Some("file") | None if obj.contains_key("file") => {
+            Some("file") if obj.contains_key("file") => {
                 let file: FileContent = serde_json::from_value(
                     obj.get("file").cloned().unwrap_or(serde_json::Value::Null),
                 )
@@ -346,11 +553,8 @@ impl<'de> serde::Deserialize<'de> for Part {
                 let metadata = obj.get("metadata").cloned();
                 Ok(Part::File { file, metadata })
             }
-            Some("data") | None if obj.contains_key("data") => {
-                let data = obj
-                    .get("data")
-                    .cloned()
-                    .unwrap_or(serde_json::Value::Null);
+            Some("data") if obj.contains_key("data") => {
+                let data = obj.get("data").cloned().unwrap_or(serde_json::Value::Null);
                 let metadata = obj.get("metadata").cloned();
                 Ok(Part::Data { data, metadata })
             }
@@ -358,9 +562,7 @@ impl<'de> serde::Deserialize<'de> for Part {
                 "unknown Part kind: {}",
                 other
             ))),
-            None => Err(serde::de::Error::custom(
-                "Part must have 'kind' field or a recognized content field (text, file, data)",
-            )),
+            None => Err(serde::de::Error::custom("Part must have a 'kind' field")),
         }
     }
 }
@@ -391,6 +593,7 @@ pub type DataPart = ();
 ///
 /// Proto ref: `message Artifact`
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct Artifact {
     /// Unique artifact identifier.
@@ -424,6 +627,7 @@ pub struct Artifact {
 ///
 /// Python SDK ref: `TaskStatusUpdateEvent` (has `kind: Literal['status-update'] = 'status-update'`)
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct TaskStatusUpdateEvent {
     /// ID of the task whose status changed.
@@ -468,6 +672,7 @@ pub struct TaskStatusUpdateEvent {
 ///
 /// Python SDK ref: `TaskArtifactUpdateEvent` (has `kind: Literal['artifact-update'] = 'artifact-update'`)
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct TaskArtifactUpdateEvent {
     /// ID of the task that produced the artifact.
@@ -517,7 +722,7 @@ fn default_preferred_transport() -> Option<String> {
 }
 
 fn default_protocol_version() -> Option<String> {
-    Some("0.3.0".to_string())
+    Some(crate::utils::PROTOCOL_VERSION.to_string())
 }
 
 /// A streaming response payload.
@@ -533,6 +738,7 @@ fn default_protocol_version() -> Option<String> {
 ///
 /// Serializes FLAT (no wrapper keys) — the `kind` field is the discriminator.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema), schemars(untagged))]
 pub enum StreamResponse {
     /// A complete task snapshot.
     Task(Task),
@@ -547,6 +753,38 @@ pub enum StreamResponse {
     ArtifactUpdate(TaskArtifactUpdateEvent),
 }
 
+impl StreamResponse {
+    /// The task status carried by this event, for task snapshots and
+    /// status updates.
+    pub fn task_status(&self) -> Option<&TaskStatus> {
+        match self {
+            StreamResponse::Task(task) => Some(&task.status),
+            StreamResponse::StatusUpdate(update) => Some(&update.status),
+            StreamResponse::Message(_) | StreamResponse::ArtifactUpdate(_) => None,
+        }
+    }
+
+    /// The `kind` discriminator of this event on the wire.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            StreamResponse::Task(_) => "task",
+            StreamResponse::Message(_) => "message",
+            StreamResponse::StatusUpdate(_) => "status-update",
+            StreamResponse::ArtifactUpdate(_) => "artifact-update",
+        }
+    }
+
+    /// The ID of the task this event belongs to, if any.
+    pub fn task_id(&self) -> Option<&str> {
+        match self {
+            StreamResponse::Task(task) => Some(&task.id),
+            StreamResponse::Message(message) => message.task_id.as_deref(),
+            StreamResponse::StatusUpdate(update) => Some(&update.task_id),
+            StreamResponse::ArtifactUpdate(update) => Some(&update.task_id),
+        }
+    }
+}
+
 impl Serialize for StreamResponse {
     fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         match self {
@@ -603,6 +841,7 @@ impl<'de> Deserialize<'de> for StreamResponse {
 ///
 /// Proto ref: `message AgentCard`
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct AgentCard {
     /// Human-readable name.
@@ -687,9 +926,12 @@ pub struct AgentCard {
 
 /// A transport interface supported by an agent.
 ///
-/// Spec v0.3.0 uses `protocolBinding`; Python SDK uses `transport`.
-/// We serialize as `protocolBinding` (spec-compliant) and accept both on deserialize.
+/// A2A spec v0.3.0 (`AgentInterface.transport` in `specification/json/a2a.json`)
+/// and the Python SDK name the binding `transport`; later proto-based drafts
+/// call it `protocolBinding`. We serialize as `transport` and accept both on
+/// deserialize.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct AgentInterface {
     /// URL of the interface endpoint.
@@ -697,10 +939,10 @@ pub struct AgentInterface {
 
     /// Transport protocol (e.g. "JSONRPC", "HTTP+JSON", "GRPC").
     ///
-    /// Serialized as `"protocolBinding"` (spec v0.3.0).
-    /// Accepts `"transport"` on deserialize for Python SDK compat.
-    #[serde(alias = "transport")]
-    pub protocol_binding: String,
+    /// Serialized as `"transport"` (spec v0.3.0, Python SDK).
+    /// Accepts `"protocolBinding"` on deserialize for proto-based peers.
+    #[serde(alias = "protocolBinding")]
+    pub transport: String,
 
     /// Optional tenant identifier.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -715,6 +957,7 @@ pub struct AgentInterface {
 ///
 /// Python SDK ref: `AgentCapabilities`
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 #[derive(Default)]
 pub struct AgentCapabilities {
@@ -741,6 +984,7 @@ pub struct AgentCapabilities {
 ///
 /// Proto ref: `message AgentExtension`
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct AgentExtension {
     /// URI identifying the extension.
@@ -763,6 +1007,7 @@ pub struct AgentExtension {
 ///
 /// Proto ref: `message AgentSkill`
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct AgentSkill {
     /// Unique skill identifier.
@@ -802,6 +1047,7 @@ pub struct AgentSkill {
 ///
 /// Proto ref: `message AgentProvider`
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct AgentProvider {
     /// Organization name.
@@ -815,6 +1061,7 @@ pub struct AgentProvider {
 ///
 /// Proto ref: `message AgentCardSignature`
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct AgentCardSignature {
     /// Base64url-encoded JWS protected header.
@@ -839,7 +1086,9 @@ pub struct AgentCardSignature {
 ///
 /// JSON: `{"type": "apiKey", "in": "header", "name": "X-API-Key"}`
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(tag = "type")]
+#[allow(clippy::large_enum_variant)]
 pub enum SecurityScheme {
     /// API key authentication.
     #[serde(rename = "apiKey")]
@@ -897,6 +1146,7 @@ pub enum SecurityScheme {
 /// Python SDK ref: `OAuthFlows` — has authorization_code, client_credentials,
 /// implicit, password. No device_code.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 #[derive(Default)]
 pub struct OAuthFlows {
@@ -921,6 +1171,7 @@ pub struct OAuthFlows {
 ///
 /// Python SDK ref: `AuthorizationCodeOAuthFlow` — no pkce_required field.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct AuthorizationCodeOAuthFlow {
     /// Authorization endpoint URL.
@@ -941,6 +1192,7 @@ pub struct AuthorizationCodeOAuthFlow {
 ///
 /// Proto ref: `message ClientCredentialsOAuthFlow`
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct ClientCredentialsOAuthFlow {
     /// Token endpoint URL.
@@ -958,6 +1210,7 @@ pub struct ClientCredentialsOAuthFlow {
 ///
 /// Proto ref: `message ImplicitOAuthFlow`
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct ImplicitOAuthFlow {
     /// Authorization endpoint URL.
@@ -975,6 +1228,7 @@ pub struct ImplicitOAuthFlow {
 ///
 /// Proto ref: `message PasswordOAuthFlow`
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct PasswordOAuthFlow {
     /// Token endpoint URL.
@@ -1003,6 +1257,7 @@ pub type SecurityRequirement = HashMap<String, Vec<String>>;
 ///
 /// Proto ref: `message PushNotificationConfig`
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct PushNotificationConfig {
     /// Optional identifier for this config.
@@ -1026,6 +1281,7 @@ pub struct PushNotificationConfig {
 /// Python SDK ref: `PushNotificationAuthenticationInfo`
 /// `schemes: list[str]` (PLURAL), `credentials: str | None`
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct PushNotificationAuthenticationInfo {
     /// List of supported authentication schemes (e.g. ["Bearer", "Basic"]).
@@ -1042,6 +1298,7 @@ pub struct PushNotificationAuthenticationInfo {
 /// Python SDK ref: `TaskPushNotificationConfig`
 /// Proto ref: `message TaskPushNotificationConfig`
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct TaskPushNotificationConfig {
     /// The id of this config.
@@ -1073,6 +1330,7 @@ pub struct TaskPushNotificationConfig {
 ///
 /// Can be a string, number, or null (for notifications).
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(untagged)]
 pub enum JsonRpcId {
     /// String identifier.
@@ -1097,6 +1355,7 @@ impl fmt::Display for JsonRpcId {
 ///
 /// Used for both requests (with `id`) and notifications (without `id`).
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct JsonRpcRequest {
     /// Protocol version — always "2.0".
@@ -1118,6 +1377,7 @@ pub struct JsonRpcRequest {
 ///
 /// Exactly one of `result` or `error` will be present.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct JsonRpcResponse {
     /// Protocol version — always "2.0".
@@ -1169,6 +1429,7 @@ impl JsonRpcResponse {
 
 /// A JSON-RPC 2.0 error object.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct JsonRpcError {
     /// Error code.
@@ -1191,6 +1452,7 @@ pub struct JsonRpcError {
 /// Proto ref: `message SendMessageRequest`
 /// Python SDK ref: `MessageSendParams`
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct SendMessageParams {
     /// The message to send.
@@ -1214,6 +1476,7 @@ pub struct SendMessageParams {
 /// Proto ref: `message SendMessageConfiguration`
 /// Python SDK ref: `MessageSendConfiguration`
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 #[derive(Default)]
 pub struct SendMessageConfiguration {
@@ -1232,6 +1495,12 @@ pub struct SendMessageConfiguration {
     /// Whether the request should block until the task completes.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub blocking: Option<bool>,
+
+    /// Time budget of the request in milliseconds, after which the task
+    /// fails with a deadline-exceeded error (SDK extension; see
+    /// [`deadline`](crate::utils::deadline)).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout_ms: Option<u64>,
 }
 
 /// Parameters for `tasks/get`.
@@ -1239,6 +1508,7 @@ pub struct SendMessageConfiguration {
 /// Proto ref: `message GetTaskRequest`
 /// Python SDK ref: `TaskQueryParams`
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct GetTaskParams {
     /// Task ID to retrieve.
@@ -1255,12 +1525,56 @@ pub struct GetTaskParams {
     /// Optional tenant identifier.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tenant: Option<String>,
+
+    /// Read-consistency hint for replicated task stores (a2a-rs extension).
+    ///
+    /// Servers whose store cannot honour the hint ignore it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub consistency: Option<ReadConsistency>,
+}
+
+/// Read-consistency hint for `tasks/get`.
+///
+/// With replicated task stores, a read right after `message/send` may hit a
+/// replica that hasn't seen the task yet. Capable
+/// [`TaskStore`](crate::server::TaskStore) backends use this hint to route
+/// or delay the read; others treat every mode as `Eventual`.
+///
+/// JSON: `{"mode": "primary"}` or `{"mode": "atLeastSequence", "sequence": 42}`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(tag = "mode", rename_all = "camelCase")]
+pub enum ReadConsistency {
+    /// Any replica may answer (default).
+    #[default]
+    Eventual,
+    /// Read from the primary / leader (read-your-writes).
+    Primary,
+    /// Wait until the answering replica has applied at least this
+    /// backend-defined write sequence number.
+    AtLeastSequence {
+        /// Minimum applied sequence number.
+        sequence: u64,
+    },
 }
 
 /// Parameters for `tasks/list`.
 ///
 /// Proto ref: `message ListTasksRequest`
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// Filters are set with the `with_*` methods:
+///
+/// ```
+/// use a2a_rs::types::{ListTasksParams, TaskState};
+///
+/// let params = ListTasksParams::new()
+///     .with_context_id("ctx-1")
+///     .with_status(TaskState::Working)
+///     .with_page_size(50);
+/// assert_eq!(params.context_id.as_deref(), Some("ctx-1"));
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct ListTasksParams {
     /// Filter by context ID.
@@ -1299,19 +1613,26 @@ pub struct ListTasksParams {
 /// Response for `tasks/list`.
 ///
 /// Proto ref: `message ListTasksResponse`
+///
+/// Servers may omit the counts and, on the last page, the token; they
+/// default to 0 and `""`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct ListTasksResponse {
     /// Tasks matching the query.
     pub tasks: Vec<Task>,
 
-    /// Token for retrieving the next page.
+    /// Token for retrieving the next page; empty on the last page.
+    #[serde(default)]
     pub next_page_token: String,
 
     /// Number of tasks in this page.
+    #[serde(default)]
     pub page_size: i32,
 
     /// Total number of matching tasks.
+    #[serde(default)]
     pub total_size: i32,
 }
 
@@ -1320,6 +1641,7 @@ pub struct ListTasksResponse {
 /// Proto ref: `message CancelTaskRequest`
 /// Python SDK ref: `TaskIdParams`
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct CancelTaskParams {
     /// ID of the task to cancel.
@@ -1338,6 +1660,7 @@ pub struct CancelTaskParams {
 ///
 /// Proto ref: `message SubscribeToTaskRequest`
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct SubscribeToTaskParams {
     /// ID of the task to subscribe to.
@@ -1356,6 +1679,7 @@ pub struct SubscribeToTaskParams {
 ///
 /// Python SDK ref: `TaskIdParams`
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct TaskIdParams {
     /// The task ID.
@@ -1374,6 +1698,7 @@ pub struct TaskIdParams {
 ///
 /// Proto ref: `message CreateTaskPushNotificationConfigRequest`
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct CreateTaskPushNotificationConfigParams {
     /// The parent task resource id.
@@ -1401,6 +1726,7 @@ pub type SetTaskPushNotificationConfigParams = CreateTaskPushNotificationConfigP
 ///
 /// Proto ref: `message GetTaskPushNotificationConfigRequest`
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct GetTaskPushNotificationConfigParams {
     /// The unique identifier (e.g. UUID) of the task.
@@ -1415,27 +1741,45 @@ pub struct GetTaskPushNotificationConfigParams {
     /// Optional metadata associated with the request.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<serde_json::Value>,
+
+    /// Optional tenant identifier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tenant: Option<String>,
 }
 
 /// Parameters for listing push notification configs for a task.
 ///
 /// Proto ref: `message ListTaskPushNotificationConfigRequest`
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct ListTaskPushNotificationConfigParams {
     /// The unique identifier (e.g. UUID) of the task.
     /// Matches Python SDK: `id: str`
     pub id: String,
 
+    /// Maximum number of configs to return; all of them if omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_size: Option<i32>,
+
+    /// `nextPageToken` of the previous page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_token: Option<String>,
+
     /// Optional metadata associated with the request.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<serde_json::Value>,
+
+    /// Optional tenant identifier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tenant: Option<String>,
 }
 
 /// Response for listing push notification configs.
 ///
 /// Proto ref: `message ListTaskPushNotificationConfigResponse`
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct ListTaskPushNotificationConfigResponse {
     /// The list of push notification configurations.
@@ -1452,6 +1796,7 @@ pub struct ListTaskPushNotificationConfigResponse {
 ///
 /// Proto ref: `message DeleteTaskPushNotificationConfigRequest`
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct DeleteTaskPushNotificationConfigParams {
     /// The unique identifier (e.g. UUID) of the task.
@@ -1465,12 +1810,17 @@ pub struct DeleteTaskPushNotificationConfigParams {
     /// Optional metadata associated with the request.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<serde_json::Value>,
+
+    /// Optional tenant identifier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tenant: Option<String>,
 }
 
 /// Parameters for getting the extended agent card.
 ///
 /// Proto ref: `message GetExtendedAgentCardRequest`
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct GetExtendedAgentCardParams {
     /// Optional tenant.
@@ -1479,6 +1829,249 @@ pub struct GetExtendedAgentCardParams {
     pub tenant: Option<String>,
 }
 
+// ============================================================================
+// Skill Validation (skills/invoke dry-run extension)
+// ============================================================================
+
+/// Parameters for `skills/invoke`.
+///
+/// Not part of the A2A spec: an SDK extension method. Only dry runs are
+/// supported — the message is checked against the skill without creating a
+/// task; use `message/send` to execute.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct SkillInvokeParams {
+    /// The [`AgentSkill::id`] to validate against.
+    pub skill_id: String,
+
+    /// The candidate message.
+    pub message: Message,
+
+    /// Validate only, without executing. Must be `true`.
+    #[serde(default)]
+    pub dry_run: bool,
+
+    /// Output MIME types the client would accept.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accepted_output_modes: Option<Vec<String>>,
+
+    /// Optional metadata.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<serde_json::Value>,
+
+    /// Optional tenant identifier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tenant: Option<String>,
+}
+
+/// Why a candidate message failed validation against a skill.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum SkillValidationCode {
+    /// The message has no parts.
+    EmptyMessage,
+    /// The message is not from the `user` role.
+    InvalidRole,
+    /// A part's MIME type is not among the skill's input modes.
+    UnsupportedInputMode,
+    /// A data part is not a JSON object.
+    InvalidData,
+    /// None of the accepted output modes is produced by the skill.
+    NoCompatibleOutputMode,
+    /// A code this SDK doesn't recognise.
+    #[serde(other)]
+    Other,
+}
+
+/// A single validation finding.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct SkillValidationIssue {
+    /// Machine-readable issue code.
+    pub code: SkillValidationCode,
+
+    /// Human-readable description.
+    pub message: String,
+
+    /// Index of the offending part in `message.parts`, if part-specific.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part_index: Option<usize>,
+}
+
+/// Result of a `skills/invoke` dry run.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct SkillValidationResult {
+    /// The skill that was validated against.
+    pub skill_id: String,
+
+    /// `true` if there are no issues.
+    pub valid: bool,
+
+    /// Everything that would prevent the skill from handling the message.
+    #[serde(default)]
+    pub issues: Vec<SkillValidationIssue>,
+}
+
+// ============================================================================
+// Context Cancellation (contexts/cancel extension)
+// ============================================================================
+
+/// Parameters for `contexts/cancel`.
+///
+/// Not part of the A2A spec: an SDK extension method that cancels every
+/// non-terminal task of a context in one request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct CancelContextParams {
+    /// The context whose tasks to cancel.
+    pub context_id: String,
+
+    /// Optional metadata, passed on to each task's cancellation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<serde_json::Value>,
+
+    /// Optional tenant identifier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tenant: Option<String>,
+}
+
+/// Outcome of canceling one task of a context.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct ContextTaskCancelResult {
+    /// The task.
+    pub task_id: String,
+
+    /// The canceled task, if cancellation succeeded.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub task: Option<Task>,
+
+    /// Why the task could not be canceled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<JsonRpcError>,
+}
+
+/// Result of `contexts/cancel`: one entry per task that was not yet in a
+/// terminal state.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct CancelContextResult {
+    /// The context that was canceled.
+    pub context_id: String,
+
+    /// Per-task outcomes, ordered by task ID.
+    #[serde(default)]
+    pub results: Vec<ContextTaskCancelResult>,
+}
+
+impl CancelContextResult {
+    /// `true` if every task was canceled.
+    pub fn all_canceled(&self) -> bool {
+        self.results.iter().all(|result| result.error.is_none())
+    }
+}
+
+// ============================================================================
+// Task Artifacts (tasks/artifacts/* extension)
+// ============================================================================
+
+/// Parameters for `tasks/artifacts/list`.
+///
+/// Not part of the A2A spec: an SDK extension method listing a task's
+/// artifacts without their parts, so clients can fetch large ones
+/// individually with `tasks/artifacts/get` instead of with every
+/// `tasks/get`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct ListArtifactsParams {
+    /// The task whose artifacts to list.
+    pub task_id: String,
+
+    /// Optional tenant identifier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tenant: Option<String>,
+}
+
+/// Parameters for `tasks/artifacts/get` (SDK extension): one artifact of a
+/// task, with its parts.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct GetArtifactParams {
+    /// The task the artifact belongs to.
+    pub task_id: String,
+
+    /// The artifact to fetch.
+    pub artifact_id: String,
+
+    /// Optional tenant identifier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tenant: Option<String>,
+}
+
+/// An [`Artifact`] without its parts, as listed by `tasks/artifacts/list`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct ArtifactSummary {
+    /// Unique artifact identifier.
+    pub artifact_id: String,
+
+    /// Human-readable name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Description of the artifact.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Number of content parts.
+    pub part_count: usize,
+
+    /// Arbitrary metadata.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<serde_json::Value>,
+
+    /// Protocol extensions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extensions: Option<Vec<String>>,
+}
+
+impl From<&Artifact> for ArtifactSummary {
+    fn from(artifact: &Artifact) -> Self {
+        Self {
+            artifact_id: artifact.artifact_id.clone(),
+            name: artifact.name.clone(),
+            description: artifact.description.clone(),
+            part_count: artifact.parts.len(),
+            metadata: artifact.metadata.clone(),
+            extensions: artifact.extensions.clone(),
+        }
+    }
+}
+
+/// Result of `tasks/artifacts/list`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct ListArtifactsResult {
+    /// The task the artifacts belong to.
+    pub task_id: String,
+
+    /// The task's artifacts, in the order the task holds them.
+    #[serde(default)]
+    pub artifacts: Vec<ArtifactSummary>,
+}
+
 // ============================================================================
 // SendMessageResponse (oneof: Task | Message)
 // ============================================================================
@@ -1493,6 +2086,8 @@ pub struct GetExtendedAgentCardParams {
 ///
 /// Serializes FLAT (no wrapper keys).
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema), schemars(untagged))]
+#[allow(clippy::large_enum_variant)]
 pub enum SendMessageResponse {
     /// A task was created/updated.
     Task(Task),
@@ -1569,6 +2164,24 @@ impl Part {
         }
     }
 
+    /// Create a file part from raw bytes, base64-encoding them.
+    ///
+    /// Produces the same JSON as [`Part::file_from_bytes`].
+    pub fn file_from_raw(
+        bytes: impl AsRef<[u8]>,
+        name: Option<String>,
+        mime_type: Option<String>,
+    ) -> Self {
+        Part::File {
+            file: FileContent::Bytes(FileWithBytes {
+                name,
+                mime_type,
+                ..FileWithBytes::from_bytes(bytes)
+            }),
+            metadata: None,
+        }
+    }
+
     /// Create a file part from a URI reference.
     ///
     /// Produces JSON: `{"kind": "file", "file": {"uri": "...", "mimeType": "...", "name": "..."}}`
@@ -1596,6 +2209,280 @@ impl Part {
             metadata: None,
         }
     }
+
+    /// Create a data part holding `value` serialized to JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`A2AError::InternalError`](crate::error::A2AError::InternalError)
+    /// if `value` can't be represented as JSON (e.g. a map with non-string
+    /// keys).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use a2a_rs::types::Part;
+    ///
+    /// #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+    /// struct Quote {
+    ///     symbol: String,
+    ///     price: f64,
+    /// }
+    ///
+    /// let quote = Quote { symbol: "ACME".into(), price: 12.5 };
+    /// let part = Part::from_serialize(&quote).unwrap();
+    /// assert_eq!(part.data_as::<Quote>().unwrap(), quote);
+    /// ```
+    pub fn from_serialize<T: Serialize + ?Sized>(value: &T) -> crate::error::A2AResult<Self> {
+        serde_json::to_value(value).map(Self::data).map_err(|e| {
+            crate::error::A2AError::internal_error(format!("Cannot serialize data part: {e}"))
+        })
+    }
+
+    /// Deserialize the payload of a data part into `T`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`A2AError::InvalidParams`](crate::error::A2AError::InvalidParams)
+    /// if this isn't a data part or its payload doesn't match `T`.
+    pub fn data_as<T: serde::de::DeserializeOwned>(&self) -> crate::error::A2AResult<T> {
+        match self {
+            Part::Data { data, .. } => T::deserialize(data).map_err(|e| {
+                crate::error::A2AError::invalid_params(format!(
+                    "Data part is not a valid {}: {e}",
+                    std::any::type_name::<T>()
+                ))
+            }),
+            _ => Err(crate::error::A2AError::invalid_params(
+                "Part is not a data part",
+            )),
+        }
+    }
+}
+
+/// Deserialize the first data part of `parts` into `T`.
+fn first_data_as<T: serde::de::DeserializeOwned>(
+    parts: &[Part],
+    owner: &str,
+) -> crate::error::A2AResult<T> {
+    parts
+        .iter()
+        .find(|part| matches!(part, Part::Data { .. }))
+        .ok_or_else(|| crate::error::A2AError::invalid_params(format!("{owner} has no data part")))?
+        .data_as()
+}
+
+/// Largest file [`Part::file_from_path`] and [`Part::file_from_reader`]
+/// accept by default (10 MiB).
+#[cfg(feature = "fs")]
+pub const DEFAULT_FILE_PART_LIMIT: u64 = 10 * 1024 * 1024;
+
+#[cfg(feature = "fs")]
+impl Part {
+    /// Read the file at `path` into a base64 file part named after it, with
+    /// the MIME type guessed from its extension.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`A2AError::InvalidParams`](crate::error::A2AError::InvalidParams)
+    /// if the file can't be read or is larger than
+    /// [`DEFAULT_FILE_PART_LIMIT`].
+    pub async fn file_from_path(
+        path: impl AsRef<std::path::Path>,
+    ) -> crate::error::A2AResult<Self> {
+        Self::file_from_path_with_limit(path, DEFAULT_FILE_PART_LIMIT).await
+    }
+
+    /// As [`file_from_path`](Self::file_from_path), accepting files of up
+    /// to `limit` bytes.
+    ///
+    /// # Errors
+    ///
+    /// As [`file_from_path`](Self::file_from_path).
+    pub async fn file_from_path_with_limit(
+        path: impl AsRef<std::path::Path>,
+        limit: u64,
+    ) -> crate::error::A2AResult<Self> {
+        let path = path.as_ref();
+        let unreadable = |e: std::io::Error| crate::error::A2AError::InvalidParams {
+            message: format!("Cannot read {}: {e}", path.display()),
+            data: None,
+        };
+        let file = tokio::fs::File::open(path).await.map_err(unreadable)?;
+        let size = file.metadata().await.map_err(unreadable)?.len();
+        if size > limit {
+            return Err(file_too_large(&path.display().to_string(), limit));
+        }
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned());
+        Self::file_from_reader(file, name, None, limit).await
+    }
+
+    /// Read `reader` to the end into a base64 file part.
+    ///
+    /// Without a `mime_type`, it is guessed from the extension of `name`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`A2AError::InvalidParams`](crate::error::A2AError::InvalidParams)
+    /// if reading fails or the content is longer than `limit` bytes.
+    pub async fn file_from_reader<R>(
+        reader: R,
+        name: Option<String>,
+        mime_type: Option<String>,
+        limit: u64,
+    ) -> crate::error::A2AResult<Self>
+    where
+        R: tokio::io::AsyncRead + Unpin,
+    {
+        use tokio::io::AsyncReadExt;
+
+        let label = name.as_deref().unwrap_or("file");
+        let mut bytes = Vec::new();
+        reader
+            .take(limit.saturating_add(1))
+            .read_to_end(&mut bytes)
+            .await
+            .map_err(|e| crate::error::A2AError::InvalidParams {
+                message: format!("Cannot read {label}: {e}"),
+                data: None,
+            })?;
+        if bytes.len() as u64 > limit {
+            return Err(file_too_large(label, limit));
+        }
+        let mime_type = mime_type.or_else(|| {
+            name.as_deref().map(|name| {
+                crate::utils::parts::guess_mime_type(std::path::Path::new(name)).to_string()
+            })
+        });
+        Ok(Self::file_from_raw(bytes, name, mime_type))
+    }
+}
+
+#[cfg(feature = "fs")]
+fn file_too_large(label: &str, limit: u64) -> crate::error::A2AError {
+    crate::error::A2AError::InvalidParams {
+        message: format!("{label} is larger than the file part limit of {limit} bytes"),
+        data: None,
+    }
+}
+
+impl FileWithBytes {
+    /// Base64-encode `bytes` into a file with no name or MIME type.
+    ///
+    /// Accepts anything byte-like — `&[u8]`, `Vec<u8>`, [`bytes::Bytes`] —
+    /// and encodes straight from it without an intermediate copy.
+    ///
+    /// # Example
+    /// ```
+    /// use a2a_rs::types::FileWithBytes;
+    ///
+    /// let file = FileWithBytes::from_bytes(b"hello");
+    /// assert_eq!(file.bytes, "aGVsbG8=");
+    /// assert_eq!(file.decoded_len(), 5);
+    /// assert_eq!(file.decoded_bytes().unwrap(), b"hello");
+    /// ```
+    pub fn from_bytes(bytes: impl AsRef<[u8]>) -> Self {
+        Self {
+            bytes: base64::engine::general_purpose::STANDARD.encode(bytes.as_ref()),
+            mime_type: None,
+            name: None,
+        }
+    }
+
+    /// Set the file name.
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Set the MIME type.
+    pub fn with_mime_type(mut self, mime_type: impl Into<String>) -> Self {
+        self.mime_type = Some(mime_type.into());
+        self
+    }
+
+    /// Decode the file content; same as [`decoded_bytes()`](Self::decoded_bytes).
+    ///
+    /// # Errors
+    ///
+    /// As [`decoded_bytes()`](Self::decoded_bytes).
+    pub fn decode(&self) -> crate::error::A2AResult<Vec<u8>> {
+        self.decoded_bytes()
+    }
+
+    /// Decode the file content.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`A2AError::InvalidParams`](crate::error::A2AError::InvalidParams)
+    /// if `bytes` isn't valid base64.
+    pub fn decoded_bytes(&self) -> crate::error::A2AResult<Vec<u8>> {
+        let mut decoded = Vec::with_capacity(self.decoded_len());
+        self.decode_into(&mut decoded)?;
+        Ok(decoded)
+    }
+
+    /// Decode the file content into a reference-counted [`bytes::Bytes`],
+    /// which can be cloned and sliced without copying the data again.
+    ///
+    /// # Errors
+    ///
+    /// As [`decoded_bytes()`](Self::decoded_bytes).
+    pub fn to_bytes(&self) -> crate::error::A2AResult<bytes::Bytes> {
+        self.decoded_bytes().map(bytes::Bytes::from)
+    }
+
+    /// Decode the file content, appending it to `buf` so one buffer can be
+    /// reused across files.
+    ///
+    /// # Errors
+    ///
+    /// As [`decoded_bytes()`](Self::decoded_bytes); `buf` is left unchanged.
+    pub fn decode_into(&self, buf: &mut Vec<u8>) -> crate::error::A2AResult<()> {
+        let len = buf.len();
+        base64::engine::general_purpose::STANDARD
+            .decode_vec(&self.bytes, buf)
+            .map_err(|e| {
+                buf.truncate(len);
+                crate::error::A2AError::InvalidParams {
+                    message: format!("File part is not valid base64: {e}"),
+                    data: None,
+                }
+            })
+    }
+
+    /// Length of the base64-encoded content.
+    pub fn encoded_len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Length of the decoded content, computed without decoding.
+    ///
+    /// Exact for valid base64.
+    pub fn decoded_len(&self) -> usize {
+        let padding = self.bytes.bytes().rev().take_while(|&b| b == b'=').count();
+        (self.bytes.len() / 4 * 3 + self.bytes.len() % 4 * 3 / 4).saturating_sub(padding)
+    }
+}
+
+impl From<bytes::Bytes> for FileWithBytes {
+    fn from(bytes: bytes::Bytes) -> Self {
+        Self::from_bytes(bytes)
+    }
+}
+
+impl From<&[u8]> for FileWithBytes {
+    fn from(bytes: &[u8]) -> Self {
+        Self::from_bytes(bytes)
+    }
+}
+
+impl From<Vec<u8>> for FileWithBytes {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self::from_bytes(bytes)
+    }
 }
 
 impl Message {
@@ -1628,6 +2515,100 @@ impl Message {
             reference_task_ids: None,
         }
     }
+
+    /// Deserialize the first data part into `T`; see [`Part::data_as`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`A2AError::InvalidParams`](crate::error::A2AError::InvalidParams)
+    /// if the message has no data part or its payload doesn't match `T`.
+    pub fn first_data_as<T: serde::de::DeserializeOwned>(&self) -> crate::error::A2AResult<T> {
+        first_data_as(&self.parts, "Message")
+    }
+}
+
+impl Artifact {
+    /// Deserialize the first data part into `T`; see [`Part::data_as`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`A2AError::InvalidParams`](crate::error::A2AError::InvalidParams)
+    /// if the artifact has no data part or its payload doesn't match `T`.
+    pub fn data_as<T: serde::de::DeserializeOwned>(&self) -> crate::error::A2AResult<T> {
+        first_data_as(&self.parts, "Artifact")
+    }
+}
+
+impl ListTasksParams {
+    /// Parameters listing all tasks.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only tasks of context `context_id`.
+    pub fn with_context_id(mut self, context_id: impl Into<String>) -> Self {
+        self.context_id = Some(context_id.into());
+        self
+    }
+
+    /// Only tasks in `state`.
+    pub fn with_status(mut self, state: TaskState) -> Self {
+        self.status = Some(state);
+        self
+    }
+
+    /// Only tasks whose status was updated after `timestamp`.
+    pub fn with_status_timestamp_after(mut self, timestamp: chrono::DateTime<chrono::Utc>) -> Self {
+        self.status_timestamp_after =
+            Some(timestamp.to_rfc3339_opts(chrono::SecondsFormat::Millis, true));
+        self
+    }
+
+    /// At most `size` tasks per page.
+    pub fn with_page_size(mut self, size: i32) -> Self {
+        self.page_size = Some(size);
+        self
+    }
+}
+
+impl Task {
+    /// Keep only the most recent `limit` messages of the history.
+    ///
+    /// A limit of 0 empties the history. Protocol `historyLength` params
+    /// treat 0 as "no limit"; see [`apply_history_length`] for that mapping.
+    ///
+    /// [`apply_history_length`]: crate::utils::apply_history_length
+    pub fn with_history_limit(mut self, limit: usize) -> Self {
+        self.truncate_history(limit);
+        self
+    }
+
+    /// In-place form of [`with_history_limit`](Self::with_history_limit).
+    pub fn truncate_history(&mut self, limit: usize) {
+        if let Some(history) = self.history.as_mut() {
+            if history.len() > limit {
+                let start = history.len() - limit;
+                history.drain(..start);
+            }
+        }
+    }
+}
+
+impl AgentCard {
+    /// Check the card against the A2A specification; see
+    /// [`validate_agent_card`](crate::utils::validate_agent_card).
+    ///
+    /// # Errors
+    ///
+    /// Returns every spec violation found, in field order.
+    pub fn validate(&self) -> Result<(), Vec<crate::utils::CardValidationError>> {
+        let errors = crate::utils::validate_agent_card(self);
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
 }
 
 impl TaskStatus {
@@ -1637,6 +2618,7 @@ impl TaskStatus {
             state,
             message: None,
             timestamp: None,
+            raw_state: None,
         }
     }
 
@@ -1646,6 +2628,31 @@ impl TaskStatus {
             state,
             message: None,
             timestamp: Some(timestamp.into()),
+            raw_state: None,
+        }
+    }
+
+    /// Attach `message` to this status.
+    pub fn with_message(mut self, message: Message) -> Self {
+        self.message = Some(message);
+        self
+    }
+
+    /// The state as sent on the wire: the original string for
+    /// unrecognised states, otherwise the [`TaskState`] name.
+    pub fn state_name(&self) -> &str {
+        match (&self.state, &self.raw_state) {
+            (TaskState::Unknown, Some(raw)) => raw,
+            (state, _) => state.as_str(),
+        }
+    }
+
+    /// The original state string if the peer sent one this SDK doesn't
+    /// recognise.
+    pub fn unrecognized_state(&self) -> Option<&str> {
+        match self.state {
+            TaskState::Unknown => self.raw_state.as_deref(),
+            _ => None,
         }
     }
 }
@@ -2127,7 +3134,7 @@ mod tests {
             url: "http://localhost:8080/a2a".to_string(),
             supported_interfaces: vec![AgentInterface {
                 url: "http://localhost:8080/a2a".to_string(),
-                protocol_binding: "JSONRPC".to_string(),
+                transport: "JSONRPC".to_string(),
                 tenant: None,
                 protocol_version: Some("0.3".to_string()),
             }],
@@ -2165,8 +3172,8 @@ mod tests {
         let json = serde_json::to_value(&card).unwrap();
         assert_eq!(json["name"], "Test Agent");
         assert_eq!(json["url"], "http://localhost:8080/a2a");
-        // Spec v0.3.0: uses "protocolBinding"
-        assert_eq!(json["supportedInterfaces"][0]["protocolBinding"], "JSONRPC");
+        // Spec v0.3.0: uses "transport"
+        assert_eq!(json["supportedInterfaces"][0]["transport"], "JSONRPC");
         assert_eq!(json["capabilities"]["streaming"], true);
         assert_eq!(json["skills"][0]["id"], "code");
     }
@@ -2196,6 +3203,7 @@ mod tests {
                 push_notification_config: None,
                 history_length: Some(10),
                 blocking: Some(true),
+                timeout_ms: None,
             }),
             metadata: None,
             tenant: None,
@@ -2269,7 +3277,7 @@ mod tests {
         match &sr {
             StreamResponse::StatusUpdate(update) => {
                 assert_eq!(update.task_id, "t1");
-                assert_eq!(update.r#final, false);
+                assert!(!update.r#final);
             }
             _ => panic!("expected StatusUpdate"),
         }
@@ -2444,7 +3452,7 @@ mod tests {
         match decoded {
             StreamResponse::StatusUpdate(e) => {
                 assert_eq!(e.task_id, "t1");
-                assert_eq!(e.r#final, true);
+                assert!(e.r#final);
             }
             _ => panic!("expected StatusUpdate"),
         }
@@ -2785,49 +3793,50 @@ mod tests {
         assert_eq!(json["type"], "apiKey");
     }
 
-    // --- AgentInterface uses "protocolBinding" (spec v0.3.0) ---
+    // --- AgentInterface uses "transport" (spec v0.3.0, Python SDK) ---
 
     #[test]
-    fn agent_interface_protocol_binding_field() {
+    fn agent_interface_transport_field() {
         let iface = AgentInterface {
             url: "https://example.com/a2a".to_string(),
-            protocol_binding: "JSONRPC".to_string(),
+            transport: "JSONRPC".to_string(),
             tenant: None,
             protocol_version: Some("0.3".to_string()),
         };
         let json = serde_json::to_value(&iface).unwrap();
 
-        // Spec v0.3.0: uses "protocolBinding"
-        assert_eq!(json["protocolBinding"], "JSONRPC");
+        // Spec v0.3.0: uses "transport"
+        assert_eq!(json["transport"], "JSONRPC");
+        assert!(json.get("protocolBinding").is_none());
         assert!(json.get("tenant").is_none());
 
         let decoded: AgentInterface = serde_json::from_value(json).unwrap();
-        assert_eq!(decoded.protocol_binding, "JSONRPC");
+        assert_eq!(decoded.transport, "JSONRPC");
     }
 
     #[test]
-    fn agent_interface_accepts_transport_alias() {
-        // Python SDK sends "transport" — we should still accept it
+    fn agent_interface_accepts_protocol_binding_alias() {
+        // Proto-based peers send "protocolBinding" — we should still accept it
         let json = serde_json::json!({
             "url": "https://example.com/a2a",
-            "transport": "JSONRPC",
+            "protocolBinding": "JSONRPC",
             "protocolVersion": "0.3"
         });
         let decoded: AgentInterface = serde_json::from_value(json).unwrap();
-        assert_eq!(decoded.protocol_binding, "JSONRPC");
+        assert_eq!(decoded.transport, "JSONRPC");
     }
 
     #[test]
     fn agent_interface_with_tenant() {
         let iface = AgentInterface {
             url: "https://example.com/a2a".to_string(),
-            protocol_binding: "GRPC".to_string(),
+            transport: "GRPC".to_string(),
             tenant: Some("my-tenant".to_string()),
             protocol_version: Some("0.3".to_string()),
         };
         let json = serde_json::to_value(&iface).unwrap();
 
-        assert_eq!(json["protocolBinding"], "GRPC");
+        assert_eq!(json["transport"], "GRPC");
         assert_eq!(json["tenant"], "my-tenant");
 
         let decoded: AgentInterface = serde_json::from_value(json).unwrap();
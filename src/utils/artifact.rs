@@ -1,10 +1,20 @@
-//! Utility functions for creating A2A Artifact objects.
+//! Utility functions for creating A2A Artifact objects, and
+//! [`ArtifactAssembler`] for rebuilding artifacts streamed in chunks.
 
-use crate::types::{Artifact, Part};
+use std::collections::{BTreeMap, HashMap};
+
+use crate::types::{Artifact, Part, TaskArtifactUpdateEvent};
 use crate::utils::parts::get_text_parts;
 use serde_json::Value;
 use uuid::Uuid;
 
+/// Event metadata key numbering the chunks of one artifact from 0.
+///
+/// Producers whose chunks may arrive out of order (e.g. merged from
+/// several workers) set it on each `artifact-update` event so that
+/// [`ArtifactAssembler`] can put them back in order.
+pub const CHUNK_INDEX_METADATA_KEY: &str = "chunkIndex";
+
 /// Creates a new Artifact object with a generated artifact_id.
 ///
 /// # Arguments
@@ -139,6 +149,196 @@ pub fn get_artifact_text(artifact: &Artifact, delimiter: &str) -> String {
     get_text_parts(&artifact.parts).join(delimiter)
 }
 
+/// Merges chunked `artifact-update` events into complete [`Artifact`]s.
+///
+/// Chunks follow the same rules as the server's task store: a chunk
+/// without `append` starts (or restarts) its artifact, one with `append`
+/// adds its parts, and one with `lastChunk` completes it. Artifacts may be
+/// interleaved and are returned in order of their first chunk.
+///
+/// Chunks carrying a [`CHUNK_INDEX_METADATA_KEY`] are applied in index
+/// order: early ones wait until the gap before them is filled, and an
+/// index seen before is dropped as a duplicate. Without an index, chunks
+/// apply in arrival order and only retransmissions after the last chunk
+/// (an appended chunk, or a repeat of the last one) are dropped.
+///
+/// The client's `ArtifactAggregator` adds completion callbacks on top of
+/// the same rules; `SseStream::collect_artifacts()` runs this over a
+/// whole stream.
+///
+/// # Example
+///
+/// ```
+/// use a2a_rs::types::{Artifact, Part, TaskArtifactUpdateEvent};
+/// use a2a_rs::utils::{get_artifact_text, ArtifactAssembler};
+/// use serde_json::json;
+///
+/// let chunk = |index: u64, text: &str, last: bool| TaskArtifactUpdateEvent {
+///     task_id: "t1".into(),
+///     context_id: "c1".into(),
+///     kind: "artifact-update".into(),
+///     artifact: Artifact {
+///         artifact_id: "report".into(),
+///         name: None,
+///         description: None,
+///         parts: vec![Part::text(text)],
+///         metadata: None,
+///         extensions: None,
+///     },
+///     append: Some(index > 0),
+///     last_chunk: Some(last),
+///     metadata: Some(json!({"chunkIndex": index})),
+/// };
+///
+/// let mut assembler = ArtifactAssembler::new();
+/// assert!(assembler.push(&chunk(1, "world", true)).is_none()); // waits for chunk 0
+/// let report = assembler.push(&chunk(0, "hello ", false)).unwrap();
+/// assert_eq!(get_artifact_text(&report, ""), "hello world");
+/// assert!(assembler.is_complete());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ArtifactAssembler {
+    /// Artifact IDs in order of their first chunk.
+    order: Vec<String>,
+    assemblies: HashMap<String, Assembly>,
+}
+
+/// One artifact being assembled.
+#[derive(Debug, Clone, Default)]
+struct Assembly {
+    /// Parts received so far; `None` until a chunk was applied.
+    artifact: Option<Artifact>,
+    complete: bool,
+    /// Index of the next indexed chunk to apply.
+    next_index: u64,
+    /// Indexed chunks that arrived early.
+    pending: BTreeMap<u64, TaskArtifactUpdateEvent>,
+    /// The last applied unindexed chunk, for duplicate detection.
+    last_chunk: Option<Value>,
+}
+
+impl Assembly {
+    /// Apply `update` in order. Returns whether it completed the artifact.
+    fn apply(&mut self, update: &TaskArtifactUpdateEvent) -> bool {
+        let incoming = &update.artifact;
+        match (&mut self.artifact, update.append.unwrap_or(false)) {
+            (Some(artifact), true) => {
+                artifact.parts.extend(incoming.parts.iter().cloned());
+                if incoming.metadata.is_some() {
+                    artifact.metadata = incoming.metadata.clone();
+                }
+            }
+            (artifact, _) => *artifact = Some(incoming.clone()),
+        }
+        self.complete = update.last_chunk.unwrap_or(false);
+        self.complete
+    }
+}
+
+impl ArtifactAssembler {
+    /// An empty assembler.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a chunk. Returns the artifact if this chunk (or the buffered
+    /// chunks it released) completed it.
+    pub fn push(&mut self, update: &TaskArtifactUpdateEvent) -> Option<Artifact> {
+        let artifact_id = &update.artifact.artifact_id;
+        if !self.assemblies.contains_key(artifact_id) {
+            self.order.push(artifact_id.clone());
+        }
+        let assembly = self.assemblies.entry(artifact_id.clone()).or_default();
+
+        let completed = match chunk_index(update) {
+            Some(index) if index < assembly.next_index || assembly.pending.contains_key(&index) => {
+                false
+            }
+            Some(index) if index > assembly.next_index => {
+                assembly.pending.insert(index, update.clone());
+                false
+            }
+            Some(_) => {
+                let mut completed = assembly.apply(update);
+                assembly.next_index += 1;
+                while let Some(next) = assembly.pending.remove(&assembly.next_index) {
+                    completed = assembly.apply(&next);
+                    assembly.next_index += 1;
+                }
+                completed
+            }
+            None => {
+                let key = chunk_key(update);
+                let retransmitted = assembly.complete
+                    && (update.append.unwrap_or(false)
+                        || assembly.last_chunk.as_ref() == Some(&key));
+                if retransmitted {
+                    return None;
+                }
+                assembly.last_chunk = Some(key);
+                assembly.apply(update)
+            }
+        };
+        if completed {
+            assembly.artifact.clone()
+        } else {
+            None
+        }
+    }
+
+    /// The artifact with this ID as assembled so far.
+    pub fn get(&self, artifact_id: &str) -> Option<&Artifact> {
+        self.assemblies.get(artifact_id)?.artifact.as_ref()
+    }
+
+    /// Whether the artifact with this ID received its last chunk.
+    pub fn is_artifact_complete(&self, artifact_id: &str) -> bool {
+        self.assemblies
+            .get(artifact_id)
+            .is_some_and(|assembly| assembly.complete)
+    }
+
+    /// Whether every artifact received its last chunk.
+    pub fn is_complete(&self) -> bool {
+        self.assemblies.values().all(|assembly| assembly.complete)
+    }
+
+    /// Number of chunks waiting for an earlier index.
+    pub fn pending_chunks(&self) -> usize {
+        self.assemblies
+            .values()
+            .map(|assembly| assembly.pending.len())
+            .sum()
+    }
+
+    /// Take the artifacts assembled so far, complete or not, in order of
+    /// their first chunk.
+    pub fn into_artifacts(mut self) -> Vec<Artifact> {
+        self.order
+            .iter()
+            .filter_map(|id| self.assemblies.remove(id)?.artifact)
+            .collect()
+    }
+}
+
+/// What identifies a repeated chunk.
+fn chunk_key(update: &TaskArtifactUpdateEvent) -> Value {
+    serde_json::json!({
+        "artifact": update.artifact,
+        "append": update.append,
+        "lastChunk": update.last_chunk,
+    })
+}
+
+/// The [`CHUNK_INDEX_METADATA_KEY`] of `update`, if set.
+fn chunk_index(update: &TaskArtifactUpdateEvent) -> Option<u64> {
+    update
+        .metadata
+        .as_ref()?
+        .get(CHUNK_INDEX_METADATA_KEY)?
+        .as_u64()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,265 @@
+//! Spec conformance checks of agent cards.
+//!
+//! [`validate_agent_card`] (also [`AgentCard::validate`]) reports every way
+//! a card departs from the A2A specification: missing required fields,
+//! malformed URLs, no interface to call the agent on, transports outside
+//! [`KNOWN_TRANSPORTS`], protocol versions this SDK can't talk to, and
+//! security requirements naming schemes the card doesn't declare.
+//!
+//! Validation is opt-in: agents build cards with
+//! [`AgentCardBuilder::try_build`](crate::builders::AgentCardBuilder::try_build),
+//! clients resolve them with
+//! [`CardResolver::with_strict_validation`](crate::client::CardResolver::with_strict_validation).
+//! Both fail with
+//! [`A2AError::InvalidAgentCard`](crate::error::A2AError::InvalidAgentCard).
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::{AgentCard, AgentInterface, SecurityRequirement};
+
+use super::version::{ProtocolVersion, ProtocolVersionRange};
+
+/// Transport bindings defined by the A2A specification.
+pub const KNOWN_TRANSPORTS: &[&str] = &["JSONRPC", "GRPC", "HTTP+JSON"];
+
+/// What is wrong with an agent card.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CardValidationErrorKind {
+    /// A required field is empty.
+    MissingField,
+    /// A URL isn't an absolute `scheme://host…` URL.
+    InvalidUrl,
+    /// Neither `url` nor any interface says where to reach the agent.
+    NoInterface,
+    /// A transport isn't one of [`KNOWN_TRANSPORTS`].
+    UnknownTransport,
+    /// A protocol version doesn't parse or is outside
+    /// [`ProtocolVersionRange::supported`].
+    UnsupportedProtocolVersion,
+    /// A security requirement names a scheme missing from
+    /// `securitySchemes`.
+    UndeclaredSecurityScheme,
+}
+
+/// A single spec violation in an agent card.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CardValidationError {
+    /// Machine-readable kind.
+    pub kind: CardValidationErrorKind,
+
+    /// Path of the offending field in the card's JSON, e.g.
+    /// `supportedInterfaces[1].transport`.
+    pub field: String,
+
+    /// Human-readable description.
+    pub message: String,
+}
+
+impl CardValidationError {
+    fn new(kind: CardValidationErrorKind, field: impl Into<String>, message: String) -> Self {
+        Self {
+            kind,
+            field: field.into(),
+            message,
+        }
+    }
+}
+
+impl std::fmt::Display for CardValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.field, self.message)
+    }
+}
+
+/// Check `card` against the A2A specification.
+///
+/// A URL is accepted if it has a scheme, `://` and a non-empty host, and
+/// no whitespace. Transports are compared case-sensitively, as the spec
+/// spells them. Cards and interfaces without a `protocolVersion` are
+/// accepted.
+///
+/// Returns an empty list if the card conforms.
+///
+/// # Example
+/// ```
+/// use a2a_rs::builders::AgentCardBuilder;
+/// use a2a_rs::utils::{validate_agent_card, CardValidationErrorKind};
+///
+/// let card = AgentCardBuilder::new("agent", "An agent", "1.0")
+///     .with_jsonrpc_interface("localhost:7420/a2a")
+///     .build();
+///
+/// let errors = validate_agent_card(&card);
+/// assert!(errors
+///     .iter()
+///     .all(|error| error.kind == CardValidationErrorKind::InvalidUrl));
+/// assert_eq!(errors[1].field, "supportedInterfaces[0].url");
+/// ```
+pub fn validate_agent_card(card: &AgentCard) -> Vec<CardValidationError> {
+    let mut errors = Vec::new();
+
+    for (field, value) in [
+        ("name", &card.name),
+        ("description", &card.description),
+        ("version", &card.version),
+    ] {
+        if value.trim().is_empty() {
+            errors.push(CardValidationError::new(
+                CardValidationErrorKind::MissingField,
+                field,
+                format!("'{field}' must not be empty"),
+            ));
+        }
+    }
+
+    let additional = card.additional_interfaces.as_deref().unwrap_or_default();
+    if card.url.is_empty() && card.supported_interfaces.is_empty() && additional.is_empty() {
+        errors.push(CardValidationError::new(
+            CardValidationErrorKind::NoInterface,
+            "supportedInterfaces",
+            "the card declares no interface and no 'url'".to_string(),
+        ));
+    }
+    if !card.url.is_empty() {
+        check_url(&mut errors, "url", &card.url);
+    }
+    if let Some(transport) = &card.preferred_transport {
+        check_transport(&mut errors, "preferredTransport", transport);
+    }
+    if let Some(version) = &card.protocol_version {
+        check_version(&mut errors, "protocolVersion", version);
+    }
+    for (i, interface) in card.supported_interfaces.iter().enumerate() {
+        check_interface(&mut errors, &format!("supportedInterfaces[{i}]"), interface);
+    }
+    for (i, interface) in additional.iter().enumerate() {
+        check_interface(
+            &mut errors,
+            &format!("additionalInterfaces[{i}]"),
+            interface,
+        );
+    }
+
+    for (field, url) in [
+        ("documentationUrl", &card.documentation_url),
+        ("iconUrl", &card.icon_url),
+    ] {
+        if let Some(url) = url {
+            check_url(&mut errors, field, url);
+        }
+    }
+    if let Some(provider) = &card.provider {
+        check_url(&mut errors, "provider.url", &provider.url);
+    }
+
+    let declared = |scheme: &str| {
+        card.security_schemes
+            .as_ref()
+            .is_some_and(|schemes| schemes.contains_key(scheme))
+    };
+    let mut check_requirements = |field: &str, requirements: &[SecurityRequirement]| {
+        for (i, requirement) in requirements.iter().enumerate() {
+            let mut schemes: Vec<&String> = requirement.keys().collect();
+            schemes.sort();
+            for scheme in schemes.into_iter().filter(|scheme| !declared(scheme)) {
+                errors.push(CardValidationError::new(
+                    CardValidationErrorKind::UndeclaredSecurityScheme,
+                    format!("{field}[{i}]"),
+                    format!("security scheme '{scheme}' is not declared in 'securitySchemes'"),
+                ));
+            }
+        }
+    };
+    check_requirements("securityRequirements", &card.security_requirements);
+    check_requirements("security", card.security.as_deref().unwrap_or_default());
+    for (i, skill) in card.skills.iter().enumerate() {
+        check_requirements(
+            &format!("skills[{i}].securityRequirements"),
+            skill.security_requirements.as_deref().unwrap_or_default(),
+        );
+        check_requirements(
+            &format!("skills[{i}].security"),
+            skill.security.as_deref().unwrap_or_default(),
+        );
+    }
+
+    errors
+}
+
+fn check_interface(errors: &mut Vec<CardValidationError>, field: &str, interface: &AgentInterface) {
+    check_url(errors, &format!("{field}.url"), &interface.url);
+    check_transport(errors, &format!("{field}.transport"), &interface.transport);
+    if let Some(version) = &interface.protocol_version {
+        check_version(errors, &format!("{field}.protocolVersion"), version);
+    }
+}
+
+fn check_url(errors: &mut Vec<CardValidationError>, field: &str, url: &str) {
+    if !is_well_formed_url(url) {
+        errors.push(CardValidationError::new(
+            CardValidationErrorKind::InvalidUrl,
+            field,
+            format!("'{url}' is not an absolute URL"),
+        ));
+    }
+}
+
+fn check_transport(errors: &mut Vec<CardValidationError>, field: &str, transport: &str) {
+    if !KNOWN_TRANSPORTS.contains(&transport) {
+        errors.push(CardValidationError::new(
+            CardValidationErrorKind::UnknownTransport,
+            field,
+            format!(
+                "unknown transport '{transport}'; expected one of {}",
+                KNOWN_TRANSPORTS.join(", ")
+            ),
+        ));
+    }
+}
+
+fn check_version(errors: &mut Vec<CardValidationError>, field: &str, version: &str) {
+    let supported = ProtocolVersionRange::supported();
+    let message = match ProtocolVersion::parse(version) {
+        Some(parsed) if supported.contains(parsed) => return,
+        Some(_) => format!("protocol version '{version}' is outside {supported}"),
+        None => format!("'{version}' is not a protocol version"),
+    };
+    errors.push(CardValidationError::new(
+        CardValidationErrorKind::UnsupportedProtocolVersion,
+        field,
+        message,
+    ));
+}
+
+/// Whether `url` looks like `scheme://host[...]`.
+fn is_well_formed_url(url: &str) -> bool {
+    let Some((scheme, rest)) = url.split_once("://") else {
+        return false;
+    };
+    let scheme_ok = scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+        && scheme
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'));
+    let host = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    let host = host.rsplit('@').next().unwrap_or_default();
+    scheme_ok && !host.is_empty() && !host.starts_with(':') && !url.contains(char::is_whitespace)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn url_forms() {
+        assert!(is_well_formed_url("http://localhost:7420/a2a"));
+        assert!(is_well_formed_url("https://user@agent.example.com"));
+        assert!(is_well_formed_url("grpc+tls://10.0.0.1:50051"));
+        assert!(!is_well_formed_url("localhost:7420"));
+        assert!(!is_well_formed_url("http://"));
+        assert!(!is_well_formed_url("http://:80/a2a"));
+        assert!(!is_well_formed_url("http://agent example.com"));
+        assert!(!is_well_formed_url("1http://agent"));
+    }
+}
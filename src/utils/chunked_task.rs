@@ -0,0 +1,180 @@
+//! Chunked `tasks/get` responses for very large tasks (SDK extension).
+//!
+//! A task with a long history or big artifacts can serialize to a response
+//! so large that proxies time out before it is written. When a client
+//! requests the [`CHUNKED_TASK_EXTENSION_URI`] extension and the agent card
+//! advertises it, the server may answer `tasks/get` for a task larger than
+//! its threshold with a `Content-Type: application/x-ndjson` body of
+//! JSON-RPC responses, one per line, whose results are [`TaskSection`]s:
+//!
+//! ```text
+//! {"jsonrpc":"2.0","id":1,"result":{"section":"head","task":{...},"historyCount":120,"artifactCount":2}}
+//! {"jsonrpc":"2.0","id":1,"result":{"section":"history","offset":0,"messages":[...]}}
+//! {"jsonrpc":"2.0","id":1,"result":{"section":"history","offset":50,"messages":[...]}}
+//! {"jsonrpc":"2.0","id":1,"result":{"section":"artifact","index":0,"artifact":{...}}}
+//! ```
+//!
+//! [`split_task`] produces the sections on the server and [`assemble_task`]
+//! rebuilds the full [`Task`] on the client, so callers of `get_task` never
+//! see the sections. An error line ends the response like a unary error.
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{A2AError, A2AResult};
+use crate::types::{Artifact, Message, Task};
+
+/// URI of the chunked `tasks/get` extension.
+pub const CHUNKED_TASK_EXTENSION_URI: &str =
+    "https://github.com/colours93/a2a-rs/extensions/chunked-task/v1";
+
+/// Content type of a chunked `tasks/get` response.
+pub const CHUNKED_TASK_CONTENT_TYPE: &str = "application/x-ndjson";
+
+/// Serialized task size above which the server chunks `tasks/get`, when
+/// none is configured (1 MiB).
+pub const DEFAULT_CHUNKED_TASK_THRESHOLD: usize = 1024 * 1024;
+
+/// Messages per [`TaskSection::History`] section.
+pub const HISTORY_PAGE_SIZE: usize = 50;
+
+/// One section of a chunked task.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "section", rename_all = "camelCase")]
+pub enum TaskSection {
+    /// The task without history and artifacts; always first.
+    #[serde(rename_all = "camelCase")]
+    Head {
+        /// Task with `history` and `artifacts` unset.
+        task: Box<Task>,
+        /// Number of history messages that follow; `None` if the task has
+        /// no `history` field.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        history_count: Option<usize>,
+        /// Number of artifacts that follow; `None` if the task has no
+        /// `artifacts` field.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        artifact_count: Option<usize>,
+    },
+    /// A page of history, starting at message `offset`.
+    #[serde(rename_all = "camelCase")]
+    History {
+        /// Index of the first message in the task's history.
+        offset: usize,
+        /// The messages.
+        messages: Vec<Message>,
+    },
+    /// A single artifact.
+    #[serde(rename_all = "camelCase")]
+    Artifact {
+        /// Index of the artifact in the task's artifacts.
+        index: usize,
+        /// The artifact.
+        artifact: Artifact,
+    },
+}
+
+/// Split `task` into a head, history pages of `history_page_size` messages
+/// and one section per artifact.
+///
+/// # Example
+///
+/// ```
+/// use a2a_rs::types::Message;
+/// use a2a_rs::utils::{assemble_task, new_task, split_task};
+///
+/// let mut task = new_task(Message::user("m0", "hi")).unwrap();
+/// task.history = Some((0..5).map(|i| Message::user(format!("m{i}"), "hi")).collect());
+///
+/// let sections = split_task(&task, 2);
+/// assert_eq!(sections.len(), 4); // head + 3 history pages
+/// assert_eq!(assemble_task(sections).unwrap().history.unwrap().len(), 5);
+/// ```
+pub fn split_task(task: &Task, history_page_size: usize) -> Vec<TaskSection> {
+    let head = Task {
+        history: None,
+        artifacts: None,
+        ..task.clone()
+    };
+    let mut sections = vec![TaskSection::Head {
+        task: Box::new(head),
+        history_count: task.history.as_ref().map(Vec::len),
+        artifact_count: task.artifacts.as_ref().map(Vec::len),
+    }];
+    let history = task.history.as_deref().unwrap_or_default();
+    for (page, messages) in history.chunks(history_page_size.max(1)).enumerate() {
+        sections.push(TaskSection::History {
+            offset: page * history_page_size.max(1),
+            messages: messages.to_vec(),
+        });
+    }
+    let artifacts = task.artifacts.as_deref().unwrap_or_default();
+    for (index, artifact) in artifacts.iter().enumerate() {
+        sections.push(TaskSection::Artifact {
+            index,
+            artifact: artifact.clone(),
+        });
+    }
+    sections
+}
+
+/// Rebuild a task from the sections [`split_task`] produced, in order.
+///
+/// Returns [`A2AError::InvalidAgentResponse`] if the head is missing or
+/// repeated, a section is out of order, or the counts in the head don't
+/// match what followed.
+pub fn assemble_task(sections: impl IntoIterator<Item = TaskSection>) -> A2AResult<Task> {
+    let invalid = |message: String| A2AError::invalid_agent_response(message);
+    let mut sections = sections.into_iter();
+    let Some(TaskSection::Head {
+        mut task,
+        history_count,
+        artifact_count,
+    }) = sections.next()
+    else {
+        return Err(invalid("chunked task does not start with a head".into()));
+    };
+
+    let mut history = Vec::with_capacity(history_count.unwrap_or_default());
+    let mut artifacts = Vec::with_capacity(artifact_count.unwrap_or_default());
+    for section in sections {
+        match section {
+            TaskSection::Head { .. } => {
+                return Err(invalid("chunked task has more than one head".into()));
+            }
+            TaskSection::History { offset, messages } => {
+                if offset != history.len() {
+                    return Err(invalid(format!(
+                        "history section at offset {offset}, expected {}",
+                        history.len()
+                    )));
+                }
+                history.extend(messages);
+            }
+            TaskSection::Artifact { index, artifact } => {
+                if index != artifacts.len() {
+                    return Err(invalid(format!(
+                        "artifact section {index}, expected {}",
+                        artifacts.len()
+                    )));
+                }
+                artifacts.push(artifact);
+            }
+        }
+    }
+
+    if history.len() != history_count.unwrap_or_default()
+        || artifacts.len() != artifact_count.unwrap_or_default()
+    {
+        return Err(invalid(format!(
+            "chunked task {} is incomplete: {} of {} messages, {} of {} artifacts",
+            task.id,
+            history.len(),
+            history_count.unwrap_or_default(),
+            artifacts.len(),
+            artifact_count.unwrap_or_default()
+        )));
+    }
+    task.history = history_count.map(|_| history);
+    task.artifacts = artifact_count.map(|_| artifacts);
+    Ok(*task)
+}
@@ -0,0 +1,130 @@
+//! Client-side compatibility checks of outgoing messages against an agent
+//! card.
+//!
+//! [`check_message_compatibility`] reports what in a message the agent
+//! declares it can't handle: parts in a MIME type outside its input modes,
+//! and extensions it requires that the caller doesn't use. With
+//! [`A2AClient::with_card_validation`] the client runs it before every
+//! `message/send` and `message/stream` and fails with
+//! [`A2AError::IncompatibleAgent`](crate::error::A2AError::IncompatibleAgent)
+//! instead of waiting for the server to reject the message.
+//!
+//! [`A2AClient::with_card_validation`]: crate::client::A2AClient::with_card_validation
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::{AgentCard, Message};
+
+use super::skills::{mime_type_matches, part_mime_type};
+
+/// What makes a message incompatible with an agent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CompatibilityIssueKind {
+    /// A part's MIME type is in neither the card's default input modes nor
+    /// any skill's.
+    UnsupportedInputMode,
+    /// The card marks an extension `required` that the message doesn't use.
+    MissingRequiredExtension,
+}
+
+/// A single reason an agent can't handle a message.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompatibilityIssue {
+    /// Machine-readable kind.
+    pub kind: CompatibilityIssueKind,
+
+    /// Human-readable description.
+    pub message: String,
+
+    /// Index of the offending part in `message.parts`, if part-specific.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part_index: Option<usize>,
+
+    /// URI of the missing extension, for
+    /// [`MissingRequiredExtension`](CompatibilityIssueKind::MissingRequiredExtension).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extension: Option<String>,
+}
+
+/// Check `message` against `card`.
+///
+/// A part is accepted if its MIME type (see [`part_mime_type`]) matches the
+/// card's `default_input_modes` or the input modes of any skill; an empty
+/// mode list accepts anything. A required extension counts as supported if
+/// it is in `message.extensions` or in `extensions` (e.g. those requested
+/// with the `X-A2A-Extensions` header).
+///
+/// Returns an empty list if the message is compatible.
+///
+/// # Example
+/// ```
+/// use a2a_rs::builders::AgentCardBuilder;
+/// use a2a_rs::types::{Message, Part};
+/// use a2a_rs::utils::{check_message_compatibility, CompatibilityIssueKind};
+/// use std::collections::HashSet;
+///
+/// let card = AgentCardBuilder::new("agent", "An agent", "1.0")
+///     .with_input_modes(vec!["text/plain".to_string()])
+///     .build();
+/// let mut message = Message::user("m1", "hi");
+/// message.parts.push(Part::data(serde_json::json!({"a": 1})));
+///
+/// let issues = check_message_compatibility(&card, &message, &HashSet::new());
+/// assert_eq!(issues.len(), 1);
+/// assert_eq!(issues[0].kind, CompatibilityIssueKind::UnsupportedInputMode);
+/// assert_eq!(issues[0].part_index, Some(1));
+/// ```
+pub fn check_message_compatibility(
+    card: &AgentCard,
+    message: &Message,
+    extensions: &HashSet<String>,
+) -> Vec<CompatibilityIssue> {
+    let mut mode_lists = vec![card.default_input_modes.as_slice()];
+    mode_lists.extend(card.skills.iter().filter_map(|s| s.input_modes.as_deref()));
+    let accepts_anything = mode_lists.iter().any(|modes| modes.is_empty());
+
+    let mut issues = Vec::new();
+    if !accepts_anything {
+        for (index, part) in message.parts.iter().enumerate() {
+            let mime_type = part_mime_type(part);
+            let accepted = mode_lists
+                .iter()
+                .flat_map(|modes| modes.iter())
+                .any(|mode| mime_type_matches(mode, mime_type));
+            if !accepted {
+                issues.push(CompatibilityIssue {
+                    kind: CompatibilityIssueKind::UnsupportedInputMode,
+                    message: format!(
+                        "Agent '{}' does not accept '{}' input",
+                        card.name, mime_type
+                    ),
+                    part_index: Some(index),
+                    extension: None,
+                });
+            }
+        }
+    }
+
+    let used = message.extensions.as_deref().unwrap_or_default();
+    for extension in card.capabilities.extensions.iter().flatten() {
+        if extension.required == Some(true)
+            && !extensions.contains(&extension.uri)
+            && !used.contains(&extension.uri)
+        {
+            issues.push(CompatibilityIssue {
+                kind: CompatibilityIssueKind::MissingRequiredExtension,
+                message: format!(
+                    "Agent '{}' requires extension '{}'",
+                    card.name, extension.uri
+                ),
+                part_index: None,
+                extension: Some(extension.uri.clone()),
+            });
+        }
+    }
+    issues
+}
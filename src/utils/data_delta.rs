@@ -0,0 +1,408 @@
+//! Delta compression of repeated Data parts in streams (SDK extension).
+//!
+//! Agents that stream evolving JSON state tend to resend nearly identical
+//! [`Part::Data`] payloads. When a client requests the
+//! [`DATA_DELTA_EXTENSION_URI`] extension and the agent card advertises it,
+//! the server may send a Data part as a JSON Patch (RFC 6902) against the
+//! previous Data part of the same artifact in the same stream:
+//!
+//! ```json
+//! {"kind": "data",
+//!  "data": [{"op": "replace", "path": "/progress", "value": 0.5}],
+//!  "metadata": {"dataDelta": "json-patch"}}
+//! ```
+//!
+//! [`DataDeltaEncoder`] produces such parts on the server (only when the
+//! patch is smaller than the full payload), and [`DataDeltaDecoder`] turns
+//! them back into full Data parts on the client, so consumers never see a
+//! patch. Task stores always hold full payloads.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use serde_json::{json, Map, Value};
+
+use crate::error::{A2AError, A2AResult};
+use crate::types::{Part, StreamResponse, TaskArtifactUpdateEvent};
+
+/// URI of the Data part delta compression extension.
+pub const DATA_DELTA_EXTENSION_URI: &str =
+    "https://github.com/colours93/a2a-rs/extensions/data-delta/v1";
+
+/// Part metadata key marking a Data part as a delta.
+pub const DATA_DELTA_METADATA_KEY: &str = "dataDelta";
+
+/// Value of [`DATA_DELTA_METADATA_KEY`] for JSON Patch deltas.
+pub const DATA_DELTA_JSON_PATCH: &str = "json-patch";
+
+/// Compute a JSON Patch that turns `old` into `new`.
+///
+/// Objects are diffed key by key and arrays element by element, with
+/// trailing elements added or removed; anything else is replaced. The
+/// result uses only `add`, `remove` and `replace` operations.
+pub fn json_diff(old: &Value, new: &Value) -> Vec<Value> {
+    let mut ops = Vec::new();
+    diff_into(&mut String::new(), old, new, &mut ops);
+    ops
+}
+
+fn diff_into(path: &mut String, old: &Value, new: &Value, ops: &mut Vec<Value>) {
+    if old == new {
+        return;
+    }
+    match (old, new) {
+        (Value::Object(old), Value::Object(new)) => {
+            for key in old.keys().filter(|k| !new.contains_key(*k)) {
+                ops.push(json!({"op": "remove", "path": child(path, key)}));
+            }
+            for (key, value) in new {
+                let len = path.len();
+                path.push_str(&child("", key));
+                match old.get(key) {
+                    Some(previous) => diff_into(path, previous, value, ops),
+                    None => ops.push(json!({"op": "add", "path": path.clone(), "value": value})),
+                }
+                path.truncate(len);
+            }
+        }
+        (Value::Array(old), Value::Array(new)) => {
+            let common = old.len().min(new.len());
+            for index in 0..common {
+                let len = path.len();
+                path.push_str(&format!("/{index}"));
+                diff_into(path, &old[index], &new[index], ops);
+                path.truncate(len);
+            }
+            for (index, value) in new.iter().enumerate().skip(common) {
+                ops.push(json!({"op": "add", "path": format!("{path}/{index}"), "value": value}));
+            }
+            for index in (common..old.len()).rev() {
+                ops.push(json!({"op": "remove", "path": format!("{path}/{index}")}));
+            }
+        }
+        _ => ops.push(json!({"op": "replace", "path": path.clone(), "value": new})),
+    }
+}
+
+/// `path` extended by the escaped object key `key`.
+fn child(path: &str, key: &str) -> String {
+    format!("{path}/{}", key.replace('~', "~0").replace('/', "~1"))
+}
+
+/// Apply a JSON Patch to `target` in place.
+///
+/// Supports `add`, `remove`, `replace` and `test`.
+///
+/// # Errors
+///
+/// Returns [`A2AError::InvalidParams`] if an operation is malformed,
+/// unsupported or refers to a missing location. `target` may be partly
+/// patched when an error is returned.
+pub fn apply_json_patch(target: &mut Value, patch: &[Value]) -> A2AResult<()> {
+    for op in patch {
+        apply_op(target, op)?;
+    }
+    Ok(())
+}
+
+fn invalid(message: String) -> A2AError {
+    A2AError::InvalidParams {
+        message,
+        data: None,
+    }
+}
+
+fn apply_op(target: &mut Value, op: &Value) -> A2AResult<()> {
+    let name = op.get("op").and_then(Value::as_str).unwrap_or_default();
+    let path = op
+        .get("path")
+        .and_then(Value::as_str)
+        .ok_or_else(|| invalid(format!("JSON Patch operation without a path: {op}")))?;
+    let value = || {
+        op.get("value")
+            .cloned()
+            .ok_or_else(|| invalid(format!("JSON Patch '{name}' without a value at '{path}'")))
+    };
+    let missing = || invalid(format!("JSON Patch path '{path}' does not exist"));
+
+    if path.is_empty() {
+        return match name {
+            "add" | "replace" => {
+                *target = value()?;
+                Ok(())
+            }
+            "test" if *target == value()? => Ok(()),
+            "test" => Err(invalid(format!("JSON Patch test failed at '{path}'"))),
+            _ => Err(invalid(format!("JSON Patch cannot '{name}' the root"))),
+        };
+    }
+
+    let (parent_path, last) = path
+        .rsplit_once('/')
+        .ok_or_else(|| invalid(format!("Invalid JSON Pointer '{path}'")))?;
+    let last = last.replace("~1", "/").replace("~0", "~");
+    let parent = target.pointer_mut(parent_path).ok_or_else(missing)?;
+
+    match (name, parent) {
+        ("add", Value::Object(map)) => {
+            map.insert(last, value()?);
+        }
+        ("add", Value::Array(items)) => {
+            let index = if last == "-" {
+                items.len()
+            } else {
+                array_index(&last, items.len() + 1).ok_or_else(missing)?
+            };
+            items.insert(index, value()?);
+        }
+        ("remove", Value::Object(map)) => {
+            map.remove(&last).ok_or_else(missing)?;
+        }
+        ("remove", Value::Array(items)) => {
+            let index = array_index(&last, items.len()).ok_or_else(missing)?;
+            items.remove(index);
+        }
+        ("replace" | "test", parent) => {
+            let slot = match parent {
+                Value::Object(map) => map.get_mut(&last),
+                Value::Array(items) => array_index(&last, items.len()).map(|i| &mut items[i]),
+                _ => None,
+            }
+            .ok_or_else(missing)?;
+            if name == "replace" {
+                *slot = value()?;
+            } else if *slot != value()? {
+                return Err(invalid(format!("JSON Patch test failed at '{path}'")));
+            }
+        }
+        ("add" | "remove", _) => return Err(missing()),
+        _ => {
+            return Err(invalid(format!(
+                "Unsupported JSON Patch operation '{name}'"
+            )))
+        }
+    }
+    Ok(())
+}
+
+/// Parse an array index token that must be below `len`.
+fn array_index(token: &str, len: usize) -> Option<usize> {
+    if token.len() > 1 && token.starts_with('0') {
+        return None;
+    }
+    token.parse().ok().filter(|index| *index < len)
+}
+
+/// Whether `part` is a delta produced by [`DataDeltaEncoder`].
+pub fn is_data_delta(part: &Part) -> bool {
+    matches!(part, Part::Data { metadata, .. } if marks_delta(metadata))
+}
+
+fn marks_delta(metadata: &Option<Value>) -> bool {
+    metadata
+        .as_ref()
+        .and_then(|m| m.get(DATA_DELTA_METADATA_KEY))
+        .and_then(Value::as_str)
+        == Some(DATA_DELTA_JSON_PATCH)
+}
+
+/// Key of the previous-payload table: `(task_id, artifact_id)`.
+type ArtifactKey = (String, String);
+
+/// Server-side encoder: replaces Data parts with JSON Patches against the
+/// previous Data part of the same artifact.
+///
+/// Use one encoder per outgoing stream, since the client reconstructs
+/// against what it has received on that stream.
+#[derive(Debug, Default)]
+pub struct DataDeltaEncoder {
+    previous: HashMap<ArtifactKey, Value>,
+}
+
+impl DataDeltaEncoder {
+    /// Create an encoder with no history.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Encode the Data parts of an artifact update; other events pass
+    /// through unchanged. A part is only replaced when its patch serializes
+    /// smaller than the full payload.
+    pub fn encode(&mut self, event: StreamResponse) -> StreamResponse {
+        let mut update = match event {
+            StreamResponse::ArtifactUpdate(update) => update,
+            StreamResponse::StatusUpdate(update) => {
+                if update.r#final {
+                    self.previous.retain(|(task, _), _| *task != update.task_id);
+                }
+                return StreamResponse::StatusUpdate(update);
+            }
+            other => return other,
+        };
+        let key = artifact_key(&update);
+        for part in &mut update.artifact.parts {
+            let Part::Data { data, metadata } = part else {
+                continue;
+            };
+            if marks_delta(metadata) {
+                continue;
+            }
+            let full = data.clone();
+            if let Some(previous) = self.previous.get(&key) {
+                let patch = Value::Array(json_diff(previous, &full));
+                if patch.to_string().len() < full.to_string().len() {
+                    *data = patch;
+                    let mut marked = match metadata.take() {
+                        Some(Value::Object(map)) => map,
+                        _ => Map::new(),
+                    };
+                    marked.insert(
+                        DATA_DELTA_METADATA_KEY.to_string(),
+                        Value::String(DATA_DELTA_JSON_PATCH.to_string()),
+                    );
+                    *metadata = Some(Value::Object(marked));
+                }
+            }
+            self.previous.insert(key.clone(), full);
+        }
+        StreamResponse::ArtifactUpdate(update)
+    }
+}
+
+/// Client-side decoder: restores full Data parts from deltas.
+///
+/// Registered automatically by
+/// [`ClientBuilder::with_data_delta`](crate::ClientBuilder::with_data_delta);
+/// with the `client` feature it implements
+/// [`StreamInterceptor`](crate::client::StreamInterceptor).
+#[derive(Debug, Default)]
+pub struct DataDeltaDecoder {
+    previous: Mutex<HashMap<ArtifactKey, Value>>,
+}
+
+impl DataDeltaDecoder {
+    /// Create a decoder with no history.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replace delta parts in `event` with the full Data parts they encode.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`A2AError::InvalidParams`] if a delta arrives without a
+    /// previous Data part for its artifact, or does not apply to it.
+    pub fn decode(&self, event: StreamResponse) -> A2AResult<StreamResponse> {
+        let mut previous = self.previous.lock().unwrap();
+        let mut update = match event {
+            StreamResponse::ArtifactUpdate(update) => update,
+            StreamResponse::StatusUpdate(update) => {
+                if update.r#final {
+                    previous.retain(|(task, _), _| *task != update.task_id);
+                }
+                return Ok(StreamResponse::StatusUpdate(update));
+            }
+            other => return Ok(other),
+        };
+        let key = artifact_key(&update);
+        for part in &mut update.artifact.parts {
+            let Part::Data { data, metadata } = part else {
+                continue;
+            };
+            if marks_delta(metadata) {
+                let mut full = previous.get(&key).cloned().ok_or_else(|| {
+                    invalid(format!(
+                        "Data delta for artifact '{}' without a previous Data part",
+                        key.1
+                    ))
+                })?;
+                let patch = data.as_array().ok_or_else(|| {
+                    invalid("Data delta payload must be a JSON Patch array".to_string())
+                })?;
+                apply_json_patch(&mut full, patch)?;
+                *data = full;
+                if let Some(Value::Object(map)) = metadata {
+                    map.remove(DATA_DELTA_METADATA_KEY);
+                    if map.is_empty() {
+                        *metadata = None;
+                    }
+                }
+            }
+            previous.insert(key.clone(), data.clone());
+        }
+        Ok(StreamResponse::ArtifactUpdate(update))
+    }
+}
+
+#[cfg(feature = "client")]
+impl crate::client::StreamInterceptor for DataDeltaDecoder {
+    fn intercept(&self, event: StreamResponse) -> Option<StreamResponse> {
+        match self.decode(event.clone()) {
+            Ok(decoded) => Some(decoded),
+            Err(e) => {
+                tracing::warn!(error = %e, "Could not reconstruct Data delta; passing it through");
+                Some(event)
+            }
+        }
+    }
+}
+
+fn artifact_key(update: &TaskArtifactUpdateEvent) -> ArtifactKey {
+    (update.task_id.clone(), update.artifact.artifact_id.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn roundtrip(old: Value, new: Value) {
+        let patch = json_diff(&old, &new);
+        let mut patched = old.clone();
+        apply_json_patch(&mut patched, &patch).unwrap();
+        assert_eq!(patched, new, "patch {patch:?}");
+    }
+
+    #[test]
+    fn diff_roundtrips() {
+        roundtrip(json!({"a": 1, "b": 2}), json!({"a": 1, "b": 3, "c": [1]}));
+        roundtrip(json!({"a": {"x": 1}, "gone": true}), json!({"a": {"x": 2}}));
+        roundtrip(json!([1, 2, 3]), json!([1, 5]));
+        roundtrip(json!([1]), json!([1, {"k": "v"}, 3]));
+        roundtrip(json!({"a/b": 1, "c~d": 2}), json!({"a/b": 2, "c~d": 3}));
+        roundtrip(json!({"a": 1}), json!([1, 2]));
+        roundtrip(json!("x"), json!("y"));
+    }
+
+    #[test]
+    fn identical_values_have_empty_patch() {
+        assert!(json_diff(&json!({"a": [1, 2]}), &json!({"a": [1, 2]})).is_empty());
+    }
+
+    #[test]
+    fn apply_rejects_bad_operations() {
+        let mut value = json!({"a": [1]});
+        assert!(apply_json_patch(&mut value, &[json!({"op": "move", "path": "/a"})]).is_err());
+        assert!(apply_json_patch(&mut value, &[json!({"op": "remove", "path": "/b"})]).is_err());
+        assert!(apply_json_patch(
+            &mut value,
+            &[json!({"op": "replace", "path": "/a/01", "value": 2})]
+        )
+        .is_err());
+        assert!(apply_json_patch(
+            &mut value,
+            &[json!({"op": "test", "path": "/a/0", "value": 2})]
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn apply_supports_append_token() {
+        let mut value = json!({"a": [1]});
+        apply_json_patch(
+            &mut value,
+            &[json!({"op": "add", "path": "/a/-", "value": 2})],
+        )
+        .unwrap();
+        assert_eq!(value, json!({"a": [1, 2]}));
+    }
+}
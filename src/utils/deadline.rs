@@ -0,0 +1,85 @@
+//! Deadlines bounding the time a request may take, end to end.
+//!
+//! A caller gives a request a deadline with the `X-A2A-Deadline` header
+//! (`x-a2a-deadline` gRPC metadata), an RFC 3339 timestamp, or a time
+//! budget with the `timeoutMs` option of `message/send`. The server runs
+//! the request with the earlier of the two as the
+//! [current deadline](current_deadline): executors read it from their
+//! `RequestContext`, and the task fails with a
+//! [`DeadlineExceeded`](crate::error::A2AError::DeadlineExceeded) error
+//! once it passes. Clients send the current deadline, so calls an agent
+//! makes to other agents while executing share the budget of the request
+//! that started it.
+
+use std::future::Future;
+use std::time::Duration;
+
+use chrono::{DateTime, SecondsFormat, Utc};
+
+/// HTTP header carrying the deadline.
+pub const DEADLINE_HEADER: &str = "X-A2A-Deadline";
+
+tokio::task_local! {
+    static CURRENT_DEADLINE: DateTime<Utc>;
+}
+
+/// The deadline `budget` from now.
+pub fn deadline_after(budget: Duration) -> DateTime<Utc> {
+    let budget = chrono::Duration::from_std(budget).unwrap_or(chrono::Duration::MAX);
+    Utc::now()
+        .checked_add_signed(budget)
+        .unwrap_or(DateTime::<Utc>::MAX_UTC)
+}
+
+/// Time left until `deadline`, zero once it has passed.
+pub fn time_remaining(deadline: DateTime<Utc>) -> Duration {
+    (deadline - Utc::now()).to_std().unwrap_or(Duration::ZERO)
+}
+
+/// The earlier of two optional deadlines.
+pub fn earliest_deadline(
+    a: Option<DateTime<Utc>>,
+    b: Option<DateTime<Utc>>,
+) -> Option<DateTime<Utc>> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(a.min(b)),
+        (a, b) => a.or(b),
+    }
+}
+
+/// A [`DEADLINE_HEADER`] value for `deadline`.
+pub fn format_deadline(deadline: DateTime<Utc>) -> String {
+    deadline.to_rfc3339_opts(SecondsFormat::Millis, true)
+}
+
+/// The deadline in a [`DEADLINE_HEADER`] value, if it is an RFC 3339
+/// timestamp.
+///
+/// # Example
+/// ```
+/// use a2a_rs::utils::{format_deadline, parse_deadline};
+///
+/// let deadline = parse_deadline("2026-05-01T12:00:00.250Z").unwrap();
+/// assert_eq!(format_deadline(deadline), "2026-05-01T12:00:00.250Z");
+/// assert!(parse_deadline("in 5 seconds").is_none());
+/// ```
+pub fn parse_deadline(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value.trim())
+        .ok()
+        .map(|deadline| deadline.with_timezone(&Utc))
+}
+
+/// Run `future` with `deadline` as the current deadline, or the current
+/// one if that is earlier.
+///
+/// Outgoing client calls made inside the scope send it. The future itself
+/// isn't cut short; see [`time_remaining()`].
+pub async fn with_deadline<F: Future>(deadline: DateTime<Utc>, future: F) -> F::Output {
+    let deadline = earliest_deadline(Some(deadline), current_deadline()).unwrap_or(deadline);
+    CURRENT_DEADLINE.scope(deadline, future).await
+}
+
+/// The deadline of the code being run, if inside [`with_deadline()`].
+pub fn current_deadline() -> Option<DateTime<Utc>> {
+    CURRENT_DEADLINE.try_with(|deadline| *deadline).ok()
+}
@@ -88,3 +88,58 @@ pub fn update_extension_header(
 
     kwargs
 }
+
+/// Outcome of extension negotiation for a single request.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ExtensionNegotiation {
+    /// Requested extensions the agent card declares — these are activated
+    /// and echoed back in the response `X-A2A-Extensions` header.
+    pub activated: HashSet<String>,
+
+    /// Extensions the card marks `required` that the client did not request.
+    pub missing_required: Vec<String>,
+}
+
+/// Negotiate extensions for a request against an agent card.
+///
+/// Per the A2A v0.3 extension rules, a client lists the extensions it wants
+/// in the `X-A2A-Extensions` request header; the agent activates those it
+/// supports (ignoring unknown URIs) and reports them in the response header.
+/// Requests that omit an extension the card marks `required` should be
+/// rejected — see [`ExtensionNegotiation::missing_required`].
+///
+/// # Example
+/// ```
+/// use a2a_rs::types::{AgentCard, AgentExtension, AgentCapabilities};
+/// use a2a_rs::utils::negotiate_extensions;
+/// use std::collections::HashSet;
+///
+/// # let card = AgentCard { name: "test".to_string(), description: "test".to_string(), version: "1.0".to_string(), url: "https://example.com".to_string(), supported_interfaces: vec![], capabilities: AgentCapabilities { extensions: Some(vec![AgentExtension { uri: "foo".to_string(), description: None, required: None, params: None }]), ..Default::default() }, default_input_modes: vec![], default_output_modes: vec![], skills: vec![], provider: None, documentation_url: None, security_schemes: None, security_requirements: vec![], signatures: None, icon_url: None, additional_interfaces: None, preferred_transport: None, protocol_version: None, supports_authenticated_extended_card: None, security: None };
+/// let requested: HashSet<String> = ["foo".to_string(), "unknown".to_string()].into();
+/// let outcome = negotiate_extensions(&card, &requested);
+/// assert_eq!(outcome.activated, HashSet::from(["foo".to_string()]));
+/// assert!(outcome.missing_required.is_empty());
+/// ```
+pub fn negotiate_extensions(card: &AgentCard, requested: &HashSet<String>) -> ExtensionNegotiation {
+    let declared = card.capabilities.extensions.as_deref().unwrap_or_default();
+    ExtensionNegotiation {
+        activated: declared
+            .iter()
+            .filter(|ext| requested.contains(&ext.uri))
+            .map(|ext| ext.uri.clone())
+            .collect(),
+        missing_required: declared
+            .iter()
+            .filter(|ext| ext.required == Some(true) && !requested.contains(&ext.uri))
+            .map(|ext| ext.uri.clone())
+            .collect(),
+    }
+}
+
+/// Format extension URIs as an `X-A2A-Extensions` header value
+/// (sorted, comma-separated).
+pub fn format_extension_header<'a>(extensions: impl IntoIterator<Item = &'a String>) -> String {
+    let mut uris: Vec<&str> = extensions.into_iter().map(String::as_str).collect();
+    uris.sort_unstable();
+    uris.join(",")
+}
@@ -0,0 +1,123 @@
+//! Client identification headers.
+//!
+//! Clients built with this SDK send `User-Agent: a2a-rs/<version>` and an
+//! `X-A2A-Client` header naming the SDK and, when configured, the
+//! application (`sdk=a2a-rs/0.1.0; app=my-app/2.3`). Servers parse both into
+//! a [`ClientIdentification`] for logging and metrics.
+
+/// HTTP header carrying the client's SDK and application identifiers.
+pub const CLIENT_IDENTIFICATION_HEADER: &str = "X-A2A-Client";
+
+/// Product token of this SDK, `a2a-rs/<version>`.
+pub const SDK_IDENTIFIER: &str = concat!("a2a-rs/", env!("CARGO_PKG_VERSION"));
+
+/// Who is calling: the SDK, the application built on it, and the raw
+/// `User-Agent`.
+///
+/// # Example
+/// ```
+/// use a2a_rs::utils::ClientIdentification;
+///
+/// let id = ClientIdentification::for_app(Some("my-app/2.3"));
+/// assert_eq!(id.user_agent.as_deref(), Some(concat!("my-app/2.3 a2a-rs/", env!("CARGO_PKG_VERSION"))));
+///
+/// let parsed = ClientIdentification::from_headers(Some(&id.header_value()), None).unwrap();
+/// assert_eq!(parsed.app.as_deref(), Some("my-app/2.3"));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ClientIdentification {
+    /// SDK product token, e.g. `a2a-rs/0.1.0`.
+    pub sdk: Option<String>,
+    /// Application identifier supplied by the caller, e.g. `my-app/2.3`.
+    pub app: Option<String>,
+    /// The `User-Agent` header.
+    pub user_agent: Option<String>,
+}
+
+impl ClientIdentification {
+    /// Identification of this SDK on behalf of `app`.
+    pub fn for_app(app: Option<&str>) -> Self {
+        let user_agent = match app {
+            Some(app) => format!("{app} {SDK_IDENTIFIER}"),
+            None => SDK_IDENTIFIER.to_string(),
+        };
+        Self {
+            sdk: Some(SDK_IDENTIFIER.to_string()),
+            app: app.map(String::from),
+            user_agent: Some(user_agent),
+        }
+    }
+
+    /// Value of the [`CLIENT_IDENTIFICATION_HEADER`] for this identification.
+    pub fn header_value(&self) -> String {
+        [("sdk", &self.sdk), ("app", &self.app)]
+            .iter()
+            .filter_map(|(key, value)| value.as_ref().map(|v| format!("{key}={v}")))
+            .collect::<Vec<_>>()
+            .join("; ")
+    }
+
+    /// Parse the [`CLIENT_IDENTIFICATION_HEADER`] and `User-Agent` values of
+    /// a request. Returns `None` if neither is present.
+    ///
+    /// A header value without `key=value` pairs is taken as the application
+    /// identifier; unknown keys are ignored.
+    pub fn from_headers(client: Option<&str>, user_agent: Option<&str>) -> Option<Self> {
+        if client.is_none() && user_agent.is_none() {
+            return None;
+        }
+        let mut id = Self {
+            user_agent: user_agent.map(String::from),
+            ..Default::default()
+        };
+        let client = client.map(str::trim).filter(|c| !c.is_empty());
+        match client {
+            Some(value) if value.contains('=') => {
+                for (key, field) in value.split(';').filter_map(|pair| pair.split_once('=')) {
+                    let field = Some(field.trim().to_string());
+                    match key.trim() {
+                        "sdk" => id.sdk = field,
+                        "app" => id.app = field,
+                        _ => {}
+                    }
+                }
+            }
+            Some(value) => id.app = Some(value.to_string()),
+            None => {}
+        }
+        Some(id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn header_roundtrip() {
+        let id = ClientIdentification::for_app(Some("bot/1"));
+        assert_eq!(
+            id.header_value(),
+            format!("sdk={SDK_IDENTIFIER}; app=bot/1")
+        );
+        let parsed =
+            ClientIdentification::from_headers(Some(&id.header_value()), id.user_agent.as_deref())
+                .unwrap();
+        assert_eq!(parsed, id);
+    }
+
+    #[test]
+    fn plain_value_is_app() {
+        let parsed = ClientIdentification::from_headers(Some("other-sdk-app"), None).unwrap();
+        assert_eq!(parsed.app.as_deref(), Some("other-sdk-app"));
+        assert_eq!(parsed.sdk, None);
+    }
+
+    #[test]
+    fn absent_headers() {
+        assert_eq!(ClientIdentification::from_headers(None, None), None);
+        let ua_only = ClientIdentification::from_headers(None, Some("curl/8")).unwrap();
+        assert_eq!(ua_only.user_agent.as_deref(), Some("curl/8"));
+        assert_eq!(ua_only.app, None);
+    }
+}
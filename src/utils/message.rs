@@ -2,8 +2,13 @@
 
 use crate::types::{Message, Part, Role};
 use crate::utils::parts::get_text_parts;
+use serde_json::Value;
 use uuid::Uuid;
 
+/// Message metadata key holding the JSON Schema of the input an
+/// `input-required` prompt asks for.
+pub const INPUT_SCHEMA_METADATA_KEY: &str = "inputSchema";
+
 /// Creates a new agent message containing a single text Part.
 ///
 /// # Arguments
@@ -112,6 +117,25 @@ pub fn get_message_text(message: &Message, delimiter: &str) -> String {
     get_text_parts(&message.parts).join(delimiter)
 }
 
+/// The JSON Schema an `input-required` prompt attached for the expected
+/// reply (see [`INPUT_SCHEMA_METADATA_KEY`]), if any.
+///
+/// # Example
+///
+/// ```
+/// use a2a_rs::utils::{get_input_schema, new_agent_text_message};
+/// use serde_json::json;
+///
+/// let mut prompt = new_agent_text_message("Which city?", None::<String>, None::<String>);
+/// assert!(get_input_schema(&prompt).is_none());
+///
+/// prompt.metadata = Some(json!({"inputSchema": {"type": "string"}}));
+/// assert_eq!(get_input_schema(&prompt), Some(&json!({"type": "string"})));
+/// ```
+pub fn get_input_schema(message: &Message) -> Option<&Value> {
+    message.metadata.as_ref()?.get(INPUT_SCHEMA_METADATA_KEY)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
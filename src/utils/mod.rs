@@ -4,15 +4,35 @@
 //! making it easier to work with messages, artifacts, parts, and tasks.
 
 pub mod artifact;
+pub mod card_validation;
+pub mod chunked_task;
+pub mod compatibility;
 pub mod constants;
+pub mod data_delta;
+pub mod deadline;
 pub mod extensions;
+pub mod identification;
 pub mod message;
 pub mod parts;
+pub mod request_id;
+pub mod skills;
+pub mod stream_encoding;
 pub mod task;
+pub mod version;
 
 pub use artifact::*;
+pub use card_validation::*;
+pub use chunked_task::*;
+pub use compatibility::*;
 pub use constants::*;
+pub use data_delta::*;
+pub use deadline::*;
 pub use extensions::*;
+pub use identification::*;
 pub use message::*;
 pub use parts::*;
+pub use request_id::*;
+pub use skills::*;
+pub use stream_encoding::*;
 pub use task::*;
+pub use version::*;
@@ -1,5 +1,7 @@
 //! Utility functions for working with A2A Part objects.
 
+use std::path::Path;
+
 use crate::types::{FileContent, Part};
 use serde_json::Value;
 
@@ -106,10 +108,78 @@ pub fn get_file_parts(parts: &[Part]) -> Vec<FileContent> {
         .collect()
 }
 
+/// Size of a part's content in bytes, as size limits count it: the UTF-8
+/// length of a text, the decoded length of inline file bytes, the length of
+/// a file URI, or the length of serialized data.
+///
+/// # Example
+///
+/// ```
+/// use a2a_rs::types::Part;
+/// use a2a_rs::utils::part_size;
+///
+/// assert_eq!(part_size(&Part::text("héllo")), 6);
+/// assert_eq!(part_size(&Part::file_from_raw(b"abcd", None, None)), 4);
+/// assert_eq!(part_size(&Part::data(serde_json::json!({"a": 1}))), 7);
+/// ```
+pub fn part_size(part: &Part) -> usize {
+    match part {
+        Part::Text { text, .. } => text.len(),
+        Part::File {
+            file: FileContent::Bytes(file),
+            ..
+        } => file.decoded_len(),
+        Part::File {
+            file: FileContent::Uri(file),
+            ..
+        } => file.uri.len(),
+        Part::Data { data, .. } => serde_json::to_vec(data).map_or(0, |json| json.len()),
+    }
+}
+
+/// MIME type for `path` by extension, `application/octet-stream` if unknown.
+///
+/// # Example
+///
+/// ```
+/// use std::path::Path;
+/// use a2a_rs::utils::guess_mime_type;
+///
+/// assert_eq!(guess_mime_type(Path::new("report.PDF")), "application/pdf");
+/// assert_eq!(guess_mime_type(Path::new("blob")), "application/octet-stream");
+/// ```
+pub fn guess_mime_type(path: &Path) -> &'static str {
+    let extension = path
+        .extension()
+        .map(|e| e.to_string_lossy().to_ascii_lowercase())
+        .unwrap_or_default();
+    match extension.as_str() {
+        "txt" | "log" => "text/plain",
+        "md" | "markdown" => "text/markdown",
+        "csv" => "text/csv",
+        "html" | "htm" => "text/html",
+        "xml" => "application/xml",
+        "json" => "application/json",
+        "yaml" | "yml" => "application/yaml",
+        "pdf" => "application/pdf",
+        "zip" => "application/zip",
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "svg" => "image/svg+xml",
+        "mp3" => "audio/mpeg",
+        "wav" => "audio/wav",
+        "mp4" => "video/mp4",
+        "docx" => "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+        "xlsx" => "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+        _ => "application/octet-stream",
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use serde_json::json;
 
     #[test]
     fn test_get_text_parts_empty() {
@@ -0,0 +1,92 @@
+//! Request IDs correlating the calls of a delegation chain.
+//!
+//! Every call carries an `X-A2A-Request-Id` header (`x-a2a-request-id` gRPC
+//! metadata). Servers take the caller's ID, or make one up when it's
+//! missing or malformed, and run the request with it as the
+//! [current request ID](current_request_id): it is in the
+//! `ServerCallContext` and `RequestContext`, echoed in the response, and
+//! recorded in the metadata of the task the request creates. Clients send
+//! the current request ID, so calls an agent makes to other agents while
+//! executing carry the ID of the request that started it, and logs and
+//! tasks along the whole chain share one ID.
+
+use std::future::Future;
+
+use crate::types::Task;
+
+/// HTTP header carrying the request ID.
+pub const REQUEST_ID_HEADER: &str = "X-A2A-Request-Id";
+
+/// Key of the task metadata entry holding the ID of the request that
+/// created the task.
+pub const REQUEST_ID_METADATA_KEY: &str = "requestId";
+
+/// Longest request ID accepted from a caller, in bytes.
+pub const MAX_REQUEST_ID_LEN: usize = 128;
+
+tokio::task_local! {
+    static CURRENT_REQUEST_ID: String;
+}
+
+/// A new random request ID.
+pub fn new_request_id() -> String {
+    uuid::Uuid::new_v4().to_string()
+}
+
+/// Whether `id` is acceptable as a request ID: 1 to
+/// [`MAX_REQUEST_ID_LEN`] visible ASCII characters.
+///
+/// # Example
+/// ```
+/// use a2a_rs::utils::is_valid_request_id;
+///
+/// assert!(is_valid_request_id("req-42"));
+/// assert!(!is_valid_request_id(""));
+/// assert!(!is_valid_request_id("two words"));
+/// ```
+pub fn is_valid_request_id(id: &str) -> bool {
+    !id.is_empty() && id.len() <= MAX_REQUEST_ID_LEN && id.bytes().all(|b| b.is_ascii_graphic())
+}
+
+/// The caller's request ID from a [`REQUEST_ID_HEADER`] value if it is
+/// [valid](is_valid_request_id), otherwise a new one.
+pub fn request_id_or_new(header: Option<&str>) -> String {
+    header
+        .map(str::trim)
+        .filter(|id| is_valid_request_id(id))
+        .map(String::from)
+        .unwrap_or_else(new_request_id)
+}
+
+/// Run `future` with `request_id` as the current request ID.
+///
+/// Outgoing client calls made inside the scope send it.
+pub async fn with_request_id<F: Future>(request_id: impl Into<String>, future: F) -> F::Output {
+    CURRENT_REQUEST_ID.scope(request_id.into(), future).await
+}
+
+/// The request ID of the code being run, if inside
+/// [`with_request_id()`].
+pub fn current_request_id() -> Option<String> {
+    CURRENT_REQUEST_ID.try_with(Clone::clone).ok()
+}
+
+/// The ID of the request that created `task`, as recorded by
+/// [`set_task_request_id()`].
+pub fn task_request_id(task: &Task) -> Option<&str> {
+    task.metadata
+        .as_ref()?
+        .get(REQUEST_ID_METADATA_KEY)?
+        .as_str()
+}
+
+/// Record `request_id` in `task`'s metadata. Metadata that isn't a JSON
+/// object is left alone.
+pub fn set_task_request_id(task: &mut Task, request_id: &str) {
+    let metadata = task
+        .metadata
+        .get_or_insert_with(|| serde_json::Value::Object(Default::default()));
+    if let Some(entries) = metadata.as_object_mut() {
+        entries.insert(REQUEST_ID_METADATA_KEY.to_string(), request_id.into());
+    }
+}
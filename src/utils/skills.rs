@@ -0,0 +1,174 @@
+//! Skill validation utilities.
+//!
+//! Checks a candidate message against an [`AgentSkill`] without running
+//! it — the logic behind the `skills/invoke` dry-run method. Clients that
+//! already hold the agent card can call [`validate_skill_message`] locally.
+
+use crate::error::{A2AError, A2AResult};
+use crate::types::{
+    AgentCard, AgentSkill, FileContent, Part, Role, SkillInvokeParams, SkillValidationCode,
+    SkillValidationIssue, SkillValidationResult,
+};
+
+/// JSON-RPC method name of the skill dry-run extension.
+pub const SKILLS_INVOKE_METHOD: &str = "skills/invoke";
+
+/// Find an [`AgentSkill`] in an [`AgentCard`] by ID.
+pub fn find_skill_by_id<'a>(card: &'a AgentCard, skill_id: &str) -> Option<&'a AgentSkill> {
+    card.skills.iter().find(|skill| skill.id == skill_id)
+}
+
+/// The MIME type a part is treated as for input-mode checks.
+///
+/// Text is `text/plain`, data is `application/json`, and files use their
+/// declared type (`application/octet-stream` if none).
+pub fn part_mime_type(part: &Part) -> &str {
+    match part {
+        Part::Text { .. } => "text/plain",
+        Part::Data { .. } => "application/json",
+        Part::File { file, .. } => {
+            let mime_type = match file {
+                FileContent::Bytes(f) => f.mime_type.as_deref(),
+                FileContent::Uri(f) => f.mime_type.as_deref(),
+            };
+            mime_type.unwrap_or("application/octet-stream")
+        }
+    }
+}
+
+/// Whether the MIME type `mode` (which may be `*/*` or `type/*`) covers
+/// `mime_type`. Parameters (`;charset=…`) and case are ignored.
+///
+/// # Example
+/// ```
+/// use a2a_rs::utils::mime_type_matches;
+///
+/// assert!(mime_type_matches("image/*", "image/png"));
+/// assert!(mime_type_matches("text/plain", "text/plain; charset=utf-8"));
+/// assert!(!mime_type_matches("text/plain", "application/json"));
+/// ```
+pub fn mime_type_matches(mode: &str, mime_type: &str) -> bool {
+    let essence = |s: &str| {
+        s.split(';')
+            .next()
+            .unwrap_or("")
+            .trim()
+            .to_ascii_lowercase()
+    };
+    let (mode, mime_type) = (essence(mode), essence(mime_type));
+    if mode == "*/*" || mode == mime_type {
+        return true;
+    }
+    match mode.strip_suffix("/*") {
+        Some(prefix) => mime_type.split('/').next() == Some(prefix),
+        None => false,
+    }
+}
+
+/// Validate `params.message` against skill `params.skill_id` of `card`.
+///
+/// Checks that the message is a non-empty user message, that every part's
+/// MIME type is among the skill's input modes (falling back to the card's
+/// defaults; an empty list accepts anything), that data parts are JSON
+/// objects, and that at least one of `params.accepted_output_modes` is
+/// produced by the skill.
+///
+/// # Errors
+///
+/// Returns [`A2AError::InvalidParams`] if the card has no such skill.
+pub fn validate_skill_message(
+    card: &AgentCard,
+    params: &SkillInvokeParams,
+) -> A2AResult<SkillValidationResult> {
+    let skill =
+        find_skill_by_id(card, &params.skill_id).ok_or_else(|| A2AError::InvalidParams {
+            message: format!("Unknown skill: {}", params.skill_id),
+            data: None,
+        })?;
+    let input_modes = skill
+        .input_modes
+        .as_deref()
+        .unwrap_or(&card.default_input_modes);
+    let output_modes = skill
+        .output_modes
+        .as_deref()
+        .unwrap_or(&card.default_output_modes);
+
+    let mut issues = Vec::new();
+    let message = &params.message;
+    if message.parts.is_empty() {
+        issues.push(issue(
+            SkillValidationCode::EmptyMessage,
+            "Message has no parts".to_string(),
+            None,
+        ));
+    }
+    if message.role != Role::User {
+        issues.push(issue(
+            SkillValidationCode::InvalidRole,
+            format!("Message role must be 'user', got '{}'", message.role),
+            None,
+        ));
+    }
+    for (index, part) in message.parts.iter().enumerate() {
+        let mime_type = part_mime_type(part);
+        if !input_modes.is_empty() && !input_modes.iter().any(|m| mime_type_matches(m, mime_type)) {
+            issues.push(issue(
+                SkillValidationCode::UnsupportedInputMode,
+                format!(
+                    "Skill '{}' does not accept '{}' (accepts: {})",
+                    skill.id,
+                    mime_type,
+                    input_modes.join(", ")
+                ),
+                Some(index),
+            ));
+        }
+        if let Part::Data { data, .. } = part {
+            if !data.is_object() {
+                issues.push(issue(
+                    SkillValidationCode::InvalidData,
+                    "Data part must be a JSON object".to_string(),
+                    Some(index),
+                ));
+            }
+        }
+    }
+    if let Some(accepted) = &params.accepted_output_modes {
+        let compatible = output_modes.is_empty()
+            || accepted.iter().any(|a| {
+                output_modes
+                    .iter()
+                    .any(|o| mime_type_matches(a, o) || mime_type_matches(o, a))
+            });
+        if !compatible {
+            issues.push(issue(
+                SkillValidationCode::NoCompatibleOutputMode,
+                format!(
+                    "Skill '{}' produces none of the accepted output modes (produces: {})",
+                    skill.id,
+                    output_modes.join(", ")
+                ),
+                None,
+            ));
+        }
+    }
+
+    Ok(SkillValidationResult {
+        skill_id: skill.id.clone(),
+        valid: issues.is_empty(),
+        issues,
+    })
+}
+
+fn issue(
+    code: SkillValidationCode,
+    message: String,
+    part_index: Option<usize>,
+) -> SkillValidationIssue {
+    SkillValidationIssue {
+        code,
+        message,
+        part_index,
+    }
+}
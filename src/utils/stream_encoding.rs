@@ -0,0 +1,158 @@
+//! Encodings of streaming JSON-RPC responses.
+//!
+//! Streaming methods (`message/stream`, `tasks/subscribe`) answer with one
+//! JSON-RPC response per event. By default these are Server-Sent Events;
+//! for proxies that strip or buffer SSE, servers built with this SDK can
+//! also write them as newline-delimited JSON over a chunked response. The
+//! client asks for an encoding with the `Accept` header and the server
+//! picks one with [`StreamEncoding::negotiate`].
+
+/// Content type of SSE streams.
+pub const SSE_CONTENT_TYPE: &str = "text/event-stream";
+
+/// Content type of newline-delimited JSON streams.
+pub const NDJSON_CONTENT_TYPE: &str = "application/x-ndjson";
+
+/// How the events of a streaming response are framed.
+///
+/// # Example
+/// ```
+/// use a2a_rs::utils::StreamEncoding;
+///
+/// let accept = StreamEncoding::Ndjson.accept_header();
+/// assert_eq!(StreamEncoding::negotiate(Some(accept)), StreamEncoding::Ndjson);
+/// assert_eq!(StreamEncoding::negotiate(None), StreamEncoding::Sse);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum StreamEncoding {
+    /// `text/event-stream`: each response is the `data:` of an SSE event.
+    #[default]
+    Sse,
+    /// `application/x-ndjson`: each response is one line of JSON. Empty
+    /// lines keep idle streams alive.
+    Ndjson,
+}
+
+impl StreamEncoding {
+    /// The response content type.
+    pub fn content_type(self) -> &'static str {
+        match self {
+            Self::Sse => SSE_CONTENT_TYPE,
+            Self::Ndjson => NDJSON_CONTENT_TYPE,
+        }
+    }
+
+    /// `Accept` header preferring this encoding. Asking for NDJSON still
+    /// accepts SSE, from servers without NDJSON support.
+    pub fn accept_header(self) -> &'static str {
+        match self {
+            Self::Sse => SSE_CONTENT_TYPE,
+            Self::Ndjson => "application/x-ndjson, text/event-stream;q=0.5",
+        }
+    }
+
+    /// The encoding of a response with `Content-Type: content_type`, if it
+    /// is a stream.
+    pub fn from_content_type(content_type: &str) -> Option<Self> {
+        let essence = content_type.split(';').next().unwrap_or_default().trim();
+        if essence.eq_ignore_ascii_case(SSE_CONTENT_TYPE) {
+            Some(Self::Sse)
+        } else if essence.eq_ignore_ascii_case(NDJSON_CONTENT_TYPE) {
+            Some(Self::Ndjson)
+        } else {
+            None
+        }
+    }
+
+    /// The encoding to answer a request with `Accept: accept` in.
+    ///
+    /// NDJSON is chosen only when the client ranks it above SSE (by
+    /// quality, then by order); anything else, including a missing header
+    /// or `*/*`, gets SSE.
+    pub fn negotiate(accept: Option<&str>) -> Self {
+        let Some(accept) = accept else {
+            return Self::Sse;
+        };
+        // (quality, position) of the best range matching each encoding.
+        let mut sse: Option<(f32, usize)> = None;
+        let mut ndjson: Option<(f32, usize)> = None;
+        for (position, range) in accept.split(',').enumerate() {
+            let mut params = range.split(';');
+            let media = params
+                .next()
+                .unwrap_or_default()
+                .trim()
+                .to_ascii_lowercase();
+            let quality = params
+                .filter_map(|param| param.split_once('='))
+                .find(|(name, _)| name.trim().eq_ignore_ascii_case("q"))
+                .and_then(|(_, value)| value.trim().parse::<f32>().ok())
+                .unwrap_or(1.0);
+            let slot = match media.as_str() {
+                SSE_CONTENT_TYPE | "text/*" | "*/*" => &mut sse,
+                NDJSON_CONTENT_TYPE => &mut ndjson,
+                _ => continue,
+            };
+            if slot.map_or(true, |(best, _)| quality > best) {
+                *slot = Some((quality, position));
+            }
+        }
+        match (ndjson, sse) {
+            (Some((q, _)), _) if q <= 0.0 => Self::Sse,
+            (Some(_), None) => Self::Ndjson,
+            (Some((nq, np)), Some((sq, sp))) if nq > sq || (nq == sq && np < sp) => Self::Ndjson,
+            _ => Self::Sse,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn negotiate_defaults_to_sse() {
+        assert_eq!(StreamEncoding::negotiate(None), StreamEncoding::Sse);
+        assert_eq!(StreamEncoding::negotiate(Some("*/*")), StreamEncoding::Sse);
+        assert_eq!(
+            StreamEncoding::negotiate(Some("text/event-stream")),
+            StreamEncoding::Sse
+        );
+        assert_eq!(
+            StreamEncoding::negotiate(Some("application/json")),
+            StreamEncoding::Sse
+        );
+    }
+
+    #[test]
+    fn negotiate_ranks_by_quality_then_order() {
+        let pick = |accept| StreamEncoding::negotiate(Some(accept));
+        assert_eq!(pick("application/x-ndjson"), StreamEncoding::Ndjson);
+        assert_eq!(
+            pick("application/x-ndjson, text/event-stream"),
+            StreamEncoding::Ndjson
+        );
+        assert_eq!(
+            pick("text/event-stream, application/x-ndjson"),
+            StreamEncoding::Sse
+        );
+        assert_eq!(
+            pick("text/event-stream;q=0.2, application/x-ndjson"),
+            StreamEncoding::Ndjson
+        );
+        assert_eq!(pick("application/x-ndjson;q=0"), StreamEncoding::Sse);
+    }
+
+    #[test]
+    fn content_type_ignores_parameters() {
+        assert_eq!(
+            StreamEncoding::from_content_type("application/x-ndjson; charset=utf-8"),
+            Some(StreamEncoding::Ndjson)
+        );
+        assert_eq!(
+            StreamEncoding::from_content_type("Text/Event-Stream"),
+            Some(StreamEncoding::Sse)
+        );
+        assert_eq!(StreamEncoding::from_content_type("application/json"), None);
+    }
+}
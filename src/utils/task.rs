@@ -4,6 +4,22 @@ use crate::error::{A2AError, A2AResult};
 use crate::types::{Artifact, Message, Part, Task, TaskState, TaskStatus};
 use uuid::Uuid;
 
+/// JSON-RPC method name of the context cancellation extension
+/// (`contexts/cancel`).
+pub const CONTEXTS_CANCEL_METHOD: &str = "contexts/cancel";
+
+/// JSON-RPC method name of the artifact listing extension
+/// (`tasks/artifacts/list`).
+pub const TASKS_ARTIFACTS_LIST_METHOD: &str = "tasks/artifacts/list";
+
+/// JSON-RPC method name of the artifact retrieval extension
+/// (`tasks/artifacts/get`).
+pub const TASKS_ARTIFACTS_GET_METHOD: &str = "tasks/artifacts/get";
+
+/// JSON-RPC method name of the incremental message upload extension
+/// (`message/sendStream`).
+pub const MESSAGE_SEND_STREAM_METHOD: &str = "message/sendStream";
+
 /// Creates a new Task object from an initial user message.
 ///
 /// Generates task and context IDs if not provided in the message.
@@ -70,11 +86,7 @@ pub fn new_task(request: Message) -> A2AResult<Task> {
         id: task_id,
         context_id,
         kind: "task".to_string(),
-        status: TaskStatus {
-            state: TaskState::Submitted,
-            message: None,
-            timestamp: None,
-        },
+        status: TaskStatus::new(TaskState::Submitted),
         artifacts: None,
         history: Some(vec![request]),
         metadata: None,
@@ -132,11 +144,7 @@ pub fn completed_task(
         id: task_id.into(),
         context_id: context_id.into(),
         kind: "task".to_string(),
-        status: TaskStatus {
-            state: TaskState::Completed,
-            message: None,
-            timestamp: None,
-        },
+        status: TaskStatus::new(TaskState::Completed),
         artifacts: Some(artifacts),
         history,
         metadata: None,
@@ -176,7 +184,7 @@ pub fn completed_task(
 ///     id: "task-1".to_string(),
 ///     context_id: "ctx-1".to_string(),
 ///     kind: "task".to_string(),
-///     status: TaskStatus { state: TaskState::Working, message: None, timestamp: None },
+///     status: TaskStatus::new(TaskState::Working),
 ///     artifacts: None,
 ///     history: Some(messages),
 ///     metadata: None,
@@ -185,18 +193,11 @@ pub fn completed_task(
 /// let limited_task = apply_history_length(task, Some(5));
 /// assert_eq!(limited_task.history.unwrap().len(), 5);
 /// ```
-pub fn apply_history_length(mut task: Task, history_length: Option<usize>) -> Task {
-    if let Some(length) = history_length {
-        if length > 0 {
-            if let Some(ref mut history) = task.history {
-                let total = history.len();
-                if total > length {
-                    *history = history.split_off(total - length);
-                }
-            }
-        }
+pub fn apply_history_length(task: Task, history_length: Option<usize>) -> Task {
+    match history_length {
+        Some(length) if length > 0 => task.with_history_limit(length),
+        _ => task,
     }
-    task
 }
 
 #[cfg(test)]
@@ -260,11 +261,7 @@ mod tests {
             id: "task-1".to_string(),
             context_id: "ctx-1".to_string(),
             kind: "task".to_string(),
-            status: TaskStatus {
-                state: TaskState::Working,
-                message: None,
-                timestamp: None,
-            },
+            status: TaskStatus::new(TaskState::Working),
             artifacts: None,
             history: Some(messages),
             metadata: None,
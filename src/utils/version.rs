@@ -0,0 +1,193 @@
+//! A2A protocol version pinning.
+//!
+//! This SDK implements protocol version [`PROTOCOL_VERSION`] and accepts
+//! peers within [`ProtocolVersionRange::supported`] (any `0.3.x`):
+//!
+//! - Servers advertise [`PROTOCOL_VERSION`] in the agent card and can be
+//!   configured to reject requests declaring an incompatible version, either
+//!   in the [`PROTOCOL_VERSION_HEADER`] or as a top-level
+//!   [`PROTOCOL_VERSION_PARAM`] in the JSON-RPC params.
+//! - Clients send [`PROTOCOL_VERSION_HEADER`] on every request and check the
+//!   card's `protocolVersion` with [`check_card_protocol_version`] before
+//!   talking to an agent.
+
+use std::fmt;
+
+use crate::error::{A2AError, A2AResult};
+use crate::types::AgentCard;
+
+/// A2A protocol version implemented by this SDK.
+pub const PROTOCOL_VERSION: &str = "0.3.0";
+
+/// HTTP header in which a caller declares its protocol version.
+pub const PROTOCOL_VERSION_HEADER: &str = "A2A-Version";
+
+/// JSON-RPC params key in which a caller may declare its protocol version.
+pub const PROTOCOL_VERSION_PARAM: &str = "protocolVersion";
+
+/// A parsed `major.minor.patch` protocol version.
+///
+/// Missing components default to zero and a leading `v` is ignored, so
+/// `"0.3"`, `"v0.3"` and `"0.3.0"` are equal.
+///
+/// # Example
+/// ```
+/// use a2a_rs::utils::ProtocolVersion;
+///
+/// let version = ProtocolVersion::parse("0.3").unwrap();
+/// assert_eq!(version.to_string(), "0.3.0");
+/// assert!(ProtocolVersion::parse("latest").is_none());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ProtocolVersion {
+    /// Major version.
+    pub major: u64,
+    /// Minor version.
+    pub minor: u64,
+    /// Patch version.
+    pub patch: u64,
+}
+
+impl ProtocolVersion {
+    /// Create a version from its components.
+    pub const fn new(major: u64, minor: u64, patch: u64) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+        }
+    }
+
+    /// The version implemented by this SDK ([`PROTOCOL_VERSION`]).
+    pub const fn current() -> Self {
+        Self::new(0, 3, 0)
+    }
+
+    /// Parse a version string. Returns `None` if it isn't one to three
+    /// dot-separated numbers.
+    pub fn parse(version: &str) -> Option<Self> {
+        let version = version.trim();
+        let version = version.strip_prefix('v').unwrap_or(version);
+        let mut parts = version.split('.');
+        let mut next = |required: bool| match parts.next() {
+            Some(part) => part.parse::<u64>().ok(),
+            None if !required => Some(0),
+            None => None,
+        };
+        let parsed = Self::new(next(true)?, next(false)?, next(false)?);
+        parts.next().is_none().then_some(parsed)
+    }
+}
+
+impl fmt::Display for ProtocolVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// A half-open range of protocol versions, `min <= v < max`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProtocolVersionRange {
+    /// Lowest accepted version.
+    pub min: ProtocolVersion,
+    /// First version no longer accepted.
+    pub max: ProtocolVersion,
+}
+
+impl ProtocolVersionRange {
+    /// Versions this SDK interoperates with: `>=0.3.0, <0.4.0`.
+    pub const fn supported() -> Self {
+        Self {
+            min: ProtocolVersion::new(0, 3, 0),
+            max: ProtocolVersion::new(0, 4, 0),
+        }
+    }
+
+    /// Whether `version` is within the range.
+    pub fn contains(&self, version: ProtocolVersion) -> bool {
+        self.min <= version && version < self.max
+    }
+
+    /// Parse `version` and check it is within the range.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`A2AError::InvalidRequest`] if `version` doesn't parse or is
+    /// outside the range. The error data lists the declared version and the
+    /// supported range.
+    pub fn check(&self, version: &str) -> A2AResult<ProtocolVersion> {
+        match ProtocolVersion::parse(version) {
+            Some(parsed) if self.contains(parsed) => Ok(parsed),
+            parsed => Err(A2AError::InvalidRequest {
+                message: match parsed {
+                    Some(_) => {
+                        format!("Unsupported A2A protocol version '{version}'; supported: {self}")
+                    }
+                    None => format!("Invalid A2A protocol version '{version}'"),
+                },
+                data: Some(serde_json::json!({
+                    "protocolVersion": version,
+                    "supportedVersions": self.to_string(),
+                })),
+            }),
+        }
+    }
+}
+
+impl fmt::Display for ProtocolVersionRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, ">={}, <{}", self.min, self.max)
+    }
+}
+
+/// Check that `card` declares a protocol version this SDK supports.
+///
+/// Cards without a `protocolVersion` are accepted.
+///
+/// # Errors
+///
+/// Returns [`A2AError::InvalidAgentResponse`] naming the agent, its declared
+/// version and the supported range.
+pub fn check_card_protocol_version(card: &AgentCard) -> A2AResult<()> {
+    let Some(version) = card.protocol_version.as_deref() else {
+        return Ok(());
+    };
+    let supported = ProtocolVersionRange::supported();
+    supported.check(version).map(drop).map_err(|_| {
+        A2AError::invalid_agent_response(format!(
+            "agent '{}' declares A2A protocol version '{version}', but this client \
+             supports {supported}",
+            card.name
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_forms() {
+        let v = ProtocolVersion::new(0, 3, 0);
+        assert_eq!(ProtocolVersion::parse("0.3"), Some(v));
+        assert_eq!(ProtocolVersion::parse(" v0.3.0 "), Some(v));
+        assert_eq!(
+            ProtocolVersion::parse("1"),
+            Some(ProtocolVersion::new(1, 0, 0))
+        );
+        assert_eq!(ProtocolVersion::parse("0.3.0.1"), None);
+        assert_eq!(ProtocolVersion::parse("0.x"), None);
+        assert_eq!(ProtocolVersion::parse(""), None);
+        assert_eq!(ProtocolVersion::current().to_string(), PROTOCOL_VERSION);
+    }
+
+    #[test]
+    fn supported_range() {
+        let range = ProtocolVersionRange::supported();
+        assert!(range.check("0.3").is_ok());
+        assert!(range.check("0.3.7").is_ok());
+        assert!(range.check("0.2.5").is_err());
+        assert!(range.check("1.0").is_err());
+        assert!(range.check("next").is_err());
+    }
+}
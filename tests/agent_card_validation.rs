@@ -0,0 +1,168 @@
+//! `AgentCard::validate`, `AgentCardBuilder::try_build` and
+//! `CardResolver::with_strict_validation`.
+
+use std::collections::HashMap;
+
+use a2a_rs::client::CardResolver;
+use a2a_rs::error::A2AError;
+use a2a_rs::types::*;
+use a2a_rs::utils::{validate_agent_card, CardValidationErrorKind};
+use a2a_rs::AgentCardBuilder;
+use axum::routing::get;
+use axum::Router;
+
+fn valid_card() -> AgentCard {
+    let mut card = AgentCardBuilder::new("Agent", "A valid agent", "1.0")
+        .with_jsonrpc_interface("http://localhost:7420/a2a")
+        .with_skill("search", "Search", "Searches", vec![])
+        .build();
+    card.security_schemes = Some(HashMap::from([(
+        "bearer".to_string(),
+        SecurityScheme::Http {
+            description: None,
+            scheme: "bearer".to_string(),
+            bearer_format: None,
+        },
+    )]));
+    card.security_requirements = vec![HashMap::from([("bearer".to_string(), vec![])])];
+    card
+}
+
+fn kinds(card: &AgentCard) -> Vec<(CardValidationErrorKind, String)> {
+    validate_agent_card(card)
+        .into_iter()
+        .map(|error| (error.kind, error.field))
+        .collect()
+}
+
+#[test]
+fn test_valid_card_passes() {
+    assert!(valid_card().validate().is_ok());
+}
+
+#[test]
+fn test_every_violation_is_reported() {
+    let mut card = valid_card();
+    card.name = " ".to_string();
+    card.protocol_version = Some("9.0".to_string());
+    card.documentation_url = Some("docs.example.com".to_string());
+    card.supported_interfaces.push(AgentInterface {
+        url: "http://localhost:50051".to_string(),
+        transport: "grpc".to_string(),
+        tenant: None,
+        protocol_version: Some("latest".to_string()),
+    });
+    card.skills[0].security_requirements =
+        Some(vec![HashMap::from([("oauth".to_string(), vec![])])]);
+
+    let errors = card.validate().unwrap_err();
+    assert_eq!(
+        errors
+            .iter()
+            .map(|error| (error.kind, error.field.as_str()))
+            .collect::<Vec<_>>(),
+        [
+            (CardValidationErrorKind::MissingField, "name"),
+            (
+                CardValidationErrorKind::UnsupportedProtocolVersion,
+                "protocolVersion"
+            ),
+            (
+                CardValidationErrorKind::UnknownTransport,
+                "supportedInterfaces[1].transport"
+            ),
+            (
+                CardValidationErrorKind::UnsupportedProtocolVersion,
+                "supportedInterfaces[1].protocolVersion"
+            ),
+            (CardValidationErrorKind::InvalidUrl, "documentationUrl"),
+            (
+                CardValidationErrorKind::UndeclaredSecurityScheme,
+                "skills[0].securityRequirements[0]"
+            ),
+        ]
+    );
+    assert!(errors[5].message.contains("'oauth'"));
+}
+
+#[test]
+fn test_card_without_interface() {
+    let card = AgentCardBuilder::new("Agent", "No way to reach it", "1.0").build();
+    assert_eq!(
+        kinds(&card),
+        [(
+            CardValidationErrorKind::NoInterface,
+            "supportedInterfaces".to_string()
+        )]
+    );
+
+    // A v0.3 card with only a `url` is reachable.
+    let mut card = card;
+    card.url = "http://localhost:7420/a2a".to_string();
+    assert!(kinds(&card).is_empty());
+}
+
+#[test]
+fn test_legacy_security_field_is_checked() {
+    let mut card = valid_card();
+    card.security = Some(vec![HashMap::from([("apiKey".to_string(), vec![])])]);
+    assert_eq!(
+        kinds(&card),
+        [(
+            CardValidationErrorKind::UndeclaredSecurityScheme,
+            "security[0]".to_string()
+        )]
+    );
+}
+
+#[test]
+fn test_try_build() {
+    let card = AgentCardBuilder::new("Agent", "An agent", "1.0")
+        .with_jsonrpc_interface("http://localhost:7420/a2a")
+        .try_build()
+        .unwrap();
+    assert_eq!(card.name, "Agent");
+
+    let err = AgentCardBuilder::new("Agent", "An agent", "1.0")
+        .with_jsonrpc_interface("http://localhost:7420/a2a")
+        .with_preferred_transport("SOAP")
+        .try_build()
+        .unwrap_err();
+    let A2AError::InvalidAgentCard { message, errors } = &err else {
+        panic!("expected InvalidAgentCard, got {err:?}");
+    };
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].kind, CardValidationErrorKind::UnknownTransport);
+    assert!(message.starts_with("preferredTransport: "), "{message}");
+    assert_eq!(err.code(), a2a_rs::error::INTERNAL_ERROR);
+}
+
+#[tokio::test]
+async fn test_strict_resolver_rejects_invalid_cards() {
+    let card = AgentCardBuilder::new("Agent", "An agent", "1.0")
+        .with_jsonrpc_interface("localhost:7420/a2a")
+        .build();
+    let app = Router::new().route(
+        "/.well-known/agent-card.json",
+        get(move || async move { axum::Json(card) }),
+    );
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+    let card = CardResolver::new().resolve(&base_url).await.unwrap();
+    assert_eq!(card.url, "localhost:7420/a2a");
+
+    let err = CardResolver::new()
+        .with_strict_validation(true)
+        .resolve(&base_url)
+        .await
+        .unwrap_err();
+    let A2AError::InvalidAgentCard { errors, .. } = err else {
+        panic!("expected InvalidAgentCard, got {err:?}");
+    };
+    assert_eq!(
+        errors.iter().map(|e| e.field.as_str()).collect::<Vec<_>>(),
+        ["url", "supportedInterfaces[0].url"]
+    );
+}
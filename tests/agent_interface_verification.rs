@@ -213,3 +213,25 @@ fn agent_interface_in_agent_card_context() {
 
     println!("=== AgentCard verification PASSED ===\n");
 }
+
+#[test]
+fn protocol_binding_input_is_written_back_as_transport() {
+    let input = serde_json::json!({
+        "url": "http://localhost:7420/a2a",
+        "protocolBinding": "GRPC",
+        "protocolVersion": "0.3"
+    });
+
+    let interface: AgentInterface = serde_json::from_value(input).unwrap();
+    assert_eq!(interface.transport, "GRPC");
+
+    let json = serde_json::to_value(&interface).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "url": "http://localhost:7420/a2a",
+            "transport": "GRPC",
+            "protocolVersion": "0.3"
+        })
+    );
+}
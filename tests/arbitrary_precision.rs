@@ -0,0 +1,131 @@
+//! Numeric fidelity of metadata and data parts with the
+//! `arbitrary-precision` feature: values beyond `f64` / `u64` are relayed
+//! byte-for-byte through serde, the server and the client.
+#![cfg(feature = "arbitrary-precision")]
+
+use std::sync::Arc;
+
+use a2a_rs::client::A2AClient;
+use a2a_rs::error::A2AResult;
+use a2a_rs::server::{AgentExecutor, EventQueue, RequestContext, TaskUpdater};
+use a2a_rs::types::*;
+use async_trait::async_trait;
+
+mod common;
+
+/// Numbers that `f64` (and `u64`) cannot represent exactly, in serde_json's
+/// output form (sorted keys, explicit exponent sign) so bytes compare equal.
+const PRECISE: &str = r#"{"big":12345678901234567890123,"dec":0.10000000000000000001,"exp":1.5e+400,"neg":-98765432109876543210}"#;
+
+fn precise() -> serde_json::Value {
+    serde_json::from_str(PRECISE).unwrap()
+}
+
+/// Relays the incoming message's data part and metadata into an artifact.
+struct RelayAgent;
+
+#[async_trait]
+impl AgentExecutor for RelayAgent {
+    async fn execute(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        let message = context.message.clone().expect("message");
+        let updater = TaskUpdater::new(event_queue, context.task_id, context.context_id);
+        updater
+            .add_artifact(
+                message.parts,
+                Some("relay".to_string()),
+                None,
+                message.metadata,
+                None,
+                Some(true),
+                None,
+            )
+            .await?;
+        updater.complete(None).await
+    }
+
+    async fn cancel(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        TaskUpdater::new(event_queue, context.task_id, context.context_id)
+            .cancel(None)
+            .await
+    }
+}
+
+fn params() -> SendMessageParams {
+    let mut message = Message::user("m1", "");
+    message.parts = vec![Part::data(precise())];
+    message.metadata = Some(precise());
+    SendMessageParams {
+        message,
+        configuration: None,
+        metadata: None,
+        tenant: None,
+    }
+}
+
+fn assert_relayed(artifact: &Artifact) {
+    let Part::Data { data, .. } = &artifact.parts[0] else {
+        panic!("expected data part");
+    };
+    assert_eq!(serde_json::to_string(data).unwrap(), PRECISE);
+    let metadata = serde_json::to_string(artifact.metadata.as_ref().unwrap()).unwrap();
+    assert_eq!(metadata, PRECISE);
+}
+
+#[test]
+fn test_types_roundtrip_exactly() {
+    let json = format!(r#"{{"kind":"data","data":{PRECISE},"metadata":{PRECISE}}}"#);
+    let part: Part = serde_json::from_str(&json).unwrap();
+    assert_eq!(serde_json::to_string(&part).unwrap(), json);
+
+    let json = format!(
+        r#"{{"kind":"task","id":"t1","contextId":"c1","status":{{"state":"completed"}},"metadata":{PRECISE}}}"#
+    );
+    let task: Task = serde_json::from_str(&json).unwrap();
+    assert!(serde_json::to_string(&task).unwrap().contains(PRECISE));
+}
+
+#[tokio::test]
+async fn test_send_relays_exactly() {
+    let (url, _handle) = common::start_test_server(Arc::new(RelayAgent)).await;
+    let client = A2AClient::from_endpoint(&format!("{url}/a2a"));
+
+    let SendMessageResponse::Task(task) = client.send_message(params()).await.unwrap() else {
+        panic!("expected task");
+    };
+    assert_relayed(&task.artifacts.unwrap()[0]);
+}
+
+#[tokio::test]
+async fn test_stream_relays_exactly() {
+    let (url, _handle) = common::start_test_server(Arc::new(RelayAgent)).await;
+    let client = A2AClient::from_endpoint(&format!("{url}/a2a"));
+
+    let mut stream = client.send_message_stream(params()).await.unwrap();
+    let mut relayed = 0;
+    while let Some(event) = stream.next().await {
+        if let StreamResponse::ArtifactUpdate(update) = event.unwrap() {
+            assert_relayed(&update.artifact);
+            relayed += 1;
+        }
+    }
+    assert_eq!(relayed, 1);
+}
+
+#[tokio::test]
+async fn test_raw_wire_bytes_preserved() {
+    let (url, _handle) = common::start_test_server(Arc::new(RelayAgent)).await;
+    let body = format!(
+        r#"{{"jsonrpc":"2.0","id":1,"method":"message/send","params":{{"message":{{"kind":"message","messageId":"m1","role":"user","parts":[{{"kind":"data","data":{PRECISE}}}]}}}}}}"#
+    );
+    let response = reqwest::Client::new()
+        .post(format!("{url}/a2a"))
+        .header("content-type", "application/json")
+        .body(body)
+        .send()
+        .await
+        .unwrap()
+        .text()
+        .await
+        .unwrap();
+    assert!(response.contains(PRECISE), "{response}");
+}
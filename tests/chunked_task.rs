@@ -0,0 +1,173 @@
+//! Chunked `tasks/get` responses for tasks above the size threshold.
+
+mod common;
+
+use std::sync::Arc;
+
+use a2a_rs::builders::{AgentCardBuilder, ClientBuilder};
+use a2a_rs::error::A2AError;
+use a2a_rs::server::{InMemoryTaskStore, RouterConfig, TaskStore};
+use a2a_rs::types::*;
+use a2a_rs::utils::{
+    assemble_task, new_task, split_task, TaskSection, CHUNKED_TASK_CONTENT_TYPE,
+    CHUNKED_TASK_EXTENSION_URI, HTTP_EXTENSION_HEADER,
+};
+use common::{start_test_server_with_config, EchoAgent};
+use serde_json::{json, Value};
+
+/// A task with 120 history messages and 3 artifacts.
+fn big_task() -> Task {
+    let mut task = new_task(Message::user("m0", "start")).unwrap();
+    task.id = "big".to_string();
+    task.context_id = "archive".to_string();
+    task.history = Some(
+        (0..120)
+            .map(|i| Message::user(format!("m{i}"), format!("message number {i}")))
+            .collect(),
+    );
+    task.artifacts = Some(
+        (0..3)
+            .map(|i| Artifact {
+                artifact_id: format!("a{i}"),
+                name: Some(format!("artifact {i}")),
+                description: None,
+                parts: vec![Part::text("x".repeat(500))],
+                metadata: None,
+                extensions: None,
+            })
+            .collect(),
+    );
+    task
+}
+
+/// Serve a store holding [`big_task()`] and a one-message task `small`,
+/// chunking above 2 KB; returns the endpoint URL.
+async fn start() -> String {
+    let store = Arc::new(InMemoryTaskStore::new());
+    store.save(big_task()).await.unwrap();
+    let mut small = new_task(Message::user("s0", "hi")).unwrap();
+    small.id = "small".to_string();
+    store.save(small).await.unwrap();
+
+    let card = |url: &str| {
+        AgentCardBuilder::new("Archive", "Holds big tasks", "1.0.0")
+            .with_jsonrpc_interface(url)
+            .with_extension(CHUNKED_TASK_EXTENSION_URI, None, false)
+            .build()
+    };
+    let config = RouterConfig::default().with_chunked_task_threshold(2048);
+    let (base_url, _server) =
+        start_test_server_with_config(Arc::new(EchoAgent), store, card, config).await;
+    format!("{base_url}/a2a")
+}
+
+/// Raw `tasks/get` for `id`: content type and body lines.
+async fn raw_get(url: &str, id: &str, extensions: &str) -> (String, Vec<Value>) {
+    let response = reqwest::Client::new()
+        .post(url)
+        .header(HTTP_EXTENSION_HEADER, extensions)
+        .json(&common::jsonrpc_request(
+            json!(1),
+            "tasks/get",
+            json!({"id": id}),
+        ))
+        .send()
+        .await
+        .unwrap();
+    let content_type = response.headers()["content-type"]
+        .to_str()
+        .unwrap()
+        .to_string();
+    let body = response.text().await.unwrap();
+    let lines = body
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    (content_type, lines)
+}
+
+#[tokio::test]
+async fn test_large_task_is_chunked() {
+    let url = start().await;
+    let (content_type, lines) = raw_get(&url, "big", CHUNKED_TASK_EXTENSION_URI).await;
+
+    assert_eq!(content_type, CHUNKED_TASK_CONTENT_TYPE);
+    // Head, three history pages of up to 50 messages, three artifacts.
+    let sections: Vec<_> = lines
+        .iter()
+        .map(|line| line["result"]["section"].as_str().unwrap())
+        .collect();
+    assert_eq!(
+        sections,
+        ["head", "history", "history", "history", "artifact", "artifact", "artifact"]
+    );
+    assert!(lines.iter().all(|line| line["id"] == 1));
+    assert_eq!(lines[0]["result"]["historyCount"], 120);
+    assert_eq!(lines[3]["result"]["offset"], 100);
+}
+
+#[tokio::test]
+async fn test_chunking_needs_request_and_size() {
+    let url = start().await;
+
+    let (content_type, lines) = raw_get(&url, "big", "").await;
+    assert!(
+        content_type.starts_with("application/json"),
+        "{content_type}"
+    );
+    assert_eq!(lines[0]["result"]["history"].as_array().unwrap().len(), 120);
+
+    let (content_type, _) = raw_get(&url, "small", CHUNKED_TASK_EXTENSION_URI).await;
+    assert!(
+        content_type.starts_with("application/json"),
+        "{content_type}"
+    );
+}
+
+#[tokio::test]
+async fn test_client_assembles_chunked_task() {
+    let url = start().await;
+    let client = ClientBuilder::new(&url)
+        .with_chunked_tasks()
+        .build_from_endpoint();
+
+    let task = client.get_task_by_id("big", None).await.unwrap();
+    assert_eq!(
+        serde_json::to_value(&task).unwrap(),
+        serde_json::to_value(big_task()).unwrap()
+    );
+
+    let err = client.get_task_by_id("missing", None).await.unwrap_err();
+    assert!(
+        matches!(err, A2AError::JsonRpc { code: -32001, .. }),
+        "{err:?}"
+    );
+}
+
+#[test]
+fn test_assemble_rejects_broken_sections() {
+    let sections = split_task(&big_task(), 50);
+
+    let err = assemble_task(sections[1..].to_vec()).unwrap_err();
+    assert!(err.to_string().contains("head"), "{err}");
+
+    let mut reordered = sections.clone();
+    reordered.swap(1, 2);
+    let err = assemble_task(reordered).unwrap_err();
+    assert!(err.to_string().contains("offset 50"), "{err}");
+
+    let mut truncated = sections.clone();
+    truncated.pop();
+    let err = assemble_task(truncated).unwrap_err();
+    assert!(err.to_string().contains("2 of 3 artifacts"), "{err}");
+
+    let task = assemble_task(sections).unwrap();
+    assert_eq!(task.history.unwrap().len(), 120);
+    assert!(matches!(
+        split_task(&new_task(Message::user("m", "hi")).unwrap(), 50)[0],
+        TaskSection::Head {
+            artifact_count: None,
+            ..
+        }
+    ));
+}
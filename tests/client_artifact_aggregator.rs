@@ -0,0 +1,242 @@
+//! `ArtifactAggregator`: interleaved artifact chunks, missing `lastChunk`
+//! and duplicate chunks.
+
+mod common;
+
+use std::sync::{Arc, Mutex};
+
+use a2a_rs::client::{A2AClient, ArtifactAggregator, ArtifactStatus};
+use a2a_rs::error::A2AResult;
+use a2a_rs::server::{AgentExecutor, EventQueue, RequestContext, TaskUpdater};
+use a2a_rs::types::*;
+use a2a_rs::utils::get_artifact_text;
+use async_trait::async_trait;
+
+fn chunk(artifact_id: &str, text: &str, append: bool, last: bool) -> StreamResponse {
+    StreamResponse::ArtifactUpdate(TaskArtifactUpdateEvent {
+        task_id: "t1".to_string(),
+        context_id: "c1".to_string(),
+        kind: "artifact-update".to_string(),
+        artifact: Artifact {
+            artifact_id: artifact_id.to_string(),
+            name: Some(format!("{artifact_id}.txt")),
+            description: None,
+            parts: vec![Part::text(text)],
+            metadata: None,
+            extensions: None,
+        },
+        append: Some(append),
+        last_chunk: Some(last),
+        metadata: None,
+    })
+}
+
+fn final_status() -> StreamResponse {
+    StreamResponse::StatusUpdate(TaskStatusUpdateEvent {
+        task_id: "t1".to_string(),
+        context_id: "c1".to_string(),
+        kind: "status-update".to_string(),
+        status: TaskStatus::new(TaskState::Completed),
+        r#final: true,
+        metadata: None,
+    })
+}
+
+type Completions = Arc<Mutex<Vec<(String, ArtifactStatus, String)>>>;
+
+fn recording_aggregator() -> (ArtifactAggregator, Completions) {
+    let completions: Completions = Arc::default();
+    let sink = completions.clone();
+    let aggregator = ArtifactAggregator::new().on_complete(move |artifact, status| {
+        sink.lock().unwrap().push((
+            artifact.artifact_id.clone(),
+            status,
+            get_artifact_text(artifact, ""),
+        ));
+    });
+    (aggregator, completions)
+}
+
+fn text(aggregator: &ArtifactAggregator, artifact_id: &str) -> String {
+    get_artifact_text(aggregator.get(artifact_id).unwrap(), "")
+}
+
+#[test]
+fn test_interleaved_chunks_are_reassembled_per_artifact() {
+    let (mut aggregator, completions) = recording_aggregator();
+    for event in [
+        chunk("a", "a1 ", false, false),
+        chunk("b", "b1 ", false, false),
+        chunk("a", "a2 ", true, false),
+        chunk("c", "c1", false, true),
+        chunk("b", "b2 ", true, false),
+        chunk("a", "a3", true, true),
+        chunk("b", "b3", true, true),
+    ] {
+        aggregator.push(&event);
+    }
+
+    assert_eq!(text(&aggregator, "a"), "a1 a2 a3");
+    assert_eq!(text(&aggregator, "b"), "b1 b2 b3");
+    assert_eq!(text(&aggregator, "c"), "c1");
+    assert!(aggregator.is_complete());
+    assert_eq!(
+        *completions.lock().unwrap(),
+        [
+            ("c".to_string(), ArtifactStatus::Complete, "c1".to_string()),
+            (
+                "a".to_string(),
+                ArtifactStatus::Complete,
+                "a1 a2 a3".to_string()
+            ),
+            (
+                "b".to_string(),
+                ArtifactStatus::Complete,
+                "b1 b2 b3".to_string()
+            ),
+        ]
+    );
+    let ids: Vec<_> = aggregator
+        .into_artifacts()
+        .into_iter()
+        .map(|a| a.artifact_id)
+        .collect();
+    assert_eq!(ids, ["a", "b", "c"], "order of first chunk");
+}
+
+#[test]
+fn test_missing_last_chunk_is_incomplete_at_stream_end() {
+    let (mut aggregator, completions) = recording_aggregator();
+    aggregator.push(&chunk("a", "a1 ", false, false));
+    aggregator.push(&chunk("b", "b1", false, true));
+    aggregator.push(&chunk("a", "a2", true, false));
+    assert_eq!(aggregator.status("a"), Some(ArtifactStatus::Streaming));
+    assert!(!aggregator.is_complete());
+
+    aggregator.push(&final_status());
+    assert_eq!(aggregator.status("a"), Some(ArtifactStatus::Incomplete));
+    assert_eq!(aggregator.status("b"), Some(ArtifactStatus::Complete));
+    assert_eq!(text(&aggregator, "a"), "a1 a2");
+    assert_eq!(
+        completions.lock().unwrap().last().unwrap(),
+        &(
+            "a".to_string(),
+            ArtifactStatus::Incomplete,
+            "a1 a2".to_string()
+        )
+    );
+
+    // Ending again reports nothing new.
+    aggregator.finish();
+    assert_eq!(completions.lock().unwrap().len(), 2);
+}
+
+#[test]
+fn test_duplicate_chunks_after_completion_are_dropped() {
+    let (mut aggregator, completions) = recording_aggregator();
+    aggregator.push(&chunk("a", "x", false, false));
+    aggregator.push(&chunk("a", "y", true, true));
+
+    // A replayed tail after completion.
+    let StreamResponse::ArtifactUpdate(late) = chunk("a", "y", true, true) else {
+        unreachable!()
+    };
+    assert!(!aggregator.push_chunk(&late));
+    let StreamResponse::ArtifactUpdate(late_append) = chunk("a", "z", true, false) else {
+        unreachable!()
+    };
+    assert!(!aggregator.push_chunk(&late_append));
+    assert_eq!(text(&aggregator, "a"), "xy");
+    assert_eq!(completions.lock().unwrap().len(), 1);
+
+    // A new first chunk restarts the artifact.
+    aggregator.push(&chunk("a", "fresh", false, true));
+    assert_eq!(text(&aggregator, "a"), "fresh");
+    assert_eq!(completions.lock().unwrap().len(), 2);
+}
+
+#[test]
+fn test_repeated_chunks_kept_unless_enabled() {
+    let events = [
+        chunk("a", "very ", false, false),
+        chunk("a", "very ", true, false),
+        chunk("a", "good", true, true),
+    ];
+
+    let mut keep = ArtifactAggregator::new();
+    events.iter().for_each(|e| keep.push(e));
+    assert_eq!(text(&keep, "a"), "very very good");
+
+    let mut dedup = ArtifactAggregator::new().drop_repeated_chunks();
+    events.iter().for_each(|e| dedup.push(e));
+    dedup.push(&events[1]);
+    assert_eq!(text(&dedup, "a"), "very very good");
+
+    let mut redelivered = ArtifactAggregator::new().drop_repeated_chunks();
+    for event in [&events[0], &events[1], &events[1], &events[2]] {
+        redelivered.push(event);
+    }
+    assert_eq!(text(&redelivered, "a"), "very very good");
+}
+
+#[test]
+fn test_append_without_first_chunk_starts_artifact() {
+    let mut aggregator = ArtifactAggregator::new();
+    aggregator.push(&chunk("a", "tail", true, true));
+    assert_eq!(text(&aggregator, "a"), "tail");
+    assert_eq!(aggregator.status("a"), Some(ArtifactStatus::Complete));
+    assert!(aggregator.get("missing").is_none());
+}
+
+/// Streams two artifacts with interleaved chunks.
+struct InterleavingAgent;
+
+#[async_trait]
+impl AgentExecutor for InterleavingAgent {
+    async fn execute(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        let updater = TaskUpdater::new(event_queue, context.task_id, context.context_id);
+        let chunks = [
+            ("left", "L1", false, false),
+            ("right", "R1", false, false),
+            ("left", "L2", true, true),
+            ("right", "R2", true, true),
+        ];
+        for (id, text, append, last) in chunks {
+            updater
+                .add_artifact(
+                    vec![Part::text(text)],
+                    Some(id.to_string()),
+                    None,
+                    None,
+                    Some(append),
+                    Some(last),
+                    None,
+                )
+                .await?;
+        }
+        updater.complete(None).await
+    }
+
+    async fn cancel(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        TaskUpdater::new(event_queue, context.task_id, context.context_id)
+            .cancel(None)
+            .await
+    }
+}
+
+#[tokio::test]
+async fn test_aggregates_a_live_stream() {
+    let (base_url, _handle) = common::start_test_server(Arc::new(InterleavingAgent)).await;
+    let client = A2AClient::from_endpoint(&format!("{base_url}/a2a"));
+    let (mut aggregator, completions) = recording_aggregator();
+
+    let mut stream = client.send_text_stream("go").await.unwrap();
+    while let Some(event) = stream.next().await {
+        aggregator.push(&event.unwrap());
+    }
+
+    assert!(aggregator.is_complete());
+    assert_eq!(text(&aggregator, "left"), "L1L2");
+    assert_eq!(text(&aggregator, "right"), "R1R2");
+    assert_eq!(completions.lock().unwrap().len(), 2);
+}
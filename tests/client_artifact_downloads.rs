@@ -0,0 +1,193 @@
+//! `ArtifactDownloader`: fetching URI file parts into memory and to disk,
+//! with size limits, MIME type checks and credentials scoped to the
+//! agent's origin.
+
+use std::sync::{Arc, Mutex};
+
+use a2a_rs::client::ArtifactDownloader;
+use a2a_rs::error::A2AError;
+use a2a_rs::types::*;
+use a2a_rs::ClientBuilder;
+use axum::http::{header, HeaderMap};
+use axum::routing::get;
+use axum::Router;
+
+/// A file server recording the `Authorization` header of each request.
+async fn start(seen: Arc<Mutex<Vec<Option<String>>>>) -> String {
+    let record = move |headers: HeaderMap| {
+        seen.lock().unwrap().push(
+            headers
+                .get(header::AUTHORIZATION)
+                .map(|v| v.to_str().unwrap().to_string()),
+        );
+    };
+    let (r1, r2) = (record.clone(), record);
+    let app = Router::new()
+        .route(
+            "/files/report.txt",
+            get(move |headers: HeaderMap| async move {
+                r1(headers);
+                (
+                    [(header::CONTENT_TYPE, "text/plain; charset=utf-8")],
+                    "quarterly report",
+                )
+            }),
+        )
+        .route(
+            "/files/image.png",
+            get(move |headers: HeaderMap| async move {
+                r2(headers);
+                ([(header::CONTENT_TYPE, "text/html")], "<html>login</html>")
+            }),
+        );
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+    base_url
+}
+
+fn uri_file(uri: String, name: Option<&str>, mime_type: &str) -> FileContent {
+    FileContent::Uri(FileWithUri {
+        uri,
+        mime_type: Some(mime_type.to_string()),
+        name: name.map(str::to_string),
+    })
+}
+
+fn task_with_files(parts: Vec<Part>) -> Task {
+    Task {
+        id: "t1".to_string(),
+        context_id: "c1".to_string(),
+        kind: "task".to_string(),
+        status: TaskStatus::new(TaskState::Completed),
+        artifacts: Some(vec![Artifact {
+            artifact_id: "a1".to_string(),
+            name: None,
+            description: None,
+            parts,
+            metadata: None,
+            extensions: None,
+        }]),
+        history: None,
+        metadata: None,
+    }
+}
+
+#[tokio::test]
+async fn test_download_into_memory() {
+    let seen = Arc::default();
+    let base = start(Arc::clone(&seen)).await;
+    let downloader = ArtifactDownloader::new().with_header("Authorization", "Bearer secret");
+
+    let file = downloader
+        .download(&uri_file(
+            format!("{base}/files/report.txt"),
+            Some("report.txt"),
+            "text/plain",
+        ))
+        .await
+        .unwrap();
+    assert_eq!(file.decode().unwrap(), b"quarterly report");
+    assert_eq!(file.name.as_deref(), Some("report.txt"));
+    assert_eq!(file.mime_type.as_deref(), Some("text/plain"));
+    assert_eq!(
+        seen.lock().unwrap().as_slice(),
+        [Some("Bearer secret".to_string())]
+    );
+
+    let err = downloader
+        .download(&uri_file(
+            format!("{base}/files/image.png"),
+            None,
+            "image/*",
+        ))
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(err, A2AError::InvalidAgentResponse { .. }),
+        "{err}"
+    );
+
+    let err = downloader
+        .clone()
+        .with_max_size(4)
+        .download(&uri_file(
+            format!("{base}/files/report.txt"),
+            None,
+            "text/plain",
+        ))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, A2AError::PayloadTooLarge { .. }), "{err}");
+
+    let err = downloader
+        .download(&uri_file(format!("{base}/missing"), None, "text/plain"))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, A2AError::Http { status: 404, .. }), "{err}");
+}
+
+#[tokio::test]
+async fn test_download_all_artifacts_to_disk() {
+    let base = start(Arc::default()).await;
+    let task = task_with_files(vec![
+        Part::text("see attached"),
+        Part::file_from_uri(
+            format!("{base}/files/report.txt"),
+            Some("../report.txt".to_string()),
+            None,
+        ),
+        Part::file_from_raw(b"inline", Some("report.txt".to_string()), None),
+        Part::file_from_raw(b"unnamed", None, None),
+    ]);
+    let dir = std::env::temp_dir().join(format!("a2a-downloads-{}", uuid::Uuid::new_v4()));
+
+    let paths = task.download_all_artifacts(&dir).await.unwrap();
+    let names: Vec<_> = paths
+        .iter()
+        .map(|path| path.strip_prefix(&dir).unwrap().to_str().unwrap())
+        .collect();
+    assert_eq!(names, ["report.txt", "a1-2-report.txt", "a1-3"]);
+    assert_eq!(std::fs::read(&paths[0]).unwrap(), b"quarterly report");
+    assert_eq!(std::fs::read(&paths[1]).unwrap(), b"inline");
+    assert_eq!(std::fs::read(&paths[2]).unwrap(), b"unnamed");
+
+    // A failed download leaves no partial file behind.
+    let target = dir.join("image.png");
+    let err = ArtifactDownloader::new()
+        .download_to(
+            &uri_file(format!("{base}/files/image.png"), None, "image/png"),
+            &target,
+        )
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(err, A2AError::InvalidAgentResponse { .. }),
+        "{err}"
+    );
+    assert!(!target.exists());
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn test_client_credentials_stay_on_the_agent_origin() {
+    let seen = Arc::default();
+    let base = start(Arc::clone(&seen)).await;
+    let client = ClientBuilder::new(format!("{base}/a2a"))
+        .with_bearer_token("agent-token")
+        .build_from_endpoint();
+    let file = uri_file(format!("{base}/files/report.txt"), None, "text/plain");
+
+    client.artifact_downloader().download(&file).await.unwrap();
+
+    // The same file served from another origin gets no credentials.
+    let other = base.replace("127.0.0.1", "localhost");
+    let file = uri_file(format!("{other}/files/report.txt"), None, "text/plain");
+    client.artifact_downloader().download(&file).await.unwrap();
+
+    assert_eq!(
+        seen.lock().unwrap().as_slice(),
+        [Some("Bearer agent-token".to_string()), None]
+    );
+}
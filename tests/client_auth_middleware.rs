@@ -1,19 +1,23 @@
 //! Port of Python SDK tests/client/test_auth_middleware.py
 //!
-//! Tests for authentication middleware / interceptor behavior.
+//! Tests for authentication middleware / interceptor behavior: the
+//! SecurityScheme types, and the `AuthProvider`s an `Authenticator` selects
+//! from an agent card's security schemes (Python's `AuthInterceptor`).
 //!
-//! Python has AuthInterceptor, InMemoryContextCredentialStore, and various
-//! security schemes (APIKey, OAuth2, OIDC, Bearer). The Rust SDK doesn't
-//! have an interceptor/middleware system yet — these tests verify that the
-//! types used in auth (SecurityScheme, AgentCard.security) serialize correctly.
-//!
-//! Skipped tests (require interceptor system not in Rust SDK):
-//! - test_auth_interceptor_skips_when_no_agent_card
+//! Skipped tests (no per-context credential store in the Rust SDK):
 //! - test_in_memory_context_credential_store
-//! - test_client_with_simple_interceptor
-//! - test_auth_interceptor_variants (all 4 scheme types)
-//! - test_auth_interceptor_skips_when_scheme_not_in_security_schemes
 
+mod common;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use a2a_rs::builders::ClientBuilder;
+use a2a_rs::client::{
+    ApiKeyAuth, AuthProvider, Authenticator, BearerAuth, CredentialService, OAuth2ClientCredentials,
+};
+use a2a_rs::server::{a2a_router, DefaultRequestHandler, InMemoryTaskStore};
 use a2a_rs::types::*;
 
 // ============================================================================
@@ -190,10 +194,286 @@ fn test_api_key_location_variants() {
     ];
 
     for (location, expected) in cases {
-        let json = serde_json::to_value(&location).unwrap();
+        let json = serde_json::to_value(location).unwrap();
         assert_eq!(json.as_str().unwrap(), expected);
 
         let decoded: ApiKeyLocation = serde_json::from_value(json).unwrap();
         assert_eq!(decoded, location);
     }
 }
+
+// ============================================================================
+// Auth providers and scheme selection
+// ============================================================================
+
+fn api_key_scheme(name: &str, location: ApiKeyLocation) -> SecurityScheme {
+    SecurityScheme::ApiKey {
+        description: None,
+        location,
+        name: name.to_string(),
+    }
+}
+
+fn bearer_scheme() -> SecurityScheme {
+    SecurityScheme::Http {
+        description: None,
+        scheme: "Bearer".to_string(),
+        bearer_format: None,
+    }
+}
+
+fn client_credentials_scheme(token_url: &str) -> SecurityScheme {
+    SecurityScheme::OAuth2 {
+        description: None,
+        flows: OAuthFlows {
+            client_credentials: Some(ClientCredentialsOAuthFlow {
+                token_url: token_url.to_string(),
+                refresh_url: None,
+                scopes: [("read".to_string(), "Read".to_string())].into(),
+            }),
+            ..Default::default()
+        },
+        oauth2_metadata_url: None,
+    }
+}
+
+fn secured_card(url: &str, schemes: Vec<(&str, SecurityScheme)>) -> AgentCard {
+    let mut card = common::test_agent_card(url);
+    card.security_schemes = Some(
+        schemes
+            .into_iter()
+            .map(|(name, scheme)| (name.to_string(), scheme))
+            .collect(),
+    );
+    card
+}
+
+#[tokio::test]
+async fn test_auth_interceptor_variants() {
+    let card = secured_card(
+        "http://agent.com/rpc",
+        vec![
+            (
+                "header",
+                api_key_scheme("X-Agent-Key", ApiKeyLocation::Header),
+            ),
+            ("cookie", api_key_scheme("session", ApiKeyLocation::Cookie)),
+            ("bearer", bearer_scheme()),
+        ],
+    );
+    // (scheme name, provider, expected header and value)
+    let cases: Vec<(&str, Arc<dyn AuthProvider>, [&str; 2])> = vec![
+        (
+            "header",
+            Arc::new(ApiKeyAuth::new("k1")),
+            ["X-Agent-Key", "k1"],
+        ),
+        (
+            "cookie",
+            Arc::new(ApiKeyAuth::new("k2")),
+            ["Cookie", "session=k2"],
+        ),
+        (
+            "bearer",
+            Arc::new(BearerAuth::new("t1")),
+            ["Authorization", "Bearer t1"],
+        ),
+    ];
+    for (name, provider, [header, value]) in cases {
+        let mut card = card.clone();
+        card.security_requirements = vec![[(name.to_string(), vec![])].into()];
+        let auth = Authenticator::for_card(&card, vec![provider]).unwrap();
+        assert_eq!(auth.scheme_name(), Some(name));
+        let credential = auth.get_credential().await.unwrap();
+        assert_eq!(
+            (credential.header.as_str(), credential.value.as_str()),
+            (header, value)
+        );
+    }
+}
+
+#[test]
+fn test_auth_interceptor_follows_requirement_order() {
+    let mut card = secured_card(
+        "http://agent.com/rpc",
+        vec![
+            (
+                "apikey",
+                api_key_scheme("X-API-Key", ApiKeyLocation::Header),
+            ),
+            (
+                "oauth",
+                client_credentials_scheme("http://auth.example/token"),
+            ),
+        ],
+    );
+    card.security = Some(vec![
+        [
+            ("apikey".to_string(), vec![]),
+            ("oauth".to_string(), vec![]),
+        ]
+        .into(),
+        [("oauth".to_string(), vec!["read".to_string()])].into(),
+        [("apikey".to_string(), vec![])].into(),
+    ]);
+    let providers: Vec<Arc<dyn AuthProvider>> = vec![
+        Arc::new(ApiKeyAuth::new("key")),
+        Arc::new(OAuth2ClientCredentials::new("id", "secret")),
+    ];
+
+    // The combined requirement is skipped; `oauth` comes before `apikey`.
+    let auth = Authenticator::for_card(&card, providers).unwrap();
+    assert_eq!(auth.scheme_name(), Some("oauth"));
+    assert_eq!(auth.scopes(), ["read"]);
+}
+
+#[tokio::test]
+async fn test_auth_interceptor_skips_when_scheme_not_in_security_schemes() {
+    let mut card = secured_card(
+        "http://agent.com/rpc",
+        vec![("query", api_key_scheme("key", ApiKeyLocation::Query))],
+    );
+    card.security = Some(vec![[("undeclared".to_string(), vec![])].into()]);
+    let err = Authenticator::for_card(&card, vec![Arc::new(ApiKeyAuth::new("key"))]).unwrap_err();
+    assert!(err.to_string().contains("query"), "{err}");
+
+    // A card without schemes gets the provider's default header.
+    let card = common::test_agent_card("http://agent.com/rpc");
+    let auth = Authenticator::for_card(&card, vec![Arc::new(ApiKeyAuth::new("key"))]).unwrap();
+    assert_eq!(auth.scheme_name(), None);
+    assert_eq!(auth.get_credential().await.unwrap().header, "X-API-Key");
+}
+
+/// Token endpoint issuing `tok-1`, `tok-2`, … valid for `expires_in`
+/// seconds; records each request's (Authorization, body).
+async fn start_token_server(expires_in: u64) -> (String, Arc<Mutex<Vec<(String, String)>>>) {
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let issued = Arc::new(AtomicUsize::new(0));
+    let sink = seen.clone();
+    let app = axum::Router::new().route(
+        "/token",
+        axum::routing::post(move |headers: axum::http::HeaderMap, body: String| {
+            let auth = headers
+                .get("authorization")
+                .and_then(|v| v.to_str().ok())
+                .unwrap_or_default()
+                .to_string();
+            sink.lock().unwrap().push((auth, body));
+            let n = issued.fetch_add(1, Ordering::SeqCst) + 1;
+            async move {
+                axum::Json(serde_json::json!({
+                    "access_token": format!("tok-{n}"),
+                    "token_type": "Bearer",
+                    "expires_in": expires_in
+                }))
+            }
+        }),
+    );
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/token", listener.local_addr().unwrap());
+    tokio::spawn(async move {
+        axum::serve(listener, app).await.unwrap();
+    });
+    (url, seen)
+}
+
+#[tokio::test]
+async fn test_oauth2_client_credentials_caches_token() {
+    let (token_url, seen) = start_token_server(3600).await;
+    let mut card = secured_card(
+        "http://agent.com/rpc",
+        vec![("oauth", client_credentials_scheme(&token_url))],
+    );
+    card.security_requirements = vec![[("oauth".to_string(), vec!["read".to_string()])].into()];
+    let auth = Authenticator::for_card(
+        &card,
+        vec![Arc::new(OAuth2ClientCredentials::new("client", "s3cret"))],
+    )
+    .unwrap();
+
+    for _ in 0..3 {
+        let credential = auth.get_credential().await.unwrap();
+        assert_eq!(credential.value, "Bearer tok-1");
+        assert!(credential.expires_at.is_some());
+    }
+    let seen = seen.lock().unwrap().clone();
+    assert_eq!(seen.len(), 1);
+    // base64("client:s3cret")
+    assert_eq!(seen[0].0, "Basic Y2xpZW50OnMzY3JldA==");
+    assert_eq!(seen[0].1, "grant_type=client_credentials&scope=read");
+}
+
+#[tokio::test]
+async fn test_oauth2_client_credentials_refreshes_near_expiry() {
+    let (token_url, seen) = start_token_server(120).await;
+    let provider = OAuth2ClientCredentials::new("client", "s3cret")
+        .with_token_url(&token_url)
+        .with_refresh_margin(Duration::from_secs(300));
+    let scheme = client_credentials_scheme("http://unused.example/token");
+
+    assert_eq!(
+        provider.credential(Some(&scheme), &[]).await.unwrap().value,
+        "Bearer tok-1"
+    );
+    assert_eq!(
+        provider.credential(Some(&scheme), &[]).await.unwrap().value,
+        "Bearer tok-2"
+    );
+    assert_eq!(seen.lock().unwrap()[0].1, "grant_type=client_credentials");
+}
+
+#[tokio::test]
+async fn test_client_builder_authenticates_from_card() {
+    let card = |url: &str| {
+        let mut card = secured_card(
+            url,
+            vec![
+                (
+                    "apikey",
+                    api_key_scheme("X-Agent-Key", ApiKeyLocation::Header),
+                ),
+                ("bearer", bearer_scheme()),
+            ],
+        );
+        card.security = Some(vec![[("bearer".to_string(), vec![])].into()]);
+        card
+    };
+
+    let handler = Arc::new(DefaultRequestHandler::new(
+        Arc::new(common::EchoAgent),
+        Arc::new(InMemoryTaskStore::new()),
+    ));
+    let seen: Arc<Mutex<Vec<Option<String>>>> = Arc::default();
+    let sink = seen.clone();
+    let app = |base_url: &str| {
+        a2a_router(handler, card(&format!("{base_url}/a2a"))).layer(axum::middleware::from_fn(
+            move |request: axum::extract::Request, next: axum::middleware::Next| {
+                if request.uri().path() == "/a2a" {
+                    sink.lock().unwrap().push(
+                        request
+                            .headers()
+                            .get("authorization")
+                            .and_then(|v| v.to_str().ok())
+                            .map(str::to_string),
+                    );
+                }
+                next.run(request)
+            },
+        ))
+    };
+    let (base_url, _server) = common::serve_router(app).await;
+
+    let client = ClientBuilder::new(&base_url)
+        .with_auth_provider(Arc::new(ApiKeyAuth::new("key")))
+        .with_auth_provider(Arc::new(BearerAuth::new("t1")))
+        .build()
+        .await
+        .unwrap();
+    client.send_text("hi").await.unwrap();
+    client.send_text("again").await.unwrap();
+
+    assert_eq!(
+        *seen.lock().unwrap(),
+        [Some("Bearer t1".to_string()), Some("Bearer t1".to_string())]
+    );
+}
@@ -37,6 +37,7 @@ impl MockTransport {
         }
     }
 
+    #[allow(dead_code)]
     fn last_method(&self) -> Option<String> {
         self.last_method.lock().unwrap().clone()
     }
@@ -151,6 +152,7 @@ async fn test_send_text_with_config() {
         blocking: Some(false),
         accepted_output_modes: Some(vec!["application/json".to_string()]),
         push_notification_config: None,
+        timeout_ms: None,
     };
 
     let result = client.send_text_with_config("test", config).await;
@@ -0,0 +1,161 @@
+//! Per-call header overrides via `CallOptions`.
+//!
+//! Spins up a tiny axum server that records request headers and replies
+//! with a canned JSON-RPC response, then checks that builder-level headers
+//! and per-call headers are merged (per-call wins) for both unary and
+//! streaming requests.
+
+use std::sync::{Arc, Mutex};
+
+use a2a_rs::client::{A2AClient, CallOptions, JsonRpcTransport};
+use a2a_rs::types::*;
+use axum::http::HeaderMap;
+use axum::response::IntoResponse;
+use axum::routing::post;
+use axum::Router;
+use serde_json::json;
+
+type Captured = Arc<Mutex<Vec<HeaderMap>>>;
+
+async fn start_capture_server() -> (String, Captured) {
+    let captured: Captured = Arc::new(Mutex::new(Vec::new()));
+    let sink = captured.clone();
+
+    let app = Router::new().route(
+        "/a2a",
+        post(move |headers: HeaderMap, body: String| {
+            let sink = sink.clone();
+            async move {
+                sink.lock().unwrap().push(headers.clone());
+                let req: serde_json::Value = serde_json::from_str(&body).unwrap();
+                let task = json!({
+                    "kind": "task",
+                    "id": "t1",
+                    "contextId": "c1",
+                    "status": {"state": "completed"}
+                });
+                if req["method"] == "message/stream" {
+                    let frame = json!({"jsonrpc": "2.0", "id": req["id"], "result": task});
+                    (
+                        [("content-type", "text/event-stream")],
+                        format!("data: {}\n\n", frame),
+                    )
+                        .into_response()
+                } else {
+                    axum::Json(json!({"jsonrpc": "2.0", "id": req["id"], "result": task}))
+                        .into_response()
+                }
+            }
+        }),
+    );
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::serve(listener, app).await.unwrap();
+    });
+
+    (format!("http://{}/a2a", addr), captured)
+}
+
+fn header<'a>(headers: &'a HeaderMap, name: &str) -> Option<&'a str> {
+    headers.get(name).and_then(|v| v.to_str().ok())
+}
+
+#[test]
+fn test_call_options_builder() {
+    let options = CallOptions::new()
+        .with_header("X-Tenant", "acme")
+        .with_header("X-Tenant", "globex");
+    assert_eq!(options.headers.len(), 1);
+    assert_eq!(options.headers["X-Tenant"], "globex");
+}
+
+#[test]
+fn test_call_options_merge_prefers_other() {
+    let per_task = CallOptions::new()
+        .with_header("X-Tenant", "acme")
+        .with_header("X-Task", "t1");
+    let per_call = CallOptions::new().with_header("X-Tenant", "globex");
+
+    let merged = per_task.merged_with(&per_call);
+    assert_eq!(merged.headers["X-Tenant"], "globex");
+    assert_eq!(merged.headers["X-Task"], "t1");
+}
+
+#[tokio::test]
+async fn test_per_call_headers_merge_with_defaults() {
+    let (url, captured) = start_capture_server().await;
+    let transport = JsonRpcTransport::new(&url)
+        .with_header("X-Default", "builder")
+        .with_header("X-Tenant", "default-tenant");
+    let client = A2AClient::with_transport(Box::new(transport));
+
+    let options = CallOptions::new()
+        .with_header("X-Tenant", "acme")
+        .with_header("X-Deadline-Ms", "5000");
+    client
+        .get_task_with_options(
+            GetTaskParams {
+                id: "t1".to_string(),
+                history_length: None,
+                metadata: None,
+                tenant: None,
+                consistency: None,
+            },
+            &options,
+        )
+        .await
+        .unwrap();
+
+    let headers = captured.lock().unwrap()[0].clone();
+    assert_eq!(header(&headers, "x-default"), Some("builder"));
+    assert_eq!(header(&headers, "x-tenant"), Some("acme"));
+    assert_eq!(header(&headers, "x-deadline-ms"), Some("5000"));
+}
+
+#[tokio::test]
+async fn test_calls_without_options_use_defaults_only() {
+    let (url, captured) = start_capture_server().await;
+    let transport = JsonRpcTransport::new(&url).with_header("X-Tenant", "default-tenant");
+    let client = A2AClient::with_transport(Box::new(transport));
+
+    client.get_task_by_id("t1", None).await.unwrap();
+
+    let headers = captured.lock().unwrap()[0].clone();
+    assert_eq!(header(&headers, "x-tenant"), Some("default-tenant"));
+    assert!(headers.get("x-deadline-ms").is_none());
+}
+
+#[tokio::test]
+async fn test_per_call_headers_on_streaming_request() {
+    let (url, captured) = start_capture_server().await;
+    let client = A2AClient::from_endpoint(&url);
+
+    let options = CallOptions::new().with_header("baggage", "user=alice");
+    let params = SendMessageParams {
+        message: Message::user("m1", "hello"),
+        configuration: None,
+        metadata: None,
+        tenant: None,
+    };
+    let mut stream = client
+        .send_message_stream_with_options(params, &options)
+        .await
+        .unwrap();
+    let event = stream.next().await.unwrap().unwrap();
+    assert!(matches!(event, StreamResponse::Task(_)));
+
+    let headers = captured.lock().unwrap()[0].clone();
+    assert_eq!(header(&headers, "baggage"), Some("user=alice"));
+    assert_eq!(header(&headers, "accept"), Some("text/event-stream"));
+}
+
+#[test]
+fn test_with_timeout_keeps_headers() {
+    let transport = JsonRpcTransport::new("http://example.com")
+        .with_header("X-Tenant", "acme")
+        .with_timeout(std::time::Duration::from_secs(5));
+    assert_eq!(transport.config().headers["X-Tenant"], "acme");
+    assert_eq!(transport.config().timeout.as_secs(), 5);
+}
@@ -0,0 +1,210 @@
+//! `AgentCardCache`: TTL, conditional revalidation with `ETag` /
+//! `Last-Modified`, disk persistence and `A2AClient::refresh_card`.
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use a2a_rs::client::{A2AClient, AgentCardCache, CardResolver};
+use a2a_rs::types::AgentCard;
+use a2a_rs::{AgentCardBuilder, ClientBuilder};
+use axum::extract::State;
+use axum::http::{header, HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use axum::Router;
+
+/// A card server whose card version can be bumped; records each request's
+/// outcome (`200`, `304`).
+#[derive(Clone, Default)]
+struct CardServer {
+    version: Arc<Mutex<u32>>,
+    log: Arc<Mutex<Vec<u16>>>,
+    /// Validate with `Last-Modified` instead of `ETag`.
+    last_modified: bool,
+}
+
+impl CardServer {
+    fn bump(&self) {
+        *self.version.lock().unwrap() += 1;
+    }
+
+    fn log(&self) -> Vec<u16> {
+        std::mem::take(&mut *self.log.lock().unwrap())
+    }
+}
+
+async fn serve_card(State(server): State<CardServer>, headers: HeaderMap) -> Response {
+    let version = *server.version.lock().unwrap();
+    let validator = if server.last_modified {
+        format!("Mon, 0{} Jan 2026 00:00:00 GMT", version + 1)
+    } else {
+        format!("\"v{version}\"")
+    };
+    let (request_header, response_header) = if server.last_modified {
+        (header::IF_MODIFIED_SINCE, header::LAST_MODIFIED)
+    } else {
+        (header::IF_NONE_MATCH, header::ETAG)
+    };
+    if headers.get(request_header).and_then(|v| v.to_str().ok()) == Some(validator.as_str()) {
+        server.log.lock().unwrap().push(304);
+        return StatusCode::NOT_MODIFIED.into_response();
+    }
+    server.log.lock().unwrap().push(200);
+    let card = AgentCardBuilder::new("Cached", "Cached agent", format!("1.{version}"))
+        .with_jsonrpc_interface("http://127.0.0.1:1/a2a")
+        .build();
+    ([(response_header, validator)], axum::Json(card)).into_response()
+}
+
+async fn start(server: CardServer) -> String {
+    let app = Router::new()
+        .route("/.well-known/agent-card.json", get(serve_card))
+        .with_state(server);
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+    base_url
+}
+
+fn card_version(card: &AgentCard) -> &str {
+    &card.version
+}
+
+#[tokio::test]
+async fn test_fresh_card_is_served_from_cache() {
+    let server = CardServer::default();
+    let base_url = start(server.clone()).await;
+    let resolver =
+        CardResolver::new().with_cache(Arc::new(AgentCardCache::new(Duration::from_secs(60))));
+
+    resolver.resolve(&base_url).await.unwrap();
+    server.bump();
+    let card = resolver.resolve(&format!("{base_url}/")).await.unwrap();
+
+    assert_eq!(card_version(&card), "1.0", "stale but within the TTL");
+    assert_eq!(server.log(), [200]);
+}
+
+#[tokio::test]
+async fn test_stale_card_is_revalidated_with_etag() {
+    let server = CardServer::default();
+    let base_url = start(server.clone()).await;
+    let cache = Arc::new(AgentCardCache::new(Duration::ZERO));
+    let resolver = CardResolver::new().with_cache(cache.clone());
+
+    resolver.resolve(&base_url).await.unwrap();
+    let card = resolver.resolve(&base_url).await.unwrap();
+    assert_eq!(card_version(&card), "1.0");
+    assert_eq!(server.log(), [200, 304]);
+
+    server.bump();
+    let card = resolver.resolve(&base_url).await.unwrap();
+    assert_eq!(card_version(&card), "1.1");
+    assert_eq!(server.log(), [200]);
+
+    let cached = cache
+        .get(&format!("{base_url}/.well-known/agent-card.json"))
+        .await
+        .unwrap();
+    assert_eq!(cached.etag.as_deref(), Some("\"v1\""));
+    assert_eq!(card_version(&cached.card), "1.1");
+}
+
+#[tokio::test]
+async fn test_stale_card_is_revalidated_with_last_modified() {
+    let server = CardServer {
+        last_modified: true,
+        ..Default::default()
+    };
+    let base_url = start(server.clone()).await;
+    let resolver = CardResolver::new().with_cache(Arc::new(AgentCardCache::new(Duration::ZERO)));
+
+    resolver.resolve(&base_url).await.unwrap();
+    resolver.resolve(&base_url).await.unwrap();
+    server.bump();
+    let card = resolver.resolve(&base_url).await.unwrap();
+
+    assert_eq!(card_version(&card), "1.1");
+    assert_eq!(server.log(), [200, 304, 200]);
+}
+
+#[tokio::test]
+async fn test_invalidate_and_clear() {
+    let server = CardServer::default();
+    let base_url = start(server.clone()).await;
+    let cache = Arc::new(AgentCardCache::new(Duration::from_secs(60)));
+    let resolver = CardResolver::new().with_cache(cache.clone());
+    let key = format!("{base_url}/.well-known/agent-card.json");
+
+    resolver.resolve(&base_url).await.unwrap();
+    cache.invalidate(&key).await.unwrap();
+    assert!(cache.get(&key).await.is_none());
+    resolver.resolve(&base_url).await.unwrap();
+    cache.clear().await.unwrap();
+    resolver.resolve(&base_url).await.unwrap();
+
+    // Unconditional fetches each time.
+    assert_eq!(server.log(), [200, 200, 200]);
+}
+
+#[tokio::test]
+async fn test_persistent_cache_survives_reopen() {
+    let server = CardServer::default();
+    let base_url = start(server.clone()).await;
+    let dir = std::env::temp_dir().join(format!("a2a-card-cache-{}", uuid::Uuid::new_v4()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("cards.json");
+
+    let cache = AgentCardCache::persistent(Duration::ZERO, &path).unwrap();
+    CardResolver::new()
+        .with_cache(Arc::new(cache))
+        .resolve(&base_url)
+        .await
+        .unwrap();
+    assert!(path.exists());
+
+    // A new process revalidates what the old one cached.
+    let cache = Arc::new(AgentCardCache::persistent(Duration::ZERO, &path).unwrap());
+    assert_eq!(cache.path(), Some(path.as_path()));
+    let card = CardResolver::new()
+        .with_cache(cache)
+        .resolve(&base_url)
+        .await
+        .unwrap();
+    assert_eq!(card_version(&card), "1.0");
+    assert_eq!(server.log(), [200, 304]);
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn test_from_url_shares_cached_card() {
+    let server = CardServer::default();
+    let base_url = start(server.clone()).await;
+
+    A2AClient::from_url(&base_url).await.unwrap();
+    let client = A2AClient::from_url(&base_url).await.unwrap();
+    assert_eq!(card_version(client.get_card().unwrap()), "1.0");
+    assert_eq!(server.log(), [200]);
+}
+
+#[tokio::test]
+async fn test_refresh_card() {
+    let server = CardServer::default();
+    let base_url = start(server.clone()).await;
+    let mut client = ClientBuilder::new(&base_url)
+        .with_card_cache(Arc::new(AgentCardCache::new(Duration::from_secs(60))))
+        .build()
+        .await
+        .unwrap();
+
+    // Revalidated despite the TTL.
+    assert_eq!(card_version(client.refresh_card().await.unwrap()), "1.0");
+    server.bump();
+    assert_eq!(card_version(client.refresh_card().await.unwrap()), "1.1");
+    assert_eq!(card_version(client.get_card().unwrap()), "1.1");
+    assert_eq!(server.log(), [200, 304, 200]);
+
+    let mut client = A2AClient::from_endpoint("http://127.0.0.1:1/a2a");
+    assert!(client.refresh_card().await.is_err());
+}
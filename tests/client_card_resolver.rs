@@ -235,6 +235,105 @@ fn test_agent_card_with_multiple_skills() {
     assert_eq!(card.skills[1].id, "extended_skill");
 }
 
+// ============================================================================
+// Transport negotiation
+// ============================================================================
+
+fn interface(url: &str, transport: &str) -> AgentInterface {
+    AgentInterface {
+        url: url.to_string(),
+        transport: transport.to_string(),
+        protocol_version: None,
+        tenant: None,
+    }
+}
+
+#[test]
+fn test_advertised_interfaces_include_additional_interfaces() {
+    let mut card = make_card(
+        "TestAgent",
+        vec![interface("http://example.com/a2a", "JSONRPC")],
+    );
+    card.additional_interfaces = Some(vec![
+        interface("http://example.com/a2a", "jsonrpc"),
+        interface("http://example.com:50051", "GRPC"),
+    ]);
+
+    let advertised = CardResolver::advertised_interfaces(&card);
+    let listed: Vec<(&str, &str)> = advertised
+        .iter()
+        .map(|iface| (iface.url.as_str(), iface.transport.as_str()))
+        .collect();
+    assert_eq!(
+        listed,
+        [
+            ("http://example.com/a2a", "JSONRPC"),
+            ("http://example.com:50051", "GRPC")
+        ]
+    );
+}
+
+#[test]
+fn test_v03_card_advertises_url_over_preferred_transport() {
+    let mut card = make_card("TestAgent", vec![]);
+    card.url = "http://example.com:50051".to_string();
+    card.preferred_transport = Some("GRPC".to_string());
+    card.additional_interfaces = Some(vec![interface("http://example.com/a2a", "JSONRPC")]);
+
+    let advertised = CardResolver::advertised_interfaces(&card);
+    assert_eq!(advertised.len(), 2);
+    assert_eq!(advertised[0].transport, "GRPC");
+    assert_eq!(advertised[0].url, "http://example.com:50051");
+
+    // A JSON-RPC-only client falls back to the additional interface.
+    let chosen = CardResolver::negotiate_interface(&card, &["JSONRPC"]).unwrap();
+    assert_eq!(chosen.url, "http://example.com/a2a");
+    let client = a2a_rs::client::A2AClient::from_card(card.clone()).unwrap();
+    if cfg!(feature = "grpc") {
+        assert_eq!(client.transport_metadata().protocol, "GRPC");
+    } else {
+        assert_eq!(client.transport_metadata().protocol, "JSONRPC");
+    }
+
+    // Without `preferredTransport` the url speaks JSON-RPC.
+    card.preferred_transport = None;
+    card.additional_interfaces = None;
+    let chosen = CardResolver::negotiate_interface(&card, &["JSONRPC"]).unwrap();
+    assert_eq!(chosen.url, "http://example.com:50051");
+}
+
+#[test]
+fn test_client_preferences_override_the_card() {
+    let mut card = make_card(
+        "TestAgent",
+        vec![
+            interface("http://example.com/a2a", "JSONRPC"),
+            interface("http://example.com/rest", "HTTP+JSON"),
+            interface("http://example.com:50051", "GRPC"),
+        ],
+    );
+    card.preferred_transport = Some("GRPC".to_string());
+    let supported = ["JSONRPC", "GRPC", "HTTP+JSON"];
+
+    let chosen = CardResolver::negotiate_interface(&card, &supported).unwrap();
+    assert_eq!(chosen.transport, "GRPC");
+    let chosen = CardResolver::negotiate_interface_preferring(
+        &card,
+        &supported,
+        &["WEBSOCKET", "http+json"],
+    )
+    .unwrap();
+    assert_eq!(chosen.transport, "HTTP+JSON");
+    // Preferences the client can't speak are skipped.
+    let chosen =
+        CardResolver::negotiate_interface_preferring(&card, &["JSONRPC"], &["GRPC"]).unwrap();
+    assert_eq!(chosen.transport, "JSONRPC");
+    // Offered by nobody: the card's preference applies.
+    let chosen =
+        CardResolver::negotiate_interface_preferring(&card, &supported, &["WEBSOCKET"]).unwrap();
+    assert_eq!(chosen.transport, "GRPC");
+}
+
 // ============================================================================
 // A2AClient::from_card — tests that card→client construction works
 // ============================================================================
@@ -249,6 +348,9 @@ fn test_client_from_card_with_jsonrpc() {
     assert!(client.is_ok());
 }
 
+// With the `grpc` feature such a card connects over gRPC (see
+// tests/grpc_transport.rs).
+#[cfg(not(feature = "grpc"))]
 #[test]
 fn test_client_from_card_without_jsonrpc_fails() {
     let card = make_card(
@@ -0,0 +1,179 @@
+//! Outgoing message validation against the agent card: incompatible
+//! messages fail client-side with `IncompatibleAgent`.
+
+mod common;
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use a2a_rs::builders::{AgentCardBuilder, ClientBuilder};
+use a2a_rs::client::{A2AClient, CallOptions};
+use a2a_rs::error::A2AError;
+use a2a_rs::types::*;
+use a2a_rs::utils::{check_message_compatibility, CompatibilityIssueKind};
+use common::EchoAgent;
+
+const REQUIRED_EXT: &str = "https://example.com/ext/required";
+
+/// Text-only card whose `images` skill also takes images, requiring
+/// [`REQUIRED_EXT`].
+fn card(url: &str) -> AgentCard {
+    let mut card = AgentCardBuilder::new("Picky", "Accepts text and images", "1.0")
+        .with_jsonrpc_interface(url)
+        .with_skill("images", "Images", "Looks at images", vec![])
+        .with_extension(REQUIRED_EXT, None, true)
+        .build();
+    card.skills[0].input_modes = Some(vec!["image/*".to_string()]);
+    card
+}
+
+fn message_with(part: Part) -> Message {
+    let mut message = Message::user("m1", "hi");
+    message.parts.push(part);
+    message
+}
+
+fn pdf() -> Part {
+    Part::file_from_bytes(
+        "JVBERi0=",
+        Some("doc.pdf".into()),
+        Some("application/pdf".into()),
+    )
+}
+
+fn params(message: Message) -> SendMessageParams {
+    SendMessageParams {
+        message,
+        configuration: None,
+        metadata: None,
+        tenant: None,
+    }
+}
+
+fn issues(err: A2AError) -> Vec<a2a_rs::utils::CompatibilityIssue> {
+    match err {
+        A2AError::IncompatibleAgent { issues, .. } => issues,
+        other => panic!("expected IncompatibleAgent, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_input_modes_include_skill_modes() {
+    let card = card("http://localhost/a2a");
+    let extensions = HashSet::from([REQUIRED_EXT.to_string()]);
+
+    let png = Part::file_from_bytes("iVBORw0=", None, Some("image/png".into()));
+    assert!(check_message_compatibility(&card, &message_with(png), &extensions).is_empty());
+
+    let found = check_message_compatibility(&card, &message_with(pdf()), &extensions);
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].kind, CompatibilityIssueKind::UnsupportedInputMode);
+    assert_eq!(found[0].part_index, Some(1));
+    assert!(found[0].message.contains("application/pdf"));
+}
+
+#[test]
+fn test_empty_input_modes_accept_anything() {
+    let mut card = card("http://localhost/a2a");
+    card.default_input_modes.clear();
+    let mut message = message_with(pdf());
+    message.extensions = Some(vec![REQUIRED_EXT.to_string()]);
+    assert!(check_message_compatibility(&card, &message, &HashSet::new()).is_empty());
+}
+
+#[test]
+fn test_missing_required_extension() {
+    let card = card("http://localhost/a2a");
+    let found = check_message_compatibility(&card, &Message::user("m1", "hi"), &HashSet::new());
+    assert_eq!(found.len(), 1);
+    assert_eq!(
+        found[0].kind,
+        CompatibilityIssueKind::MissingRequiredExtension
+    );
+    assert_eq!(found[0].extension.as_deref(), Some(REQUIRED_EXT));
+}
+
+#[tokio::test]
+async fn test_client_fails_fast() {
+    // Nothing listens here: an attempted send would be a transport error.
+    let url = "http://127.0.0.1:9/a2a";
+    let client = A2AClient::from_endpoint(url)
+        .with_agent_card(card(url))
+        .with_card_validation(true);
+
+    let found = issues(
+        client
+            .send_message(params(message_with(pdf())))
+            .await
+            .unwrap_err(),
+    );
+    let kinds: Vec<_> = found.iter().map(|issue| issue.kind).collect();
+    assert_eq!(
+        kinds,
+        [
+            CompatibilityIssueKind::UnsupportedInputMode,
+            CompatibilityIssueKind::MissingRequiredExtension
+        ]
+    );
+    assert!(client
+        .send_message_stream(params(message_with(pdf())))
+        .await
+        .is_err_and(|err| matches!(err, A2AError::IncompatibleAgent { .. })));
+
+    let unchecked = A2AClient::from_endpoint(url).with_agent_card(card(url));
+    let err = unchecked
+        .send_message(params(message_with(pdf())))
+        .await
+        .unwrap_err();
+    assert!(
+        !matches!(err, A2AError::IncompatibleAgent { .. }),
+        "{err:?}"
+    );
+}
+
+#[tokio::test]
+async fn test_requested_extensions_count_as_supported() {
+    let (base_url, _server) = common::start_test_server(Arc::new(EchoAgent)).await;
+    let url = format!("{base_url}/a2a");
+    let client = A2AClient::from_endpoint(&url)
+        .with_agent_card(card(&url))
+        .with_card_validation(true);
+
+    let options = CallOptions::new().with_extensions([REQUIRED_EXT]);
+    client
+        .send_message_with_options(params(Message::user("m1", "hi")), &options)
+        .await
+        .unwrap();
+
+    let client = client.with_supported_extensions([REQUIRED_EXT]);
+    client
+        .send_message(params(Message::user("m2", "hi")))
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_builder_validates_against_resolved_card() {
+    let (base_url, _server) = common::start_test_server(Arc::new(EchoAgent)).await;
+    let client = ClientBuilder::new(&base_url)
+        .with_card_validation(true)
+        .build()
+        .await
+        .unwrap();
+    assert_eq!(client.get_card().unwrap().name, "Test Echo Agent");
+
+    let found = issues(
+        client
+            .send_message(params(message_with(Part::data(
+                serde_json::json!({"a": 1}),
+            ))))
+            .await
+            .unwrap_err(),
+    );
+    assert_eq!(found[0].part_index, Some(1));
+
+    client
+        .send_message(params(Message::user("m1", "hi")))
+        .await
+        .unwrap();
+}
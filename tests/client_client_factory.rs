@@ -3,8 +3,9 @@
 //! Tests for client factory / construction patterns.
 //!
 //! Python's ClientFactory selects transports (JsonRpc, REST, gRPC) based on
-//! agent card preferences. In Rust, the A2AClient only supports JSON-RPC,
-//! so we test the construction patterns that exist.
+//! agent card preferences. In Rust, the A2AClient supports JSON-RPC (and
+//! gRPC with the `grpc` feature, see tests/grpc_transport.rs), so we test
+//! the construction patterns that exist.
 //!
 //! Skipped tests (Python-specific or require features not in Rust SDK):
 //! - test_client_factory_selects_secondary_transport_url (REST transport)
@@ -66,7 +67,7 @@ fn test_client_selects_jsonrpc_transport() {
 
 #[test]
 fn test_client_no_compatible_transport_errors() {
-    let card = make_card("Test Agent", "http://primary-url.com", "gRPC");
+    let card = make_card("Test Agent", "http://primary-url.com", "HTTP+JSON");
     let result = A2AClient::from_card(card);
     assert!(result.is_err());
     let err_msg = format!("{}", result.unwrap_err());
@@ -98,7 +99,7 @@ fn test_client_from_endpoint() {
 
 #[test]
 fn test_client_with_transport() {
-    use a2a_rs::client::{JsonRpcTransport, Transport};
+    use a2a_rs::client::JsonRpcTransport;
 
     let transport = JsonRpcTransport::new("http://primary-url.com");
     let client = A2AClient::with_transport(Box::new(transport));
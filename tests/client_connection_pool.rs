@@ -0,0 +1,137 @@
+//! Connection settings on `TransportConfig` / `ClientBuilder`, per-call
+//! timeouts, and clones of `A2AClient` sharing one connection pool.
+
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use a2a_rs::client::{A2AClient, CallOptions, Http2Settings, JsonRpcTransport, TransportConfig};
+use a2a_rs::error::A2AError;
+use a2a_rs::types::*;
+use a2a_rs::ClientBuilder;
+use axum::extract::{ConnectInfo, State};
+use axum::routing::post;
+use axum::Router;
+use serde_json::{json, Value};
+
+/// Peer addresses of the requests an agent received.
+type Peers = Arc<Mutex<Vec<SocketAddr>>>;
+
+/// An agent answering `tasks/get` after `params.metadata.delayMs`,
+/// recording the peer address of every request.
+async fn start() -> (Peers, String) {
+    async fn handle(
+        State(peers): State<Peers>,
+        ConnectInfo(peer): ConnectInfo<SocketAddr>,
+        axum::Json(request): axum::Json<Value>,
+    ) -> axum::Json<Value> {
+        peers.lock().unwrap().push(peer);
+        if let Some(ms) = request["params"]["metadata"]["delayMs"].as_u64() {
+            tokio::time::sleep(Duration::from_millis(ms)).await;
+        }
+        let task = json!({
+            "id": request["params"]["id"],
+            "contextId": "c1",
+            "kind": "task",
+            "status": {"state": "completed"}
+        });
+        axum::Json(json!({"jsonrpc": "2.0", "id": request["id"], "result": task}))
+    }
+
+    let peers = Peers::default();
+    let app = Router::new()
+        .route("/a2a", post(handle))
+        .with_state(Arc::clone(&peers));
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/a2a", listener.local_addr().unwrap());
+    tokio::spawn(async move {
+        axum::serve(
+            listener,
+            app.into_make_service_with_connect_info::<SocketAddr>(),
+        )
+        .await
+        .unwrap()
+    });
+    (peers, url)
+}
+
+fn get_task(id: &str, delay_ms: Option<u64>) -> GetTaskParams {
+    GetTaskParams {
+        id: id.to_string(),
+        history_length: None,
+        metadata: delay_ms.map(|ms| json!({"delayMs": ms})),
+        tenant: None,
+        consistency: None,
+    }
+}
+
+#[tokio::test]
+async fn test_clones_share_connections() {
+    let (peers, url) = start().await;
+    let client = ClientBuilder::new(&url)
+        .with_pool_max_idle_per_host(4)
+        .with_pool_idle_timeout(Duration::from_secs(30))
+        .with_tcp_keepalive(Duration::from_secs(15))
+        .build_from_endpoint();
+
+    client.get_task_by_id("t1", None).await.unwrap();
+    for id in ["t2", "t3"] {
+        let clone = client.clone();
+        let task = tokio::spawn(async move { clone.get_task_by_id(id, None).await })
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(task.id, id);
+    }
+
+    let peers = peers.lock().unwrap();
+    assert_eq!(peers.len(), 3);
+    assert!(peers.iter().all(|peer| *peer == peers[0]), "{peers:?}");
+}
+
+#[tokio::test]
+async fn test_per_call_timeout() {
+    let (_, url) = start().await;
+    let config = TransportConfig::default().connect_timeout(Duration::from_secs(5));
+    let client = A2AClient::with_transport(Box::new(JsonRpcTransport::with_config(&url, config)));
+
+    let options = CallOptions::new().with_timeout(Duration::from_millis(50));
+    let err = client
+        .get_task_with_options(get_task("t1", Some(1_000)), &options)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, A2AError::Timeout(_)), "{err}");
+
+    // Other calls keep the transport's timeout.
+    let task = client
+        .get_task_with_options(get_task("t1", Some(100)), &CallOptions::new())
+        .await
+        .unwrap();
+    assert_eq!(task.status.state, TaskState::Completed);
+
+    // A later layer's timeout wins when merging.
+    let merged = options.merged_with(&CallOptions::new().with_timeout(Duration::from_secs(2)));
+    assert_eq!(merged.timeout, Some(Duration::from_secs(2)));
+    assert_eq!(
+        options.merged_with(&CallOptions::new()).timeout,
+        Some(Duration::from_millis(50))
+    );
+}
+
+#[test]
+fn test_http2_settings() {
+    let settings = Http2Settings::new()
+        .with_keep_alive_interval(Duration::from_secs(10))
+        .with_keep_alive_timeout(Duration::from_secs(5))
+        .with_keep_alive_while_idle(true)
+        .with_adaptive_window(true);
+    let config = TransportConfig::default().http2(settings.clone());
+    assert_eq!(config.http2.as_ref(), Some(&settings));
+    assert!(!settings.prior_knowledge);
+
+    // Settings survive rebuilding the transport's client.
+    let transport = JsonRpcTransport::with_config("http://localhost:7420/a2a", config)
+        .with_timeout(Duration::from_secs(5))
+        .with_header("X-Tenant", "acme");
+    assert_eq!(transport.config().http2, Some(settings));
+}
@@ -0,0 +1,143 @@
+//! `Conversation`: multi-turn exchanges in one context, and the server's
+//! `ContextStore` recording the context's tasks.
+
+mod common;
+
+use std::sync::Arc;
+
+use a2a_rs::client::A2AClient;
+use a2a_rs::error::A2AResult;
+use a2a_rs::server::{
+    AgentExecutor, ContextStore, EventQueue, InMemoryContextStore, RequestContext, TaskUpdater,
+};
+use a2a_rs::types::*;
+use a2a_rs::utils::get_message_text;
+use a2a_rs::ServerBuilder;
+use async_trait::async_trait;
+use common::serve_router;
+
+/// Asks for a time before booking; other requests are answered with the
+/// tasks they reference.
+struct BookingAgent;
+
+#[async_trait]
+impl AgentExecutor for BookingAgent {
+    async fn execute(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        let input = context.get_user_input("");
+        let message = context.message.clone().unwrap();
+        let waiting = context
+            .task
+            .as_ref()
+            .is_some_and(|task| task.status.state == TaskState::InputRequired);
+        let updater = TaskUpdater::new(event_queue, context.task_id, context.context_id);
+        if waiting {
+            return updater
+                .complete_with_text(&format!("Booked for {input}"))
+                .await;
+        }
+        if input.starts_with("Book") {
+            let prompt = updater.new_agent_message(vec![Part::text("What time?")], None);
+            return updater.requires_input(Some(prompt), true).await;
+        }
+        let references = message.reference_task_ids.unwrap_or_default().len();
+        updater
+            .complete_with_text(&format!("{references} earlier tasks"))
+            .await
+    }
+
+    async fn cancel(&self, _context: RequestContext, _event_queue: EventQueue) -> A2AResult<()> {
+        Ok(())
+    }
+}
+
+async fn start(contexts: Arc<InMemoryContextStore>) -> A2AClient {
+    let (base_url, _server) = serve_router(|_| {
+        ServerBuilder::new(Arc::new(BookingAgent))
+            // Record replies in task history, for `Conversation::history`.
+            .with_agent_card(|card| card.with_state_transition_history(true))
+            .with_context_store(contexts)
+            .build()
+    })
+    .await;
+    A2AClient::from_endpoint(&format!("{base_url}/a2a"))
+}
+
+fn status_text(response: SendMessageResponse) -> String {
+    let SendMessageResponse::Task(task) = response else {
+        panic!("expected a task");
+    };
+    get_message_text(task.status.message.as_ref().unwrap(), "")
+}
+
+#[tokio::test]
+async fn test_conversation_threads_context_and_tasks() {
+    let contexts = Arc::new(InMemoryContextStore::new());
+    let client = start(Arc::clone(&contexts)).await;
+    let mut conversation = client.conversation();
+    assert!(conversation.context_id().is_none());
+
+    // The first task waits for input; the answer continues it.
+    let response = conversation.send("Book a table").await.unwrap();
+    assert_eq!(status_text(response), "What time?");
+    let context_id = conversation.context_id().unwrap().to_string();
+    let first = conversation.open_task_id().unwrap().to_string();
+
+    let response = conversation.send("8 pm").await.unwrap();
+    assert_eq!(status_text(response), "Booked for 8 pm");
+    assert!(conversation.open_task_id().is_none());
+    assert_eq!(conversation.task_ids(), std::slice::from_ref(&first));
+
+    // The next message starts a new task in the same context, referencing
+    // the first.
+    let response = conversation.send("Anything else?").await.unwrap();
+    assert_eq!(status_text(response), "1 earlier tasks");
+    let task_ids = conversation.task_ids();
+    assert_eq!(task_ids.len(), 2);
+    assert_eq!(conversation.context_id(), Some(context_id.as_str()));
+
+    let tasks = conversation.tasks().await.unwrap();
+    assert!(tasks.iter().all(|task| task.context_id == context_id));
+    assert_eq!(tasks[0].status.state, TaskState::Completed);
+
+    let turns: Vec<_> = conversation
+        .history()
+        .await
+        .unwrap()
+        .iter()
+        .map(|m| (m.role, get_message_text(m, "")))
+        .collect();
+    assert_eq!(
+        turns,
+        [
+            (Role::User, "Book a table".to_string()),
+            (Role::Agent, "What time?".to_string()),
+            (Role::User, "8 pm".to_string()),
+            (Role::Agent, "Booked for 8 pm".to_string()),
+            (Role::User, "Anything else?".to_string()),
+            (Role::Agent, "1 earlier tasks".to_string()),
+        ]
+    );
+
+    let stored = contexts.get(&context_id).await.unwrap().unwrap();
+    assert_eq!(stored.task_ids, task_ids);
+}
+
+#[tokio::test]
+async fn test_resumed_conversation_keeps_the_context() {
+    let contexts = Arc::new(InMemoryContextStore::new());
+    let client = start(Arc::clone(&contexts)).await;
+    let mut first = client.conversation();
+    first.send("Hello").await.unwrap();
+    let context_id = first.context_id().unwrap().to_string();
+
+    let mut resumed = client.resume_conversation(&context_id);
+    let response = resumed.send("Hello again").await.unwrap();
+    assert_eq!(status_text(response), "0 earlier tasks");
+    assert_eq!(resumed.context_id(), Some(context_id.as_str()));
+
+    let stored = contexts.get(&context_id).await.unwrap().unwrap();
+    assert_eq!(stored.task_ids.len(), 2);
+    assert!(contexts.get("unknown").await.unwrap().is_none());
+    contexts.delete(&context_id).await.unwrap();
+    assert!(contexts.get(&context_id).await.unwrap().is_none());
+}
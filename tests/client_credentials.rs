@@ -0,0 +1,225 @@
+//! Per-request credentials and transparent SSE resumption on token expiry.
+//!
+//! The mock server emulates a load balancer that cuts each SSE connection
+//! when the token it was opened with expires; the client must reconnect
+//! with a fresh token via `tasks/resubscribe` before that happens.
+
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use a2a_rs::client::{A2AClient, Credential, CredentialService, JsonRpcTransport};
+use a2a_rs::error::A2AResult;
+use a2a_rs::types::*;
+use async_trait::async_trait;
+use axum::http::HeaderMap;
+use axum::response::sse::{Event, Sse};
+use axum::response::IntoResponse;
+use axum::routing::post;
+use axum::Router;
+use serde_json::{json, Value};
+
+const TOKEN_TTL: Duration = Duration::from_millis(300);
+const TOTAL_EVENTS: u64 = 40;
+
+/// Issues `tok-1`, `tok-2`, … each valid for `ttl` (if any).
+struct RotatingTokens {
+    issued: AtomicUsize,
+    ttl: Option<Duration>,
+}
+
+impl RotatingTokens {
+    fn new(ttl: Option<Duration>) -> Arc<Self> {
+        Arc::new(Self {
+            issued: AtomicUsize::new(0),
+            ttl,
+        })
+    }
+}
+
+#[async_trait]
+impl CredentialService for RotatingTokens {
+    async fn get_credential(&self) -> A2AResult<Credential> {
+        let n = self.issued.fetch_add(1, Ordering::SeqCst) + 1;
+        let credential = Credential::bearer(format!("tok-{n}"));
+        Ok(match self.ttl {
+            Some(ttl) => credential.expires_in(ttl),
+            None => credential,
+        })
+    }
+}
+
+/// (method, Authorization header) of every request the server received.
+type Seen = Arc<Mutex<Vec<(String, String)>>>;
+
+fn status_event(seq: u64, state: TaskState, is_final: bool) -> Value {
+    json!({
+        "kind": "status-update",
+        "taskId": "t1",
+        "contextId": "c1",
+        "status": {"state": state},
+        "final": is_final,
+        "metadata": {"seq": seq}
+    })
+}
+
+/// Streams status updates every 20ms with a global sequence number; each
+/// connection is cut after `TOKEN_TTL`, and the stream completes once
+/// `TOTAL_EVENTS` have been emitted overall.
+async fn start_server() -> (String, Seen) {
+    let seen: Seen = Arc::new(Mutex::new(Vec::new()));
+    let sink = seen.clone();
+    let seq = Arc::new(AtomicU64::new(0));
+
+    let app = Router::new().route(
+        "/a2a",
+        post(move |headers: HeaderMap, body: String| {
+            let sink = sink.clone();
+            let seq = seq.clone();
+            async move {
+                let req: Value = serde_json::from_str(&body).unwrap();
+                let method = req["method"].as_str().unwrap_or_default().to_string();
+                let auth = headers
+                    .get("authorization")
+                    .and_then(|v| v.to_str().ok())
+                    .unwrap_or_default()
+                    .to_string();
+                sink.lock().unwrap().push((method.clone(), auth));
+
+                if method != "message/stream" && method != "tasks/resubscribe" {
+                    let task = json!({
+                        "kind": "task",
+                        "id": "t1",
+                        "contextId": "c1",
+                        "status": {"state": "completed"}
+                    });
+                    return axum::Json(json!({"jsonrpc": "2.0", "id": req["id"], "result": task}))
+                        .into_response();
+                }
+
+                let id = req["id"].clone();
+                let stream = async_stream::stream! {
+                    let cut = tokio::time::Instant::now() + TOKEN_TTL;
+                    while tokio::time::Instant::now() < cut {
+                        let n = seq.fetch_add(1, Ordering::SeqCst);
+                        let done = n + 1 >= TOTAL_EVENTS;
+                        let state = if done { TaskState::Completed } else { TaskState::Working };
+                        let frame = json!({
+                            "jsonrpc": "2.0",
+                            "id": id,
+                            "result": status_event(n, state, done)
+                        });
+                        yield Ok::<_, std::convert::Infallible>(
+                            Event::default().data(frame.to_string()),
+                        );
+                        if done {
+                            return;
+                        }
+                        tokio::time::sleep(Duration::from_millis(20)).await;
+                    }
+                };
+                Sse::new(stream).into_response()
+            }
+        }),
+    );
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::serve(listener, app).await.unwrap();
+    });
+
+    (format!("http://{}/a2a", addr), seen)
+}
+
+fn stream_params() -> SendMessageParams {
+    SendMessageParams {
+        message: Message::user("m1", "go"),
+        configuration: None,
+        metadata: None,
+        tenant: None,
+    }
+}
+
+#[test]
+fn test_credential_constructors() {
+    let bearer = Credential::bearer("abc");
+    assert_eq!(bearer.header, "Authorization");
+    assert_eq!(bearer.value, "Bearer abc");
+    assert!(bearer.expires_at.is_none());
+
+    let api_key = Credential::header("X-API-Key", "k").expires_in(Duration::from_secs(1));
+    assert_eq!(api_key.header, "X-API-Key");
+    assert!(api_key.expires_at.is_some());
+}
+
+#[tokio::test]
+async fn test_unary_requests_fetch_fresh_credentials() {
+    let (url, seen) = start_server().await;
+    let tokens = RotatingTokens::new(None);
+    let transport = JsonRpcTransport::new(&url).with_credentials(tokens.clone());
+    let client = A2AClient::with_transport(Box::new(transport));
+
+    client.get_task_by_id("t1", None).await.unwrap();
+    client.get_task_by_id("t1", None).await.unwrap();
+
+    let seen = seen.lock().unwrap();
+    assert_eq!(seen[0].1, "Bearer tok-1");
+    assert_eq!(seen[1].1, "Bearer tok-2");
+}
+
+#[tokio::test]
+async fn test_stream_resumes_before_token_expiry() {
+    let (url, seen) = start_server().await;
+    let transport = JsonRpcTransport::new(&url)
+        .with_credentials(RotatingTokens::new(Some(TOKEN_TTL)))
+        .with_credential_refresh_margin(Duration::from_millis(150));
+    let client = A2AClient::with_transport(Box::new(transport));
+
+    let mut stream = client.send_message_stream(stream_params()).await.unwrap();
+    let mut last_seq = None;
+    let mut finished = false;
+    while let Some(event) = stream.next().await {
+        let StreamResponse::StatusUpdate(update) = event.expect("no error surfaces") else {
+            panic!("expected status update");
+        };
+        let seq = update.metadata.unwrap()["seq"].as_u64().unwrap();
+        if let Some(last) = last_seq {
+            assert!(seq > last, "events out of order: {seq} after {last}");
+        }
+        last_seq = Some(seq);
+        if update.r#final {
+            finished = true;
+            break;
+        }
+    }
+    assert!(finished, "stream ended before the final event");
+
+    let seen = seen.lock().unwrap();
+    assert_eq!(
+        seen[0],
+        ("message/stream".to_string(), "Bearer tok-1".into())
+    );
+    assert!(seen.len() >= 3, "expected several reconnects, got {seen:?}");
+    for (i, (method, auth)) in seen.iter().enumerate().skip(1) {
+        assert_eq!(method, "tasks/resubscribe");
+        assert_eq!(auth, &format!("Bearer tok-{}", i + 1));
+    }
+}
+
+#[tokio::test]
+async fn test_stream_without_expiry_is_not_resumed() {
+    let (url, seen) = start_server().await;
+    let transport = JsonRpcTransport::new(&url).with_credentials(RotatingTokens::new(None));
+    let client = A2AClient::with_transport(Box::new(transport));
+
+    let mut stream = client.send_message_stream(stream_params()).await.unwrap();
+    while let Some(event) = stream.next().await {
+        event.unwrap();
+    }
+
+    // The server cut the only connection; nothing reconnected.
+    let seen = seen.lock().unwrap();
+    assert_eq!(seen.len(), 1);
+    assert_eq!(seen[0].0, "message/stream");
+}
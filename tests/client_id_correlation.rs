@@ -0,0 +1,173 @@
+//! JSON-RPC id correlation: responses and SSE envelopes whose `id` does
+//! not match the request surface `ProtocolViolation` unless relaxed with
+//! `IdCorrelation`.
+
+use std::sync::Arc;
+
+use a2a_rs::client::{A2AClient, IdCorrelation, JsonRpcTransport};
+use a2a_rs::error::A2AError;
+use axum::response::IntoResponse;
+use axum::routing::post;
+use axum::Router;
+use serde_json::{json, Value};
+
+/// Maps the request id to the id of each response (or SSE frame).
+type Responder = Arc<dyn Fn(&Value, usize) -> Value + Send + Sync>;
+
+/// A server that answers `tasks/get` with a task and `message/stream` with
+/// two frames, using ids chosen by `ids`. `tasks/cancel` fails.
+async fn start_server(ids: impl Fn(&Value, usize) -> Value + Send + Sync + 'static) -> String {
+    let ids: Responder = Arc::new(ids);
+    let app = Router::new().route(
+        "/a2a",
+        post(move |body: String| {
+            let ids = ids.clone();
+            async move {
+                let req: Value = serde_json::from_str(&body).unwrap();
+                let task = json!({
+                    "kind": "task",
+                    "id": "t1",
+                    "contextId": "c1",
+                    "status": {"state": "working"}
+                });
+                match req["method"].as_str() {
+                    Some("message/stream") => {
+                        let frames: String = (0..2)
+                            .map(|i| {
+                                let frame =
+                                    json!({"jsonrpc": "2.0", "id": ids(&req["id"], i), "result": task});
+                                format!("data: {frame}\n\n")
+                            })
+                            .collect();
+                        ([("content-type", "text/event-stream")], frames).into_response()
+                    }
+                    Some("tasks/cancel") => axum::Json(json!({
+                        "jsonrpc": "2.0",
+                        "id": ids(&req["id"], 0),
+                        "error": {"code": -32001, "message": "Task not found"}
+                    }))
+                    .into_response(),
+                    _ => axum::Json(json!({"jsonrpc": "2.0", "id": ids(&req["id"], 0), "result": task}))
+                        .into_response(),
+                }
+            }
+        }),
+    );
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::serve(listener, app).await.unwrap();
+    });
+    format!("http://{addr}/a2a")
+}
+
+fn client(url: &str, mode: IdCorrelation) -> A2AClient {
+    A2AClient::with_transport(Box::new(
+        JsonRpcTransport::new(url).with_id_correlation(mode),
+    ))
+}
+
+#[tokio::test]
+async fn test_matching_ids_are_accepted() {
+    let url = start_server(|id, _| id.clone()).await;
+    let client = client(&url, IdCorrelation::Strict);
+
+    assert_eq!(client.get_task_by_id("t1", None).await.unwrap().id, "t1");
+    let mut stream = client.send_text_stream("hi").await.unwrap();
+    assert!(stream.next().await.unwrap().is_ok());
+    assert!(stream.next().await.unwrap().is_ok());
+    assert!(stream.next().await.is_none());
+}
+
+#[tokio::test]
+async fn test_mismatched_response_id_is_a_protocol_violation() {
+    let url = start_server(|_, _| json!("someone-else")).await;
+    let err = client(&url, IdCorrelation::default())
+        .get_task_by_id("t1", None)
+        .await
+        .unwrap_err();
+
+    let A2AError::ProtocolViolation(message) = &err else {
+        panic!("expected protocol violation, got {err:?}");
+    };
+    assert!(message.contains("tasks/get"), "{message}");
+    assert!(message.contains("\"someone-else\""), "{message}");
+}
+
+#[tokio::test]
+async fn test_id_type_must_match() {
+    // The request id is a string; echoing it as a number is a mismatch.
+    let url = start_server(|_, _| json!(1)).await;
+    let err = client(&url, IdCorrelation::Strict)
+        .get_task_by_id("t1", None)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, A2AError::ProtocolViolation(_)), "{err:?}");
+}
+
+#[tokio::test]
+async fn test_missing_id_on_success_is_a_protocol_violation() {
+    let url = start_server(|_, _| Value::Null).await;
+    let err = client(&url, IdCorrelation::Strict)
+        .get_task_by_id("t1", None)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, A2AError::ProtocolViolation(_)), "{err:?}");
+}
+
+#[tokio::test]
+async fn test_error_response_with_null_id_is_accepted() {
+    let url = start_server(|_, _| Value::Null).await;
+    let err = client(&url, IdCorrelation::Strict)
+        .cancel_task_by_id("t1")
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), -32001, "{err:?}");
+}
+
+#[tokio::test]
+async fn test_mismatched_sse_envelope_ends_stream() {
+    let url = start_server(|id, i| if i == 0 { id.clone() } else { json!("stale") }).await;
+    let mut stream = client(&url, IdCorrelation::Strict)
+        .send_text_stream("hi")
+        .await
+        .unwrap();
+
+    assert!(stream.next().await.unwrap().is_ok());
+    let err = stream.next().await.unwrap().unwrap_err();
+    let A2AError::ProtocolViolation(message) = &err else {
+        panic!("expected protocol violation, got {err:?}");
+    };
+    assert!(message.contains("message/stream"), "{message}");
+    assert!(stream.next().await.is_none());
+}
+
+#[tokio::test]
+async fn test_relaxed_modes_accept_mismatches() {
+    let url = start_server(|_, _| json!("someone-else")).await;
+    for mode in [IdCorrelation::Warn, IdCorrelation::Off] {
+        let client = client(&url, mode);
+        assert!(client.get_task_by_id("t1", None).await.is_ok(), "{mode:?}");
+
+        let mut stream = client.send_text_stream("hi").await.unwrap();
+        let mut events = 0;
+        while let Some(event) = stream.next().await {
+            event.unwrap();
+            events += 1;
+        }
+        assert_eq!(events, 2, "{mode:?}");
+    }
+}
+
+#[test]
+fn test_id_correlation_survives_rebuilds() {
+    let transport = JsonRpcTransport::new("http://localhost:1/a2a")
+        .with_id_correlation(IdCorrelation::Off)
+        .with_header("X-Tenant", "acme");
+    assert_eq!(transport.id_correlation(), IdCorrelation::Off);
+    assert_eq!(
+        JsonRpcTransport::new("http://localhost:1/a2a").id_correlation(),
+        IdCorrelation::Strict
+    );
+}
@@ -0,0 +1,173 @@
+//! `User-Agent` / `X-A2A-Client` identification headers, from `ClientBuilder`
+//! to `ServerCallContext`.
+
+mod common;
+
+use std::sync::{Arc, Mutex};
+
+use a2a_rs::builders::ClientBuilder;
+use a2a_rs::client::{A2AClient, JsonRpcTransport};
+use a2a_rs::error::A2AResult;
+use a2a_rs::server::{
+    a2a_router, AgentExecutor, DefaultRequestHandler, EventQueue, InMemoryTaskStore,
+    RequestContext, TaskUpdater,
+};
+use a2a_rs::utils::{ClientIdentification, SDK_IDENTIFIER};
+use async_trait::async_trait;
+use axum::extract::Request;
+use axum::middleware::Next;
+
+/// Records the identification each execution sees.
+#[derive(Default)]
+struct RecordingAgent {
+    seen: Mutex<Vec<Option<ClientIdentification>>>,
+}
+
+#[async_trait]
+impl AgentExecutor for RecordingAgent {
+    async fn execute(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        self.seen
+            .lock()
+            .unwrap()
+            .push(context.client_identification().cloned());
+        TaskUpdater::new(event_queue, context.task_id, context.context_id)
+            .complete_with_text("ok")
+            .await
+    }
+
+    async fn cancel(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        TaskUpdater::new(event_queue, context.task_id, context.context_id)
+            .cancel(None)
+            .await
+    }
+}
+
+/// `(path, user-agent, x-a2a-client)` of every HTTP request received.
+type Seen = Arc<Mutex<Vec<(String, Option<String>, Option<String>)>>>;
+
+/// Start a server; returns its base URL, the agent and the request log.
+async fn start() -> (String, Arc<RecordingAgent>, Seen) {
+    let agent = Arc::new(RecordingAgent::default());
+    let handler = Arc::new(DefaultRequestHandler::new(
+        agent.clone(),
+        Arc::new(InMemoryTaskStore::new()),
+    ));
+    let seen = Seen::default();
+    let log = seen.clone();
+    let app = |base_url: &str| {
+        a2a_router(handler, common::test_agent_card(&format!("{base_url}/a2a"))).layer(
+            axum::middleware::from_fn(move |request: Request, next: Next| {
+                let header = |name: &str| {
+                    request
+                        .headers()
+                        .get(name)
+                        .and_then(|v| v.to_str().ok())
+                        .map(String::from)
+                };
+                log.lock().unwrap().push((
+                    request.uri().path().to_string(),
+                    header("user-agent"),
+                    header("x-a2a-client"),
+                ));
+                next.run(request)
+            }),
+        )
+    };
+    let (base_url, _server) = common::serve_router(app).await;
+    (base_url, agent, seen)
+}
+
+fn last_identification(agent: &RecordingAgent) -> ClientIdentification {
+    agent
+        .seen
+        .lock()
+        .unwrap()
+        .last()
+        .cloned()
+        .expect("executor ran")
+        .expect("identification present")
+}
+
+#[tokio::test]
+async fn test_default_client_identifies_sdk() {
+    let (base_url, agent, _) = start().await;
+    let client = A2AClient::from_endpoint(&format!("{base_url}/a2a"));
+
+    client.send_text("hi").await.unwrap();
+
+    let id = last_identification(&agent);
+    assert_eq!(id.sdk.as_deref(), Some(SDK_IDENTIFIER));
+    assert_eq!(id.app, None);
+    assert_eq!(id.user_agent.as_deref(), Some(SDK_IDENTIFIER));
+}
+
+#[tokio::test]
+async fn test_builder_client_id_on_every_request() {
+    let (base_url, agent, seen) = start().await;
+    let client = ClientBuilder::new(&base_url)
+        .with_client_id("my-app/2.3")
+        .build()
+        .await
+        .unwrap();
+
+    client.send_text("hi").await.unwrap();
+
+    let id = last_identification(&agent);
+    assert_eq!(id.app.as_deref(), Some("my-app/2.3"));
+    assert_eq!(id.sdk.as_deref(), Some(SDK_IDENTIFIER));
+    let expected_ua = format!("my-app/2.3 {SDK_IDENTIFIER}");
+    assert_eq!(id.user_agent.as_deref(), Some(expected_ua.as_str()));
+
+    let seen = seen.lock().unwrap();
+    assert!(
+        seen.len() >= 2,
+        "card resolution and message/send: {seen:?}"
+    );
+    for (path, user_agent, client) in seen.iter() {
+        assert_eq!(user_agent.as_deref(), Some(expected_ua.as_str()), "{path}");
+        assert!(
+            client.as_deref().unwrap().contains("app=my-app/2.3"),
+            "{path}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_explicit_user_agent_wins() {
+    let (base_url, agent, _) = start().await;
+    let client = ClientBuilder::new(format!("{base_url}/a2a"))
+        .with_user_agent("custom-agent/9")
+        .with_client_id("my-app/2.3")
+        .build_from_endpoint();
+
+    client.send_text("hi").await.unwrap();
+
+    let id = last_identification(&agent);
+    assert_eq!(id.user_agent.as_deref(), Some("custom-agent/9"));
+    assert_eq!(id.app.as_deref(), Some("my-app/2.3"));
+}
+
+#[tokio::test]
+async fn test_transport_with_client_id() {
+    let (base_url, agent, _) = start().await;
+    let transport = JsonRpcTransport::new(format!("{base_url}/a2a")).with_client_id("svc/1");
+    let client = A2AClient::with_transport(Box::new(transport));
+
+    client.send_text("hi").await.unwrap();
+
+    assert_eq!(last_identification(&agent).app.as_deref(), Some("svc/1"));
+}
+
+#[tokio::test]
+async fn test_requests_without_headers_have_no_identification() {
+    let (base_url, agent, _) = start().await;
+
+    reqwest::Client::new()
+        .post(format!("{base_url}/a2a"))
+        .json(&common::message_send_request(1, "hi"))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(agent.seen.lock().unwrap().as_slice(), &[None]);
+}
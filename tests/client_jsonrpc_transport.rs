@@ -28,7 +28,7 @@
 //! - Extension header tests (X-A2A-Extensions header)
 //! - test_send_message_streaming_server_error_propagates (403 during SSE)
 
-use a2a_rs::client::{JsonRpcTransport, Transport, TransportConfig};
+use a2a_rs::client::{JsonRpcTransport, TransportConfig};
 use a2a_rs::types::*;
 use std::time::Duration;
 
@@ -54,6 +54,7 @@ fn test_transport_with_custom_config() {
     let config = TransportConfig {
         timeout: Duration::from_secs(30),
         headers: [("X-Custom".to_string(), "value".to_string())].into(),
+        ..Default::default()
     };
     let transport = JsonRpcTransport::with_config("http://example.com", config);
     assert_eq!(transport.url(), "http://example.com");
@@ -114,6 +115,7 @@ fn test_custom_transport_config() {
             ("X-Custom".to_string(), "value".to_string()),
         ]
         .into(),
+        ..Default::default()
     };
     assert_eq!(config.timeout, Duration::from_secs(5));
     assert_eq!(config.headers.len(), 2);
@@ -268,6 +270,7 @@ fn test_send_message_params_with_configuration() {
             blocking: Some(true),
             accepted_output_modes: Some(vec!["text/plain".to_string()]),
             push_notification_config: None,
+            timeout_ms: None,
         }),
         metadata: None,
         tenant: None,
@@ -0,0 +1,183 @@
+//! `A2AClient::list_tasks_paged`: following `nextPageToken` across pages,
+//! and the `ListTasksParams` filter builders.
+
+mod common;
+
+use std::sync::Arc;
+
+use a2a_rs::client::A2AClient;
+use a2a_rs::error::A2AResult;
+use a2a_rs::server::{InMemoryTaskStore, TaskStore};
+use a2a_rs::types::*;
+use chrono::{TimeZone, Utc};
+use futures::{StreamExt, TryStreamExt};
+use serde_json::json;
+
+use common::{start_test_server_with_store, EchoAgent};
+
+fn task(id: &str, context_id: &str, state: TaskState) -> Task {
+    Task {
+        id: id.to_string(),
+        context_id: context_id.to_string(),
+        kind: "task".to_string(),
+        status: TaskStatus::new(state),
+        artifacts: None,
+        history: None,
+        metadata: None,
+    }
+}
+
+async fn client_with_tasks() -> (A2AClient, tokio::task::JoinHandle<()>) {
+    let store = Arc::new(InMemoryTaskStore::new());
+    for (id, context_id, state) in [
+        ("t1", "c1", TaskState::Completed),
+        ("t2", "c1", TaskState::Working),
+        ("t3", "c2", TaskState::Completed),
+        ("t4", "c1", TaskState::Completed),
+        ("t5", "c2", TaskState::Failed),
+    ] {
+        store.save(task(id, context_id, state)).await.unwrap();
+    }
+    let (base_url, handle) = start_test_server_with_store(Arc::new(EchoAgent), store).await;
+    let client = A2AClient::from_url(&base_url).await.unwrap();
+    (client, handle)
+}
+
+async fn ids(client: &A2AClient, params: ListTasksParams) -> A2AResult<Vec<String>> {
+    client
+        .list_tasks_paged(params)
+        .map_ok(|task| task.id)
+        .try_collect()
+        .await
+}
+
+#[tokio::test]
+async fn test_paged_listing_follows_page_tokens() {
+    let (client, _server) = client_with_tasks().await;
+
+    let first = client
+        .list_tasks(ListTasksParams::new().with_page_size(2))
+        .await
+        .unwrap();
+    assert_eq!(first.tasks.len(), 2);
+    assert!(!first.next_page_token.is_empty());
+
+    let all = ids(&client, ListTasksParams::new().with_page_size(2))
+        .await
+        .unwrap();
+    assert_eq!(all, ["t1", "t2", "t3", "t4", "t5"]);
+
+    // Resumes after the given token.
+    let mut params = ListTasksParams::new().with_page_size(2);
+    params.page_token = Some(first.next_page_token);
+    let rest = ids(&client, params).await.unwrap();
+    assert_eq!(rest, ["t3", "t4", "t5"]);
+}
+
+#[tokio::test]
+async fn test_paged_listing_applies_filters() {
+    let (client, _server) = client_with_tasks().await;
+
+    let params = ListTasksParams::new()
+        .with_context_id("c1")
+        .with_page_size(1);
+    assert_eq!(ids(&client, params).await.unwrap(), ["t1", "t2", "t4"]);
+
+    let params = ListTasksParams::new()
+        .with_status(TaskState::Completed)
+        .with_page_size(2);
+    assert_eq!(ids(&client, params).await.unwrap(), ["t1", "t3", "t4"]);
+
+    let params = ListTasksParams::new()
+        .with_context_id("c2")
+        .with_status(TaskState::Completed);
+    assert_eq!(ids(&client, params).await.unwrap(), ["t3"]);
+}
+
+#[tokio::test]
+async fn test_pages_are_fetched_lazily() {
+    let (client, _server) = client_with_tasks().await;
+
+    let tasks = client.list_tasks_paged(ListTasksParams::new().with_page_size(2));
+    let first: Vec<Task> = tasks.take(1).try_collect().await.unwrap();
+    assert_eq!(first[0].id, "t1");
+}
+
+#[test]
+fn test_filter_builders_serialize_to_spec_fields() {
+    let after = Utc.with_ymd_and_hms(2025, 1, 2, 3, 4, 5).unwrap();
+    let params = ListTasksParams::new()
+        .with_context_id("c1")
+        .with_status(TaskState::InputRequired)
+        .with_status_timestamp_after(after)
+        .with_page_size(10);
+
+    assert_eq!(
+        serde_json::to_value(&params).unwrap(),
+        json!({
+            "contextId": "c1",
+            "status": "input-required",
+            "statusTimestampAfter": "2025-01-02T03:04:05.000Z",
+            "pageSize": 10,
+        })
+    );
+    assert_eq!(
+        serde_json::to_value(ListTasksParams::new()).unwrap(),
+        json!({})
+    );
+}
+
+#[cfg(feature = "testing")]
+mod mock {
+    use super::*;
+    use a2a_rs::error;
+    use a2a_rs::testing::{fixtures, MockAgentServer, MockReply};
+
+    fn page(ids: &[&str], next_page_token: &str) -> MockReply {
+        let tasks: Vec<Task> = ids
+            .iter()
+            .map(|id| fixtures::task(id, TaskState::Completed))
+            .collect();
+        MockReply::result(json!({
+            "tasks": tasks,
+            "nextPageToken": next_page_token,
+            "pageSize": ids.len(),
+            "totalSize": 3,
+        }))
+    }
+
+    #[tokio::test]
+    async fn test_each_page_request_carries_the_filters_and_token() {
+        let server = MockAgentServer::start().await.unwrap();
+        server.on_next("tasks/list", page(&["a", "b"], "b"));
+        server.on_next("tasks/list", page(&["c"], ""));
+        let client = A2AClient::from_url(server.url()).await.unwrap();
+
+        let params = ListTasksParams::new().with_context_id("ctx");
+        assert_eq!(ids(&client, params).await.unwrap(), ["a", "b", "c"]);
+
+        let requests = server.requests_for("tasks/list");
+        assert_eq!(requests.len(), 2);
+        let first: ListTasksParams = requests[0].params_as().unwrap();
+        let second: ListTasksParams = requests[1].params_as().unwrap();
+        assert_eq!(first.page_token, None);
+        assert_eq!(second.page_token.as_deref(), Some("b"));
+        assert_eq!(second.context_id.as_deref(), Some("ctx"));
+    }
+
+    #[tokio::test]
+    async fn test_repeated_page_token_ends_the_stream_with_an_error() {
+        let server = MockAgentServer::start().await.unwrap();
+        server.on("tasks/list", page(&["a"], "same"));
+        let client = A2AClient::from_url(server.url()).await.unwrap();
+
+        let results: Vec<A2AResult<Task>> = client
+            .list_tasks_paged(ListTasksParams::new())
+            .collect()
+            .await;
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].as_ref().unwrap().id, "a");
+        let err = results[1].as_ref().unwrap_err();
+        assert_eq!(err.code(), error::INVALID_AGENT_RESPONSE);
+    }
+}
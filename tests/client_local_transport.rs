@@ -0,0 +1,137 @@
+//! `LocalTransport`: the client calling a `RequestHandler` in-process.
+
+mod common;
+
+use std::sync::Arc;
+
+use a2a_rs::client::{A2AClient, CallOptions, LocalTransport, Transport};
+use a2a_rs::error::{self, A2AResult};
+use a2a_rs::server::{
+    AgentExecutor, DefaultRequestHandler, EventQueue, InMemoryTaskStore, RequestContext,
+};
+use a2a_rs::types::*;
+use a2a_rs::utils::get_message_text;
+use a2a_rs::AgentCardBuilder;
+use async_trait::async_trait;
+
+const EXTENSION: &str = "https://example.com/ext/tone/v1";
+
+/// Replies with the extensions activated for the call, comma-separated.
+struct ExtensionsAgent;
+
+#[async_trait]
+impl AgentExecutor for ExtensionsAgent {
+    async fn execute(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        let mut activated: Vec<String> = context
+            .call_context
+            .as_ref()
+            .map(|ctx| ctx.activated_extensions.iter().cloned().collect())
+            .unwrap_or_default();
+        activated.sort();
+        context
+            .task_updater(event_queue)
+            .complete_with_text(&activated.join(","))
+            .await
+    }
+
+    async fn cancel(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        context.task_updater(event_queue).cancel(None).await
+    }
+}
+
+fn transport(executor: Arc<dyn AgentExecutor>) -> LocalTransport {
+    let handler = DefaultRequestHandler::new(executor, Arc::new(InMemoryTaskStore::new()));
+    LocalTransport::new(Arc::new(handler))
+}
+
+fn reply_text(response: SendMessageResponse) -> String {
+    let SendMessageResponse::Task(task) = response else {
+        panic!("expected a task, got {response:?}");
+    };
+    get_message_text(task.status.message.as_ref().unwrap(), "")
+}
+
+#[tokio::test]
+async fn test_send_and_get_task() {
+    let client = A2AClient::with_transport(Box::new(transport(Arc::new(common::EchoAgent))));
+
+    let SendMessageResponse::Task(task) = client.send_text("hello").await.unwrap() else {
+        panic!("expected a task");
+    };
+    assert_eq!(task.status.state, TaskState::Completed);
+    assert_eq!(
+        get_message_text(task.status.message.as_ref().unwrap(), ""),
+        "Echo: hello"
+    );
+
+    let fetched = client.get_task_by_id(&task.id, None).await.unwrap();
+    assert_eq!(fetched.id, task.id);
+    assert_eq!(fetched.status.state, TaskState::Completed);
+
+    let err = client.get_task_by_id("missing", None).await.unwrap_err();
+    assert_eq!(err.code(), error::TASK_NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_stream_ends_after_final_status() {
+    let client = A2AClient::with_transport(Box::new(transport(Arc::new(common::SlowEchoAgent))));
+
+    let mut stream = client.send_text_stream("work").await.unwrap();
+    let mut events = Vec::new();
+    while let Some(event) = stream.next().await {
+        events.push(event.unwrap());
+    }
+    assert!(events
+        .iter()
+        .any(|event| matches!(event, StreamResponse::ArtifactUpdate(_))));
+    let Some(StreamResponse::StatusUpdate(update)) = events.last() else {
+        panic!("expected a final status, got {events:?}");
+    };
+    assert!(update.r#final);
+    assert_eq!(update.status.state, TaskState::Completed);
+}
+
+#[tokio::test]
+async fn test_handler_errors_are_json_rpc_errors() {
+    let transport = transport(Arc::new(common::EchoAgent));
+
+    let request = JsonRpcRequest::new(
+        JsonRpcId::Number(7),
+        "tasks/get",
+        Some(serde_json::json!({"historyLength": 1})),
+    );
+    let response = transport.send(&request).await.unwrap();
+    assert_eq!(response.id, Some(JsonRpcId::Number(7)));
+    assert_eq!(response.error.unwrap().code, error::INVALID_PARAMS);
+
+    let request = JsonRpcRequest::new(JsonRpcId::Number(8), "tasks/frobnicate", None);
+    let response = transport.send(&request).await.unwrap();
+    assert_eq!(response.error.unwrap().code, error::METHOD_NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_extensions_and_capabilities_follow_the_agent_card() {
+    let card = AgentCardBuilder::new("Local", "In-process agent", "1.0")
+        .with_extension(EXTENSION, None, false)
+        .build();
+    let client = A2AClient::with_transport(Box::new(
+        transport(Arc::new(ExtensionsAgent)).with_agent_card(card),
+    ));
+
+    let options = CallOptions::new().with_extensions([EXTENSION, "https://example.com/other"]);
+    let params = SendMessageParams {
+        message: Message::user(uuid::Uuid::new_v4().to_string(), "hi"),
+        configuration: None,
+        metadata: None,
+        tenant: None,
+    };
+    let response = client
+        .send_message_with_options(params.clone(), &options)
+        .await
+        .unwrap();
+    assert_eq!(reply_text(response), EXTENSION);
+
+    // The card doesn't declare streaming.
+    let err = client.send_message_stream(params).await.unwrap_err();
+    assert_eq!(err.code(), error::UNSUPPORTED_OPERATION);
+}
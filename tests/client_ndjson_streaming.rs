@@ -0,0 +1,144 @@
+//! Streaming over newline-delimited JSON: negotiated with `Accept`, served
+//! by `axum_integration` and read by the client into the same `SseStream`.
+
+mod common;
+
+use std::sync::Arc;
+
+use a2a_rs::builders::ClientBuilder;
+use a2a_rs::client::{A2AClient, NdjsonStream};
+use a2a_rs::types::*;
+use a2a_rs::utils::{StreamEncoding, NDJSON_CONTENT_TYPE};
+use axum::response::IntoResponse;
+use axum::routing::post;
+use axum::Router;
+use common::{jsonrpc_request, start_test_server, EchoAgent};
+use futures::StreamExt;
+
+fn ndjson_client(base_url: &str) -> A2AClient {
+    ClientBuilder::new(format!("{base_url}/a2a"))
+        .with_stream_encoding(StreamEncoding::Ndjson)
+        .build_from_endpoint()
+}
+
+fn stream_request() -> serde_json::Value {
+    jsonrpc_request(
+        serde_json::json!(7),
+        "message/stream",
+        serde_json::json!({
+            "message": {
+                "messageId": "m1",
+                "role": "user",
+                "parts": [{"kind": "text", "text": "hello"}]
+            }
+        }),
+    )
+}
+
+#[tokio::test]
+async fn test_server_streams_ndjson_when_preferred() {
+    let (base_url, _server) = start_test_server(Arc::new(EchoAgent)).await;
+
+    let response = reqwest::Client::new()
+        .post(format!("{base_url}/a2a"))
+        .header("Accept", StreamEncoding::Ndjson.accept_header())
+        .json(&stream_request())
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.headers()["content-type"], NDJSON_CONTENT_TYPE);
+    assert_eq!(response.headers()["x-accel-buffering"], "no");
+
+    let body = response.text().await.unwrap();
+    let lines: Vec<serde_json::Value> = body
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert!(!lines.is_empty());
+    assert!(lines.iter().all(|line| line["id"] == 7));
+    let last = &lines.last().unwrap()["result"];
+    assert_eq!(last["kind"], "status-update");
+    assert_eq!(last["final"], true);
+
+    // The same request without a preference gets SSE.
+    let response = reqwest::Client::new()
+        .post(format!("{base_url}/a2a"))
+        .json(&stream_request())
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.headers()["content-type"], "text/event-stream");
+}
+
+#[tokio::test]
+async fn test_client_reads_ndjson_streams() {
+    let (base_url, _server) = start_test_server(Arc::new(EchoAgent)).await;
+    let client = ndjson_client(&base_url);
+
+    let mut stream = client.send_text_stream("hello").await.unwrap();
+    let mut events = Vec::new();
+    while let Some(event) = stream.next().await {
+        events.push(event.unwrap());
+    }
+    let Some(StreamResponse::StatusUpdate(last)) = events.last() else {
+        panic!("expected a final status update, got {events:?}");
+    };
+    assert!(last.r#final);
+    assert_eq!(last.status.state, TaskState::Completed);
+
+    let task = client.get_task_by_id(&last.task_id, None).await.unwrap();
+    assert_eq!(task.status.state, TaskState::Completed);
+}
+
+#[tokio::test]
+async fn test_client_falls_back_to_sse() {
+    // A server that only speaks SSE, whatever the client prefers.
+    let event = serde_json::json!({
+        "kind": "message", "messageId": "r1", "role": "agent",
+        "parts": [{"kind": "text", "text": "over sse"}]
+    });
+    let body = format!("event: message\ndata: {event}\n\n");
+    let app = Router::new().route(
+        "/a2a",
+        post(
+            move || async move { ([("content-type", "text/event-stream")], body).into_response() },
+        ),
+    );
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+    let mut stream = ndjson_client(&base_url)
+        .send_text_stream("hello")
+        .await
+        .unwrap();
+    let Some(Ok(StreamResponse::Message(message))) = stream.next().await else {
+        panic!("expected a message");
+    };
+    assert_eq!(message.message_id, "r1");
+    assert!(stream.next().await.is_none());
+}
+
+#[tokio::test]
+async fn test_ndjson_stream_parses_split_lines() {
+    let event = serde_json::json!({
+        "kind": "status-update", "taskId": "t1", "contextId": "c1",
+        "status": {"state": "working"}, "final": false
+    })
+    .to_string();
+    let (head, tail) = event.split_at(20);
+    let chunks = vec![
+        Ok::<_, std::io::Error>(bytes::Bytes::from(format!("\n{head}"))),
+        Ok(bytes::Bytes::from(format!("{tail}\n\n{{not json}}\n"))),
+    ];
+    let events: Vec<_> = NdjsonStream::new(futures::stream::iter(chunks))
+        .collect()
+        .await;
+    assert_eq!(events.len(), 2);
+    assert!(matches!(
+        &events[0],
+        Ok(StreamResponse::StatusUpdate(update)) if update.task_id == "t1"
+    ));
+    assert!(events[1].is_err());
+}
@@ -0,0 +1,162 @@
+//! `tasks/get` read-consistency hints and the client's not-found retry
+//! window for freshly created tasks.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use a2a_rs::client::A2AClient;
+use a2a_rs::types::*;
+use axum::routing::post;
+use axum::Router;
+use serde_json::{json, Value};
+
+/// Server that creates task `t1` on `message/send` but answers the first
+/// `misses` `tasks/get` calls with `TaskNotFound`, like a lagging replica.
+/// Records the params of every `tasks/get` call.
+async fn start_lagging_server(misses: usize) -> (String, Arc<Mutex<Vec<Value>>>) {
+    let gets: Arc<Mutex<Vec<Value>>> = Arc::new(Mutex::new(Vec::new()));
+    let sink = gets.clone();
+    let counter = Arc::new(AtomicUsize::new(0));
+
+    let app = Router::new().route(
+        "/a2a",
+        post(move |body: String| {
+            let sink = sink.clone();
+            let counter = counter.clone();
+            async move {
+                let req: Value = serde_json::from_str(&body).unwrap();
+                let task = json!({
+                    "kind": "task",
+                    "id": "t1",
+                    "contextId": "c1",
+                    "status": {"state": "submitted"}
+                });
+                if req["method"] == "tasks/get" {
+                    sink.lock().unwrap().push(req["params"].clone());
+                    if counter.fetch_add(1, Ordering::SeqCst) < misses {
+                        return axum::Json(json!({
+                            "jsonrpc": "2.0",
+                            "id": req["id"],
+                            "error": {"code": -32001, "message": "Task not found"}
+                        }));
+                    }
+                }
+                axum::Json(json!({"jsonrpc": "2.0", "id": req["id"], "result": task}))
+            }
+        }),
+    );
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::serve(listener, app).await.unwrap();
+    });
+
+    (format!("http://{}/a2a", addr), gets)
+}
+
+#[test]
+fn test_read_consistency_serde() {
+    assert_eq!(
+        serde_json::to_value(ReadConsistency::Primary).unwrap(),
+        json!({"mode": "primary"})
+    );
+    assert_eq!(
+        serde_json::to_value(ReadConsistency::AtLeastSequence { sequence: 42 }).unwrap(),
+        json!({"mode": "atLeastSequence", "sequence": 42})
+    );
+    let parsed: ReadConsistency = serde_json::from_value(json!({"mode": "eventual"})).unwrap();
+    assert_eq!(parsed, ReadConsistency::Eventual);
+    assert_eq!(ReadConsistency::default(), ReadConsistency::Eventual);
+}
+
+#[test]
+fn test_get_task_params_omit_consistency_by_default() {
+    let params = GetTaskParams {
+        id: "t1".to_string(),
+        history_length: None,
+        metadata: None,
+        tenant: None,
+        consistency: None,
+    };
+    let value = serde_json::to_value(&params).unwrap();
+    assert!(value.get("consistency").is_none());
+}
+
+#[tokio::test]
+async fn test_get_task_retries_not_found_for_recent_task() {
+    let (url, gets) = start_lagging_server(2).await;
+    let client = A2AClient::from_endpoint(&url).with_not_found_retry(Duration::from_secs(5));
+
+    client.send_text("hello").await.unwrap();
+    let task = client.get_task_by_id("t1", None).await.unwrap();
+    assert_eq!(task.id, "t1");
+
+    let gets = gets.lock().unwrap();
+    assert_eq!(gets.len(), 3);
+    // The first read goes out as-is; retries ask for the primary.
+    assert!(gets[0].get("consistency").is_none());
+    assert_eq!(gets[1]["consistency"], json!({"mode": "primary"}));
+    assert_eq!(gets[2]["consistency"], json!({"mode": "primary"}));
+}
+
+#[tokio::test]
+async fn test_get_task_keeps_explicit_consistency_on_retry() {
+    let (url, gets) = start_lagging_server(1).await;
+    let client = A2AClient::from_endpoint(&url).with_not_found_retry(Duration::from_secs(5));
+
+    client.send_text("hello").await.unwrap();
+    client
+        .get_task(GetTaskParams {
+            id: "t1".to_string(),
+            history_length: None,
+            metadata: None,
+            tenant: None,
+            consistency: Some(ReadConsistency::AtLeastSequence { sequence: 7 }),
+        })
+        .await
+        .unwrap();
+
+    let gets = gets.lock().unwrap();
+    assert_eq!(gets.len(), 2);
+    assert_eq!(
+        gets[1]["consistency"],
+        json!({"mode": "atLeastSequence", "sequence": 7})
+    );
+}
+
+#[tokio::test]
+async fn test_get_task_does_not_retry_without_option() {
+    let (url, gets) = start_lagging_server(1).await;
+    let client = A2AClient::from_endpoint(&url);
+
+    client.send_text("hello").await.unwrap();
+    let err = client.get_task_by_id("t1", None).await.unwrap_err();
+    assert!(matches!(
+        err,
+        a2a_rs::A2AError::JsonRpc { code: -32001, .. }
+    ));
+    assert_eq!(gets.lock().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_get_task_does_not_retry_unknown_task() {
+    let (url, gets) = start_lagging_server(1).await;
+    let client = A2AClient::from_endpoint(&url).with_not_found_retry(Duration::from_secs(5));
+
+    // Never created through this client, so a miss is final.
+    assert!(client.get_task_by_id("t1", None).await.is_err());
+    assert_eq!(gets.lock().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_get_task_gives_up_after_window() {
+    let (url, gets) = start_lagging_server(usize::MAX).await;
+    let client = A2AClient::from_endpoint(&url).with_not_found_retry(Duration::from_millis(120));
+
+    client.send_text("hello").await.unwrap();
+    assert!(client.get_task_by_id("t1", None).await.is_err());
+    let attempts = gets.lock().unwrap().len();
+    assert!((2..10).contains(&attempts), "attempts = {attempts}");
+}
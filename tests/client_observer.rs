@@ -0,0 +1,141 @@
+//! Tests for `TransportObserver` per-call metrics.
+//!
+//! Connection-level assertions (DNS, connect time, connection reuse) only
+//! run with `--features metrics`.
+
+use std::sync::{Arc, Mutex};
+
+use a2a_rs::client::{A2AClient, CallMetrics, JsonRpcTransport, TransportObserver};
+use axum::routing::post;
+use axum::Router;
+use serde_json::json;
+
+#[derive(Default)]
+struct Recorder {
+    calls: Mutex<Vec<CallMetrics>>,
+}
+
+impl TransportObserver for Recorder {
+    fn on_call(&self, metrics: &CallMetrics) {
+        self.calls.lock().unwrap().push(metrics.clone());
+    }
+}
+
+/// Start a server answering every JSON-RPC call with a completed task.
+async fn start_server() -> std::net::SocketAddr {
+    let app = Router::new().route(
+        "/a2a",
+        post(|body: String| async move {
+            let req: serde_json::Value = serde_json::from_str(&body).unwrap();
+            axum::Json(json!({
+                "jsonrpc": "2.0",
+                "id": req["id"],
+                "result": {
+                    "kind": "task",
+                    "id": "t1",
+                    "contextId": "c1",
+                    "status": {"state": "completed"}
+                }
+            }))
+        }),
+    );
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::serve(listener, app).await.unwrap();
+    });
+    addr
+}
+
+#[tokio::test]
+async fn test_observer_receives_call_metrics() {
+    let addr = start_server().await;
+    let recorder = Arc::new(Recorder::default());
+    let url = format!("http://{}/a2a", addr);
+    let transport = JsonRpcTransport::new(&url).with_observer(recorder.clone());
+    let client = A2AClient::with_transport(Box::new(transport));
+
+    client.get_task_by_id("t1", None).await.unwrap();
+
+    let calls = recorder.calls.lock().unwrap();
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].method, "tasks/get");
+    assert_eq!(calls[0].url, url);
+    assert_eq!(calls[0].status, Some(200));
+    assert!(!calls[0].streaming);
+    assert!(calls[0].error.is_none());
+}
+
+#[tokio::test]
+async fn test_observer_survives_builder_rebuilds() {
+    let addr = start_server().await;
+    let recorder = Arc::new(Recorder::default());
+    let transport = JsonRpcTransport::new(format!("http://{}/a2a", addr))
+        .with_observer(recorder.clone())
+        .with_header("X-Test", "1")
+        .with_timeout(std::time::Duration::from_secs(5));
+    let client = A2AClient::with_transport(Box::new(transport));
+
+    client.get_task_by_id("t1", None).await.unwrap();
+    assert_eq!(recorder.calls.lock().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_observer_reports_transport_errors() {
+    // Bind then drop a listener to get a port nothing is listening on.
+    let addr = tokio::net::TcpListener::bind("127.0.0.1:0")
+        .await
+        .unwrap()
+        .local_addr()
+        .unwrap();
+    let recorder = Arc::new(Recorder::default());
+    let transport =
+        JsonRpcTransport::new(format!("http://{}/a2a", addr)).with_observer(recorder.clone());
+    let client = A2AClient::with_transport(Box::new(transport));
+
+    assert!(client.get_task_by_id("t1", None).await.is_err());
+
+    let calls = recorder.calls.lock().unwrap();
+    assert_eq!(calls.len(), 1);
+    assert!(calls[0].status.is_none());
+    assert!(calls[0].error.is_some());
+}
+
+#[cfg(feature = "metrics")]
+#[tokio::test]
+async fn test_connection_metrics_and_reuse() {
+    let addr = start_server().await;
+    let recorder = Arc::new(Recorder::default());
+    // Use a hostname so the timing resolver is exercised.
+    let transport = JsonRpcTransport::new(format!("http://localhost:{}/a2a", addr.port()))
+        .with_observer(recorder.clone());
+    let client = A2AClient::with_transport(Box::new(transport));
+
+    client.get_task_by_id("t1", None).await.unwrap();
+    client.get_task_by_id("t1", None).await.unwrap();
+
+    let calls = recorder.calls.lock().unwrap();
+    assert_eq!(calls[0].reused_connection, Some(false));
+    assert!(calls[0].connect.is_some());
+    assert!(calls[0].dns.is_some());
+
+    assert_eq!(calls[1].reused_connection, Some(true));
+    assert!(calls[1].connect.is_none());
+    assert!(calls[1].dns.is_none());
+}
+
+#[cfg(not(feature = "metrics"))]
+#[tokio::test]
+async fn test_connection_metrics_absent_without_feature() {
+    let addr = start_server().await;
+    let recorder = Arc::new(Recorder::default());
+    let transport =
+        JsonRpcTransport::new(format!("http://{}/a2a", addr)).with_observer(recorder.clone());
+    let client = A2AClient::with_transport(Box::new(transport));
+
+    client.get_task_by_id("t1", None).await.unwrap();
+
+    let calls = recorder.calls.lock().unwrap();
+    assert!(calls[0].reused_connection.is_none());
+    assert!(calls[0].connect.is_none());
+}
@@ -0,0 +1,140 @@
+//! `A2AClient::wait_for_completion`: wait for a task by streaming or by
+//! polling with backoff, with typed errors for failed and rejected tasks.
+
+mod common;
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use a2a_rs::client::{A2AClient, PollOptions};
+use a2a_rs::error::{A2AError, A2AResult};
+use a2a_rs::server::{AgentExecutor, EventQueue, RequestContext, TaskUpdater};
+use a2a_rs::types::*;
+use async_trait::async_trait;
+
+/// Works for a moment, then acts on the message: `fail`, `reject`, `ask`,
+/// `hang` (never finishes) or anything else (completes).
+struct DelayedAgent;
+
+#[async_trait]
+impl AgentExecutor for DelayedAgent {
+    async fn execute(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        let updater = TaskUpdater::new(
+            event_queue,
+            context.task_id.clone(),
+            context.context_id.clone(),
+        );
+        updater.start_work(None).await?;
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        match context.get_user_input("").as_str() {
+            "fail" => updater.failed_with_text("boom").await,
+            "reject" => updater.reject(None).await,
+            "ask" => updater.requires_input(None, true).await,
+            "hang" => std::future::pending().await,
+            _ => updater.complete_with_text("done").await,
+        }
+    }
+
+    async fn cancel(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        let updater = TaskUpdater::new(event_queue, context.task_id, context.context_id);
+        updater.cancel(None).await
+    }
+}
+
+/// Start a task saying `text` and return its ID without waiting for it.
+async fn start_task(client: &A2AClient, text: &str) -> String {
+    let mut stream = client.send_text_stream(text).await.unwrap();
+    match stream.next().await.unwrap().unwrap() {
+        StreamResponse::Task(task) => task.id,
+        StreamResponse::StatusUpdate(update) => update.task_id,
+        other => panic!("unexpected first event {other:?}"),
+    }
+}
+
+async fn client() -> A2AClient {
+    let (base_url, _server) = common::start_test_server(Arc::new(DelayedAgent)).await;
+    let url = format!("{base_url}/a2a");
+    A2AClient::from_endpoint(&url).with_agent_card(common::test_agent_card(&url))
+}
+
+fn polling() -> PollOptions {
+    PollOptions::new()
+        .with_interval(Duration::from_millis(20))
+        .with_streaming(false)
+}
+
+#[tokio::test]
+async fn test_waits_by_polling_and_by_streaming() {
+    let client = client().await;
+
+    let task_id = start_task(&client, "hello").await;
+    let task = client
+        .wait_for_completion(&task_id, &polling())
+        .await
+        .unwrap();
+    assert_eq!(task.status.state, TaskState::Completed);
+
+    let task_id = start_task(&client, "hello").await;
+    let task = client
+        .wait_for_completion(&task_id, &PollOptions::new())
+        .await
+        .unwrap();
+    assert_eq!(task.id, task_id);
+    assert_eq!(task.status.state, TaskState::Completed);
+}
+
+#[tokio::test]
+async fn test_failed_and_rejected_are_typed_errors() {
+    let client = client().await;
+
+    let task_id = start_task(&client, "fail").await;
+    match client.wait_for_completion(&task_id, &polling()).await {
+        Err(A2AError::TaskFailed { message, task }) => {
+            assert_eq!(message, "boom");
+            assert_eq!(task.id, task_id);
+        }
+        other => panic!("expected TaskFailed, got {other:?}"),
+    }
+
+    let task_id = start_task(&client, "reject").await;
+    let err = client
+        .wait_for_completion(&task_id, &PollOptions::new())
+        .await
+        .unwrap_err();
+    assert!(matches!(err, A2AError::TaskRejected { .. }), "{err:?}");
+    assert!(err.to_string().contains("no reason given"), "{err}");
+}
+
+#[tokio::test]
+async fn test_returns_interrupted_task() {
+    let client = client().await;
+    let task_id = start_task(&client, "ask").await;
+    let task = client
+        .wait_for_completion(&task_id, &polling())
+        .await
+        .unwrap();
+    assert_eq!(task.status.state, TaskState::InputRequired);
+}
+
+#[tokio::test]
+async fn test_timeout_and_missing_task() {
+    let client = client().await;
+    let task_id = start_task(&client, "hang").await;
+    let err = client
+        .wait_for_completion(
+            &task_id,
+            &polling().with_timeout(Duration::from_millis(300)),
+        )
+        .await
+        .unwrap_err();
+    assert!(matches!(err, A2AError::Timeout(_)), "{err:?}");
+
+    let err = client
+        .wait_for_completion("missing", &polling())
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(err, A2AError::JsonRpc { code: -32001, .. }),
+        "{err:?}"
+    );
+}
@@ -0,0 +1,143 @@
+//! `RetryPolicy` on `JsonRpcTransport`: transient failures are retried
+//! for idempotent methods and sends with a `messageId` only.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use a2a_rs::client::{A2AClient, JsonRpcTransport, RetryPolicy, TransportConfig};
+use a2a_rs::error::A2AError;
+use a2a_rs::types::*;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::routing::post;
+use axum::Router;
+use serde_json::{json, Value};
+
+/// An agent failing the first `failures` requests with 502, then answering
+/// with a completed task. Records the method of every request.
+#[derive(Clone, Default)]
+struct FlakyAgent {
+    failures: Arc<AtomicUsize>,
+    methods: Arc<Mutex<Vec<String>>>,
+}
+
+async fn handle(
+    State(agent): State<FlakyAgent>,
+    axum::Json(request): axum::Json<Value>,
+) -> Response {
+    agent
+        .methods
+        .lock()
+        .unwrap()
+        .push(request["method"].as_str().unwrap().to_string());
+    let failing = agent
+        .failures
+        .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+        .is_ok();
+    if failing {
+        return (StatusCode::BAD_GATEWAY, "upstream unavailable").into_response();
+    }
+    let task = json!({
+        "id": "t1",
+        "contextId": "c1",
+        "kind": "task",
+        "status": {"state": "completed"}
+    });
+    axum::Json(json!({"jsonrpc": "2.0", "id": request["id"], "result": task})).into_response()
+}
+
+async fn start(failures: usize) -> (FlakyAgent, String) {
+    let agent = FlakyAgent::default();
+    agent.failures.store(failures, Ordering::SeqCst);
+    let app = Router::new()
+        .route("/a2a", post(handle))
+        .with_state(agent.clone());
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/a2a", listener.local_addr().unwrap());
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+    (agent, url)
+}
+
+fn policy() -> RetryPolicy {
+    RetryPolicy::new()
+        .with_max_retries(3)
+        .with_initial_backoff(Duration::from_millis(5))
+}
+
+fn client(url: &str, policy: RetryPolicy) -> A2AClient {
+    let config = TransportConfig::default().retry(policy);
+    A2AClient::with_transport(Box::new(JsonRpcTransport::with_config(url, config)))
+}
+
+fn calls(agent: &FlakyAgent) -> Vec<String> {
+    std::mem::take(&mut *agent.methods.lock().unwrap())
+}
+
+#[tokio::test]
+async fn test_idempotent_calls_are_retried() {
+    let (agent, url) = start(2).await;
+    let client = client(&url, policy());
+
+    let task = client.get_task_by_id("t1", None).await.unwrap();
+    assert_eq!(task.status.state, TaskState::Completed);
+    assert_eq!(calls(&agent), ["tasks/get"; 3]);
+
+    // Not idempotent: the first 502 is final.
+    agent.failures.store(1, Ordering::SeqCst);
+    let err = client.cancel_task_by_id("t1").await.unwrap_err();
+    assert!(matches!(err, A2AError::Http { status: 502, .. }), "{err}");
+    assert_eq!(calls(&agent), ["tasks/cancel"]);
+}
+
+#[tokio::test]
+async fn test_sends_with_message_id_are_retried() {
+    let (agent, url) = start(1).await;
+
+    client(&url, policy()).send_text("hello").await.unwrap();
+    assert_eq!(calls(&agent), ["message/send"; 2]);
+
+    agent.failures.store(1, Ordering::SeqCst);
+    let err = client(&url, policy().with_retry_sends(false))
+        .send_text("hello")
+        .await
+        .unwrap_err();
+    assert!(matches!(err, A2AError::Http { status: 502, .. }), "{err}");
+    assert_eq!(calls(&agent), ["message/send"]);
+
+    agent.failures.store(1, Ordering::SeqCst);
+    let params = SendMessageParams {
+        message: Message::user("", "no id"),
+        configuration: None,
+        metadata: None,
+        tenant: None,
+    };
+    let err = client(&url, policy())
+        .send_message(params)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, A2AError::Http { status: 502, .. }), "{err}");
+    assert_eq!(calls(&agent), ["message/send"]);
+}
+
+#[tokio::test]
+async fn test_retries_are_bounded() {
+    let (agent, url) = start(10).await;
+
+    let err = client(&url, policy().with_max_retries(2))
+        .get_task_by_id("t1", None)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, A2AError::Http { status: 502, .. }), "{err}");
+    assert_eq!(calls(&agent).len(), 3);
+
+    // Without a policy, nothing is retried.
+    let transport = JsonRpcTransport::new(&url);
+    let err = A2AClient::with_transport(Box::new(transport))
+        .get_task_by_id("t1", None)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, A2AError::Http { status: 502, .. }), "{err}");
+    assert_eq!(calls(&agent).len(), 1);
+}
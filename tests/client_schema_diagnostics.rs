@@ -0,0 +1,157 @@
+//! Schema diagnostics: responses that don't match the A2A types are
+//! explained with the offending field path, expected and found types and a
+//! payload excerpt.
+
+use a2a_rs::client::{diagnose, A2AClient, EXCERPT_LIMIT};
+use a2a_rs::error::A2AError;
+use a2a_rs::types::*;
+use axum::response::sse::{Event, Sse};
+use axum::response::IntoResponse;
+use axum::routing::post;
+use axum::Router;
+use serde_json::{json, Value};
+
+/// A task whose second history message has a part without `kind`.
+fn malformed_task() -> Value {
+    json!({
+        "kind": "task",
+        "id": "t1",
+        "contextId": "c1",
+        "status": {"state": "working"},
+        "history": [
+            {"kind": "message", "messageId": "m1", "role": "user", "parts": [{"kind": "text", "text": "hi"}]},
+            {"kind": "message", "messageId": "m2", "role": "agent", "parts": [{"text": "no kind"}]}
+        ]
+    })
+}
+
+/// Server answering every call with [`malformed_task()`], as a unary
+/// result or a single SSE event.
+async fn start_malformed_server() -> String {
+    let app = Router::new().route(
+        "/a2a",
+        post(|body: String| async move {
+            let req: Value = serde_json::from_str(&body).unwrap();
+            let frame = json!({"jsonrpc": "2.0", "id": req["id"], "result": malformed_task()});
+            if req["method"] == "message/stream" {
+                let stream = futures::stream::once(async move {
+                    Ok::<_, std::convert::Infallible>(Event::default().data(frame.to_string()))
+                });
+                return Sse::new(stream).into_response();
+            }
+            axum::Json(frame).into_response()
+        }),
+    );
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::serve(listener, app).await.unwrap();
+    });
+    format!("http://{}/a2a", addr)
+}
+
+fn invalid_json(err: A2AError) -> String {
+    match err {
+        A2AError::InvalidJson(message) => message,
+        other => panic!("expected InvalidJson, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_diagnose_points_at_nested_field() {
+    let diagnostic = diagnose::<Task>(&malformed_task()).unwrap();
+    assert_eq!(diagnostic.target, "Task");
+    assert_eq!(diagnostic.path, "history[1].parts[0]");
+    assert!(diagnostic.message.contains("kind"), "{diagnostic:?}");
+    assert_eq!(diagnostic.found, Some("object"));
+    assert_eq!(diagnostic.excerpt, r#"{"text":"no kind"}"#);
+}
+
+#[test]
+fn test_diagnose_reports_expected_and_found_types() {
+    let value = json!({"kind": "message", "messageId": 42, "role": "user", "parts": []});
+    let diagnostic = diagnose::<Message>(&value).unwrap();
+    assert_eq!(diagnostic.path, "messageId");
+    assert!(
+        diagnostic.message.contains("expected a string"),
+        "{diagnostic:?}"
+    );
+    assert_eq!(diagnostic.found, Some("number"));
+
+    let missing = json!({"kind": "task", "contextId": "c1", "status": {"state": "working"}});
+    let diagnostic = diagnose::<Task>(&missing).unwrap();
+    assert_eq!(diagnostic.path, ".");
+    assert!(diagnostic.message.contains("missing field `id`"));
+
+    assert!(diagnose::<Task>(
+        &json!({"kind": "task", "id": "t", "contextId": "c", "status": {"state": "working"}})
+    )
+    .is_none());
+}
+
+#[test]
+fn test_diagnose_descends_into_kind_tagged_wrappers() {
+    let diagnostic = diagnose::<SendMessageResponse>(&malformed_task()).unwrap();
+    assert_eq!(diagnostic.target, "Task");
+    assert_eq!(diagnostic.path, "history[1].parts[0]");
+
+    let update = json!({
+        "kind": "status-update",
+        "taskId": "t1",
+        "contextId": "c1",
+        "status": {"state": "working", "timestamp": false},
+        "final": false
+    });
+    let diagnostic = diagnose::<StreamResponse>(&update).unwrap();
+    assert_eq!(diagnostic.target, "TaskStatusUpdateEvent");
+    assert_eq!(diagnostic.path, "status.timestamp");
+    assert_eq!(diagnostic.found, Some("boolean"));
+}
+
+#[test]
+fn test_excerpt_is_truncated() {
+    let mut task = malformed_task();
+    task.as_object_mut().unwrap().remove("history");
+    task["metadata"] = json!({"padding": "x".repeat(1000)});
+    task["id"] = json!(7);
+    let diagnostic = diagnose::<Task>(&task).unwrap();
+    assert_eq!(diagnostic.path, "id");
+    assert_eq!(diagnostic.excerpt.chars().count(), EXCERPT_LIMIT + 1);
+    assert!(diagnostic.excerpt.ends_with('…'));
+}
+
+#[tokio::test]
+async fn test_client_diagnostics_are_opt_in() {
+    let url = start_malformed_server().await;
+
+    let plain = invalid_json(
+        A2AClient::from_endpoint(&url)
+            .get_task_by_id("t1", None)
+            .await
+            .unwrap_err(),
+    );
+    assert!(!plain.contains("history[1].parts[0]"), "{plain}");
+
+    let client = A2AClient::from_endpoint(&url).with_schema_diagnostics(true);
+    let message = invalid_json(client.get_task_by_id("t1", None).await.unwrap_err());
+    assert!(
+        message.contains("Task at `history[1].parts[0]`"),
+        "{message}"
+    );
+    assert!(message.contains(r#"near {"text":"no kind"}"#), "{message}");
+
+    let message = invalid_json(client.send_text("hi").await.unwrap_err());
+    assert!(message.contains("`history[1].parts[0]`"), "{message}");
+}
+
+#[tokio::test]
+async fn test_stream_errors_carry_the_path() {
+    let client = A2AClient::from_endpoint(&start_malformed_server().await);
+    let mut stream = client.send_text_stream("hi").await.unwrap();
+    let message = invalid_json(stream.next().await.unwrap().unwrap_err());
+    assert!(
+        message.contains("Task at `history[1].parts[0]`"),
+        "{message}"
+    );
+}
@@ -0,0 +1,337 @@
+//! `A2AClient::send_text_with_files`: inline attachments, uploads and the
+//! streaming variant; `send_file`, `send_data` and `MessageBuilder`.
+
+mod common;
+
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use a2a_rs::builders::ClientBuilder;
+use a2a_rs::client::{A2AClient, FileSource, FileUploader, MessageBuilder};
+use a2a_rs::error::{A2AError, A2AResult};
+use a2a_rs::server::{AgentExecutor, EventQueue, RequestContext, TaskUpdater};
+use a2a_rs::types::*;
+use async_trait::async_trait;
+
+/// Records the parts of every message it receives, then completes.
+#[derive(Default)]
+struct RecordingAgent {
+    received: Mutex<Vec<Vec<Part>>>,
+}
+
+#[async_trait]
+impl AgentExecutor for RecordingAgent {
+    async fn execute(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        let parts = context.message.as_ref().unwrap().parts.clone();
+        self.received.lock().unwrap().push(parts);
+        TaskUpdater::new(event_queue, context.task_id, context.context_id)
+            .complete_with_text("got it")
+            .await
+    }
+
+    async fn cancel(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        TaskUpdater::new(event_queue, context.task_id, context.context_id)
+            .cancel(None)
+            .await
+    }
+}
+
+/// Pretends to upload, recording the calls.
+#[derive(Default)]
+struct FakeUploader {
+    uploads: Mutex<Vec<(PathBuf, String)>>,
+}
+
+#[async_trait]
+impl FileUploader for FakeUploader {
+    async fn upload(&self, path: &Path, mime_type: &str) -> A2AResult<String> {
+        self.uploads
+            .lock()
+            .unwrap()
+            .push((path.to_path_buf(), mime_type.to_string()));
+        let name = path.file_name().unwrap().to_string_lossy();
+        Ok(format!("https://files.example/{name}"))
+    }
+}
+
+/// A fresh directory holding `files`.
+fn write_files(files: &[(&str, &[u8])]) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("a2a-files-test-{}", uuid::Uuid::new_v4()));
+    std::fs::create_dir_all(&dir).unwrap();
+    for (name, content) in files {
+        std::fs::write(dir.join(name), content).unwrap();
+    }
+    dir
+}
+
+async fn start() -> (Arc<RecordingAgent>, String) {
+    let agent = Arc::new(RecordingAgent::default());
+    let (base_url, _handle) = common::start_test_server(agent.clone()).await;
+    (agent, format!("{base_url}/a2a"))
+}
+
+#[tokio::test]
+async fn test_small_files_are_sent_inline() {
+    let (agent, url) = start().await;
+    let dir = write_files(&[("notes.txt", b"hello"), ("chart.PNG", &[0x89, b'P', 0])]);
+    let client = A2AClient::from_endpoint(&url);
+
+    client
+        .send_text_with_files("summarize", &[dir.join("notes.txt"), dir.join("chart.PNG")])
+        .await
+        .unwrap();
+
+    let parts = agent.received.lock().unwrap()[0].clone();
+    assert_eq!(parts.len(), 3);
+    assert!(matches!(&parts[0], Part::Text { text, .. } if text == "summarize"));
+    let files: Vec<_> = parts[1..]
+        .iter()
+        .map(|part| match part {
+            Part::File {
+                file: FileContent::Bytes(file),
+                ..
+            } => file.clone(),
+            other => panic!("expected inline file, got {other:?}"),
+        })
+        .collect();
+    assert_eq!(files[0].name.as_deref(), Some("notes.txt"));
+    assert_eq!(files[0].mime_type.as_deref(), Some("text/plain"));
+    assert_eq!(files[0].decoded_bytes().unwrap(), b"hello");
+    assert_eq!(files[1].mime_type.as_deref(), Some("image/png"));
+    assert_eq!(files[1].decoded_bytes().unwrap(), [0x89, b'P', 0]);
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[tokio::test]
+async fn test_large_files_are_uploaded() {
+    let (agent, url) = start().await;
+    let dir = write_files(&[("small.json", b"{}"), ("big.csv", b"a,b\n1,2\n")]);
+    let uploader = Arc::new(FakeUploader::default());
+    let client = ClientBuilder::new(&url)
+        .with_inline_file_limit(4)
+        .with_file_uploader(uploader.clone())
+        .build_from_endpoint();
+
+    client
+        .send_text_with_files("load", &[dir.join("small.json"), dir.join("big.csv")])
+        .await
+        .unwrap();
+
+    assert_eq!(
+        *uploader.uploads.lock().unwrap(),
+        [(dir.join("big.csv"), "text/csv".to_string())]
+    );
+    let parts = agent.received.lock().unwrap()[0].clone();
+    assert!(matches!(
+        &parts[1],
+        Part::File { file: FileContent::Bytes(f), .. } if f.bytes == "e30="
+    ));
+    match &parts[2] {
+        Part::File {
+            file: FileContent::Uri(file),
+            ..
+        } => {
+            assert_eq!(file.uri, "https://files.example/big.csv");
+            assert_eq!(file.name.as_deref(), Some("big.csv"));
+            assert_eq!(file.mime_type.as_deref(), Some("text/csv"));
+        }
+        other => panic!("expected URI file, got {other:?}"),
+    }
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[tokio::test]
+async fn test_nothing_sent_when_a_file_cannot_be_attached() {
+    let (agent, url) = start().await;
+    let dir = write_files(&[("big.bin", &[0; 16])]);
+    let client = A2AClient::from_endpoint(&url).with_inline_file_limit(8);
+
+    let err = client
+        .send_text_with_files("x", &[dir.join("big.bin")])
+        .await
+        .unwrap_err();
+    assert!(matches!(err, A2AError::InvalidParams { .. }), "{err}");
+    assert!(err.to_string().contains("FileUploader"), "{err}");
+
+    let err = client
+        .send_text_with_files("x", &[dir.join("missing.txt")])
+        .await
+        .unwrap_err();
+    assert!(matches!(err, A2AError::InvalidParams { .. }), "{err}");
+    assert!(err.to_string().contains("missing.txt"), "{err}");
+
+    assert!(agent.received.lock().unwrap().is_empty());
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[tokio::test]
+async fn test_streaming_variant() {
+    let (agent, url) = start().await;
+    let dir = write_files(&[("a.md", b"# hi")]);
+    let client = A2AClient::from_endpoint(&url);
+
+    let mut stream = client
+        .send_text_with_files_stream("render", &[dir.join("a.md")])
+        .await
+        .unwrap();
+    let mut last_state = None;
+    while let Some(event) = stream.next().await {
+        if let StreamResponse::StatusUpdate(update) = event.unwrap() {
+            last_state = Some(update.status.state);
+        }
+    }
+    assert_eq!(last_state, Some(TaskState::Completed));
+
+    let parts = agent.received.lock().unwrap()[0].clone();
+    assert!(matches!(
+        &parts[1],
+        Part::File { file: FileContent::Bytes(f), .. } if f.mime_type.as_deref() == Some("text/markdown")
+    ));
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+fn inline_file(part: &Part) -> FileWithBytes {
+    match part {
+        Part::File {
+            file: FileContent::Bytes(file),
+            ..
+        } => file.clone(),
+        other => panic!("expected inline file, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn test_send_file_from_path_or_bytes() {
+    let (agent, url) = start().await;
+    let dir = write_files(&[("data.json", b"[1]")]);
+    let client = A2AClient::from_endpoint(&url);
+
+    client.send_file(dir.join("data.json"), None).await.unwrap();
+    client
+        .send_file(b"raw", Some("application/x-custom"))
+        .await
+        .unwrap();
+    client.send_file(vec![1, 2], None).await.unwrap();
+
+    let received = agent.received.lock().unwrap().clone();
+    assert!(received.iter().all(|parts| parts.len() == 1));
+    let from_path = inline_file(&received[0][0]);
+    assert_eq!(from_path.name.as_deref(), Some("data.json"));
+    assert_eq!(from_path.mime_type.as_deref(), Some("application/json"));
+    assert_eq!(from_path.decoded_bytes().unwrap(), b"[1]");
+    let typed = inline_file(&received[1][0]);
+    assert_eq!(typed.name, None);
+    assert_eq!(typed.mime_type.as_deref(), Some("application/x-custom"));
+    assert_eq!(typed.decoded_bytes().unwrap(), b"raw");
+    let untyped = inline_file(&received[2][0]);
+    assert_eq!(
+        untyped.mime_type.as_deref(),
+        Some("application/octet-stream")
+    );
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[tokio::test]
+async fn test_send_data_and_streaming_counterparts() {
+    let (agent, url) = start().await;
+    let client = A2AClient::from_endpoint(&url);
+    let value = serde_json::json!({"city": "Berlin", "days": 3});
+
+    let response = client.send_data(value.clone()).await.unwrap();
+    assert!(matches!(response, SendMessageResponse::Task(_)));
+    for mut stream in [
+        client.send_data_stream(value.clone()).await.unwrap(),
+        client.send_file_stream(b"abc", None).await.unwrap(),
+    ] {
+        while let Some(event) = stream.next().await {
+            event.unwrap();
+        }
+    }
+
+    let received = agent.received.lock().unwrap().clone();
+    assert_eq!(received.len(), 3);
+    for parts in &received[..2] {
+        assert!(matches!(&parts[..], [Part::Data { data, .. }] if *data == value));
+    }
+    assert_eq!(
+        inline_file(&received[2][0]).decoded_bytes().unwrap(),
+        b"abc"
+    );
+}
+
+#[tokio::test]
+async fn test_message_builder_parts_and_ids() {
+    let (agent, url) = start().await;
+    let dir = write_files(&[("big.csv", b"a,b\n1,2\n")]);
+    let uploader = Arc::new(FakeUploader::default());
+    let client = ClientBuilder::new(&url)
+        .with_inline_file_limit(4)
+        .with_file_uploader(uploader.clone())
+        .build_from_endpoint();
+
+    let response = client
+        .send(
+            MessageBuilder::new()
+                .text("compare")
+                .file(dir.join("big.csv"), None)
+                .file(&b"xy"[..], Some("text/plain"))
+                .data(serde_json::json!({"k": 1}))
+                .context_id("ctx-42"),
+        )
+        .await
+        .unwrap();
+    let SendMessageResponse::Task(task) = response else {
+        panic!("expected a task");
+    };
+    assert_eq!(task.context_id, "ctx-42");
+
+    let parts = agent.received.lock().unwrap()[0].clone();
+    assert_eq!(parts.len(), 4);
+    assert!(matches!(&parts[0], Part::Text { text, .. } if text == "compare"));
+    assert!(matches!(
+        &parts[1],
+        Part::File { file: FileContent::Uri(f), .. } if f.uri == "https://files.example/big.csv"
+    ));
+    // Bytes are never uploaded, whatever their size.
+    assert_eq!(inline_file(&parts[2]).decoded_bytes().unwrap(), b"xy");
+    assert!(matches!(&parts[3], Part::Data { .. }));
+    assert_eq!(uploader.uploads.lock().unwrap().len(), 1);
+
+    // The task ID is sent: the server refuses to continue a finished task.
+    let err = client
+        .send(MessageBuilder::new().text("more").task_id(&task.id))
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("terminal state"), "{err}");
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[tokio::test]
+async fn test_message_builder_build_inlines_files() {
+    let dir = write_files(&[("notes.txt", b"hello")]);
+
+    let message = MessageBuilder::new()
+        .file(dir.join("notes.txt"), None)
+        .task_id("t1")
+        .build()
+        .await
+        .unwrap();
+    assert_eq!(message.role, Role::User);
+    assert_eq!(message.task_id.as_deref(), Some("t1"));
+    assert_eq!(message.context_id, None);
+    assert_eq!(
+        inline_file(&message.parts[0]).decoded_bytes().unwrap(),
+        b"hello"
+    );
+
+    let err = MessageBuilder::new()
+        .file(FileSource::Path(dir.join("missing.txt")), None)
+        .build()
+        .await
+        .unwrap_err();
+    assert!(matches!(err, A2AError::InvalidParams { .. }), "{err}");
+    std::fs::remove_dir_all(dir).unwrap();
+}
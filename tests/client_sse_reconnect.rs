@@ -0,0 +1,195 @@
+//! SSE reconnects: streams that drop mid-task resume via
+//! `tasks/resubscribe` with `Last-Event-ID`, skip replayed events, and
+//! fail only once the retry budget is spent.
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use a2a_rs::builders::ClientBuilder;
+use a2a_rs::client::{A2AClient, SseReconnectPolicy, SseStream, LAST_EVENT_ID_HEADER};
+use a2a_rs::error::A2AError;
+use a2a_rs::types::StreamResponse;
+use axum::body::Body;
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::routing::post;
+use axum::Router;
+use futures::StreamExt;
+use serde_json::{json, Value};
+
+fn status(state: &str, r#final: bool) -> Value {
+    json!({
+        "kind": "status-update",
+        "taskId": "t1",
+        "contextId": "c1",
+        "status": {"state": state},
+        "final": r#final
+    })
+}
+
+fn artifact(text: &str) -> Value {
+    json!({
+        "kind": "artifact-update",
+        "taskId": "t1",
+        "contextId": "c1",
+        "artifact": {"artifactId": "a1", "parts": [{"kind": "text", "text": text}]}
+    })
+}
+
+/// An SSE body sending `frames` as `(id, event)` and then, if `drop`,
+/// aborting the connection.
+fn sse(frames: Vec<(u32, Value)>, drop: bool) -> Response {
+    let chunks = frames
+        .into_iter()
+        .map(|(id, event)| Ok(format!("id: {id}\ndata: {event}\n\n")));
+    let abort = futures::stream::once(async {
+        // Let the frames reach the client before the connection breaks.
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        Err(std::io::Error::new(
+            std::io::ErrorKind::ConnectionReset,
+            "connection reset",
+        ))
+    })
+    .take(usize::from(drop));
+    (
+        [("content-type", "text/event-stream")],
+        Body::from_stream(futures::stream::iter(chunks).chain(abort)),
+    )
+        .into_response()
+}
+
+/// `Last-Event-ID` of each `tasks/resubscribe` received.
+type Resubscribes = Arc<Mutex<Vec<Option<String>>>>;
+
+/// A server whose `message/stream` sends two events and drops. Each
+/// `tasks/resubscribe` is answered by `resubscribe` (given the attempt
+/// number).
+async fn start_server(resubscribe: fn(usize) -> Response) -> (String, Resubscribes) {
+    let seen = Resubscribes::default();
+    let recorded = seen.clone();
+    let app = Router::new().route(
+        "/a2a",
+        post(move |headers: HeaderMap, body: String| {
+            let seen = recorded.clone();
+            async move {
+                let req: Value = serde_json::from_str(&body).unwrap();
+                match req["method"].as_str() {
+                    Some("message/stream") => sse(
+                        vec![(1, status("working", false)), (2, artifact("part one"))],
+                        true,
+                    ),
+                    Some("tasks/resubscribe") => {
+                        assert_eq!(req["params"]["id"], "t1");
+                        let last = headers
+                            .get(LAST_EVENT_ID_HEADER)
+                            .map(|v| v.to_str().unwrap().to_string());
+                        let attempt = {
+                            let mut seen = seen.lock().unwrap();
+                            seen.push(last);
+                            seen.len()
+                        };
+                        resubscribe(attempt)
+                    }
+                    _ => StatusCode::NOT_FOUND.into_response(),
+                }
+            }
+        }),
+    );
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::serve(listener, app).await.unwrap();
+    });
+    (format!("http://{addr}/a2a"), seen)
+}
+
+fn client(url: &str, retries: u32) -> A2AClient {
+    ClientBuilder::new(url)
+        .with_sse_reconnect(
+            SseReconnectPolicy::new()
+                .with_max_retries(retries)
+                .with_initial_backoff(Duration::from_millis(10)),
+        )
+        .build_from_endpoint()
+}
+
+async fn drain(mut stream: SseStream) -> (Vec<StreamResponse>, Option<A2AError>) {
+    let mut events = Vec::new();
+    while let Some(event) = stream.next().await {
+        match event {
+            Ok(event) => events.push(event),
+            Err(e) => return (events, Some(e)),
+        }
+    }
+    (events, None)
+}
+
+#[tokio::test]
+async fn test_stream_resumes_after_dropped_connection() {
+    // The first attempt is refused, the second replays event 2 and then
+    // finishes the task.
+    let (url, resubscribes) = start_server(|attempt| match attempt {
+        1 => StatusCode::SERVICE_UNAVAILABLE.into_response(),
+        _ => sse(
+            vec![
+                (2, artifact("part one")),
+                (3, artifact("part two")),
+                (4, status("completed", true)),
+            ],
+            false,
+        ),
+    })
+    .await;
+
+    let stream = client(&url, 3).send_text_stream("hi").await.unwrap();
+    let (events, error) = drain(stream).await;
+    assert!(error.is_none(), "{error:?}");
+
+    let kinds: Vec<&str> = events.iter().map(|e| e.kind()).collect();
+    assert_eq!(
+        kinds,
+        [
+            "status-update",
+            "artifact-update",
+            "artifact-update",
+            "status-update"
+        ]
+    );
+    let StreamResponse::ArtifactUpdate(second) = &events[2] else {
+        unreachable!()
+    };
+    assert_eq!(
+        a2a_rs::utils::get_text_parts(&second.artifact.parts),
+        ["part two"]
+    );
+    assert_eq!(
+        *resubscribes.lock().unwrap(),
+        [Some("2".to_string()), Some("2".to_string())]
+    );
+}
+
+#[tokio::test]
+async fn test_error_surfaces_once_retries_are_exhausted() {
+    let (url, resubscribes) = start_server(|_| StatusCode::BAD_GATEWAY.into_response()).await;
+
+    let stream = client(&url, 2).send_text_stream("hi").await.unwrap();
+    let (events, error) = drain(stream).await;
+    assert_eq!(events.len(), 2);
+    assert!(
+        matches!(error, Some(A2AError::Http { status: 502, .. })),
+        "{error:?}"
+    );
+    assert_eq!(resubscribes.lock().unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_streams_do_not_reconnect_without_a_policy() {
+    let (url, resubscribes) = start_server(|_| unreachable!()).await;
+
+    let client = A2AClient::from_endpoint(&url);
+    let (events, error) = drain(client.send_text_stream("hi").await.unwrap()).await;
+    assert_eq!(events.len(), 2);
+    assert!(matches!(error, Some(A2AError::Transport(_))), "{error:?}");
+    assert!(resubscribes.lock().unwrap().is_empty());
+}
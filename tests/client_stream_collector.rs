@@ -0,0 +1,201 @@
+//! `StreamCollector` / `A2AClient::send_text_collect`: streamed text as one
+//! string or as deltas, following `append` / `lastChunk`.
+
+mod common;
+
+use std::sync::Arc;
+
+use a2a_rs::client::{A2AClient, SseStream, StreamCollector, TextDelta};
+use a2a_rs::error::{A2AError, A2AResult};
+use a2a_rs::server::{AgentExecutor, EventQueue, RequestContext, TaskUpdater};
+use a2a_rs::types::*;
+use async_trait::async_trait;
+
+fn chunk(artifact_id: &str, text: &str, append: bool, last: bool) -> StreamResponse {
+    StreamResponse::ArtifactUpdate(TaskArtifactUpdateEvent {
+        task_id: "t1".to_string(),
+        context_id: "c1".to_string(),
+        kind: "artifact-update".to_string(),
+        artifact: Artifact {
+            artifact_id: artifact_id.to_string(),
+            name: None,
+            description: None,
+            parts: vec![Part::text(text)],
+            metadata: None,
+            extensions: None,
+        },
+        append: Some(append),
+        last_chunk: Some(last),
+        metadata: None,
+    })
+}
+
+fn status(state: TaskState, text: Option<&str>, r#final: bool) -> StreamResponse {
+    let mut status = TaskStatus::new(state);
+    status.message = text.map(|text| Message::agent("m", text));
+    StreamResponse::StatusUpdate(TaskStatusUpdateEvent {
+        task_id: "t1".to_string(),
+        context_id: "c1".to_string(),
+        kind: "status-update".to_string(),
+        status,
+        r#final,
+        metadata: None,
+    })
+}
+
+fn stream(events: Vec<StreamResponse>) -> SseStream {
+    let events: Vec<A2AResult<StreamResponse>> = events.into_iter().map(Ok).collect();
+    SseStream::from_stream(futures::stream::iter(events))
+}
+
+async fn deltas(collector: &mut StreamCollector) -> Vec<TextDelta> {
+    let mut deltas = Vec::new();
+    while let Some(delta) = collector.next_delta().await {
+        deltas.push(delta.unwrap());
+    }
+    deltas
+}
+
+fn append(text: &str) -> TextDelta {
+    TextDelta::Append(text.to_string())
+}
+
+#[tokio::test]
+async fn test_appended_chunks_yield_incremental_deltas() {
+    let mut collector = StreamCollector::new(stream(vec![
+        status(TaskState::Working, None, false),
+        chunk("a", "Hel", false, false),
+        chunk("a", "lo", true, false),
+        chunk("a", ", world", true, true),
+        status(TaskState::Completed, Some("Done."), true),
+    ]));
+
+    assert_eq!(
+        deltas(&mut collector).await,
+        [
+            append("Hel"),
+            append("lo"),
+            append(", world"),
+            append("\nDone.")
+        ]
+    );
+    assert_eq!(collector.text(), "Hello, world\nDone.");
+    assert_eq!(collector.status().unwrap().state, TaskState::Completed);
+}
+
+#[tokio::test]
+async fn test_restarted_and_interleaved_artifacts_reset_the_text() {
+    let mut collector = StreamCollector::new(stream(vec![
+        chunk("a", "draft", false, false),
+        chunk("b", "notes", false, false),
+        // Grows an artifact before the last one.
+        chunk("a", "!", true, false),
+        // Starts "b" over.
+        chunk("b", "N", false, false),
+        chunk("b", "otes", true, true),
+    ]))
+    .with_separator(" | ");
+
+    assert_eq!(
+        deltas(&mut collector).await,
+        [
+            append("draft"),
+            append(" | notes"),
+            TextDelta::Reset("draft! | notes".to_string()),
+            TextDelta::Reset("draft! | N".to_string()),
+            append("otes"),
+        ]
+    );
+    assert_eq!(collector.text(), "draft! | Notes");
+}
+
+#[tokio::test]
+async fn test_chunks_after_last_chunk_are_dropped() {
+    let collected = StreamCollector::new(stream(vec![
+        chunk("a", "one", false, false),
+        chunk("a", " two", true, true),
+        // Retransmissions of a completed artifact.
+        chunk("a", " two", true, true),
+        chunk("a", "one two", false, true),
+        // User messages and status updates without text add nothing.
+        StreamResponse::Message(Message::user("u", "ignored")),
+        status(TaskState::Completed, None, true),
+        // Nothing after the final status update is read.
+        chunk("z", "late", false, true),
+    ]))
+    .collect()
+    .await
+    .unwrap();
+    assert_eq!(collected, "one two");
+}
+
+#[tokio::test]
+async fn test_direct_reply_is_the_whole_stream() {
+    let collected = StreamCollector::new(stream(vec![
+        StreamResponse::Message(Message::agent("r", "just this")),
+        chunk("z", "late", false, true),
+    ]))
+    .collect()
+    .await
+    .unwrap();
+    assert_eq!(collected, "just this");
+}
+
+#[tokio::test]
+async fn test_stream_error_is_returned() {
+    let events: Vec<A2AResult<StreamResponse>> = vec![
+        Ok(chunk("a", "partial", false, false)),
+        Err(A2AError::Transport("connection reset".to_string())),
+    ];
+    let mut collector = StreamCollector::new(SseStream::from_stream(futures::stream::iter(events)));
+
+    assert_eq!(
+        collector.next_delta().await.unwrap().unwrap(),
+        append("partial")
+    );
+    assert!(collector.next_delta().await.unwrap().is_err());
+    assert_eq!(collector.text(), "partial");
+}
+
+/// Streams its reply word by word as one artifact, then completes.
+struct WordAgent;
+
+#[async_trait]
+impl AgentExecutor for WordAgent {
+    async fn execute(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        let updater = TaskUpdater::new(event_queue, context.task_id, context.context_id);
+        updater.start_work(None).await?;
+        let words = ["The ", "quick ", "fox"];
+        for (i, word) in words.iter().enumerate() {
+            updater
+                .add_artifact(
+                    vec![Part::text(*word)],
+                    Some("reply".to_string()),
+                    None,
+                    None,
+                    Some(i > 0),
+                    Some(i == words.len() - 1),
+                    None,
+                )
+                .await?;
+        }
+        updater.complete(None).await
+    }
+
+    async fn cancel(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        TaskUpdater::new(event_queue, context.task_id, context.context_id)
+            .cancel(None)
+            .await
+    }
+}
+
+#[tokio::test]
+async fn test_send_text_collect() {
+    let (base_url, _handle) = common::start_test_server(Arc::new(WordAgent)).await;
+    let client = A2AClient::from_endpoint(&format!("{base_url}/a2a"));
+
+    assert_eq!(
+        client.send_text_collect("go").await.unwrap(),
+        "The quick fox"
+    );
+}
@@ -0,0 +1,137 @@
+//! Client-side `StreamInterceptor`s: dropping, modifying and tapping
+//! streaming events before they reach the consumer.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use a2a_rs::builders::ClientBuilder;
+use a2a_rs::client::{A2AClient, StreamInterceptor};
+use a2a_rs::error::A2AResult;
+use a2a_rs::server::{AgentExecutor, EventQueue, RequestContext, TaskUpdater};
+use a2a_rs::types::*;
+use async_trait::async_trait;
+use futures::StreamExt;
+
+mod common;
+
+/// Reports a secret in a working status, emits an artifact, then completes.
+struct ChattyAgent;
+
+#[async_trait]
+impl AgentExecutor for ChattyAgent {
+    async fn execute(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        let updater = TaskUpdater::new(event_queue, context.task_id, context.context_id);
+        updater.start_work_with_text("token=hunter2").await?;
+        updater
+            .add_artifact(
+                vec![Part::text("report")],
+                Some("a1".to_string()),
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+            .await?;
+        updater.complete_with_text("done").await
+    }
+
+    async fn cancel(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        TaskUpdater::new(event_queue, context.task_id, context.context_id)
+            .cancel(None)
+            .await
+    }
+}
+
+/// Replaces every text part of status messages with `[redacted]`.
+struct Redact;
+
+impl StreamInterceptor for Redact {
+    fn intercept(&self, mut event: StreamResponse) -> Option<StreamResponse> {
+        if let StreamResponse::StatusUpdate(update) = &mut event {
+            if let Some(message) = update.status.message.as_mut() {
+                for part in &mut message.parts {
+                    if let Part::Text { text, .. } = part {
+                        *text = "[redacted]".to_string();
+                    }
+                }
+            }
+        }
+        Some(event)
+    }
+}
+
+fn drop_artifacts(event: StreamResponse) -> Option<StreamResponse> {
+    match event {
+        StreamResponse::ArtifactUpdate(_) => None,
+        other => Some(other),
+    }
+}
+
+fn status_texts(events: &[StreamResponse]) -> Vec<String> {
+    events
+        .iter()
+        .filter_map(|e| match e {
+            StreamResponse::StatusUpdate(u) => u.status.message.as_ref(),
+            _ => None,
+        })
+        .flat_map(|m| m.parts.iter())
+        .filter_map(|p| match p {
+            Part::Text { text, .. } => Some(text.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn test_interceptors_drop_and_modify_in_order() {
+    let (url, _handle) = common::start_test_server(Arc::new(ChattyAgent)).await;
+    let seen = Arc::new(AtomicUsize::new(0));
+    let tap = {
+        let seen = seen.clone();
+        move |event: StreamResponse| {
+            seen.fetch_add(1, Ordering::SeqCst);
+            Some(event)
+        }
+    };
+    let client = A2AClient::from_endpoint(&format!("{url}/a2a"))
+        .with_stream_interceptor(Arc::new(drop_artifacts))
+        .with_stream_interceptor(Arc::new(Redact))
+        .with_stream_interceptor(Arc::new(tap));
+
+    let mut stream = client.send_text_stream("go").await.unwrap();
+    let mut events = Vec::new();
+    while let Some(event) = stream.next().await {
+        events.push(event.unwrap());
+    }
+
+    assert!(!events
+        .iter()
+        .any(|e| matches!(e, StreamResponse::ArtifactUpdate(_))));
+    assert_eq!(status_texts(&events), vec!["[redacted]", "[redacted]"]);
+    // The tap runs after the drop, so it only saw delivered events.
+    assert_eq!(seen.load(Ordering::SeqCst), events.len());
+}
+
+#[tokio::test]
+async fn test_builder_interceptors_apply_to_futures_stream() {
+    let (url, _handle) = common::start_test_server(Arc::new(ChattyAgent)).await;
+    let client = ClientBuilder::new(format!("{url}/a2a"))
+        .with_stream_interceptor(Arc::new(drop_artifacts))
+        .build_from_endpoint();
+
+    let events: Vec<_> = client
+        .send_text_stream("go")
+        .await
+        .unwrap()
+        .into_stream()
+        .map(|e| e.unwrap())
+        .collect()
+        .await;
+
+    assert!(!events.is_empty());
+    assert!(!events
+        .iter()
+        .any(|e| matches!(e, StreamResponse::ArtifactUpdate(_))));
+    assert_eq!(status_texts(&events), vec!["token=hunter2", "done"]);
+}
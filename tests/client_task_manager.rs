@@ -26,6 +26,7 @@ fn test_task_construction() {
             state: TaskState::Working,
             message: None,
             timestamp: None,
+            raw_state: None,
         },
         history: None,
         artifacts: None,
@@ -59,6 +60,7 @@ fn test_task_status_with_message() {
         state: TaskState::Completed,
         message: Some(message),
         timestamp: None,
+        raw_state: None,
     };
 
     assert_eq!(status.state, TaskState::Completed);
@@ -94,7 +96,7 @@ fn test_status_update_event_deserialization() {
     assert_eq!(event.context_id, "context456");
     assert_eq!(event.status.state, TaskState::Completed);
     assert!(event.status.message.is_some());
-    assert_eq!(event.r#final, true);
+    assert!(event.r#final);
 }
 
 #[test]
@@ -109,6 +111,7 @@ fn test_status_update_creates_task_if_not_exists() {
             state: TaskState::Working,
             message: None,
             timestamp: None,
+            raw_state: None,
         },
         r#final: false,
         metadata: None,
@@ -178,6 +181,7 @@ fn test_task_with_history() {
             state: TaskState::Completed,
             message: None,
             timestamp: None,
+            raw_state: None,
         },
         history: Some(vec![msg]),
         artifacts: None,
@@ -211,6 +215,7 @@ fn test_task_with_artifacts() {
             state: TaskState::Completed,
             message: None,
             timestamp: None,
+            raw_state: None,
         },
         history: None,
         artifacts: Some(vec![artifact]),
@@ -265,6 +270,7 @@ fn test_status_message_can_be_moved_to_history() {
             state: TaskState::Working,
             message: None, // cleared after moving to history
             timestamp: None,
+            raw_state: None,
         },
         history: Some(history),
         artifacts: None,
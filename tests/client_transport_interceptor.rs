@@ -0,0 +1,266 @@
+//! `TransportInterceptor`: an ordered chain around every JSON-RPC call and
+//! stream establishment of `JsonRpcTransport`.
+
+mod common;
+
+use std::sync::{Arc, Mutex};
+
+use a2a_rs::builders::ClientBuilder;
+use a2a_rs::client::{
+    A2AClient, InterceptedCall, JsonRpcTransport, SendMessageResponse, TransportInterceptor,
+};
+use a2a_rs::error::{A2AError, A2AResult};
+use a2a_rs::server::{
+    AgentExecutor, EventQueue, InMemoryTaskStore, RequestContext, RouterConfig, TaskUpdater,
+};
+use a2a_rs::types::*;
+use a2a_rs::utils::get_message_text;
+use async_trait::async_trait;
+use common::start_test_server_with_config;
+use serde_json::json;
+
+/// Replies with the `X-Tenant` header the request arrived with.
+struct TenantAgent;
+
+#[async_trait]
+impl AgentExecutor for TenantAgent {
+    async fn execute(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        let tenant = context
+            .http_request()
+            .and_then(|info| info.header("x-tenant"))
+            .unwrap_or("none")
+            .to_string();
+        TaskUpdater::new(event_queue, context.task_id, context.context_id)
+            .complete_with_text(&format!("tenant {tenant}"))
+            .await
+    }
+
+    async fn cancel(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        TaskUpdater::new(event_queue, context.task_id, context.context_id)
+            .cancel(None)
+            .await
+    }
+}
+
+/// Serve [`TenantAgent`]; returns the endpoint URL.
+async fn start() -> String {
+    let config = RouterConfig::default()
+        .with_captured_header("x-tenant")
+        .unwrap();
+    let (base_url, _server) = start_test_server_with_config(
+        Arc::new(TenantAgent),
+        Arc::new(InMemoryTaskStore::new()),
+        card,
+        config,
+    )
+    .await;
+    format!("{base_url}/a2a")
+}
+
+fn card(url: &str) -> AgentCard {
+    a2a_rs::builders::AgentCardBuilder::new("Tenant", "Echoes the tenant", "1.0")
+        .with_jsonrpc_interface(url)
+        .with_streaming(true)
+        .build()
+}
+
+type Log = Arc<Mutex<Vec<String>>>;
+
+/// Logs every hook as `<name>:<hook>:<method>`. Optionally sets the
+/// tenant, tags task results, or fails requests.
+#[derive(Default)]
+struct Recorder {
+    name: &'static str,
+    log: Log,
+    tenant: Option<&'static str>,
+    tag_results: bool,
+    fail_requests: bool,
+}
+
+impl Recorder {
+    fn record(&self, hook: &str, call: &InterceptedCall) {
+        self.log
+            .lock()
+            .unwrap()
+            .push(format!("{}:{hook}:{}", self.name, call.method()));
+    }
+}
+
+#[async_trait]
+impl TransportInterceptor for Recorder {
+    async fn on_request(&self, call: &mut InterceptedCall) -> A2AResult<()> {
+        self.record("request", call);
+        if self.fail_requests {
+            return Err(A2AError::invalid_request("blocked by policy"));
+        }
+        if let Some(tenant) = self.tenant {
+            call.set_header("X-Tenant", tenant);
+        }
+        Ok(())
+    }
+
+    async fn on_response(
+        &self,
+        call: &InterceptedCall,
+        response: &mut JsonRpcResponse,
+    ) -> A2AResult<()> {
+        self.record("response", call);
+        if self.tag_results {
+            if let Some(result) = response.result.as_mut() {
+                result["metadata"] = json!({"interceptedBy": self.name});
+            }
+        }
+        Ok(())
+    }
+
+    async fn on_stream_opened(&self, call: &InterceptedCall) -> A2AResult<()> {
+        self.record("stream", call);
+        Ok(())
+    }
+
+    async fn on_error(&self, call: &InterceptedCall, error: &A2AError) {
+        self.record("error", call);
+        assert!(!error.to_string().is_empty());
+    }
+}
+
+fn entries(log: &Log) -> Vec<String> {
+    std::mem::take(&mut *log.lock().unwrap())
+}
+
+fn reply(response: SendMessageResponse) -> Task {
+    match response {
+        SendMessageResponse::Task(task) => task,
+        other => panic!("expected a task, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn test_unary_chain_order_headers_and_rewrite() {
+    let url = start().await;
+    let log = Log::default();
+    let client = ClientBuilder::new(&url)
+        .with_transport_interceptor(Arc::new(Recorder {
+            name: "outer",
+            log: log.clone(),
+            tenant: Some("acme"),
+            ..Default::default()
+        }))
+        .with_transport_interceptor(Arc::new(Recorder {
+            name: "inner",
+            log: log.clone(),
+            tag_results: true,
+            ..Default::default()
+        }))
+        .build_from_endpoint();
+
+    let task = reply(client.send_text("hi").await.unwrap());
+    let message = task.status.message.as_ref().unwrap();
+    assert_eq!(get_message_text(message, ""), "tenant acme");
+    assert_eq!(task.metadata, Some(json!({"interceptedBy": "inner"})));
+    assert_eq!(
+        entries(&log),
+        [
+            "outer:request:message/send",
+            "inner:request:message/send",
+            "inner:response:message/send",
+            "outer:response:message/send",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_stream_establishment_is_intercepted() {
+    let url = start().await;
+    let log = Log::default();
+    let transport = JsonRpcTransport::new(&url).with_interceptor(Arc::new(Recorder {
+        name: "tap",
+        log: log.clone(),
+        tenant: Some("globex"),
+        ..Default::default()
+    }));
+    let client = A2AClient::with_transport(Box::new(transport));
+
+    let mut stream = client.send_text_stream("hi").await.unwrap();
+    assert_eq!(
+        entries(&log),
+        ["tap:request:message/stream", "tap:stream:message/stream"]
+    );
+    let mut texts = Vec::new();
+    while let Some(event) = stream.next().await {
+        if let StreamResponse::StatusUpdate(update) = event.unwrap() {
+            if let Some(message) = &update.status.message {
+                texts.push(get_message_text(message, ""));
+            }
+        }
+    }
+    assert_eq!(texts, ["tenant globex"]);
+}
+
+#[tokio::test]
+async fn test_failing_request_hook_aborts_the_call() {
+    let url = start().await;
+    let log = Log::default();
+    let transport = JsonRpcTransport::new(&url)
+        .with_interceptor(Arc::new(Recorder {
+            name: "outer",
+            log: log.clone(),
+            ..Default::default()
+        }))
+        .with_interceptor(Arc::new(Recorder {
+            name: "policy",
+            log: log.clone(),
+            fail_requests: true,
+            ..Default::default()
+        }))
+        .with_interceptor(Arc::new(Recorder {
+            name: "unreached",
+            log: log.clone(),
+            ..Default::default()
+        }));
+    let client = A2AClient::with_transport(Box::new(transport));
+
+    let err = client.send_text("hi").await.unwrap_err();
+    assert!(err.to_string().contains("blocked by policy"), "{err}");
+    assert_eq!(
+        entries(&log),
+        [
+            "outer:request:message/send",
+            "policy:request:message/send",
+            "outer:error:message/send",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_transport_errors_reach_every_interceptor() {
+    // Nothing listens on a port that was just released.
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}/a2a", listener.local_addr().unwrap());
+    drop(listener);
+
+    let log = Log::default();
+    let transport = JsonRpcTransport::new(&url)
+        .with_interceptor(Arc::new(Recorder {
+            name: "a",
+            log: log.clone(),
+            ..Default::default()
+        }))
+        .with_interceptor(Arc::new(Recorder {
+            name: "b",
+            log: log.clone(),
+            ..Default::default()
+        }));
+    let client = A2AClient::with_transport(Box::new(transport));
+
+    assert!(client.send_text_stream("hi").await.is_err());
+    assert_eq!(
+        entries(&log),
+        [
+            "a:request:message/stream",
+            "b:request:message/stream",
+            "b:error:message/stream",
+            "a:error:message/stream",
+        ]
+    );
+}
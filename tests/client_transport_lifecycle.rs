@@ -0,0 +1,235 @@
+//! Object-safe `Transport` trait: custom transports under `A2AClient`,
+//! lifecycle events and transport metadata.
+
+mod common;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use a2a_rs::client::{
+    A2AClient, CallMetrics, JsonRpcTransport, SendMessageResponse, SseStream, Transport,
+    TransportEvent, TransportMetadata, TransportObserver,
+};
+use a2a_rs::error::{A2AError, A2AResult};
+use a2a_rs::types::*;
+use a2a_rs::utils::get_message_text;
+use async_trait::async_trait;
+
+/// Answers calls in-process with an agent message echoing the text.
+#[derive(Default)]
+struct InProcessTransport {
+    connects: AtomicUsize,
+    closes: Arc<AtomicUsize>,
+}
+
+fn echo(request: &JsonRpcRequest) -> A2AResult<Message> {
+    let params: SendMessageParams =
+        serde_json::from_value(request.params.clone().unwrap_or_default()).map_err(|e| {
+            A2AError::InvalidParams {
+                message: e.to_string(),
+                data: None,
+            }
+        })?;
+    let text = get_message_text(&params.message, "");
+    Ok(Message::agent("reply", format!("echo: {text}")))
+}
+
+#[async_trait]
+impl Transport for InProcessTransport {
+    async fn send(&self, request: &JsonRpcRequest) -> A2AResult<JsonRpcResponse> {
+        let reply = serde_json::to_value(echo(request)?).unwrap();
+        Ok(JsonRpcResponse::success(request.id.clone(), reply))
+    }
+
+    async fn send_stream(&self, request: &JsonRpcRequest) -> A2AResult<SseStream> {
+        let reply = echo(request)?;
+        let events = vec![
+            Ok(StreamResponse::Message(Message::agent("ack", "working"))),
+            Ok(StreamResponse::Message(reply)),
+        ];
+        Ok(SseStream::from_stream(futures::stream::iter(events)))
+    }
+
+    async fn connect(&self) -> A2AResult<()> {
+        self.connects.fetch_add(1, Ordering::SeqCst);
+        Ok(())
+    }
+
+    async fn close(&self) -> A2AResult<()> {
+        self.closes.fetch_add(1, Ordering::SeqCst);
+        Ok(())
+    }
+
+    fn metadata(&self) -> TransportMetadata {
+        TransportMetadata::new("IN_PROCESS").with_streaming(true)
+    }
+}
+
+/// Implements only the required methods.
+struct MinimalTransport;
+
+#[async_trait]
+impl Transport for MinimalTransport {
+    async fn send(&self, _request: &JsonRpcRequest) -> A2AResult<JsonRpcResponse> {
+        Err(A2AError::Transport("unreachable".to_string()))
+    }
+
+    async fn send_stream(&self, _request: &JsonRpcRequest) -> A2AResult<SseStream> {
+        Err(A2AError::Transport("unreachable".to_string()))
+    }
+}
+
+#[derive(Default)]
+struct Recorder {
+    events: Mutex<Vec<TransportEvent>>,
+}
+
+impl TransportObserver for Recorder {
+    fn on_call(&self, _metrics: &CallMetrics) {}
+
+    fn on_event(&self, event: &TransportEvent) {
+        self.events.lock().unwrap().push(event.clone());
+    }
+}
+
+impl Recorder {
+    fn events(&self) -> Vec<TransportEvent> {
+        self.events.lock().unwrap().clone()
+    }
+
+    /// Wait until `event` has been recorded.
+    async fn wait_for(&self, event: &TransportEvent) {
+        for _ in 0..100 {
+            if self.events().contains(event) {
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        panic!("{event:?} not seen in {:?}", self.events());
+    }
+}
+
+#[tokio::test]
+async fn test_custom_transport_under_client() {
+    let transport = InProcessTransport::default();
+    let closes = transport.closes.clone();
+    transport.connect().await.unwrap();
+    assert_eq!(transport.connects.load(Ordering::SeqCst), 1);
+    let client = A2AClient::with_transport(Box::new(transport));
+
+    client.connect().await.unwrap();
+    let metadata = client.transport_metadata();
+    assert_eq!(metadata.protocol, "IN_PROCESS");
+    assert_eq!(metadata.endpoint, None);
+
+    let SendMessageResponse::Message(reply) = client.send_text("hi").await.unwrap() else {
+        panic!("expected direct message");
+    };
+    assert_eq!(get_message_text(&reply, ""), "echo: hi");
+
+    let mut stream = client.send_text_stream("there").await.unwrap();
+    let mut texts = Vec::new();
+    while let Some(event) = stream.next().await {
+        if let StreamResponse::Message(message) = event.unwrap() {
+            texts.push(get_message_text(&message, ""));
+        }
+    }
+    assert_eq!(texts, ["working", "echo: there"]);
+
+    client.close().await.unwrap();
+    assert_eq!(closes.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_default_lifecycle_methods() {
+    let mut transport = MinimalTransport;
+    transport.set_observer(Arc::new(Recorder::default()));
+    transport.connect().await.unwrap();
+    transport.close().await.unwrap();
+    assert_eq!(transport.metadata(), TransportMetadata::new("custom"));
+}
+
+#[tokio::test]
+async fn test_jsonrpc_transport_lifecycle_events() {
+    let (base_url, _handle) = common::start_test_server(Arc::new(common::EchoAgent)).await;
+    let url = format!("{base_url}/a2a");
+    let recorder = Arc::new(Recorder::default());
+    let client = A2AClient::from_endpoint(&url).with_transport_observer(recorder.clone());
+
+    assert_eq!(
+        client.transport_metadata(),
+        TransportMetadata::new("JSONRPC").with_endpoint(&url)
+    );
+
+    client.send_text("hi").await.unwrap();
+    client.send_text("again").await.unwrap();
+    let mut stream = client.send_text_stream("stream").await.unwrap();
+    while stream.next().await.is_some() {}
+    let closed = TransportEvent::StreamClosed {
+        method: "message/stream".to_string(),
+    };
+    recorder.wait_for(&closed).await;
+    client.close().await.unwrap();
+
+    assert_eq!(
+        recorder.events(),
+        [
+            TransportEvent::Connected {
+                endpoint: url.clone()
+            },
+            TransportEvent::StreamOpened {
+                method: "message/stream".to_string()
+            },
+            closed,
+            TransportEvent::Disconnected {
+                endpoint: url,
+                reason: None
+            },
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_dropped_stream_reports_closed() {
+    let (base_url, _handle) = common::start_test_server(Arc::new(common::SlowEchoAgent)).await;
+    let recorder = Arc::new(Recorder::default());
+    let client = A2AClient::from_endpoint(&format!("{base_url}/a2a"))
+        .with_transport_observer(recorder.clone());
+
+    let stream = client.send_text_stream("slow").await.unwrap();
+    drop(stream);
+
+    recorder
+        .wait_for(&TransportEvent::StreamClosed {
+            method: "message/stream".to_string(),
+        })
+        .await;
+}
+
+#[tokio::test]
+async fn test_unreachable_endpoint_reports_disconnect() {
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}/a2a", listener.local_addr().unwrap());
+    drop(listener);
+    let recorder = Arc::new(Recorder::default());
+    let transport = JsonRpcTransport::new(&url).with_observer(recorder.clone());
+    let client = A2AClient::with_transport(Box::new(transport));
+
+    client.connect().await.unwrap();
+    assert!(client.send_text("hi").await.is_err());
+    assert!(client.send_text("hi").await.is_err());
+
+    let events = recorder.events();
+    assert_eq!(events.len(), 2, "{events:?}");
+    assert_eq!(
+        events[0],
+        TransportEvent::Connected {
+            endpoint: url.clone()
+        }
+    );
+    assert!(
+        matches!(&events[1], TransportEvent::Disconnected { endpoint, reason: Some(_) } if *endpoint == url),
+        "{events:?}"
+    );
+}
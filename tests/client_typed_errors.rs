@@ -0,0 +1,182 @@
+//! `ClientError`: client failures sorted into transport, protocol, agent,
+//! discovery and timeout errors, with the raw response and retry-ability.
+
+mod common;
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use a2a_rs::client::{A2AClient, CallOptions, ClientError, PollOptions, RawResponse};
+use a2a_rs::error::{self, A2AError};
+use a2a_rs::types::*;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::routing::post;
+use axum::Router;
+use common::{start_test_server, FailingAgent, SlowEchoAgent};
+
+/// Serve `/a2a` with a fixed HTTP status and body.
+async fn start_fixed(status: StatusCode, body: &'static str) -> A2AClient {
+    let app = Router::new().route(
+        "/a2a",
+        post(move || async move { (status, body).into_response() }),
+    );
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/a2a", listener.local_addr().unwrap());
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+    A2AClient::from_endpoint(&url)
+}
+
+#[tokio::test]
+async fn test_connection_failure_is_retryable_transport_error() {
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}/a2a", listener.local_addr().unwrap());
+    drop(listener);
+
+    let err = ClientError::from(
+        A2AClient::from_endpoint(&url)
+            .send_text("hello")
+            .await
+            .unwrap_err(),
+    );
+    assert!(matches!(err, ClientError::Transport { response: None, .. }));
+    assert!(err.is_retryable());
+}
+
+#[tokio::test]
+async fn test_http_errors_keep_the_raw_response() {
+    let client = start_fixed(StatusCode::SERVICE_UNAVAILABLE, "overloaded").await;
+    let err = ClientError::from(client.send_text("hello").await.unwrap_err());
+    assert_eq!(
+        err.raw_response(),
+        Some(&RawResponse {
+            status: 503,
+            body: "overloaded".to_string()
+        })
+    );
+    assert!(err.is_retryable());
+
+    let client = start_fixed(StatusCode::FORBIDDEN, "no").await;
+    let err = ClientError::from(client.send_text("hello").await.unwrap_err());
+    assert_eq!(err.raw_response().unwrap().status, 403);
+    assert!(!err.is_retryable());
+}
+
+#[tokio::test]
+async fn test_rate_limited_response_says_when_to_retry() {
+    let body = r#"{"jsonrpc":"2.0","id":null,"error":{"code":-32029,"message":"Rate limited","data":{"retryAfterMs":1500}}}"#;
+    let client = start_fixed(StatusCode::TOO_MANY_REQUESTS, body).await;
+    let err = ClientError::from(client.send_text("hello").await.unwrap_err());
+    assert!(err.is_retryable());
+    assert_eq!(err.retry_after(), Some(Duration::from_millis(1500)));
+}
+
+#[tokio::test]
+async fn test_json_rpc_errors_are_protocol_errors() {
+    let (base_url, _server) = start_test_server(Arc::new(SlowEchoAgent)).await;
+    let client = A2AClient::from_endpoint(&format!("{base_url}/a2a"));
+
+    let err = ClientError::from(client.get_task_by_id("missing", None).await.unwrap_err());
+    let ClientError::Protocol(rpc) = &err else {
+        panic!("expected a protocol error, got {err:?}");
+    };
+    assert_eq!(rpc.code, error::TASK_NOT_FOUND);
+    assert!(!err.is_retryable());
+    assert!(err.raw_response().is_none());
+}
+
+#[tokio::test]
+async fn test_timeouts_are_retryable() {
+    let app = Router::new().route(
+        "/a2a",
+        post(|| async {
+            tokio::time::sleep(Duration::from_secs(5)).await;
+            StatusCode::OK
+        }),
+    );
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/a2a", listener.local_addr().unwrap());
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+    let params = SendMessageParams {
+        message: Message::user("m1", "hello"),
+        configuration: None,
+        metadata: None,
+        tenant: None,
+    };
+    let options = CallOptions::default().with_timeout(Duration::from_millis(50));
+
+    let err = ClientError::from(
+        A2AClient::from_endpoint(&url)
+            .send_message_with_options(params, &options)
+            .await
+            .unwrap_err(),
+    );
+    assert!(matches!(err, ClientError::Timeout(_)), "got {err:?}");
+    assert!(err.is_retryable());
+}
+
+#[tokio::test]
+async fn test_failed_tasks_are_agent_failures() {
+    let (base_url, _server) = start_test_server(Arc::new(FailingAgent)).await;
+    let client = A2AClient::from_endpoint(&format!("{base_url}/a2a"));
+    let SendMessageResponse::Task(task) = client.send_text("hello").await.unwrap() else {
+        panic!("expected a task");
+    };
+
+    let err = client
+        .wait_for_completion(&task.id, &PollOptions::default())
+        .await
+        .unwrap_err();
+    let err = ClientError::from(err);
+    let ClientError::AgentFailed {
+        status,
+        task: failed,
+    } = &err
+    else {
+        panic!("expected an agent failure, got {err:?}");
+    };
+    assert_eq!(status.state, TaskState::Failed);
+    assert_eq!(failed.id, task.id);
+    assert!(!err.is_retryable());
+}
+
+#[tokio::test]
+async fn test_discovery_errors_wrap_the_cause() {
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    drop(listener);
+
+    let err = ClientError::discovery(A2AClient::from_url(&url).await.unwrap_err());
+    assert!(matches!(err, ClientError::Discovery(_)));
+    assert!(err.is_retryable());
+    assert!(std::error::Error::source(&err).is_some());
+
+    let invalid = A2AError::InvalidAgentCard {
+        message: "missing name".to_string(),
+        errors: Vec::new(),
+    };
+    let err = ClientError::from(invalid);
+    assert!(matches!(err, ClientError::Discovery(_)));
+    assert!(!err.is_retryable());
+}
+
+#[test]
+fn test_malformed_responses_are_protocol_errors() {
+    let err = ClientError::from(A2AError::InvalidJson("not json".to_string()));
+    let ClientError::Protocol(rpc) = err else {
+        panic!("expected a protocol error");
+    };
+    assert_eq!(rpc.code, error::INVALID_AGENT_RESPONSE);
+    assert_eq!(rpc.message, "not json");
+
+    let err = ClientError::from(A2AError::JsonRpc {
+        code: -32042,
+        message: "custom".to_string(),
+        data: Some(serde_json::json!({"detail": 1})),
+    });
+    let ClientError::Protocol(rpc) = err else {
+        panic!("expected a protocol error");
+    };
+    assert_eq!(rpc.code, -32042);
+    assert_eq!(rpc.data, Some(serde_json::json!({"detail": 1})));
+}
@@ -0,0 +1,79 @@
+//! Clients talking to peers on newer protocol versions: task states this
+//! SDK doesn't recognise parse as `TaskState::Unknown` and keep the
+//! original string in `TaskStatus::raw_state`.
+
+use a2a_rs::client::A2AClient;
+use a2a_rs::types::*;
+use axum::response::sse::{Event, Sse};
+use axum::response::IntoResponse;
+use axum::routing::post;
+use axum::Router;
+use serde_json::{json, Value};
+
+/// Server reporting task `t1` in the future state `paused`, both for unary
+/// calls and as a single status update on streams.
+async fn start_future_server() -> String {
+    let app = Router::new().route(
+        "/a2a",
+        post(|body: String| async move {
+            let req: Value = serde_json::from_str(&body).unwrap();
+            if req["method"] == "message/stream" {
+                let frame = json!({
+                    "jsonrpc": "2.0",
+                    "id": req["id"],
+                    "result": {
+                        "kind": "status-update",
+                        "taskId": "t1",
+                        "contextId": "c1",
+                        "status": {"state": "paused"},
+                        "final": true
+                    }
+                });
+                let stream = futures::stream::once(async move {
+                    Ok::<_, std::convert::Infallible>(Event::default().data(frame.to_string()))
+                });
+                return Sse::new(stream).into_response();
+            }
+            let task = json!({
+                "kind": "task",
+                "id": "t1",
+                "contextId": "c1",
+                "status": {"state": "paused"}
+            });
+            axum::Json(json!({"jsonrpc": "2.0", "id": req["id"], "result": task})).into_response()
+        }),
+    );
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::serve(listener, app).await.unwrap();
+    });
+
+    format!("http://{}/a2a", addr)
+}
+
+#[tokio::test]
+async fn test_unary_calls_surface_raw_state() {
+    let client = A2AClient::from_endpoint(&start_future_server().await);
+
+    let task = client.get_task_by_id("t1", None).await.unwrap();
+    assert_eq!(task.status.state, TaskState::Unknown);
+    assert_eq!(task.status.unrecognized_state(), Some("paused"));
+
+    let SendMessageResponse::Task(task) = client.send_text("hi").await.unwrap() else {
+        panic!("expected task");
+    };
+    assert_eq!(task.status.raw_state.as_deref(), Some("paused"));
+}
+
+#[tokio::test]
+async fn test_stream_events_surface_raw_state() {
+    let client = A2AClient::from_endpoint(&start_future_server().await);
+
+    let mut stream = client.send_text_stream("hi").await.unwrap();
+    let event = stream.next().await.unwrap().unwrap();
+    let status = event.task_status().unwrap();
+    assert_eq!(status.state, TaskState::Unknown);
+    assert_eq!(status.state_name(), "paused");
+}
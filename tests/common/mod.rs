@@ -1,14 +1,16 @@
 //! Shared test utilities for integration tests.
 
+#![allow(dead_code)]
+
 use std::sync::Arc;
 
 use a2a_rs::builders::AgentCardBuilder;
 use a2a_rs::error::{A2AError, A2AResult};
 use a2a_rs::server::{
-    a2a_router, AgentExecutor, DefaultRequestHandler, EventQueue, InMemoryTaskStore,
-    RequestContext, TaskStore, TaskUpdater,
+    a2a_router, a2a_router_with_config, AgentExecutor, DefaultRequestHandler, EventQueue,
+    InMemoryTaskStore, RequestContext, RequestHandler, RouterConfig, TaskStore, TaskUpdater,
 };
-use a2a_rs::types::Part;
+use a2a_rs::types::{AgentCard, Part};
 use async_trait::async_trait;
 
 /// A simple echo agent that echoes back the text from the user's message.
@@ -134,14 +136,54 @@ pub async fn start_test_server(
 pub async fn start_test_server_with_store(
     executor: Arc<dyn AgentExecutor>,
     store: Arc<dyn TaskStore>,
+) -> (String, tokio::task::JoinHandle<()>) {
+    start_test_server_with_card(executor, store, test_agent_card).await
+}
+
+/// Start a test server on a random port with a specific task store and the
+/// agent card `card` builds for the endpoint URL.
+pub async fn start_test_server_with_card(
+    executor: Arc<dyn AgentExecutor>,
+    store: Arc<dyn TaskStore>,
+    card: impl FnOnce(&str) -> AgentCard,
+) -> (String, tokio::task::JoinHandle<()>) {
+    let handler = Arc::new(DefaultRequestHandler::new(executor, store));
+    start_test_server_with_handler(handler, card).await
+}
+
+/// Like [`start_test_server_with_card`], for a request handler the test
+/// configured itself.
+pub async fn start_test_server_with_handler(
+    handler: Arc<dyn RequestHandler>,
+    card: impl FnOnce(&str) -> AgentCard,
+) -> (String, tokio::task::JoinHandle<()>) {
+    serve_router(|base_url| a2a_router(handler, card(&format!("{}/a2a", base_url)))).await
+}
+
+/// Like [`start_test_server_with_card`], with router `config`.
+pub async fn start_test_server_with_config(
+    executor: Arc<dyn AgentExecutor>,
+    store: Arc<dyn TaskStore>,
+    card: impl FnOnce(&str) -> AgentCard,
+    config: RouterConfig,
+) -> (String, tokio::task::JoinHandle<()>) {
+    let handler = Arc::new(DefaultRequestHandler::new(executor, store));
+    serve_router(|base_url| {
+        a2a_router_with_config(handler, card(&format!("{}/a2a", base_url)), config)
+    })
+    .await
+}
+
+/// Serve the router `app` builds for the server's base URL on a random
+/// port, with the peer address as connect info. Returns the base URL and a
+/// handle to shut it down.
+pub async fn serve_router(
+    app: impl FnOnce(&str) -> axum::Router,
 ) -> (String, tokio::task::JoinHandle<()>) {
     let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
     let addr = listener.local_addr().unwrap();
     let base_url = format!("http://{}", addr);
-
-    let handler = Arc::new(DefaultRequestHandler::new(executor, store));
-    let agent_card = test_agent_card(&format!("{}/a2a", base_url));
-    let app = a2a_router(handler, agent_card);
+    let app = app(&base_url).into_make_service_with_connect_info::<std::net::SocketAddr>();
 
     let handle = tokio::spawn(async move {
         axum::serve(listener, app).await.unwrap();
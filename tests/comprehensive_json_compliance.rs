@@ -595,7 +595,7 @@ fn test_task_state_enum_values() {
     ];
 
     for (state, expected) in states {
-        let json = serde_json::to_value(&state).unwrap();
+        let json = serde_json::to_value(state).unwrap();
         println!("{:?} -> {}", state, json);
         assert_eq!(
             json.as_str().unwrap(),
@@ -614,7 +614,7 @@ fn test_role_enum_values() {
     let roles = vec![(Role::User, "user"), (Role::Agent, "agent")];
 
     for (role, expected) in roles {
-        let json = serde_json::to_value(&role).unwrap();
+        let json = serde_json::to_value(role).unwrap();
         println!("{:?} -> {}", role, json);
         assert_eq!(json.as_str().unwrap(), expected, "Role must be lowercase");
     }
@@ -788,6 +788,7 @@ fn test_full_roundtrip_all_types() {
             state: TaskState::Completed,
             message: None,
             timestamp: Some("2026-02-12T00:00:00Z".to_string()),
+            raw_state: None,
         },
         artifacts: Some(vec![Artifact {
             artifact_id: "art-1".to_string(),
@@ -0,0 +1,146 @@
+//! `contexts/cancel`: cancel every non-terminal task of a context in one
+//! request, with per-task results.
+
+mod common;
+
+use std::sync::Arc;
+
+use a2a_rs::client::A2AClient;
+use a2a_rs::error::A2AResult;
+use a2a_rs::server::{
+    AgentExecutor, DefaultRequestHandler, EventQueue, InMemoryTaskStore, RequestContext,
+    RequestHandler, SendMessageParams, TaskUpdater,
+};
+use a2a_rs::types::*;
+use async_trait::async_trait;
+use serde_json::json;
+
+/// Starts working on a task and never finishes it. Messages saying "done"
+/// complete right away.
+struct ParkedAgent;
+
+#[async_trait]
+impl AgentExecutor for ParkedAgent {
+    async fn execute(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        let updater = TaskUpdater::new(
+            event_queue,
+            context.task_id.clone(),
+            context.context_id.clone(),
+        );
+        if context.get_user_input("") == "done" {
+            return updater.complete(None).await;
+        }
+        updater.start_work(None).await?;
+        std::future::pending().await
+    }
+
+    async fn cancel(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        let updater = TaskUpdater::new(event_queue, context.task_id, context.context_id);
+        updater.cancel(None).await
+    }
+}
+
+/// Serve [`ParkedAgent`]; returns the handler and a client for it.
+async fn start() -> (Arc<DefaultRequestHandler>, A2AClient) {
+    let handler = Arc::new(DefaultRequestHandler::new(
+        Arc::new(ParkedAgent),
+        Arc::new(InMemoryTaskStore::new()),
+    ));
+    let (base_url, _server) =
+        common::start_test_server_with_handler(handler.clone(), common::test_agent_card).await;
+    (
+        handler,
+        A2AClient::from_endpoint(&format!("{base_url}/a2a")),
+    )
+}
+
+/// Send `text` in `context_id` and wait until the task is working or
+/// finished; returns the task ID.
+async fn start_task(handler: &DefaultRequestHandler, context_id: &str, text: &str) -> String {
+    let mut message = Message::user(uuid::Uuid::new_v4().to_string(), text);
+    message.context_id = Some(context_id.to_string());
+    let mut events = handler
+        .on_message_send_stream(SendMessageParams {
+            message,
+            configuration: None,
+            metadata: None,
+            tenant: None,
+        })
+        .await
+        .unwrap();
+    loop {
+        if let StreamResponse::StatusUpdate(update) = events.recv().await.unwrap() {
+            if update.status.state != TaskState::Submitted {
+                return update.task_id;
+            }
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_cancels_every_open_task_of_the_context() {
+    let (handler, client) = start().await;
+
+    let mut open = Vec::new();
+    for i in 0..3 {
+        open.push(start_task(&handler, "conversation", &format!("tool {i}")).await);
+    }
+    let finished = start_task(&handler, "conversation", "done").await;
+    let elsewhere = start_task(&handler, "other", "tool").await;
+
+    let result = client.cancel_context_by_id("conversation").await.unwrap();
+    assert_eq!(result.context_id, "conversation");
+    assert!(result.all_canceled(), "{result:?}");
+    open.sort();
+    let canceled: Vec<_> = result.results.iter().map(|r| r.task_id.clone()).collect();
+    assert_eq!(canceled, open);
+    for entry in &result.results {
+        assert_eq!(
+            entry.task.as_ref().unwrap().status.state,
+            TaskState::Canceled
+        );
+    }
+
+    let state = |id: String| {
+        let client = &client;
+        async move { client.get_task_by_id(&id, None).await.unwrap().status.state }
+    };
+    assert_eq!(state(finished).await, TaskState::Completed);
+    assert_eq!(state(elsewhere).await, TaskState::Working);
+
+    // Nothing left to cancel.
+    let again = client.cancel_context_by_id("conversation").await.unwrap();
+    assert!(again.results.is_empty());
+}
+
+#[tokio::test]
+async fn test_invalid_params() {
+    let (base_url, _server) = common::start_test_server(Arc::new(ParkedAgent)).await;
+    let response: serde_json::Value = reqwest::Client::new()
+        .post(format!("{base_url}/a2a"))
+        .json(&common::jsonrpc_request(
+            json!(1),
+            "contexts/cancel",
+            json!({"context": "x"}),
+        ))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(response["error"]["code"], -32602);
+}
+
+#[test]
+fn test_result_wire_format() {
+    let result: CancelContextResult = serde_json::from_value(json!({
+        "contextId": "c1",
+        "results": [
+            {"taskId": "t1", "error": {"code": -32002, "message": "Task cannot be canceled"}}
+        ]
+    }))
+    .unwrap();
+    assert!(!result.all_canceled());
+    assert_eq!(result.results[0].error.as_ref().unwrap().code, -32002);
+}
@@ -0,0 +1,217 @@
+//! Data part delta compression over `message/stream`.
+
+mod common;
+
+use std::sync::Arc;
+
+use a2a_rs::builders::{AgentCardBuilder, ClientBuilder};
+use a2a_rs::client::A2AClient;
+use a2a_rs::error::A2AResult;
+use a2a_rs::server::{AgentExecutor, EventQueue, InMemoryTaskStore, RequestContext, TaskUpdater};
+use a2a_rs::types::*;
+use a2a_rs::utils::{DataDeltaEncoder, DATA_DELTA_EXTENSION_URI, HTTP_EXTENSION_HEADER};
+use async_trait::async_trait;
+use serde_json::{json, Value};
+
+const STEPS: usize = 4;
+
+/// The evolving state the agent publishes at `step`.
+fn state(step: usize) -> Value {
+    json!({
+        "step": step,
+        "rows": (0..20).map(|i| json!({"id": i, "label": format!("row number {i}")})).collect::<Vec<_>>(),
+        "log": (0..=step).map(|i| format!("finished step {i}")).collect::<Vec<_>>(),
+    })
+}
+
+/// Publishes the full state after every step as a replacement of one
+/// artifact.
+struct StateAgent;
+
+#[async_trait]
+impl AgentExecutor for StateAgent {
+    async fn execute(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        let updater = TaskUpdater::new(event_queue, context.task_id, context.context_id);
+        updater.start_work(None).await?;
+        for step in 0..STEPS {
+            updater
+                .add_artifact(
+                    vec![Part::data(state(step))],
+                    Some("state".to_string()),
+                    None,
+                    None,
+                    Some(false),
+                    Some(step + 1 == STEPS),
+                    None,
+                )
+                .await?;
+        }
+        updater.complete(None).await
+    }
+
+    async fn cancel(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        TaskUpdater::new(event_queue, context.task_id, context.context_id)
+            .cancel(None)
+            .await
+    }
+}
+
+/// Start the agent, advertising the extension when `advertise` is set.
+async fn start(advertise: bool) -> String {
+    let card = |url: &str| {
+        let mut card = AgentCardBuilder::new("State", "Streams evolving state", "1.0.0")
+            .with_jsonrpc_interface(url)
+            .with_streaming(true);
+        if advertise {
+            card = card.with_extension(DATA_DELTA_EXTENSION_URI, None, false);
+        }
+        card.build()
+    };
+    let (base_url, _server) = common::start_test_server_with_card(
+        Arc::new(StateAgent),
+        Arc::new(InMemoryTaskStore::new()),
+        card,
+    )
+    .await;
+    format!("{base_url}/a2a")
+}
+
+fn params() -> SendMessageParams {
+    SendMessageParams {
+        message: Message::user("m1", "go"),
+        configuration: None,
+        metadata: None,
+        tenant: None,
+    }
+}
+
+/// Stream a message and collect the Data parts and the task ID.
+async fn data_parts(client: &A2AClient) -> (Vec<Part>, String) {
+    let mut stream = client.send_message_stream(params()).await.unwrap();
+    let mut parts = Vec::new();
+    let mut task_id = String::new();
+    while let Some(event) = stream.next().await {
+        if let StreamResponse::ArtifactUpdate(update) = event.unwrap() {
+            task_id = update.task_id;
+            parts.extend(update.artifact.parts);
+        }
+    }
+    (parts, task_id)
+}
+
+/// Raw SSE body of a `message/stream` call, requesting `extensions`.
+async fn raw_stream(url: &str, extensions: &str) -> String {
+    reqwest::Client::new()
+        .post(url)
+        .header(HTTP_EXTENSION_HEADER, extensions)
+        .json(&common::jsonrpc_request(
+            json!(1),
+            "message/stream",
+            serde_json::to_value(params()).unwrap(),
+        ))
+        .send()
+        .await
+        .unwrap()
+        .text()
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_client_reconstructs_full_data_parts() {
+    let url = start(true).await;
+    let client = ClientBuilder::new(&url)
+        .with_data_delta()
+        .build_from_endpoint();
+
+    let (parts, task_id) = data_parts(&client).await;
+
+    let expected: Vec<Value> = (0..STEPS).map(state).collect();
+    let received: Vec<Value> = parts
+        .iter()
+        .map(|part| match part {
+            Part::Data { data, metadata } => {
+                assert!(metadata.is_none(), "delta marker left on {part:?}");
+                data.clone()
+            }
+            other => panic!("unexpected part {other:?}"),
+        })
+        .collect();
+    assert_eq!(received, expected);
+
+    let task = client
+        .get_task(GetTaskParams {
+            id: task_id,
+            history_length: None,
+            metadata: None,
+            tenant: None,
+            consistency: None,
+        })
+        .await
+        .unwrap();
+    let Part::Data { data, .. } = &task.artifacts.unwrap()[0].parts[0] else {
+        panic!("expected data part");
+    };
+    assert_eq!(*data, state(STEPS - 1), "task store keeps full payloads");
+}
+
+#[tokio::test]
+async fn test_deltas_sent_only_when_negotiated() {
+    let url = start(true).await;
+
+    let with = raw_stream(&url, DATA_DELTA_EXTENSION_URI).await;
+    assert_eq!(
+        with.matches("\"dataDelta\":\"json-patch\"").count(),
+        STEPS - 1
+    );
+
+    let without = raw_stream(&url, "urn:other").await;
+    assert!(!without.contains("dataDelta"));
+
+    let not_advertised = raw_stream(&start(false).await, DATA_DELTA_EXTENSION_URI).await;
+    assert!(!not_advertised.contains("dataDelta"));
+}
+
+#[tokio::test]
+async fn test_with_data_delta_keeps_other_extensions() {
+    let url = start(true).await;
+    let client = ClientBuilder::new(&url)
+        .with_extensions(["urn:other"])
+        .with_data_delta()
+        .build_from_endpoint();
+
+    let mut stream = client.send_message_stream(params()).await.unwrap();
+    let activated = stream.activated_extensions().clone();
+    while stream.next().await.is_some() {}
+
+    assert!(activated.contains(DATA_DELTA_EXTENSION_URI));
+}
+
+#[test]
+fn test_encoder_skips_deltas_larger_than_payload() {
+    let mut encoder = DataDeltaEncoder::new();
+    let update = |data: Value| {
+        StreamResponse::ArtifactUpdate(TaskArtifactUpdateEvent {
+            task_id: "t1".to_string(),
+            context_id: "c1".to_string(),
+            kind: "artifact-update".to_string(),
+            artifact: Artifact {
+                artifact_id: "a1".to_string(),
+                name: None,
+                description: None,
+                parts: vec![Part::data(data)],
+                metadata: None,
+                extensions: None,
+            },
+            append: None,
+            last_chunk: None,
+            metadata: None,
+        })
+    };
+
+    encoder.encode(update(json!({"a": 1})));
+    let StreamResponse::ArtifactUpdate(second) = encoder.encode(update(json!({"b": 2}))) else {
+        panic!("expected artifact update");
+    };
+    assert!(!a2a_rs::utils::is_data_delta(&second.artifact.parts[0]));
+}
@@ -0,0 +1,224 @@
+//! Deadlines: `timeoutMs` and `X-A2A-Deadline` bound a request's
+//! execution, reach the executor and are passed on along a delegation
+//! chain.
+
+mod common;
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use a2a_rs::client::{A2AClient, CallOptions, LocalTransport};
+use a2a_rs::error::{A2AResult, DEADLINE_EXCEEDED};
+use a2a_rs::server::{
+    AgentExecutor, DefaultRequestHandler, EventQueue, ExecutorErrorReport, ExecutorErrorReporter,
+    ExecutorFailureKind, InMemoryTaskStore, RequestContext, TaskStore, TaskUpdater,
+};
+use a2a_rs::types::*;
+use a2a_rs::utils::{
+    current_deadline, deadline_after, format_deadline, get_message_text, parse_deadline,
+    with_deadline,
+};
+use async_trait::async_trait;
+use common::{start_test_server, start_test_server_with_store};
+
+/// Agent completing with its deadline, or "none".
+struct DeadlineAgent;
+
+#[async_trait]
+impl AgentExecutor for DeadlineAgent {
+    async fn execute(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        assert_eq!(context.deadline(), current_deadline());
+        let text = match context.deadline() {
+            Some(deadline) => {
+                assert!(context.time_remaining().unwrap() > Duration::ZERO);
+                format_deadline(deadline)
+            }
+            None => "none".to_string(),
+        };
+        TaskUpdater::new(event_queue, context.task_id, context.context_id)
+            .complete_with_text(&text)
+            .await
+    }
+
+    async fn cancel(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        TaskUpdater::new(event_queue, context.task_id, context.context_id)
+            .cancel(None)
+            .await
+    }
+}
+
+/// Agent asking another agent and completing with its answer.
+struct DelegatingAgent {
+    downstream: A2AClient,
+}
+
+#[async_trait]
+impl AgentExecutor for DelegatingAgent {
+    async fn execute(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        let task = expect_task(self.downstream.send_text("when?").await?);
+        TaskUpdater::new(event_queue, context.task_id, context.context_id)
+            .complete_with_text(&status_text(&task))
+            .await
+    }
+
+    async fn cancel(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        TaskUpdater::new(event_queue, context.task_id, context.context_id)
+            .cancel(None)
+            .await
+    }
+}
+
+/// Starts work and never finishes.
+struct StuckAgent;
+
+#[async_trait]
+impl AgentExecutor for StuckAgent {
+    async fn execute(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        TaskUpdater::new(event_queue, context.task_id, context.context_id)
+            .start_work(None)
+            .await?;
+        std::future::pending().await
+    }
+
+    async fn cancel(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        TaskUpdater::new(event_queue, context.task_id, context.context_id)
+            .cancel(None)
+            .await
+    }
+}
+
+#[derive(Default)]
+struct Collect(Mutex<Vec<ExecutorErrorReport>>);
+
+impl ExecutorErrorReporter for Collect {
+    fn report(&self, report: &ExecutorErrorReport) {
+        self.0.lock().unwrap().push(report.clone());
+    }
+}
+
+fn status_text(task: &Task) -> String {
+    get_message_text(task.status.message.as_ref().expect("status message"), "")
+}
+
+fn expect_task(response: SendMessageResponse) -> Task {
+    match response {
+        SendMessageResponse::Task(task) => task,
+        other => panic!("expected a task, got {other:?}"),
+    }
+}
+
+fn params(timeout_ms: Option<u64>) -> SendMessageParams {
+    SendMessageParams {
+        message: Message::user("m1", "hi"),
+        configuration: Some(SendMessageConfiguration {
+            blocking: Some(true),
+            timeout_ms,
+            ..Default::default()
+        }),
+        metadata: None,
+        tenant: None,
+    }
+}
+
+#[tokio::test]
+async fn test_timeout_fails_task_with_deadline_exceeded() {
+    let store = Arc::new(InMemoryTaskStore::new());
+    let reports = Arc::new(Collect::default());
+    let handler = Arc::new(
+        DefaultRequestHandler::new(Arc::new(StuckAgent), store.clone())
+            .with_error_reporter(reports.clone()),
+    );
+    let client = A2AClient::with_transport(Box::new(LocalTransport::new(handler)));
+
+    let err = tokio::time::timeout(
+        Duration::from_secs(5),
+        client.send_message(params(Some(100))),
+    )
+    .await
+    .expect("deadline not enforced")
+    .unwrap_err();
+    assert!(err.is_deadline_exceeded(), "{err:?}");
+    let task_id = err.data().unwrap()["taskId"].as_str().unwrap().to_string();
+
+    let stored = store.get(&task_id).await.unwrap().unwrap();
+    assert_eq!(stored.status.state, TaskState::Failed);
+    assert!(status_text(&stored).contains("deadline"));
+    let reports = reports.0.lock().unwrap();
+    assert_eq!(reports.len(), 1);
+    assert_eq!(reports[0].kind, ExecutorFailureKind::DeadlineExceeded);
+}
+
+#[tokio::test]
+async fn test_deadline_exceeded_reaches_http_clients() {
+    let (base_url, _server) = start_test_server(Arc::new(StuckAgent)).await;
+    let client = A2AClient::from_endpoint(&format!("{base_url}/a2a"));
+
+    let options = CallOptions::new().with_deadline(deadline_after(Duration::from_millis(100)));
+    let err = client
+        .send_message_with_options(params(None), &options)
+        .await
+        .unwrap_err();
+    assert!(err.is_deadline_exceeded(), "{err:?}");
+    assert_eq!(err.code(), DEADLINE_EXCEEDED);
+}
+
+#[tokio::test]
+async fn test_executor_sees_the_earliest_deadline() {
+    let store = Arc::new(InMemoryTaskStore::new());
+    let (base_url, _server) =
+        start_test_server_with_store(Arc::new(DeadlineAgent), store.clone()).await;
+    let client = A2AClient::from_endpoint(&format!("{base_url}/a2a"));
+
+    let task = expect_task(client.send_message(params(None)).await.unwrap());
+    assert_eq!(status_text(&task), "none");
+
+    // The header is earlier than the budget.
+    let deadline = deadline_after(Duration::from_secs(30));
+    let options = CallOptions::new().with_deadline(deadline);
+    let task = expect_task(
+        client
+            .send_message_with_options(params(Some(60_000)), &options)
+            .await
+            .unwrap(),
+    );
+    assert_eq!(status_text(&task), format_deadline(deadline));
+
+    // The budget is earlier than the header.
+    let options = CallOptions::new().with_deadline(deadline_after(Duration::from_secs(600)));
+    let task = expect_task(
+        client
+            .send_message_with_options(params(Some(30_000)), &options)
+            .await
+            .unwrap(),
+    );
+    let seen = parse_deadline(&status_text(&task)).unwrap();
+    assert!(seen < deadline_after(Duration::from_secs(31)));
+
+    // A malformed header is ignored.
+    let options = CallOptions::new().with_header("X-A2A-Deadline", "soon");
+    let task = expect_task(
+        client
+            .send_message_with_options(params(None), &options)
+            .await
+            .unwrap(),
+    );
+    assert_eq!(status_text(&task), "none");
+}
+
+#[tokio::test]
+async fn test_delegated_calls_carry_the_deadline() {
+    let (downstream_url, _downstream) = start_test_server(Arc::new(DeadlineAgent)).await;
+    let agent = DelegatingAgent {
+        downstream: A2AClient::from_endpoint(&format!("{downstream_url}/a2a")),
+    };
+    let (base_url, _server) = start_test_server(Arc::new(agent)).await;
+    let client = A2AClient::from_endpoint(&format!("{base_url}/a2a"));
+
+    let deadline = deadline_after(Duration::from_secs(30));
+    let task = expect_task(
+        with_deadline(deadline, client.send_message(params(None)))
+            .await
+            .unwrap(),
+    );
+    assert_eq!(status_text(&task), format_deadline(deadline));
+}
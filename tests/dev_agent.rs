@@ -0,0 +1,155 @@
+//! The `dev-agent` reference executor: echo/mirror replies, chunked
+//! artifacts, simulated failures, input-required round trips and
+//! per-message metadata overrides.
+#![cfg(feature = "dev-agent")]
+
+mod common;
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use a2a_rs::client::A2AClient;
+use a2a_rs::server::dev_agent::DEV_AGENT_ARTIFACT_ID;
+use a2a_rs::server::{DevAgent, DevAgentConfig, DevAgentMode, InMemoryTaskStore};
+use a2a_rs::types::*;
+use serde_json::json;
+
+async fn start(config: DevAgentConfig) -> A2AClient {
+    let (base_url, _server) = common::start_test_server_with_card(
+        Arc::new(DevAgent::new(config)),
+        Arc::new(InMemoryTaskStore::new()),
+        DevAgent::agent_card,
+    )
+    .await;
+    A2AClient::from_endpoint(&format!("{base_url}/a2a"))
+}
+
+fn params(message: Message) -> SendMessageParams {
+    SendMessageParams {
+        message,
+        configuration: None,
+        metadata: None,
+        tenant: None,
+    }
+}
+
+fn task_of(response: SendMessageResponse) -> Task {
+    match response {
+        SendMessageResponse::Task(task) => task,
+        SendMessageResponse::Message(_) => panic!("expected task"),
+    }
+}
+
+fn texts(parts: &[Part]) -> Vec<&str> {
+    parts
+        .iter()
+        .filter_map(|p| match p {
+            Part::Text { text, .. } => Some(text.as_str()),
+            _ => None,
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn test_echo_by_default() {
+    let client = start(DevAgentConfig::default()).await;
+    let task = task_of(client.send_text("hello").await.unwrap());
+    assert_eq!(task.status.state, TaskState::Completed);
+    let artifact = &task.artifacts.unwrap()[0];
+    assert_eq!(artifact.artifact_id, DEV_AGENT_ARTIFACT_ID);
+    assert_eq!(texts(&artifact.parts), vec!["Echo: hello"]);
+}
+
+#[tokio::test]
+async fn test_mirror_returns_all_parts() {
+    let client = start(DevAgentConfig::default().with_mode(DevAgentMode::Mirror)).await;
+    let mut message = Message::user("m1", "hi");
+    message.parts.push(Part::data(json!({"k": 1})));
+
+    let task = task_of(client.send_message(params(message.clone())).await.unwrap());
+    let mirrored = serde_json::to_value(&task.artifacts.unwrap()[0].parts).unwrap();
+    assert_eq!(mirrored, serde_json::to_value(&message.parts).unwrap());
+}
+
+#[tokio::test]
+async fn test_chunked_artifacts_stream_in_order() {
+    let client = start(DevAgentConfig::default().with_chunks(3)).await;
+    let mut stream = client.send_text_stream("abcdef").await.unwrap();
+
+    let mut chunks = Vec::new();
+    while let Some(event) = stream.next().await {
+        if let StreamResponse::ArtifactUpdate(update) = event.unwrap() {
+            chunks.push((
+                texts(&update.artifact.parts).concat(),
+                update.append,
+                update.last_chunk,
+            ));
+        }
+    }
+    assert_eq!(chunks.len(), 3);
+    let joined: String = chunks.iter().map(|(t, ..)| t.as_str()).collect();
+    assert_eq!(joined, "Echo: abcdef");
+    assert_eq!(chunks[0].1, Some(false));
+    assert_eq!(chunks[1].1, Some(true));
+    assert_eq!(chunks[2].2, Some(true));
+
+    // The stored task holds the assembled artifact.
+    let task = task_of(client.send_text("abcdef").await.unwrap());
+    assert_eq!(
+        texts(&task.artifacts.unwrap()[0].parts).concat(),
+        "Echo: abcdef"
+    );
+}
+
+#[tokio::test]
+async fn test_fail_rate_one_always_fails() {
+    let client = start(DevAgentConfig::default().with_fail_rate(1.0)).await;
+    let task = task_of(client.send_text("hi").await.unwrap());
+    assert_eq!(task.status.state, TaskState::Failed);
+    assert!(task.artifacts.unwrap_or_default().is_empty());
+}
+
+#[tokio::test]
+async fn test_input_required_round_trip() {
+    let client = start(DevAgentConfig::default().with_input_required(true)).await;
+    let first = task_of(client.send_text("hi").await.unwrap());
+    assert_eq!(first.status.state, TaskState::InputRequired);
+
+    let mut answer = Message::user("m2", "yes");
+    answer.task_id = Some(first.id.clone());
+    answer.context_id = Some(first.context_id.clone());
+    let second = task_of(client.send_message(params(answer)).await.unwrap());
+    assert_eq!(second.id, first.id);
+    assert_eq!(second.status.state, TaskState::Completed);
+    assert_eq!(
+        texts(&second.artifacts.unwrap()[0].parts),
+        vec!["Echo: yes"]
+    );
+}
+
+#[tokio::test]
+async fn test_metadata_overrides_config() {
+    let client = start(DevAgentConfig::default()).await;
+    let mut message = Message::user("m1", "hi");
+    message.metadata = Some(json!({"devAgent": {"delayMs": 50, "failRate": 1.0}}));
+
+    let started = Instant::now();
+    let task = task_of(client.send_message(params(message)).await.unwrap());
+    assert!(started.elapsed() >= Duration::from_millis(50));
+    assert_eq!(task.status.state, TaskState::Failed);
+}
+
+#[test]
+fn test_invalid_overrides_are_rejected() {
+    let mut message = Message::user("m1", "hi");
+    message.metadata = Some(json!({"devAgent": {"chunks": "many"}}));
+    assert!(DevAgentConfig::default()
+        .for_message(Some(&message))
+        .is_err());
+
+    message.metadata = Some(json!({"devAgent": {"chunks": 0, "failRate": 7}}));
+    let config = DevAgentConfig::default()
+        .for_message(Some(&message))
+        .unwrap();
+    assert_eq!((config.chunks, config.fail_rate), (1, 1.0));
+}
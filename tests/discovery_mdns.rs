@@ -0,0 +1,56 @@
+//! mDNS announcement and local discovery (`discovery-mdns` feature).
+#![cfg(feature = "discovery-mdns")]
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use a2a_rs::discovery::{advertise, discover_local_urls, discover_local_with_timeout};
+use a2a_rs::error::A2AResult;
+use a2a_rs::server::{AgentExecutor, EventQueue, RequestContext, TaskUpdater};
+use async_trait::async_trait;
+
+mod common;
+
+struct NoopAgent;
+
+#[async_trait]
+impl AgentExecutor for NoopAgent {
+    async fn execute(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        TaskUpdater::new(event_queue, context.task_id, context.context_id)
+            .complete(None)
+            .await
+    }
+
+    async fn cancel(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        TaskUpdater::new(event_queue, context.task_id, context.context_id)
+            .cancel(None)
+            .await
+    }
+}
+
+#[tokio::test]
+async fn test_advertised_agent_is_discovered() {
+    let (url, _handle) = common::start_test_server(Arc::new(NoopAgent)).await;
+    let advertiser = advertise("Discovery Test Agent", &url).await.unwrap();
+    assert_eq!(advertiser.url(), url);
+
+    let cards = discover_local_with_timeout(Duration::from_millis(500))
+        .await
+        .unwrap();
+    // Other agents on the LAN may answer too.
+    assert!(cards
+        .iter()
+        .any(|c| c.name == common::test_agent_card(&url).name));
+
+    advertiser.stop().await.unwrap();
+    let urls = discover_local_urls(Duration::from_millis(300))
+        .await
+        .unwrap();
+    assert!(!urls.contains(&url));
+}
+
+#[tokio::test]
+async fn test_invalid_instance_name_is_rejected() {
+    let err = advertise("", "http://127.0.0.1:1").await.unwrap_err();
+    assert!(matches!(err, a2a_rs::A2AError::InvalidParams { .. }));
+}
@@ -11,39 +11,39 @@ use std::collections::{HashMap, HashSet};
 
 #[test]
 fn test_get_requested_extensions_empty() {
-    assert_eq!(get_requested_extensions(&vec![]), HashSet::new());
+    assert_eq!(get_requested_extensions(&[]), HashSet::new());
 }
 
 #[test]
 fn test_get_requested_extensions_single() {
-    let result = get_requested_extensions(&vec!["foo".to_string()]);
+    let result = get_requested_extensions(&["foo".to_string()]);
     assert_eq!(result, vec!["foo"].into_iter().map(String::from).collect());
 }
 
 #[test]
 fn test_get_requested_extensions_multiple() {
-    let result = get_requested_extensions(&vec!["foo".to_string(), "bar".to_string()]);
+    let result = get_requested_extensions(&["foo".to_string(), "bar".to_string()]);
     let expected: HashSet<String> = vec!["foo", "bar"].into_iter().map(String::from).collect();
     assert_eq!(result, expected);
 }
 
 #[test]
 fn test_get_requested_extensions_comma_separated() {
-    let result = get_requested_extensions(&vec!["foo, bar".to_string()]);
+    let result = get_requested_extensions(&["foo, bar".to_string()]);
     let expected: HashSet<String> = vec!["foo", "bar"].into_iter().map(String::from).collect();
     assert_eq!(result, expected);
 }
 
 #[test]
 fn test_get_requested_extensions_comma_no_space() {
-    let result = get_requested_extensions(&vec!["foo,bar".to_string()]);
+    let result = get_requested_extensions(&["foo,bar".to_string()]);
     let expected: HashSet<String> = vec!["foo", "bar"].into_iter().map(String::from).collect();
     assert_eq!(result, expected);
 }
 
 #[test]
 fn test_get_requested_extensions_mixed() {
-    let result = get_requested_extensions(&vec!["foo".to_string(), "bar,baz".to_string()]);
+    let result = get_requested_extensions(&["foo".to_string(), "bar,baz".to_string()]);
     let expected: HashSet<String> = vec!["foo", "bar", "baz"]
         .into_iter()
         .map(String::from)
@@ -53,7 +53,7 @@ fn test_get_requested_extensions_mixed() {
 
 #[test]
 fn test_get_requested_extensions_empty_segments() {
-    let result = get_requested_extensions(&vec!["foo,, bar".to_string(), "baz".to_string()]);
+    let result = get_requested_extensions(&["foo,, bar".to_string(), "baz".to_string()]);
     let expected: HashSet<String> = vec!["foo", "bar", "baz"]
         .into_iter()
         .map(String::from)
@@ -63,7 +63,7 @@ fn test_get_requested_extensions_empty_segments() {
 
 #[test]
 fn test_get_requested_extensions_with_spaces() {
-    let result = get_requested_extensions(&vec![" foo , bar ".to_string(), "baz".to_string()]);
+    let result = get_requested_extensions(&[" foo , bar ".to_string(), "baz".to_string()]);
     let expected: HashSet<String> = vec!["foo", "bar", "baz"]
         .into_iter()
         .map(String::from)
@@ -0,0 +1,173 @@
+//! `X-A2A-Extensions` negotiation between the client transport and the
+//! axum integration.
+
+mod common;
+
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+
+use a2a_rs::builders::AgentCardBuilder;
+use a2a_rs::client::{A2AClient, CallMetrics, CallOptions, JsonRpcTransport, TransportObserver};
+use a2a_rs::error::A2AResult;
+use a2a_rs::server::{AgentExecutor, EventQueue, InMemoryTaskStore, RequestContext, TaskUpdater};
+use a2a_rs::types::*;
+use a2a_rs::utils::{format_extension_header, negotiate_extensions};
+use async_trait::async_trait;
+
+const EXT_A: &str = "urn:ext:a";
+const EXT_B: &str = "urn:ext:b";
+
+/// Replies with the extensions the call context reports as activated.
+struct ReportingAgent;
+
+#[async_trait]
+impl AgentExecutor for ReportingAgent {
+    async fn execute(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        let activated = context
+            .call_context
+            .as_ref()
+            .map(|c| format_extension_header(&c.activated_extensions))
+            .unwrap_or_else(|| "<no context>".to_string());
+        TaskUpdater::new(event_queue, context.task_id, context.context_id)
+            .complete_with_text(&activated)
+            .await
+    }
+
+    async fn cancel(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        TaskUpdater::new(event_queue, context.task_id, context.context_id)
+            .cancel(None)
+            .await
+    }
+}
+
+#[derive(Default)]
+struct Recorder {
+    calls: Mutex<Vec<CallMetrics>>,
+}
+
+impl TransportObserver for Recorder {
+    fn on_call(&self, metrics: &CallMetrics) {
+        self.calls.lock().unwrap().push(metrics.clone());
+    }
+}
+
+fn card(url: &str, required: bool) -> AgentCard {
+    AgentCardBuilder::new("Ext Agent", "Negotiates extensions", "0.1.0")
+        .with_jsonrpc_interface(url)
+        .with_streaming(true)
+        .with_extension(EXT_A, None, false)
+        .with_extension(EXT_B, None, required)
+        .build()
+}
+
+async fn start_server(required: bool) -> String {
+    let (base_url, _server) = common::start_test_server_with_card(
+        Arc::new(ReportingAgent),
+        Arc::new(InMemoryTaskStore::new()),
+        |url| card(url, required),
+    )
+    .await;
+    format!("{base_url}/a2a")
+}
+
+fn reply_text(response: SendMessageResponse) -> String {
+    let SendMessageResponse::Task(task) = response else {
+        panic!("expected task");
+    };
+    match &task.status.message.unwrap().parts[0] {
+        Part::Text { text, .. } => text.clone(),
+        other => panic!("unexpected part {other:?}"),
+    }
+}
+
+fn set(uris: &[&str]) -> HashSet<String> {
+    uris.iter().map(|s| s.to_string()).collect()
+}
+
+#[test]
+fn test_negotiate_ignores_unknown_and_flags_required() {
+    let card = card("http://x/a2a", true);
+    let outcome = negotiate_extensions(&card, &set(&[EXT_A, "urn:unknown"]));
+    assert_eq!(outcome.activated, set(&[EXT_A]));
+    assert_eq!(outcome.missing_required, vec![EXT_B.to_string()]);
+
+    let outcome = negotiate_extensions(&card, &set(&[EXT_A, EXT_B]));
+    assert_eq!(outcome.activated, set(&[EXT_A, EXT_B]));
+    assert!(outcome.missing_required.is_empty());
+}
+
+#[test]
+fn test_call_options_with_extensions_sets_header() {
+    let options = CallOptions::new().with_extensions([EXT_A, EXT_B]);
+    assert_eq!(options.headers["X-A2A-Extensions"], "urn:ext:a,urn:ext:b");
+}
+
+#[tokio::test]
+async fn test_unary_call_activates_supported_extensions() {
+    let url = start_server(false).await;
+    let recorder = Arc::new(Recorder::default());
+    let transport = JsonRpcTransport::new(&url)
+        .with_extensions([EXT_A, "urn:unknown"])
+        .with_observer(recorder.clone());
+    let client = A2AClient::with_transport(Box::new(transport));
+
+    let text = reply_text(client.send_text("hi").await.unwrap());
+    assert_eq!(text, EXT_A);
+
+    let calls = recorder.calls.lock().unwrap();
+    assert_eq!(calls[0].activated_extensions, Some(set(&[EXT_A])));
+}
+
+#[tokio::test]
+async fn test_no_header_means_nothing_activated() {
+    let url = start_server(false).await;
+    let recorder = Arc::new(Recorder::default());
+    let transport = JsonRpcTransport::new(&url).with_observer(recorder.clone());
+    let client = A2AClient::with_transport(Box::new(transport));
+
+    let text = reply_text(client.send_text("hi").await.unwrap());
+    assert_eq!(text, "");
+    assert_eq!(
+        recorder.calls.lock().unwrap()[0].activated_extensions,
+        Some(HashSet::new())
+    );
+}
+
+#[tokio::test]
+async fn test_per_call_extensions_on_stream() {
+    let url = start_server(false).await;
+    let client = A2AClient::from_endpoint(&url);
+
+    let params = SendMessageParams {
+        message: Message::user("m1", "hi"),
+        configuration: None,
+        metadata: None,
+        tenant: None,
+    };
+    let options = CallOptions::new().with_extensions([EXT_A, EXT_B]);
+    let mut stream = client
+        .send_message_stream_with_options(params, &options)
+        .await
+        .unwrap();
+    assert_eq!(stream.activated_extensions(), &set(&[EXT_A, EXT_B]));
+    while let Some(event) = stream.next().await {
+        event.unwrap();
+    }
+}
+
+#[tokio::test]
+async fn test_missing_required_extension_is_rejected() {
+    let url = start_server(true).await;
+
+    let client = A2AClient::from_endpoint(&url);
+    let err = client.send_text("hi").await.unwrap_err();
+    assert!(matches!(
+        err,
+        a2a_rs::A2AError::JsonRpc { code: -32600, ref message, .. } if message.contains(EXT_B)
+    ));
+
+    let client = A2AClient::with_transport(Box::new(
+        JsonRpcTransport::new(&url).with_extensions([EXT_B]),
+    ));
+    assert_eq!(reply_text(client.send_text("hi").await.unwrap()), EXT_B);
+}
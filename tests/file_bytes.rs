@@ -0,0 +1,262 @@
+//! Binary-safe `FileWithBytes` helpers and base64 validation on deserialize.
+
+use a2a_rs::error::A2AError;
+use a2a_rs::types::*;
+use serde_json::json;
+
+#[test]
+fn test_from_bytes_roundtrip() {
+    let raw: Vec<u8> = (0..=255).collect();
+    let file = FileWithBytes::from_bytes(&raw)
+        .with_name("all.bin")
+        .with_mime_type("application/octet-stream");
+
+    assert_eq!(file.encoded_len(), 344);
+    assert_eq!(file.decoded_len(), 256);
+    assert_eq!(file.decoded_bytes().unwrap(), raw);
+
+    let json = serde_json::to_value(&file).unwrap();
+    assert_eq!(json["name"], "all.bin");
+    let back: FileWithBytes = serde_json::from_value(json).unwrap();
+    assert_eq!(back, file);
+}
+
+#[test]
+fn test_decoded_len_matches_for_every_padding() {
+    for len in 0..8 {
+        let raw = vec![0xAB; len];
+        let file = FileWithBytes::from_bytes(&raw);
+        assert_eq!(file.decoded_len(), len, "{}", file.bytes);
+
+        let unpadded = FileWithBytes {
+            bytes: file.bytes.trim_end_matches('=').to_string(),
+            mime_type: None,
+            name: None,
+        };
+        assert_eq!(unpadded.decoded_len(), len, "{}", unpadded.bytes);
+    }
+}
+
+#[test]
+fn test_bytes_conversions() {
+    let shared = bytes::Bytes::from_static(b"hello");
+    let file = FileWithBytes::from(shared.clone());
+    assert_eq!(file.bytes, "aGVsbG8=");
+    assert_eq!(FileWithBytes::from(b"hello".to_vec()), file);
+    assert_eq!(FileWithBytes::from(&b"hello"[..]), file);
+
+    let decoded = file.to_bytes().unwrap();
+    assert_eq!(decoded, shared);
+    assert_eq!(decoded.slice(1..3), &b"el"[..]);
+}
+
+#[test]
+fn test_decode_into_reuses_buffer() {
+    let mut buf = b"prefix:".to_vec();
+    FileWithBytes::from_bytes(b"hello")
+        .decode_into(&mut buf)
+        .unwrap();
+    assert_eq!(buf, b"prefix:hello");
+
+    let bad = FileWithBytes {
+        bytes: "not base64!".to_string(),
+        mime_type: None,
+        name: None,
+    };
+    let err = bad.decode_into(&mut buf).unwrap_err();
+    assert!(matches!(err, A2AError::InvalidParams { .. }), "{err}");
+    assert_eq!(buf, b"prefix:hello", "buffer untouched on error");
+    assert!(bad.decoded_bytes().is_err());
+}
+
+#[test]
+fn test_file_from_raw_part() {
+    let part = Part::file_from_raw(b"hello", Some("a.txt".into()), Some("text/plain".into()));
+    assert_eq!(
+        serde_json::to_value(&part).unwrap(),
+        serde_json::to_value(Part::file_from_bytes(
+            "aGVsbG8=",
+            Some("a.txt".into()),
+            Some("text/plain".into())
+        ))
+        .unwrap()
+    );
+}
+
+#[test]
+fn test_valid_base64_deserializes() {
+    for bytes in ["", "AQID", "SGVsbG8=", "SGk=", "+/+/"] {
+        let part: Part =
+            serde_json::from_value(json!({"kind": "file", "file": {"bytes": bytes}})).unwrap();
+        assert!(
+            matches!(
+                part,
+                Part::File {
+                    file: FileContent::Bytes(_),
+                    ..
+                }
+            ),
+            "{bytes}"
+        );
+    }
+    let uri: FileContent = serde_json::from_value(json!({"uri": "https://x/y"})).unwrap();
+    assert!(matches!(uri, FileContent::Uri(_)));
+}
+
+#[cfg(not(feature = "lenient-base64"))]
+#[test]
+fn test_invalid_base64_rejected() {
+    for bytes in ["not base64!", "SGVsbG8", "SGVsbG8===", "SGVs\nbG8="] {
+        let err = serde_json::from_value::<Part>(json!({
+            "kind": "file",
+            "file": {"bytes": bytes, "name": "x"}
+        }))
+        .unwrap_err();
+        assert!(
+            err.to_string().contains("not valid base64"),
+            "{bytes}: {err}"
+        );
+    }
+
+    let request = json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "message/send",
+        "params": {"message": {
+            "kind": "message",
+            "messageId": "m1",
+            "role": "user",
+            "parts": [{"kind": "file", "file": {"bytes": "%%%%"}}]
+        }}
+    });
+    let request: JsonRpcRequest = serde_json::from_value(request).unwrap();
+    assert!(serde_json::from_value::<SendMessageParams>(request.params.unwrap()).is_err());
+}
+
+#[cfg(feature = "lenient-base64")]
+#[test]
+fn test_invalid_base64_accepted_when_lenient() {
+    let part: Part = serde_json::from_value(json!({
+        "kind": "file",
+        "file": {"bytes": "not base64!"}
+    }))
+    .unwrap();
+    let Part::File {
+        file: FileContent::Bytes(file),
+        ..
+    } = part
+    else {
+        panic!("expected bytes");
+    };
+    assert!(file.decoded_bytes().is_err());
+}
+
+#[test]
+fn test_decode_matches_decoded_bytes() {
+    let file = FileWithBytes::from_bytes([0u8, 159, 146, 150]);
+    assert_eq!(file.decode().unwrap(), [0u8, 159, 146, 150]);
+    assert!(FileWithBytes {
+        bytes: "%%%%".to_string(),
+        mime_type: None,
+        name: None,
+    }
+    .decode()
+    .is_err());
+}
+
+#[tokio::test]
+async fn test_download_inline_and_uri_files() {
+    use axum::routing::get;
+
+    let app = axum::Router::new()
+        .route("/report.bin", get(|| async { vec![1u8, 2, 3, 255] }))
+        .route(
+            "/missing",
+            get(|| async { (axum::http::StatusCode::NOT_FOUND, "gone") }),
+        );
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base = format!("http://{}", listener.local_addr().unwrap());
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+    let inline = FileContent::Bytes(FileWithBytes::from_bytes(b"inline"));
+    assert_eq!(inline.download().await.unwrap(), b"inline");
+
+    let remote = FileContent::Uri(FileWithUri {
+        uri: format!("{base}/report.bin"),
+        mime_type: None,
+        name: None,
+    });
+    assert_eq!(remote.download().await.unwrap(), [1u8, 2, 3, 255]);
+
+    let missing = FileContent::Uri(FileWithUri {
+        uri: format!("{base}/missing"),
+        mime_type: None,
+        name: None,
+    });
+    match missing.download().await {
+        Err(A2AError::Http { status, body }) => {
+            assert_eq!(status, 404);
+            assert_eq!(body, "gone");
+        }
+        other => panic!("expected an HTTP error, got {other:?}"),
+    }
+}
+
+#[cfg(feature = "fs")]
+#[tokio::test]
+async fn test_file_from_path_reads_and_guesses_mime_type() {
+    let dir = std::env::temp_dir().join(format!("a2a-file-part-{}", uuid::Uuid::new_v4()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("chart.png");
+    let raw: Vec<u8> = (0..=255).collect();
+    std::fs::write(&path, &raw).unwrap();
+
+    let Part::File {
+        file: FileContent::Bytes(file),
+        ..
+    } = Part::file_from_path(&path).await.unwrap()
+    else {
+        panic!("expected bytes");
+    };
+    assert_eq!(file.name.as_deref(), Some("chart.png"));
+    assert_eq!(file.mime_type.as_deref(), Some("image/png"));
+    assert_eq!(file.decode().unwrap(), raw);
+
+    let err = Part::file_from_path_with_limit(&path, 255)
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("larger than"), "{err}");
+    assert!(Part::file_from_path(dir.join("absent.txt")).await.is_err());
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[cfg(feature = "fs")]
+#[tokio::test]
+async fn test_file_from_reader_enforces_limit() {
+    let part = Part::file_from_reader(&b"a,b\n1,2\n"[..], Some("t.csv".into()), None, 8)
+        .await
+        .unwrap();
+    let Part::File {
+        file: FileContent::Bytes(file),
+        ..
+    } = part
+    else {
+        panic!("expected bytes");
+    };
+    assert_eq!(file.mime_type.as_deref(), Some("text/csv"));
+    assert_eq!(file.decode().unwrap(), b"a,b\n1,2\n");
+
+    let explicit = Part::file_from_reader(&b"{}"[..], None, Some("application/json".into()), 8)
+        .await
+        .unwrap();
+    assert!(matches!(
+        explicit,
+        Part::File { file: FileContent::Bytes(FileWithBytes { mime_type: Some(ref m), .. }), .. }
+            if m == "application/json"
+    ));
+
+    assert!(Part::file_from_reader(&b"123456789"[..], None, None, 8)
+        .await
+        .is_err());
+}
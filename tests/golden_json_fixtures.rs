@@ -34,6 +34,7 @@ fn assert_serializes_to<T: serde::Serialize>(value: &T, expected: &Value) {
 }
 
 /// Deserialize golden JSON and re-serialize, checking round-trip matches golden.
+#[allow(dead_code)]
 fn assert_round_trip<T: serde::Serialize + serde::de::DeserializeOwned>(golden: &Value) {
     let deserialized: T = serde_json::from_value(golden.clone())
         .unwrap_or_else(|e| panic!("Failed to deserialize golden JSON: {e}\nJSON: {golden}"));
@@ -48,51 +49,51 @@ fn assert_round_trip<T: serde::Serialize + serde::de::DeserializeOwned>(golden:
 #[test]
 fn golden_task_state_submitted() {
     let state = TaskState::Submitted;
-    let json = serde_json::to_value(&state).unwrap();
+    let json = serde_json::to_value(state).unwrap();
     assert_eq!(json, json!("submitted"));
 }
 
 #[test]
 fn golden_task_state_working() {
-    let json = serde_json::to_value(&TaskState::Working).unwrap();
+    let json = serde_json::to_value(TaskState::Working).unwrap();
     assert_eq!(json, json!("working"));
 }
 
 #[test]
 fn golden_task_state_completed() {
-    let json = serde_json::to_value(&TaskState::Completed).unwrap();
+    let json = serde_json::to_value(TaskState::Completed).unwrap();
     assert_eq!(json, json!("completed"));
 }
 
 #[test]
 fn golden_task_state_failed() {
-    let json = serde_json::to_value(&TaskState::Failed).unwrap();
+    let json = serde_json::to_value(TaskState::Failed).unwrap();
     assert_eq!(json, json!("failed"));
 }
 
 #[test]
 fn golden_task_state_canceled() {
-    let json = serde_json::to_value(&TaskState::Canceled).unwrap();
+    let json = serde_json::to_value(TaskState::Canceled).unwrap();
     assert_eq!(json, json!("canceled"));
 }
 
 #[test]
 fn golden_task_state_input_required() {
     // Proto: TASK_STATE_INPUT_REQUIRED → kebab-case: "input-required"
-    let json = serde_json::to_value(&TaskState::InputRequired).unwrap();
+    let json = serde_json::to_value(TaskState::InputRequired).unwrap();
     assert_eq!(json, json!("input-required"));
 }
 
 #[test]
 fn golden_task_state_rejected() {
-    let json = serde_json::to_value(&TaskState::Rejected).unwrap();
+    let json = serde_json::to_value(TaskState::Rejected).unwrap();
     assert_eq!(json, json!("rejected"));
 }
 
 #[test]
 fn golden_task_state_auth_required() {
     // Proto: TASK_STATE_AUTH_REQUIRED → kebab-case: "auth-required"
-    let json = serde_json::to_value(&TaskState::AuthRequired).unwrap();
+    let json = serde_json::to_value(TaskState::AuthRequired).unwrap();
     assert_eq!(json, json!("auth-required"));
 }
 
@@ -122,13 +123,13 @@ fn golden_task_state_deserialize_all() {
 
 #[test]
 fn golden_role_user() {
-    let json = serde_json::to_value(&Role::User).unwrap();
+    let json = serde_json::to_value(Role::User).unwrap();
     assert_eq!(json, json!("user"));
 }
 
 #[test]
 fn golden_role_agent() {
-    let json = serde_json::to_value(&Role::Agent).unwrap();
+    let json = serde_json::to_value(Role::Agent).unwrap();
     assert_eq!(json, json!("agent"));
 }
 
@@ -1941,6 +1942,7 @@ fn golden_verify_camel_case_field_names() {
             state: TaskState::Working,
             message: None,
             timestamp: None,
+            raw_state: None,
         },
         artifacts: None,
         history: None,
@@ -2018,6 +2020,7 @@ fn golden_verify_status_update_camel_case() {
             state: TaskState::Working,
             message: None,
             timestamp: None,
+            raw_state: None,
         },
         r#final: false,
         metadata: None,
@@ -2064,9 +2067,13 @@ fn golden_verify_artifact_update_camel_case() {
 // ============================================================================
 
 #[test]
-fn golden_reject_invalid_task_state() {
-    let result = serde_json::from_str::<TaskState>("\"invalid-state\"");
-    assert!(result.is_err(), "Should reject unknown task state");
+fn golden_unrecognized_task_state_maps_to_unknown() {
+    // Future states must not fail parsing; they map to Unknown.
+    let state = serde_json::from_str::<TaskState>("\"invalid-state\"").unwrap();
+    assert_eq!(state, TaskState::Unknown);
+
+    let result = serde_json::from_str::<TaskState>("42");
+    assert!(result.is_err(), "Should reject non-string task state");
 }
 
 #[test]
@@ -2165,6 +2172,7 @@ fn golden_task_optional_fields_omitted() {
             state: TaskState::Submitted,
             message: None,
             timestamp: None,
+            raw_state: None,
         },
         artifacts: None,
         history: None,
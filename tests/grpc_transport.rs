@@ -0,0 +1,311 @@
+//! gRPC binding (`grpc` feature): `GrpcTransport` against `GrpcService`,
+//! error mapping, transport negotiation from the agent card and the
+//! protobuf conversions.
+#![cfg(feature = "grpc")]
+
+mod common;
+
+use std::sync::Arc;
+
+use a2a_rs::builders::ClientBuilder;
+use a2a_rs::client::{A2AClient, CardResolver, GrpcTransport, Transport};
+use a2a_rs::error::{self, A2AError};
+use a2a_rs::grpc::proto;
+use a2a_rs::server::{
+    AgentExecutor, DefaultRequestHandler, GrpcService, InMemoryTaskStore, RequestHandler,
+};
+use a2a_rs::types::*;
+use a2a_rs::utils::get_message_text;
+use common::{EchoAgent, SlowEchoAgent};
+use serde_json::json;
+use tonic::transport::server::TcpIncoming;
+
+fn handler(executor: Arc<dyn AgentExecutor>) -> Arc<dyn RequestHandler> {
+    Arc::new(DefaultRequestHandler::new(
+        executor,
+        Arc::new(InMemoryTaskStore::new()),
+    ))
+}
+
+/// Serve `handler` over gRPC on an ephemeral port; returns its URL.
+async fn start_grpc_server(handler: Arc<dyn RequestHandler>) -> String {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(
+        tonic::transport::Server::builder()
+            .add_service(GrpcService::new(handler))
+            .serve_with_incoming(TcpIncoming::from(listener)),
+    );
+    format!("http://{addr}")
+}
+
+/// Serve `handler` over JSON-RPC on an ephemeral port; returns the base URL.
+async fn start_jsonrpc_server(handler: Arc<dyn RequestHandler>) -> String {
+    let (base_url, _server) =
+        common::start_test_server_with_handler(handler, common::test_agent_card).await;
+    base_url
+}
+
+fn grpc_client(url: &str) -> A2AClient {
+    A2AClient::with_transport(Box::new(GrpcTransport::new(url).unwrap()))
+}
+
+fn status_text(task: &Task) -> String {
+    get_message_text(task.status.message.as_ref().unwrap(), "")
+}
+
+fn interface(url: &str, transport: &str) -> AgentInterface {
+    AgentInterface {
+        url: url.to_string(),
+        transport: transport.to_string(),
+        tenant: None,
+        protocol_version: None,
+    }
+}
+
+#[tokio::test]
+async fn test_send_message_over_grpc() {
+    let url = start_grpc_server(handler(Arc::new(EchoAgent))).await;
+    let client = grpc_client(&url);
+
+    let SendMessageResponse::Task(task) = client.send_text("hello").await.unwrap() else {
+        panic!("expected a task");
+    };
+    assert_eq!(task.status.state, TaskState::Completed);
+    assert_eq!(status_text(&task), "Echo: hello");
+
+    let fetched = client.get_task_by_id(&task.id, None).await.unwrap();
+    assert_eq!(fetched.id, task.id);
+    assert_eq!(fetched.context_id, task.context_id);
+    assert_eq!(fetched.status.state, TaskState::Completed);
+}
+
+#[tokio::test]
+async fn test_stream_ends_with_final_status() {
+    let url = start_grpc_server(handler(Arc::new(SlowEchoAgent))).await;
+    let client = grpc_client(&url);
+
+    let mut stream = client.send_text_stream("hi").await.unwrap();
+    let mut events = Vec::new();
+    while let Some(event) = stream.next().await {
+        events.push(event.unwrap());
+    }
+    let Some(StreamResponse::StatusUpdate(last)) = events.last() else {
+        panic!("expected a status update last, got {events:?}");
+    };
+    assert!(last.r#final);
+    assert_eq!(last.status.state, TaskState::Completed);
+}
+
+#[tokio::test]
+async fn test_a2a_errors_keep_their_codes() {
+    let url = start_grpc_server(handler(Arc::new(EchoAgent))).await;
+    let client = grpc_client(&url);
+
+    let err = client.get_task_by_id("missing", None).await.unwrap_err();
+    assert_eq!(err.code(), error::TASK_NOT_FOUND, "{err:?}");
+    let err = client.cancel_task_by_id("missing").await.unwrap_err();
+    assert_eq!(err.code(), error::TASK_NOT_FOUND, "{err:?}");
+    let err = client
+        .resubscribe_by_id("missing")
+        .await
+        .map(drop)
+        .unwrap_err();
+    assert_eq!(err.code(), error::TASK_NOT_FOUND, "{err:?}");
+}
+
+#[tokio::test]
+async fn test_unmapped_methods_are_unsupported() {
+    let url = start_grpc_server(handler(Arc::new(EchoAgent))).await;
+    let transport = GrpcTransport::new(&url).unwrap();
+
+    let request = JsonRpcRequest {
+        jsonrpc: "2.0".to_string(),
+        id: Some(JsonRpcId::Number(7)),
+        method: "tasks/pushNotificationConfig/get".to_string(),
+        params: Some(json!({"id": "t1"})),
+    };
+    let response = transport.send(&request).await.unwrap();
+    assert_eq!(response.id, Some(JsonRpcId::Number(7)));
+    assert_eq!(response.error.unwrap().code, error::UNSUPPORTED_OPERATION);
+}
+
+#[tokio::test]
+async fn test_unreachable_endpoint_is_a_transport_error() {
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    drop(listener);
+
+    let transport = GrpcTransport::new(&url).unwrap();
+    let err = transport.connect().await.unwrap_err();
+    assert!(matches!(err, A2AError::Transport(_)), "{err:?}");
+
+    let err = grpc_client(&url).send_text("hi").await.unwrap_err();
+    assert!(matches!(err, A2AError::Transport(_)), "{err:?}");
+}
+
+#[tokio::test]
+async fn test_one_handler_serves_both_transports() {
+    let handler = handler(Arc::new(EchoAgent));
+    let grpc_url = start_grpc_server(handler.clone()).await;
+    let base_url = start_jsonrpc_server(handler).await;
+
+    let SendMessageResponse::Task(task) = grpc_client(&grpc_url).send_text("both").await.unwrap()
+    else {
+        panic!("expected a task");
+    };
+    let over_jsonrpc = A2AClient::from_endpoint(&format!("{base_url}/a2a"))
+        .get_task_by_id(&task.id, None)
+        .await
+        .unwrap();
+    assert_eq!(status_text(&over_jsonrpc), "Echo: both");
+}
+
+#[tokio::test]
+async fn test_from_card_negotiates_preferred_transport() {
+    let handler = handler(Arc::new(EchoAgent));
+    let grpc_url = start_grpc_server(handler.clone()).await;
+    let base_url = start_jsonrpc_server(handler).await;
+    let jsonrpc_url = format!("{base_url}/a2a");
+
+    let mut card = common::test_agent_card(&jsonrpc_url);
+    card.supported_interfaces.push(interface(&grpc_url, "GRPC"));
+    card.preferred_transport = Some("GRPC".to_string());
+
+    let client = A2AClient::from_card(card.clone()).unwrap();
+    let metadata = client.transport_metadata();
+    assert_eq!(metadata.protocol, "GRPC");
+    assert_eq!(metadata.endpoint.as_deref(), Some(grpc_url.as_str()));
+    assert!(client.send_text("negotiated").await.is_ok());
+
+    card.preferred_transport = Some("JSONRPC".to_string());
+    let client = A2AClient::from_card(card).unwrap();
+    assert_eq!(client.transport_metadata().protocol, "JSONRPC");
+}
+
+#[tokio::test]
+async fn test_builder_preference_overrides_card() {
+    let handler = handler(Arc::new(EchoAgent));
+    let grpc_url = start_grpc_server(handler.clone()).await;
+    let card = |url: &str| {
+        let mut card = common::test_agent_card(url);
+        card.supported_interfaces.push(interface(&grpc_url, "GRPC"));
+        card.preferred_transport = Some("GRPC".to_string());
+        card
+    };
+    let (base_url, _server) = common::start_test_server_with_handler(handler, card).await;
+
+    let client = ClientBuilder::new(&base_url).build().await.unwrap();
+    assert_eq!(client.transport_metadata().protocol, "GRPC");
+
+    let client = ClientBuilder::new(&base_url)
+        .with_preferred_transport("jsonrpc")
+        .build()
+        .await
+        .unwrap();
+    assert_eq!(client.transport_metadata().protocol, "JSONRPC");
+    assert!(client.send_text("preferred").await.is_ok());
+}
+
+#[test]
+fn test_negotiation_falls_back_to_first_usable_interface() {
+    let mut card = common::test_agent_card("http://localhost:1/a2a");
+    card.supported_interfaces = vec![
+        interface("http://localhost:2", "HTTP+JSON"),
+        interface("http://localhost:3", "grpc"),
+        interface("http://localhost:1/a2a", "JSONRPC"),
+    ];
+    card.preferred_transport = Some("HTTP+JSON".to_string());
+
+    let chosen = CardResolver::negotiate_interface(&card, CardResolver::SUPPORTED_TRANSPORTS);
+    assert_eq!(chosen.unwrap().url, "http://localhost:3");
+    let chosen = CardResolver::negotiate_interface(&card, &["JSONRPC"]);
+    assert_eq!(chosen.unwrap().url, "http://localhost:1/a2a");
+    assert!(CardResolver::negotiate_interface(&card, &["WEBSOCKET"]).is_none());
+}
+
+#[test]
+fn test_message_round_trips_through_proto() {
+    let message = Message {
+        message_id: "m1".to_string(),
+        role: Role::Agent,
+        kind: "message".to_string(),
+        parts: vec![
+            Part::text("hello"),
+            Part::file_from_raw(b"\x00\x01binary", Some("a.bin".to_string()), None),
+            Part::file_from_uri("https://example.com/a.png", None, Some("image/png".into())),
+            Part::data(json!({"count": 3, "ratio": 0.5, "tags": ["a", null]})),
+        ],
+        context_id: Some("c1".to_string()),
+        task_id: None,
+        metadata: Some(json!({"trace": "abc"})),
+        extensions: Some(vec!["https://example.com/ext".to_string()]),
+        reference_task_ids: None,
+    };
+
+    let encoded = proto::Message::try_from(message.clone()).unwrap();
+    let Some(proto::part::Part::File(file)) = &encoded.content[1].part else {
+        panic!("expected a file part");
+    };
+    assert_eq!(
+        file.file,
+        Some(proto::file_part::File::FileWithBytes(
+            b"\x00\x01binary".to_vec()
+        ))
+    );
+
+    let decoded = Message::try_from(encoded).unwrap();
+    assert_eq!(
+        serde_json::to_value(&decoded).unwrap(),
+        serde_json::to_value(&message).unwrap()
+    );
+}
+
+#[test]
+fn test_task_round_trips_through_proto() {
+    let mut status = TaskStatus::new(TaskState::Canceled);
+    status.timestamp = Some("2026-01-02T03:04:05.250+00:00".to_string());
+    let task = Task {
+        id: "t1".to_string(),
+        context_id: "c1".to_string(),
+        kind: "task".to_string(),
+        status,
+        artifacts: Some(vec![Artifact {
+            artifact_id: "a1".to_string(),
+            name: Some("out.txt".to_string()),
+            description: None,
+            parts: vec![Part::text("result")],
+            metadata: None,
+            extensions: None,
+        }]),
+        history: None,
+        metadata: None,
+    };
+
+    let encoded = proto::Task::try_from(task.clone()).unwrap();
+    assert_eq!(
+        encoded.status.as_ref().unwrap().state,
+        proto::TaskState::Cancelled as i32
+    );
+    let decoded = Task::try_from(encoded).unwrap();
+    assert_eq!(
+        serde_json::to_value(&decoded).unwrap(),
+        serde_json::to_value(&task).unwrap()
+    );
+
+    let missing_status = proto::Task {
+        id: "t1".to_string(),
+        ..Default::default()
+    };
+    let err = Task::try_from(missing_status).unwrap_err();
+    assert_eq!(err.code(), error::INVALID_PARAMS);
+}
+
+#[tokio::test]
+async fn test_from_card_accepts_grpc_only_card() {
+    let mut card = common::test_agent_card("http://localhost:1/a2a");
+    card.supported_interfaces = vec![interface("http://localhost:50051", "gRPC")];
+
+    let client = A2AClient::from_card(card).unwrap();
+    assert_eq!(client.transport_metadata().protocol, "GRPC");
+}
@@ -0,0 +1,117 @@
+//! `TaskUpdater::require_input` and `A2AClient::continue_task`: an agent
+//! pauses with a prompt and input schema, the client answers in the same
+//! task.
+
+mod common;
+
+use std::sync::Arc;
+
+use a2a_rs::client::{create_text_message, A2AClient, SendMessageResponse};
+use a2a_rs::error::A2AResult;
+use a2a_rs::server::{AgentExecutor, EventQueue, RequestContext, TaskUpdater};
+use a2a_rs::types::*;
+use a2a_rs::utils::{get_input_schema, get_message_text};
+use async_trait::async_trait;
+use serde_json::json;
+
+/// Asks for a city, then reports the weather there.
+struct WeatherAgent;
+
+#[async_trait]
+impl AgentExecutor for WeatherAgent {
+    async fn execute(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        let input = context.get_user_input("");
+        let updater = TaskUpdater::new(event_queue, context.task_id, context.context_id);
+        match context.task {
+            Some(task) if task.status.state == TaskState::InputRequired => {
+                updater
+                    .complete_with_text(&format!("Sunny in {input}"))
+                    .await
+            }
+            _ => {
+                updater
+                    .require_input("Which city?", Some(json!({"type": "string"})))
+                    .await
+            }
+        }
+    }
+
+    async fn cancel(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        TaskUpdater::new(event_queue, context.task_id, context.context_id)
+            .cancel(None)
+            .await
+    }
+}
+
+fn task(response: SendMessageResponse) -> Task {
+    match response {
+        SendMessageResponse::Task(task) => task,
+        other => panic!("expected a task, got {other:?}"),
+    }
+}
+
+fn status_text(task: &Task) -> String {
+    get_message_text(task.status.message.as_ref().unwrap(), "")
+}
+
+async fn client() -> A2AClient {
+    let (base_url, _handle) = common::start_test_server(Arc::new(WeatherAgent)).await;
+    A2AClient::from_endpoint(&format!("{base_url}/a2a"))
+}
+
+#[tokio::test]
+async fn test_prompt_carries_schema_and_continuation_completes() {
+    let client = client().await;
+
+    let paused = task(client.send_text("weather?").await.unwrap());
+    assert_eq!(paused.status.state, TaskState::InputRequired);
+    let prompt = paused.status.message.as_ref().unwrap();
+    assert_eq!(get_message_text(prompt, ""), "Which city?");
+    assert_eq!(get_input_schema(prompt), Some(&json!({"type": "string"})));
+
+    let reply = create_text_message(Role::User, "Oslo");
+    let done = task(client.continue_task(&paused.id, reply).await.unwrap());
+    assert_eq!(done.id, paused.id);
+    assert_eq!(done.context_id, paused.context_id);
+    assert_eq!(done.status.state, TaskState::Completed);
+    assert_eq!(status_text(&done), "Sunny in Oslo");
+
+    // The finished task can't be continued.
+    let reply = create_text_message(Role::User, "Bergen");
+    assert!(client.continue_task(&paused.id, reply).await.is_err());
+}
+
+#[tokio::test]
+async fn test_streaming_continuation() {
+    let client = client().await;
+    let paused = task(client.send_text("weather?").await.unwrap());
+
+    let mut reply = create_text_message(Role::User, "Lima");
+    reply.context_id = Some(paused.context_id.clone());
+    let mut stream = client
+        .continue_task_stream(&paused.id, reply)
+        .await
+        .unwrap();
+    let mut last = None;
+    while let Some(event) = stream.next().await {
+        if let StreamResponse::StatusUpdate(update) = event.unwrap() {
+            assert_eq!(update.task_id, paused.id);
+            assert_eq!(update.context_id, paused.context_id);
+            last = Some(update.status);
+        }
+    }
+    let status = last.unwrap();
+    assert_eq!(status.state, TaskState::Completed);
+    assert_eq!(
+        get_message_text(status.message.as_ref().unwrap(), ""),
+        "Sunny in Lima"
+    );
+}
+
+#[tokio::test]
+async fn test_unknown_task_fails_before_sending() {
+    let client = client().await;
+    let reply = create_text_message(Role::User, "Oslo");
+    let err = client.continue_task("missing", reply).await.unwrap_err();
+    assert!(err.to_string().contains("not found"), "{err}");
+}
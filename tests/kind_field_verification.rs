@@ -16,6 +16,7 @@ fn test_task_has_kind_field_in_json() {
             state: TaskState::Submitted,
             message: None,
             timestamp: Some("2026-02-12T00:00:00Z".to_string()),
+            raw_state: None,
         },
         artifacts: None,
         history: None,
@@ -106,6 +107,7 @@ fn test_task_and_message_kind_fields_together() {
             state: TaskState::Working,
             message: None,
             timestamp: Some("2026-02-12T12:00:00Z".to_string()),
+            raw_state: None,
         },
         artifacts: None,
         history: None,
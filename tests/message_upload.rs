@@ -0,0 +1,314 @@
+//! `message/sendStream`: message parts uploaded incrementally, read by the
+//! executor through `IncrementalMessage` as they arrive, over HTTP and the
+//! local transport.
+
+mod common;
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use a2a_rs::client::{A2AClient, LocalTransport};
+use a2a_rs::error::{A2AError, A2AResult};
+use a2a_rs::server::{
+    AgentExecutor, DefaultRequestHandler, EventQueue, GetTaskParams, InMemoryTaskStore,
+    IncrementalMessage, RequestContext, RequestHandler, SendMessageParams, TaskStore, TaskUpdater,
+};
+use a2a_rs::types::*;
+use async_trait::async_trait;
+use serde_json::json;
+use tokio::sync::mpsc;
+
+/// Agent completing with the text of every part, read one at a time; each
+/// text is reported on `seen` as soon as the agent reads it.
+struct UploadAgent {
+    seen: Option<mpsc::UnboundedSender<String>>,
+}
+
+#[async_trait]
+impl AgentExecutor for UploadAgent {
+    async fn execute(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        let updater = TaskUpdater::new(event_queue, context.task_id, context.context_id);
+        let mut incoming = context
+            .incremental_message
+            .unwrap_or_else(|| IncrementalMessage::complete(context.message.unwrap()));
+        updater.start_work(None).await?;
+        let mut texts = Vec::new();
+        while let Some(part) = incoming.next_part().await {
+            match part {
+                Ok(Part::Text { text, .. }) => {
+                    if let Some(seen) = &self.seen {
+                        let _ = seen.send(text.clone());
+                    }
+                    texts.push(text);
+                }
+                Ok(_) => {}
+                Err(e) => return updater.failed_with_text(&e.to_string()).await,
+            }
+        }
+        updater.complete_with_text(&texts.join(" ")).await
+    }
+
+    async fn cancel(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        TaskUpdater::new(event_queue, context.task_id, context.context_id)
+            .cancel(None)
+            .await
+    }
+}
+
+fn params(text: &str) -> SendMessageParams {
+    SendMessageParams {
+        message: Message::user("m1", text),
+        configuration: None,
+        metadata: None,
+        tenant: None,
+    }
+}
+
+/// Client-side params of a `message/sendStream` call.
+fn upload_params(text: &str) -> a2a_rs::types::SendMessageParams {
+    a2a_rs::types::SendMessageParams {
+        message: Message::user("m1", text),
+        configuration: None,
+        metadata: None,
+        tenant: None,
+    }
+}
+
+fn status_text(task: &Task) -> String {
+    let message = task.status.message.as_ref().expect("status message");
+    match &message.parts[0] {
+        Part::Text { text, .. } => text.clone(),
+        part => panic!("expected text, got {part:?}"),
+    }
+}
+
+fn expect_task(response: SendMessageResponse) -> Task {
+    match response {
+        SendMessageResponse::Task(task) => task,
+        other => panic!("expected a task, got {other:?}"),
+    }
+}
+
+async fn serve(handler: Arc<DefaultRequestHandler>) -> String {
+    let (base_url, _server) =
+        common::start_test_server_with_handler(handler, common::test_agent_card).await;
+    format!("{base_url}/a2a")
+}
+
+#[tokio::test]
+async fn test_incremental_message_hands_out_parts_as_they_arrive() {
+    let (sender, mut incoming) = IncrementalMessage::channel(Message::user("m1", "one"));
+    let mut other = incoming.clone();
+    assert_eq!(incoming.message().message_id, "m1");
+    assert!(incoming.message().parts.is_empty());
+
+    assert!(matches!(incoming.next_part().await, Some(Ok(_))));
+    let waiting = tokio::spawn(async move { incoming.next_part().await });
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    assert!(!waiting.is_finished());
+    sender.send(Part::text("two"));
+    assert!(matches!(waiting.await.unwrap(), Some(Ok(_))));
+    assert!(!other.is_complete());
+    sender.finish();
+
+    // Every handle reads every part; assembling doesn't consume them.
+    let whole = other.assemble().await.unwrap();
+    assert_eq!(whole.parts.len(), 2);
+    assert!(other.next_part().await.is_some());
+    assert!(other.next_part().await.is_some());
+    assert!(other.next_part().await.is_none());
+    assert_eq!(other.parts_received(), 2);
+}
+
+#[tokio::test]
+async fn test_dropped_sender_breaks_off_the_message() {
+    let (sender, mut incoming) = IncrementalMessage::channel(Message::user("m1", "one"));
+    drop(sender);
+
+    assert!(matches!(incoming.next_part().await, Some(Ok(_))));
+    assert!(matches!(
+        incoming.next_part().await,
+        Some(Err(A2AError::InvalidParams { .. }))
+    ));
+    assert!(incoming.next_part().await.is_none());
+    assert!(incoming.assemble().await.is_err());
+}
+
+#[tokio::test]
+async fn test_agent_reads_parts_while_the_client_uploads() {
+    let (seen_tx, mut seen) = mpsc::unbounded_channel();
+    let store = Arc::new(InMemoryTaskStore::new());
+    let agent = UploadAgent {
+        seen: Some(seen_tx),
+    };
+    let handler = Arc::new(DefaultRequestHandler::new(Arc::new(agent), store.clone()));
+    let client = A2AClient::from_endpoint(&serve(handler).await);
+
+    let (parts_tx, parts_rx) = mpsc::unbounded_channel();
+    let parts = futures::stream::unfold(parts_rx, |mut rx| async move {
+        rx.recv().await.map(|part| (part, rx))
+    });
+    let upload = tokio::spawn(async move {
+        client
+            .send_message_incremental(upload_params("one"), parts)
+            .await
+    });
+    // Each part is only sent once the agent read the previous one.
+    let wait = Duration::from_secs(5);
+    assert_eq!(
+        tokio::time::timeout(wait, seen.recv())
+            .await
+            .unwrap()
+            .unwrap(),
+        "one"
+    );
+    parts_tx.send(Part::text("two")).unwrap();
+    assert_eq!(
+        tokio::time::timeout(wait, seen.recv())
+            .await
+            .unwrap()
+            .unwrap(),
+        "two"
+    );
+    parts_tx.send(Part::text("three")).unwrap();
+    drop(parts_tx);
+
+    let task = expect_task(upload.await.unwrap().unwrap());
+    assert_eq!(task.status.state, TaskState::Completed);
+    assert_eq!(status_text(&task), "one two three");
+
+    // The task's history holds the whole message.
+    let stored = store.get(&task.id).await.unwrap().unwrap();
+    let history = stored.history.unwrap_or_default();
+    let uploaded = history.iter().find(|m| m.message_id == "m1").unwrap();
+    assert_eq!(uploaded.parts.len(), 3);
+}
+
+#[tokio::test]
+async fn test_invalid_part_line_fails_the_upload() {
+    let handler = Arc::new(DefaultRequestHandler::new(
+        Arc::new(UploadAgent { seen: None }),
+        Arc::new(InMemoryTaskStore::new()),
+    ));
+    let url = serve(handler).await;
+    let request = json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "message/sendStream",
+        "params": {"message": Message::user("m1", "one")},
+    });
+    let body = format!(
+        "{request}\n{}\nnot a part\n",
+        json!({"kind": "text", "text": "two"})
+    );
+
+    let response: serde_json::Value = reqwest::Client::new()
+        .post(&url)
+        .header("Content-Type", "application/x-ndjson")
+        .body(body)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(response["result"]["status"]["state"], "failed");
+
+    // Other methods can't be uploaded.
+    let request = json!({"jsonrpc": "2.0", "id": 2, "method": "tasks/get", "params": {"id": "t1"}});
+    let response: serde_json::Value = reqwest::Client::new()
+        .post(&url)
+        .header("Content-Type", "application/x-ndjson")
+        .body(format!("{request}\n"))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(response["error"]["code"], -32600);
+}
+
+#[tokio::test]
+async fn test_local_transport_feeds_parts_to_the_handler() {
+    let store = Arc::new(InMemoryTaskStore::new());
+    let handler = Arc::new(DefaultRequestHandler::new(
+        Arc::new(UploadAgent { seen: None }),
+        store.clone(),
+    ));
+    let client = A2AClient::with_transport(Box::new(LocalTransport::new(handler)));
+
+    let parts = futures::stream::iter(vec![Part::text("two"), Part::text("three")]);
+    let task = expect_task(
+        client
+            .send_message_incremental(upload_params("one"), parts)
+            .await
+            .unwrap(),
+    );
+    assert_eq!(status_text(&task), "one two three");
+
+    // Without further parts the method behaves like message/send.
+    let task = expect_task(
+        client
+            .send_message_incremental(upload_params("alone"), futures::stream::empty())
+            .await
+            .unwrap(),
+    );
+    let stored = store.get(&task.id).await.unwrap().unwrap();
+    assert_eq!(status_text(&stored), "alone");
+}
+
+/// Handler implementing only `message/send`, recording what it got.
+struct SendOnly;
+
+#[async_trait]
+impl RequestHandler for SendOnly {
+    async fn on_message_send(&self, params: SendMessageParams) -> A2AResult<SendMessageResponse> {
+        Ok(SendMessageResponse::Message(params.message))
+    }
+
+    async fn on_message_send_stream(
+        &self,
+        _: SendMessageParams,
+    ) -> A2AResult<tokio::sync::broadcast::Receiver<StreamResponse>> {
+        Err(A2AError::unsupported_operation("send only"))
+    }
+
+    async fn on_get_task(&self, params: GetTaskParams) -> A2AResult<Task> {
+        Err(A2AError::task_not_found(params.id))
+    }
+
+    async fn on_list_tasks(
+        &self,
+        _: a2a_rs::server::TaskListParams,
+    ) -> A2AResult<a2a_rs::server::TaskListResponse> {
+        Err(A2AError::unsupported_operation("send only"))
+    }
+
+    async fn on_cancel_task(&self, _: a2a_rs::server::CancelTaskParams) -> A2AResult<Task> {
+        Err(A2AError::unsupported_operation("send only"))
+    }
+
+    async fn on_subscribe_to_task(
+        &self,
+        _: a2a_rs::server::SubscribeToTaskParams,
+    ) -> A2AResult<tokio::sync::broadcast::Receiver<StreamResponse>> {
+        Err(A2AError::unsupported_operation("send only"))
+    }
+}
+
+#[tokio::test]
+async fn test_default_handler_method_sends_the_assembled_message() {
+    let (sender, incoming) = IncrementalMessage::channel(Message::user("m1", "one"));
+    sender.send(Part::text("two"));
+    sender.finish();
+
+    let response = SendOnly
+        .on_message_send_incremental(params("one"), incoming)
+        .await
+        .unwrap();
+    let SendMessageResponse::Message(message) = response else {
+        panic!("expected a message, got {response:?}");
+    };
+    assert_eq!(message.parts.len(), 2);
+}
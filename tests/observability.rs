@@ -0,0 +1,266 @@
+//! `observability` feature: W3C trace-context propagation between agents
+//! and task / SSE metrics.
+
+#![cfg(feature = "observability")]
+
+mod common;
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use a2a_rs::client::A2AClient;
+use a2a_rs::error::A2AResult;
+use a2a_rs::observability::{TraceContext, TRACEPARENT_HEADER};
+use a2a_rs::server::{
+    a2a_router_with_config, metrics_router, AgentExecutor, DefaultRequestHandler, EventQueue,
+    InMemoryTaskStore, MetricsRecorder, OpenMetricsRecorder, RequestContext, RouterConfig,
+    TaskUpdater,
+};
+use a2a_rs::types::*;
+use async_trait::async_trait;
+
+/// Records the trace context each execution runs in, then echoes.
+#[derive(Default)]
+struct TraceRecorder {
+    seen: Mutex<Vec<TraceContext>>,
+}
+
+#[async_trait]
+impl AgentExecutor for TraceRecorder {
+    async fn execute(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        self.seen
+            .lock()
+            .unwrap()
+            .push(TraceContext::current().expect("no trace context"));
+        common::EchoAgent.execute(context, event_queue).await
+    }
+
+    async fn cancel(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        common::EchoAgent.cancel(context, event_queue).await
+    }
+}
+
+/// Forwards the user's text to `downstream`.
+struct Forwarder {
+    downstream: A2AClient,
+    seen: Mutex<Option<TraceContext>>,
+}
+
+#[async_trait]
+impl AgentExecutor for Forwarder {
+    async fn execute(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        *self.seen.lock().unwrap() = TraceContext::current();
+        self.downstream
+            .send_text(&context.get_user_input(""))
+            .await?;
+        TaskUpdater::new(event_queue, context.task_id, context.context_id)
+            .complete_with_text("forwarded")
+            .await
+    }
+
+    async fn cancel(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        TaskUpdater::new(event_queue, context.task_id, context.context_id)
+            .cancel(None)
+            .await
+    }
+}
+
+/// Adds an artifact, then completes after a pause.
+struct PausingAgent;
+
+#[async_trait]
+impl AgentExecutor for PausingAgent {
+    async fn execute(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        let updater = TaskUpdater::new(event_queue, context.task_id, context.context_id);
+        updater.start_work(None).await?;
+        updater
+            .add_artifact(
+                vec![Part::text("partial")],
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+            .await?;
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        updater.complete_with_text("done").await
+    }
+
+    async fn cancel(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        TaskUpdater::new(event_queue, context.task_id, context.context_id)
+            .cancel(None)
+            .await
+    }
+}
+
+#[test]
+fn test_traceparent_parse_and_format() {
+    let header = "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01";
+    let trace = TraceContext::parse(header, Some("vendor=1")).unwrap();
+    assert_eq!(trace.trace_id, "4bf92f3577b34da6a3ce929d0e0e4736");
+    assert_eq!(trace.span_id, "00f067aa0ba902b7");
+    assert!(trace.sampled);
+    assert_eq!(trace.trace_state.as_deref(), Some("vendor=1"));
+    assert_eq!(trace.traceparent(), header);
+
+    let unsampled = TraceContext::parse(
+        "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-00",
+        None,
+    );
+    assert!(!unsampled.unwrap().sampled);
+
+    for invalid in [
+        "",
+        "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7",
+        "00-4BF92F3577B34DA6A3CE929D0E0E4736-00f067aa0ba902b7-01",
+        "00-00000000000000000000000000000000-00f067aa0ba902b7-01",
+        "00-4bf92f3577b34da6a3ce929d0e0e4736-0000000000000000-01",
+        "ff-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01",
+        "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01-extra",
+    ] {
+        assert!(TraceContext::parse(invalid, None).is_none(), "{invalid}");
+    }
+
+    let root = TraceContext::new_root();
+    assert_eq!(TraceContext::parse(&root.traceparent(), None), Some(root));
+}
+
+#[tokio::test]
+async fn test_server_continues_incoming_traceparent() {
+    let agent = Arc::new(TraceRecorder::default());
+    let (base_url, _handle) = common::start_test_server(agent.clone()).await;
+
+    let parent = TraceContext::new_root();
+    let response = reqwest::Client::new()
+        .post(format!("{base_url}/a2a"))
+        .header(TRACEPARENT_HEADER, parent.traceparent())
+        .json(&common::message_send_request(1, "hi"))
+        .send()
+        .await
+        .unwrap();
+    assert!(response.status().is_success());
+
+    let seen = agent.seen.lock().unwrap().clone();
+    assert_eq!(seen.len(), 1);
+    assert_eq!(seen[0].trace_id, parent.trace_id);
+    assert_ne!(seen[0].span_id, parent.span_id);
+
+    // Without a (valid) header each request starts its own trace.
+    let response = reqwest::Client::new()
+        .post(format!("{base_url}/a2a"))
+        .header(TRACEPARENT_HEADER, "garbage")
+        .json(&common::message_send_request(2, "hi"))
+        .send()
+        .await
+        .unwrap();
+    assert!(response.status().is_success());
+    let seen = agent.seen.lock().unwrap().clone();
+    assert_ne!(seen[1].trace_id, parent.trace_id);
+}
+
+#[tokio::test]
+async fn test_trace_propagates_through_agent_to_agent_calls() {
+    let downstream = Arc::new(TraceRecorder::default());
+    let (downstream_url, _) = common::start_test_server(downstream.clone()).await;
+    let forwarder = Arc::new(Forwarder {
+        downstream: A2AClient::from_endpoint(&format!("{downstream_url}/a2a")),
+        seen: Mutex::default(),
+    });
+    let (url, _) = common::start_test_server(forwarder.clone()).await;
+    let client = A2AClient::from_endpoint(&format!("{url}/a2a"));
+
+    let root = TraceContext::new_root();
+    root.clone().scope(client.send_text("hello")).await.unwrap();
+
+    let upstream = forwarder.seen.lock().unwrap().clone().unwrap();
+    assert_eq!(upstream.trace_id, root.trace_id);
+    let downstream = downstream.seen.lock().unwrap().clone();
+    assert_eq!(downstream.len(), 1);
+    assert_eq!(downstream[0].trace_id, root.trace_id);
+    assert_ne!(downstream[0].span_id, upstream.span_id);
+}
+
+/// Serve `executor` with `metrics` on the handler, the router and
+/// `GET /metrics`; returns the base URL.
+async fn serve_with_metrics(
+    executor: Arc<dyn AgentExecutor>,
+    metrics: Arc<OpenMetricsRecorder>,
+) -> String {
+    let handler = DefaultRequestHandler::new(executor, Arc::new(InMemoryTaskStore::new()))
+        .with_metrics(metrics.clone());
+    let config = RouterConfig::default().with_metrics(metrics.clone());
+    let (base_url, _server) = common::serve_router(|base_url| {
+        a2a_router_with_config(
+            Arc::new(handler),
+            common::test_agent_card(&format!("{base_url}/a2a")),
+            config,
+        )
+        .merge(metrics_router(metrics))
+    })
+    .await;
+    base_url
+}
+
+#[tokio::test]
+async fn test_metrics_count_transitions_durations_and_subscribers() {
+    let metrics = Arc::new(OpenMetricsRecorder::new());
+    let base_url = serve_with_metrics(Arc::new(PausingAgent), metrics.clone()).await;
+    let client = A2AClient::from_endpoint(&format!("{base_url}/a2a"));
+
+    client.send_text("one").await.unwrap();
+    let mut stream = client.send_text_stream("two").await.unwrap();
+    let first = stream.next().await.unwrap().unwrap();
+    assert!(first.task_id().is_some());
+    assert_eq!(metrics.sse_subscribers(), 1);
+    while let Some(event) = stream.next().await {
+        event.unwrap();
+    }
+    drop(stream);
+
+    for _ in 0..100 {
+        if metrics.sse_subscribers() == 0 {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    assert_eq!(metrics.sse_subscribers(), 0);
+    assert_eq!(metrics.tasks_finished(TaskState::Completed), 2);
+    assert_eq!(metrics.state_transitions(TaskState::Completed), 2);
+    assert_eq!(metrics.events_published("artifact-update"), 2);
+    assert!(metrics.state_transitions(TaskState::Working) >= 2);
+
+    let body = reqwest::get(format!("{base_url}/metrics"))
+        .await
+        .unwrap()
+        .text()
+        .await
+        .unwrap();
+    assert!(body.contains("a2a_task_state_transitions_total{state=\"completed\"} 2"));
+    assert!(body.contains("a2a_task_duration_seconds_count{state=\"completed\"} 2"));
+    assert!(body.contains("a2a_task_duration_seconds_bucket{state=\"completed\",le=\"+Inf\"} 2"));
+    assert!(body.contains("a2a_events_published_total{kind=\"artifact-update\"} 2"));
+    assert!(body.contains("a2a_sse_subscribers 0"));
+    assert!(body.ends_with("# EOF\n"));
+}
+
+#[test]
+fn test_recorder_methods_default_to_no_ops() {
+    struct Nothing;
+    impl MetricsRecorder for Nothing {}
+
+    let recorder = Nothing;
+    recorder.event_published("t", "status-update");
+    recorder.task_state_changed("t", TaskState::Working);
+    recorder.task_finished("t", TaskState::Failed, Duration::from_secs(1));
+    recorder.sse_subscriber_opened();
+    recorder.sse_subscriber_closed();
+
+    let metrics = OpenMetricsRecorder::new();
+    metrics.task_finished("t", TaskState::Failed, Duration::from_millis(30));
+    let rendered = metrics.render_openmetrics();
+    assert!(rendered.contains("a2a_task_duration_seconds_bucket{state=\"failed\",le=\"0.01\"} 0"));
+    assert!(rendered.contains("a2a_task_duration_seconds_bucket{state=\"failed\",le=\"0.05\"} 1"));
+    assert_eq!(metrics.tasks_finished(TaskState::Failed), 1);
+}
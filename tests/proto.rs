@@ -0,0 +1,104 @@
+//! Protobuf flavor of the protocol types (`proto` feature): binary
+//! round trips and resource-name mapping.
+#![cfg(feature = "proto")]
+
+use a2a_rs::error::A2AError;
+use a2a_rs::proto;
+use a2a_rs::types::*;
+use prost::Message as _;
+use serde_json::json;
+
+#[test]
+fn test_task_survives_binary_encoding() {
+    let task = Task {
+        id: "t1".to_string(),
+        context_id: "c1".to_string(),
+        kind: "task".to_string(),
+        status: TaskStatus {
+            state: TaskState::InputRequired,
+            message: Some(Message::agent("m2", "which one?")),
+            timestamp: Some("2025-01-02T03:04:05+00:00".to_string()),
+            raw_state: None,
+        },
+        artifacts: Some(vec![Artifact {
+            artifact_id: "a1".to_string(),
+            name: Some("out".to_string()),
+            description: None,
+            parts: vec![Part::data(json!({"n": 1}))],
+            metadata: None,
+            extensions: None,
+        }]),
+        history: Some(vec![Message::user("m1", "hi")]),
+        metadata: Some(json!({"priority": "high"})),
+    };
+
+    let bytes = proto::Task::try_from(task).unwrap().encode_to_vec();
+    let task = Task::try_from(proto::Task::decode(&*bytes).unwrap()).unwrap();
+
+    assert_eq!(task.id, "t1");
+    assert_eq!(task.status.state, TaskState::InputRequired);
+    assert_eq!(
+        task.status.timestamp.as_deref(),
+        Some("2025-01-02T03:04:05+00:00")
+    );
+    assert_eq!(task.history.unwrap()[0].message_id, "m1");
+    let artifact = &task.artifacts.unwrap()[0];
+    assert!(matches!(&artifact.parts[0], Part::Data { data, .. } if data == &json!({"n": 1})));
+    assert_eq!(task.metadata, Some(json!({"priority": "high"})));
+}
+
+#[test]
+fn test_push_config_names() {
+    let config = TaskPushNotificationConfig {
+        id: None,
+        task_id: "t1".to_string(),
+        push_notification_config: PushNotificationConfig {
+            id: Some("hook".to_string()),
+            url: "https://example.com/hook".to_string(),
+            token: Some("secret".to_string()),
+            authentication: None,
+        },
+        tenant: None,
+    };
+    let message = proto::TaskPushNotificationConfig::from(config);
+    assert_eq!(message.name, "tasks/t1/pushNotificationConfigs/hook");
+
+    let config = TaskPushNotificationConfig::try_from(message).unwrap();
+    assert_eq!(config.task_id, "t1");
+    assert_eq!(config.id.as_deref(), Some("hook"));
+    assert_eq!(
+        config.push_notification_config.token.as_deref(),
+        Some("secret")
+    );
+
+    let err = TaskPushNotificationConfig::try_from(proto::TaskPushNotificationConfig {
+        name: "tasks/t1".to_string(),
+        push_notification_config: Some(proto::PushNotificationConfig::default()),
+    })
+    .unwrap_err();
+    assert!(matches!(err, A2AError::InvalidParams { .. }), "{err:?}");
+}
+
+#[test]
+fn test_request_names() {
+    let request = proto::GetTaskRequest::from(GetTaskParams {
+        id: "t1".to_string(),
+        history_length: Some(5),
+        metadata: None,
+        tenant: None,
+        consistency: None,
+    });
+    assert_eq!(request.name, "tasks/t1");
+    let params = GetTaskParams::from(request);
+    assert_eq!(params.id, "t1");
+    assert_eq!(params.history_length, Some(5));
+
+    let params = CancelTaskParams::from(proto::CancelTaskRequest {
+        name: "tasks/t2".to_string(),
+    });
+    assert_eq!(params.id, "t2");
+    let params = SubscribeToTaskParams::from(proto::TaskSubscriptionRequest {
+        name: "t3".to_string(),
+    });
+    assert_eq!(params.id, "t3");
+}
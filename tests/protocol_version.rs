@@ -0,0 +1,162 @@
+//! Protocol version advertisement, strict server-side pinning and the
+//! client-side card check.
+
+mod common;
+
+use std::sync::Arc;
+
+use a2a_rs::builders::{AgentCardBuilder, ClientBuilder, ServerBuilder};
+use a2a_rs::client::A2AClient;
+use a2a_rs::error::{self, A2AError};
+use a2a_rs::utils::{PROTOCOL_VERSION, PROTOCOL_VERSION_HEADER};
+use serde_json::{json, Value};
+
+/// Start an echo agent whose card declares `card_version`.
+async fn start(strict: bool, card_version: &str) -> String {
+    let (base_url, _server) = common::serve_router(|base_url| {
+        let mut card = common::test_agent_card(&format!("{base_url}/a2a"));
+        card.protocol_version = Some(card_version.to_string());
+        ServerBuilder::new(Arc::new(common::EchoAgent))
+            .with_agent_card_direct(card)
+            .with_strict_protocol_version(strict)
+            .build()
+    })
+    .await;
+    base_url
+}
+
+/// POST `body` to the JSON-RPC endpoint, optionally declaring `version` in
+/// the header. Returns the version response header and the body.
+async fn post(base_url: &str, version: Option<&str>, body: Value) -> (Option<String>, Value) {
+    let mut request = reqwest::Client::new()
+        .post(format!("{base_url}/a2a"))
+        .json(&body);
+    if let Some(version) = version {
+        request = request.header(PROTOCOL_VERSION_HEADER, version);
+    }
+    let response = request.send().await.unwrap();
+    let advertised = response
+        .headers()
+        .get(PROTOCOL_VERSION_HEADER)
+        .map(|v| v.to_str().unwrap().to_string());
+    (advertised, response.json().await.unwrap())
+}
+
+#[test]
+fn test_card_builder_advertises_current_version() {
+    let card = AgentCardBuilder::new("A", "B", "1.0.0")
+        .with_jsonrpc_interface("http://localhost/a2a")
+        .build();
+    assert_eq!(card.protocol_version.as_deref(), Some(PROTOCOL_VERSION));
+    assert_eq!(
+        card.supported_interfaces[0].protocol_version.as_deref(),
+        Some(PROTOCOL_VERSION)
+    );
+}
+
+#[tokio::test]
+async fn test_responses_advertise_version() {
+    let base_url = start(false, PROTOCOL_VERSION).await;
+    let (advertised, body) = post(&base_url, None, common::message_send_request(1, "hi")).await;
+    assert_eq!(advertised.as_deref(), Some(PROTOCOL_VERSION));
+    assert!(body.get("result").is_some(), "{body}");
+}
+
+#[tokio::test]
+async fn test_strict_server_rejects_incompatible_header() {
+    let base_url = start(true, PROTOCOL_VERSION).await;
+
+    let (advertised, body) = post(
+        &base_url,
+        Some("1.0"),
+        common::message_send_request(1, "hi"),
+    )
+    .await;
+
+    assert_eq!(advertised.as_deref(), Some(PROTOCOL_VERSION));
+    assert_eq!(body["id"], 1);
+    assert_eq!(body["error"]["code"], error::INVALID_REQUEST);
+    assert_eq!(body["error"]["data"]["protocolVersion"], "1.0");
+    assert!(body["error"]["message"]
+        .as_str()
+        .unwrap()
+        .contains("Unsupported A2A protocol version '1.0'"));
+}
+
+#[tokio::test]
+async fn test_strict_server_rejects_incompatible_param() {
+    let base_url = start(true, PROTOCOL_VERSION).await;
+    let mut request = common::message_send_request(1, "hi");
+    request["params"]["protocolVersion"] = json!("0.2.5");
+
+    let (_, body) = post(&base_url, None, request).await;
+
+    assert_eq!(body["error"]["code"], error::INVALID_REQUEST);
+}
+
+#[tokio::test]
+async fn test_strict_server_accepts_compatible_and_undeclared() {
+    let base_url = start(true, PROTOCOL_VERSION).await;
+
+    for version in [Some("0.3"), Some("0.3.1"), None] {
+        let (_, body) = post(&base_url, version, common::message_send_request(1, "hi")).await;
+        assert!(body.get("result").is_some(), "{version:?}: {body}");
+    }
+}
+
+#[tokio::test]
+async fn test_lenient_server_accepts_incompatible() {
+    let base_url = start(false, PROTOCOL_VERSION).await;
+    let (_, body) = post(
+        &base_url,
+        Some("1.0"),
+        common::message_send_request(1, "hi"),
+    )
+    .await;
+    assert!(body.get("result").is_some(), "{body}");
+}
+
+#[tokio::test]
+async fn test_client_declares_version_to_strict_server() {
+    let base_url = start(true, PROTOCOL_VERSION).await;
+
+    let client = ClientBuilder::new(&base_url).build().await.unwrap();
+    client.send_text("hi").await.unwrap();
+
+    let pinned = ClientBuilder::new(format!("{base_url}/a2a"))
+        .with_header(PROTOCOL_VERSION_HEADER, "2.0")
+        .build_from_endpoint();
+    let err = pinned.send_text("hi").await.unwrap_err();
+    assert_eq!(err.code(), error::INVALID_REQUEST, "{err}");
+}
+
+#[tokio::test]
+async fn test_client_rejects_unsupported_card_version() {
+    let base_url = start(false, "1.0").await;
+
+    let err = ClientBuilder::new(&base_url).build().await.unwrap_err();
+    assert!(
+        matches!(err, A2AError::InvalidAgentResponse { .. }),
+        "{err}"
+    );
+    let message = err.to_string();
+    assert!(message.contains("Test Echo Agent"), "{message}");
+    assert!(message.contains("'1.0'"), "{message}");
+    assert!(message.contains(">=0.3.0, <0.4.0"), "{message}");
+
+    let mut card = common::test_agent_card(&format!("{base_url}/a2a"));
+    card.protocol_version = Some("1.0".to_string());
+    assert!(A2AClient::from_card(card).is_err());
+}
+
+#[tokio::test]
+async fn test_version_check_can_be_disabled() {
+    let base_url = start(false, "1.0").await;
+
+    let client = ClientBuilder::new(&base_url)
+        .with_protocol_version_check(false)
+        .build()
+        .await
+        .unwrap();
+    client.send_text("hi").await.unwrap();
+}
@@ -0,0 +1,179 @@
+//! `X-A2A-Request-Id`: request IDs generated or taken from the caller,
+//! exposed to executors, recorded on tasks and passed on along a
+//! delegation chain, over HTTP and the local transport.
+
+mod common;
+
+use std::sync::Arc;
+
+use a2a_rs::client::{A2AClient, CallOptions, LocalTransport};
+use a2a_rs::error::A2AResult;
+use a2a_rs::server::{
+    AgentExecutor, DefaultRequestHandler, EventQueue, InMemoryTaskStore, RequestContext, TaskStore,
+    TaskUpdater,
+};
+use a2a_rs::types::*;
+use a2a_rs::utils::{is_valid_request_id, task_request_id, with_request_id};
+use async_trait::async_trait;
+use common::{start_test_server, start_test_server_with_store};
+use serde_json::json;
+
+/// Agent completing with the ID of the request it serves.
+struct RequestIdAgent;
+
+#[async_trait]
+impl AgentExecutor for RequestIdAgent {
+    async fn execute(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        let request_id = context.request_id().unwrap_or("none").to_string();
+        TaskUpdater::new(event_queue, context.task_id, context.context_id)
+            .complete_with_text(&request_id)
+            .await
+    }
+
+    async fn cancel(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        TaskUpdater::new(event_queue, context.task_id, context.context_id)
+            .cancel(None)
+            .await
+    }
+}
+
+/// Agent asking another agent and completing with its answer.
+struct DelegatingAgent {
+    downstream: A2AClient,
+}
+
+#[async_trait]
+impl AgentExecutor for DelegatingAgent {
+    async fn execute(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        let answer = self.downstream.send_text("which request?").await?;
+        let SendMessageResponse::Task(task) = answer else {
+            panic!("expected a task, got {answer:?}");
+        };
+        TaskUpdater::new(event_queue, context.task_id, context.context_id)
+            .complete_with_text(&status_text(&task))
+            .await
+    }
+
+    async fn cancel(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        TaskUpdater::new(event_queue, context.task_id, context.context_id)
+            .cancel(None)
+            .await
+    }
+}
+
+fn status_text(task: &Task) -> String {
+    let message = task.status.message.as_ref().expect("status message");
+    match &message.parts[0] {
+        Part::Text { text, .. } => text.clone(),
+        part => panic!("expected text, got {part:?}"),
+    }
+}
+
+fn expect_task(response: SendMessageResponse) -> Task {
+    match response {
+        SendMessageResponse::Task(task) => task,
+        other => panic!("expected a task, got {other:?}"),
+    }
+}
+
+fn params(text: &str) -> SendMessageParams {
+    SendMessageParams {
+        message: Message::user("m1", text),
+        configuration: None,
+        metadata: None,
+        tenant: None,
+    }
+}
+
+#[tokio::test]
+async fn test_callers_request_id_reaches_executor_and_task() {
+    let store = Arc::new(InMemoryTaskStore::new());
+    let (base_url, _server) =
+        start_test_server_with_store(Arc::new(RequestIdAgent), store.clone()).await;
+    let client = A2AClient::from_endpoint(&format!("{base_url}/a2a"));
+
+    let options = CallOptions::new().with_request_id("req-1");
+    let task = expect_task(
+        client
+            .send_message_with_options(params("hi"), &options)
+            .await
+            .unwrap(),
+    );
+    assert_eq!(status_text(&task), "req-1");
+    let stored = store.get(&task.id).await.unwrap().unwrap();
+    assert_eq!(task_request_id(&stored), Some("req-1"));
+
+    // Without one the client makes one up.
+    let task = expect_task(client.send_text("hi").await.unwrap());
+    let request_id = status_text(&task);
+    assert!(is_valid_request_id(&request_id));
+    assert_ne!(request_id, "req-1");
+}
+
+#[tokio::test]
+async fn test_server_echoes_or_replaces_the_request_id() {
+    let (base_url, _server) = start_test_server(Arc::new(RequestIdAgent)).await;
+    let request = json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "message/send",
+        "params": {"message": Message::user("m1", "hi")},
+    });
+    let post = |request_id: Option<&'static str>| {
+        let mut builder = reqwest::Client::new()
+            .post(format!("{base_url}/a2a"))
+            .json(&request);
+        if let Some(request_id) = request_id {
+            builder = builder.header("X-A2A-Request-Id", request_id);
+        }
+        builder.send()
+    };
+
+    let response = post(Some("abc-123")).await.unwrap();
+    assert_eq!(response.headers()["x-a2a-request-id"], "abc-123");
+    let body: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(body["result"]["metadata"]["requestId"], "abc-123");
+
+    for sent in [None, Some("two words")] {
+        let response = post(sent).await.unwrap();
+        let echoed = response.headers()["x-a2a-request-id"].to_str().unwrap();
+        assert!(is_valid_request_id(echoed), "{echoed}");
+        assert_ne!(Some(echoed), sent);
+    }
+}
+
+#[tokio::test]
+async fn test_delegated_calls_carry_the_same_request_id() {
+    let (downstream_url, _downstream) = start_test_server(Arc::new(RequestIdAgent)).await;
+    let agent = DelegatingAgent {
+        downstream: A2AClient::from_endpoint(&format!("{downstream_url}/a2a")),
+    };
+    let (base_url, _server) = start_test_server(Arc::new(agent)).await;
+    let client = A2AClient::from_endpoint(&format!("{base_url}/a2a"));
+
+    let options = CallOptions::new().with_request_id("chain-7");
+    let task = expect_task(
+        client
+            .send_message_with_options(params("hi"), &options)
+            .await
+            .unwrap(),
+    );
+    assert_eq!(status_text(&task), "chain-7");
+}
+
+#[tokio::test]
+async fn test_local_transport_sends_the_current_request_id() {
+    let handler = Arc::new(DefaultRequestHandler::new(
+        Arc::new(RequestIdAgent),
+        Arc::new(InMemoryTaskStore::new()),
+    ));
+    let client = A2AClient::with_transport(Box::new(LocalTransport::new(handler)));
+
+    let task = expect_task(
+        with_request_id("outer", client.send_text("hi"))
+            .await
+            .unwrap(),
+    );
+    assert_eq!(status_text(&task), "outer");
+    assert_eq!(task_request_id(&task), Some("outer"));
+}
@@ -0,0 +1,66 @@
+//! JSON Schema export of the protocol types (`schemars` feature).
+#![cfg(feature = "schemars")]
+
+use a2a_rs::schema::{export_all, write_all};
+use serde_json::{json, Value};
+
+fn schema(name: &str) -> Value {
+    let schemas = export_all();
+    let schema = schemas
+        .get(name)
+        .unwrap_or_else(|| panic!("no schema for {name}"));
+    serde_json::to_value(schema).unwrap()
+}
+
+#[test]
+fn test_export_all_covers_protocol_types() {
+    let schemas = export_all();
+    for name in [
+        "Task",
+        "Message",
+        "Part",
+        "AgentCard",
+        "SecurityScheme",
+        "JsonRpcRequest",
+        "SendMessageParams",
+        "StreamResponse",
+        "TaskPushNotificationConfig",
+    ] {
+        assert!(schemas.contains_key(name), "missing {name}");
+    }
+    assert!(schemas.len() >= 60, "{} schemas", schemas.len());
+}
+
+#[test]
+fn test_schemas_follow_wire_names() {
+    let task = schema("Task");
+    let required = task["required"].as_array().unwrap();
+    assert!(required.contains(&json!("contextId")), "{required:?}");
+    assert!(!required.contains(&json!("kind")), "kind has a default");
+
+    // `rawState` is not a wire field.
+    let status = schema("TaskStatus");
+    let properties = status["properties"].as_object().unwrap();
+    assert!(properties.contains_key("state"));
+    assert!(!properties.contains_key("rawState"));
+    assert!(!properties.contains_key("raw_state"));
+
+    let state = schema("TaskState").to_string();
+    assert!(state.contains("\"input-required\""), "{state}");
+
+    // Flat unions of their inner types.
+    let stream = schema("StreamResponse");
+    assert_eq!(stream["anyOf"].as_array().unwrap().len(), 4, "{stream}");
+}
+
+#[test]
+fn test_write_all() {
+    let dir = std::env::temp_dir().join(format!("a2a-schemas-{}", uuid::Uuid::new_v4()));
+    let paths = write_all(&dir).unwrap();
+    assert_eq!(paths.len(), export_all().len());
+
+    let card: Value =
+        serde_json::from_slice(&std::fs::read(dir.join("AgentCard.json")).unwrap()).unwrap();
+    assert_eq!(card["title"], "AgentCard");
+    std::fs::remove_dir_all(&dir).unwrap();
+}
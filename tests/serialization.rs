@@ -39,6 +39,60 @@ fn task_state_all_variants_serialize() {
     }
 }
 
+#[test]
+fn task_state_accepts_proto_names() {
+    let state: TaskState = serde_json::from_str(r#""TASK_STATE_INPUT_REQUIRED""#).unwrap();
+    assert_eq!(state, TaskState::InputRequired);
+    let state: TaskState = serde_json::from_str(r#""TASK_STATE_CANCELLED""#).unwrap();
+    assert_eq!(state, TaskState::Canceled);
+}
+
+#[test]
+fn task_status_preserves_unrecognized_state() {
+    let status: TaskStatus =
+        serde_json::from_value(json!({"state": "paused", "timestamp": "2025-01-01T00:00:00Z"}))
+            .unwrap();
+    assert_eq!(status.state, TaskState::Unknown);
+    assert_eq!(status.raw_state.as_deref(), Some("paused"));
+    assert_eq!(status.unrecognized_state(), Some("paused"));
+    assert_eq!(status.state_name(), "paused");
+
+    // Round trip writes the original string back.
+    let json = serde_json::to_value(&status).unwrap();
+    assert_eq!(
+        json,
+        json!({"state": "paused", "timestamp": "2025-01-01T00:00:00Z"})
+    );
+    assert!(json.get("rawState").is_none());
+}
+
+#[test]
+fn task_status_known_state_has_no_raw_state() {
+    let status: TaskStatus = serde_json::from_value(json!({"state": "unknown"})).unwrap();
+    assert_eq!(status.state, TaskState::Unknown);
+    assert!(status.raw_state.is_none());
+    assert!(status.unrecognized_state().is_none());
+
+    let status: TaskStatus = serde_json::from_value(json!({"state": "working"})).unwrap();
+    assert_eq!(status.state, TaskState::Working);
+    assert!(status.raw_state.is_none());
+}
+
+#[test]
+fn stream_event_with_unrecognized_state_parses() {
+    let event: StreamResponse = serde_json::from_value(json!({
+        "kind": "status-update",
+        "taskId": "t1",
+        "contextId": "c1",
+        "status": {"state": "suspended"},
+        "final": false
+    }))
+    .unwrap();
+    let status = event.task_status().unwrap();
+    assert_eq!(status.state, TaskState::Unknown);
+    assert_eq!(status.unrecognized_state(), Some("suspended"));
+}
+
 // ============================================================================
 // Role
 // ============================================================================
@@ -418,6 +472,7 @@ fn task_status_serialization() {
         state: TaskState::Working,
         message: Some(Message::agent("m1", "Processing")),
         timestamp: Some("2025-01-01T00:00:00Z".to_string()),
+        raw_state: None,
     };
     let json = serde_json::to_value(&status).unwrap();
 
@@ -516,7 +571,7 @@ fn stream_response_status_update_roundtrip() {
     match decoded {
         StreamResponse::StatusUpdate(e) => {
             assert_eq!(e.task_id, "t1");
-            assert_eq!(e.r#final, true);
+            assert!(e.r#final);
         }
         _ => panic!("Expected StatusUpdate variant"),
     }
@@ -1071,6 +1126,7 @@ fn send_message_params_serialization() {
             push_notification_config: None,
             history_length: Some(10),
             blocking: Some(true),
+            timeout_ms: None,
         }),
         metadata: None,
         tenant: None,
@@ -1093,6 +1149,7 @@ fn get_task_params_serialization() {
         history_length: Some(5),
         metadata: None,
         tenant: None,
+        consistency: None,
     };
     let json = serde_json::to_value(&params).unwrap();
 
@@ -1268,6 +1325,7 @@ fn spot_check_push_notification_config_param_field_names() {
         id: "task-123".to_string(),
         push_notification_config_id: "config-456".to_string(),
         metadata: None,
+        tenant: None,
     };
     let json = serde_json::to_value(&params).unwrap();
 
@@ -1280,6 +1338,7 @@ fn spot_check_push_notification_config_param_field_names() {
         id: "task-789".to_string(),
         push_notification_config_id: Some("config-abc".to_string()),
         metadata: None,
+        tenant: None,
     };
     let json = serde_json::to_value(&params).unwrap();
     assert_eq!(json["id"], "task-789");
@@ -1288,7 +1347,10 @@ fn spot_check_push_notification_config_param_field_names() {
     // ListTaskPushNotificationConfigParams
     let params = ListTaskPushNotificationConfigParams {
         id: "task-def".to_string(),
+        page_size: None,
+        page_token: None,
         metadata: None,
+        tenant: None,
     };
     let json = serde_json::to_value(&params).unwrap();
     assert_eq!(json["id"], "task-def");
@@ -1323,7 +1385,7 @@ fn spot_check_agent_card_defaults_on_deserialize() {
 fn spot_check_role_unspecified() {
     // Role should support "unspecified" variant (Python SDK format)
     let role = Role::Unspecified;
-    let json = serde_json::to_value(&role).unwrap();
+    let json = serde_json::to_value(role).unwrap();
     assert_eq!(json, "unspecified");
 
     let decoded: Role = serde_json::from_str(r#""unspecified""#).unwrap();
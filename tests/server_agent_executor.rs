@@ -5,7 +5,7 @@ use std::collections::HashSet;
 use std::sync::Arc;
 
 use a2a_rs::server::{
-    InMemoryTaskStore, RequestContext, RequestContextBuilder, ServerCallContext,
+    CancellationToken, InMemoryTaskStore, RequestContext, RequestContextBuilder, ServerCallContext,
     SimpleRequestContextBuilder, TaskStore,
 };
 use a2a_rs::types::*;
@@ -23,7 +23,13 @@ fn make_context(text: &str) -> RequestContext {
         configuration: None,
         related_tasks: Vec::new(),
         metadata: None,
+        tenant: None,
         call_context: None,
+        task_store: None,
+        workspace: None,
+        cancellation_token: CancellationToken::new(),
+        incremental_message: None,
+        content_validator: None,
     }
 }
 
@@ -53,7 +59,13 @@ fn test_request_context_get_user_input_multiple_parts() {
         configuration: None,
         related_tasks: Vec::new(),
         metadata: None,
+        tenant: None,
         call_context: None,
+        task_store: None,
+        workspace: None,
+        cancellation_token: CancellationToken::new(),
+        incremental_message: None,
+        content_validator: None,
     };
     assert_eq!(ctx.get_user_input(" "), "Hello World");
     assert_eq!(ctx.get_user_input(", "), "Hello, World");
@@ -69,7 +81,13 @@ fn test_request_context_get_user_input_no_message() {
         configuration: None,
         related_tasks: Vec::new(),
         metadata: None,
+        tenant: None,
         call_context: None,
+        task_store: None,
+        workspace: None,
+        cancellation_token: CancellationToken::new(),
+        incremental_message: None,
+        content_validator: None,
     };
     assert_eq!(ctx.get_user_input(" "), "");
 }
@@ -101,7 +119,13 @@ fn test_request_context_get_user_input_non_text_parts_ignored() {
         configuration: None,
         related_tasks: Vec::new(),
         metadata: None,
+        tenant: None,
         call_context: None,
+        task_store: None,
+        workspace: None,
+        cancellation_token: CancellationToken::new(),
+        incremental_message: None,
+        content_validator: None,
     };
     assert_eq!(ctx.get_user_input(" "), "Hello World");
 }
@@ -119,6 +143,7 @@ fn test_request_context_attach_related_task() {
             state: TaskState::Completed,
             message: None,
             timestamp: None,
+            raw_state: None,
         },
         artifacts: None,
         history: None,
@@ -161,6 +186,12 @@ fn test_request_context_requested_extensions() {
             s
         },
         activated_extensions: HashSet::new(),
+        http: None,
+        client: None,
+        user: None,
+        caller_identities: Vec::new(),
+        request_id: None,
+        deadline: None,
     });
 
     let exts = ctx.requested_extensions();
@@ -258,6 +289,7 @@ async fn test_simple_builder_resolves_ids_from_task() {
             state: TaskState::Working,
             message: None,
             timestamp: None,
+            raw_state: None,
         },
         artifacts: None,
         history: None,
@@ -314,6 +346,7 @@ async fn test_simple_builder_populates_referred_tasks() {
             state: TaskState::Completed,
             message: None,
             timestamp: None,
+            raw_state: None,
         },
         artifacts: None,
         history: None,
@@ -354,6 +387,7 @@ async fn test_simple_builder_no_populate_when_disabled() {
             state: TaskState::Completed,
             message: None,
             timestamp: None,
+            raw_state: None,
         },
         artifacts: None,
         history: None,
@@ -403,6 +437,12 @@ async fn test_simple_builder_with_call_context() {
             s
         },
         activated_extensions: HashSet::new(),
+        http: None,
+        client: None,
+        user: None,
+        caller_identities: Vec::new(),
+        request_id: None,
+        deadline: None,
     };
 
     let ctx = builder
@@ -426,6 +466,7 @@ async fn test_simple_builder_passes_configuration() {
             push_notification_config: None,
             history_length: Some(5),
             blocking: Some(true),
+            timeout_ms: None,
         }),
         metadata: Some(serde_json::json!({"key": "value"})),
         tenant: None,
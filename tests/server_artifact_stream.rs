@@ -0,0 +1,202 @@
+//! Tests for streaming artifacts of referenced tasks into executors
+//! (`TaskStore::open_artifact`, `RequestContext::load_artifact_stream`).
+
+use std::sync::Arc;
+
+use a2a_rs::error::{A2AError, A2AResult};
+use a2a_rs::server::{
+    AgentExecutor, CancellationToken, DefaultRequestHandler, EventQueue, InMemoryTaskStore,
+    PartsReader, RequestContext, RequestHandler, SendMessageParams, TaskStore, TaskUpdater,
+};
+use a2a_rs::types::*;
+use async_trait::async_trait;
+use serde_json::json;
+use tokio::io::AsyncReadExt;
+
+fn upstream_task() -> Task {
+    Task {
+        id: "upstream".to_string(),
+        context_id: "c1".to_string(),
+        kind: "task".to_string(),
+        status: TaskStatus::new(TaskState::Completed),
+        artifacts: Some(vec![Artifact {
+            artifact_id: "report".to_string(),
+            name: None,
+            description: None,
+            parts: vec![
+                Part::text("header;"),
+                // "hello" in base64.
+                Part::file_from_bytes("aGVsbG8=", None, None),
+                Part::data(json!({"n": 1})),
+            ],
+            metadata: None,
+            extensions: None,
+        }]),
+        history: None,
+        metadata: None,
+    }
+}
+
+async fn store_with_upstream() -> Arc<dyn TaskStore> {
+    let store: Arc<dyn TaskStore> = Arc::new(InMemoryTaskStore::new());
+    store.save(upstream_task()).await.unwrap();
+    store
+}
+
+fn context(
+    reference_task_ids: Option<Vec<String>>,
+    store: Option<Arc<dyn TaskStore>>,
+) -> RequestContext {
+    let mut message = Message::user("m1", "go");
+    message.reference_task_ids = reference_task_ids;
+    RequestContext {
+        task_id: "t1".to_string(),
+        context_id: "c1".to_string(),
+        message: Some(message),
+        task: None,
+        configuration: None,
+        related_tasks: Vec::new(),
+        metadata: None,
+        tenant: None,
+        call_context: None,
+        task_store: store,
+        workspace: None,
+        cancellation_token: CancellationToken::new(),
+        incremental_message: None,
+        content_validator: None,
+    }
+}
+
+#[tokio::test]
+async fn test_parts_reader_encodes_parts_in_order() {
+    let mut reader = PartsReader::new(upstream_task().artifacts.unwrap().remove(0).parts);
+    // Read through a tiny buffer to cross part boundaries.
+    let mut out = Vec::new();
+    let mut buf = [0u8; 3];
+    loop {
+        let n = reader.read(&mut buf).await.unwrap();
+        if n == 0 {
+            break;
+        }
+        out.extend_from_slice(&buf[..n]);
+    }
+    assert_eq!(out, br#"header;hello{"n":1}"#);
+}
+
+#[tokio::test]
+async fn test_parts_reader_rejects_uri_and_bad_base64() {
+    let mut reader = PartsReader::new(vec![Part::file_from_uri("https://x/y", None, None)]);
+    let err = reader.read_to_end(&mut Vec::new()).await.unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::Unsupported);
+
+    let mut reader = PartsReader::new(vec![Part::file_from_bytes("not base64!", None, None)]);
+    let err = reader.read_to_end(&mut Vec::new()).await.unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+}
+
+#[tokio::test]
+async fn test_open_artifact_errors() {
+    let store = store_with_upstream().await;
+    assert!(matches!(
+        store.open_artifact("missing", "report").await,
+        Err(A2AError::TaskNotFound { .. })
+    ));
+    assert!(matches!(
+        store.open_artifact("upstream", "missing").await,
+        Err(A2AError::InvalidParams { .. })
+    ));
+}
+
+#[tokio::test]
+async fn test_load_artifact_stream_from_referenced_task() {
+    let store = store_with_upstream().await;
+    let ctx = context(Some(vec!["upstream".to_string()]), Some(store));
+
+    let mut stream = ctx
+        .load_artifact_stream("upstream", "report")
+        .await
+        .unwrap();
+    let mut out = String::new();
+    stream.read_to_string(&mut out).await.unwrap();
+    assert_eq!(out, r#"header;hello{"n":1}"#);
+}
+
+#[tokio::test]
+async fn test_load_artifact_stream_requires_reference() {
+    let store = store_with_upstream().await;
+    let ctx = context(None, Some(store));
+    assert!(matches!(
+        ctx.load_artifact_stream("upstream", "report").await,
+        Err(A2AError::InvalidParams { .. })
+    ));
+}
+
+#[tokio::test]
+async fn test_load_artifact_stream_without_store() {
+    let ctx = context(Some(vec!["upstream".to_string()]), None);
+    assert!(matches!(
+        ctx.load_artifact_stream("upstream", "report").await,
+        Err(A2AError::InternalError { .. })
+    ));
+}
+
+/// Agent that reads the referenced upstream artifact and replies with it.
+struct PipelineAgent;
+
+#[async_trait]
+impl AgentExecutor for PipelineAgent {
+    async fn execute(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        let upstream = context
+            .message
+            .as_ref()
+            .and_then(|m| m.reference_task_ids.as_ref())
+            .and_then(|ids| ids.first().cloned())
+            .unwrap();
+        let mut stream = context.load_artifact_stream(&upstream, "report").await?;
+        let mut content = String::new();
+        stream
+            .read_to_string(&mut content)
+            .await
+            .map_err(|e| A2AError::InternalError {
+                message: e.to_string(),
+                data: None,
+            })?;
+        TaskUpdater::new(event_queue, context.task_id, context.context_id)
+            .complete_with_text(&content)
+            .await
+    }
+
+    async fn cancel(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        TaskUpdater::new(event_queue, context.task_id, context.context_id)
+            .cancel(None)
+            .await
+    }
+}
+
+#[tokio::test]
+async fn test_default_handler_gives_executor_store_access() {
+    let store = store_with_upstream().await;
+    let handler = DefaultRequestHandler::new(Arc::new(PipelineAgent), store);
+
+    let mut message = Message::user("m2", "summarise");
+    message.reference_task_ids = Some(vec!["upstream".to_string()]);
+    let response = handler
+        .on_message_send(SendMessageParams {
+            message,
+            configuration: None,
+            metadata: None,
+            tenant: None,
+        })
+        .await
+        .unwrap();
+
+    let SendMessageResponse::Task(task) = response else {
+        panic!("expected task");
+    };
+    assert_eq!(task.status.state, TaskState::Completed);
+    let reply = task.status.message.unwrap();
+    assert!(matches!(
+        &reply.parts[0],
+        Part::Text { text, .. } if text == r#"header;hello{"n":1}"#
+    ));
+}
@@ -0,0 +1,288 @@
+//! Server-side authentication: the router checks callers against the
+//! card's security schemes and exposes them as `ServerCallContext::user`.
+
+mod common;
+
+use std::sync::Arc;
+
+use a2a_rs::builders::AgentCardBuilder;
+use a2a_rs::error::A2AResult;
+use a2a_rs::server::{
+    AgentExecutor, AuthenticatedUser, Authenticator, EventQueue, InMemoryTaskStore, RequestContext,
+    RouterConfig, SecuritySchemeAuthenticator, StaticCredentials, TaskUpdater,
+};
+use a2a_rs::types::*;
+use async_trait::async_trait;
+use serde_json::{json, Value};
+
+/// Greets the authenticated user; asks anonymous callers to authenticate.
+struct WhoAmIAgent;
+
+#[async_trait]
+impl AgentExecutor for WhoAmIAgent {
+    async fn execute(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        let updater = TaskUpdater::new(
+            event_queue,
+            context.task_id.clone(),
+            context.context_id.clone(),
+        );
+        match context.user() {
+            Some(user) => {
+                let text = format!("hello {} via {}", user.name, user.scheme);
+                updater.complete_with_text(&text).await
+            }
+            None => {
+                let message = Message::agent("auth", "please sign in");
+                updater.requires_auth(Some(message), true).await
+            }
+        }
+    }
+
+    async fn cancel(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        let updater = TaskUpdater::new(event_queue, context.task_id, context.context_id);
+        updater.cancel(None).await
+    }
+}
+
+/// Card declaring a header API key, a query API key, a cookie API key and
+/// an HTTP bearer scheme.
+fn card(url: &str) -> AgentCard {
+    let api_key = |location, name: &str| SecurityScheme::ApiKey {
+        description: None,
+        location,
+        name: name.to_string(),
+    };
+    let mut card = AgentCardBuilder::new("Guarded", "Needs credentials", "1.0")
+        .with_jsonrpc_interface(url)
+        .build();
+    card.security_schemes = Some(
+        [
+            (
+                "header".to_string(),
+                api_key(ApiKeyLocation::Header, "X-Key"),
+            ),
+            ("query".to_string(), api_key(ApiKeyLocation::Query, "key")),
+            (
+                "cookie".to_string(),
+                api_key(ApiKeyLocation::Cookie, "session"),
+            ),
+            (
+                "bearer".to_string(),
+                SecurityScheme::Http {
+                    description: None,
+                    scheme: "bearer".to_string(),
+                    bearer_format: None,
+                },
+            ),
+        ]
+        .into(),
+    );
+    card
+}
+
+fn credentials() -> Arc<StaticCredentials> {
+    Arc::new(
+        StaticCredentials::new()
+            .with_credential("alice-key", AuthenticatedUser::new("alice"))
+            .with_credential(
+                "bob-token",
+                AuthenticatedUser::new("bob").with_scopes(["read"]),
+            ),
+    )
+}
+
+/// Serve [`WhoAmIAgent`] behind `card` (built for the endpoint URL) with
+/// `authenticator`; returns the endpoint URL.
+async fn start(
+    card: impl FnOnce(&str) -> AgentCard,
+    authenticator: Arc<dyn Authenticator>,
+) -> String {
+    let config = RouterConfig::default().with_authenticator(authenticator);
+    let (base_url, _server) = common::start_test_server_with_config(
+        Arc::new(WhoAmIAgent),
+        Arc::new(InMemoryTaskStore::new()),
+        card,
+        config,
+    )
+    .await;
+    format!("{base_url}/a2a")
+}
+
+/// Send "hi" with `request` customizing the HTTP request; returns the
+/// status and JSON body.
+async fn send(
+    url: &str,
+    request: impl FnOnce(reqwest::RequestBuilder) -> reqwest::RequestBuilder,
+) -> (u16, Value) {
+    let body = common::jsonrpc_request(
+        json!(1),
+        "message/send",
+        json!({"message": {"kind": "message", "messageId": "m1", "role": "user",
+                           "parts": [{"kind": "text", "text": "hi"}]}}),
+    );
+    let response = request(reqwest::Client::new().post(url).json(&body))
+        .send()
+        .await
+        .unwrap();
+    let status = response.status().as_u16();
+    (status, response.json().await.unwrap())
+}
+
+fn reply(body: &Value) -> &str {
+    body["result"]["status"]["message"]["parts"][0]["text"]
+        .as_str()
+        .unwrap_or_else(|| panic!("no reply in {body}"))
+}
+
+#[tokio::test]
+async fn test_credentials_from_every_location() {
+    let auth = SecuritySchemeAuthenticator::new(&card(""))
+        .with_validator("header", credentials())
+        .with_validator("query", credentials())
+        .with_validator("cookie", credentials())
+        .with_validator("bearer", credentials());
+    let url = start(card, Arc::new(auth)).await;
+
+    let (status, body) = send(&url, |r| r.header("X-Key", "alice-key")).await;
+    assert_eq!(status, 200);
+    assert_eq!(reply(&body), "hello alice via header");
+
+    let (_, body) = send(&format!("{url}?key=alice%2Dkey"), |r| r).await;
+    assert_eq!(reply(&body), "hello alice via query");
+
+    let (_, body) = send(&url, |r| {
+        r.header("Cookie", "theme=dark; session=alice-key")
+    })
+    .await;
+    assert_eq!(reply(&body), "hello alice via cookie");
+
+    let (_, body) = send(&url, |r| r.bearer_auth("bob-token")).await;
+    assert_eq!(reply(&body), "hello bob via bearer");
+}
+
+#[tokio::test]
+async fn test_rejects_missing_and_invalid_credentials() {
+    let auth = SecuritySchemeAuthenticator::new(&card(""))
+        .with_validator("header", credentials())
+        .with_validator("bearer", credentials());
+    let url = start(card, Arc::new(auth)).await;
+
+    let response = reqwest::Client::new()
+        .post(&url)
+        .json(&json!({}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 401);
+    assert_eq!(response.headers()["www-authenticate"], "Bearer");
+
+    let (status, body) = send(&url, |r| r.header("X-Key", "wrong")).await;
+    assert_eq!(status, 401);
+    assert!(
+        body["error"]["message"]
+            .as_str()
+            .unwrap()
+            .contains("security scheme 'header'"),
+        "{body}"
+    );
+
+    // The card stays public.
+    let card_url = url.replace("/a2a", "/.well-known/agent.json");
+    let response = reqwest::get(card_url).await.unwrap();
+    assert_eq!(response.status(), 200);
+}
+
+#[tokio::test]
+async fn test_requirement_scopes() {
+    let with_requirements = |url: &str| {
+        let mut card = card(url);
+        card.security_requirements = vec![
+            [("bearer".to_string(), vec!["write".to_string()])].into(),
+            [("header".to_string(), vec![])].into(),
+        ];
+        card
+    };
+    let auth = SecuritySchemeAuthenticator::new(&with_requirements(""))
+        .with_validator("header", credentials())
+        .with_validator("bearer", credentials());
+    let url = start(with_requirements, Arc::new(auth)).await;
+
+    let (status, body) = send(&url, |r| r.bearer_auth("bob-token")).await;
+    assert_eq!(status, 403, "{body}");
+
+    let (status, _) = send(&url, |r| r.header("X-Key", "alice-key")).await;
+    assert_eq!(status, 200);
+}
+
+#[tokio::test]
+async fn test_anonymous_caller_gets_auth_required() {
+    let open_card = |url: &str| {
+        AgentCardBuilder::new("Open", "Declares no security", "1.0")
+            .with_jsonrpc_interface(url)
+            .build()
+    };
+    // The authenticator still runs, but callers without credentials pass.
+    let auth = SecuritySchemeAuthenticator::new(&card("")).with_validator("header", credentials());
+    let url = start(open_card, Arc::new(auth)).await;
+
+    let (status, body) = send(&url, |r| r).await;
+    assert_eq!(status, 200);
+    assert_eq!(body["result"]["status"]["state"], "auth-required");
+    assert_eq!(reply(&body), "please sign in");
+}
+
+#[cfg(feature = "client")]
+#[tokio::test]
+async fn test_oauth2_token_introspection() {
+    use a2a_rs::server::OAuth2Introspection;
+    use axum::http::HeaderMap;
+
+    let introspect = axum::Router::new().route(
+        "/introspect",
+        axum::routing::post(|headers: HeaderMap, body: String| async move {
+            assert!(headers.contains_key("authorization"));
+            let body = if body.contains("token=good") {
+                json!({"active": true, "sub": "carol", "scope": "read write", "tenant": "acme"})
+            } else {
+                json!({"active": false})
+            };
+            axum::Json(body)
+        }),
+    );
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let introspect_url = format!("http://{}/introspect", listener.local_addr().unwrap());
+    tokio::spawn(async move {
+        axum::serve(listener, introspect).await.unwrap();
+    });
+
+    let oauth_card = |url: &str| {
+        let mut card = AgentCardBuilder::new("OAuth", "Takes OAuth tokens", "1.0")
+            .with_jsonrpc_interface(url)
+            .build();
+        card.security_schemes = Some(
+            [(
+                "oauth".to_string(),
+                SecurityScheme::OAuth2 {
+                    description: None,
+                    flows: OAuthFlows::default(),
+                    oauth2_metadata_url: None,
+                },
+            )]
+            .into(),
+        );
+        card.security_requirements =
+            vec![[("oauth".to_string(), vec!["write".to_string()])].into()];
+        card
+    };
+    let validator = OAuth2Introspection::new(introspect_url, "agent", "secret");
+    let auth = SecuritySchemeAuthenticator::new(&oauth_card(""))
+        .with_validator("oauth", Arc::new(validator));
+    let url = start(oauth_card, Arc::new(auth)).await;
+
+    let (status, body) = send(&url, |r| r.bearer_auth("good")).await;
+    assert_eq!(status, 200, "{body}");
+    assert_eq!(reply(&body), "hello carol via oauth");
+
+    let (status, _) = send(&url, |r| r.bearer_auth("bad")).await;
+    assert_eq!(status, 401);
+}
@@ -0,0 +1,121 @@
+//! `RequestContext::cancellation_token`: fired by `tasks/cancel` so running
+//! executors can stop cooperatively; updaters holding it refuse anything
+//! but `canceled` afterwards.
+
+mod common;
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use a2a_rs::client::A2AClient;
+use a2a_rs::error::A2AResult;
+use a2a_rs::server::{
+    AgentExecutor, CancellationToken, EventQueue, RequestContext, RequestContextBuilder,
+    SimpleRequestContextBuilder, TaskUpdater,
+};
+use a2a_rs::types::*;
+use async_trait::async_trait;
+
+/// Hands the work to a background job that runs until the task is
+/// canceled, then tries to complete it anyway.
+struct BackgroundAgent {
+    outcome: Arc<Mutex<Option<String>>>,
+}
+
+#[async_trait]
+impl AgentExecutor for BackgroundAgent {
+    async fn execute(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        let updater = context.task_updater(event_queue);
+        updater.start_work(None).await?;
+        let token = context.cancellation_token.clone();
+        let outcome = Arc::clone(&self.outcome);
+        tokio::spawn(async move {
+            token.cancelled().await;
+            let result = updater.complete_with_text("too late").await;
+            *outcome.lock().unwrap() = Some(match result {
+                Ok(()) => "completed".to_string(),
+                Err(e) => e.to_string(),
+            });
+        });
+        std::future::pending().await
+    }
+
+    async fn cancel(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        assert!(context.is_cancelled());
+        context.task_updater(event_queue).cancel(None).await
+    }
+}
+
+#[tokio::test]
+async fn test_cancel_fires_the_token_of_the_running_execution() {
+    let outcome = Arc::new(Mutex::new(None));
+    let agent = BackgroundAgent {
+        outcome: Arc::clone(&outcome),
+    };
+    let (base_url, _handle) = common::start_test_server(Arc::new(agent)).await;
+    let client = A2AClient::from_endpoint(&format!("{base_url}/a2a"));
+
+    let mut stream = client.send_text_stream("work").await.unwrap();
+    let task_id = loop {
+        if let StreamResponse::StatusUpdate(update) = stream.next().await.unwrap().unwrap() {
+            assert_eq!(update.status.state, TaskState::Working);
+            break update.task_id;
+        }
+    };
+
+    let task = client.cancel_task_by_id(&task_id).await.unwrap();
+    assert_eq!(task.status.state, TaskState::Canceled);
+
+    for _ in 0..100 {
+        if outcome.lock().unwrap().is_some() {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    let outcome = outcome.lock().unwrap().clone().unwrap();
+    assert!(outcome.contains("was canceled"), "{outcome}");
+
+    let task = client.get_task_by_id(&task_id, None).await.unwrap();
+    assert_eq!(task.status.state, TaskState::Canceled);
+}
+
+#[tokio::test]
+async fn test_updater_refuses_all_but_cancel_once_cancelled() {
+    let queue = EventQueue::with_default_capacity();
+    let mut events = queue.subscribe();
+    let token = CancellationToken::new();
+    let updater =
+        TaskUpdater::new(queue, "t1".into(), "c1".into()).with_cancellation(token.clone());
+
+    updater.start_work(None).await.unwrap();
+    assert!(!updater.is_cancelled());
+    token.cancel();
+    assert!(updater.is_cancelled());
+
+    assert!(updater.start_work(None).await.is_err());
+    assert!(updater.complete(None).await.is_err());
+    assert!(updater
+        .add_artifact(vec![Part::text("x")], None, None, None, None, None, None)
+        .await
+        .is_err());
+    updater.cancel(None).await.unwrap();
+
+    let states: Vec<_> = std::iter::from_fn(|| events.try_recv().ok())
+        .filter_map(|event| event.task_status().map(|status| status.state))
+        .collect();
+    assert_eq!(states, [TaskState::Working, TaskState::Canceled]);
+}
+
+#[tokio::test]
+async fn test_context_updater_targets_the_task() {
+    let context = SimpleRequestContextBuilder::default()
+        .build(None, Some("t1"), Some("c1"), None, None)
+        .await
+        .unwrap();
+    assert!(!context.is_cancelled());
+    let updater = context.task_updater(EventQueue::with_default_capacity());
+    assert_eq!((updater.task_id(), updater.context_id()), ("t1", "c1"));
+
+    context.cancellation_token.cancel();
+    assert!(updater.is_cancelled());
+}
@@ -0,0 +1,184 @@
+//! `chat_router`: OpenAI-style `POST /chat` served from the same handler as
+//! the A2A routes.
+
+mod common;
+
+use std::sync::Arc;
+
+use a2a_rs::client::A2AClient;
+use a2a_rs::server::{
+    a2a_router, chat_router, AgentExecutor, DefaultRequestHandler, InMemoryTaskStore,
+    RequestHandler,
+};
+use a2a_rs::utils::get_message_text;
+use common::{EchoAgent, FailingAgent, SlowEchoAgent};
+use serde_json::{json, Value};
+
+/// Serve both routers for `executor`; returns the base URL.
+async fn start_dual_server(executor: Arc<dyn AgentExecutor>) -> String {
+    let handler: Arc<dyn RequestHandler> = Arc::new(DefaultRequestHandler::new(
+        executor,
+        Arc::new(InMemoryTaskStore::new()),
+    ));
+    let (base_url, _server) = common::serve_router(|base_url| {
+        a2a_router(
+            handler.clone(),
+            common::test_agent_card(&format!("{base_url}/a2a")),
+        )
+        .merge(chat_router(handler))
+    })
+    .await;
+    base_url
+}
+
+async fn chat(base_url: &str, body: Value) -> (u16, Value) {
+    let response = reqwest::Client::new()
+        .post(format!("{base_url}/chat"))
+        .json(&body)
+        .send()
+        .await
+        .unwrap();
+    let status = response.status().as_u16();
+    (status, response.json().await.unwrap())
+}
+
+/// The `data:` payloads of a streamed chat response.
+async fn chat_stream(base_url: &str, body: Value) -> Vec<String> {
+    let response = reqwest::Client::new()
+        .post(format!("{base_url}/chat"))
+        .json(&body)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 200);
+    let text = response.text().await.unwrap();
+    text.lines()
+        .filter_map(|line| line.strip_prefix("data:"))
+        .map(|data| data.trim().to_string())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_chat_completion() {
+    let base_url = start_dual_server(Arc::new(EchoAgent)).await;
+    let (status, body) = chat(
+        &base_url,
+        json!({
+            "model": "echo",
+            "messages": [
+                {"role": "system", "content": "be brief"},
+                {"role": "user", "content": "first"},
+                {"role": "assistant", "content": "Echo: first"},
+                {"role": "user", "content": [{"type": "text", "text": "hello"}]}
+            ]
+        }),
+    )
+    .await;
+
+    assert_eq!(status, 200, "{body}");
+    assert_eq!(body["object"], "chat.completion");
+    assert_eq!(body["model"], "echo");
+    assert_eq!(body["choices"][0]["message"]["role"], "assistant");
+    assert_eq!(body["choices"][0]["message"]["content"], "Echo: hello");
+    assert_eq!(body["choices"][0]["finish_reason"], "stop");
+    assert!(body["context_id"].is_string());
+}
+
+#[tokio::test]
+async fn test_chat_task_is_visible_over_a2a() {
+    let base_url = start_dual_server(Arc::new(EchoAgent)).await;
+    let (_, body) = chat(
+        &base_url,
+        json!({"messages": [{"role": "user", "content": "shared"}]}),
+    )
+    .await;
+
+    let task = A2AClient::from_endpoint(&format!("{base_url}/a2a"))
+        .get_task_by_id(body["id"].as_str().unwrap(), None)
+        .await
+        .unwrap();
+    assert_eq!(
+        get_message_text(task.status.message.as_ref().unwrap(), ""),
+        "Echo: shared"
+    );
+    assert_eq!(body["context_id"], task.context_id.as_str());
+}
+
+#[tokio::test]
+async fn test_chat_prefers_artifact_text() {
+    let base_url = start_dual_server(Arc::new(SlowEchoAgent)).await;
+    let (_, body) = chat(
+        &base_url,
+        json!({"messages": [{"role": "user", "content": "x"}]}),
+    )
+    .await;
+    assert_eq!(body["choices"][0]["message"]["content"], "Processed: x");
+}
+
+#[tokio::test]
+async fn test_chat_stream_emits_deltas_and_done() {
+    let base_url = start_dual_server(Arc::new(SlowEchoAgent)).await;
+    let events = chat_stream(
+        &base_url,
+        json!({"stream": true, "messages": [{"role": "user", "content": "x"}]}),
+    )
+    .await;
+
+    assert_eq!(events.last().map(String::as_str), Some("[DONE]"));
+    let chunks: Vec<Value> = events[..events.len() - 1]
+        .iter()
+        .map(|data| serde_json::from_str(data).unwrap())
+        .collect();
+    assert!(chunks
+        .iter()
+        .all(|c| c["object"] == "chat.completion.chunk" && c["id"] == chunks[0]["id"]));
+    assert_eq!(chunks[0]["choices"][0]["delta"]["role"], "assistant");
+    let content: String = chunks
+        .iter()
+        .filter_map(|c| c["choices"][0]["delta"]["content"].as_str())
+        .collect();
+    assert_eq!(content, "Processed: x");
+    let last = chunks.last().unwrap();
+    assert_eq!(last["choices"][0]["finish_reason"], "stop");
+}
+
+#[tokio::test]
+async fn test_chat_failure_is_an_error() {
+    let base_url = start_dual_server(Arc::new(FailingAgent)).await;
+    let (status, body) = chat(
+        &base_url,
+        json!({"messages": [{"role": "user", "content": "x"}]}),
+    )
+    .await;
+    assert_eq!(status, 502, "{body}");
+    assert_eq!(body["error"]["type"], "agent_error");
+    assert!(body["error"]["message"]
+        .as_str()
+        .unwrap()
+        .contains("intentionally failed"));
+
+    let events = chat_stream(
+        &base_url,
+        json!({"stream": true, "messages": [{"role": "user", "content": "x"}]}),
+    )
+    .await;
+    let error: Value = serde_json::from_str(&events[events.len() - 2]).unwrap();
+    assert_eq!(error["error"]["type"], "agent_error");
+    assert_eq!(events.last().map(String::as_str), Some("[DONE]"));
+}
+
+#[tokio::test]
+async fn test_chat_rejects_bad_requests() {
+    let base_url = start_dual_server(Arc::new(EchoAgent)).await;
+
+    let (status, body) = chat(
+        &base_url,
+        json!({"messages": [{"role": "system", "content": "no user"}]}),
+    )
+    .await;
+    assert_eq!(status, 400);
+    assert_eq!(body["error"]["type"], "invalid_request_error");
+
+    let (status, _) = chat(&base_url, json!({"prompt": "legacy"})).await;
+    assert_eq!(status, 400);
+}
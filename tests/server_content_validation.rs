@@ -0,0 +1,257 @@
+//! Content validation of incoming messages and outgoing artifacts.
+
+use std::sync::Arc;
+
+use a2a_rs::error::{self, A2AError, A2AResult};
+use a2a_rs::server::{
+    AgentExecutor, ContentLimits, ContentValidator, ContentViolation, DefaultRequestHandler,
+    EventQueue, InMemoryTaskStore, RequestContext, RequestHandler, SendMessageParams,
+    TaskListParams, TaskStore,
+};
+use a2a_rs::types::*;
+use async_trait::async_trait;
+use serde_json::json;
+
+/// Publishes its input text as an artifact, then completes.
+struct ArtifactAgent;
+
+#[async_trait]
+impl AgentExecutor for ArtifactAgent {
+    async fn execute(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        let input = context.get_user_input(" ");
+        let updater = context.task_updater(event_queue);
+        updater
+            .add_artifact(
+                vec![Part::text(input)],
+                Some("echo".to_string()),
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+            .await?;
+        updater.complete(None).await
+    }
+
+    async fn cancel(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        context.task_updater(event_queue).cancel(None).await
+    }
+}
+
+/// Refuses any content mentioning `word`, in the given direction.
+struct Moderator {
+    word: &'static str,
+    outgoing: bool,
+}
+
+#[async_trait]
+impl ContentValidator for Moderator {
+    async fn validate_message(&self, message: &Message) -> Result<(), ContentViolation> {
+        if !self.outgoing && text_of(&message.parts).contains(self.word) {
+            return Err(ContentViolation::refused(format!(
+                "mentions '{}'",
+                self.word
+            )));
+        }
+        Ok(())
+    }
+
+    async fn validate_artifact(&self, artifact: &Artifact) -> Result<(), ContentViolation> {
+        if self.outgoing && text_of(&artifact.parts).contains(self.word) {
+            return Err(ContentViolation::refused(format!(
+                "mentions '{}'",
+                self.word
+            )));
+        }
+        Ok(())
+    }
+}
+
+fn text_of(parts: &[Part]) -> String {
+    parts
+        .iter()
+        .filter_map(|part| match part {
+            Part::Text { text, .. } => Some(text.as_str()),
+            _ => None,
+        })
+        .collect()
+}
+
+fn params(parts: Vec<Part>) -> SendMessageParams {
+    let mut message = Message::user(uuid::Uuid::new_v4().to_string(), "");
+    message.parts = parts;
+    SendMessageParams {
+        message,
+        configuration: None,
+        metadata: None,
+        tenant: None,
+    }
+}
+
+fn handler(
+    validator: Arc<dyn ContentValidator>,
+    store: Arc<InMemoryTaskStore>,
+) -> DefaultRequestHandler {
+    DefaultRequestHandler::new(Arc::new(ArtifactAgent), store).with_content_validator(validator)
+}
+
+async fn task_count(store: &InMemoryTaskStore) -> usize {
+    store
+        .list(&TaskListParams::default())
+        .await
+        .unwrap()
+        .tasks
+        .len()
+}
+
+#[tokio::test]
+async fn test_all_content_is_accepted_by_default() {
+    let store = Arc::new(InMemoryTaskStore::new());
+    let handler = DefaultRequestHandler::new(Arc::new(ArtifactAgent), store.clone());
+
+    let response = handler
+        .on_message_send(params(vec![Part::text("anything")]))
+        .await
+        .unwrap();
+    let SendMessageResponse::Task(task) = response else {
+        panic!("expected task");
+    };
+    assert_eq!(task.status.state, TaskState::Completed);
+    assert_eq!(task.artifacts.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_refused_message_is_rejected_before_a_task_exists() {
+    let store = Arc::new(InMemoryTaskStore::new());
+    let moderator = Moderator {
+        word: "forbidden",
+        outgoing: false,
+    };
+    let handler = handler(Arc::new(moderator), store.clone());
+
+    let err = handler
+        .on_message_send(params(vec![Part::text("something forbidden")]))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, A2AError::InvalidParams { .. }), "{err}");
+    assert_eq!(err.code(), error::INVALID_PARAMS);
+    assert_eq!(
+        JsonRpcError::from(err).data,
+        Some(json!({"reason": "mentions 'forbidden'"}))
+    );
+
+    let err = handler
+        .on_message_send_stream(params(vec![Part::text("forbidden")]))
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), error::INVALID_PARAMS);
+    assert_eq!(task_count(&store).await, 0);
+}
+
+#[tokio::test]
+async fn test_content_limits_map_to_spec_errors() {
+    let store = Arc::new(InMemoryTaskStore::new());
+    let limits = ContentLimits::new()
+        .with_max_part_size(8)
+        .with_allowed_media_types(["text/*"]);
+    let handler = handler(Arc::new(limits), store.clone());
+
+    let err = handler
+        .on_message_send(params(vec![Part::file_from_raw(
+            b"png",
+            None,
+            Some("image/png".to_string()),
+        )]))
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(err, A2AError::ContentTypeNotSupported { .. }),
+        "{err}"
+    );
+    assert_eq!(err.code(), error::CONTENT_TYPE_NOT_SUPPORTED);
+    assert_eq!(
+        JsonRpcError::from(err).data,
+        Some(json!({"mimeType": "image/png", "allowedMediaTypes": ["text/*"]}))
+    );
+
+    let err = handler
+        .on_message_send(params(vec![Part::text("ok"), Part::text("much too long")]))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, A2AError::PayloadTooLarge { .. }), "{err}");
+    assert_eq!(err.code(), error::INVALID_REQUEST);
+    assert!(err.to_string().contains("Message part 1"), "{err}");
+    assert_eq!(task_count(&store).await, 0);
+
+    handler
+        .on_message_send(params(vec![Part::text("short")]))
+        .await
+        .unwrap();
+    assert_eq!(task_count(&store).await, 1);
+}
+
+#[tokio::test]
+async fn test_refused_artifact_is_not_published_and_fails_the_task() {
+    let store = Arc::new(InMemoryTaskStore::new());
+    let moderator = Moderator {
+        word: "secret",
+        outgoing: true,
+    };
+    let handler = handler(Arc::new(moderator), store.clone());
+
+    // Messages pass; only the artifact echoing them is refused.
+    let err = handler
+        .on_message_send(params(vec![Part::text("the secret")]))
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(err, A2AError::InvalidAgentResponse { .. }),
+        "{err}"
+    );
+    assert_eq!(err.code(), error::INVALID_AGENT_RESPONSE);
+
+    let mut rx = handler
+        .on_message_send_stream(params(vec![Part::text("another secret")]))
+        .await
+        .unwrap();
+    let update = loop {
+        match rx.recv().await.unwrap() {
+            StreamResponse::ArtifactUpdate(_) => panic!("refused artifact was published"),
+            StreamResponse::StatusUpdate(update) if update.r#final => break update,
+            _ => {}
+        }
+    };
+    assert_eq!(update.status.state, TaskState::Failed);
+    assert_eq!(
+        update.metadata.unwrap()["error"]["data"],
+        json!({"reason": "mentions 'secret'"})
+    );
+
+    let tasks = store.list(&TaskListParams::default()).await.unwrap().tasks;
+    assert!(tasks.iter().all(|task| task.artifacts.is_none()));
+}
+
+#[tokio::test]
+async fn test_validators_compose_in_order() {
+    let validators: Vec<Arc<dyn ContentValidator>> = vec![
+        Arc::new(ContentLimits::new().with_max_part_size(16)),
+        Arc::new(Moderator {
+            word: "spam",
+            outgoing: false,
+        }),
+    ];
+    let message = |text: &str| Message::user("m1", text);
+
+    assert!(validators.validate_message(&message("hello")).await.is_ok());
+    assert_eq!(
+        validators.validate_message(&message("spam")).await,
+        Err(ContentViolation::refused("mentions 'spam'"))
+    );
+    assert!(matches!(
+        validators
+            .validate_message(&message("spam, far too long"))
+            .await,
+        Err(ContentViolation::TooLarge { limit: 16, .. })
+    ));
+}
@@ -0,0 +1,157 @@
+//! `Delegator`: an orchestrator delegating to a downstream agent forwards
+//! its context, republishes downstream artifacts and cancels downstream
+//! work when its own task is canceled.
+
+mod common;
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use a2a_rs::client::{create_text_message, A2AClient, SendMessageResponse};
+use a2a_rs::error::A2AResult;
+use a2a_rs::server::{
+    AgentExecutor, EventQueue, InMemoryTaskStore, RequestContext, TaskListParams, TaskStore,
+    TaskUpdater,
+};
+use a2a_rs::types::*;
+use a2a_rs::utils::{get_artifact_text, get_message_text};
+use async_trait::async_trait;
+
+/// Delegates the user's text to `downstream` and reports its reply.
+struct Orchestrator {
+    downstream: Arc<A2AClient>,
+}
+
+#[async_trait]
+impl AgentExecutor for Orchestrator {
+    async fn execute(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        let updater = TaskUpdater::new(
+            event_queue,
+            context.task_id.clone(),
+            context.context_id.clone(),
+        );
+        updater.start_work(None).await?;
+        let delegator = context.delegator(self.downstream.clone(), &updater.scoped("echo"));
+        let request = create_text_message(Role::User, &context.get_user_input(""));
+        let reply = match delegator.delegate(request).await? {
+            SendMessageResponse::Task(task) => {
+                let status = task.status.message.as_ref();
+                format!(
+                    "{} in {}",
+                    status.map(|m| get_message_text(m, "")).unwrap_or_default(),
+                    task.context_id
+                )
+            }
+            SendMessageResponse::Message(message) => get_message_text(&message, ""),
+        };
+        updater.complete_with_text(&reply).await
+    }
+
+    async fn cancel(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        TaskUpdater::new(event_queue, context.task_id, context.context_id)
+            .cancel(None)
+            .await
+    }
+}
+
+/// Works until canceled.
+struct StuckAgent;
+
+#[async_trait]
+impl AgentExecutor for StuckAgent {
+    async fn execute(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        TaskUpdater::new(event_queue, context.task_id, context.context_id)
+            .start_work(None)
+            .await?;
+        tokio::time::sleep(Duration::from_secs(60)).await;
+        Ok(())
+    }
+
+    async fn cancel(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        TaskUpdater::new(event_queue, context.task_id, context.context_id)
+            .cancel(None)
+            .await
+    }
+}
+
+/// Serve `downstream` and an [`Orchestrator`] delegating to it; returns
+/// the orchestrator's client and the downstream store.
+async fn start(downstream: Arc<dyn AgentExecutor>) -> (A2AClient, Arc<InMemoryTaskStore>) {
+    let store = Arc::new(InMemoryTaskStore::new());
+    let (downstream_url, _) = common::start_test_server_with_store(downstream, store.clone()).await;
+    let orchestrator = Orchestrator {
+        downstream: Arc::new(A2AClient::from_endpoint(&format!("{downstream_url}/a2a"))),
+    };
+    let (url, _) = common::start_test_server(Arc::new(orchestrator)).await;
+    (A2AClient::from_endpoint(&format!("{url}/a2a")), store)
+}
+
+async fn downstream_tasks(store: &InMemoryTaskStore) -> Vec<Task> {
+    store.list(&TaskListParams::default()).await.unwrap().tasks
+}
+
+#[tokio::test]
+async fn test_delegate_forwards_context_and_republishes_artifacts() {
+    let (client, store) = start(Arc::new(common::SlowEchoAgent)).await;
+
+    let task = match client.send_text("hi").await.unwrap() {
+        SendMessageResponse::Task(task) => task,
+        other => panic!("expected a task, got {other:?}"),
+    };
+    assert_eq!(task.status.state, TaskState::Completed);
+    assert_eq!(
+        get_message_text(task.status.message.as_ref().unwrap(), ""),
+        format!("Done: hi in {}", task.context_id)
+    );
+
+    let artifacts = task.artifacts.unwrap();
+    assert_eq!(artifacts.len(), 1);
+    assert!(artifacts[0].artifact_id.starts_with("echo/"));
+    assert_eq!(artifacts[0].name.as_deref(), Some("output"));
+    assert_eq!(get_artifact_text(&artifacts[0], ""), "Processed: hi");
+
+    let downstream = downstream_tasks(&store).await;
+    assert_eq!(downstream.len(), 1);
+    assert_eq!(downstream[0].context_id, task.context_id);
+}
+
+#[tokio::test]
+async fn test_canceling_the_upstream_task_cancels_downstream() {
+    let (client, store) = start(Arc::new(StuckAgent)).await;
+
+    let mut stream = client.send_text_stream("work").await.unwrap();
+    let task_id = loop {
+        let event = stream.next().await.unwrap().unwrap();
+        if let Some(id) = event.task_id() {
+            break id.to_string();
+        }
+    };
+    let working = |tasks: &[Task]| {
+        tasks
+            .first()
+            .is_some_and(|t| t.status.state == TaskState::Working)
+    };
+    for _ in 0..100 {
+        if working(&downstream_tasks(&store).await) {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    assert!(working(&downstream_tasks(&store).await));
+
+    let canceled = client.cancel_task_by_id(&task_id).await.unwrap();
+    assert_eq!(canceled.status.state, TaskState::Canceled);
+
+    let downstream_canceled = |tasks: &[Task]| {
+        tasks
+            .first()
+            .is_some_and(|t| t.status.state == TaskState::Canceled)
+    };
+    for _ in 0..100 {
+        if downstream_canceled(&downstream_tasks(&store).await) {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    panic!("downstream task was not canceled");
+}
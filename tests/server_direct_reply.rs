@@ -0,0 +1,150 @@
+//! Direct message replies: an agent answering with `EventQueue::reply_message`
+//! instead of a task, over `message/send`, `message/stream` and the client.
+
+mod common;
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use a2a_rs::builders::ClientBuilder;
+use a2a_rs::error::A2AResult;
+use a2a_rs::server::{
+    AgentExecutor, ContextStore, DefaultRequestHandler, EventQueue, InMemoryContextStore,
+    InMemoryTaskStore, RequestContext, RequestHandler, SendMessageParams, TaskListParams,
+    TaskStore, TaskUpdater,
+};
+use a2a_rs::types::*;
+use async_trait::async_trait;
+use common::start_test_server;
+
+/// Agent replying with a message, without a task. A message with
+/// "follow up" in it is answered with a message after working on the task.
+struct ReplyAgent;
+
+#[async_trait]
+impl AgentExecutor for ReplyAgent {
+    async fn execute(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        let text = context.get_user_input(" ");
+        let mut reply = Message::agent("r1", format!("re: {text}"));
+        reply.context_id = Some(context.context_id.clone());
+        if !text.contains("follow up") {
+            return event_queue.reply_message(reply).await;
+        }
+        let updater = TaskUpdater::new(event_queue.clone(), context.task_id, context.context_id);
+        updater.start_work(None).await?;
+        reply.task_id = Some(updater.task_id().to_string());
+        event_queue
+            .enqueue_event(StreamResponse::Message(reply))
+            .await?;
+        updater.complete(None).await
+    }
+
+    async fn cancel(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        TaskUpdater::new(event_queue, context.task_id, context.context_id)
+            .cancel(None)
+            .await
+    }
+}
+
+fn params(text: &str) -> SendMessageParams {
+    let mut message = Message::user("m1", text);
+    message.context_id = Some("ctx".to_string());
+    SendMessageParams {
+        message,
+        configuration: None,
+        metadata: None,
+        tenant: None,
+    }
+}
+
+async fn task_count(store: &dyn TaskStore) -> usize {
+    store
+        .list(&TaskListParams::default())
+        .await
+        .unwrap()
+        .tasks
+        .len()
+}
+
+#[tokio::test]
+async fn test_send_returns_the_message_without_a_task() {
+    let store = Arc::new(InMemoryTaskStore::new());
+    let handler = DefaultRequestHandler::new(Arc::new(ReplyAgent), store.clone());
+
+    let response = handler.on_message_send(params("hi")).await.unwrap();
+    let SendMessageResponse::Message(reply) = response else {
+        panic!("expected a message, got {response:?}");
+    };
+    assert_eq!(reply.message_id, "r1");
+    assert_eq!(reply.task_id, None);
+    assert_eq!(reply.context_id.as_deref(), Some("ctx"));
+    assert_eq!(task_count(&*store).await, 0);
+}
+
+#[tokio::test]
+async fn test_stream_yields_only_the_message() {
+    let store = Arc::new(InMemoryTaskStore::new());
+    let handler = DefaultRequestHandler::new(Arc::new(ReplyAgent), store.clone());
+
+    let mut rx = handler.on_message_send_stream(params("hi")).await.unwrap();
+    let event = tokio::time::timeout(Duration::from_secs(5), rx.recv())
+        .await
+        .unwrap()
+        .unwrap();
+    let StreamResponse::Message(reply) = event else {
+        panic!("expected a message, got {event:?}");
+    };
+    assert_eq!(reply.task_id, None);
+    assert_eq!(task_count(&*store).await, 0);
+}
+
+#[tokio::test]
+async fn test_retried_message_is_answered_again() {
+    let store = Arc::new(InMemoryTaskStore::new());
+    let contexts = Arc::new(InMemoryContextStore::new());
+    let handler = DefaultRequestHandler::new(Arc::new(ReplyAgent), store.clone())
+        .with_message_dedup(Duration::from_secs(60))
+        .with_context_store(contexts.clone());
+
+    for _ in 0..2 {
+        let response = handler.on_message_send(params("hi")).await.unwrap();
+        assert!(
+            matches!(&response, SendMessageResponse::Message(reply) if reply.message_id == "r1"),
+            "expected the reply, got {response:?}"
+        );
+    }
+    assert_eq!(task_count(&*store).await, 0);
+    assert_eq!(contexts.get("ctx").await.unwrap(), None);
+}
+
+#[tokio::test]
+async fn test_message_after_other_events_belongs_to_the_task() {
+    let store = Arc::new(InMemoryTaskStore::new());
+    let handler = DefaultRequestHandler::new(Arc::new(ReplyAgent), store.clone());
+
+    let response = handler.on_message_send(params("follow up")).await.unwrap();
+    let SendMessageResponse::Task(task) = response else {
+        panic!("expected a task, got {response:?}");
+    };
+    assert_eq!(task.status.state, TaskState::Completed);
+    let history = task.history.unwrap_or_default();
+    assert!(history.iter().any(|m| m.message_id == "r1"));
+    assert_eq!(task_count(&*store).await, 1);
+}
+
+#[tokio::test]
+async fn test_client_stream_ends_after_the_direct_reply() {
+    let (base_url, _server) = start_test_server(Arc::new(ReplyAgent)).await;
+    let client = ClientBuilder::new(format!("{base_url}/a2a")).build_from_endpoint();
+
+    let response = client.send_text("hi").await.unwrap();
+    assert!(matches!(response, SendMessageResponse::Message(_)));
+
+    let mut stream = client.send_text_stream("hi").await.unwrap();
+    let first = stream.next().await.unwrap().unwrap();
+    assert!(matches!(first, StreamResponse::Message(_)));
+    let rest = tokio::time::timeout(Duration::from_secs(5), stream.next())
+        .await
+        .expect("stream stayed open after the reply");
+    assert!(rest.is_none());
+}
@@ -0,0 +1,105 @@
+//! Structured error data from executors: the failed status, the JSON-RPC
+//! error of `message/send`, and typed access on the client.
+
+mod common;
+
+use std::sync::Arc;
+
+use a2a_rs::client::{A2AClient, PollOptions};
+use a2a_rs::error::{self, A2AError, A2AResult};
+use a2a_rs::server::{AgentExecutor, EventQueue, RequestContext};
+use a2a_rs::types::*;
+use a2a_rs::utils::get_message_text;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct QuotaExceeded {
+    limit: u32,
+    reset_in_secs: u64,
+}
+
+/// Fails with a quota error carrying [`QuotaExceeded`].
+struct QuotaAgent;
+
+#[async_trait]
+impl AgentExecutor for QuotaAgent {
+    async fn execute(&self, _context: RequestContext, _event_queue: EventQueue) -> A2AResult<()> {
+        Err(
+            A2AError::unsupported_operation("Daily quota exceeded").with_data(QuotaExceeded {
+                limit: 100,
+                reset_in_secs: 3600,
+            }),
+        )
+    }
+
+    async fn cancel(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        context.task_updater(event_queue).cancel(None).await
+    }
+}
+
+async fn client(executor: Arc<dyn AgentExecutor>) -> A2AClient {
+    let (base_url, _) = common::start_test_server(executor).await;
+    A2AClient::from_endpoint(&format!("{base_url}/a2a"))
+}
+
+#[tokio::test]
+async fn test_send_returns_structured_error() {
+    let client = client(Arc::new(QuotaAgent)).await;
+
+    let err = client.send_text("hi").await.unwrap_err();
+    assert_eq!(err.code(), error::UNSUPPORTED_OPERATION);
+    assert!(err.to_string().contains("Daily quota exceeded"), "{err}");
+    assert_eq!(
+        err.error_data::<QuotaExceeded>().unwrap(),
+        QuotaExceeded {
+            limit: 100,
+            reset_in_secs: 3600
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_stream_ends_with_failed_status_carrying_error() {
+    let client = client(Arc::new(QuotaAgent)).await;
+
+    let mut stream = client.send_text_stream("hi").await.unwrap();
+    let mut last = None;
+    while let Some(event) = stream.next().await {
+        last = Some(event.unwrap());
+    }
+    let Some(StreamResponse::StatusUpdate(update)) = last else {
+        panic!("expected a final status, got {last:?}");
+    };
+    assert_eq!(update.status.state, TaskState::Failed);
+    let text = get_message_text(update.status.message.as_ref().unwrap(), "");
+    assert!(text.starts_with("Agent execution failed:"), "{text}");
+    let error = &update.metadata.unwrap()["error"];
+    assert_eq!(error["code"], error::UNSUPPORTED_OPERATION);
+    assert_eq!(error["data"]["resetInSecs"], 3600);
+
+    // The error is kept on the stored task, so pollers see it too.
+    let err = client
+        .wait_for_completion(&update.task_id, &PollOptions::default())
+        .await
+        .unwrap_err();
+    assert!(matches!(err, A2AError::TaskFailed { .. }), "{err}");
+    assert_eq!(err.error_data::<QuotaExceeded>().unwrap().limit, 100);
+}
+
+#[tokio::test]
+async fn test_error_without_data_still_returns_failed_task() {
+    let client = client(Arc::new(common::FailingAgent)).await;
+
+    let SendMessageResponse::Task(task) = client.send_text("hi").await.unwrap() else {
+        panic!("expected a task");
+    };
+    assert_eq!(task.status.state, TaskState::Failed);
+    let error = &task.metadata.unwrap()["error"];
+    assert_eq!(error["code"], error::INTERNAL_ERROR);
+    assert!(error["message"]
+        .as_str()
+        .unwrap()
+        .contains("Agent intentionally failed"));
+}
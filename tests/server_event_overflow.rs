@@ -0,0 +1,249 @@
+//! Event queue overflow: `EventReceiver` turning lag into gap events,
+//! events recovered from an `EventStore`, or a disconnect, and stream
+//! subscribers of `DefaultRequestHandler` falling behind.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use a2a_rs::error::A2AResult;
+use a2a_rs::server::{
+    gap_missed, AgentExecutor, DefaultRequestHandler, EventQueue, EventStore, InMemoryEventStore,
+    InMemoryQueueManager, InMemoryTaskStore, OverflowPolicy, QueueManager, RequestContext,
+    RequestHandler, SendMessageParams, TaskUpdater,
+};
+use a2a_rs::types::*;
+use async_trait::async_trait;
+use tokio::sync::broadcast::error::RecvError;
+
+fn status(state: TaskState) -> StreamResponse {
+    StreamResponse::StatusUpdate(TaskStatusUpdateEvent {
+        task_id: "t1".to_string(),
+        context_id: "c1".to_string(),
+        kind: "status-update".to_string(),
+        status: TaskStatus::new(state),
+        r#final: false,
+        metadata: None,
+    })
+}
+
+fn artifact(i: usize) -> StreamResponse {
+    StreamResponse::ArtifactUpdate(TaskArtifactUpdateEvent {
+        task_id: "t1".to_string(),
+        context_id: "c1".to_string(),
+        kind: "artifact-update".to_string(),
+        artifact: Artifact {
+            artifact_id: format!("a{i}"),
+            name: None,
+            description: None,
+            parts: vec![Part::text(format!("chunk {i}"))],
+            metadata: None,
+            extensions: None,
+        },
+        append: None,
+        last_chunk: None,
+        metadata: None,
+    })
+}
+
+fn artifact_id(event: &StreamResponse) -> Option<&str> {
+    match event {
+        StreamResponse::ArtifactUpdate(update) => Some(&update.artifact.artifact_id),
+        _ => None,
+    }
+}
+
+/// A receiver that read the `working` status, then fell 5 artifacts behind
+/// a queue of capacity 2.
+async fn lagging(queue: &EventQueue) -> a2a_rs::server::EventReceiver {
+    let mut receiver = queue.receiver();
+    queue.publish(status(TaskState::Working)).unwrap();
+    receiver.recv().await.unwrap();
+    for i in 0..5 {
+        queue.publish(artifact(i)).unwrap();
+    }
+    receiver
+}
+
+#[tokio::test]
+async fn test_drop_oldest_announces_the_gap() {
+    let queue = EventQueue::new(2);
+    assert_eq!(queue.overflow_policy(), OverflowPolicy::DropOldest);
+    let mut receiver = lagging(&queue).await;
+
+    let gap = receiver.recv().await.unwrap();
+    assert_eq!(gap_missed(&gap), Some(3));
+    let StreamResponse::StatusUpdate(update) = &gap else {
+        panic!("expected a status update, got {gap:?}");
+    };
+    assert_eq!(update.task_id, "t1");
+    assert_eq!(update.status.state, TaskState::Working);
+    assert!(!update.r#final);
+
+    assert_eq!(artifact_id(&receiver.recv().await.unwrap()), Some("a3"));
+    assert_eq!(artifact_id(&receiver.recv().await.unwrap()), Some("a4"));
+    assert_eq!(receiver.missed(), 3);
+    assert_eq!(gap_missed(&status(TaskState::Working)), None);
+}
+
+#[tokio::test]
+async fn test_disconnect_ends_the_lagging_stream() {
+    let queue = EventQueue::new(2).with_overflow_policy(OverflowPolicy::Disconnect);
+    let mut receiver = lagging(&queue).await;
+
+    assert!(matches!(receiver.recv().await, Err(RecvError::Closed)));
+    assert!(matches!(receiver.recv().await, Err(RecvError::Closed)));
+    assert_eq!(receiver.missed(), 3);
+}
+
+#[tokio::test]
+async fn test_buffer_to_store_recovers_missed_events() {
+    let store = Arc::new(InMemoryEventStore::new());
+    let queue = EventQueue::new(2).with_overflow_policy(OverflowPolicy::BufferToStore);
+    let mut receiver = queue.receiver().with_store(store.clone(), "t1", 0);
+
+    for event in std::iter::once(status(TaskState::Working)).chain((0..5).map(artifact)) {
+        store.append("t1", &event).await.unwrap();
+        queue.publish(event).unwrap();
+    }
+
+    assert!(matches!(
+        receiver.recv().await.unwrap(),
+        StreamResponse::StatusUpdate(_)
+    ));
+    let mut ids = Vec::new();
+    for _ in 0..5 {
+        ids.push(
+            artifact_id(&receiver.recv().await.unwrap())
+                .unwrap()
+                .to_string(),
+        );
+    }
+    assert_eq!(ids, ["a0", "a1", "a2", "a3", "a4"]);
+    assert_eq!(receiver.missed(), 0);
+}
+
+#[tokio::test]
+async fn test_buffer_to_store_without_the_events_falls_back_to_a_gap() {
+    let queue = EventQueue::new(2).with_overflow_policy(OverflowPolicy::BufferToStore);
+    let store = Arc::new(InMemoryEventStore::new());
+    let mut receiver = queue.receiver().with_store(store, "t1", 0);
+    queue.publish(status(TaskState::Working)).unwrap();
+    receiver.recv().await.unwrap();
+    for i in 0..5 {
+        queue.publish(artifact(i)).unwrap();
+    }
+
+    assert_eq!(gap_missed(&receiver.recv().await.unwrap()), Some(3));
+    assert_eq!(receiver.missed(), 3);
+}
+
+#[tokio::test]
+async fn test_queue_manager_configures_new_queues_and_taps() {
+    let manager = InMemoryQueueManager::new()
+        .with_capacity(8)
+        .with_overflow_policy(OverflowPolicy::Disconnect);
+
+    let queue = manager.create_or_tap("t1").await;
+    assert_eq!(queue.capacity(), 8);
+    assert_eq!(queue.overflow_policy(), OverflowPolicy::Disconnect);
+    let tap = manager.create_or_tap("t1").await;
+    assert_eq!(tap.capacity(), 8);
+    assert_eq!(tap.overflow_policy(), OverflowPolicy::Disconnect);
+}
+
+const CHUNKS: usize = 40;
+
+/// Agent emitting many artifacts, pausing between them so the event
+/// pipeline keeps up while subscribers may not.
+struct ChattyAgent;
+
+#[async_trait]
+impl AgentExecutor for ChattyAgent {
+    async fn execute(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        let updater = TaskUpdater::new(event_queue, context.task_id, context.context_id);
+        updater.start_work(None).await?;
+        for i in 0..CHUNKS {
+            updater
+                .add_artifact(
+                    vec![Part::text(format!("chunk {i}"))],
+                    Some(format!("a{i}")),
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                )
+                .await?;
+            tokio::time::sleep(Duration::from_millis(1)).await;
+        }
+        updater.complete(None).await
+    }
+
+    async fn cancel(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        TaskUpdater::new(event_queue, context.task_id, context.context_id)
+            .cancel(None)
+            .await
+    }
+}
+
+/// Start a stream, read nothing until the agent is done, then drain it.
+async fn slow_subscriber(handler: DefaultRequestHandler) -> Vec<StreamResponse> {
+    let mut rx = handler
+        .on_message_send_stream(SendMessageParams {
+            message: Message::user("m1", "go"),
+            configuration: None,
+            metadata: None,
+            tenant: None,
+        })
+        .await
+        .unwrap();
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    let mut events = Vec::new();
+    loop {
+        let event = tokio::time::timeout(Duration::from_secs(5), rx.recv())
+            .await
+            .expect("timed out waiting for event")
+            .expect("stream ended before the final event");
+        let is_final = matches!(&event, StreamResponse::StatusUpdate(update) if update.r#final);
+        events.push(event);
+        if is_final {
+            return events;
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_slow_stream_subscriber_gets_a_gap_event() {
+    let handler =
+        DefaultRequestHandler::new(Arc::new(ChattyAgent), Arc::new(InMemoryTaskStore::new()))
+            .with_event_queue_capacity(4);
+
+    let events = slow_subscriber(handler).await;
+    let missed: u64 = events.iter().filter_map(gap_missed).sum();
+    assert!(missed > 0, "expected a gap in {events:?}");
+    // Every event but the gaps is live: working, the artifacts, completed.
+    let gaps = events
+        .iter()
+        .filter(|event| gap_missed(event).is_some())
+        .count();
+    assert_eq!((events.len() - gaps) as u64 + missed, CHUNKS as u64 + 2);
+    let Some(StreamResponse::StatusUpdate(last)) = events.last() else {
+        unreachable!();
+    };
+    assert_eq!(last.status.state, TaskState::Completed);
+}
+
+#[tokio::test]
+async fn test_slow_stream_subscriber_reads_missed_events_from_the_event_store() {
+    let handler =
+        DefaultRequestHandler::new(Arc::new(ChattyAgent), Arc::new(InMemoryTaskStore::new()))
+            .with_event_store(Arc::new(InMemoryEventStore::new()))
+            .with_event_queue_capacity(4)
+            .with_overflow_policy(OverflowPolicy::BufferToStore);
+
+    let events = slow_subscriber(handler).await;
+    assert!(events.iter().all(|event| gap_missed(event).is_none()));
+    let ids: Vec<&str> = events.iter().filter_map(artifact_id).collect();
+    let expected: Vec<String> = (0..CHUNKS).map(|i| format!("a{i}")).collect();
+    assert_eq!(ids, expected);
+}
@@ -0,0 +1,200 @@
+//! Tests for the DefaultRequestHandler event pipeline: every executor event
+//! is applied to the TaskStore before any subscriber can observe it.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use a2a_rs::error::A2AResult;
+use a2a_rs::server::{
+    AgentExecutor, DefaultRequestHandler, EventQueue, InMemoryTaskStore, RequestContext,
+    RequestHandler, SendMessageParams, SubscribeToTaskParams, TaskStore, TaskUpdater,
+};
+use a2a_rs::types::*;
+use async_trait::async_trait;
+use tokio::sync::broadcast;
+
+const CHUNKS: usize = 200;
+
+/// Agent that bursts many artifact events with no pauses, then a direct
+/// message, then completes.
+struct BurstAgent;
+
+#[async_trait]
+impl AgentExecutor for BurstAgent {
+    async fn execute(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        let updater = TaskUpdater::new(
+            event_queue.clone(),
+            context.task_id.clone(),
+            context.context_id.clone(),
+        );
+        updater.start_work(None).await?;
+        for i in 0..CHUNKS {
+            updater
+                .add_artifact(
+                    vec![Part::text(format!("chunk {i}"))],
+                    Some(format!("a{i}")),
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                )
+                .await?;
+            if i == CHUNKS / 2 {
+                // Give a late subscriber a window to attach mid-stream.
+                tokio::time::sleep(Duration::from_millis(20)).await;
+            }
+        }
+        event_queue
+            .enqueue_event(StreamResponse::Message(
+                updater.new_agent_message(vec![Part::text("side note")], None),
+            ))
+            .await?;
+        updater.complete(None).await
+    }
+
+    async fn cancel(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        TaskUpdater::new(event_queue, context.task_id, context.context_id)
+            .cancel(None)
+            .await
+    }
+}
+
+fn params() -> SendMessageParams {
+    SendMessageParams {
+        message: Message::user("m1", "go"),
+        configuration: None,
+        metadata: None,
+        tenant: None,
+    }
+}
+
+/// Drain `rx` until a final event, checking each event is already stored.
+async fn drain_checking_store(
+    mut rx: broadcast::Receiver<StreamResponse>,
+    store: Arc<dyn TaskStore>,
+    task_id: Option<String>,
+) -> Vec<StreamResponse> {
+    let mut seen = Vec::new();
+    loop {
+        let event = tokio::time::timeout(Duration::from_secs(5), rx.recv())
+            .await
+            .expect("timed out waiting for event")
+            .expect("channel closed before final event");
+
+        let id = match &event {
+            StreamResponse::StatusUpdate(e) => e.task_id.clone(),
+            StreamResponse::ArtifactUpdate(e) => e.task_id.clone(),
+            StreamResponse::Task(t) => t.id.clone(),
+            StreamResponse::Message(m) => m.task_id.clone().unwrap(),
+        };
+        if let Some(ref expected) = task_id {
+            assert_eq!(&id, expected);
+        }
+        let task = store.get(&id).await.unwrap().expect("task stored");
+
+        match &event {
+            StreamResponse::ArtifactUpdate(e) => {
+                let artifacts = task.artifacts.clone().unwrap_or_default();
+                assert!(
+                    artifacts
+                        .iter()
+                        .any(|a| a.artifact_id == e.artifact.artifact_id),
+                    "artifact {} delivered before it was persisted",
+                    e.artifact.artifact_id
+                );
+            }
+            StreamResponse::StatusUpdate(e) => {
+                assert_eq!(task.status.state, e.status.state);
+            }
+            StreamResponse::Message(m) => {
+                let history = task.history.clone().unwrap_or_default();
+                assert!(history.iter().any(|h| h.message_id == m.message_id));
+            }
+            StreamResponse::Task(_) => {}
+        }
+
+        let done = matches!(&event, StreamResponse::StatusUpdate(e) if e.r#final);
+        seen.push(event);
+        if done {
+            return seen;
+        }
+    }
+}
+
+#[tokio::test]
+async fn stream_events_are_persisted_before_delivery() {
+    let store: Arc<dyn TaskStore> = Arc::new(InMemoryTaskStore::new());
+    let handler = DefaultRequestHandler::new(Arc::new(BurstAgent), store.clone());
+
+    let rx = handler.on_message_send_stream(params()).await.unwrap();
+    let events = drain_checking_store(rx, store.clone(), None).await;
+
+    // working + CHUNKS artifacts + message + completed — nothing lost.
+    assert_eq!(events.len(), CHUNKS + 3);
+    let artifact_count = events
+        .iter()
+        .filter(|e| matches!(e, StreamResponse::ArtifactUpdate(_)))
+        .count();
+    assert_eq!(artifact_count, CHUNKS);
+}
+
+#[tokio::test]
+async fn late_subscriber_only_sees_persisted_events() {
+    let store: Arc<dyn TaskStore> = Arc::new(InMemoryTaskStore::new());
+    let handler = Arc::new(DefaultRequestHandler::new(
+        Arc::new(BurstAgent),
+        store.clone(),
+    ));
+
+    let rx = handler.on_message_send_stream(params()).await.unwrap();
+    let task_id = store
+        .list(&Default::default())
+        .await
+        .unwrap()
+        .tasks
+        .pop()
+        .unwrap()
+        .id;
+
+    // Attach a second subscriber while the executor is mid-burst.
+    tokio::time::sleep(Duration::from_millis(5)).await;
+    let late_rx = handler
+        .on_subscribe_to_task(SubscribeToTaskParams {
+            id: task_id.clone(),
+            metadata: None,
+            tenant: None,
+        })
+        .await
+        .unwrap();
+
+    let (first, late) = tokio::join!(
+        drain_checking_store(rx, store.clone(), Some(task_id.clone())),
+        drain_checking_store(late_rx, store.clone(), Some(task_id.clone())),
+    );
+    assert_eq!(first.len(), CHUNKS + 3);
+    assert!(!late.is_empty());
+
+    // The store ends up with every artifact regardless of who was listening.
+    let task = store.get(&task_id).await.unwrap().unwrap();
+    assert_eq!(task.status.state, TaskState::Completed);
+    assert_eq!(task.artifacts.unwrap().len(), CHUNKS);
+}
+
+#[tokio::test]
+async fn send_returns_fully_persisted_task() {
+    let store: Arc<dyn TaskStore> = Arc::new(InMemoryTaskStore::new());
+    let handler = DefaultRequestHandler::new(Arc::new(BurstAgent), store.clone());
+
+    let response = handler.on_message_send(params()).await.unwrap();
+    let SendMessageResponse::Task(task) = response else {
+        panic!("expected task");
+    };
+    assert_eq!(task.status.state, TaskState::Completed);
+    assert_eq!(task.artifacts.as_ref().unwrap().len(), CHUNKS);
+    let history = task.history.unwrap();
+    assert!(history.iter().any(|m| m
+        .parts
+        .iter()
+        .any(|p| matches!(p, Part::Text { text, .. } if text == "side note"))));
+}
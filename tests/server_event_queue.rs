@@ -57,6 +57,7 @@ async fn test_event_queue_enqueue_and_receive() {
             state: TaskState::Working,
             message: None,
             timestamp: None,
+            raw_state: None,
         },
         r#final: false,
         metadata: None,
@@ -87,6 +88,7 @@ async fn test_event_queue_publish_sync() {
             state: TaskState::Completed,
             message: None,
             timestamp: None,
+            raw_state: None,
         },
         r#final: true,
         metadata: None,
@@ -123,6 +125,7 @@ async fn test_closed_queue_drops_enqueue() {
             state: TaskState::Working,
             message: None,
             timestamp: None,
+            raw_state: None,
         },
         r#final: false,
         metadata: None,
@@ -147,6 +150,7 @@ async fn test_closed_queue_drops_publish() {
             state: TaskState::Working,
             message: None,
             timestamp: None,
+            raw_state: None,
         },
         r#final: false,
         metadata: None,
@@ -170,6 +174,7 @@ async fn test_event_queue_multiple_subscribers() {
             state: TaskState::Working,
             message: None,
             timestamp: None,
+            raw_state: None,
         },
         r#final: false,
         metadata: None,
@@ -193,6 +198,7 @@ async fn test_event_queue_no_subscribers_ok() {
             state: TaskState::Working,
             message: None,
             timestamp: None,
+            raw_state: None,
         },
         r#final: false,
         metadata: None,
@@ -208,6 +214,7 @@ async fn test_event_queue_no_subscribers_ok() {
                 state: TaskState::Working,
                 message: None,
                 timestamp: None,
+                raw_state: None,
             },
             r#final: false,
             metadata: None,
@@ -231,6 +238,7 @@ async fn test_event_queue_tap_creates_child() {
             state: TaskState::Working,
             message: None,
             timestamp: None,
+            raw_state: None,
         },
         r#final: false,
         metadata: None,
@@ -273,6 +281,7 @@ async fn test_tap_multiple_children() {
             state: TaskState::Working,
             message: None,
             timestamp: None,
+            raw_state: None,
         },
         r#final: false,
         metadata: None,
@@ -297,6 +306,7 @@ fn make_status_event(task_id: &str, state: TaskState, is_final: bool) -> StreamR
             state,
             message: None,
             timestamp: None,
+            raw_state: None,
         },
         r#final: is_final,
         metadata: None,
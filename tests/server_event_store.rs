@@ -0,0 +1,202 @@
+//! Event store: every published event is recorded, and `tasks/subscribe`
+//! / `tasks/resubscribe` replay a task's events before live ones, also
+//! once the task has finished.
+
+mod common;
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use a2a_rs::client::{A2AClient, SseStream};
+use a2a_rs::error::A2AResult;
+use a2a_rs::server::{
+    AgentExecutor, DefaultRequestHandler, EventQueue, EventStore, FileEventStore,
+    InMemoryEventStore, InMemoryTaskStore, RequestContext, TaskUpdater,
+};
+use a2a_rs::types::*;
+use async_trait::async_trait;
+use common::SlowEchoAgent;
+
+/// Adds an artifact, then completes after a pause.
+struct PausingAgent;
+
+#[async_trait]
+impl AgentExecutor for PausingAgent {
+    async fn execute(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        let updater = TaskUpdater::new(event_queue, context.task_id, context.context_id);
+        updater.start_work(None).await?;
+        updater
+            .add_artifact(
+                vec![Part::text("partial")],
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+            .await?;
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        updater.complete_with_text("done").await
+    }
+
+    async fn cancel(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        TaskUpdater::new(event_queue, context.task_id, context.context_id)
+            .cancel(None)
+            .await
+    }
+}
+
+/// Serve `executor` recording events in `events` (if any); returns the
+/// endpoint URL.
+async fn serve(executor: Arc<dyn AgentExecutor>, events: Option<Arc<dyn EventStore>>) -> String {
+    let mut handler = DefaultRequestHandler::new(executor, Arc::new(InMemoryTaskStore::new()));
+    if let Some(events) = events {
+        handler = handler.with_event_store(events);
+    }
+    let (base_url, _server) =
+        common::start_test_server_with_handler(Arc::new(handler), common::test_agent_card).await;
+    format!("{base_url}/a2a")
+}
+
+async fn send_task(client: &A2AClient) -> Task {
+    match client.send_text("hi").await.unwrap() {
+        SendMessageResponse::Task(task) => task,
+        other => panic!("expected Task response, got {other:?}"),
+    }
+}
+
+async fn kinds(mut stream: SseStream) -> Vec<String> {
+    let mut kinds = Vec::new();
+    while let Some(event) = stream.next().await {
+        let event = event.unwrap();
+        kinds.push(match &event {
+            StreamResponse::StatusUpdate(update) => update.status.state.to_string(),
+            other => other.kind().to_string(),
+        });
+    }
+    kinds
+}
+
+#[tokio::test]
+async fn test_in_memory_store_appends_in_sequence() {
+    let store = InMemoryEventStore::new();
+    let event = |text: &str| StreamResponse::Message(Message::agent("m", text));
+
+    assert_eq!(store.append("t1", &event("a")).await.unwrap(), 1);
+    assert_eq!(store.append("t1", &event("b")).await.unwrap(), 2);
+    assert_eq!(store.append("t2", &event("c")).await.unwrap(), 1);
+
+    let events = store.events("t1", 0).await.unwrap();
+    assert_eq!(
+        events.iter().map(|e| e.sequence).collect::<Vec<_>>(),
+        [1, 2]
+    );
+    assert_eq!(store.events("t1", 1).await.unwrap()[0].sequence, 2);
+    assert!(store.events("unknown", 0).await.unwrap().is_empty());
+
+    store.remove("t1").await.unwrap();
+    assert!(store.events("t1", 0).await.unwrap().is_empty());
+    assert_eq!(store.events("t2", 0).await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_file_store_survives_reopen() {
+    let dir = std::env::temp_dir().join(format!("a2a-events-{}", uuid::Uuid::new_v4()));
+    let event = |text: &str| StreamResponse::Message(Message::agent("m", text));
+    let task_id = "../tasks/t 1";
+
+    let store = FileEventStore::open(&dir).unwrap();
+    store.append(task_id, &event("a")).await.unwrap();
+    store.append(task_id, &event("b")).await.unwrap();
+    // Task IDs can't escape the directory.
+    assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 1);
+
+    // A crash mid-write leaves a torn line, which is skipped.
+    let file = std::fs::read_dir(&dir).unwrap().next().unwrap().unwrap();
+    let mut text = std::fs::read_to_string(file.path()).unwrap();
+    text.push_str("{\"sequence\":3,\"ev");
+    std::fs::write(file.path(), text).unwrap();
+
+    let store = FileEventStore::open(&dir).unwrap();
+    assert_eq!(store.events(task_id, 0).await.unwrap().len(), 2);
+    assert_eq!(store.append(task_id, &event("c")).await.unwrap(), 3);
+    let events = store.events(task_id, 1).await.unwrap();
+    assert_eq!(
+        events.iter().map(|e| e.sequence).collect::<Vec<_>>(),
+        [2, 3]
+    );
+
+    store.remove(task_id).await.unwrap();
+    assert!(store.events(task_id, 0).await.unwrap().is_empty());
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn test_resubscribe_to_finished_task_replays_its_events() {
+    let store: Arc<dyn EventStore> = Arc::new(InMemoryEventStore::new());
+    let url = serve(Arc::new(SlowEchoAgent), Some(store.clone())).await;
+    let client = A2AClient::from_endpoint(&url);
+
+    let task = send_task(&client).await;
+    assert_eq!(task.status.state, TaskState::Completed);
+    assert!(!store.events(&task.id, 0).await.unwrap().is_empty());
+
+    let replayed = kinds(client.resubscribe_by_id(&task.id).await.unwrap()).await;
+    assert_eq!(replayed, ["artifact-update", "completed"]);
+}
+
+#[tokio::test]
+async fn test_finished_task_without_event_store_cannot_be_resubscribed() {
+    let url = serve(Arc::new(SlowEchoAgent), None).await;
+
+    let task = send_task(&A2AClient::from_endpoint(&url)).await;
+    let response: serde_json::Value = reqwest::Client::new()
+        .post(&url)
+        .json(&common::jsonrpc_request(
+            serde_json::json!(1),
+            "tasks/resubscribe",
+            serde_json::json!({"id": task.id}),
+        ))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(response["error"]["code"], -32602, "{response}");
+}
+
+#[tokio::test]
+async fn test_late_subscriber_sees_earlier_events_once() {
+    let url = serve(
+        Arc::new(PausingAgent),
+        Some(Arc::new(InMemoryEventStore::new())),
+    )
+    .await;
+    let client = A2AClient::from_endpoint(&url);
+
+    let mut stream = client.send_text_stream("hi").await.unwrap();
+    let mut first = Vec::new();
+    let task_id = loop {
+        let event = stream.next().await.unwrap().unwrap();
+        let is_artifact = matches!(event, StreamResponse::ArtifactUpdate(_));
+        first.push(event);
+        if is_artifact {
+            break first[0].task_id().unwrap().to_string();
+        }
+    };
+
+    let late = kinds(client.resubscribe_by_id(&task_id).await.unwrap()).await;
+    assert_eq!(
+        late,
+        first
+            .iter()
+            .map(|event| match event {
+                StreamResponse::StatusUpdate(update) => update.status.state.to_string(),
+                other => other.kind().to_string(),
+            })
+            .chain(["completed".to_string()])
+            .collect::<Vec<_>>()
+    );
+}
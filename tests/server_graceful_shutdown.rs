@@ -0,0 +1,141 @@
+//! Graceful shutdown: in-flight tasks drain, stragglers are canceled, and
+//! the server stops once their streams have closed.
+
+mod common;
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use a2a_rs::builders::ServerBuilder;
+use a2a_rs::client::{A2AClient, SseStream};
+use a2a_rs::error::A2AResult;
+use a2a_rs::server::{
+    AgentExecutor, EventQueue, InMemoryTaskStore, RequestContext, TaskStore, TaskUpdater,
+};
+use a2a_rs::types::*;
+use async_trait::async_trait;
+use tokio::sync::oneshot;
+use tokio::task::JoinHandle;
+
+/// Starts work, then completes after `delay` unless canceled first.
+struct DelayedAgent {
+    delay: Duration,
+}
+
+#[async_trait]
+impl AgentExecutor for DelayedAgent {
+    async fn execute(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        let cancellation = context.cancellation_token.clone();
+        let updater = TaskUpdater::new(event_queue, context.task_id, context.context_id);
+        updater.start_work(None).await?;
+        tokio::select! {
+            _ = tokio::time::sleep(self.delay) => updater.complete_with_text("done").await,
+            _ = cancellation.cancelled() => Ok(()),
+        }
+    }
+
+    async fn cancel(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        TaskUpdater::new(event_queue, context.task_id, context.context_id)
+            .cancel(None)
+            .await
+    }
+}
+
+struct Server {
+    client: A2AClient,
+    store: Arc<InMemoryTaskStore>,
+    shutdown: oneshot::Sender<()>,
+    serving: JoinHandle<std::io::Result<()>>,
+}
+
+async fn start(delay: Duration, timeout: Duration) -> Server {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/a2a", listener.local_addr().unwrap());
+    let store = Arc::new(InMemoryTaskStore::new());
+    let (shutdown, signal) = oneshot::channel::<()>();
+    let serving = tokio::spawn(
+        ServerBuilder::new(Arc::new(DelayedAgent { delay }))
+            .with_agent_card_direct(common::test_agent_card(&url))
+            .with_task_store(store.clone())
+            .with_graceful_shutdown(async move {
+                let _ = signal.await;
+            })
+            .with_shutdown_timeout(timeout)
+            .serve(listener),
+    );
+    Server {
+        client: A2AClient::from_endpoint(&url),
+        store,
+        shutdown,
+        serving,
+    }
+}
+
+/// Wait for the stream's first event; returns its task ID.
+async fn started(stream: &mut SseStream) -> String {
+    let event = stream.next().await.unwrap().unwrap();
+    event.task_id().unwrap().to_string()
+}
+
+async fn last_state(mut stream: SseStream) -> TaskState {
+    let mut state = None;
+    while let Some(event) = stream.next().await {
+        if let StreamResponse::StatusUpdate(update) = event.unwrap() {
+            state = Some(update.status.state);
+        }
+    }
+    state.unwrap()
+}
+
+#[tokio::test]
+async fn test_running_tasks_finish_within_grace_period() {
+    let server = start(Duration::from_millis(200), Duration::from_secs(5)).await;
+
+    let mut stream = server.client.send_text_stream("hi").await.unwrap();
+    let task_id = started(&mut stream).await;
+    server.shutdown.send(()).unwrap();
+
+    assert_eq!(last_state(stream).await, TaskState::Completed);
+    tokio::time::timeout(Duration::from_secs(5), server.serving)
+        .await
+        .expect("server did not stop")
+        .unwrap()
+        .unwrap();
+    let task = server.store.get(&task_id).await.unwrap().unwrap();
+    assert_eq!(task.status.state, TaskState::Completed);
+}
+
+#[tokio::test]
+async fn test_tasks_still_running_after_grace_period_are_canceled() {
+    let server = start(Duration::from_secs(60), Duration::from_millis(100)).await;
+
+    let mut stream = server.client.send_text_stream("hi").await.unwrap();
+    let task_id = started(&mut stream).await;
+    server.shutdown.send(()).unwrap();
+
+    assert_eq!(last_state(stream).await, TaskState::Canceled);
+    tokio::time::timeout(Duration::from_secs(5), server.serving)
+        .await
+        .expect("server did not stop")
+        .unwrap()
+        .unwrap();
+    let task = server.store.get(&task_id).await.unwrap().unwrap();
+    assert_eq!(task.status.state, TaskState::Canceled);
+}
+
+#[tokio::test]
+async fn test_new_messages_are_refused_while_draining() {
+    let server = start(Duration::from_millis(300), Duration::from_secs(5)).await;
+
+    let mut stream = server.client.send_text_stream("first").await.unwrap();
+    started(&mut stream).await;
+    server.shutdown.send(()).unwrap();
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    let refused = server.client.send_text("second").await;
+    assert!(
+        matches!(refused, Err(ref e) if e.to_string().contains("shutting down")),
+        "{refused:?}"
+    );
+    assert_eq!(last_state(stream).await, TaskState::Completed);
+}
@@ -0,0 +1,170 @@
+//! `historyLength` on `message/send`, `tasks/get` and `tasks/list`, and
+//! `Task::with_history_limit`. Follows the Python SDK: the most recent N
+//! messages are kept, and 0 or an absent value leaves history untouched.
+
+mod common;
+
+use std::sync::Arc;
+
+use a2a_rs::server::{InMemoryTaskStore, TaskStore};
+use a2a_rs::types::*;
+use a2a_rs::utils::{apply_history_length, get_message_text};
+use serde_json::{json, Value};
+
+fn message(text: &str) -> Message {
+    Message {
+        message_id: uuid::Uuid::new_v4().to_string(),
+        role: Role::User,
+        parts: vec![Part::text(text)],
+        context_id: Some("ctx".to_string()),
+        task_id: None,
+        reference_task_ids: None,
+        metadata: None,
+        extensions: None,
+        kind: "message".to_string(),
+    }
+}
+
+/// A task with history `m0`..`m{count - 1}`.
+fn task(id: &str, state: TaskState, count: usize) -> Task {
+    Task {
+        id: id.to_string(),
+        context_id: "ctx".to_string(),
+        kind: "task".to_string(),
+        status: TaskStatus::new(state),
+        artifacts: None,
+        history: Some((0..count).map(|i| message(&format!("m{i}"))).collect()),
+        metadata: None,
+    }
+}
+
+fn texts(task: &Value) -> Vec<String> {
+    task["history"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|message| message["parts"][0]["text"].as_str().unwrap().to_string())
+        .collect()
+}
+
+/// Serve [`common::EchoAgent`] with `tasks` stored; returns the endpoint.
+async fn serve(tasks: Vec<Task>) -> String {
+    let store = Arc::new(InMemoryTaskStore::new());
+    for task in tasks {
+        store.save(task).await.unwrap();
+    }
+    let (base_url, _handle) =
+        common::start_test_server_with_store(Arc::new(common::EchoAgent), store).await;
+    format!("{base_url}/a2a")
+}
+
+async fn call(url: &str, method: &str, params: Value) -> Value {
+    let response: Value = reqwest::Client::new()
+        .post(url)
+        .json(&common::jsonrpc_request(json!(1), method, params))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert!(response.get("error").is_none(), "{response}");
+    response["result"].clone()
+}
+
+#[test]
+fn test_with_history_limit_keeps_the_tail() {
+    let trimmed = task("t", TaskState::Working, 5).with_history_limit(2);
+    let history = trimmed.history.unwrap();
+    assert_eq!(history.len(), 2);
+    let kept: Vec<_> = history.iter().map(|m| get_message_text(m, "")).collect();
+    assert_eq!(kept, ["m3", "m4"]);
+
+    let untouched = task("t", TaskState::Working, 2).with_history_limit(10);
+    assert_eq!(untouched.history.unwrap().len(), 2);
+
+    assert!(task("t", TaskState::Working, 3)
+        .with_history_limit(0)
+        .history
+        .unwrap()
+        .is_empty());
+
+    let mut no_history = task("t", TaskState::Working, 0);
+    no_history.history = None;
+    assert!(no_history.with_history_limit(1).history.is_none());
+}
+
+#[test]
+fn test_protocol_zero_and_none_mean_unlimited() {
+    let full = |n| {
+        apply_history_length(task("t", TaskState::Working, 4), n)
+            .history
+            .unwrap()
+            .len()
+    };
+    assert_eq!(full(None), 4);
+    assert_eq!(full(Some(0)), 4);
+    assert_eq!(full(Some(3)), 3);
+}
+
+#[tokio::test]
+async fn test_get_task_history_length() {
+    let url = serve(vec![task("t1", TaskState::Working, 5)]).await;
+
+    let result = call(&url, "tasks/get", json!({"id": "t1", "historyLength": 2})).await;
+    assert_eq!(texts(&result), ["m3", "m4"]);
+
+    for params in [json!({"id": "t1"}), json!({"id": "t1", "historyLength": 0})] {
+        let result = call(&url, "tasks/get", params).await;
+        assert_eq!(texts(&result).len(), 5);
+    }
+}
+
+#[tokio::test]
+async fn test_list_tasks_history_length() {
+    let url = serve(vec![
+        task("t1", TaskState::Working, 5),
+        task("t2", TaskState::Completed, 1),
+    ])
+    .await;
+
+    let result = call(&url, "tasks/list", json!({"historyLength": 3})).await;
+    let tasks = result["tasks"].as_array().unwrap();
+    assert_eq!(tasks.len(), 2);
+    for task in tasks {
+        let expected: &[&str] = match task["id"].as_str().unwrap() {
+            "t1" => &["m2", "m3", "m4"],
+            _ => &["m0"],
+        };
+        assert_eq!(texts(task), expected);
+    }
+
+    let result = call(&url, "tasks/list", json!({})).await;
+    let lengths: usize = result["tasks"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|task| texts(task).len())
+        .sum();
+    assert_eq!(lengths, 6);
+}
+
+#[tokio::test]
+async fn test_send_message_history_length() {
+    let url = serve(vec![task("t1", TaskState::InputRequired, 4)]).await;
+
+    let mut followup = message("more");
+    followup.task_id = Some("t1".to_string());
+    let result = call(
+        &url,
+        "message/send",
+        json!({"message": followup, "configuration": {"historyLength": 2}}),
+    )
+    .await;
+    assert_eq!(result["status"]["state"], "completed");
+    assert_eq!(texts(&result), ["more", "Echo: more"]);
+
+    // The stored task keeps its full history.
+    let result = call(&url, "tasks/get", json!({"id": "t1"})).await;
+    assert_eq!(texts(&result).len(), 6);
+}
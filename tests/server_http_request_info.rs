@@ -0,0 +1,146 @@
+//! Executor access to HTTP request details (`HttpRequestInfo`).
+
+mod common;
+
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+
+use a2a_rs::builders::ServerBuilder;
+use a2a_rs::error::A2AResult;
+use a2a_rs::server::{
+    a2a_router, AgentExecutor, DefaultRequestHandler, EventQueue, HttpRequestInfo,
+    InMemoryTaskStore, RequestContext, TaskUpdater,
+};
+use async_trait::async_trait;
+
+/// Records the HTTP info each execution sees.
+#[derive(Default)]
+struct RecordingAgent {
+    seen: Mutex<Vec<Option<HttpRequestInfo>>>,
+}
+
+#[async_trait]
+impl AgentExecutor for RecordingAgent {
+    async fn execute(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        self.seen
+            .lock()
+            .unwrap()
+            .push(context.http_request().cloned());
+        TaskUpdater::new(event_queue, context.task_id, context.context_id)
+            .complete_with_text("ok")
+            .await
+    }
+
+    async fn cancel(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        TaskUpdater::new(event_queue, context.task_id, context.context_id)
+            .cancel(None)
+            .await
+    }
+}
+
+async fn serve(app: axum::Router, connect_info: bool) -> String {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/a2a", listener.local_addr().unwrap());
+    tokio::spawn(async move {
+        if connect_info {
+            axum::serve(
+                listener,
+                app.into_make_service_with_connect_info::<SocketAddr>(),
+            )
+            .await
+            .unwrap();
+        } else {
+            axum::serve(listener, app).await.unwrap();
+        }
+    });
+    url
+}
+
+async fn send(url: &str) {
+    let response = reqwest::Client::new()
+        .post(url)
+        .header("user-agent", "recording-test/1.0")
+        .header("x-tenant", "acme")
+        .header("authorization", "Bearer secret")
+        .json(&common::message_send_request(1, "hi"))
+        .send()
+        .await
+        .unwrap();
+    assert!(response.status().is_success());
+}
+
+fn last_seen(agent: &RecordingAgent) -> HttpRequestInfo {
+    agent
+        .seen
+        .lock()
+        .unwrap()
+        .last()
+        .cloned()
+        .expect("executor ran")
+        .expect("http info present")
+}
+
+#[tokio::test]
+async fn test_builder_captures_allow_listed_headers_and_peer() {
+    let agent = Arc::new(RecordingAgent::default());
+    let app = ServerBuilder::new(agent.clone())
+        .with_captured_headers(["User-Agent", "x-tenant"])
+        .build();
+    let url = serve(app, true).await;
+
+    send(&url).await;
+
+    let info = last_seen(&agent);
+    assert_eq!(info.method, "POST");
+    assert_eq!(info.path, "/a2a");
+    assert_eq!(info.user_agent(), Some("recording-test/1.0"));
+    assert_eq!(info.header("X-Tenant"), Some("acme"));
+    assert_eq!(info.header("authorization"), None);
+    assert_eq!(info.headers.len(), 2);
+    assert!(info.peer_addr.unwrap().ip().is_loopback());
+}
+
+#[tokio::test]
+async fn test_default_router_captures_no_headers() {
+    let agent = Arc::new(RecordingAgent::default());
+    let handler = Arc::new(DefaultRequestHandler::new(
+        agent.clone(),
+        Arc::new(InMemoryTaskStore::new()),
+    ));
+    let app = a2a_router(handler, common::test_agent_card("http://localhost/a2a"));
+    let url = serve(app, false).await;
+
+    send(&url).await;
+
+    let info = last_seen(&agent);
+    assert_eq!(info.method, "POST");
+    assert!(info.headers.is_empty());
+    assert_eq!(info.peer_addr, None, "no connect info without the service");
+}
+
+#[tokio::test]
+async fn test_direct_handler_calls_have_no_http_info() {
+    let agent = Arc::new(RecordingAgent::default());
+    let handler = DefaultRequestHandler::new(agent.clone(), Arc::new(InMemoryTaskStore::new()));
+
+    a2a_rs::server::RequestHandler::on_message_send(
+        &handler,
+        a2a_rs::server::SendMessageParams {
+            message: a2a_rs::types::Message::user("m1", "hi"),
+            configuration: None,
+            metadata: None,
+            tenant: None,
+        },
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(agent.seen.lock().unwrap().as_slice(), &[None]);
+}
+
+#[test]
+fn test_invalid_captured_header_is_rejected() {
+    assert!(a2a_rs::server::RouterConfig::default()
+        .with_captured_header("bad header")
+        .is_err());
+}
@@ -0,0 +1,155 @@
+//! Expiry of tasks left waiting in `input-required`.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use a2a_rs::error::A2AResult;
+use a2a_rs::server::{
+    AgentExecutor, DefaultRequestHandler, EventQueue, ExpiryOutcome, InMemoryTaskStore,
+    InputRequiredExpiry, RequestContext, RequestHandler, SendMessageParams, TaskStore, TaskUpdater,
+};
+use a2a_rs::types::*;
+use async_trait::async_trait;
+
+/// Asks for input on the first message of a task, completes on the next.
+struct AskingAgent;
+
+#[async_trait]
+impl AgentExecutor for AskingAgent {
+    async fn execute(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        let resumed = context
+            .task
+            .as_ref()
+            .is_some_and(|t| t.status.state == TaskState::InputRequired);
+        let updater = TaskUpdater::new(event_queue, context.task_id, context.context_id);
+        if resumed {
+            updater.complete(None).await
+        } else {
+            updater.requires_input(None, true).await
+        }
+    }
+
+    async fn cancel(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        TaskUpdater::new(event_queue, context.task_id, context.context_id)
+            .cancel(None)
+            .await
+    }
+}
+
+fn params(message: Message) -> SendMessageParams {
+    SendMessageParams {
+        message,
+        configuration: None,
+        metadata: None,
+        tenant: None,
+    }
+}
+
+fn task_of(response: SendMessageResponse) -> Task {
+    match response {
+        SendMessageResponse::Task(task) => task,
+        SendMessageResponse::Message(_) => panic!("expected task"),
+    }
+}
+
+fn handler(store: Arc<InMemoryTaskStore>, policy: InputRequiredExpiry) -> DefaultRequestHandler {
+    DefaultRequestHandler::new(Arc::new(AskingAgent), store).with_input_required_expiry(policy)
+}
+
+#[tokio::test]
+async fn test_waiting_task_fails_after_timeout_and_calls_back() {
+    let store = Arc::new(InMemoryTaskStore::new());
+    let expired = Arc::new(AtomicUsize::new(0));
+    let policy = InputRequiredExpiry::new(Duration::from_millis(50)).on_expire({
+        let expired = expired.clone();
+        move |task: &Task| {
+            assert_eq!(task.status.state, TaskState::Failed);
+            expired.fetch_add(1, Ordering::SeqCst);
+        }
+    });
+    let handler = handler(store.clone(), policy);
+
+    let task = task_of(
+        handler
+            .on_message_send(params(Message::user("m1", "hi")))
+            .await
+            .unwrap(),
+    );
+    assert_eq!(task.status.state, TaskState::InputRequired);
+
+    tokio::time::sleep(Duration::from_millis(150)).await;
+    let stored = store.get(&task.id).await.unwrap().unwrap();
+    assert_eq!(stored.status.state, TaskState::Failed);
+    assert_eq!(expired.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_stream_subscribers_receive_final_update() {
+    let store = Arc::new(InMemoryTaskStore::new());
+    let policy =
+        InputRequiredExpiry::new(Duration::from_millis(30)).with_outcome(ExpiryOutcome::Canceled);
+    let handler = handler(store, policy);
+
+    let mut rx = handler
+        .on_message_send_stream(params(Message::user("m1", "hi")))
+        .await
+        .unwrap();
+    let mut states = Vec::new();
+    while let Ok(Ok(event)) = tokio::time::timeout(Duration::from_secs(1), rx.recv()).await {
+        if let StreamResponse::StatusUpdate(update) = event {
+            states.push((update.status.state, update.r#final));
+            if update.status.state == TaskState::Canceled {
+                break;
+            }
+        }
+    }
+    assert_eq!(
+        states,
+        vec![
+            (TaskState::InputRequired, true),
+            (TaskState::Canceled, true)
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_reply_before_timeout_prevents_expiry() {
+    let store = Arc::new(InMemoryTaskStore::new());
+    let handler = handler(
+        store.clone(),
+        InputRequiredExpiry::new(Duration::from_millis(80)),
+    );
+
+    let task = task_of(
+        handler
+            .on_message_send(params(Message::user("m1", "hi")))
+            .await
+            .unwrap(),
+    );
+    let mut reply = Message::user("m2", "yes");
+    reply.task_id = Some(task.id.clone());
+    reply.context_id = Some(task.context_id.clone());
+    let resumed = task_of(handler.on_message_send(params(reply)).await.unwrap());
+    assert_eq!(resumed.status.state, TaskState::Completed);
+
+    tokio::time::sleep(Duration::from_millis(150)).await;
+    let stored = store.get(&task.id).await.unwrap().unwrap();
+    assert_eq!(stored.status.state, TaskState::Completed);
+}
+
+#[tokio::test]
+async fn test_no_expiry_by_default() {
+    let store = Arc::new(InMemoryTaskStore::new());
+    let handler = DefaultRequestHandler::new(Arc::new(AskingAgent), store.clone());
+
+    let task = task_of(
+        handler
+            .on_message_send(params(Message::user("m1", "hi")))
+            .await
+            .unwrap(),
+    );
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    let stored = store.get(&task.id).await.unwrap().unwrap();
+    assert_eq!(stored.status.state, TaskState::InputRequired);
+}
@@ -0,0 +1,199 @@
+//! `ServerInterceptor`: hooks before and after every method of
+//! `DefaultRequestHandler`, for audit logging, quotas and rejections.
+
+mod common;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use a2a_rs::error::{A2AError, A2AResult};
+use a2a_rs::server::{
+    DefaultRequestHandler, GetTaskParams, InMemoryTaskStore, InterceptedRequest, RequestHandler,
+    ServerInterceptor,
+};
+use async_trait::async_trait;
+use serde_json::{json, Value};
+
+type Log = Arc<Mutex<Vec<String>>>;
+
+/// Logs `before <method> <task>` and `after <method> ok|<error>`.
+struct AuditLog {
+    name: &'static str,
+    log: Log,
+}
+
+#[async_trait]
+impl ServerInterceptor for AuditLog {
+    async fn before(&self, request: &InterceptedRequest<'_>) -> A2AResult<()> {
+        let transport = if request.call_context.is_some() {
+            "http"
+        } else {
+            "direct"
+        };
+        self.log.lock().unwrap().push(format!(
+            "{} before {} {} {transport}",
+            self.name,
+            request.method,
+            request.params.task_id().unwrap_or("-"),
+        ));
+        Ok(())
+    }
+
+    async fn after(&self, request: &InterceptedRequest<'_>, outcome: Result<(), &A2AError>) {
+        let outcome = match outcome {
+            Ok(()) => "ok".to_string(),
+            Err(e) => e.to_string(),
+        };
+        self.log
+            .lock()
+            .unwrap()
+            .push(format!("{} after {} {outcome}", self.name, request.method));
+    }
+}
+
+/// Allows `limit` messages, then rejects `message/send`.
+struct Quota {
+    limit: usize,
+    used: AtomicUsize,
+}
+
+#[async_trait]
+impl ServerInterceptor for Quota {
+    async fn before(&self, request: &InterceptedRequest<'_>) -> A2AResult<()> {
+        if request.method == "message/send"
+            && self.used.fetch_add(1, Ordering::SeqCst) >= self.limit
+        {
+            return Err(A2AError::invalid_request("message quota exhausted"));
+        }
+        Ok(())
+    }
+}
+
+fn entries(log: &Log) -> Vec<String> {
+    std::mem::take(&mut *log.lock().unwrap())
+}
+
+/// Serve `handler` over HTTP; returns the endpoint URL.
+async fn serve(handler: Arc<DefaultRequestHandler>) -> String {
+    let (base_url, _server) =
+        common::start_test_server_with_handler(handler, common::test_agent_card).await;
+    format!("{base_url}/a2a")
+}
+
+async fn call(url: &str, method: &str, params: Value) -> Value {
+    reqwest::Client::new()
+        .post(url)
+        .json(&common::jsonrpc_request(json!(1), method, params))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap()
+}
+
+fn send_params(text: &str) -> Value {
+    json!({"message": {"kind": "message", "messageId": uuid::Uuid::new_v4().to_string(),
+                       "role": "user", "parts": [{"kind": "text", "text": text}]}})
+}
+
+#[tokio::test]
+async fn test_hooks_wrap_every_method_in_chain_order() {
+    let log = Log::default();
+    let handler = Arc::new(
+        DefaultRequestHandler::new(
+            Arc::new(common::EchoAgent),
+            Arc::new(InMemoryTaskStore::new()),
+        )
+        .with_interceptor(Arc::new(AuditLog {
+            name: "outer",
+            log: log.clone(),
+        }))
+        .with_interceptor(Arc::new(AuditLog {
+            name: "inner",
+            log: log.clone(),
+        })),
+    );
+    let url = serve(handler.clone()).await;
+
+    let response = call(&url, "message/send", send_params("hi")).await;
+    let task_id = response["result"]["id"].as_str().unwrap().to_string();
+    assert_eq!(
+        entries(&log),
+        [
+            "outer before message/send - http",
+            "inner before message/send - http",
+            "inner after message/send ok",
+            "outer after message/send ok",
+        ]
+    );
+
+    // Aliases are reported under the canonical name.
+    call(&url, "GetTask", json!({"id": task_id})).await;
+    let log_entries = entries(&log);
+    assert_eq!(
+        log_entries[0],
+        format!("outer before tasks/get {task_id} http")
+    );
+
+    // Failures reach `after`; direct calls have no call context.
+    let err = handler
+        .on_get_task(GetTaskParams {
+            id: "missing".to_string(),
+            history_length: None,
+            metadata: None,
+            tenant: None,
+            consistency: None,
+        })
+        .await
+        .unwrap_err();
+    let log_entries = entries(&log);
+    assert_eq!(log_entries[0], "outer before tasks/get missing direct");
+    assert_eq!(log_entries[3], format!("outer after tasks/get {err}"));
+}
+
+#[tokio::test]
+async fn test_rejection_stops_the_request() {
+    let log = Log::default();
+    let handler = Arc::new(
+        DefaultRequestHandler::new(
+            Arc::new(common::EchoAgent),
+            Arc::new(InMemoryTaskStore::new()),
+        )
+        .with_interceptor(Arc::new(AuditLog {
+            name: "audit",
+            log: log.clone(),
+        }))
+        .with_interceptor(Arc::new(Quota {
+            limit: 1,
+            used: AtomicUsize::new(0),
+        }))
+        .with_interceptor(Arc::new(AuditLog {
+            name: "unreached",
+            log: log.clone(),
+        })),
+    );
+    let url = serve(handler).await;
+
+    let response = call(&url, "message/send", send_params("first")).await;
+    assert_eq!(response["result"]["status"]["state"], "completed");
+    entries(&log);
+
+    let response = call(&url, "message/send", send_params("second")).await;
+    assert_eq!(response["error"]["code"], -32600, "{response}");
+    assert_eq!(
+        response["error"]["message"],
+        "Invalid request: message quota exhausted"
+    );
+    assert_eq!(
+        entries(&log),
+        [
+            "audit before message/send - http",
+            "audit after message/send Invalid request: message quota exhausted",
+        ]
+    );
+
+    // Other methods still pass.
+    let response = call(&url, "tasks/list", json!({})).await;
+    assert!(response["result"].is_object(), "{response}");
+}
@@ -0,0 +1,265 @@
+//! `KvStore` backends and the stores built on them: `KvTaskStore`,
+//! `KvSubscriptionRegistry` and message dedup shared through a store.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use a2a_rs::error::A2AResult;
+use a2a_rs::server::{
+    AgentExecutor, DefaultRequestHandler, EventQueue, InMemoryKvStore, KvStore,
+    KvSubscriptionRegistry, KvTaskStore, RequestContext, RequestHandler, SendMessageParams,
+    SubscriptionRecord, SubscriptionRegistry, TaskListParams, TaskStore, TaskUpdater,
+};
+use a2a_rs::types::*;
+use async_trait::async_trait;
+
+fn make_task(id: &str, ctx: &str, state: TaskState) -> Task {
+    Task {
+        id: id.to_string(),
+        context_id: ctx.to_string(),
+        kind: "task".to_string(),
+        status: TaskStatus::new(state),
+        artifacts: None,
+        history: None,
+        metadata: None,
+    }
+}
+
+/// Exercise the `KvStore` contract against `store`.
+async fn check_kv_contract(store: &dyn KvStore) {
+    assert_eq!(store.get("a/1").await.unwrap(), None);
+    store.put("a/2", b"two".to_vec()).await.unwrap();
+    store.put("a/1", b"one".to_vec()).await.unwrap();
+    store.put("b/1", b"other".to_vec()).await.unwrap();
+    store.put("a/1", b"uno".to_vec()).await.unwrap();
+    assert_eq!(store.get("a/1").await.unwrap(), Some(b"uno".to_vec()));
+
+    let entries = store.scan_prefix("a/").await.unwrap();
+    assert_eq!(
+        entries,
+        vec![
+            ("a/1".to_string(), b"uno".to_vec()),
+            ("a/2".to_string(), b"two".to_vec()),
+        ]
+    );
+
+    store.delete("a/1").await.unwrap();
+    store.delete("a/1").await.unwrap();
+    assert_eq!(store.get("a/1").await.unwrap(), None);
+    assert_eq!(store.scan_prefix("a/").await.unwrap().len(), 1);
+    assert!(store.scan_prefix("c/").await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_in_memory_kv_contract() {
+    check_kv_contract(&InMemoryKvStore::new()).await;
+}
+
+#[tokio::test]
+async fn test_kv_task_store_round_trip() {
+    let store = KvTaskStore::new(Arc::new(InMemoryKvStore::new()));
+    let task = make_task("t1", "c1", TaskState::Working);
+    store.save(task.clone()).await.unwrap();
+    assert_eq!(store.get("t1").await.unwrap().unwrap().context_id, "c1");
+
+    store.delete("t1").await.unwrap();
+    assert!(store.get("t1").await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_kv_task_store_list_filters_and_pages() {
+    let store = KvTaskStore::new(Arc::new(InMemoryKvStore::new()));
+    for (id, ctx, state) in [
+        ("t1", "c1", TaskState::Working),
+        ("t2", "c2", TaskState::Working),
+        ("t3", "c1", TaskState::Completed),
+        ("t4", "c1", TaskState::Working),
+        ("t5", "c1", TaskState::Working),
+    ] {
+        store.save(make_task(id, ctx, state)).await.unwrap();
+    }
+
+    let params = TaskListParams {
+        context_id: Some("c1".to_string()),
+        status: Some(vec![TaskState::Working]),
+        page_size: Some(2),
+        page_token: None,
+        history_length: None,
+        tenant: None,
+    };
+    let first = store.list(&params).await.unwrap();
+    let ids: Vec<_> = first.tasks.iter().map(|t| t.id.as_str()).collect();
+    assert_eq!(ids, ["t1", "t4"]);
+    assert_eq!(first.next_page_token.as_deref(), Some("t4"));
+
+    let second = store
+        .list(&TaskListParams {
+            page_token: first.next_page_token,
+            ..params
+        })
+        .await
+        .unwrap();
+    let ids: Vec<_> = second.tasks.iter().map(|t| t.id.as_str()).collect();
+    assert_eq!(ids, ["t5"]);
+    assert!(second.next_page_token.is_none());
+}
+
+#[tokio::test]
+async fn test_stores_share_one_backend_without_clashing() {
+    let kv: Arc<dyn KvStore> = Arc::new(InMemoryKvStore::new());
+    let tasks = KvTaskStore::new(kv.clone());
+    let registry = KvSubscriptionRegistry::new(kv.clone());
+
+    tasks
+        .save(make_task("t1", "c1", TaskState::Working))
+        .await
+        .unwrap();
+    registry
+        .save(SubscriptionRecord {
+            task_id: "t1".to_string(),
+            context_id: "c1".to_string(),
+            last_sequence: 3,
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(kv.scan_prefix("task/").await.unwrap().len(), 1);
+    assert_eq!(kv.scan_prefix("subscription/").await.unwrap().len(), 1);
+    assert_eq!(registry.list().await.unwrap()[0].task_id, "t1");
+    assert_eq!(
+        tasks
+            .list(&TaskListParams::default())
+            .await
+            .unwrap()
+            .tasks
+            .len(),
+        1
+    );
+
+    registry.remove("t1").await.unwrap();
+    assert!(registry.list().await.unwrap().is_empty());
+    assert!(tasks.get("t1").await.unwrap().is_some());
+}
+
+/// Completes immediately, counting executions.
+#[derive(Default)]
+struct CountingAgent {
+    runs: AtomicUsize,
+}
+
+#[async_trait]
+impl AgentExecutor for CountingAgent {
+    async fn execute(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        self.runs.fetch_add(1, Ordering::SeqCst);
+        TaskUpdater::new(event_queue, context.task_id, context.context_id)
+            .complete_with_text("done")
+            .await
+    }
+
+    async fn cancel(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        TaskUpdater::new(event_queue, context.task_id, context.context_id)
+            .cancel(None)
+            .await
+    }
+}
+
+fn params(message_id: &str) -> SendMessageParams {
+    let mut message = Message::user(message_id, "hi");
+    message.context_id = Some("c1".to_string());
+    SendMessageParams {
+        message,
+        configuration: None,
+        metadata: None,
+        tenant: None,
+    }
+}
+
+fn task_of(response: SendMessageResponse) -> Task {
+    match response {
+        SendMessageResponse::Task(task) => task,
+        other => panic!("expected a task, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn test_dedup_store_is_shared_between_handlers() {
+    let kv: Arc<dyn KvStore> = Arc::new(InMemoryKvStore::new());
+    let tasks = Arc::new(KvTaskStore::new(kv.clone()));
+    let agent = Arc::new(CountingAgent::default());
+    let replica = || {
+        DefaultRequestHandler::new(agent.clone(), tasks.clone())
+            .with_message_dedup_store(Duration::from_secs(60), kv.clone())
+    };
+
+    let first = task_of(replica().on_message_send(params("m1")).await.unwrap());
+    // A second handler, e.g. after a restart, recognises the duplicate.
+    let again = task_of(replica().on_message_send(params("m1")).await.unwrap());
+    assert_eq!(again.id, first.id);
+    assert_eq!(agent.runs.load(Ordering::SeqCst), 1);
+    assert_eq!(kv.scan_prefix("dedup/").await.unwrap().len(), 1);
+
+    let other = task_of(replica().on_message_send(params("m2")).await.unwrap());
+    assert_ne!(other.id, first.id);
+    assert_eq!(agent.runs.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_stale_dedup_records_are_ignored() {
+    let kv: Arc<dyn KvStore> = Arc::new(InMemoryKvStore::new());
+    let tasks = Arc::new(KvTaskStore::new(kv.clone()));
+    let agent = Arc::new(CountingAgent::default());
+    let replica = || {
+        DefaultRequestHandler::new(agent.clone(), tasks.clone())
+            .with_message_dedup_store(Duration::from_millis(20), kv.clone())
+    };
+
+    let first = task_of(replica().on_message_send(params("m1")).await.unwrap());
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    let later = task_of(replica().on_message_send(params("m1")).await.unwrap());
+    assert_ne!(later.id, first.id);
+    assert_eq!(agent.runs.load(Ordering::SeqCst), 2);
+}
+
+#[cfg(feature = "kv-sled")]
+#[tokio::test]
+async fn test_sled_kv_contract_and_reopen() {
+    use a2a_rs::server::SledKvStore;
+
+    let dir = std::env::temp_dir().join(format!("a2a-kv-{}", uuid::Uuid::new_v4()));
+    {
+        let store = SledKvStore::open(&dir).unwrap();
+        check_kv_contract(&store).await;
+        let tasks = KvTaskStore::new(Arc::new(store.clone()));
+        tasks
+            .save(make_task("t1", "c1", TaskState::Working))
+            .await
+            .unwrap();
+        store.flush().await.unwrap();
+    }
+
+    let tasks = KvTaskStore::new(Arc::new(SledKvStore::open(&dir).unwrap()));
+    assert_eq!(tasks.get("t1").await.unwrap().unwrap().context_id, "c1");
+    drop(tasks);
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+/// Runs only when `A2A_TEST_REDIS_URL` points at a Redis server.
+#[cfg(feature = "kv-redis")]
+#[tokio::test]
+async fn test_redis_kv_contract() {
+    use a2a_rs::server::RedisKvStore;
+
+    let Ok(url) = std::env::var("A2A_TEST_REDIS_URL") else {
+        return;
+    };
+    let prefix = format!("a2a-test-{}:", uuid::Uuid::new_v4());
+    let store = RedisKvStore::connect(&url)
+        .await
+        .unwrap()
+        .with_key_prefix(prefix);
+    check_kv_contract(&store).await;
+    for (key, _) in store.scan_prefix("").await.unwrap() {
+        store.delete(&key).await.unwrap();
+    }
+}
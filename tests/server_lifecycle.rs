@@ -0,0 +1,203 @@
+//! `TaskLifecycleListener`: callbacks on task creation, state changes,
+//! added artifacts and terminal states, delivered in order per listener
+//! and isolated from failing listeners.
+
+mod common;
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use a2a_rs::client::A2AClient;
+use a2a_rs::error::{A2AError, A2AResult};
+use a2a_rs::server::{
+    AgentExecutor, DefaultRequestHandler, EventQueue, InMemoryTaskStore, RequestContext,
+    RequestHandler, SendMessageParams, TaskEvent, TaskLifecycleListener, TaskLifecycleListeners,
+    TaskManager, TaskUpdater,
+};
+use a2a_rs::types::*;
+use a2a_rs::ServerBuilder;
+use async_trait::async_trait;
+use common::EchoAgent;
+
+/// Starts work, adds a `report` artifact, then completes.
+struct ReportAgent;
+
+#[async_trait]
+impl AgentExecutor for ReportAgent {
+    async fn execute(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        let updater = TaskUpdater::new(event_queue, context.task_id, context.context_id);
+        updater.start_work(None).await?;
+        updater
+            .add_artifact(
+                vec![Part::text("all good")],
+                None,
+                Some("report".to_string()),
+                None,
+                None,
+                None,
+                None,
+            )
+            .await?;
+        updater.complete_with_text("done").await
+    }
+
+    async fn cancel(&self, _context: RequestContext, _event_queue: EventQueue) -> A2AResult<()> {
+        Ok(())
+    }
+}
+
+/// Records every callback as a short string.
+#[derive(Default)]
+struct Recorder(Mutex<Vec<String>>);
+
+impl Recorder {
+    /// The recorded callbacks, once `count` have arrived.
+    async fn wait_for(&self, count: usize) -> Vec<String> {
+        for _ in 0..200 {
+            let calls = self.0.lock().unwrap().clone();
+            if calls.len() >= count {
+                return calls;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        panic!(
+            "expected {count} callbacks, got {:?}",
+            self.0.lock().unwrap()
+        );
+    }
+}
+
+#[async_trait]
+impl TaskLifecycleListener for Recorder {
+    async fn on_task_created(&self, task: &Task) -> A2AResult<()> {
+        let call = format!("created {}", task.status.state);
+        self.0.lock().unwrap().push(call);
+        Ok(())
+    }
+
+    async fn on_state_changed(&self, task: &Task, from: TaskState) -> A2AResult<()> {
+        let call = format!("{from} -> {}", task.status.state);
+        self.0.lock().unwrap().push(call);
+        Ok(())
+    }
+
+    async fn on_artifact_added(
+        &self,
+        _task: &Task,
+        event: &TaskArtifactUpdateEvent,
+    ) -> A2AResult<()> {
+        let name = event.artifact.name.as_deref().unwrap_or_default();
+        self.0.lock().unwrap().push(format!("artifact {name}"));
+        Ok(())
+    }
+
+    async fn on_terminal(&self, task: &Task) -> A2AResult<()> {
+        let call = format!("terminal {}", task.status.state);
+        self.0.lock().unwrap().push(call);
+        Ok(())
+    }
+}
+
+/// Fails one callback and panics in another.
+struct Faulty;
+
+#[async_trait]
+impl TaskLifecycleListener for Faulty {
+    async fn on_task_created(&self, _task: &Task) -> A2AResult<()> {
+        Err(A2AError::internal_error("billing unavailable"))
+    }
+
+    async fn on_state_changed(&self, _task: &Task, _from: TaskState) -> A2AResult<()> {
+        panic!("listener bug");
+    }
+}
+
+fn send(text: &str) -> SendMessageParams {
+    SendMessageParams {
+        message: Message::user("m1", text),
+        configuration: None,
+        metadata: None,
+        tenant: None,
+    }
+}
+
+#[tokio::test]
+async fn test_listener_sees_the_task_lifecycle() {
+    let recorder = Arc::new(Recorder::default());
+    let handler =
+        DefaultRequestHandler::new(Arc::new(ReportAgent), Arc::new(InMemoryTaskStore::new()))
+            .with_lifecycle_listener(Arc::new(Faulty))
+            .with_lifecycle_listener(recorder.clone());
+
+    handler
+        .on_message_send(send("report please"))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        recorder.wait_for(5).await,
+        [
+            "created submitted",
+            "submitted -> working",
+            "artifact report",
+            "working -> completed",
+            "terminal completed",
+        ]
+    );
+
+    // The failing listener keeps its queue: later tasks still reach both.
+    handler.on_message_send(send("again")).await.unwrap();
+    assert_eq!(recorder.wait_for(10).await.len(), 10);
+}
+
+#[tokio::test]
+async fn test_task_manager_reports_tasks_it_creates() {
+    let recorder = Arc::new(Recorder::default());
+    let listeners = TaskLifecycleListeners::new().with(recorder.clone());
+    let mut manager = TaskManager::new(None, None, Box::new(InMemoryTaskStore::new()), None)
+        .unwrap()
+        .with_listeners(listeners);
+
+    let status = TaskStatus::new(TaskState::Failed);
+    manager
+        .save_task_event(TaskEvent::StatusUpdate(TaskStatusUpdateEvent {
+            task_id: "t1".to_string(),
+            context_id: "c1".to_string(),
+            kind: "status-update".to_string(),
+            status,
+            r#final: true,
+            metadata: None,
+        }))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        recorder.wait_for(2).await,
+        ["created failed", "terminal failed"]
+    );
+}
+
+#[tokio::test]
+async fn test_server_builder_registers_listeners() {
+    let recorder = Arc::new(Recorder::default());
+    let (base_url, _server) = common::serve_router(|_| {
+        ServerBuilder::new(Arc::new(EchoAgent))
+            .with_listener(recorder.clone())
+            .build()
+    })
+    .await;
+
+    A2AClient::from_endpoint(&format!("{base_url}/a2a"))
+        .send_text("hello")
+        .await
+        .unwrap();
+
+    assert_eq!(
+        recorder.wait_for(3).await,
+        [
+            "created submitted",
+            "submitted -> completed",
+            "terminal completed"
+        ]
+    );
+}
@@ -0,0 +1,170 @@
+//! Duplicate `(contextId, messageId)` detection in `DefaultRequestHandler`.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use a2a_rs::error::A2AResult;
+use a2a_rs::server::{
+    AgentExecutor, DefaultRequestHandler, EventQueue, InMemoryTaskStore, RequestContext,
+    RequestHandler, SendMessageParams, TaskUpdater,
+};
+use a2a_rs::types::*;
+use async_trait::async_trait;
+
+/// Completes after a short delay, counting executions.
+#[derive(Default)]
+struct CountingAgent {
+    runs: AtomicUsize,
+}
+
+#[async_trait]
+impl AgentExecutor for CountingAgent {
+    async fn execute(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        self.runs.fetch_add(1, Ordering::SeqCst);
+        let updater = TaskUpdater::new(event_queue, context.task_id, context.context_id);
+        updater.start_work(None).await?;
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        updater.complete_with_text("done").await
+    }
+
+    async fn cancel(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        TaskUpdater::new(event_queue, context.task_id, context.context_id)
+            .cancel(None)
+            .await
+    }
+}
+
+fn params(message_id: &str, context_id: Option<&str>) -> SendMessageParams {
+    let mut message = Message::user(message_id, "hi");
+    message.context_id = context_id.map(String::from);
+    SendMessageParams {
+        message,
+        configuration: None,
+        metadata: None,
+        tenant: None,
+    }
+}
+
+fn handler(agent: Arc<CountingAgent>, window: Option<Duration>) -> DefaultRequestHandler {
+    let handler = DefaultRequestHandler::new(agent, Arc::new(InMemoryTaskStore::new()));
+    match window {
+        Some(window) => handler.with_message_dedup(window),
+        None => handler,
+    }
+}
+
+fn task_of(response: SendMessageResponse) -> Task {
+    match response {
+        SendMessageResponse::Task(task) => task,
+        SendMessageResponse::Message(_) => panic!("expected task"),
+    }
+}
+
+#[tokio::test]
+async fn test_duplicate_send_returns_original_task() {
+    let agent = Arc::new(CountingAgent::default());
+    let handler = handler(agent.clone(), Some(Duration::from_secs(60)));
+
+    let first = task_of(
+        handler
+            .on_message_send(params("m1", Some("c1")))
+            .await
+            .unwrap(),
+    );
+    let second = task_of(
+        handler
+            .on_message_send(params("m1", Some("c1")))
+            .await
+            .unwrap(),
+    );
+
+    assert_eq!(first.id, second.id);
+    assert_eq!(second.status.state, TaskState::Completed);
+    assert_eq!(agent.runs.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_concurrent_duplicates_create_one_task() {
+    let agent = Arc::new(CountingAgent::default());
+    let handler = Arc::new(handler(agent.clone(), Some(Duration::from_secs(60))));
+
+    let sends = (0..4).map(|_| {
+        let handler = handler.clone();
+        tokio::spawn(async move { handler.on_message_send(params("m1", None)).await })
+    });
+    let mut ids = Vec::new();
+    for send in sends {
+        ids.push(task_of(send.await.unwrap().unwrap()).id);
+    }
+
+    ids.dedup();
+    assert_eq!(ids.len(), 1);
+    assert_eq!(agent.runs.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_key_includes_context_id() {
+    let agent = Arc::new(CountingAgent::default());
+    let handler = handler(agent.clone(), Some(Duration::from_secs(60)));
+
+    let a = task_of(
+        handler
+            .on_message_send(params("m1", Some("c1")))
+            .await
+            .unwrap(),
+    );
+    let b = task_of(
+        handler
+            .on_message_send(params("m1", Some("c2")))
+            .await
+            .unwrap(),
+    );
+
+    assert_ne!(a.id, b.id);
+    assert_eq!(agent.runs.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_duplicates_after_window_are_new_tasks() {
+    let agent = Arc::new(CountingAgent::default());
+    let handler = handler(agent.clone(), Some(Duration::from_millis(20)));
+
+    let a = task_of(handler.on_message_send(params("m1", None)).await.unwrap());
+    tokio::time::sleep(Duration::from_millis(40)).await;
+    let b = task_of(handler.on_message_send(params("m1", None)).await.unwrap());
+
+    assert_ne!(a.id, b.id);
+}
+
+#[tokio::test]
+async fn test_dedup_disabled_by_default() {
+    let agent = Arc::new(CountingAgent::default());
+    let handler = handler(agent.clone(), None);
+
+    let a = task_of(handler.on_message_send(params("m1", None)).await.unwrap());
+    let b = task_of(handler.on_message_send(params("m1", None)).await.unwrap());
+
+    assert_ne!(a.id, b.id);
+    assert_eq!(agent.runs.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_duplicate_stream_replays_completed_task() {
+    let agent = Arc::new(CountingAgent::default());
+    let handler = handler(agent.clone(), Some(Duration::from_secs(60)));
+
+    let original = task_of(handler.on_message_send(params("m1", None)).await.unwrap());
+
+    let mut rx = handler
+        .on_message_send_stream(params("m1", None))
+        .await
+        .unwrap();
+    let StreamResponse::Task(task) = rx.recv().await.unwrap() else {
+        panic!("expected task snapshot");
+    };
+    assert_eq!(task.id, original.id);
+    assert_eq!(task.status.state, TaskState::Completed);
+    assert!(rx.recv().await.is_err(), "replay closes after the snapshot");
+    assert_eq!(agent.runs.load(Ordering::SeqCst), 1);
+}
@@ -0,0 +1,136 @@
+//! `MultiAgentRouter`: several agents with their own cards and executors
+//! served under `/agents/{name}` by one router.
+
+mod common;
+
+use std::sync::Arc;
+
+use a2a_rs::builders::AgentCardBuilder;
+use a2a_rs::client::A2AClient;
+use a2a_rs::error::A2AError;
+use a2a_rs::server::{DefaultRequestHandler, InMemoryTaskStore, MultiAgentRouter};
+use a2a_rs::types::*;
+use common::{serve_router, EchoAgent, FailingAgent};
+
+fn card(name: &str, url: &str) -> AgentCard {
+    AgentCardBuilder::new(name, format!("The {name} agent"), "0.1.0")
+        .with_jsonrpc_interface(url)
+        .build()
+}
+
+fn handler(executor: Arc<dyn a2a_rs::server::AgentExecutor>) -> Arc<DefaultRequestHandler> {
+    Arc::new(DefaultRequestHandler::new(
+        executor,
+        Arc::new(InMemoryTaskStore::new()),
+    ))
+}
+
+fn get_params(id: &str) -> GetTaskParams {
+    GetTaskParams {
+        id: id.to_string(),
+        history_length: None,
+        metadata: None,
+        tenant: None,
+        consistency: None,
+    }
+}
+
+#[tokio::test]
+async fn test_each_agent_has_its_own_card_and_executor() {
+    // The cards carry the real URLs.
+    let (base_url, _server) = serve_router(|base_url| {
+        MultiAgentRouter::new()
+            .with_agent(
+                "echo",
+                card("echo", &format!("{base_url}/agents/echo/a2a")),
+                handler(Arc::new(EchoAgent)),
+            )
+            .unwrap()
+            .with_agent(
+                "failing",
+                card("failing", &format!("{base_url}/agents/failing/a2a")),
+                handler(Arc::new(FailingAgent)),
+            )
+            .unwrap()
+            .build()
+    })
+    .await;
+
+    let echo = A2AClient::from_url(&format!("{base_url}/agents/echo"))
+        .await
+        .unwrap();
+    assert_eq!(echo.get_card().unwrap().name, "echo");
+    let SendMessageResponse::Task(task) = echo.send_text("hello").await.unwrap() else {
+        panic!("expected a task");
+    };
+    assert_eq!(task.status.state, TaskState::Completed);
+
+    let failing = A2AClient::from_url(&format!("{base_url}/agents/failing"))
+        .await
+        .unwrap();
+    assert_eq!(failing.get_card().unwrap().name, "failing");
+    let failed = match failing.send_text("hello").await.unwrap() {
+        SendMessageResponse::Task(task) => task,
+        other => panic!("expected a task, got {other:?}"),
+    };
+    assert_eq!(failed.status.state, TaskState::Failed);
+
+    // Each agent has its own task store.
+    assert!(echo.get_task(get_params(&task.id)).await.is_ok());
+    assert!(echo.get_task(get_params(&failed.id)).await.is_err());
+}
+
+#[tokio::test]
+async fn test_agents_lists_all_cards() {
+    let router = MultiAgentRouter::new()
+        .with_agent(
+            "one",
+            card("one", "http://localhost/agents/one/a2a"),
+            handler(Arc::new(EchoAgent)),
+        )
+        .unwrap()
+        .with_agent(
+            "two",
+            card("two", "http://localhost/agents/two/a2a"),
+            handler(Arc::new(EchoAgent)),
+        )
+        .unwrap();
+    assert_eq!(router.agent_names().collect::<Vec<_>>(), ["one", "two"]);
+    let (base_url, _server) = serve_router(|_| router.build()).await;
+
+    let cards: Vec<AgentCard> = reqwest::get(format!("{base_url}/agents"))
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    let names: Vec<_> = cards.iter().map(|card| card.name.as_str()).collect();
+    assert_eq!(names, ["one", "two"]);
+
+    let response = reqwest::get(format!("{base_url}/agents/three/.well-known/agent.json"))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::NOT_FOUND);
+}
+
+#[test]
+fn test_invalid_or_duplicate_names_are_refused() {
+    let add = |router: MultiAgentRouter, name: &str| {
+        router.with_agent(
+            name,
+            card(name, "http://localhost/a2a"),
+            handler(Arc::new(EchoAgent)),
+        )
+    };
+    for name in ["", "a/b", "..", "with space"] {
+        assert!(matches!(
+            add(MultiAgentRouter::new(), name),
+            Err(A2AError::InvalidParams { .. })
+        ));
+    }
+    let router = add(MultiAgentRouter::new(), "agent-1.v2").unwrap();
+    assert!(matches!(
+        add(router, "agent-1.v2"),
+        Err(A2AError::InvalidParams { .. })
+    ));
+}
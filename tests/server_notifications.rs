@@ -0,0 +1,153 @@
+//! JSON-RPC notifications: `A2AClient::notify` and the server's `204`
+//! handling of requests without an `id`.
+
+mod common;
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use a2a_rs::client::A2AClient;
+use a2a_rs::server::{DefaultRequestHandler, InMemoryTaskStore, TaskListParams, TaskStore};
+use serde_json::{json, Value};
+
+type Received = Arc<Mutex<Vec<(String, Value)>>>;
+
+/// Start an echo agent whose handler records non-A2A notifications.
+async fn start() -> (String, Arc<InMemoryTaskStore>, Received) {
+    let store = Arc::new(InMemoryTaskStore::new());
+    let received: Received = Arc::default();
+    let sink = received.clone();
+    let handler = DefaultRequestHandler::new(Arc::new(common::EchoAgent), store.clone())
+        .with_notification_handler(move |method, params| {
+            sink.lock()
+                .unwrap()
+                .push((method.to_string(), params.clone()));
+        });
+    let (base_url, _server) =
+        common::start_test_server_with_handler(Arc::new(handler), common::test_agent_card).await;
+    (base_url, store, received)
+}
+
+/// Wait until `done` holds; notifications are processed in the background.
+async fn eventually(done: impl Fn() -> bool) {
+    for _ in 0..100 {
+        if done() {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    panic!("condition not reached");
+}
+
+async fn post(base_url: &str, body: Value) -> reqwest::Response {
+    reqwest::Client::new()
+        .post(format!("{base_url}/a2a"))
+        .json(&body)
+        .send()
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_notification_gets_no_content() {
+    let (base_url, _, received) = start().await;
+    let response = post(
+        &base_url,
+        json!({"jsonrpc": "2.0", "method": "telemetry/ping", "params": {"seq": 1}}),
+    )
+    .await;
+
+    assert_eq!(response.status(), reqwest::StatusCode::NO_CONTENT);
+    assert!(response.bytes().await.unwrap().is_empty());
+    eventually(|| !received.lock().unwrap().is_empty()).await;
+    assert_eq!(
+        *received.lock().unwrap(),
+        [("telemetry/ping".to_string(), json!({"seq": 1}))]
+    );
+}
+
+#[tokio::test]
+async fn test_a2a_method_notification_still_runs() {
+    let (base_url, store, received) = start().await;
+    let mut body = common::message_send_request(1, "hello");
+    body.as_object_mut().unwrap().remove("id");
+    let response = post(&base_url, body).await;
+    assert_eq!(response.status(), reqwest::StatusCode::NO_CONTENT);
+
+    let list = || async { store.list(&TaskListParams::default()).await.unwrap() };
+    for _ in 0..100 {
+        if !list().await.tasks.is_empty() {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    assert_eq!(list().await.tasks.len(), 1);
+    assert!(received.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_null_id_is_a_request() {
+    let (base_url, _, received) = start().await;
+    let response = post(
+        &base_url,
+        json!({"jsonrpc": "2.0", "id": null, "method": "telemetry/ping"}),
+    )
+    .await;
+
+    assert_eq!(response.status(), reqwest::StatusCode::OK);
+    let body: Value = response.json().await.unwrap();
+    assert_eq!(body["error"]["code"], a2a_rs::error::METHOD_NOT_FOUND);
+    assert!(received.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_invalid_params_notification_is_silent() {
+    let (base_url, store, _) = start().await;
+    let response = post(
+        &base_url,
+        json!({"jsonrpc": "2.0", "method": "message/send", "params": {"bogus": true}}),
+    )
+    .await;
+    assert_eq!(response.status(), reqwest::StatusCode::NO_CONTENT);
+    assert!(response.bytes().await.unwrap().is_empty());
+
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    let list = store.list(&TaskListParams::default()).await.unwrap();
+    assert!(list.tasks.is_empty());
+}
+
+#[tokio::test]
+async fn test_client_notify() {
+    let (base_url, _, received) = start().await;
+    let client = A2AClient::from_endpoint(&format!("{base_url}/a2a"));
+
+    client
+        .notify("telemetry/ping", json!({"seq": 7}))
+        .await
+        .unwrap();
+    client.notify("telemetry/bye", ()).await.unwrap();
+
+    eventually(|| received.lock().unwrap().len() == 2).await;
+    let mut received = received.lock().unwrap().clone();
+    received.sort_by(|a, b| a.0.cmp(&b.0));
+    assert_eq!(
+        received,
+        [
+            ("telemetry/bye".to_string(), Value::Null),
+            ("telemetry/ping".to_string(), json!({"seq": 7})),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_client_notify_reports_delivery_failure() {
+    let client = A2AClient::from_endpoint("http://127.0.0.1:1/a2a");
+    let err = client
+        .notify("telemetry/ping", json!({}))
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(err, a2a_rs::error::A2AError::Transport(_)),
+        "{err}"
+    );
+}
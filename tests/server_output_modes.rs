@@ -0,0 +1,301 @@
+//! `acceptedOutputModes` enforcement through `OutputAdapter`, and input
+//! modes checked against the agent card with `with_content_negotiation`.
+
+use std::sync::Arc;
+
+use a2a_rs::error::{self, A2AError, A2AResult};
+use a2a_rs::server::output_modes::{mode_accepted, part_mode};
+use a2a_rs::server::{
+    AgentExecutor, DefaultRequestHandler, EventQueue, InMemoryTaskStore, JsonEnvelope,
+    OutputAdapter, RequestContext, RequestHandler, SendMessageConfiguration, SendMessageParams,
+    TaskStore, TaskUpdater, UnsupportedOutput,
+};
+use a2a_rs::types::*;
+use a2a_rs::AgentCardBuilder;
+use async_trait::async_trait;
+use serde_json::json;
+
+/// Emits an artifact with a text and a data part, then completes with text.
+struct MixedAgent;
+
+#[async_trait]
+impl AgentExecutor for MixedAgent {
+    async fn execute(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        let updater = TaskUpdater::new(event_queue, context.task_id, context.context_id);
+        updater
+            .add_artifact(
+                vec![Part::text("summary"), Part::data(json!({"score": 3}))],
+                Some("result".to_string()),
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+            .await?;
+        updater.complete_with_text("done").await
+    }
+
+    async fn cancel(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        TaskUpdater::new(event_queue, context.task_id, context.context_id)
+            .cancel(None)
+            .await
+    }
+}
+
+fn handler(
+    adapter: Option<Arc<dyn OutputAdapter>>,
+) -> (DefaultRequestHandler, Arc<InMemoryTaskStore>) {
+    let store = Arc::new(InMemoryTaskStore::new());
+    let mut handler = DefaultRequestHandler::new(Arc::new(MixedAgent), store.clone());
+    if let Some(adapter) = adapter {
+        handler = handler.with_output_adapter(adapter);
+    }
+    (handler, store)
+}
+
+fn params(accepted: &[&str]) -> SendMessageParams {
+    SendMessageParams {
+        message: Message::user(uuid::Uuid::new_v4().to_string(), "go"),
+        configuration: Some(SendMessageConfiguration {
+            accepted_output_modes: Some(accepted.iter().map(|m| m.to_string()).collect()),
+            blocking: None,
+            history_length: None,
+            push_notification_config: None,
+            timeout: None,
+        }),
+        metadata: None,
+        tenant: None,
+    }
+}
+
+fn task(response: SendMessageResponse) -> Task {
+    match response {
+        SendMessageResponse::Task(task) => task,
+        other => panic!("expected task, got {other:?}"),
+    }
+}
+
+fn artifact_parts(task: &Task) -> Vec<Part> {
+    task.artifacts.as_ref().unwrap()[0].parts.clone()
+}
+
+#[tokio::test]
+async fn test_without_adapter_output_is_unchanged() {
+    let (handler, _) = handler(None);
+    let task = task(
+        handler
+            .on_message_send(params(&["application/json"]))
+            .await
+            .unwrap(),
+    );
+    assert_eq!(task.status.state, TaskState::Completed);
+    assert_eq!(artifact_parts(&task).len(), 2);
+}
+
+#[tokio::test]
+async fn test_accepted_output_passes_through() {
+    let (handler, _) = handler(Some(Arc::new(UnsupportedOutput::Reject)));
+    for accepted in [&["text/plain", "application/json"][..], &["*/*"], &[]] {
+        let task = task(handler.on_message_send(params(accepted)).await.unwrap());
+        assert_eq!(task.status.state, TaskState::Completed, "{accepted:?}");
+        assert_eq!(artifact_parts(&task).len(), 2);
+    }
+}
+
+#[tokio::test]
+async fn test_reject_fails_task_with_content_type_error() {
+    let (handler, store) = handler(Some(Arc::new(UnsupportedOutput::Reject)));
+    let params = params(&["application/json"]);
+    let err = handler.on_message_send(params).await.unwrap_err();
+
+    assert!(
+        matches!(err, A2AError::ContentTypeNotSupported { .. }),
+        "{err}"
+    );
+    assert_eq!(err.code(), error::CONTENT_TYPE_NOT_SUPPORTED);
+    assert!(err.to_string().contains("'text/plain'"), "{err}");
+
+    let tasks = store
+        .list(&a2a_rs::server::TaskListParams::default())
+        .await
+        .unwrap();
+    assert_eq!(tasks.tasks[0].status.state, TaskState::Failed);
+}
+
+#[tokio::test]
+async fn test_reject_on_stream_publishes_failed_status_with_error() {
+    let (handler, _) = handler(Some(Arc::new(UnsupportedOutput::Reject)));
+    let mut rx = handler
+        .on_message_send_stream(params(&["application/json"]))
+        .await
+        .unwrap();
+
+    let update = loop {
+        if let StreamResponse::StatusUpdate(update) = rx.recv().await.unwrap() {
+            break update;
+        }
+    };
+    assert_eq!(update.status.state, TaskState::Failed);
+    assert!(update.r#final);
+    let error = &update.metadata.unwrap()["error"];
+    assert_eq!(error["code"], error::CONTENT_TYPE_NOT_SUPPORTED);
+    assert_eq!(error["data"]["mimeType"], "text/plain");
+}
+
+#[tokio::test]
+async fn test_drop_leaves_out_unaccepted_parts() {
+    let (handler, _) = handler(Some(Arc::new(UnsupportedOutput::Drop)));
+    let task = task(
+        handler
+            .on_message_send(params(&["application/json"]))
+            .await
+            .unwrap(),
+    );
+    assert_eq!(task.status.state, TaskState::Completed);
+    assert!(task.status.message.is_none(), "text status message dropped");
+    let parts = artifact_parts(&task);
+    assert_eq!(parts.len(), 1);
+    assert!(matches!(&parts[0], Part::Data { data, .. } if data["score"] == 3));
+}
+
+#[tokio::test]
+async fn test_json_envelope_transcodes_text() {
+    let (handler, _) = handler(Some(Arc::new(JsonEnvelope::new(UnsupportedOutput::Reject))));
+    let task = task(
+        handler
+            .on_message_send(params(&["application/json"]))
+            .await
+            .unwrap(),
+    );
+    assert_eq!(task.status.state, TaskState::Completed);
+    let parts = artifact_parts(&task);
+    assert!(matches!(&parts[0], Part::Data { data, .. } if *data == json!({"text": "summary"})));
+    let status_parts = &task.status.message.unwrap().parts;
+    assert!(matches!(&status_parts[0], Part::Data { data, .. } if data["text"] == "done"));
+
+    // Nothing to envelope into: the fallback rejects.
+    let err = handler
+        .on_message_send(params(&["image/png"]))
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), error::CONTENT_TYPE_NOT_SUPPORTED);
+}
+
+#[test]
+fn test_mode_matching() {
+    let accepted = |modes: &[&str]| modes.iter().map(|m| m.to_string()).collect::<Vec<_>>();
+    assert!(mode_accepted("text/plain", &accepted(&["text/*"])));
+    assert!(mode_accepted(
+        "text/plain",
+        &accepted(&["TEXT/Plain; charset=utf-8"])
+    ));
+    assert!(mode_accepted("image/png", &accepted(&["*/*"])));
+    assert!(mode_accepted("image/png", &[]));
+    assert!(!mode_accepted(
+        "image/png",
+        &accepted(&["image/jpeg", "text/*"])
+    ));
+
+    assert_eq!(part_mode(&Part::text("x")), "text/plain");
+    assert_eq!(part_mode(&Part::data(json!({}))), "application/json");
+    assert_eq!(
+        part_mode(&Part::file_from_uri(
+            "https://x/a.pdf",
+            None,
+            Some("application/pdf".into())
+        )),
+        "application/pdf"
+    );
+    assert_eq!(
+        part_mode(&Part::file_from_bytes("AQID", None, None)),
+        "application/octet-stream"
+    );
+}
+
+/// Accepts text by default; `vision` takes images and text.
+fn negotiating_handler() -> (DefaultRequestHandler, Arc<InMemoryTaskStore>) {
+    let mut card = AgentCardBuilder::new("Agent", "Test agent", "1.0.0")
+        .with_input_modes(vec!["text/plain".to_string()])
+        .with_skill("vision", "Vision", "Describes images", vec![])
+        .build();
+    card.skills[0].input_modes = Some(vec!["image/*".to_string(), "text/plain".to_string()]);
+    let store = Arc::new(InMemoryTaskStore::new());
+    let handler = DefaultRequestHandler::new(Arc::new(MixedAgent), store.clone())
+        .with_content_negotiation(&card);
+    (handler, store)
+}
+
+fn image() -> Part {
+    Part::file_from_bytes("iVBORw==", None, Some("image/png".to_string()))
+}
+
+#[tokio::test]
+async fn test_input_outside_default_modes_is_rejected() {
+    let (handler, store) = negotiating_handler();
+    let mut params = params(&[]);
+    params.message.parts.push(Part::data(json!({"x": 1})));
+
+    let err = handler.on_message_send(params.clone()).await.unwrap_err();
+    assert_eq!(err.code(), error::CONTENT_TYPE_NOT_SUPPORTED);
+    assert!(err.to_string().contains("'application/json'"), "{err}");
+    let err = handler.on_message_send_stream(params).await.unwrap_err();
+    assert_eq!(err.code(), error::CONTENT_TYPE_NOT_SUPPORTED);
+
+    // No task was created.
+    let tasks = store
+        .list(&a2a_rs::server::TaskListParams::default())
+        .await
+        .unwrap();
+    assert!(tasks.tasks.is_empty());
+}
+
+#[tokio::test]
+async fn test_input_is_checked_against_requested_skill() {
+    let (handler, _) = negotiating_handler();
+
+    // An image is only accepted by `vision`.
+    let mut params = params(&[]);
+    params.message.parts.push(image());
+    let err = handler.on_message_send(params.clone()).await.unwrap_err();
+    assert!(
+        matches!(err, A2AError::ContentTypeNotSupported { ref message, .. } if message.starts_with("Agent")),
+        "{err}"
+    );
+
+    params.message.metadata = Some(json!({"skillId": "vision"}));
+    let completed = task(handler.on_message_send(params.clone()).await.unwrap());
+    assert_eq!(completed.status.state, TaskState::Completed);
+
+    // The skill may also be named in the request metadata.
+    params.message.metadata = None;
+    params.metadata = Some(json!({"skillId": "vision"}));
+    let completed = task(handler.on_message_send(params).await.unwrap());
+    assert_eq!(completed.status.state, TaskState::Completed);
+}
+
+#[tokio::test]
+async fn test_content_negotiation_drops_unaccepted_output() {
+    let (handler, _) = negotiating_handler();
+    let task = task(
+        handler
+            .on_message_send(params(&["application/json"]))
+            .await
+            .unwrap(),
+    );
+    assert_eq!(task.status.state, TaskState::Completed);
+    let parts = artifact_parts(&task);
+    assert!(matches!(&parts[..], [Part::Data { .. }]));
+
+    // An explicit adapter is kept.
+    let card = AgentCardBuilder::new("Agent", "Test agent", "1.0.0").build();
+    let handler =
+        DefaultRequestHandler::new(Arc::new(MixedAgent), Arc::new(InMemoryTaskStore::new()))
+            .with_output_adapter(Arc::new(UnsupportedOutput::Reject))
+            .with_content_negotiation(&card);
+    let err = handler
+        .on_message_send(params(&["application/json"]))
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), error::CONTENT_TYPE_NOT_SUPPORTED);
+}
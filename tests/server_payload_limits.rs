@@ -0,0 +1,237 @@
+//! Request body, message part and artifact size limits, and chunked
+//! artifact emission with `TaskUpdater::stream_artifact`.
+
+mod common;
+
+use std::sync::Arc;
+
+use a2a_rs::error::{self, A2AError, A2AResult};
+use a2a_rs::server::{
+    AgentExecutor, DefaultRequestHandler, EventQueue, InMemoryTaskStore, RequestContext,
+    RequestHandler, RouterConfig, SendMessageParams, TaskStore,
+};
+use a2a_rs::types::*;
+use a2a_rs::AgentCardBuilder;
+use async_trait::async_trait;
+use serde_json::{json, Value};
+
+/// Emits the requested number of bytes as a chunked artifact, then
+/// completes.
+struct BlobAgent;
+
+#[async_trait]
+impl AgentExecutor for BlobAgent {
+    async fn execute(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        let size: usize = context.get_user_input(" ").parse().unwrap_or(0);
+        let updater = context.task_updater(event_queue);
+        for (i, chunk) in vec![b'x'; size].chunks(4).enumerate() {
+            updater
+                .add_artifact(
+                    vec![Part::file_from_raw(chunk, None, None)],
+                    Some("blob".to_string()),
+                    None,
+                    None,
+                    Some(i > 0),
+                    None,
+                    None,
+                )
+                .await?;
+        }
+        updater.complete(None).await
+    }
+
+    async fn cancel(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        context.task_updater(event_queue).cancel(None).await
+    }
+}
+
+fn params(text: &str) -> SendMessageParams {
+    SendMessageParams {
+        message: Message::user(uuid::Uuid::new_v4().to_string(), text),
+        configuration: None,
+        metadata: None,
+        tenant: None,
+    }
+}
+
+#[tokio::test]
+async fn test_oversized_body_gets_413() {
+    let card = |url: &str| {
+        AgentCardBuilder::new("Limited", "Small bodies", "1.0")
+            .with_jsonrpc_interface(url)
+            .build()
+    };
+    let config = RouterConfig::default().with_max_body_size(1024);
+    let (base_url, _server) = common::start_test_server_with_config(
+        Arc::new(BlobAgent),
+        Arc::new(InMemoryTaskStore::new()),
+        card,
+        config,
+    )
+    .await;
+    let url = format!("{base_url}/a2a");
+
+    let client = reqwest::Client::new();
+    let small = common::message_send_request(1, "8");
+    let response = client.post(&url).json(&small).send().await.unwrap();
+    assert_eq!(response.status(), 200);
+
+    let large = common::message_send_request(2, &"x".repeat(2048));
+    let response = client.post(&url).json(&large).send().await.unwrap();
+    assert_eq!(response.status(), 413);
+    let body: Value = response.json().await.unwrap();
+    assert_eq!(body["error"]["code"], error::INVALID_REQUEST);
+    assert_eq!(body["error"]["data"]["limit"], 1024);
+    assert!(body["error"]["data"]["size"].as_u64().unwrap() > 2048);
+}
+
+#[tokio::test]
+async fn test_oversized_part_is_rejected_before_a_task_exists() {
+    let store = Arc::new(InMemoryTaskStore::new());
+    let handler =
+        DefaultRequestHandler::new(Arc::new(BlobAgent), store.clone()).with_max_part_size(4);
+
+    handler.on_message_send(params("8")).await.unwrap();
+    let mut oversized = params("12345");
+    oversized.message.parts.insert(0, Part::text("ok"));
+    let err = handler.on_message_send(oversized).await.unwrap_err();
+
+    assert!(matches!(err, A2AError::PayloadTooLarge { .. }), "{err}");
+    assert_eq!(err.code(), error::INVALID_REQUEST);
+    assert!(err.to_string().contains("Message part 1"), "{err}");
+    let tasks = store
+        .list(&a2a_rs::server::TaskListParams::default())
+        .await
+        .unwrap();
+    assert_eq!(tasks.tasks.len(), 1);
+}
+
+#[tokio::test]
+async fn test_artifact_over_limit_fails_task() {
+    let store = Arc::new(InMemoryTaskStore::new());
+    let handler =
+        DefaultRequestHandler::new(Arc::new(BlobAgent), store.clone()).with_max_artifact_size(10);
+
+    let response = handler.on_message_send(params("10")).await.unwrap();
+    let SendMessageResponse::Task(task) = response else {
+        panic!("expected task");
+    };
+    assert_eq!(task.status.state, TaskState::Completed);
+
+    // Appended chunks count together: 4 + 4 + 4 > 10.
+    let err = handler.on_message_send(params("12")).await.unwrap_err();
+    assert!(matches!(err, A2AError::PayloadTooLarge { .. }), "{err}");
+    let data = JsonRpcError::from(err).data.unwrap();
+    assert_eq!(data, json!({"limit": 10, "size": 12}));
+
+    let mut rx = handler.on_message_send_stream(params("12")).await.unwrap();
+    let update = loop {
+        if let StreamResponse::StatusUpdate(update) = rx.recv().await.unwrap() {
+            break update;
+        }
+    };
+    assert_eq!(update.status.state, TaskState::Failed);
+    assert_eq!(
+        update.metadata.unwrap()["error"]["data"],
+        json!({"limit": 10, "size": 12})
+    );
+}
+
+#[cfg(feature = "fs")]
+mod stream_artifact {
+    use super::*;
+
+    /// Streams its input text back as an artifact, 3 bytes per chunk.
+    struct StreamingAgent;
+
+    #[async_trait]
+    impl AgentExecutor for StreamingAgent {
+        async fn execute(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+            let input = context.get_user_input(" ");
+            let updater = context.task_updater(event_queue).scoped("echo");
+            let id = updater
+                .stream_artifact(
+                    input.as_bytes(),
+                    3,
+                    Some("echo.txt".to_string()),
+                    Some("text/plain".to_string()),
+                )
+                .await?;
+            assert!(id.starts_with("echo/"), "{id}");
+            updater.complete(None).await
+        }
+
+        async fn cancel(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+            context.task_updater(event_queue).cancel(None).await
+        }
+    }
+
+    fn content(artifact: &Artifact) -> Vec<u8> {
+        artifact
+            .parts
+            .iter()
+            .flat_map(|part| match part {
+                Part::File {
+                    file: FileContent::Bytes(file),
+                    ..
+                } => file.decode().unwrap(),
+                other => panic!("expected file bytes, got {other:?}"),
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_chunks_are_reassembled() {
+        let handler = DefaultRequestHandler::new(
+            Arc::new(StreamingAgent),
+            Arc::new(InMemoryTaskStore::new()),
+        );
+        let mut rx = handler
+            .on_message_send_stream(params("hello world"))
+            .await
+            .unwrap();
+
+        let mut flags = Vec::new();
+        loop {
+            match rx.recv().await.unwrap() {
+                StreamResponse::ArtifactUpdate(update) => {
+                    flags.push((update.append, update.last_chunk));
+                }
+                StreamResponse::StatusUpdate(update) if update.r#final => break,
+                _ => {}
+            }
+        }
+        assert_eq!(
+            flags,
+            [
+                (Some(false), Some(false)),
+                (Some(true), Some(false)),
+                (Some(true), Some(false)),
+                (Some(true), Some(true)),
+            ]
+        );
+
+        let response = handler.on_message_send(params("abcdef")).await.unwrap();
+        let SendMessageResponse::Task(task) = response else {
+            panic!("expected task");
+        };
+        let artifacts = task.artifacts.unwrap();
+        assert_eq!(artifacts.len(), 1);
+        assert_eq!(artifacts[0].parts.len(), 2);
+        assert_eq!(content(&artifacts[0]), b"abcdef");
+    }
+
+    #[tokio::test]
+    async fn test_zero_chunk_size_is_invalid() {
+        let updater = a2a_rs::server::TaskUpdater::new(
+            EventQueue::with_default_capacity(),
+            "t1".to_string(),
+            "c1".to_string(),
+        );
+        let err = updater
+            .stream_artifact(&b"abc"[..], 0, None, None)
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), error::INVALID_PARAMS);
+    }
+}
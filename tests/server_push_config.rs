@@ -0,0 +1,580 @@
+//! `tasks/pushNotificationConfig/*` served by `DefaultRequestHandler` from a
+//! `PushNotificationConfigStore`.
+
+mod common;
+
+use std::sync::Arc;
+
+use a2a_rs::client::A2AClient;
+use a2a_rs::error::{A2AError, A2AResult};
+use a2a_rs::server::{
+    DefaultRequestHandler, EncryptedPushNotificationConfigStore, InMemoryKvStore,
+    InMemoryPushNotificationConfigStore, InMemoryTaskStore, KvPushNotificationConfigStore,
+    PushNotificationConfigStore, RequestHandler, SecretCipher, SecretContext,
+    SendMessageConfiguration, SendMessageParams,
+};
+use a2a_rs::types::*;
+use common::EchoAgent;
+use serde_json::json;
+
+fn handler() -> DefaultRequestHandler {
+    DefaultRequestHandler::new(Arc::new(EchoAgent), Arc::new(InMemoryTaskStore::new()))
+        .with_push_config_store(Arc::new(InMemoryPushNotificationConfigStore::new()))
+}
+
+/// Serve [`handler()`]; returns the A2A endpoint URL.
+async fn start_server() -> String {
+    let (base_url, _server) =
+        common::start_test_server_with_handler(Arc::new(handler()), common::test_agent_card).await;
+    format!("{base_url}/a2a")
+}
+
+fn webhook(url: &str) -> PushNotificationConfig {
+    PushNotificationConfig {
+        id: None,
+        url: url.to_string(),
+        token: Some("secret".to_string()),
+        authentication: None,
+    }
+}
+
+fn task_config(task_id: &str, config_id: &str, url: &str) -> TaskPushNotificationConfig {
+    let mut config = webhook(url);
+    config.id = Some(config_id.to_string());
+    TaskPushNotificationConfig {
+        id: None,
+        task_id: task_id.to_string(),
+        push_notification_config: config,
+        tenant: None,
+    }
+}
+
+#[tokio::test]
+async fn test_client_round_trip() {
+    let client = A2AClient::from_endpoint(&start_server().await);
+    let task = match client.send_text("hi").await.unwrap() {
+        SendMessageResponse::Task(task) => task,
+        other => panic!("expected a task, got {other:?}"),
+    };
+
+    for (config_id, url) in [
+        ("b", "https://b.example/hook"),
+        ("a", "https://a.example/hook"),
+    ] {
+        let set = client
+            .set_task_callback(SetTaskPushNotificationConfigParams {
+                task_id: task.id.clone(),
+                config_id: config_id.to_string(),
+                config: webhook(url),
+                tenant: None,
+            })
+            .await
+            .unwrap();
+        assert_eq!(set.task_id, task.id);
+        assert_eq!(set.push_notification_config.id.as_deref(), Some(config_id));
+    }
+
+    let got = client
+        .get_task_callback(GetTaskPushNotificationConfigParams {
+            id: task.id.clone(),
+            push_notification_config_id: Some("b".to_string()),
+            metadata: None,
+            tenant: None,
+        })
+        .await
+        .unwrap();
+    assert_eq!(got.push_notification_config.url, "https://b.example/hook");
+    assert_eq!(
+        got.push_notification_config.token.as_deref(),
+        Some("secret")
+    );
+
+    let list = |id: String| ListTaskPushNotificationConfigParams {
+        id,
+        page_size: None,
+        page_token: None,
+        metadata: None,
+        tenant: None,
+    };
+    let configs = client
+        .list_task_callbacks(list(task.id.clone()))
+        .await
+        .unwrap();
+    let ids: Vec<_> = configs
+        .iter()
+        .map(|c| c.push_notification_config.id.as_deref().unwrap())
+        .collect();
+    assert_eq!(ids, ["a", "b"]);
+
+    client
+        .delete_task_callback(DeleteTaskPushNotificationConfigParams {
+            id: task.id.clone(),
+            push_notification_config_id: "a".to_string(),
+            metadata: None,
+            tenant: None,
+        })
+        .await
+        .unwrap();
+    let configs = client
+        .list_task_callbacks(list(task.id.clone()))
+        .await
+        .unwrap();
+    assert_eq!(configs.len(), 1);
+    assert_eq!(configs[0].push_notification_config.id.as_deref(), Some("b"));
+}
+
+#[tokio::test]
+async fn test_unknown_task_is_not_found() {
+    let client = A2AClient::from_endpoint(&start_server().await);
+    let err = client
+        .list_task_callbacks(ListTaskPushNotificationConfigParams {
+            id: "missing".to_string(),
+            page_size: None,
+            page_token: None,
+            metadata: None,
+            tenant: None,
+        })
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(err, A2AError::JsonRpc { code: -32001, .. }),
+        "{err:?}"
+    );
+}
+
+#[tokio::test]
+async fn test_spec_shape_and_default_id() {
+    let handler = handler();
+    let task = match handler
+        .on_message_send(SendMessageParams {
+            message: Message::user("m1", "hi"),
+            configuration: None,
+            metadata: None,
+            tenant: None,
+        })
+        .await
+        .unwrap()
+    {
+        SendMessageResponse::Task(task) => task,
+        other => panic!("expected a task, got {other:?}"),
+    };
+
+    // `TaskPushNotificationConfig` params without a config ID.
+    let set = handler
+        .on_set_task_push_notification_config(json!({
+            "taskId": task.id,
+            "pushNotificationConfig": {"url": "https://example.com/hook"}
+        }))
+        .await
+        .unwrap();
+    assert_eq!(set["pushNotificationConfig"]["id"], task.id.as_str());
+
+    let got = handler
+        .on_get_task_push_notification_config(json!({"id": task.id}))
+        .await
+        .unwrap();
+    assert_eq!(
+        got["pushNotificationConfig"]["url"],
+        "https://example.com/hook"
+    );
+
+    let err = handler
+        .on_get_task_push_notification_config(
+            json!({"id": task.id, "pushNotificationConfigId": "other"}),
+        )
+        .await
+        .unwrap_err();
+    assert!(matches!(err, A2AError::InvalidParams { .. }), "{err:?}");
+
+    let err = handler
+        .on_set_task_push_notification_config(json!({"taskId": task.id}))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, A2AError::InvalidParams { .. }), "{err:?}");
+}
+
+#[tokio::test]
+async fn test_config_sent_with_message_is_stored() {
+    let handler = handler();
+    let response = handler
+        .on_message_send(SendMessageParams {
+            message: Message::user("m1", "hi"),
+            configuration: Some(SendMessageConfiguration {
+                accepted_output_modes: None,
+                blocking: Some(true),
+                history_length: None,
+                push_notification_config: Some(
+                    json!({"id": "inline", "url": "https://example.com/inline"}),
+                ),
+                timeout: None,
+            }),
+            metadata: None,
+            tenant: None,
+        })
+        .await
+        .unwrap();
+    let SendMessageResponse::Task(task) = response else {
+        panic!("expected a task");
+    };
+
+    let configs = handler
+        .on_list_task_push_notification_config(json!({"id": task.id}))
+        .await
+        .unwrap();
+    assert_eq!(configs.as_array().unwrap().len(), 1);
+    assert_eq!(configs[0]["pushNotificationConfig"]["id"], "inline");
+}
+
+#[tokio::test]
+async fn test_kv_store_scopes_configs_by_task() {
+    let kv = Arc::new(InMemoryKvStore::new());
+    let store = KvPushNotificationConfigStore::new(kv.clone());
+    store
+        .set(task_config("t1", "a", "https://a.example"))
+        .await
+        .unwrap();
+    store
+        .set(task_config("t10", "a", "https://other.example"))
+        .await
+        .unwrap();
+    store
+        .set(task_config("t1", "a", "https://replaced.example"))
+        .await
+        .unwrap();
+
+    let configs = store.list("t1").await.unwrap();
+    assert_eq!(configs.len(), 1);
+    assert_eq!(
+        configs[0].push_notification_config.url,
+        "https://replaced.example"
+    );
+
+    // A second store over the same backend sees the same configs.
+    let reopened = KvPushNotificationConfigStore::new(kv);
+    assert!(reopened.get("t10", "a").await.unwrap().is_some());
+    reopened.delete("t10", "a").await.unwrap();
+    reopened.delete("t10", "a").await.unwrap();
+    assert!(store.get("t10", "a").await.unwrap().is_none());
+    assert_eq!(store.list("t1").await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_kv_store_keeps_slashed_task_ids_apart() {
+    let store = KvPushNotificationConfigStore::new(Arc::new(InMemoryKvStore::new()));
+    store
+        .set(task_config("a", "b/c", "https://a.example"))
+        .await
+        .unwrap();
+    store
+        .set(task_config("a/b", "c", "https://ab.example"))
+        .await
+        .unwrap();
+    store
+        .set(task_config("a%2Fb", "c", "https://encoded.example"))
+        .await
+        .unwrap();
+
+    let urls = |configs: Vec<TaskPushNotificationConfig>| -> Vec<String> {
+        configs
+            .into_iter()
+            .map(|c| c.push_notification_config.url)
+            .collect()
+    };
+    assert_eq!(urls(store.list("a").await.unwrap()), ["https://a.example"]);
+    assert_eq!(
+        urls(store.list("a/b").await.unwrap()),
+        ["https://ab.example"]
+    );
+    assert_eq!(
+        urls(store.list("a%2Fb").await.unwrap()),
+        ["https://encoded.example"]
+    );
+    let got = store.get("a/b", "c").await.unwrap().unwrap();
+    assert_eq!(got.task_id, "a/b");
+
+    store.delete("a", "b/c").await.unwrap();
+    assert!(store.list("a").await.unwrap().is_empty());
+    assert_eq!(store.list("a/b").await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_list_pages_through_configs() {
+    let handler = handler();
+    let task = match handler
+        .on_message_send(SendMessageParams {
+            message: Message::user("m1", "hi"),
+            configuration: None,
+            metadata: None,
+            tenant: None,
+        })
+        .await
+        .unwrap()
+    {
+        SendMessageResponse::Task(task) => task,
+        other => panic!("expected a task, got {other:?}"),
+    };
+    for config_id in ["c", "a", "d", "b"] {
+        handler
+            .on_set_task_push_notification_config(
+                serde_json::to_value(task_config(&task.id, config_id, "https://example.com"))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+    }
+    let ids = |page: &serde_json::Value| -> Vec<String> {
+        page["configs"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|c| {
+                c["pushNotificationConfig"]["id"]
+                    .as_str()
+                    .unwrap()
+                    .to_string()
+            })
+            .collect()
+    };
+
+    let first = handler
+        .on_list_task_push_notification_config(json!({"id": task.id, "pageSize": 3}))
+        .await
+        .unwrap();
+    assert_eq!(ids(&first), ["a", "b", "c"]);
+    assert_eq!(first["nextPageToken"], "c");
+
+    let last = handler
+        .on_list_task_push_notification_config(json!({"id": task.id, "pageToken": "c"}))
+        .await
+        .unwrap();
+    assert_eq!(ids(&last), ["d"]);
+    assert!(last.get("nextPageToken").is_none());
+
+    // Without paging params, the spec's bare array.
+    let all = handler
+        .on_list_task_push_notification_config(json!({"id": task.id}))
+        .await
+        .unwrap();
+    assert_eq!(all.as_array().unwrap().len(), 4);
+
+    let err = handler
+        .on_list_task_push_notification_config(json!({"id": task.id, "pageSize": 0}))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, A2AError::InvalidParams { .. }), "{err:?}");
+}
+
+/// Reverses secrets behind a marker; enough to see what gets stored.
+struct ReversingCipher;
+
+impl SecretCipher for ReversingCipher {
+    fn encrypt(&self, plaintext: &str, _context: SecretContext<'_>) -> A2AResult<String> {
+        Ok(format!(
+            "enc:{}",
+            plaintext.chars().rev().collect::<String>()
+        ))
+    }
+
+    fn decrypt(&self, ciphertext: &str, _context: SecretContext<'_>) -> A2AResult<String> {
+        ciphertext
+            .strip_prefix("enc:")
+            .map(|reversed| reversed.chars().rev().collect())
+            .ok_or_else(|| A2AError::internal_error("not encrypted"))
+    }
+}
+
+#[tokio::test]
+async fn test_encrypted_store_keeps_secrets_encrypted_at_rest() {
+    let inner = Arc::new(InMemoryPushNotificationConfigStore::new());
+    let store = EncryptedPushNotificationConfigStore::new(inner.clone(), Arc::new(ReversingCipher));
+    let mut config = task_config("t1", "a", "https://a.example");
+    config.push_notification_config.authentication = Some(PushNotificationAuthenticationInfo {
+        schemes: vec!["Bearer".to_string()],
+        credentials: Some("hunter2".to_string()),
+    });
+    store.set(config).await.unwrap();
+    store
+        .set(task_config("t1", "b", "https://b.example"))
+        .await
+        .unwrap();
+
+    let stored = inner.get("t1", "a").await.unwrap().unwrap();
+    let push = &stored.push_notification_config;
+    assert_eq!(push.token.as_deref(), Some("enc:terces"));
+    assert_eq!(
+        push.authentication.as_ref().unwrap().credentials.as_deref(),
+        Some("enc:2retnuh")
+    );
+    assert_eq!(push.url, "https://a.example");
+
+    let read = store.get("t1", "a").await.unwrap().unwrap();
+    let push = &read.push_notification_config;
+    assert_eq!(push.token.as_deref(), Some("secret"));
+    assert_eq!(
+        push.authentication.as_ref().unwrap().credentials.as_deref(),
+        Some("hunter2")
+    );
+    let listed = store.list("t1").await.unwrap();
+    assert!(listed
+        .iter()
+        .all(|c| c.push_notification_config.token.as_deref() == Some("secret")));
+    let page = store.list_page("t1", 1, Some("a")).await.unwrap();
+    assert_eq!(page.configs.len(), 1);
+    assert_eq!(
+        page.configs[0].push_notification_config.token.as_deref(),
+        Some("secret")
+    );
+
+    // Plaintext stored before the wrapping can't be read through it.
+    inner
+        .set(task_config("t2", "a", "https://a.example"))
+        .await
+        .unwrap();
+    assert!(store.get("t2", "a").await.is_err());
+}
+
+#[cfg(feature = "push-secrets")]
+#[test]
+fn test_aes_gcm_cipher() {
+    use a2a_rs::server::AesGcmSecretCipher;
+
+    let context = SecretContext {
+        task_id: "t1",
+        config_id: "a",
+    };
+    let cipher = AesGcmSecretCipher::new(&[7u8; 32]).unwrap();
+    let first = cipher.encrypt("secret", context).unwrap();
+    let second = cipher.encrypt("secret", context).unwrap();
+    assert_ne!(first, second, "nonces must differ");
+    assert!(!first.contains("secret"));
+    assert_eq!(cipher.decrypt(&first, context).unwrap(), "secret");
+    assert_eq!(cipher.decrypt(&second, context).unwrap(), "secret");
+
+    use base64::Engine;
+    let key = base64::engine::general_purpose::STANDARD.encode([7u8; 32]);
+    let same_key = AesGcmSecretCipher::from_base64(&key).unwrap();
+    assert_eq!(same_key.decrypt(&first, context).unwrap(), "secret");
+
+    let other = AesGcmSecretCipher::new(&[9u8; 32]).unwrap();
+    assert!(other.decrypt(&first, context).is_err());
+    assert!(cipher.decrypt("not base64!", context).is_err());
+    assert!(cipher.decrypt("c2hvcnQ=", context).is_err());
+
+    let err = AesGcmSecretCipher::new(&[0u8; 16]).unwrap_err();
+    assert!(matches!(err, A2AError::InvalidParams { .. }), "{err:?}");
+}
+
+#[cfg(feature = "push-secrets")]
+#[test]
+fn test_aes_gcm_ciphertext_is_bound_to_its_config() {
+    use a2a_rs::server::AesGcmSecretCipher;
+
+    let cipher = AesGcmSecretCipher::new(&[7u8; 32]).unwrap();
+    let context = |task_id, config_id| SecretContext { task_id, config_id };
+    let sealed = cipher.encrypt("secret", context("t1", "a")).unwrap();
+    assert_eq!(
+        cipher.decrypt(&sealed, context("t1", "a")).unwrap(),
+        "secret"
+    );
+    assert!(cipher.decrypt(&sealed, context("t1", "b")).is_err());
+    assert!(cipher.decrypt(&sealed, context("t2", "a")).is_err());
+    assert!(cipher.decrypt(&sealed, context("t1a", "")).is_err());
+    assert!(cipher.decrypt(&sealed, context("t", "1a")).is_err());
+}
+
+#[cfg(feature = "push-secrets")]
+#[tokio::test]
+async fn test_encrypted_store_rejects_swapped_ciphertext() {
+    use a2a_rs::server::AesGcmSecretCipher;
+
+    let inner = Arc::new(InMemoryPushNotificationConfigStore::new());
+    let cipher = Arc::new(AesGcmSecretCipher::new(&[7u8; 32]).unwrap());
+    let store = EncryptedPushNotificationConfigStore::new(inner.clone(), cipher);
+    store
+        .set(task_config("t1", "a", "https://a.example"))
+        .await
+        .unwrap();
+    store
+        .set(task_config("t1", "b", "https://b.example"))
+        .await
+        .unwrap();
+    assert_eq!(
+        store
+            .get("t1", "b")
+            .await
+            .unwrap()
+            .unwrap()
+            .push_notification_config
+            .token
+            .as_deref(),
+        Some("secret")
+    );
+
+    // Copy a's sealed token into b, and into another task's config.
+    let sealed = inner
+        .get("t1", "a")
+        .await
+        .unwrap()
+        .unwrap()
+        .push_notification_config
+        .token;
+    let mut swapped = inner.get("t1", "b").await.unwrap().unwrap();
+    swapped.push_notification_config.token = sealed.clone();
+    inner.set(swapped).await.unwrap();
+    let mut moved = task_config("t2", "a", "https://a.example");
+    moved.push_notification_config.token = sealed;
+    inner.set(moved).await.unwrap();
+
+    assert!(store.get("t1", "b").await.is_err());
+    assert!(store.get("t2", "a").await.is_err());
+    assert!(store.get("t1", "a").await.unwrap().is_some());
+}
+
+#[cfg(feature = "push-sqlite")]
+#[tokio::test]
+async fn test_sqlite_store_pages_and_survives_reopen() {
+    use a2a_rs::server::SqlitePushNotificationConfigStore;
+
+    let path = std::env::temp_dir().join(format!("a2a-push-{}.db", uuid::Uuid::new_v4()));
+    {
+        let store = SqlitePushNotificationConfigStore::open(&path).unwrap();
+        for config_id in ["c", "a", "b"] {
+            store
+                .set(task_config("t1", config_id, "https://example.com"))
+                .await
+                .unwrap();
+        }
+        store
+            .set(task_config("t10", "a", "https://other.example"))
+            .await
+            .unwrap();
+        store
+            .set(task_config("t1", "a", "https://replaced.example"))
+            .await
+            .unwrap();
+    }
+
+    let store = SqlitePushNotificationConfigStore::open(&path).unwrap();
+    let a = store.get("t1", "a").await.unwrap().unwrap();
+    assert_eq!(a.push_notification_config.url, "https://replaced.example");
+    assert_eq!(store.list("t1").await.unwrap().len(), 3);
+
+    let page = store.list_page("t1", 2, None).await.unwrap();
+    let ids: Vec<_> = page
+        .configs
+        .iter()
+        .map(|c| c.push_notification_config.id.clone().unwrap())
+        .collect();
+    assert_eq!(ids, ["a", "b"]);
+    assert_eq!(page.next_page_token.as_deref(), Some("b"));
+    let page = store.list_page("t1", 2, Some("b")).await.unwrap();
+    assert_eq!(page.configs.len(), 1);
+    assert_eq!(page.next_page_token, None);
+
+    store.delete("t1", "a").await.unwrap();
+    store.delete("t1", "a").await.unwrap();
+    assert!(store.get("t1", "a").await.unwrap().is_none());
+    assert_eq!(store.list("t10").await.unwrap().len(), 1);
+    drop(store);
+    let _ = std::fs::remove_file(&path);
+}
@@ -0,0 +1,227 @@
+//! Server-side rate limiting: per-IP, per-API-key, per-tenant and custom
+//! buckets, the `429` / -32029 response, and the caller identities exposed
+//! to executors.
+
+mod common;
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use a2a_rs::builders::AgentCardBuilder;
+use a2a_rs::client::A2AClient;
+use a2a_rs::error::{self, A2AResult};
+use a2a_rs::server::{
+    AgentExecutor, CallerIdentity, EventQueue, InMemoryTaskStore, RateLimit, RateLimitRequest,
+    RateLimitScope, RateLimiter, RequestContext, RouterConfig,
+};
+use async_trait::async_trait;
+use axum::http::HeaderMap;
+use serde_json::{json, Value};
+
+/// Replies with the caller identities, e.g. `apiKey=…-key`.
+struct IdentityAgent;
+
+#[async_trait]
+impl AgentExecutor for IdentityAgent {
+    async fn execute(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        let identities: Vec<String> = context
+            .caller_identities()
+            .iter()
+            .map(|identity| format!("{}={}", identity.scope, identity.key))
+            .collect();
+        context
+            .task_updater(event_queue)
+            .complete_with_text(&identities.join(","))
+            .await
+    }
+
+    async fn cancel(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        context.task_updater(event_queue).cancel(None).await
+    }
+}
+
+/// Serve [`IdentityAgent`] behind `limiter`, with connect info; returns
+/// the endpoint URL.
+async fn start(limiter: RateLimiter) -> String {
+    let card = |url: &str| {
+        AgentCardBuilder::new("Limited", "Rate limited", "1.0")
+            .with_jsonrpc_interface(url)
+            .build()
+    };
+    let config = RouterConfig::default().with_rate_limiter(Arc::new(limiter));
+    let (base_url, _server) = common::start_test_server_with_config(
+        Arc::new(IdentityAgent),
+        Arc::new(InMemoryTaskStore::new()),
+        card,
+        config,
+    )
+    .await;
+    format!("{base_url}/a2a")
+}
+
+/// Send "hi" with `params` merged in and `request` customizing the HTTP
+/// request; returns the response.
+async fn send(
+    url: &str,
+    params: Value,
+    request: impl FnOnce(reqwest::RequestBuilder) -> reqwest::RequestBuilder,
+) -> reqwest::Response {
+    let mut all = json!({"message": {"kind": "message", "messageId": "m1", "role": "user",
+                                     "parts": [{"kind": "text", "text": "hi"}]}});
+    all.as_object_mut()
+        .unwrap()
+        .extend(params.as_object().unwrap().clone());
+    let body = common::jsonrpc_request(json!(7), "message/send", all);
+    request(reqwest::Client::new().post(url).json(&body))
+        .send()
+        .await
+        .unwrap()
+}
+
+async fn reply(response: reqwest::Response) -> String {
+    assert_eq!(response.status(), 200);
+    let body: Value = response.json().await.unwrap();
+    body["result"]["status"]["message"]["parts"][0]["text"]
+        .as_str()
+        .unwrap_or_else(|| panic!("no reply in {body}"))
+        .to_string()
+}
+
+#[tokio::test]
+async fn test_api_key_limit_answers_429_with_retry_after() {
+    let url = start(RateLimiter::new().per_api_key("X-Api-Key", RateLimit::per_minute(2))).await;
+    let key = |r: reqwest::RequestBuilder| r.header("x-api-key", "alice-secret-key");
+
+    assert_eq!(
+        reply(send(&url, json!({}), key).await).await,
+        "apiKey=…-key"
+    );
+    send(&url, json!({}), key).await;
+    let response = send(&url, json!({}), key).await;
+
+    assert_eq!(response.status(), 429);
+    let retry_after: u64 = response.headers()["retry-after"]
+        .to_str()
+        .unwrap()
+        .parse()
+        .unwrap();
+    assert!((1..=30).contains(&retry_after), "{retry_after}");
+    let body: Value = response.json().await.unwrap();
+    assert_eq!(body["id"], 7);
+    assert_eq!(body["error"]["code"], error::RATE_LIMITED);
+    let retry_after_ms = body["error"]["data"]["retryAfterMs"].as_u64().unwrap();
+    assert!((29_000..=30_000).contains(&retry_after_ms), "{body}");
+
+    // Other keys, and requests without one, have their own budget.
+    let other = |r: reqwest::RequestBuilder| r.header("x-api-key", "bob");
+    assert_eq!(reply(send(&url, json!({}), other).await).await, "apiKey=…");
+    assert_eq!(reply(send(&url, json!({}), |r| r).await).await, "");
+}
+
+#[tokio::test]
+async fn test_tenant_and_ip_limits() {
+    let limiter = RateLimiter::new()
+        .per_ip(RateLimit::per_minute(3))
+        .per_tenant(RateLimit::per_minute(1));
+    let url = start(limiter).await;
+
+    assert_eq!(
+        reply(send(&url, json!({"tenant": "acme"}), |r| r).await).await,
+        "ip=127.0.0.1,tenant=acme"
+    );
+    let response = send(&url, json!({"tenant": "acme"}), |r| r).await;
+    assert_eq!(response.status(), 429);
+    // The rejected request took no IP token.
+    assert_eq!(
+        reply(send(&url, json!({"tenant": "globex"}), |r| r).await).await,
+        "ip=127.0.0.1,tenant=globex"
+    );
+    assert_eq!(
+        reply(send(&url, json!({}), |r| r).await).await,
+        "ip=127.0.0.1"
+    );
+    let response = send(&url, json!({}), |r| r).await;
+    assert_eq!(response.status(), 429);
+}
+
+#[tokio::test]
+async fn test_custom_key_and_client_retry_after() {
+    let limiter = RateLimiter::new().per_key("forwardedIp", RateLimit::per_minute(1), |req| {
+        let forwarded = req.header("x-forwarded-for")?;
+        Some(forwarded.split(',').next()?.trim().to_string())
+    });
+    let url = start(limiter).await;
+    let forwarded = |r: reqwest::RequestBuilder| r.header("x-forwarded-for", "10.0.0.1, 10.0.0.2");
+
+    assert_eq!(
+        reply(send(&url, json!({}), forwarded).await).await,
+        "forwardedIp=10.0.0.1"
+    );
+
+    // No header: not limited by the rule. The A2A client surfaces the
+    // wait once limited.
+    let client = A2AClient::from_endpoint(&url);
+    client.send_text("one").await.unwrap();
+    let limited = RateLimiter::new().per_key("all", RateLimit::per_minute(1), |_| {
+        Some("everyone".to_string())
+    });
+    let client = A2AClient::from_endpoint(&start(limited).await);
+    client.send_text("one").await.unwrap();
+    let err = client.send_text("two").await.unwrap_err();
+    let wait = err.retry_after().expect("retry-after in the error");
+    assert!(wait > Duration::from_secs(50) && wait <= Duration::from_secs(60));
+}
+
+#[test]
+fn test_buckets_refill() {
+    let limiter = RateLimiter::new().per_key(
+        "all",
+        RateLimit::new(1, Duration::from_millis(50)).with_burst(2),
+        |_| Some("everyone".to_string()),
+    );
+    let headers = HeaderMap::new();
+    let request = RateLimitRequest::new(&headers, "message/send");
+
+    let identities = limiter.check(&request).unwrap();
+    assert_eq!(
+        identities,
+        [CallerIdentity {
+            scope: RateLimitScope::Custom("all".to_string()),
+            key: "everyone".to_string()
+        }]
+    );
+    limiter.check(&request).unwrap();
+    let err = limiter.check(&request).unwrap_err();
+    assert_eq!(err.code(), error::RATE_LIMITED);
+    assert!(err.retry_after().unwrap() <= Duration::from_millis(50));
+
+    std::thread::sleep(Duration::from_millis(60));
+    limiter.check(&request).unwrap();
+    assert!(limiter.check(&request).is_err());
+}
+
+#[test]
+fn test_full_limiter_evicts_least_recently_used_bucket() {
+    let limiter = RateLimiter::new()
+        .per_key("caller", RateLimit::per_minute(1), |req| {
+            req.header("x-caller").map(str::to_string)
+        })
+        .with_max_buckets(2);
+    let check = |caller: &'static str| {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-caller", caller.parse().unwrap());
+        limiter.check(&RateLimitRequest::new(&headers, "message/send"))
+    };
+
+    check("a").unwrap();
+    check("b").unwrap();
+    assert!(check("a").is_err());
+    assert!(check("b").is_err());
+
+    // No bucket has refilled, so the new caller evicts the least recently
+    // used one, a's.
+    check("c").unwrap();
+    assert!(check("b").is_err());
+    check("a").unwrap();
+    assert!(check("a").is_err());
+}
@@ -135,6 +135,82 @@ async fn test_message_send_with_nonexistent_task_id_errors() {
     }
 }
 
+// ---- Task state machine ----
+
+/// Publishes raw status updates `working`, `submitted`, `completed`,
+/// bypassing the updater's checks.
+struct BackwardsAgent;
+
+#[async_trait]
+impl AgentExecutor for BackwardsAgent {
+    async fn execute(
+        &self,
+        context: RequestContext,
+        event_queue: EventQueue,
+    ) -> a2a_rs::error::A2AResult<()> {
+        for state in [
+            TaskState::Working,
+            TaskState::Submitted,
+            TaskState::Completed,
+        ] {
+            event_queue
+                .enqueue_event(StreamResponse::StatusUpdate(TaskStatusUpdateEvent {
+                    task_id: context.task_id.clone(),
+                    context_id: context.context_id.clone(),
+                    kind: "status-update".to_string(),
+                    status: TaskStatus::new(state),
+                    r#final: state == TaskState::Completed,
+                    metadata: None,
+                }))
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn cancel(
+        &self,
+        _context: RequestContext,
+        _event_queue: EventQueue,
+    ) -> a2a_rs::error::A2AResult<()> {
+        Ok(())
+    }
+}
+
+async fn streamed_states(handler: &DefaultRequestHandler) -> Vec<TaskState> {
+    let mut rx = handler
+        .on_message_send_stream(make_send_params("go"))
+        .await
+        .unwrap();
+    let mut states = Vec::new();
+    loop {
+        if let StreamResponse::StatusUpdate(update) = rx.recv().await.unwrap() {
+            states.push(update.status.state);
+            if update.r#final {
+                return states;
+            }
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_invalid_transition_is_dropped_from_stream() {
+    let handler = make_handler(Arc::new(BackwardsAgent));
+    assert_eq!(
+        streamed_states(&handler).await,
+        [TaskState::Working, TaskState::Completed]
+    );
+
+    let handler = make_handler(Arc::new(BackwardsAgent)).with_transition_checks(false);
+    assert_eq!(
+        streamed_states(&handler).await,
+        [
+            TaskState::Working,
+            TaskState::Submitted,
+            TaskState::Completed
+        ]
+    );
+}
+
 // ---- on_get_task tests ----
 
 #[tokio::test]
@@ -153,11 +229,144 @@ async fn test_get_task_returns_task() {
         history_length: None,
         metadata: None,
         tenant: None,
+        consistency: None,
     };
     let task = handler.on_get_task(get_params).await.unwrap();
     assert_eq!(task.status.state, TaskState::Completed);
 }
 
+async fn get_history(handler: &DefaultRequestHandler) -> Vec<Message> {
+    let response = handler
+        .on_message_send(make_send_params("Hello"))
+        .await
+        .unwrap();
+    let SendMessageResponse::Task(task) = response else {
+        panic!("Expected Task");
+    };
+    let get_params = a2a_rs::server::GetTaskParams {
+        id: task.id,
+        history_length: None,
+        metadata: None,
+        tenant: None,
+        consistency: None,
+    };
+    let task = handler.on_get_task(get_params).await.unwrap();
+    task.history.unwrap_or_default()
+}
+
+#[tokio::test]
+async fn test_get_task_history_includes_agent_reply() {
+    let handler = make_handler(Arc::new(ImmediateCompleteAgent));
+    let history = get_history(&handler).await;
+    assert_eq!(history.len(), 2);
+    assert_eq!(history[0].role, Role::User);
+    assert_eq!(history[1].role, Role::Agent);
+}
+
+#[tokio::test]
+async fn test_get_task_history_on_replace_policy() {
+    let handler = make_handler(Arc::new(ImmediateCompleteAgent))
+        .with_history_policy(a2a_rs::server::HistoryPolicy::OnReplace);
+    let history = get_history(&handler).await;
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].role, Role::User);
+}
+
+/// Agent that asks for a city, then answers.
+struct MultiTurnAgent;
+
+#[async_trait]
+impl AgentExecutor for MultiTurnAgent {
+    async fn execute(
+        &self,
+        context: RequestContext,
+        event_queue: EventQueue,
+    ) -> a2a_rs::error::A2AResult<()> {
+        let input = context.get_user_input("");
+        let resumed = context
+            .task
+            .as_ref()
+            .is_some_and(|task| task.status.state == TaskState::InputRequired);
+        let updater =
+            a2a_rs::server::TaskUpdater::new(event_queue, context.task_id, context.context_id);
+        if resumed {
+            return updater
+                .complete_with_text(&format!("Sunny in {input}"))
+                .await;
+        }
+        updater.start_work_with_text("Thinking").await?;
+        let prompt = updater.new_agent_message(vec![Part::text("Which city?")], None);
+        updater.requires_input(Some(prompt), true).await
+    }
+
+    async fn cancel(
+        &self,
+        _context: RequestContext,
+        _event_queue: EventQueue,
+    ) -> a2a_rs::error::A2AResult<()> {
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn test_get_task_history_reconstructs_conversation() {
+    let handler = make_handler(Arc::new(MultiTurnAgent));
+    let response = handler
+        .on_message_send(make_send_params("Weather?"))
+        .await
+        .unwrap();
+    let SendMessageResponse::Task(task) = response else {
+        panic!("Expected Task");
+    };
+    let mut follow_up = make_send_params_with_task_id("Paris", &task.id);
+    follow_up.message.message_id = "m2".to_string();
+    handler.on_message_send(follow_up).await.unwrap();
+
+    let get_params = a2a_rs::server::GetTaskParams {
+        id: task.id,
+        history_length: None,
+        metadata: None,
+        tenant: None,
+        consistency: None,
+    };
+    let task = handler.on_get_task(get_params).await.unwrap();
+    let turns: Vec<_> = task
+        .history
+        .unwrap()
+        .iter()
+        .map(|m| (m.role, a2a_rs::utils::get_message_text(m, "")))
+        .collect();
+    assert_eq!(
+        turns,
+        [
+            (Role::User, "Weather?".to_string()),
+            (Role::Agent, "Thinking".to_string()),
+            (Role::Agent, "Which city?".to_string()),
+            (Role::User, "Paris".to_string()),
+            (Role::Agent, "Sunny in Paris".to_string()),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_history_policy_follows_card_capability() {
+    let card = a2a_rs::AgentCardBuilder::new("Agent", "An agent", "1.0")
+        .with_state_transition_history(false)
+        .build();
+    let handler =
+        make_handler(Arc::new(ImmediateCompleteAgent)).with_history_policy_for_card(&card);
+    let history = get_history(&handler).await;
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].role, Role::User);
+
+    let card = a2a_rs::AgentCardBuilder::new("Agent", "An agent", "1.0")
+        .with_state_transition_history(true)
+        .build();
+    let handler =
+        make_handler(Arc::new(ImmediateCompleteAgent)).with_history_policy_for_card(&card);
+    assert_eq!(get_history(&handler).await.len(), 2);
+}
+
 #[tokio::test]
 async fn test_get_task_not_found() {
     let handler = make_handler(Arc::new(ImmediateCompleteAgent));
@@ -167,6 +376,7 @@ async fn test_get_task_not_found() {
         history_length: None,
         metadata: None,
         tenant: None,
+        consistency: None,
     };
     let result = handler.on_get_task(get_params).await;
     assert!(result.is_err());
@@ -188,6 +398,7 @@ async fn test_get_task_with_history_length() {
         history_length: Some(1),
         metadata: None,
         tenant: None,
+        consistency: None,
     };
     let task = handler.on_get_task(get_params).await.unwrap();
     if let Some(history) = &task.history {
@@ -195,6 +406,79 @@ async fn test_get_task_with_history_length() {
     }
 }
 
+/// Store that records the consistency hint passed to each read.
+struct RecordingConsistencyStore {
+    inner: InMemoryTaskStore,
+    seen: std::sync::Mutex<Vec<ReadConsistency>>,
+}
+
+#[async_trait]
+impl TaskStore for RecordingConsistencyStore {
+    async fn save(&self, task: Task) -> a2a_rs::error::A2AResult<()> {
+        self.inner.save(task).await
+    }
+
+    async fn get(&self, task_id: &str) -> a2a_rs::error::A2AResult<Option<Task>> {
+        self.inner.get(task_id).await
+    }
+
+    async fn get_with_consistency(
+        &self,
+        task_id: &str,
+        consistency: &ReadConsistency,
+    ) -> a2a_rs::error::A2AResult<Option<Task>> {
+        self.seen.lock().unwrap().push(*consistency);
+        self.inner.get(task_id).await
+    }
+
+    async fn delete(&self, task_id: &str) -> a2a_rs::error::A2AResult<()> {
+        self.inner.delete(task_id).await
+    }
+
+    async fn list(
+        &self,
+        params: &a2a_rs::server::TaskListParams,
+    ) -> a2a_rs::error::A2AResult<a2a_rs::server::TaskListResponse> {
+        self.inner.list(params).await
+    }
+}
+
+#[tokio::test]
+async fn test_get_task_passes_consistency_to_store() {
+    let store = Arc::new(RecordingConsistencyStore {
+        inner: InMemoryTaskStore::new(),
+        seen: std::sync::Mutex::new(Vec::new()),
+    });
+    let handler = DefaultRequestHandler::new(Arc::new(ImmediateCompleteAgent), store.clone());
+
+    let response = handler
+        .on_message_send(make_send_params("Hello"))
+        .await
+        .unwrap();
+    let task_id = match &response {
+        SendMessageResponse::Task(t) => t.id.clone(),
+        _ => panic!("Expected Task"),
+    };
+
+    for consistency in [None, Some(ReadConsistency::Primary)] {
+        handler
+            .on_get_task(a2a_rs::server::GetTaskParams {
+                id: task_id.clone(),
+                history_length: None,
+                metadata: None,
+                tenant: None,
+                consistency,
+            })
+            .await
+            .unwrap();
+    }
+
+    assert_eq!(
+        *store.seen.lock().unwrap(),
+        vec![ReadConsistency::Eventual, ReadConsistency::Primary]
+    );
+}
+
 // ---- on_cancel_task tests ----
 
 #[tokio::test]
@@ -331,6 +615,8 @@ async fn test_list_tasks_empty() {
         status: None,
         page_size: None,
         page_token: None,
+        history_length: None,
+        tenant: None,
     };
     let result = handler.on_list_tasks(params).await.unwrap();
     assert!(result.tasks.is_empty());
@@ -347,6 +633,8 @@ async fn test_list_tasks_after_send() {
         status: None,
         page_size: None,
         page_token: None,
+        history_length: None,
+        tenant: None,
     };
     let result = handler.on_list_tasks(list_params).await.unwrap();
     assert_eq!(result.tasks.len(), 1);
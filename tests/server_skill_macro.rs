@@ -0,0 +1,213 @@
+//! `#[skills]` (`macros` feature): dispatch by `skillId` metadata, the
+//! default skill, cancellation and the generated skills list.
+#![cfg(feature = "macros")]
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use a2a_rs::error::{A2AError, A2AResult};
+use a2a_rs::server::{skills, AgentExecutor, CancellationToken, EventQueue, RequestContext};
+use a2a_rs::types::*;
+use a2a_rs::AgentCardBuilder;
+use serde_json::json;
+
+#[derive(Default)]
+struct Toolbox {
+    cancels: AtomicUsize,
+}
+
+#[skills]
+impl Toolbox {
+    /// Translate text into French.
+    #[skill(
+        id = "translate",
+        name = "Translate",
+        tags("i18n", "text"),
+        examples("Translate 'hello'"),
+        default
+    )]
+    async fn translate(&self, context: RequestContext, queue: EventQueue) -> A2AResult<()> {
+        let reply = format!("fr: {}", context.get_user_input(" "));
+        context.task_updater(queue).complete_with_text(&reply).await
+    }
+
+    #[skill(
+        id = "summarize",
+        description = "Summarize a document",
+        input_modes("text/plain", "application/pdf"),
+        output_modes("text/plain")
+    )]
+    async fn summarize(&self, context: RequestContext, queue: EventQueue) -> A2AResult<()> {
+        context
+            .task_updater(queue)
+            .complete_with_text("summary")
+            .await
+    }
+
+    #[cancel]
+    async fn stop(&self, context: RequestContext, queue: EventQueue) -> A2AResult<()> {
+        self.cancels.fetch_add(1, Ordering::SeqCst);
+        context.task_updater(queue).cancel(None).await
+    }
+}
+
+/// One skill, no `default`, no `#[cancel]`.
+struct Echo;
+
+#[skills]
+impl Echo {
+    #[skill(id = "echo")]
+    async fn echo(&self, context: RequestContext, queue: EventQueue) -> A2AResult<()> {
+        let reply = context.get_user_input(" ");
+        context.task_updater(queue).complete_with_text(&reply).await
+    }
+}
+
+fn context(text: &str, message_skill: Option<&str>, request_skill: Option<&str>) -> RequestContext {
+    let mut message = Message::user("m1", text);
+    message.metadata = message_skill.map(|skill| json!({ "skillId": skill }));
+    RequestContext {
+        task_id: "t1".to_string(),
+        context_id: "c1".to_string(),
+        message: Some(message),
+        task: None,
+        configuration: None,
+        related_tasks: Vec::new(),
+        metadata: request_skill.map(|skill| json!({ "skillId": skill })),
+        tenant: None,
+        call_context: None,
+        task_store: None,
+        workspace: None,
+        cancellation_token: CancellationToken::new(),
+        incremental_message: None,
+        content_validator: None,
+    }
+}
+
+/// Run `executor` on `context` and return the final status text.
+async fn run(executor: &dyn AgentExecutor, context: RequestContext) -> A2AResult<String> {
+    let queue = EventQueue::new(16);
+    let mut rx = queue.subscribe();
+    executor.execute(context, queue).await?;
+    match rx.try_recv().unwrap() {
+        StreamResponse::StatusUpdate(update) => {
+            assert_eq!(update.status.state, TaskState::Completed);
+            Ok(update
+                .status
+                .message
+                .map(|m| get_text(&m))
+                .unwrap_or_default())
+        }
+        other => panic!("expected a status update, got {other:?}"),
+    }
+}
+
+fn get_text(message: &Message) -> String {
+    message
+        .parts
+        .iter()
+        .filter_map(|part| match part {
+            Part::Text { text, .. } => Some(text.as_str()),
+            _ => None,
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn test_dispatches_on_skill_id() {
+    let toolbox = Toolbox::default();
+    assert_eq!(
+        run(&toolbox, context("doc", Some("summarize"), None))
+            .await
+            .unwrap(),
+        "summary"
+    );
+    // The request metadata is used when the message names no skill, and
+    // the message's wins over it.
+    assert_eq!(
+        run(&toolbox, context("doc", None, Some("summarize")))
+            .await
+            .unwrap(),
+        "summary"
+    );
+    assert_eq!(
+        run(
+            &toolbox,
+            context("hi", Some("translate"), Some("summarize"))
+        )
+        .await
+        .unwrap(),
+        "fr: hi"
+    );
+}
+
+#[tokio::test]
+async fn test_message_without_skill_goes_to_default() {
+    assert_eq!(
+        run(&Toolbox::default(), context("hi", None, None))
+            .await
+            .unwrap(),
+        "fr: hi"
+    );
+    // A lone skill is the default.
+    assert_eq!(run(&Echo, context("hi", None, None)).await.unwrap(), "hi");
+}
+
+#[tokio::test]
+async fn test_unknown_skill_is_invalid_params() {
+    let err = run(&Echo, context("hi", Some("draw"), None))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, A2AError::InvalidParams { ref message, .. } if message.contains("draw")));
+}
+
+#[tokio::test]
+async fn test_cancel() {
+    let toolbox = Toolbox::default();
+    let queue = EventQueue::new(16);
+    let mut rx = queue.subscribe();
+    toolbox
+        .cancel(context("", None, None), queue.clone())
+        .await
+        .unwrap();
+    assert_eq!(toolbox.cancels.load(Ordering::SeqCst), 1);
+
+    // Without `#[cancel]` the task is simply canceled.
+    Echo.cancel(context("", None, None), queue).await.unwrap();
+    for _ in 0..2 {
+        match rx.try_recv().unwrap() {
+            StreamResponse::StatusUpdate(update) => {
+                assert_eq!(update.status.state, TaskState::Canceled)
+            }
+            other => panic!("expected a status update, got {other:?}"),
+        }
+    }
+}
+
+#[test]
+fn test_skills_for_agent_card() {
+    let card = AgentCardBuilder::new("Toolbox", "Tools", "1.0.0")
+        .with_skills(Toolbox::skills())
+        .build();
+    let json = serde_json::to_value(&card.skills).unwrap();
+    assert_eq!(
+        json,
+        json!([
+            {
+                "id": "translate",
+                "name": "Translate",
+                "description": "Translate text into French.",
+                "tags": ["i18n", "text"],
+                "examples": ["Translate 'hello'"]
+            },
+            {
+                "id": "summarize",
+                "name": "summarize",
+                "description": "Summarize a document",
+                "tags": [],
+                "inputModes": ["text/plain", "application/pdf"],
+                "outputModes": ["text/plain"]
+            }
+        ])
+    );
+    assert_eq!(Echo::skills()[0].description, "");
+}
@@ -0,0 +1,255 @@
+//! `SkillRouter`: dispatch by skill ID or another metadata key, the
+//! fallback executor, input-mode checks and routing cancellations.
+
+use std::sync::{Arc, Mutex};
+
+use a2a_rs::error::{A2AError, A2AResult};
+use a2a_rs::server::{AgentExecutor, CancellationToken, EventQueue, RequestContext, SkillRouter};
+use a2a_rs::types::*;
+use async_trait::async_trait;
+use serde_json::{json, Value};
+
+/// Records which calls it received.
+struct Recorder {
+    name: &'static str,
+    calls: Arc<Mutex<Vec<String>>>,
+}
+
+#[async_trait]
+impl AgentExecutor for Recorder {
+    async fn execute(&self, _context: RequestContext, _queue: EventQueue) -> A2AResult<()> {
+        self.calls
+            .lock()
+            .unwrap()
+            .push(format!("{}:execute", self.name));
+        Ok(())
+    }
+
+    async fn cancel(&self, _context: RequestContext, _queue: EventQueue) -> A2AResult<()> {
+        self.calls
+            .lock()
+            .unwrap()
+            .push(format!("{}:cancel", self.name));
+        Ok(())
+    }
+}
+
+fn recorder(name: &'static str, calls: &Arc<Mutex<Vec<String>>>) -> Arc<dyn AgentExecutor> {
+    Arc::new(Recorder {
+        name,
+        calls: calls.clone(),
+    })
+}
+
+fn skill(id: &str, input_modes: Option<Vec<&str>>) -> AgentSkill {
+    AgentSkill {
+        id: id.to_string(),
+        name: id.to_string(),
+        description: String::new(),
+        tags: Vec::new(),
+        examples: None,
+        input_modes: input_modes.map(|modes| modes.into_iter().map(str::to_string).collect()),
+        output_modes: None,
+        security_requirements: None,
+        security: None,
+    }
+}
+
+fn context(
+    parts: Vec<Part>,
+    message_metadata: Option<Value>,
+    metadata: Option<Value>,
+) -> RequestContext {
+    let mut message = Message::user("m1", "");
+    message.parts = parts;
+    message.metadata = message_metadata;
+    RequestContext {
+        task_id: "t1".to_string(),
+        context_id: "c1".to_string(),
+        message: Some(message),
+        task: None,
+        configuration: None,
+        related_tasks: Vec::new(),
+        metadata,
+        tenant: None,
+        call_context: None,
+        task_store: None,
+        workspace: None,
+        cancellation_token: CancellationToken::new(),
+        incremental_message: None,
+        content_validator: None,
+    }
+}
+
+fn text_for(skill_id: &str) -> RequestContext {
+    context(
+        vec![Part::text("hi")],
+        Some(json!({ "skillId": skill_id })),
+        None,
+    )
+}
+
+async fn execute(router: &SkillRouter, context: RequestContext) -> A2AResult<()> {
+    router.execute(context, EventQueue::new(16)).await
+}
+
+#[tokio::test]
+async fn test_routes_by_skill_id() {
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let router = SkillRouter::new()
+        .route("translate", recorder("translate", &calls))
+        .route_skill(&skill("summarize", None), recorder("summarize", &calls));
+
+    execute(&router, text_for("summarize")).await.unwrap();
+    execute(&router, text_for("translate")).await.unwrap();
+    // The request metadata is used when the message has none.
+    execute(
+        &router,
+        context(
+            vec![Part::text("hi")],
+            None,
+            Some(json!({ "skillId": "summarize" })),
+        ),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        *calls.lock().unwrap(),
+        [
+            "summarize:execute",
+            "translate:execute",
+            "summarize:execute"
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_routes_by_custom_metadata_key() {
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let router = SkillRouter::new()
+        .with_metadata_key("agent")
+        .route("billing", recorder("billing", &calls));
+
+    execute(
+        &router,
+        context(
+            vec![Part::text("hi")],
+            Some(json!({ "agent": "billing" })),
+            None,
+        ),
+    )
+    .await
+    .unwrap();
+    // `skillId` is not looked at any more.
+    let err = execute(&router, text_for("billing")).await.unwrap_err();
+    assert!(
+        matches!(err, A2AError::InvalidParams { ref message, .. } if message.contains("agent"))
+    );
+    assert_eq!(*calls.lock().unwrap(), ["billing:execute"]);
+}
+
+#[tokio::test]
+async fn test_unrouted_messages_go_to_fallback() {
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let router = SkillRouter::new()
+        .route("translate", recorder("translate", &calls))
+        .with_fallback(recorder("fallback", &calls));
+
+    execute(&router, text_for("draw")).await.unwrap();
+    execute(&router, context(vec![Part::text("hi")], None, None))
+        .await
+        .unwrap();
+    assert_eq!(
+        *calls.lock().unwrap(),
+        ["fallback:execute", "fallback:execute"]
+    );
+}
+
+#[tokio::test]
+async fn test_unrouted_messages_without_fallback_are_rejected() {
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let router = SkillRouter::new().route("translate", recorder("translate", &calls));
+
+    let err = execute(&router, text_for("draw")).await.unwrap_err();
+    assert!(matches!(err, A2AError::InvalidParams { ref message, .. } if message.contains("draw")));
+    let err = execute(&router, context(vec![Part::text("hi")], None, None))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, A2AError::InvalidParams { .. }));
+    assert!(calls.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_skill_input_modes_are_enforced() {
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let router = SkillRouter::new().route_skill(
+        &skill("vision", Some(vec!["image/*", "text/plain"])),
+        recorder("vision", &calls),
+    );
+    let metadata = || Some(json!({ "skillId": "vision" }));
+    let image = Part::file_from_bytes(
+        "iVBORw==",
+        Some("cat.png".to_string()),
+        Some("image/png".to_string()),
+    );
+
+    execute(
+        &router,
+        context(vec![Part::text("what is this?"), image], metadata(), None),
+    )
+    .await
+    .unwrap();
+    let err = execute(
+        &router,
+        context(vec![Part::data(json!({ "x": 1 }))], metadata(), None),
+    )
+    .await
+    .unwrap_err();
+    assert!(
+        matches!(err, A2AError::ContentTypeNotSupported { ref message, .. } if message.contains("application/json"))
+    );
+    assert_eq!(err.code(), -32005);
+    assert_eq!(*calls.lock().unwrap(), ["vision:execute"]);
+}
+
+#[tokio::test]
+async fn test_cancel_is_routed_by_task_history() {
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let router = SkillRouter::new()
+        .route("translate", recorder("translate", &calls))
+        .route("summarize", recorder("summarize", &calls));
+
+    let mut first = Message::user("m1", "hi");
+    first.metadata = Some(json!({ "skillId": "translate" }));
+    let mut second = Message::user("m2", "and this");
+    second.metadata = Some(json!({ "skillId": "summarize" }));
+    let task = Task {
+        id: "t1".to_string(),
+        context_id: "c1".to_string(),
+        kind: "task".to_string(),
+        status: TaskStatus::new(TaskState::Working),
+        artifacts: None,
+        history: Some(vec![first, second, Message::agent("m3", "working")]),
+        metadata: None,
+    };
+
+    let mut cancel = context(Vec::new(), None, None);
+    cancel.message = None;
+    cancel.task = Some(task);
+    router.cancel(cancel, EventQueue::new(16)).await.unwrap();
+    assert_eq!(*calls.lock().unwrap(), ["summarize:cancel"]);
+
+    // With nowhere to route it the task is canceled directly.
+    let queue = EventQueue::new(16);
+    let mut rx = queue.subscribe();
+    let mut cancel = context(Vec::new(), None, None);
+    cancel.message = None;
+    router.cancel(cancel, queue).await.unwrap();
+    match rx.try_recv().unwrap() {
+        StreamResponse::StatusUpdate(update) => {
+            assert_eq!(update.status.state, TaskState::Canceled)
+        }
+        other => panic!("expected a status update, got {other:?}"),
+    }
+}
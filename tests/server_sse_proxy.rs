@@ -0,0 +1,195 @@
+//! SSE delivery through a buffering HTTP/1.1 reverse proxy.
+//!
+//! The proxy follows nginx's `proxy_buffering on` behaviour: it reads the
+//! whole upstream body before answering unless the upstream response carries
+//! `X-Accel-Buffering: no`. It runs in-process rather than as a container so
+//! the test needs nothing beyond `cargo test`.
+
+mod common;
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use a2a_rs::error::A2AResult;
+use a2a_rs::server::{
+    a2a_router_with_sse_config, AgentExecutor, DefaultRequestHandler, EventQueue,
+    InMemoryTaskStore, RequestContext, SseConfig, TaskUpdater,
+};
+use axum::body::{Body, Bytes};
+use axum::extract::State;
+use axum::http::HeaderMap;
+use axum::response::Response;
+use axum::routing::post;
+use axum::Router;
+use futures::StreamExt;
+
+/// Gap between the `working` update and completion.
+const AGENT_PAUSE: Duration = Duration::from_millis(600);
+
+/// Reports `working`, pauses, then completes.
+struct PausingAgent;
+
+#[async_trait::async_trait]
+impl AgentExecutor for PausingAgent {
+    async fn execute(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        let updater = TaskUpdater::new(event_queue, context.task_id, context.context_id);
+        updater.start_work(None).await?;
+        tokio::time::sleep(AGENT_PAUSE).await;
+        updater.complete_with_text("done").await
+    }
+
+    async fn cancel(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        TaskUpdater::new(event_queue, context.task_id, context.context_id)
+            .cancel(None)
+            .await
+    }
+}
+
+async fn start_upstream(sse: SseConfig) -> String {
+    let handler = Arc::new(DefaultRequestHandler::new(
+        Arc::new(PausingAgent),
+        Arc::new(InMemoryTaskStore::new()),
+    ));
+    let (base_url, _server) = common::serve_router(|base_url| {
+        a2a_router_with_sse_config(
+            handler,
+            common::test_agent_card(&format!("{base_url}/a2a")),
+            sse,
+        )
+    })
+    .await;
+    base_url
+}
+
+async fn proxy(State(upstream): State<String>, body: Bytes) -> Response {
+    let response = reqwest::Client::new()
+        .post(format!("{upstream}/a2a"))
+        .header("content-type", "application/json")
+        .body(body)
+        .send()
+        .await
+        .unwrap();
+    let unbuffered = response
+        .headers()
+        .get("x-accel-buffering")
+        .is_some_and(|v| v.as_bytes().eq_ignore_ascii_case(b"no"));
+
+    let mut builder = Response::builder().status(response.status().as_u16());
+    for (name, value) in response.headers() {
+        if matches!(
+            name.as_str(),
+            "connection" | "content-length" | "transfer-encoding"
+        ) {
+            continue;
+        }
+        builder = builder.header(name.as_str(), value.as_bytes());
+    }
+    let body = if unbuffered {
+        Body::from_stream(response.bytes_stream())
+    } else {
+        Body::from(response.bytes().await.unwrap())
+    };
+    builder.body(body).unwrap()
+}
+
+/// Start a buffering proxy in front of an upstream A2A server configured
+/// with `sse`; returns the proxy's JSON-RPC URL.
+async fn start_behind_proxy(sse: SseConfig) -> String {
+    let upstream = start_upstream(sse).await;
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/a2a", listener.local_addr().unwrap());
+    let app = Router::new()
+        .route("/a2a", post(proxy))
+        .with_state(upstream);
+    tokio::spawn(async move {
+        axum::serve(listener, app).await.unwrap();
+    });
+    url
+}
+
+/// Stream a message through `url`; returns the response headers and the
+/// time until the first SSE event arrived.
+async fn first_event_latency(url: &str) -> (HeaderMap, Duration) {
+    let started = Instant::now();
+    let response = reqwest::Client::new()
+        .post(url)
+        .json(&common::jsonrpc_request(
+            serde_json::json!(1),
+            "message/stream",
+            serde_json::json!({
+                "message": {
+                    "messageId": "m1",
+                    "role": "user",
+                    "parts": [{"kind": "text", "text": "hi"}]
+                }
+            }),
+        ))
+        .send()
+        .await
+        .unwrap();
+    let headers = response.headers().clone();
+    let mut body = response.bytes_stream();
+    let mut received = Vec::new();
+    while let Some(chunk) = body.next().await {
+        received.extend_from_slice(&chunk.unwrap());
+        if received.windows(6).any(|w| w == b"data: ") {
+            return (headers, started.elapsed());
+        }
+    }
+    panic!("stream ended without an event");
+}
+
+#[tokio::test]
+async fn test_default_headers_prevent_proxy_buffering() {
+    let url = start_behind_proxy(SseConfig::default()).await;
+
+    let (headers, latency) = first_event_latency(&url).await;
+
+    assert_eq!(headers["x-accel-buffering"], "no");
+    assert_eq!(headers["cache-control"], "no-cache, no-transform");
+    assert_eq!(headers["content-type"], "text/event-stream");
+    assert!(
+        latency < AGENT_PAUSE / 2,
+        "first event took {latency:?} through the proxy"
+    );
+}
+
+#[tokio::test]
+async fn test_proxy_buffers_without_accel_header() {
+    let url = start_behind_proxy(SseConfig::default().without_header("x-accel-buffering")).await;
+
+    let (headers, latency) = first_event_latency(&url).await;
+
+    assert!(headers.get("x-accel-buffering").is_none());
+    assert!(
+        latency >= AGENT_PAUSE,
+        "proxy should have held the stream until it ended, got {latency:?}"
+    );
+}
+
+#[tokio::test]
+async fn test_custom_headers_are_sent() {
+    let sse = SseConfig::bare()
+        .with_header("Cache-Control", "no-store")
+        .unwrap()
+        .with_header("X-Accel-Buffering", "no")
+        .unwrap()
+        .with_header("x-stream-region", "eu-west")
+        .unwrap()
+        .with_keep_alive_interval(Duration::from_secs(5));
+    assert_eq!(sse.keep_alive_interval(), Duration::from_secs(5));
+    let url = start_behind_proxy(sse).await;
+
+    let (headers, _) = first_event_latency(&url).await;
+
+    assert_eq!(headers["cache-control"], "no-store");
+    assert_eq!(headers["x-stream-region"], "eu-west");
+}
+
+#[test]
+fn test_invalid_header_is_rejected() {
+    assert!(SseConfig::default().with_header("bad header", "x").is_err());
+    assert!(SseConfig::default()
+        .with_header("x-ok", "line\nbreak")
+        .is_err());
+}
@@ -0,0 +1,300 @@
+//! Subscription registry: open task streams are recorded and recovered by
+//! a new handler after a restart.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use a2a_rs::error::A2AResult;
+use a2a_rs::server::{
+    AgentExecutor, CancelTaskParams, DefaultRequestHandler, EventQueue, FileSubscriptionRegistry,
+    InMemorySubscriptionRegistry, InMemoryTaskStore, RequestContext, RequestHandler,
+    SendMessageParams, SubscribeToTaskParams, SubscriptionRecord, SubscriptionRegistry,
+    TaskUpdater,
+};
+use a2a_rs::types::*;
+use async_trait::async_trait;
+use tokio::sync::broadcast;
+
+/// Starts work and never finishes on "hang"; completes on anything else.
+struct HangingAgent;
+
+#[async_trait]
+impl AgentExecutor for HangingAgent {
+    async fn execute(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        let input = context.get_user_input(" ");
+        let updater = TaskUpdater::new(event_queue, context.task_id, context.context_id);
+        if input == "hang" {
+            updater.start_work(None).await?;
+            std::future::pending::<()>().await;
+        }
+        updater.complete_with_text("finished").await
+    }
+
+    async fn cancel(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        TaskUpdater::new(event_queue, context.task_id, context.context_id)
+            .cancel(None)
+            .await
+    }
+}
+
+fn handler(
+    store: &Arc<InMemoryTaskStore>,
+    registry: &Arc<dyn SubscriptionRegistry>,
+) -> DefaultRequestHandler {
+    DefaultRequestHandler::new(Arc::new(HangingAgent), store.clone())
+        .with_subscription_registry(registry.clone())
+}
+
+fn params(text: &str, task_id: Option<&str>) -> SendMessageParams {
+    let mut message = Message::user(uuid::Uuid::new_v4().to_string(), text);
+    message.task_id = task_id.map(String::from);
+    SendMessageParams {
+        message,
+        configuration: None,
+        metadata: None,
+        tenant: None,
+    }
+}
+
+fn subscribe_params(task_id: &str) -> SubscribeToTaskParams {
+    SubscribeToTaskParams {
+        id: task_id.to_string(),
+        metadata: None,
+        tenant: None,
+    }
+}
+
+async fn next(rx: &mut broadcast::Receiver<StreamResponse>) -> StreamResponse {
+    tokio::time::timeout(Duration::from_secs(5), rx.recv())
+        .await
+        .expect("event within 5s")
+        .unwrap()
+}
+
+async fn next_state(rx: &mut broadcast::Receiver<StreamResponse>) -> TaskState {
+    loop {
+        if let StreamResponse::StatusUpdate(update) = next(rx).await {
+            return update.status.state;
+        }
+    }
+}
+
+/// Start a hanging task on a first handler; returns its ID once the
+/// `working` status is published.
+async fn start_hanging_task(handler: &DefaultRequestHandler) -> String {
+    let mut rx = handler
+        .on_message_send_stream(params("hang", None))
+        .await
+        .unwrap();
+    loop {
+        if let StreamResponse::StatusUpdate(update) = next(&mut rx).await {
+            assert_eq!(update.status.state, TaskState::Working);
+            return update.task_id;
+        }
+    }
+}
+
+/// Wait until the registry holds exactly `expected` (pipeline updates are
+/// asynchronous).
+async fn wait_for_records(registry: &Arc<dyn SubscriptionRegistry>, expected: usize) {
+    for _ in 0..100 {
+        if registry.list().await.unwrap().len() == expected {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    panic!("registry never held {expected} record(s)");
+}
+
+#[tokio::test]
+async fn test_open_streams_are_recorded_until_final() {
+    let store = Arc::new(InMemoryTaskStore::new());
+    let registry: Arc<dyn SubscriptionRegistry> = Arc::new(InMemorySubscriptionRegistry::new());
+    let handler = handler(&store, &registry);
+
+    let task_id = start_hanging_task(&handler).await;
+    wait_for_records(&registry, 1).await;
+    let records = registry.list().await.unwrap();
+    assert_eq!(records[0].task_id, task_id);
+    assert_eq!(records[0].last_sequence, 1);
+
+    handler
+        .on_cancel_task(CancelTaskParams {
+            id: task_id,
+            metadata: None,
+            tenant: None,
+        })
+        .await
+        .unwrap();
+    wait_for_records(&registry, 0).await;
+}
+
+#[tokio::test]
+async fn test_recovered_stream_serves_snapshot_then_events() {
+    let store = Arc::new(InMemoryTaskStore::new());
+    let registry: Arc<dyn SubscriptionRegistry> = Arc::new(InMemorySubscriptionRegistry::new());
+    let task_id = start_hanging_task(&handler(&store, &registry)).await;
+    wait_for_records(&registry, 1).await;
+
+    // "Restart": a fresh handler over the same store and registry.
+    let restarted = handler(&store, &registry);
+    assert_eq!(restarted.recover().await.unwrap(), 1);
+
+    let mut rx = restarted
+        .on_subscribe_to_task(subscribe_params(&task_id))
+        .await
+        .unwrap();
+    match next(&mut rx).await {
+        StreamResponse::Task(task) => {
+            assert_eq!(task.id, task_id);
+            assert_eq!(task.status.state, TaskState::Working);
+        }
+        other => panic!("expected snapshot, got {other:?}"),
+    }
+
+    let canceled = restarted
+        .on_cancel_task(CancelTaskParams {
+            id: task_id.clone(),
+            metadata: None,
+            tenant: None,
+        })
+        .await
+        .unwrap();
+    assert_eq!(canceled.status.state, TaskState::Canceled);
+    assert_eq!(next_state(&mut rx).await, TaskState::Canceled);
+    wait_for_records(&registry, 0).await;
+}
+
+#[tokio::test]
+async fn test_follow_up_message_continues_recovered_stream() {
+    let store = Arc::new(InMemoryTaskStore::new());
+    let registry: Arc<dyn SubscriptionRegistry> = Arc::new(InMemorySubscriptionRegistry::new());
+    let task_id = start_hanging_task(&handler(&store, &registry)).await;
+    wait_for_records(&registry, 1).await;
+
+    let restarted = handler(&store, &registry);
+    restarted.recover().await.unwrap();
+    let mut rx = restarted
+        .on_resubscribe_to_task(subscribe_params(&task_id))
+        .await
+        .unwrap();
+    assert!(matches!(next(&mut rx).await, StreamResponse::Task(_)));
+
+    // The executor is started again; the subscriber stays attached and
+    // sequence numbers continue from the previous process.
+    restarted
+        .on_message_send_stream(params("hang", Some(&task_id)))
+        .await
+        .unwrap();
+    assert_eq!(next_state(&mut rx).await, TaskState::Working);
+    for _ in 0..100 {
+        if registry.list().await.unwrap()[0].last_sequence == 2 {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    assert_eq!(registry.list().await.unwrap()[0].last_sequence, 2);
+
+    restarted
+        .on_cancel_task(CancelTaskParams {
+            id: task_id,
+            metadata: None,
+            tenant: None,
+        })
+        .await
+        .unwrap();
+    assert_eq!(next_state(&mut rx).await, TaskState::Canceled);
+    wait_for_records(&registry, 0).await;
+}
+
+#[tokio::test]
+async fn test_recover_drops_stale_records() {
+    let store = Arc::new(InMemoryTaskStore::new());
+    let registry: Arc<dyn SubscriptionRegistry> = Arc::new(InMemorySubscriptionRegistry::new());
+    let handler = handler(&store, &registry);
+    let finished = handler.on_message_send(params("done", None)).await.unwrap();
+    let SendMessageResponse::Task(finished) = finished else {
+        panic!("expected task");
+    };
+    for task_id in [finished.id.as_str(), "no-such-task"] {
+        registry
+            .save(SubscriptionRecord {
+                task_id: task_id.to_string(),
+                context_id: "ctx".to_string(),
+                last_sequence: 4,
+            })
+            .await
+            .unwrap();
+    }
+
+    assert_eq!(handler.recover().await.unwrap(), 0);
+    assert!(registry.list().await.unwrap().is_empty());
+    assert!(handler
+        .on_subscribe_to_task(subscribe_params("no-such-task"))
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_recover_without_registry_is_a_no_op() {
+    let handler =
+        DefaultRequestHandler::new(Arc::new(HangingAgent), Arc::new(InMemoryTaskStore::new()));
+    assert_eq!(handler.recover().await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn test_file_registry_persists_records() {
+    let dir = std::env::temp_dir().join(format!("a2a-subs-test-{}", uuid::Uuid::new_v4()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("subscriptions.json");
+    let record = |task_id: &str, last_sequence| SubscriptionRecord {
+        task_id: task_id.to_string(),
+        context_id: "ctx".to_string(),
+        last_sequence,
+    };
+
+    let registry = FileSubscriptionRegistry::open(&path).unwrap();
+    assert!(registry.list().await.unwrap().is_empty());
+    assert!(!path.exists(), "created on first change");
+    registry.save(record("a", 1)).await.unwrap();
+    registry.save(record("b", 2)).await.unwrap();
+    registry.save(record("a", 3)).await.unwrap();
+    registry.remove("b").await.unwrap();
+    registry.remove("missing").await.unwrap();
+
+    let reopened = FileSubscriptionRegistry::open(&path).unwrap();
+    assert_eq!(reopened.list().await.unwrap(), [record("a", 3)]);
+    let files: Vec<_> = std::fs::read_dir(&dir).unwrap().collect();
+    assert_eq!(files.len(), 1, "no temporary file left behind");
+
+    std::fs::write(&path, "not json").unwrap();
+    let err = FileSubscriptionRegistry::open(&path).unwrap_err();
+    assert!(err.to_string().contains("subscriptions.json"), "{err}");
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[tokio::test]
+async fn test_recovery_from_file_registry() {
+    let dir = std::env::temp_dir().join(format!("a2a-subs-test-{}", uuid::Uuid::new_v4()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("subscriptions.json");
+    let store = Arc::new(InMemoryTaskStore::new());
+
+    let registry: Arc<dyn SubscriptionRegistry> =
+        Arc::new(FileSubscriptionRegistry::open(&path).unwrap());
+    let task_id = start_hanging_task(&handler(&store, &registry)).await;
+    wait_for_records(&registry, 1).await;
+
+    let reopened: Arc<dyn SubscriptionRegistry> =
+        Arc::new(FileSubscriptionRegistry::open(&path).unwrap());
+    let restarted = handler(&store, &reopened);
+    assert_eq!(restarted.recover().await.unwrap(), 1);
+    let mut rx = restarted
+        .on_subscribe_to_task(subscribe_params(&task_id))
+        .await
+        .unwrap();
+    assert!(matches!(next(&mut rx).await, StreamResponse::Task(t) if t.id == task_id));
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
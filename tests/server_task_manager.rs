@@ -1,9 +1,11 @@
 //! Tests for TaskManager — ported from Python SDK's
 //! tests/server/tasks/test_task_manager.py
 
-use a2a_rs::server::task_manager::{append_artifact_to_task, TaskEvent, TaskManager};
-use a2a_rs::server::task_store::InMemoryTaskStore;
-use a2a_rs::server::TaskStore;
+use a2a_rs::server::task_manager::{
+    append_artifact_to_task, HistoryPolicy, TaskEvent, TaskManager,
+};
+use a2a_rs::server::task_store::{InMemoryTaskStore, TaskListParams, TaskListResponse};
+use a2a_rs::server::{TaskStore, TaskUpdate, VersionedTask};
 use a2a_rs::types::*;
 
 fn make_task(id: &str, ctx: &str) -> Task {
@@ -15,6 +17,7 @@ fn make_task(id: &str, ctx: &str) -> Task {
             state: TaskState::Submitted,
             message: None,
             timestamp: None,
+            raw_state: None,
         },
         artifacts: None,
         history: None,
@@ -22,6 +25,7 @@ fn make_task(id: &str, ctx: &str) -> Task {
     }
 }
 
+#[allow(dead_code)]
 fn make_task_with_state(id: &str, ctx: &str, state: TaskState) -> Task {
     let mut t = make_task(id, ctx);
     t.status.state = state;
@@ -37,6 +41,7 @@ fn make_status_event(task_id: &str, ctx_id: &str, state: TaskState) -> TaskStatu
             state,
             message: None,
             timestamp: None,
+            raw_state: None,
         },
         r#final: matches!(
             state,
@@ -332,6 +337,99 @@ async fn test_status_message_moved_to_history() {
     assert_eq!(task.status.message.unwrap().message_id, "m2");
 }
 
+#[tokio::test]
+async fn test_on_replace_policy_keeps_final_status_out_of_history() {
+    let store = Box::new(InMemoryTaskStore::new());
+    store.save(make_task("t1", "ctx1")).await.unwrap();
+    let mut mgr = TaskManager::new(Some("t1".to_string()), None, store, None).unwrap();
+    assert_eq!(mgr.history_policy(), HistoryPolicy::OnReplace);
+
+    let mut event = make_status_event("t1", "ctx1", TaskState::Completed);
+    event.status.message = Some(Message::agent("m1", "Done"));
+    let task = mgr
+        .save_task_event(TaskEvent::StatusUpdate(event))
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(task.history.unwrap_or_default().is_empty());
+
+    let reply = StreamResponse::Message(Message::agent("m2", "Direct"));
+    mgr.process(reply).await.unwrap();
+    let task = mgr.get_task().await.unwrap().unwrap();
+    assert!(task.history.unwrap_or_default().is_empty());
+}
+
+#[tokio::test]
+async fn test_agent_messages_policy_records_conversation() {
+    let store = Box::new(InMemoryTaskStore::new());
+    let mut task = make_task("t1", "ctx1");
+    task.history = Some(vec![Message::user("u1", "Hi")]);
+    store.save(task).await.unwrap();
+    let mut mgr = TaskManager::new(Some("t1".to_string()), None, store, None)
+        .unwrap()
+        .with_history_policy(HistoryPolicy::AgentMessages);
+
+    let mut event = make_status_event("t1", "ctx1", TaskState::Working);
+    event.status.message = Some(Message::agent("m1", "Thinking"));
+    mgr.save_task_event(TaskEvent::StatusUpdate(event))
+        .await
+        .unwrap();
+
+    mgr.process(StreamResponse::Message(Message::agent("m2", "Partial")))
+        .await
+        .unwrap();
+
+    // Replacing m1 must not record it twice.
+    let mut event = make_status_event("t1", "ctx1", TaskState::Completed);
+    event.status.message = Some(Message::agent("m3", "Done"));
+    let task = mgr
+        .save_task_event(TaskEvent::StatusUpdate(event))
+        .await
+        .unwrap()
+        .unwrap();
+
+    let ids: Vec<_> = task
+        .history
+        .unwrap()
+        .iter()
+        .map(|m| m.message_id.clone())
+        .collect();
+    assert_eq!(ids, vec!["u1", "m1", "m2", "m3"]);
+    assert_eq!(task.status.message.unwrap().message_id, "m3");
+}
+
+#[tokio::test]
+async fn test_agent_messages_policy_ignores_user_status_messages() {
+    let store = Box::new(InMemoryTaskStore::new());
+    store.save(make_task("t1", "ctx1")).await.unwrap();
+    let mut mgr = TaskManager::new(Some("t1".to_string()), None, store, None)
+        .unwrap()
+        .with_history_policy(HistoryPolicy::AgentMessages);
+
+    let mut event = make_status_event("t1", "ctx1", TaskState::Working);
+    event.status.message = Some(Message::user("u1", "echo"));
+    let task = mgr
+        .save_task_event(TaskEvent::StatusUpdate(event))
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(task.history.unwrap_or_default().is_empty());
+}
+
+#[test]
+fn test_history_policy_for_capabilities() {
+    let policy = |state_transition_history| {
+        HistoryPolicy::for_capabilities(&AgentCapabilities {
+            state_transition_history,
+            ..Default::default()
+        })
+    };
+    assert_eq!(policy(Some(true)), HistoryPolicy::AgentMessages);
+    assert_eq!(policy(Some(false)), HistoryPolicy::OnReplace);
+    // Agents that don't declare the capability keep the default.
+    assert_eq!(policy(None), HistoryPolicy::OnReplace);
+}
+
 // ---- update_with_message tests ----
 
 #[test]
@@ -626,6 +724,181 @@ async fn test_multiple_status_transitions() {
     assert_eq!(task.status.state, TaskState::Completed);
 }
 
+// ---- State machine ----
+
+#[tokio::test]
+async fn test_leaving_terminal_state_is_refused() {
+    let store = std::sync::Arc::new(InMemoryTaskStore::new());
+    store
+        .save(make_task_with_state("t1", "ctx1", TaskState::Completed))
+        .await
+        .unwrap();
+    let mut mgr = TaskManager::new(
+        Some("t1".to_string()),
+        Some("ctx1".to_string()),
+        Box::new(store.clone()),
+        None,
+    )
+    .unwrap();
+
+    let event = make_status_event("t1", "ctx1", TaskState::Working);
+    let err = mgr
+        .save_task_event(TaskEvent::StatusUpdate(event))
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(err, a2a_rs::A2AError::InvalidStateTransition { .. }),
+        "{err}"
+    );
+    let snapshot = make_task_with_state("t1", "ctx1", TaskState::Failed);
+    assert!(mgr
+        .save_task_event(TaskEvent::Task(snapshot))
+        .await
+        .is_err());
+
+    let stored = store.get("t1").await.unwrap().unwrap();
+    assert_eq!(stored.status.state, TaskState::Completed);
+}
+
+#[tokio::test]
+async fn test_back_to_submitted_is_refused_unless_checks_are_off() {
+    let store = std::sync::Arc::new(InMemoryTaskStore::new());
+    store
+        .save(make_task_with_state("t1", "ctx1", TaskState::Working))
+        .await
+        .unwrap();
+    let mut mgr = TaskManager::new(
+        Some("t1".to_string()),
+        Some("ctx1".to_string()),
+        Box::new(store.clone()),
+        None,
+    )
+    .unwrap();
+    let event = make_status_event("t1", "ctx1", TaskState::Submitted);
+    assert!(mgr
+        .save_task_event(TaskEvent::StatusUpdate(event.clone()))
+        .await
+        .is_err());
+
+    let mut mgr = mgr.with_transition_checks(false);
+    let task = mgr
+        .save_task_event(TaskEvent::StatusUpdate(event))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(task.status.state, TaskState::Submitted);
+}
+
+// ---- Concurrent writers ----
+
+/// Store letting another writer move task `t1` to `interfering` right
+/// after the manager's first versioned read.
+struct RacingStore {
+    inner: InMemoryTaskStore,
+    interfering: std::sync::Mutex<Option<TaskState>>,
+}
+
+#[async_trait::async_trait]
+impl TaskStore for RacingStore {
+    async fn save(&self, task: Task) -> a2a_rs::A2AResult<()> {
+        self.inner.save(task).await
+    }
+
+    async fn get(&self, task_id: &str) -> a2a_rs::A2AResult<Option<Task>> {
+        self.inner.get(task_id).await
+    }
+
+    async fn get_versioned(&self, task_id: &str) -> a2a_rs::A2AResult<Option<VersionedTask>> {
+        let read = self.inner.get_versioned(task_id).await?;
+        let interfering = self.interfering.lock().unwrap().take();
+        if let Some(state) = interfering {
+            self.inner
+                .update(
+                    task_id,
+                    None,
+                    Box::new(move |task| {
+                        task.status.state = state;
+                        Ok(())
+                    }),
+                )
+                .await?;
+        }
+        Ok(read)
+    }
+
+    async fn update(
+        &self,
+        task_id: &str,
+        expected_version: Option<u64>,
+        update: TaskUpdate,
+    ) -> a2a_rs::A2AResult<Task> {
+        self.inner.update(task_id, expected_version, update).await
+    }
+
+    async fn delete(&self, task_id: &str) -> a2a_rs::A2AResult<()> {
+        self.inner.delete(task_id).await
+    }
+
+    async fn list(&self, params: &TaskListParams) -> a2a_rs::A2AResult<TaskListResponse> {
+        self.inner.list(params).await
+    }
+}
+
+async fn racing_manager(interfering: TaskState) -> (std::sync::Arc<RacingStore>, TaskManager) {
+    let store = std::sync::Arc::new(RacingStore {
+        inner: InMemoryTaskStore::new(),
+        interfering: std::sync::Mutex::new(Some(interfering)),
+    });
+    store
+        .save(make_task_with_state("t1", "ctx1", TaskState::Working))
+        .await
+        .unwrap();
+    let mgr = TaskManager::new(
+        Some("t1".to_string()),
+        Some("ctx1".to_string()),
+        Box::new(store.clone()),
+        None,
+    )
+    .unwrap();
+    (store, mgr)
+}
+
+#[tokio::test]
+async fn test_snapshot_does_not_overwrite_a_concurrent_cancel() {
+    let (store, mut mgr) = racing_manager(TaskState::Canceled).await;
+
+    // Checked against `working`, but the task was canceled before the
+    // write: the retry sees the cancellation and refuses the snapshot.
+    let snapshot = make_task_with_state("t1", "ctx1", TaskState::Completed);
+    let err = mgr
+        .save_task_event(TaskEvent::Task(snapshot))
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(err, a2a_rs::A2AError::InvalidStateTransition { .. }),
+        "{err}"
+    );
+    let stored = store.get("t1").await.unwrap().unwrap();
+    assert_eq!(stored.status.state, TaskState::Canceled);
+}
+
+#[tokio::test]
+async fn test_snapshot_is_retried_after_a_conflict() {
+    let (store, mut mgr) = racing_manager(TaskState::InputRequired).await;
+
+    let snapshot = make_task_with_state("t1", "ctx1", TaskState::Completed);
+    let task = mgr
+        .save_task_event(TaskEvent::Task(snapshot))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(task.status.state, TaskState::Completed);
+    // Saved, the other writer's update, and the retried snapshot.
+    let stored = store.get_versioned("t1").await.unwrap().unwrap();
+    assert_eq!(stored.task.status.state, TaskState::Completed);
+    assert_eq!(stored.version, 3);
+}
+
 // ---- Metadata merging ----
 
 #[tokio::test]
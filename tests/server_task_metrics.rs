@@ -0,0 +1,174 @@
+//! Task state gauges driven by `ObservedTaskStore` change notifications.
+
+use std::sync::{Arc, Mutex};
+
+use a2a_rs::server::{
+    openmetrics_router, DefaultRequestHandler, InMemoryTaskStore, ObservedTaskStore,
+    RequestHandler, SendMessageParams, TaskStateGauges, TaskStore, TaskStoreChange,
+    TaskStoreObserver, OPENMETRICS_CONTENT_TYPE,
+};
+use a2a_rs::types::*;
+
+mod common;
+
+fn task(id: &str, state: TaskState, tenant: Option<&str>) -> Task {
+    let mut task: Task = serde_json::from_value(serde_json::json!({
+        "kind": "task",
+        "id": id,
+        "contextId": "c1",
+        "status": {"state": state.as_str()},
+    }))
+    .unwrap();
+    task.metadata = tenant.map(|t| serde_json::json!({ "tenant": t }));
+    task
+}
+
+fn observed(gauges: Arc<TaskStateGauges>) -> ObservedTaskStore<InMemoryTaskStore> {
+    ObservedTaskStore::new(InMemoryTaskStore::new())
+        .with_observer(gauges)
+        .with_tenant_resolver(|task| {
+            task.metadata
+                .as_ref()?
+                .get("tenant")?
+                .as_str()
+                .map(String::from)
+        })
+}
+
+#[derive(Default)]
+struct Recorder(Mutex<Vec<TaskStoreChange>>);
+
+impl TaskStoreObserver for Recorder {
+    fn on_change(&self, change: &TaskStoreChange) {
+        self.0.lock().unwrap().push(change.clone());
+    }
+}
+
+#[tokio::test]
+async fn test_gauges_follow_transitions_per_tenant() {
+    let gauges = Arc::new(TaskStateGauges::new());
+    let store = observed(gauges.clone());
+
+    store
+        .save(task("t1", TaskState::Working, Some("acme")))
+        .await
+        .unwrap();
+    store
+        .save(task("t2", TaskState::Working, Some("acme")))
+        .await
+        .unwrap();
+    store
+        .save(task("t3", TaskState::InputRequired, None))
+        .await
+        .unwrap();
+    assert_eq!(gauges.count(Some("acme"), TaskState::Working), 2);
+    assert_eq!(gauges.count(None, TaskState::InputRequired), 1);
+
+    store
+        .save(task("t1", TaskState::Completed, Some("acme")))
+        .await
+        .unwrap();
+    store.delete("t2").await.unwrap();
+    assert_eq!(gauges.count(Some("acme"), TaskState::Working), 0);
+    assert_eq!(gauges.count(Some("acme"), TaskState::Completed), 1);
+    assert_eq!(gauges.count(None, TaskState::Working), 0);
+}
+
+#[tokio::test]
+async fn test_resave_in_same_state_keeps_entry_time() {
+    let gauges = Arc::new(TaskStateGauges::new());
+    let store = observed(gauges.clone());
+
+    store
+        .save(task("t1", TaskState::Working, None))
+        .await
+        .unwrap();
+    let since = |g: &TaskStateGauges| {
+        g.snapshot()
+            .into_iter()
+            .find(|g| g.state == TaskState::Working)
+            .and_then(|g| g.oldest_since)
+            .unwrap()
+    };
+    let first = since(&gauges);
+    tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+    store
+        .save(task("t1", TaskState::Working, None))
+        .await
+        .unwrap();
+    assert_eq!(since(&gauges), first);
+}
+
+#[tokio::test]
+async fn test_openmetrics_rendering() {
+    let gauges = Arc::new(TaskStateGauges::new());
+    let store = observed(gauges.clone());
+    store
+        .save(task("t1", TaskState::Working, Some("a\"b")))
+        .await
+        .unwrap();
+
+    let text = gauges.render_openmetrics();
+    assert!(text.starts_with("# TYPE a2a_tasks gauge\n"));
+    assert!(text.contains("a2a_tasks{tenant=\"a\\\"b\",state=\"working\"} 1\n"));
+    assert!(text.contains("a2a_tasks{tenant=\"a\\\"b\",state=\"input-required\"} 0\n"));
+    assert!(
+        text.contains("a2a_tasks_oldest_timestamp_seconds{tenant=\"a\\\"b\",state=\"working\"} ")
+    );
+    assert!(
+        !text.contains("a2a_tasks_oldest_timestamp_seconds{tenant=\"a\\\"b\",state=\"completed\"}")
+    );
+    assert!(text.ends_with("# EOF\n"));
+}
+
+#[tokio::test]
+async fn test_replay_seeds_from_existing_tasks() {
+    let inner = InMemoryTaskStore::new();
+    inner
+        .save(task("t1", TaskState::Working, None))
+        .await
+        .unwrap();
+    inner
+        .save(task("t2", TaskState::Submitted, None))
+        .await
+        .unwrap();
+
+    let recorder = Arc::new(Recorder::default());
+    let store = ObservedTaskStore::new(inner).with_observer(recorder.clone());
+    store.replay().await.unwrap();
+
+    {
+        let changes = recorder.0.lock().unwrap();
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].state, Some(TaskState::Working));
+    }
+
+    // Deleting an unknown task reports nothing.
+    store.delete("missing").await.unwrap();
+    assert_eq!(recorder.0.lock().unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_request_handler_updates_gauges_and_router_serves_them() {
+    let gauges = Arc::new(TaskStateGauges::new());
+    let store = Arc::new(observed(gauges.clone()));
+    let handler = DefaultRequestHandler::new(Arc::new(common::EchoAgent), store);
+
+    handler
+        .on_message_send(SendMessageParams {
+            message: Message::user("m1", "hi"),
+            configuration: None,
+            metadata: None,
+            tenant: None,
+        })
+        .await
+        .unwrap();
+    assert_eq!(gauges.count(None, TaskState::Completed), 1);
+    assert_eq!(gauges.count(None, TaskState::Working), 0);
+
+    let (base_url, _server) = common::serve_router(|_| openmetrics_router(gauges)).await;
+    let response = reqwest::get(format!("{base_url}/metrics")).await.unwrap();
+    assert_eq!(response.headers()["content-type"], OPENMETRICS_CONTENT_TYPE);
+    let body = response.text().await.unwrap();
+    assert!(body.contains("a2a_tasks{tenant=\"\",state=\"completed\"} 1\n"));
+}
@@ -0,0 +1,194 @@
+//! Task retention: `TaskStore::purge`, `TaskSweeper` limits by age and
+//! count, and the sweeper `ServerBuilder::with_retention` runs.
+
+mod common;
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use a2a_rs::builders::ServerBuilder;
+use a2a_rs::server::{
+    InMemoryKvStore, InMemoryTaskStore, KvTaskStore, ObservedTaskStore, RetentionPolicy,
+    SweepReport, TaskListParams, TaskStore, TaskStoreChange, TaskStoreObserver, TaskSweeper,
+};
+use a2a_rs::types::*;
+use chrono::Utc;
+use common::EchoAgent;
+
+/// A task that entered `state` `age` ago.
+fn task_aged(id: &str, state: TaskState, age: Duration) -> Task {
+    let timestamp = Utc::now() - chrono::Duration::from_std(age).unwrap();
+    Task {
+        id: id.to_string(),
+        context_id: "ctx".to_string(),
+        kind: "task".to_string(),
+        status: TaskStatus::with_timestamp(state, timestamp.to_rfc3339()),
+        artifacts: None,
+        history: None,
+        metadata: None,
+    }
+}
+
+const HOUR: Duration = Duration::from_secs(3600);
+
+async fn ids(store: &dyn TaskStore) -> Vec<String> {
+    let mut ids: Vec<String> = store
+        .list(&TaskListParams::default())
+        .await
+        .unwrap()
+        .tasks
+        .into_iter()
+        .map(|task| task.id)
+        .collect();
+    ids.sort();
+    ids
+}
+
+/// Old and recent tasks in every kind of state.
+async fn seed(store: &dyn TaskStore) {
+    for task in [
+        task_aged("old-completed", TaskState::Completed, 3 * HOUR),
+        task_aged("old-failed", TaskState::Failed, 2 * HOUR),
+        task_aged("old-working", TaskState::Working, 3 * HOUR),
+        task_aged("new-completed", TaskState::Completed, Duration::ZERO),
+    ] {
+        store.save(task).await.unwrap();
+    }
+}
+
+async fn check_purge(store: &dyn TaskStore) {
+    seed(store).await;
+    let purged = store.purge(Utc::now() - chrono::Duration::hours(1)).await;
+    let mut purged: Vec<String> = purged.unwrap().into_iter().map(|task| task.id).collect();
+    purged.sort();
+    assert_eq!(purged, ["old-completed", "old-failed"]);
+    assert_eq!(ids(store).await, ["new-completed", "old-working"]);
+}
+
+#[tokio::test]
+async fn test_purge_removes_only_old_finished_tasks() {
+    check_purge(&InMemoryTaskStore::new()).await;
+    check_purge(&KvTaskStore::new(Arc::new(InMemoryKvStore::new()))).await;
+}
+
+#[tokio::test]
+async fn test_sweeper_expires_by_age() {
+    let store = Arc::new(InMemoryTaskStore::new());
+    seed(&*store).await;
+    let policy = RetentionPolicy::default().with_max_terminal_age(HOUR);
+
+    let report = TaskSweeper::new(store.clone(), policy)
+        .sweep()
+        .await
+        .unwrap();
+    assert_eq!(
+        report,
+        SweepReport {
+            expired: 2,
+            evicted: 0
+        }
+    );
+    assert_eq!(ids(&*store).await, ["new-completed", "old-working"]);
+}
+
+#[tokio::test]
+async fn test_sweeper_evicts_oldest_finished_tasks_over_capacity() {
+    let store = Arc::new(InMemoryTaskStore::new());
+    seed(&*store).await;
+    let sweeper = TaskSweeper::new(store.clone(), RetentionPolicy::default().with_max_tasks(2));
+
+    let report = sweeper.sweep().await.unwrap();
+    assert_eq!(report.evicted, 2);
+    assert_eq!(ids(&*store).await, ["new-completed", "old-working"]);
+
+    // Running tasks are never evicted, even over capacity.
+    let sweeper = TaskSweeper::new(store.clone(), RetentionPolicy::default().with_max_tasks(0));
+    assert_eq!(sweeper.sweep().await.unwrap().evicted, 1);
+    assert_eq!(ids(&*store).await, ["old-working"]);
+}
+
+#[derive(Default)]
+struct Deletions(Mutex<Vec<String>>);
+
+impl TaskStoreObserver for Deletions {
+    fn on_change(&self, change: &TaskStoreChange) {
+        if change.state.is_none() {
+            self.0.lock().unwrap().push(change.task_id.clone());
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_observed_store_reports_purged_tasks() {
+    let deletions = Arc::new(Deletions::default());
+    let store = ObservedTaskStore::new(InMemoryTaskStore::new()).with_observer(deletions.clone());
+    seed(&store).await;
+
+    store
+        .purge(Utc::now() - chrono::Duration::hours(1))
+        .await
+        .unwrap();
+    let mut deleted = deletions.0.lock().unwrap().clone();
+    deleted.sort();
+    assert_eq!(deleted, ["old-completed", "old-failed"]);
+}
+
+#[tokio::test]
+async fn test_server_builder_runs_the_sweeper() {
+    let store = Arc::new(InMemoryTaskStore::new());
+    seed(&*store).await;
+    let (stop, stopped) = tokio::sync::oneshot::channel::<()>();
+    let _app = ServerBuilder::new(Arc::new(EchoAgent))
+        .with_task_store(store.clone())
+        .with_retention(
+            RetentionPolicy::default()
+                .with_max_terminal_age(HOUR)
+                .with_sweep_interval(Duration::from_millis(10)),
+        )
+        .with_graceful_shutdown(async move {
+            let _ = stopped.await;
+        })
+        .build();
+
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert_eq!(ids(&*store).await, ["new-completed", "old-working"]);
+
+    // After shutdown, nothing is swept any more.
+    stop.send(()).unwrap();
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    store
+        .save(task_aged("late", TaskState::Completed, 2 * HOUR))
+        .await
+        .unwrap();
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert!(ids(&*store).await.contains(&"late".to_string()));
+}
+
+#[cfg(feature = "observability")]
+#[tokio::test]
+async fn test_evictions_are_reported_to_metrics() {
+    use a2a_rs::server::{OpenMetricsRecorder, EVICTION_CAPACITY, EVICTION_EXPIRED};
+
+    let store = Arc::new(InMemoryTaskStore::new());
+    seed(&*store).await;
+    store
+        .save(task_aged("newer-completed", TaskState::Completed, HOUR / 2))
+        .await
+        .unwrap();
+    let metrics = Arc::new(OpenMetricsRecorder::new());
+    let policy = RetentionPolicy::default()
+        .with_max_terminal_age(HOUR)
+        .with_max_tasks(2);
+
+    TaskSweeper::new(store.clone(), policy)
+        .with_metrics(metrics.clone())
+        .sweep()
+        .await
+        .unwrap();
+    assert_eq!(metrics.tasks_evicted(EVICTION_EXPIRED), 2);
+    assert_eq!(metrics.tasks_evicted(EVICTION_CAPACITY), 1);
+    assert!(metrics
+        .render_openmetrics()
+        .contains("a2a_tasks_evicted_total{reason=\"capacity\"} 1"));
+    assert_eq!(ids(&*store).await, ["new-completed", "old-working"]);
+}
@@ -1,8 +1,11 @@
 //! Tests for InMemoryTaskStore — ported from Python SDK's
 //! tests/server/tasks/test_inmemory_task_store.py
 
-use a2a_rs::server::task_store::{TaskListParams, TaskListResponse};
-use a2a_rs::server::{InMemoryTaskStore, TaskStore};
+use std::sync::Arc;
+
+use a2a_rs::error::A2AError;
+use a2a_rs::server::task_store::TaskListParams;
+use a2a_rs::server::{InMemoryKvStore, InMemoryTaskStore, KvTaskStore, TaskStore};
 use a2a_rs::types::*;
 
 fn make_task(id: &str, ctx: &str) -> Task {
@@ -14,6 +17,7 @@ fn make_task(id: &str, ctx: &str) -> Task {
             state: TaskState::Submitted,
             message: None,
             timestamp: None,
+            raw_state: None,
         },
         artifacts: None,
         history: None,
@@ -30,6 +34,7 @@ fn make_task_with_state(id: &str, ctx: &str, state: TaskState) -> Task {
             state,
             message: None,
             timestamp: None,
+            raw_state: None,
         },
         artifacts: None,
         history: None,
@@ -312,11 +317,174 @@ async fn test_task_update_preserves_order() {
     assert_eq!(result.tasks[1].status.state, TaskState::Completed);
 }
 
+// ---- Transactional update tests ----
+
+/// Append `n` history messages to `t1` from concurrent updates.
+async fn concurrent_appends(store: Arc<dyn TaskStore>, n: usize) -> Task {
+    store.save(make_task("t1", "ctx1")).await.unwrap();
+    let handles: Vec<_> = (0..n)
+        .map(|i| {
+            let store = store.clone();
+            tokio::spawn(async move {
+                store
+                    .update(
+                        "t1",
+                        None,
+                        Box::new(move |task| {
+                            let message = Message::user(format!("m{i}"), "hi");
+                            task.history.get_or_insert_with(Vec::new).push(message);
+                            Ok(())
+                        }),
+                    )
+                    .await
+                    .unwrap()
+            })
+        })
+        .collect();
+    for h in handles {
+        h.await.unwrap();
+    }
+    store.get("t1").await.unwrap().unwrap()
+}
+
+#[tokio::test]
+async fn test_update_is_atomic() {
+    let task = concurrent_appends(Arc::new(InMemoryTaskStore::new()), 50).await;
+    assert_eq!(task.history.unwrap().len(), 50);
+
+    let kv = KvTaskStore::new(Arc::new(InMemoryKvStore::new()));
+    let task = concurrent_appends(Arc::new(kv), 50).await;
+    assert_eq!(task.history.unwrap().len(), 50);
+}
+
+#[tokio::test]
+async fn test_update_returns_updated_task() {
+    let store = InMemoryTaskStore::new();
+    store.save(make_task("t1", "ctx1")).await.unwrap();
+    let updated = store
+        .update(
+            "t1",
+            None,
+            Box::new(|task| {
+                task.status.state = TaskState::Working;
+                Ok(())
+            }),
+        )
+        .await
+        .unwrap();
+    assert_eq!(updated.status.state, TaskState::Working);
+    assert_eq!(
+        store.get("t1").await.unwrap().unwrap().status.state,
+        TaskState::Working
+    );
+}
+
+#[tokio::test]
+async fn test_failed_update_leaves_task_unchanged() {
+    let stores: [Arc<dyn TaskStore>; 2] = [
+        Arc::new(InMemoryTaskStore::new()),
+        Arc::new(KvTaskStore::new(Arc::new(InMemoryKvStore::new()))),
+    ];
+    for store in stores {
+        store.save(make_task("t1", "ctx1")).await.unwrap();
+        let err = store
+            .update(
+                "t1",
+                None,
+                Box::new(|task| {
+                    task.status.state = TaskState::Failed;
+                    Err(A2AError::invalid_params("rejected"))
+                }),
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(err, A2AError::InvalidParams { .. }), "{err:?}");
+        assert_eq!(
+            store.get("t1").await.unwrap().unwrap().status.state,
+            TaskState::Submitted
+        );
+
+        let err = store
+            .update("missing", None, Box::new(|_| Ok(())))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, A2AError::TaskNotFound { .. }), "{err:?}");
+    }
+}
+
+#[tokio::test]
+async fn test_versions_count_writes() {
+    let stores: [Arc<dyn TaskStore>; 2] = [
+        Arc::new(InMemoryTaskStore::new()),
+        Arc::new(KvTaskStore::new(Arc::new(InMemoryKvStore::new()))),
+    ];
+    for store in stores {
+        assert!(store.get_versioned("t1").await.unwrap().is_none());
+        store.save(make_task("t1", "ctx1")).await.unwrap();
+        assert_eq!(store.get_versioned("t1").await.unwrap().unwrap().version, 1);
+        store.save(make_task("t1", "ctx1")).await.unwrap();
+        store
+            .update("t1", Some(2), Box::new(|_| Ok(())))
+            .await
+            .unwrap();
+        let stored = store.get_versioned("t1").await.unwrap().unwrap();
+        assert_eq!(stored.version, 3);
+        assert_eq!(stored.task.id, "t1");
+
+        // A deleted task starts over.
+        store.delete("t1").await.unwrap();
+        store.save(make_task("t1", "ctx1")).await.unwrap();
+        assert_eq!(store.get_versioned("t1").await.unwrap().unwrap().version, 1);
+    }
+}
+
+#[tokio::test]
+async fn test_update_at_stale_version_is_refused() {
+    let stores: [Arc<dyn TaskStore>; 2] = [
+        Arc::new(InMemoryTaskStore::new()),
+        Arc::new(KvTaskStore::new(Arc::new(InMemoryKvStore::new()))),
+    ];
+    for store in stores {
+        store.save(make_task("t1", "ctx1")).await.unwrap();
+        let read = store.get_versioned("t1").await.unwrap().unwrap();
+        // Another writer gets in first.
+        store
+            .update(
+                "t1",
+                None,
+                Box::new(|task| {
+                    task.status.state = TaskState::Canceled;
+                    Ok(())
+                }),
+            )
+            .await
+            .unwrap();
+
+        let err = store
+            .update(
+                "t1",
+                Some(read.version),
+                Box::new(|task| {
+                    task.status.state = TaskState::Working;
+                    Ok(())
+                }),
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(err, A2AError::VersionConflict { .. }), "{err:?}");
+        assert_eq!(err.data().unwrap()["actual"], 2);
+        assert_eq!(
+            store.get("t1").await.unwrap().unwrap().status.state,
+            TaskState::Canceled
+        );
+    }
+}
+
 // ---- Concurrency tests ----
 
 #[tokio::test]
 async fn test_concurrent_saves() {
-    let store = std::sync::Arc::new(InMemoryTaskStore::new());
+    let store = Arc::new(InMemoryTaskStore::new());
     let mut handles = vec![];
 
     for i in 0..10 {
@@ -339,7 +507,7 @@ async fn test_concurrent_saves() {
 
 #[tokio::test]
 async fn test_concurrent_gets() {
-    let store = std::sync::Arc::new(InMemoryTaskStore::new());
+    let store = Arc::new(InMemoryTaskStore::new());
     store.save(make_task("t1", "ctx1")).await.unwrap();
 
     let mut handles = vec![];
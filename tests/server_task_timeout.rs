@@ -0,0 +1,128 @@
+//! Per-task timeouts: executions running past the limit are canceled and
+//! their tasks fail.
+
+mod common;
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use a2a_rs::builders::ServerBuilder;
+use a2a_rs::client::A2AClient;
+use a2a_rs::error::A2AResult;
+use a2a_rs::server::{
+    AgentExecutor, CancellationToken, DefaultRequestHandler, EventQueue, ExecutorErrorReport,
+    ExecutorErrorReporter, ExecutorFailureKind, InMemoryTaskStore, RequestContext, RequestHandler,
+    SendMessageParams, TaskStore, TaskUpdater,
+};
+use a2a_rs::types::*;
+use a2a_rs::utils::get_message_text;
+use async_trait::async_trait;
+
+/// Starts work and never finishes; keeps its cancellation token.
+#[derive(Default)]
+struct StuckAgent {
+    token: Mutex<Option<CancellationToken>>,
+}
+
+#[async_trait]
+impl AgentExecutor for StuckAgent {
+    async fn execute(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        *self.token.lock().unwrap() = Some(context.cancellation_token.clone());
+        TaskUpdater::new(event_queue, context.task_id, context.context_id)
+            .start_work(None)
+            .await?;
+        std::future::pending().await
+    }
+
+    async fn cancel(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        TaskUpdater::new(event_queue, context.task_id, context.context_id)
+            .cancel(None)
+            .await
+    }
+}
+
+#[derive(Default)]
+struct Collect(Mutex<Vec<ExecutorErrorReport>>);
+
+impl ExecutorErrorReporter for Collect {
+    fn report(&self, report: &ExecutorErrorReport) {
+        self.0.lock().unwrap().push(report.clone());
+    }
+}
+
+async fn send(handler: &DefaultRequestHandler) -> Task {
+    let response = handler
+        .on_message_send(SendMessageParams {
+            message: Message::user("m1", "hi"),
+            configuration: None,
+            metadata: None,
+            tenant: None,
+        })
+        .await
+        .unwrap();
+    let SendMessageResponse::Task(task) = response else {
+        panic!("expected a task");
+    };
+    task
+}
+
+#[tokio::test]
+async fn test_execution_past_timeout_fails_task() {
+    let agent = Arc::new(StuckAgent::default());
+    let store = Arc::new(InMemoryTaskStore::new());
+    let reports = Arc::new(Collect::default());
+    let handler = DefaultRequestHandler::new(agent.clone(), store.clone())
+        .with_task_timeout(Duration::from_millis(100))
+        .with_error_reporter(reports.clone());
+
+    let task = tokio::time::timeout(Duration::from_secs(5), send(&handler))
+        .await
+        .expect("timeout not enforced");
+    assert_eq!(task.status.state, TaskState::Failed);
+    let text = get_message_text(task.status.message.as_ref().unwrap(), "");
+    assert!(text.contains("timed out"), "{text}");
+
+    let stored = store.get(&task.id).await.unwrap().unwrap();
+    assert_eq!(stored.status.state, TaskState::Failed);
+    assert!(agent.token.lock().unwrap().as_ref().unwrap().is_cancelled());
+
+    let reports = reports.0.lock().unwrap();
+    assert_eq!(reports.len(), 1);
+    assert_eq!(reports[0].kind, ExecutorFailureKind::Timeout);
+}
+
+#[tokio::test]
+async fn test_execution_within_timeout_is_unaffected() {
+    let handler = DefaultRequestHandler::new(
+        Arc::new(common::EchoAgent),
+        Arc::new(InMemoryTaskStore::new()),
+    )
+    .with_task_timeout(Duration::from_secs(5));
+
+    let task = send(&handler).await;
+    assert_eq!(task.status.state, TaskState::Completed);
+}
+
+#[tokio::test]
+async fn test_server_builder_task_timeout_ends_stream_with_failure() {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/a2a", listener.local_addr().unwrap());
+    tokio::spawn(
+        ServerBuilder::new(Arc::new(StuckAgent::default()))
+            .with_agent_card_direct(common::test_agent_card(&url))
+            .with_task_timeout(Duration::from_millis(100))
+            .serve(listener),
+    );
+
+    let client = A2AClient::from_endpoint(&url);
+    let mut stream = client.send_text_stream("hi").await.unwrap();
+    let mut last = None;
+    while let Some(event) = stream.next().await {
+        if let StreamResponse::StatusUpdate(update) = event.unwrap() {
+            last = Some(update);
+        }
+    }
+    let last = last.unwrap();
+    assert_eq!(last.status.state, TaskState::Failed);
+    assert!(last.r#final);
+}
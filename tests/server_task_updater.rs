@@ -156,6 +156,45 @@ async fn test_complete_then_cancel_rejected() {
     assert!(result.is_err());
 }
 
+#[tokio::test]
+async fn test_terminal_update_error_is_invalid_state_transition() {
+    let (updater, _queue) = make_updater();
+    updater.complete(None).await.unwrap();
+    let err = updater.start_work(None).await.unwrap_err();
+    assert!(
+        matches!(err, a2a_rs::A2AError::InvalidStateTransition { .. }),
+        "{err}"
+    );
+    assert!(
+        err.to_string().contains("terminal state completed"),
+        "{err}"
+    );
+}
+
+#[tokio::test]
+async fn test_back_to_submitted_is_refused() {
+    let (updater, queue) = make_updater();
+    let mut rx = queue.subscribe();
+    updater.start_work(None).await.unwrap();
+    updater.requires_input(None, false).await.unwrap();
+    updater.start_work(None).await.unwrap();
+
+    let err = updater.submit(None).await.unwrap_err();
+    assert!(
+        matches!(err, a2a_rs::A2AError::InvalidStateTransition { .. }),
+        "{err}"
+    );
+    assert_eq!(std::iter::from_fn(|| rx.try_recv().ok()).count(), 3);
+
+    // Without checks only the terminal guard remains.
+    let lax =
+        TaskUpdater::new(queue, "t2".to_string(), "ctx1".to_string()).without_transition_checks();
+    lax.start_work(None).await.unwrap();
+    lax.submit(None).await.unwrap();
+    lax.complete(None).await.unwrap();
+    assert!(lax.start_work(None).await.is_err());
+}
+
 // ---- Non-terminal states ----
 
 #[tokio::test]
@@ -575,3 +614,161 @@ async fn test_concurrent_updates_terminal_check() {
     assert_eq!(successes, 1);
     assert_eq!(failures, 4);
 }
+
+// ---- Cloning and scoped sub-updaters ----
+
+#[tokio::test]
+async fn test_cloned_updaters_share_terminal_state() {
+    let (updater, _queue) = make_updater();
+    let clones: Vec<TaskUpdater> = (0..5).map(|_| updater.clone()).collect();
+
+    let handles: Vec<_> = clones
+        .into_iter()
+        .map(|u| tokio::spawn(async move { u.complete(None).await }))
+        .collect();
+    let results: Vec<_> = futures::future::join_all(handles)
+        .await
+        .into_iter()
+        .map(|r| r.unwrap())
+        .collect();
+
+    assert_eq!(results.iter().filter(|r| r.is_ok()).count(), 1);
+    assert!(updater.is_terminal().await);
+    assert!(updater.failed(None).await.is_err());
+}
+
+#[tokio::test]
+async fn test_scoped_updater_prefixes_artifact_ids() {
+    let (updater, queue) = make_updater();
+    let mut rx = queue.subscribe();
+
+    let sub = updater.scoped("search");
+    assert_eq!(sub.artifact_prefix(), Some("search"));
+    sub.add_artifact(
+        vec![Part::text("hit")],
+        Some("results".to_string()),
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+    sub.add_artifact(vec![Part::text("auto")], None, None, None, None, None, None)
+        .await
+        .unwrap();
+
+    let ids: Vec<String> = (0..2)
+        .map(|_| match rx.try_recv().unwrap() {
+            StreamResponse::ArtifactUpdate(e) => e.artifact.artifact_id,
+            other => panic!("Expected ArtifactUpdate, got {:?}", other),
+        })
+        .collect();
+    assert_eq!(ids[0], "search/results");
+    assert!(ids[1].starts_with("search/"));
+}
+
+#[tokio::test]
+async fn test_nested_scopes_and_shared_terminal() {
+    let (updater, queue) = make_updater();
+    let mut rx = queue.subscribe();
+
+    let inner = updater.scoped("outer").scoped("inner");
+    assert_eq!(inner.artifact_prefix(), Some("outer/inner"));
+    assert_eq!(updater.artifact_prefix(), None);
+
+    inner.complete(None).await.unwrap();
+    let _ = rx.try_recv().unwrap();
+    assert!(updater.is_terminal().await);
+    assert!(updater.complete(None).await.is_err());
+}
+
+// ---- ArtifactWriter ----
+
+fn artifact_updates(
+    rx: &mut tokio::sync::broadcast::Receiver<StreamResponse>,
+) -> Vec<TaskArtifactUpdateEvent> {
+    let mut updates = Vec::new();
+    while let Ok(event) = rx.try_recv() {
+        match event {
+            StreamResponse::ArtifactUpdate(update) => updates.push(update),
+            other => panic!("Expected ArtifactUpdate, got {:?}", other),
+        }
+    }
+    updates
+}
+
+#[tokio::test]
+async fn test_artifact_writer_batches_parts_into_chunks() {
+    let (updater, queue) = make_updater();
+    let mut rx = queue.subscribe();
+
+    let mut writer = updater
+        .scoped("report")
+        .new_artifact("report.md")
+        .with_chunk_size(8);
+    assert!(writer.artifact_id().starts_with("report/"));
+    writer.write_text("abc").await.unwrap();
+    writer.write_text("defgh").await.unwrap();
+    // Nothing is published until a chunk is complete.
+    assert!(rx.try_recv().is_err());
+    writer.write_part(Part::text("i")).await.unwrap();
+    writer.write_text("jk").await.unwrap();
+    let artifact_id = writer.finish().await.unwrap();
+
+    let updates = artifact_updates(&mut rx);
+    assert_eq!(updates.len(), 2);
+    assert!(updates.iter().all(|u| u.artifact.artifact_id == artifact_id
+        && u.artifact.name.as_deref() == Some("report.md")));
+    assert_eq!(updates[0].artifact.parts.len(), 2);
+    assert_eq!(
+        (updates[0].append, updates[0].last_chunk),
+        (Some(false), Some(false))
+    );
+    assert_eq!(updates[1].artifact.parts.len(), 2);
+    assert_eq!(
+        (updates[1].append, updates[1].last_chunk),
+        (Some(true), Some(true))
+    );
+}
+
+#[tokio::test]
+async fn test_artifact_writer_splits_long_text_at_char_boundaries() {
+    let (updater, queue) = make_updater();
+    let mut rx = queue.subscribe();
+
+    let mut writer = updater.new_artifact("poem").with_chunk_size(4);
+    writer.write_text("héllo wörld").await.unwrap();
+    writer.finish().await.unwrap();
+
+    let updates = artifact_updates(&mut rx);
+    let texts: Vec<String> = updates
+        .iter()
+        .flat_map(|u| &u.artifact.parts)
+        .map(|part| match part {
+            Part::Text { text, .. } => text.clone(),
+            other => panic!("Expected text, got {:?}", other),
+        })
+        .collect();
+    assert!(texts.iter().all(|t| t.len() <= 4), "{texts:?}");
+    assert_eq!(texts.concat(), "héllo wörld");
+    assert_eq!(updates.last().unwrap().last_chunk, Some(true));
+    assert!(updates[1..].iter().all(|u| u.append == Some(true)));
+}
+
+#[tokio::test]
+async fn test_artifact_writer_finish_without_content() {
+    let (updater, queue) = make_updater();
+    let mut rx = queue.subscribe();
+
+    updater.new_artifact("empty").finish().await.unwrap();
+
+    let updates = artifact_updates(&mut rx);
+    assert_eq!(updates.len(), 1);
+    assert!(updates[0].artifact.parts.is_empty());
+    assert_eq!(
+        (updates[0].append, updates[0].last_chunk),
+        (Some(false), Some(true))
+    );
+}
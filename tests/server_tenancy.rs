@@ -0,0 +1,375 @@
+//! Multi-tenant partitioning: tasks belong to the tenant they were created
+//! for, and other tenants can neither read, list, cancel nor continue them,
+//! nor touch their push notification configs.
+
+mod common;
+
+use std::sync::{Arc, Mutex};
+
+use a2a_rs::error::{A2AError, A2AResult, TASK_NOT_FOUND};
+use a2a_rs::server::{
+    task_tenant, AgentExecutor, CancelTaskParams, DefaultRequestHandler, EventQueue, GetTaskParams,
+    InMemoryPushNotificationConfigStore, InMemoryTaskStore, PushNotificationConfigStore,
+    RequestContext, RequestHandler, SendMessageParams, TaskListParams, TaskStore, TaskUpdater,
+    TENANT_METADATA_KEY,
+};
+use a2a_rs::types::*;
+use async_trait::async_trait;
+use serde_json::json;
+
+/// Asks for input, recording the tenant of each execution.
+#[derive(Default)]
+struct AskingAgent {
+    tenants: Mutex<Vec<Option<String>>>,
+}
+
+#[async_trait]
+impl AgentExecutor for AskingAgent {
+    async fn execute(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        self.tenants.lock().unwrap().push(context.tenant.clone());
+        TaskUpdater::new(event_queue, context.task_id, context.context_id)
+            .requires_input(None, true)
+            .await
+    }
+
+    async fn cancel(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        TaskUpdater::new(event_queue, context.task_id, context.context_id)
+            .cancel(None)
+            .await
+    }
+}
+
+/// Completes with status update metadata that tries to hand the task to
+/// tenant `b`.
+struct RetenantingAgent;
+
+#[async_trait]
+impl AgentExecutor for RetenantingAgent {
+    async fn execute(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        let metadata = json!({TENANT_METADATA_KEY: "b", "tenant": "client data"});
+        TaskUpdater::new(event_queue, context.task_id, context.context_id)
+            .update_status(TaskState::Completed, None, true, Some(metadata))
+            .await
+    }
+
+    async fn cancel(&self, _context: RequestContext, _event_queue: EventQueue) -> A2AResult<()> {
+        Ok(())
+    }
+}
+
+fn send_params(message: Message, tenant: Option<&str>) -> SendMessageParams {
+    SendMessageParams {
+        message,
+        configuration: None,
+        metadata: None,
+        tenant: tenant.map(String::from),
+    }
+}
+
+async fn create(handler: &DefaultRequestHandler, tenant: Option<&str>) -> Task {
+    let response = handler
+        .on_message_send(send_params(Message::user("m1", "hi"), tenant))
+        .await
+        .unwrap();
+    let SendMessageResponse::Task(task) = response else {
+        panic!("expected a task");
+    };
+    task
+}
+
+fn get_params(id: &str, tenant: Option<&str>) -> GetTaskParams {
+    GetTaskParams {
+        id: id.to_string(),
+        history_length: None,
+        metadata: None,
+        tenant: tenant.map(String::from),
+        consistency: None,
+    }
+}
+
+async fn list(handler: &DefaultRequestHandler, tenant: Option<&str>) -> Vec<String> {
+    let response = handler
+        .on_list_tasks(TaskListParams {
+            tenant: tenant.map(String::from),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    response.tasks.into_iter().map(|task| task.id).collect()
+}
+
+fn task(id: &str) -> Task {
+    Task {
+        id: id.to_string(),
+        context_id: "c1".to_string(),
+        kind: "task".to_string(),
+        status: TaskStatus {
+            state: TaskState::Working,
+            message: None,
+            timestamp: None,
+            raw_state: None,
+        },
+        artifacts: None,
+        history: None,
+        metadata: None,
+    }
+}
+
+#[tokio::test]
+async fn test_store_partitions_tasks_by_tenant() {
+    let store = InMemoryTaskStore::new();
+    store.save_for_tenant(Some("a"), task("t1")).await.unwrap();
+    store.save_for_tenant(Some("b"), task("t2")).await.unwrap();
+    store.save_for_tenant(None, task("t3")).await.unwrap();
+
+    let t1 = store
+        .get_for_tenant(Some("a"), "t1")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(task_tenant(&t1), Some("a"));
+    assert!(matches!(
+        store.get_for_tenant(Some("b"), "t1").await,
+        Err(A2AError::TaskNotFound { .. })
+    ));
+    assert!(matches!(
+        store.get_for_tenant(None, "t1").await,
+        Err(A2AError::TaskNotFound { .. })
+    ));
+    assert!(store.get_for_tenant(None, "t3").await.unwrap().is_some());
+    assert!(store
+        .get_for_tenant(Some("a"), "gone")
+        .await
+        .unwrap()
+        .is_none());
+
+    let ids = |tenant: Option<&str>| TaskListParams {
+        tenant: tenant.map(String::from),
+        ..Default::default()
+    };
+    let listed = |page: a2a_rs::server::TaskListResponse| {
+        page.tasks.into_iter().map(|t| t.id).collect::<Vec<_>>()
+    };
+    assert_eq!(listed(store.list(&ids(Some("a"))).await.unwrap()), ["t1"]);
+    assert_eq!(listed(store.list(&ids(Some(""))).await.unwrap()), ["t3"]);
+    assert_eq!(store.list(&ids(None)).await.unwrap().tasks.len(), 3);
+}
+
+#[tokio::test]
+async fn test_tenant_reaches_executor() {
+    let agent = Arc::new(AskingAgent::default());
+    let handler = DefaultRequestHandler::new(agent.clone(), Arc::new(InMemoryTaskStore::new()));
+
+    create(&handler, Some("a")).await;
+    create(&handler, None).await;
+    assert_eq!(
+        *agent.tenants.lock().unwrap(),
+        [Some("a".to_string()), None]
+    );
+}
+
+#[tokio::test]
+async fn test_other_tenants_cannot_read_or_list_tasks() {
+    let handler = DefaultRequestHandler::new(
+        Arc::new(AskingAgent::default()),
+        Arc::new(InMemoryTaskStore::new()),
+    );
+    let task = create(&handler, Some("a")).await;
+
+    let own = handler
+        .on_get_task(get_params(&task.id, Some("a")))
+        .await
+        .unwrap();
+    assert_eq!(own.status.state, TaskState::InputRequired);
+    for tenant in [Some("b"), None] {
+        let err = handler
+            .on_get_task(get_params(&task.id, tenant))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, A2AError::TaskNotFound { .. }), "{err:?}");
+        assert_eq!(err.code(), TASK_NOT_FOUND);
+    }
+
+    assert_eq!(list(&handler, Some("a")).await, [task.id]);
+    assert!(list(&handler, Some("b")).await.is_empty());
+    assert!(list(&handler, None).await.is_empty());
+}
+
+#[tokio::test]
+async fn test_other_tenants_cannot_cancel_or_continue_tasks() {
+    let store = Arc::new(InMemoryTaskStore::new());
+    let handler = DefaultRequestHandler::new(Arc::new(AskingAgent::default()), store.clone());
+    let task = create(&handler, Some("a")).await;
+
+    let err = handler
+        .on_cancel_task(CancelTaskParams {
+            id: task.id.clone(),
+            metadata: None,
+            tenant: Some("b".to_string()),
+        })
+        .await
+        .unwrap_err();
+    assert!(matches!(err, A2AError::TaskNotFound { .. }), "{err:?}");
+
+    let mut follow_up = Message::user("m2", "more");
+    follow_up.task_id = Some(task.id.clone());
+    follow_up.context_id = Some(task.context_id.clone());
+    let err = handler
+        .on_message_send(send_params(follow_up.clone(), Some("b")))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, A2AError::TaskNotFound { .. }), "{err:?}");
+
+    let stored = store.get(&task.id).await.unwrap().unwrap();
+    assert_eq!(stored.status.state, TaskState::InputRequired);
+    assert_eq!(stored.history.unwrap().len(), 1);
+
+    // The owner still can.
+    let response = handler
+        .on_message_send(send_params(follow_up, Some("a")))
+        .await
+        .unwrap();
+    let SendMessageResponse::Task(continued) = response else {
+        panic!("expected a task");
+    };
+    assert_eq!(task_tenant(&continued), Some("a"));
+    let canceled = handler
+        .on_cancel_task(CancelTaskParams {
+            id: task.id.clone(),
+            metadata: None,
+            tenant: Some("a".to_string()),
+        })
+        .await
+        .unwrap();
+    assert_eq!(canceled.status.state, TaskState::Canceled);
+}
+
+#[tokio::test]
+async fn test_other_tenants_cannot_touch_push_configs() {
+    let configs = Arc::new(InMemoryPushNotificationConfigStore::new());
+    let handler = DefaultRequestHandler::new(
+        Arc::new(AskingAgent::default()),
+        Arc::new(InMemoryTaskStore::new()),
+    )
+    .with_push_config_store(configs.clone());
+    let task = create(&handler, Some("a")).await;
+    let set = |tenant: &str, url: &str| {
+        json!({
+            "taskId": task.id,
+            "pushNotificationConfig": {"id": "hook", "url": url, "token": "secret"},
+            "tenant": tenant,
+        })
+    };
+    handler
+        .on_set_task_push_notification_config(set("a", "https://a.example/hook"))
+        .await
+        .unwrap();
+
+    let foreign = [
+        handler
+            .on_set_task_push_notification_config(set("b", "https://b.example/hook"))
+            .await
+            .map(drop),
+        handler
+            .on_get_task_push_notification_config(
+                json!({"id": task.id, "pushNotificationConfigId": "hook", "tenant": "b"}),
+            )
+            .await
+            .map(drop),
+        handler
+            .on_list_task_push_notification_config(json!({"id": task.id, "tenant": "b"}))
+            .await
+            .map(drop),
+        handler
+            .on_delete_task_push_notification_config(
+                json!({"id": task.id, "pushNotificationConfigId": "hook", "tenant": "b"}),
+            )
+            .await,
+    ];
+    for result in foreign {
+        let err = result.unwrap_err();
+        assert!(matches!(err, A2AError::TaskNotFound { .. }), "{err:?}");
+    }
+    let stored = configs.get(&task.id, "hook").await.unwrap().unwrap();
+    assert_eq!(
+        stored.push_notification_config.url,
+        "https://a.example/hook"
+    );
+
+    // The owner still can.
+    let own = handler
+        .on_get_task_push_notification_config(
+            json!({"id": task.id, "pushNotificationConfigId": "hook", "tenant": "a"}),
+        )
+        .await
+        .unwrap();
+    assert_eq!(own["pushNotificationConfig"]["token"], "secret");
+}
+
+#[tokio::test]
+async fn test_cross_tenant_get_looks_like_missing_task_on_the_wire() {
+    let (base_url, _handle) = common::start_test_server(Arc::new(AskingAgent::default())).await;
+    let client = reqwest::Client::new();
+    let call = |method: &str, params: serde_json::Value| {
+        client
+            .post(format!("{base_url}/a2a"))
+            .json(&common::jsonrpc_request(json!(1), method, params))
+            .send()
+    };
+
+    let created: serde_json::Value = call(
+        "message/send",
+        json!({
+            "message": {"kind": "message", "messageId": "m1", "role": "user",
+                        "parts": [{"kind": "text", "text": "hi"}]},
+            "tenant": "a"
+        }),
+    )
+    .await
+    .unwrap()
+    .json()
+    .await
+    .unwrap();
+    let task_id = created["result"]["id"].as_str().unwrap().to_string();
+
+    let foreign: serde_json::Value = call("tasks/get", json!({"id": task_id, "tenant": "b"}))
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    let missing: serde_json::Value = call("tasks/get", json!({"id": "nope", "tenant": "b"}))
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(foreign["error"]["code"], TASK_NOT_FOUND);
+    assert_eq!(foreign["error"]["code"], missing["error"]["code"]);
+
+    let own: serde_json::Value = call("tasks/get", json!({"id": task_id, "tenant": "a"}))
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(own["result"]["id"], task_id.as_str());
+}
+
+#[tokio::test]
+async fn test_status_update_metadata_cannot_change_tenant() {
+    let store = Arc::new(InMemoryTaskStore::new());
+    let handler = DefaultRequestHandler::new(Arc::new(RetenantingAgent), store.clone());
+    let task = create(&handler, Some("a")).await;
+
+    let stored = store.get(&task.id).await.unwrap().unwrap();
+    assert_eq!(stored.status.state, TaskState::Completed);
+    assert_eq!(task_tenant(&stored), Some("a"));
+    // A plain "tenant" entry is the agent's own metadata.
+    assert_eq!(stored.metadata.unwrap()["tenant"], "client data");
+    let err = handler
+        .on_get_task(get_params(&task.id, Some("b")))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, A2AError::TaskNotFound { .. }), "{err:?}");
+}
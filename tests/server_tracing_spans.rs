@@ -0,0 +1,173 @@
+//! Tracing spans linking executor enqueue, pipeline persistence and SSE
+//! writes of streamed events by sequence number.
+
+mod common;
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use a2a_rs::error::A2AResult;
+use a2a_rs::server::{AgentExecutor, EventQueue, RequestContext, TaskUpdater};
+use a2a_rs::types::*;
+use async_trait::async_trait;
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::Subscriber;
+use tracing_subscriber::layer::{Context, SubscriberExt};
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::Layer;
+
+/// Emits working, an artifact and completed.
+struct ThreeEventAgent;
+
+#[async_trait]
+impl AgentExecutor for ThreeEventAgent {
+    async fn execute(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        let updater = TaskUpdater::new(event_queue, context.task_id, context.context_id);
+        updater.start_work(None).await?;
+        updater
+            .add_artifact(
+                vec![Part::text("chunk")],
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+            .await?;
+        updater.complete_with_text("done").await
+    }
+
+    async fn cancel(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        TaskUpdater::new(event_queue, context.task_id, context.context_id)
+            .cancel(None)
+            .await
+    }
+}
+
+/// A recorded span: name, fields and the name of its parent.
+#[derive(Debug, Clone, Default)]
+struct SpanRecord {
+    name: String,
+    fields: HashMap<String, String>,
+    parent: Option<String>,
+}
+
+impl SpanRecord {
+    fn field(&self, name: &str) -> &str {
+        self.fields.get(name).map(String::as_str).unwrap_or("")
+    }
+}
+
+impl Visit for SpanRecord {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        self.fields
+            .insert(field.name().to_string(), format!("{value:?}"));
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.fields
+            .insert(field.name().to_string(), value.to_string());
+    }
+}
+
+#[derive(Clone, Default)]
+struct Recorder {
+    spans: Arc<Mutex<HashMap<u64, SpanRecord>>>,
+}
+
+impl<S: Subscriber + for<'a> LookupSpan<'a>> Layer<S> for Recorder {
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let mut record = SpanRecord {
+            name: attrs.metadata().name().to_string(),
+            parent: ctx
+                .span(id)
+                .and_then(|span| span.parent())
+                .map(|parent| parent.name().to_string()),
+            ..Default::default()
+        };
+        attrs.record(&mut record);
+        self.spans.lock().unwrap().insert(id.into_u64(), record);
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, _ctx: Context<'_, S>) {
+        if let Some(record) = self.spans.lock().unwrap().get_mut(&id.into_u64()) {
+            values.record(record);
+        }
+    }
+}
+
+impl Recorder {
+    fn named(&self, name: &str) -> Vec<SpanRecord> {
+        let mut spans: Vec<_> = self
+            .spans
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|span| span.name == name)
+            .cloned()
+            .collect();
+        spans.sort_by_key(|span| span.field("sequence").parse::<u64>().unwrap_or(0));
+        spans
+    }
+}
+
+#[tokio::test]
+async fn test_streamed_events_are_traced_end_to_end() {
+    let recorder = Recorder::default();
+    let _guard =
+        tracing::subscriber::set_default(tracing_subscriber::registry().with(recorder.clone()));
+
+    let (base_url, _handle) = common::start_test_server(Arc::new(ThreeEventAgent)).await;
+    let mut body = common::message_send_request(1, "go");
+    body["method"] = "message/stream".into();
+    let response = reqwest::Client::new()
+        .post(format!("{base_url}/a2a"))
+        .json(&body)
+        .send()
+        .await
+        .unwrap();
+    let text = response.text().await.unwrap();
+    assert!(text.contains("event: done"), "{text}");
+
+    let persists = recorder.named("a2a.event.persist");
+    let writes = recorder.named("a2a.sse.write");
+    assert_eq!(persists.len(), 3, "{persists:?}");
+    assert_eq!(writes.len(), 3, "{writes:?}");
+
+    let kinds = ["status-update", "artifact-update", "status-update"];
+    for (i, kind) in kinds.iter().enumerate() {
+        let sequence = (i + 1).to_string();
+        assert_eq!(persists[i].field("sequence"), sequence);
+        assert_eq!(persists[i].field("source_sequence"), sequence);
+        assert_eq!(persists[i].field("kind"), *kind);
+        assert_eq!(persists[i].parent.as_deref(), Some("a2a.event_pipeline"));
+        assert_eq!(writes[i].field("sequence"), sequence);
+        assert_eq!(writes[i].field("kind"), *kind);
+        assert_eq!(writes[i].parent.as_deref(), Some("a2a.sse"));
+    }
+
+    // The executor's enqueue spans carry the source sequence and task ID.
+    // They nest in the executor's span: `a2a.execute` with the request ID,
+    // within which the `observability` feature opens its own.
+    let pipeline = &recorder.named("a2a.event_pipeline")[0];
+    let task_id = pipeline.field("task_id").to_string();
+    let executor_span = Some(if cfg!(feature = "observability") {
+        "a2a.executor.execute"
+    } else {
+        "a2a.execute"
+    });
+    let enqueues: Vec<_> = recorder
+        .named("a2a.event.enqueue")
+        .into_iter()
+        .filter(|span| {
+            span.parent.as_deref() == executor_span && span.field("task_id").contains(&task_id)
+        })
+        .collect();
+    let sequences: Vec<_> = enqueues.iter().map(|s| s.field("sequence")).collect();
+    assert_eq!(sequences, ["1", "2", "3"]);
+
+    let stream = &recorder.named("a2a.sse")[0];
+    assert!(stream.field("task_id").contains(&task_id), "{stream:?}");
+}
@@ -0,0 +1,196 @@
+//! Per-task `Workspace` provisioned by `DefaultRequestHandler`.
+
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use a2a_rs::error::A2AResult;
+use a2a_rs::server::{
+    AgentExecutor, DefaultRequestHandler, EventQueue, InMemoryTaskStore, RequestContext,
+    RequestHandler, SendMessageParams, TaskUpdater, Workspace,
+};
+use a2a_rs::types::*;
+use async_trait::async_trait;
+
+/// Materializes uploaded files; asks for more input until told "done",
+/// panics when told "panic".
+#[derive(Default)]
+struct FileAgent {
+    /// Workspace directory and file names seen on each turn.
+    turns: Mutex<Vec<(PathBuf, Vec<String>)>>,
+}
+
+#[async_trait]
+impl AgentExecutor for FileAgent {
+    async fn execute(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        let workspace = context.workspace().expect("handler provides a workspace");
+        let message = context.message.as_ref().unwrap();
+        workspace.materialize_message(message)?;
+        let mut names: Vec<String> = std::fs::read_dir(workspace.dir()?)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+            .collect();
+        names.sort();
+        self.turns
+            .lock()
+            .unwrap()
+            .push((workspace.path().to_path_buf(), names));
+
+        let input = context.get_user_input(" ");
+        let updater = TaskUpdater::new(event_queue, context.task_id, context.context_id);
+        match input.as_str() {
+            "done" => updater.complete_with_text("ok").await,
+            "panic" => panic!("executor blew up"),
+            _ => updater.requires_input(None, true).await,
+        }
+    }
+
+    async fn cancel(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        TaskUpdater::new(event_queue, context.task_id, context.context_id)
+            .cancel(None)
+            .await
+    }
+}
+
+fn params(text: &str, task_id: Option<&str>, files: Vec<Part>) -> SendMessageParams {
+    let mut message = Message::user(uuid::Uuid::new_v4().to_string(), text);
+    message.parts.extend(files);
+    message.task_id = task_id.map(String::from);
+    SendMessageParams {
+        message,
+        configuration: None,
+        metadata: None,
+        tenant: None,
+    }
+}
+
+fn file(base64: &str, name: &str) -> Part {
+    Part::file_from_bytes(base64, Some(name.to_string()), None)
+}
+
+/// A fresh directory to use as workspace root.
+fn root() -> PathBuf {
+    let root = std::env::temp_dir().join(format!("a2a-ws-test-{}", uuid::Uuid::new_v4()));
+    std::fs::create_dir_all(&root).unwrap();
+    root
+}
+
+async fn wait_removed(path: &Path) {
+    for _ in 0..100 {
+        if !path.exists() {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    panic!("{} still exists", path.display());
+}
+
+fn task_id(response: SendMessageResponse) -> String {
+    match response {
+        SendMessageResponse::Task(task) => task.id,
+        other => panic!("expected task, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn test_workspace_persists_across_turns_and_is_removed_on_completion() {
+    let root = root();
+    let agent = Arc::new(FileAgent::default());
+    let handler = DefaultRequestHandler::new(agent.clone(), Arc::new(InMemoryTaskStore::new()))
+        .with_workspace_root(&root);
+
+    let response = handler
+        .on_message_send(params("first", None, vec![file("aGVsbG8=", "a.txt")]))
+        .await
+        .unwrap();
+    let id = task_id(response);
+    let (dir, _) = agent.turns.lock().unwrap()[0].clone();
+    assert!(dir.starts_with(&root));
+    assert_eq!(std::fs::read(dir.join("a.txt")).unwrap(), b"hello");
+
+    handler
+        .on_message_send(params(
+            "done",
+            Some(&id),
+            vec![file("d29ybGQ=", "a.txt"), file("d29ybGQ=", "../b.txt")],
+        ))
+        .await
+        .unwrap();
+
+    let turns = agent.turns.lock().unwrap().clone();
+    assert_eq!(turns[1].0, dir, "same workspace on the second turn");
+    assert_eq!(turns[1].1, ["a-1.txt", "a.txt", "b.txt"]);
+    wait_removed(&dir).await;
+    std::fs::remove_dir_all(root).unwrap();
+}
+
+#[tokio::test]
+async fn test_workspace_removed_when_executor_panics() {
+    let root = root();
+    let agent = Arc::new(FileAgent::default());
+    let handler = DefaultRequestHandler::new(agent.clone(), Arc::new(InMemoryTaskStore::new()))
+        .with_workspace_root(&root);
+
+    // A panicking executor publishes no final event, so don't wait for one.
+    let _ = tokio::time::timeout(
+        Duration::from_millis(200),
+        handler.on_message_send(params("panic", None, vec![file("aGVsbG8=", "a.txt")])),
+    )
+    .await;
+
+    let (dir, names) = agent.turns.lock().unwrap()[0].clone();
+    assert_eq!(names, ["a.txt"]);
+    wait_removed(&dir).await;
+    std::fs::remove_dir_all(root).unwrap();
+}
+
+#[tokio::test]
+async fn test_workspace_removed_on_cancel() {
+    let root = root();
+    let agent = Arc::new(FileAgent::default());
+    let handler = DefaultRequestHandler::new(agent.clone(), Arc::new(InMemoryTaskStore::new()))
+        .with_workspace_root(&root);
+
+    let id = task_id(
+        handler
+            .on_message_send(params("wait", None, vec![file("aGVsbG8=", "a.txt")]))
+            .await
+            .unwrap(),
+    );
+    let (dir, _) = agent.turns.lock().unwrap()[0].clone();
+    assert!(dir.exists());
+
+    handler
+        .on_cancel_task(a2a_rs::server::CancelTaskParams {
+            id,
+            metadata: None,
+            tenant: None,
+        })
+        .await
+        .unwrap();
+
+    wait_removed(&dir).await;
+    std::fs::remove_dir_all(root).unwrap();
+}
+
+#[test]
+fn test_workspace_file_names_stay_inside() {
+    let root = root();
+    let workspace = Workspace::new(&root);
+    assert!(!workspace.path().exists(), "created lazily");
+
+    for name in ["../../etc/passwd", "C:\\temp\\x.bin", "plain.txt"] {
+        let path = workspace.file_path(name).unwrap();
+        assert_eq!(path.parent(), Some(workspace.path()), "{name}");
+    }
+    assert!(workspace.file_path("..").is_err());
+    assert!(workspace.file_path("dir/").is_err());
+
+    assert_eq!(workspace.materialize(&Part::text("hi")).unwrap(), None);
+    assert!(workspace.materialize(&file("not base64!", "x")).is_err());
+
+    let dir = workspace.path().to_path_buf();
+    drop(workspace);
+    assert!(!dir.exists(), "last clone removes the directory");
+    std::fs::remove_dir_all(root).unwrap();
+}
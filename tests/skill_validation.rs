@@ -0,0 +1,200 @@
+//! `skills/invoke` dry runs: validating a candidate message against a skill
+//! without executing it.
+
+mod common;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use a2a_rs::builders::AgentCardBuilder;
+use a2a_rs::client::A2AClient;
+use a2a_rs::error::A2AResult;
+use a2a_rs::server::{AgentExecutor, EventQueue, InMemoryTaskStore, RequestContext, TaskUpdater};
+use a2a_rs::types::*;
+use a2a_rs::utils::{mime_type_matches, validate_skill_message};
+use a2a_rs::A2AError;
+use async_trait::async_trait;
+use serde_json::json;
+
+fn card(url: &str) -> AgentCard {
+    let mut card = AgentCardBuilder::new("Vision", "Describes images", "0.1.0")
+        .with_jsonrpc_interface(url)
+        .with_skill("describe", "Describe", "Describe an image", vec![])
+        .with_skill("chat", "Chat", "Free-form chat", vec![])
+        .build();
+    card.skills[0].input_modes = Some(vec!["text/plain".into(), "image/*".into()]);
+    card.skills[0].output_modes = Some(vec!["text/plain".into()]);
+    card
+}
+
+fn params(skill_id: &str, message: Message) -> SkillInvokeParams {
+    SkillInvokeParams {
+        skill_id: skill_id.to_string(),
+        message,
+        dry_run: true,
+        accepted_output_modes: None,
+        metadata: None,
+        tenant: None,
+    }
+}
+
+fn message(parts: Vec<Part>) -> Message {
+    let mut message = Message::user("m1", "");
+    message.parts = parts;
+    message
+}
+
+fn codes(result: &SkillValidationResult) -> Vec<SkillValidationCode> {
+    result.issues.iter().map(|i| i.code).collect()
+}
+
+/// Counts executions, to prove dry runs never reach the executor.
+#[derive(Default)]
+struct CountingAgent {
+    runs: AtomicUsize,
+}
+
+#[async_trait]
+impl AgentExecutor for CountingAgent {
+    async fn execute(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        self.runs.fetch_add(1, Ordering::SeqCst);
+        TaskUpdater::new(event_queue, context.task_id, context.context_id)
+            .complete(None)
+            .await
+    }
+
+    async fn cancel(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        TaskUpdater::new(event_queue, context.task_id, context.context_id)
+            .cancel(None)
+            .await
+    }
+}
+
+async fn start_server(agent: Arc<CountingAgent>) -> String {
+    let (base_url, _server) =
+        common::start_test_server_with_card(agent, Arc::new(InMemoryTaskStore::new()), card).await;
+    format!("{base_url}/a2a")
+}
+
+#[test]
+fn test_mime_type_matching() {
+    assert!(mime_type_matches("*/*", "application/pdf"));
+    assert!(mime_type_matches("IMAGE/*", "image/png"));
+    assert!(!mime_type_matches("image/*", "text/plain"));
+    assert!(!mime_type_matches("text/plain", "text/html"));
+}
+
+#[test]
+fn test_valid_message_has_no_issues() {
+    let msg = message(vec![
+        Part::text("what is this?"),
+        Part::file_from_uri("https://x/cat.png", None, Some("image/png".into())),
+    ]);
+    let result = validate_skill_message(&card("http://x"), &params("describe", msg)).unwrap();
+    assert!(result.valid);
+    assert!(result.issues.is_empty());
+}
+
+#[test]
+fn test_structural_and_mode_issues() {
+    let mut msg = message(vec![Part::text("hi"), Part::data(json!([1, 2]))]);
+    msg.role = Role::Agent;
+    let mut p = params("describe", msg);
+    p.accepted_output_modes = Some(vec!["audio/mpeg".into()]);
+
+    let result = validate_skill_message(&card("http://x"), &p).unwrap();
+    assert!(!result.valid);
+    assert_eq!(
+        codes(&result),
+        vec![
+            SkillValidationCode::InvalidRole,
+            SkillValidationCode::UnsupportedInputMode,
+            SkillValidationCode::InvalidData,
+            SkillValidationCode::NoCompatibleOutputMode,
+        ]
+    );
+    assert_eq!(result.issues[1].part_index, Some(1));
+}
+
+#[test]
+fn test_skill_without_modes_uses_card_defaults() {
+    // `chat` declares no modes; the builder's default is text/plain.
+    let msg = message(vec![Part::data(json!({"k": 1}))]);
+    let result = validate_skill_message(&card("http://x"), &params("chat", msg)).unwrap();
+    assert_eq!(
+        codes(&result),
+        vec![SkillValidationCode::UnsupportedInputMode]
+    );
+
+    let result =
+        validate_skill_message(&card("http://x"), &params("chat", message(vec![]))).unwrap();
+    assert_eq!(codes(&result), vec![SkillValidationCode::EmptyMessage]);
+}
+
+#[test]
+fn test_unknown_skill_is_invalid_params() {
+    let err = validate_skill_message(
+        &card("http://x"),
+        &params("nope", message(vec![Part::text("hi")])),
+    )
+    .unwrap_err();
+    assert!(matches!(err, A2AError::InvalidParams { .. }));
+}
+
+#[test]
+fn test_unknown_issue_code_deserializes() {
+    let issue: SkillValidationIssue =
+        serde_json::from_value(json!({"code": "too-long", "message": "x"})).unwrap();
+    assert_eq!(issue.code, SkillValidationCode::Other);
+}
+
+#[tokio::test]
+async fn test_client_dry_run_does_not_execute() {
+    let agent = Arc::new(CountingAgent::default());
+    let client = A2AClient::from_endpoint(&start_server(agent.clone()).await);
+
+    let mut p = params(
+        "describe",
+        message(vec![Part::file_from_uri("https://x/a.pdf", None, None)]),
+    );
+    // The client forces a dry run.
+    p.dry_run = false;
+    let result = client.validate_skill(p).await.unwrap();
+    assert_eq!(result.skill_id, "describe");
+    assert_eq!(
+        codes(&result),
+        vec![SkillValidationCode::UnsupportedInputMode]
+    );
+
+    let err = client
+        .validate_skill(params("nope", message(vec![Part::text("hi")])))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, A2AError::JsonRpc { code: -32602, .. }));
+
+    assert_eq!(agent.runs.load(Ordering::SeqCst), 0);
+}
+
+#[tokio::test]
+async fn test_server_rejects_non_dry_run() {
+    let url = start_server(Arc::new(CountingAgent::default())).await;
+    let body = json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "skills/invoke",
+        "params": {"skillId": "describe", "message": Message::user("m1", "hi")}
+    });
+    let response: serde_json::Value = reqwest::Client::new()
+        .post(&url)
+        .json(&body)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(
+        response["error"]["code"],
+        a2a_rs::error::UNSUPPORTED_OPERATION
+    );
+}
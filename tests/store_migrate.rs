@@ -0,0 +1,167 @@
+//! `store::migrate` between `TaskStore` backends.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use a2a_rs::error::{A2AError, A2AResult};
+use a2a_rs::server::{InMemoryTaskStore, TaskListParams, TaskListResponse, TaskStore};
+use a2a_rs::store::{migrate, MigrationOptions, MigrationProgress};
+use a2a_rs::types::*;
+use a2a_rs::utils::new_task;
+use async_trait::async_trait;
+
+async fn seeded(count: usize) -> InMemoryTaskStore {
+    let store = InMemoryTaskStore::new();
+    for i in 0..count {
+        let task = new_task(Message::user(format!("m{i}"), format!("task {i}"))).unwrap();
+        store.save(task).await.unwrap();
+    }
+    store
+}
+
+async fn all_ids(store: &dyn TaskStore) -> Vec<String> {
+    let mut ids: Vec<String> = store
+        .list(&TaskListParams::default())
+        .await
+        .unwrap()
+        .tasks
+        .into_iter()
+        .map(|t| t.id)
+        .collect();
+    ids.sort();
+    ids
+}
+
+/// Destination that fails every save after the first `budget`.
+struct FlakyStore {
+    inner: InMemoryTaskStore,
+    budget: AtomicUsize,
+}
+
+#[async_trait]
+impl TaskStore for FlakyStore {
+    async fn save(&self, task: Task) -> A2AResult<()> {
+        if self
+            .budget
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |b| b.checked_sub(1))
+            .is_err()
+        {
+            return Err(A2AError::internal_error("disk full"));
+        }
+        self.inner.save(task).await
+    }
+
+    async fn get(&self, task_id: &str) -> A2AResult<Option<Task>> {
+        self.inner.get(task_id).await
+    }
+
+    async fn delete(&self, task_id: &str) -> A2AResult<()> {
+        self.inner.delete(task_id).await
+    }
+
+    async fn list(&self, params: &TaskListParams) -> A2AResult<TaskListResponse> {
+        self.inner.list(params).await
+    }
+}
+
+#[tokio::test]
+async fn test_migrates_all_tasks_in_pages() {
+    let source = seeded(25).await;
+    let dest = InMemoryTaskStore::new();
+    let reports = Arc::new(Mutex::new(Vec::<MigrationProgress>::new()));
+    let log = reports.clone();
+
+    let report = migrate(
+        &source,
+        &dest,
+        MigrationOptions::new()
+            .with_page_size(10)
+            .on_progress(move |p| log.lock().unwrap().push(p.clone())),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(report.copied, 25);
+    assert_eq!(report.pages, 3);
+    assert_eq!(report.checkpoint, None);
+    assert_eq!(all_ids(&dest).await, all_ids(&source).await);
+
+    let reports = reports.lock().unwrap();
+    let copied: Vec<usize> = reports.iter().map(|p| p.copied).collect();
+    assert_eq!(copied, [10, 20, 25]);
+    assert!(reports[0].checkpoint.is_some());
+    assert_eq!(reports[2].checkpoint, None);
+}
+
+#[tokio::test]
+async fn test_resumes_from_checkpoint_after_failure() {
+    let source = seeded(25).await;
+    let dest = FlakyStore {
+        inner: InMemoryTaskStore::new(),
+        budget: AtomicUsize::new(15),
+    };
+    let last = Arc::new(Mutex::new(None::<String>));
+    let log = last.clone();
+
+    let err = migrate(
+        &source,
+        &dest,
+        MigrationOptions::new()
+            .with_page_size(10)
+            .on_progress(move |p| *log.lock().unwrap() = p.checkpoint.clone()),
+    )
+    .await
+    .unwrap_err();
+    assert!(err.to_string().contains("disk full"));
+
+    let checkpoint = last.lock().unwrap().clone().expect("first page finished");
+    dest.budget.store(usize::MAX, Ordering::SeqCst);
+    let report = migrate(
+        &source,
+        &dest,
+        MigrationOptions::new()
+            .with_page_size(10)
+            .resume_from(checkpoint),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(report.copied, 15, "only pages after the checkpoint");
+    assert_eq!(all_ids(&dest).await, all_ids(&source).await);
+}
+
+#[tokio::test]
+async fn test_skip_existing_keeps_destination_copy() {
+    let source = seeded(3).await;
+    let dest = InMemoryTaskStore::new();
+    let mut newer = source
+        .list(&TaskListParams::default())
+        .await
+        .unwrap()
+        .tasks
+        .remove(0);
+    newer.status.state = TaskState::Completed;
+    dest.save(newer.clone()).await.unwrap();
+
+    let report = migrate(&source, &dest, MigrationOptions::new().skip_existing(true))
+        .await
+        .unwrap();
+
+    assert_eq!((report.copied, report.skipped), (2, 1));
+    let kept = dest.get(&newer.id).await.unwrap().unwrap();
+    assert_eq!(kept.status.state, TaskState::Completed);
+}
+
+#[tokio::test]
+async fn test_empty_source() {
+    let report = migrate(
+        &InMemoryTaskStore::new(),
+        &InMemoryTaskStore::new(),
+        MigrationOptions::new(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(report.copied, 0);
+    assert_eq!(report.checkpoint, None);
+}
@@ -0,0 +1,182 @@
+//! `tasks/artifacts/list` and `tasks/artifacts/get`: a task's artifacts
+//! listed without their parts and fetched one at a time, from the task
+//! store, the handler and the client.
+
+mod common;
+
+use std::sync::Arc;
+
+use a2a_rs::client::A2AClient;
+use a2a_rs::error::{A2AError, A2AResult};
+use a2a_rs::server::{
+    set_task_tenant, CancelTaskParams, GetTaskParams, InMemoryKvStore, InMemoryTaskStore,
+    KvTaskStore, RequestHandler, SendMessageParams, SubscribeToTaskParams, TaskListParams,
+    TaskListResponse, TaskStore,
+};
+use a2a_rs::types::*;
+use async_trait::async_trait;
+use common::EchoAgent;
+use serde_json::json;
+use tokio::sync::broadcast;
+
+fn artifact(id: &str, parts: usize) -> Artifact {
+    Artifact {
+        artifact_id: id.to_string(),
+        name: Some(format!("{id}.txt")),
+        description: None,
+        parts: (0..parts)
+            .map(|i| Part::text(format!("part {i}")))
+            .collect(),
+        metadata: None,
+        extensions: None,
+    }
+}
+
+fn task(id: &str) -> Task {
+    Task {
+        id: id.to_string(),
+        context_id: "ctx".to_string(),
+        kind: "task".to_string(),
+        status: TaskStatus::new(TaskState::Completed),
+        artifacts: Some(vec![artifact("report", 3), artifact("log", 1)]),
+        history: None,
+        metadata: None,
+    }
+}
+
+async fn check_store(store: &dyn TaskStore) {
+    store.save(task("t1")).await.unwrap();
+    let mut owned = task("t2");
+    set_task_tenant(&mut owned, Some("acme"));
+    store.save(owned).await.unwrap();
+
+    let summaries = store.list_artifacts(None, "t1").await.unwrap();
+    let ids: Vec<_> = summaries.iter().map(|a| a.artifact_id.as_str()).collect();
+    assert_eq!(ids, ["report", "log"]);
+    assert_eq!(summaries[0].part_count, 3);
+    assert_eq!(summaries[0].name.as_deref(), Some("report.txt"));
+
+    let report = store.get_artifact(None, "t1", "report").await.unwrap();
+    assert_eq!(report.parts.len(), 3);
+    assert!(matches!(
+        store.get_artifact(None, "t1", "missing").await,
+        Err(A2AError::InvalidParams { .. })
+    ));
+    assert!(matches!(
+        store.list_artifacts(None, "nope").await,
+        Err(A2AError::TaskNotFound { .. })
+    ));
+
+    // Another tenant's task is off limits.
+    assert!(store.list_artifacts(None, "t2").await.is_err());
+    assert!(store
+        .get_artifact(Some("other"), "t2", "log")
+        .await
+        .is_err());
+    assert!(store.get_artifact(Some("acme"), "t2", "log").await.is_ok());
+}
+
+#[tokio::test]
+async fn test_task_stores_serve_artifacts() {
+    check_store(&InMemoryTaskStore::new()).await;
+    check_store(&KvTaskStore::new(Arc::new(InMemoryKvStore::new()))).await;
+}
+
+#[tokio::test]
+async fn test_client_lists_and_fetches_artifacts() {
+    let store = Arc::new(InMemoryTaskStore::new());
+    store.save(task("t1")).await.unwrap();
+    let (base_url, _server) =
+        common::start_test_server_with_card(Arc::new(EchoAgent), store, common::test_agent_card)
+            .await;
+    let client = A2AClient::from_endpoint(&format!("{base_url}/a2a"));
+
+    let listed = client.list_artifacts("t1").await.unwrap();
+    assert_eq!(listed.task_id, "t1");
+    assert_eq!(listed.artifacts.len(), 2);
+    assert_eq!(listed.artifacts[1].part_count, 1);
+
+    let log = client.get_artifact("t1", "log").await.unwrap();
+    assert_eq!(log.artifact_id, "log");
+    assert_eq!(log.parts.len(), 1);
+
+    let err = client.get_artifact("t1", "missing").await.unwrap_err();
+    assert!(
+        matches!(err, A2AError::JsonRpc { code: -32602, .. }),
+        "{err:?}"
+    );
+    let err = client.list_artifacts("nope").await.unwrap_err();
+    assert!(
+        matches!(err, A2AError::JsonRpc { code: -32001, .. }),
+        "{err:?}"
+    );
+}
+
+/// Handler implementing only the required methods, serving one task.
+struct TaskOnly;
+
+#[async_trait]
+impl RequestHandler for TaskOnly {
+    async fn on_message_send(&self, _: SendMessageParams) -> A2AResult<SendMessageResponse> {
+        Err(A2AError::unsupported_operation("read only"))
+    }
+
+    async fn on_message_send_stream(
+        &self,
+        _: SendMessageParams,
+    ) -> A2AResult<broadcast::Receiver<StreamResponse>> {
+        Err(A2AError::unsupported_operation("read only"))
+    }
+
+    async fn on_get_task(&self, params: GetTaskParams) -> A2AResult<Task> {
+        match params.id.as_str() {
+            "t1" => Ok(task("t1")),
+            id => Err(A2AError::task_not_found(id)),
+        }
+    }
+
+    async fn on_list_tasks(&self, _: TaskListParams) -> A2AResult<TaskListResponse> {
+        Err(A2AError::unsupported_operation("read only"))
+    }
+
+    async fn on_cancel_task(&self, _: CancelTaskParams) -> A2AResult<Task> {
+        Err(A2AError::unsupported_operation("read only"))
+    }
+
+    async fn on_subscribe_to_task(
+        &self,
+        _: SubscribeToTaskParams,
+    ) -> A2AResult<broadcast::Receiver<StreamResponse>> {
+        Err(A2AError::unsupported_operation("read only"))
+    }
+}
+
+#[tokio::test]
+async fn test_default_handler_methods_use_get_task() {
+    let listed = TaskOnly
+        .on_list_artifacts(ListArtifactsParams {
+            task_id: "t1".to_string(),
+            tenant: None,
+        })
+        .await
+        .unwrap();
+    assert_eq!(listed.artifacts.len(), 2);
+
+    let params = |artifact_id: &str| GetArtifactParams {
+        task_id: "t1".to_string(),
+        artifact_id: artifact_id.to_string(),
+        tenant: None,
+    };
+    let report = TaskOnly.on_get_artifact(params("report")).await.unwrap();
+    assert_eq!(report.parts.len(), 3);
+    assert!(TaskOnly.on_get_artifact(params("missing")).await.is_err());
+}
+
+#[test]
+fn test_summary_wire_format() {
+    let summary = ArtifactSummary::from(&artifact("report", 2));
+    assert_eq!(
+        serde_json::to_value(&summary).unwrap(),
+        json!({"artifactId": "report", "name": "report.txt", "partCount": 2})
+    );
+}
@@ -0,0 +1,145 @@
+//! `MockAgentServer` (`testing` feature): scripted replies, request
+//! recording and fault injection, seen through the real client.
+#![cfg(feature = "testing")]
+
+use std::time::Duration;
+
+use a2a_rs::client::{A2AClient, CallOptions, JsonRpcTransport};
+use a2a_rs::error::{self, A2AError};
+use a2a_rs::testing::{fixtures, Fault, MockAgentServer, MockReply};
+use a2a_rs::types::*;
+use serde_json::json;
+
+async fn client(server: &MockAgentServer) -> A2AClient {
+    A2AClient::from_url(server.url()).await.unwrap()
+}
+
+fn task_id(response: SendMessageResponse) -> String {
+    match response {
+        SendMessageResponse::Task(task) => task.id,
+        other => panic!("expected a task, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn test_scripted_replies_and_recorded_requests() {
+    let server = MockAgentServer::start().await.unwrap();
+    server.on(
+        "message/send",
+        MockReply::task(&fixtures::task("default", TaskState::Completed)),
+    );
+    server.on_next(
+        "message/send",
+        MockReply::task(&fixtures::task("first", TaskState::Working)),
+    );
+    let client = client(&server).await;
+
+    let options = CallOptions::new().with_header("X-Test", "yes");
+    let params = SendMessageParams {
+        message: Message::user("m1", "hello"),
+        configuration: None,
+        metadata: None,
+        tenant: None,
+    };
+    let first = client
+        .send_message_with_options(params, &options)
+        .await
+        .unwrap();
+    assert_eq!(task_id(first), "first");
+    assert_eq!(task_id(client.send_text("again").await.unwrap()), "default");
+    assert_eq!(task_id(client.send_text("more").await.unwrap()), "default");
+
+    let requests = server.requests_for("message/send");
+    assert_eq!(requests.len(), 3);
+    assert_eq!(requests[0].header("x-test"), Some("yes"));
+    let params: SendMessageParams = requests[0].params_as().unwrap();
+    assert_eq!(params.message.message_id, "m1");
+
+    let err = client.get_task_by_id("t1", None).await.unwrap_err();
+    assert_eq!(err.code(), error::METHOD_NOT_FOUND);
+    assert_eq!(server.requests().len(), 4);
+}
+
+#[tokio::test]
+async fn test_stream_events_and_errors() {
+    let server = MockAgentServer::start().await.unwrap();
+    server.on(
+        "message/stream",
+        MockReply::events(fixtures::completed_run("t1", &["Hello, ", "world"]))
+            .with_event_delay(Duration::from_millis(5)),
+    );
+    server.on(
+        "tasks/cancel",
+        MockReply::error(
+            A2AError::task_not_cancelable("Already done").with_data(json!({"state": "completed"})),
+        ),
+    );
+    let client = client(&server).await;
+
+    let mut stream = client.send_text_stream("hi").await.unwrap();
+    let mut kinds = Vec::new();
+    while let Some(event) = stream.next().await {
+        kinds.push(event.unwrap().kind());
+    }
+    assert_eq!(
+        kinds,
+        [
+            "task",
+            "status-update",
+            "artifact-update",
+            "artifact-update",
+            "status-update"
+        ]
+    );
+
+    let err = client.cancel_task_by_id("t1").await.unwrap_err();
+    assert_eq!(err.code(), error::TASK_NOT_CANCELABLE);
+    assert_eq!(err.data().unwrap()["state"], "completed");
+}
+
+#[tokio::test]
+async fn test_faults() {
+    let server = MockAgentServer::start().await.unwrap();
+    let task = fixtures::task("t1", TaskState::Completed);
+    server.on_next(
+        "message/send",
+        MockReply::task(&task).with_fault(Fault::MalformedJson),
+    );
+    server.on_next(
+        "message/send",
+        MockReply::task(&task).with_fault(Fault::Status(503)),
+    );
+    server.on(
+        "message/send",
+        MockReply::task(&task).with_delay(Duration::from_millis(500)),
+    );
+    server.on(
+        "message/stream",
+        MockReply::events(fixtures::completed_run("t1", &["a", "b"]))
+            .with_fault(Fault::DropStream { after: 2 }),
+    );
+    let transport =
+        JsonRpcTransport::new(server.endpoint()).with_timeout(Duration::from_millis(100));
+    let client = A2AClient::with_transport(Box::new(transport));
+
+    let err = client.send_text("malformed").await.unwrap_err();
+    assert!(matches!(err, A2AError::InvalidJson(_)), "{err}");
+    let err = client.send_text("unavailable").await.unwrap_err();
+    assert!(err.to_string().contains("503"), "{err}");
+    let err = client.send_text("slow").await.unwrap_err();
+    assert!(matches!(err, A2AError::Timeout(_)), "{err}");
+
+    let mut stream = self::client(&server)
+        .await
+        .send_text_stream("dropped")
+        .await
+        .unwrap();
+    let mut events = Vec::new();
+    while let Some(event) = stream.next().await {
+        match event {
+            Ok(event) => events.push(event),
+            Err(_) => break,
+        }
+    }
+    assert_eq!(events.len(), 2);
+}
@@ -0,0 +1,78 @@
+//! Typed `Part::Data` payloads: `Part::from_serialize`, `Part::data_as`,
+//! `Message::first_data_as` and `Artifact::data_as`.
+
+use std::collections::BTreeMap;
+
+use a2a_rs::error::A2AError;
+use a2a_rs::types::*;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Booking {
+    flight_number: String,
+    seats: u32,
+}
+
+fn booking() -> Booking {
+    Booking {
+        flight_number: "LH400".to_string(),
+        seats: 2,
+    }
+}
+
+#[test]
+fn test_from_serialize_roundtrips_through_json() {
+    let part = Part::from_serialize(&booking()).unwrap();
+    let json = serde_json::to_value(&part).unwrap();
+    assert_eq!(
+        json,
+        json!({"kind": "data", "data": {"flightNumber": "LH400", "seats": 2}})
+    );
+
+    let back: Part = serde_json::from_value(json).unwrap();
+    assert_eq!(back.data_as::<Booking>().unwrap(), booking());
+}
+
+#[test]
+fn test_data_as_errors() {
+    let err = Part::text("hi").data_as::<Booking>().unwrap_err();
+    assert!(matches!(err, A2AError::InvalidParams { .. }), "{err}");
+
+    let err = Part::data(json!({"flightNumber": 400}))
+        .data_as::<Booking>()
+        .unwrap_err();
+    assert!(matches!(err, A2AError::InvalidParams { .. }));
+    assert!(err.to_string().contains("Booking"), "{err}");
+
+    let mut bad_keys = BTreeMap::new();
+    bad_keys.insert((1, 2), "tuple keys aren't JSON");
+    assert!(Part::from_serialize(&bad_keys).is_err());
+}
+
+#[test]
+fn test_message_and_artifact_pick_the_first_data_part() {
+    let mut message = Message::user("m1", "book this");
+    assert!(message.first_data_as::<Booking>().is_err());
+
+    message
+        .parts
+        .push(Part::from_serialize(&booking()).unwrap());
+    message.parts.push(Part::data(json!({"ignored": true})));
+    assert_eq!(message.first_data_as::<Booking>().unwrap(), booking());
+
+    let artifact = Artifact {
+        artifact_id: "a1".to_string(),
+        name: None,
+        description: None,
+        parts: vec![
+            Part::text("summary"),
+            Part::from_serialize(&booking()).unwrap(),
+        ],
+        metadata: None,
+        extensions: None,
+    };
+    assert_eq!(artifact.data_as::<Booking>().unwrap(), booking());
+    assert_eq!(artifact.data_as::<serde_json::Value>().unwrap()["seats"], 2);
+}
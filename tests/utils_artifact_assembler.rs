@@ -0,0 +1,185 @@
+//! `ArtifactAssembler` and `SseStream::collect_artifacts()`: chunked
+//! artifact updates merged in order, with out-of-order and duplicate chunks.
+
+mod common;
+
+use std::sync::Arc;
+
+use a2a_rs::client::A2AClient;
+use a2a_rs::error::A2AResult;
+use a2a_rs::server::{AgentExecutor, EventQueue, RequestContext, TaskUpdater};
+use a2a_rs::types::*;
+use a2a_rs::utils::{get_artifact_text, ArtifactAssembler};
+use async_trait::async_trait;
+use serde_json::json;
+
+fn chunk(artifact_id: &str, text: &str, append: bool, last: bool) -> TaskArtifactUpdateEvent {
+    TaskArtifactUpdateEvent {
+        task_id: "t1".to_string(),
+        context_id: "c1".to_string(),
+        kind: "artifact-update".to_string(),
+        artifact: Artifact {
+            artifact_id: artifact_id.to_string(),
+            name: Some(format!("{artifact_id}.txt")),
+            description: None,
+            parts: vec![Part::text(text)],
+            metadata: None,
+            extensions: None,
+        },
+        append: Some(append),
+        last_chunk: Some(last),
+        metadata: None,
+    }
+}
+
+/// Chunk number `index` of `artifact_id`, of `count` chunks.
+fn indexed(artifact_id: &str, index: u64, count: u64) -> TaskArtifactUpdateEvent {
+    let mut update = chunk(
+        artifact_id,
+        &index.to_string(),
+        index > 0,
+        index + 1 == count,
+    );
+    update.metadata = Some(json!({"chunkIndex": index}));
+    update
+}
+
+fn text(assembler: &ArtifactAssembler, artifact_id: &str) -> String {
+    get_artifact_text(assembler.get(artifact_id).unwrap(), "")
+}
+
+#[test]
+fn test_interleaved_chunks_in_first_seen_order() {
+    let mut assembler = ArtifactAssembler::new();
+    assert!(assembler.push(&chunk("b", "B1", false, false)).is_none());
+    assert!(assembler.push(&chunk("a", "A1", false, false)).is_none());
+    let b = assembler.push(&chunk("b", "B2", true, true)).unwrap();
+    assert_eq!(get_artifact_text(&b, ""), "B1B2");
+    assert_eq!(b.name.as_deref(), Some("b.txt"));
+    assert!(assembler.is_artifact_complete("b"));
+    assert!(!assembler.is_complete());
+
+    let a = assembler.push(&chunk("a", "A2", true, true)).unwrap();
+    assert_eq!(get_artifact_text(&a, ""), "A1A2");
+    assert!(assembler.is_complete());
+
+    let ids: Vec<_> = assembler
+        .into_artifacts()
+        .into_iter()
+        .map(|artifact| artifact.artifact_id)
+        .collect();
+    assert_eq!(ids, ["b", "a"]);
+}
+
+#[test]
+fn test_out_of_order_indexed_chunks() {
+    let mut assembler = ArtifactAssembler::new();
+    for index in [3, 1, 2] {
+        assert!(assembler.push(&indexed("a", index, 4)).is_none());
+    }
+    assert_eq!(assembler.pending_chunks(), 3);
+    assert!(assembler.get("a").is_none());
+
+    let artifact = assembler.push(&indexed("a", 0, 4)).unwrap();
+    assert_eq!(get_artifact_text(&artifact, ""), "0123");
+    assert_eq!(assembler.pending_chunks(), 0);
+    assert!(assembler.is_complete());
+}
+
+#[test]
+fn test_duplicate_indexed_chunks_are_dropped() {
+    let mut assembler = ArtifactAssembler::new();
+    assembler.push(&indexed("a", 0, 3));
+    assembler.push(&indexed("a", 0, 3));
+    assembler.push(&indexed("a", 2, 3));
+    assembler.push(&indexed("a", 2, 3));
+    assert_eq!(assembler.pending_chunks(), 1);
+    assert!(assembler.push(&indexed("a", 1, 3)).is_some());
+    assert!(assembler.push(&indexed("a", 1, 3)).is_none());
+    assert_eq!(text(&assembler, "a"), "012");
+}
+
+#[test]
+fn test_retransmissions_after_last_chunk_are_dropped() {
+    let mut assembler = ArtifactAssembler::new();
+    assembler.push(&chunk("a", "x", false, false));
+    // Mid-stream repeats are kept: token streams repeat legitimately.
+    assembler.push(&chunk("a", "x", true, false));
+    assert!(assembler.push(&chunk("a", "y", true, true)).is_some());
+    assert!(assembler.push(&chunk("a", "y", true, true)).is_none());
+    assert!(assembler.push(&chunk("a", "z", true, false)).is_none());
+    assert_eq!(text(&assembler, "a"), "xxy");
+
+    // A new first chunk restarts the artifact.
+    assembler.push(&chunk("a", "fresh", false, false));
+    assert_eq!(text(&assembler, "a"), "fresh");
+    assert!(!assembler.is_complete());
+}
+
+#[test]
+fn test_incomplete_artifacts_are_returned_as_far_as_they_got() {
+    let mut assembler = ArtifactAssembler::new();
+    assembler.push(&chunk("a", "half", false, false));
+    assembler.push(&indexed("b", 1, 2));
+    let artifacts = assembler.into_artifacts();
+    assert_eq!(artifacts.len(), 1);
+    assert_eq!(get_artifact_text(&artifacts[0], ""), "half");
+}
+
+/// Streams two artifacts with interleaved chunks, then a status update.
+struct ChunkingAgent;
+
+#[async_trait]
+impl AgentExecutor for ChunkingAgent {
+    async fn execute(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        let updater = TaskUpdater::new(event_queue, context.task_id, context.context_id);
+        let chunks = [
+            ("left", "L1", false, false),
+            ("right", "R1", false, false),
+            ("left", "L2", true, true),
+            ("right", "R2", true, false),
+        ];
+        for (id, text, append, last) in chunks {
+            updater
+                .add_artifact(
+                    vec![Part::text(text)],
+                    Some(id.to_string()),
+                    None,
+                    None,
+                    Some(append),
+                    Some(last),
+                    None,
+                )
+                .await?;
+        }
+        updater.complete(None).await
+    }
+
+    async fn cancel(&self, context: RequestContext, event_queue: EventQueue) -> A2AResult<()> {
+        TaskUpdater::new(event_queue, context.task_id, context.context_id)
+            .cancel(None)
+            .await
+    }
+}
+
+#[tokio::test]
+async fn test_collect_artifacts_from_a_live_stream() {
+    let (base_url, _handle) = common::start_test_server(Arc::new(ChunkingAgent)).await;
+    let client = A2AClient::from_endpoint(&format!("{base_url}/a2a"));
+
+    let stream = client.send_text_stream("go").await.unwrap();
+    let artifacts = stream.collect_artifacts().await.unwrap();
+    let texts: Vec<_> = artifacts
+        .iter()
+        .map(|artifact| {
+            (
+                artifact.artifact_id.as_str(),
+                get_artifact_text(artifact, ""),
+            )
+        })
+        .collect();
+    assert_eq!(
+        texts,
+        [("left", "L1L2".to_string()), ("right", "R1R2".to_string())]
+    );
+}
@@ -1,7 +1,7 @@
 //! Tests for utils::task module
 //! Ported from reference/a2a-python/tests/utils/test_task.py
 
-use a2a_rs::types::{Artifact, Message, Part, Role, TaskState};
+use a2a_rs::types::{Message, Part, Role, TaskState};
 use a2a_rs::utils::{completed_task, new_task, new_text_artifact};
 use uuid::Uuid;
 